@@ -0,0 +1,203 @@
+//! 大量のタスクに対する一覧・検索・統計のベンチマーク
+//!
+//! 合成したタスク（1万件・10万件）をメモリとSQLiteに用意し、公開APIの`TaskService`で計測します。
+//! SQLへの条件の変換などの性能への影響を、変更の前後で比較するために使います。
+//!
+//! ```sh
+//! cargo bench --bench task_queries
+//! # 件数を絞る場合（カンマ区切り）
+//! YARU_BENCH_SIZES=10000 cargo bench --bench task_queries -- search
+//! ```
+
+use chrono::{Duration, NaiveDate};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use tempfile::TempDir;
+use tokio::runtime::Runtime;
+use yaru::api::{
+    CreateTagDTO, CreateTaskDTO, Priority, SearchField, Status, TaskFilter, TaskSortKey, Yaru,
+};
+
+/// 既定の件数
+const DEFAULT_SIZES: &[usize] = &[10_000, 100_000];
+
+/// 1回のトランザクションで追加する件数
+const SEED_CHUNK: usize = 5_000;
+
+/// タイトルに使う語彙
+const WORDS: &[&str] = &[
+    "資料",
+    "作成",
+    "レビュー",
+    "会議",
+    "請求書",
+    "買い物",
+    "report",
+    "deploy",
+    "refactor",
+    "backup",
+];
+
+/// 計測する件数（環境変数`YARU_BENCH_SIZES`で上書きできる）
+fn sizes() -> Vec<usize> {
+    std::env::var("YARU_BENCH_SIZES")
+        .ok()
+        .map(|value| {
+            value
+                .split(',')
+                .map(|size| {
+                    size.trim()
+                        .parse()
+                        .expect("YARU_BENCH_SIZES must be numbers")
+                })
+                .collect()
+        })
+        .unwrap_or_else(|| DEFAULT_SIZES.to_vec())
+}
+
+/// 計測対象の保存先
+struct Backend {
+    name: &'static str,
+    yaru: Yaru,
+    /// SQLiteのデータベースファイルを計測の終了まで保持する
+    _dir: Option<TempDir>,
+}
+
+/// i番目の合成タスク（内容は件数によらず決定的）
+fn synthetic_task(i: usize, tag_ids: &[i32]) -> CreateTaskDTO {
+    let base = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+    CreateTaskDTO {
+        title: format!(
+            "{} {} #{}",
+            WORDS[i % WORDS.len()],
+            WORDS[(i / WORDS.len()) % WORDS.len()],
+            i
+        ),
+        description: Some(format!("{}の作業メモ", WORDS[(i * 7) % WORDS.len()])),
+        status: Some([Status::Pending, Status::InProgress, Status::Completed][i % 3]),
+        priority: Some(
+            [
+                Priority::Low,
+                Priority::Medium,
+                Priority::High,
+                Priority::Critical,
+            ][i % 4],
+        ),
+        tags: vec![tag_ids[i % tag_ids.len()]],
+        due_date: i
+            .is_multiple_of(2)
+            .then(|| base + Duration::days((i % 365) as i64)),
+        custom_fields: Default::default(),
+        recurrence: None,
+        estimate: None,
+        context: None,
+        assignee: None,
+    }
+}
+
+/// タグと合成タスクを登録する
+async fn seed(yaru: &Yaru, size: usize) {
+    let mut tag_ids = Vec::new();
+    for name in ["仕事", "個人", "学習"] {
+        let tag = yaru
+            .tags()
+            .add(CreateTagDTO {
+                name: name.to_string(),
+                description: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        tag_ids.push(tag.id);
+    }
+    for start in (0..size).step_by(SEED_CHUNK) {
+        let tasks = (start..(start + SEED_CHUNK).min(size))
+            .map(|i| synthetic_task(i, &tag_ids))
+            .collect();
+        yaru.tasks().add_many(tasks).await.unwrap();
+    }
+}
+
+/// 件数ごとにメモリとSQLiteの保存先を用意する
+fn backends(runtime: &Runtime, size: usize) -> Vec<Backend> {
+    runtime.block_on(async {
+        let in_memory = Yaru::in_memory();
+        seed(&in_memory, size).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!(
+            "sqlite://{}?mode=rwc",
+            dir.path().join("bench.db").display()
+        );
+        let sqlite = Yaru::open(&url).await.unwrap();
+        seed(&sqlite, size).await;
+
+        vec![
+            Backend {
+                name: "in_memory",
+                yaru: in_memory,
+                _dir: None,
+            },
+            Backend {
+                name: "sqlite",
+                yaru: sqlite,
+                _dir: Some(dir),
+            },
+        ]
+    })
+}
+
+fn task_queries(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    for size in sizes() {
+        let backends = backends(&runtime, size);
+
+        let mut group = c.benchmark_group(format!("tasks_{}", size));
+        // 10万件では1回の計測に時間がかかるため、標本数を減らす
+        group.sample_size(10);
+        for backend in &backends {
+            let tasks = backend.yaru.tasks();
+            group.bench_function(BenchmarkId::new("list", backend.name), |b| {
+                b.to_async(&runtime)
+                    .iter(|| async { black_box(tasks.list().await.unwrap()) })
+            });
+            group.bench_function(BenchmarkId::new("list_pending_due", backend.name), |b| {
+                let filters = [
+                    TaskFilter::Status(Status::Pending),
+                    TaskFilter::DueBefore(NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()),
+                ];
+                b.to_async(&runtime).iter(|| async {
+                    black_box(
+                        tasks
+                            .list_filtered(&filters, TaskSortKey::Id)
+                            .await
+                            .unwrap(),
+                    )
+                })
+            });
+            group.bench_function(BenchmarkId::new("search", backend.name), |b| {
+                b.to_async(&runtime).iter(|| async {
+                    black_box(tasks.search("資料 report", SearchField::All).await.unwrap())
+                })
+            });
+            group.bench_function(BenchmarkId::new("stats", backend.name), |b| {
+                b.to_async(&runtime)
+                    .iter(|| async { black_box(tasks.stats().await.unwrap()) })
+            });
+        }
+        group.finish();
+
+        runtime.block_on(async {
+            for backend in backends {
+                backend.yaru.close().await.unwrap();
+            }
+        });
+    }
+}
+
+criterion_group!(benches, task_queries);
+criterion_main!(benches);
@@ -2,6 +2,13 @@
 
 pub mod prelude;
 
+pub mod checklist_items;
+pub mod custom_fields;
+pub mod external_refs;
+pub mod settings;
 pub mod tags;
+pub mod task_links;
+pub mod task_status_changes;
 pub mod task_tags;
 pub mod tasks;
+pub mod time_entries;
@@ -1,5 +1,12 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
+pub use super::checklist_items::Entity as ChecklistItems;
+pub use super::custom_fields::Entity as CustomFields;
+pub use super::external_refs::Entity as ExternalRefs;
+pub use super::settings::Entity as Settings;
 pub use super::tags::Entity as Tags;
+pub use super::task_links::Entity as TaskLinks;
+pub use super::task_status_changes::Entity as TaskStatusChanges;
 pub use super::task_tags::Entity as TaskTags;
 pub use super::tasks::Entity as Tasks;
+pub use super::time_entries::Entity as TimeEntries;
@@ -16,12 +16,59 @@ pub struct Model {
     pub updated_at: DateTimeWithTimeZone,
     pub due_date: Option<Date>,
     pub completed_at: Option<DateTimeWithTimeZone>,
+    pub recurrence: Option<String>,
+    pub snoozed_until: Option<Date>,
+    pub estimate_minutes: Option<i32>,
+    pub context: Option<String>,
+    pub assignee: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::checklist_items::Entity")]
+    ChecklistItems,
+    #[sea_orm(has_many = "super::custom_fields::Entity")]
+    CustomFields,
+    #[sea_orm(has_many = "super::external_refs::Entity")]
+    ExternalRefs,
+    #[sea_orm(has_many = "super::task_links::Entity")]
+    TaskLinks,
+    #[sea_orm(has_many = "super::task_status_changes::Entity")]
+    TaskStatusChanges,
     #[sea_orm(has_many = "super::task_tags::Entity")]
     TaskTags,
+    #[sea_orm(has_many = "super::time_entries::Entity")]
+    TimeEntries,
+}
+
+impl Related<super::checklist_items::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChecklistItems.def()
+    }
+}
+
+impl Related<super::custom_fields::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomFields.def()
+    }
+}
+
+impl Related<super::external_refs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ExternalRefs.def()
+    }
+}
+
+impl Related<super::task_links::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskLinks.def()
+    }
+}
+
+impl Related<super::task_status_changes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskStatusChanges.def()
+    }
 }
 
 impl Related<super::task_tags::Entity> for Entity {
@@ -30,6 +77,12 @@ impl Related<super::task_tags::Entity> for Entity {
     }
 }
 
+impl Related<super::time_entries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TimeEntries.def()
+    }
+}
+
 impl Related<super::tags::Entity> for Entity {
     fn to() -> RelationDef {
         super::task_tags::Relation::Tags.def()
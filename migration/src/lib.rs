@@ -2,6 +2,20 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20251231_013331_create_tasks_and_tags_tables;
 mod m20260101_010000_add_due_date_and_completed_at_to_tasks;
+mod m20261017_000000_create_settings_table;
+mod m20261018_000000_create_time_entries_table;
+mod m20261019_000000_add_tag_name_nocase_index;
+mod m20261020_000000_add_parent_id_to_tags;
+mod m20261021_000000_create_custom_fields_table;
+mod m20261022_000000_create_checklist_items_table;
+mod m20261023_000000_create_task_links_table;
+mod m20261024_000000_create_external_refs_table;
+mod m20261025_000000_add_recurrence_to_tasks;
+mod m20261026_000000_add_snoozed_until_to_tasks;
+mod m20261027_000000_add_estimate_minutes_to_tasks;
+mod m20261028_000000_add_context_to_tasks;
+mod m20261029_000000_create_task_status_changes_table;
+mod m20261030_000000_add_assignee_to_tasks;
 pub mod seeder;
 
 pub struct Migrator;
@@ -12,6 +26,20 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20251231_013331_create_tasks_and_tags_tables::Migration),
             Box::new(m20260101_010000_add_due_date_and_completed_at_to_tasks::Migration),
+            Box::new(m20261017_000000_create_settings_table::Migration),
+            Box::new(m20261018_000000_create_time_entries_table::Migration),
+            Box::new(m20261019_000000_add_tag_name_nocase_index::Migration),
+            Box::new(m20261020_000000_add_parent_id_to_tags::Migration),
+            Box::new(m20261021_000000_create_custom_fields_table::Migration),
+            Box::new(m20261022_000000_create_checklist_items_table::Migration),
+            Box::new(m20261023_000000_create_task_links_table::Migration),
+            Box::new(m20261024_000000_create_external_refs_table::Migration),
+            Box::new(m20261025_000000_add_recurrence_to_tasks::Migration),
+            Box::new(m20261026_000000_add_snoozed_until_to_tasks::Migration),
+            Box::new(m20261027_000000_add_estimate_minutes_to_tasks::Migration),
+            Box::new(m20261028_000000_add_context_to_tasks::Migration),
+            Box::new(m20261029_000000_create_task_status_changes_table::Migration),
+            Box::new(m20261030_000000_add_assignee_to_tasks::Migration),
         ]
     }
 }
@@ -0,0 +1,61 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タスクに費やした作業時間（ポモドーロなど）の記録
+        // タスクを削除すると、そのタスクの作業記録も削除される
+        manager
+            .create_table(
+                Table::create()
+                    .table(TimeEntries::Table)
+                    .if_not_exists()
+                    .col(pk_auto(TimeEntries::Id))
+                    .col(integer(TimeEntries::TaskId))
+                    .col(timestamp_with_time_zone(TimeEntries::StartedAt))
+                    .col(timestamp_with_time_zone(TimeEntries::EndedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TimeEntries::Table, TimeEntries::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_time_entries_task_id")
+                    .table(TimeEntries::Table)
+                    .col(TimeEntries::TaskId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TimeEntries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TimeEntries {
+    Table,
+    Id,
+    TaskId,
+    StartedAt,
+    EndedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
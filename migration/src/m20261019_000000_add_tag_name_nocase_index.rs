@@ -0,0 +1,135 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+/// タグ名の最大バイト数（TagNameの上限と同じ）
+const MAX_TAG_NAME_BYTES: usize = 50;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        let backend = manager.get_database_backend();
+
+        // 大文字小文字だけが異なる既存の重複タグは、最初に作られたもの以外の名前にIDを付けて区別する
+        let duplicates = db
+            .query_all(Statement::from_string(
+                backend,
+                "SELECT id, name FROM tags \
+                 WHERE id NOT IN (SELECT MIN(id) FROM tags GROUP BY LOWER(name))",
+            ))
+            .await?;
+        for row in duplicates {
+            let id: i32 = row.try_get("", "id")?;
+            let name: String = row.try_get("", "name")?;
+            db.execute(Statement::from_sql_and_values(
+                backend,
+                "UPDATE tags SET name = ? WHERE id = ?",
+                [renamed_duplicate(&name, id).into(), id.into()],
+            ))
+            .await?;
+        }
+
+        // タグ名を大文字小文字を区別せずに一意にする
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_tags_name_nocase ON tags (name COLLATE NOCASE)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_tags_name_nocase")
+                    .table(Tags::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// 重複したタグの名前に` (ID)`を付ける
+///
+/// タグ名の上限を超えないように、元の名前を文字の境界で切り詰めます。
+fn renamed_duplicate(name: &str, id: i32) -> String {
+    let suffix = format!(" ({})", id);
+    let mut end = MAX_TAG_NAME_BYTES
+        .saturating_sub(suffix.len())
+        .min(name.len());
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", &name[..end], suffix)
+}
+
+#[derive(DeriveIden)]
+enum Tags {
+    Table,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm_migration::sea_orm::Database;
+
+    #[test]
+    fn test_renamed_duplicate_fits_tag_name_limit() {
+        // Act
+        let ascii = renamed_duplicate(&"a".repeat(48), 12);
+        // 3バイトの文字の途中で切らない
+        let multibyte = renamed_duplicate(&"あ".repeat(16), 7);
+
+        // Assert
+        assert_eq!(ascii, format!("{} (12)", "a".repeat(45)));
+        assert_eq!(multibyte, format!("{} (7)", "あ".repeat(15)));
+        assert!(multibyte.len() <= MAX_TAG_NAME_BYTES);
+        assert_eq!(renamed_duplicate("Work", 3), "Work (3)");
+    }
+
+    #[tokio::test]
+    async fn test_up_renames_duplicates_within_tag_name_limit() {
+        // Arrange: 大文字小文字だけが異なる48バイトのタグ名
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db.execute_unprepared(
+            "CREATE TABLE tags (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL)",
+        )
+        .await
+        .unwrap();
+        let name = "a".repeat(48);
+        db.execute_unprepared(&format!(
+            "INSERT INTO tags (name) VALUES ('{}'), ('{}')",
+            name,
+            name.to_uppercase()
+        ))
+        .await
+        .unwrap();
+
+        // Act
+        Migration.up(&SchemaManager::new(&db)).await.unwrap();
+
+        // Assert
+        let rows = db
+            .query_all(Statement::from_string(
+                db.get_database_backend(),
+                "SELECT name FROM tags ORDER BY id",
+            ))
+            .await
+            .unwrap();
+        let names: Vec<String> = rows
+            .iter()
+            .map(|row| row.try_get("", "name").unwrap())
+            .collect();
+        assert_eq!(names, vec![name, format!("{} (2)", "A".repeat(46))]);
+        assert!(names.iter().all(|name| name.len() <= MAX_TAG_NAME_BYTES));
+    }
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タスクごとのカスタムフィールド（キーと値の組）
+        // タスクを削除すると、そのタスクのカスタムフィールドも削除される
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomFields::Table)
+                    .if_not_exists()
+                    .col(pk_auto(CustomFields::Id))
+                    .col(integer(CustomFields::TaskId))
+                    .col(string(CustomFields::Key))
+                    .col(text(CustomFields::Value))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CustomFields::Table, CustomFields::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 1つのタスクに同じキーのフィールドは1つだけ
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_custom_fields_task_id_key")
+                    .table(CustomFields::Table)
+                    .col(CustomFields::TaskId)
+                    .col(CustomFields::Key)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CustomFields::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CustomFields {
+    Table,
+    Id,
+    TaskId,
+    Key,
+    Value,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タスクごとのチェックリスト項目（positionの昇順で表示）
+        // タスクを削除すると、そのタスクのチェックリストも削除される
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChecklistItems::Table)
+                    .if_not_exists()
+                    .col(pk_auto(ChecklistItems::Id))
+                    .col(integer(ChecklistItems::TaskId))
+                    .col(integer(ChecklistItems::Position))
+                    .col(text(ChecklistItems::Text))
+                    .col(boolean(ChecklistItems::Done).default(false))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ChecklistItems::Table, ChecklistItems::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_checklist_items_task_id_position")
+                    .table(ChecklistItems::Table)
+                    .col(ChecklistItems::TaskId)
+                    .col(ChecklistItems::Position)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ChecklistItems::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ChecklistItems {
+    Table,
+    Id,
+    TaskId,
+    Position,
+    Text,
+    Done,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
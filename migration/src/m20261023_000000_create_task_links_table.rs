@@ -0,0 +1,77 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タスクとgitのコミット・ブランチの紐づけ（branchはdetached HEADの場合NULL）
+        // タスクを削除すると、そのタスクの紐づけも削除される
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskLinks::Table)
+                    .if_not_exists()
+                    .col(pk_auto(TaskLinks::Id))
+                    .col(integer(TaskLinks::TaskId))
+                    .col(text(TaskLinks::Repository))
+                    .col(string_null(TaskLinks::Branch))
+                    .col(string(TaskLinks::CommitHash))
+                    .col(timestamp_with_time_zone(TaskLinks::LinkedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TaskLinks::Table, TaskLinks::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_links_task_id")
+                    .table(TaskLinks::Table)
+                    .col(TaskLinks::TaskId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // フックから現在のブランチに紐づくタスクを探すためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_links_repository_branch")
+                    .table(TaskLinks::Table)
+                    .col(TaskLinks::Repository)
+                    .col(TaskLinks::Branch)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskLinks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskLinks {
+    Table,
+    Id,
+    TaskId,
+    Repository,
+    Branch,
+    CommitHash,
+    LinkedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
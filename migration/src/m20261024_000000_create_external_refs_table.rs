@@ -0,0 +1,81 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タスクと外部サービスの項目（GitHubのIssueなど）の対応
+        // remote_updated_at・synced_statusは差分同期のために最後に同期した時点の状態を持つ
+        // タスクを削除すると、そのタスクの対応も削除される
+        manager
+            .create_table(
+                Table::create()
+                    .table(ExternalRefs::Table)
+                    .if_not_exists()
+                    .col(pk_auto(ExternalRefs::Id))
+                    .col(integer(ExternalRefs::TaskId))
+                    .col(string(ExternalRefs::Source))
+                    .col(string(ExternalRefs::ExternalId))
+                    .col(text_null(ExternalRefs::Url))
+                    .col(timestamp_with_time_zone(ExternalRefs::RemoteUpdatedAt))
+                    .col(string(ExternalRefs::SyncedStatus))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ExternalRefs::Table, ExternalRefs::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_external_refs_task_id")
+                    .table(ExternalRefs::Table)
+                    .col(ExternalRefs::TaskId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 外部の項目は1つのタスクにのみ対応する
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_external_refs_source_external_id")
+                    .table(ExternalRefs::Table)
+                    .col(ExternalRefs::Source)
+                    .col(ExternalRefs::ExternalId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ExternalRefs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ExternalRefs {
+    Table,
+    Id,
+    TaskId,
+    Source,
+    ExternalId,
+    Url,
+    RemoteUpdatedAt,
+    SyncedStatus,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
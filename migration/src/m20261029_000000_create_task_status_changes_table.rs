@@ -0,0 +1,63 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // タスクのステータスの変更履歴（フロー統計でステータスごとの滞在時間を求めるために使用）
+        // タスクを削除すると、そのタスクの履歴も削除される
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskStatusChanges::Table)
+                    .if_not_exists()
+                    .col(pk_auto(TaskStatusChanges::Id))
+                    .col(integer(TaskStatusChanges::TaskId))
+                    .col(string(TaskStatusChanges::FromStatus))
+                    .col(string(TaskStatusChanges::ToStatus))
+                    .col(timestamp_with_time_zone(TaskStatusChanges::ChangedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TaskStatusChanges::Table, TaskStatusChanges::TaskId)
+                            .to(Tasks::Table, Tasks::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_status_changes_task_id")
+                    .table(TaskStatusChanges::Table)
+                    .col(TaskStatusChanges::TaskId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskStatusChanges::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskStatusChanges {
+    Table,
+    Id,
+    TaskId,
+    FromStatus,
+    ToStatus,
+    ChangedAt,
+}
+
+#[derive(DeriveIden)]
+enum Tasks {
+    Table,
+    Id,
+}
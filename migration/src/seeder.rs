@@ -6,7 +6,7 @@ use sea_orm_migration::prelude::*;
 /// 環境変数RUN_SEEDERが設定されている場合のみシーダーを実行する。
 /// マイグレーションの`up()`メソッド内で以下のように使用する:
 ///
-/// ```rust
+/// ```rust,ignore
 /// use crate::seeder;
 ///
 /// async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
@@ -63,7 +63,7 @@ use sea_orm_migration::prelude::*;
 /// `async { seed_data(txn).await }` が返すFutureは `txn` の参照を保持します。
 /// したがって、Futureのライフタイムは `txn` のライフタイムに束縛されます。
 ///
-/// ```rust
+/// ```rust,ignore
 /// // ❌ 理想だが表現できない
 /// F: for<'b> FnOnce(&'b DatabaseTransaction) -> Fut<'b>
 /// //                                               ^^^^
@@ -73,7 +73,7 @@ use sea_orm_migration::prelude::*;
 ///
 /// ## 3. トレイトオブジェクトで解決
 ///
-/// ```rust
+/// ```rust,ignore
 /// // ✅ dyn Future + 'b でライフタイム依存を直接記述
 /// F: for<'b> FnOnce(&'b DatabaseTransaction)
 ///     -> Pin<Box<dyn Future<Output = Result<(), DbErr>> + Send + 'b>>
@@ -87,7 +87,7 @@ use sea_orm_migration::prelude::*;
 ///
 /// ## 失敗例との比較
 ///
-/// ```rust
+/// ```rust,ignore
 /// // ❌ 失敗例1: Futのライフタイム依存が表現できない
 /// F: for<'b> FnOnce(&'b DatabaseTransaction) -> Fut,
 /// Fut: Future<Output = Result<(), DbErr>>,
@@ -114,7 +114,7 @@ use sea_orm_migration::prelude::*;
 ///
 /// # 使用例
 ///
-/// ```rust
+/// ```rust,ignore
 /// seeder::run_if_enabled(manager, |txn| {
 ///     Box::pin(async {
 ///         // シーディング処理
@@ -0,0 +1,376 @@
+//! 他のRustプログラムからyaruを組み込むための公開API
+//!
+//! `Yaru`で保存先を開き、`TaskService`・`TagService`からタスクとタグを操作します。
+//! 入出力にはCLIと同じDTOを使用します。
+//!
+//! ```rust,ignore
+//! let yaru = yaru::api::Yaru::open("sqlite://tasks.db?mode=rwc").await?;
+//! let task = yaru
+//!     .tasks()
+//!     .add(CreateTaskDTO {
+//!         title: "請求書を送る".to_string(),
+//!         description: None,
+//!         status: None,
+//!         priority: Some(Priority::High),
+//!         tags: vec![],
+//!         due_date: None,
+//!         custom_fields: Default::default(),
+//!         recurrence: None,
+//!     })
+//!     .await?;
+//! yaru.tasks().complete(task.id).await?;
+//! yaru.close().await?;
+//! ```
+
+mod error;
+mod tag_service;
+mod task_service;
+
+pub use crate::{
+    application::{
+        dto::{
+            CreateTagDTO, CreateTaskDTO, DurationSummaryDTO, FlowStatsDTO, StatsDTO, TagDTO,
+            TagInfo, TaskDTO, UpdateTagDTO, UpdateTaskDTO,
+            task_dto::{ChecklistItemDTO, ExternalRefDTO, GitLinkDTO},
+        },
+        use_cases::task::list_tasks::{TaskFilter, TaskSortKey},
+    },
+    domain::task::{
+        specification::SearchField,
+        value_objects::{Priority, Status},
+    },
+};
+pub use error::{Error, Result};
+pub use tag_service::TagService;
+pub use task_service::TaskService;
+
+use crate::{
+    domain::{
+        tag::repository::TagRepository,
+        task::{repository::TaskRepository, status_transition::StatusTransitionPolicy},
+        unit_of_work::UnitOfWorkFactory,
+    },
+    infrastructure::{
+        DatabaseConnectionManager,
+        config::{SqliteConfig, StorageBackend},
+        load_config,
+    },
+    interface::persistence::{
+        in_memory::{InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory},
+        json::{JsonFileStore, JsonTagRepository, JsonTaskRepository, JsonUnitOfWorkFactory},
+        read_only::{ReadOnlyTagRepository, ReadOnlyTaskRepository, ReadOnlyUnitOfWorkFactory},
+        sea_orm::{
+            SeaOrmDatabaseAdmin, SeaOrmEncryptionManager, SeaOrmTagRepository,
+            SeaOrmTaskRepository, SeaOrmUnitOfWorkFactory,
+        },
+    },
+};
+use anyhow::Context;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+/// Yaru - 保存先を開いたハンドル
+///
+/// `tasks()`・`tags()`で取得したサービスは、同じ保存先を共有します。
+/// SQLiteの場合は、使い終わったら`close`で接続を閉じてください。
+pub struct Yaru {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    transition_policy: StatusTransitionPolicy,
+    db: Option<DatabaseConnection>,
+}
+
+impl Yaru {
+    /// 設定ファイル（`~/.config/yaru/config.toml`）の保存先を開く
+    ///
+    /// CLIと同じデータベースまたはJSONファイルを使用し、ステータス遷移の設定も反映します。
+    /// `[storage] read_only = true`の場合は、`read_only`を呼び出した状態で開きます。
+    pub async fn from_config() -> Result<Self> {
+        let config = load_config()?;
+        let transition_policy = crate::build_transition_policy(&config.workflow)?;
+
+        let mut yaru = match config.storage.backend {
+            StorageBackend::Json => Self::open_json(&config.storage.json_path)?,
+            StorageBackend::Sqlite => {
+                let db = DatabaseConnectionManager::connect_from_config(&config).await?;
+                let admin = SeaOrmDatabaseAdmin::new(db.clone());
+                if config.storage.read_only {
+                    admin.check_up_to_date().await?;
+                } else {
+                    admin.ensure_up_to_date(config.storage.auto_migrate).await?;
+                }
+                Self::from_database(db, None).await?
+            }
+        };
+        yaru.transition_policy = transition_policy;
+        if config.storage.read_only {
+            yaru = yaru.read_only();
+        }
+        Ok(yaru)
+    }
+
+    /// SQLiteデータベースを開く（未適用のマイグレーションは適用する）
+    ///
+    /// 暗号化されたデータベースは`open_encrypted`で開いてください。
+    pub async fn open(database_url: &str) -> Result<Self> {
+        let db = Self::connect(database_url).await?;
+        Self::from_database(db, None).await
+    }
+
+    /// 暗号化されたSQLiteデータベースをパスフレーズで開く
+    pub async fn open_encrypted(database_url: &str, passphrase: &str) -> Result<Self> {
+        let db = Self::connect(database_url).await?;
+        Self::from_database(db, Some(passphrase)).await
+    }
+
+    /// JSONファイルを開く（存在しない場合は最初の保存時に作成する）
+    pub fn open_json(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let store = Arc::new(JsonFileStore::open(path.as_ref())?);
+        Ok(Self {
+            task_repository: Arc::new(JsonTaskRepository::new(store.clone())),
+            tag_repository: Arc::new(JsonTagRepository::new(store.clone())),
+            unit_of_work: Arc::new(JsonUnitOfWorkFactory::new(store)),
+            transition_policy: StatusTransitionPolicy::default(),
+            db: None,
+        })
+    }
+
+    /// メモリ上の保存先を作成（テストや一時的な利用向け）
+    pub fn in_memory() -> Self {
+        let task_repository = Arc::new(InMemoryTaskRepository::new());
+        let tag_repository = Arc::new(InMemoryTagRepository::new());
+        Self {
+            unit_of_work: Arc::new(InMemoryUnitOfWorkFactory::new(
+                task_repository.clone(),
+                tag_repository.clone(),
+            )),
+            task_repository,
+            tag_repository,
+            transition_policy: StatusTransitionPolicy::default(),
+            db: None,
+        }
+    }
+
+    /// 読み取り専用のハンドルにする
+    ///
+    /// 以降のタスク・タグの追加・更新・削除は、保存先を変更せずにエラーになります
+    /// （`Error::is_read_only`で判定できます）。認証のない参照用のエンドポイントなどに使います。
+    pub fn read_only(self) -> Self {
+        Self {
+            task_repository: Arc::new(ReadOnlyTaskRepository::new(self.task_repository)),
+            tag_repository: Arc::new(ReadOnlyTagRepository::new(self.tag_repository)),
+            unit_of_work: Arc::new(ReadOnlyUnitOfWorkFactory::new(self.unit_of_work)),
+            ..self
+        }
+    }
+
+    /// タスク操作のサービスを取得
+    pub fn tasks(&self) -> TaskService {
+        TaskService::new(
+            self.task_repository.clone(),
+            self.tag_repository.clone(),
+            self.unit_of_work.clone(),
+            self.transition_policy.clone(),
+        )
+    }
+
+    /// タグ操作のサービスを取得
+    pub fn tags(&self) -> TagService {
+        TagService::new(self.tag_repository.clone())
+    }
+
+    /// データベース接続を閉じる（SQLite以外では何もしない）
+    pub async fn close(self) -> Result<()> {
+        if let Some(db) = self.db {
+            db.close().await.context("Failed to close database")?;
+        }
+        Ok(())
+    }
+
+    /// データベースに接続し、スキーマを最新にする
+    async fn connect(database_url: &str) -> Result<DatabaseConnection> {
+        let db =
+            DatabaseConnectionManager::connect_with(database_url, &SqliteConfig::default()).await?;
+        SeaOrmDatabaseAdmin::new(db.clone())
+            .ensure_up_to_date(true)
+            .await?;
+        Ok(db)
+    }
+
+    /// 接続済みのデータベースからリポジトリを作成
+    ///
+    /// 暗号化されている場合はパスフレーズが必要です。
+    async fn from_database(db: DatabaseConnection, passphrase: Option<&str>) -> Result<Self> {
+        let encryption = SeaOrmEncryptionManager::new(db.clone());
+        let cipher = match (encryption.is_encrypted().await?, passphrase) {
+            (true, Some(passphrase)) => Some(Arc::new(encryption.unlock(passphrase).await?)),
+            (true, None) => {
+                return Err(anyhow::anyhow!(
+                    "Database is encrypted; open it with Yaru::open_encrypted"
+                )
+                .into());
+            }
+            (false, _) => None,
+        };
+
+        Ok(Self {
+            task_repository: Arc::new(
+                SeaOrmTaskRepository::new(db.clone()).with_cipher(cipher.clone()),
+            ),
+            tag_repository: Arc::new(SeaOrmTagRepository::new(db.clone())),
+            unit_of_work: Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone()).with_cipher(cipher)),
+            transition_policy: StatusTransitionPolicy::default(),
+            db: Some(db),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_task(title: &str, tags: Vec<i32>) -> CreateTaskDTO {
+        CreateTaskDTO {
+            title: title.to_string(),
+            description: None,
+            status: None,
+            priority: None,
+            tags,
+            due_date: None,
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_task_lifecycle() {
+        // Arrange
+        let yaru = Yaru::in_memory();
+        let tag = yaru
+            .tags()
+            .add(CreateTagDTO {
+                name: "仕事".to_string(),
+                description: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        // Act
+        let task = yaru
+            .tasks()
+            .add(new_task("請求書を送る", vec![tag.id]))
+            .await
+            .unwrap();
+        let completed = yaru.tasks().complete(task.id).await.unwrap();
+        let pending = yaru
+            .tasks()
+            .list_filtered(&[TaskFilter::Status(Status::Pending)], TaskSortKey::Id)
+            .await
+            .unwrap();
+        let found = yaru
+            .tasks()
+            .search("請求書", SearchField::Title)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(completed.status, Status::Completed);
+        assert_eq!(completed.tags[0].name, "仕事");
+        assert!(pending.is_empty());
+        assert_eq!(found.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_many_and_stats() {
+        // Arrange
+        let yaru = Yaru::in_memory();
+
+        // Act
+        let created = yaru
+            .tasks()
+            .add_many(vec![new_task("牛乳", vec![]), new_task("卵", vec![])])
+            .await
+            .unwrap();
+        yaru.tasks().complete(created[0].id).await.unwrap();
+        let stats = yaru.tasks().stats().await.unwrap();
+
+        // Assert
+        assert_eq!(created.len(), 2);
+        assert_eq!(created[1].title, "卵");
+        assert_eq!(stats.total_count, 2);
+        assert_eq!(stats.status_stats["completed"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_many_rolls_back_on_error() {
+        // Arrange
+        let yaru = Yaru::in_memory();
+
+        // Act: 2件目のタイトルが空のため失敗する
+        let result = yaru
+            .tasks()
+            .add_many(vec![new_task("牛乳", vec![]), new_task("", vec![])])
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert!(yaru.tasks().list().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_only_rejects_changes() {
+        // Arrange
+        let yaru = Yaru::in_memory();
+        let task = yaru.tasks().add(new_task("牛乳", vec![])).await.unwrap();
+        let yaru = yaru.read_only();
+
+        // Act
+        let added = yaru.tasks().add(new_task("卵", vec![])).await;
+        let completed = yaru.tasks().complete(task.id).await;
+        let tasks = yaru.tasks().list().await.unwrap();
+
+        // Assert
+        assert!(added.unwrap_err().is_read_only());
+        assert!(completed.unwrap_err().is_read_only());
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].status, Status::Pending);
+        assert!(!yaru.tasks().get(42).await.unwrap_err().is_read_only());
+    }
+
+    #[tokio::test]
+    async fn test_missing_task_returns_error() {
+        // Arrange
+        let yaru = Yaru::in_memory();
+
+        // Act
+        let result = yaru.tasks().get(42).await;
+
+        // Assert
+        assert!(result.unwrap_err().to_string().contains("42"));
+    }
+
+    #[tokio::test]
+    async fn test_open_sqlite_persists_tasks() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", dir.path().join("api.db").display());
+        let yaru = Yaru::open(&url).await.unwrap();
+        let task = yaru.tasks().add(new_task("牛乳", vec![])).await.unwrap();
+        yaru.close().await.unwrap();
+
+        // Act
+        let reopened = Yaru::open(&url).await.unwrap();
+        let tasks = reopened.tasks().list().await.unwrap();
+
+        // Assert
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].id, task.id);
+        assert_eq!(tasks[0].title, "牛乳");
+        reopened.close().await.unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+use crate::interface::persistence::read_only::ReadOnlyError;
+use std::fmt;
+
+/// 公開APIのエラー
+///
+/// 内部のエラーをまとめて保持します。`Display`は最上位のメッセージを、
+/// `{:#}`は原因を含めたメッセージを表示します。
+#[derive(Debug)]
+pub struct Error(anyhow::Error);
+
+/// 公開APIの結果型
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// 読み取り専用のハンドル（`Yaru::read_only`）で変更しようとしたエラーか
+    pub fn is_read_only(&self) -> bool {
+        self.0.chain().any(|cause| cause.is::<ReadOnlyError>())
+    }
+
+    /// 原因を含めたエラーの連鎖を取得
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> {
+        self.0.chain()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#}", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(error: anyhow::Error) -> Self {
+        Self(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_error_display_with_context() {
+        // Arrange
+        let error: Error = Err::<(), _>(anyhow::anyhow!("disk full"))
+            .context("Failed to save task")
+            .unwrap_err()
+            .into();
+
+        // Act & Assert
+        assert_eq!(error.to_string(), "Failed to save task");
+        assert_eq!(format!("{error:#}"), "Failed to save task: disk full");
+        assert_eq!(error.chain().count(), 2);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    api::Result,
+    application::{
+        dto::{CreateTagDTO, TagDTO, UpdateTagDTO},
+        use_cases::tag::{
+            add_tag::AddTagUseCase, delete_tag::DeleteTagUseCase, edit_tag::EditTagUseCase,
+            list_tags::ListTagsUseCase, show_tag::ShowTagUseCase,
+        },
+    },
+    domain::tag::repository::TagRepository,
+};
+use std::sync::Arc;
+
+/// TagService - タグ操作の公開API
+///
+/// CLIと同じユースケースを呼び出します。入出力にはDTOを使用します。
+#[derive(Clone)]
+pub struct TagService {
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+impl TagService {
+    pub(super) fn new(tag_repository: Arc<dyn TagRepository>) -> Self {
+        Self { tag_repository }
+    }
+
+    /// すべてのタグを取得
+    pub async fn list(&self) -> Result<Vec<TagDTO>> {
+        Ok(ListTagsUseCase::new(self.tag_repository.clone())
+            .execute()
+            .await?)
+    }
+
+    /// IDを指定してタグを取得（存在しない場合はエラー）
+    pub async fn get(&self, id: i32) -> Result<TagDTO> {
+        Ok(ShowTagUseCase::new(self.tag_repository.clone())
+            .execute(id)
+            .await?)
+    }
+
+    /// タグを追加
+    pub async fn add(&self, tag: CreateTagDTO) -> Result<TagDTO> {
+        Ok(AddTagUseCase::new(self.tag_repository.clone())
+            .execute(tag)
+            .await?)
+    }
+
+    /// タグを部分更新
+    pub async fn update(&self, id: i32, changes: UpdateTagDTO) -> Result<TagDTO> {
+        Ok(EditTagUseCase::new(self.tag_repository.clone())
+            .execute(id, changes)
+            .await?)
+    }
+
+    /// タグを削除
+    pub async fn delete(&self, id: i32) -> Result<()> {
+        Ok(DeleteTagUseCase::new(self.tag_repository.clone())
+            .execute(id)
+            .await?)
+    }
+}
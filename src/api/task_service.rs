@@ -0,0 +1,136 @@
+use crate::{
+    api::Result,
+    application::{
+        dto::{CreateTaskDTO, FlowStatsDTO, StatsDTO, TaskDTO, UpdateTaskDTO},
+        use_cases::task::{
+            add_task::AddTaskUseCase,
+            delete_task::DeleteTaskUseCase,
+            edit_task::EditTaskUseCase,
+            list_tasks::{ListTasksUseCase, TaskFilter, TaskSortKey},
+            search_tasks::{SearchFilter, SearchTasksUseCase},
+            show_stats::ShowStatsUseCase,
+            show_task::ShowTaskUseCase,
+        },
+    },
+    domain::{
+        tag::repository::TagRepository,
+        task::{
+            repository::TaskRepository, specification::SearchField,
+            status_transition::StatusTransitionPolicy, value_objects::Status,
+        },
+        unit_of_work::UnitOfWorkFactory,
+    },
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+/// TaskService - タスク操作の公開API
+///
+/// CLIと同じユースケースを呼び出します。入出力にはDTOを使用します。
+#[derive(Clone)]
+pub struct TaskService {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    transition_policy: StatusTransitionPolicy,
+}
+
+impl TaskService {
+    pub(super) fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
+        transition_policy: StatusTransitionPolicy,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            unit_of_work,
+            transition_policy,
+        }
+    }
+
+    /// すべてのタスクをID順に取得
+    pub async fn list(&self) -> Result<Vec<TaskDTO>> {
+        Ok(self.list_use_case().execute().await?)
+    }
+
+    /// 条件に一致するタスクを取得（条件はすべてAND結合）
+    pub async fn list_filtered(
+        &self,
+        filters: &[TaskFilter],
+        sort: TaskSortKey,
+    ) -> Result<Vec<TaskDTO>> {
+        Ok(self.list_use_case().execute_filtered(filters, sort).await?)
+    }
+
+    /// IDを指定してタスクを取得（存在しない場合はエラー）
+    pub async fn get(&self, id: i32) -> Result<TaskDTO> {
+        let use_case =
+            ShowTaskUseCase::new(self.task_repository.clone(), self.tag_repository.clone());
+        Ok(use_case.execute(id).await?)
+    }
+
+    /// キーワードでタスクを検索（構文は`yaru task search`と同じ）
+    pub async fn search(&self, keywords: &str, field: SearchField) -> Result<Vec<TaskDTO>> {
+        let use_case =
+            SearchTasksUseCase::new(self.task_repository.clone(), self.tag_repository.clone());
+        Ok(use_case
+            .execute(keywords, field, &SearchFilter::default())
+            .await?)
+    }
+
+    /// タスクを追加
+    pub async fn add(&self, task: CreateTaskDTO) -> Result<TaskDTO> {
+        let use_case = AddTaskUseCase::new(self.unit_of_work.clone());
+        Ok(use_case.execute(task).await?)
+    }
+
+    /// 複数のタスクを1つのトランザクションで追加（いずれかが失敗した場合はすべて取り消す）
+    pub async fn add_many(&self, tasks: Vec<CreateTaskDTO>) -> Result<Vec<TaskDTO>> {
+        let use_case = AddTaskUseCase::new(self.unit_of_work.clone());
+        let tasks = tasks.into_iter().map(|task| (task, Vec::new())).collect();
+        Ok(use_case.execute_batch(tasks, false).await?)
+    }
+
+    /// タスクを部分更新
+    pub async fn update(&self, id: i32, changes: UpdateTaskDTO) -> Result<TaskDTO> {
+        let use_case =
+            EditTaskUseCase::new(self.task_repository.clone(), self.tag_repository.clone())
+                .with_transition_policy(self.transition_policy.clone());
+        Ok(use_case.execute(id, changes).await?)
+    }
+
+    /// タスクを完了にする
+    pub async fn complete(&self, id: i32) -> Result<TaskDTO> {
+        let changes = UpdateTaskDTO {
+            status: Some(Status::Completed),
+            ..Default::default()
+        };
+        self.update(id, changes).await
+    }
+
+    /// タスクを削除
+    pub async fn delete(&self, id: i32) -> Result<()> {
+        let use_case = DeleteTaskUseCase::new(self.task_repository.clone());
+        Ok(use_case.execute(id).await?)
+    }
+
+    /// 全タスクの統計情報を取得（`yaru task stats`と同じ集計）
+    pub async fn stats(&self) -> Result<StatsDTO> {
+        let use_case =
+            ShowStatsUseCase::new(self.task_repository.clone(), self.tag_repository.clone());
+        Ok(use_case.execute().await?)
+    }
+
+    /// タスクの流れの統計を取得（`yaru task stats --flow`と同じ集計）
+    pub async fn flow_stats(&self) -> Result<FlowStatsDTO> {
+        let use_case =
+            ShowStatsUseCase::new(self.task_repository.clone(), self.tag_repository.clone());
+        Ok(use_case.execute_flow(Utc::now()).await?)
+    }
+
+    fn list_use_case(&self) -> ListTasksUseCase {
+        ListTasksUseCase::new(self.task_repository.clone(), self.tag_repository.clone())
+    }
+}
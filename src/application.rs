@@ -1,2 +1,3 @@
 pub mod dto;
+pub mod tag_cache;
 pub mod use_cases;
@@ -1,7 +1,28 @@
+pub mod agenda_dto;
+pub mod apply_dto;
+pub mod completion_dto;
+pub mod context_dto;
+pub mod estimate_accuracy_dto;
+pub mod flow_stats_dto;
 pub mod stats_dto;
+pub mod summary_dto;
 pub mod tag_dto;
+pub mod task_detail_dto;
 pub mod task_dto;
+pub mod task_group_dto;
+pub mod time_entry_dto;
+pub mod workload_dto;
 
+pub use agenda_dto::{AgendaDTO, AgendaDayDTO};
+pub use apply_dto::{ApplyOperation, ApplyReportDTO, OperationResultDTO, OperationStatus};
+pub use completion_dto::CompletionDTO;
+pub use context_dto::ContextUsageDTO;
+pub use estimate_accuracy_dto::{EstimateAccuracyDTO, EstimateAccuracyRowDTO};
+pub use flow_stats_dto::{DurationSummaryDTO, FlowStatsDTO};
 pub use stats_dto::StatsDTO;
+pub use summary_dto::TaskSummaryDTO;
 pub use tag_dto::{CreateTagDTO, TagDTO, UpdateTagDTO};
 pub use task_dto::{CreateTaskDTO, TagInfo, TaskDTO, UpdateTaskDTO};
+pub use task_group_dto::TaskGroupDTO;
+pub use time_entry_dto::TimeEntryDTO;
+pub use workload_dto::{WorkloadDTO, WorkloadDayDTO};
@@ -0,0 +1,40 @@
+use crate::application::dto::task_dto::TaskDTO;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 今日やるべきタスクと今後の予定をまとめたDTO
+///
+/// `yaru today`・`yaru agenda`で表示するために使用されます。
+/// 各セクションのタスクは優先度の高い順に並び、同じタスクは複数のセクションに含まれません。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgendaDTO {
+    /// 基準日（今日）
+    pub today: NaiveDate,
+    /// 期限切れの未完了のタスク
+    pub overdue: Vec<TaskDTO>,
+    /// 今日が期限の未完了のタスク
+    pub due_today: Vec<TaskDTO>,
+    /// 明日以降に期限がある未完了のタスク（日付ごと、日付の昇順）
+    pub upcoming: Vec<AgendaDayDTO>,
+    /// 上のセクションに含まれない進行中のタスク
+    pub in_progress: Vec<TaskDTO>,
+}
+
+impl AgendaDTO {
+    /// 表示するタスクがないか
+    pub fn is_empty(&self) -> bool {
+        self.overdue.is_empty()
+            && self.due_today.is_empty()
+            && self.upcoming.is_empty()
+            && self.in_progress.is_empty()
+    }
+}
+
+/// 1日分の予定を表すDTO
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgendaDayDTO {
+    /// 期限日
+    pub date: NaiveDate,
+    /// その日が期限のタスク
+    pub tasks: Vec<TaskDTO>,
+}
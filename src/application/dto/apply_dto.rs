@@ -0,0 +1,98 @@
+use crate::application::dto::{
+    CreateTagDTO, CreateTaskDTO, TagDTO, TaskDTO, UpdateTagDTO, UpdateTaskDTO,
+};
+use serde::{Deserialize, Serialize};
+
+/// 一括実行する操作
+///
+/// `op`で操作の種類を指定し、残りのフィールドは各操作の入力DTOと同じです。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ApplyOperation {
+    CreateTask {
+        #[serde(flatten)]
+        task: CreateTaskDTO,
+        /// 付与するタグ名（`tags`のタグIDと合わせて付与する）
+        #[serde(default)]
+        tag_names: Vec<String>,
+    },
+    UpdateTask {
+        id: i32,
+        #[serde(flatten)]
+        changes: UpdateTaskDTO,
+    },
+    DeleteTask {
+        id: i32,
+    },
+    CreateTag {
+        #[serde(flatten)]
+        tag: CreateTagDTO,
+    },
+    UpdateTag {
+        id: i32,
+        #[serde(flatten)]
+        changes: UpdateTagDTO,
+    },
+    DeleteTag {
+        id: i32,
+    },
+}
+
+impl ApplyOperation {
+    /// 操作の種類（`create_task`など）
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CreateTask { .. } => "create_task",
+            Self::UpdateTask { .. } => "update_task",
+            Self::DeleteTask { .. } => "delete_task",
+            Self::CreateTag { .. } => "create_tag",
+            Self::UpdateTag { .. } => "update_tag",
+            Self::DeleteTag { .. } => "delete_tag",
+        }
+    }
+
+    /// タスク・タグを削除する操作か
+    pub fn is_deletion(&self) -> bool {
+        matches!(self, Self::DeleteTask { .. } | Self::DeleteTag { .. })
+    }
+}
+
+/// 操作の実行結果の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationStatus {
+    /// 成功した（一括実行全体が取り消された場合も含む）
+    Ok,
+    /// 失敗した
+    Error,
+    /// 前の操作が失敗したため実行しなかった
+    Skipped,
+}
+
+/// 1つの操作の実行結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationResultDTO {
+    /// 入力での位置（0始まり）
+    pub index: usize,
+    pub op: String,
+    pub status: OperationStatus,
+    /// 作成・更新したタスク
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task: Option<TaskDTO>,
+    /// 作成・更新したタグ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<TagDTO>,
+    /// 削除したタスク・タグのID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 一括実行の結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplyReportDTO {
+    /// 変更を確定したか（いずれかの操作が失敗した場合はすべて取り消す）
+    pub committed: bool,
+    pub results: Vec<OperationResultDTO>,
+}
@@ -0,0 +1,13 @@
+use crate::application::dto::task_dto::TaskDTO;
+use serde::{Deserialize, Serialize};
+
+/// タスクの完了結果を表すDTO
+///
+/// `task done`で完了したタスクと、同時に作成したフォローアップのタスクを保持します。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletionDTO {
+    /// 完了したタスク
+    pub completed: TaskDTO,
+    /// 作成したフォローアップのタスク（作成しなかった場合はNone）
+    pub follow_up: Option<TaskDTO>,
+}
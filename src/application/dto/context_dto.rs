@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// 使用中のコンテキストと、そのコンテキストの未完了タスク数を表すDTO
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextUsageDTO {
+    /// コンテキスト（`@home`など）
+    pub context: String,
+    /// 未完了のタスク数
+    pub open_count: usize,
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// 見積もり時間と実績時間（作業記録の合計）を比べたレポートのDTO
+///
+/// `yaru report accuracy`で表示するために使用されます。
+/// 見積もりがあり、作業記録のあるタスクだけを集計します。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EstimateAccuracyDTO {
+    /// タグごとに集計したか（falseの場合はタスクごと）
+    pub by_tag: bool,
+    /// タスクごと・タグごとの集計（タグ別の場合、複数のタグを持つタスクは各行に含まれる）
+    pub rows: Vec<EstimateAccuracyRowDTO>,
+    /// 全体の集計（複数の行に含まれるタスクも1回だけ数える、見出しは空）
+    pub total: EstimateAccuracyRowDTO,
+    /// 見積もりはあるが作業記録のないタスクの数（集計には含まれない）
+    pub untracked_count: usize,
+}
+
+/// 見積もりと実績を比べた1行分のDTO
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EstimateAccuracyRowDTO {
+    /// 行の見出し（タスクの場合は`[ID] タイトル`、タグの場合はタグ名）
+    pub label: String,
+    /// 集計したタスクの数
+    pub task_count: usize,
+    /// 見積もり時間の合計（分）
+    pub estimated_minutes: u32,
+    /// 実績時間の合計（分）
+    pub actual_minutes: u32,
+}
+
+impl EstimateAccuracyRowDTO {
+    /// 実績と見積もりの差（分、見積もりを超えた場合は正の値）
+    pub fn variance_minutes(&self) -> i64 {
+        i64::from(self.actual_minutes) - i64::from(self.estimated_minutes)
+    }
+
+    /// 見積もりに対する差の割合（%、四捨五入、見積もりが0分の場合はNone）
+    pub fn variance_percent(&self) -> Option<i64> {
+        (self.estimated_minutes > 0).then(|| {
+            (self.variance_minutes() as f64 * 100.0 / f64::from(self.estimated_minutes)).round()
+                as i64
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(estimated_minutes: u32, actual_minutes: u32) -> EstimateAccuracyRowDTO {
+        EstimateAccuracyRowDTO {
+            label: "[1] 資料作成".to_string(),
+            task_count: 1,
+            estimated_minutes,
+            actual_minutes,
+        }
+    }
+
+    #[test]
+    fn test_variance() {
+        assert_eq!(row(60, 75).variance_minutes(), 15);
+        assert_eq!(row(60, 75).variance_percent(), Some(25));
+        assert_eq!(row(90, 60).variance_minutes(), -30);
+        assert_eq!(row(90, 60).variance_percent(), Some(-33));
+        assert_eq!(row(0, 30).variance_percent(), None);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::domain::task::value_objects::{DurationSummary, FlowStats};
+use serde::{Deserialize, Serialize};
+
+/// タスクの流れの統計をまとめたDTO
+///
+/// `yaru task stats --flow`で表示するために使用されます。時間はすべて分単位です。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowStatsDTO {
+    /// 集計したタスク数
+    pub task_count: usize,
+    /// 保留中の平均滞在時間
+    pub pending: DurationSummaryDTO,
+    /// 進行中の平均滞在時間
+    pub in_progress: DurationSummaryDTO,
+    /// 平均サイクルタイム（最初の着手から完了まで）
+    pub cycle_time: DurationSummaryDTO,
+    /// 平均リードタイム（作成から完了まで）
+    pub lead_time: DurationSummaryDTO,
+}
+
+/// 平均の時間と、平均に含めたタスク数を表すDTO
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurationSummaryDTO {
+    /// 平均に含めたタスク数
+    pub count: usize,
+    /// 平均（分、対象のタスクがない場合はNone）
+    pub average_minutes: Option<i64>,
+}
+
+impl From<&DurationSummary> for DurationSummaryDTO {
+    fn from(summary: &DurationSummary) -> Self {
+        Self {
+            count: summary.count(),
+            average_minutes: summary.average().map(|average| average.num_minutes()),
+        }
+    }
+}
+
+impl From<FlowStats> for FlowStatsDTO {
+    fn from(stats: FlowStats) -> Self {
+        Self {
+            task_count: stats.task_count(),
+            pending: stats.pending().into(),
+            in_progress: stats.in_progress().into(),
+            cycle_time: stats.cycle_time().into(),
+            lead_time: stats.lead_time().into(),
+        }
+    }
+}
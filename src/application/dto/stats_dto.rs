@@ -1,8 +1,11 @@
 use crate::domain::tag::value_objects::TagId;
-use crate::domain::task::value_objects::{DueDateStatus, Priority, Status, TaskStats};
+use crate::domain::task::value_objects::{Assignee, DueDateStatus, Priority, Status, TaskStats};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// 担当者×ステータス集計で担当者なしのタスクに使うキー
+pub const UNASSIGNED_LABEL: &str = "(Unassigned)";
+
 /// 統計情報の読み取り専用表現（DTO）
 ///
 /// TaskStatsをPresentation層で扱いやすい形式に変換します。
@@ -18,8 +21,15 @@ pub struct StatsDTO {
     pub tag_stats: HashMap<String, usize>,
     /// 優先度×ステータス クロス集計（キーは "priority:status" 形式）
     pub priority_status_matrix: HashMap<String, usize>,
+    /// 担当者×ステータス クロス集計（キーは "assignee:status" 形式、担当者のいるタスクがない場合は空）
+    #[serde(default)]
+    pub assignee_status_matrix: HashMap<String, usize>,
     /// 全体統計
     pub total_count: usize,
+    /// 期限が近いとみなす日数（`due_soon`の境界）
+    pub due_soon_days: u32,
+    /// 期限が迫りつつあるとみなす日数（`due_upcoming`の境界）
+    pub due_upcoming_days: u32,
 }
 
 impl StatsDTO {
@@ -37,7 +47,7 @@ impl StatsDTO {
         for status in [Status::Pending, Status::InProgress, Status::Completed] {
             let count = stats.status_count(&status);
             if count > 0 {
-                status_stats.insert(status_to_string(&status), count);
+                status_stats.insert(status.to_string(), count);
             }
         }
 
@@ -51,18 +61,13 @@ impl StatsDTO {
         ] {
             let count = stats.priority_count(&priority);
             if count > 0 {
-                priority_stats.insert(priority_to_string(&priority), count);
+                priority_stats.insert(priority.to_string(), count);
             }
         }
 
         // 期限関連統計を文字列キーに変換
         let mut due_date_stats = HashMap::new();
-        for due_date_status in [
-            DueDateStatus::Overdue,
-            DueDateStatus::DueToday,
-            DueDateStatus::DueThisWeek,
-            DueDateStatus::NoDueDate,
-        ] {
+        for due_date_status in DueDateStatus::ORDER {
             let count = stats.due_date_count(&due_date_status);
             if count > 0 {
                 due_date_stats.insert(due_date_status_to_string(&due_date_status), count);
@@ -93,23 +98,37 @@ impl StatsDTO {
             for status in [Status::Pending, Status::InProgress, Status::Completed] {
                 let count = stats.priority_status_count(&priority, &status);
                 if count > 0 {
-                    let key = format!(
-                        "{}:{}",
-                        priority_to_string(&priority),
-                        status_to_string(&status)
-                    );
+                    let key = format!("{}:{}", priority, status);
                     priority_status_matrix.insert(key, count);
                 }
             }
         }
 
+        // 担当者×ステータス クロス集計を文字列キーに変換（担当者なしは "(Unassigned)"）
+        let mut assignee_status_matrix = HashMap::new();
+        let has_assignee = stats
+            .assignee_status_matrix()
+            .keys()
+            .any(|(assignee, _)| assignee.is_some());
+        if has_assignee {
+            for ((assignee, status), count) in stats.assignee_status_matrix() {
+                let name = assignee
+                    .as_ref()
+                    .map_or_else(|| UNASSIGNED_LABEL.to_string(), Assignee::to_string);
+                assignee_status_matrix.insert(format!("{}:{}", name, status), *count);
+            }
+        }
+
         Self {
             status_stats,
             priority_stats,
             due_date_stats,
             tag_stats,
             priority_status_matrix,
+            assignee_status_matrix,
             total_count: stats.total_count(),
+            due_soon_days: stats.due_date_buckets().soon_days(),
+            due_upcoming_days: stats.due_date_buckets().upcoming_days(),
         }
     }
 }
@@ -124,28 +143,13 @@ impl From<TaskStats> for StatsDTO {
 }
 
 // ヘルパー関数
-fn status_to_string(status: &Status) -> String {
-    match status {
-        Status::Pending => "pending".to_string(),
-        Status::InProgress => "in_progress".to_string(),
-        Status::Completed => "completed".to_string(),
-    }
-}
-
-fn priority_to_string(priority: &Priority) -> String {
-    match priority {
-        Priority::Low => "low".to_string(),
-        Priority::Medium => "medium".to_string(),
-        Priority::High => "high".to_string(),
-        Priority::Critical => "critical".to_string(),
-    }
-}
-
 fn due_date_status_to_string(due_date_status: &DueDateStatus) -> String {
     match due_date_status {
         DueDateStatus::Overdue => "overdue".to_string(),
         DueDateStatus::DueToday => "due_today".to_string(),
-        DueDateStatus::DueThisWeek => "due_this_week".to_string(),
+        DueDateStatus::DueSoon => "due_soon".to_string(),
+        DueDateStatus::DueUpcoming => "due_upcoming".to_string(),
+        DueDateStatus::DueLater => "due_later".to_string(),
         DueDateStatus::NoDueDate => "no_due_date".to_string(),
     }
 }
@@ -153,7 +157,7 @@ fn due_date_status_to_string(due_date_status: &DueDateStatus) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::task::value_objects::{DueDateStatus, Priority, Status};
+    use crate::domain::task::value_objects::{DueDateBuckets, DueDateStatus, Priority, Status};
 
     #[test]
     fn test_stats_dto_from_task_stats() {
@@ -221,6 +225,42 @@ mod tests {
         assert!(dto.priority_status_matrix.is_empty());
     }
 
+    #[test]
+    fn test_stats_dto_assignee_status_matrix() {
+        // Arrange
+        let alice = Assignee::new("alice").unwrap();
+        let task_stats = |matrix| {
+            TaskStats::new(
+                HashMap::new(),
+                HashMap::new(),
+                HashMap::new(),
+                HashMap::new(),
+                HashMap::new(),
+                3,
+            )
+            .with_assignee_status_matrix(matrix)
+        };
+        let assigned = HashMap::from([
+            ((Some(alice.clone()), Status::Pending), 2),
+            ((None, Status::Completed), 1),
+        ]);
+        let unassigned = HashMap::from([((None, Status::Pending), 3)]);
+
+        // Act
+        let dto = StatsDTO::from(task_stats(assigned));
+        let unassigned_dto = StatsDTO::from(task_stats(unassigned));
+
+        // Assert
+        assert_eq!(dto.assignee_status_matrix.len(), 2);
+        assert_eq!(dto.assignee_status_matrix.get("alice:pending"), Some(&2));
+        assert_eq!(
+            dto.assignee_status_matrix.get("(Unassigned):completed"),
+            Some(&1)
+        );
+        // 担当者のいるタスクがない場合は集計しない
+        assert!(unassigned_dto.assignee_status_matrix.is_empty());
+    }
+
     #[test]
     fn test_stats_dto_only_total_count() {
         let mut status_stats = HashMap::new();
@@ -241,4 +281,28 @@ mod tests {
         assert_eq!(dto.status_stats.get("pending"), Some(&10));
         assert!(dto.priority_stats.is_empty());
     }
+
+    #[test]
+    fn test_stats_dto_due_date_buckets() {
+        let mut due_date_stats = HashMap::new();
+        due_date_stats.insert(DueDateStatus::DueSoon, 2);
+        due_date_stats.insert(DueDateStatus::DueUpcoming, 1);
+
+        let task_stats = TaskStats::new(
+            HashMap::new(),
+            HashMap::new(),
+            due_date_stats,
+            HashMap::new(),
+            HashMap::new(),
+            3,
+        )
+        .with_due_date_buckets(DueDateBuckets::new(3, 14).unwrap());
+
+        let dto = StatsDTO::from(task_stats);
+
+        assert_eq!(dto.due_date_stats.get("due_soon"), Some(&2));
+        assert_eq!(dto.due_date_stats.get("due_upcoming"), Some(&1));
+        assert_eq!(dto.due_soon_days, 3);
+        assert_eq!(dto.due_upcoming_days, 14);
+    }
 }
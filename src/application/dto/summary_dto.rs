@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// 未完了のタスクの件数と、完了の連続記録の概要を表すDTO
+///
+/// `yaru summary`でステータスバーやプロンプトに表示するために使用されます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TaskSummaryDTO {
+    /// 未完了のタスク数
+    pub open: u64,
+    /// 今日が期限の未完了のタスク数
+    pub due_today: u64,
+    /// 期限切れの未完了のタスク数
+    pub overdue: u64,
+    /// 今日完了したタスク数（以下の完了の連続記録は、計算を指定した場合のみ）
+    pub completed_today: u64,
+    /// 完了したタスクの総数
+    pub completed_total: u64,
+    /// タスクを完了した日の現在の連続日数
+    pub streak_days: u32,
+    /// タスクを完了した日の最長の連続日数
+    pub longest_streak_days: u32,
+}
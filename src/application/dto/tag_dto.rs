@@ -1,4 +1,4 @@
-use crate::domain::tag::aggregate::TagAggregate;
+use crate::domain::tag::{aggregate::TagAggregate, hierarchy::TagHierarchy};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +10,10 @@ pub struct TagDTO {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
+    /// 親タグのID（ルートのタグはNone）
+    pub parent_id: Option<i32>,
+    /// ルートからのパス（例: `work/project-a`）
+    pub path: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -19,6 +23,9 @@ pub struct TagDTO {
 pub struct CreateTagDTO {
     pub name: String,
     pub description: Option<String>,
+    /// 親タグのID
+    #[serde(default)]
+    pub parent_id: Option<i32>,
 }
 
 /// タグ更新時の入力DTO
@@ -26,14 +33,31 @@ pub struct CreateTagDTO {
 pub struct UpdateTagDTO {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// 親タグの変更（`Some(None)`でルートのタグにする）
+    #[serde(default)]
+    pub parent_id: Option<Option<i32>>,
 }
 
-// TagAggregateからTagDTOへの変換
+impl TagDTO {
+    /// 親子関係からパスを解決してTagDTOに変換
+    pub fn from_aggregate_in_hierarchy(tag: TagAggregate, hierarchy: &TagHierarchy) -> Self {
+        let path = hierarchy.path(tag.id());
+        let mut dto = TagDTO::from(tag);
+        if let Some(path) = path {
+            dto.path = path;
+        }
+        dto
+    }
+}
+
+// TagAggregateからTagDTOへの変換（パスはタグ名のみ）
 impl From<TagAggregate> for TagDTO {
     fn from(tag: TagAggregate) -> Self {
         Self {
             id: tag.id().value(),
             name: tag.name().value().to_string(),
+            parent_id: tag.parent_id().map(|id| id.value()),
+            path: tag.name().value().to_string(),
             description: if tag.description().value().is_empty() {
                 None
             } else {
@@ -48,31 +72,31 @@ impl From<TagAggregate> for TagDTO {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::tag::{
-        aggregate::TagAggregate,
-        value_objects::{TagDescription, TagName},
-    };
+    use crate::domain::tag::aggregate::NewTag;
+    use crate::domain::tag::value_objects::{TagDescription, TagId, TagName};
 
     #[test]
     fn test_tag_dto_from_aggregate() {
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("重要").unwrap(),
             TagDescription::new("重要なタスク").unwrap(),
-        );
+        )
+        .into_aggregate(TagId::new(1).unwrap());
 
         let dto = TagDTO::from(tag);
 
-        assert_eq!(dto.id, 0); // デフォルトID
+        assert_eq!(dto.id, 1);
         assert_eq!(dto.name, "重要");
         assert_eq!(dto.description, Some("重要なタスク".to_string()));
     }
 
     #[test]
     fn test_tag_dto_from_aggregate_empty_description() {
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("タグ").unwrap(),
             TagDescription::new("").unwrap(),
-        );
+        )
+        .into_aggregate(TagId::new(1).unwrap());
 
         let dto = TagDTO::from(tag);
 
@@ -85,6 +109,7 @@ mod tests {
         let dto = CreateTagDTO {
             name: "新しいタグ".to_string(),
             description: Some("説明".to_string()),
+            parent_id: None,
         };
 
         assert_eq!(dto.name, "新しいタグ");
@@ -96,6 +121,7 @@ mod tests {
         let dto = CreateTagDTO {
             name: "タグ".to_string(),
             description: None,
+            parent_id: None,
         };
 
         assert_eq!(dto.name, "タグ");
@@ -0,0 +1,50 @@
+use crate::application::dto::task_dto::TaskDTO;
+use crate::domain::task::value_objects::Status;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 関連情報付きのタスク詳細を表すDTO
+///
+/// `task show`で、タスク本体に加えて期限までの日数や同じタグを持つタスクなどの
+/// 派生情報を表示するために使用されます。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskDetailDTO {
+    /// タスク本体
+    pub task: TaskDTO,
+    /// 期限までの日数（負の値は期限切れの日数）。期限なし・完了済みの場合はNone
+    pub days_until_due: Option<i64>,
+    /// 作成からの経過日数
+    pub age_days: i64,
+    /// 最終更新からの経過日数
+    pub days_since_update: i64,
+    /// 同じタグを持つタスク（共通するタグが多い順）
+    pub related_tasks: Vec<RelatedTaskDTO>,
+    /// 変更履歴の概要（古い順）
+    pub history: Vec<TaskHistoryEntryDTO>,
+}
+
+/// 同じタグを持つ関連タスク
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedTaskDTO {
+    pub id: i32,
+    pub title: String,
+    pub status: Status,
+    /// 共通するタグ名
+    pub shared_tags: Vec<String>,
+}
+
+/// 変更履歴の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskHistoryKind {
+    Created,
+    Updated,
+    Completed,
+}
+
+/// 変更履歴の1項目
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskHistoryEntryDTO {
+    pub kind: TaskHistoryKind,
+    pub occurred_at: DateTime<Utc>,
+}
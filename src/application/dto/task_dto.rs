@@ -7,7 +7,7 @@ use crate::domain::{
 };
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// タグ参照情報を表すDTO
 ///
@@ -19,22 +19,74 @@ pub struct TagInfo {
     pub name: String,
 }
 
+/// チェックリスト項目を表すDTO
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItemDTO {
+    pub text: String,
+    pub done: bool,
+}
+
+/// gitのコミット・ブランチとの紐づけを表すDTO
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitLinkDTO {
+    pub repository: String,
+    pub branch: Option<String>,
+    pub commit: String,
+    pub linked_at: DateTime<Utc>,
+}
+
+/// 外部サービスの項目との対応を表すDTO
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalRefDTO {
+    pub source: String,
+    pub external_id: String,
+    pub url: Option<String>,
+}
+
 /// タスクの読み取り専用表現（DTO）
 ///
 /// Use CaseからPresentation層への出力に使用されます。
-/// Value Objectではなくプリミティブ型を使用し、シリアライズ可能にします。
+/// ステータス・優先度はドメインの列挙型、日時はchronoの型で保持し、
+/// 利用側で文字列を解釈せずに扱えるようにします（JSONでは`pending`・`high`などで表します）。
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaskDTO {
     pub id: i32,
     pub title: String,
     pub description: Option<String>,
-    pub status: String,
-    pub priority: String,
+    pub status: Status,
+    pub priority: Priority,
     pub tags: Vec<TagInfo>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub due_date: Option<NaiveDate>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// カスタムフィールド（キー -> 値）
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
+    /// チェックリスト（表示順）
+    #[serde(default)]
+    pub checklist: Vec<ChecklistItemDTO>,
+    /// gitのコミット・ブランチとの紐づけ（紐づけた順）
+    #[serde(default)]
+    pub git_links: Vec<GitLinkDTO>,
+    /// 外部サービスの項目との対応（GitHubのIssueなど）
+    #[serde(default)]
+    pub external_refs: Vec<ExternalRefDTO>,
+    /// 繰り返しルール（`weekly`, `every 2 weeks`など）
+    #[serde(default)]
+    pub recurrence: Option<String>,
+    /// スヌーズの期限（この日まで既定の一覧やアジェンダに表示しない）
+    #[serde(default)]
+    pub snoozed_until: Option<NaiveDate>,
+    /// 見積もり時間（分）
+    #[serde(default)]
+    pub estimate_minutes: Option<u32>,
+    /// コンテキスト（`@home`など）
+    #[serde(default)]
+    pub context: Option<String>,
+    /// 担当者
+    #[serde(default)]
+    pub assignee: Option<String>,
 }
 
 /// タスク作成時の入力DTO
@@ -44,10 +96,26 @@ pub struct TaskDTO {
 pub struct CreateTaskDTO {
     pub title: String,
     pub description: Option<String>,
-    pub status: Option<String>,
-    pub priority: Option<String>,
+    pub status: Option<Status>,
+    pub priority: Option<Priority>,
+    #[serde(default)]
     pub tags: Vec<i32>,
     pub due_date: Option<NaiveDate>,
+    /// カスタムフィールド（キー -> 値）
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
+    /// 繰り返しルール（`weekly`, `every 2 weeks`など）
+    #[serde(default)]
+    pub recurrence: Option<String>,
+    /// 見積もり時間（`2h30m`, `45m`など）
+    #[serde(default)]
+    pub estimate: Option<String>,
+    /// コンテキスト（`@home`など）
+    #[serde(default)]
+    pub context: Option<String>,
+    /// 担当者
+    #[serde(default)]
+    pub assignee: Option<String>,
 }
 
 /// タスク更新時の入力DTO
@@ -57,10 +125,39 @@ pub struct CreateTaskDTO {
 pub struct UpdateTaskDTO {
     pub title: Option<String>,
     pub description: Option<String>,
-    pub status: Option<String>,
-    pub priority: Option<String>,
+    pub status: Option<Status>,
+    pub priority: Option<Priority>,
     pub tags: Option<Vec<i32>>,
     pub due_date: Option<NaiveDate>,
+    /// 再開を明示するか（完了済みのタスクを未完了に戻す場合に必要）
+    #[serde(default)]
+    pub reopen: bool,
+    /// 設定するカスタムフィールド（同じキーは値を置き換える）
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
+    /// 削除するカスタムフィールドのキー
+    #[serde(default)]
+    pub remove_custom_fields: Vec<String>,
+    /// 設定する繰り返しルール
+    pub recurrence: Option<String>,
+    /// 繰り返しルールを解除するか
+    #[serde(default)]
+    pub clear_recurrence: bool,
+    /// 設定する見積もり時間
+    pub estimate: Option<String>,
+    /// 見積もり時間を解除するか
+    #[serde(default)]
+    pub clear_estimate: bool,
+    /// 設定するコンテキスト
+    pub context: Option<String>,
+    /// コンテキストを解除するか
+    #[serde(default)]
+    pub clear_context: bool,
+    /// 設定する担当者
+    pub assignee: Option<String>,
+    /// 担当者を解除するか
+    #[serde(default)]
+    pub clear_assignee: bool,
 }
 
 // TaskAggregateからTaskDTOへの変換
@@ -77,13 +174,50 @@ impl From<TaskAggregate> for TaskDTO {
             } else {
                 Some(task.description().value().to_string())
             },
-            status: status_to_string(task.status()),
-            priority: priority_to_string(task.priority()),
+            status: *task.status(),
+            priority: *task.priority(),
             tags: Vec::new(), // タグ情報はユースケース層で設定
             created_at: *task.created_at(),
             updated_at: *task.updated_at(),
             due_date: task.due_date().as_ref().map(|dd| dd.value()),
             completed_at: *task.completed_at(),
+            custom_fields: task
+                .custom_fields()
+                .iter()
+                .map(|field| (field.key().to_string(), field.value().to_string()))
+                .collect(),
+            checklist: task
+                .checklist()
+                .iter()
+                .map(|item| ChecklistItemDTO {
+                    text: item.text().to_string(),
+                    done: item.is_done(),
+                })
+                .collect(),
+            git_links: task
+                .git_links()
+                .iter()
+                .map(|link| GitLinkDTO {
+                    repository: link.repository().to_string(),
+                    branch: link.branch().map(str::to_string),
+                    commit: link.commit().to_string(),
+                    linked_at: *link.linked_at(),
+                })
+                .collect(),
+            external_refs: task
+                .external_refs()
+                .iter()
+                .map(|external_ref| ExternalRefDTO {
+                    source: external_ref.source().to_string(),
+                    external_id: external_ref.external_id().to_string(),
+                    url: external_ref.url().map(str::to_string),
+                })
+                .collect(),
+            recurrence: task.recurrence().map(ToString::to_string),
+            snoozed_until: task.snoozed_until(),
+            estimate_minutes: task.estimate().map(|estimate| estimate.minutes()),
+            context: task.context().map(ToString::to_string),
+            assignee: task.assignee().map(ToString::to_string),
         }
     }
 }
@@ -120,54 +254,34 @@ impl TaskDTO {
     }
 }
 
-// Statusを文字列に変換
-fn status_to_string(status: &Status) -> String {
-    match status {
-        Status::Pending => "pending".to_string(),
-        Status::InProgress => "in_progress".to_string(),
-        Status::Completed => "completed".to_string(),
-    }
-}
-
-// Priorityを文字列に変換
-fn priority_to_string(priority: &Priority) -> String {
-    match priority {
-        Priority::Low => "low".to_string(),
-        Priority::Medium => "medium".to_string(),
-        Priority::High => "high".to_string(),
-        Priority::Critical => "critical".to_string(),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::tag::aggregate::NewTag;
     use crate::domain::task::{
-        aggregate::TaskAggregate,
-        value_objects::{Priority, Status, TaskDescription, TaskTitle},
+        builder::TaskBuilder,
+        value_objects::{Priority, Status, TaskDescription, TaskId, TaskTitle},
     };
 
     #[test]
     fn test_task_dto_from_aggregate() {
         // TaskAggregateを作成
-        let task = TaskAggregate::new(
-            TaskTitle::new("テストタスク").unwrap(),
-            TaskDescription::new("テスト説明").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("テストタスク").unwrap())
+            .description(TaskDescription::new("テスト説明").unwrap())
+            .priority(Priority::High)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // TaskDTOに変換
         let dto = TaskDTO::from(task.clone());
 
         // 検証
-        assert_eq!(dto.id, 0); // デフォルトID
+        assert_eq!(dto.id, 1);
         assert_eq!(dto.title, "テストタスク");
         assert_eq!(dto.description, Some("テスト説明".to_string()));
-        assert_eq!(dto.status, "pending");
-        assert_eq!(dto.priority, "high");
+        assert_eq!(dto.status, Status::Pending);
+        assert_eq!(dto.priority, Priority::High);
         assert_eq!(dto.tags, Vec::<TagInfo>::new()); // From実装では空
         assert_eq!(dto.due_date, None);
         assert_eq!(dto.completed_at, None);
@@ -178,40 +292,35 @@ mod tests {
     fn test_task_dto_from_aggregate_with_tags() {
         use crate::domain::tag::value_objects::TagId;
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タグ付きタスク").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::InProgress,
-            Priority::Medium,
-            vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タグ付きタスク").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .status(Status::InProgress)
+            .tags(vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         let dto = TaskDTO::from(task);
 
         // From実装ではタグ情報は空（ユースケース層で設定される）
         assert_eq!(dto.tags, Vec::<TagInfo>::new());
-        assert_eq!(dto.status, "in_progress");
-        assert_eq!(dto.priority, "medium");
+        assert_eq!(dto.status, Status::InProgress);
+        assert_eq!(dto.priority, Priority::Medium);
     }
 
     #[test]
     fn test_task_dto_from_completed_task() {
-        let mut task = TaskAggregate::new(
-            TaskTitle::new("完了タスク").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(TaskTitle::new("完了タスク").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .priority(Priority::Low)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // タスクを完了
-        task.complete().unwrap();
+        task.complete(&FixedClock::at(2026, 1, 10, 9, 0)).unwrap();
 
         let dto = TaskDTO::from(task);
 
-        assert_eq!(dto.status, "completed");
+        assert_eq!(dto.status, Status::Completed);
         assert!(dto.completed_at.is_some());
     }
 
@@ -224,6 +333,11 @@ mod tests {
             priority: None,
             tags: vec![],
             due_date: None,
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
         };
 
         assert_eq!(dto.title, "新しいタスク");
@@ -237,16 +351,21 @@ mod tests {
         let dto = CreateTaskDTO {
             title: "詳細タスク".to_string(),
             description: Some("詳細説明".to_string()),
-            status: Some("pending".to_string()),
-            priority: Some("high".to_string()),
+            status: Some(Status::Pending),
+            priority: Some(Priority::High),
             tags: vec![1, 2, 3],
             due_date: Some(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()),
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
         };
 
         assert_eq!(dto.title, "詳細タスク");
         assert_eq!(dto.description, Some("詳細説明".to_string()));
-        assert_eq!(dto.status, Some("pending".to_string()));
-        assert_eq!(dto.priority, Some("high".to_string()));
+        assert_eq!(dto.status, Some(Status::Pending));
+        assert_eq!(dto.priority, Some(Priority::High));
         assert_eq!(dto.tags, vec![1, 2, 3]);
         assert!(dto.due_date.is_some());
     }
@@ -267,30 +386,55 @@ mod tests {
     fn test_update_task_dto_partial() {
         let dto = UpdateTaskDTO {
             title: Some("更新タイトル".to_string()),
-            status: Some("completed".to_string()),
+            status: Some(Status::Completed),
             ..Default::default()
         };
 
         assert_eq!(dto.title, Some("更新タイトル".to_string()));
-        assert_eq!(dto.status, Some("completed".to_string()));
+        assert_eq!(dto.status, Some(Status::Completed));
         assert_eq!(dto.description, None);
         assert_eq!(dto.priority, None);
     }
 
+    #[test]
+    fn test_task_dto_json_uses_keys_for_status_and_priority() {
+        let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+            .status(Status::InProgress)
+            .priority(Priority::Critical)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        let json = serde_json::to_value(TaskDTO::from(task)).unwrap();
+
+        assert_eq!(json["status"], "in_progress");
+        assert_eq!(json["priority"], "critical");
+    }
+
+    #[test]
+    fn test_create_task_dto_rejects_unknown_priority() {
+        let valid: CreateTaskDTO =
+            serde_json::from_str(r#"{"title": "a", "description": null, "status": "completed", "priority": "high", "due_date": null}"#)
+                .unwrap();
+        let invalid = serde_json::from_str::<CreateTaskDTO>(
+            r#"{"title": "a", "description": null, "status": null, "priority": "urgent", "due_date": null}"#,
+        );
+
+        assert_eq!(valid.status, Some(Status::Completed));
+        assert_eq!(valid.priority, Some(Priority::High));
+        assert!(invalid.is_err());
+    }
+
     #[test]
     fn test_from_aggregate_with_tags_empty_tag_map() {
         use crate::domain::tag::value_objects::TagId;
         use std::collections::HashMap;
 
         // タグIDを持つタスクを作成
-        let task = TaskAggregate::new(
-            TaskTitle::new("タスク").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .tags(vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // 空のタグマップ
         let tag_map: HashMap<i32, &crate::domain::tag::aggregate::TagAggregate> = HashMap::new();
@@ -309,24 +453,24 @@ mod tests {
         use std::collections::HashMap;
 
         // タグを作成
-        let tag1 = TagAggregate::new(
+        let tag1 = NewTag::new(
             TagName::new("仕事").unwrap(),
             TagDescription::new("仕事関連").unwrap(),
-        );
-        let tag2 = TagAggregate::new(
+        )
+        .into_aggregate(TagId::new(1).unwrap());
+        let tag2 = NewTag::new(
             TagName::new("緊急").unwrap(),
             TagDescription::new("緊急対応").unwrap(),
-        );
+        )
+        .into_aggregate(TagId::new(2).unwrap());
 
         // タスクを作成（タグIDは1と2）
-        let task = TaskAggregate::new(
-            TaskTitle::new("タスク").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .priority(Priority::High)
+            .tags(vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // タグマップを作成
         let mut tag_map: HashMap<i32, &TagAggregate> = HashMap::new();
@@ -349,20 +493,18 @@ mod tests {
         use std::collections::HashMap;
 
         // タグを1つだけ作成
-        let tag1 = TagAggregate::new(
+        let tag1 = NewTag::new(
             TagName::new("仕事").unwrap(),
             TagDescription::new("仕事関連").unwrap(),
-        );
+        )
+        .into_aggregate(TagId::new(1).unwrap());
 
         // タスクを作成（タグIDは1と2だが、タグマップには1のみ）
-        let task = TaskAggregate::new(
-            TaskTitle::new("タスク").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .tags(vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // タグマップには1のみ登録
         let mut tag_map: HashMap<i32, &TagAggregate> = HashMap::new();
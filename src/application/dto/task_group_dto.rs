@@ -0,0 +1,20 @@
+use crate::application::dto::task_dto::TaskDTO;
+use serde::{Deserialize, Serialize};
+
+/// グループ化されたタスク一覧を表すDTO
+///
+/// `task list --group-by`でグループごとに表示するために使用されます。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskGroupDTO {
+    /// グループの見出し（例: "Pending", "High", タグ名）
+    pub label: String,
+    /// グループに属するタスク
+    pub tasks: Vec<TaskDTO>,
+}
+
+impl TaskGroupDTO {
+    /// グループに属するタスク数を取得
+    pub fn count(&self) -> usize {
+        self.tasks.len()
+    }
+}
@@ -0,0 +1,26 @@
+use crate::domain::time_entry::aggregate::TimeEntryAggregate;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 作業記録の読み取り専用表現（DTO）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeEntryDTO {
+    pub id: i32,
+    pub task_id: i32,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    /// 作業時間（分）
+    pub duration_minutes: i64,
+}
+
+impl From<TimeEntryAggregate> for TimeEntryDTO {
+    fn from(entry: TimeEntryAggregate) -> Self {
+        Self {
+            id: entry.id().value(),
+            task_id: entry.task_id().value(),
+            started_at: *entry.started_at(),
+            ended_at: *entry.ended_at(),
+            duration_minutes: entry.duration().num_minutes(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 期間内の日ごとの作業量（見積もり時間の合計）をまとめたDTO
+///
+/// `yaru workload`で表示するために使用されます。期限日ごとに未完了のタスクの見積もりを合計し、
+/// 1日の作業可能時間を超える日を過負荷として示します。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkloadDTO {
+    /// 1日の作業可能時間（分）
+    pub daily_capacity_minutes: u32,
+    /// 期間内の各日（日付の昇順、タスクのない日も含む）
+    pub days: Vec<WorkloadDayDTO>,
+}
+
+impl WorkloadDTO {
+    /// 期間内の見積もり時間の合計（分）
+    pub fn total_minutes(&self) -> u32 {
+        self.days.iter().map(|day| day.estimated_minutes).sum()
+    }
+
+    /// 見積もりのないタスクの数
+    pub fn unestimated_count(&self) -> usize {
+        self.days.iter().map(|day| day.unestimated_count).sum()
+    }
+}
+
+/// 1日分の作業量を表すDTO
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkloadDayDTO {
+    /// 期限日
+    pub date: NaiveDate,
+    /// その日が期限のタスクの数
+    pub task_count: usize,
+    /// 見積もり時間の合計（分）
+    pub estimated_minutes: u32,
+    /// 見積もりのないタスクの数
+    pub unestimated_count: usize,
+    /// 見積もりの合計が1日の作業可能時間を超えているか
+    pub overcommitted: bool,
+}
@@ -0,0 +1,219 @@
+use crate::domain::tag::{
+    aggregate::{NewTag, TagAggregate},
+    repository::TagRepository,
+    value_objects::TagId,
+};
+use anyhow::Result;
+use std::{
+    collections::HashSet,
+    sync::{Arc, RwLock},
+};
+
+/// TagCache - タグの読み込み結果をキャッシュするTagRepository
+///
+/// 最初の読み込み時に全タグを取得して保持し、以降の検索はキャッシュから返します（リードスルー）。
+/// このキャッシュを経由したタグの作成・更新・削除ではキャッシュを破棄します。
+///
+/// # Note
+/// トランザクション内のリポジトリなど、キャッシュを経由せずにタグを変更した場合は
+/// `invalidate`を呼び出してください。
+pub struct TagCache {
+    inner: Arc<dyn TagRepository>,
+    tags: RwLock<Option<Arc<Vec<TagAggregate>>>>,
+}
+
+impl TagCache {
+    /// 新しいTagCacheを作成
+    ///
+    /// # Arguments
+    /// * `inner` - 実際にタグを読み書きするリポジトリ
+    pub fn new(inner: Arc<dyn TagRepository>) -> Self {
+        Self {
+            inner,
+            tags: RwLock::new(None),
+        }
+    }
+
+    /// キャッシュを破棄し、次回の読み込みでリポジトリから再取得させる
+    pub fn invalidate(&self) {
+        *self.tags.write().unwrap() = None;
+    }
+
+    /// キャッシュ済みの全タグを取得（未取得の場合はリポジトリから読み込む）
+    async fn load(&self) -> Result<Arc<Vec<TagAggregate>>> {
+        let cached = self.tags.read().unwrap().clone();
+        if let Some(tags) = cached {
+            return Ok(tags);
+        }
+
+        let tags = Arc::new(self.inner.find_all().await?);
+        *self.tags.write().unwrap() = Some(tags.clone());
+        Ok(tags)
+    }
+}
+
+#[async_trait::async_trait]
+impl TagRepository for TagCache {
+    async fn find_by_id(&self, id: &TagId) -> Result<Option<TagAggregate>> {
+        let tags = self.load().await?;
+        Ok(tags.iter().find(|tag| tag.id() == id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<TagAggregate>> {
+        Ok(self.load().await?.as_ref().clone())
+    }
+
+    async fn save(&self, tag: NewTag) -> Result<TagAggregate> {
+        let result = self.inner.save(tag).await;
+        self.invalidate();
+        result
+    }
+
+    async fn update(&self, tag: TagAggregate) -> Result<TagAggregate> {
+        let result = self.inner.update(tag).await;
+        self.invalidate();
+        result
+    }
+
+    async fn delete(&self, id: &TagId) -> Result<bool> {
+        let result = self.inner.delete(id).await;
+        self.invalidate();
+        result
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>> {
+        let tags = self.load().await?;
+        Ok(tags.iter().find(|tag| tag.name().matches(name)).cloned())
+    }
+
+    async fn find_by_ids(&self, ids: &[TagId]) -> Result<Vec<TagAggregate>> {
+        let ids: HashSet<&TagId> = ids.iter().collect();
+        let tags = self.load().await?;
+        Ok(tags
+            .iter()
+            .filter(|tag| ids.contains(tag.id()))
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::tag::value_objects::{TagDescription, TagName},
+        interface::persistence::in_memory::InMemoryTagRepository,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// find_allの呼び出し回数を数えるリポジトリ
+    struct CountingTagRepository {
+        inner: InMemoryTagRepository,
+        find_all_calls: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl TagRepository for CountingTagRepository {
+        async fn find_by_id(&self, id: &TagId) -> Result<Option<TagAggregate>> {
+            self.inner.find_by_id(id).await
+        }
+
+        async fn find_all(&self) -> Result<Vec<TagAggregate>> {
+            self.find_all_calls.fetch_add(1, Ordering::SeqCst);
+            self.inner.find_all().await
+        }
+
+        async fn save(&self, tag: NewTag) -> Result<TagAggregate> {
+            self.inner.save(tag).await
+        }
+
+        async fn update(&self, tag: TagAggregate) -> Result<TagAggregate> {
+            self.inner.update(tag).await
+        }
+
+        async fn delete(&self, id: &TagId) -> Result<bool> {
+            self.inner.delete(id).await
+        }
+
+        async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>> {
+            self.inner.find_by_name(name).await
+        }
+
+        async fn find_by_ids(&self, ids: &[TagId]) -> Result<Vec<TagAggregate>> {
+            self.inner.find_by_ids(ids).await
+        }
+    }
+
+    fn setup() -> (Arc<CountingTagRepository>, TagCache) {
+        let repo = Arc::new(CountingTagRepository {
+            inner: InMemoryTagRepository::new(),
+            find_all_calls: AtomicUsize::new(0),
+        });
+        let cache = TagCache::new(repo.clone());
+        (repo, cache)
+    }
+
+    fn new_tag(name: &str) -> NewTag {
+        NewTag::new(
+            TagName::new(name).unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_tag_cache_reads_through_once() {
+        // Arrange
+        let (repo, cache) = setup();
+        let work = repo.save(new_tag("仕事")).await.unwrap();
+        repo.save(new_tag("私用")).await.unwrap();
+
+        // Act
+        let all = cache.find_all().await.unwrap();
+        let by_id = cache.find_by_id(work.id()).await.unwrap();
+        let by_ids = cache.find_by_ids(&[*work.id()]).await.unwrap();
+        let by_name = cache.find_by_name("私用").await.unwrap();
+
+        // Assert
+        assert_eq!(all.len(), 2);
+        assert_eq!(by_id.unwrap().name().value(), "仕事");
+        assert_eq!(by_ids.len(), 1);
+        assert!(by_name.is_some());
+        assert_eq!(repo.find_all_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tag_cache_invalidated_on_mutation() {
+        // Arrange
+        let (repo, cache) = setup();
+        cache.save(new_tag("仕事")).await.unwrap();
+        assert_eq!(cache.find_all().await.unwrap().len(), 1);
+
+        // Act
+        let saved = cache.save(new_tag("私用")).await.unwrap();
+        let after_save = cache.find_all().await.unwrap();
+        cache.delete(saved.id()).await.unwrap();
+        let after_delete = cache.find_all().await.unwrap();
+
+        // Assert
+        assert_eq!(after_save.len(), 2);
+        assert_eq!(after_delete.len(), 1);
+        assert_eq!(repo.find_all_calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_tag_cache_invalidate_reloads_external_changes() {
+        // Arrange
+        let (repo, cache) = setup();
+        assert!(cache.find_all().await.unwrap().is_empty());
+        // キャッシュを経由せずに変更
+        repo.save(new_tag("仕事")).await.unwrap();
+        assert!(cache.find_all().await.unwrap().is_empty());
+
+        // Act
+        cache.invalidate();
+        let tags = cache.find_all().await.unwrap();
+
+        // Assert
+        assert_eq!(tags.len(), 1);
+    }
+}
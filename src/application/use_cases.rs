@@ -1,2 +1,4 @@
+pub mod apply_operations;
 pub mod tag;
 pub mod task;
+pub mod time_entry;
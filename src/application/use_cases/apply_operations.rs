@@ -0,0 +1,336 @@
+use crate::{
+    application::{
+        dto::{ApplyOperation, ApplyReportDTO, OperationResultDTO, OperationStatus},
+        use_cases::{
+            tag::{add_tag::AddTagUseCase, delete_tag::DeleteTagUseCase, edit_tag::EditTagUseCase},
+            task::{
+                add_task::AddTaskUseCase, delete_task::DeleteTaskUseCase,
+                edit_task::EditTaskUseCase,
+            },
+        },
+    },
+    domain::{
+        clock::{Clock, SystemClock},
+        task::{status_transition::StatusTransitionPolicy, value_objects::PastDueDatePolicy},
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+    },
+};
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// ApplyOperationsUseCase - タスク・タグの操作の一括実行のユースケース
+///
+/// 操作を順に1つのトランザクション（UnitOfWork）内で実行し、すべて成功した場合のみ確定します。
+/// いずれかの操作が失敗した場合は、それまでの変更をすべて取り消し、残りの操作は実行しません。
+pub struct ApplyOperationsUseCase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    transition_policy: StatusTransitionPolicy,
+    past_due_date_policy: PastDueDatePolicy,
+    create_missing_tags: bool,
+    clock: Arc<dyn Clock>,
+}
+
+impl ApplyOperationsUseCase {
+    /// 新しいApplyOperationsUseCaseを作成
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self {
+            unit_of_work,
+            transition_policy: StatusTransitionPolicy::default(),
+            past_due_date_policy: PastDueDatePolicy::default(),
+            create_missing_tags: true,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// ステータスの遷移ポリシーを設定する
+    pub fn with_transition_policy(mut self, policy: StatusTransitionPolicy) -> Self {
+        self.transition_policy = policy;
+        self
+    }
+
+    /// 過去の期限日の扱いを設定する
+    pub fn with_past_due_date_policy(mut self, policy: PastDueDatePolicy) -> Self {
+        self.past_due_date_policy = policy;
+        self
+    }
+
+    /// `tag_names`に存在しないタグ名を指定した場合に作成するかを設定する
+    pub fn with_create_missing_tags(mut self, create: bool) -> Self {
+        self.create_missing_tags = create;
+        self
+    }
+
+    /// 期限日の検証や変更日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 操作を一括実行する
+    ///
+    /// 操作の失敗は`Err`ではなく結果の`status`と`committed`で返します。
+    ///
+    /// # Returns
+    /// * `Err` - トランザクションの開始・確定・取り消しに失敗した場合
+    #[instrument(name = "apply_operations", skip_all, fields(count = operations.len()))]
+    pub async fn execute(&self, operations: Vec<ApplyOperation>) -> Result<ApplyReportDTO> {
+        let uow = self.unit_of_work.begin().await?;
+        let mut results = Vec::with_capacity(operations.len());
+        let mut failed = false;
+
+        for (index, operation) in operations.into_iter().enumerate() {
+            let mut result = OperationResultDTO {
+                index,
+                op: operation.name().to_string(),
+                status: OperationStatus::Skipped,
+                task: None,
+                tag: None,
+                deleted_id: None,
+                error: None,
+            };
+            if !failed {
+                match self.apply(uow.as_ref(), operation, &mut result).await {
+                    Ok(()) => result.status = OperationStatus::Ok,
+                    Err(e) => {
+                        tracing::debug!(index, error = %e, "operation failed");
+                        result.status = OperationStatus::Error;
+                        result.error = Some(format!("{:#}", e));
+                        failed = true;
+                    }
+                }
+            }
+            results.push(result);
+        }
+
+        if failed {
+            uow.rollback().await?;
+        } else {
+            uow.commit().await?;
+        }
+        Ok(ApplyReportDTO {
+            committed: !failed,
+            results,
+        })
+    }
+
+    /// 1つの操作を実行し、結果を記録する
+    async fn apply(
+        &self,
+        uow: &dyn UnitOfWork,
+        operation: ApplyOperation,
+        result: &mut OperationResultDTO,
+    ) -> Result<()> {
+        match operation {
+            ApplyOperation::CreateTask { task, tag_names } => {
+                let use_case = AddTaskUseCase::new(self.unit_of_work.clone())
+                    .with_past_due_date_policy(self.past_due_date_policy)
+                    .with_clock(self.clock.clone());
+                result.task = Some(
+                    use_case
+                        .execute_in(uow, task, &tag_names, self.create_missing_tags)
+                        .await?,
+                );
+            }
+            ApplyOperation::UpdateTask { id, changes } => {
+                let use_case = EditTaskUseCase::new(uow.task_repository(), uow.tag_repository())
+                    .with_transition_policy(self.transition_policy.clone())
+                    .with_clock(self.clock.clone());
+                result.task = Some(use_case.execute(id, changes).await?);
+            }
+            ApplyOperation::DeleteTask { id } => {
+                DeleteTaskUseCase::new(uow.task_repository())
+                    .execute(id)
+                    .await?;
+                result.deleted_id = Some(id);
+            }
+            ApplyOperation::CreateTag { tag } => {
+                result.tag = Some(
+                    AddTagUseCase::new(uow.tag_repository())
+                        .execute(tag)
+                        .await?,
+                );
+            }
+            ApplyOperation::UpdateTag { id, changes } => {
+                result.tag = Some(
+                    EditTagUseCase::new(uow.tag_repository())
+                        .execute(id, changes)
+                        .await?,
+                );
+            }
+            ApplyOperation::DeleteTag { id } => {
+                DeleteTagUseCase::new(uow.tag_repository())
+                    .execute(id)
+                    .await?;
+                result.deleted_id = Some(id);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::value_objects::Status;
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::{
+        domain::{tag::repository::TagRepository, task::repository::TaskRepository},
+        interface::persistence::{
+            failing::{FailingUnitOfWorkFactory, FaultInjector},
+            in_memory::{InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory},
+            sea_orm::{SeaOrmTagRepository, SeaOrmTaskRepository, SeaOrmUnitOfWorkFactory},
+        },
+    };
+    use sea_orm::{EntityTrait, PaginatorTrait};
+
+    fn operations(json: &str) -> Vec<ApplyOperation> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn setup() -> (
+        Arc<InMemoryTaskRepository>,
+        Arc<InMemoryTagRepository>,
+        ApplyOperationsUseCase,
+    ) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = ApplyOperationsUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo.clone(),
+        )));
+        (task_repo, tag_repo, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_apply_commits_all_operations() {
+        // Arrange
+        let (task_repo, tag_repo, use_case) = setup();
+        let ops = operations(
+            r#"[
+                {"op": "create_tag", "name": "work"},
+                {"op": "create_task", "title": "Write report", "priority": "high", "tag_names": ["work", "urgent"]},
+                {"op": "create_task", "title": "Temp"},
+                {"op": "update_task", "id": 1, "status": "in_progress"},
+                {"op": "delete_task", "id": 2}
+            ]"#,
+        );
+
+        // Act
+        let report = use_case.execute(ops).await.unwrap();
+
+        // Assert
+        assert!(report.committed);
+        assert!(
+            report
+                .results
+                .iter()
+                .all(|r| r.status == OperationStatus::Ok)
+        );
+        assert_eq!(report.results[1].task.as_ref().unwrap().tags.len(), 2);
+        assert_eq!(
+            report.results[3].task.as_ref().unwrap().status,
+            Status::InProgress
+        );
+        assert_eq!(report.results[4].deleted_id, Some(2));
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 1);
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_apply_rolls_back_on_failure() {
+        // Arrange
+        let (task_repo, tag_repo, use_case) = setup();
+        let ops = operations(
+            r#"[
+                {"op": "create_task", "title": "Kept?", "tag_names": ["new"]},
+                {"op": "delete_task", "id": 99},
+                {"op": "create_tag", "name": "never"}
+            ]"#,
+        );
+
+        // Act
+        let report = use_case.execute(ops).await.unwrap();
+
+        // Assert
+        assert!(!report.committed);
+        let statuses: Vec<OperationStatus> = report.results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                OperationStatus::Ok,
+                OperationStatus::Error,
+                OperationStatus::Skipped
+            ]
+        );
+        assert!(report.results[1].error.as_ref().unwrap().contains("99"));
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+        assert!(tag_repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_respects_create_missing_tags() {
+        let (_task_repo, _tag_repo, use_case) = setup();
+        let use_case = use_case.with_create_missing_tags(false);
+
+        let report = use_case
+            .execute(operations(
+                r#"[{"op": "create_task", "title": "x", "tag_names": ["missing"]}]"#,
+            ))
+            .await
+            .unwrap();
+
+        assert!(!report.committed);
+        assert_eq!(report.results[0].status, OperationStatus::Error);
+    }
+
+    #[tokio::test]
+    async fn test_apply_repository_failure_rolls_back_database() {
+        // Arrange: タスクの更新がデータベースで失敗する
+        let (_dir, db) = setup_db().await;
+        let faults = Arc::new(FaultInjector::fail_on("task.update"));
+        let use_case = ApplyOperationsUseCase::new(Arc::new(FailingUnitOfWorkFactory::new(
+            Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone())),
+            faults,
+        )))
+        .with_create_missing_tags(true);
+        let ops = operations(
+            r#"[
+                {"op": "create_task", "title": "Write report", "tag_names": ["work"]},
+                {"op": "update_task", "id": 1, "status": "in_progress"},
+                {"op": "create_task", "title": "Later"}
+            ]"#,
+        );
+
+        // Act
+        let report = use_case.execute(ops).await.unwrap();
+
+        // Assert: 失敗した操作と理由が分かり、作成したタスク・タグ・関連付けは残らない
+        assert!(!report.committed);
+        assert_eq!(report.results[1].status, OperationStatus::Error);
+        assert!(
+            report.results[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("Injected failure in task.update")
+        );
+        assert_eq!(report.results[2].status, OperationStatus::Skipped);
+        assert!(
+            SeaOrmTaskRepository::new(db.clone())
+                .find_all()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            SeaOrmTagRepository::new(db.clone())
+                .find_all()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let task_tags = entity::task_tags::Entity::find().count(&db).await.unwrap();
+        assert_eq!(task_tags, 0);
+    }
+}
@@ -1,13 +1,15 @@
 use crate::{
     application::dto::{CreateTagDTO, TagDTO},
     domain::tag::{
-        aggregate::TagAggregate,
+        aggregate::NewTag,
+        hierarchy::TagHierarchy,
         repository::TagRepository,
-        value_objects::{TagDescription, TagName},
+        value_objects::{TagDescription, TagId, TagName},
     },
 };
-use anyhow::Result;
+use anyhow::{Result, bail};
 use std::sync::Arc;
+use tracing::instrument;
 
 /// AddTagUseCase - タグ作成のユースケース
 ///
@@ -24,25 +26,64 @@ impl AddTagUseCase {
 
     /// タグを作成する
     ///
+    /// タグ名は大文字小文字を区別せずに一意です。`parent_id`を指定すると、そのタグの子として作成します。
+    ///
     /// # Arguments
     /// * `dto` - タグ作成時の入力DTO
     ///
     /// # Returns
     /// * `Ok(TagDTO)` - 作成されたタグ
-    /// * `Err` - エラーが発生した場合
+    /// * `Err` - 同じ名前のタグが既にある場合、またはエラーが発生した場合
+    #[instrument(name = "add_tag", skip_all)]
     pub async fn execute(&self, dto: CreateTagDTO) -> Result<TagDTO> {
         // Value Objectsを作成
         let name = TagName::new(dto.name)?;
         let description = TagDescription::new(dto.description.unwrap_or_default())?;
 
-        // Aggregateを作成
-        let tag = TagAggregate::new(name, description);
+        if let Some(existing) = self.tag_repository.find_by_name(name.value()).await? {
+            bail!(
+                "Tag '{}' already exists (id {})",
+                existing.name().value(),
+                existing.id().value()
+            );
+        }
+
+        // 保存前のタグを作成
+        let mut tags = self.tag_repository.find_all().await?;
+        let parent_id = dto.parent_id.map(TagId::new).transpose()?;
+        let tag =
+            NewTag::new(name, description).with_parent(parent_id, &TagHierarchy::new(&tags))?;
 
         // リポジトリに保存
         let saved_tag = self.tag_repository.save(tag).await?;
 
-        // DTOに変換して返す
-        Ok(TagDTO::from(saved_tag))
+        // DTOに変換して返す（パスは親タグを含めて解決する）
+        tags.push(saved_tag.clone());
+        Ok(TagDTO::from_aggregate_in_hierarchy(
+            saved_tag,
+            &TagHierarchy::new(&tags),
+        ))
+    }
+
+    /// 同じ名前のタグがあればそれを返し、なければ作成する
+    ///
+    /// スクリプトから冪等にタグを用意するためのものです。既存のタグの説明は変更しません。
+    ///
+    /// # Returns
+    /// * `Ok((TagDTO, bool))` - タグと、新しく作成したかどうか
+    /// * `Err` - エラーが発生した場合
+    #[instrument(name = "get_or_create_tag", skip_all)]
+    pub async fn get_or_create(&self, dto: CreateTagDTO) -> Result<(TagDTO, bool)> {
+        let name = TagName::new(dto.name.clone())?;
+        if let Some(existing) = self.tag_repository.find_by_name(name.value()).await? {
+            let tags = self.tag_repository.find_all().await?;
+            return Ok((
+                TagDTO::from_aggregate_in_hierarchy(existing, &TagHierarchy::new(&tags)),
+                false,
+            ));
+        }
+
+        Ok((self.execute(dto).await?, true))
     }
 }
 
@@ -60,6 +101,7 @@ mod tests {
         let dto = CreateTagDTO {
             name: "重要".to_string(),
             description: Some("重要なタスク用".to_string()),
+            parent_id: None,
         };
 
         // Act
@@ -81,6 +123,7 @@ mod tests {
         let dto = CreateTagDTO {
             name: "緊急".to_string(),
             description: None,
+            parent_id: None,
         };
 
         // Act
@@ -102,6 +145,7 @@ mod tests {
         let dto = CreateTagDTO {
             name: "".to_string(),
             description: None,
+            parent_id: None,
         };
 
         // Act
@@ -120,6 +164,7 @@ mod tests {
         let dto = CreateTagDTO {
             name: "   ".to_string(),
             description: None,
+            parent_id: None,
         };
 
         // Act
@@ -140,6 +185,7 @@ mod tests {
         let dto = CreateTagDTO {
             name: long_name,
             description: None,
+            parent_id: None,
         };
 
         // Act
@@ -148,4 +194,93 @@ mod tests {
         // Assert
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_add_tag_with_duplicate_name_ignoring_case() {
+        // Arrange
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTagUseCase::new(tag_repo);
+        let first = use_case
+            .execute(CreateTagDTO {
+                name: "Work".to_string(),
+                description: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        // Act
+        let result = use_case
+            .execute(CreateTagDTO {
+                name: "work".to_string(),
+                description: None,
+                parent_id: None,
+            })
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Tag 'Work' already exists (id {})", first.id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_or_create_returns_existing_tag() {
+        // Arrange
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTagUseCase::new(tag_repo.clone());
+        let dto = || CreateTagDTO {
+            name: "仕事".to_string(),
+            description: None,
+            parent_id: None,
+        };
+
+        // Act
+        let (created, was_created) = use_case.get_or_create(dto()).await.unwrap();
+        let (existing, was_created_again) = use_case.get_or_create(dto()).await.unwrap();
+
+        // Assert
+        assert!(was_created);
+        assert!(!was_created_again);
+        assert_eq!(created.id, existing.id);
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_tag_with_parent_returns_path() {
+        // Arrange
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTagUseCase::new(tag_repo);
+        let parent = use_case
+            .execute(CreateTagDTO {
+                name: "work".to_string(),
+                description: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+
+        // Act
+        let child = use_case
+            .execute(CreateTagDTO {
+                name: "project-a".to_string(),
+                description: None,
+                parent_id: Some(parent.id),
+            })
+            .await
+            .unwrap();
+        let missing_parent = use_case
+            .execute(CreateTagDTO {
+                name: "orphan".to_string(),
+                description: None,
+                parent_id: Some(999),
+            })
+            .await;
+
+        // Assert
+        assert_eq!(child.parent_id, Some(parent.id));
+        assert_eq!(child.path, "work/project-a");
+        assert!(missing_parent.is_err());
+    }
 }
@@ -1,6 +1,7 @@
 use crate::domain::tag::{repository::TagRepository, value_objects::TagId};
 use anyhow::Result;
 use std::sync::Arc;
+use tracing::instrument;
 
 /// DeleteTagUseCase - タグ削除のユースケース
 ///
@@ -22,10 +23,27 @@ impl DeleteTagUseCase {
     ///
     /// # Returns
     /// * `Ok(())` - 削除成功
-    /// * `Err` - エラーが発生した場合（タグが見つからない場合を含む）
+    /// * `Err` - エラーが発生した場合（タグが見つからない場合、子タグがある場合を含む）
+    #[instrument(name = "delete_tag", skip(self))]
     pub async fn execute(&self, id: i32) -> Result<()> {
         let tag_id = TagId::new(id)?;
 
+        // 子タグがあるタグは削除しない（子タグが親を失うのを防ぐ）
+        let children = self
+            .tag_repository
+            .find_all()
+            .await?
+            .iter()
+            .filter(|tag| tag.parent_id() == Some(tag_id))
+            .count();
+        if children > 0 {
+            anyhow::bail!(
+                "Tag ID {} has {} child tag(s) and cannot be deleted",
+                id,
+                children
+            );
+        }
+
         // タグを削除
         let deleted = self.tag_repository.delete(&tag_id).await?;
 
@@ -41,7 +59,8 @@ impl DeleteTagUseCase {
 mod tests {
     use super::*;
     use crate::domain::tag::{
-        aggregate::TagAggregate,
+        aggregate::NewTag,
+        hierarchy::TagHierarchy,
         value_objects::{TagDescription, TagName},
     };
     use crate::interface::persistence::in_memory::InMemoryTagRepository;
@@ -51,7 +70,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("削除対象").unwrap(),
             TagDescription::new("").unwrap(),
         );
@@ -102,11 +121,11 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag1 = TagAggregate::new(
+        let tag1 = NewTag::new(
             TagName::new("タグ1").unwrap(),
             TagDescription::new("").unwrap(),
         );
-        let tag2 = TagAggregate::new(
+        let tag2 = NewTag::new(
             TagName::new("タグ2").unwrap(),
             TagDescription::new("").unwrap(),
         );
@@ -136,4 +155,33 @@ mod tests {
         let all_tags = tag_repo.find_all().await.unwrap();
         assert_eq!(all_tags.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_delete_tag_with_children_fails() {
+        // Arrange
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let parent = tag_repo
+            .save(NewTag::new(
+                TagName::new("work").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let hierarchy = TagHierarchy::new(std::slice::from_ref(&parent));
+        let child = NewTag::new(
+            TagName::new("project-a").unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+        .with_parent(Some(*parent.id()), &hierarchy)
+        .unwrap();
+        tag_repo.save(child).await.unwrap();
+        let use_case = DeleteTagUseCase::new(tag_repo.clone());
+
+        // Act
+        let result = use_case.execute(parent.id().value()).await;
+
+        // Assert
+        assert!(result.unwrap_err().to_string().contains("1 child tag(s)"));
+        assert!(tag_repo.find_by_id(parent.id()).await.unwrap().is_some());
+    }
 }
@@ -1,12 +1,14 @@
 use crate::{
     application::dto::{TagDTO, UpdateTagDTO},
     domain::tag::{
+        hierarchy::TagHierarchy,
         repository::TagRepository,
         value_objects::{TagDescription, TagId, TagName},
     },
 };
 use anyhow::Result;
 use std::sync::Arc;
+use tracing::instrument;
 
 /// EditTagUseCase - タグ更新のユースケース
 ///
@@ -30,6 +32,7 @@ impl EditTagUseCase {
     /// # Returns
     /// * `Ok(TagDTO)` - 更新されたタグ
     /// * `Err` - エラーが発生した場合
+    #[instrument(name = "edit_tag", skip(self, dto))]
     pub async fn execute(&self, id: i32, dto: UpdateTagDTO) -> Result<TagDTO> {
         let tag_id = TagId::new(id)?;
 
@@ -52,11 +55,31 @@ impl EditTagUseCase {
             tag.change_description(description)?;
         }
 
+        // 親タグの更新（循環する親子関係は拒否する）
+        let tags = self.tag_repository.find_all().await?;
+        if let Some(parent_id) = dto.parent_id {
+            let parent_id = parent_id.map(TagId::new).transpose()?;
+            tag.change_parent(parent_id, &TagHierarchy::new(&tags))?;
+        }
+
         // リポジトリに保存
         let updated_tag = self.tag_repository.update(tag).await?;
 
-        // DTOに変換して返す
-        Ok(TagDTO::from(updated_tag))
+        // DTOに変換して返す（パスは更新後の親子関係で解決する）
+        let tags: Vec<_> = tags
+            .into_iter()
+            .map(|tag| {
+                if tag.id() == updated_tag.id() {
+                    updated_tag.clone()
+                } else {
+                    tag
+                }
+            })
+            .collect();
+        Ok(TagDTO::from_aggregate_in_hierarchy(
+            updated_tag,
+            &TagHierarchy::new(&tags),
+        ))
     }
 }
 
@@ -64,7 +87,7 @@ impl EditTagUseCase {
 mod tests {
     use super::*;
     use crate::domain::tag::{
-        aggregate::TagAggregate,
+        aggregate::NewTag,
         value_objects::{TagDescription, TagName},
     };
     use crate::interface::persistence::in_memory::InMemoryTagRepository;
@@ -74,7 +97,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("元の名前").unwrap(),
             TagDescription::new("説明").unwrap(),
         );
@@ -102,7 +125,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("タグ名").unwrap(),
             TagDescription::new("元の説明").unwrap(),
         );
@@ -130,7 +153,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("元の名前").unwrap(),
             TagDescription::new("元の説明").unwrap(),
         );
@@ -141,6 +164,7 @@ mod tests {
         let dto = UpdateTagDTO {
             name: Some("新しい名前".to_string()),
             description: Some("新しい説明".to_string()),
+            ..Default::default()
         };
 
         // Act
@@ -195,7 +219,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("タグ名").unwrap(),
             TagDescription::new("").unwrap(),
         );
@@ -220,7 +244,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("タグ名").unwrap(),
             TagDescription::new("説明").unwrap(),
         );
@@ -240,4 +264,54 @@ mod tests {
         assert_eq!(updated_tag.name, "タグ名");
         assert_eq!(updated_tag.description, Some("説明".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_edit_tag_parent_rejects_cycle() {
+        // Arrange
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let parent = tag_repo
+            .save(NewTag::new(
+                TagName::new("work").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let child = tag_repo
+            .save(NewTag::new(
+                TagName::new("project-a").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let use_case = EditTagUseCase::new(tag_repo);
+        let move_under = |parent_id: i32| UpdateTagDTO {
+            parent_id: Some(Some(parent_id)),
+            ..Default::default()
+        };
+
+        // Act
+        let nested = use_case
+            .execute(child.id().value(), move_under(parent.id().value()))
+            .await
+            .unwrap();
+        let cycle = use_case
+            .execute(parent.id().value(), move_under(child.id().value()))
+            .await;
+        let detached = use_case
+            .execute(
+                child.id().value(),
+                UpdateTagDTO {
+                    parent_id: Some(None),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(nested.path, "work/project-a");
+        assert!(cycle.unwrap_err().to_string().contains("cycle"));
+        assert_eq!(detached.parent_id, None);
+        assert_eq!(detached.path, "project-a");
+    }
 }
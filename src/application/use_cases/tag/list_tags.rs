@@ -1,6 +1,10 @@
-use crate::{application::dto::TagDTO, domain::tag::repository::TagRepository};
+use crate::{
+    application::dto::TagDTO,
+    domain::tag::{hierarchy::TagHierarchy, repository::TagRepository},
+};
 use anyhow::Result;
 use std::sync::Arc;
+use tracing::instrument;
 
 /// ListTagsUseCase - タグ一覧取得のユースケース
 ///
@@ -17,12 +21,19 @@ impl ListTagsUseCase {
 
     /// 全タグを取得する
     ///
+    /// 各タグのパスは親タグを含めて解決します。
+    ///
     /// # Returns
     /// * `Ok(Vec<TagDTO>)` - タグの一覧
     /// * `Err` - エラーが発生した場合
+    #[instrument(name = "list_tags", skip_all)]
     pub async fn execute(&self) -> Result<Vec<TagDTO>> {
         let tags = self.tag_repository.find_all().await?;
-        Ok(tags.into_iter().map(TagDTO::from).collect())
+        let hierarchy = TagHierarchy::new(&tags);
+        Ok(tags
+            .into_iter()
+            .map(|tag| TagDTO::from_aggregate_in_hierarchy(tag, &hierarchy))
+            .collect())
     }
 }
 
@@ -30,7 +41,7 @@ impl ListTagsUseCase {
 mod tests {
     use super::*;
     use crate::domain::tag::{
-        aggregate::TagAggregate,
+        aggregate::NewTag,
         value_objects::{TagDescription, TagName},
     };
     use crate::interface::persistence::in_memory::InMemoryTagRepository;
@@ -55,7 +66,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("重要").unwrap(),
             TagDescription::new("重要なタスク用").unwrap(),
         );
@@ -78,15 +89,15 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag1 = TagAggregate::new(
+        let tag1 = NewTag::new(
             TagName::new("重要").unwrap(),
             TagDescription::new("重要なタスク用").unwrap(),
         );
-        let tag2 = TagAggregate::new(
+        let tag2 = NewTag::new(
             TagName::new("緊急").unwrap(),
             TagDescription::new("").unwrap(),
         );
-        let tag3 = TagAggregate::new(
+        let tag3 = NewTag::new(
             TagName::new("作業中").unwrap(),
             TagDescription::new("現在作業中のタスク").unwrap(),
         );
@@ -1,9 +1,10 @@
 use crate::{
     application::dto::TagDTO,
-    domain::tag::{repository::TagRepository, value_objects::TagId},
+    domain::tag::{hierarchy::TagHierarchy, repository::TagRepository, value_objects::TagId},
 };
 use anyhow::Result;
 use std::sync::Arc;
+use tracing::instrument;
 
 /// ShowTagUseCase - タグ詳細取得のユースケース
 ///
@@ -26,6 +27,7 @@ impl ShowTagUseCase {
     /// # Returns
     /// * `Ok(TagDTO)` - タグの詳細
     /// * `Err` - エラーが発生した場合（タグが見つからない場合を含む）
+    #[instrument(name = "show_tag", skip(self))]
     pub async fn execute(&self, id: i32) -> Result<TagDTO> {
         let tag_id = TagId::new(id)?;
 
@@ -35,7 +37,11 @@ impl ShowTagUseCase {
             .await?
             .ok_or_else(|| anyhow::anyhow!("Tag ID {} does not exist", id))?;
 
-        Ok(TagDTO::from(tag))
+        let tags = self.tag_repository.find_all().await?;
+        Ok(TagDTO::from_aggregate_in_hierarchy(
+            tag,
+            &TagHierarchy::new(&tags),
+        ))
     }
 }
 
@@ -43,7 +49,7 @@ impl ShowTagUseCase {
 mod tests {
     use super::*;
     use crate::domain::tag::{
-        aggregate::TagAggregate,
+        aggregate::NewTag,
         value_objects::{TagDescription, TagName},
     };
     use crate::interface::persistence::in_memory::InMemoryTagRepository;
@@ -53,7 +59,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("重要").unwrap(),
             TagDescription::new("重要なタスク用").unwrap(),
         );
@@ -104,7 +110,7 @@ mod tests {
         // Arrange
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("緊急").unwrap(),
             TagDescription::new("").unwrap(),
         );
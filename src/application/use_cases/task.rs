@@ -1,7 +1,25 @@
 pub mod add_task;
+pub mod apply_overdue_tag;
+pub mod complete_task;
 pub mod delete_task;
+pub mod due_calendar;
 pub mod edit_task;
+pub mod find_similar_tasks;
+pub mod import_tasks;
+pub mod list_contexts;
+pub mod list_stale_tasks;
 pub mod list_tasks;
+pub mod manage_checklist;
+pub mod manage_git_links;
+pub mod pick_task;
+pub mod query_tasks;
+pub mod run_scheduled_jobs;
 pub mod search_tasks;
+pub mod show_agenda;
 pub mod show_stats;
 pub mod show_task;
+pub mod show_workload;
+pub mod snooze_task;
+pub mod summarize_tasks;
+pub mod sync_databases;
+pub mod sync_issues;
@@ -1,37 +1,103 @@
 use crate::{
     application::dto::{CreateTaskDTO, TagInfo, TaskDTO},
     domain::{
-        tag::repository::TagRepository,
+        clock::{Clock, SystemClock},
+        tag::{
+            aggregate::NewTag,
+            repository::TagRepository,
+            value_objects::{TagDescription, TagName},
+        },
         task::{
-            aggregate::TaskAggregate,
+            aggregate::{NewTask, TaskAggregate},
+            builder::TaskBuilder,
             repository::TaskRepository,
-            value_objects::{Priority, Status, TaskDescription, TaskTitle},
+            value_objects::{
+                Assignee, CustomField, DueDate, Estimate, PastDueDatePolicy, Priority, Recurrence,
+                Status, TaskContext, TaskDescription, TaskTitle,
+            },
         },
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory, commit_or_rollback},
     },
 };
-use anyhow::{Result, bail};
-use std::{collections::HashMap, sync::Arc};
+use anyhow::{Context, Result, bail};
+use std::{collections::HashMap, fmt, sync::Arc};
+use tracing::instrument;
+
+/// タスク追加のエラー
+///
+/// 表示する文言をインターフェース層で言語に合わせて選べるよう、種類ごとに分けています。
+/// `anyhow::Error::chain`の中から`downcast_ref`で取り出せます。
+#[derive(Debug)]
+pub enum AddTaskError {
+    /// 指定したIDのタグが存在しない
+    TagIdNotFound(i32),
+    /// 指定した名前のタグが存在しない
+    TagNotFound(String),
+    /// 1件のタスクを追加できなかったため、どのタスクも追加していない
+    TaskFailed {
+        title: String,
+        source: anyhow::Error,
+    },
+    /// タスクを保存できなかったため、どのタスクも追加していない
+    SaveFailed(anyhow::Error),
+}
+
+impl fmt::Display for AddTaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TagIdNotFound(id) => write!(f, "Tag ID {} does not exist", id),
+            Self::TagNotFound(name) => write!(f, "Tag '{}' does not exist", name),
+            Self::TaskFailed { title, .. } => {
+                write!(f, "Failed to add task '{}'; no tasks were added", title)
+            }
+            Self::SaveFailed(_) => f.write_str("Failed to add tasks; no tasks were added"),
+        }
+    }
+}
+
+impl std::error::Error for AddTaskError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::TaskFailed { source, .. } | Self::SaveFailed(source) => Some(source.as_ref()),
+            Self::TagIdNotFound(_) | Self::TagNotFound(_) => None,
+        }
+    }
+}
 
 /// AddTaskUseCase - タスク追加のユースケース
 ///
 /// 新しいタスクを作成してリポジトリに保存します。
+/// タグの検証・作成とタスクの保存は1つのトランザクション（UnitOfWork）内で行い、
+/// 途中で失敗した場合はすべての変更を取り消します。
+/// 過去の期限日は`PastDueDatePolicy`（既定は許可）に従って検証します。
 pub struct AddTaskUseCase {
-    task_repository: Arc<dyn TaskRepository>,
-    tag_repository: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    past_due_date_policy: PastDueDatePolicy,
+    clock: Arc<dyn Clock>,
 }
 
 impl AddTaskUseCase {
     /// 新しいAddTaskUseCaseを作成
-    pub fn new(
-        task_repository: Arc<dyn TaskRepository>,
-        tag_repository: Arc<dyn TagRepository>,
-    ) -> Self {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
         Self {
-            task_repository,
-            tag_repository,
+            unit_of_work,
+            past_due_date_policy: PastDueDatePolicy::default(),
+            clock: Arc::new(SystemClock::default()),
         }
     }
 
+    /// 過去の期限日の扱いを設定する
+    pub fn with_past_due_date_policy(mut self, policy: PastDueDatePolicy) -> Self {
+        self.past_due_date_policy = policy;
+        self
+    }
+
+    /// 期限日が過去かどうかの判定に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// タスクを追加する
     ///
     /// # Arguments
@@ -40,7 +106,148 @@ impl AddTaskUseCase {
     /// # Returns
     /// * `Ok(TaskDTO)` - 作成されたタスク
     /// * `Err` - エラーが発生した場合
+    #[instrument(name = "add_task", skip_all)]
     pub async fn execute(&self, dto: CreateTaskDTO) -> Result<TaskDTO> {
+        self.check_due_date(&dto)?;
+        let uow = self.unit_of_work.begin().await?;
+        let result = AddTaskTransaction::new(uow.as_ref()).add(dto).await;
+        commit_or_rollback(uow, result).await
+    }
+
+    /// タグ名を指定してタスクを追加する
+    ///
+    /// タグ名は大文字小文字を区別せずに既存タグと照合し、`dto.tags`のタグIDと合わせて付与します。
+    /// 自動作成したタグも、タスクの保存に失敗した場合は取り消されます。
+    ///
+    /// # Arguments
+    /// * `dto` - タスク作成時の入力DTO
+    /// * `tag_names` - 付与するタグ名
+    /// * `create_missing` - 存在しないタグを自動作成するか
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 作成されたタスク
+    /// * `Err` - `create_missing`がfalseで存在しないタグ名が含まれる場合など
+    #[instrument(name = "add_task", skip(self, dto))]
+    pub async fn execute_with_tag_names(
+        &self,
+        dto: CreateTaskDTO,
+        tag_names: &[String],
+        create_missing: bool,
+    ) -> Result<TaskDTO> {
+        let uow = self.unit_of_work.begin().await?;
+        let result = self
+            .execute_in(uow.as_ref(), dto, tag_names, create_missing)
+            .await;
+        commit_or_rollback(uow, result).await
+    }
+
+    /// 複数のタスクを1つのトランザクションで追加する
+    ///
+    /// いずれかのタスクの追加に失敗した場合は、作成したタスクとタグをすべて取り消します。
+    ///
+    /// # Arguments
+    /// * `tasks` - タスク作成時の入力DTOと、付与するタグ名の組
+    /// * `create_missing` - 存在しないタグを自動作成するか
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskDTO>)` - 作成されたタスク（入力の順）
+    /// * `Err` - いずれかのタスクの追加に失敗した場合
+    #[instrument(name = "add_tasks", skip_all, fields(count = tasks.len()))]
+    pub async fn execute_batch(
+        &self,
+        tasks: Vec<(CreateTaskDTO, Vec<String>)>,
+        create_missing: bool,
+    ) -> Result<Vec<TaskDTO>> {
+        let uow = self.unit_of_work.begin().await?;
+        let result = async {
+            let transaction = AddTaskTransaction::new(uow.as_ref());
+            let mut new_tasks = Vec::with_capacity(tasks.len());
+            for (dto, tag_names) in tasks {
+                let title = dto.title.clone();
+                let task = async {
+                    self.check_due_date(&dto)?;
+                    let dto = transaction
+                        .resolve_tag_names(dto, &tag_names, create_missing)
+                        .await?;
+                    transaction.build(dto).await
+                }
+                .await
+                .map_err(|source| AddTaskError::TaskFailed { title, source })?;
+                new_tasks.push(task);
+            }
+            // タスクはまとめて保存し、往復回数を削減する
+            let saved = transaction
+                .task_repository
+                .save_all(new_tasks)
+                .await
+                .map_err(AddTaskError::SaveFailed)?;
+            transaction.to_dtos(saved).await
+        }
+        .await;
+        commit_or_rollback(uow, result).await
+    }
+
+    /// 開始済みのトランザクション内で、タグ名を指定してタスクを追加する
+    ///
+    /// 複数の操作を1つのトランザクションで行う場合に使用します。コミットは呼び出し側で行います。
+    pub async fn execute_in(
+        &self,
+        uow: &dyn UnitOfWork,
+        dto: CreateTaskDTO,
+        tag_names: &[String],
+        create_missing: bool,
+    ) -> Result<TaskDTO> {
+        self.check_due_date(&dto)?;
+        let transaction = AddTaskTransaction::new(uow);
+        let dto = transaction
+            .resolve_tag_names(dto, tag_names, create_missing)
+            .await?;
+        transaction.add(dto).await
+    }
+
+    /// 期限日が過去の場合にポリシーに従って検証する
+    fn check_due_date(&self, dto: &CreateTaskDTO) -> Result<()> {
+        if let Some(date) = dto.due_date {
+            let today = self.clock.today();
+            if self
+                .past_due_date_policy
+                .check(&DueDate::new(date)?, today)?
+            {
+                tracing::debug!(due_date = %date, "adding task with a past due date");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 1つのトランザクション内でタスク追加の処理を行う
+///
+/// リポジトリはUnitOfWorkから取得したものを使用し、処理の終了時に破棄されます。
+struct AddTaskTransaction {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+impl AddTaskTransaction {
+    fn new(uow: &dyn UnitOfWork) -> Self {
+        Self {
+            task_repository: uow.task_repository(),
+            tag_repository: uow.tag_repository(),
+        }
+    }
+
+    /// タスクを検証して保存する
+    async fn add(&self, dto: CreateTaskDTO) -> Result<TaskDTO> {
+        let task = self.build(dto).await?;
+        let saved_task = self.task_repository.save(task).await?;
+        self.to_dtos(vec![saved_task])
+            .await?
+            .pop()
+            .context("Failed to read back the saved task")
+    }
+
+    /// 入力を検証して保存前のタスクを作成する
+    async fn build(&self, dto: CreateTaskDTO) -> Result<NewTask> {
         // タイトルのバリデーション
         let title = TaskTitle::new(dto.title)?;
 
@@ -51,21 +258,9 @@ impl AddTaskUseCase {
             TaskDescription::new("")?
         };
 
-        // ステータスの変換（デフォルト: Pending）
-        // Display形式（"InProgress"）とフィルタ形式（"in_progress"）の両方をサポート
-        let status = if let Some(status_str) = dto.status {
-            Status::from_str_anyhow(&status_str)
-                .or_else(|_| Status::from_filter_value(&status_str))?
-        } else {
-            Status::Pending
-        };
-
-        // 優先度の変換（デフォルト: Medium）
-        let priority = if let Some(priority_str) = dto.priority {
-            Priority::from_str_anyhow(&priority_str)?
-        } else {
-            Priority::Medium
-        };
+        // ステータス（デフォルト: Pending）と優先度（デフォルト: Medium）
+        let status = dto.status.unwrap_or(Status::Pending);
+        let priority = dto.priority.unwrap_or(Priority::Medium);
 
         // タグの存在確認（一括）
         if !dto.tags.is_empty() {
@@ -88,7 +283,7 @@ impl AddTaskUseCase {
 
                 for tag_id in &dto.tags {
                     if !found_ids.contains(tag_id) {
-                        bail!("タグID {}は存在しません", tag_id);
+                        bail!(AddTaskError::TagIdNotFound(*tag_id));
                     }
                 }
             }
@@ -107,20 +302,63 @@ impl AddTaskUseCase {
 
         // 期限日の変換
         let due_date = if let Some(date) = dto.due_date {
-            use crate::domain::task::value_objects::DueDate;
             Some(DueDate::new(date)?)
         } else {
             None
         };
 
-        // TaskAggregateを作成
-        let task = TaskAggregate::new(title, description, status, priority, tag_ids, due_date);
-
-        // リポジトリに保存
-        let saved_task = self.task_repository.save(task).await?;
+        // カスタムフィールドのバリデーション
+        let custom_fields: Vec<CustomField> = dto
+            .custom_fields
+            .iter()
+            .map(|(key, value)| CustomField::new(key, value.as_str()))
+            .collect::<Result<_>>()?;
+
+        // 繰り返しルールのバリデーション
+        let recurrence = dto
+            .recurrence
+            .as_deref()
+            .map(str::parse::<Recurrence>)
+            .transpose()?;
+
+        // 見積もり時間のバリデーション
+        let estimate = dto
+            .estimate
+            .as_deref()
+            .map(str::parse::<Estimate>)
+            .transpose()?;
+
+        // コンテキストのバリデーション
+        let context = dto.context.as_deref().map(TaskContext::new).transpose()?;
+
+        // 担当者のバリデーション
+        let assignee = dto.assignee.as_deref().map(Assignee::new).transpose()?;
+
+        // 保存前のタスクを作成
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .status(status)
+            .priority(priority)
+            .tags(tag_ids)
+            .due_date(due_date)
+            .custom_fields(custom_fields)
+            .recurrence(recurrence)
+            .estimate(estimate)
+            .context(context)
+            .assignee(assignee)
+            .build();
+
+        Ok(task)
+    }
 
-        // タグ情報を取得（既に検証済みなので安全）
-        let tag_ids: Vec<_> = saved_task.tags().clone();
+    /// 保存したタスクをタグ情報を解決したDTOに変換する（タグはまとめて取得する）
+    async fn to_dtos(&self, saved_tasks: Vec<TaskAggregate>) -> Result<Vec<TaskDTO>> {
+        let mut tag_ids: Vec<_> = saved_tasks
+            .iter()
+            .flat_map(|task| task.tags().iter().copied())
+            .collect();
+        tag_ids.sort_by_key(|tag_id| tag_id.value());
+        tag_ids.dedup();
         let tags = if !tag_ids.is_empty() {
             self.tag_repository.find_by_ids(&tag_ids).await?
         } else {
@@ -130,21 +368,62 @@ impl AddTaskUseCase {
         // タグマップを作成
         let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
 
-        // タグ詳細を解決
-        let tag_details = saved_task
-            .tags()
-            .iter()
-            .filter_map(|tag_id| {
-                tag_map.get(&tag_id.value()).map(|tag| TagInfo {
-                    id: tag.id().value(),
-                    name: tag.name().value().to_string(),
-                })
+        Ok(saved_tasks
+            .into_iter()
+            .map(|saved_task| {
+                // タグ詳細を解決
+                let tag_details = saved_task
+                    .tags()
+                    .iter()
+                    .filter_map(|tag_id| {
+                        tag_map.get(&tag_id.value()).map(|tag| TagInfo {
+                            id: tag.id().value(),
+                            name: tag.name().value().to_string(),
+                        })
+                    })
+                    .collect();
+                let mut dto = TaskDTO::from(saved_task);
+                dto.tags = tag_details;
+                dto
             })
-            .collect();
+            .collect())
+    }
+
+    /// タグ名をタグIDに解決し、`dto.tags`に追加する（必要に応じてタグを作成）
+    async fn resolve_tag_names(
+        &self,
+        mut dto: CreateTaskDTO,
+        tag_names: &[String],
+        create_missing: bool,
+    ) -> Result<CreateTaskDTO> {
+        let mut existing_tags = self.tag_repository.find_all().await?;
+
+        for name in tag_names {
+            let name = name.trim();
+            if name.is_empty() {
+                continue;
+            }
+
+            let existing = existing_tags.iter().find(|tag| tag.name().matches(name));
+
+            let tag_id = match existing {
+                Some(tag) => tag.id().value(),
+                None if create_missing => {
+                    let tag = NewTag::new(TagName::new(name)?, TagDescription::new("")?);
+                    let saved_tag = self.tag_repository.save(tag).await?;
+                    let id = saved_tag.id().value();
+                    // 同じ名前が重複して指定された場合に二重作成しないよう、作成済みとして扱う
+                    existing_tags.push(saved_tag);
+                    id
+                }
+                None => bail!(AddTaskError::TagNotFound(name.to_string())),
+            };
+
+            if !dto.tags.contains(&tag_id) {
+                dto.tags.push(tag_id);
+            }
+        }
 
-        // DTOに変換して返す
-        let mut dto = TaskDTO::from(saved_task);
-        dto.tags = tag_details;
         Ok(dto)
     }
 }
@@ -152,17 +431,25 @@ impl AddTaskUseCase {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::tag::{
-        aggregate::TagAggregate, value_objects::TagDescription, value_objects::TagName,
+    use crate::domain::clock::FixedClock;
+    use crate::domain::tag::{value_objects::TagDescription, value_objects::TagName};
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::interface::persistence::{
+        failing::{FailingUnitOfWorkFactory, FaultInjector},
+        in_memory::{InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory},
+        sea_orm::{SeaOrmTagRepository, SeaOrmTaskRepository, SeaOrmUnitOfWorkFactory},
     };
-    use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
+    use sea_orm::{EntityTrait, PaginatorTrait};
 
     #[tokio::test]
     async fn test_add_task_minimal() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
-        let use_case = AddTaskUseCase::new(task_repo.clone(), tag_repo);
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo,
+        )));
 
         let dto = CreateTaskDTO {
             title: "新しいタスク".to_string(),
@@ -171,6 +458,11 @@ mod tests {
             priority: None,
             tags: vec![],
             due_date: None,
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
         };
 
         // Act
@@ -181,8 +473,8 @@ mod tests {
         let task = result.unwrap();
         assert_eq!(task.title, "新しいタスク");
         assert_eq!(task.description, None);
-        assert_eq!(task.status, "pending");
-        assert_eq!(task.priority, "medium");
+        assert_eq!(task.status, Status::Pending);
+        assert_eq!(task.priority, Priority::Medium);
         assert!(task.tags.is_empty());
         assert!(task.due_date.is_none());
 
@@ -191,6 +483,64 @@ mod tests {
         assert_eq!(all_tasks.len(), 1);
     }
 
+    /// 2025-06-15を基準に、指定した日数後の期限を持つ入力DTOを作成
+    fn due_in(days: i64) -> CreateTaskDTO {
+        CreateTaskDTO {
+            title: "期限付き".to_string(),
+            description: None,
+            status: None,
+            priority: None,
+            tags: vec![],
+            due_date: Some(
+                chrono::NaiveDate::from_ymd_opt(2025, 6, 15).unwrap()
+                    + chrono::Duration::days(days),
+            ),
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_task_past_due_date_rejected_by_policy() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo,
+        )))
+        .with_past_due_date_policy(PastDueDatePolicy::Reject)
+        .with_clock(Arc::new(FixedClock::at(2025, 6, 15, 12, 0)));
+
+        // Act
+        let yesterday = use_case.execute(due_in(-1)).await;
+        let today = use_case.execute(due_in(0)).await;
+
+        // Assert
+        assert!(yesterday.unwrap_err().to_string().contains("in the past"));
+        assert!(today.is_ok());
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_task_past_due_date_allowed_by_default() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo, tag_repo,
+        )));
+
+        // Act
+        let result = use_case.execute(due_in(-30)).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_add_task_full() {
         // Arrange
@@ -198,21 +548,29 @@ mod tests {
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
         // タグを事前に作成
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("重要").unwrap(),
             TagDescription::new("重要なタスク").unwrap(),
         );
         let saved_tag = tag_repo.save(tag).await.unwrap();
 
-        let use_case = AddTaskUseCase::new(task_repo.clone(), tag_repo);
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo,
+        )));
 
         let dto = CreateTaskDTO {
             title: "詳細タスク".to_string(),
             description: Some("詳細な説明".to_string()),
-            status: Some("in_progress".to_string()),
-            priority: Some("high".to_string()),
+            status: Some(Status::InProgress),
+            priority: Some(Priority::High),
             tags: vec![saved_tag.id().value()],
             due_date: Some(chrono::NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()),
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
         };
 
         // Act
@@ -223,8 +581,8 @@ mod tests {
         let task = result.unwrap();
         assert_eq!(task.title, "詳細タスク");
         assert_eq!(task.description, Some("詳細な説明".to_string()));
-        assert_eq!(task.status, "in_progress");
-        assert_eq!(task.priority, "high");
+        assert_eq!(task.status, Status::InProgress);
+        assert_eq!(task.priority, Priority::High);
         assert_eq!(task.tags.len(), 1);
         assert_eq!(task.tags[0].id, saved_tag.id().value());
         assert_eq!(task.tags[0].name, saved_tag.name().value());
@@ -236,7 +594,9 @@ mod tests {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
-        let use_case = AddTaskUseCase::new(task_repo, tag_repo);
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo, tag_repo,
+        )));
 
         let dto = CreateTaskDTO {
             title: "タスク".to_string(),
@@ -245,6 +605,11 @@ mod tests {
             priority: None,
             tags: vec![999], // 存在しないタグID
             due_date: None,
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
         };
 
         // Act
@@ -252,7 +617,7 @@ mod tests {
 
         // Assert
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("存在しません"));
+        assert!(result.unwrap_err().to_string().contains("does not exist"));
     }
 
     #[tokio::test]
@@ -260,7 +625,9 @@ mod tests {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
-        let use_case = AddTaskUseCase::new(task_repo, tag_repo);
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo, tag_repo,
+        )));
 
         let dto = CreateTaskDTO {
             title: "".to_string(),
@@ -269,6 +636,11 @@ mod tests {
             priority: None,
             tags: vec![],
             due_date: None,
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
         };
 
         // Act
@@ -279,26 +651,304 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_add_task_with_invalid_priority() {
+    async fn test_add_task_with_custom_fields() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
-        let use_case = AddTaskUseCase::new(task_repo, tag_repo);
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo, tag_repo,
+        )));
+        let dto = CreateTaskDTO {
+            custom_fields: [("Estimate", "3h"), ("client", "ACME")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..minimal_dto()
+        };
+
+        // Act
+        let task = use_case.execute(dto).await.unwrap();
 
+        // Assert: キーは小文字に正規化される
+        assert_eq!(task.custom_fields.len(), 2);
+        assert_eq!(task.custom_fields["estimate"], "3h");
+        assert_eq!(task.custom_fields["client"], "ACME");
+    }
+
+    #[tokio::test]
+    async fn test_add_task_with_invalid_custom_field_key() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo,
+        )));
         let dto = CreateTaskDTO {
-            title: "タスク".to_string(),
+            custom_fields: [("1st".to_string(), "x".to_string())].into(),
+            ..minimal_dto()
+        };
+
+        // Act
+        let result = use_case.execute(dto).await;
+
+        // Assert
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid custom field key")
+        );
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+    }
+
+    fn minimal_dto() -> CreateTaskDTO {
+        CreateTaskDTO {
+            title: "タグ名指定タスク".to_string(),
             description: None,
             status: None,
-            priority: Some("invalid".to_string()),
+            priority: None,
             tags: vec![],
             due_date: None,
-        };
+            custom_fields: Default::default(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_task_with_tag_names_resolves_existing_case_insensitive() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let work = tag_repo
+            .save(NewTag::new(
+                TagName::new("Work").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo,
+            tag_repo.clone(),
+        )));
 
         // Act
-        let result = use_case.execute(dto).await;
+        let task = use_case
+            .execute_with_tag_names(minimal_dto(), &["work".to_string()], true)
+            .await
+            .unwrap();
+
+        // Assert: 既存タグが使われ、新しいタグは作成されない
+        assert_eq!(task.tags.len(), 1);
+        assert_eq!(task.tags[0].id, work.id().value());
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_task_with_tag_names_creates_missing() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo,
+            tag_repo.clone(),
+        )));
+
+        // Act
+        let task = use_case
+            .execute_with_tag_names(
+                minimal_dto(),
+                &["仕事".to_string(), "緊急".to_string(), "仕事".to_string()],
+                true,
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        let names: Vec<&str> = task.tags.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["仕事", "緊急"]);
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_task_with_tag_names_missing_without_auto_create() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo.clone(),
+        )));
+
+        // Act
+        let result = use_case
+            .execute_with_tag_names(minimal_dto(), &["仕事".to_string()], false)
+            .await;
+
+        // Assert: タスクもタグも作成されない
+        assert_eq!(result.unwrap_err().to_string(), "Tag '仕事' does not exist");
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+        assert!(tag_repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_task_with_tag_names_rolls_back_created_tags_on_failure() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo.clone(),
+        )));
+        let mut dto = minimal_dto();
+        dto.title = "".to_string(); // タグ作成後、タイトルの検証で失敗させる
+
+        // Act
+        let result = use_case
+            .execute_with_tag_names(dto, &["仕事".to_string()], true)
+            .await;
+
+        // Assert: 自動作成されたタグも取り消される
+        assert!(result.is_err());
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+        assert!(tag_repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_tasks_in_batch_shares_created_tags() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo.clone(),
+        )));
+        let mut second = minimal_dto();
+        second.title = "2つ目".to_string();
+
+        // Act
+        let created = use_case
+            .execute_batch(
+                vec![
+                    (minimal_dto(), vec!["仕事".to_string()]),
+                    (second, vec!["仕事".to_string(), "家".to_string()]),
+                ],
+                true,
+            )
+            .await
+            .unwrap();
 
         // Assert
+        let titles: Vec<&str> = created.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["タグ名指定タスク", "2つ目"]);
+        assert_eq!(created[0].tags[0].id, created[1].tags[0].id);
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 2);
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_tasks_in_batch_rolls_back_all_on_failure() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = AddTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo.clone(),
+        )));
+        let mut invalid = minimal_dto();
+        invalid.title = "a".repeat(101);
+
+        // Act
+        let result = use_case
+            .execute_batch(
+                vec![(minimal_dto(), vec!["仕事".to_string()]), (invalid, vec![])],
+                true,
+            )
+            .await;
+
+        // Assert: 1つ目のタスクと作成したタグも取り消される
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Invalid priority"));
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+        assert!(tag_repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_tasks_in_batch_leaves_no_rows_when_save_fails() {
+        // Arrange: タスクをまとめて保存した後に失敗する
+        let (_dir, db) = setup_db().await;
+        let faults = Arc::new(FaultInjector::fail_on("task.save_all").after_call());
+        let use_case = AddTaskUseCase::new(Arc::new(FailingUnitOfWorkFactory::new(
+            Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone())),
+            faults,
+        )));
+        let mut second = minimal_dto();
+        second.title = "2つ目".to_string();
+
+        // Act
+        let result = use_case
+            .execute_batch(
+                vec![
+                    (minimal_dto(), vec!["仕事".to_string()]),
+                    (second, vec!["仕事".to_string()]),
+                ],
+                true,
+            )
+            .await;
+
+        // Assert: タスク・タグ・関連付けは残らない
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains("Failed to add tasks; no tasks were added"));
+        assert!(message.contains("Injected failure in task.save_all"));
+        assert!(
+            SeaOrmTaskRepository::new(db.clone())
+                .find_all()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            SeaOrmTagRepository::new(db.clone())
+                .find_all()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let task_tags = entity::task_tags::Entity::find().count(&db).await.unwrap();
+        assert_eq!(task_tags, 0);
+    }
+
+    #[tokio::test]
+    async fn test_add_task_commit_failure_leaves_no_rows() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let faults = Arc::new(FaultInjector::fail_on("commit"));
+        let use_case = AddTaskUseCase::new(Arc::new(FailingUnitOfWorkFactory::new(
+            Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone())),
+            faults.clone(),
+        )));
+
+        // Act
+        let result = use_case
+            .execute_with_tag_names(minimal_dto(), &["仕事".to_string()], true)
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Injected failure in commit"
+        );
+        assert_eq!(faults.calls(), 1);
+        assert!(
+            SeaOrmTaskRepository::new(db.clone())
+                .find_all()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let task_tags = entity::task_tags::Entity::find().count(&db).await.unwrap();
+        assert_eq!(task_tags, 0);
     }
 }
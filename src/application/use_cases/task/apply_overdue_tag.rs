@@ -0,0 +1,241 @@
+use crate::{
+    application::dto::task_dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        services::BusinessCalendar,
+        tag::{
+            aggregate::NewTag,
+            repository::TagRepository,
+            value_objects::{TagDescription, TagName},
+        },
+        task::{
+            aggregate::TaskAggregate,
+            overdue_tag_policy::{OverdueTagChange, OverdueTagPolicy, is_overdue_on_calendar},
+            repository::TaskRepository,
+        },
+    },
+};
+use anyhow::Result;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tracing::{info, instrument};
+
+/// 期限切れのタグを付け外しした結果
+#[derive(Debug, Default)]
+pub struct OverdueTagReport {
+    /// 期限切れになったためタグを付けたタスク
+    pub tagged: Vec<TaskDTO>,
+    /// 期限切れでなくなったためタグを外したタスク
+    pub untagged: Vec<TaskDTO>,
+}
+
+/// ApplyOverdueTagUseCase - 期限切れのタスクへの自動タグ付けのユースケース
+///
+/// `OverdueTagPolicy`に従って、期限切れのタスクに設定されたタグを付け、
+/// 期限日の変更や完了で期限切れでなくなったタスクからタグを外します。
+/// タグの付け外しは自動の処理のため、タスクの更新日時は変更しません。
+pub struct ApplyOverdueTagUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    clock: Arc<dyn Clock>,
+    business_calendar: Option<BusinessCalendar>,
+}
+
+impl ApplyOverdueTagUseCase {
+    /// 新しいApplyOverdueTagUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            clock: Arc::new(SystemClock::default()),
+            business_calendar: None,
+        }
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 期限切れの判定に使う営業日のカレンダーを設定する（Noneの場合は休日を考慮しない）
+    pub fn with_business_calendar(mut self, calendar: Option<BusinessCalendar>) -> Self {
+        self.business_calendar = calendar;
+        self
+    }
+
+    /// すべてのタスクのタグを期限切れかどうかに合わせる
+    ///
+    /// タグが存在しない場合は、タグを付けるタスクがあるときだけ作成します。
+    ///
+    /// # Arguments
+    /// * `tag_name` - 期限切れのタスクに付けるタグの名前
+    #[instrument(name = "apply_overdue_tag", skip(self))]
+    pub async fn execute(&self, tag_name: &str) -> Result<OverdueTagReport> {
+        let tag_name = TagName::new(tag_name.to_string())?;
+        let today = self.clock.today();
+        let mut tasks = self.task_repository.find_all().await?;
+
+        let existing_tag = self.tag_repository.find_by_name(tag_name.value()).await?;
+        let tag_id = match existing_tag {
+            Some(tag) => *tag.id(),
+            None => {
+                // タグを付けるタスクがない場合はタグを作成しない
+                let calendar = self.business_calendar.as_ref();
+                if !tasks
+                    .iter()
+                    .any(|task| is_overdue_on_calendar(task, today, calendar))
+                {
+                    return Ok(OverdueTagReport::default());
+                }
+                let tag = NewTag::new(tag_name, TagDescription::new("")?);
+                *self.tag_repository.save(tag).await?.id()
+            }
+        };
+        let policy =
+            OverdueTagPolicy::new(tag_id).with_business_calendar(self.business_calendar.clone());
+
+        let mut tagged = Vec::new();
+        let mut untagged = Vec::new();
+        for task in tasks.iter_mut() {
+            let Some(change) = policy.apply(task, today) else {
+                continue;
+            };
+            for event in task.domain_events() {
+                info!(
+                    event = event.event_name(),
+                    task_id = task.id().value(),
+                    "Overdue tag policy applied"
+                );
+            }
+            task.clear_events();
+            let updated = self.task_repository.update(task.clone()).await?;
+            match change {
+                OverdueTagChange::Applied => tagged.push(updated),
+                OverdueTagChange::Removed => untagged.push(updated),
+            }
+        }
+
+        Ok(OverdueTagReport {
+            tagged: self.to_dtos(tagged).await?,
+            untagged: self.to_dtos(untagged).await?,
+        })
+    }
+
+    /// タグ情報を一括取得してDTOに変換（N+1問題の回避）
+    async fn to_dtos(&self, tasks: Vec<TaskAggregate>) -> Result<Vec<TaskDTO>> {
+        let tag_ids: Vec<_> = tasks
+            .iter()
+            .flat_map(|task| task.tags().iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            clock::FixedClock,
+            task::{
+                builder::TaskBuilder,
+                value_objects::{DueDate, TaskTitle},
+            },
+        },
+        interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository},
+    };
+    use chrono::NaiveDate;
+
+    fn new_task(title: &str, due: Option<NaiveDate>) -> crate::domain::task::aggregate::NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .due_date(due.map(|due| DueDate::new(due).unwrap()))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_apply_overdue_tag_tags_and_untags() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let jan = |d| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let overdue = task_repo
+            .save(new_task("期限切れ", Some(jan(9))))
+            .await
+            .unwrap();
+        let mut rescheduled = task_repo
+            .save(new_task("延期", Some(jan(20))))
+            .await
+            .unwrap();
+        task_repo.save(new_task("期限なし", None)).await.unwrap();
+        let use_case = ApplyOverdueTagUseCase::new(task_repo.clone(), tag_repo.clone())
+            .with_clock(Arc::new(FixedClock::at(2026, 1, 10, 9, 0)));
+
+        // 延期する前のタスクにタグが付いている状態を作る
+        let tag = tag_repo
+            .save(NewTag::new(
+                TagName::new("期限切れ".to_string()).unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        rescheduled.set_automatic_tag(*tag.id(), true);
+        task_repo.update(rescheduled.clone()).await.unwrap();
+
+        // Act
+        let report = use_case.execute("期限切れ").await.unwrap();
+
+        // Assert
+        assert_eq!(report.tagged.len(), 1);
+        assert_eq!(report.tagged[0].id, overdue.id().value());
+        assert_eq!(report.tagged[0].tags[0].name, "期限切れ");
+        assert_eq!(report.untagged.len(), 1);
+        assert_eq!(report.untagged[0].id, rescheduled.id().value());
+        let stored = task_repo.find_by_id(overdue.id()).await.unwrap().unwrap();
+        assert_eq!(stored.tags(), &vec![*tag.id()]);
+        assert_eq!(stored.updated_at(), overdue.updated_at());
+
+        // 2回目は変更なし
+        let report = use_case.execute("期限切れ").await.unwrap();
+        assert!(report.tagged.is_empty() && report.untagged.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_overdue_tag_creates_tag_only_when_needed() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        task_repo
+            .save(new_task("期限前", NaiveDate::from_ymd_opt(2026, 1, 10)))
+            .await
+            .unwrap();
+        let use_case = ApplyOverdueTagUseCase::new(task_repo.clone(), tag_repo.clone())
+            .with_clock(Arc::new(FixedClock::at(2026, 1, 10, 9, 0)));
+
+        // Act
+        let report = use_case.execute("期限切れ").await.unwrap();
+
+        // Assert
+        assert!(report.tagged.is_empty());
+        assert!(tag_repo.find_all().await.unwrap().is_empty());
+
+        // 期限を過ぎるとタグが作成される
+        let use_case = use_case.with_clock(Arc::new(FixedClock::at(2026, 1, 11, 9, 0)));
+        let report = use_case.execute("期限切れ").await.unwrap();
+        assert_eq!(report.tagged.len(), 1);
+        assert!(tag_repo.find_by_name("期限切れ").await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    application::dto::{CompletionDTO, TaskDTO},
+    domain::{
+        clock::{Clock, SystemClock},
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            status_transition::StatusTransitionPolicy,
+            value_objects::{Status, TaskId, TaskTitle},
+        },
+        unit_of_work::{UnitOfWorkFactory, commit_or_rollback},
+    },
+};
+use anyhow::{Result, bail};
+use std::sync::Arc;
+use tracing::instrument;
+
+/// CompleteTaskUseCase - タスク完了のユースケース
+///
+/// タスクを完了にし、必要に応じて続けて行うフォローアップのタスクを作成します。
+/// 完了とフォローアップの作成は1つのトランザクション（UnitOfWork）内で行います。
+/// 返すTaskDTOのタグ情報は解決しません（結果の表示用）。
+pub struct CompleteTaskUseCase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    transition_policy: StatusTransitionPolicy,
+    clock: Arc<dyn Clock>,
+}
+
+impl CompleteTaskUseCase {
+    /// 新しいCompleteTaskUseCaseを作成
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self {
+            unit_of_work,
+            transition_policy: StatusTransitionPolicy::default(),
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// ステータスの遷移ポリシーを設定する
+    pub fn with_transition_policy(mut self, policy: StatusTransitionPolicy) -> Self {
+        self.transition_policy = policy;
+        self
+    }
+
+    /// ステータスの変更日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// タスクを完了にする
+    ///
+    /// # Arguments
+    /// * `task_id` - 完了するタスクのID
+    /// * `follow_up_title` - 指定した場合、このタイトルでフォローアップのタスクを作成する
+    ///
+    /// # Returns
+    /// * `Ok(CompletionDTO)` - 完了したタスクと、作成したフォローアップのタスク
+    /// * `Err` - タスクが存在しない場合、既に完了している場合、または遷移ポリシーで禁止されている場合
+    #[instrument(name = "complete_task", skip(self))]
+    pub async fn execute(
+        &self,
+        task_id: i32,
+        follow_up_title: Option<String>,
+    ) -> Result<CompletionDTO> {
+        let follow_up_title = follow_up_title.map(TaskTitle::new).transpose()?;
+        let uow = self.unit_of_work.begin().await?;
+        let result = async {
+            let task_repository = uow.task_repository();
+            let mut task = find_task(task_repository.as_ref(), task_id).await?;
+            if *task.status() == Status::Completed {
+                bail!("Task {} is already completed", task_id);
+            }
+            task.change_status(
+                Status::Completed,
+                &self.transition_policy,
+                self.clock.as_ref(),
+            )?;
+            let completed = task_repository.update(task).await?;
+            let follow_up = match follow_up_title {
+                Some(title) => Some(task_repository.save(completed.follow_up(title)).await?),
+                None => None,
+            };
+            Ok(CompletionDTO {
+                completed: TaskDTO::from(completed),
+                follow_up: follow_up.map(TaskDTO::from),
+            })
+        }
+        .await;
+        commit_or_rollback(uow, result).await
+    }
+
+    /// 完了済みのタスクにフォローアップのタスクを作成する
+    ///
+    /// `task edit`などで既に完了にしたタスクに、後からフォローアップを追加する場合に使用します。
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 作成したフォローアップのタスク
+    /// * `Err` - タスクが存在しない場合、またはタイトルが不正な場合
+    #[instrument(name = "add_follow_up", skip(self))]
+    pub async fn add_follow_up(&self, task_id: i32, title: String) -> Result<TaskDTO> {
+        let title = TaskTitle::new(title)?;
+        let uow = self.unit_of_work.begin().await?;
+        let result = async {
+            let task_repository = uow.task_repository();
+            let task = find_task(task_repository.as_ref(), task_id).await?;
+            let follow_up = task_repository.save(task.follow_up(title)).await?;
+            Ok(TaskDTO::from(follow_up))
+        }
+        .await;
+        commit_or_rollback(uow, result).await
+    }
+}
+
+async fn find_task(task_repository: &dyn TaskRepository, task_id: i32) -> Result<TaskAggregate> {
+    task_repository
+        .find_by_id(&TaskId::new(task_id)?)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Task ID {} does not exist", task_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            tag::value_objects::TagId,
+            task::{
+                builder::TaskBuilder,
+                status_transition::TransitionRule,
+                value_objects::{Priority, TaskContext},
+            },
+        },
+        interface::persistence::in_memory::{
+            InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory,
+        },
+    };
+
+    async fn setup() -> (Arc<InMemoryTaskRepository>, CompleteTaskUseCase, i32) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let task = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("設計レビュー").unwrap())
+                    .priority(Priority::High)
+                    .tags(vec![TagId::new(1).unwrap()])
+                    .context(Some(TaskContext::new("@office").unwrap()))
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let use_case = CompleteTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            Arc::new(InMemoryTagRepository::new()),
+        )));
+        (task_repo, use_case, task.id().value())
+    }
+
+    #[tokio::test]
+    async fn test_complete_task_with_follow_up() {
+        // Arrange
+        let (task_repo, use_case, id) = setup().await;
+
+        // Act
+        let result = use_case
+            .execute(id, Some("指摘を反映".to_string()))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.completed.status, Status::Completed);
+        let follow_up = result.follow_up.unwrap();
+        assert_eq!(follow_up.title, "指摘を反映");
+        assert_eq!(follow_up.status, Status::Pending);
+        assert_eq!(follow_up.priority, Priority::High);
+        assert_eq!(follow_up.context.as_deref(), Some("@office"));
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_complete_task_without_follow_up() {
+        // Arrange
+        let (task_repo, use_case, id) = setup().await;
+
+        // Act
+        let result = use_case.execute(id, None).await.unwrap();
+        let again = use_case.execute(id, None).await;
+
+        // Assert
+        assert!(result.follow_up.is_none());
+        assert!(again.is_err());
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_complete_task_errors_leave_task_unchanged() {
+        // Arrange
+        let (task_repo, use_case, id) = setup().await;
+        let forbidden = CompleteTaskUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            Arc::new(InMemoryTagRepository::new()),
+        )))
+        .with_transition_policy(StatusTransitionPolicy::default().with_rule(
+            Status::Pending,
+            Status::Completed,
+            TransitionRule::Forbidden,
+        ));
+
+        // Act
+        let too_long = use_case.execute(id, Some("x".repeat(101))).await;
+        let not_allowed = forbidden.execute(id, Some("次".to_string())).await;
+
+        // Assert
+        assert!(too_long.is_err());
+        assert!(not_allowed.is_err());
+        let tasks = task_repo.find_all().await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].status(), &Status::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_add_follow_up_to_completed_task() {
+        // Arrange
+        let (task_repo, use_case, id) = setup().await;
+        use_case.execute(id, None).await.unwrap();
+
+        // Act
+        let follow_up = use_case
+            .add_follow_up(id, "次の作業".to_string())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(follow_up.title, "次の作業");
+        assert_ne!(follow_up.id, id);
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 2);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::domain::task::{repository::TaskRepository, value_objects::TaskId};
 use anyhow::{Result, bail};
 use std::sync::Arc;
+use tracing::instrument;
 
 /// DeleteTaskUseCase - タスク削除のユースケース
 ///
@@ -23,6 +24,7 @@ impl DeleteTaskUseCase {
     /// # Returns
     /// * `Ok(())` - タスクが削除された場合
     /// * `Err` - エラーが発生した場合（タスクが見つからない場合を含む）
+    #[instrument(name = "delete_task", skip(self))]
     pub async fn execute(&self, id: i32) -> Result<()> {
         let task_id = TaskId::new(id)?;
 
@@ -41,8 +43,8 @@ impl DeleteTaskUseCase {
 mod tests {
     use super::*;
     use crate::domain::task::{
-        aggregate::TaskAggregate,
-        value_objects::{Priority, Status, TaskDescription, TaskTitle},
+        builder::TaskBuilder,
+        value_objects::{Priority, TaskTitle},
     };
     use crate::interface::persistence::in_memory::InMemoryTaskRepository;
 
@@ -51,14 +53,7 @@ mod tests {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("削除するタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("削除するタスク").unwrap()).build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = DeleteTaskUseCase::new(task_repo.clone());
@@ -106,23 +101,13 @@ mod tests {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
 
-        let task1 = TaskAggregate::new(
-            TaskTitle::new("タスク1").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
-
-        let task2 = TaskAggregate::new(
-            TaskTitle::new("タスク2").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
+        let task1 = TaskBuilder::new(TaskTitle::new("タスク1").unwrap())
+            .priority(Priority::Low)
+            .build();
+
+        let task2 = TaskBuilder::new(TaskTitle::new("タスク2").unwrap())
+            .priority(Priority::Low)
+            .build();
 
         let saved1 = task_repo.save(task1).await.unwrap();
         let saved2 = task_repo.save(task2).await.unwrap();
@@ -0,0 +1,135 @@
+use crate::{
+    application::dto::task_dto::TaskDTO,
+    domain::{
+        tag::repository::TagRepository,
+        task::{repository::TaskRepository, value_objects::Status},
+    },
+};
+use anyhow::{Result, anyhow};
+use chrono::{Months, NaiveDate};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tracing::instrument;
+
+/// DueCalendarUseCase - 月ごとの期限日カレンダーのユースケース
+///
+/// 指定した月に期限日がある未完了のタスクを取得します。
+pub struct DueCalendarUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+impl DueCalendarUseCase {
+    /// 新しいDueCalendarUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+        }
+    }
+
+    /// 指定した月に期限日があるタスクを取得する
+    ///
+    /// # Arguments
+    /// * `year` - 年
+    /// * `month` - 月（1〜12）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskDTO>)` - 期限日の昇順に並んだタスクのリスト（完了済みを除く）
+    /// * `Err` - 月が不正な場合など
+    #[instrument(name = "due_calendar", skip(self))]
+    pub async fn execute(&self, year: i32, month: u32) -> Result<Vec<TaskDTO>> {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)
+            .ok_or_else(|| anyhow!("Invalid month: {}-{}", year, month))?;
+        let last = first
+            .checked_add_months(Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .ok_or_else(|| anyhow!("Invalid month: {}-{}", year, month))?;
+
+        let tasks: Vec<_> = self
+            .task_repository
+            .find_by_due_date_range(first, last)
+            .await?
+            .into_iter()
+            .filter(|task| *task.status() != Status::Completed)
+            .collect();
+
+        // タグ情報を一括取得（N+1問題の回避）
+        let tag_ids: Vec<_> = tasks
+            .iter()
+            .flat_map(|task| task.tags().iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::task::{
+            builder::TaskBuilder,
+            value_objects::{DueDate, TaskTitle},
+        },
+        interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository},
+    };
+
+    async fn save_task(repo: &InMemoryTaskRepository, title: &str, status: Status, due: NaiveDate) {
+        repo.save(
+            TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .due_date(Some(DueDate::new(due).unwrap()))
+                .build(),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_due_calendar_returns_open_tasks_in_month() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        save_task(&task_repo, "月末", Status::Pending, date(2, 28)).await;
+        save_task(&task_repo, "月初", Status::InProgress, date(2, 1)).await;
+        save_task(&task_repo, "完了済み", Status::Completed, date(2, 10)).await;
+        save_task(&task_repo, "翌月", Status::Pending, date(3, 1)).await;
+        let use_case = DueCalendarUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let tasks = use_case.execute(2026, 2).await.unwrap();
+
+        // Assert
+        let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["月初", "月末"]);
+    }
+
+    #[tokio::test]
+    async fn test_due_calendar_invalid_month() {
+        // Arrange
+        let use_case = DueCalendarUseCase::new(
+            Arc::new(InMemoryTaskRepository::new()),
+            Arc::new(InMemoryTagRepository::new()),
+        );
+
+        // Act
+        let result = use_case.execute(2026, 13).await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
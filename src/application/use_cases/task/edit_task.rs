@@ -1,24 +1,31 @@
 use crate::{
     application::dto::{TagInfo, TaskDTO, UpdateTaskDTO},
     domain::{
+        clock::{Clock, SystemClock},
         tag::{repository::TagRepository, value_objects::TagId},
         task::{
             repository::TaskRepository,
+            status_transition::StatusTransitionPolicy,
             value_objects::{
-                DueDate, Priority, Status, TaskDescription, TaskId as TaskIdVO, TaskTitle,
+                Assignee, CustomField, DueDate, Estimate, Recurrence, TaskContext, TaskDescription,
+                TaskId as TaskIdVO, TaskTitle,
             },
         },
     },
 };
 use anyhow::{Result, bail};
 use std::{collections::HashMap, sync::Arc};
+use tracing::instrument;
 
 /// EditTaskUseCase - タスク更新のユースケース
 ///
 /// 既存のタスクを部分更新します。
+/// ステータスの変更は遷移ポリシー（既定は`StatusTransitionPolicy::default()`）で検証します。
 pub struct EditTaskUseCase {
     task_repository: Arc<dyn TaskRepository>,
     tag_repository: Arc<dyn TagRepository>,
+    transition_policy: StatusTransitionPolicy,
+    clock: Arc<dyn Clock>,
 }
 
 impl EditTaskUseCase {
@@ -30,9 +37,23 @@ impl EditTaskUseCase {
         Self {
             task_repository,
             tag_repository,
+            transition_policy: StatusTransitionPolicy::default(),
+            clock: Arc::new(SystemClock::default()),
         }
     }
 
+    /// ステータスの遷移ポリシーを設定する
+    pub fn with_transition_policy(mut self, policy: StatusTransitionPolicy) -> Self {
+        self.transition_policy = policy;
+        self
+    }
+
+    /// ステータスの変更日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// タスクを更新する
     ///
     /// # Arguments
@@ -41,7 +62,8 @@ impl EditTaskUseCase {
     ///
     /// # Returns
     /// * `Ok(TaskDTO)` - 更新されたタスク
-    /// * `Err` - エラーが発生した場合
+    /// * `Err` - 許可されないステータス遷移など、エラーが発生した場合
+    #[instrument(name = "edit_task", skip(self, dto))]
     pub async fn execute(&self, id: i32, dto: UpdateTaskDTO) -> Result<TaskDTO> {
         let task_id = TaskIdVO::new(id)?;
 
@@ -55,27 +77,27 @@ impl EditTaskUseCase {
         // タイトルの更新
         if let Some(title_str) = dto.title {
             let title = TaskTitle::new(title_str)?;
-            task.change_title(title)?;
+            task.change_title(title, self.clock.as_ref())?;
         }
 
         // 説明の更新
         if let Some(description_str) = dto.description {
             let description = TaskDescription::new(description_str)?;
-            task.change_description(description)?;
+            task.change_description(description, self.clock.as_ref())?;
         }
 
         // ステータスの更新
-        // Display形式（"InProgress"）とフィルタ形式（"in_progress"）の両方をサポート
-        if let Some(status_str) = dto.status {
-            let status = Status::from_str_anyhow(&status_str)
-                .or_else(|_| Status::from_filter_value(&status_str))?;
-            task.change_status(status)?;
+        if let Some(status) = dto.status {
+            if dto.reopen {
+                task.reopen(status, &self.transition_policy, self.clock.as_ref())?;
+            } else {
+                task.change_status(status, &self.transition_policy, self.clock.as_ref())?;
+            }
         }
 
         // 優先度の更新
-        if let Some(priority_str) = dto.priority {
-            let priority = Priority::from_str_anyhow(&priority_str)?;
-            task.change_priority(priority)?;
+        if let Some(priority) = dto.priority {
+            task.change_priority(priority, self.clock.as_ref())?;
         }
 
         // タグの更新
@@ -107,13 +129,49 @@ impl EditTaskUseCase {
             let tag_id_vos = tag_id_vos?;
 
             // 既存のタグをすべて削除して新しいタグを追加
-            task.replace_tags(tag_id_vos)?;
+            task.replace_tags(tag_id_vos, self.clock.as_ref())?;
         }
 
         // 期限日の更新
         if let Some(due_date) = dto.due_date {
             let due_date_vo = Some(DueDate::new(due_date)?);
-            task.change_due_date(due_date_vo)?;
+            task.change_due_date(due_date_vo, self.clock.as_ref())?;
+        }
+
+        // カスタムフィールドの更新（削除してから設定）
+        for key in &dto.remove_custom_fields {
+            task.remove_custom_field(key, self.clock.as_ref())?;
+        }
+        for (key, value) in &dto.custom_fields {
+            task.set_custom_field(CustomField::new(key, value.as_str())?, self.clock.as_ref())?;
+        }
+
+        // 繰り返しルールの更新
+        if dto.clear_recurrence {
+            task.change_recurrence(None, self.clock.as_ref())?;
+        } else if let Some(recurrence) = dto.recurrence {
+            task.change_recurrence(Some(recurrence.parse::<Recurrence>()?), self.clock.as_ref())?;
+        }
+
+        // 見積もり時間の更新
+        if dto.clear_estimate {
+            task.change_estimate(None, self.clock.as_ref())?;
+        } else if let Some(estimate) = dto.estimate {
+            task.change_estimate(Some(estimate.parse::<Estimate>()?), self.clock.as_ref())?;
+        }
+
+        // コンテキストの更新
+        if dto.clear_context {
+            task.change_context(None, self.clock.as_ref())?;
+        } else if let Some(context) = dto.context {
+            task.change_context(Some(TaskContext::new(context)?), self.clock.as_ref())?;
+        }
+
+        // 担当者の更新
+        if dto.clear_assignee {
+            task.change_assignee(None, self.clock.as_ref())?;
+        } else if let Some(assignee) = dto.assignee {
+            task.change_assignee(Some(Assignee::new(assignee)?), self.clock.as_ref())?;
         }
 
         // リポジトリに保存
@@ -154,7 +212,7 @@ mod tests {
     use super::*;
     use crate::{
         domain::task::{
-            aggregate::TaskAggregate,
+            builder::TaskBuilder,
             value_objects::{Priority, Status, TaskDescription, TaskTitle},
         },
         interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository},
@@ -166,14 +224,9 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("元のタイトル").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("元のタイトル").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
@@ -193,6 +246,73 @@ mod tests {
         assert_eq!(updated_task.description, Some("説明".to_string())); // 変更なし
     }
 
+    async fn save_completed_task(task_repo: &InMemoryTaskRepository) -> i32 {
+        let task = TaskBuilder::new(TaskTitle::new("完了済み").unwrap())
+            .status(Status::Completed)
+            .build();
+        task_repo.save(task).await.unwrap().id().value()
+    }
+
+    #[tokio::test]
+    async fn test_edit_task_reopen_requires_flag() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let id = save_completed_task(&task_repo).await;
+        let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
+
+        // Act
+        let rejected = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    status: Some(Status::Pending),
+                    ..Default::default()
+                },
+            )
+            .await;
+        let reopened = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    status: Some(Status::Pending),
+                    reopen: true,
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        // Assert
+        assert!(rejected.unwrap_err().to_string().contains("--reopen"));
+        let reopened = reopened.unwrap();
+        assert_eq!(reopened.status, Status::Pending);
+        assert!(reopened.completed_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_edit_task_with_permissive_policy() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let id = save_completed_task(&task_repo).await;
+        let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo)
+            .with_transition_policy(StatusTransitionPolicy::permissive());
+
+        // Act
+        let result = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    status: Some(Status::InProgress),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap().status, Status::InProgress);
+    }
+
     #[tokio::test]
     async fn test_edit_task_not_found() {
         // Arrange
@@ -238,14 +358,9 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
@@ -264,25 +379,45 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_edit_task_with_invalid_title() {
+    async fn test_edit_task_custom_fields() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let mut task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
+        task.set_custom_field(CustomField::new("estimate", "3h").unwrap());
+        let saved_task = task_repo.save(task).await.unwrap();
+        let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
+
+        let dto = UpdateTaskDTO {
+            custom_fields: [("client".to_string(), "ACME".to_string())].into(),
+            remove_custom_fields: vec!["Estimate".to_string()],
+            ..Default::default()
+        };
+
+        // Act
+        let updated_task = use_case
+            .execute(saved_task.id().value(), dto)
+            .await
+            .unwrap();
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
+        // Assert
+        assert_eq!(
+            updated_task.custom_fields,
+            [("client".to_string(), "ACME".to_string())].into()
         );
-        let saved_task = task_repo.save(task).await.unwrap();
+    }
 
+    #[tokio::test]
+    async fn test_edit_task_unset_missing_custom_field() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
+        let saved_task = task_repo.save(task).await.unwrap();
         let use_case = EditTaskUseCase::new(task_repo, tag_repo);
 
         let dto = UpdateTaskDTO {
-            title: Some("".to_string()), // 空のタイトル
+            remove_custom_fields: vec!["client".to_string()],
             ..Default::default()
         };
 
@@ -290,29 +425,25 @@ mod tests {
         let result = use_case.execute(saved_task.id().value(), dto).await;
 
         // Assert
-        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Custom field 'client' does not exist"
+        );
     }
 
     #[tokio::test]
-    async fn test_edit_task_description() {
+    async fn test_edit_task_with_invalid_title() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("元の説明").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
         let saved_task = task_repo.save(task).await.unwrap();
 
-        let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
+        let use_case = EditTaskUseCase::new(task_repo, tag_repo);
 
         let dto = UpdateTaskDTO {
-            description: Some("更新後の説明".to_string()),
+            title: Some("".to_string()), // 空のタイトル
             ..Default::default()
         };
 
@@ -320,32 +451,24 @@ mod tests {
         let result = use_case.execute(saved_task.id().value(), dto).await;
 
         // Assert
-        assert!(result.is_ok());
-        let updated_task = result.unwrap();
-        assert_eq!(updated_task.description, Some("更新後の説明".to_string()));
-        assert_eq!(updated_task.title, "タイトル"); // 変更なし
+        assert!(result.is_err());
     }
 
     #[tokio::test]
-    async fn test_edit_task_status() {
+    async fn test_edit_task_description() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap())
+            .description(TaskDescription::new("元の説明").unwrap())
+            .build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
 
         let dto = UpdateTaskDTO {
-            status: Some("in_progress".to_string()),
+            description: Some("更新後の説明".to_string()),
             ..Default::default()
         };
 
@@ -355,29 +478,23 @@ mod tests {
         // Assert
         assert!(result.is_ok());
         let updated_task = result.unwrap();
-        assert_eq!(updated_task.status, "in_progress");
+        assert_eq!(updated_task.description, Some("更新後の説明".to_string()));
+        assert_eq!(updated_task.title, "タイトル"); // 変更なし
     }
 
     #[tokio::test]
-    async fn test_edit_task_priority() {
+    async fn test_edit_task_status() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
 
         let dto = UpdateTaskDTO {
-            priority: Some("high".to_string()),
+            status: Some(Status::InProgress),
             ..Default::default()
         };
 
@@ -387,29 +504,22 @@ mod tests {
         // Assert
         assert!(result.is_ok());
         let updated_task = result.unwrap();
-        assert_eq!(updated_task.priority, "high");
+        assert_eq!(updated_task.status, Status::InProgress);
     }
 
     #[tokio::test]
-    async fn test_edit_task_priority_invalid() {
+    async fn test_edit_task_priority() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
         let saved_task = task_repo.save(task).await.unwrap();
 
-        let use_case = EditTaskUseCase::new(task_repo, tag_repo);
+        let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
 
         let dto = UpdateTaskDTO {
-            priority: Some("invalid".to_string()),
+            priority: Some(Priority::High),
             ..Default::default()
         };
 
@@ -417,8 +527,9 @@ mod tests {
         let result = use_case.execute(saved_task.id().value(), dto).await;
 
         // Assert
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Invalid priority"));
+        assert!(result.is_ok());
+        let updated_task = result.unwrap();
+        assert_eq!(updated_task.priority, Priority::High);
     }
 
     #[tokio::test]
@@ -429,14 +540,14 @@ mod tests {
 
         // タグを作成
         use crate::domain::tag::{
-            aggregate::TagAggregate,
+            aggregate::NewTag,
             value_objects::{TagDescription, TagName},
         };
-        let tag1 = TagAggregate::new(
+        let tag1 = NewTag::new(
             TagName::new("タグ1").unwrap(),
             TagDescription::new("").unwrap(),
         );
-        let tag2 = TagAggregate::new(
+        let tag2 = NewTag::new(
             TagName::new("タグ2").unwrap(),
             TagDescription::new("").unwrap(),
         );
@@ -444,14 +555,7 @@ mod tests {
         let saved_tag2 = tag_repo.save(tag2).await.unwrap();
 
         // タスクを作成
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
@@ -488,14 +592,7 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo, tag_repo);
@@ -524,14 +621,7 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
@@ -553,20 +643,100 @@ mod tests {
         assert_eq!(updated_task.due_date, Some(new_due_date));
     }
 
+    #[tokio::test]
+    async fn test_edit_task_recurrence() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
+        let id = task_repo.save(task).await.unwrap().id().value();
+        let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
+
+        // Act & Assert: 設定すると正規化した形式になる
+        let updated = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    recurrence: Some("every 1 week".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.recurrence.as_deref(), Some("weekly"));
+
+        // Act & Assert: 解除
+        let updated = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    clear_recurrence: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.recurrence, None);
+
+        // Act & Assert: 不正なルール
+        let result = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    recurrence: Some("hourly".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_edit_task_estimate() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let task = TaskBuilder::new(TaskTitle::new("タイトル").unwrap()).build();
+        let id = task_repo.save(task).await.unwrap().id().value();
+        let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
+
+        // Act & Assert: 設定
+        let updated = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    estimate: Some("2h30m".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.estimate_minutes, Some(150));
+
+        // Act & Assert: 解除
+        let updated = use_case
+            .execute(
+                id,
+                UpdateTaskDTO {
+                    clear_estimate: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.estimate_minutes, None);
+    }
+
     #[tokio::test]
     async fn test_edit_task_multiple_fields() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("元のタイトル").unwrap(),
-            TaskDescription::new("元の説明").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("元のタイトル").unwrap())
+            .description(TaskDescription::new("元の説明").unwrap())
+            .priority(Priority::Low)
+            .build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = EditTaskUseCase::new(task_repo.clone(), tag_repo);
@@ -577,10 +747,12 @@ mod tests {
         let dto = UpdateTaskDTO {
             title: Some("新しいタイトル".to_string()),
             description: Some("新しい説明".to_string()),
-            status: Some("in_progress".to_string()),
-            priority: Some("critical".to_string()),
+            status: Some(Status::InProgress),
+            priority: Some(Priority::Critical),
             due_date: Some(new_due_date),
             tags: None,
+            reopen: false,
+            ..Default::default()
         };
 
         // Act
@@ -591,8 +763,8 @@ mod tests {
         let updated_task = result.unwrap();
         assert_eq!(updated_task.title, "新しいタイトル");
         assert_eq!(updated_task.description, Some("新しい説明".to_string()));
-        assert_eq!(updated_task.status, "in_progress");
-        assert_eq!(updated_task.priority, "critical");
+        assert_eq!(updated_task.status, Status::InProgress);
+        assert_eq!(updated_task.priority, Priority::Critical);
         assert_eq!(updated_task.due_date, Some(new_due_date));
     }
 }
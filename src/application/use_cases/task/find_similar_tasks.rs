@@ -0,0 +1,116 @@
+use crate::{
+    application::dto::task_dto::TaskDTO,
+    domain::{
+        services::DuplicateTitleDetector, tag::repository::TagRepository,
+        task::repository::TaskRepository,
+    },
+};
+use anyhow::Result;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tracing::instrument;
+
+/// FindSimilarTasksUseCase - タイトルが似ているタスクを探すユースケース
+///
+/// タスクを追加する前に、重複していそうな未完了のタスクを確認するために使用します。
+pub struct FindSimilarTasksUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    detector: DuplicateTitleDetector,
+}
+
+impl FindSimilarTasksUseCase {
+    /// 新しいFindSimilarTasksUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            detector: DuplicateTitleDetector::default(),
+        }
+    }
+
+    /// 類似度のしきい値（0.0〜1.0）を設定する
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.detector = DuplicateTitleDetector::new(threshold);
+        self
+    }
+
+    /// タイトルが似ている未完了のタスクを取得する
+    ///
+    /// # Arguments
+    /// * `title` - 追加しようとしているタスクのタイトル
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskDTO>)` - 類似度の高い順に並んだタスクのリスト
+    /// * `Err` - エラーが発生した場合
+    #[instrument(name = "find_similar_tasks", skip(self))]
+    pub async fn execute(&self, title: &str) -> Result<Vec<TaskDTO>> {
+        let tasks = self.task_repository.find_all().await?;
+        let similar: Vec<_> = self
+            .detector
+            .find_similar(title, &tasks)
+            .into_iter()
+            .map(|candidate| candidate.task.clone())
+            .collect();
+
+        // タグ情報を一括取得（N+1問題の回避）
+        let tag_ids: Vec<_> = similar
+            .iter()
+            .flat_map(|task| task.tags().iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+
+        Ok(similar
+            .into_iter()
+            .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::task::{
+            builder::TaskBuilder,
+            value_objects::{Status, TaskTitle},
+        },
+        interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository},
+    };
+
+    async fn save_task(repo: &InMemoryTaskRepository, title: &str, status: Status) {
+        repo.save(
+            TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .build(),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_find_similar_tasks() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        save_task(&task_repo, "週次レポートを書く", Status::Pending).await;
+        save_task(&task_repo, "週次レポートを書く", Status::Completed).await;
+        save_task(&task_repo, "牛乳を買う", Status::Pending).await;
+        let use_case = FindSimilarTasksUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let similar = use_case.execute("週次レポートを書く！").await.unwrap();
+
+        // Assert
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].id, 1);
+    }
+}
@@ -0,0 +1,414 @@
+use crate::{
+    application::{dto::TaskDTO, use_cases::task::sync_issues::truncate_title},
+    domain::{
+        tag::{
+            aggregate::NewTag,
+            repository::TagRepository,
+            value_objects::{TagDescription, TagId, TagName},
+        },
+        task::{
+            builder::TaskBuilder,
+            repository::TaskRepository,
+            value_objects::{DueDate, ExternalRef, Priority, Status, TaskDescription, TaskTitle},
+        },
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory, commit_or_rollback},
+    },
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::sync::Arc;
+use tracing::instrument;
+
+/// 他のタスク管理ツールから読み込んだタスク
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTask {
+    /// 読み込み元での識別子（二重に取り込まないための対応付けに使う）
+    pub source_id: String,
+    pub title: String,
+    pub description: String,
+    pub status: Status,
+    pub priority: Priority,
+    /// タグ名（プロジェクト・ラベルなど）
+    pub tags: Vec<String>,
+    pub due_date: Option<NaiveDate>,
+    /// 読み込み元での作成日時（不明な場合は取り込んだ時刻）
+    pub created_at: Option<DateTime<Utc>>,
+    /// 読み込み元での完了日時（完了済みのタスクのみ、不明な場合は取り込んだ時刻）
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// 取り込みの計画（ドライランの差分）
+#[derive(Debug, Default)]
+pub struct ImportPlan {
+    /// 作成するタスク
+    pub create: Vec<ImportedTask>,
+    /// 取り込み済みのため作成しないタスク
+    pub skip: Vec<ImportedTask>,
+    /// 作成するタグ
+    pub new_tags: Vec<String>,
+}
+
+/// 取り込みの結果
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// 作成したタスク
+    pub created: Vec<TaskDTO>,
+    /// 取り込み済みのため作成しなかったタスクの件数
+    pub skipped: usize,
+    /// 作成したタグ
+    pub new_tags: Vec<String>,
+}
+
+/// ImportTasksUseCase - 他のタスク管理ツールからのタスクの取り込みのユースケース
+///
+/// 読み込んだタスクを1つのトランザクション（UnitOfWork）内で作成し、存在しないタグは作成します。
+/// 取り込んだタスクには読み込み元の識別子を外部の項目との対応として記録し、
+/// 同じファイルを再度取り込んでも重複して作成しません。
+/// 返すTaskDTOのタグ情報は解決しません。
+pub struct ImportTasksUseCase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl ImportTasksUseCase {
+    /// 新しいImportTasksUseCaseを作成
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { unit_of_work }
+    }
+
+    /// 取り込んだ場合に作成されるタスクとタグを求める（変更は行わない）
+    ///
+    /// # Arguments
+    /// * `source` - 読み込み元の種類（`todoist`など）
+    /// * `tasks` - 読み込んだタスク
+    ///
+    /// # Returns
+    /// * `Err` - タイトルなどが不正なタスクが含まれる場合
+    #[instrument(name = "import_tasks.plan", skip(self, tasks), fields(count = tasks.len()))]
+    pub async fn plan(&self, source: &str, tasks: Vec<ImportedTask>) -> Result<ImportPlan> {
+        let uow = self.unit_of_work.begin().await?;
+        let result = ImportTransaction::new(uow.as_ref())
+            .plan(source, tasks)
+            .await;
+        uow.rollback().await?;
+        result
+    }
+
+    /// タスクを取り込む
+    ///
+    /// 途中で失敗した場合は、作成したタスクとタグをすべて取り消します。
+    #[instrument(name = "import_tasks", skip(self, tasks), fields(count = tasks.len()))]
+    pub async fn execute(&self, source: &str, tasks: Vec<ImportedTask>) -> Result<ImportReport> {
+        let uow = self.unit_of_work.begin().await?;
+        let result = {
+            let transaction = ImportTransaction::new(uow.as_ref());
+            match transaction.plan(source, tasks).await {
+                Ok(plan) => transaction.import(source, plan).await,
+                Err(e) => Err(e),
+            }
+        };
+        commit_or_rollback(uow, result).await
+    }
+}
+
+/// 1つのトランザクション内で取り込みの処理を行う
+struct ImportTransaction {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+impl ImportTransaction {
+    fn new(uow: &dyn UnitOfWork) -> Self {
+        Self {
+            task_repository: uow.task_repository(),
+            tag_repository: uow.tag_repository(),
+        }
+    }
+
+    /// 取り込み済みのタスクと存在しないタグを判定し、タスクを検証する
+    async fn plan(&self, source: &str, tasks: Vec<ImportedTask>) -> Result<ImportPlan> {
+        let existing_tasks = self.task_repository.find_all().await?;
+        let existing_tags = self.tag_repository.find_all().await?;
+        let mut plan = ImportPlan::default();
+
+        for mut task in tasks {
+            let imported = existing_tasks
+                .iter()
+                .any(|existing| existing.external_ref(source, &task.source_id).is_some())
+                || plan.create.iter().any(|t| t.source_id == task.source_id);
+            if imported {
+                plan.skip.push(task);
+                continue;
+            }
+
+            task.title = truncate_title(&task.title);
+            TaskTitle::new(task.title.as_str())
+                .and_then(|_| TaskDescription::new(task.description.as_str()))
+                .and_then(|_| task.due_date.map(DueDate::new).transpose())
+                .with_context(|| format!("Invalid task {} ({})", task.source_id, task.title))?;
+
+            task.tags.retain(|name| !name.trim().is_empty());
+            for name in &task.tags {
+                TagName::new(name.trim())
+                    .with_context(|| format!("Invalid tag in task {}", task.source_id))?;
+                let known = existing_tags.iter().any(|tag| tag.name().matches(name))
+                    || plan
+                        .new_tags
+                        .iter()
+                        .any(|new| new.to_lowercase() == name.trim().to_lowercase());
+                if !known {
+                    plan.new_tags.push(name.trim().to_string());
+                }
+            }
+            plan.create.push(task);
+        }
+
+        Ok(plan)
+    }
+
+    /// 計画に従ってタグとタスクを作成する
+    async fn import(&self, source: &str, plan: ImportPlan) -> Result<ImportReport> {
+        let mut tags = self.tag_repository.find_all().await?;
+        for name in &plan.new_tags {
+            let tag = NewTag::new(TagName::new(name.as_str())?, TagDescription::new("")?);
+            tags.push(self.tag_repository.save(tag).await?);
+        }
+
+        let mut report = ImportReport {
+            skipped: plan.skip.len(),
+            new_tags: plan.new_tags,
+            ..Default::default()
+        };
+        let imported_at = Utc::now();
+        let mut new_tasks = Vec::with_capacity(plan.create.len());
+        for task in plan.create {
+            let mut tag_ids: Vec<TagId> = Vec::new();
+            for name in &task.tags {
+                if let Some(tag) = tags.iter().find(|tag| tag.name().matches(name))
+                    && !tag_ids.contains(tag.id())
+                {
+                    tag_ids.push(*tag.id());
+                }
+            }
+
+            let mut new_task = TaskBuilder::new(TaskTitle::new(task.title)?)
+                .description(TaskDescription::new(task.description)?)
+                .status(task.status)
+                .priority(task.priority)
+                .tags(tag_ids)
+                .due_date(task.due_date.map(DueDate::new).transpose()?)
+                .created_at(task.created_at)
+                .completed_at(task.completed_at)
+                .build();
+            new_task.add_external_ref(ExternalRef::new(
+                source,
+                task.source_id,
+                None,
+                imported_at,
+                task.status,
+            )?)?;
+            new_tasks.push(new_task);
+        }
+        // タスクはまとめて保存し、往復回数を削減する
+        let saved = self
+            .task_repository
+            .save_all(new_tasks)
+            .await
+            .context("Failed to import tasks; nothing was imported")?;
+        report.created = saved.into_iter().map(TaskDTO::from).collect();
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::import::taskwarrior;
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::interface::persistence::{
+        failing::{FailingUnitOfWorkFactory, FaultInjector},
+        in_memory::{InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory},
+        sea_orm::{SeaOrmTagRepository, SeaOrmTaskRepository, SeaOrmUnitOfWorkFactory},
+    };
+    use sea_orm::{EntityTrait, PaginatorTrait};
+
+    fn imported(source_id: &str, title: &str, tags: &[&str]) -> ImportedTask {
+        ImportedTask {
+            source_id: source_id.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            status: Status::Pending,
+            priority: Priority::High,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            due_date: NaiveDate::from_ymd_opt(2026, 11, 1),
+            created_at: None,
+            completed_at: None,
+        }
+    }
+
+    fn setup() -> (
+        Arc<InMemoryTaskRepository>,
+        Arc<InMemoryTagRepository>,
+        ImportTasksUseCase,
+    ) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = ImportTasksUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo.clone(),
+        )));
+        (task_repo, tag_repo, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_plan_does_not_change_anything() {
+        // Arrange
+        let (task_repo, tag_repo, use_case) = setup();
+        tag_repo
+            .save(NewTag::new(
+                TagName::new("Work").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        // Act
+        let plan = use_case
+            .plan(
+                "todoist",
+                vec![
+                    imported("1", "Write report", &["work", "Urgent"]),
+                    imported("2", "Call mom", &["urgent"]),
+                ],
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(plan.create.len(), 2);
+        assert_eq!(plan.new_tags, vec!["Urgent"]);
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_imports_once() {
+        // Arrange
+        let (task_repo, tag_repo, use_case) = setup();
+        let tasks = vec![
+            imported("1", "Write report", &["work"]),
+            imported("2", "Call mom", &[]),
+        ];
+
+        // Act
+        let report = use_case.execute("todoist", tasks.clone()).await.unwrap();
+        let again = use_case.execute("todoist", tasks.clone()).await.unwrap();
+        let other_source = use_case.plan("taskwarrior", tasks).await.unwrap();
+
+        // Assert
+        assert_eq!(report.created.len(), 2);
+        assert_eq!(report.created[0].priority, Priority::High);
+        assert_eq!(report.new_tags, vec!["work"]);
+        assert!(again.created.is_empty());
+        assert_eq!(again.skipped, 2);
+        assert_eq!(other_source.create.len(), 2);
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 2);
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_invalid_task() {
+        // Arrange
+        let (task_repo, _tag_repo, use_case) = setup();
+        let tasks = vec![imported("1", "Valid", &[]), imported("2", "  ", &[])];
+
+        // Act
+        let result = use_case.execute("todoist", tasks).await;
+
+        // Assert
+        assert!(result.unwrap_err().to_string().contains("Invalid task 2"));
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_save_failure_leaves_no_rows() {
+        // Arrange: タスクをまとめて保存した後に失敗する
+        let (_dir, db) = setup_db().await;
+        let faults = Arc::new(FaultInjector::fail_on("task.save_all").after_call());
+        let use_case = ImportTasksUseCase::new(Arc::new(FailingUnitOfWorkFactory::new(
+            Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone())),
+            faults,
+        )));
+        let tasks = vec![
+            imported("1", "Write report", &["work"]),
+            imported("2", "Review", &["work", "review"]),
+        ];
+
+        // Act
+        let result = use_case.execute("todoist", tasks).await;
+
+        // Assert: タスク・タグ・関連付けは残らない
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains("Failed to import tasks; nothing was imported"));
+        assert!(message.contains("Injected failure in task.save_all"));
+        assert!(
+            SeaOrmTaskRepository::new(db.clone())
+                .find_all()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            SeaOrmTagRepository::new(db.clone())
+                .find_all()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let task_tags = entity::task_tags::Entity::find().count(&db).await.unwrap();
+        assert_eq!(task_tags, 0);
+    }
+
+    #[tokio::test]
+    async fn test_execute_keeps_taskwarrior_timestamps() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let use_case = ImportTasksUseCase::new(Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone())));
+        let export = r#"[
+            {"uuid": "a-1", "description": "Write report", "status": "completed",
+             "entry": "20260105T090000Z", "end": "20260106T183000Z"},
+            {"uuid": "a-2", "description": "Call mom", "status": "pending",
+             "entry": "20260107T120000Z"}
+        ]"#;
+        let tasks = taskwarrior::parse(export).unwrap();
+
+        // Act
+        use_case
+            .execute(taskwarrior::TASKWARRIOR_SOURCE, tasks)
+            .await
+            .unwrap();
+
+        // Assert
+        let saved = SeaOrmTaskRepository::new(db).find_all().await.unwrap();
+        let report = saved
+            .iter()
+            .find(|t| t.title().value() == "Write report")
+            .unwrap();
+        let call = saved
+            .iter()
+            .find(|t| t.title().value() == "Call mom")
+            .unwrap();
+        assert_eq!(
+            report.created_at(),
+            &"2026-01-05T09:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            report.completed_at(),
+            &Some("2026-01-06T18:30:00Z".parse::<DateTime<Utc>>().unwrap())
+        );
+        assert_eq!(
+            call.created_at(),
+            &"2026-01-07T12:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(call.completed_at(), &None);
+    }
+}
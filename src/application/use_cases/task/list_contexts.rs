@@ -0,0 +1,93 @@
+use crate::{
+    application::dto::ContextUsageDTO,
+    domain::task::{
+        repository::TaskRepository,
+        specification::{AndSpecification, NotSpecification, TaskByStatus, TaskHasContext},
+        value_objects::Status,
+    },
+};
+use anyhow::Result;
+use std::{collections::BTreeMap, sync::Arc};
+use tracing::instrument;
+
+/// ListContextsUseCase - 使用中のコンテキスト一覧のユースケース
+///
+/// 未完了のタスクに設定されているコンテキストを、名前順にタスク数とともに取得します。
+pub struct ListContextsUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+}
+
+impl ListContextsUseCase {
+    /// 新しいListContextsUseCaseを作成
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self { task_repository }
+    }
+
+    /// 使用中のコンテキストを取得する
+    #[instrument(name = "list_contexts", skip_all)]
+    pub async fn execute(&self) -> Result<Vec<ContextUsageDTO>> {
+        let tasks = self
+            .task_repository
+            .find_by_specification(Box::new(AndSpecification::new(
+                Box::new(NotSpecification::new(Box::new(TaskByStatus::new(
+                    Status::Completed,
+                )))),
+                Box::new(TaskHasContext),
+            )))
+            .await?;
+
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for context in tasks.iter().filter_map(|task| task.context()) {
+            *counts.entry(context.to_string()).or_default() += 1;
+        }
+
+        Ok(counts
+            .into_iter()
+            .map(|(context, open_count)| ContextUsageDTO {
+                context,
+                open_count,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::task::{
+            builder::TaskBuilder,
+            value_objects::{TaskContext, TaskTitle},
+        },
+        interface::persistence::in_memory::InMemoryTaskRepository,
+    };
+
+    #[tokio::test]
+    async fn test_list_contexts_counts_open_tasks() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        for (title, status, context) in [
+            ("掃除", Status::Pending, Some("@home")),
+            ("洗濯", Status::InProgress, Some("@home")),
+            ("会議", Status::Pending, Some("@office")),
+            ("完了済み", Status::Completed, Some("@errands")),
+            ("どこでも", Status::Pending, None),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .context(context.map(|c| TaskContext::new(c).unwrap()))
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        // Act
+        let contexts = ListContextsUseCase::new(task_repo).execute().await.unwrap();
+
+        // Assert
+        let pairs: Vec<_> = contexts
+            .iter()
+            .map(|usage| (usage.context.as_str(), usage.open_count))
+            .collect();
+        assert_eq!(pairs, vec![("@home", 2), ("@office", 1)]);
+    }
+}
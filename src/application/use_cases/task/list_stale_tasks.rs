@@ -0,0 +1,154 @@
+use crate::{
+    application::dto::task_dto::TaskDTO,
+    domain::{
+        tag::repository::TagRepository,
+        task::{
+            repository::TaskRepository,
+            specification::{AndSpecification, NotSpecification, TaskByStatus, TaskSnoozed},
+            stale_policy::StalePolicy,
+            value_objects::Status,
+        },
+    },
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tracing::instrument;
+
+/// ListStaleTasksUseCase - 放置されているタスクの一覧のユースケース
+///
+/// ステータスごとのしきい値以上の日数、更新されていない未完了のタスクを取得します。
+pub struct ListStaleTasksUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    policy: StalePolicy,
+}
+
+impl ListStaleTasksUseCase {
+    /// 新しいListStaleTasksUseCaseを作成（しきい値は`StalePolicy::default`）
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            policy: StalePolicy::default(),
+        }
+    }
+
+    /// 放置を判定するポリシーを指定する
+    pub fn with_policy(mut self, policy: StalePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// 放置されているタスクを、更新が古い順に取得する
+    ///
+    /// スヌーズ中のタスクは意図して後回しにしているため含めません。
+    ///
+    /// # Arguments
+    /// * `now` - 経過日数の基準日時
+    #[instrument(name = "list_stale_tasks", skip(self))]
+    pub async fn execute(&self, now: DateTime<Utc>) -> Result<Vec<TaskDTO>> {
+        let today = now.naive_utc().date();
+        let mut tasks: Vec<_> = self
+            .task_repository
+            .find_by_specification(Box::new(AndSpecification::new(
+                Box::new(NotSpecification::new(Box::new(TaskByStatus::new(
+                    Status::Completed,
+                )))),
+                Box::new(NotSpecification::new(Box::new(TaskSnoozed::new(today)))),
+            )))
+            .await?
+            .into_iter()
+            .filter(|task| self.policy.is_stale(task, now))
+            .collect();
+        tasks.sort_by_key(|task| (*task.updated_at(), task.id().value()));
+
+        // タグ情報を一括取得してDTOに変換（N+1問題の回避）
+        let tag_ids: Vec<_> = tasks
+            .iter()
+            .flat_map(|task| task.tags().iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::SystemClock;
+    use crate::{
+        domain::task::{builder::TaskBuilder, value_objects::TaskTitle},
+        interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository},
+    };
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_list_stale_tasks_by_status_threshold() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        for (title, status) in [
+            ("保留中", Status::Pending),
+            ("進行中", Status::InProgress),
+            ("完了済み", Status::Completed),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+        let mut snoozed = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("スヌーズ中").unwrap())
+                    .status(Status::InProgress)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let now = Utc::now() + Duration::days(10);
+        snoozed
+            .snooze(
+                now.date_naive() + Duration::days(1),
+                &SystemClock::default(),
+            )
+            .unwrap();
+        task_repo.update(snoozed).await.unwrap();
+        let use_case =
+            ListStaleTasksUseCase::new(task_repo, Arc::new(InMemoryTagRepository::new()))
+                .with_policy(
+                    StalePolicy::disabled()
+                        .with_threshold(Status::Pending, 30)
+                        .with_threshold(Status::InProgress, 7),
+                );
+
+        // Act
+        let after_10_days = use_case.execute(now).await.unwrap();
+        let after_40_days = use_case
+            .execute(Utc::now() + Duration::days(40))
+            .await
+            .unwrap();
+
+        // Assert
+        let titles = |tasks: &[TaskDTO]| -> Vec<String> {
+            tasks.iter().map(|task| task.title.clone()).collect()
+        };
+        assert_eq!(titles(&after_10_days), vec!["進行中"]);
+        assert_eq!(
+            titles(&after_40_days),
+            vec!["保留中", "進行中", "スヌーズ中"]
+        );
+    }
+}
@@ -1,12 +1,120 @@
 use crate::{
-    application::dto::task_dto::TaskDTO,
-    domain::{tag::repository::TagRepository, task::repository::TaskRepository},
+    application::dto::{TaskGroupDTO, task_dto::TaskDTO},
+    domain::{
+        clock::{Clock, SystemClock},
+        services::{UrgencyCalculator, UrgencyWeights},
+        tag::{aggregate::TagAggregate, hierarchy::TagHierarchy, repository::TagRepository},
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            specification::{
+                AndSpecification, NotSpecification, OrSpecification, TaskByAssignee, TaskByStatus,
+                TaskByTag, TaskCreatedAfter, TaskDueAfter, TaskDueBefore, TaskHasContext,
+                TaskInContext, TaskSnoozed, TaskSpecification, TaskUpdatedSince,
+            },
+            value_objects::{
+                Assignee, DueDateBuckets, DueDateStatus, Priority, Status, TaskContext,
+            },
+        },
+    },
 };
 use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
 use std::{
-    collections::{HashMap, HashSet},
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, HashMap, HashSet},
     sync::Arc,
 };
+use strum::IntoEnumIterator;
+use tracing::instrument;
+
+/// タスク一覧のグループ化の基準
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskGroupBy {
+    /// ステータス別（Pending → In Progress → Completed）
+    Status,
+    /// 優先度別（Critical → Low）
+    Priority,
+    /// タグ別（複数タグを持つタスクは各グループに含まれる）
+    Tag,
+    /// 期限の状況別（Overdue → No Due Date）
+    Due,
+}
+
+/// タスク一覧の並び順
+///
+/// いずれの基準でも、同じ値のタスクはID順に並びます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskSortKey {
+    /// ID順
+    #[default]
+    Id,
+    /// タイトル順
+    Title,
+    /// 優先度の高い順
+    Priority,
+    /// 期限日の近い順（期限なしは最後）
+    Due,
+    /// 作成日時の新しい順
+    Created,
+    /// 更新日時の新しい順
+    Updated,
+    /// 緊急度スコアの高い順（優先度・期限日の近さ・経過日数などから計算）
+    Urgency,
+}
+
+impl TaskSortKey {
+    /// 2つのタスクを比較
+    ///
+    /// 緊急度順は重みの設定に依存するため、ここでは比較せずID順とします
+    /// （`ListTasksUseCase`がスコアを計算して並べ替えます）。
+    pub(super) fn compare(self, a: &TaskAggregate, b: &TaskAggregate) -> Ordering {
+        let ordering = match self {
+            Self::Id | Self::Urgency => Ordering::Equal,
+            Self::Title => a.title().value().cmp(b.title().value()),
+            Self::Priority => b.priority().cmp(a.priority()),
+            Self::Due => {
+                // 期限なしを最後にするため、Noneを後ろに並べる
+                let key =
+                    |task: &TaskAggregate| task.due_date().map_or((1, None), |d| (0, Some(d)));
+                key(a).cmp(&key(b))
+            }
+            Self::Created => Reverse(a.created_at()).cmp(&Reverse(b.created_at())),
+            Self::Updated => Reverse(a.updated_at()).cmp(&Reverse(b.updated_at())),
+        };
+        ordering.then_with(|| a.id().value().cmp(&b.id().value()))
+    }
+}
+
+/// タスク一覧の絞り込み条件
+///
+/// 複数の条件を指定した場合は、すべての条件を満たすタスクが対象になります。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskFilter {
+    /// ステータスで絞り込み
+    Status(Status),
+    /// 指定したステータスのタスクを除外
+    ExcludeStatus(Status),
+    /// タグ名またはタグのパスで絞り込み（大文字小文字を区別しない）
+    ///
+    /// `partial`がtrueの場合は部分一致し、一致した全タグのいずれかを持つタスクが対象になります。
+    /// 親タグに一致した場合は、その子孫のタグを持つタスクも対象になります。
+    TagName { name: String, partial: bool },
+    /// 期限日が指定日より前
+    DueBefore(NaiveDate),
+    /// 期限日が指定日より後
+    DueAfter(NaiveDate),
+    /// 指定日時以降に作成された
+    CreatedAfter(DateTime<Utc>),
+    /// 指定日時以降に更新された
+    UpdatedSince(DateTime<Utc>),
+    /// スヌーズ中のタスクを除外（設定したタイムゾーンの今日を基準に判定）
+    ExcludeSnoozed,
+    /// 指定したコンテキストのタスクと、コンテキストが設定されていないタスクに絞り込み
+    Context(TaskContext),
+    /// 担当者で絞り込み
+    Assignee(Assignee),
+}
 
 /// ListTasksUseCase - タスク一覧取得のユースケース
 ///
@@ -15,6 +123,9 @@ use std::{
 pub struct ListTasksUseCase {
     task_repository: Arc<dyn TaskRepository>,
     tag_repository: Arc<dyn TagRepository>,
+    urgency: UrgencyCalculator,
+    due_date_buckets: DueDateBuckets,
+    clock: Arc<dyn Clock>,
 }
 
 impl ListTasksUseCase {
@@ -26,32 +137,65 @@ impl ListTasksUseCase {
         Self {
             task_repository,
             tag_repository,
+            urgency: UrgencyCalculator::default(),
+            due_date_buckets: DueDateBuckets::default(),
+            clock: Arc::new(SystemClock::default()),
         }
     }
 
+    /// 緊急度順で並べるときの重みを設定
+    pub fn with_urgency_weights(mut self, weights: UrgencyWeights) -> Self {
+        self.urgency = UrgencyCalculator::new(weights);
+        self
+    }
+
+    /// 期限日でグループ化するときの分類の境界を設定
+    pub fn with_due_date_buckets(mut self, buckets: DueDateBuckets) -> Self {
+        self.due_date_buckets = buckets;
+        self
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// タスク一覧を取得する
     ///
     /// # Returns
     /// * `Ok(Vec<TaskDTO>)` - タスクのリスト
     /// * `Err` - エラーが発生した場合
+    #[instrument(name = "list_tasks", skip_all)]
     pub async fn execute(&self) -> Result<Vec<TaskDTO>> {
-        // 1. 全タスクを取得
-        let tasks = self.task_repository.find_all().await?;
+        self.execute_filtered(&[], TaskSortKey::default()).await
+    }
 
-        // 2. 全タスクのタグIDを収集（重複排除）
-        let all_tag_ids: HashSet<_> = tasks
-            .iter()
-            .flat_map(|task| task.tags().iter().copied())
-            .collect();
+    /// 条件に一致するタスク一覧を取得する
+    ///
+    /// # Arguments
+    /// * `filters` - 絞り込み条件（空の場合は全タスク）
+    /// * `sort` - 並び順
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskDTO>)` - タスクのリスト
+    /// * `Err` - 指定したタグ名に一致するタグが存在しない場合など
+    #[instrument(name = "list_tasks", skip_all, fields(filters = filters.len()))]
+    pub async fn execute_filtered(
+        &self,
+        filters: &[TaskFilter],
+        sort: TaskSortKey,
+    ) -> Result<Vec<TaskDTO>> {
+        // 1. 条件に一致するタスクを取得
+        let tasks = self.find_tasks(filters, sort).await?;
 
-        // 3. タグ情報を一括取得（N+1問題の回避）
-        let tag_ids_vec: Vec<_> = all_tag_ids.into_iter().collect();
-        let tags = self.tag_repository.find_by_ids(&tag_ids_vec).await?;
+        // 2. タグ情報を一括取得（N+1問題の回避）
+        let tags = self.find_tags_for(&tasks).await?;
 
-        // 4. TagId -> TagAggregateのマップを作成
+        // 3. TagId -> TagAggregateのマップを作成
         let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
 
-        // 5. TaskDTOに変換（タグ詳細を含む）
+        // 4. TaskDTOに変換（タグ詳細を含む）
         let task_dtos = tasks
             .into_iter()
             .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
@@ -59,15 +203,230 @@ impl ListTasksUseCase {
 
         Ok(task_dtos)
     }
+
+    /// タスク一覧をグループ化して取得する
+    ///
+    /// グループは基準ごとに定められた順序で並び、タスクが存在しないグループは含まれません。
+    ///
+    /// # Arguments
+    /// * `group_by` - グループ化の基準
+    /// * `filters` - 絞り込み条件（空の場合は全タスク）
+    /// * `sort` - グループ内の並び順
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskGroupDTO>)` - グループのリスト
+    /// * `Err` - エラーが発生した場合
+    #[instrument(name = "list_tasks", skip_all)]
+    pub async fn execute_grouped(
+        &self,
+        group_by: TaskGroupBy,
+        filters: &[TaskFilter],
+        sort: TaskSortKey,
+    ) -> Result<Vec<TaskGroupDTO>> {
+        let tasks = self.find_tasks(filters, sort).await?;
+        let tags = self.find_tags_for(&tasks).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+        let today = self.clock.today();
+
+        // (並び順, 見出し) -> タスクのリスト
+        let mut groups: BTreeMap<(usize, String), Vec<TaskDTO>> = BTreeMap::new();
+        for task in tasks {
+            let keys = group_keys(&task, group_by, &tag_map, today, self.due_date_buckets);
+            let dto = TaskDTO::from_aggregate_with_tags(task, &tag_map);
+            for key in keys {
+                groups.entry(key).or_default().push(dto.clone());
+            }
+        }
+
+        Ok(groups
+            .into_iter()
+            .map(|((_, label), tasks)| TaskGroupDTO { label, tasks })
+            .collect())
+    }
+
+    /// 絞り込み条件に一致するタスクを指定した順に並べて取得
+    pub(super) async fn find_tasks(
+        &self,
+        filters: &[TaskFilter],
+        sort: TaskSortKey,
+    ) -> Result<Vec<TaskAggregate>> {
+        let mut spec: Option<Box<dyn TaskSpecification>> = None;
+        for filter in filters {
+            let next = self.build_specification(filter).await?;
+            spec = Some(match spec {
+                Some(current) => Box::new(AndSpecification::new(current, next)),
+                None => next,
+            });
+        }
+
+        let mut tasks = match spec {
+            Some(spec) => self.task_repository.find_by_specification(spec).await?,
+            None => self.task_repository.find_all().await?,
+        };
+        if sort == TaskSortKey::Urgency {
+            tasks = self.sort_by_urgency(tasks);
+        } else {
+            tasks.sort_by(|a, b| sort.compare(a, b));
+        }
+
+        Ok(tasks)
+    }
+
+    /// 緊急度スコアの高い順に並べる（同じスコアはID順）
+    fn sort_by_urgency(&self, tasks: Vec<TaskAggregate>) -> Vec<TaskAggregate> {
+        let today = self.clock.today();
+        let mut scored: Vec<_> = tasks
+            .into_iter()
+            .map(|task| (self.urgency.score(&task, today), task))
+            .collect();
+        scored.sort_by(|(score_a, a), (score_b, b)| {
+            score_b
+                .total_cmp(score_a)
+                .then_with(|| a.id().value().cmp(&b.id().value()))
+        });
+        scored.into_iter().map(|(_, task)| task).collect()
+    }
+
+    /// 絞り込み条件をSpecificationに変換
+    ///
+    /// タスクはタグIDのみを保持するため、タグ名はTagRepositoryでタグIDに解決してから
+    /// TaskByTagのSpecificationを組み立てます。
+    async fn build_specification(&self, filter: &TaskFilter) -> Result<Box<dyn TaskSpecification>> {
+        match filter {
+            TaskFilter::Status(status) => Ok(Box::new(TaskByStatus::new(*status))),
+            TaskFilter::ExcludeStatus(status) => Ok(Box::new(NotSpecification::new(Box::new(
+                TaskByStatus::new(*status),
+            )))),
+            TaskFilter::TagName { name, partial } => {
+                let tags = self.tag_repository.find_all().await?;
+                tag_name_specification(&tags, name, *partial)
+            }
+            TaskFilter::DueBefore(date) => Ok(Box::new(TaskDueBefore::new(*date))),
+            TaskFilter::DueAfter(date) => Ok(Box::new(TaskDueAfter::new(*date))),
+            TaskFilter::CreatedAfter(at) => Ok(Box::new(TaskCreatedAfter::new(*at))),
+            TaskFilter::UpdatedSince(at) => Ok(Box::new(TaskUpdatedSince::new(*at))),
+            TaskFilter::ExcludeSnoozed => Ok(Box::new(NotSpecification::new(Box::new(
+                TaskSnoozed::new(self.clock.today()),
+            )))),
+            TaskFilter::Context(context) => Ok(Box::new(OrSpecification::new(
+                Box::new(TaskInContext::new(context.clone())),
+                Box::new(NotSpecification::new(Box::new(TaskHasContext))),
+            ))),
+            TaskFilter::Assignee(assignee) => Ok(Box::new(TaskByAssignee::new(assignee.clone()))),
+        }
+    }
+
+    /// タスクに紐づくタグを一括取得
+    pub(super) async fn find_tags_for(&self, tasks: &[TaskAggregate]) -> Result<Vec<TagAggregate>> {
+        // 全タスクのタグIDを収集（重複排除）
+        let all_tag_ids: HashSet<_> = tasks
+            .iter()
+            .flat_map(|task| task.tags().iter().copied())
+            .collect();
+
+        let tag_ids_vec: Vec<_> = all_tag_ids.into_iter().collect();
+        self.tag_repository.find_by_ids(&tag_ids_vec).await
+    }
+}
+
+/// タスクが属するグループのキー（並び順, 見出し）を算出
+///
+/// タグ別の場合のみ、1つのタスクが複数のグループに属することがあります。
+fn group_keys(
+    task: &TaskAggregate,
+    group_by: TaskGroupBy,
+    tag_map: &HashMap<i32, &TagAggregate>,
+    today: NaiveDate,
+    due_date_buckets: DueDateBuckets,
+) -> Vec<(usize, String)> {
+    match group_by {
+        TaskGroupBy::Status => {
+            let order = Status::iter()
+                .position(|s| &s == task.status())
+                .unwrap_or(0);
+            vec![(order, task.status().display_name().to_string())]
+        }
+        TaskGroupBy::Priority => {
+            // 優先度の高い順に並べる
+            let order = Priority::iter()
+                .rev()
+                .position(|p| &p == task.priority())
+                .unwrap_or(0);
+            vec![(order, task.priority().display_name().to_string())]
+        }
+        TaskGroupBy::Tag => {
+            let names: Vec<String> = task
+                .tags()
+                .iter()
+                .filter_map(|tag_id| tag_map.get(&tag_id.value()))
+                .map(|tag| tag.name().value().to_string())
+                .collect();
+
+            if names.is_empty() {
+                // タグなしのタスクは最後にまとめる
+                vec![(1, "(No tags)".to_string())]
+            } else {
+                names.into_iter().map(|name| (0, name)).collect()
+            }
+        }
+        TaskGroupBy::Due => {
+            let due = task.due_date().as_ref().map(|d| d.value());
+            let status = DueDateStatus::from_due_date(due, today, due_date_buckets);
+            let order = DueDateStatus::ORDER
+                .iter()
+                .position(|s| s == &status)
+                .unwrap_or(0);
+            vec![(order, status.display_name().to_string())]
+        }
+    }
+}
+
+/// タグ名またはタグのパスに一致するタグを持つタスクのSpecificationを作成
+///
+/// 一致したタグとその子孫のタグのいずれかを持つタスクが対象になります。
+/// 一致するタグがない場合はエラーを返します。
+pub(super) fn tag_name_specification(
+    tags: &[TagAggregate],
+    name: &str,
+    partial: bool,
+) -> Result<Box<dyn TaskSpecification>> {
+    let needle = name.to_lowercase();
+    let hierarchy = TagHierarchy::new(tags);
+    // タグ名のほか、パス（例: work/project-a）でも一致させる
+    let matched: Vec<_> = tags
+        .iter()
+        .filter(|tag| {
+            let tag_name = tag.name().value().to_lowercase();
+            let tag_path = hierarchy.path(tag.id()).unwrap_or_default().to_lowercase();
+            if partial {
+                tag_name.contains(&needle) || tag_path.contains(&needle)
+            } else {
+                tag_name == needle || tag_path == needle
+            }
+        })
+        // 親タグで絞り込んだ場合は子孫のタグも対象にする
+        .flat_map(|tag| hierarchy.self_and_descendants(tag.id()))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    let mut specs = matched
+        .iter()
+        .map(|tag_id| Box::new(TaskByTag::new(*tag_id)) as Box<dyn TaskSpecification>);
+    let first = specs
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Tag '{}' does not exist", name))?;
+
+    Ok(specs.fold(first, |acc, spec| Box::new(OrSpecification::new(acc, spec))))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::task::{
-        aggregate::TaskAggregate,
-        value_objects::{Priority, Status, TaskDescription, TaskTitle},
-    };
+    use crate::domain::tag::aggregate::NewTag;
+    use crate::domain::tag::value_objects::{TagDescription, TagName};
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::value_objects::{DueDate, TaskDescription, TaskTitle};
     use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
 
     #[tokio::test]
@@ -92,14 +451,10 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タスク1").unwrap(),
-            TaskDescription::new("説明1").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タスク1").unwrap())
+            .description(TaskDescription::new("説明1").unwrap())
+            .priority(Priority::High)
+            .build();
         task_repo.save(task).await.unwrap();
 
         let use_case = ListTasksUseCase::new(task_repo, tag_repo);
@@ -113,8 +468,8 @@ mod tests {
         assert_eq!(tasks.len(), 1);
         assert_eq!(tasks[0].title, "タスク1");
         assert_eq!(tasks[0].description, Some("説明1".to_string()));
-        assert_eq!(tasks[0].status, "pending");
-        assert_eq!(tasks[0].priority, "high");
+        assert_eq!(tasks[0].status, Status::Pending);
+        assert_eq!(tasks[0].priority, Priority::High);
     }
 
     #[tokio::test]
@@ -123,32 +478,18 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task1 = TaskAggregate::new(
-            TaskTitle::new("タスク1").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
+        let task1 = TaskBuilder::new(TaskTitle::new("タスク1").unwrap())
+            .priority(Priority::Low)
+            .build();
 
-        let task2 = TaskAggregate::new(
-            TaskTitle::new("タスク2").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::InProgress,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task2 = TaskBuilder::new(TaskTitle::new("タスク2").unwrap())
+            .status(Status::InProgress)
+            .build();
 
-        let task3 = TaskAggregate::new(
-            TaskTitle::new("タスク3").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Completed,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task3 = TaskBuilder::new(TaskTitle::new("タスク3").unwrap())
+            .status(Status::Completed)
+            .priority(Priority::High)
+            .build();
 
         task_repo.save(task1).await.unwrap();
         task_repo.save(task2).await.unwrap();
@@ -177,32 +518,15 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let pending_task = TaskAggregate::new(
-            TaskTitle::new("Pendingタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let pending_task = TaskBuilder::new(TaskTitle::new("Pendingタスク").unwrap()).build();
 
-        let in_progress_task = TaskAggregate::new(
-            TaskTitle::new("InProgressタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::InProgress,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let in_progress_task = TaskBuilder::new(TaskTitle::new("InProgressタスク").unwrap())
+            .status(Status::InProgress)
+            .build();
 
-        let completed_task = TaskAggregate::new(
-            TaskTitle::new("Completedタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Completed,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let completed_task = TaskBuilder::new(TaskTitle::new("Completedタスク").unwrap())
+            .status(Status::Completed)
+            .build();
 
         task_repo.save(pending_task).await.unwrap();
         task_repo.save(in_progress_task).await.unwrap();
@@ -219,9 +543,529 @@ mod tests {
         assert_eq!(tasks.len(), 3);
 
         // すべてのステータスが含まれていることを確認
-        let statuses: Vec<String> = tasks.iter().map(|t| t.status.clone()).collect();
-        assert!(statuses.contains(&"pending".to_string()));
-        assert!(statuses.contains(&"in_progress".to_string()));
-        assert!(statuses.contains(&"completed".to_string()));
+        let statuses: Vec<Status> = tasks.iter().map(|t| t.status).collect();
+        assert!(statuses.contains(&Status::Pending));
+        assert!(statuses.contains(&Status::InProgress));
+        assert!(statuses.contains(&Status::Completed));
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_grouped_by_status() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+
+        for (title, status) in [
+            ("完了タスク", Status::Completed),
+            ("保留タスク1", Status::Pending),
+            ("保留タスク2", Status::Pending),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        let use_case = ListTasksUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let groups = use_case
+            .execute_grouped(TaskGroupBy::Status, &[], TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert: 空のグループ（In Progress）は含まれず、定義順に並ぶ
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].label, "Pending");
+        assert_eq!(groups[0].count(), 2);
+        assert_eq!(groups[1].label, "Completed");
+        assert_eq!(groups[1].count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_grouped_by_priority_descending() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+
+        for priority in [Priority::Low, Priority::Critical, Priority::Medium] {
+            let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+                .priority(priority)
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        let use_case = ListTasksUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let groups = use_case
+            .execute_grouped(TaskGroupBy::Priority, &[], TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["Critical", "Medium", "Low"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_grouped_by_tag() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+
+        let work = tag_repo
+            .save(NewTag::new(
+                TagName::new("仕事").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let urgent = tag_repo
+            .save(NewTag::new(
+                TagName::new("緊急").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        let tagged = TaskBuilder::new(TaskTitle::new("両方のタグ").unwrap())
+            .tags(vec![*work.id(), *urgent.id()])
+            .build();
+        let untagged = TaskBuilder::new(TaskTitle::new("タグなし").unwrap()).build();
+        task_repo.save(tagged).await.unwrap();
+        task_repo.save(untagged).await.unwrap();
+
+        let use_case = ListTasksUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let groups = use_case
+            .execute_grouped(TaskGroupBy::Tag, &[], TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert: 複数タグのタスクは各グループに含まれ、タグなしは最後
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["仕事", "緊急", "(No tags)"]);
+        assert_eq!(groups[0].tasks[0].title, "両方のタグ");
+        assert_eq!(groups[1].tasks[0].title, "両方のタグ");
+        assert_eq!(groups[2].tasks[0].title, "タグなし");
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_grouped_by_due() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let today = SystemClock::default().today();
+
+        for due in [
+            None,
+            Some(today + chrono::Duration::days(45)),
+            Some(today - chrono::Duration::days(1)),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+                .due_date(due.map(|d| DueDate::new(d).unwrap()))
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        let use_case = ListTasksUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let groups = use_case
+            .execute_grouped(TaskGroupBy::Due, &[], TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["Overdue", "Due Later", "No Due Date"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_grouped_by_due_with_custom_buckets() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let today = SystemClock::default().today();
+
+        for days in [2, 5, 20] {
+            let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+                .due_date(Some(
+                    DueDate::new(today + chrono::Duration::days(days)).unwrap(),
+                ))
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        let use_case = ListTasksUseCase::new(task_repo, tag_repo)
+            .with_due_date_buckets(DueDateBuckets::new(3, 10).unwrap());
+
+        // Act
+        let groups = use_case
+            .execute_grouped(TaskGroupBy::Due, &[], TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["Due Soon", "Due Upcoming", "Due Later"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_grouped_empty() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = ListTasksUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let groups = use_case
+            .execute_grouped(TaskGroupBy::Status, &[], TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(groups.is_empty());
+    }
+
+    /// タグ名フィルタのテスト用データ（Work/Workshop/私用タグと各タスク）を作成
+    async fn setup_tagged_tasks() -> ListTasksUseCase {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+
+        let mut tag_ids = Vec::new();
+        for name in ["Work", "Workshop", "私用"] {
+            let tag = tag_repo
+                .save(NewTag::new(
+                    TagName::new(name).unwrap(),
+                    TagDescription::new("").unwrap(),
+                ))
+                .await
+                .unwrap();
+            tag_ids.push(*tag.id());
+        }
+
+        for (title, status, tags) in [
+            ("仕事タスク", Status::Pending, vec![tag_ids[0]]),
+            ("勉強会タスク", Status::Completed, vec![tag_ids[1]]),
+            ("私用タスク", Status::Pending, vec![tag_ids[2]]),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .tags(tags)
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        ListTasksUseCase::new(task_repo, tag_repo)
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_filtered_by_tag_name_case_insensitive() {
+        // Arrange
+        let use_case = setup_tagged_tasks().await;
+        let filters = vec![TaskFilter::TagName {
+            name: "work".to_string(),
+            partial: false,
+        }];
+
+        // Act
+        let tasks = use_case
+            .execute_filtered(&filters, TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert: 完全一致のため"Workshop"は含まれない
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "仕事タスク");
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_filtered_by_tag_name_partial() {
+        // Arrange
+        let use_case = setup_tagged_tasks().await;
+        let filters = vec![TaskFilter::TagName {
+            name: "WORK".to_string(),
+            partial: true,
+        }];
+
+        // Act
+        let mut titles: Vec<String> = use_case
+            .execute_filtered(&filters, TaskSortKey::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.title)
+            .collect();
+        titles.sort();
+
+        // Assert
+        assert_eq!(titles, vec!["仕事タスク", "勉強会タスク"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_filtered_by_parent_tag_includes_children() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let work = tag_repo
+            .save(NewTag::new(
+                TagName::new("work").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let project = NewTag::new(
+            TagName::new("project-a").unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+        .with_parent(
+            Some(*work.id()),
+            &TagHierarchy::new(std::slice::from_ref(&work)),
+        )
+        .unwrap();
+        let project = tag_repo.save(project).await.unwrap();
+        for (title, tags) in [
+            ("親タグのタスク", vec![*work.id()]),
+            ("子タグのタスク", vec![*project.id()]),
+            ("タグなし", vec![]),
+        ] {
+            task_repo
+                .save(
+                    TaskBuilder::new(TaskTitle::new(title).unwrap())
+                        .tags(tags)
+                        .build(),
+                )
+                .await
+                .unwrap();
+        }
+        let use_case = ListTasksUseCase::new(task_repo, tag_repo);
+        let filter = |name: &str| {
+            vec![TaskFilter::TagName {
+                name: name.to_string(),
+                partial: false,
+            }]
+        };
+
+        // Act
+        let by_parent = use_case
+            .execute_filtered(&filter("Work"), TaskSortKey::default())
+            .await
+            .unwrap();
+        let by_path = use_case
+            .execute_filtered(&filter("work/project-a"), TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        let titles: Vec<_> = by_parent.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["親タグのタスク", "子タグのタスク"]);
+        assert_eq!(by_path.len(), 1);
+        assert_eq!(by_path[0].title, "子タグのタスク");
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_filtered_by_tag_and_status() {
+        // Arrange
+        let use_case = setup_tagged_tasks().await;
+        let filters = vec![
+            TaskFilter::TagName {
+                name: "work".to_string(),
+                partial: true,
+            },
+            TaskFilter::Status(Status::Completed),
+        ];
+
+        // Act
+        let tasks = use_case
+            .execute_filtered(&filters, TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "勉強会タスク");
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_filtered_by_unknown_tag() {
+        // Arrange
+        let use_case = setup_tagged_tasks().await;
+        let filters = vec![TaskFilter::TagName {
+            name: "存在しない".to_string(),
+            partial: false,
+        }];
+
+        // Act
+        let result = use_case
+            .execute_filtered(&filters, TaskSortKey::default())
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Tag '存在しない' does not exist"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_grouped_with_filter() {
+        // Arrange
+        let use_case = setup_tagged_tasks().await;
+        let filters = vec![TaskFilter::Status(Status::Pending)];
+
+        // Act
+        let groups = use_case
+            .execute_grouped(TaskGroupBy::Tag, &filters, TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        let labels: Vec<&str> = groups.iter().map(|g| g.label.as_str()).collect();
+        assert_eq!(labels, vec!["Work", "私用"]);
+    }
+
+    async fn setup_sortable_tasks() -> ListTasksUseCase {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+
+        let today = SystemClock::default().today();
+        for (title, priority, due_in) in [
+            ("期限なし", Priority::Critical, None),
+            ("来週", Priority::Low, Some(7)),
+            ("明日", Priority::High, Some(1)),
+        ] {
+            let due_date =
+                due_in.map(|days| DueDate::new(today + chrono::Duration::days(days)).unwrap());
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .priority(priority)
+                .due_date(due_date)
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        ListTasksUseCase::new(task_repo, tag_repo)
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_sorted_by_due_puts_no_due_date_last() {
+        // Arrange
+        let use_case = setup_sortable_tasks().await;
+
+        // Act
+        let tasks = use_case
+            .execute_filtered(&[], TaskSortKey::Due)
+            .await
+            .unwrap();
+
+        // Assert
+        let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["明日", "来週", "期限なし"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_sorted_by_priority_descending() {
+        // Arrange
+        let use_case = setup_sortable_tasks().await;
+
+        // Act
+        let tasks = use_case
+            .execute_filtered(&[], TaskSortKey::Priority)
+            .await
+            .unwrap();
+
+        // Assert
+        let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["期限なし", "明日", "来週"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_sorted_by_urgency() {
+        // Arrange
+        let use_case = setup_sortable_tasks().await;
+        let priority_only = UrgencyWeights::new(10.0, 0.0, 0.0, 0.0).unwrap();
+
+        // Act
+        let by_default_weights = use_case
+            .execute_filtered(&[], TaskSortKey::Urgency)
+            .await
+            .unwrap();
+        let by_priority_only = use_case
+            .with_urgency_weights(priority_only)
+            .execute_filtered(&[], TaskSortKey::Urgency)
+            .await
+            .unwrap();
+
+        // Assert
+        let titles =
+            |tasks: &[TaskDTO]| -> Vec<String> { tasks.iter().map(|t| t.title.clone()).collect() };
+        assert_eq!(
+            titles(&by_default_weights),
+            vec!["明日", "期限なし", "来週"]
+        );
+        assert_eq!(titles(&by_priority_only), vec!["期限なし", "明日", "来週"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_default_sort_is_id() {
+        // Arrange
+        let use_case = setup_sortable_tasks().await;
+
+        // Act
+        let tasks = use_case.execute().await.unwrap();
+
+        // Assert
+        let ids: Vec<i32> = tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_excluding_completed() {
+        // Arrange
+        let use_case = setup_tagged_tasks().await;
+        let filters = vec![TaskFilter::ExcludeStatus(Status::Completed)];
+
+        // Act
+        let tasks = use_case
+            .execute_filtered(&filters, TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(tasks.len(), 2);
+        assert!(tasks.iter().all(|t| t.status != Status::Completed));
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_filtered_by_dates() {
+        // Arrange
+        let use_case = setup_sortable_tasks().await;
+        let today = SystemClock::default().today();
+        let filters = vec![
+            TaskFilter::DueAfter(today),
+            TaskFilter::DueBefore(today + chrono::Duration::days(7)),
+            TaskFilter::CreatedAfter(Utc::now() - chrono::Duration::days(1)),
+        ];
+        let future = vec![TaskFilter::UpdatedSince(
+            Utc::now() + chrono::Duration::days(1),
+        )];
+
+        // Act
+        let tasks = use_case
+            .execute_filtered(&filters, TaskSortKey::default())
+            .await
+            .unwrap();
+        let none_updated = use_case
+            .execute_filtered(&future, TaskSortKey::default())
+            .await
+            .unwrap();
+
+        // Assert: 期限日のないタスクは期限日の条件を満たさない
+        let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["明日"]);
+        assert!(none_updated.is_empty());
     }
 }
@@ -0,0 +1,164 @@
+use crate::{
+    application::dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            value_objects::{ChecklistItem, TaskId},
+        },
+    },
+};
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// ManageChecklistUseCase - タスクのチェックリスト操作のユースケース
+///
+/// チェックリスト項目の追加・完了状態の切り替え・削除を行います。
+/// 項目は1から始まる番号で指定します。
+/// 返すTaskDTOのタグ情報は解決しません（チェックリストの表示用）。
+pub struct ManageChecklistUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ManageChecklistUseCase {
+    /// 新しいManageChecklistUseCaseを作成
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self {
+            task_repository,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// 更新日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// タスクを取得する（チェックリストの一覧表示用）
+    #[instrument(name = "checklist.list", skip(self))]
+    pub async fn list(&self, task_id: i32) -> Result<TaskDTO> {
+        Ok(TaskDTO::from(self.find_task(task_id).await?))
+    }
+
+    /// チェックリストの末尾に項目を追加する
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 更新後のタスク
+    /// * `Err` - タスクが存在しない場合、または項目の内容が不正な場合
+    #[instrument(name = "checklist.add", skip(self, text))]
+    pub async fn add(&self, task_id: i32, text: &str) -> Result<TaskDTO> {
+        let item = ChecklistItem::new(text, false)?;
+        let mut task = self.find_task(task_id).await?;
+        task.add_checklist_item(item, self.clock.as_ref())?;
+        self.save(task).await
+    }
+
+    /// チェックリスト項目の完了状態を切り替える
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 更新後のタスク
+    /// * `Err` - タスクまたは項目が存在しない場合
+    #[instrument(name = "checklist.toggle", skip(self))]
+    pub async fn toggle(&self, task_id: i32, number: usize) -> Result<TaskDTO> {
+        let mut task = self.find_task(task_id).await?;
+        task.toggle_checklist_item(number, self.clock.as_ref())?;
+        self.save(task).await
+    }
+
+    /// チェックリスト項目を削除する
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 更新後のタスク
+    /// * `Err` - タスクまたは項目が存在しない場合
+    #[instrument(name = "checklist.remove", skip(self))]
+    pub async fn remove(&self, task_id: i32, number: usize) -> Result<TaskDTO> {
+        let mut task = self.find_task(task_id).await?;
+        task.remove_checklist_item(number, self.clock.as_ref())?;
+        self.save(task).await
+    }
+
+    async fn find_task(&self, task_id: i32) -> Result<TaskAggregate> {
+        self.task_repository
+            .find_by_id(&TaskId::new(task_id)?)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task ID {} does not exist", task_id))
+    }
+
+    async fn save(&self, task: TaskAggregate) -> Result<TaskDTO> {
+        let updated = self.task_repository.update(task).await?;
+        Ok(TaskDTO::from(updated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::value_objects::TaskTitle;
+    use crate::interface::persistence::in_memory::InMemoryTaskRepository;
+
+    async fn setup() -> (ManageChecklistUseCase, i32) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let task = task_repo
+            .save(TaskBuilder::new(TaskTitle::new("リリース準備").unwrap()).build())
+            .await
+            .unwrap();
+        (ManageChecklistUseCase::new(task_repo), task.id().value())
+    }
+
+    #[tokio::test]
+    async fn test_checklist_add_toggle_remove() {
+        // Arrange
+        let (use_case, task_id) = setup().await;
+
+        // Act
+        use_case.add(task_id, "write tests").await.unwrap();
+        use_case.add(task_id, "update docs").await.unwrap();
+        use_case.add(task_id, "tag release").await.unwrap();
+        use_case.toggle(task_id, 1).await.unwrap();
+        use_case.remove(task_id, 2).await.unwrap();
+        let task = use_case.list(task_id).await.unwrap();
+
+        // Assert
+        let items: Vec<_> = task
+            .checklist
+            .iter()
+            .map(|item| (item.text.as_str(), item.done))
+            .collect();
+        assert_eq!(items, vec![("write tests", true), ("tag release", false)]);
+    }
+
+    #[tokio::test]
+    async fn test_checklist_toggle_missing_item() {
+        // Arrange
+        let (use_case, task_id) = setup().await;
+
+        // Act
+        let result = use_case.toggle(task_id, 1).await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Checklist item 1 does not exist (task has 0 items)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_checklist_task_not_found() {
+        // Arrange
+        let (use_case, _) = setup().await;
+
+        // Act
+        let result = use_case.add(999, "write tests").await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Task ID 999 does not exist"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    application::dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            status_transition::StatusTransitionPolicy,
+            value_objects::{GitLink, Status, TaskId},
+        },
+    },
+};
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// ManageGitLinksUseCase - タスクとgitの紐づけのユースケース
+///
+/// タスクへのコミット・ブランチの紐づけ、ブランチに紐づく未完了タスクの検索、
+/// マージされたタスクの完了を行います。
+/// 返すTaskDTOのタグ情報は解決しません。
+pub struct ManageGitLinksUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    transition_policy: StatusTransitionPolicy,
+    clock: Arc<dyn Clock>,
+}
+
+impl ManageGitLinksUseCase {
+    /// 新しいManageGitLinksUseCaseを作成
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self {
+            task_repository,
+            transition_policy: StatusTransitionPolicy::default(),
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// ステータスの遷移ポリシーを設定する
+    pub fn with_transition_policy(mut self, policy: StatusTransitionPolicy) -> Self {
+        self.transition_policy = policy;
+        self
+    }
+
+    /// ステータスの変更日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// タスクにコミット・ブランチを紐づける
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 更新後のタスク
+    /// * `Err` - タスクが存在しない場合、または同じコミットに紐づけ済みの場合
+    #[instrument(name = "git.link", skip(self, link))]
+    pub async fn link(&self, task_id: i32, link: GitLink) -> Result<TaskDTO> {
+        let mut task = self.find_task(task_id).await?;
+        task.link_git(link, self.clock.as_ref())?;
+        let updated = self.task_repository.update(task).await?;
+        Ok(TaskDTO::from(updated))
+    }
+
+    /// 指定したリポジトリ・ブランチに紐づく未完了のタスクをID順に取得する
+    #[instrument(name = "git.open_tasks_on_branch", skip(self))]
+    pub async fn open_tasks_on_branch(
+        &self,
+        repository: &str,
+        branch: &str,
+    ) -> Result<Vec<TaskDTO>> {
+        let mut tasks: Vec<TaskDTO> = self
+            .task_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|task| *task.status() != Status::Completed)
+            .filter(|task| {
+                task.git_links()
+                    .iter()
+                    .any(|link| link.repository() == repository && link.branch() == Some(branch))
+            })
+            .map(TaskDTO::from)
+            .collect();
+        tasks.sort_by_key(|task| task.id);
+        Ok(tasks)
+    }
+
+    /// マージされたタスクを完了にする
+    ///
+    /// # Returns
+    /// * `Ok(Some(TaskDTO))` - 完了にしたタスク
+    /// * `Ok(None)` - すでに完了している場合
+    /// * `Err` - タスクが存在しない場合、または遷移ポリシーで禁止されている場合
+    #[instrument(name = "git.complete", skip(self))]
+    pub async fn complete(&self, task_id: i32) -> Result<Option<TaskDTO>> {
+        let mut task = self.find_task(task_id).await?;
+        if *task.status() == Status::Completed {
+            return Ok(None);
+        }
+        task.change_status(
+            Status::Completed,
+            &self.transition_policy,
+            self.clock.as_ref(),
+        )?;
+        let updated = self.task_repository.update(task).await?;
+        Ok(Some(TaskDTO::from(updated)))
+    }
+
+    async fn find_task(&self, task_id: i32) -> Result<TaskAggregate> {
+        self.task_repository
+            .find_by_id(&TaskId::new(task_id)?)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task ID {} does not exist", task_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::{status_transition::TransitionRule, value_objects::TaskTitle};
+    use crate::interface::persistence::in_memory::InMemoryTaskRepository;
+    use chrono::Utc;
+
+    async fn setup() -> (Arc<InMemoryTaskRepository>, Vec<i32>) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let mut ids = Vec::new();
+        for title in ["ログイン画面", "API設計", "ドキュメント"] {
+            let task = task_repo
+                .save(TaskBuilder::new(TaskTitle::new(title).unwrap()).build())
+                .await
+                .unwrap();
+            ids.push(task.id().value());
+        }
+        (task_repo, ids)
+    }
+
+    fn link(branch: &str) -> GitLink {
+        GitLink::new("/src/app", Some(branch.to_string()), "abc1234", Utc::now()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_open_tasks_on_branch() {
+        // Arrange
+        let (task_repo, ids) = setup().await;
+        let use_case = ManageGitLinksUseCase::new(task_repo);
+        use_case.link(ids[0], link("feature/login")).await.unwrap();
+        use_case.link(ids[1], link("main")).await.unwrap();
+        use_case.link(ids[2], link("feature/login")).await.unwrap();
+        use_case.complete(ids[2]).await.unwrap();
+
+        // Act
+        let tasks = use_case
+            .open_tasks_on_branch("/src/app", "feature/login")
+            .await
+            .unwrap();
+
+        // Assert
+        let found: Vec<i32> = tasks.iter().map(|task| task.id).collect();
+        assert_eq!(found, vec![ids[0]]);
+        assert_eq!(
+            tasks[0].git_links[0].branch.as_deref(),
+            Some("feature/login")
+        );
+        assert!(
+            use_case
+                .open_tasks_on_branch("/src/other", "feature/login")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_skips_completed_task() {
+        // Arrange
+        let (task_repo, ids) = setup().await;
+        let use_case = ManageGitLinksUseCase::new(task_repo);
+
+        // Act
+        let first = use_case.complete(ids[0]).await.unwrap();
+        let second = use_case.complete(ids[0]).await.unwrap();
+
+        // Assert
+        assert_eq!(first.unwrap().status, Status::Completed);
+        assert!(second.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_complete_respects_transition_policy() {
+        // Arrange
+        let (task_repo, ids) = setup().await;
+        let policy = StatusTransitionPolicy::default().with_rule(
+            Status::Pending,
+            Status::Completed,
+            TransitionRule::Forbidden,
+        );
+        let use_case = ManageGitLinksUseCase::new(task_repo).with_transition_policy(policy);
+
+        // Act
+        let result = use_case.complete(ids[0]).await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
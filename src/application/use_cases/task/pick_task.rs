@@ -0,0 +1,255 @@
+use super::list_tasks::{ListTasksUseCase, TaskFilter, TaskSortKey};
+use crate::{
+    application::dto::task_dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        services::{UrgencyCalculator, UrgencyWeights},
+        tag::repository::TagRepository,
+        task::{repository::TaskRepository, value_objects::Status},
+    },
+};
+use anyhow::Result;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tracing::instrument;
+
+/// PickTaskUseCase - 未完了のタスクから1件をランダムに選ぶユースケース
+///
+/// 何から手を付けるか迷ったときのために、`ListTasksUseCase`と同じ絞り込み条件の
+/// Specificationで候補を集め、その中から1件を選びます。
+/// 完了済みのタスクとスヌーズ中のタスクは常に候補から除外します。
+pub struct PickTaskUseCase {
+    list: ListTasksUseCase,
+    urgency: UrgencyCalculator,
+    weighted: bool,
+    clock: Arc<dyn Clock>,
+    rng: Mutex<fastrand::Rng>,
+}
+
+impl PickTaskUseCase {
+    /// 緊急度で重み付けする場合に、どのタスクにも加える重み
+    ///
+    /// 緊急度スコアが0以下のタスクにも選ばれる可能性を残すためのものです。
+    const WEIGHT_FLOOR: f64 = 1.0;
+
+    /// 新しいPickTaskUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            list: ListTasksUseCase::new(task_repository, tag_repository),
+            urgency: UrgencyCalculator::default(),
+            weighted: false,
+            clock: Arc::new(SystemClock::default()),
+            rng: Mutex::new(fastrand::Rng::new()),
+        }
+    }
+
+    /// 緊急度スコアに比例した確率で選ぶかを設定（既定は均等）
+    pub fn with_urgency_weighting(mut self, weighted: bool) -> Self {
+        self.weighted = weighted;
+        self
+    }
+
+    /// 緊急度で重み付けするときの重みを設定
+    pub fn with_urgency_weights(mut self, weights: UrgencyWeights) -> Self {
+        self.urgency = UrgencyCalculator::new(weights);
+        self
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.list = self.list.with_clock(clock.clone());
+        self.clock = clock;
+        self
+    }
+
+    /// 乱数のシードを設定する（同じシードでは同じ順にタスクが選ばれる、Noneの場合はランダム）
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        if let Some(seed) = seed {
+            self.rng = Mutex::new(fastrand::Rng::with_seed(seed));
+        }
+        self
+    }
+
+    /// 条件に一致する未完了のタスクから1件を選ぶ
+    ///
+    /// # Arguments
+    /// * `filters` - 絞り込み条件（空の場合は未完了の全タスク）
+    /// * `excluded` - 候補から除くタスクのID（選び直すときに、すでに選んだタスクを指定する）
+    ///
+    /// # Returns
+    /// * `Ok(Some(TaskDTO))` - 選ばれたタスク
+    /// * `Ok(None)` - 候補となるタスクがない場合
+    /// * `Err` - 指定したタグ名に一致するタグが存在しない場合など
+    #[instrument(name = "pick_task", skip_all, fields(filters = filters.len()))]
+    pub async fn execute(
+        &self,
+        filters: &[TaskFilter],
+        excluded: &[i32],
+    ) -> Result<Option<TaskDTO>> {
+        let mut filters = filters.to_vec();
+        filters.push(TaskFilter::ExcludeStatus(Status::Completed));
+        filters.push(TaskFilter::ExcludeSnoozed);
+        let mut candidates = self.list.find_tasks(&filters, TaskSortKey::Id).await?;
+        candidates.retain(|task| !excluded.contains(&task.id().value()));
+
+        let today = self.clock.today();
+        let weights: Vec<f64> = candidates
+            .iter()
+            .map(|task| {
+                if self.weighted {
+                    self.urgency.score(task, today).max(0.0) + Self::WEIGHT_FLOOR
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+        let index = {
+            let mut rng = self.rng.lock().unwrap_or_else(|e| e.into_inner());
+            weighted_index(&weights, &mut rng)
+        };
+        let Some(task) = index.map(|index| candidates.swap_remove(index)) else {
+            return Ok(None);
+        };
+
+        let tags = self.list.find_tags_for(std::slice::from_ref(&task)).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+        Ok(Some(TaskDTO::from_aggregate_with_tags(task, &tag_map)))
+    }
+}
+
+/// 重みに比例した確率で添字を1つ選ぶ（空の場合はNone）
+fn weighted_index(weights: &[f64], rng: &mut fastrand::Rng) -> Option<usize> {
+    let total: f64 = weights.iter().sum();
+    if weights.is_empty() || total <= 0.0 {
+        return None;
+    }
+    let mut target = rng.f64() * total;
+    for (index, weight) in weights.iter().enumerate() {
+        if target < *weight {
+            return Some(index);
+        }
+        target -= weight;
+    }
+    // 浮動小数点の誤差で末尾を超えた場合は最後の要素
+    Some(weights.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            clock::FixedClock,
+            task::{
+                builder::TaskBuilder,
+                value_objects::{Priority, TaskTitle},
+            },
+        },
+        interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository},
+    };
+
+    async fn setup_tasks() -> (Arc<InMemoryTaskRepository>, Arc<InMemoryTagRepository>) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        for (title, status, priority) in [
+            ("低い", Status::Pending, Priority::Low),
+            ("重大", Status::Pending, Priority::Critical),
+            ("完了", Status::Completed, Priority::Critical),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .priority(priority)
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+        (task_repo, tag_repo)
+    }
+
+    #[test]
+    fn test_weighted_index() {
+        // Arrange
+        let mut rng = fastrand::Rng::with_seed(7);
+
+        // Act
+        let picks: Vec<_> = (0..100)
+            .map(|_| weighted_index(&[0.0, 1.0, 0.0], &mut rng))
+            .collect();
+
+        // Assert
+        assert!(picks.iter().all(|pick| *pick == Some(1)));
+        assert_eq!(weighted_index(&[], &mut rng), None);
+        assert_eq!(weighted_index(&[0.0, 0.0], &mut rng), None);
+    }
+
+    #[tokio::test]
+    async fn test_pick_task_skips_completed_and_excluded() {
+        // Arrange
+        let (task_repo, tag_repo) = setup_tasks().await;
+        let use_case = PickTaskUseCase::new(task_repo, tag_repo)
+            .with_clock(Arc::new(FixedClock::at(2026, 1, 10, 9, 0)))
+            .with_seed(Some(42));
+
+        // Act
+        let first = use_case.execute(&[], &[]).await.unwrap().unwrap();
+        let second = use_case.execute(&[], &[first.id]).await.unwrap().unwrap();
+        let none = use_case.execute(&[], &[first.id, second.id]).await.unwrap();
+
+        // Assert
+        assert_ne!(first.status, Status::Completed);
+        assert_ne!(second.status, Status::Completed);
+        assert_ne!(first.id, second.id);
+        assert!(none.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pick_task_is_reproducible_with_seed() {
+        // Arrange
+        let (task_repo, tag_repo) = setup_tasks().await;
+        let pick_ids = |seed| {
+            let use_case = PickTaskUseCase::new(task_repo.clone(), tag_repo.clone())
+                .with_urgency_weighting(true)
+                .with_seed(Some(seed));
+            async move {
+                let mut ids = Vec::new();
+                for _ in 0..20 {
+                    ids.push(use_case.execute(&[], &[]).await.unwrap().unwrap().id);
+                }
+                ids
+            }
+        };
+
+        // Act
+        let first = pick_ids(3).await;
+        let second = pick_ids(3).await;
+
+        // Assert
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn test_pick_task_weighted_prefers_urgent_tasks() {
+        // Arrange
+        let (task_repo, tag_repo) = setup_tasks().await;
+        let use_case = PickTaskUseCase::new(task_repo, tag_repo)
+            .with_urgency_weighting(true)
+            .with_urgency_weights(UrgencyWeights::new(30.0, 0.0, 0.0, 0.0).unwrap())
+            .with_seed(Some(1));
+
+        // Act
+        let mut critical = 0;
+        for _ in 0..200 {
+            let task = use_case.execute(&[], &[]).await.unwrap().unwrap();
+            if task.title == "重大" {
+                critical += 1;
+            }
+        }
+
+        // Assert: 重み31対1のため、ほとんどが重大なタスクになる
+        assert!(critical > 180, "critical picked {critical} times");
+    }
+}
@@ -0,0 +1,920 @@
+use crate::{
+    application::{
+        dto::task_dto::TaskDTO,
+        use_cases::task::list_tasks::{TaskSortKey, tag_name_specification},
+    },
+    domain::{
+        clock::{Clock, SystemClock},
+        tag::{aggregate::TagAggregate, repository::TagRepository},
+        task::{
+            repository::TaskRepository,
+            specification::{
+                AndSpecification, NotSpecification, OrSpecification, SearchField, TaskById,
+                TaskByKeyword, TaskByPriority, TaskByStatus, TaskCreatedAfter, TaskDueAfter,
+                TaskDueBefore, TaskDueBetween, TaskHasDueDate, TaskSpecification, TaskUpdatedSince,
+            },
+            value_objects::{Priority, Status, TaskId},
+        },
+    },
+};
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use std::{
+    collections::{HashMap, HashSet},
+    iter::Peekable,
+    str::{Chars, FromStr},
+    sync::Arc,
+};
+use strum::IntoEnumIterator;
+use tracing::instrument;
+
+/// 比較演算子
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// 部分一致（`~`）
+    Contains,
+}
+
+impl CompareOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Contains => "~",
+        }
+    }
+}
+
+/// 条件に指定できる項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueryField {
+    Id,
+    Status,
+    Priority,
+    Tag,
+    Due,
+    Created,
+    Updated,
+    Title,
+    Description,
+}
+
+impl FromStr for QueryField {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "id" => Ok(Self::Id),
+            "status" => Ok(Self::Status),
+            "priority" => Ok(Self::Priority),
+            "tag" => Ok(Self::Tag),
+            "due" => Ok(Self::Due),
+            "created" => Ok(Self::Created),
+            "updated" => Ok(Self::Updated),
+            "title" => Ok(Self::Title),
+            "description" => Ok(Self::Description),
+            _ => anyhow::bail!("Unknown field in query: '{}'", s),
+        }
+    }
+}
+
+/// 条件式
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum QueryExpr {
+    Compare {
+        field: QueryField,
+        op: CompareOp,
+        value: String,
+    },
+    And(Box<QueryExpr>, Box<QueryExpr>),
+    Or(Box<QueryExpr>, Box<QueryExpr>),
+    Not(Box<QueryExpr>),
+}
+
+impl QueryExpr {
+    /// タグの条件を含むか（タグの一覧を取得する必要があるか）
+    fn uses_tags(&self) -> bool {
+        match self {
+            Self::Compare { field, .. } => *field == QueryField::Tag,
+            Self::And(left, right) | Self::Or(left, right) => left.uses_tags() || right.uses_tags(),
+            Self::Not(inner) => inner.uses_tags(),
+        }
+    }
+}
+
+/// 解析済みのクエリ
+///
+/// 条件式・並び順・件数の上限からなります。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskQuery {
+    filter: Option<QueryExpr>,
+    sort: TaskSortKey,
+    /// 並び順の向き（`None`の場合は`yaru task list --sort`と同じ既定の向き）
+    descending: Option<bool>,
+    limit: Option<usize>,
+}
+
+impl FromStr for TaskQuery {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        QueryParser::new(tokenize(s)?).parse()
+    }
+}
+
+/// クエリの字句
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// 項目名・値・キーワード
+    Word(String),
+    /// 引用符で囲まれた値
+    Quoted(String),
+    Op(CompareOp),
+    LParen,
+    RParen,
+}
+
+impl Token {
+    /// キーワード（大文字小文字を区別しない）と一致するか
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Self::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Word(word) => format!("'{}'", word),
+            Self::Quoted(text) => format!("\"{}\"", text),
+            Self::Op(op) => format!("'{}'", op.symbol()),
+            Self::LParen => "'('".to_string(),
+            Self::RParen => "')'".to_string(),
+        }
+    }
+}
+
+/// 値の一部になれない記号
+const DELIMITERS: &[char] = &['(', ')', '=', '!', '<', '>', '~', '"', '\''];
+
+/// クエリを字句に分割
+fn tokenize(query: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let token = match c {
+            '(' => {
+                chars.next();
+                Token::LParen
+            }
+            ')' => {
+                chars.next();
+                Token::RParen
+            }
+            '"' | '\'' => {
+                chars.next();
+                Token::Quoted(read_quoted(&mut chars, c)?)
+            }
+            '=' | '!' | '<' | '>' | '~' => Token::Op(read_operator(&mut chars)?),
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || DELIMITERS.contains(&c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                Token::Word(word)
+            }
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// 閉じ引用符までを読み込む
+fn read_quoted(chars: &mut Peekable<Chars>, quote: char) -> Result<String> {
+    let mut text = String::new();
+    for c in chars.by_ref() {
+        if c == quote {
+            return Ok(text);
+        }
+        text.push(c);
+    }
+    anyhow::bail!("Unterminated quote in query: {}{}", quote, text)
+}
+
+/// 比較演算子を読み込む
+fn read_operator(chars: &mut Peekable<Chars>) -> Result<CompareOp> {
+    let first = chars.next().unwrap_or_default();
+    let followed_by_eq = chars.peek() == Some(&'=');
+    let op = match (first, followed_by_eq) {
+        ('=', _) => CompareOp::Eq,
+        ('!', true) => CompareOp::Ne,
+        ('<', true) => CompareOp::Le,
+        ('>', true) => CompareOp::Ge,
+        ('<', false) => CompareOp::Lt,
+        ('>', false) => CompareOp::Gt,
+        ('~', _) => CompareOp::Contains,
+        _ => anyhow::bail!("Invalid operator in query: '{}'", first),
+    };
+    if matches!(op, CompareOp::Ne | CompareOp::Le | CompareOp::Ge) {
+        chars.next();
+    }
+    Ok(op)
+}
+
+/// クエリの構文解析器（再帰下降）
+///
+/// ```text
+/// query   := [or] [ORDER BY key [ASC|DESC]] [LIMIT n]
+/// or      := and (OR and)*
+/// and     := not (AND not)*
+/// not     := NOT not | '(' or ')' | field op value
+/// ```
+struct QueryParser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl QueryParser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn parse(mut self) -> Result<TaskQuery> {
+        let filter = match self.peek() {
+            None => None,
+            Some(token) if token.is_keyword("ORDER") || token.is_keyword("LIMIT") => None,
+            Some(_) => Some(self.parse_or()?),
+        };
+
+        let (sort, descending) = if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            let key = self.expect_word("a sort key after ORDER BY")?;
+            let sort = parse_sort_key(&key)?;
+            let descending = if self.eat_keyword("DESC") {
+                Some(true)
+            } else if self.eat_keyword("ASC") {
+                Some(false)
+            } else {
+                None
+            };
+            (sort, descending)
+        } else {
+            (TaskSortKey::default(), None)
+        };
+
+        let limit =
+            if self.eat_keyword("LIMIT") {
+                let value = self.expect_word("a number after LIMIT")?;
+                Some(value.parse().map_err(|_| {
+                    anyhow::anyhow!("LIMIT must be a non-negative integer: {}", value)
+                })?)
+            } else {
+                None
+            };
+
+        if let Some(token) = self.peek() {
+            anyhow::bail!("Unexpected {} in query", token.describe());
+        }
+
+        Ok(TaskQuery {
+            filter,
+            sort,
+            descending,
+            limit,
+        })
+    }
+
+    fn parse_or(&mut self) -> Result<QueryExpr> {
+        let mut expr = self.parse_and()?;
+        while self.eat_keyword("OR") {
+            expr = QueryExpr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<QueryExpr> {
+        let mut expr = self.parse_not()?;
+        while self.eat_keyword("AND") {
+            expr = QueryExpr::And(Box::new(expr), Box::new(self.parse_not()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<QueryExpr> {
+        if self.eat_keyword("NOT") {
+            return Ok(QueryExpr::Not(Box::new(self.parse_not()?)));
+        }
+        if self.peek() == Some(&Token::LParen) {
+            self.position += 1;
+            let expr = self.parse_or()?;
+            if self.next() != Some(Token::RParen) {
+                anyhow::bail!("Missing ')' in query");
+            }
+            return Ok(expr);
+        }
+
+        let field: QueryField = self.expect_word("a field name")?.parse()?;
+        let op = match self.next() {
+            Some(Token::Op(op)) => op,
+            Some(token) => anyhow::bail!("Expected an operator but found {}", token.describe()),
+            None => anyhow::bail!("Expected an operator at the end of the query"),
+        };
+        let value = match self.next() {
+            Some(Token::Word(value) | Token::Quoted(value)) => value,
+            Some(token) => anyhow::bail!("Expected a value but found {}", token.describe()),
+            None => anyhow::bail!("Expected a value at the end of the query"),
+        };
+        Ok(QueryExpr::Compare { field, op, value })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let matched = self.peek().is_some_and(|token| token.is_keyword(keyword));
+        if matched {
+            self.position += 1;
+        }
+        matched
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            anyhow::bail!("Expected {} in query", keyword)
+        }
+    }
+
+    fn expect_word(&mut self, expected: &str) -> Result<String> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            Some(token) => anyhow::bail!("Expected {} but found {}", expected, token.describe()),
+            None => anyhow::bail!("Expected {} at the end of the query", expected),
+        }
+    }
+}
+
+/// 並び替えのキーを変換
+fn parse_sort_key(key: &str) -> Result<TaskSortKey> {
+    match key.to_lowercase().as_str() {
+        "id" => Ok(TaskSortKey::Id),
+        "title" => Ok(TaskSortKey::Title),
+        "priority" => Ok(TaskSortKey::Priority),
+        "due" => Ok(TaskSortKey::Due),
+        "created" => Ok(TaskSortKey::Created),
+        "updated" => Ok(TaskSortKey::Updated),
+        _ => anyhow::bail!("Unknown sort key in query: '{}'", key),
+    }
+}
+
+/// 日付の値を変換
+///
+/// `today`・`tomorrow`・`yesterday`、YYYY-MM-DD形式の日付、または今日からの相対日数
+/// （`+3d`、`-2w`など）を受け付けます。
+fn parse_date_value(value: &str, today: NaiveDate) -> Result<NaiveDate> {
+    match value.to_lowercase().as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return Ok(today + Duration::days(1)),
+        "yesterday" => return Ok(today - Duration::days(1)),
+        _ => {}
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date);
+    }
+
+    let invalid = || {
+        anyhow::anyhow!(
+            "Invalid date in query: '{}'. Use today, tomorrow, yesterday, YYYY-MM-DD or an offset such as -7d or +2w",
+            value
+        )
+    };
+    let (amount, unit) = value.split_at(value.len().saturating_sub(1));
+    if !amount.starts_with(['+', '-']) {
+        return Err(invalid());
+    }
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let offset = match unit {
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    today.checked_add_signed(offset).ok_or_else(invalid)
+}
+
+/// 日付の0時（ローカル時刻）をUTCの日時に変換
+fn start_of_day(date: NaiveDate) -> Result<DateTime<Utc>> {
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .map(|at| at.with_timezone(&Utc))
+        .ok_or_else(|| anyhow::anyhow!("Invalid local date in query: {}", date))
+}
+
+fn not(spec: Box<dyn TaskSpecification>) -> Box<dyn TaskSpecification> {
+    Box::new(NotSpecification::new(spec))
+}
+
+fn and(
+    left: Box<dyn TaskSpecification>,
+    right: Box<dyn TaskSpecification>,
+) -> Box<dyn TaskSpecification> {
+    Box::new(AndSpecification::new(left, right))
+}
+
+/// 条件式をSpecificationに変換
+struct QueryCompiler {
+    tags: Vec<TagAggregate>,
+    today: NaiveDate,
+}
+
+impl QueryCompiler {
+    fn compile(&self, expr: &QueryExpr) -> Result<Box<dyn TaskSpecification>> {
+        match expr {
+            QueryExpr::And(left, right) => Ok(and(self.compile(left)?, self.compile(right)?)),
+            QueryExpr::Or(left, right) => Ok(Box::new(OrSpecification::new(
+                self.compile(left)?,
+                self.compile(right)?,
+            ))),
+            QueryExpr::Not(inner) => Ok(not(self.compile(inner)?)),
+            QueryExpr::Compare { field, op, value } => self.compile_compare(*field, *op, value),
+        }
+    }
+
+    fn compile_compare(
+        &self,
+        field: QueryField,
+        op: CompareOp,
+        value: &str,
+    ) -> Result<Box<dyn TaskSpecification>> {
+        let unsupported = || {
+            anyhow::anyhow!(
+                "Operator '{}' is not supported for {:?}",
+                op.symbol(),
+                field
+            )
+        };
+        // 等号・不等号のみを受け付ける項目の共通処理
+        let equality = |spec: Box<dyn TaskSpecification>| match op {
+            CompareOp::Eq => Ok(spec),
+            CompareOp::Ne => Ok(not(spec)),
+            _ => Err(unsupported()),
+        };
+
+        match field {
+            QueryField::Id => equality(Box::new(TaskById::new(TaskId::new(
+                value
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Invalid task ID in query: {}", value))?,
+            )?))),
+            QueryField::Status => equality(Box::new(TaskByStatus::new(value.parse::<Status>()?))),
+            QueryField::Tag => match op {
+                CompareOp::Contains => tag_name_specification(&self.tags, value, true),
+                _ => equality(tag_name_specification(&self.tags, value, false)?),
+            },
+            QueryField::Title | QueryField::Description => {
+                if op != CompareOp::Contains {
+                    return Err(unsupported());
+                }
+                let search_field = if field == QueryField::Title {
+                    SearchField::Title
+                } else {
+                    SearchField::Description
+                };
+                Ok(Box::new(TaskByKeyword::new(
+                    vec![value.to_string()],
+                    search_field,
+                )))
+            }
+            QueryField::Priority => {
+                let priority: Priority = value.parse()?;
+                let matches = |candidate: &Priority| match op {
+                    CompareOp::Eq => *candidate == priority,
+                    CompareOp::Ne => *candidate != priority,
+                    CompareOp::Lt => *candidate < priority,
+                    CompareOp::Le => *candidate <= priority,
+                    CompareOp::Gt => *candidate > priority,
+                    CompareOp::Ge => *candidate >= priority,
+                    CompareOp::Contains => false,
+                };
+                if op == CompareOp::Contains {
+                    return Err(unsupported());
+                }
+                Priority::iter()
+                    .filter(matches)
+                    .map(|p| Box::new(TaskByPriority::new(p)) as Box<dyn TaskSpecification>)
+                    .reduce(|acc, spec| Box::new(OrSpecification::new(acc, spec)))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Condition 'priority {} {}' can never match",
+                            op.symbol(),
+                            value
+                        )
+                    })
+            }
+            QueryField::Due => self.compile_due(op, value).ok_or_else(unsupported)?,
+            QueryField::Created | QueryField::Updated => {
+                let since = |at: DateTime<Utc>| -> Box<dyn TaskSpecification> {
+                    if field == QueryField::Created {
+                        Box::new(TaskCreatedAfter::new(at))
+                    } else {
+                        Box::new(TaskUpdatedSince::new(at))
+                    }
+                };
+                let date = parse_date_value(value, self.today)?;
+                let start = start_of_day(date)?;
+                let next_day = start_of_day(date + Duration::days(1))?;
+                match op {
+                    CompareOp::Ge => Ok(since(start)),
+                    CompareOp::Gt => Ok(since(next_day)),
+                    CompareOp::Lt => Ok(not(since(start))),
+                    CompareOp::Le => Ok(not(since(next_day))),
+                    CompareOp::Eq => Ok(and(since(start), not(since(next_day)))),
+                    CompareOp::Ne => Ok(not(and(since(start), not(since(next_day))))),
+                    CompareOp::Contains => Err(unsupported()),
+                }
+            }
+        }
+    }
+
+    /// 期限日の条件を変換（`due = none`で期限日のないタスク）
+    ///
+    /// 対応していない演算子の場合は`None`を返します。
+    fn compile_due(
+        &self,
+        op: CompareOp,
+        value: &str,
+    ) -> Option<Result<Box<dyn TaskSpecification>>> {
+        if value.eq_ignore_ascii_case("none") {
+            let has_due_date = Box::new(TaskHasDueDate);
+            return match op {
+                CompareOp::Eq => Some(Ok(not(has_due_date))),
+                CompareOp::Ne => Some(Ok(has_due_date)),
+                _ => None,
+            };
+        }
+
+        let date = match parse_date_value(value, self.today) {
+            Ok(date) => date,
+            Err(e) => return Some(Err(e)),
+        };
+        let spec: Box<dyn TaskSpecification> = match op {
+            CompareOp::Lt => Box::new(TaskDueBefore::new(date)),
+            CompareOp::Le => Box::new(TaskDueBefore::new(date + Duration::days(1))),
+            CompareOp::Gt => Box::new(TaskDueAfter::new(date)),
+            CompareOp::Ge => Box::new(TaskDueAfter::new(date - Duration::days(1))),
+            CompareOp::Eq => Box::new(TaskDueBetween::new(date, date)),
+            CompareOp::Ne => not(Box::new(TaskDueBetween::new(date, date))),
+            CompareOp::Contains => return None,
+        };
+        Some(Ok(spec))
+    }
+}
+
+/// QueryTasksUseCase - クエリ言語によるタスク検索のユースケース
+///
+/// `status = pending AND (priority >= high OR due < today) ORDER BY due LIMIT 20`
+/// のようなクエリを解析し、Specificationと並び順に変換して検索します。
+pub struct QueryTasksUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl QueryTasksUseCase {
+    /// 新しいQueryTasksUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// `today`などの日付の基準に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// クエリに一致するタスクを取得する
+    ///
+    /// # Arguments
+    /// * `query` - クエリ文字列（空の場合は全タスク）
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskDTO>)` - 並び替えと件数の制限を適用したタスクのリスト
+    /// * `Err` - 構文エラーや存在しないタグを指定した場合など
+    #[instrument(name = "query_tasks", skip(self))]
+    pub async fn execute(&self, query: &str) -> Result<Vec<TaskDTO>> {
+        // 1. クエリを解析してSpecificationに変換
+        let query: TaskQuery = query.parse()?;
+        let mut tasks = match &query.filter {
+            Some(expr) => {
+                let compiler = QueryCompiler {
+                    tags: if expr.uses_tags() {
+                        self.tag_repository.find_all().await?
+                    } else {
+                        Vec::new()
+                    },
+                    today: self.clock.today(),
+                };
+                let spec = compiler.compile(expr)?;
+                self.task_repository.find_by_specification(spec).await?
+            }
+            None => self.task_repository.find_all().await?,
+        };
+
+        // 2. 並び替えて件数を制限
+        //    ASC/DESCは値の昇順・降順を表す（優先度・作成日時・更新日時の既定は降順）
+        let default_descending = matches!(
+            query.sort,
+            TaskSortKey::Priority | TaskSortKey::Created | TaskSortKey::Updated
+        );
+        let reverse = query
+            .descending
+            .is_some_and(|descending| descending != default_descending);
+        tasks.sort_by(|a, b| {
+            let ordering = query.sort.compare(a, b);
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        if let Some(limit) = query.limit {
+            tasks.truncate(limit);
+        }
+
+        // 3. タグ情報を一括取得してDTOに変換（N+1問題の回避）
+        let tag_ids: Vec<_> = tasks
+            .iter()
+            .flat_map(|task| task.tags().iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tag::aggregate::NewTag;
+    use crate::domain::{
+        tag::value_objects::{TagDescription, TagName},
+        task::{
+            builder::TaskBuilder,
+            value_objects::{DueDate, TaskTitle},
+        },
+    };
+    use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
+
+    fn compare(field: QueryField, op: CompareOp, value: &str) -> QueryExpr {
+        QueryExpr::Compare {
+            field,
+            op,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_precedence_order_and_limit() {
+        // Arrange
+        let query = "status = pending AND (priority >= high OR due < today) ORDER BY due LIMIT 20";
+
+        // Act
+        let parsed: TaskQuery = query.parse().unwrap();
+
+        // Assert: ANDはORより優先され、括弧でまとめた部分が右辺になる
+        assert_eq!(
+            parsed,
+            TaskQuery {
+                filter: Some(QueryExpr::And(
+                    Box::new(compare(QueryField::Status, CompareOp::Eq, "pending")),
+                    Box::new(QueryExpr::Or(
+                        Box::new(compare(QueryField::Priority, CompareOp::Ge, "high")),
+                        Box::new(compare(QueryField::Due, CompareOp::Lt, "today")),
+                    )),
+                )),
+                sort: TaskSortKey::Due,
+                descending: None,
+                limit: Some(20),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_not_quotes_and_lowercase_keywords() {
+        // Act
+        let parsed: TaskQuery =
+            "not tag = 'home office' or title ~ \"月次 レポート\" order by priority desc"
+                .parse()
+                .unwrap();
+
+        // Assert
+        assert_eq!(
+            parsed.filter,
+            Some(QueryExpr::Or(
+                Box::new(QueryExpr::Not(Box::new(compare(
+                    QueryField::Tag,
+                    CompareOp::Eq,
+                    "home office"
+                )))),
+                Box::new(compare(
+                    QueryField::Title,
+                    CompareOp::Contains,
+                    "月次 レポート"
+                )),
+            ))
+        );
+        assert_eq!(parsed.sort, TaskSortKey::Priority);
+        assert_eq!(parsed.descending, Some(true));
+    }
+
+    #[test]
+    fn test_parse_order_without_filter() {
+        // Act
+        let parsed: TaskQuery = "ORDER BY created LIMIT 5".parse().unwrap();
+
+        // Assert
+        assert_eq!(parsed.filter, None);
+        assert_eq!(parsed.sort, TaskSortKey::Created);
+        assert_eq!(parsed.limit, Some(5));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for query in [
+            "status",
+            "status =",
+            "color = red",
+            "(status = pending",
+            "status = pending AND",
+            "status = 'pending",
+            "status = pending LIMIT -1",
+            "status = pending ORDER due",
+            "status = pending extra",
+            "ORDER BY size",
+        ] {
+            assert!(query.parse::<TaskQuery>().is_err(), "{query}");
+        }
+    }
+
+    #[test]
+    fn test_parse_date_value() {
+        // Arrange
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+
+        // Act & Assert
+        assert_eq!(parse_date_value("today", today).unwrap(), today);
+        assert_eq!(parse_date_value("Tomorrow", today).unwrap(), date(10, 19));
+        assert_eq!(parse_date_value("-7d", today).unwrap(), date(10, 11));
+        assert_eq!(parse_date_value("+2w", today).unwrap(), date(11, 1));
+        assert_eq!(parse_date_value("2026-12-24", today).unwrap(), date(12, 24));
+        assert!(parse_date_value("7d", today).is_err());
+        assert!(parse_date_value("next week", today).is_err());
+    }
+
+    async fn setup() -> QueryTasksUseCase {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let work = tag_repo
+            .save(NewTag::new(
+                TagName::new("work").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        let today = Local::now().date_naive();
+        for (title, status, priority, due_in, tags) in [
+            ("期限切れ", Status::Pending, Priority::Low, Some(-1), vec![]),
+            (
+                "重要",
+                Status::Pending,
+                Priority::Critical,
+                None,
+                vec![*work.id()],
+            ),
+            (
+                "来週",
+                Status::Pending,
+                Priority::Medium,
+                Some(7),
+                vec![*work.id()],
+            ),
+            (
+                "完了済み",
+                Status::Completed,
+                Priority::High,
+                Some(-3),
+                vec![],
+            ),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .priority(priority)
+                .tags(tags)
+                .due_date(due_in.map(|days| DueDate::new(today + Duration::days(days)).unwrap()))
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        QueryTasksUseCase::new(task_repo, tag_repo)
+    }
+
+    async fn titles(use_case: &QueryTasksUseCase, query: &str) -> Vec<String> {
+        use_case
+            .execute(query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_execute_filters_sorts_and_limits() {
+        // Arrange
+        let use_case = setup().await;
+
+        // Act & Assert
+        assert_eq!(
+            titles(
+                &use_case,
+                "status = pending AND (priority >= high OR due < today) ORDER BY due"
+            )
+            .await,
+            vec!["期限切れ", "重要"]
+        );
+        assert_eq!(
+            titles(&use_case, "tag = work ORDER BY priority LIMIT 1").await,
+            vec!["重要"]
+        );
+        assert_eq!(
+            titles(&use_case, "due = none OR due >= +7d ORDER BY id DESC").await,
+            vec!["来週", "重要"]
+        );
+        assert_eq!(
+            titles(&use_case, "NOT status = done AND title ~ 期限").await,
+            vec!["期限切れ"]
+        );
+        assert_eq!(titles(&use_case, "created >= today").await.len(), 4);
+        assert!(titles(&use_case, "updated < today").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_reports_semantic_errors() {
+        // Arrange
+        let use_case = setup().await;
+
+        // Act & Assert
+        for query in [
+            "tag = missing",
+            "status < pending",
+            "priority > critical",
+            "title = foo",
+            "id = abc",
+        ] {
+            assert!(use_case.execute(query).await.is_err(), "{query}");
+        }
+    }
+}
@@ -0,0 +1,553 @@
+use crate::{
+    application::dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        services::BusinessCalendar,
+        tag::repository::TagRepository,
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            value_objects::{DueDate, Status},
+        },
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory, commit_or_rollback},
+    },
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate};
+use std::{collections::HashMap, sync::Arc};
+use tracing::instrument;
+
+/// 期限切れのタスクの通知先（デスクトップ通知など）
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// タスクが期限切れになったことを通知する
+    async fn notify_overdue(&self, task: &TaskDTO) -> Result<()>;
+}
+
+/// 自動アーカイブしたタスクの保存先
+#[async_trait]
+pub trait TaskArchive: Send + Sync {
+    /// タスクを保存する（保存に失敗した場合、タスクは削除されない）
+    async fn store(&self, tasks: &[TaskDTO]) -> Result<()>;
+}
+
+/// 繰り返しのタスクから作成した次の回のタスク
+#[derive(Debug, Clone)]
+pub struct GeneratedTask {
+    /// 元にした完了済みのタスクのID
+    pub source_id: i32,
+    pub task: TaskDTO,
+}
+
+/// 定期実行の結果
+#[derive(Debug, Default)]
+pub struct ScheduledJobsReport {
+    /// 繰り返しルールから作成したタスク
+    pub generated: Vec<GeneratedTask>,
+    /// 期限切れを通知したタスク
+    pub notified: Vec<TaskDTO>,
+    /// アーカイブして削除したタスク
+    pub archived: Vec<TaskDTO>,
+    /// 実行できなかった処理
+    pub warnings: Vec<String>,
+}
+
+/// RunScheduledJobsUseCase - 定期実行（cron）のユースケース
+///
+/// 次の処理を順に行います。
+/// 1. 完了済みの繰り返しのタスクから、期限日が近づいた次の回のタスクを作成する
+/// 2. 前回の実行以降に期限切れになったタスクを通知する
+/// 3. 完了してから一定の日数が経ったタスクをアーカイブに保存して削除する
+///
+/// 営業日のカレンダーを設定した場合、次の回の期限日が休日なら次の営業日にずらし、
+/// 期限切れは期限日の翌営業日から扱います。
+///
+/// 作成とアーカイブはそれぞれ1つのトランザクション（UnitOfWork）内で行います。
+/// 返すTaskDTOのタグ情報は解決しません（アーカイブしたタスクのみ解決します）。
+pub struct RunScheduledJobsUseCase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    recurrence_lead_days: u32,
+    archive_after_days: Option<u32>,
+    business_calendar: Option<BusinessCalendar>,
+    clock: Arc<dyn Clock>,
+}
+
+impl RunScheduledJobsUseCase {
+    /// 新しいRunScheduledJobsUseCaseを作成
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self {
+            unit_of_work,
+            recurrence_lead_days: 0,
+            archive_after_days: None,
+            business_calendar: None,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// 更新日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 営業日のカレンダーを設定する（Noneの場合は休日を考慮しない）
+    pub fn with_business_calendar(mut self, calendar: Option<BusinessCalendar>) -> Self {
+        self.business_calendar = calendar;
+        self
+    }
+
+    /// 次の回のタスクを期限日の何日前に作成するかを設定する
+    pub fn with_recurrence_lead_days(mut self, days: u32) -> Self {
+        self.recurrence_lead_days = days;
+        self
+    }
+
+    /// 完了してから何日経ったタスクをアーカイブするかを設定する（Noneの場合はアーカイブしない）
+    pub fn with_archive_after_days(mut self, days: Option<u32>) -> Self {
+        self.archive_after_days = days;
+        self
+    }
+
+    /// 定期実行の処理を行う
+    ///
+    /// # Arguments
+    /// * `today` - 今日の日付
+    /// * `last_run` - 前回の実行日（初回はNone。期限切れの通知を1度だけにするために使う）
+    /// * `notifier` - 期限切れの通知先（Noneの場合は通知しない）
+    /// * `archive` - アーカイブの保存先
+    ///
+    /// 個々のタスクの通知に失敗した場合は`warnings`に記録して続行します。
+    #[instrument(name = "cron.run", skip(self, notifier, archive))]
+    pub async fn run(
+        &self,
+        today: NaiveDate,
+        last_run: Option<NaiveDate>,
+        notifier: Option<&dyn Notifier>,
+        archive: &dyn TaskArchive,
+    ) -> Result<ScheduledJobsReport> {
+        let mut report = ScheduledJobsReport::default();
+
+        let uow = self.unit_of_work.begin().await?;
+        let result = ScheduledJobsTransaction::new(uow.as_ref())
+            .generate_recurring(
+                today,
+                self.recurrence_lead_days,
+                self.business_calendar.as_ref(),
+                self.clock.as_ref(),
+            )
+            .await;
+        report.generated = commit_or_rollback(uow, result).await?;
+
+        if let Some(notifier) = notifier {
+            let uow = self.unit_of_work.begin().await?;
+            let result = ScheduledJobsTransaction::new(uow.as_ref())
+                .newly_overdue(today, last_run, self.business_calendar.as_ref())
+                .await;
+            for task in commit_or_rollback(uow, result).await? {
+                match notifier.notify_overdue(&task).await {
+                    Ok(()) => report.notified.push(task),
+                    Err(e) => report
+                        .warnings
+                        .push(format!("Failed to notify task {}: {:#}", task.id, e)),
+                }
+            }
+        }
+
+        if let Some(days) = self.archive_after_days {
+            let uow = self.unit_of_work.begin().await?;
+            let result = ScheduledJobsTransaction::new(uow.as_ref())
+                .archive_completed(today, days, archive)
+                .await;
+            report.archived = commit_or_rollback(uow, result).await?;
+        }
+
+        tracing::info!(
+            generated = report.generated.len(),
+            notified = report.notified.len(),
+            archived = report.archived.len(),
+            "scheduled jobs finished"
+        );
+        Ok(report)
+    }
+}
+
+/// 1つのトランザクション内で定期実行の処理を行う
+///
+/// リポジトリはUnitOfWorkから取得したものを使用し、処理の終了時に破棄されます。
+struct ScheduledJobsTransaction {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+impl ScheduledJobsTransaction {
+    fn new(uow: &dyn UnitOfWork) -> Self {
+        Self {
+            task_repository: uow.task_repository(),
+            tag_repository: uow.tag_repository(),
+        }
+    }
+
+    /// 完了済みの繰り返しのタスクから、期限日が`today + lead_days`以前になる次の回のタスクを作成する
+    ///
+    /// `calendar`を指定した場合、休日になる期限日は次の営業日にずらします。
+    async fn generate_recurring(
+        &self,
+        today: NaiveDate,
+        lead_days: u32,
+        calendar: Option<&BusinessCalendar>,
+        clock: &dyn Clock,
+    ) -> Result<Vec<GeneratedTask>> {
+        let horizon = today + Days::new(lead_days.into());
+        let mut generated = Vec::new();
+        for mut task in self.task_repository.find_all().await? {
+            let Some(mut next_due) = task.next_occurrence_date(today)? else {
+                continue;
+            };
+            if let Some(calendar) = calendar {
+                next_due = calendar.roll_forward(next_due)?;
+            }
+            if next_due > horizon {
+                continue;
+            }
+            let next = task.spawn_next_occurrence(DueDate::new(next_due)?, clock)?;
+            let source_id = task.id().value();
+            self.task_repository.update(task).await?;
+            let saved = self.task_repository.save(next).await?;
+            tracing::debug!(source_id, task_id = saved.id().value(), due_date = %next_due, "generated recurring task");
+            generated.push(GeneratedTask {
+                source_id,
+                task: TaskDTO::from(saved),
+            });
+        }
+        Ok(generated)
+    }
+
+    /// 前回の実行以降に期限切れになった未完了のタスク
+    ///
+    /// 今日の時点で期限切れで、前回の実行時点ではまだ期限切れでなかったものです。
+    /// `calendar`を指定した場合は、期限日の翌営業日から期限切れとして扱います。
+    async fn newly_overdue(
+        &self,
+        today: NaiveDate,
+        last_run: Option<NaiveDate>,
+        calendar: Option<&BusinessCalendar>,
+    ) -> Result<Vec<TaskDTO>> {
+        let is_overdue_on = |due_date: &DueDate, date: NaiveDate| match calendar {
+            Some(calendar) => calendar.is_overdue(due_date.value(), date),
+            None => due_date.is_before(date),
+        };
+        let mut tasks: Vec<TaskAggregate> = self
+            .task_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|task| *task.status() != Status::Completed)
+            .filter(|task| {
+                task.due_date().is_some_and(|due_date| {
+                    is_overdue_on(&due_date, today)
+                        && last_run.is_none_or(|last_run| !is_overdue_on(&due_date, last_run))
+                })
+            })
+            .collect();
+        tasks.sort_by_key(|task| (*task.due_date(), task.id().value()));
+        Ok(tasks.into_iter().map(TaskDTO::from).collect())
+    }
+
+    /// 完了してから`days`日以上経ったタスクをアーカイブに保存して削除する
+    ///
+    /// 次の回をまだ作成していない繰り返しのタスクは、ルールが失われないよう対象外にします。
+    async fn archive_completed(
+        &self,
+        today: NaiveDate,
+        days: u32,
+        archive: &dyn TaskArchive,
+    ) -> Result<Vec<TaskDTO>> {
+        let Some(cutoff) = today.checked_sub_days(Days::new(days.into())) else {
+            return Ok(vec![]);
+        };
+        let tasks: Vec<TaskAggregate> = self
+            .task_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|task| {
+                *task.status() == Status::Completed
+                    && task.recurrence().is_none()
+                    && task
+                        .completed_at()
+                        .is_some_and(|completed_at| completed_at.date_naive() <= cutoff)
+            })
+            .collect();
+        if tasks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let tags = self.tag_repository.find_all().await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+        let ids: Vec<_> = tasks.iter().map(|task| *task.id()).collect();
+        let archived: Vec<TaskDTO> = tasks
+            .into_iter()
+            .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
+            .collect();
+
+        archive.store(&archived).await?;
+        for id in &ids {
+            self.task_repository.delete(id).await?;
+        }
+        Ok(archived)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::{aggregate::NewTask, builder::TaskBuilder};
+    use crate::{
+        domain::task::value_objects::TaskTitle,
+        interface::persistence::in_memory::{
+            InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory,
+        },
+    };
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        notified: Mutex<Vec<i32>>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        async fn notify_overdue(&self, task: &TaskDTO) -> Result<()> {
+            self.notified.lock().unwrap().push(task.id);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingArchive {
+        stored: Mutex<Vec<i32>>,
+    }
+
+    #[async_trait]
+    impl TaskArchive for RecordingArchive {
+        async fn store(&self, tasks: &[TaskDTO]) -> Result<()> {
+            self.stored
+                .lock()
+                .unwrap()
+                .extend(tasks.iter().map(|task| task.id));
+            Ok(())
+        }
+    }
+
+    struct FailingArchive;
+
+    #[async_trait]
+    impl TaskArchive for FailingArchive {
+        async fn store(&self, _tasks: &[TaskDTO]) -> Result<()> {
+            anyhow::bail!("disk full")
+        }
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).unwrap()
+    }
+
+    fn task(title: &str, due: Option<NaiveDate>) -> NewTask {
+        new_task(title, Status::Pending, due)
+    }
+
+    fn completed(title: &str, due: Option<NaiveDate>) -> NewTask {
+        new_task(title, Status::Completed, due)
+    }
+
+    fn new_task(title: &str, status: Status, due: Option<NaiveDate>) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .status(status)
+            .due_date(due.map(|due| DueDate::new(due).unwrap()))
+            .build()
+    }
+
+    fn setup() -> (Arc<InMemoryTaskRepository>, RunScheduledJobsUseCase) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = RunScheduledJobsUseCase::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo,
+        )));
+        (task_repo, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_run_generates_next_occurrence_within_lead_days() {
+        // Arrange
+        let (task_repo, use_case) = setup();
+        let mut weekly = completed("週次レビュー", Some(date(12)));
+        weekly.set_recurrence(Some("weekly".parse().unwrap()));
+        let weekly = task_repo.save(weekly).await.unwrap();
+        let mut monthly = completed("請求書", Some(date(1)));
+        monthly.set_recurrence(Some("monthly".parse().unwrap()));
+        task_repo.save(monthly).await.unwrap();
+
+        // Act: 次の回は10/19と11/1。3日前から作成する
+        let report = use_case
+            .with_recurrence_lead_days(3)
+            .run(date(17), None, None, &RecordingArchive::default())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(report.generated.len(), 1);
+        let generated = &report.generated[0];
+        assert_eq!(generated.source_id, weekly.id().value());
+        assert_eq!(generated.task.due_date, Some(date(19)));
+        assert_eq!(generated.task.status, Status::Pending);
+        assert_eq!(generated.task.recurrence.as_deref(), Some("weekly"));
+        let source = task_repo.find_by_id(weekly.id()).await.unwrap().unwrap();
+        assert!(source.recurrence().is_none());
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_notifies_only_newly_overdue_tasks() {
+        // Arrange
+        let (task_repo, use_case) = setup();
+        let old = task_repo
+            .save(task("前から期限切れ", Some(date(10))))
+            .await
+            .unwrap();
+        let new = task_repo
+            .save(task("昨日が期限", Some(date(16))))
+            .await
+            .unwrap();
+        task_repo
+            .save(task("今日が期限", Some(date(17))))
+            .await
+            .unwrap();
+        task_repo
+            .save(completed("完了済み", Some(date(16))))
+            .await
+            .unwrap();
+        let notifier = RecordingNotifier::default();
+        let archive = RecordingArchive::default();
+
+        // Act
+        let first = use_case
+            .run(date(17), None, Some(&notifier), &archive)
+            .await
+            .unwrap();
+        let second = use_case
+            .run(date(17), Some(date(12)), Some(&notifier), &archive)
+            .await
+            .unwrap();
+
+        // Assert: 初回はすべての期限切れ、2回目は前回の実行以降に期限切れになったもののみ
+        let ids = |tasks: &[TaskDTO]| tasks.iter().map(|task| task.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(&first.notified),
+            vec![old.id().value(), new.id().value()]
+        );
+        assert_eq!(ids(&second.notified), vec![new.id().value()]);
+        assert_eq!(notifier.notified.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_moves_recurring_due_date_to_business_day() {
+        // Arrange: 10/16は金曜日。毎日の繰り返しの次の回（10/17）は土曜日になる
+        let (task_repo, use_case) = setup();
+        let mut daily = completed("日報", Some(date(16)));
+        daily.set_recurrence(Some("daily".parse().unwrap()));
+        task_repo.save(daily).await.unwrap();
+
+        // Act
+        let report = use_case
+            .with_recurrence_lead_days(3)
+            .with_business_calendar(Some(BusinessCalendar::default()))
+            .run(date(16), None, None, &RecordingArchive::default())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(report.generated.len(), 1);
+        assert_eq!(report.generated[0].task.due_date, Some(date(19)));
+    }
+
+    #[tokio::test]
+    async fn test_run_notifies_overdue_from_next_business_day() {
+        // Arrange: 金曜日（10/16）が期限のタスク
+        let (task_repo, use_case) = setup();
+        let friday = task_repo
+            .save(task("金曜日が期限", Some(date(16))))
+            .await
+            .unwrap();
+        let use_case = use_case.with_business_calendar(Some(BusinessCalendar::default()));
+        let notifier = RecordingNotifier::default();
+        let archive = RecordingArchive::default();
+
+        // Act: 土曜日の時点では期限切れにならず、月曜日の実行で通知する
+        let saturday = use_case
+            .run(date(17), None, Some(&notifier), &archive)
+            .await
+            .unwrap();
+        let monday = use_case
+            .run(date(19), Some(date(17)), Some(&notifier), &archive)
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(saturday.notified.is_empty());
+        assert_eq!(monday.notified.len(), 1);
+        assert_eq!(monday.notified[0].id, friday.id().value());
+    }
+
+    #[tokio::test]
+    async fn test_run_archives_completed_tasks() {
+        // Arrange
+        let (task_repo, use_case) = setup();
+        let done = task_repo.save(completed("完了済み", None)).await.unwrap();
+        task_repo.save(task("未完了", None)).await.unwrap();
+        let today = chrono::Utc::now().date_naive();
+        // 次の回をまだ作成していない繰り返しのタスクはアーカイブしない
+        let mut yearly = completed("年次の更新", Some(today));
+        yearly.set_recurrence(Some("yearly".parse().unwrap()));
+        task_repo.save(yearly).await.unwrap();
+        let archive = RecordingArchive::default();
+
+        // Act: 完了した日からの日数が足りない場合はアーカイブしない
+        let use_case = use_case.with_archive_after_days(Some(0));
+        let report = use_case
+            .run(today - Days::new(1), None, None, &archive)
+            .await
+            .unwrap();
+        assert!(report.archived.is_empty());
+
+        let report = use_case
+            .run(today + Days::new(1), None, None, &archive)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(report.archived.len(), 1);
+        assert_eq!(*archive.stored.lock().unwrap(), vec![done.id().value()]);
+        assert!(task_repo.find_by_id(done.id()).await.unwrap().is_none());
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_run_keeps_tasks_when_archive_fails() {
+        // Arrange
+        let (task_repo, use_case) = setup();
+        task_repo.save(completed("完了済み", None)).await.unwrap();
+        let tomorrow = chrono::Utc::now().date_naive() + Days::new(1);
+
+        // Act
+        let result = use_case
+            .with_archive_after_days(Some(0))
+            .run(tomorrow, None, None, &FailingArchive)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 1);
+    }
+}
@@ -1,18 +1,111 @@
 use crate::{
     application::dto::task_dto::TaskDTO,
     domain::{
-        tag::repository::TagRepository,
+        tag::{hierarchy::TagHierarchy, repository::TagRepository, value_objects::TagId},
         task::{
             repository::TaskRepository,
-            specification::{SearchField, TaskByKeyword},
+            specification::{
+                AndSpecification, NotSpecification, OrSpecification, SearchField,
+                TaskByCustomField, TaskByKeyword, TaskByPriority, TaskByStatus, TaskByTag,
+                TaskCreatedAfter, TaskDueAfter, TaskDueBefore, TaskSpecification, TaskUpdatedSince,
+            },
+            value_objects::{CustomField, Priority, Status},
         },
     },
 };
 use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
 use std::{
+    cmp::Reverse,
     collections::{HashMap, HashSet},
+    ops::Range,
     sync::Arc,
 };
+use tracing::instrument;
+
+/// キーワードと組み合わせる属性の絞り込み条件
+///
+/// 指定された条件はすべてAND条件で結合されます。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilter {
+    /// ステータス
+    pub status: Option<Status>,
+    /// 優先度
+    pub priority: Option<Priority>,
+    /// 付いている必要があるタグ（すべて、子孫のタグでも可）
+    pub tag_ids: Vec<TagId>,
+    /// 期限日がこの日付より前
+    pub due_before: Option<NaiveDate>,
+    /// 期限日がこの日付より後
+    pub due_after: Option<NaiveDate>,
+    /// この日時以降に作成された
+    pub created_after: Option<DateTime<Utc>>,
+    /// この日時以降に更新された
+    pub updated_since: Option<DateTime<Utc>>,
+    /// 値が一致する必要があるカスタムフィールド（すべて）
+    pub custom_fields: Vec<CustomField>,
+}
+
+impl SearchFilter {
+    /// 絞り込み条件が1つも指定されていないか
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 絞り込み条件をSpecificationのリストに変換
+    ///
+    /// タグの条件は、そのタグか子孫のタグのいずれかが付いていれば満たします。
+    fn to_specifications(&self, hierarchy: &TagHierarchy) -> Vec<Box<dyn TaskSpecification>> {
+        let mut specs: Vec<Box<dyn TaskSpecification>> = Vec::new();
+        if let Some(status) = self.status {
+            specs.push(Box::new(TaskByStatus::new(status)));
+        }
+        if let Some(priority) = self.priority {
+            specs.push(Box::new(TaskByPriority::new(priority)));
+        }
+        for tag_id in &self.tag_ids {
+            let spec = hierarchy
+                .self_and_descendants(tag_id)
+                .into_iter()
+                .map(|id| Box::new(TaskByTag::new(id)) as Box<dyn TaskSpecification>)
+                .reduce(|acc, spec| Box::new(OrSpecification::new(acc, spec)));
+            if let Some(spec) = spec {
+                specs.push(spec);
+            }
+        }
+        if let Some(date) = self.due_before {
+            specs.push(Box::new(TaskDueBefore::new(date)));
+        }
+        if let Some(date) = self.due_after {
+            specs.push(Box::new(TaskDueAfter::new(date)));
+        }
+        if let Some(at) = self.created_after {
+            specs.push(Box::new(TaskCreatedAfter::new(at)));
+        }
+        if let Some(at) = self.updated_since {
+            specs.push(Box::new(TaskUpdatedSince::new(at)));
+        }
+        for field in &self.custom_fields {
+            specs.push(Box::new(TaskByCustomField::new(field.clone())));
+        }
+        specs
+    }
+}
+
+/// 関連度でタイトルの一致に掛ける重み（説明の一致より上位にする）
+const TITLE_WEIGHT: u32 = 3;
+
+/// 検索結果の並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchSort {
+    /// 関連度の高い順（タイトルでの一致・検索語が近くにあるものを上位にする）
+    #[default]
+    Relevance,
+    /// 期限日の近い順（期限日なしは最後、同じ期限日は関連度の高い順）
+    Due,
+    /// 優先度の高い順（同じ優先度は関連度の高い順）
+    Priority,
+}
 
 /// SearchTasksUseCase - タスク検索のユースケース
 ///
@@ -21,6 +114,7 @@ use std::{
 pub struct SearchTasksUseCase {
     task_repository: Arc<dyn TaskRepository>,
     tag_repository: Arc<dyn TagRepository>,
+    sort: SearchSort,
 }
 
 impl SearchTasksUseCase {
@@ -32,22 +126,55 @@ impl SearchTasksUseCase {
         Self {
             task_repository,
             tag_repository,
+            sort: SearchSort::default(),
         }
     }
 
+    /// 検索結果の並び順を指定する（既定は関連度の高い順）
+    pub fn with_sort(mut self, sort: SearchSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
     /// タスクを検索する
     ///
+    /// キーワードは次の構文で指定できます。
+    /// - `foo bar`: 空白区切りのキーワードをすべて含む（AND条件）
+    /// - `foo OR bar`: いずれかの条件を満たす（`OR`は大文字のみ）
+    /// - `"monthly report"`: 引用符で囲んだフレーズをそのまま含む
+    /// - `-foo`: キーワードを含まない
+    ///
+    /// 絞り込み条件が指定されている場合は、キーワードの条件とAND結合します。
+    /// 結果は[`with_sort`](Self::with_sort)で指定した順に並べます（関連度が同じ場合はIDの順）。
+    ///
     /// # Arguments
-    /// * `keywords` - 検索キーワード（空白区切りの文字列）
+    /// * `keywords` - 検索クエリ（空の場合は絞り込み条件のみで検索）
     /// * `field` - 検索対象フィールド
+    /// * `filter` - ステータス・優先度・タグ・期限日による絞り込み条件
     ///
     /// # Returns
     /// * `Ok(Vec<TaskDTO>)` - 検索結果のタスクリスト
     /// * `Err` - エラーが発生した場合
-    pub async fn execute(&self, keywords: &str, field: SearchField) -> Result<Vec<TaskDTO>> {
-        // 1. キーワードを分割してSpecificationを作成
-        let keyword_vec: Vec<String> = keywords.split_whitespace().map(|s| s.to_string()).collect();
-        let spec = Box::new(TaskByKeyword::new(keyword_vec, field));
+    #[instrument(name = "search_tasks", skip(self))]
+    pub async fn execute(
+        &self,
+        keywords: &str,
+        field: SearchField,
+        filter: &SearchFilter,
+    ) -> Result<Vec<TaskDTO>> {
+        // 1. 検索クエリを解析し、絞り込み条件とAND結合したSpecificationを作成
+        // タグで絞り込む場合のみ、子孫のタグを解決するために全タグを取得する
+        let hierarchy = if filter.tag_ids.is_empty() {
+            TagHierarchy::default()
+        } else {
+            TagHierarchy::new(&self.tag_repository.find_all().await?)
+        };
+        let spec = filter
+            .to_specifications(&hierarchy)
+            .into_iter()
+            .fold(parse_query(keywords, field), |left, right| {
+                Box::new(AndSpecification::new(left, right))
+            });
 
         // 2. Specificationに基づいてタスクを検索
         let tasks = self.task_repository.find_by_specification(spec).await?;
@@ -71,20 +198,262 @@ impl SearchTasksUseCase {
             .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
             .collect();
 
-        Ok(task_dtos)
+        // 7. 関連度を求めて並べ替え
+        Ok(rank(
+            task_dtos,
+            &highlight_terms(keywords),
+            field,
+            self.sort,
+        ))
+    }
+}
+
+/// 検索結果を指定された順に並べ替える（並び順が同じタスクは元の順序を保つ）
+fn rank(
+    tasks: Vec<TaskDTO>,
+    terms: &[String],
+    field: SearchField,
+    sort: SearchSort,
+) -> Vec<TaskDTO> {
+    let mut scored: Vec<(u32, TaskDTO)> = tasks
+        .into_iter()
+        .map(|task| (relevance(&task, terms, field), task))
+        .collect();
+    match sort {
+        SearchSort::Relevance => scored.sort_by_key(|(score, _)| Reverse(*score)),
+        SearchSort::Due => scored
+            .sort_by_key(|(score, task)| (task.due_date.is_none(), task.due_date, Reverse(*score))),
+        SearchSort::Priority => {
+            scored.sort_by_key(|(score, task)| (Reverse(task.priority), Reverse(*score)))
+        }
+    }
+    scored.into_iter().map(|(_, task)| task).collect()
+}
+
+/// タスクの関連度（タイトルでの一致は説明での一致の`TITLE_WEIGHT`倍）
+fn relevance(task: &TaskDTO, terms: &[String], field: SearchField) -> u32 {
+    let title = match field {
+        SearchField::Title | SearchField::All => text_score(&task.title, terms),
+        SearchField::Description => 0,
+    };
+    let description = match (field, &task.description) {
+        (SearchField::Description | SearchField::All, Some(description)) => {
+            text_score(description, terms)
+        }
+        _ => 0,
+    };
+    title * TITLE_WEIGHT + description
+}
+
+/// 1つのテキストでの検索語の一致の度合い
+///
+/// 含む検索語の数と出現回数（検索語ごとに3回まで）を数え、
+/// すべての検索語を含む場合は検索語どうしが近いほど（フレーズに近いほど）加点します。
+fn text_score(text: &str, terms: &[String]) -> u32 {
+    let chars: Vec<char> = text.chars().collect();
+    let occurrences: Vec<(usize, Vec<usize>)> = terms
+        .iter()
+        .map(|term| (term.chars().count(), term_positions(&chars, term)))
+        .collect();
+    let matched = occurrences
+        .iter()
+        .filter(|(_, positions)| !positions.is_empty())
+        .count() as u32;
+    if matched == 0 {
+        return 0;
+    }
+
+    let frequency: u32 = occurrences
+        .iter()
+        .map(|(_, positions)| positions.len().min(3) as u32)
+        .sum();
+    let proximity = match shortest_gap(&occurrences) {
+        Some(gap) if occurrences.len() >= 2 => 200 / (10 + gap as u32),
+        _ => 0,
+    };
+    matched * 10 + frequency + proximity
+}
+
+/// すべての検索語を1回ずつ含む最短の範囲で、検索語の間にある文字数（含まない検索語がある場合はNone）
+fn shortest_gap(occurrences: &[(usize, Vec<usize>)]) -> Option<usize> {
+    let total_len: usize = occurrences.iter().map(|(len, _)| len).sum();
+    occurrences
+        .iter()
+        .flat_map(|(len, positions)| positions.iter().map(move |&start| (start, start + len)))
+        .filter_map(|(start, first_end)| {
+            // 範囲の先頭から後ろで、各検索語の最初の出現までを範囲とする
+            occurrences
+                .iter()
+                .map(|(len, positions)| {
+                    positions
+                        .iter()
+                        .find(|&&position| position >= start)
+                        .map(|position| position + len)
+                })
+                .try_fold(first_end, |end, next| next.map(|next| end.max(next)))
+                .map(|end| (end - start).saturating_sub(total_len))
+        })
+        .min()
+}
+
+/// テキストで検索語が出現する位置（文字単位、大文字小文字を無視）
+fn term_positions(chars: &[char], term: &str) -> Vec<usize> {
+    let term: Vec<char> = term.chars().collect();
+    if term.is_empty() || term.len() > chars.len() {
+        return vec![];
     }
+    (0..=chars.len() - term.len())
+        .filter(|&start| {
+            chars[start..start + term.len()]
+                .iter()
+                .zip(&term)
+                .all(|(c, t)| c.to_lowercase().eq(t.to_lowercase()))
+        })
+        .collect()
+}
+
+/// 強調表示する検索語（除外語以外の検索語を小文字にしたもの）
+pub fn highlight_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = tokenize(query)
+        .into_iter()
+        .filter_map(|token| match token {
+            QueryToken::Term {
+                text,
+                negated: false,
+            } => Some(text.trim().to_lowercase()),
+            _ => None,
+        })
+        .filter(|term| !term.is_empty())
+        .collect();
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+/// テキストで検索語に一致する範囲（バイト単位、重なる範囲はまとめる）
+pub fn match_ranges(text: &str, terms: &[String]) -> Vec<Range<usize>> {
+    let indices: Vec<(usize, char)> = text.char_indices().collect();
+    let chars: Vec<char> = indices.iter().map(|(_, c)| *c).collect();
+    let byte_at = |index: usize| indices.get(index).map_or(text.len(), |(byte, _)| *byte);
+
+    let mut ranges: Vec<Range<usize>> = terms
+        .iter()
+        .flat_map(|term| {
+            let len = term.chars().count();
+            term_positions(&chars, term)
+                .into_iter()
+                .map(move |start| (start, start + len))
+        })
+        .map(|(start, end)| byte_at(start)..byte_at(end))
+        .collect();
+    ranges.sort_by_key(|range| (range.start, range.end));
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// 検索クエリの字句
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum QueryToken {
+    /// 検索語（`negated`がtrueの場合は除外）
+    Term { text: String, negated: bool },
+    /// OR演算子
+    Or,
+}
+
+/// 検索クエリを字句に分割
+///
+/// 引用符で囲まれた部分は空白を含む1つの検索語として扱います。
+/// 閉じ引用符がない場合は末尾までをフレーズとみなします。
+fn tokenize(query: &str) -> Vec<QueryToken> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let negated = c == '-';
+        if negated {
+            chars.next();
+        }
+
+        let (text, quoted) = if chars.peek() == Some(&'"') {
+            chars.next();
+            let phrase: String = chars.by_ref().take_while(|&c| c != '"').collect();
+            (phrase, true)
+        } else {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            (word, false)
+        };
+
+        if !negated && !quoted && text == "OR" {
+            tokens.push(QueryToken::Or);
+        } else if !text.trim().is_empty() {
+            tokens.push(QueryToken::Term { text, negated });
+        }
+    }
+
+    tokens
+}
+
+/// 検索クエリをSpecificationの木に変換
+///
+/// `OR`で区切られたグループのいずれかを満たし、各グループ内ではすべての検索語を満たす
+/// （除外語は含まない）タスクにマッチします。検索語がない場合はすべてのタスクにマッチします。
+fn parse_query(query: &str, field: SearchField) -> Box<dyn TaskSpecification> {
+    let tokens = tokenize(query);
+
+    let groups = tokens
+        .split(|token| *token == QueryToken::Or)
+        .filter_map(|group| {
+            group
+                .iter()
+                .filter_map(|token| match token {
+                    QueryToken::Term { text, negated } => {
+                        let keyword: Box<dyn TaskSpecification> =
+                            Box::new(TaskByKeyword::new(vec![text.clone()], field));
+                        Some(if *negated {
+                            Box::new(NotSpecification::new(keyword)) as Box<dyn TaskSpecification>
+                        } else {
+                            keyword
+                        })
+                    }
+                    QueryToken::Or => None,
+                })
+                .reduce(|left, right| Box::new(AndSpecification::new(left, right)))
+        });
+
+    groups
+        .reduce(|left, right| Box::new(OrSpecification::new(left, right)))
+        .unwrap_or_else(|| Box::new(TaskByKeyword::new(vec![], field)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::tag::{
-        aggregate::TagAggregate,
+        aggregate::NewTag,
         value_objects::{TagDescription, TagName},
     };
     use crate::domain::task::{
-        aggregate::TaskAggregate,
-        value_objects::{Priority, Status, TaskDescription, TaskTitle},
+        builder::TaskBuilder,
+        value_objects::{DueDate, TaskDescription, TaskTitle},
     };
     use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
 
@@ -96,7 +465,9 @@ mod tests {
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: 検索実行
-        let result = use_case.execute("買い物", SearchField::All).await;
+        let result = use_case
+            .execute("買い物", SearchField::All, &SearchFilter::default())
+            .await;
 
         // Assert: 空の結果が返る
         assert!(result.is_ok());
@@ -112,20 +483,15 @@ mod tests {
 
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title).description(description).build();
         task_repo.save(task).await.unwrap();
 
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: 「買い物」で検索
-        let result = use_case.execute("買い物", SearchField::All).await;
+        let result = use_case
+            .execute("買い物", SearchField::All, &SearchFilter::default())
+            .await;
 
         // Assert: 1件マッチ
         assert!(result.is_ok());
@@ -142,32 +508,23 @@ mod tests {
 
         let title1 = TaskTitle::new("レポート作成").unwrap();
         let description1 = TaskDescription::new("月次レポートを作成する").unwrap();
-        let task1 = TaskAggregate::new(
-            title1,
-            description1,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task1 = TaskBuilder::new(title1)
+            .description(description1)
+            .priority(Priority::High)
+            .build();
         task_repo.save(task1).await.unwrap();
 
         let title2 = TaskTitle::new("レポート提出").unwrap();
         let description2 = TaskDescription::new("レポートを提出する").unwrap();
-        let task2 = TaskAggregate::new(
-            title2,
-            description2,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task2 = TaskBuilder::new(title2).description(description2).build();
         task_repo.save(task2).await.unwrap();
 
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: 「レポート 作成」で検索（AND条件）
-        let result = use_case.execute("レポート 作成", SearchField::All).await;
+        let result = use_case
+            .execute("レポート 作成", SearchField::All, &SearchFilter::default())
+            .await;
 
         // Assert: 「レポート作成」のみマッチ（「レポート提出」はマッチしない）
         assert!(result.is_ok());
@@ -184,20 +541,15 @@ mod tests {
 
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title).description(description).build();
         task_repo.save(task).await.unwrap();
 
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: タイトルのみ検索で「買い物」を検索
-        let result = use_case.execute("買い物", SearchField::Title).await;
+        let result = use_case
+            .execute("買い物", SearchField::Title, &SearchFilter::default())
+            .await;
 
         // Assert: 1件マッチ
         assert!(result.is_ok());
@@ -205,7 +557,9 @@ mod tests {
         assert_eq!(tasks.len(), 1);
 
         // Act: タイトルのみ検索で「牛乳」を検索
-        let result2 = use_case.execute("牛乳", SearchField::Title).await;
+        let result2 = use_case
+            .execute("牛乳", SearchField::Title, &SearchFilter::default())
+            .await;
 
         // Assert: 「牛乳」はタイトルにないのでマッチしない
         assert!(result2.is_ok());
@@ -221,20 +575,15 @@ mod tests {
 
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title).description(description).build();
         task_repo.save(task).await.unwrap();
 
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: 説明のみ検索で「牛乳」を検索
-        let result = use_case.execute("牛乳", SearchField::Description).await;
+        let result = use_case
+            .execute("牛乳", SearchField::Description, &SearchFilter::default())
+            .await;
 
         // Assert: 1件マッチ
         assert!(result.is_ok());
@@ -242,7 +591,9 @@ mod tests {
         assert_eq!(tasks.len(), 1);
 
         // Act: 説明のみ検索で「買い物」を検索
-        let result2 = use_case.execute("買い物", SearchField::Description).await;
+        let result2 = use_case
+            .execute("買い物", SearchField::Description, &SearchFilter::default())
+            .await;
 
         // Assert: 「買い物」は説明にないのでマッチしない
         assert!(result2.is_ok());
@@ -258,20 +609,15 @@ mod tests {
 
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title).description(description).build();
         task_repo.save(task).await.unwrap();
 
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: マッチしないキーワードで検索
-        let result = use_case.execute("会議", SearchField::All).await;
+        let result = use_case
+            .execute("会議", SearchField::All, &SearchFilter::default())
+            .await;
 
         // Assert: 0件
         assert!(result.is_ok());
@@ -288,27 +634,25 @@ mod tests {
         // タグを作成
         let tag_name = TagName::new("買い物").unwrap();
         let tag_description = TagDescription::new("").unwrap();
-        let tag = TagAggregate::new(tag_name, tag_description);
+        let tag = NewTag::new(tag_name, tag_description);
         let saved_tag = tag_repo.save(tag).await.unwrap();
         let tag_id = saved_tag.id();
 
         // タグ付きタスクを作成
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![*tag_id],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .tags(vec![*tag_id])
+            .build();
         task_repo.save(task).await.unwrap();
 
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: 検索実行
-        let result = use_case.execute("買い物", SearchField::All).await;
+        let result = use_case
+            .execute("買い物", SearchField::All, &SearchFilter::default())
+            .await;
 
         // Assert: タグ情報も含めて返却される
         assert!(result.is_ok());
@@ -326,20 +670,18 @@ mod tests {
 
         let title = TaskTitle::new("Bug Report").unwrap();
         let description = TaskDescription::new("Fix critical bug").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .build();
         task_repo.save(task).await.unwrap();
 
         let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
 
         // Act: 小文字で検索
-        let result = use_case.execute("bug", SearchField::All).await;
+        let result = use_case
+            .execute("bug", SearchField::All, &SearchFilter::default())
+            .await;
 
         // Assert: 大文字小文字を無視してマッチ
         assert!(result.is_ok());
@@ -347,4 +689,339 @@ mod tests {
         assert_eq!(tasks.len(), 1);
         assert_eq!(tasks[0].title, "Bug Report");
     }
+
+    /// タイトルと説明を指定してタスクを登録
+    async fn save_task(repo: &InMemoryTaskRepository, title: &str, description: &str) {
+        let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .description(TaskDescription::new(description).unwrap())
+            .build();
+        repo.save(task).await.unwrap();
+    }
+
+    /// 検索結果のタイトルを取得
+    async fn search_titles(repo: Arc<InMemoryTaskRepository>, query: &str) -> Vec<String> {
+        let use_case = SearchTasksUseCase::new(repo, Arc::new(InMemoryTagRepository::new()));
+        let mut titles: Vec<String> = use_case
+            .execute(query, SearchField::All, &SearchFilter::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect();
+        titles.sort();
+        titles
+    }
+
+    #[test]
+    fn test_tokenize_query() {
+        let tokens = tokenize(r#"foo OR "monthly report" -bar -"old draft" or"#);
+
+        let term = |text: &str, negated: bool| QueryToken::Term {
+            text: text.to_string(),
+            negated,
+        };
+        assert_eq!(
+            tokens,
+            vec![
+                term("foo", false),
+                QueryToken::Or,
+                term("monthly report", false),
+                term("bar", true),
+                term("old draft", true),
+                // 小文字のorは通常の検索語
+                term("or", false),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_ranks_by_relevance() {
+        // Arrange: 作成順は関連度の低い順
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        save_task(&repo, "Weekly sync", "prepare the monthly report").await;
+        save_task(&repo, "Report on monthly budget", "").await;
+        save_task(&repo, "Monthly report", "").await;
+        let use_case = SearchTasksUseCase::new(repo, Arc::new(InMemoryTagRepository::new()));
+
+        // Act
+        let titles: Vec<String> = use_case
+            .execute("monthly report", SearchField::All, &SearchFilter::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect();
+
+        // Assert: タイトルで隣り合う > タイトルで離れている > 説明のみ
+        assert_eq!(
+            titles,
+            vec!["Monthly report", "Report on monthly budget", "Weekly sync"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_sort_by_priority_and_due() {
+        // Arrange
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        let date = |day: u32| DueDate::new(NaiveDate::from_ymd_opt(2030, 1, day).unwrap()).unwrap();
+        for (title, priority, due) in [
+            ("report later", Priority::Critical, Some(date(20))),
+            ("report", Priority::Low, None),
+            ("report soon", Priority::Medium, Some(date(5))),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .priority(priority)
+                .due_date(due)
+                .build();
+            repo.save(task).await.unwrap();
+        }
+        let search = |sort: SearchSort| {
+            let use_case =
+                SearchTasksUseCase::new(repo.clone(), Arc::new(InMemoryTagRepository::new()))
+                    .with_sort(sort);
+            async move {
+                use_case
+                    .execute("report", SearchField::All, &SearchFilter::default())
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|task| task.title)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        // Act & Assert
+        assert_eq!(
+            search(SearchSort::Due).await,
+            vec!["report soon", "report later", "report"]
+        );
+        assert_eq!(
+            search(SearchSort::Priority).await,
+            vec!["report later", "report soon", "report"]
+        );
+    }
+
+    #[test]
+    fn test_text_score_prefers_close_terms() {
+        let terms = highlight_terms("monthly report");
+        let adjacent = text_score("Monthly report", &terms);
+        let apart = text_score("Report for the monthly meeting", &terms);
+        let partial = text_score("Monthly meeting", &terms);
+        assert!(adjacent > apart);
+        assert!(apart > partial);
+        assert_eq!(text_score("Weekly sync", &terms), 0);
+    }
+
+    #[test]
+    fn test_highlight_terms_and_match_ranges() {
+        // Arrange
+        let terms = highlight_terms(r#"Report OR "月次 レポート" -draft report"#);
+
+        // Act
+        let ranges = match_ranges("REPORT: 月次 レポート（report）", &terms);
+
+        // Assert: 除外語は含まず、大文字小文字を無視して一致した範囲をバイト単位で返す
+        assert_eq!(terms, vec!["report", "月次 レポート"]);
+        let text = "REPORT: 月次 レポート（report）";
+        let matched: Vec<&str> = ranges.iter().map(|range| &text[range.clone()]).collect();
+        assert_eq!(matched, vec!["REPORT", "月次 レポート", "report"]);
+        assert!(match_ranges("abc", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_tokenize_ignores_empty_terms() {
+        assert_eq!(tokenize(r#"  - "" "unterminated"#).len(), 1);
+        assert!(tokenize("   ").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_or_condition() {
+        // Arrange
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        save_task(&repo, "買い物", "").await;
+        save_task(&repo, "掃除", "").await;
+        save_task(&repo, "洗濯", "").await;
+
+        // Act
+        let titles = search_titles(repo, "買い物 OR 掃除").await;
+
+        // Assert
+        assert_eq!(titles, vec!["掃除", "買い物"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_quoted_phrase() {
+        // Arrange
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        save_task(&repo, "Monthly report", "").await;
+        save_task(&repo, "Report for monthly meeting", "").await;
+
+        // Act
+        let titles = search_titles(repo, r#""monthly report""#).await;
+
+        // Assert: フレーズとして連続して含むもののみ
+        assert_eq!(titles, vec!["Monthly report"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_exclude_term() {
+        // Arrange
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        save_task(&repo, "レポート作成", "").await;
+        save_task(&repo, "レポート提出", "下書き").await;
+
+        // Act
+        let titles = search_titles(repo, "レポート -下書き").await;
+
+        // Assert
+        assert_eq!(titles, vec!["レポート作成"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_and_binds_tighter_than_or() {
+        // Arrange
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        save_task(&repo, "レポート作成", "").await;
+        save_task(&repo, "レポート提出", "").await;
+        save_task(&repo, "買い物", "").await;
+
+        // Act: (レポート AND 作成) OR 買い物
+        let titles = search_titles(repo, "レポート 作成 OR 買い物").await;
+
+        // Assert
+        assert_eq!(titles, vec!["レポート作成", "買い物"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_with_filter() {
+        // Arrange
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_id = TagId::new(3).unwrap();
+        let due =
+            |d: u32| Some(DueDate::new(NaiveDate::from_ymd_opt(2025, 12, d).unwrap()).unwrap());
+        let tasks = [
+            (
+                "レポート作成",
+                Status::Pending,
+                Priority::High,
+                vec![tag_id],
+                due(20),
+            ),
+            (
+                "レポート提出",
+                Status::Pending,
+                Priority::Low,
+                vec![tag_id],
+                due(20),
+            ),
+            (
+                "レポート確認",
+                Status::Completed,
+                Priority::High,
+                vec![tag_id],
+                due(20),
+            ),
+            (
+                "レポート清書",
+                Status::Pending,
+                Priority::High,
+                vec![],
+                due(20),
+            ),
+            (
+                "レポート期限なし",
+                Status::Pending,
+                Priority::High,
+                vec![tag_id],
+                None,
+            ),
+            (
+                "買い物",
+                Status::Pending,
+                Priority::High,
+                vec![tag_id],
+                due(20),
+            ),
+        ];
+        for (title, status, priority, tags, due_date) in tasks {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .priority(priority)
+                .tags(tags)
+                .due_date(due_date)
+                .build();
+            repo.save(task).await.unwrap();
+        }
+        let use_case = SearchTasksUseCase::new(repo, Arc::new(InMemoryTagRepository::new()));
+        let filter = SearchFilter {
+            status: Some(Status::Pending),
+            priority: Some(Priority::High),
+            tag_ids: vec![tag_id],
+            due_before: NaiveDate::from_ymd_opt(2026, 1, 1),
+            due_after: None,
+            created_after: None,
+            updated_since: None,
+            custom_fields: vec![],
+        };
+
+        // Act
+        let with_keyword = use_case
+            .execute("レポート", SearchField::All, &filter)
+            .await
+            .unwrap();
+        let filter_only = use_case
+            .execute("", SearchField::All, &filter)
+            .await
+            .unwrap();
+
+        // Assert: すべての条件を満たすタスクのみ
+        let titles: Vec<_> = with_keyword.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["レポート作成"]);
+        let mut titles: Vec<_> = filter_only.iter().map(|t| t.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, vec!["レポート作成", "買い物"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_with_custom_field_filter() {
+        // Arrange
+        let repo = Arc::new(InMemoryTaskRepository::new());
+        for (title, client) in [
+            ("請求書A", Some("ACME")),
+            ("請求書B", Some("Globex")),
+            ("請求書C", None),
+        ] {
+            let mut task = TaskBuilder::new(TaskTitle::new(title).unwrap()).build();
+            if let Some(client) = client {
+                task.set_custom_field(CustomField::new("client", client).unwrap());
+            }
+            repo.save(task).await.unwrap();
+        }
+        let use_case = SearchTasksUseCase::new(repo, Arc::new(InMemoryTagRepository::new()));
+        let filter = SearchFilter {
+            custom_fields: vec!["client=acme".parse().unwrap()],
+            ..Default::default()
+        };
+
+        // Act
+        let result = use_case
+            .execute("", SearchField::All, &filter)
+            .await
+            .unwrap();
+
+        // Assert
+        let titles: Vec<_> = result.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["請求書A"]);
+        assert_eq!(result[0].custom_fields["client"], "ACME");
+    }
+
+    #[test]
+    fn test_search_filter_is_empty() {
+        assert!(SearchFilter::default().is_empty());
+        let filter = SearchFilter {
+            due_after: NaiveDate::from_ymd_opt(2026, 1, 1),
+            ..Default::default()
+        };
+        assert!(!filter.is_empty());
+    }
 }
@@ -0,0 +1,311 @@
+use crate::{
+    application::dto::{AgendaDTO, AgendaDayDTO, task_dto::TaskDTO},
+    domain::{
+        clock::{Clock, SystemClock},
+        tag::repository::TagRepository,
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            specification::{
+                AndSpecification, NotSpecification, TaskByStatus, TaskDueBefore, TaskSnoozed,
+            },
+            value_objects::{Status, TaskContext},
+        },
+    },
+};
+use anyhow::Result;
+use chrono::Duration;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
+use tracing::instrument;
+
+/// ShowAgendaUseCase - 今日の予定（アジェンダ）のユースケース
+///
+/// 期限切れ・今日期限・今後の期限・進行中のタスクをセクションに分けて取得します。
+pub struct ShowAgendaUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    context: Option<TaskContext>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ShowAgendaUseCase {
+    /// 新しいShowAgendaUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            context: None,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// 指定したコンテキストのタスク（とコンテキストのないタスク）に絞り込む
+    pub fn with_context(mut self, context: Option<TaskContext>) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 今日から`days`日後までの予定を取得する
+    ///
+    /// 期限切れの判定は`TaskAggregate::is_overdue`と同じく、Clockのタイムゾーンでの今日を基準にします。
+    /// スヌーズ中のタスクと、`with_context`で指定したコンテキスト以外のタスクはどのセクションにも含めません。
+    ///
+    /// # Arguments
+    /// * `days` - 明日以降の何日分の期限を含めるか（0の場合は今日の分のみ）
+    #[instrument(name = "show_agenda", skip(self))]
+    pub async fn execute(&self, days: u32) -> Result<AgendaDTO> {
+        let today = self.clock.today();
+        let last_day = today + Duration::days(i64::from(days));
+
+        // 1. 各セクションのタスクを取得（完了済みとスヌーズ中は除く）
+        let open = || {
+            Box::new(AndSpecification::new(
+                Box::new(NotSpecification::new(Box::new(TaskByStatus::new(
+                    Status::Completed,
+                )))),
+                Box::new(NotSpecification::new(Box::new(TaskSnoozed::new(today)))),
+            ))
+        };
+        let overdue = self
+            .task_repository
+            .find_by_specification(Box::new(AndSpecification::new(
+                open(),
+                Box::new(TaskDueBefore::new(today)),
+            )))
+            .await?;
+        let mut by_date: BTreeMap<_, Vec<TaskAggregate>> = BTreeMap::new();
+        for task in self
+            .task_repository
+            .find_by_due_date_range(today, last_day)
+            .await?
+        {
+            if let Some(due_date) = task.due_date()
+                && *task.status() != Status::Completed
+                && !task.is_snoozed(today)
+            {
+                by_date.entry(due_date.value()).or_default().push(task);
+            }
+        }
+        let mut overdue = overdue;
+        if let Some(context) = &self.context {
+            overdue.retain(|task| task.is_visible_in_context(context));
+            for tasks in by_date.values_mut() {
+                tasks.retain(|task| task.is_visible_in_context(context));
+            }
+            by_date.retain(|_, tasks| !tasks.is_empty());
+        }
+        let due_today = by_date.remove(&today).unwrap_or_default();
+
+        // 2. 進行中のタスクは、ほかのセクションに含まれないものだけを表示する
+        let shown: HashSet<_> = overdue
+            .iter()
+            .chain(&due_today)
+            .chain(by_date.values().flatten())
+            .map(|task| task.id().value())
+            .collect();
+        let in_progress: Vec<_> = self
+            .task_repository
+            .find_by_specification(Box::new(AndSpecification::new(
+                Box::new(TaskByStatus::new(Status::InProgress)),
+                Box::new(NotSpecification::new(Box::new(TaskSnoozed::new(today)))),
+            )))
+            .await?
+            .into_iter()
+            .filter(|task| !shown.contains(&task.id().value()))
+            .filter(|task| {
+                self.context
+                    .as_ref()
+                    .is_none_or(|context| task.is_visible_in_context(context))
+            })
+            .collect();
+
+        // 3. タグ情報を一括取得してDTOに変換（N+1問題の回避）
+        let tag_ids: Vec<_> = overdue
+            .iter()
+            .chain(&due_today)
+            .chain(by_date.values().flatten())
+            .chain(&in_progress)
+            .flat_map(|task| task.tags().iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+        let section = |mut tasks: Vec<TaskAggregate>| -> Vec<TaskDTO> {
+            // 優先度の高い順、同じ優先度では期限日の早い順に並べる
+            tasks.sort_by_key(|task| {
+                (
+                    Reverse(*task.priority()),
+                    task.due_date().map(|d| d.value()),
+                    task.id().value(),
+                )
+            });
+            tasks
+                .into_iter()
+                .map(|task| TaskDTO::from_aggregate_with_tags(task, &tag_map))
+                .collect()
+        };
+
+        Ok(AgendaDTO {
+            today,
+            overdue: section(overdue),
+            due_today: section(due_today),
+            upcoming: by_date
+                .into_iter()
+                .map(|(date, tasks)| AgendaDayDTO {
+                    date,
+                    tasks: section(tasks),
+                })
+                .collect(),
+            in_progress: section(in_progress),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::{
+        aggregate::NewTask,
+        builder::TaskBuilder,
+        value_objects::{DueDate, Priority, TaskTitle},
+    };
+    use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
+    use chrono::NaiveDate;
+
+    fn task(
+        title: &str,
+        status: Status,
+        priority: Priority,
+        due_date: Option<NaiveDate>,
+    ) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .status(status)
+            .priority(priority)
+            .due_date(due_date.map(|date| DueDate::new(date).unwrap()))
+            .build()
+    }
+
+    fn titles(tasks: &[TaskDTO]) -> Vec<&str> {
+        tasks.iter().map(|task| task.title.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_agenda_sections() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let today = SystemClock::default().today();
+        let day = |offset: i64| Some(today + Duration::days(offset));
+        for new_task in [
+            task("期限切れ・低", Status::Pending, Priority::Low, day(-3)),
+            task("期限切れ・高", Status::Pending, Priority::High, day(-1)),
+            task("完了済み", Status::Completed, Priority::High, day(-1)),
+            task("今日", Status::InProgress, Priority::Medium, day(0)),
+            task("2日後", Status::Pending, Priority::Medium, day(2)),
+            task("10日後", Status::InProgress, Priority::Medium, day(10)),
+            task("期限なし", Status::Pending, Priority::High, None),
+        ] {
+            task_repo.save(new_task).await.unwrap();
+        }
+        for new_task in [
+            task("スヌーズ中・今日", Status::Pending, Priority::High, day(0)),
+            task(
+                "スヌーズ中・進行中",
+                Status::InProgress,
+                Priority::High,
+                None,
+            ),
+        ] {
+            let mut snoozed = task_repo.save(new_task).await.unwrap();
+            snoozed
+                .snooze(today + Duration::days(1), &FixedClock::on(today))
+                .unwrap();
+            task_repo.update(snoozed).await.unwrap();
+        }
+        let use_case = ShowAgendaUseCase::new(task_repo, Arc::new(InMemoryTagRepository::new()));
+
+        // Act
+        let agenda = use_case.execute(7).await.unwrap();
+
+        // Assert: 進行中のタスクは、今日期限のセクションに含まれるものを除く（スヌーズ中は表示しない）
+        assert_eq!(titles(&agenda.overdue), ["期限切れ・高", "期限切れ・低"]);
+        assert_eq!(titles(&agenda.due_today), ["今日"]);
+        assert_eq!(agenda.upcoming.len(), 1);
+        assert_eq!(agenda.upcoming[0].date, today + Duration::days(2));
+        assert_eq!(titles(&agenda.upcoming[0].tasks), ["2日後"]);
+        assert_eq!(titles(&agenda.in_progress), ["10日後"]);
+    }
+
+    #[tokio::test]
+    async fn test_today_excludes_upcoming() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tomorrow = SystemClock::default().today() + Duration::days(1);
+        task_repo
+            .save(task(
+                "明日",
+                Status::Pending,
+                Priority::High,
+                Some(tomorrow),
+            ))
+            .await
+            .unwrap();
+        let use_case = ShowAgendaUseCase::new(task_repo, Arc::new(InMemoryTagRepository::new()));
+
+        // Act
+        let agenda = use_case.execute(0).await.unwrap();
+
+        // Assert
+        assert!(agenda.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_agenda_with_context() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let today = SystemClock::default().today();
+        for (title, status, due, context) in [
+            (
+                "自宅・期限切れ",
+                Status::Pending,
+                Some(today - Duration::days(1)),
+                Some("@home"),
+            ),
+            ("会社・今日", Status::Pending, Some(today), Some("@office")),
+            ("どこでも・今日", Status::Pending, Some(today), None),
+            ("会社・進行中", Status::InProgress, None, Some("@office")),
+            ("自宅・進行中", Status::InProgress, None, Some("@home")),
+        ] {
+            let new_task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .due_date(due.map(|date| DueDate::new(date).unwrap()))
+                .context(context.map(|c| TaskContext::new(c).unwrap()))
+                .build();
+            task_repo.save(new_task).await.unwrap();
+        }
+        let use_case = ShowAgendaUseCase::new(task_repo, Arc::new(InMemoryTagRepository::new()))
+            .with_context(Some(TaskContext::new("@home").unwrap()));
+
+        // Act
+        let agenda = use_case.execute(0).await.unwrap();
+
+        // Assert: コンテキストのないタスクはどのコンテキストでも表示する
+        assert_eq!(titles(&agenda.overdue), ["自宅・期限切れ"]);
+        assert_eq!(titles(&agenda.due_today), ["どこでも・今日"]);
+        assert_eq!(titles(&agenda.in_progress), ["自宅・進行中"]);
+    }
+}
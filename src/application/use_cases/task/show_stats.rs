@@ -1,13 +1,17 @@
 use crate::{
-    application::dto::StatsDTO,
+    application::dto::{FlowStatsDTO, StatsDTO},
     domain::{
-        services::TaskStatisticsService, tag::repository::TagRepository,
-        task::repository::TaskRepository,
+        clock::{Clock, SystemClock},
+        services::{FlowStatsCollector, TaskStatsCollector},
+        tag::repository::TagRepository,
+        task::{repository::TaskRepository, value_objects::DueDateBuckets},
     },
 };
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
 use std::{collections::HashMap, sync::Arc};
+use tracing::instrument;
 
 /// ShowStatsUseCase - タスク統計表示のユースケース
 ///
@@ -15,6 +19,8 @@ use std::{collections::HashMap, sync::Arc};
 pub struct ShowStatsUseCase {
     task_repository: Arc<dyn TaskRepository>,
     tag_repository: Arc<dyn TagRepository>,
+    due_date_buckets: DueDateBuckets,
+    clock: Arc<dyn Clock>,
 }
 
 impl ShowStatsUseCase {
@@ -26,23 +32,41 @@ impl ShowStatsUseCase {
         Self {
             task_repository,
             tag_repository,
+            due_date_buckets: DueDateBuckets::default(),
+            clock: Arc::new(SystemClock::default()),
         }
     }
 
+    /// 期限関連統計の分類に使用する境界を設定
+    pub fn with_due_date_buckets(mut self, buckets: DueDateBuckets) -> Self {
+        self.due_date_buckets = buckets;
+        self
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// タスクの統計情報を取得する
     ///
     /// # Returns
     /// * `Ok(StatsDTO)` - 統計情報
     /// * `Err` - エラーが発生した場合
+    #[instrument(name = "show_stats", skip_all)]
     pub async fn execute(&self) -> Result<StatsDTO> {
-        // 全タスクを取得
-        let tasks = self.task_repository.find_all().await?;
-
-        // 今日の日付を取得
-        let today = Utc::now().naive_utc().date();
-
-        // TaskStatisticsServiceで統計を計算
-        let stats = TaskStatisticsService::calculate_stats(&tasks, today);
+        // 設定したタイムゾーンでの今日の日付を取得
+        let today = self.clock.today();
+
+        // 全タスクをストリームで読み込みながら統計を計算（全件をメモリに保持しない）
+        let mut collector =
+            TaskStatsCollector::new(today).with_due_date_buckets(self.due_date_buckets);
+        let mut tasks = self.task_repository.find_all_stream();
+        while let Some(task) = tasks.try_next().await? {
+            collector.add(&task);
+        }
+        let stats = collector.finish();
 
         // タグIDからタグ名へのマッピングを作成
         let all_tags = self.tag_repository.find_all().await?;
@@ -54,18 +78,34 @@ impl ShowStatsUseCase {
         // DTOに変換（タグ名マップ付き）
         Ok(StatsDTO::from_task_stats_with_tag_names(stats, tag_names))
     }
+
+    /// タスクの流れの統計（ステータスごとの滞在時間・サイクルタイム・リードタイム）を取得する
+    ///
+    /// # Arguments
+    /// * `now` - 基準日時（未完了のタスクは現在のステータスの滞在時間をこの日時まで数える）
+    #[instrument(name = "show_flow_stats", skip_all)]
+    pub async fn execute_flow(&self, now: DateTime<Utc>) -> Result<FlowStatsDTO> {
+        let mut collector = FlowStatsCollector::new(now);
+        let mut tasks = self.task_repository.find_all_stream();
+        while let Some(task) = tasks.try_next().await? {
+            collector.add(&task);
+        }
+        Ok(collector.finish().into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::clock::Timezone;
     use crate::domain::tag::value_objects::TagId;
     use crate::domain::task::{
-        aggregate::TaskAggregate,
-        value_objects::{DueDate, Priority, Status, TaskDescription, TaskTitle},
+        builder::TaskBuilder,
+        status_transition::StatusTransitionPolicy,
+        value_objects::{DueDate, Priority, Status, TaskTitle},
     };
     use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
-    use chrono::{Duration, Utc};
+    use chrono::{Duration, FixedOffset, Utc};
 
     #[tokio::test]
     async fn test_show_stats_empty() {
@@ -91,14 +131,9 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タスク1").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タスク1").unwrap())
+            .priority(Priority::High)
+            .build();
         task_repo.save(task).await.unwrap();
 
         let use_case = ShowStatsUseCase::new(task_repo, tag_repo);
@@ -121,35 +156,20 @@ mod tests {
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
         // Pendingタスク（High）
-        let task1 = TaskAggregate::new(
-            TaskTitle::new("タスク1").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task1 = TaskBuilder::new(TaskTitle::new("タスク1").unwrap())
+            .priority(Priority::High)
+            .build();
 
         // InProgressタスク（Medium）
-        let task2 = TaskAggregate::new(
-            TaskTitle::new("タスク2").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::InProgress,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task2 = TaskBuilder::new(TaskTitle::new("タスク2").unwrap())
+            .status(Status::InProgress)
+            .build();
 
         // Completedタスク（Low）
-        let mut task3 = TaskAggregate::new(
-            TaskTitle::new("タスク3").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
-        task3.complete().unwrap();
+        let task3 = TaskBuilder::new(TaskTitle::new("タスク3").unwrap())
+            .status(Status::Completed)
+            .priority(Priority::Low)
+            .build();
 
         task_repo.save(task1).await.unwrap();
         task_repo.save(task2).await.unwrap();
@@ -178,15 +198,11 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let past_date = Utc::now().naive_utc().date() - Duration::days(1);
-        let task = TaskAggregate::new(
-            TaskTitle::new("期限切れタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![],
-            Some(DueDate::new(past_date).unwrap()),
-        );
+        let past_date = SystemClock::default().today() - Duration::days(1);
+        let task = TaskBuilder::new(TaskTitle::new("期限切れタスク").unwrap())
+            .priority(Priority::High)
+            .due_date(Some(DueDate::new(past_date).unwrap()))
+            .build();
         task_repo.save(task).await.unwrap();
 
         let use_case = ShowStatsUseCase::new(task_repo, tag_repo);
@@ -201,29 +217,43 @@ mod tests {
         assert_eq!(stats.due_date_stats.get("overdue"), Some(&1));
     }
 
+    #[tokio::test]
+    async fn test_show_stats_overdue_uses_clock_timezone() {
+        // Arrange: UTC-12:00の今日が期限のタスクは、UTC+14:00では常に期限を過ぎている
+        let behind = SystemClock::new(Timezone::Fixed(FixedOffset::west_opt(12 * 3600).unwrap()));
+        let ahead = SystemClock::new(Timezone::Fixed(FixedOffset::east_opt(14 * 3600).unwrap()));
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let task = TaskBuilder::new(TaskTitle::new("日付の境界").unwrap())
+            .due_date(Some(DueDate::new(behind.today()).unwrap()))
+            .build();
+        task_repo.save(task).await.unwrap();
+        let use_case = |clock: SystemClock| {
+            ShowStatsUseCase::new(task_repo.clone(), tag_repo.clone()).with_clock(Arc::new(clock))
+        };
+
+        // Act
+        let behind_stats = use_case(behind).execute().await.unwrap();
+        let ahead_stats = use_case(ahead).execute().await.unwrap();
+
+        // Assert
+        assert_eq!(behind_stats.due_date_stats.get("due_today"), Some(&1));
+        assert_eq!(ahead_stats.due_date_stats.get("overdue"), Some(&1));
+    }
+
     #[tokio::test]
     async fn test_show_stats_with_tags() {
         // Arrange
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task1 = TaskAggregate::new(
-            TaskTitle::new("タスク1").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![TagId::new(1).unwrap()],
-            None,
-        );
-
-        let task2 = TaskAggregate::new(
-            TaskTitle::new("タスク2").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()],
-            None,
-        );
+        let task1 = TaskBuilder::new(TaskTitle::new("タスク1").unwrap())
+            .tags(vec![TagId::new(1).unwrap()])
+            .build();
+
+        let task2 = TaskBuilder::new(TaskTitle::new("タスク2").unwrap())
+            .tags(vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()])
+            .build();
 
         task_repo.save(task1).await.unwrap();
         task_repo.save(task2).await.unwrap();
@@ -239,4 +269,41 @@ mod tests {
         assert_eq!(stats.total_count, 2);
         // タグの統計情報は含まれる（ただし、タグ名ではなくIDで集計される）
     }
+
+    #[tokio::test]
+    async fn test_show_flow_stats_uses_recorded_status_changes() {
+        // Arrange: 着手して完了したタスクと、保留中のタスク
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let policy = StatusTransitionPolicy::default();
+        let mut done = task_repo
+            .save(TaskBuilder::new(TaskTitle::new("完了").unwrap()).build())
+            .await
+            .unwrap();
+        let clock = SystemClock::default();
+        done.change_status(Status::InProgress, &policy, &clock)
+            .unwrap();
+        done.change_status(Status::Completed, &policy, &clock)
+            .unwrap();
+        task_repo.update(done).await.unwrap();
+        task_repo
+            .save(TaskBuilder::new(TaskTitle::new("保留").unwrap()).build())
+            .await
+            .unwrap();
+        let use_case = ShowStatsUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let stats = use_case
+            .execute_flow(Utc::now() + Duration::hours(2))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(stats.task_count, 2);
+        assert_eq!(stats.pending.count, 2);
+        assert_eq!(stats.in_progress.count, 1);
+        assert_eq!(stats.cycle_time.count, 1);
+        assert_eq!(stats.cycle_time.average_minutes, Some(0));
+        assert_eq!(stats.lead_time.count, 1);
+    }
 }
@@ -1,12 +1,25 @@
 use crate::{
-    application::dto::task_dto::{TagInfo, TaskDTO},
+    application::dto::{
+        task_detail_dto::{RelatedTaskDTO, TaskDetailDTO, TaskHistoryEntryDTO, TaskHistoryKind},
+        task_dto::{TagInfo, TaskDTO},
+    },
     domain::{
-        tag::repository::TagRepository,
-        task::{repository::TaskRepository, value_objects::TaskId},
+        clock::{Clock, SystemClock},
+        tag::{aggregate::TagAggregate, repository::TagRepository},
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            specification::{OrSpecification, TaskByTag, TaskSpecification},
+            value_objects::{Status, TaskId},
+        },
     },
 };
 use anyhow::Result;
 use std::{collections::HashMap, sync::Arc};
+use tracing::instrument;
+
+/// 関連タスクとして表示する最大件数
+const MAX_RELATED_TASKS: usize = 10;
 
 /// ShowTaskUseCase - タスク詳細取得のユースケース
 ///
@@ -15,6 +28,7 @@ use std::{collections::HashMap, sync::Arc};
 pub struct ShowTaskUseCase {
     task_repository: Arc<dyn TaskRepository>,
     tag_repository: Arc<dyn TagRepository>,
+    clock: Arc<dyn Clock>,
 }
 
 impl ShowTaskUseCase {
@@ -26,9 +40,16 @@ impl ShowTaskUseCase {
         Self {
             task_repository,
             tag_repository,
+            clock: Arc::new(SystemClock::default()),
         }
     }
 
+    /// 期限までの日数や経過日数の計算に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// タスクの詳細を取得する
     ///
     /// # Arguments
@@ -37,51 +58,170 @@ impl ShowTaskUseCase {
     /// # Returns
     /// * `Ok(TaskDTO)` - タスクの詳細
     /// * `Err` - エラーが発生した場合（タスクが見つからない場合を含む）
+    #[instrument(name = "show_task", skip(self))]
     pub async fn execute(&self, id: i32) -> Result<TaskDTO> {
+        let task = self.find_task(id).await?;
+        let tag_map = self.find_tag_map(&task).await?;
+
+        Ok(to_dto(task, &tag_map))
+    }
+
+    /// 関連情報付きでタスクの詳細を取得する
+    ///
+    /// タスク本体に加えて、期限までの日数、経過日数、同じタグを持つタスク、
+    /// 変更履歴の概要を返します。
+    ///
+    /// # Arguments
+    /// * `id` - 取得するタスクのID
+    ///
+    /// # Returns
+    /// * `Ok(TaskDetailDTO)` - 関連情報付きのタスクの詳細
+    /// * `Err` - エラーが発生した場合（タスクが見つからない場合を含む）
+    #[instrument(name = "show_task_with_related", skip(self))]
+    pub async fn execute_with_related(&self, id: i32) -> Result<TaskDetailDTO> {
+        let task = self.find_task(id).await?;
+        let tag_map = self.find_tag_map(&task).await?;
+
+        // 同じタグを1つ以上持つタスクを検索
+        let spec = task
+            .tags()
+            .iter()
+            .map(|tag_id| Box::new(TaskByTag::new(*tag_id)) as Box<dyn TaskSpecification>)
+            .reduce(|left, right| Box::new(OrSpecification::new(left, right)));
+        let siblings = match spec {
+            Some(spec) => self.task_repository.find_by_specification(spec).await?,
+            None => Vec::new(),
+        };
+
+        let mut related_tasks: Vec<RelatedTaskDTO> = siblings
+            .into_iter()
+            .filter(|sibling| sibling.id() != task.id())
+            .map(|sibling| {
+                let shared_tags = task
+                    .tags()
+                    .iter()
+                    .filter(|tag_id| sibling.tags().contains(tag_id))
+                    .filter_map(|tag_id| tag_map.get(&tag_id.value()))
+                    .map(|tag| tag.name().value().to_string())
+                    .collect();
+                let sibling = TaskDTO::from(sibling);
+                RelatedTaskDTO {
+                    id: sibling.id,
+                    title: sibling.title,
+                    status: sibling.status,
+                    shared_tags,
+                }
+            })
+            .collect();
+        related_tasks.sort_by(|a, b| {
+            b.shared_tags
+                .len()
+                .cmp(&a.shared_tags.len())
+                .then(a.id.cmp(&b.id))
+        });
+        related_tasks.truncate(MAX_RELATED_TASKS);
+
+        Ok(build_detail(
+            to_dto(task, &tag_map),
+            related_tasks,
+            self.clock.as_ref(),
+        ))
+    }
+
+    /// IDでタスクを取得（存在しない場合はエラー）
+    async fn find_task(&self, id: i32) -> Result<TaskAggregate> {
         let task_id = TaskId::new(id)?;
 
-        let task = self
-            .task_repository
+        self.task_repository
             .find_by_id(&task_id)
             .await?
-            .ok_or_else(|| anyhow::anyhow!("Task ID {} does not exist", id))?;
+            .ok_or_else(|| anyhow::anyhow!("Task ID {} does not exist", id))
+    }
 
-        // タグ情報を取得
-        let tag_ids: Vec<_> = task.tags().clone();
-        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+    /// タスクに付けられたタグをIDで引けるマップとして取得
+    async fn find_tag_map(&self, task: &TaskAggregate) -> Result<HashMap<i32, TagAggregate>> {
+        let tags = self.tag_repository.find_by_ids(task.tags()).await?;
 
-        // タグマップを作成
-        let tag_map: HashMap<_, _> = tags.iter().map(|tag| (tag.id().value(), tag)).collect();
+        Ok(tags
+            .into_iter()
+            .map(|tag| (tag.id().value(), tag))
+            .collect())
+    }
+}
 
-        // TaskDTOに変換（タグ詳細を含む）
-        let tag_details = task
-            .tags()
-            .iter()
-            .filter_map(|tag_id| {
-                tag_map.get(&tag_id.value()).map(|tag| TagInfo {
-                    id: tag.id().value(),
-                    name: tag.name().value().to_string(),
-                })
+/// TaskDTOに変換（タグ詳細を含む）
+fn to_dto(task: TaskAggregate, tag_map: &HashMap<i32, TagAggregate>) -> TaskDTO {
+    let tag_details = task
+        .tags()
+        .iter()
+        .filter_map(|tag_id| {
+            tag_map.get(&tag_id.value()).map(|tag| TagInfo {
+                id: tag.id().value(),
+                name: tag.name().value().to_string(),
             })
-            .collect();
+        })
+        .collect();
+
+    let mut dto = TaskDTO::from(task);
+    dto.tags = tag_details;
+    dto
+}
+
+/// タスクの日時から派生情報を計算し、TaskDetailDTOを構築
+///
+/// 変更履歴は保存されている作成・更新・完了日時から組み立てます。
+fn build_detail(
+    task: TaskDTO,
+    related_tasks: Vec<RelatedTaskDTO>,
+    clock: &dyn Clock,
+) -> TaskDetailDTO {
+    let now = clock.now();
+    let today = clock.today();
+    let days_until_due = match task.status {
+        Status::Completed => None,
+        _ => task.due_date.map(|due| (due - today).num_days()),
+    };
 
-        let mut dto = TaskDTO::from(task);
-        dto.tags = tag_details;
+    let mut history = vec![TaskHistoryEntryDTO {
+        kind: TaskHistoryKind::Created,
+        occurred_at: task.created_at,
+    }];
+    if let Some(completed_at) = task.completed_at {
+        history.push(TaskHistoryEntryDTO {
+            kind: TaskHistoryKind::Completed,
+            occurred_at: completed_at,
+        });
+    }
+    if task.updated_at > task.created_at && Some(task.updated_at) != task.completed_at {
+        history.push(TaskHistoryEntryDTO {
+            kind: TaskHistoryKind::Updated,
+            occurred_at: task.updated_at,
+        });
+    }
+    history.sort_by_key(|entry| entry.occurred_at);
 
-        Ok(dto)
+    TaskDetailDTO {
+        days_until_due,
+        age_days: (now - task.created_at).num_days(),
+        days_since_update: (now - task.updated_at).num_days(),
+        related_tasks,
+        history,
+        task,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::tag::value_objects::TagId;
-    use crate::domain::task::{
-        aggregate::TaskAggregate,
-        value_objects::{DueDate, Priority, Status, TaskDescription, TaskTitle},
+    use crate::domain::clock::FixedClock;
+    use crate::domain::tag::aggregate::NewTag;
+    use crate::domain::tag::value_objects::{TagDescription, TagId, TagName};
+    use crate::domain::task::value_objects::{
+        DueDate, Priority, Status, TaskDescription, TaskTitle,
     };
+    use crate::domain::task::{aggregate::NewTask, builder::TaskBuilder};
     use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
-    use chrono::NaiveDate;
+    use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 
     #[tokio::test]
     async fn test_show_task_success() {
@@ -89,14 +229,11 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("表示するタスク").unwrap(),
-            TaskDescription::new("詳細な説明").unwrap(),
-            Status::InProgress,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("表示するタスク").unwrap())
+            .description(TaskDescription::new("詳細な説明").unwrap())
+            .status(Status::InProgress)
+            .priority(Priority::High)
+            .build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = ShowTaskUseCase::new(task_repo, tag_repo);
@@ -110,8 +247,8 @@ mod tests {
         assert_eq!(task_dto.id, saved_task.id().value());
         assert_eq!(task_dto.title, "表示するタスク");
         assert_eq!(task_dto.description, Some("詳細な説明".to_string()));
-        assert_eq!(task_dto.status, "in_progress");
-        assert_eq!(task_dto.priority, "high");
+        assert_eq!(task_dto.status, Status::InProgress);
+        assert_eq!(task_dto.priority, Priority::High);
     }
 
     #[tokio::test]
@@ -149,14 +286,9 @@ mod tests {
         let task_repo = Arc::new(InMemoryTaskRepository::new());
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("タグ付きタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("タグ付きタスク").unwrap())
+            .tags(vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()])
+            .build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = ShowTaskUseCase::new(task_repo, tag_repo);
@@ -178,14 +310,10 @@ mod tests {
         let tag_repo = Arc::new(InMemoryTagRepository::new());
 
         let due_date = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
-        let task = TaskAggregate::new(
-            TaskTitle::new("期限付きタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![],
-            Some(DueDate::new(due_date).unwrap()),
-        );
+        let task = TaskBuilder::new(TaskTitle::new("期限付きタスク").unwrap())
+            .priority(Priority::High)
+            .due_date(Some(DueDate::new(due_date).unwrap()))
+            .build();
         let saved_task = task_repo.save(task).await.unwrap();
 
         let use_case = ShowTaskUseCase::new(task_repo, tag_repo);
@@ -198,4 +326,198 @@ mod tests {
         let task_dto = result.unwrap();
         assert_eq!(task_dto.due_date, Some(due_date));
     }
+
+    fn new_task(title: &str, status: Status, tags: Vec<TagId>) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .status(status)
+            .tags(tags)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_show_task_with_related_finds_tasks_sharing_tags() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let work = tag_repo
+            .save(NewTag::new(
+                TagName::new("仕事").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let urgent = tag_repo
+            .save(NewTag::new(
+                TagName::new("急ぎ").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let work_id = *work.id();
+        let urgent_id = *urgent.id();
+
+        let target = task_repo
+            .save(new_task("対象", Status::Pending, vec![work_id, urgent_id]))
+            .await
+            .unwrap();
+        let one_tag = task_repo
+            .save(new_task("仕事のみ", Status::Pending, vec![work_id]))
+            .await
+            .unwrap();
+        let both_tags = task_repo
+            .save(new_task(
+                "両方",
+                Status::Completed,
+                vec![urgent_id, work_id],
+            ))
+            .await
+            .unwrap();
+        task_repo
+            .save(new_task("タグなし", Status::Pending, vec![]))
+            .await
+            .unwrap();
+
+        let use_case = ShowTaskUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let detail = use_case
+            .execute_with_related(target.id().value())
+            .await
+            .unwrap();
+
+        // Assert: 自身とタグを共有しないタスクは含まず、共通するタグが多い順
+        assert_eq!(detail.task.title, "対象");
+        let related_ids: Vec<i32> = detail.related_tasks.iter().map(|t| t.id).collect();
+        assert_eq!(
+            related_ids,
+            vec![both_tags.id().value(), one_tag.id().value()]
+        );
+        assert_eq!(detail.related_tasks[0].shared_tags, vec!["仕事", "急ぎ"]);
+        assert_eq!(detail.related_tasks[0].status, Status::Completed);
+        assert_eq!(detail.related_tasks[1].shared_tags, vec!["仕事"]);
+        assert_eq!(detail.history[0].kind, TaskHistoryKind::Created);
+    }
+
+    #[tokio::test]
+    async fn test_show_task_with_related_without_tags() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let target = task_repo
+            .save(new_task("対象", Status::Pending, vec![]))
+            .await
+            .unwrap();
+        task_repo
+            .save(new_task("他のタスク", Status::Pending, vec![]))
+            .await
+            .unwrap();
+        let use_case = ShowTaskUseCase::new(task_repo, tag_repo);
+
+        // Act
+        let detail = use_case
+            .execute_with_related(target.id().value())
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(detail.related_tasks.is_empty());
+        assert_eq!(detail.age_days, 0);
+        assert_eq!(detail.days_since_update, 0);
+    }
+
+    #[tokio::test]
+    async fn test_show_task_with_related_not_found() {
+        let use_case = ShowTaskUseCase::new(
+            Arc::new(InMemoryTaskRepository::new()),
+            Arc::new(InMemoryTagRepository::new()),
+        );
+
+        let result = use_case.execute_with_related(999).await;
+
+        assert!(result.unwrap_err().to_string().contains("does not exist"));
+    }
+
+    fn task_dto_at(created_at: DateTime<Utc>) -> TaskDTO {
+        TaskDTO {
+            id: 1,
+            title: "タスク".to_string(),
+            description: None,
+            status: Status::Pending,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at,
+            updated_at: created_at,
+            due_date: None,
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_build_detail_computes_days() {
+        // Arrange
+        let now = Utc.with_ymd_and_hms(2025, 1, 10, 12, 0, 0).unwrap();
+        let mut task = task_dto_at(now - Duration::days(7));
+        task.updated_at = now - Duration::days(2);
+        task.due_date = NaiveDate::from_ymd_opt(2025, 1, 8);
+
+        // Act
+        let detail = build_detail(task, vec![], &FixedClock::new(now));
+
+        // Assert
+        assert_eq!(detail.days_until_due, Some(-2));
+        assert_eq!(detail.age_days, 7);
+        assert_eq!(detail.days_since_update, 2);
+        let kinds: Vec<_> = detail.history.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![TaskHistoryKind::Created, TaskHistoryKind::Updated]
+        );
+    }
+
+    #[test]
+    fn test_build_detail_completed_task() {
+        // Arrange: 完了と同時に更新された場合、更新は履歴に含めない
+        let now = Utc.with_ymd_and_hms(2025, 1, 10, 12, 0, 0).unwrap();
+        let completed_at = now - Duration::days(1);
+        let mut task = task_dto_at(now - Duration::days(3));
+        task.status = Status::Completed;
+        task.completed_at = Some(completed_at);
+        task.updated_at = completed_at;
+        task.due_date = NaiveDate::from_ymd_opt(2025, 1, 20);
+
+        // Act
+        let detail = build_detail(task, vec![], &FixedClock::new(now));
+
+        // Assert: 完了済みタスクは期限までの日数を表示しない
+        assert_eq!(detail.days_until_due, None);
+        let kinds: Vec<_> = detail.history.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![TaskHistoryKind::Created, TaskHistoryKind::Completed]
+        );
+    }
+
+    #[test]
+    fn test_build_detail_uses_clock_timezone() {
+        // Arrange: UTCでは1月9日だが、+09:00では1月10日
+        let now = Utc.with_ymd_and_hms(2025, 1, 9, 20, 0, 0).unwrap();
+        let clock = FixedClock::new(now).with_timezone("+09:00".parse().unwrap());
+        let mut task = task_dto_at(now - Duration::days(1));
+        task.due_date = NaiveDate::from_ymd_opt(2025, 1, 12);
+
+        // Act
+        let detail = build_detail(task, vec![], &clock);
+
+        // Assert
+        assert_eq!(detail.days_until_due, Some(2));
+    }
 }
@@ -0,0 +1,186 @@
+use crate::{
+    application::dto::{WorkloadDTO, WorkloadDayDTO},
+    domain::{
+        clock::{Clock, SystemClock},
+        task::{
+            repository::TaskRepository,
+            value_objects::{Estimate, Status},
+        },
+    },
+};
+use anyhow::{Result, bail};
+use chrono::NaiveDate;
+use std::{collections::BTreeMap, sync::Arc};
+use tracing::instrument;
+
+/// ShowWorkloadUseCase - 日ごとの作業量を集計するユースケース
+///
+/// 期限日ごとに未完了のタスクの見積もり時間を合計し、1日の作業可能時間と比べます。
+/// 完了済みとスヌーズ中のタスクは含めません。
+pub struct ShowWorkloadUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ShowWorkloadUseCase {
+    /// 新しいShowWorkloadUseCaseを作成
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self {
+            task_repository,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// `from`から`to`まで（両端を含む）の日ごとの作業量を取得する
+    ///
+    /// スヌーズ中かどうかは、Clockのタイムゾーンでの今日を基準に判定します。
+    ///
+    /// # Arguments
+    /// * `daily_capacity` - 1日の作業可能時間（これを超える日を過負荷とする）
+    #[instrument(name = "show_workload", skip(self))]
+    pub async fn execute(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        daily_capacity: Estimate,
+    ) -> Result<WorkloadDTO> {
+        if from > to {
+            bail!("Invalid period: {} is after {}", from, to);
+        }
+        let today = self.clock.today();
+
+        let mut days: BTreeMap<NaiveDate, WorkloadDayDTO> = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| {
+                let day = WorkloadDayDTO {
+                    date,
+                    task_count: 0,
+                    estimated_minutes: 0,
+                    unestimated_count: 0,
+                    overcommitted: false,
+                };
+                (date, day)
+            })
+            .collect();
+        for task in self
+            .task_repository
+            .find_by_due_date_range(from, to)
+            .await?
+        {
+            if *task.status() == Status::Completed || task.is_snoozed(today) {
+                continue;
+            }
+            let Some(day) = task
+                .due_date()
+                .and_then(|due_date| days.get_mut(&due_date.value()))
+            else {
+                continue;
+            };
+            day.task_count += 1;
+            match task.estimate() {
+                Some(estimate) => day.estimated_minutes += estimate.minutes(),
+                None => day.unestimated_count += 1,
+            }
+        }
+
+        let daily_capacity_minutes = daily_capacity.minutes();
+        Ok(WorkloadDTO {
+            daily_capacity_minutes,
+            days: days
+                .into_values()
+                .map(|day| WorkloadDayDTO {
+                    overcommitted: day.estimated_minutes > daily_capacity_minutes,
+                    ..day
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::{
+        builder::TaskBuilder,
+        value_objects::{DueDate, TaskTitle},
+    };
+    use crate::interface::persistence::in_memory::InMemoryTaskRepository;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_workload_sums_estimates_per_day() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let today = SystemClock::default().today();
+        let tomorrow = today + Duration::days(1);
+        for (title, status, due_date, estimate) in [
+            ("設計", Status::Pending, today, Some("3h")),
+            ("実装", Status::InProgress, today, Some("6h")),
+            ("レビュー", Status::Pending, today, None),
+            ("完了済み", Status::Completed, today, Some("8h")),
+            ("明日", Status::Pending, tomorrow, Some("1h30m")),
+            (
+                "期間外",
+                Status::Pending,
+                today + Duration::days(5),
+                Some("2h"),
+            ),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .due_date(Some(DueDate::new(due_date).unwrap()))
+                .estimate(estimate.map(|e| e.parse().unwrap()))
+                .build();
+            task_repo.save(task).await.unwrap();
+        }
+        let use_case = ShowWorkloadUseCase::new(task_repo);
+
+        // Act
+        let workload = use_case
+            .execute(today, today + Duration::days(2), "8h".parse().unwrap())
+            .await
+            .unwrap();
+
+        // Assert: タスクのない日も含み、作業可能時間を超える日は過負荷になる
+        let summary: Vec<_> = workload
+            .days
+            .iter()
+            .map(|day| {
+                (
+                    day.task_count,
+                    day.estimated_minutes,
+                    day.unestimated_count,
+                    day.overcommitted,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(3, 540, 1, true), (1, 90, 0, false), (0, 0, 0, false)]
+        );
+        assert_eq!(workload.total_minutes(), 630);
+        assert_eq!(workload.unestimated_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_workload_rejects_reversed_period() {
+        // Arrange
+        let use_case = ShowWorkloadUseCase::new(Arc::new(InMemoryTaskRepository::new()));
+        let today = SystemClock::default().today();
+
+        // Act
+        let result = use_case
+            .execute(today, today - Duration::days(1), "8h".parse().unwrap())
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    application::dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        task::{aggregate::TaskAggregate, repository::TaskRepository, value_objects::TaskId},
+    },
+};
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// SnoozeTaskUseCase - タスクのスヌーズのユースケース
+///
+/// スヌーズしたタスクは、期限の日まで既定の一覧やアジェンダに表示されません。
+/// 期限の当日になると自動的に表示されるため、解除の操作は必須ではありません。
+/// 返すTaskDTOのタグ情報は解決しません（結果の表示用）。
+pub struct SnoozeTaskUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl SnoozeTaskUseCase {
+    /// 新しいSnoozeTaskUseCaseを作成
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self {
+            task_repository,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// タスクを指定した日までスヌーズする
+    ///
+    /// 今日の判定は`TaskAggregate::is_overdue`と同じく、Clockのタイムゾーンでの今日を基準にします。
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 更新後のタスク
+    /// * `Err` - タスクが存在しない場合、完了済みの場合、または日付が今日以前の場合
+    #[instrument(name = "snooze_task", skip(self))]
+    pub async fn snooze(&self, task_id: i32, until: NaiveDate) -> Result<TaskDTO> {
+        let mut task = self.find_task(task_id).await?;
+        task.snooze(until, self.clock.as_ref())?;
+        self.save(task).await
+    }
+
+    /// タスクのスヌーズを解除する
+    ///
+    /// # Returns
+    /// * `Ok(TaskDTO)` - 更新後のタスク
+    /// * `Err` - タスクが存在しない場合
+    #[instrument(name = "unsnooze_task", skip(self))]
+    pub async fn unsnooze(&self, task_id: i32) -> Result<TaskDTO> {
+        let mut task = self.find_task(task_id).await?;
+        task.unsnooze(self.clock.as_ref())?;
+        self.save(task).await
+    }
+
+    async fn find_task(&self, task_id: i32) -> Result<TaskAggregate> {
+        self.task_repository
+            .find_by_id(&TaskId::new(task_id)?)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Task ID {} does not exist", task_id))
+    }
+
+    async fn save(&self, task: TaskAggregate) -> Result<TaskDTO> {
+        let updated = self.task_repository.update(task).await?;
+        Ok(TaskDTO::from(updated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::value_objects::TaskTitle;
+    use crate::interface::persistence::in_memory::InMemoryTaskRepository;
+    use chrono::Duration;
+
+    async fn setup() -> (SnoozeTaskUseCase, i32) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let task = task_repo
+            .save(TaskBuilder::new(TaskTitle::new("請求書を送る").unwrap()).build())
+            .await
+            .unwrap();
+        (SnoozeTaskUseCase::new(task_repo), task.id().value())
+    }
+
+    #[tokio::test]
+    async fn test_snooze_and_unsnooze() {
+        // Arrange
+        let (use_case, task_id) = setup().await;
+        let until = SystemClock::default().today() + Duration::days(3);
+
+        // Act
+        let snoozed = use_case.snooze(task_id, until).await.unwrap();
+        let unsnoozed = use_case.unsnooze(task_id).await.unwrap();
+
+        // Assert
+        assert_eq!(snoozed.snoozed_until, Some(until));
+        assert_eq!(unsnoozed.snoozed_until, None);
+    }
+
+    #[tokio::test]
+    async fn test_snooze_rejects_past_date() {
+        // Arrange
+        let (use_case, task_id) = setup().await;
+        let today = SystemClock::default().today();
+
+        // Act
+        let result = use_case.snooze(task_id, today).await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    application::dto::TaskSummaryDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        services::TaskStatisticsService,
+        task::{
+            repository::TaskRepository,
+            specification::{
+                AndSpecification, NotSpecification, TaskByStatus, TaskDueBefore, TaskDueBetween,
+                TaskSnoozed, TaskSpecification,
+            },
+            value_objects::{CompletionStreak, Status},
+        },
+    },
+};
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// SummarizeTasksUseCase - 未完了のタスクの件数と完了の連続記録を集計するユースケース
+///
+/// 未完了のタスクは件数だけをリポジトリに問い合わせ、タスク自体は読み込みません。
+/// 完了の連続記録は、指定した場合のみ完了済みのタスクを読み込んで計算します。
+pub struct SummarizeTasksUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    clock: Arc<dyn Clock>,
+    completion_streak: bool,
+}
+
+impl SummarizeTasksUseCase {
+    /// 新しいSummarizeTasksUseCaseを作成
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self {
+            task_repository,
+            clock: Arc::new(SystemClock::default()),
+            completion_streak: false,
+        }
+    }
+
+    /// 「今日」の判定に使用するClockを設定（既定はシステムのタイムゾーン）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 完了の連続記録も計算する（指定しない場合、連続記録の項目は0）
+    pub fn with_completion_streak(mut self) -> Self {
+        self.completion_streak = true;
+        self
+    }
+
+    /// 未完了・今日期限・期限切れのタスク数と、完了の連続記録を取得する
+    ///
+    /// スヌーズ中のタスクは数えません。
+    ///
+    /// 期限切れの判定は`TaskAggregate::is_overdue`と同じく、Clockのタイムゾーンでの今日を基準にします。
+    #[instrument(name = "summarize_tasks", skip_all)]
+    pub async fn execute(&self) -> Result<TaskSummaryDTO> {
+        let today = self.clock.today();
+        // 完了済みとスヌーズ中のタスクは数えない
+        let open = || -> Box<dyn TaskSpecification> {
+            Box::new(AndSpecification::new(
+                Box::new(NotSpecification::new(Box::new(TaskByStatus::new(
+                    Status::Completed,
+                )))),
+                Box::new(NotSpecification::new(Box::new(TaskSnoozed::new(today)))),
+            ))
+        };
+
+        let repo = &self.task_repository;
+        let streak = if self.completion_streak {
+            let completed = repo
+                .find_by_specification(Box::new(TaskByStatus::new(Status::Completed)))
+                .await?;
+            TaskStatisticsService::completion_streak(&completed, self.clock.as_ref())
+        } else {
+            CompletionStreak::default()
+        };
+        Ok(TaskSummaryDTO {
+            open: repo.count_by_specification(open()).await?,
+            due_today: repo
+                .count_by_specification(Box::new(AndSpecification::new(
+                    open(),
+                    Box::new(TaskDueBetween::new(today, today)),
+                )))
+                .await?,
+            overdue: repo
+                .count_by_specification(Box::new(AndSpecification::new(
+                    open(),
+                    Box::new(TaskDueBefore::new(today)),
+                )))
+                .await?,
+            completed_today: streak.completed_today() as u64,
+            completed_total: streak.completed_total() as u64,
+            streak_days: streak.current_days(),
+            longest_streak_days: streak.longest_days(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::clock::Timezone;
+    use crate::domain::task::{
+        aggregate::NewTask,
+        builder::TaskBuilder,
+        value_objects::{DueDate, TaskTitle},
+    };
+    use crate::interface::persistence::in_memory::InMemoryTaskRepository;
+    use chrono::{Duration, FixedOffset, NaiveDate};
+
+    fn task(title: &str, status: Status, due_date: Option<NaiveDate>) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .status(status)
+            .due_date(due_date.map(|date| DueDate::new(date).unwrap()))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_summarize_counts_open_due_today_and_overdue() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let today = SystemClock::default().today();
+        let yesterday = today - Duration::days(1);
+        for new_task in [
+            task("期限なし", Status::Pending, None),
+            task("今日", Status::InProgress, Some(today)),
+            task("昨日", Status::Pending, Some(yesterday)),
+            task("完了済みの期限切れ", Status::Completed, Some(yesterday)),
+            task("明日", Status::Pending, Some(today + Duration::days(1))),
+        ] {
+            task_repo.save(new_task).await.unwrap();
+        }
+        let mut snoozed = task_repo
+            .save(task("スヌーズ中", Status::Pending, Some(yesterday)))
+            .await
+            .unwrap();
+        snoozed
+            .snooze(today + Duration::days(1), &FixedClock::on(today))
+            .unwrap();
+        task_repo.update(snoozed).await.unwrap();
+        let use_case = SummarizeTasksUseCase::new(task_repo).with_completion_streak();
+
+        // Act
+        let summary = use_case.execute().await.unwrap();
+
+        // Assert: 完了済みとスヌーズ中のタスクはどの件数にも含まれない
+        assert_eq!(
+            summary,
+            TaskSummaryDTO {
+                open: 4,
+                due_today: 1,
+                overdue: 1,
+                completed_today: 1,
+                completed_total: 1,
+                streak_days: 1,
+                longest_streak_days: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_summarize_uses_clock_timezone() {
+        // Arrange: UTC-12:00とUTC+14:00では、常に日付が1日以上ずれる
+        let behind = SystemClock::new(Timezone::Fixed(FixedOffset::west_opt(12 * 3600).unwrap()));
+        let ahead = SystemClock::new(Timezone::Fixed(FixedOffset::east_opt(14 * 3600).unwrap()));
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        task_repo
+            .save(task("西の今日", Status::Pending, Some(behind.today())))
+            .await
+            .unwrap();
+
+        // Act
+        let behind_summary = SummarizeTasksUseCase::new(task_repo.clone())
+            .with_clock(Arc::new(behind))
+            .execute()
+            .await
+            .unwrap();
+        let ahead_summary = SummarizeTasksUseCase::new(task_repo)
+            .with_clock(Arc::new(ahead))
+            .execute()
+            .await
+            .unwrap();
+
+        // Assert: 同じ時刻でも、タイムゾーンが進んでいれば期限切れになる
+        assert_eq!((behind_summary.due_today, behind_summary.overdue), (1, 0));
+        assert_eq!((ahead_summary.due_today, ahead_summary.overdue), (0, 1));
+    }
+}
@@ -0,0 +1,1047 @@
+use crate::{
+    application::dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        tag::{
+            aggregate::{NewTag, TagAggregate},
+            repository::TagRepository,
+            value_objects::{TagDescription, TagId, TagName},
+        },
+        task::{
+            aggregate::{TaskAggregate, TaskReconstructParams},
+            builder::TaskBuilder,
+            repository::TaskRepository,
+            value_objects::ExternalRef,
+        },
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+    },
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    sync::Arc,
+};
+use tracing::instrument;
+
+/// データベース間の同期で、タスクの対応付けに記録する外部の項目の種類
+///
+/// 識別子は同期したすべてのデータベースで共通の、ランダムに作成したIDです。
+pub const REPLICA_SOURCE: &str = "yaru";
+
+/// 前回同期したときのタスクの内容（共通のID → 内容の指紋）
+pub type SyncBase = BTreeMap<String, String>;
+
+/// データベースごとの同期の状態の保存先
+#[async_trait]
+pub trait SyncStateStore: Send + Sync {
+    /// このデータベースを識別するID（初回に作成して保存する）
+    async fn replica_id(&self) -> Result<String>;
+
+    /// このデータベースに新しいIDを割り当てる（複製したデータベースを区別するため）
+    async fn reset_replica_id(&self) -> Result<String>;
+
+    /// 指定したデータベースと前回同期したときの内容（同期したことがない場合は空）
+    async fn load_base(&self, peer_id: &str) -> Result<SyncBase>;
+
+    /// 指定したデータベースと同期したときの内容を保存する
+    async fn save_base(&self, peer_id: &str, base: &SyncBase) -> Result<()>;
+}
+
+/// 同期するデータベース
+pub struct Replica {
+    pub task_repository: Arc<dyn TaskRepository>,
+    pub tag_repository: Arc<dyn TagRepository>,
+    pub unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    pub state: Arc<dyn SyncStateStore>,
+}
+
+/// 同期するデータベースのどちらか
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncSide {
+    /// 使用中のデータベース
+    Local,
+    /// 同期相手のデータベース
+    Remote,
+}
+
+impl SyncSide {
+    /// もう一方のデータベース
+    fn other(self) -> Self {
+        match self {
+            SyncSide::Local => SyncSide::Remote,
+            SyncSide::Remote => SyncSide::Local,
+        }
+    }
+}
+
+/// 競合の解決方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// 使用中のデータベースの内容（削除を含む）を相手に反映する
+    KeepLocal,
+    /// 相手のデータベースの内容（削除を含む）を使用中のデータベースに反映する
+    KeepRemote,
+    /// どちらも変更せず、次回の同期で再び確認する
+    Skip,
+}
+
+/// 前回の同期以降に両方のデータベースで変更されたタスク（削除された側はNone）
+#[derive(Debug, Clone)]
+pub struct SyncConflict {
+    pub local: Option<TaskDTO>,
+    pub remote: Option<TaskDTO>,
+}
+
+impl SyncConflict {
+    /// 競合しているタスクのタイトル（両方にある場合は使用中のデータベースのもの）
+    pub fn title(&self) -> &str {
+        self.local
+            .as_ref()
+            .or(self.remote.as_ref())
+            .map(|task| task.title.as_str())
+            .unwrap_or_default()
+    }
+}
+
+/// 競合の解決方法を決める（対話的な確認や、設定による自動的な選択）
+pub trait ConflictResolver {
+    fn resolve(&self, conflict: &SyncConflict) -> Result<ConflictResolution>;
+}
+
+/// 同期による変更の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// 同期による変更
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncChange {
+    /// 変更したデータベース
+    pub side: SyncSide,
+    pub kind: SyncChangeKind,
+    pub title: String,
+}
+
+/// 同期の結果
+#[derive(Debug, Default)]
+pub struct DatabaseSyncReport {
+    /// 反映した変更（ドライランの場合は反映する変更）
+    pub changes: Vec<SyncChange>,
+    /// 解決せずに残した競合のタスクのタイトル
+    pub skipped: Vec<String>,
+}
+
+/// 1件のタスクの同期の方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    /// 両方が同じ内容
+    InSync,
+    /// 片方の内容をもう一方に反映する（反映元）
+    Copy(SyncSide),
+    /// 片方のタスクを削除する（削除する側）
+    Delete(SyncSide),
+    /// 競合を解決せずに残す
+    Skip,
+}
+
+/// 同期の対象のタスクの組
+#[derive(Debug, Default)]
+struct Pair {
+    local: Option<usize>,
+    remote: Option<usize>,
+}
+
+/// 1つのデータベースの読み込んだ内容
+struct Snapshot {
+    tasks: Vec<TaskAggregate>,
+    tags: Vec<TagAggregate>,
+    /// 共通のIDを割り当てた（保存が必要な）タスクの位置
+    assigned: BTreeSet<usize>,
+}
+
+impl Snapshot {
+    async fn load(replica: &Replica) -> Result<Self> {
+        Ok(Self {
+            tasks: replica.task_repository.find_all().await?,
+            tags: replica.tag_repository.find_all().await?,
+            assigned: BTreeSet::new(),
+        })
+    }
+
+    /// タスクのタグ名（タグの順）
+    fn tag_names(&self, task: &TaskAggregate) -> Vec<String> {
+        task.tags()
+            .iter()
+            .filter_map(|id| self.tags.iter().find(|tag| tag.id() == id))
+            .map(|tag| tag.name().value().to_string())
+            .collect()
+    }
+
+    /// 同期で比べるタスクの内容の指紋
+    ///
+    /// データベースごとに異なるIDや更新日時は含めず、タグは名前で比べます。
+    fn fingerprint(&self, index: usize) -> String {
+        let task = &self.tasks[index];
+        let mut tags: Vec<String> = self
+            .tag_names(task)
+            .iter()
+            .map(|name| name.to_lowercase())
+            .collect();
+        tags.sort();
+        let mut content = vec![
+            task.title().value().to_string(),
+            task.description().value().to_string(),
+            format!("{:?}", task.status()),
+            format!("{:?}", task.priority()),
+            tags.join(","),
+            format!("{:?}", task.due_date().as_ref().map(|d| d.value())),
+            format!("{:?}", task.completed_at().map(|at| at.timestamp())),
+            format!("{:?}", task.recurrence().map(ToString::to_string)),
+            format!("{:?}", task.snoozed_until()),
+            format!("{:?}", task.estimate().map(|e| e.minutes())),
+            format!("{:?}", task.context().map(ToString::to_string)),
+        ];
+        // 担当者に対応する前の指紋と変わらないよう、設定されている場合だけ含める
+        content.extend(
+            task.assignee()
+                .map(|assignee| format!("assignee={}", assignee)),
+        );
+        content.extend(
+            task.custom_fields()
+                .iter()
+                .map(|field| format!("{}={}", field.key(), field.value())),
+        );
+        content.extend(
+            task.checklist()
+                .iter()
+                .map(|item| format!("[{}] {}", item.is_done(), item.text())),
+        );
+        format!("{:016x}", fnv1a(content.join("\n").as_bytes()))
+    }
+}
+
+/// SyncDatabasesUseCase - 2つのデータベースの間でタスクを双方向に同期するユースケース
+///
+/// タスクには両方のデータベースで共通のIDを外部の項目との対応として記録し、
+/// 前回同期したときの内容と比べて、片方だけで変更・作成・削除されたタスクをもう一方に反映します。
+/// 両方で変更されたタスクは`ConflictResolver`で解決方法を決めます。
+/// 競合の解決はデータベースへの書き込みの前に行い、書き込みはデータベースごとに1つのトランザクションで行います。
+/// 作業記録・gitの紐づけ・ステータスの変更履歴は同期しません。
+pub struct SyncDatabasesUseCase {
+    local: Replica,
+    remote: Replica,
+    clock: Arc<dyn Clock>,
+}
+
+impl SyncDatabasesUseCase {
+    /// 新しいSyncDatabasesUseCaseを作成
+    pub fn new(local: Replica, remote: Replica) -> Self {
+        Self {
+            local,
+            remote,
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// 共通のIDを割り当てた日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 2つのデータベースを同期する
+    ///
+    /// # Arguments
+    /// * `resolver` - 競合の解決方法を決める
+    /// * `dry_run` - trueの場合は反映する変更を求めるだけで、書き込みは行わない
+    #[instrument(name = "sync.databases", skip_all, fields(dry_run))]
+    pub async fn sync(
+        &self,
+        resolver: &dyn ConflictResolver,
+        dry_run: bool,
+    ) -> Result<DatabaseSyncReport> {
+        let local_id = self.local.state.replica_id().await?;
+        let mut remote_id = self.remote.state.replica_id().await?;
+        if remote_id == local_id {
+            // 同期済みのデータベースを複製した場合は、相手を別のデータベースとして扱う
+            remote_id = self.remote.state.reset_replica_id().await?;
+        }
+        // 片方の保存に失敗していた場合に備え、使用中のデータベースに記録がなければ相手の記録を使う
+        let mut base = self.local.state.load_base(&remote_id).await?;
+        if base.is_empty() {
+            base = self.remote.state.load_base(&local_id).await?;
+        }
+
+        let mut local = Snapshot::load(&self.local).await?;
+        let mut remote = Snapshot::load(&self.remote).await?;
+        // 同じデータベースの複製を初めて同期する場合は、片方にしかないタスクが
+        // 作成されたのか削除されたのかを区別できないため、競合として確認する
+        let copies = base.is_empty() && shares_tasks(&local, &remote);
+        let pairs = pair_tasks(&mut local, &mut remote, &base, self.clock.as_ref())?;
+
+        let mut report = DatabaseSyncReport::default();
+        let mut plan = Vec::new();
+        for (sync_id, pair) in pairs {
+            let action = decide(&sync_id, &pair, &local, &remote, &base, copies, resolver)?;
+            if action == Action::Skip {
+                let conflict = conflict(&pair, &local, &remote);
+                report.skipped.push(conflict.title().to_string());
+            }
+            plan.push((sync_id, pair, action));
+        }
+
+        report.changes = plan
+            .iter()
+            .filter_map(|(_, pair, action)| describe(pair, *action, &local, &remote))
+            .collect();
+        if dry_run {
+            return Ok(report);
+        }
+
+        let new_base = self.apply(&plan, &mut local, &mut remote, &base).await?;
+        self.local.state.save_base(&remote_id, &new_base).await?;
+        self.remote.state.save_base(&local_id, &new_base).await?;
+        Ok(report)
+    }
+
+    /// 同期の方法に従って両方のデータベースに書き込み、同期後の内容を返す
+    async fn apply(
+        &self,
+        plan: &[(String, Pair, Action)],
+        local: &mut Snapshot,
+        remote: &mut Snapshot,
+        base: &SyncBase,
+    ) -> Result<SyncBase> {
+        let local_uow = self.local.unit_of_work.begin().await?;
+        let remote_uow = self.remote.unit_of_work.begin().await?;
+        let result = write_changes(
+            plan,
+            (local_uow.as_ref(), local),
+            (remote_uow.as_ref(), remote),
+            base,
+        )
+        .await;
+
+        match result {
+            Ok(new_base) => {
+                local_uow.commit().await?;
+                remote_uow
+                    .commit()
+                    .await
+                    .context("Failed to commit the other database; run the sync again")?;
+                Ok(new_base)
+            }
+            Err(e) => {
+                local_uow.rollback().await?;
+                remote_uow.rollback().await?;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// 2つのデータベースに、複製元が同じ（IDと作成日時が同じ）タスクがあるか
+fn shares_tasks(local: &Snapshot, remote: &Snapshot) -> bool {
+    let remote_tasks: HashSet<(i32, DateTime<Utc>)> = remote
+        .tasks
+        .iter()
+        .map(|task| (task.id().value(), *task.created_at()))
+        .collect();
+    local
+        .tasks
+        .iter()
+        .any(|task| remote_tasks.contains(&(task.id().value(), *task.created_at())))
+}
+
+/// 共通のIDでタスクを組にする
+///
+/// 共通のIDがないタスクにはIDを割り当てます。同じデータベースを複製した場合に備え、
+/// IDと作成日時が同じタスクは同じタスクとみなして同じIDを割り当てます
+/// （タイトルは片方で変更されている場合があるため比べません）。
+fn pair_tasks(
+    local: &mut Snapshot,
+    remote: &mut Snapshot,
+    base: &SyncBase,
+    clock: &dyn Clock,
+) -> Result<BTreeMap<String, Pair>> {
+    let unassigned = |snapshot: &Snapshot| -> Vec<usize> {
+        (0..snapshot.tasks.len())
+            .filter(|&index| sync_id(&snapshot.tasks[index]).is_none())
+            .collect()
+    };
+    let mut remote_unassigned = unassigned(remote);
+    for index in unassigned(local) {
+        let id = new_sync_id();
+        let task = &local.tasks[index];
+        if let Some(position) = remote_unassigned.iter().position(|&other| {
+            let other = &remote.tasks[other];
+            other.id() == task.id() && other.created_at() == task.created_at()
+        }) {
+            assign(remote, remote_unassigned.remove(position), &id, clock)?;
+        }
+        assign(local, index, &id, clock)?;
+    }
+    for index in remote_unassigned {
+        assign(remote, index, &new_sync_id(), clock)?;
+    }
+
+    let mut pairs: BTreeMap<String, Pair> = base
+        .keys()
+        .map(|id| (id.clone(), Pair::default()))
+        .collect();
+    for (index, task) in local.tasks.iter().enumerate() {
+        if let Some(id) = sync_id(task) {
+            pairs.entry(id.to_string()).or_default().local = Some(index);
+        }
+    }
+    for (index, task) in remote.tasks.iter().enumerate() {
+        if let Some(id) = sync_id(task) {
+            pairs.entry(id.to_string()).or_default().remote = Some(index);
+        }
+    }
+    Ok(pairs)
+}
+
+/// タスクに共通のIDを割り当てる（保存は同期の書き込み時に行う）
+fn assign(snapshot: &mut Snapshot, index: usize, id: &str, clock: &dyn Clock) -> Result<()> {
+    let task = &mut snapshot.tasks[index];
+    let status = *task.status();
+    let external_ref = ExternalRef::new(REPLICA_SOURCE, id, None, clock.now(), status)?;
+    task.add_external_ref(external_ref, clock)?;
+    snapshot.assigned.insert(index);
+    Ok(())
+}
+
+/// タスクの同期の方法を決める
+///
+/// 前回同期したときの内容と比べ、変更されていない側にもう一方の変更を反映します。
+/// `copies`がtrueの場合（複製したデータベースの初回の同期）、前回の内容がないタスクの違いはすべて競合とします。
+fn decide(
+    sync_id: &str,
+    pair: &Pair,
+    local: &Snapshot,
+    remote: &Snapshot,
+    base: &SyncBase,
+    copies: bool,
+    resolver: &dyn ConflictResolver,
+) -> Result<Action> {
+    let base = base.get(sync_id);
+    let local_print = pair.local.map(|index| local.fingerprint(index));
+    let remote_print = pair.remote.map(|index| remote.fingerprint(index));
+    let changed = |print: &Option<String>| print.as_ref() != base;
+
+    let action = match (&local_print, &remote_print) {
+        (None, None) => return Ok(Action::InSync),
+        (Some(l), Some(r)) if l == r => return Ok(Action::InSync),
+        _ if base.is_none() && copies => return resolve(pair, local, remote, resolver),
+        // 前回の同期の後に作成されたタスク
+        (Some(_), None) if base.is_none() => return Ok(Action::Copy(SyncSide::Local)),
+        (None, Some(_)) if base.is_none() => return Ok(Action::Copy(SyncSide::Remote)),
+        _ => match (changed(&local_print), changed(&remote_print)) {
+            (true, false) if local_print.is_some() => Action::Copy(SyncSide::Local),
+            (true, false) => Action::Delete(SyncSide::Remote),
+            (false, true) if remote_print.is_some() => Action::Copy(SyncSide::Remote),
+            (false, true) => Action::Delete(SyncSide::Local),
+            _ => return resolve(pair, local, remote, resolver),
+        },
+    };
+    Ok(action)
+}
+
+/// 競合の解決方法を確認し、同期の方法にする
+fn resolve(
+    pair: &Pair,
+    local: &Snapshot,
+    remote: &Snapshot,
+    resolver: &dyn ConflictResolver,
+) -> Result<Action> {
+    Ok(match resolver.resolve(&conflict(pair, local, remote))? {
+        ConflictResolution::KeepLocal if pair.local.is_some() => Action::Copy(SyncSide::Local),
+        ConflictResolution::KeepLocal => Action::Delete(SyncSide::Remote),
+        ConflictResolution::KeepRemote if pair.remote.is_some() => Action::Copy(SyncSide::Remote),
+        ConflictResolution::KeepRemote => Action::Delete(SyncSide::Local),
+        ConflictResolution::Skip => Action::Skip,
+    })
+}
+
+/// 組になったタスクの競合
+fn conflict(pair: &Pair, local: &Snapshot, remote: &Snapshot) -> SyncConflict {
+    SyncConflict {
+        local: pair
+            .local
+            .map(|index| TaskDTO::from(local.tasks[index].clone())),
+        remote: pair
+            .remote
+            .map(|index| TaskDTO::from(remote.tasks[index].clone())),
+    }
+}
+
+/// 同期の方法を変更として表す（変更しない場合はNone）
+fn describe(
+    pair: &Pair,
+    action: Action,
+    local: &Snapshot,
+    remote: &Snapshot,
+) -> Option<SyncChange> {
+    let task = |side: SyncSide| match side {
+        SyncSide::Local => pair.local.map(|index| &local.tasks[index]),
+        SyncSide::Remote => pair.remote.map(|index| &remote.tasks[index]),
+    };
+    let (side, kind, source) = match action {
+        Action::InSync | Action::Skip => return None,
+        Action::Copy(from) => {
+            let kind = match task(from.other()) {
+                Some(_) => SyncChangeKind::Updated,
+                None => SyncChangeKind::Created,
+            };
+            (from.other(), kind, task(from)?)
+        }
+        Action::Delete(side) => (side, SyncChangeKind::Deleted, task(side)?),
+    };
+    Some(SyncChange {
+        side,
+        kind,
+        title: source.title().value().to_string(),
+    })
+}
+
+/// 両方のデータベースに変更を書き込み、同期後の内容を返す
+///
+/// リポジトリはこの関数の中で取得し、終了時に解放します（コミットの前に解放が必要なため）。
+async fn write_changes(
+    plan: &[(String, Pair, Action)],
+    (local_uow, local): (&dyn UnitOfWork, &mut Snapshot),
+    (remote_uow, remote): (&dyn UnitOfWork, &mut Snapshot),
+    base: &SyncBase,
+) -> Result<SyncBase> {
+    let repositories = |uow: &dyn UnitOfWork| (uow.task_repository(), uow.tag_repository());
+    let (local_tasks, local_tags) = repositories(local_uow);
+    let (remote_tasks, remote_tags) = repositories(remote_uow);
+
+    let mut new_base = SyncBase::new();
+    for (sync_id, pair, action) in plan {
+        match *action {
+            Action::InSync => {
+                if let Some(index) = pair.local {
+                    new_base.insert(sync_id.clone(), local.fingerprint(index));
+                }
+            }
+            Action::Skip => {
+                // 前回の内容がない場合も、次回の同期で再び競合になるよう空の指紋を記録する
+                let print = base.get(sync_id).cloned().unwrap_or_default();
+                new_base.insert(sync_id.clone(), print);
+            }
+            Action::Copy(SyncSide::Local) => {
+                let index = pair.local.context("Missing task to copy")?;
+                new_base.insert(sync_id.clone(), local.fingerprint(index));
+                copy_task(
+                    &local.tasks[index],
+                    local,
+                    pair.remote,
+                    remote,
+                    &remote_tasks,
+                    &remote_tags,
+                )
+                .await?;
+                if let Some(target) = pair.remote {
+                    remote.assigned.remove(&target);
+                }
+            }
+            Action::Copy(SyncSide::Remote) => {
+                let index = pair.remote.context("Missing task to copy")?;
+                new_base.insert(sync_id.clone(), remote.fingerprint(index));
+                copy_task(
+                    &remote.tasks[index],
+                    remote,
+                    pair.local,
+                    local,
+                    &local_tasks,
+                    &local_tags,
+                )
+                .await?;
+                if let Some(target) = pair.local {
+                    local.assigned.remove(&target);
+                }
+            }
+            Action::Delete(side) => {
+                let (snapshot, tasks, index) = match side {
+                    SyncSide::Local => (&mut *local, &local_tasks, pair.local),
+                    SyncSide::Remote => (&mut *remote, &remote_tasks, pair.remote),
+                };
+                let index = index.context("Missing task to delete")?;
+                tasks.delete(snapshot.tasks[index].id()).await?;
+                snapshot.assigned.remove(&index);
+            }
+        }
+    }
+
+    // 共通のIDを割り当てただけのタスクを保存する
+    for (snapshot, tasks) in [(&*local, &local_tasks), (&*remote, &remote_tasks)] {
+        for &index in &snapshot.assigned {
+            tasks.update(snapshot.tasks[index].clone()).await?;
+        }
+    }
+    Ok(new_base)
+}
+
+/// タスクの内容をもう一方のデータベースに反映する（対応するタスクがなければ作成する）
+///
+/// 反映先のタスクのgitの紐づけ・外部の項目との対応・ステータスの変更履歴はそのまま残します。
+async fn copy_task(
+    source: &TaskAggregate,
+    source_snapshot: &Snapshot,
+    target_index: Option<usize>,
+    target_snapshot: &mut Snapshot,
+    task_repository: &Arc<dyn TaskRepository>,
+    tag_repository: &Arc<dyn TagRepository>,
+) -> Result<TaskAggregate> {
+    let mut tag_ids: Vec<TagId> = Vec::new();
+    for name in source_snapshot.tag_names(source) {
+        let existing = target_snapshot
+            .tags
+            .iter()
+            .find(|tag| tag.name().matches(&name));
+        let tag = match existing {
+            Some(tag) => tag.clone(),
+            None => {
+                let tag = tag_repository
+                    .save(NewTag::new(TagName::new(name)?, TagDescription::new("")?))
+                    .await?;
+                target_snapshot.tags.push(tag.clone());
+                tag
+            }
+        };
+        if !tag_ids.contains(tag.id()) {
+            tag_ids.push(*tag.id());
+        }
+    }
+
+    let target = match target_index {
+        Some(index) => target_snapshot.tasks[index].clone(),
+        None => {
+            let mut new_task = TaskBuilder::new(source.title().clone()).build();
+            for external_ref in source.external_refs() {
+                if external_ref.source() == REPLICA_SOURCE {
+                    new_task.add_external_ref(external_ref.clone())?;
+                }
+            }
+            task_repository.save(new_task).await?
+        }
+    };
+
+    let updated = TaskAggregate::reconstruct(TaskReconstructParams {
+        id: *target.id(),
+        title: source.title().clone(),
+        description: source.description().clone(),
+        status: *source.status(),
+        priority: *source.priority(),
+        tags: tag_ids,
+        created_at: *target.created_at(),
+        updated_at: *source.updated_at(),
+        due_date: *source.due_date(),
+        completed_at: *source.completed_at(),
+        custom_fields: source.custom_fields().to_vec(),
+        checklist: source.checklist().to_vec(),
+        git_links: target.git_links().to_vec(),
+        external_refs: target.external_refs().to_vec(),
+        recurrence: source.recurrence().cloned(),
+        snoozed_until: source.snoozed_until(),
+        estimate: source.estimate(),
+        context: source.context().cloned(),
+        assignee: source.assignee().cloned(),
+        status_history: target.status_history().to_vec(),
+    });
+    task_repository.update(updated).await
+}
+
+/// タスクの共通のID
+fn sync_id(task: &TaskAggregate) -> Option<&str> {
+    task.external_refs()
+        .iter()
+        .find(|external_ref| external_ref.source() == REPLICA_SOURCE)
+        .map(|external_ref| external_ref.external_id())
+}
+
+/// 新しい共通のID（128ビットの乱数）
+fn new_sync_id() -> String {
+    format!("{:016x}{:016x}", fastrand::u64(..), fastrand::u64(..))
+}
+
+/// FNV-1aハッシュ（保存する値のため、Rustのバージョンによらず同じ値になるものを使う）
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::value_objects::{TaskId, TaskTitle};
+    use crate::interface::persistence::in_memory::{
+        InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory,
+    };
+    use std::sync::Mutex;
+
+    /// メモリ上に同期の状態を保存する
+    struct MemoryStateStore {
+        id: Mutex<String>,
+        bases: Mutex<BTreeMap<String, SyncBase>>,
+    }
+
+    #[async_trait]
+    impl SyncStateStore for MemoryStateStore {
+        async fn replica_id(&self) -> Result<String> {
+            Ok(self.id.lock().unwrap().clone())
+        }
+
+        async fn reset_replica_id(&self) -> Result<String> {
+            let mut id = self.id.lock().unwrap();
+            id.push('\'');
+            Ok(id.clone())
+        }
+
+        async fn load_base(&self, peer_id: &str) -> Result<SyncBase> {
+            Ok(self
+                .bases
+                .lock()
+                .unwrap()
+                .get(peer_id)
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        async fn save_base(&self, peer_id: &str, base: &SyncBase) -> Result<()> {
+            self.bases
+                .lock()
+                .unwrap()
+                .insert(peer_id.to_string(), base.clone());
+            Ok(())
+        }
+    }
+
+    /// 常に同じ方法で競合を解決し、競合の件数を数える
+    struct FixedResolver {
+        resolution: ConflictResolution,
+        conflicts: Mutex<usize>,
+    }
+
+    impl FixedResolver {
+        fn new(resolution: ConflictResolution) -> Self {
+            Self {
+                resolution,
+                conflicts: Mutex::new(0),
+            }
+        }
+    }
+
+    impl ConflictResolver for FixedResolver {
+        fn resolve(&self, _conflict: &SyncConflict) -> Result<ConflictResolution> {
+            *self.conflicts.lock().unwrap() += 1;
+            Ok(self.resolution)
+        }
+    }
+
+    /// テスト用のデータベース
+    struct Database {
+        tasks: Arc<InMemoryTaskRepository>,
+        tags: Arc<InMemoryTagRepository>,
+        state: Arc<MemoryStateStore>,
+    }
+
+    impl Database {
+        fn new(id: &str) -> Self {
+            Self {
+                tasks: Arc::new(InMemoryTaskRepository::new()),
+                tags: Arc::new(InMemoryTagRepository::new()),
+                state: Arc::new(MemoryStateStore {
+                    id: Mutex::new(id.to_string()),
+                    bases: Mutex::new(BTreeMap::new()),
+                }),
+            }
+        }
+
+        fn replica(&self) -> Replica {
+            Replica {
+                task_repository: self.tasks.clone(),
+                tag_repository: self.tags.clone(),
+                unit_of_work: Arc::new(InMemoryUnitOfWorkFactory::new(
+                    self.tasks.clone(),
+                    self.tags.clone(),
+                )),
+                state: self.state.clone(),
+            }
+        }
+
+        async fn add(&self, title: &str) -> TaskAggregate {
+            self.tasks
+                .save(TaskBuilder::new(TaskTitle::new(title).unwrap()).build())
+                .await
+                .unwrap()
+        }
+
+        async fn find(&self, title: &str) -> Option<TaskAggregate> {
+            self.tasks
+                .find_all()
+                .await
+                .unwrap()
+                .into_iter()
+                .find(|task| task.title().value() == title)
+        }
+
+        async fn titles(&self) -> Vec<String> {
+            let mut titles: Vec<String> = self
+                .tasks
+                .find_all()
+                .await
+                .unwrap()
+                .iter()
+                .map(|task| task.title().value().to_string())
+                .collect();
+            titles.sort();
+            titles
+        }
+
+        async fn rename(&self, title: &str, new_title: &str) {
+            let mut task = self.find(title).await.unwrap();
+            task.change_title(TaskTitle::new(new_title).unwrap(), &SystemClock::default())
+                .unwrap();
+            self.tasks.update(task).await.unwrap();
+        }
+    }
+
+    async fn sync(
+        local: &Database,
+        remote: &Database,
+        resolver: &FixedResolver,
+    ) -> DatabaseSyncReport {
+        SyncDatabasesUseCase::new(local.replica(), remote.replica())
+            .sync(resolver, false)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sync_copies_new_tasks_both_ways() {
+        // Arrange
+        let local = Database::new("laptop");
+        let remote = Database::new("desktop");
+        let tag = local
+            .tags
+            .save(NewTag::new(
+                TagName::new("work").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let mut task = local.add("資料を作る").await;
+        task.add_tag(*tag.id(), &SystemClock::default()).unwrap();
+        local.tasks.update(task).await.unwrap();
+        remote.add("買い物").await;
+        let resolver = FixedResolver::new(ConflictResolution::Skip);
+
+        // Act
+        let first = sync(&local, &remote, &resolver).await;
+        let second = sync(&local, &remote, &resolver).await;
+
+        // Assert
+        assert!(first.changes.contains(&SyncChange {
+            side: SyncSide::Remote,
+            kind: SyncChangeKind::Created,
+            title: "資料を作る".to_string(),
+        }));
+        assert!(first.changes.contains(&SyncChange {
+            side: SyncSide::Local,
+            kind: SyncChangeKind::Created,
+            title: "買い物".to_string(),
+        }));
+        assert_eq!(first.changes.len(), 2);
+        assert!(second.changes.is_empty());
+        assert_eq!(local.titles().await, remote.titles().await);
+
+        let copied = remote.find("資料を作る").await.unwrap();
+        let remote_tags = remote.tags.find_all().await.unwrap();
+        assert_eq!(remote_tags.len(), 1);
+        assert_eq!(copied.tags(), &vec![*remote_tags[0].id()]);
+        assert_eq!(
+            sync_id(&copied),
+            sync_id(&local.find("資料を作る").await.unwrap())
+        );
+        assert_eq!(*resolver.conflicts.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sync_propagates_edits_and_deletions() {
+        // Arrange
+        let local = Database::new("laptop");
+        let remote = Database::new("desktop");
+        local.add("A").await;
+        local.add("B").await;
+        let resolver = FixedResolver::new(ConflictResolution::Skip);
+        sync(&local, &remote, &resolver).await;
+        local.rename("A", "A2").await;
+        let deleted = remote.find("B").await.unwrap();
+        remote.tasks.delete(deleted.id()).await.unwrap();
+
+        // Act
+        let report = sync(&local, &remote, &resolver).await;
+
+        // Assert
+        assert_eq!(local.titles().await, vec!["A2"]);
+        assert_eq!(remote.titles().await, vec!["A2"]);
+        assert!(report.changes.contains(&SyncChange {
+            side: SyncSide::Remote,
+            kind: SyncChangeKind::Updated,
+            title: "A2".to_string(),
+        }));
+        assert!(report.changes.contains(&SyncChange {
+            side: SyncSide::Local,
+            kind: SyncChangeKind::Deleted,
+            title: "B".to_string(),
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_sync_resolves_conflicts() {
+        // Arrange
+        let local = Database::new("laptop");
+        let remote = Database::new("desktop");
+        local.add("A").await;
+        sync(
+            &local,
+            &remote,
+            &FixedResolver::new(ConflictResolution::Skip),
+        )
+        .await;
+        local.rename("A", "A (laptop)").await;
+        remote.rename("A", "A (desktop)").await;
+
+        // Act
+        let skip = FixedResolver::new(ConflictResolution::Skip);
+        let skipped = sync(&local, &remote, &skip).await;
+        let keep_remote = FixedResolver::new(ConflictResolution::KeepRemote);
+        let resolved = sync(&local, &remote, &keep_remote).await;
+
+        // Assert: スキップした競合は次回の同期で再び確認する
+        assert_eq!(skipped.skipped, vec!["A (laptop)"]);
+        assert!(skipped.changes.is_empty());
+        assert_eq!(*keep_remote.conflicts.lock().unwrap(), 1);
+        assert!(resolved.skipped.is_empty());
+        assert_eq!(local.titles().await, vec!["A (desktop)"]);
+        assert_eq!(remote.titles().await, vec!["A (desktop)"]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_dry_run_does_not_write() {
+        // Arrange
+        let local = Database::new("laptop");
+        let remote = Database::new("desktop");
+        local.add("A").await;
+        let resolver = FixedResolver::new(ConflictResolution::Skip);
+
+        // Act
+        let report = SyncDatabasesUseCase::new(local.replica(), remote.replica())
+            .sync(&resolver, true)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(report.changes.len(), 1);
+        assert!(remote.titles().await.is_empty());
+        assert!(sync_id(&local.find("A").await.unwrap()).is_none());
+        assert!(local.state.load_base("desktop").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync_gives_copied_database_a_new_id() {
+        // Arrange: 同期済みのデータベースを複製すると、IDも同じになる
+        let local = Database::new("laptop");
+        let remote = Database::new("laptop");
+        local.add("A").await;
+
+        // Act
+        sync(
+            &local,
+            &remote,
+            &FixedResolver::new(ConflictResolution::Skip),
+        )
+        .await;
+
+        // Assert
+        let remote_id = remote.state.replica_id().await.unwrap();
+        assert_ne!(remote_id, "laptop");
+        assert_eq!(remote.titles().await, vec!["A"]);
+        assert!(!local.state.load_base(&remote_id).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pair_tasks_matches_copied_tasks() {
+        // Arrange: 同じデータベースを複製した場合は、IDと作成日時が同じになる
+        let task = TaskBuilder::new(TaskTitle::new("A").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let snapshot = |tasks: Vec<TaskAggregate>| Snapshot {
+            tasks,
+            tags: Vec::new(),
+            assigned: BTreeSet::new(),
+        };
+        let mut local = snapshot(vec![task.clone()]);
+        let mut remote = snapshot(vec![task]);
+
+        // Act
+        let pairs = pair_tasks(
+            &mut local,
+            &mut remote,
+            &SyncBase::new(),
+            &SystemClock::default(),
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(pairs.len(), 1);
+        let pair = pairs.values().next().unwrap();
+        assert_eq!((pair.local, pair.remote), (Some(0), Some(0)));
+        assert_eq!(sync_id(&local.tasks[0]), sync_id(&remote.tasks[0]));
+    }
+
+    #[tokio::test]
+    async fn test_first_sync_of_copies_reports_conflicts() {
+        // Arrange: 同期していないデータベースを複製し、それぞれで変更・削除する
+        let local = Database::new("laptop");
+        let remote = Database::new("desktop");
+        for title in ["A", "B", "C"] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap()).build();
+            local.tasks.save(task.clone()).await.unwrap();
+            remote.tasks.save(task).await.unwrap();
+        }
+        local.rename("A", "A2").await;
+        let deleted = remote.find("B").await.unwrap();
+        remote.tasks.delete(deleted.id()).await.unwrap();
+
+        // Act
+        let skip = FixedResolver::new(ConflictResolution::Skip);
+        let skipped = sync(&local, &remote, &skip).await;
+
+        // Assert: 複製せず、変更・削除を競合として確認する
+        assert_eq!(*skip.conflicts.lock().unwrap(), 2);
+        assert_eq!(skipped.skipped.len(), 2);
+        assert!(skipped.changes.is_empty());
+        assert_eq!(local.titles().await, vec!["A2", "B", "C"]);
+        assert_eq!(remote.titles().await, vec!["A", "C"]);
+
+        // Act: スキップした競合は次回の同期で再び確認する
+        let keep_remote = FixedResolver::new(ConflictResolution::KeepRemote);
+        let resolved = sync(&local, &remote, &keep_remote).await;
+
+        // Assert
+        assert_eq!(*keep_remote.conflicts.lock().unwrap(), 2);
+        assert!(resolved.skipped.is_empty());
+        assert_eq!(local.titles().await, vec!["A", "C"]);
+        assert_eq!(remote.titles().await, vec!["A", "C"]);
+    }
+}
@@ -0,0 +1,557 @@
+use crate::{
+    application::dto::TaskDTO,
+    domain::{
+        clock::{Clock, SystemClock},
+        tag::{
+            aggregate::{NewTag, TagAggregate},
+            repository::TagRepository,
+            value_objects::{TagDescription, TagId, TagName},
+        },
+        task::{
+            aggregate::TaskAggregate,
+            builder::TaskBuilder,
+            repository::TaskRepository,
+            status_transition::StatusTransitionPolicy,
+            value_objects::{DueDate, ExternalRef, Status, TaskDescription, TaskTitle},
+        },
+    },
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::sync::Arc;
+use tracing::instrument;
+
+/// タスクのタイトルの最大バイト数（TaskTitleの制約）
+const MAX_TITLE_BYTES: usize = 100;
+
+/// Issueトラッカーから取得したIssue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    /// ラベル名（タグとして取り込む）
+    pub labels: Vec<String>,
+    /// マイルストーンの期限（期限日として取り込む）
+    pub due_date: Option<NaiveDate>,
+    pub closed: bool,
+    pub url: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Issueトラッカー（GitHubなど）へのアクセス
+#[async_trait]
+pub trait IssueTracker: Send + Sync {
+    /// 対応付けに使う外部サービスの種類（`github`など）
+    fn source(&self) -> &str;
+
+    /// Issue番号から、Issueを一意に指す識別子（`owner/name#42`など）を作る
+    fn external_id(&self, number: u64) -> String;
+
+    /// このトラッカーのIssueを指す識別子であれば、Issue番号を返す
+    fn issue_number(&self, external_id: &str) -> Option<u64>;
+
+    /// Issueを取得する
+    ///
+    /// `since`がNoneの場合はオープンなIssueのみ、指定した場合はその日時以降に更新された
+    /// すべてのIssue（クローズ済みを含む）を返します。
+    async fn fetch_issues(&self, since: Option<DateTime<Utc>>) -> Result<Vec<RemoteIssue>>;
+
+    /// Issueをクローズ・再オープンし、変更後の更新日時を返す
+    async fn set_closed(&self, number: u64, closed: bool) -> Result<DateTime<Utc>>;
+}
+
+/// 同期の結果
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// Issueから作成したタスク
+    pub imported: Vec<TaskDTO>,
+    /// Issueの変更を反映したタスク
+    pub updated: Vec<TaskDTO>,
+    /// ステータスの変更をIssueに反映したタスク
+    pub pushed: Vec<TaskDTO>,
+    /// 反映できなかった項目
+    pub warnings: Vec<String>,
+}
+
+/// SyncIssuesUseCase - Issueトラッカーとの同期のユースケース
+///
+/// オープンなIssueをタスクとして取り込み（ラベル→タグ、マイルストーンの期限→期限日）、
+/// 前回の同期以降に更新されたIssueの変更をタスクに反映します。
+/// 前回の同期時点の状態をタスクの外部の項目との対応に記録し、差分だけを取得します。
+/// 返すTaskDTOのタグ情報は解決しません。
+pub struct SyncIssuesUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    transition_policy: StatusTransitionPolicy,
+    clock: Arc<dyn Clock>,
+}
+
+impl SyncIssuesUseCase {
+    /// 新しいSyncIssuesUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            transition_policy: StatusTransitionPolicy::default(),
+            clock: Arc::new(SystemClock::default()),
+        }
+    }
+
+    /// ステータスの遷移ポリシーを設定する
+    pub fn with_transition_policy(mut self, policy: StatusTransitionPolicy) -> Self {
+        self.transition_policy = policy;
+        self
+    }
+
+    /// ステータスの変更日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Issueトラッカーと同期する
+    ///
+    /// `push`を指定した場合は、取り込む前にタスクの完了・再開をIssueのクローズ・再オープンとして反映します。
+    /// 個々のタスク・Issueの反映に失敗した場合は`warnings`に記録して続行します。
+    #[instrument(name = "sync.issues", skip(self, tracker), fields(source = tracker.source()))]
+    pub async fn sync(&self, tracker: &dyn IssueTracker, push: bool) -> Result<SyncReport> {
+        let mut report = SyncReport::default();
+        let source = tracker.source().to_string();
+
+        let mut linked: Vec<(u64, TaskAggregate)> = self
+            .task_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter_map(|task| {
+                let number = task
+                    .external_refs()
+                    .iter()
+                    .filter(|external_ref| external_ref.source() == source)
+                    .find_map(|external_ref| tracker.issue_number(external_ref.external_id()))?;
+                Some((number, task))
+            })
+            .collect();
+        linked.sort_by_key(|(number, _)| *number);
+
+        let since = linked
+            .iter()
+            .filter_map(|(number, task)| {
+                task.external_ref(&source, &tracker.external_id(*number))
+                    .map(|external_ref| *external_ref.remote_updated_at())
+            })
+            .max();
+
+        if push {
+            for (number, task) in linked.iter_mut() {
+                if let Err(e) = self.push_status(tracker, *number, task, &mut report).await {
+                    report
+                        .warnings
+                        .push(format!("{}: {:#}", tracker.external_id(*number), e));
+                }
+            }
+        }
+
+        let mut tags = self.tag_repository.find_all().await?;
+        for issue in tracker.fetch_issues(since).await? {
+            let external_id = tracker.external_id(issue.number);
+            let result = match linked
+                .iter_mut()
+                .find(|(number, _)| *number == issue.number)
+            {
+                Some((_, task)) => {
+                    self.pull_issue(&source, &external_id, &issue, task, &mut tags, &mut report)
+                        .await
+                }
+                None if issue.closed => Ok(()),
+                None => {
+                    self.import_issue(&source, &external_id, &issue, &mut tags, &mut report)
+                        .await
+                }
+            };
+            if let Err(e) = result {
+                report.warnings.push(format!("{}: {:#}", external_id, e));
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// タスクの完了・再開をIssueに反映する
+    async fn push_status(
+        &self,
+        tracker: &dyn IssueTracker,
+        number: u64,
+        task: &mut TaskAggregate,
+        report: &mut SyncReport,
+    ) -> Result<()> {
+        let source = tracker.source().to_string();
+        let external_id = tracker.external_id(number);
+        let Some(external_ref) = task.external_ref(&source, &external_id) else {
+            return Ok(());
+        };
+        let closed = *task.status() == Status::Completed;
+        if closed == (external_ref.synced_status() == Status::Completed) {
+            return Ok(());
+        }
+
+        let remote_updated_at = tracker.set_closed(number, closed).await?;
+        let status = *task.status();
+        task.record_external_sync(&source, &external_id, remote_updated_at, status)?;
+        let updated = self.task_repository.update(task.clone()).await?;
+        report.pushed.push(TaskDTO::from(updated));
+        Ok(())
+    }
+
+    /// 前回の同期以降に更新されたIssueの変更をタスクに反映する
+    ///
+    /// ステータスは、Issueのクローズ・再オープンが前回の同期から変わった場合のみ反映し、
+    /// まだIssueに反映していないタスク側の変更は上書きしません。
+    async fn pull_issue(
+        &self,
+        source: &str,
+        external_id: &str,
+        issue: &RemoteIssue,
+        task: &mut TaskAggregate,
+        tags: &mut Vec<TagAggregate>,
+        report: &mut SyncReport,
+    ) -> Result<()> {
+        let Some(external_ref) = task.external_ref(source, external_id) else {
+            return Ok(());
+        };
+        if issue.updated_at <= *external_ref.remote_updated_at() {
+            return Ok(());
+        }
+        let was_closed = external_ref.synced_status() == Status::Completed;
+
+        let title = TaskTitle::new(truncate_title(&issue.title))?;
+        if task.title() != &title {
+            task.change_title(title, self.clock.as_ref())?;
+        }
+        if task.description().value() != issue.body {
+            task.change_description(
+                TaskDescription::new(issue.body.as_str())?,
+                self.clock.as_ref(),
+            )?;
+        }
+        let due_date = issue.due_date.map(DueDate::new).transpose()?;
+        if task.due_date() != &due_date {
+            task.change_due_date(due_date, self.clock.as_ref())?;
+        }
+        for tag_id in self.resolve_labels(&issue.labels, tags, report).await? {
+            if !task.tags().contains(&tag_id) {
+                task.add_tag(tag_id, self.clock.as_ref())?;
+            }
+        }
+
+        let completed = *task.status() == Status::Completed;
+        if issue.closed && !was_closed && !completed {
+            task.change_status(
+                Status::Completed,
+                &self.transition_policy,
+                self.clock.as_ref(),
+            )?;
+        } else if !issue.closed && was_closed && completed {
+            task.reopen(
+                Status::Pending,
+                &self.transition_policy,
+                self.clock.as_ref(),
+            )?;
+        }
+
+        let synced_status = if issue.closed {
+            Status::Completed
+        } else {
+            Status::Pending
+        };
+        task.record_external_sync(source, external_id, issue.updated_at, synced_status)?;
+        let updated = self.task_repository.update(task.clone()).await?;
+        report.updated.push(TaskDTO::from(updated));
+        Ok(())
+    }
+
+    /// オープンなIssueからタスクを作成する
+    async fn import_issue(
+        &self,
+        source: &str,
+        external_id: &str,
+        issue: &RemoteIssue,
+        tags: &mut Vec<TagAggregate>,
+        report: &mut SyncReport,
+    ) -> Result<()> {
+        let tag_ids = self.resolve_labels(&issue.labels, tags, report).await?;
+        let mut task = TaskBuilder::new(TaskTitle::new(truncate_title(&issue.title))?)
+            .description(TaskDescription::new(issue.body.as_str())?)
+            .tags(tag_ids)
+            .due_date(issue.due_date.map(DueDate::new).transpose()?)
+            .build();
+        task.add_external_ref(ExternalRef::new(
+            source,
+            external_id,
+            issue.url.clone(),
+            issue.updated_at,
+            Status::Pending,
+        )?)?;
+
+        let saved = self.task_repository.save(task).await?;
+        report.imported.push(TaskDTO::from(saved));
+        Ok(())
+    }
+
+    /// ラベル名をタグIDに解決する（存在しないタグは作成する）
+    ///
+    /// タグ名として使えないラベルは`warnings`に記録して無視します。
+    async fn resolve_labels(
+        &self,
+        labels: &[String],
+        tags: &mut Vec<TagAggregate>,
+        report: &mut SyncReport,
+    ) -> Result<Vec<TagId>> {
+        let mut tag_ids = Vec::new();
+        for label in labels {
+            let tag_id = match tags.iter().find(|tag| tag.name().matches(label)) {
+                Some(tag) => *tag.id(),
+                None => {
+                    let name = match TagName::new(label.as_str()) {
+                        Ok(name) => name,
+                        Err(e) => {
+                            report
+                                .warnings
+                                .push(format!("Label '{}' was not imported: {:#}", label, e));
+                            continue;
+                        }
+                    };
+                    let tag = NewTag::new(name, TagDescription::new("")?);
+                    let saved = self.tag_repository.save(tag).await?;
+                    let id = *saved.id();
+                    tags.push(saved);
+                    id
+                }
+            };
+            if !tag_ids.contains(&tag_id) {
+                tag_ids.push(tag_id);
+            }
+        }
+        Ok(tag_ids)
+    }
+}
+
+/// タイトルをタスクのタイトルの長さの上限に収まるよう、文字の境界で切り詰める
+pub(crate) fn truncate_title(title: &str) -> String {
+    let title = title.trim();
+    if title.len() <= MAX_TITLE_BYTES {
+        return title.to_string();
+    }
+    let mut end = MAX_TITLE_BYTES;
+    while !title.is_char_boundary(end) {
+        end -= 1;
+    }
+    title[..end].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::value_objects::TaskId;
+    use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
+    use chrono::Duration;
+    use std::sync::Mutex;
+
+    /// メモリ上のIssueを返すトラッカー
+    struct FakeTracker {
+        issues: Mutex<Vec<RemoteIssue>>,
+        /// 最後に受け取った`since`
+        since: Mutex<Option<DateTime<Utc>>>,
+    }
+
+    impl FakeTracker {
+        fn new(issues: Vec<RemoteIssue>) -> Self {
+            Self {
+                issues: Mutex::new(issues),
+                since: Mutex::new(None),
+            }
+        }
+
+        fn edit(&self, number: u64, f: impl FnOnce(&mut RemoteIssue)) {
+            let mut issues = self.issues.lock().unwrap();
+            // 更新したIssueが最も新しく更新されたIssueになる
+            let latest = issues.iter().map(|i| i.updated_at).max().unwrap();
+            let issue = issues.iter_mut().find(|i| i.number == number).unwrap();
+            f(issue);
+            issue.updated_at = latest + Duration::minutes(1);
+        }
+    }
+
+    #[async_trait]
+    impl IssueTracker for FakeTracker {
+        fn source(&self) -> &str {
+            "github"
+        }
+
+        fn external_id(&self, number: u64) -> String {
+            format!("acme/app#{}", number)
+        }
+
+        fn issue_number(&self, external_id: &str) -> Option<u64> {
+            external_id.strip_prefix("acme/app#")?.parse().ok()
+        }
+
+        async fn fetch_issues(&self, since: Option<DateTime<Utc>>) -> Result<Vec<RemoteIssue>> {
+            *self.since.lock().unwrap() = since;
+            Ok(self
+                .issues
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|issue| match since {
+                    Some(since) => issue.updated_at >= since,
+                    None => !issue.closed,
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn set_closed(&self, number: u64, closed: bool) -> Result<DateTime<Utc>> {
+            self.edit(number, |issue| issue.closed = closed);
+            let issues = self.issues.lock().unwrap();
+            Ok(issues
+                .iter()
+                .find(|i| i.number == number)
+                .unwrap()
+                .updated_at)
+        }
+    }
+
+    fn issue(number: u64, title: &str, labels: &[&str]) -> RemoteIssue {
+        RemoteIssue {
+            number,
+            title: title.to_string(),
+            body: format!("body of {}", number),
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+            due_date: None,
+            closed: false,
+            url: Some(format!("https://github.com/acme/app/issues/{}", number)),
+            updated_at: DateTime::parse_from_rfc3339("2026-10-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc)
+                + Duration::hours(number as i64),
+        }
+    }
+
+    fn setup() -> (Arc<InMemoryTaskRepository>, SyncIssuesUseCase) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let use_case = SyncIssuesUseCase::new(task_repo.clone(), tag_repo);
+        (task_repo, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_sync_imports_open_issues() {
+        // Arrange
+        let (task_repo, use_case) = setup();
+        let mut first = issue(1, "Fix login", &["bug", "Bug"]);
+        first.due_date = NaiveDate::from_ymd_opt(2026, 12, 1);
+        let mut closed = issue(2, "Old issue", &[]);
+        closed.closed = true;
+        let tracker = FakeTracker::new(vec![first, closed, issue(3, "Add search", &["bug"])]);
+
+        // Act
+        let report = use_case.sync(&tracker, false).await.unwrap();
+
+        // Assert
+        let titles: Vec<&str> = report.imported.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Fix login", "Add search"]);
+        assert!(report.warnings.is_empty());
+        let task = task_repo
+            .find_by_id(&TaskId::new(1).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.tags().len(), 1);
+        assert_eq!(task.due_date().unwrap().value().to_string(), "2026-12-01");
+        assert_eq!(task.description().value(), "body of 1");
+        assert!(task.external_ref("github", "acme/app#1").is_some());
+        assert_eq!(
+            report.imported[1].external_refs[0].external_id,
+            "acme/app#3"
+        );
+        assert_eq!(*tracker.since.lock().unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_sync_pulls_changes_incrementally() {
+        // Arrange
+        let (task_repo, use_case) = setup();
+        let tracker = FakeTracker::new(vec![issue(1, "Fix login", &[]), issue(2, "Docs", &[])]);
+        use_case.sync(&tracker, false).await.unwrap();
+        tracker.edit(1, |issue| {
+            issue.title = "Fix login page".to_string();
+            issue.closed = true;
+        });
+
+        // Act
+        let report = use_case.sync(&tracker, false).await.unwrap();
+        let again = use_case.sync(&tracker, false).await.unwrap();
+
+        // Assert
+        assert!(report.imported.is_empty());
+        assert_eq!(report.updated.len(), 1);
+        assert_eq!(report.updated[0].title, "Fix login page");
+        assert_eq!(report.updated[0].status, Status::Completed);
+        assert!(again.updated.is_empty());
+        assert_eq!(
+            *tracker.since.lock().unwrap(),
+            Some(tracker.issues.lock().unwrap()[0].updated_at)
+        );
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_sync_pushes_status_changes() {
+        // Arrange
+        let (task_repo, use_case) = setup();
+        let tracker = FakeTracker::new(vec![issue(1, "Fix login", &[]), issue(2, "Docs", &[])]);
+        use_case.sync(&tracker, false).await.unwrap();
+        let mut task = task_repo
+            .find_by_id(&TaskId::new(1).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        task.complete(&FixedClock::at(2026, 1, 10, 9, 0)).unwrap();
+        task_repo.update(task).await.unwrap();
+
+        // Act
+        let without_push = use_case.sync(&tracker, false).await.unwrap();
+        let report = use_case.sync(&tracker, true).await.unwrap();
+
+        // Assert
+        assert!(without_push.pushed.is_empty());
+        assert!(without_push.updated.is_empty());
+        let pushed: Vec<i32> = report.pushed.iter().map(|t| t.id).collect();
+        assert_eq!(pushed, vec![1]);
+        assert!(tracker.issues.lock().unwrap()[0].closed);
+        // 反映したIssueの更新は次の取得でタスクの変更として扱わない
+        assert!(report.updated.is_empty());
+        let task = task_repo
+            .find_by_id(&TaskId::new(1).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*task.status(), Status::Completed);
+    }
+
+    #[test]
+    fn test_truncate_title() {
+        assert_eq!(truncate_title(" short "), "short");
+        let long = "あ".repeat(40);
+        let truncated = truncate_title(&long);
+        assert_eq!(truncated, "あ".repeat(33));
+        assert!(TaskTitle::new(truncated).is_ok());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod record_time_entry;
+pub mod report_estimate_accuracy;
@@ -0,0 +1,120 @@
+use crate::{
+    application::dto::TimeEntryDTO,
+    domain::{
+        task::{repository::TaskRepository, value_objects::TaskId},
+        time_entry::{aggregate::NewTimeEntry, repository::TimeEntryRepository},
+    },
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tracing::instrument;
+
+/// RecordTimeEntryUseCase - 作業記録のユースケース
+///
+/// タスクに費やした作業時間（ポモドーロの作業フェーズなど）を記録します。
+pub struct RecordTimeEntryUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    time_entry_repository: Arc<dyn TimeEntryRepository>,
+}
+
+impl RecordTimeEntryUseCase {
+    /// 新しいRecordTimeEntryUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        time_entry_repository: Arc<dyn TimeEntryRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            time_entry_repository,
+        }
+    }
+
+    /// 作業記録を保存する
+    ///
+    /// # Arguments
+    /// * `task_id` - 作業したタスクのID
+    /// * `started_at` - 作業の開始日時
+    /// * `ended_at` - 作業の終了日時
+    ///
+    /// # Returns
+    /// * `Ok(TimeEntryDTO)` - 保存された作業記録
+    /// * `Err` - タスクが存在しない場合、または終了日時が開始日時より前の場合
+    #[instrument(name = "record_time_entry", skip(self))]
+    pub async fn execute(
+        &self,
+        task_id: i32,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+    ) -> Result<TimeEntryDTO> {
+        let task_id = TaskId::new(task_id)?;
+        if self.task_repository.find_by_id(&task_id).await?.is_none() {
+            anyhow::bail!("Task ID {} does not exist", task_id.value());
+        }
+
+        let entry = NewTimeEntry::new(task_id, started_at, ended_at)?;
+        let saved = self.time_entry_repository.save(entry).await?;
+
+        Ok(TimeEntryDTO::from(saved))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::task::{
+            builder::TaskBuilder,
+            value_objects::{Status, TaskTitle},
+        },
+        interface::persistence::in_memory::{InMemoryTaskRepository, InMemoryTimeEntryRepository},
+    };
+    use chrono::TimeDelta;
+
+    #[tokio::test]
+    async fn test_record_time_entry_success() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let time_entry_repo = Arc::new(InMemoryTimeEntryRepository::new());
+        let task = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("資料作成").unwrap())
+                    .status(Status::InProgress)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let use_case = RecordTimeEntryUseCase::new(task_repo, time_entry_repo.clone());
+        let started_at = Utc::now() - TimeDelta::minutes(25);
+
+        // Act
+        let entry = use_case
+            .execute(task.id().value(), started_at, Utc::now())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(entry.task_id, task.id().value());
+        assert_eq!(entry.duration_minutes, 25);
+        assert_eq!(
+            time_entry_repo.find_by_task(task.id()).await.unwrap().len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_time_entry_unknown_task() {
+        // Arrange
+        let use_case = RecordTimeEntryUseCase::new(
+            Arc::new(InMemoryTaskRepository::new()),
+            Arc::new(InMemoryTimeEntryRepository::new()),
+        );
+        let now = Utc::now();
+
+        // Act
+        let result = use_case.execute(99, now, now).await;
+
+        // Assert
+        assert_eq!(result.unwrap_err().to_string(), "Task ID 99 does not exist");
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{
+    application::dto::{EstimateAccuracyDTO, EstimateAccuracyRowDTO},
+    domain::{
+        tag::repository::TagRepository,
+        task::{aggregate::TaskAggregate, repository::TaskRepository, value_objects::Status},
+        time_entry::repository::TimeEntryRepository,
+    },
+};
+use anyhow::Result;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
+use tracing::instrument;
+
+/// 見積もりの精度レポートの集計単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccuracyGroupBy {
+    /// タスクごと（ID順）
+    #[default]
+    Task,
+    /// タグごと（タグ名順、タグのないタスクは最後にまとめる）
+    Tag,
+}
+
+/// ReportEstimateAccuracyUseCase - 見積もり時間と実績時間を比べるユースケース
+///
+/// タスクの見積もりと、作業記録（ポモドーロの作業フェーズなど）の合計を比べ、
+/// 計画の見直しに使えるよう差の割合を集計します。
+/// 既定では完了済みのタスクだけを対象にします（作業中のタスクは実績が確定していないため）。
+pub struct ReportEstimateAccuracyUseCase {
+    task_repository: Arc<dyn TaskRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    time_entry_repository: Arc<dyn TimeEntryRepository>,
+}
+
+impl ReportEstimateAccuracyUseCase {
+    /// 新しいReportEstimateAccuracyUseCaseを作成
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        time_entry_repository: Arc<dyn TimeEntryRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+            time_entry_repository,
+        }
+    }
+
+    /// 見積もりと実績を比べたレポートを作成する
+    ///
+    /// # Arguments
+    /// * `group_by` - 集計単位
+    /// * `include_open` - 未完了のタスクも含めるか
+    #[instrument(name = "report_estimate_accuracy", skip(self))]
+    pub async fn execute(
+        &self,
+        group_by: AccuracyGroupBy,
+        include_open: bool,
+    ) -> Result<EstimateAccuracyDTO> {
+        // タスクIDごとの実績時間（秒）
+        let mut actual_seconds: HashMap<i32, i64> = HashMap::new();
+        for entry in self.time_entry_repository.find_all().await? {
+            *actual_seconds.entry(entry.task_id().value()).or_default() +=
+                entry.duration().num_seconds();
+        }
+
+        let mut untracked_count = 0;
+        let mut measured = Vec::new();
+        for task in self.task_repository.find_all().await? {
+            let Some(estimate) = task.estimate() else {
+                continue;
+            };
+            if !include_open && *task.status() != Status::Completed {
+                continue;
+            }
+            match actual_seconds.get(&task.id().value()) {
+                Some(seconds) => measured.push((task, estimate.minutes(), to_minutes(*seconds))),
+                None => untracked_count += 1,
+            }
+        }
+        measured.sort_by_key(|(task, _, _)| task.id().value());
+
+        let rows = match group_by {
+            AccuracyGroupBy::Task => measured
+                .iter()
+                .map(|(task, estimated, actual)| EstimateAccuracyRowDTO {
+                    label: format!("[{}] {}", task.id().value(), task.title().value()),
+                    task_count: 1,
+                    estimated_minutes: *estimated,
+                    actual_minutes: *actual,
+                })
+                .collect(),
+            AccuracyGroupBy::Tag => self.rows_by_tag(&measured).await?,
+        };
+        let total = measured.iter().fold(
+            EstimateAccuracyRowDTO {
+                label: String::new(),
+                task_count: 0,
+                estimated_minutes: 0,
+                actual_minutes: 0,
+            },
+            |mut total, (_, estimated, actual)| {
+                add_to_row(&mut total, *estimated, *actual);
+                total
+            },
+        );
+
+        Ok(EstimateAccuracyDTO {
+            by_tag: group_by == AccuracyGroupBy::Tag,
+            rows,
+            total,
+            untracked_count,
+        })
+    }
+
+    /// タグごとに集計する（複数のタグを持つタスクは各タグに含める）
+    async fn rows_by_tag(
+        &self,
+        measured: &[(TaskAggregate, u32, u32)],
+    ) -> Result<Vec<EstimateAccuracyRowDTO>> {
+        let tag_ids: Vec<_> = measured
+            .iter()
+            .flat_map(|(task, _, _)| task.tags().iter().copied())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let tags = self.tag_repository.find_by_ids(&tag_ids).await?;
+        let tag_names: HashMap<_, _> = tags
+            .iter()
+            .map(|tag| (tag.id().value(), tag.name().value()))
+            .collect();
+
+        // (並び順, タグ名) -> 集計
+        let mut rows: BTreeMap<(usize, String), EstimateAccuracyRowDTO> = BTreeMap::new();
+        for (task, estimated, actual) in measured {
+            let names: Vec<_> = task
+                .tags()
+                .iter()
+                .filter_map(|tag_id| tag_names.get(&tag_id.value()))
+                .map(|name| (0, name.to_string()))
+                .collect();
+            let keys = if names.is_empty() {
+                // タグなしのタスクは最後にまとめる
+                vec![(1, "(No tags)".to_string())]
+            } else {
+                names
+            };
+            for key in keys {
+                let row = rows
+                    .entry(key.clone())
+                    .or_insert_with(|| EstimateAccuracyRowDTO {
+                        label: key.1,
+                        task_count: 0,
+                        estimated_minutes: 0,
+                        actual_minutes: 0,
+                    });
+                add_to_row(row, *estimated, *actual);
+            }
+        }
+
+        Ok(rows.into_values().collect())
+    }
+}
+
+/// 1件のタスクの見積もりと実績を集計に加える
+fn add_to_row(row: &mut EstimateAccuracyRowDTO, estimated: u32, actual: u32) {
+    row.task_count += 1;
+    row.estimated_minutes += estimated;
+    row.actual_minutes += actual;
+}
+
+/// 秒を分に変換（四捨五入）
+fn to_minutes(seconds: i64) -> u32 {
+    u32::try_from((seconds.max(0) + 30) / 60).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            tag::{
+                aggregate::NewTag,
+                value_objects::{TagDescription, TagName},
+            },
+            task::{builder::TaskBuilder, value_objects::TaskTitle},
+            time_entry::aggregate::NewTimeEntry,
+        },
+        interface::persistence::in_memory::{
+            InMemoryTagRepository, InMemoryTaskRepository, InMemoryTimeEntryRepository,
+        },
+    };
+    use chrono::{TimeDelta, Utc};
+
+    async fn setup() -> ReportEstimateAccuracyUseCase {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let time_entry_repo = Arc::new(InMemoryTimeEntryRepository::new());
+        let work = tag_repo
+            .save(NewTag::new(
+                TagName::new("仕事".to_string()).unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let started_at = Utc::now() - TimeDelta::hours(10);
+        for (title, status, tags, estimate, actual_minutes) in [
+            (
+                "資料作成",
+                Status::Completed,
+                vec![*work.id()],
+                Some("1h"),
+                Some(75),
+            ),
+            (
+                "レビュー",
+                Status::Completed,
+                vec![*work.id()],
+                Some("1h"),
+                Some(30),
+            ),
+            ("掃除", Status::Completed, vec![], Some("30m"), Some(30)),
+            ("作業中", Status::InProgress, vec![], Some("2h"), Some(60)),
+            ("記録なし", Status::Completed, vec![], Some("1h"), None),
+            ("見積もりなし", Status::Completed, vec![], None, Some(45)),
+        ] {
+            let task = TaskBuilder::new(TaskTitle::new(title).unwrap())
+                .status(status)
+                .tags(tags)
+                .estimate(estimate.map(|e| e.parse().unwrap()))
+                .build();
+            let task = task_repo.save(task).await.unwrap();
+            if let Some(minutes) = actual_minutes {
+                let entry = NewTimeEntry::new(
+                    *task.id(),
+                    started_at,
+                    started_at + TimeDelta::minutes(minutes),
+                )
+                .unwrap();
+                time_entry_repo.save(entry).await.unwrap();
+            }
+        }
+        ReportEstimateAccuracyUseCase::new(task_repo, tag_repo, time_entry_repo)
+    }
+
+    #[tokio::test]
+    async fn test_report_by_task_compares_completed_tasks() {
+        // Arrange
+        let use_case = setup().await;
+
+        // Act
+        let report = use_case
+            .execute(AccuracyGroupBy::Task, false)
+            .await
+            .unwrap();
+
+        // Assert
+        let rows: Vec<_> = report
+            .rows
+            .iter()
+            .map(|row| {
+                (
+                    row.label.as_str(),
+                    row.estimated_minutes,
+                    row.actual_minutes,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("[1] 資料作成", 60, 75),
+                ("[2] レビュー", 60, 30),
+                ("[3] 掃除", 30, 30)
+            ]
+        );
+        assert_eq!(report.total.task_count, 3);
+        assert_eq!(report.total.estimated_minutes, 150);
+        assert_eq!(report.total.actual_minutes, 135);
+        assert_eq!(report.total.variance_percent(), Some(-10));
+        assert_eq!(report.untracked_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_report_by_tag_including_open_tasks() {
+        // Arrange
+        let use_case = setup().await;
+
+        // Act
+        let report = use_case.execute(AccuracyGroupBy::Tag, true).await.unwrap();
+
+        // Assert
+        let rows: Vec<_> = report
+            .rows
+            .iter()
+            .map(|row| {
+                (
+                    row.label.as_str(),
+                    row.task_count,
+                    row.estimated_minutes,
+                    row.actual_minutes,
+                )
+            })
+            .collect();
+        assert_eq!(rows, vec![("仕事", 2, 120, 105), ("(No tags)", 2, 150, 90)]);
+        assert_eq!(report.total.task_count, 4);
+    }
+}
@@ -1,3 +1,6 @@
+pub mod clock;
 pub mod services;
 pub mod tag;
 pub mod task;
+pub mod time_entry;
+pub mod unit_of_work;
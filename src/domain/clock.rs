@@ -0,0 +1,200 @@
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, Utc};
+
+/// 日付の基準にするタイムゾーン
+///
+/// 「今日」がいつかはタイムゾーンによって変わります。期限切れの判定などで、
+/// UTCではなく利用者のタイムゾーンの日付を使うために指定します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timezone {
+    /// システムのタイムゾーン
+    #[default]
+    Local,
+    /// UTC
+    Utc,
+    /// 固定のオフセット（`+09:00`など）
+    Fixed(FixedOffset),
+}
+
+impl Timezone {
+    /// UTCの日時を、このタイムゾーンでの日付に変換
+    pub fn date_of(&self, at: DateTime<Utc>) -> NaiveDate {
+        match self {
+            Self::Local => at.with_timezone(&Local).date_naive(),
+            Self::Utc => at.date_naive(),
+            Self::Fixed(offset) => at.with_timezone(offset).date_naive(),
+        }
+    }
+}
+
+impl std::str::FromStr for Timezone {
+    type Err = anyhow::Error;
+
+    /// `local`, `utc`、または`+09:00`形式のオフセットを受け付ける
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(Self::Local),
+            "utc" | "z" => Ok(Self::Utc),
+            _ => s.parse::<FixedOffset>().map(Self::Fixed).map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid timezone: {} (expected local, utc, or an offset like +09:00)",
+                    s
+                )
+            }),
+        }
+    }
+}
+
+/// Clock trait - 現在日時の取得を抽象化する
+///
+/// 「今日」の判定を設定したタイムゾーンで行うために、ユースケースやTUIへ注入します。
+pub trait Clock: Send + Sync {
+    /// 現在の日時（UTC）
+    fn now(&self) -> DateTime<Utc>;
+
+    /// 日付の基準にするタイムゾーン
+    fn timezone(&self) -> Timezone;
+
+    /// 基準のタイムゾーンでの今日の日付
+    fn today(&self) -> NaiveDate {
+        self.timezone().date_of(self.now())
+    }
+}
+
+/// SystemClock - システムの現在日時を返すClock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SystemClock {
+    timezone: Timezone,
+}
+
+impl SystemClock {
+    /// タイムゾーンを指定して作成
+    pub fn new(timezone: Timezone) -> Self {
+        Self { timezone }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn timezone(&self) -> Timezone {
+        self.timezone
+    }
+}
+
+/// FixedClock - 常に同じ日時を返すClock（テスト用）
+///
+/// 時刻を固定して、日付の境界をまたぐ処理などを実行のたびに同じ結果で検証するために使用します。
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    now: DateTime<Utc>,
+    timezone: Timezone,
+}
+
+#[cfg(test)]
+impl FixedClock {
+    /// 現在日時を固定して作成（タイムゾーンはUTC）
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now,
+            timezone: Timezone::Utc,
+        }
+    }
+
+    /// 年月日と時刻（UTC）を指定して作成
+    pub fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> Self {
+        use chrono::TimeZone;
+        Self::new(
+            Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+                .single()
+                .expect("valid date and time"),
+        )
+    }
+
+    /// 指定した日の正午（UTC）に固定して作成
+    pub fn on(date: NaiveDate) -> Self {
+        Self::new(date.and_hms_opt(12, 0, 0).expect("valid time").and_utc())
+    }
+
+    /// 日付の基準にするタイムゾーンを設定
+    pub fn with_timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// 固定した日時を`delta`だけ進めたClock
+    pub fn advanced(self, delta: chrono::TimeDelta) -> Self {
+        Self {
+            now: self.now + delta,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    fn timezone(&self) -> Timezone {
+        self.timezone
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_timezone_from_str() {
+        assert_eq!("local".parse::<Timezone>().unwrap(), Timezone::Local);
+        assert_eq!("UTC".parse::<Timezone>().unwrap(), Timezone::Utc);
+        assert_eq!(
+            "+09:00".parse::<Timezone>().unwrap(),
+            Timezone::Fixed(FixedOffset::east_opt(9 * 3600).unwrap())
+        );
+        assert!("Asia/Tokyo".parse::<Timezone>().is_err());
+    }
+
+    #[test]
+    fn test_date_of_near_midnight() {
+        // Arrange: UTC 2026-01-10 20:30は日本時間で翌日5:30、ニューヨーク時間で当日15:30
+        let at = Utc.with_ymd_and_hms(2026, 1, 10, 20, 30, 0).unwrap();
+        let tokyo = Timezone::Fixed(FixedOffset::east_opt(9 * 3600).unwrap());
+        let new_york = Timezone::Fixed(FixedOffset::west_opt(5 * 3600).unwrap());
+
+        // Act & Assert
+        assert_eq!(
+            tokyo.date_of(at),
+            NaiveDate::from_ymd_opt(2026, 1, 11).unwrap()
+        );
+        assert_eq!(
+            new_york.date_of(at),
+            NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()
+        );
+        assert_eq!(
+            Timezone::Utc.date_of(at),
+            NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fixed_clock_today_in_timezone() {
+        // Arrange
+        let clock = FixedClock::at(2026, 1, 10, 20, 30);
+        let tokyo = clock.with_timezone(Timezone::Fixed(FixedOffset::east_opt(9 * 3600).unwrap()));
+
+        // Act & Assert
+        assert_eq!(clock.today(), NaiveDate::from_ymd_opt(2026, 1, 10).unwrap());
+        assert_eq!(tokyo.today(), NaiveDate::from_ymd_opt(2026, 1, 11).unwrap());
+        assert_eq!(
+            clock.advanced(chrono::TimeDelta::hours(4)).today(),
+            NaiveDate::from_ymd_opt(2026, 1, 11).unwrap()
+        );
+    }
+}
@@ -1,3 +1,11 @@
+pub mod business_calendar_service;
+pub mod duplicate_title_service;
+pub mod flow_statistics_service;
 pub mod task_statistics_service;
+pub mod urgency_service;
 
-pub use task_statistics_service::TaskStatisticsService;
+pub use business_calendar_service::BusinessCalendar;
+pub use duplicate_title_service::DuplicateTitleDetector;
+pub use flow_statistics_service::FlowStatsCollector;
+pub use task_statistics_service::{TaskStatisticsService, TaskStatsCollector};
+pub use urgency_service::{UrgencyCalculator, UrgencyWeights};
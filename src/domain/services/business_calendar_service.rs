@@ -0,0 +1,159 @@
+use anyhow::{Context, Result, bail};
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+
+/// BusinessCalendar - 営業日を判定するドメインサービス
+///
+/// 土曜日・日曜日と、登録した祝日を休日として扱います。
+/// 営業日単位の期限日（`+3bd`）、繰り返しのタスクの期限日、期限切れの判定に使用します。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl BusinessCalendar {
+    /// 休日が続く日数の上限（祝日の登録ミスなどで探索が終わらないことを防ぐ）
+    const MAX_CONSECUTIVE_HOLIDAYS: u32 = 366;
+
+    /// 祝日を指定して作成
+    pub fn with_holidays(holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self {
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+
+    /// 営業日（土日・祝日以外）かどうか
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+    }
+
+    /// `date`の`days`営業日後の日付
+    ///
+    /// `date`自体が休日でも、翌日以降の営業日から数えます（金曜日の1営業日後は月曜日）。
+    pub fn add_business_days(&self, date: NaiveDate, days: u32) -> Result<NaiveDate> {
+        let mut current = date;
+        for _ in 0..days {
+            current = self.next_business_day_after(current)?;
+        }
+        Ok(current)
+    }
+
+    /// `date`が営業日ならその日、休日なら次の営業日
+    pub fn roll_forward(&self, date: NaiveDate) -> Result<NaiveDate> {
+        if self.is_business_day(date) {
+            Ok(date)
+        } else {
+            self.next_business_day_after(date)
+        }
+    }
+
+    /// 期限日`due_date`のタスクが`today`の時点で期限切れかどうか
+    ///
+    /// 期限日の翌営業日になった時点で期限切れとします。金曜日が期限のタスクは、
+    /// 土日の間は期限切れにならず、月曜日（祝日なら火曜日）に期限切れになります。
+    pub fn is_overdue(&self, due_date: NaiveDate, today: NaiveDate) -> bool {
+        if due_date >= today {
+            return false;
+        }
+        self.next_business_day_after(due_date)
+            .is_ok_and(|overdue_from| overdue_from <= today)
+    }
+
+    /// `date`より後で最初の営業日
+    fn next_business_day_after(&self, date: NaiveDate) -> Result<NaiveDate> {
+        let mut current = date;
+        for _ in 0..Self::MAX_CONSECUTIVE_HOLIDAYS {
+            current = current
+                .checked_add_days(Days::new(1))
+                .with_context(|| format!("No business day after {} is in range", date))?;
+            if self.is_business_day(current) {
+                return Ok(current);
+            }
+        }
+        bail!(
+            "No business day within {} days after {}",
+            Self::MAX_CONSECUTIVE_HOLIDAYS,
+            date
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_is_business_day() {
+        // Arrange: 2026-01-02は金曜日、2026-01-01は祝日
+        let calendar = BusinessCalendar::with_holidays([date(1, 1)]);
+
+        // Act & Assert
+        assert!(calendar.is_business_day(date(1, 2)));
+        assert!(!calendar.is_business_day(date(1, 3)));
+        assert!(!calendar.is_business_day(date(1, 4)));
+        assert!(!calendar.is_business_day(date(1, 1)));
+    }
+
+    #[test]
+    fn test_add_business_days_skips_weekends_and_holidays() {
+        // Arrange: 2026-01-09（金）の後、1-12（月）は祝日
+        let calendar = BusinessCalendar::with_holidays([date(1, 12)]);
+
+        // Act & Assert
+        assert_eq!(
+            calendar.add_business_days(date(1, 9), 1).unwrap(),
+            date(1, 13)
+        );
+        assert_eq!(
+            calendar.add_business_days(date(1, 7), 3).unwrap(),
+            date(1, 13)
+        );
+        assert_eq!(
+            calendar.add_business_days(date(1, 10), 0).unwrap(),
+            date(1, 10)
+        );
+    }
+
+    #[test]
+    fn test_add_business_days_from_weekend() {
+        let calendar = BusinessCalendar::default();
+
+        // 土曜日の1営業日後は月曜日
+        assert_eq!(
+            calendar.add_business_days(date(1, 10), 1).unwrap(),
+            date(1, 12)
+        );
+    }
+
+    #[test]
+    fn test_roll_forward() {
+        let calendar = BusinessCalendar::with_holidays([date(1, 12)]);
+
+        assert_eq!(calendar.roll_forward(date(1, 9)).unwrap(), date(1, 9));
+        assert_eq!(calendar.roll_forward(date(1, 10)).unwrap(), date(1, 13));
+    }
+
+    #[test]
+    fn test_is_overdue_waits_for_next_business_day() {
+        // Arrange: 2026-01-09（金）が期限
+        let calendar = BusinessCalendar::default();
+        let due = date(1, 9);
+
+        // Act & Assert
+        assert!(!calendar.is_overdue(due, date(1, 9)));
+        assert!(!calendar.is_overdue(due, date(1, 10)));
+        assert!(!calendar.is_overdue(due, date(1, 11)));
+        assert!(calendar.is_overdue(due, date(1, 12)));
+    }
+
+    #[test]
+    fn test_is_overdue_on_weekday() {
+        let calendar = BusinessCalendar::default();
+
+        assert!(calendar.is_overdue(date(1, 6), date(1, 7)));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::domain::task::{aggregate::TaskAggregate, value_objects::Status};
+
+/// DuplicateTitleDetector - タイトルが似ている未完了タスクを検出するドメインサービス
+///
+/// タイトルを正規化（大文字小文字・空白・記号の違いを無視）したうえで、
+/// レーベンシュタイン距離から求めた類似度がしきい値以上のタスクを重複候補とします。
+pub struct DuplicateTitleDetector {
+    threshold: f64,
+}
+
+/// 重複候補のタスクと類似度
+#[derive(Debug)]
+pub struct SimilarTask<'a> {
+    pub task: &'a TaskAggregate,
+    /// 類似度（0.0〜1.0、1.0は正規化後に一致）
+    pub similarity: f64,
+}
+
+impl Default for DuplicateTitleDetector {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_THRESHOLD)
+    }
+}
+
+impl DuplicateTitleDetector {
+    /// 既定の類似度のしきい値
+    pub const DEFAULT_THRESHOLD: f64 = 0.8;
+
+    /// 類似度のしきい値（0.0〜1.0）を指定して作成
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold: threshold.clamp(0.0, 1.0),
+        }
+    }
+
+    /// タイトルが似ている未完了のタスクを、類似度の高い順に返す
+    pub fn find_similar<'a>(
+        &self,
+        title: &str,
+        tasks: &'a [TaskAggregate],
+    ) -> Vec<SimilarTask<'a>> {
+        let normalized = normalize(title);
+        let mut similar: Vec<_> = tasks
+            .iter()
+            .filter(|task| *task.status() != Status::Completed)
+            .map(|task| SimilarTask {
+                task,
+                similarity: similarity(&normalized, &normalize(task.title().value())),
+            })
+            .filter(|candidate| candidate.similarity >= self.threshold)
+            .collect();
+        similar.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        similar
+    }
+}
+
+/// 比較用にタイトルを正規化する（小文字化し、英数字以外の記号と空白を取り除く）
+fn normalize(title: &str) -> Vec<char> {
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// 正規化したタイトル同士の類似度
+fn similarity(a: &[char], b: &[char]) -> f64 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / longest as f64
+}
+
+/// レーベンシュタイン距離（挿入・削除・置換の最小回数）
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::value_objects::{TaskId, TaskTitle};
+
+    fn task(title: &str, status: Status) -> TaskAggregate {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .status(status)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap())
+    }
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(levenshtein(&chars(""), &chars("abc")), 3);
+        assert_eq!(levenshtein(&chars("買い物"), &chars("買い物")), 0);
+    }
+
+    #[test]
+    fn test_normalize_ignores_case_spaces_and_punctuation() {
+        assert_eq!(normalize("Write  Report!"), normalize("write report"));
+        assert_eq!(normalize("資料 作成。"), normalize("資料作成"));
+    }
+
+    #[test]
+    fn test_find_similar_returns_open_tasks_above_threshold() {
+        // Arrange
+        let tasks = vec![
+            task("Write monthly report", Status::Pending),
+            task("write monthly reports", Status::InProgress),
+            task("Write monthly report", Status::Completed),
+            task("Buy milk", Status::Pending),
+        ];
+        let detector = DuplicateTitleDetector::default();
+
+        // Act
+        let similar = detector.find_similar("Write Monthly Report", &tasks);
+
+        // Assert
+        let titles: Vec<_> = similar.iter().map(|s| s.task.title().value()).collect();
+        assert_eq!(
+            titles,
+            vec!["Write monthly report", "write monthly reports"]
+        );
+        assert_eq!(similar[0].similarity, 1.0);
+        assert!(similar[1].similarity < 1.0);
+    }
+
+    #[test]
+    fn test_find_similar_with_strict_threshold() {
+        let tasks = vec![task("write monthly reports", Status::Pending)];
+        let detector = DuplicateTitleDetector::new(1.0);
+
+        let similar = detector.find_similar("Write monthly report", &tasks);
+
+        assert!(similar.is_empty());
+    }
+}
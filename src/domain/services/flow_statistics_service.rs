@@ -0,0 +1,261 @@
+use crate::domain::task::{
+    aggregate::TaskAggregate,
+    value_objects::{DurationSummary, FlowStats, Status},
+};
+use chrono::{DateTime, Duration, Utc};
+
+/// FlowStatsCollector - タスクを1件ずつ受け取り、タスクの流れの統計を集計する
+///
+/// ステータスの変更履歴から、タスクが各ステータスにいた期間を復元して集計します。
+/// 履歴の記録に対応する前に作成したタスクは、作成時から現在のステータスだったものとして扱います。
+pub struct FlowStatsCollector {
+    now: DateTime<Utc>,
+    task_count: usize,
+    pending: DurationSummary,
+    in_progress: DurationSummary,
+    cycle_time: DurationSummary,
+    lead_time: DurationSummary,
+}
+
+impl FlowStatsCollector {
+    /// 新しいFlowStatsCollectorを作成
+    ///
+    /// # Arguments
+    /// * `now` - 基準日時（未完了のタスクの現在のステータスの滞在時間に使用）
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now,
+            task_count: 0,
+            pending: DurationSummary::default(),
+            in_progress: DurationSummary::default(),
+            cycle_time: DurationSummary::default(),
+            lead_time: DurationSummary::default(),
+        }
+    }
+
+    /// タスクを集計に加える
+    pub fn add(&mut self, task: &TaskAggregate) {
+        self.task_count += 1;
+
+        // ステータスごとの滞在時間（一度もそのステータスにいなかったタスクは平均に含めない）
+        let mut pending = None;
+        let mut in_progress = None;
+        for (status, from, to) in Self::periods(task, self.now) {
+            let elapsed = (to - from).max(Duration::zero());
+            match status {
+                Status::Pending => *pending.get_or_insert_with(Duration::zero) += elapsed,
+                Status::InProgress => *in_progress.get_or_insert_with(Duration::zero) += elapsed,
+                Status::Completed => {}
+            }
+        }
+        if let Some(elapsed) = pending {
+            self.pending.add(elapsed);
+        }
+        if let Some(elapsed) = in_progress {
+            self.in_progress.add(elapsed);
+        }
+
+        // サイクルタイム・リードタイム（完了済みのタスクのみ）
+        if let (Status::Completed, Some(completed_at)) = (task.status(), task.completed_at()) {
+            self.lead_time
+                .add((*completed_at - *task.created_at()).max(Duration::zero()));
+
+            let started_at = if task.initial_status() == Status::InProgress {
+                Some(*task.created_at())
+            } else {
+                task.status_history()
+                    .iter()
+                    .find(|change| change.to() == Status::InProgress)
+                    .map(|change| *change.changed_at())
+            };
+            if let Some(started_at) = started_at {
+                self.cycle_time
+                    .add((*completed_at - started_at).max(Duration::zero()));
+            }
+        }
+    }
+
+    /// 集計結果を取得
+    pub fn finish(self) -> FlowStats {
+        FlowStats::new(
+            self.task_count,
+            self.pending,
+            self.in_progress,
+            self.cycle_time,
+            self.lead_time,
+        )
+    }
+
+    /// タスクが各ステータスにいた期間（ステータス・開始・終了）を古い順に返す
+    ///
+    /// 最後の期間は現在のステータスで、`now`までとします。
+    fn periods(
+        task: &TaskAggregate,
+        now: DateTime<Utc>,
+    ) -> Vec<(Status, DateTime<Utc>, DateTime<Utc>)> {
+        let mut periods = Vec::new();
+        let mut status = task.initial_status();
+        let mut since = *task.created_at();
+        for change in task.status_history() {
+            periods.push((status, since, *change.changed_at()));
+            status = change.to();
+            since = *change.changed_at();
+        }
+        periods.push((status, since, now));
+        periods
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::{
+        aggregate::TaskReconstructParams,
+        value_objects::{Priority, StatusChange, TaskDescription, TaskId, TaskTitle},
+    };
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 10, hour, 0, 0).unwrap()
+    }
+
+    /// 9時に作成し、履歴のとおりにステータスを変更したタスク
+    fn task_with_history(history: Vec<StatusChange>) -> TaskAggregate {
+        let status = history.last().map_or(Status::Pending, StatusChange::to);
+        let completed_at = history
+            .last()
+            .filter(|change| change.to() == Status::Completed)
+            .map(|change| *change.changed_at());
+        TaskAggregate::reconstruct(TaskReconstructParams {
+            id: TaskId::new(1).unwrap(),
+            title: TaskTitle::new("タスク").unwrap(),
+            description: TaskDescription::default(),
+            status,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: at(9),
+            updated_at: at(9),
+            due_date: None,
+            completed_at,
+            custom_fields: vec![],
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate: None,
+            context: None,
+            assignee: None,
+            status_history: history,
+        })
+    }
+
+    #[test]
+    fn test_completed_task_flow_times() {
+        // Arrange: 9時作成、10時に着手、13時に完了
+        let task = task_with_history(vec![
+            StatusChange::new(Status::Pending, Status::InProgress, at(10)),
+            StatusChange::new(Status::InProgress, Status::Completed, at(13)),
+        ]);
+        let mut collector = FlowStatsCollector::new(at(18));
+
+        // Act
+        collector.add(&task);
+        let stats = collector.finish();
+
+        // Assert
+        assert_eq!(stats.task_count(), 1);
+        assert_eq!(stats.pending().average(), Some(Duration::hours(1)));
+        assert_eq!(stats.in_progress().average(), Some(Duration::hours(3)));
+        assert_eq!(stats.cycle_time().average(), Some(Duration::hours(3)));
+        assert_eq!(stats.lead_time().average(), Some(Duration::hours(4)));
+    }
+
+    #[test]
+    fn test_open_task_counts_current_status_until_now() {
+        // Arrange: 9時作成、11時に着手して進行中のまま
+        let task = task_with_history(vec![StatusChange::new(
+            Status::Pending,
+            Status::InProgress,
+            at(11),
+        )]);
+        let mut collector = FlowStatsCollector::new(at(15));
+
+        // Act
+        collector.add(&task);
+        let stats = collector.finish();
+
+        // Assert: 未完了のためサイクルタイム・リードタイムには含めない
+        assert_eq!(stats.pending().average(), Some(Duration::hours(2)));
+        assert_eq!(stats.in_progress().average(), Some(Duration::hours(4)));
+        assert_eq!(stats.cycle_time().count(), 0);
+        assert_eq!(stats.lead_time().count(), 0);
+    }
+
+    #[test]
+    fn test_reopened_task_sums_time_per_status() {
+        // Arrange: 着手→保留に戻す→再び着手→完了
+        let task = task_with_history(vec![
+            StatusChange::new(Status::Pending, Status::InProgress, at(10)),
+            StatusChange::new(Status::InProgress, Status::Pending, at(11)),
+            StatusChange::new(Status::Pending, Status::InProgress, at(14)),
+            StatusChange::new(Status::InProgress, Status::Completed, at(16)),
+        ]);
+        let mut collector = FlowStatsCollector::new(at(18));
+
+        // Act
+        collector.add(&task);
+        let stats = collector.finish();
+
+        // Assert: サイクルタイムは最初の着手から数える
+        assert_eq!(stats.pending().average(), Some(Duration::hours(4)));
+        assert_eq!(stats.in_progress().average(), Some(Duration::hours(3)));
+        assert_eq!(stats.cycle_time().average(), Some(Duration::hours(6)));
+    }
+
+    #[test]
+    fn test_task_completed_without_starting_has_no_cycle_time() {
+        // Arrange: 保留中から直接完了
+        let task = task_with_history(vec![StatusChange::new(
+            Status::Pending,
+            Status::Completed,
+            at(12),
+        )]);
+        let mut collector = FlowStatsCollector::new(at(18));
+
+        // Act
+        collector.add(&task);
+        let stats = collector.finish();
+
+        // Assert
+        assert_eq!(stats.in_progress().count(), 0);
+        assert_eq!(stats.cycle_time().count(), 0);
+        assert_eq!(stats.lead_time().average(), Some(Duration::hours(3)));
+    }
+
+    #[test]
+    fn test_averages_across_tasks() {
+        // Arrange
+        let tasks = [
+            task_with_history(vec![]),
+            task_with_history(vec![StatusChange::new(
+                Status::Pending,
+                Status::InProgress,
+                at(13),
+            )]),
+        ];
+        let mut collector = FlowStatsCollector::new(at(15));
+
+        // Act
+        for task in &tasks {
+            collector.add(task);
+        }
+        let stats = collector.finish();
+
+        // Assert: 保留中は6時間と4時間、進行中は1件のみ
+        assert_eq!(stats.task_count(), 2);
+        assert_eq!(stats.pending().count(), 2);
+        assert_eq!(stats.pending().average(), Some(Duration::hours(5)));
+        assert_eq!(stats.in_progress().average(), Some(Duration::hours(2)));
+    }
+}
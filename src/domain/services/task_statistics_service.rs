@@ -1,9 +1,15 @@
-use crate::domain::task::{
-    aggregate::TaskAggregate,
-    value_objects::{DueDateStatus, Priority, Status, TaskStats},
+use crate::domain::{
+    clock::Clock,
+    tag::value_objects::TagId,
+    task::{
+        aggregate::TaskAggregate,
+        value_objects::{
+            Assignee, CompletionStreak, DueDateBuckets, DueDateStatus, Priority, Status, TaskStats,
+        },
+    },
 };
-use chrono::NaiveDate;
-use std::collections::HashMap;
+use chrono::{Duration, NaiveDate};
+use std::collections::{BTreeSet, HashMap};
 
 /// TaskStatisticsService - タスクの統計情報を計算するドメインサービス
 ///
@@ -12,94 +18,188 @@ use std::collections::HashMap;
 pub struct TaskStatisticsService;
 
 impl TaskStatisticsService {
-    /// タスクリストから統計情報を計算
+    /// 完了済みのタスクの完了日時から、完了した日の連続記録を計算
+    ///
+    /// 完了日はClockのタイムゾーンでの日付です。今日まだ1件も完了していない場合も、
+    /// 昨日まで続いていれば連続は途切れていないものとして扱います。
     ///
     /// # Arguments
-    /// * `tasks` - 統計を計算するタスクのリスト
-    /// * `today` - 基準日（期限切れ判定などに使用）
+    /// * `tasks` - 完了済みのタスク（完了日時のないタスクは数えない）
+    /// * `clock` - 今日の日付と完了日のタイムゾーンを取得するClock
+    pub fn completion_streak(tasks: &[TaskAggregate], clock: &dyn Clock) -> CompletionStreak {
+        let today = clock.today();
+        let timezone = clock.timezone();
+        let completed_on: Vec<NaiveDate> = tasks
+            .iter()
+            .filter(|task| *task.status() == Status::Completed)
+            .filter_map(|task| task.completed_at().map(|at| timezone.date_of(at)))
+            .collect();
+        let completed_today = completed_on.iter().filter(|date| **date == today).count();
+        // 未来の完了日（時計のずれなど）は連続日数に含めない
+        let days: BTreeSet<NaiveDate> = completed_on
+            .iter()
+            .copied()
+            .filter(|date| *date <= today)
+            .collect();
+
+        let mut longest_days = 0;
+        let mut run = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for day in &days {
+            run = match previous {
+                Some(previous) if *day - previous == Duration::days(1) => run + 1,
+                _ => 1,
+            };
+            longest_days = longest_days.max(run);
+            previous = Some(*day);
+        }
+        let current_days = match previous {
+            Some(last) if today - last <= Duration::days(1) => run,
+            _ => 0,
+        };
+
+        CompletionStreak::new(
+            completed_today,
+            completed_on.len(),
+            current_days,
+            longest_days,
+        )
+    }
+}
+
+/// TaskStatsCollector - タスクを1件ずつ受け取り統計情報を集計する
+///
+/// ストリームで取得したタスクなど、全件をメモリに保持せずに統計を計算する場合に使用します。
+pub struct TaskStatsCollector {
+    today: NaiveDate,
+    due_date_buckets: DueDateBuckets,
+    total_count: usize,
+    status_stats: HashMap<Status, usize>,
+    priority_stats: HashMap<Priority, usize>,
+    due_date_stats: HashMap<DueDateStatus, usize>,
+    tag_stats: HashMap<Option<TagId>, usize>,
+    priority_status_matrix: HashMap<(Priority, Status), usize>,
+    assignee_status_matrix: HashMap<(Option<Assignee>, Status), usize>,
+}
+
+impl TaskStatsCollector {
+    /// 新しいTaskStatsCollectorを作成
     ///
-    /// # Returns
-    /// * `TaskStats` - 計算された統計情報
-    pub fn calculate_stats(tasks: &[TaskAggregate], today: NaiveDate) -> TaskStats {
-        let total_count = tasks.len();
-
-        let mut status_stats: HashMap<Status, usize> = HashMap::new();
-        let mut priority_stats: HashMap<Priority, usize> = HashMap::new();
-        let mut due_date_stats: HashMap<DueDateStatus, usize> = HashMap::new();
-        let mut tag_stats: HashMap<Option<crate::domain::tag::value_objects::TagId>, usize> =
-            HashMap::new();
-        let mut priority_status_matrix: HashMap<(Priority, Status), usize> = HashMap::new();
+    /// # Arguments
+    /// * `today` - 基準日（期限切れ判定などに使用）
+    pub fn new(today: NaiveDate) -> Self {
+        Self {
+            today,
+            due_date_buckets: DueDateBuckets::default(),
+            total_count: 0,
+            status_stats: HashMap::new(),
+            priority_stats: HashMap::new(),
+            due_date_stats: HashMap::new(),
+            tag_stats: HashMap::new(),
+            priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
+        }
+    }
 
-        for task in tasks {
-            // ステータス別カウント
-            *status_stats.entry(*task.status()).or_default() += 1;
-
-            // 優先度別カウント
-            *priority_stats.entry(*task.priority()).or_default() += 1;
-
-            // 期限関連カウント (完了済みタスクは除外)
-            if task.status() != &Status::Completed {
-                if let Some(due_date) = task.due_date() {
-                    let due = due_date.value();
-                    if due < today {
-                        *due_date_stats.entry(DueDateStatus::Overdue).or_default() += 1;
-                    } else if due == today {
-                        *due_date_stats.entry(DueDateStatus::DueToday).or_default() += 1;
-                    } else {
-                        let week_later = today + chrono::Duration::days(7);
-                        if due <= week_later {
-                            *due_date_stats
-                                .entry(DueDateStatus::DueThisWeek)
-                                .or_default() += 1;
-                        }
-                    }
-                } else {
-                    *due_date_stats.entry(DueDateStatus::NoDueDate).or_default() += 1;
-                }
-            }
+    /// 期限関連統計の分類に使用する境界を設定
+    pub fn with_due_date_buckets(mut self, buckets: DueDateBuckets) -> Self {
+        self.due_date_buckets = buckets;
+        self
+    }
 
-            // タグ別統計
-            if task.tags().is_empty() {
-                // タグなしのタスクはNoneをキーとして集計
-                *tag_stats.entry(None).or_default() += 1;
-            } else {
-                for tag_id in task.tags() {
-                    // タグIDをそのままキーとして使用
-                    *tag_stats.entry(Some(*tag_id)).or_default() += 1;
-                }
-            }
+    /// タスクを集計に加える
+    pub fn add(&mut self, task: &TaskAggregate) {
+        self.total_count += 1;
 
-            // 優先度×ステータス クロス集計
-            *priority_status_matrix
-                .entry((*task.priority(), *task.status()))
+        // ステータス別カウント
+        *self.status_stats.entry(*task.status()).or_default() += 1;
+
+        // 優先度別カウント
+        *self.priority_stats.entry(*task.priority()).or_default() += 1;
+
+        // 期限関連カウント (完了済みタスクは除外)
+        if task.status() != &Status::Completed {
+            let due = task.due_date().as_ref().map(|d| d.value());
+            *self
+                .due_date_stats
+                .entry(DueDateStatus::from_due_date(
+                    due,
+                    self.today,
+                    self.due_date_buckets,
+                ))
                 .or_default() += 1;
         }
 
+        // タグ別統計
+        if task.tags().is_empty() {
+            // タグなしのタスクはNoneをキーとして集計
+            *self.tag_stats.entry(None).or_default() += 1;
+        } else {
+            for tag_id in task.tags() {
+                // タグIDをそのままキーとして使用
+                *self.tag_stats.entry(Some(*tag_id)).or_default() += 1;
+            }
+        }
+
+        // 優先度×ステータス クロス集計
+        *self
+            .priority_status_matrix
+            .entry((*task.priority(), *task.status()))
+            .or_default() += 1;
+
+        // 担当者×ステータス クロス集計（担当者なしのタスクはNoneをキーとして集計）
+        *self
+            .assignee_status_matrix
+            .entry((task.assignee().cloned(), *task.status()))
+            .or_default() += 1;
+    }
+
+    /// 集計結果を取得
+    pub fn finish(self) -> TaskStats {
         TaskStats::new(
-            status_stats,
-            priority_stats,
-            due_date_stats,
-            tag_stats,
-            priority_status_matrix,
-            total_count,
+            self.status_stats,
+            self.priority_stats,
+            self.due_date_stats,
+            self.tag_stats,
+            self.priority_status_matrix,
+            self.total_count,
         )
+        .with_due_date_buckets(self.due_date_buckets)
+        .with_assignee_status_matrix(self.assignee_status_matrix)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::task::value_objects::{DueDate, TaskDescription, TaskTitle};
-    use chrono::{Duration, Utc};
+    use crate::domain::clock::FixedClock;
+    use crate::domain::clock::SystemClock;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::value_objects::{DueDate, TaskDescription, TaskId, TaskTitle};
+    use chrono::Duration;
+
+    /// 統計の基準日時（時刻を固定する）
+    fn clock() -> FixedClock {
+        FixedClock::at(2026, 1, 10, 23, 30)
+    }
+
+    /// タスクを1件ずつTaskStatsCollectorに加えて集計する
+    fn collect_stats(tasks: &[TaskAggregate], clock: &dyn Clock) -> TaskStats {
+        let mut collector = TaskStatsCollector::new(clock.today());
+        for task in tasks {
+            collector.add(task);
+        }
+        collector.finish()
+    }
 
     #[test]
-    fn test_calculate_stats_empty_tasks() {
+    fn test_collect_stats_empty_tasks() {
         // Arrange
         let tasks = vec![];
-        let today = Utc::now().naive_utc().date();
+        let clock = clock();
 
         // Act
-        let stats = TaskStatisticsService::calculate_stats(&tasks, today);
+        let stats = collect_stats(&tasks, &clock);
 
         // Assert
         assert_eq!(stats.total_count(), 0);
@@ -108,9 +208,9 @@ mod tests {
     }
 
     #[test]
-    fn test_calculate_stats_status_counts() {
+    fn test_collect_stats_status_counts() {
         // Arrange
-        let today = Utc::now().naive_utc().date();
+        let clock = clock();
         let tasks = vec![
             create_test_task(Status::Pending, Priority::Medium, None),
             create_test_task(Status::Pending, Priority::High, None),
@@ -118,7 +218,7 @@ mod tests {
         ];
 
         // Act
-        let stats = TaskStatisticsService::calculate_stats(&tasks, today);
+        let stats = collect_stats(&tasks, &clock);
 
         // Assert
         assert_eq!(stats.total_count(), 3);
@@ -128,9 +228,9 @@ mod tests {
     }
 
     #[test]
-    fn test_calculate_stats_priority_counts() {
+    fn test_collect_stats_priority_counts() {
         // Arrange
-        let today = Utc::now().naive_utc().date();
+        let clock = clock();
         let tasks = vec![
             create_test_task(Status::Pending, Priority::High, None),
             create_test_task(Status::Pending, Priority::High, None),
@@ -139,7 +239,7 @@ mod tests {
         ];
 
         // Act
-        let stats = TaskStatisticsService::calculate_stats(&tasks, today);
+        let stats = collect_stats(&tasks, &clock);
 
         // Assert
         assert_eq!(stats.total_count(), 4);
@@ -150,9 +250,10 @@ mod tests {
     }
 
     #[test]
-    fn test_calculate_stats_due_date_counts() {
+    fn test_collect_stats_due_date_counts() {
         // Arrange
-        let today = Utc::now().naive_utc().date();
+        let clock = clock();
+        let today = clock.today();
         let overdue_date = today - Duration::days(1);
         let today_date = today;
         let this_week_date = today + Duration::days(3);
@@ -177,19 +278,44 @@ mod tests {
         ];
 
         // Act
-        let stats = TaskStatisticsService::calculate_stats(&tasks, today);
+        let stats = collect_stats(&tasks, &clock);
 
         // Assert
         assert_eq!(stats.due_date_count(&DueDateStatus::Overdue), 1);
         assert_eq!(stats.due_date_count(&DueDateStatus::DueToday), 1);
-        assert_eq!(stats.due_date_count(&DueDateStatus::DueThisWeek), 1);
+        assert_eq!(stats.due_date_count(&DueDateStatus::DueSoon), 1);
         assert_eq!(stats.due_date_count(&DueDateStatus::NoDueDate), 1);
     }
 
     #[test]
-    fn test_calculate_stats_priority_status_matrix() {
+    fn test_collector_uses_configured_due_date_buckets() {
         // Arrange
-        let today = Utc::now().naive_utc().date();
+        let clock = clock();
+        let today = clock.today();
+        let buckets = DueDateBuckets::new(3, 10).unwrap();
+        let mut collector = TaskStatsCollector::new(today).with_due_date_buckets(buckets);
+
+        // Act
+        for days in [2, 5, 20] {
+            collector.add(&create_test_task(
+                Status::Pending,
+                Priority::Medium,
+                Some(DueDate::new(today + Duration::days(days)).unwrap()),
+            ));
+        }
+        let stats = collector.finish();
+
+        // Assert
+        assert_eq!(stats.due_date_count(&DueDateStatus::DueSoon), 1);
+        assert_eq!(stats.due_date_count(&DueDateStatus::DueUpcoming), 1);
+        assert_eq!(stats.due_date_count(&DueDateStatus::DueLater), 1);
+        assert_eq!(stats.due_date_buckets(), buckets);
+    }
+
+    #[test]
+    fn test_collect_stats_priority_status_matrix() {
+        // Arrange
+        let clock = clock();
         let tasks = vec![
             create_test_task(Status::Pending, Priority::High, None),
             create_test_task(Status::Pending, Priority::High, None),
@@ -198,7 +324,7 @@ mod tests {
         ];
 
         // Act
-        let stats = TaskStatisticsService::calculate_stats(&tasks, today);
+        let stats = collect_stats(&tasks, &clock);
 
         // Assert
         assert_eq!(
@@ -219,6 +345,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_collect_stats_assignee_status_matrix() {
+        // Arrange
+        let clock = clock();
+        let alice = Assignee::new("alice").unwrap();
+        let mut tasks = vec![
+            create_test_task(Status::Pending, Priority::High, None),
+            create_test_task(Status::Pending, Priority::High, None),
+            create_test_task(Status::Completed, Priority::Low, None),
+        ];
+        tasks[0]
+            .change_assignee(Some(alice.clone()), &SystemClock::default())
+            .unwrap();
+        tasks[2]
+            .change_assignee(Some(alice.clone()), &SystemClock::default())
+            .unwrap();
+
+        // Act
+        let stats = collect_stats(&tasks, &clock);
+
+        // Assert
+        let matrix = stats.assignee_status_matrix();
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix[&(Some(alice.clone()), Status::Pending)], 1);
+        assert_eq!(matrix[&(Some(alice), Status::Completed)], 1);
+        assert_eq!(matrix[&(None, Status::Pending)], 1);
+    }
+
+    /// 指定した日時（UTC）に完了したタスクを作成
+    fn completed_at(day: u32, hour: u32) -> TaskAggregate {
+        let mut task = create_test_task(Status::Pending, Priority::Medium, None);
+        task.complete(&FixedClock::at(2026, 1, day, hour, 0))
+            .unwrap();
+        task
+    }
+
+    #[test]
+    fn test_completion_streak() {
+        // Arrange: 1/3〜1/5の3日連続と、1/8〜1/10の3日連続（1/10は2件）
+        let tasks: Vec<_> = [(3, 9), (4, 9), (5, 9), (8, 9), (9, 9), (10, 9), (10, 12)]
+            .into_iter()
+            .map(|(day, hour)| completed_at(day, hour))
+            .chain([create_test_task(Status::Pending, Priority::Low, None)])
+            .collect();
+
+        // Act
+        let streak = TaskStatisticsService::completion_streak(&tasks, &clock());
+
+        // Assert
+        assert_eq!(streak, CompletionStreak::new(2, 7, 3, 3));
+    }
+
+    #[test]
+    fn test_completion_streak_continues_until_today_ends() {
+        // Arrange: 昨日まで4日連続、今日はまだ完了していない
+        let tasks: Vec<_> = (6..=9).map(|day| completed_at(day, 9)).collect();
+
+        // Act
+        let streak = TaskStatisticsService::completion_streak(&tasks, &clock());
+        let broken =
+            TaskStatisticsService::completion_streak(&tasks, &FixedClock::at(2026, 1, 11, 9, 0));
+
+        // Assert
+        assert_eq!(streak.completed_today(), 0);
+        assert_eq!(streak.current_days(), 4);
+        assert_eq!(broken.current_days(), 0);
+        assert_eq!(broken.longest_days(), 4);
+    }
+
+    #[test]
+    fn test_completion_streak_uses_clock_timezone() {
+        // Arrange: UTCの1/9 20:00は+09:00では1/10
+        let tasks = vec![completed_at(9, 20)];
+        let clock = clock().with_timezone("+09:00".parse().unwrap());
+
+        // Act
+        let streak = TaskStatisticsService::completion_streak(&tasks, &clock);
+
+        // Assert: +09:00の今日は1/11なので、1/10の完了で連続が続いている
+        assert_eq!(streak.completed_today(), 0);
+        assert_eq!(streak.current_days(), 1);
+    }
+
     // Helper function to create test tasks
     fn create_test_task(
         status: Status,
@@ -227,6 +436,12 @@ mod tests {
     ) -> TaskAggregate {
         let title = TaskTitle::new("Test Task").unwrap();
         let description = TaskDescription::new("").unwrap();
-        TaskAggregate::new(title, description, status, priority, vec![], due_date)
+        TaskBuilder::new(title)
+            .description(description)
+            .status(status)
+            .priority(priority)
+            .due_date(due_date)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap())
     }
 }
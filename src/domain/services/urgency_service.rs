@@ -0,0 +1,208 @@
+use crate::domain::task::{aggregate::TaskAggregate, value_objects::Status};
+use anyhow::{Result, bail};
+use chrono::NaiveDate;
+
+/// 緊急度スコアの各要素の重み
+///
+/// 各要素は0.0〜1.0に正規化され、重みを掛けて合計した値が緊急度スコアになります。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UrgencyWeights {
+    /// 優先度（低: 0.0、中: 1/3、高: 2/3、重大: 1.0）
+    pub priority: f64,
+    /// 期限日の近さ（14日以上先: 0.2、期限切れ7日以上: 1.0、期限なし: 0.0）
+    pub due: f64,
+    /// 作成からの経過日数（365日で1.0）
+    pub age: f64,
+    /// 着手できない状態（スヌーズ中）の場合に加える重み（通常は負の値）
+    pub blocked: f64,
+}
+
+impl Default for UrgencyWeights {
+    fn default() -> Self {
+        Self {
+            priority: 6.0,
+            due: 12.0,
+            age: 2.0,
+            blocked: -5.0,
+        }
+    }
+}
+
+impl UrgencyWeights {
+    /// 重みを検証して作成
+    pub fn new(priority: f64, due: f64, age: f64, blocked: f64) -> Result<Self> {
+        if ![priority, due, age, blocked].iter().all(|w| w.is_finite()) {
+            bail!("Urgency weights must be finite numbers");
+        }
+        Ok(Self {
+            priority,
+            due,
+            age,
+            blocked,
+        })
+    }
+}
+
+/// UrgencyCalculator - タスクの緊急度スコアを計算するドメインサービス
+///
+/// 優先度・期限日の近さ・経過日数・着手できない状態を重み付けして合計します。
+/// 完了済みのタスクのスコアは常に0.0です。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UrgencyCalculator {
+    weights: UrgencyWeights,
+}
+
+impl UrgencyCalculator {
+    /// 期限日の要素が最大になる期限切れの日数
+    const DUE_OVERDUE_DAYS: i64 = 7;
+    /// 期限日の要素が最小になる残り日数
+    const DUE_FAR_DAYS: i64 = 14;
+    /// 期限日が遠い場合の要素の値
+    const DUE_FAR_FACTOR: f64 = 0.2;
+    /// 経過日数の要素が最大になる日数
+    const AGE_MAX_DAYS: f64 = 365.0;
+
+    /// 重みを指定して作成
+    pub fn new(weights: UrgencyWeights) -> Self {
+        Self { weights }
+    }
+
+    /// タスクの緊急度スコア（大きいほど緊急）
+    ///
+    /// # Arguments
+    /// * `task` - 対象のタスク
+    /// * `today` - 基準日（期限日の近さ・経過日数・スヌーズの判定に使用）
+    pub fn score(&self, task: &TaskAggregate, today: NaiveDate) -> f64 {
+        if *task.status() == Status::Completed {
+            return 0.0;
+        }
+        let blocked = if task.is_snoozed(today) { 1.0 } else { 0.0 };
+
+        self.weights.priority * priority_factor(task)
+            + self.weights.due * due_factor(task, today)
+            + self.weights.age * age_factor(task, today)
+            + self.weights.blocked * blocked
+    }
+}
+
+/// 優先度の要素（低: 0.0 〜 重大: 1.0）
+fn priority_factor(task: &TaskAggregate) -> f64 {
+    (*task.priority() as u8 - 1) as f64 / 3.0
+}
+
+/// 期限日の近さの要素
+///
+/// 残り14日以上で0.2、期限切れ7日以上で1.0とし、その間は線形に補間します。
+fn due_factor(task: &TaskAggregate, today: NaiveDate) -> f64 {
+    let Some(due_date) = task.due_date() else {
+        return 0.0;
+    };
+    let days_left = (due_date.value() - today).num_days().clamp(
+        -UrgencyCalculator::DUE_OVERDUE_DAYS,
+        UrgencyCalculator::DUE_FAR_DAYS,
+    );
+    let span = (UrgencyCalculator::DUE_FAR_DAYS + UrgencyCalculator::DUE_OVERDUE_DAYS) as f64;
+    let elapsed = (UrgencyCalculator::DUE_FAR_DAYS - days_left) as f64 / span;
+    UrgencyCalculator::DUE_FAR_FACTOR + (1.0 - UrgencyCalculator::DUE_FAR_FACTOR) * elapsed
+}
+
+/// 作成からの経過日数の要素（365日で1.0）
+fn age_factor(task: &TaskAggregate, today: NaiveDate) -> f64 {
+    let days = (today - task.created_at().date_naive()).num_days().max(0) as f64;
+    (days / UrgencyCalculator::AGE_MAX_DAYS).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::task::{
+        builder::TaskBuilder,
+        value_objects::{DueDate, Priority, TaskId, TaskTitle},
+    };
+    use chrono::{Duration, Utc};
+
+    fn task(priority: Priority, due_in_days: Option<i64>) -> TaskAggregate {
+        let today = Utc::now().naive_utc().date();
+        TaskBuilder::new(TaskTitle::new("task").unwrap())
+            .priority(priority)
+            .due_date(due_in_days.map(|days| DueDate::new(today + Duration::days(days)).unwrap()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap())
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_priority_factor() {
+        assert_close(priority_factor(&task(Priority::Low, None)), 0.0);
+        assert_close(priority_factor(&task(Priority::Medium, None)), 1.0 / 3.0);
+        assert_close(priority_factor(&task(Priority::High, None)), 2.0 / 3.0);
+        assert_close(priority_factor(&task(Priority::Critical, None)), 1.0);
+    }
+
+    #[test]
+    fn test_due_factor() {
+        let today = Utc::now().naive_utc().date();
+        let factor = |days| due_factor(&task(Priority::Medium, days), today);
+
+        assert_close(factor(None), 0.0);
+        assert_close(factor(Some(30)), 0.2);
+        assert_close(factor(Some(14)), 0.2);
+        // 期限日の当日は、14日先から期限切れ7日までの21日のうち14日進んだ位置
+        assert_close(factor(Some(0)), 0.2 + 0.8 * 14.0 / 21.0);
+        assert_close(factor(Some(-7)), 1.0);
+        assert_close(factor(Some(-30)), 1.0);
+        assert!(factor(Some(1)) < factor(Some(0)));
+    }
+
+    #[test]
+    fn test_age_factor() {
+        let task = task(Priority::Medium, None);
+        let created = task.created_at().date_naive();
+
+        assert_close(age_factor(&task, created), 0.0);
+        assert_close(age_factor(&task, created + Duration::days(73)), 0.2);
+        assert_close(age_factor(&task, created + Duration::days(800)), 1.0);
+    }
+
+    #[test]
+    fn test_score_combines_weighted_factors() {
+        // Arrange
+        let today = Utc::now().naive_utc().date();
+        let calculator = UrgencyCalculator::new(UrgencyWeights::new(3.0, 10.0, 0.0, -4.0).unwrap());
+        let mut snoozed = task(Priority::Critical, Some(-7));
+        snoozed
+            .snooze(today + Duration::days(2), &FixedClock::on(today))
+            .unwrap();
+        let mut completed = task(Priority::Critical, Some(-7));
+        completed
+            .complete(&FixedClock::at(2026, 1, 10, 9, 0))
+            .unwrap();
+
+        // Act & Assert
+        assert_close(calculator.score(&task(Priority::Low, None), today), 0.0);
+        assert_close(
+            calculator.score(&task(Priority::Critical, Some(-7)), today),
+            13.0,
+        );
+        assert_close(calculator.score(&snoozed, today), 9.0);
+        assert_close(calculator.score(&completed, today), 0.0);
+        assert!(
+            calculator.score(&task(Priority::Low, Some(0)), today)
+                > calculator.score(&task(Priority::High, None), today)
+        );
+    }
+
+    #[test]
+    fn test_weights_must_be_finite() {
+        assert!(UrgencyWeights::new(1.0, f64::NAN, 1.0, 1.0).is_err());
+        assert!(UrgencyWeights::new(f64::INFINITY, 1.0, 1.0, 1.0).is_err());
+        assert!(UrgencyWeights::new(0.0, 0.0, 0.0, 0.0).is_ok());
+    }
+}
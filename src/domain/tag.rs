@@ -1,3 +1,4 @@
 pub mod aggregate;
+pub mod hierarchy;
 pub mod repository;
 pub mod value_objects;
@@ -1,4 +1,7 @@
-use crate::domain::tag::value_objects::{TagDescription, TagId, TagName};
+use crate::domain::tag::{
+    hierarchy::TagHierarchy,
+    value_objects::{TagDescription, TagId, TagName},
+};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
@@ -10,35 +13,87 @@ pub struct TagReconstructParams {
     pub id: TagId,
     pub name: TagName,
     pub description: TagDescription,
+    pub parent_id: Option<TagId>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-/// TagAggregate - タグのAggregate Root
+/// NewTag - まだ保存されていないタグ
 ///
-/// タグのビジネスルールを実装し、不変条件を保護します。
+/// IDはリポジトリ（データベース）が保存時に割り当てるため、保存前のタグはIDを持ちません。
+/// リポジトリは割り当てたIDで[`NewTag::into_aggregate`]を呼び出し、TagAggregateに変換します。
 #[derive(Debug, Clone, PartialEq)]
-pub struct TagAggregate {
-    id: TagId,
+pub struct NewTag {
     name: TagName,
     description: TagDescription,
+    parent_id: Option<TagId>,
     created_at: DateTime<Utc>,
-    updated_at: DateTime<Utc>,
 }
 
-impl TagAggregate {
-    /// 新しいタグを作成します（ファクトリメソッド）
+impl NewTag {
+    /// 新しいタグを作成します（ルートのタグとして作成します）
     pub fn new(name: TagName, description: TagDescription) -> Self {
-        let now = Utc::now();
         Self {
-            id: TagId::new(0).unwrap(), // デフォルトは0、リポジトリで新しいIDを割り当てる
             name,
             description,
-            created_at: now,
-            updated_at: now,
+            parent_id: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// 親タグを指定します（Noneでルートのタグにする）
+    ///
+    /// 親が存在しない場合はエラーになります。
+    pub fn with_parent(mut self, parent: Option<TagId>, hierarchy: &TagHierarchy) -> Result<Self> {
+        if let Some(parent) = &parent {
+            hierarchy.ensure_parent_exists(parent)?;
+        }
+        self.parent_id = parent;
+        Ok(self)
+    }
+
+    /// リポジトリが割り当てたIDでTagAggregateに変換します
+    pub fn into_aggregate(self, id: TagId) -> TagAggregate {
+        TagAggregate {
+            id,
+            name: self.name,
+            description: self.description,
+            parent_id: self.parent_id,
+            created_at: self.created_at,
+            updated_at: self.created_at,
         }
     }
 
+    // Getters
+    pub fn name(&self) -> &TagName {
+        &self.name
+    }
+
+    pub fn description(&self) -> &TagDescription {
+        &self.description
+    }
+
+    pub fn parent_id(&self) -> Option<TagId> {
+        self.parent_id
+    }
+}
+
+/// TagAggregate - タグのAggregate Root
+///
+/// タグのビジネスルールを実装し、不変条件を保護します。
+/// 新しいタグは[`NewTag`]として作成し、リポジトリで保存してIDを割り当てます。
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagAggregate {
+    id: TagId,
+    name: TagName,
+    description: TagDescription,
+    /// 親タグのID（ルートのタグはNone）
+    parent_id: Option<TagId>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TagAggregate {
     /// リポジトリからの再構築用ファクトリメソッド
     ///
     /// データベースから読み込んだデータをTagAggregateに変換する際に使用します。
@@ -47,25 +102,12 @@ impl TagAggregate {
             id: params.id,
             name: params.name,
             description: params.description,
+            parent_id: params.parent_id,
             created_at: params.created_at,
             updated_at: params.updated_at,
         }
     }
 
-    /// IDを設定した新しいインスタンスを返す
-    ///
-    /// リポジトリがタグを保存する際に新しいIDを割り当てるために使用します。
-    #[allow(dead_code)]
-    pub fn with_id(self, id: TagId) -> Self {
-        Self {
-            id,
-            name: self.name,
-            description: self.description,
-            created_at: self.created_at,
-            updated_at: self.updated_at,
-        }
-    }
-
     /// タグの名前を変更します
     pub fn change_name(&mut self, new_name: TagName) -> Result<()> {
         self.name = new_name;
@@ -80,6 +122,18 @@ impl TagAggregate {
         Ok(())
     }
 
+    /// 親タグを変更します（Noneでルートのタグにする）
+    ///
+    /// 親が存在しない場合や、親子関係が循環する場合はエラーになります。
+    pub fn change_parent(&mut self, parent: Option<TagId>, hierarchy: &TagHierarchy) -> Result<()> {
+        if let Some(parent) = &parent {
+            hierarchy.ensure_can_attach(&self.id, parent)?;
+        }
+        self.parent_id = parent;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     // Getters
     pub fn id(&self) -> &TagId {
         &self.id
@@ -93,6 +147,10 @@ impl TagAggregate {
         &self.description
     }
 
+    pub fn parent_id(&self) -> Option<TagId> {
+        self.parent_id
+    }
+
     pub fn created_at(&self) -> &DateTime<Utc> {
         &self.created_at
     }
@@ -113,11 +171,15 @@ mod tests {
         let description = TagDescription::new("重要なタスク").unwrap();
 
         // Act
-        let tag = TagAggregate::new(name.clone(), description.clone());
+        let tag =
+            NewTag::new(name.clone(), description.clone()).into_aggregate(TagId::new(1).unwrap());
 
         // Assert
+        assert_eq!(tag.id().value(), 1);
         assert_eq!(tag.name(), &name);
         assert_eq!(tag.description(), &description);
+        assert_eq!(tag.parent_id(), None);
+        assert_eq!(tag.created_at(), tag.updated_at());
     }
 
     #[test]
@@ -125,7 +187,7 @@ mod tests {
         // Arrange
         let name = TagName::new("元の名前").unwrap();
         let description = TagDescription::new("説明").unwrap();
-        let mut tag = TagAggregate::new(name, description);
+        let mut tag = NewTag::new(name, description).into_aggregate(TagId::new(1).unwrap());
         let new_name = TagName::new("新しい名前").unwrap();
 
         // Act
@@ -141,7 +203,7 @@ mod tests {
         // Arrange
         let name = TagName::new("タグ").unwrap();
         let description = TagDescription::new("元の説明").unwrap();
-        let mut tag = TagAggregate::new(name, description);
+        let mut tag = NewTag::new(name, description).into_aggregate(TagId::new(1).unwrap());
         let new_description = TagDescription::new("新しい説明").unwrap();
 
         // Act
@@ -151,4 +213,30 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(tag.description(), &new_description);
     }
+
+    #[test]
+    fn test_change_parent_rejects_own_descendant() {
+        // Arrange
+        let mut parent = NewTag::new(
+            TagName::new("work").unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+        .into_aggregate(TagId::new(1).unwrap());
+        let mut child = NewTag::new(
+            TagName::new("project-a").unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+        .into_aggregate(TagId::new(2).unwrap());
+        let hierarchy = TagHierarchy::new(&[parent.clone(), child.clone()]);
+        child.change_parent(Some(*parent.id()), &hierarchy).unwrap();
+        let hierarchy = TagHierarchy::new(&[parent.clone(), child.clone()]);
+
+        // Act
+        let result = parent.change_parent(Some(*child.id()), &hierarchy);
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(parent.parent_id(), None);
+        assert_eq!(child.parent_id(), Some(*parent.id()));
+    }
 }
@@ -0,0 +1,186 @@
+use crate::domain::tag::{aggregate::TagAggregate, value_objects::TagId};
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+
+/// パス表示でタグ名を区切る文字
+pub const PATH_SEPARATOR: &str = "/";
+
+/// TagHierarchy - タグの親子関係
+///
+/// タグの一覧から親子関係を組み立て、パス表示（例: `work/project-a`）、
+/// 子孫タグの列挙、親子関係の循環の検出を行います。
+#[derive(Debug, Clone, Default)]
+pub struct TagHierarchy {
+    names: HashMap<TagId, String>,
+    parents: HashMap<TagId, TagId>,
+}
+
+impl TagHierarchy {
+    /// タグの一覧から親子関係を組み立てる
+    pub fn new(tags: &[TagAggregate]) -> Self {
+        Self {
+            names: tags
+                .iter()
+                .map(|tag| (*tag.id(), tag.name().value().to_string()))
+                .collect(),
+            parents: tags
+                .iter()
+                .filter_map(|tag| tag.parent_id().map(|parent| (*tag.id(), parent)))
+                .collect(),
+        }
+    }
+
+    /// ルートから順に親のタグIDを辿った祖先の一覧（自身は含まない）
+    ///
+    /// データが循環していても停止するよう、同じタグに戻った時点で打ち切ります。
+    pub fn ancestors(&self, id: &TagId) -> Vec<TagId> {
+        let mut ancestors = Vec::new();
+        let mut current = self.parents.get(id);
+        while let Some(parent) = current {
+            if parent == id || ancestors.contains(parent) {
+                break;
+            }
+            ancestors.push(*parent);
+            current = self.parents.get(parent);
+        }
+        ancestors.reverse();
+        ancestors
+    }
+
+    /// ルートからのパス（例: `work/project-a`）
+    ///
+    /// 一覧に含まれないタグの場合は`None`を返します。
+    pub fn path(&self, id: &TagId) -> Option<String> {
+        let name = self.names.get(id)?;
+        let mut segments: Vec<&str> = self
+            .ancestors(id)
+            .iter()
+            .filter_map(|ancestor| self.names.get(ancestor).map(String::as_str))
+            .collect();
+        segments.push(name);
+        Some(segments.join(PATH_SEPARATOR))
+    }
+
+    /// 自身とすべての子孫のタグID
+    pub fn self_and_descendants(&self, id: &TagId) -> Vec<TagId> {
+        let mut found = vec![*id];
+        let mut index = 0;
+        while index < found.len() {
+            let current = found[index];
+            for (child, parent) in &self.parents {
+                if *parent == current && !found.contains(child) {
+                    found.push(*child);
+                }
+            }
+            index += 1;
+        }
+        found
+    }
+
+    /// 親にするタグが存在することを確認する
+    pub fn ensure_parent_exists(&self, parent: &TagId) -> Result<()> {
+        if !self.names.contains_key(parent) {
+            bail!("Parent tag ID {} does not exist", parent.value());
+        }
+        Ok(())
+    }
+
+    /// タグを指定した親の子にできるか検証する
+    ///
+    /// 親が存在しない場合や、自身・子孫を親にして循環する場合はエラーになります。
+    pub fn ensure_can_attach(&self, tag: &TagId, parent: &TagId) -> Result<()> {
+        self.ensure_parent_exists(parent)?;
+        if self.self_and_descendants(tag).contains(parent) {
+            bail!(
+                "Tag ID {} cannot be moved under tag ID {} because it would create a cycle",
+                tag.value(),
+                parent.value()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tag::{
+        aggregate::TagReconstructParams,
+        value_objects::{TagDescription, TagName},
+    };
+    use chrono::Utc;
+
+    fn tag(id: i32, name: &str, parent: Option<i32>) -> TagAggregate {
+        TagAggregate::reconstruct(TagReconstructParams {
+            id: TagId::new(id).unwrap(),
+            name: TagName::new(name).unwrap(),
+            description: TagDescription::new("").unwrap(),
+            parent_id: parent.map(|p| TagId::new(p).unwrap()),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+    }
+
+    fn id(value: i32) -> TagId {
+        TagId::new(value).unwrap()
+    }
+
+    fn hierarchy() -> TagHierarchy {
+        TagHierarchy::new(&[
+            tag(1, "work", None),
+            tag(2, "project-a", Some(1)),
+            tag(3, "design", Some(2)),
+            tag(4, "home", None),
+        ])
+    }
+
+    #[test]
+    fn test_path_joins_ancestor_names() {
+        let hierarchy = hierarchy();
+
+        assert_eq!(hierarchy.path(&id(1)).unwrap(), "work");
+        assert_eq!(hierarchy.path(&id(3)).unwrap(), "work/project-a/design");
+        assert!(hierarchy.path(&id(99)).is_none());
+    }
+
+    #[test]
+    fn test_self_and_descendants() {
+        // Arrange
+        let hierarchy = hierarchy();
+
+        // Act
+        let mut ids = hierarchy.self_and_descendants(&id(1));
+        ids.sort_by_key(|tag_id| tag_id.value());
+
+        // Assert
+        assert_eq!(ids, vec![id(1), id(2), id(3)]);
+        assert_eq!(hierarchy.self_and_descendants(&id(4)), vec![id(4)]);
+    }
+
+    #[test]
+    fn test_ensure_can_attach_rejects_cycles() {
+        let hierarchy = hierarchy();
+
+        assert!(hierarchy.ensure_can_attach(&id(4), &id(3)).is_ok());
+        assert!(hierarchy.ensure_can_attach(&id(1), &id(1)).is_err());
+        let error = hierarchy.ensure_can_attach(&id(1), &id(3)).unwrap_err();
+        assert!(error.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn test_ensure_can_attach_rejects_missing_parent() {
+        let hierarchy = hierarchy();
+
+        let error = hierarchy.ensure_can_attach(&id(4), &id(99)).unwrap_err();
+
+        assert_eq!(error.to_string(), "Parent tag ID 99 does not exist");
+    }
+
+    #[test]
+    fn test_ancestors_stop_on_corrupted_cycle() {
+        let hierarchy = TagHierarchy::new(&[tag(1, "a", Some(2)), tag(2, "b", Some(1))]);
+
+        assert_eq!(hierarchy.ancestors(&id(1)), vec![id(2)]);
+        assert_eq!(hierarchy.path(&id(1)).unwrap(), "b/a");
+    }
+}
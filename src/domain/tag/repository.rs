@@ -1,4 +1,7 @@
-use crate::domain::tag::{aggregate::TagAggregate, value_objects::TagId};
+use crate::domain::tag::{
+    aggregate::{NewTag, TagAggregate},
+    value_objects::TagId,
+};
 use anyhow::Result;
 
 /// TagRepository trait - タグの永続化を抽象化
@@ -27,15 +30,15 @@ pub trait TagRepository: Send + Sync {
 
     /// 新しいタグを保存
     ///
-    /// IDが0の場合、新しいIDを割り当てます。
+    /// IDはリポジトリ（データベース）が割り当てます。
     ///
     /// # Arguments
     /// * `tag` - 保存するタグ
     ///
     /// # Returns
     /// * `Ok(TagAggregate)` - 保存されたタグ（IDが割り当てられている）
-    /// * `Err` - エラーが発生した場合
-    async fn save(&self, tag: TagAggregate) -> Result<TagAggregate>;
+    /// * `Err` - 同じ名前（大文字小文字を区別しない）のタグが既にある場合など
+    async fn save(&self, tag: NewTag) -> Result<TagAggregate>;
 
     /// 既存のタグを更新
     ///
@@ -44,7 +47,7 @@ pub trait TagRepository: Send + Sync {
     ///
     /// # Returns
     /// * `Ok(TagAggregate)` - 更新されたタグ
-    /// * `Err` - エラーが発生した場合
+    /// * `Err` - 同じ名前（大文字小文字を区別しない）の別のタグがある場合など
     async fn update(&self, tag: TagAggregate) -> Result<TagAggregate>;
 
     /// IDでタグを削除
@@ -58,7 +61,7 @@ pub trait TagRepository: Send + Sync {
     /// * `Err` - エラーが発生した場合
     async fn delete(&self, id: &TagId) -> Result<bool>;
 
-    /// 名前でタグを検索
+    /// 名前でタグを検索（大文字小文字を区別しない）
     ///
     /// # Arguments
     /// * `name` - 検索するタグの名前
@@ -67,7 +70,6 @@ pub trait TagRepository: Send + Sync {
     /// * `Ok(Some(TagAggregate))` - タグが見つかった場合
     /// * `Ok(None)` - タグが見つからなかった場合
     /// * `Err` - エラーが発生した場合
-    #[allow(dead_code)]
     async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>>;
 
     /// 複数のIDでタグを一括検索
@@ -23,6 +23,13 @@ impl TagName {
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    /// 大文字小文字を区別せずに名前が一致するか
+    ///
+    /// タグ名は大文字小文字を区別せずに一意です。
+    pub fn matches(&self, name: &str) -> bool {
+        self.0.to_lowercase() == name.trim().to_lowercase()
+    }
 }
 
 #[cfg(test)]
@@ -35,6 +42,14 @@ mod tests {
         assert_eq!(name.value(), "重要");
     }
 
+    #[test]
+    fn test_tag_name_matches_ignores_case() {
+        let name = TagName::new("Work").unwrap();
+        assert!(name.matches("work"));
+        assert!(name.matches(" WORK "));
+        assert!(!name.matches("works"));
+    }
+
     #[test]
     fn test_tag_name_single_char() {
         let name = TagName::new("A").unwrap();
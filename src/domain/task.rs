@@ -1,5 +1,11 @@
 pub mod aggregate;
+#[cfg(test)]
+pub(crate) mod arbitrary;
+pub mod builder;
 pub mod events;
+pub mod overdue_tag_policy;
 pub mod repository;
 pub mod specification;
+pub mod stale_policy;
+pub mod status_transition;
 pub mod value_objects;
@@ -1,12 +1,22 @@
 use crate::domain::{
+    clock::Clock,
     tag::value_objects::TagId,
     task::{
-        events::{DomainEvent, TaskCompleted, TaskTagAdded, TaskTagRemoved, TaskTitleChanged},
-        value_objects::{DueDate, Priority, Status, TaskDescription, TaskId, TaskTitle},
+        builder::TaskBuilder,
+        events::{
+            DomainEvent, TaskCompleted, TaskStatusChanged, TaskTagAdded, TaskTagRemoved,
+            TaskTitleChanged,
+        },
+        status_transition::StatusTransitionPolicy,
+        value_objects::{
+            Assignee, ChecklistItem, CustomField, DueDate, Estimate, ExternalRef, GitLink,
+            Priority, Recurrence, Status, StatusChange, TaskContext, TaskDescription, TaskId,
+            TaskTitle,
+        },
     },
 };
 use anyhow::{Result, bail};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// TaskAggregate の再構築用パラメータ
 ///
@@ -23,6 +33,16 @@ pub struct TaskReconstructParams {
     pub updated_at: DateTime<Utc>,
     pub due_date: Option<DueDate>,
     pub completed_at: Option<DateTime<Utc>>,
+    pub custom_fields: Vec<CustomField>,
+    pub checklist: Vec<ChecklistItem>,
+    pub git_links: Vec<GitLink>,
+    pub external_refs: Vec<ExternalRef>,
+    pub recurrence: Option<Recurrence>,
+    pub snoozed_until: Option<NaiveDate>,
+    pub estimate: Option<Estimate>,
+    pub context: Option<TaskContext>,
+    pub assignee: Option<Assignee>,
+    pub status_history: Vec<StatusChange>,
 }
 
 /// TaskAggregate - タスクのAggregate Root
@@ -40,6 +60,26 @@ pub struct TaskAggregate {
     updated_at: DateTime<Utc>,
     due_date: Option<DueDate>,
     completed_at: Option<DateTime<Utc>>,
+    /// カスタムフィールド（キーの昇順、キーは重複しない）
+    custom_fields: Vec<CustomField>,
+    /// チェックリスト（表示順）
+    checklist: Vec<ChecklistItem>,
+    /// gitのコミット・ブランチとの紐づけ（紐づけた順）
+    git_links: Vec<GitLink>,
+    /// 外部サービスの項目との対応（追加した順）
+    external_refs: Vec<ExternalRef>,
+    /// 繰り返しルール（完了後に次の回のタスクを作成する）
+    recurrence: Option<Recurrence>,
+    /// スヌーズの期限（この日まで一覧やアジェンダに表示しない）
+    snoozed_until: Option<NaiveDate>,
+    /// 見積もり時間
+    estimate: Option<Estimate>,
+    /// タスクを実行できる状況（`@home`など）
+    context: Option<TaskContext>,
+    /// 担当者
+    assignee: Option<Assignee>,
+    /// ステータスの変更履歴（変更した順）
+    status_history: Vec<StatusChange>,
     // Domain Events
     domain_events: Vec<Box<dyn DomainEvent>>,
 }
@@ -57,6 +97,16 @@ impl Clone for TaskAggregate {
             updated_at: self.updated_at,
             due_date: self.due_date,
             completed_at: self.completed_at,
+            custom_fields: self.custom_fields.clone(),
+            checklist: self.checklist.clone(),
+            git_links: self.git_links.clone(),
+            external_refs: self.external_refs.clone(),
+            recurrence: self.recurrence,
+            snoozed_until: self.snoozed_until,
+            estimate: self.estimate,
+            context: self.context.clone(),
+            assignee: self.assignee.clone(),
+            status_history: self.status_history.clone(),
             // domain_eventsはクローン時には空にする
             domain_events: Vec::new(),
         }
@@ -75,13 +125,61 @@ impl PartialEq for TaskAggregate {
             && self.updated_at == other.updated_at
             && self.due_date == other.due_date
             && self.completed_at == other.completed_at
+            && self.custom_fields == other.custom_fields
+            && self.checklist == other.checklist
+            && self.git_links == other.git_links
+            && self.external_refs == other.external_refs
+            && self.recurrence == other.recurrence
+            && self.snoozed_until == other.snoozed_until
+            && self.estimate == other.estimate
+            && self.context == other.context
+            && self.assignee == other.assignee
+            && self.status_history == other.status_history
         // domain_eventsは比較しない
     }
 }
 
-impl TaskAggregate {
+/// NewTask - まだ保存されていないタスク
+///
+/// IDはリポジトリ（データベース）が保存時に割り当てるため、保存前のタスクはIDを持ちません。
+/// リポジトリは割り当てたIDで[`NewTask::into_aggregate`]を呼び出し、TaskAggregateに変換します。
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewTask {
+    title: TaskTitle,
+    description: TaskDescription,
+    status: Status,
+    priority: Priority,
+    tags: Vec<TagId>,
+    created_at: DateTime<Utc>,
+    due_date: Option<DueDate>,
+    completed_at: Option<DateTime<Utc>>,
+    /// カスタムフィールド（キーの昇順、キーは重複しない）
+    custom_fields: Vec<CustomField>,
+    /// チェックリスト（表示順）
+    checklist: Vec<ChecklistItem>,
+    /// gitのコミット・ブランチとの紐づけ（削除したタスクを作り直す場合に引き継ぐ）
+    git_links: Vec<GitLink>,
+    /// 外部サービスの項目との対応（追加した順）
+    external_refs: Vec<ExternalRef>,
+    /// 繰り返しルール
+    recurrence: Option<Recurrence>,
+    /// スヌーズの期限（削除したタスクを作り直す場合に引き継ぐ）
+    snoozed_until: Option<NaiveDate>,
+    /// 見積もり時間
+    estimate: Option<Estimate>,
+    /// タスクを実行できる状況
+    context: Option<TaskContext>,
+    /// 担当者
+    assignee: Option<Assignee>,
+    /// ステータスの変更履歴（削除したタスクを作り直す場合に引き継ぐ）
+    status_history: Vec<StatusChange>,
+}
+
+impl NewTask {
     /// 新しいタスクを作成します（ファクトリメソッド）
-    pub fn new(
+    ///
+    /// タスクの作成には[`TaskBuilder`]を使用してください。
+    pub(super) fn new(
         title: TaskTitle,
         description: TaskDescription,
         status: Status,
@@ -90,7 +188,6 @@ impl TaskAggregate {
         due_date: Option<DueDate>,
     ) -> Self {
         let now = Utc::now();
-        let id = TaskId::new(0).unwrap(); // デフォルトは0、リポジトリで新しいIDを割り当てる
 
         // Status::Completedで作成する場合の処理
         let completed_at = if status == Status::Completed {
@@ -99,32 +196,181 @@ impl TaskAggregate {
             None
         };
 
-        let mut domain_events: Vec<Box<dyn DomainEvent>> = Vec::new();
-        if status == Status::Completed {
-            let event = TaskCompleted::new(id, now);
-            domain_events.push(Box::new(event));
-        }
-
         Self {
-            id,
             title,
             description,
             status,
             priority,
             tags,
             created_at: now,
-            updated_at: now,
             due_date,
             completed_at,
+            custom_fields: Vec::new(),
+            checklist: Vec::new(),
+            git_links: Vec::new(),
+            external_refs: Vec::new(),
+            recurrence: None,
+            snoozed_until: None,
+            estimate: None,
+            context: None,
+            assignee: None,
+            status_history: Vec::new(),
+        }
+    }
+
+    /// カスタムフィールドを設定します（同じキーのフィールドは置き換える）
+    pub fn set_custom_field(&mut self, field: CustomField) {
+        match self
+            .custom_fields
+            .binary_search_by(|existing| existing.key().cmp(field.key()))
+        {
+            Ok(index) => self.custom_fields[index] = field,
+            Err(index) => self.custom_fields.insert(index, field),
+        }
+    }
+
+    /// 外部サービスの項目との対応を追加します
+    ///
+    /// 同じ項目との対応が既にある場合はエラーを返します。
+    pub fn add_external_ref(&mut self, external_ref: ExternalRef) -> Result<()> {
+        if self
+            .external_refs
+            .iter()
+            .any(|existing| existing.points_to(external_ref.source(), external_ref.external_id()))
+        {
+            bail!("Task is already linked to {}", external_ref);
+        }
+        self.external_refs.push(external_ref);
+        Ok(())
+    }
+
+    /// 繰り返しルールを設定します
+    pub fn set_recurrence(&mut self, recurrence: Option<Recurrence>) {
+        self.recurrence = recurrence;
+    }
+
+    /// 見積もり時間を設定します
+    pub fn set_estimate(&mut self, estimate: Option<Estimate>) {
+        self.estimate = estimate;
+    }
+
+    /// コンテキストを設定します
+    pub fn set_context(&mut self, context: Option<TaskContext>) {
+        self.context = context;
+    }
+
+    /// 担当者を設定します
+    pub fn set_assignee(&mut self, assignee: Option<Assignee>) {
+        self.assignee = assignee;
+    }
+
+    /// 作成日時を設定します（インポート元の作成日時を引き継ぐ場合に使用）
+    pub fn set_created_at(&mut self, created_at: DateTime<Utc>) {
+        self.created_at = created_at;
+    }
+
+    /// 完了日時を設定します
+    ///
+    /// 完了状態でないタスクには完了日時がないため、Completed以外のステータスでは何もしません。
+    pub fn set_completed_at(&mut self, completed_at: DateTime<Utc>) {
+        if self.status == Status::Completed {
+            self.completed_at = Some(completed_at);
+        }
+    }
+
+    /// リポジトリが割り当てたIDでTaskAggregateに変換します
+    ///
+    /// 完了状態で作成したタスクには、TaskCompletedイベントを記録します。
+    pub fn into_aggregate(self, id: TaskId) -> TaskAggregate {
+        let mut domain_events: Vec<Box<dyn DomainEvent>> = Vec::new();
+        if let Some(completed_at) = self.completed_at {
+            domain_events.push(Box::new(TaskCompleted::new(id, completed_at)));
+        }
+
+        TaskAggregate {
+            id,
+            title: self.title,
+            description: self.description,
+            status: self.status,
+            priority: self.priority,
+            tags: self.tags,
+            created_at: self.created_at,
+            updated_at: self.created_at,
+            due_date: self.due_date,
+            completed_at: self.completed_at,
+            custom_fields: self.custom_fields,
+            checklist: self.checklist,
+            git_links: self.git_links,
+            external_refs: self.external_refs,
+            recurrence: self.recurrence,
+            snoozed_until: self.snoozed_until,
+            estimate: self.estimate,
+            context: self.context,
+            assignee: self.assignee,
+            status_history: self.status_history,
             domain_events,
         }
     }
 
+    // Getters
+    pub fn title(&self) -> &TaskTitle {
+        &self.title
+    }
+
+    pub fn description(&self) -> &TaskDescription {
+        &self.description
+    }
+
+    pub fn status(&self) -> &Status {
+        &self.status
+    }
+
+    pub fn priority(&self) -> &Priority {
+        &self.priority
+    }
+
+    pub fn due_date(&self) -> &Option<DueDate> {
+        &self.due_date
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn completed_at(&self) -> &Option<DateTime<Utc>> {
+        &self.completed_at
+    }
+
+    pub fn recurrence(&self) -> Option<&Recurrence> {
+        self.recurrence.as_ref()
+    }
+
+    pub fn snoozed_until(&self) -> Option<NaiveDate> {
+        self.snoozed_until
+    }
+
+    pub fn estimate(&self) -> Option<Estimate> {
+        self.estimate
+    }
+
+    pub fn context(&self) -> Option<&TaskContext> {
+        self.context.as_ref()
+    }
+
+    pub fn assignee(&self) -> Option<&Assignee> {
+        self.assignee.as_ref()
+    }
+}
+
+impl TaskAggregate {
     /// リポジトリからの再構築用ファクトリメソッド
     ///
     /// データベースから読み込んだデータをTaskAggregateに変換する際に使用します。
     /// ドメインイベントは空の状態で作成されます。
     pub fn reconstruct(params: TaskReconstructParams) -> Self {
+        let mut custom_fields = params.custom_fields;
+        custom_fields.sort_by(|a, b| a.key().cmp(b.key()));
+        custom_fields.dedup_by(|a, b| a.key() == b.key());
         Self {
             id: params.id,
             title: params.title,
@@ -136,66 +382,81 @@ impl TaskAggregate {
             updated_at: params.updated_at,
             due_date: params.due_date,
             completed_at: params.completed_at,
+            custom_fields,
+            checklist: params.checklist,
+            git_links: params.git_links,
+            external_refs: params.external_refs,
+            recurrence: params.recurrence,
+            snoozed_until: params.snoozed_until,
+            estimate: params.estimate,
+            context: params.context,
+            assignee: params.assignee,
+            status_history: params.status_history,
             domain_events: Vec::new(),
         }
     }
 
-    /// IDを設定した新しいインスタンスを返す
+    /// 削除したタスクを作り直すための保存前のタスクに変換します
     ///
-    /// リポジトリがタスクを保存する際に新しいIDを割り当てるために使用します。
-    /// 元のインスタンスは変更せず、新しいインスタンスを返します。
-    #[allow(dead_code)]
-    pub fn with_id(self, id: TaskId) -> Self {
-        Self {
-            id,
+    /// IDとドメインイベント以外（作成日時・完了日時・チェックリスト・gitの紐づけ・
+    /// 外部の項目との対応・ステータスの変更履歴など）をそのまま引き継ぎます。
+    pub fn into_new_task(self) -> NewTask {
+        NewTask {
             title: self.title,
             description: self.description,
             status: self.status,
             priority: self.priority,
             tags: self.tags,
             created_at: self.created_at,
-            updated_at: self.updated_at,
             due_date: self.due_date,
             completed_at: self.completed_at,
-            domain_events: self.domain_events,
+            custom_fields: self.custom_fields,
+            checklist: self.checklist,
+            git_links: self.git_links,
+            external_refs: self.external_refs,
+            recurrence: self.recurrence,
+            snoozed_until: self.snoozed_until,
+            estimate: self.estimate,
+            context: self.context,
+            assignee: self.assignee,
+            status_history: self.status_history,
         }
     }
 
     /// タスクを完了します（利便性メソッド）
     ///
-    /// change_status(Status::Completed)を呼び出すラッパーメソッドです。
-    /// ステータスをCompletedに変更し、completed_atを現在時刻に設定します。
+    /// 既定の遷移ポリシーでchange_status(Status::Completed)を呼び出すラッパーメソッドです。
+    /// ステータスをCompletedに変更し、completed_atを`clock`の現在時刻に設定します。
     /// 既に完了している場合は、何もしません。
     #[allow(dead_code)]
-    pub fn complete(&mut self) -> Result<()> {
-        self.change_status(Status::Completed)
+    pub fn complete(&mut self, clock: &dyn Clock) -> Result<()> {
+        self.change_status(Status::Completed, &StatusTransitionPolicy::default(), clock)
     }
 
     /// タスクが期限切れかどうかを判定します
     ///
     /// 以下の条件で期限切れと判定されます：
     /// - 期限が設定されている
-    /// - 現在の日付が期限を過ぎている
+    /// - 今日の日付が期限を過ぎている
     /// - タスクが完了していない
-    #[allow(dead_code)]
-    pub fn is_overdue(&self) -> bool {
+    ///
+    /// # Arguments
+    /// * `today` - 基準日（利用者のタイムゾーンでの今日。`Clock::today()`で取得）
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
         if self.status == Status::Completed {
             return false;
         }
 
-        if let Some(due_date) = &self.due_date {
-            let today = Utc::now().naive_utc().date();
-            return due_date.value() < today;
-        }
-
-        false
+        self.due_date
+            .as_ref()
+            .is_some_and(|due_date| due_date.value() < today)
     }
 
     /// タスクのタイトルを変更します
-    pub fn change_title(&mut self, new_title: TaskTitle) -> Result<()> {
+    pub fn change_title(&mut self, new_title: TaskTitle, clock: &dyn Clock) -> Result<()> {
         let old_title = self.title.clone();
         self.title = new_title.clone();
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         // Domain Event発行
         let event = TaskTitleChanged::new(self.id, old_title, new_title);
@@ -205,24 +466,64 @@ impl TaskAggregate {
     }
 
     /// タスクの説明を変更します
-    pub fn change_description(&mut self, new_description: TaskDescription) -> Result<()> {
+    pub fn change_description(
+        &mut self,
+        new_description: TaskDescription,
+        clock: &dyn Clock,
+    ) -> Result<()> {
         self.description = new_description;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
         Ok(())
     }
 
     /// タスクのステータスを変更します
     ///
+    /// 遷移ポリシーで許可されない遷移（再開の明示が必要なものを含む）はエラーになります。
+    /// ステータスが変わった場合はTaskStatusChangedイベントが発行されます。
     /// Status::Completedへ変更する場合、completed_atが自動的に設定され、
     /// TaskCompletedイベントが発行されます。
     /// Completedから他のステータスへ変更する場合、completed_atはクリアされます。
-    pub fn change_status(&mut self, new_status: Status) -> Result<()> {
+    /// 更新日時・完了日時・ステータスの変更履歴には`clock`の現在時刻を記録します。
+    pub fn change_status(
+        &mut self,
+        new_status: Status,
+        policy: &StatusTransitionPolicy,
+        clock: &dyn Clock,
+    ) -> Result<()> {
+        policy.validate(self.status, new_status, false)?;
+        self.apply_status(new_status, clock.now());
+        Ok(())
+    }
+
+    /// タスクを再開してステータスを変更します
+    ///
+    /// change_statusと同様ですが、再開の明示が必要な遷移（完了済みから未完了へ戻すなど）も許可します。
+    /// 遷移ポリシーで禁止された遷移はエラーになります。
+    pub fn reopen(
+        &mut self,
+        new_status: Status,
+        policy: &StatusTransitionPolicy,
+        clock: &dyn Clock,
+    ) -> Result<()> {
+        policy.validate(self.status, new_status, true)?;
+        self.apply_status(new_status, clock.now());
+        Ok(())
+    }
+
+    fn apply_status(&mut self, new_status: Status, now: DateTime<Utc>) {
         let old_status = self.status;
-        let now = Utc::now();
 
         self.status = new_status;
         self.updated_at = now;
 
+        if old_status != new_status {
+            let event = TaskStatusChanged::new(self.id, old_status, new_status);
+            // フロー統計のため、イベントの内容を履歴として記録する（日時は完了日時と揃える）
+            self.status_history
+                .push(StatusChange::new(event.from, event.to, now));
+            self.domain_events.push(Box::new(event));
+        }
+
         // Completedへの変更時の処理
         if new_status == Status::Completed && old_status != Status::Completed {
             self.completed_at = Some(now);
@@ -235,30 +536,32 @@ impl TaskAggregate {
         else if old_status == Status::Completed && new_status != Status::Completed {
             self.completed_at = None;
         }
-
-        Ok(())
     }
 
     /// タスクの優先度を変更します
-    pub fn change_priority(&mut self, new_priority: Priority) -> Result<()> {
+    pub fn change_priority(&mut self, new_priority: Priority, clock: &dyn Clock) -> Result<()> {
         self.priority = new_priority;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
         Ok(())
     }
 
     /// タスクの期限日を変更します
-    pub fn change_due_date(&mut self, new_due_date: Option<DueDate>) -> Result<()> {
+    pub fn change_due_date(
+        &mut self,
+        new_due_date: Option<DueDate>,
+        clock: &dyn Clock,
+    ) -> Result<()> {
         self.due_date = new_due_date;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
         Ok(())
     }
 
     /// タスクのタグを置き換えます
     ///
     /// 既存のタグをすべて削除して、新しいタグリストで置き換えます。
-    pub fn replace_tags(&mut self, new_tags: Vec<TagId>) -> Result<()> {
+    pub fn replace_tags(&mut self, new_tags: Vec<TagId>, clock: &dyn Clock) -> Result<()> {
         self.tags = new_tags;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
         Ok(())
     }
 
@@ -266,12 +569,12 @@ impl TaskAggregate {
     ///
     /// 既に同じタグが存在する場合はエラーを返します。
     #[allow(dead_code)]
-    pub fn add_tag(&mut self, tag_id: TagId) -> Result<()> {
+    pub fn add_tag(&mut self, tag_id: TagId, clock: &dyn Clock) -> Result<()> {
         if self.tags.contains(&tag_id) {
             bail!("Tag ID {} is already added", tag_id.value());
         }
         self.tags.push(tag_id);
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         // Domain Event発行
         let event = TaskTagAdded::new(self.id, tag_id);
@@ -284,7 +587,7 @@ impl TaskAggregate {
     ///
     /// 指定したタグが存在しない場合はエラーを返します。
     #[allow(dead_code)]
-    pub fn remove_tag(&mut self, tag_id: &TagId) -> Result<()> {
+    pub fn remove_tag(&mut self, tag_id: &TagId, clock: &dyn Clock) -> Result<()> {
         let original_len = self.tags.len();
         self.tags.retain(|t| t != tag_id);
 
@@ -292,7 +595,7 @@ impl TaskAggregate {
             bail!("Tag ID {} does not exist", tag_id.value());
         }
 
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         // Domain Event発行
         let event = TaskTagRemoved::new(self.id, *tag_id);
@@ -301,6 +604,291 @@ impl TaskAggregate {
         Ok(())
     }
 
+    /// ポリシーに従って自動で付け外しするタグ（期限切れのタグなど）を設定します
+    ///
+    /// 利用者による変更ではないため、更新日時は変更しません（放置の判定に影響させないため）。
+    /// タグを付けた・外した場合はドメインイベントを発行し、trueを返します。
+    pub fn set_automatic_tag(&mut self, tag_id: TagId, attached: bool) -> bool {
+        if self.tags.contains(&tag_id) == attached {
+            return false;
+        }
+        if attached {
+            self.tags.push(tag_id);
+            self.domain_events
+                .push(Box::new(TaskTagAdded::new(self.id, tag_id)));
+        } else {
+            self.tags.retain(|t| *t != tag_id);
+            self.domain_events
+                .push(Box::new(TaskTagRemoved::new(self.id, tag_id)));
+        }
+        true
+    }
+
+    /// カスタムフィールドを設定します
+    ///
+    /// 同じキーのフィールドがある場合は値を置き換えます。
+    pub fn set_custom_field(&mut self, field: CustomField, clock: &dyn Clock) -> Result<()> {
+        match self
+            .custom_fields
+            .binary_search_by(|existing| existing.key().cmp(field.key()))
+        {
+            Ok(index) => self.custom_fields[index] = field,
+            Err(index) => self.custom_fields.insert(index, field),
+        }
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// カスタムフィールドを削除します
+    ///
+    /// 指定したキーのフィールドが存在しない場合はエラーを返します。
+    pub fn remove_custom_field(&mut self, key: &str, clock: &dyn Clock) -> Result<()> {
+        let key = CustomField::normalize_key(key)?;
+        let original_len = self.custom_fields.len();
+        self.custom_fields.retain(|field| field.key() != key);
+
+        if self.custom_fields.len() == original_len {
+            bail!("Custom field '{}' does not exist", key);
+        }
+
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// チェックリストの末尾に項目を追加します
+    pub fn add_checklist_item(&mut self, item: ChecklistItem, clock: &dyn Clock) -> Result<()> {
+        self.checklist.push(item);
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// チェックリスト項目の完了状態を切り替えます
+    ///
+    /// 項目は1から始まる番号で指定し、切り替え後の項目を返します。
+    pub fn toggle_checklist_item(
+        &mut self,
+        number: usize,
+        clock: &dyn Clock,
+    ) -> Result<&ChecklistItem> {
+        let index = self.checklist_index(number)?;
+        self.checklist[index] = self.checklist[index].toggled();
+        self.updated_at = clock.now();
+        Ok(&self.checklist[index])
+    }
+
+    /// チェックリスト項目を削除します
+    ///
+    /// 項目は1から始まる番号で指定し、削除した項目を返します。
+    pub fn remove_checklist_item(
+        &mut self,
+        number: usize,
+        clock: &dyn Clock,
+    ) -> Result<ChecklistItem> {
+        let index = self.checklist_index(number)?;
+        let removed = self.checklist.remove(index);
+        self.updated_at = clock.now();
+        Ok(removed)
+    }
+
+    /// gitのコミット・ブランチとの紐づけを追加します
+    ///
+    /// 同じリポジトリ・ブランチ・コミットに紐づけ済みの場合はエラーになります。
+    pub fn link_git(&mut self, link: GitLink, clock: &dyn Clock) -> Result<()> {
+        if self
+            .git_links
+            .iter()
+            .any(|existing| existing.points_to_same(&link))
+        {
+            bail!("Task is already linked to commit {}", link.short_commit());
+        }
+        self.git_links.push(link);
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// 外部サービスの項目との対応を追加します
+    ///
+    /// 同じ項目に対応付け済みの場合はエラーになります。
+    pub fn add_external_ref(&mut self, external_ref: ExternalRef, clock: &dyn Clock) -> Result<()> {
+        if self
+            .external_ref(external_ref.source(), external_ref.external_id())
+            .is_some()
+        {
+            bail!("Task is already linked to {}", external_ref);
+        }
+        self.external_refs.push(external_ref);
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// 外部サービスの項目と同期した時点の状態を記録します
+    ///
+    /// `synced_status`には外部の項目の状態に対応するステータスを指定します。
+    /// 同期の記録はタスクの内容の変更ではないため、更新日時は変えません。
+    pub fn record_external_sync(
+        &mut self,
+        source: &str,
+        external_id: &str,
+        remote_updated_at: DateTime<Utc>,
+        synced_status: Status,
+    ) -> Result<()> {
+        let Some(index) = self
+            .external_refs
+            .iter()
+            .position(|existing| existing.points_to(source, external_id))
+        else {
+            bail!("Task is not linked to {} {}", source, external_id);
+        };
+        let synced = self.external_refs[index]
+            .clone()
+            .synced(remote_updated_at, synced_status);
+        self.external_refs[index] = synced;
+        Ok(())
+    }
+
+    /// 繰り返しルールを設定します
+    pub fn change_recurrence(
+        &mut self,
+        recurrence: Option<Recurrence>,
+        clock: &dyn Clock,
+    ) -> Result<()> {
+        self.recurrence = recurrence;
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// 見積もり時間を変更します
+    pub fn change_estimate(&mut self, estimate: Option<Estimate>, clock: &dyn Clock) -> Result<()> {
+        self.estimate = estimate;
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// コンテキストを変更します
+    pub fn change_context(
+        &mut self,
+        context: Option<TaskContext>,
+        clock: &dyn Clock,
+    ) -> Result<()> {
+        self.context = context;
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// 担当者を変更します
+    pub fn change_assignee(&mut self, assignee: Option<Assignee>, clock: &dyn Clock) -> Result<()> {
+        self.assignee = assignee;
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// タスクを指定した日までスヌーズします
+    ///
+    /// スヌーズ中のタスクは既定の一覧やアジェンダに表示されません。
+    /// スヌーズの期限は`clock`の今日より後の日付である必要があり、完了済みのタスクはスヌーズできません。
+    pub fn snooze(&mut self, until: NaiveDate, clock: &dyn Clock) -> Result<()> {
+        let today = clock.today();
+        if self.status == Status::Completed {
+            bail!("Cannot snooze completed task {}", self.id.value());
+        }
+        if until <= today {
+            bail!("Snooze date must be after today ({}): {}", today, until);
+        }
+        self.snoozed_until = Some(until);
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// スヌーズを解除します
+    pub fn unsnooze(&mut self, clock: &dyn Clock) -> Result<()> {
+        self.snoozed_until = None;
+        self.updated_at = clock.now();
+        Ok(())
+    }
+
+    /// `today`の時点でスヌーズ中かどうか
+    ///
+    /// スヌーズの期限の当日になると自動的にスヌーズ中ではなくなります。
+    pub fn is_snoozed(&self, today: NaiveDate) -> bool {
+        self.snoozed_until.is_some_and(|until| until > today)
+    }
+
+    /// 繰り返しのタスクの次の回の期限日を求めます
+    ///
+    /// 完了済みで繰り返しルールがあるタスクのみが対象です。期限日（なければ完了日）から
+    /// ルールに従って求め、`today`より前になる発生日は飛ばします。
+    pub fn next_occurrence_date(&self, today: NaiveDate) -> Result<Option<NaiveDate>> {
+        let Some(recurrence) = self.recurrence else {
+            return Ok(None);
+        };
+        if self.status != Status::Completed {
+            return Ok(None);
+        }
+        let base = match (&self.due_date, &self.completed_at) {
+            (Some(due_date), _) => due_date.value(),
+            (None, Some(completed_at)) => completed_at.date_naive(),
+            (None, None) => return Ok(None),
+        };
+        recurrence.next_on_or_after(base, today).map(Some)
+    }
+
+    /// 繰り返しのタスクの次の回のタスクを作成します
+    ///
+    /// タイトル・説明・優先度・タグ・カスタムフィールド・チェックリスト（未完了に戻す）・繰り返しルール・
+    /// 見積もり時間・コンテキスト・担当者を引き継ぎます。次の回を作成するのは1度だけにするため、このタスクの繰り返しルールは解除します。
+    pub fn spawn_next_occurrence(
+        &mut self,
+        due_date: DueDate,
+        clock: &dyn Clock,
+    ) -> Result<NewTask> {
+        let Some(recurrence) = self.recurrence else {
+            bail!("Task {} does not recur", self.id.value());
+        };
+        let mut next = TaskBuilder::new(self.title.clone())
+            .description(self.description.clone())
+            .priority(self.priority)
+            .tags(self.tags.clone())
+            .due_date(Some(due_date))
+            .estimate(self.estimate)
+            .context(self.context.clone())
+            .assignee(self.assignee.clone())
+            .build();
+        next.custom_fields = self.custom_fields.clone();
+        next.checklist = self
+            .checklist
+            .iter()
+            .map(|item| ChecklistItem::new(item.text(), false))
+            .collect::<Result<_>>()?;
+        next.recurrence = Some(recurrence);
+        self.change_recurrence(None, clock)?;
+        Ok(next)
+    }
+
+    /// このタスクに続けて行うフォローアップのタスクを作成します
+    ///
+    /// 優先度・タグ・コンテキスト・担当者を引き継ぎます。説明・期限日・チェックリスト・繰り返しルール・
+    /// 見積もり時間は作業ごとに異なるため引き継ぎません。
+    pub fn follow_up(&self, title: TaskTitle) -> NewTask {
+        TaskBuilder::new(title)
+            .priority(self.priority)
+            .tags(self.tags.clone())
+            .context(self.context.clone())
+            .assignee(self.assignee.clone())
+            .build()
+    }
+
+    /// 1から始まる項目番号をインデックスに変換
+    fn checklist_index(&self, number: usize) -> Result<usize> {
+        if number == 0 || number > self.checklist.len() {
+            bail!(
+                "Checklist item {} does not exist (task has {} items)",
+                number,
+                self.checklist.len()
+            );
+        }
+        Ok(number - 1)
+    }
+
     // Getters
     pub fn id(&self) -> &TaskId {
         &self.id
@@ -338,12 +926,78 @@ impl TaskAggregate {
         &self.due_date
     }
 
+    pub fn custom_fields(&self) -> &[CustomField] {
+        &self.custom_fields
+    }
+
+    /// 指定したキーのカスタムフィールド（キーは大文字小文字を区別しない）
+    pub fn custom_field(&self, key: &str) -> Option<&CustomField> {
+        let key = key.trim().to_ascii_lowercase();
+        self.custom_fields.iter().find(|field| field.key() == key)
+    }
+
     pub fn completed_at(&self) -> &Option<DateTime<Utc>> {
         &self.completed_at
     }
 
+    pub fn checklist(&self) -> &[ChecklistItem] {
+        &self.checklist
+    }
+
+    pub fn git_links(&self) -> &[GitLink] {
+        &self.git_links
+    }
+
+    pub fn external_refs(&self) -> &[ExternalRef] {
+        &self.external_refs
+    }
+
+    pub fn recurrence(&self) -> Option<&Recurrence> {
+        self.recurrence.as_ref()
+    }
+
+    pub fn snoozed_until(&self) -> Option<NaiveDate> {
+        self.snoozed_until
+    }
+
+    pub fn estimate(&self) -> Option<Estimate> {
+        self.estimate
+    }
+
+    pub fn context(&self) -> Option<&TaskContext> {
+        self.context.as_ref()
+    }
+
+    pub fn assignee(&self) -> Option<&Assignee> {
+        self.assignee.as_ref()
+    }
+
+    pub fn status_history(&self) -> &[StatusChange] {
+        &self.status_history
+    }
+
+    /// 作成時のステータス（最初の変更の変更前、変更がなければ現在のステータス）
+    pub fn initial_status(&self) -> Status {
+        self.status_history
+            .first()
+            .map_or(self.status, StatusChange::from)
+    }
+
+    /// 指定したコンテキストで表示するタスクかどうか
+    ///
+    /// コンテキストが設定されていないタスクは、どのコンテキストでも表示します。
+    pub fn is_visible_in_context(&self, context: &TaskContext) -> bool {
+        self.context.as_ref().is_none_or(|own| own == context)
+    }
+
+    /// 外部サービスの項目との対応を取得
+    pub fn external_ref(&self, source: &str, external_id: &str) -> Option<&ExternalRef> {
+        self.external_refs
+            .iter()
+            .find(|existing| existing.points_to(source, external_id))
+    }
+
     /// ドメインイベントを取得します
-    #[allow(dead_code)]
     pub fn domain_events(&self) -> &Vec<Box<dyn DomainEvent>> {
         &self.domain_events
     }
@@ -351,7 +1005,6 @@ impl TaskAggregate {
     /// ドメインイベントをクリアします
     ///
     /// イベントハンドラで処理した後に呼び出されることを想定しています。
-    #[allow(dead_code)]
     pub fn clear_events(&mut self) {
         self.domain_events.clear();
     }
@@ -360,7 +1013,14 @@ impl TaskAggregate {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
+    use crate::domain::clock::{FixedClock, Timezone};
+    use crate::domain::task::status_transition::TransitionRule;
+    use chrono::{Duration, FixedOffset, TimeZone};
+
+    /// ステータスの変更日時の基準（時刻を固定する）
+    fn clock() -> FixedClock {
+        FixedClock::at(2026, 1, 10, 9, 0)
+    }
 
     #[test]
     fn test_new_task() {
@@ -373,16 +1033,17 @@ mod tests {
         let due_date = None;
 
         // Act
-        let task = TaskAggregate::new(
-            title.clone(),
-            description.clone(),
-            status,
-            priority,
-            tags.clone(),
-            due_date,
-        );
+        let task = TaskBuilder::new(title.clone())
+            .description(description.clone())
+            .status(status)
+            .priority(priority)
+            .tags(tags.clone())
+            .due_date(due_date)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Assert
+        assert_eq!(task.id().value(), 1);
         assert_eq!(task.title(), &title);
         assert_eq!(task.description(), &description);
         assert_eq!(task.status(), &status);
@@ -403,14 +1064,14 @@ mod tests {
         let due_date = None;
 
         // Act
-        let task = TaskAggregate::new(
-            title.clone(),
-            description.clone(),
-            status,
-            priority,
-            tags.clone(),
-            due_date,
-        );
+        let task = TaskBuilder::new(title.clone())
+            .description(description.clone())
+            .status(status)
+            .priority(priority)
+            .tags(tags.clone())
+            .due_date(due_date)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Assert
         assert_eq!(task.status(), &Status::Completed);
@@ -424,14 +1085,11 @@ mod tests {
         let description = TaskDescription::new("").unwrap();
 
         // Act
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Completed,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .status(Status::Completed)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Assert
         assert_eq!(task.domain_events().len(), 1);
@@ -448,17 +1106,15 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("完了するタスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::InProgress,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .status(Status::InProgress)
+            .priority(Priority::High)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        let result = task.complete();
+        let result = task.complete(&clock());
 
         // Assert
         assert!(result.is_ok());
@@ -471,19 +1127,16 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("既に完了したタスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
-        task.complete().unwrap();
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::Low)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.complete(&clock()).unwrap();
         let first_completed_at = *task.completed_at();
 
         // Act
-        let result = task.complete();
+        let result = task.complete(&clock());
 
         // Assert
         assert!(result.is_ok());
@@ -496,17 +1149,14 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("期限なしタスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
 
         // Act & Assert
-        assert!(!task.is_overdue());
+        assert!(!task.is_overdue(today));
     }
 
     #[test]
@@ -514,19 +1164,18 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("期限切れタスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let past_date = Utc::now().naive_utc().date() - Duration::days(1);
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let past_date = today - Duration::days(1);
         let due_date = Some(DueDate::new(past_date).unwrap());
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            due_date,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .due_date(due_date)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act & Assert
-        assert!(task.is_overdue());
+        assert!(task.is_overdue(today));
     }
 
     #[test]
@@ -534,19 +1183,17 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("未来の期限").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let future_date = Utc::now().naive_utc().date() + Duration::days(1);
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let future_date = today + Duration::days(1);
         let due_date = Some(DueDate::new(future_date).unwrap());
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            due_date,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .due_date(due_date)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act & Assert
-        assert!(!task.is_overdue());
+        assert!(!task.is_overdue(today));
     }
 
     #[test]
@@ -554,20 +1201,35 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("完了した期限切れタスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let past_date = Utc::now().naive_utc().date() - Duration::days(1);
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let past_date = today - Duration::days(1);
         let due_date = Some(DueDate::new(past_date).unwrap());
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            due_date,
-        );
-        task.complete().unwrap();
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .due_date(due_date)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.complete(&clock()).unwrap();
 
         // Act & Assert
-        assert!(!task.is_overdue());
+        assert!(!task.is_overdue(today));
+    }
+
+    #[test]
+    fn test_is_overdue_depends_on_timezone_near_midnight() {
+        // Arrange: UTC 2026-01-10 20:30は日本時間で1月11日5:30
+        let due_date = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let task = TaskBuilder::new(TaskTitle::new("日付の境界").unwrap())
+            .due_date(Some(DueDate::new(due_date).unwrap()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let now = Utc.with_ymd_and_hms(2026, 1, 10, 20, 30, 0).unwrap();
+        let tokyo = Timezone::Fixed(FixedOffset::east_opt(9 * 3600).unwrap());
+
+        // Act & Assert
+        assert!(task.is_overdue(tokyo.date_of(now)));
+        assert!(!task.is_overdue(Timezone::Utc.date_of(now)));
     }
 
     #[test]
@@ -575,41 +1237,293 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("元のタイトル").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let new_title = TaskTitle::new("新しいタイトル").unwrap();
 
         // Act
-        let result = task.change_title(new_title.clone());
+        let result = task.change_title(new_title.clone(), &clock());
 
         // Assert
         assert!(result.is_ok());
         assert_eq!(task.title(), &new_title);
     }
 
+    #[test]
+    fn test_mutators_record_clock_time_as_updated_at() {
+        // Arrange: 操作ごとに1分ずつ進めたClockを使う
+        let mut task = TaskBuilder::new(TaskTitle::new("更新日時").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let linked_at = clock().now();
+        type Operation = Box<dyn Fn(&mut TaskAggregate, &FixedClock)>;
+        let operations: Vec<(&str, Operation)> = vec![
+            (
+                "change_title",
+                Box::new(|task, clock| {
+                    task.change_title(TaskTitle::new("変更後").unwrap(), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "change_description",
+                Box::new(|task, clock| {
+                    task.change_description(TaskDescription::new("説明").unwrap(), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "change_priority",
+                Box::new(|task, clock| task.change_priority(Priority::High, clock).unwrap()),
+            ),
+            (
+                "change_due_date",
+                Box::new(|task, clock| {
+                    task.change_due_date(Some(DueDate::new(clock.today()).unwrap()), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "replace_tags",
+                Box::new(|task, clock| {
+                    task.replace_tags(vec![TagId::new(1).unwrap()], clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "add_tag",
+                Box::new(|task, clock| task.add_tag(TagId::new(2).unwrap(), clock).unwrap()),
+            ),
+            (
+                "remove_tag",
+                Box::new(|task, clock| task.remove_tag(&TagId::new(2).unwrap(), clock).unwrap()),
+            ),
+            (
+                "set_custom_field",
+                Box::new(|task, clock| {
+                    task.set_custom_field(CustomField::new("client", "ACME").unwrap(), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "remove_custom_field",
+                Box::new(|task, clock| task.remove_custom_field("client", clock).unwrap()),
+            ),
+            (
+                "add_checklist_item",
+                Box::new(|task, clock| {
+                    task.add_checklist_item(ChecklistItem::new("項目", false).unwrap(), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "toggle_checklist_item",
+                Box::new(|task, clock| {
+                    task.toggle_checklist_item(1, clock).unwrap();
+                }),
+            ),
+            (
+                "remove_checklist_item",
+                Box::new(|task, clock| {
+                    task.remove_checklist_item(1, clock).unwrap();
+                }),
+            ),
+            (
+                "link_git",
+                Box::new(move |task, clock| {
+                    task.link_git(
+                        GitLink::new("/src/yaru", None, "0123456789abcdef", linked_at).unwrap(),
+                        clock,
+                    )
+                    .unwrap()
+                }),
+            ),
+            (
+                "add_external_ref",
+                Box::new(move |task, clock| {
+                    task.add_external_ref(
+                        ExternalRef::new("github", "1", None, linked_at, Status::Pending).unwrap(),
+                        clock,
+                    )
+                    .unwrap()
+                }),
+            ),
+            (
+                "change_recurrence",
+                Box::new(|task, clock| {
+                    task.change_recurrence(Some("daily".parse().unwrap()), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "change_estimate",
+                Box::new(|task, clock| {
+                    task.change_estimate(Some("45m".parse().unwrap()), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "change_context",
+                Box::new(|task, clock| {
+                    task.change_context(Some(TaskContext::new("work").unwrap()), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "change_assignee",
+                Box::new(|task, clock| {
+                    task.change_assignee(Some(Assignee::new("alice").unwrap()), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "snooze",
+                Box::new(|task, clock| {
+                    task.snooze(clock.today() + Duration::days(1), clock)
+                        .unwrap()
+                }),
+            ),
+            (
+                "unsnooze",
+                Box::new(|task, clock| task.unsnooze(clock).unwrap()),
+            ),
+        ];
+
+        for (minutes, (name, operation)) in operations.into_iter().enumerate() {
+            let clock = clock().advanced(Duration::minutes(minutes as i64 + 1));
+
+            // Act
+            operation(&mut task, &clock);
+
+            // Assert
+            assert_eq!(*task.updated_at(), clock.now(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_set_and_remove_custom_fields() {
+        // Arrange
+        let mut task = TaskBuilder::new(TaskTitle::new("カスタムフィールド").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act
+        task.set_custom_field("estimate=3h".parse().unwrap(), &clock())
+            .unwrap();
+        task.set_custom_field("client=ACME".parse().unwrap(), &clock())
+            .unwrap();
+        task.set_custom_field("Estimate=5h".parse().unwrap(), &clock())
+            .unwrap();
+        let removed = task.remove_custom_field("CLIENT", &clock());
+        let missing = task.remove_custom_field("client", &clock());
+
+        // Assert
+        assert!(removed.is_ok());
+        assert!(missing.is_err());
+        let fields: Vec<_> = task.custom_fields().iter().map(|f| f.to_string()).collect();
+        assert_eq!(fields, vec!["estimate=5h"]);
+        assert_eq!(task.custom_field("ESTIMATE").unwrap().value(), "5h");
+    }
+
+    #[test]
+    fn test_link_git_rejects_duplicate_link() {
+        // Arrange
+        let mut task = TaskBuilder::new(TaskTitle::new("git連携").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let link = |commit: &str| {
+            GitLink::new("/src/yaru", Some("main".to_string()), commit, Utc::now()).unwrap()
+        };
+
+        // Act
+        task.link_git(link("1111111"), &clock()).unwrap();
+        task.link_git(link("2222222"), &clock()).unwrap();
+        let duplicate = task.link_git(link("1111111"), &clock());
+
+        // Assert
+        assert_eq!(
+            duplicate.unwrap_err().to_string(),
+            "Task is already linked to commit 1111111"
+        );
+        assert_eq!(task.git_links().len(), 2);
+    }
+
+    #[test]
+    fn test_external_refs() {
+        // Arrange
+        let mut task = TaskBuilder::new(TaskTitle::new("外部連携").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let external_ref =
+            |id: &str| ExternalRef::new("github", id, None, Utc::now(), Status::Pending).unwrap();
+        task.add_external_ref(external_ref("a/b#1"), &clock())
+            .unwrap();
+        let updated_at = *task.updated_at();
+        let remote_updated_at = Utc::now();
+
+        // Act
+        let duplicate = task.add_external_ref(external_ref("a/b#1"), &clock());
+        task.record_external_sync("github", "a/b#1", remote_updated_at, Status::Completed)
+            .unwrap();
+        let missing =
+            task.record_external_sync("github", "a/b#2", remote_updated_at, Status::Completed);
+
+        // Assert
+        assert_eq!(
+            duplicate.unwrap_err().to_string(),
+            "Task is already linked to github a/b#1"
+        );
+        assert!(missing.is_err());
+        let synced = task.external_ref("github", "a/b#1").unwrap();
+        assert_eq!(synced.synced_status(), Status::Completed);
+        assert_eq!(synced.remote_updated_at(), &remote_updated_at);
+        assert_eq!(task.updated_at(), &updated_at);
+    }
+
+    #[test]
+    fn test_checklist_items() {
+        // Arrange
+        let mut task = TaskBuilder::new(TaskTitle::new("チェックリスト").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        for text in ["write tests", "review", "release"] {
+            task.add_checklist_item(ChecklistItem::new(text, false).unwrap(), &clock())
+                .unwrap();
+        }
+
+        // Act
+        let toggled = task.toggle_checklist_item(1, &clock()).unwrap().clone();
+        let removed = task.remove_checklist_item(2, &clock()).unwrap();
+        let out_of_range = task.toggle_checklist_item(3, &clock()).map(|_| ());
+        let zero = task.remove_checklist_item(0, &clock());
+
+        // Assert
+        assert!(toggled.is_done());
+        assert_eq!(removed.text(), "review");
+        assert_eq!(
+            out_of_range.unwrap_err().to_string(),
+            "Checklist item 3 does not exist (task has 2 items)"
+        );
+        assert!(zero.is_err());
+        let items: Vec<_> = task.checklist().iter().map(|i| i.to_string()).collect();
+        assert_eq!(items, vec!["[x] write tests", "[ ] release"]);
+    }
+
     #[test]
     fn test_add_tag() {
         // Arrange
         let title = TaskTitle::new("タグ追加テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let tag_id = TagId::new(1).unwrap();
 
         // Act
-        let result = task.add_tag(tag_id);
+        let result = task.add_tag(tag_id, &clock());
 
         // Assert
         assert!(result.is_ok());
@@ -623,17 +1537,14 @@ mod tests {
         let title = TaskTitle::new("重複タグテスト").unwrap();
         let description = TaskDescription::new("").unwrap();
         let tag_id = TagId::new(1).unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![tag_id],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .tags(vec![tag_id])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        let result = task.add_tag(tag_id);
+        let result = task.add_tag(tag_id, &clock());
 
         // Assert
         assert!(result.is_err());
@@ -646,17 +1557,14 @@ mod tests {
         let title = TaskTitle::new("タグ削除テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
         let tag_id = TagId::new(1).unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![tag_id],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .tags(vec![tag_id])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        let result = task.remove_tag(&tag_id);
+        let result = task.remove_tag(&tag_id, &clock());
 
         // Assert
         assert!(result.is_ok());
@@ -668,18 +1576,14 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("存在しないタグ削除テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let tag_id = TagId::new(1).unwrap();
 
         // Act
-        let result = task.remove_tag(&tag_id);
+        let result = task.remove_tag(&tag_id, &clock());
 
         // Assert
         assert!(result.is_err());
@@ -691,22 +1595,18 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("イベント発行テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        task.complete().unwrap();
+        task.complete(&clock()).unwrap();
 
         // Assert
-        assert_eq!(task.domain_events().len(), 1);
+        assert_eq!(task.domain_events().len(), 2);
         assert!(
-            task.domain_events()[0]
+            task.domain_events()[1]
                 .as_any()
                 .downcast_ref::<TaskCompleted>()
                 .is_some()
@@ -718,18 +1618,14 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("元のタイトル").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let new_title = TaskTitle::new("新しいタイトル").unwrap();
 
         // Act
-        task.change_title(new_title).unwrap();
+        task.change_title(new_title, &clock()).unwrap();
 
         // Assert
         assert_eq!(task.domain_events().len(), 1);
@@ -746,18 +1642,14 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("タグ追加イベントテスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let tag_id = TagId::new(1).unwrap();
 
         // Act
-        task.add_tag(tag_id).unwrap();
+        task.add_tag(tag_id, &clock()).unwrap();
 
         // Assert
         assert_eq!(task.domain_events().len(), 1);
@@ -775,17 +1667,14 @@ mod tests {
         let title = TaskTitle::new("タグ削除イベントテスト").unwrap();
         let description = TaskDescription::new("").unwrap();
         let tag_id = TagId::new(1).unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![tag_id],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .tags(vec![tag_id])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        task.remove_tag(&tag_id).unwrap();
+        task.remove_tag(&tag_id, &clock()).unwrap();
 
         // Assert
         assert_eq!(task.domain_events().len(), 1);
@@ -797,21 +1686,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_automatic_tag_keeps_updated_at() {
+        // Arrange
+        let tag_id = TagId::new(1).unwrap();
+        let mut task = TaskBuilder::new(TaskTitle::new("自動タグ").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let updated_at = *task.updated_at();
+
+        // Act & Assert: 付ける・外すときだけ変更とイベントがある
+        assert!(task.set_automatic_tag(tag_id, true));
+        assert!(!task.set_automatic_tag(tag_id, true));
+        assert_eq!(task.tags(), &vec![tag_id]);
+        assert!(task.set_automatic_tag(tag_id, false));
+        assert!(task.tags().is_empty());
+        assert_eq!(*task.updated_at(), updated_at);
+        assert_eq!(task.domain_events().len(), 2);
+        assert!(
+            task.domain_events()[1]
+                .as_any()
+                .downcast_ref::<TaskTagRemoved>()
+                .is_some()
+        );
+    }
+
     #[test]
     fn test_clear_events() {
         // Arrange
         let title = TaskTitle::new("イベントクリアテスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
-        task.complete().unwrap();
-        assert_eq!(task.domain_events().len(), 1);
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.complete(&clock()).unwrap();
+        assert_eq!(task.domain_events().len(), 2);
 
         // Act
         task.clear_events();
@@ -825,17 +1735,17 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("タスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        let result = task.change_status(Status::Completed);
+        let result = task.change_status(
+            Status::Completed,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        );
 
         // Assert
         assert!(result.is_ok());
@@ -843,27 +1753,70 @@ mod tests {
         assert!(task.completed_at().is_some());
     }
 
+    #[test]
+    fn test_change_status_records_history() {
+        // Arrange
+        let policy = StatusTransitionPolicy::default();
+        let mut task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        let completed_clock = clock().advanced(chrono::TimeDelta::hours(2));
+
+        // Act: 同じステータスへの変更は記録しない
+        task.change_status(Status::InProgress, &policy, &clock())
+            .unwrap();
+        task.change_status(Status::InProgress, &policy, &clock())
+            .unwrap();
+        task.change_status(Status::Completed, &policy, &completed_clock)
+            .unwrap();
+
+        // Assert
+        let history: Vec<(Status, Status)> = task
+            .status_history()
+            .iter()
+            .map(|change| (change.from(), change.to()))
+            .collect();
+        assert_eq!(
+            history,
+            vec![
+                (Status::Pending, Status::InProgress),
+                (Status::InProgress, Status::Completed),
+            ]
+        );
+        assert_eq!(task.initial_status(), Status::Pending);
+        assert_eq!(*task.status_history()[0].changed_at(), clock().now());
+        assert_eq!(
+            *task.status_history()[1].changed_at(),
+            completed_clock.now()
+        );
+        assert_eq!(*task.completed_at(), Some(completed_clock.now()));
+        assert_eq!(*task.updated_at(), completed_clock.now());
+    }
+
     #[test]
     fn test_change_status_to_completed_emits_event() {
         // Arrange
         let title = TaskTitle::new("タスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::InProgress,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .status(Status::InProgress)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        task.change_status(Status::Completed).unwrap();
+        task.change_status(
+            Status::Completed,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        )
+        .unwrap();
 
         // Assert
-        assert_eq!(task.domain_events().len(), 1);
+        assert_eq!(task.domain_events().len(), 2);
         assert!(
-            task.domain_events()[0]
+            task.domain_events()[1]
                 .as_any()
                 .downcast_ref::<TaskCompleted>()
                 .is_some()
@@ -875,19 +1828,19 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("タスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
-        task.complete().unwrap(); // 一度完了させる
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.complete(&clock()).unwrap(); // 一度完了させる
         assert!(task.completed_at().is_some());
 
         // Act
-        let result = task.change_status(Status::InProgress);
+        let result = task.reopen(
+            Status::InProgress,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        );
 
         // Assert
         assert!(result.is_ok());
@@ -896,46 +1849,226 @@ mod tests {
     }
 
     #[test]
-    fn test_change_status_to_non_completed_does_not_emit_event() {
+    fn test_change_status_from_completed_without_reopen_fails() {
         // Arrange
         let title = TaskTitle::new("タスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .status(Status::Completed)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act
+        let result = task.change_status(
+            Status::Pending,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        );
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(task.status(), &Status::Completed);
+        assert!(task.completed_at().is_some());
+    }
+
+    #[test]
+    fn test_reopen_rejects_forbidden_transition() {
+        // Arrange
+        let policy = StatusTransitionPolicy::default().with_rule(
+            Status::Completed,
             Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
+            TransitionRule::Forbidden,
         );
+        let title = TaskTitle::new("タスク").unwrap();
+        let description = TaskDescription::new("").unwrap();
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .status(Status::Completed)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act
-        task.change_status(Status::InProgress).unwrap();
+        let result = task.reopen(Status::Pending, &policy, &clock());
 
         // Assert
-        assert_eq!(task.domain_events().len(), 0);
+        assert!(result.is_err());
+        assert_eq!(task.status(), &Status::Completed);
     }
 
     #[test]
-    fn test_change_status_already_completed_does_not_change_completed_at() {
+    fn test_change_status_emits_status_changed_event() {
         // Arrange
         let title = TaskTitle::new("タスク").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let mut task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act
+        task.change_status(
+            Status::InProgress,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        )
+        .unwrap();
+        task.change_status(
+            Status::InProgress,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        )
+        .unwrap();
+
+        // Assert: 同じステータスへの変更ではイベントは発行されない
+        assert_eq!(task.domain_events().len(), 1);
+        let event = task.domain_events()[0]
+            .as_any()
+            .downcast_ref::<TaskStatusChanged>()
+            .unwrap();
+        assert_eq!(event.from, Status::Pending);
+        assert_eq!(event.to, Status::InProgress);
+    }
+
+    #[test]
+    fn test_change_status_to_non_completed_does_not_emit_completed_event() {
+        // Arrange
+        let title = TaskTitle::new("タスク").unwrap();
+        let description = TaskDescription::new("").unwrap();
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act
+        task.change_status(
+            Status::InProgress,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        )
+        .unwrap();
+
+        // Assert
+        assert!(
+            task.domain_events()
+                .iter()
+                .all(|event| event.as_any().downcast_ref::<TaskCompleted>().is_none())
         );
-        task.complete().unwrap();
+    }
+
+    #[test]
+    fn test_change_status_already_completed_does_not_change_completed_at() {
+        // Arrange
+        let title = TaskTitle::new("タスク").unwrap();
+        let description = TaskDescription::new("").unwrap();
+        let mut task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.complete(&clock()).unwrap();
         let first_completed_at = *task.completed_at();
 
         // Act
-        task.change_status(Status::Completed).unwrap();
+        task.change_status(
+            Status::Completed,
+            &StatusTransitionPolicy::default(),
+            &clock(),
+        )
+        .unwrap();
 
         // Assert
         assert_eq!(task.completed_at(), &first_completed_at);
     }
+
+    #[test]
+    fn test_spawn_next_occurrence() {
+        // Arrange
+        let due = NaiveDate::from_ymd_opt(2026, 10, 5).unwrap();
+        let mut task = TaskBuilder::new(TaskTitle::new("週次レビュー").unwrap())
+            .priority(Priority::High)
+            .tags(vec![TagId::new(1).unwrap()])
+            .due_date(Some(DueDate::new(due).unwrap()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.add_checklist_item(ChecklistItem::new("振り返り", true).unwrap(), &clock())
+            .unwrap();
+        task.change_recurrence(Some("weekly".parse().unwrap()), &clock())
+            .unwrap();
+
+        // Assert: 完了するまでは次の回はない
+        assert_eq!(task.next_occurrence_date(due).unwrap(), None);
+
+        // Act
+        task.complete(&clock()).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 20).unwrap();
+        let next_due = task.next_occurrence_date(today).unwrap().unwrap();
+        let next = task
+            .spawn_next_occurrence(DueDate::new(next_due).unwrap(), &clock())
+            .unwrap()
+            .into_aggregate(TaskId::new(2).unwrap());
+
+        // Assert: 過ぎた発生日は飛ばし、繰り返しルールは次の回に移る
+        assert_eq!(next_due, NaiveDate::from_ymd_opt(2026, 10, 26).unwrap());
+        assert_eq!(next.status(), &Status::Pending);
+        assert_eq!(next.priority(), &Priority::High);
+        assert_eq!(next.tags(), task.tags());
+        assert!(!next.checklist()[0].is_done());
+        assert_eq!(next.recurrence().unwrap().to_string(), "weekly");
+        assert!(task.recurrence().is_none());
+        assert_eq!(task.next_occurrence_date(today).unwrap(), None);
+    }
+
+    #[test]
+    fn test_follow_up_inherits_priority_tags_and_context() {
+        // Arrange
+        let mut task = TaskBuilder::new(TaskTitle::new("設計レビュー").unwrap())
+            .priority(Priority::High)
+            .tags(vec![TagId::new(1).unwrap(), TagId::new(2).unwrap()])
+            .due_date(Some(
+                DueDate::new(NaiveDate::from_ymd_opt(2026, 10, 5).unwrap()).unwrap(),
+            ))
+            .context(Some(TaskContext::new("@office").unwrap()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.complete(&clock()).unwrap();
+
+        // Act
+        let follow_up = task
+            .follow_up(TaskTitle::new("設計レビューの指摘を反映").unwrap())
+            .into_aggregate(TaskId::new(2).unwrap());
+
+        // Assert
+        assert_eq!(follow_up.title().value(), "設計レビューの指摘を反映");
+        assert_eq!(follow_up.status(), &Status::Pending);
+        assert_eq!(follow_up.priority(), &Priority::High);
+        assert_eq!(follow_up.tags(), task.tags());
+        assert_eq!(follow_up.context(), task.context());
+        assert!(follow_up.due_date().is_none());
+    }
+
+    #[test]
+    fn test_snooze_hides_task_until_date() {
+        // Arrange
+        let today = NaiveDate::from_ymd_opt(2026, 10, 20).unwrap();
+        let until = NaiveDate::from_ymd_opt(2026, 10, 23).unwrap();
+        let mut task = TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act
+        task.snooze(until, &FixedClock::on(today)).unwrap();
+
+        // Assert: 期限の当日になるとスヌーズ中ではなくなる
+        assert_eq!(task.snoozed_until(), Some(until));
+        assert!(task.is_snoozed(today));
+        assert!(task.is_snoozed(until.pred_opt().unwrap()));
+        assert!(!task.is_snoozed(until));
+        assert!(task.snooze(today, &FixedClock::on(today)).is_err());
+
+        task.unsnooze(&clock()).unwrap();
+        assert!(!task.is_snoozed(today));
+
+        task.complete(&clock()).unwrap();
+        assert!(task.snooze(until, &FixedClock::on(today)).is_err());
+    }
 }
@@ -0,0 +1,205 @@
+//! プロパティテスト用のジェネレーター
+//!
+//! proptestで値オブジェクト・TaskAggregate・Specificationの木を生成します。
+//! キーワードやタイトルは小さな語彙から組み立てるため、検索条件が一致するケースも十分に生成されます。
+
+use crate::domain::{
+    tag::value_objects::TagId,
+    task::{
+        aggregate::{TaskAggregate, TaskReconstructParams},
+        specification::{SearchField, TaskSpecDefinition},
+        value_objects::{
+            Assignee, CustomField, DueDate, Priority, Status, TaskContext, TaskDescription, TaskId,
+            TaskTitle,
+        },
+    },
+};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use proptest::{collection::vec, option, prelude::*, sample::select};
+use strum::IntoEnumIterator;
+
+/// タイトル・説明・キーワードに使う語彙（大文字小文字の違いを含む）
+pub const WORDS: &[&str] = &["資料", "作成", "Report", "review", "買い物", "MEETING"];
+
+/// コンテキストに使う名前
+const CONTEXTS: &[&str] = &["home", "office", "errand"];
+
+/// 担当者に使う名前
+const ASSIGNEES: &[&str] = &["alice", "Bob"];
+
+/// カスタムフィールドのキー
+const FIELD_KEYS: &[&str] = &["owner", "sprint"];
+
+/// 日付の基準日（前後60日の範囲で生成する）
+fn base_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()
+}
+
+/// 基準日の前後60日の日付
+pub fn date() -> impl Strategy<Value = NaiveDate> {
+    (-60i64..=60).prop_map(|offset| base_date() + Duration::days(offset))
+}
+
+/// 基準日の前後60日の日時（時単位）
+fn date_time() -> impl Strategy<Value = DateTime<Utc>> {
+    (-60i64 * 24..=60 * 24).prop_map(|hours| {
+        Utc.from_utc_datetime(&base_date().and_hms_opt(0, 0, 0).unwrap()) + Duration::hours(hours)
+    })
+}
+
+/// ステータス
+pub fn status() -> impl Strategy<Value = Status> {
+    select(Status::iter().collect::<Vec<_>>())
+}
+
+/// 優先度
+pub fn priority() -> impl Strategy<Value = Priority> {
+    select(Priority::iter().collect::<Vec<_>>())
+}
+
+/// 期限日
+pub fn due_date() -> impl Strategy<Value = DueDate> {
+    date().prop_map(|date| DueDate::new(date).unwrap())
+}
+
+/// 語彙を空白で連結した文章
+fn sentence(max_words: usize) -> impl Strategy<Value = String> {
+    vec(select(WORDS), 0..=max_words).prop_map(|words| words.join(" "))
+}
+
+/// 語彙から組み立てた有効なタイトル
+pub fn task_title() -> impl Strategy<Value = TaskTitle> {
+    vec(select(WORDS), 1..=4).prop_map(|words| TaskTitle::new(words.join(" ")).unwrap())
+}
+
+/// 検索キーワード（大文字小文字の違いや前後の空白を含む）
+pub fn keyword() -> impl Strategy<Value = String> {
+    (select(WORDS), any::<bool>(), " {0,2}", " {0,2}").prop_map(
+        |(word, upper, leading, trailing)| {
+            let word = if upper {
+                word.to_uppercase()
+            } else {
+                word.to_lowercase()
+            };
+            format!("{}{}{}", leading, word, trailing)
+        },
+    )
+}
+
+/// 任意の項目を組み合わせたTaskAggregate
+///
+/// IDは1〜5、タグは1〜3の範囲で生成します。
+pub fn task_aggregate() -> impl Strategy<Value = TaskAggregate> {
+    (
+        (1..=5i32, task_title(), sentence(3), status(), priority()),
+        (
+            vec(1..=3i32, 0..=2),
+            option::of(due_date()),
+            option::of(select(CONTEXTS)),
+            option::of((select(FIELD_KEYS), select(WORDS))),
+            option::of(select(ASSIGNEES)),
+        ),
+        (date_time(), 0i64..=48, option::of(date())),
+    )
+        .prop_map(
+            |(
+                (id, title, description, status, priority),
+                (tag_ids, due_date, context, custom_field, assignee),
+                (created_at, updated_hours, snoozed_until),
+            )| {
+                let mut tag_ids = tag_ids;
+                tag_ids.sort();
+                tag_ids.dedup();
+                let tags = tag_ids
+                    .into_iter()
+                    .map(|id| TagId::new(id).unwrap())
+                    .collect();
+                TaskAggregate::reconstruct(TaskReconstructParams {
+                    id: TaskId::new(id).unwrap(),
+                    title,
+                    description: TaskDescription::new(description).unwrap(),
+                    status,
+                    priority,
+                    tags,
+                    created_at,
+                    updated_at: created_at + Duration::hours(updated_hours),
+                    due_date,
+                    completed_at: (status == Status::Completed).then_some(created_at),
+                    custom_fields: custom_field
+                        .map(|(key, value)| CustomField::new(key, value).unwrap())
+                        .into_iter()
+                        .collect(),
+                    checklist: Vec::new(),
+                    git_links: Vec::new(),
+                    external_refs: Vec::new(),
+                    recurrence: None,
+                    snoozed_until,
+                    estimate: None,
+                    context: context.map(|name| TaskContext::new(name).unwrap()),
+                    assignee: assignee.map(|name| Assignee::new(name).unwrap()),
+                    status_history: Vec::new(),
+                })
+            },
+        )
+}
+
+/// 検索対象フィールド
+fn search_field() -> impl Strategy<Value = SearchField> {
+    select(vec![
+        SearchField::Title,
+        SearchField::Description,
+        SearchField::All,
+    ])
+}
+
+/// 単独の条件（Specificationの葉）
+pub fn leaf_definition() -> impl Strategy<Value = TaskSpecDefinition> {
+    prop_oneof![
+        (1..=5i32).prop_map(|id| TaskSpecDefinition::Id { id }),
+        status().prop_map(|status| TaskSpecDefinition::Status { status }),
+        priority().prop_map(|priority| TaskSpecDefinition::Priority { priority }),
+        (1..=3i32).prop_map(|tag_id| TaskSpecDefinition::Tag { tag_id }),
+        (select(FIELD_KEYS), select(WORDS)).prop_map(|(key, value)| {
+            TaskSpecDefinition::CustomField {
+                key: key.to_string(),
+                value: value.to_string(),
+            }
+        }),
+        date().prop_map(|date| TaskSpecDefinition::DueBefore { date }),
+        date().prop_map(|date| TaskSpecDefinition::DueAfter { date }),
+        (date(), date()).prop_map(|(from, to)| TaskSpecDefinition::DueBetween { from, to }),
+        Just(TaskSpecDefinition::HasDueDate),
+        date().prop_map(|today| TaskSpecDefinition::Overdue { today }),
+        date().prop_map(|today| TaskSpecDefinition::Snoozed { today }),
+        select(CONTEXTS).prop_map(|context| TaskSpecDefinition::Context {
+            context: context.to_string(),
+        }),
+        Just(TaskSpecDefinition::HasContext),
+        select(ASSIGNEES).prop_map(|assignee| TaskSpecDefinition::Assignee {
+            assignee: assignee.to_string(),
+        }),
+        date_time().prop_map(|at| TaskSpecDefinition::CreatedAfter { at }),
+        date_time().prop_map(|at| TaskSpecDefinition::UpdatedSince { at }),
+        (vec(keyword(), 0..=2), search_field())
+            .prop_map(|(keywords, field)| TaskSpecDefinition::Keyword { keywords, field }),
+    ]
+}
+
+/// AND・OR・NOTを組み合わせたSpecificationの木（深さ4まで）
+pub fn spec_definition() -> impl Strategy<Value = TaskSpecDefinition> {
+    leaf_definition().prop_recursive(4, 16, 2, |inner| {
+        prop_oneof![
+            (inner.clone(), inner.clone()).prop_map(|(left, right)| TaskSpecDefinition::And {
+                left: Box::new(left),
+                right: Box::new(right),
+            }),
+            (inner.clone(), inner.clone()).prop_map(|(left, right)| TaskSpecDefinition::Or {
+                left: Box::new(left),
+                right: Box::new(right),
+            }),
+            inner.prop_map(|inner| TaskSpecDefinition::Not {
+                inner: Box::new(inner),
+            }),
+        ]
+    })
+}
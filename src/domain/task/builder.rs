@@ -0,0 +1,235 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    tag::value_objects::TagId,
+    task::{
+        aggregate::NewTask,
+        value_objects::{
+            Assignee, CustomField, DueDate, Estimate, Priority, Recurrence, Status, TaskContext,
+            TaskDescription, TaskTitle,
+        },
+    },
+};
+
+/// TaskBuilder - 保存前のタスク（NewTask）を組み立てるビルダー
+///
+/// 必須のタイトルは`new`の引数で受け取るため、タイトルのないタスクはコンパイル時に作成できません。
+/// 省略した項目は既定値（説明は空、ステータスはPending、優先度はMedium、タグ・期限日・
+/// カスタムフィールド・繰り返しルール・見積もり時間はなし、作成日時は現在時刻）になります。
+#[derive(Debug, Clone)]
+pub struct TaskBuilder {
+    title: TaskTitle,
+    description: TaskDescription,
+    status: Status,
+    priority: Priority,
+    tags: Vec<TagId>,
+    due_date: Option<DueDate>,
+    custom_fields: Vec<CustomField>,
+    recurrence: Option<Recurrence>,
+    estimate: Option<Estimate>,
+    context: Option<TaskContext>,
+    assignee: Option<Assignee>,
+    created_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+impl TaskBuilder {
+    /// タイトルを指定してビルダーを作成
+    pub fn new(title: TaskTitle) -> Self {
+        Self {
+            title,
+            description: TaskDescription::default(),
+            status: Status::Pending,
+            priority: Priority::Medium,
+            tags: Vec::new(),
+            due_date: None,
+            custom_fields: Vec::new(),
+            recurrence: None,
+            estimate: None,
+            context: None,
+            assignee: None,
+            created_at: None,
+            completed_at: None,
+        }
+    }
+
+    /// 説明を設定
+    pub fn description(mut self, description: TaskDescription) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// ステータスを設定（Completedの場合は完了日時を作成時刻にする）
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// 優先度を設定
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// タグを設定
+    pub fn tags(mut self, tags: Vec<TagId>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// 期限日を設定
+    pub fn due_date(mut self, due_date: Option<DueDate>) -> Self {
+        self.due_date = due_date;
+        self
+    }
+
+    /// カスタムフィールドを設定（同じキーのフィールドは後のもので置き換える）
+    pub fn custom_fields(mut self, custom_fields: Vec<CustomField>) -> Self {
+        self.custom_fields = custom_fields;
+        self
+    }
+
+    /// 繰り返しルールを設定
+    pub fn recurrence(mut self, recurrence: Option<Recurrence>) -> Self {
+        self.recurrence = recurrence;
+        self
+    }
+
+    /// 見積もり時間を設定
+    pub fn estimate(mut self, estimate: Option<Estimate>) -> Self {
+        self.estimate = estimate;
+        self
+    }
+
+    /// コンテキストを設定
+    pub fn context(mut self, context: Option<TaskContext>) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// 担当者を設定
+    pub fn assignee(mut self, assignee: Option<Assignee>) -> Self {
+        self.assignee = assignee;
+        self
+    }
+
+    /// 作成日時を設定（Noneの場合は現在時刻）
+    pub fn created_at(mut self, created_at: Option<DateTime<Utc>>) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// 完了日時を設定（ステータスがCompletedの場合のみ使用し、Noneの場合は作成時刻）
+    pub fn completed_at(mut self, completed_at: Option<DateTime<Utc>>) -> Self {
+        self.completed_at = completed_at;
+        self
+    }
+
+    /// NewTaskを作成
+    pub fn build(self) -> NewTask {
+        let mut task = NewTask::new(
+            self.title,
+            self.description,
+            self.status,
+            self.priority,
+            self.tags,
+            self.due_date,
+        );
+        for field in self.custom_fields {
+            task.set_custom_field(field);
+        }
+        task.set_recurrence(self.recurrence);
+        task.set_estimate(self.estimate);
+        task.set_context(self.context);
+        task.set_assignee(self.assignee);
+        if let Some(created_at) = self.created_at {
+            task.set_created_at(created_at);
+        }
+        if let Some(completed_at) = self.completed_at {
+            task.set_completed_at(completed_at);
+        }
+        task
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::value_objects::TaskId;
+
+    #[test]
+    fn test_builder_defaults() {
+        // Arrange & Act
+        let task = TaskBuilder::new(TaskTitle::new("買い物").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Assert
+        assert_eq!(task.title().value(), "買い物");
+        assert_eq!(task.description().value(), "");
+        assert_eq!(task.status(), &Status::Pending);
+        assert_eq!(task.priority(), &Priority::Medium);
+        assert!(task.tags().is_empty());
+        assert_eq!(task.due_date(), &None);
+        assert!(task.custom_fields().is_empty());
+        assert!(task.recurrence().is_none());
+        assert_eq!(task.completed_at(), &None);
+    }
+
+    #[test]
+    fn test_builder_sets_optional_fields() {
+        // Arrange
+        let due_date = DueDate::new(chrono::NaiveDate::from_ymd_opt(2026, 11, 1).unwrap()).unwrap();
+
+        // Act
+        let task = TaskBuilder::new(TaskTitle::new("請求書").unwrap())
+            .description(TaskDescription::new("月末締め").unwrap())
+            .status(Status::Completed)
+            .priority(Priority::High)
+            .tags(vec![TagId::new(3).unwrap()])
+            .due_date(Some(due_date))
+            .custom_fields(vec![
+                CustomField::new("client", "ACME").unwrap(),
+                CustomField::new("Client", "Globex").unwrap(),
+            ])
+            .recurrence(Some("monthly".parse().unwrap()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Assert
+        assert_eq!(task.description().value(), "月末締め");
+        assert_eq!(task.status(), &Status::Completed);
+        assert!(task.completed_at().is_some());
+        assert_eq!(task.priority(), &Priority::High);
+        assert_eq!(task.tags(), &vec![TagId::new(3).unwrap()]);
+        assert_eq!(task.due_date(), &Some(due_date));
+        let fields: Vec<String> = task.custom_fields().iter().map(|f| f.to_string()).collect();
+        assert_eq!(fields, vec!["client=Globex"]);
+        assert_eq!(task.recurrence().unwrap().to_string(), "monthly");
+    }
+
+    #[test]
+    fn test_builder_keeps_given_timestamps() {
+        // Arrange
+        let created_at = "2026-01-05T09:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let completed_at = "2026-01-06T18:30:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        // Act
+        let completed = TaskBuilder::new(TaskTitle::new("請求書").unwrap())
+            .status(Status::Completed)
+            .created_at(Some(created_at))
+            .completed_at(Some(completed_at))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let pending = TaskBuilder::new(TaskTitle::new("見積書").unwrap())
+            .completed_at(Some(completed_at))
+            .build()
+            .into_aggregate(TaskId::new(2).unwrap());
+
+        // Assert
+        assert_eq!(completed.created_at(), &created_at);
+        assert_eq!(completed.updated_at(), &created_at);
+        assert_eq!(completed.completed_at(), &Some(completed_at));
+        assert_eq!(pending.completed_at(), &None);
+    }
+}
@@ -196,7 +196,7 @@
 
 use crate::domain::{
     tag::value_objects::TagId,
-    task::value_objects::{TaskId, TaskTitle},
+    task::value_objects::{Status, TaskId, TaskTitle},
 };
 use chrono::{DateTime, Utc};
 use std::fmt::Debug;
@@ -280,6 +280,40 @@ impl DomainEvent for TaskCompleted {
     }
 }
 
+/// TaskStatusChanged - タスクステータス変更イベント
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskStatusChanged {
+    pub task_id: TaskId,
+    pub from: Status,
+    pub to: Status,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskStatusChanged {
+    pub fn new(task_id: TaskId, from: Status, to: Status) -> Self {
+        Self {
+            task_id,
+            from,
+            to,
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for TaskStatusChanged {
+    fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+
+    fn event_name(&self) -> &str {
+        "TaskStatusChanged"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 /// TaskTitleChanged - タスクタイトル変更イベント
 #[derive(Debug, Clone, PartialEq)]
 pub struct TaskTitleChanged {
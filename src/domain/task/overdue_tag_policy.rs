@@ -0,0 +1,167 @@
+use crate::domain::{
+    services::BusinessCalendar,
+    tag::value_objects::TagId,
+    task::{aggregate::TaskAggregate, value_objects::Status},
+};
+use chrono::NaiveDate;
+
+/// 期限切れのタグの付け外し
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverdueTagChange {
+    /// 期限切れになったためタグを付けた
+    Applied,
+    /// 期限日の変更・完了などで期限切れでなくなったためタグを外した
+    Removed,
+}
+
+/// OverdueTagPolicy - 期限切れのタスクに自動でタグを付けるポリシー
+///
+/// 期限切れの未完了のタスクには指定したタグを付け、期限日を延ばした・完了したなどで
+/// 期限切れでなくなったタスクからはタグを外します。
+/// 営業日のカレンダーを設定した場合は、期限日の翌営業日から期限切れとして扱います。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverdueTagPolicy {
+    tag_id: TagId,
+    calendar: Option<BusinessCalendar>,
+}
+
+impl OverdueTagPolicy {
+    /// 付け外しするタグを指定して作成
+    pub fn new(tag_id: TagId) -> Self {
+        Self {
+            tag_id,
+            calendar: None,
+        }
+    }
+
+    /// 営業日のカレンダーを設定する（Noneの場合は休日を考慮しない）
+    pub fn with_business_calendar(mut self, calendar: Option<BusinessCalendar>) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// タスクが`today`の時点で期限切れか
+    pub fn is_overdue(&self, task: &TaskAggregate, today: NaiveDate) -> bool {
+        is_overdue_on_calendar(task, today, self.calendar.as_ref())
+    }
+
+    /// タスクのタグを期限切れかどうかに合わせる
+    ///
+    /// タグを付けた・外した場合は、その変更を返します（タスクにはドメインイベントが記録されます）。
+    pub fn apply(&self, task: &mut TaskAggregate, today: NaiveDate) -> Option<OverdueTagChange> {
+        let overdue = self.is_overdue(task, today);
+        task.set_automatic_tag(self.tag_id, overdue)
+            .then_some(if overdue {
+                OverdueTagChange::Applied
+            } else {
+                OverdueTagChange::Removed
+            })
+    }
+}
+
+/// タスクが`today`の時点で期限切れか（`calendar`がNoneの場合は休日を考慮しない）
+///
+/// タグを作成する前に、付ける対象のタスクがあるかを判定するために使用します。
+pub fn is_overdue_on_calendar(
+    task: &TaskAggregate,
+    today: NaiveDate,
+    calendar: Option<&BusinessCalendar>,
+) -> bool {
+    match (calendar, task.due_date()) {
+        (Some(calendar), Some(due_date)) => {
+            *task.status() != Status::Completed && calendar.is_overdue(due_date.value(), today)
+        }
+        _ => task.is_overdue(today),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::SystemClock;
+    use crate::domain::{
+        clock::FixedClock,
+        task::{
+            builder::TaskBuilder,
+            status_transition::StatusTransitionPolicy,
+            value_objects::{DueDate, TaskId, TaskTitle},
+        },
+    };
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn task_due(due: NaiveDate) -> TaskAggregate {
+        TaskBuilder::new(TaskTitle::new("期限のあるタスク").unwrap())
+            .due_date(Some(DueDate::new(due).unwrap()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap())
+    }
+
+    #[test]
+    fn test_apply_tags_overdue_task_and_removes_after_reschedule() {
+        // Arrange
+        let tag_id = TagId::new(7).unwrap();
+        let policy = OverdueTagPolicy::new(tag_id);
+        let mut task = task_due(date(1, 9));
+
+        // Act & Assert
+        assert_eq!(policy.apply(&mut task, date(1, 9)), None);
+        assert_eq!(
+            policy.apply(&mut task, date(1, 10)),
+            Some(OverdueTagChange::Applied)
+        );
+        assert_eq!(task.tags(), &vec![tag_id]);
+        assert_eq!(policy.apply(&mut task, date(1, 11)), None);
+
+        task.change_due_date(
+            Some(DueDate::new(date(1, 20)).unwrap()),
+            &SystemClock::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            policy.apply(&mut task, date(1, 11)),
+            Some(OverdueTagChange::Removed)
+        );
+        assert!(task.tags().is_empty());
+    }
+
+    #[test]
+    fn test_apply_removes_tag_from_completed_task() {
+        // Arrange
+        let tag_id = TagId::new(7).unwrap();
+        let policy = OverdueTagPolicy::new(tag_id);
+        let mut task = task_due(date(1, 9));
+        policy.apply(&mut task, date(1, 10));
+
+        // Act
+        task.change_status(
+            Status::Completed,
+            &StatusTransitionPolicy::default(),
+            &FixedClock::at(2026, 1, 10, 9, 0),
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(
+            policy.apply(&mut task, date(1, 10)),
+            Some(OverdueTagChange::Removed)
+        );
+    }
+
+    #[test]
+    fn test_apply_with_business_calendar_waits_for_next_business_day() {
+        // Arrange: 2026-01-09（金）が期限
+        let policy = OverdueTagPolicy::new(TagId::new(7).unwrap())
+            .with_business_calendar(Some(BusinessCalendar::default()));
+        let mut task = task_due(date(1, 9));
+
+        // Act & Assert
+        assert_eq!(policy.apply(&mut task, date(1, 11)), None);
+        assert_eq!(
+            policy.apply(&mut task, date(1, 12)),
+            Some(OverdueTagChange::Applied)
+        );
+    }
+}
@@ -1,7 +1,17 @@
 use crate::domain::task::{
-    aggregate::TaskAggregate, specification::TaskSpecification, value_objects::TaskId,
+    aggregate::{NewTask, TaskAggregate},
+    specification::TaskSpecification,
+    value_objects::TaskId,
 };
 use anyhow::Result;
+use chrono::NaiveDate;
+use futures_util::{
+    StreamExt, TryStreamExt,
+    stream::{self, BoxStream},
+};
+
+/// タスクを1件ずつ返す非同期ストリーム
+pub type TaskStream<'a> = BoxStream<'a, Result<TaskAggregate>>;
 
 /// TaskRepository trait - タスクの永続化を抽象化
 ///
@@ -27,6 +37,20 @@ pub trait TaskRepository: Send + Sync {
     /// * `Err` - エラーが発生した場合
     async fn find_all(&self) -> Result<Vec<TaskAggregate>>;
 
+    /// 全タスクをストリームとして取得
+    ///
+    /// 大量のタスクを扱う処理（統計など）で、全件をメモリに載せずに順次処理するために使用します。
+    /// デフォルト実装は`find_all`の結果を順に返すため、メモリ使用量は削減されません。
+    ///
+    /// # Returns
+    /// * `TaskStream` - タスクを1件ずつ返すストリーム（取得失敗時は`Err`を返す）
+    fn find_all_stream(&self) -> TaskStream<'_> {
+        stream::once(self.find_all())
+            .map_ok(|tasks| stream::iter(tasks.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+
     /// Specificationに基づいてタスクを検索
     ///
     /// # Arguments
@@ -40,9 +64,41 @@ pub trait TaskRepository: Send + Sync {
         spec: Box<dyn TaskSpecification>,
     ) -> Result<Vec<TaskAggregate>>;
 
+    /// Specificationを満たすタスクの件数を取得
+    ///
+    /// ステータスバーの表示など、件数だけが必要な場合に使用します。
+    /// デフォルト実装は`find_by_specification`の結果を数えるため、タスクの読み込みは削減されません。
+    ///
+    /// # Arguments
+    /// * `spec` - 検索条件を表すSpecification
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - 条件を満たすタスクの件数
+    /// * `Err` - エラーが発生した場合
+    async fn count_by_specification(&self, spec: Box<dyn TaskSpecification>) -> Result<u64> {
+        Ok(self.find_by_specification(spec).await?.len() as u64)
+    }
+
+    /// 期限日が指定した期間内（両端を含む）のタスクを取得
+    ///
+    /// 期限日の昇順、同じ期限日ではIDの昇順に並びます。期限日のないタスクは含まれません。
+    ///
+    /// # Arguments
+    /// * `from` - 期間の開始日
+    /// * `to` - 期間の終了日
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskAggregate>)` - 期間内に期限日があるタスクのリスト
+    /// * `Err` - エラーが発生した場合
+    async fn find_by_due_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<TaskAggregate>>;
+
     /// 新しいタスクを保存
     ///
-    /// IDが0の場合、新しいIDを割り当てます。
+    /// IDはリポジトリ（データベース）が割り当てます。割り当てたIDは再利用しません。
     ///
     /// # Arguments
     /// * `task` - 保存するタスク
@@ -50,7 +106,26 @@ pub trait TaskRepository: Send + Sync {
     /// # Returns
     /// * `Ok(TaskAggregate)` - 保存されたタスク（IDが割り当てられている）
     /// * `Err` - エラーが発生した場合
-    async fn save(&self, task: TaskAggregate) -> Result<TaskAggregate>;
+    async fn save(&self, task: NewTask) -> Result<TaskAggregate>;
+
+    /// 複数のタスクを一括保存
+    ///
+    /// インポートなどの大量登録向けです。実装によってはまとめて挿入し、往復回数を削減します。
+    /// デフォルト実装は`save`を順に呼び出します。
+    ///
+    /// # Arguments
+    /// * `tasks` - 保存するタスク
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TaskAggregate>)` - 保存されたタスク（入力と同じ順序）
+    /// * `Err` - エラーが発生した場合
+    async fn save_all(&self, tasks: Vec<NewTask>) -> Result<Vec<TaskAggregate>> {
+        let mut saved = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            saved.push(self.save(task).await?);
+        }
+        Ok(saved)
+    }
 
     /// 既存のタスクを更新
     ///
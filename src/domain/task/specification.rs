@@ -4,9 +4,12 @@ use crate::domain::{
     tag::value_objects::TagId,
     task::{
         aggregate::TaskAggregate,
-        value_objects::{Priority, Status, TaskId},
+        value_objects::{Assignee, CustomField, Priority, Status, TaskContext, TaskId},
     },
 };
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 
 /// TaskSpecification trait - タスクの検索条件を抽象化
 ///
@@ -32,7 +35,7 @@ use crate::domain::{
 ///     .collect();
 ///
 /// // OR条件の組み合わせ - 「期限切れ」または「高優先度」のタスク
-/// let spec = Box::new(TaskOverdue::new())
+/// let spec = Box::new(TaskOverdue::new(clock.today()))
 ///     .or(Box::new(TaskByPriority::new(Priority::High)));
 /// let urgent_tasks: Vec<TaskAggregate> = all_tasks
 ///     .into_iter()
@@ -42,7 +45,7 @@ use crate::domain::{
 /// // 複雑な条件 - (「保留中」かつ「高優先度」) または「期限切れ」
 /// let spec = Box::new(TaskByStatus::new(Status::Pending))
 ///     .and(Box::new(TaskByPriority::new(Priority::High)))
-///     .or(Box::new(TaskOverdue::new()));
+///     .or(Box::new(TaskOverdue::new(clock.today())));
 /// ```
 ///
 /// # 応用例
@@ -71,6 +74,27 @@ pub trait TaskSpecification: Send + Sync {
     /// * `false` - 条件を満たさない
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool;
 
+    /// 保存・受け渡し用の定義に変換
+    ///
+    /// 定義から`TaskSpecDefinition::into_specification`で同じ条件のSpecificationを復元できます。
+    fn definition(&self) -> TaskSpecDefinition;
+
+    /// 条件をクエリ用の条件に変換（SQLクエリ用）
+    ///
+    /// 変換した条件は、このSpecificationと同じタスクに一致します。
+    /// 変換できない条件（キーワードなど）を含む場合は`None`を返します。
+    fn criteria(&self) -> Option<TaskCriteria> {
+        None
+    }
+
+    /// 事前の絞り込みに使える条件（SQLクエリ用）
+    ///
+    /// このSpecificationを満たすタスクは、必ずこの条件も満たします。
+    /// リポジトリはこの条件で絞り込んだ後、`is_satisfied_by`で残りの条件を判定します。
+    fn prefilter(&self) -> Option<TaskCriteria> {
+        self.criteria()
+    }
+
     /// 他のSpecificationとAND結合
     fn and(self: Box<Self>, other: Box<dyn TaskSpecification>) -> Box<dyn TaskSpecification>
     where
@@ -94,6 +118,175 @@ pub trait TaskSpecification: Send + Sync {
     }
 }
 
+/// クエリに変換できる検索条件
+///
+/// Specificationのうち、リポジトリがSQLなどに変換してデータベース側で絞り込める条件を表します。
+/// 期限日の条件は、期限日が設定されていないタスクには一致しません。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskCriteria {
+    Id(TaskId),
+    Status(Status),
+    Priority(Priority),
+    Tag(TagId),
+    /// 期限日が指定日より前
+    DueBefore(NaiveDate),
+    /// 期限日が指定日より後
+    DueAfter(NaiveDate),
+    /// 期限日が期間内（両端を含む）
+    DueBetween(NaiveDate, NaiveDate),
+    /// 期限日が設定されている
+    HasDueDate,
+    /// スヌーズの期限が指定日より後
+    SnoozedAfter(NaiveDate),
+    /// コンテキストが一致する
+    Context(TaskContext),
+    /// コンテキストが設定されている
+    HasContext,
+    /// 担当者が一致する
+    Assignee(Assignee),
+    /// 作成日時が指定日時以降
+    CreatedAfter(DateTime<Utc>),
+    /// 更新日時が指定日時以降
+    UpdatedSince(DateTime<Utc>),
+    And(Box<TaskCriteria>, Box<TaskCriteria>),
+    Or(Box<TaskCriteria>, Box<TaskCriteria>),
+    Not(Box<TaskCriteria>),
+}
+
+/// Specificationの定義（シリアライズ可能な表現）
+///
+/// Specificationの木構造をJSONで保存・受け渡しするための表現です。
+/// IDやカスタムフィールドはプリミティブな値で保持し、復元時に検証します。
+///
+/// # 例
+/// ```rust,ignore
+/// // {"type":"not","inner":{"type":"status","status":"Completed"}}
+/// let json = NotSpecification::new(Box::new(TaskByStatus::new(Status::Completed)))
+///     .definition()
+///     .to_json()?;
+/// let spec = TaskSpecDefinition::from_json(&json)?.into_specification()?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TaskSpecDefinition {
+    Id {
+        id: i32,
+    },
+    Status {
+        status: Status,
+    },
+    Priority {
+        priority: Priority,
+    },
+    Tag {
+        tag_id: i32,
+    },
+    CustomField {
+        key: String,
+        value: String,
+    },
+    Overdue {
+        today: NaiveDate,
+    },
+    DueBefore {
+        date: NaiveDate,
+    },
+    DueAfter {
+        date: NaiveDate,
+    },
+    DueBetween {
+        from: NaiveDate,
+        to: NaiveDate,
+    },
+    HasDueDate,
+    Snoozed {
+        today: NaiveDate,
+    },
+    Context {
+        context: String,
+    },
+    HasContext,
+    Assignee {
+        assignee: String,
+    },
+    CreatedAfter {
+        at: DateTime<Utc>,
+    },
+    UpdatedSince {
+        at: DateTime<Utc>,
+    },
+    Keyword {
+        keywords: Vec<String>,
+        #[serde(default = "default_search_field")]
+        field: SearchField,
+    },
+    And {
+        left: Box<TaskSpecDefinition>,
+        right: Box<TaskSpecDefinition>,
+    },
+    Or {
+        left: Box<TaskSpecDefinition>,
+        right: Box<TaskSpecDefinition>,
+    },
+    Not {
+        inner: Box<TaskSpecDefinition>,
+    },
+}
+
+fn default_search_field() -> SearchField {
+    SearchField::All
+}
+
+impl TaskSpecDefinition {
+    /// 定義からSpecificationを作成
+    ///
+    /// IDやカスタムフィールドが不正な場合はエラーを返します。
+    pub fn into_specification(self) -> Result<Box<dyn TaskSpecification>> {
+        let spec: Box<dyn TaskSpecification> = match self {
+            Self::Id { id } => Box::new(TaskById::new(TaskId::new(id)?)),
+            Self::Status { status } => Box::new(TaskByStatus::new(status)),
+            Self::Priority { priority } => Box::new(TaskByPriority::new(priority)),
+            Self::Tag { tag_id } => Box::new(TaskByTag::new(TagId::new(tag_id)?)),
+            Self::CustomField { key, value } => {
+                Box::new(TaskByCustomField::new(CustomField::new(key, value)?))
+            }
+            Self::Overdue { today } => Box::new(TaskOverdue::new(today)),
+            Self::DueBefore { date } => Box::new(TaskDueBefore::new(date)),
+            Self::DueAfter { date } => Box::new(TaskDueAfter::new(date)),
+            Self::DueBetween { from, to } => Box::new(TaskDueBetween::new(from, to)),
+            Self::HasDueDate => Box::new(TaskHasDueDate),
+            Self::Snoozed { today } => Box::new(TaskSnoozed::new(today)),
+            Self::Context { context } => Box::new(TaskInContext::new(TaskContext::new(context)?)),
+            Self::HasContext => Box::new(TaskHasContext),
+            Self::Assignee { assignee } => Box::new(TaskByAssignee::new(Assignee::new(assignee)?)),
+            Self::CreatedAfter { at } => Box::new(TaskCreatedAfter::new(at)),
+            Self::UpdatedSince { at } => Box::new(TaskUpdatedSince::new(at)),
+            Self::Keyword { keywords, field } => Box::new(TaskByKeyword::new(keywords, field)),
+            Self::And { left, right } => Box::new(AndSpecification::new(
+                left.into_specification()?,
+                right.into_specification()?,
+            )),
+            Self::Or { left, right } => Box::new(OrSpecification::new(
+                left.into_specification()?,
+                right.into_specification()?,
+            )),
+            Self::Not { inner } => Box::new(NotSpecification::new(inner.into_specification()?)),
+        };
+        Ok(spec)
+    }
+
+    /// JSON文字列に変換
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// JSON文字列から変換
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Invalid specification definition: {}", e))
+    }
+}
+
 /// ステータスでフィルタリング
 ///
 /// # 使用シーン
@@ -122,6 +315,16 @@ impl TaskSpecification for TaskByStatus {
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
         task.status() == &self.status
     }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Status {
+            status: self.status,
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Status(self.status))
+    }
 }
 
 /// 優先度でフィルタリング
@@ -152,6 +355,16 @@ impl TaskSpecification for TaskByPriority {
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
         task.priority() == &self.priority
     }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Priority {
+            priority: self.priority,
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Priority(self.priority))
+    }
 }
 
 /// タグでフィルタリング
@@ -183,6 +396,51 @@ impl TaskSpecification for TaskByTag {
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
         task.tags().contains(&self.tag_id)
     }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Tag {
+            tag_id: self.tag_id.value(),
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Tag(self.tag_id))
+    }
+}
+
+/// カスタムフィールドの値でフィルタリング
+///
+/// 値は大文字小文字を区別せずに完全一致で比較します。
+///
+/// # 例
+/// ```rust,ignore
+/// // client=ACMEのタスクのみを取得
+/// let spec = TaskByCustomField::new(CustomField::new("client", "ACME").unwrap());
+/// let acme_tasks = tasks.into_iter().filter(|t| spec.is_satisfied_by(t)).collect();
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskByCustomField {
+    field: CustomField,
+}
+
+impl TaskByCustomField {
+    pub fn new(field: CustomField) -> Self {
+        Self { field }
+    }
+}
+
+impl TaskSpecification for TaskByCustomField {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.custom_field(self.field.key())
+            .is_some_and(|field| field.value_matches(self.field.value()))
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::CustomField {
+            key: self.field.key().to_string(),
+            value: self.field.value().to_string(),
+        }
+    }
 }
 
 /// 期限切れタスクでフィルタリング
@@ -194,33 +452,364 @@ impl TaskSpecification for TaskByTag {
 ///
 /// # 例
 /// ```rust,ignore
-/// // 期限切れのタスクのみを取得
-/// let spec = TaskOverdue::new();
+/// // 期限切れのタスクのみを取得（今日の日付は設定したタイムゾーンのClockから取得）
+/// let spec = TaskOverdue::new(clock.today());
 /// let overdue_tasks = tasks.into_iter().filter(|t| spec.is_satisfied_by(t)).collect();
 /// ```
 #[derive(Debug, Clone)]
-pub struct TaskOverdue;
+pub struct TaskOverdue {
+    today: NaiveDate,
+}
 
 impl TaskOverdue {
-    pub fn new() -> Self {
-        Self
+    /// 基準日（利用者のタイムゾーンでの今日）を指定して作成
+    pub fn new(today: NaiveDate) -> Self {
+        Self { today }
     }
 }
 
-impl Default for TaskOverdue {
-    fn default() -> Self {
-        Self::new()
+impl TaskSpecification for TaskOverdue {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.is_overdue(self.today)
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Overdue { today: self.today }
     }
 }
 
-impl TaskSpecification for TaskOverdue {
+/// 期限日が指定日より前のタスクでフィルタリング
+///
+/// 期限日が設定されていないタスクは条件を満たしません。
+///
+/// # 使用シーン
+/// - 「年内に期限が来るタスク」のような期限日の範囲での検索
+///
+/// # 例
+/// ```rust,ignore
+/// // 2026年より前に期限が来るタスク
+/// let spec = TaskDueBefore::new(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskDueBefore {
+    date: NaiveDate,
+}
+
+impl TaskDueBefore {
+    pub fn new(date: NaiveDate) -> Self {
+        Self { date }
+    }
+}
+
+impl TaskSpecification for TaskDueBefore {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.due_date()
+            .as_ref()
+            .is_some_and(|due| due.is_before(self.date))
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::DueBefore { date: self.date }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::DueBefore(self.date))
+    }
+}
+
+/// 期限日が指定日より後のタスクでフィルタリング
+///
+/// 期限日が設定されていないタスクは条件を満たしません。
+///
+/// # 例
+/// ```rust,ignore
+/// // 来月以降に期限が来るタスク
+/// let spec = TaskDueAfter::new(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskDueAfter {
+    date: NaiveDate,
+}
+
+impl TaskDueAfter {
+    pub fn new(date: NaiveDate) -> Self {
+        Self { date }
+    }
+}
+
+impl TaskSpecification for TaskDueAfter {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.due_date()
+            .as_ref()
+            .is_some_and(|due| due.is_after(self.date))
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::DueAfter { date: self.date }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::DueAfter(self.date))
+    }
+}
+
+/// 期限日が指定した期間内（両端を含む）のタスクでフィルタリング
+///
+/// 期限日が設定されていないタスクは条件を満たしません。
+///
+/// # 例
+/// ```rust,ignore
+/// // 10月中に期限が来るタスク
+/// let spec = TaskDueBetween::new(
+///     NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2026, 10, 31).unwrap(),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskDueBetween {
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+impl TaskDueBetween {
+    pub fn new(from: NaiveDate, to: NaiveDate) -> Self {
+        Self { from, to }
+    }
+}
+
+impl TaskSpecification for TaskDueBetween {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.due_date()
+            .as_ref()
+            .is_some_and(|due| (self.from..=self.to).contains(&due.value()))
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::DueBetween {
+            from: self.from,
+            to: self.to,
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::DueBetween(self.from, self.to))
+    }
+}
+
+/// 期限日が設定されているタスクでフィルタリング
+///
+/// # 例
+/// ```rust,ignore
+/// // 期限日のないタスク
+/// let spec = NotSpecification::new(Box::new(TaskHasDueDate));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskHasDueDate;
+
+impl TaskSpecification for TaskHasDueDate {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.due_date().is_some()
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::HasDueDate
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::HasDueDate)
+    }
+}
+
+/// 指定した日の時点でスヌーズ中のタスクでフィルタリング
+///
+/// スヌーズの期限の当日になったタスクは条件を満たしません（自動的にスヌーズが解除されます）。
+///
+/// # 例
+/// ```rust,ignore
+/// // スヌーズ中のタスクを除く
+/// let spec = NotSpecification::new(Box::new(TaskSnoozed::new(today)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskSnoozed {
+    today: NaiveDate,
+}
+
+impl TaskSnoozed {
+    pub fn new(today: NaiveDate) -> Self {
+        Self { today }
+    }
+}
+
+impl TaskSpecification for TaskSnoozed {
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
-        task.is_overdue()
+        task.is_snoozed(self.today)
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Snoozed { today: self.today }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::SnoozedAfter(self.today))
+    }
+}
+
+/// コンテキストでフィルタリング
+///
+/// # 例
+/// ```rust,ignore
+/// // @homeのタスクと、コンテキストが設定されていないタスク
+/// let spec = OrSpecification::new(
+///     Box::new(TaskInContext::new(TaskContext::new("@home")?)),
+///     Box::new(NotSpecification::new(Box::new(TaskHasContext))),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskInContext {
+    context: TaskContext,
+}
+
+impl TaskInContext {
+    pub fn new(context: TaskContext) -> Self {
+        Self { context }
+    }
+}
+
+impl TaskSpecification for TaskInContext {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.context() == Some(&self.context)
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Context {
+            context: self.context.to_string(),
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Context(self.context.clone()))
+    }
+}
+
+/// コンテキストが設定されているタスクでフィルタリング
+#[derive(Debug, Clone)]
+pub struct TaskHasContext;
+
+impl TaskSpecification for TaskHasContext {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.context().is_some()
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::HasContext
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::HasContext)
+    }
+}
+
+/// 担当者でフィルタリング
+#[derive(Debug, Clone)]
+pub struct TaskByAssignee {
+    assignee: Assignee,
+}
+
+impl TaskByAssignee {
+    pub fn new(assignee: Assignee) -> Self {
+        Self { assignee }
+    }
+}
+
+impl TaskSpecification for TaskByAssignee {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        task.assignee() == Some(&self.assignee)
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Assignee {
+            assignee: self.assignee.to_string(),
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Assignee(self.assignee.clone()))
+    }
+}
+
+/// 指定日時以降に作成されたタスクでフィルタリング
+///
+/// # 使用シーン
+/// - 「この1週間に追加したタスク」の確認
+///
+/// # 例
+/// ```rust,ignore
+/// // 7日以内に作成されたタスク
+/// let spec = TaskCreatedAfter::new(Utc::now() - Duration::days(7));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskCreatedAfter {
+    at: DateTime<Utc>,
+}
+
+impl TaskCreatedAfter {
+    pub fn new(at: DateTime<Utc>) -> Self {
+        Self { at }
+    }
+}
+
+impl TaskSpecification for TaskCreatedAfter {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        *task.created_at() >= self.at
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::CreatedAfter { at: self.at }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::CreatedAfter(self.at))
+    }
+}
+
+/// 指定日時以降に更新されたタスクでフィルタリング
+///
+/// # 使用シーン
+/// - 前回の確認以降に変更があったタスクの確認
+///
+/// # 例
+/// ```rust,ignore
+/// // 24時間以内に更新されたタスク
+/// let spec = TaskUpdatedSince::new(Utc::now() - Duration::hours(24));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TaskUpdatedSince {
+    at: DateTime<Utc>,
+}
+
+impl TaskUpdatedSince {
+    pub fn new(at: DateTime<Utc>) -> Self {
+        Self { at }
+    }
+}
+
+impl TaskSpecification for TaskUpdatedSince {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        *task.updated_at() >= self.at
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::UpdatedSince { at: self.at }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::UpdatedSince(self.at))
     }
 }
 
 /// 検索対象フィールド
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SearchField {
     /// タイトルのみ
     Title,
@@ -297,6 +886,13 @@ impl TaskSpecification for TaskByKeyword {
             .iter()
             .all(|keyword| searchable_text.contains(keyword))
     }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Keyword {
+            keywords: self.keywords.clone(),
+            field: self.field,
+        }
+    }
 }
 
 /// IDでフィルタリング
@@ -328,6 +924,16 @@ impl TaskSpecification for TaskById {
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
         task.id() == &self.id
     }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Id {
+            id: self.id.value(),
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Id(self.id))
+    }
 }
 
 /// AND条件
@@ -349,10 +955,40 @@ pub struct AndSpecification {
     right: Box<dyn TaskSpecification>,
 }
 
+impl AndSpecification {
+    /// 動的に組み立てたSpecification同士をAND結合
+    pub fn new(left: Box<dyn TaskSpecification>, right: Box<dyn TaskSpecification>) -> Self {
+        Self { left, right }
+    }
+}
+
 impl TaskSpecification for AndSpecification {
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
         self.left.is_satisfied_by(task) && self.right.is_satisfied_by(task)
     }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::And {
+            left: Box::new(self.left.definition()),
+            right: Box::new(self.right.definition()),
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::And(
+            Box::new(self.left.criteria()?),
+            Box::new(self.right.criteria()?),
+        ))
+    }
+
+    /// 片方しか変換できない場合も、変換できる方の条件で事前に絞り込める
+    fn prefilter(&self) -> Option<TaskCriteria> {
+        match (self.left.prefilter(), self.right.prefilter()) {
+            (Some(left), Some(right)) => Some(TaskCriteria::And(Box::new(left), Box::new(right))),
+            (Some(criteria), None) | (None, Some(criteria)) => Some(criteria),
+            (None, None) => None,
+        }
+    }
 }
 
 /// OR条件
@@ -367,38 +1003,112 @@ impl TaskSpecification for AndSpecification {
 /// ```rust,ignore
 /// // 「高優先度」または「期限切れ」のタスク
 /// let spec = Box::new(TaskByPriority::new(Priority::High))
-///     .or(Box::new(TaskOverdue::new()));
+///     .or(Box::new(TaskOverdue::new(clock.today())));
 /// ```
 pub struct OrSpecification {
     left: Box<dyn TaskSpecification>,
     right: Box<dyn TaskSpecification>,
 }
 
+impl OrSpecification {
+    /// 動的に組み立てたSpecification同士をOR結合
+    pub fn new(left: Box<dyn TaskSpecification>, right: Box<dyn TaskSpecification>) -> Self {
+        Self { left, right }
+    }
+}
+
 impl TaskSpecification for OrSpecification {
     fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
         self.left.is_satisfied_by(task) || self.right.is_satisfied_by(task)
     }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Or {
+            left: Box::new(self.left.definition()),
+            right: Box::new(self.right.definition()),
+        }
+    }
+
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Or(
+            Box::new(self.left.criteria()?),
+            Box::new(self.right.criteria()?),
+        ))
+    }
+
+    fn prefilter(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Or(
+            Box::new(self.left.prefilter()?),
+            Box::new(self.right.prefilter()?),
+        ))
+    }
+}
+
+/// NOT条件
+///
+/// Specificationの条件を反転し、条件を満たさないタスクを抽出します。
+///
+/// # 使用シーン
+/// - 検索クエリの除外キーワード（`-完了`）
+/// - 「完了以外」のように、特定の条件に当てはまるタスクを除外
+///
+/// # 例
+/// ```rust,ignore
+/// // 「完了」以外のタスク
+/// let spec = NotSpecification::new(Box::new(TaskByStatus::new(Status::Completed)));
+/// ```
+pub struct NotSpecification {
+    inner: Box<dyn TaskSpecification>,
+}
+
+impl NotSpecification {
+    /// 動的に組み立てたSpecificationを反転
+    pub fn new(inner: Box<dyn TaskSpecification>) -> Self {
+        Self { inner }
+    }
+}
+
+impl TaskSpecification for NotSpecification {
+    fn is_satisfied_by(&self, task: &TaskAggregate) -> bool {
+        !self.inner.is_satisfied_by(task)
+    }
+
+    fn definition(&self) -> TaskSpecDefinition {
+        TaskSpecDefinition::Not {
+            inner: Box::new(self.inner.definition()),
+        }
+    }
+
+    /// 事前の絞り込み条件を反転すると取りこぼしが出るため、完全に変換できる場合のみ変換する
+    fn criteria(&self) -> Option<TaskCriteria> {
+        Some(TaskCriteria::Not(Box::new(self.inner.criteria()?)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::clock::{FixedClock, SystemClock};
+    use crate::domain::task::arbitrary::{keyword, spec_definition, task_aggregate};
+    use crate::domain::task::builder::TaskBuilder;
     use crate::domain::task::value_objects::{TaskDescription, TaskTitle};
-    use chrono::{Duration, Utc};
+    use chrono::Duration;
+    use proptest::{collection::vec, prelude::*, sample::Index};
+
+    /// 定義からSpecificationを作成（生成した定義は常に有効）
+    fn build(definition: &TaskSpecDefinition) -> Box<dyn TaskSpecification> {
+        definition.clone().into_specification().unwrap()
+    }
 
     #[test]
     fn test_task_by_status() {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByStatus::new(Status::Pending);
 
         // Act & Assert
@@ -413,14 +1123,11 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByPriority::new(Priority::High);
 
         // Act & Assert
@@ -430,20 +1137,40 @@ mod tests {
         assert!(!spec_low.is_satisfied_by(&task));
     }
 
+    #[test]
+    fn test_task_by_custom_field() {
+        // Arrange
+        let mut task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.set_custom_field(
+            CustomField::new("client", "ACME").unwrap(),
+            &SystemClock::default(),
+        )
+        .unwrap();
+
+        // Act & Assert
+        let spec = TaskByCustomField::new(CustomField::new("Client", "acme").unwrap());
+        assert!(spec.is_satisfied_by(&task));
+
+        let spec_other = TaskByCustomField::new(CustomField::new("client", "Globex").unwrap());
+        assert!(!spec_other.is_satisfied_by(&task));
+
+        let spec_missing = TaskByCustomField::new(CustomField::new("estimate", "3h").unwrap());
+        assert!(!spec_missing.is_satisfied_by(&task));
+    }
+
     #[test]
     fn test_task_by_tag() {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
         let tag_id = TagId::new(1).unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![tag_id],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .tags(vec![tag_id])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByTag::new(tag_id);
 
         // Act & Assert
@@ -459,20 +1186,177 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let past_date = Utc::now().naive_utc().date() - Duration::days(1);
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let past_date = today - Duration::days(1);
         let due_date = Some(super::super::value_objects::DueDate::new(past_date).unwrap());
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            due_date,
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .due_date(due_date)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let spec = TaskOverdue::new(today);
+
+        // Act & Assert
+        assert!(spec.is_satisfied_by(&task));
+    }
+
+    #[test]
+    fn test_task_overdue_with_frozen_clock() {
+        // Arrange: UTC 2026-01-10 20:30（日本時間では翌日5:30）に、期限日が1月10日のタスク
+        use crate::domain::clock::{Clock, FixedClock, Timezone};
+        let task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .due_date(Some(
+                super::super::value_objects::DueDate::new(
+                    NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+                )
+                .unwrap(),
+            ))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let utc = FixedClock::at(2026, 1, 10, 20, 30);
+        let tokyo = utc.with_timezone(Timezone::Fixed(
+            chrono::FixedOffset::east_opt(9 * 3600).unwrap(),
+        ));
+
+        // Act & Assert
+        assert!(!TaskOverdue::new(utc.today()).is_satisfied_by(&task));
+        assert!(TaskOverdue::new(tokyo.today()).is_satisfied_by(&task));
+    }
+
+    #[test]
+    fn test_task_due_before_and_after() {
+        // Arrange
+        let date = |d: u32| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .due_date(Some(
+                super::super::value_objects::DueDate::new(date(10)).unwrap(),
+            ))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let no_due_date = TaskBuilder::new(TaskTitle::new("期限なし").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act & Assert: 境界の日付は含まない
+        assert!(TaskDueBefore::new(date(11)).is_satisfied_by(&task));
+        assert!(!TaskDueBefore::new(date(10)).is_satisfied_by(&task));
+        assert!(TaskDueAfter::new(date(9)).is_satisfied_by(&task));
+        assert!(!TaskDueAfter::new(date(10)).is_satisfied_by(&task));
+        assert!(!TaskDueBefore::new(date(31)).is_satisfied_by(&no_due_date));
+        assert!(!TaskDueAfter::new(date(1)).is_satisfied_by(&no_due_date));
+    }
+
+    #[test]
+    fn test_task_due_between() {
+        // Arrange
+        let date = |d: u32| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .due_date(Some(
+                super::super::value_objects::DueDate::new(date(10)).unwrap(),
+            ))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let no_due_date = TaskBuilder::new(TaskTitle::new("期限なし").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act & Assert: 両端の日付を含む
+        assert!(TaskDueBetween::new(date(10), date(20)).is_satisfied_by(&task));
+        assert!(TaskDueBetween::new(date(1), date(10)).is_satisfied_by(&task));
+        assert!(!TaskDueBetween::new(date(11), date(20)).is_satisfied_by(&task));
+        assert!(!TaskDueBetween::new(date(1), date(31)).is_satisfied_by(&no_due_date));
+        assert!(TaskHasDueDate.is_satisfied_by(&task));
+        assert!(!TaskHasDueDate.is_satisfied_by(&no_due_date));
+    }
+
+    #[test]
+    fn test_task_snoozed() {
+        // Arrange
+        let date = |d: u32| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let mut task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        task.snooze(date(10), &FixedClock::on(date(1))).unwrap();
+
+        // Act & Assert: スヌーズの期限の当日には条件を満たさない
+        assert!(TaskSnoozed::new(date(9)).is_satisfied_by(&task));
+        assert!(!TaskSnoozed::new(date(10)).is_satisfied_by(&task));
+        assert_eq!(
+            TaskSnoozed::new(date(9)).criteria(),
+            Some(TaskCriteria::SnoozedAfter(date(9)))
         );
-        let spec = TaskOverdue::new();
+    }
+
+    #[test]
+    fn test_task_by_assignee() {
+        // Arrange
+        let alice = Assignee::new("alice").unwrap();
+        let task = TaskBuilder::new(TaskTitle::new("レビュー").unwrap())
+            .assignee(Some(alice.clone()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let unassigned = TaskBuilder::new(TaskTitle::new("未割り当て").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(2).unwrap());
+        let spec = TaskByAssignee::new(alice.clone());
 
         // Act & Assert
         assert!(spec.is_satisfied_by(&task));
+        assert!(!spec.is_satisfied_by(&unassigned));
+        assert!(!TaskByAssignee::new(Assignee::new("bob").unwrap()).is_satisfied_by(&task));
+        assert_eq!(spec.criteria(), Some(TaskCriteria::Assignee(alice)));
+    }
+
+    #[test]
+    fn test_task_in_context() {
+        // Arrange
+        let home = TaskContext::new("@home").unwrap();
+        let task = TaskBuilder::new(TaskTitle::new("掃除").unwrap())
+            .context(Some(home.clone()))
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let no_context = TaskBuilder::new(TaskTitle::new("どこでも").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(2).unwrap());
+        let visible_at_home = OrSpecification::new(
+            Box::new(TaskInContext::new(home.clone())),
+            Box::new(NotSpecification::new(Box::new(TaskHasContext))),
+        );
+        let visible_at_office = OrSpecification::new(
+            Box::new(TaskInContext::new(TaskContext::new("@office").unwrap())),
+            Box::new(NotSpecification::new(Box::new(TaskHasContext))),
+        );
+
+        // Act & Assert
+        assert!(TaskInContext::new(home.clone()).is_satisfied_by(&task));
+        assert!(!TaskInContext::new(home.clone()).is_satisfied_by(&no_context));
+        assert!(visible_at_home.is_satisfied_by(&task));
+        assert!(visible_at_home.is_satisfied_by(&no_context));
+        assert!(!visible_at_office.is_satisfied_by(&task));
+        assert!(visible_at_office.is_satisfied_by(&no_context));
+        assert_eq!(
+            TaskInContext::new(home.clone()).criteria(),
+            Some(TaskCriteria::Context(home))
+        );
+    }
+
+    #[test]
+    fn test_task_created_after_and_updated_since() {
+        // Arrange
+        let task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let created_at = *task.created_at();
+
+        // Act & Assert: 指定日時ちょうども含む
+        assert!(TaskCreatedAfter::new(created_at).is_satisfied_by(&task));
+        assert!(TaskCreatedAfter::new(created_at - Duration::days(7)).is_satisfied_by(&task));
+        assert!(!TaskCreatedAfter::new(created_at + Duration::seconds(1)).is_satisfied_by(&task));
+        assert!(TaskUpdatedSince::new(*task.updated_at()).is_satisfied_by(&task));
+        assert!(
+            !TaskUpdatedSince::new(*task.updated_at() + Duration::hours(1)).is_satisfied_by(&task)
+        );
     }
 
     #[test]
@@ -480,15 +1364,11 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
-        let spec = TaskById::new(TaskId::new(0).unwrap());
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let spec = TaskById::new(TaskId::new(1).unwrap());
 
         // Act & Assert
         assert!(spec.is_satisfied_by(&task));
@@ -502,14 +1382,11 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         let spec_status = Box::new(TaskByStatus::new(Status::Pending));
         let spec_priority: Box<dyn TaskSpecification> =
@@ -525,14 +1402,11 @@ mod tests {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         let spec_status = Box::new(TaskByStatus::new(Status::Completed));
         let spec_priority: Box<dyn TaskSpecification> =
@@ -543,20 +1417,33 @@ mod tests {
         assert!(or_spec.is_satisfied_by(&task));
     }
 
+    #[test]
+    fn test_not_specification() {
+        // Arrange
+        let task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .priority(Priority::High)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+        let not_completed = NotSpecification::new(Box::new(TaskByStatus::new(Status::Completed)));
+        let not_pending = NotSpecification::new(Box::new(TaskByStatus::new(Status::Pending)));
+
+        // Act & Assert
+        assert!(not_completed.is_satisfied_by(&task));
+        assert!(!not_pending.is_satisfied_by(&task));
+    }
+
     #[test]
     fn test_complex_specification() {
         // Arrange
         let title = TaskTitle::new("テスト").unwrap();
         let description = TaskDescription::new("").unwrap();
         let tag_id = TagId::new(1).unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::High,
-            vec![tag_id],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .priority(Priority::High)
+            .tags(vec![tag_id])
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // (Status == Pending AND Priority == High) OR Tag == 1
         let spec_status: Box<dyn TaskSpecification> = Box::new(TaskByStatus::new(Status::Pending));
@@ -577,6 +1464,114 @@ mod tests {
         assert!(complex_spec.is_satisfied_by(&task));
     }
 
+    #[test]
+    fn test_criteria_translates_combinators() {
+        // Arrange
+        let date = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let spec = Box::new(TaskByStatus::new(Status::Pending)).and(Box::new(
+            NotSpecification::new(Box::new(TaskDueBefore::new(date))),
+        ));
+
+        // Act
+        let criteria = spec.criteria();
+
+        // Assert
+        assert_eq!(
+            criteria,
+            Some(TaskCriteria::And(
+                Box::new(TaskCriteria::Status(Status::Pending)),
+                Box::new(TaskCriteria::Not(Box::new(TaskCriteria::DueBefore(date)))),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_prefilter_keeps_translatable_side_of_and() {
+        // Arrange
+        let keyword = || -> Box<dyn TaskSpecification> {
+            Box::new(TaskByKeyword::new(
+                vec!["買い物".to_string()],
+                SearchField::All,
+            ))
+        };
+        let pending = || Box::new(TaskByStatus::new(Status::Pending));
+        let and_spec = pending().and(keyword());
+        let or_spec = pending().or(keyword());
+        let not_spec = NotSpecification::new(pending().and(keyword()));
+
+        // Act & Assert: ANDは変換できる方だけで絞り込めるが、完全には変換できない
+        assert_eq!(and_spec.criteria(), None);
+        assert_eq!(
+            and_spec.prefilter(),
+            Some(TaskCriteria::Status(Status::Pending))
+        );
+        // OR・NOTは片方でも変換できなければ絞り込めない
+        assert_eq!(or_spec.prefilter(), None);
+        assert_eq!(not_spec.prefilter(), None);
+    }
+
+    #[test]
+    fn test_definition_json_round_trip() {
+        // Arrange: (Pending AND NOT 期限日 < 2026-02-01) OR (タグ3 AND キーワード)
+        let date = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let pending_not_due = Box::new(TaskByStatus::new(Status::Pending)).and(Box::new(
+            NotSpecification::new(Box::new(TaskDueBefore::new(date))),
+        ));
+        let tagged_keyword = Box::new(TaskByTag::new(TagId::new(3).unwrap())).and(Box::new(
+            TaskByKeyword::new(vec!["Report".to_string()], SearchField::Title),
+        ));
+        let spec = OrSpecification::new(pending_not_due, tagged_keyword);
+        let definition = spec.definition();
+
+        // Act
+        let json = definition.to_json().unwrap();
+        let restored = TaskSpecDefinition::from_json(&json).unwrap();
+
+        // Assert
+        assert_eq!(restored, definition);
+        assert_eq!(
+            restored.into_specification().unwrap().definition(),
+            definition
+        );
+    }
+
+    #[test]
+    fn test_definition_from_json() {
+        // Arrange
+        let json = r#"{"type":"not","inner":{"type":"status","status":"Completed"}}"#;
+        let task = TaskBuilder::new(TaskTitle::new("テスト").unwrap())
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
+
+        // Act
+        let spec = TaskSpecDefinition::from_json(json)
+            .unwrap()
+            .into_specification()
+            .unwrap();
+
+        // Assert
+        assert!(spec.is_satisfied_by(&task));
+        assert_eq!(
+            spec.criteria(),
+            Some(TaskCriteria::Not(Box::new(TaskCriteria::Status(
+                Status::Completed
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_definition_rejects_invalid_values() {
+        // Act & Assert
+        assert!(TaskSpecDefinition::from_json(r#"{"type":"unknown"}"#).is_err());
+        let invalid_id = TaskSpecDefinition::from_json(r#"{"type":"id","id":0}"#).unwrap();
+        assert!(invalid_id.into_specification().is_err());
+        let invalid_field = TaskSpecDefinition::CustomField {
+            key: "1st".to_string(),
+            value: "x".to_string(),
+        };
+        assert!(invalid_field.into_specification().is_err());
+    }
+
     // TaskByKeyword のテストケース
 
     #[test]
@@ -584,14 +1579,10 @@ mod tests {
         // Arrange: タイトルに「買い物」を含むタスク
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(vec!["買い物".to_string()], SearchField::All);
 
         // Act & Assert: タイトルに含まれるのでマッチするはず
@@ -603,14 +1594,10 @@ mod tests {
         // Arrange: 大文字小文字を無視
         let title = TaskTitle::new("Bug Report").unwrap();
         let description = TaskDescription::new("").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(vec!["bug".to_string()], SearchField::All);
 
         // Act & Assert: 大文字小文字を無視してマッチするはず
@@ -622,14 +1609,10 @@ mod tests {
         // Arrange: 説明に「レポート」を含むタスク
         let title = TaskTitle::new("週次作業").unwrap();
         let description = TaskDescription::new("週次レポートを作成する").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(vec!["レポート".to_string()], SearchField::All);
 
         // Act & Assert: 説明に含まれるのでマッチするはず
@@ -641,14 +1624,10 @@ mod tests {
         // Arrange: タイトルのみ検索対象
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act & Assert: タイトルに「買い物」があるのでマッチ
         let spec_title = TaskByKeyword::new(vec!["買い物".to_string()], SearchField::Title);
@@ -664,14 +1643,10 @@ mod tests {
         // Arrange: 説明のみ検索対象
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act & Assert: 説明に「牛乳」があるのでマッチ
         let spec_milk = TaskByKeyword::new(vec!["牛乳".to_string()], SearchField::Description);
@@ -688,14 +1663,10 @@ mod tests {
         // Arrange: タイトルと説明の両方が検索対象
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
 
         // Act & Assert: タイトルに「買い物」があるのでマッチ
         let spec_shopping = TaskByKeyword::new(vec!["買い物".to_string()], SearchField::All);
@@ -711,14 +1682,10 @@ mod tests {
         // Arrange: 複数キーワード（AND条件）
         let title = TaskTitle::new("レポート作成").unwrap();
         let description = TaskDescription::new("月次レポートを作成する").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(
             vec!["レポート".to_string(), "作成".to_string()],
             SearchField::All,
@@ -733,14 +1700,10 @@ mod tests {
         // Arrange: 複数キーワードのうち一部のみ含む
         let title = TaskTitle::new("レポート作成").unwrap();
         let description = TaskDescription::new("月次レポートを作成する").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(
             vec!["レポート".to_string(), "提出".to_string()],
             SearchField::All,
@@ -755,14 +1718,10 @@ mod tests {
         // Arrange: 空のキーワードリスト
         let title = TaskTitle::new("タスク").unwrap();
         let description = TaskDescription::new("説明").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(vec![], SearchField::All);
 
         // Act & Assert: 空のキーワードリストは全タスクにマッチ
@@ -774,14 +1733,10 @@ mod tests {
         // Arrange: マッチしないキーワード
         let title = TaskTitle::new("買い物リスト").unwrap();
         let description = TaskDescription::new("牛乳を買う").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(vec!["会議".to_string()], SearchField::All);
 
         // Act & Assert: 「会議」は含まれないのでマッチしない
@@ -793,14 +1748,10 @@ mod tests {
         // Arrange: 日本語キーワード
         let title = TaskTitle::new("プロジェクト会議").unwrap();
         let description = TaskDescription::new("次回のプロジェクト会議の議題を確認").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(vec!["プロジェクト".to_string()], SearchField::All);
 
         // Act & Assert: 日本語キーワードが正しく動作する
@@ -812,17 +1763,108 @@ mod tests {
         // Arrange: 空白文字のみのキーワードは無視される
         let title = TaskTitle::new("タスク").unwrap();
         let description = TaskDescription::new("説明").unwrap();
-        let task = TaskAggregate::new(
-            title,
-            description,
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(title)
+            .description(description)
+            .build()
+            .into_aggregate(TaskId::new(1).unwrap());
         let spec = TaskByKeyword::new(vec!["  ".to_string(), "".to_string()], SearchField::All);
 
         // Act & Assert: 空白のみのキーワードは除外され、空のキーワードリストとして扱われる
         assert!(spec.is_satisfied_by(&task));
     }
+
+    proptest! {
+        #[test]
+        fn test_and_or_not_follow_boolean_algebra(
+            task in task_aggregate(),
+            left in spec_definition(),
+            right in spec_definition(),
+        ) {
+            // Arrange
+            let a = build(&left).is_satisfied_by(&task);
+            let b = build(&right).is_satisfied_by(&task);
+            let and = AndSpecification::new(build(&left), build(&right));
+            let or = OrSpecification::new(build(&left), build(&right));
+            let not = NotSpecification::new(build(&left));
+
+            // Act & Assert: 論理演算の結果と一致する
+            prop_assert_eq!(and.is_satisfied_by(&task), a && b);
+            prop_assert_eq!(or.is_satisfied_by(&task), a || b);
+            prop_assert_eq!(not.is_satisfied_by(&task), !a);
+
+            // 交換法則
+            let and_swapped = AndSpecification::new(build(&right), build(&left));
+            let or_swapped = OrSpecification::new(build(&right), build(&left));
+            prop_assert_eq!(and_swapped.is_satisfied_by(&task), and.is_satisfied_by(&task));
+            prop_assert_eq!(or_swapped.is_satisfied_by(&task), or.is_satisfied_by(&task));
+
+            // ド・モルガンの法則
+            let not_and = NotSpecification::new(Box::new(and));
+            let or_of_nots = OrSpecification::new(
+                Box::new(NotSpecification::new(build(&left))),
+                Box::new(NotSpecification::new(build(&right))),
+            );
+            prop_assert_eq!(not_and.is_satisfied_by(&task), or_of_nots.is_satisfied_by(&task));
+
+            // 二重否定
+            let double_not = NotSpecification::new(Box::new(not));
+            prop_assert_eq!(double_not.is_satisfied_by(&task), a);
+        }
+
+        #[test]
+        fn test_definition_round_trip_preserves_matching(
+            task in task_aggregate(),
+            definition in spec_definition(),
+        ) {
+            // Arrange
+            let spec = build(&definition);
+
+            // Act: Specification → 定義 → JSON → 定義 → Specification
+            let restored_definition = TaskSpecDefinition::from_json(&spec.definition().to_json().unwrap()).unwrap();
+            let restored = build(&restored_definition);
+
+            // Assert: 正規化後の定義は変化せず、同じタスクに一致する
+            prop_assert_eq!(&restored.definition(), &spec.definition());
+            prop_assert_eq!(restored.is_satisfied_by(&task), spec.is_satisfied_by(&task));
+        }
+
+        #[test]
+        fn test_task_by_keyword_normalizes_keywords(
+            task in task_aggregate(),
+            keywords in vec(keyword(), 0..=3),
+        ) {
+            // Arrange
+            let upper: Vec<String> = keywords.iter().map(|k| k.to_uppercase()).collect();
+            let lower: Vec<String> = keywords.iter().map(|k| k.trim().to_lowercase()).collect();
+
+            // Act
+            let spec = TaskByKeyword::new(keywords, SearchField::All);
+
+            // Assert: 正規化後のキーワードは前後の空白がない小文字で、大文字小文字は結果に影響しない
+            let TaskSpecDefinition::Keyword { keywords: normalized, .. } = spec.definition() else {
+                unreachable!();
+            };
+            prop_assert!(normalized.iter().all(|k| !k.is_empty() && k.trim() == k && k.to_lowercase() == *k));
+            for variant in [upper, lower] {
+                let other = TaskByKeyword::new(variant, SearchField::All);
+                prop_assert_eq!(other.is_satisfied_by(&task), spec.is_satisfied_by(&task));
+            }
+        }
+
+        #[test]
+        fn test_task_by_keyword_matches_any_word_of_title(
+            task in task_aggregate(),
+            index in any::<Index>(),
+        ) {
+            // Arrange: タイトルに含まれる単語を大文字にしてキーワードにする
+            let words: Vec<&str> = task.title().value().split_whitespace().collect();
+            let word = index.get(&words).to_uppercase();
+
+            // Act
+            let spec = TaskByKeyword::new(vec![word], SearchField::Title);
+
+            // Assert
+            prop_assert!(spec.is_satisfied_by(&task));
+        }
+    }
 }
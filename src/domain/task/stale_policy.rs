@@ -0,0 +1,128 @@
+use crate::domain::task::{aggregate::TaskAggregate, value_objects::Status};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// StalePolicy - 放置されているタスクを判定するポリシー
+///
+/// ステータスごとに、更新されないまま経過してよい日数（しきい値）を持ちます。
+/// しきい値以上の日数が経過した未完了のタスクを「放置されている」とみなします。
+/// しきい値が設定されていないステータスと、完了済みのタスクは判定の対象外です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StalePolicy {
+    thresholds: HashMap<Status, u32>,
+}
+
+impl Default for StalePolicy {
+    /// 保留中は30日、進行中は14日
+    fn default() -> Self {
+        Self::disabled()
+            .with_threshold(Status::Pending, 30)
+            .with_threshold(Status::InProgress, 14)
+    }
+}
+
+impl StalePolicy {
+    /// どのタスクも放置とみなさないポリシーを作成
+    pub fn disabled() -> Self {
+        Self {
+            thresholds: HashMap::new(),
+        }
+    }
+
+    /// 未完了のすべてのステータスに同じしきい値を設定したポリシーを作成
+    pub fn uniform(days: u32) -> Self {
+        Self::disabled()
+            .with_threshold(Status::Pending, days)
+            .with_threshold(Status::InProgress, days)
+    }
+
+    /// ステータスのしきい値を設定したポリシーを返す（0日の場合はそのステータスを判定しない）
+    pub fn with_threshold(mut self, status: Status, days: u32) -> Self {
+        if days == 0 || status == Status::Completed {
+            self.thresholds.remove(&status);
+        } else {
+            self.thresholds.insert(status, days);
+        }
+        self
+    }
+
+    /// ステータスのしきい値（日数）
+    pub fn threshold(&self, status: Status) -> Option<u32> {
+        self.thresholds.get(&status).copied()
+    }
+
+    /// 放置されている場合、最後の更新からの経過日数を返す
+    ///
+    /// # Arguments
+    /// * `status` - タスクのステータス
+    /// * `updated_at` - タスクの更新日時
+    /// * `now` - 基準日時
+    pub fn idle_days(
+        &self,
+        status: Status,
+        updated_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Option<i64> {
+        let threshold = self.threshold(status)?;
+        let days = (now - updated_at).num_days();
+        (days >= i64::from(threshold)).then_some(days)
+    }
+
+    /// タスクが放置されているか
+    pub fn is_stale(&self, task: &TaskAggregate, now: DateTime<Utc>) -> bool {
+        self.idle_days(*task.status(), *task.updated_at(), now)
+            .is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_default_policy_thresholds() {
+        let policy = StalePolicy::default();
+
+        assert_eq!(policy.threshold(Status::Pending), Some(30));
+        assert_eq!(policy.threshold(Status::InProgress), Some(14));
+        assert_eq!(policy.threshold(Status::Completed), None);
+    }
+
+    #[test]
+    fn test_idle_days_per_status() {
+        // Arrange
+        let now = Utc::now();
+        let policy = StalePolicy::disabled()
+            .with_threshold(Status::Pending, 10)
+            .with_threshold(Status::InProgress, 3);
+        let days_ago = |days| now - Duration::days(days);
+
+        // Act & Assert
+        assert_eq!(policy.idle_days(Status::Pending, days_ago(9), now), None);
+        assert_eq!(
+            policy.idle_days(Status::Pending, days_ago(10), now),
+            Some(10)
+        );
+        assert_eq!(
+            policy.idle_days(Status::InProgress, days_ago(5), now),
+            Some(5)
+        );
+        assert_eq!(
+            policy.idle_days(Status::Completed, days_ago(100), now),
+            None
+        );
+    }
+
+    #[test]
+    fn test_zero_threshold_disables_status() {
+        let policy = StalePolicy::uniform(7).with_threshold(Status::Pending, 0);
+
+        assert_eq!(policy.threshold(Status::Pending), None);
+        assert_eq!(policy.threshold(Status::InProgress), Some(7));
+        assert_eq!(
+            StalePolicy::disabled().with_threshold(Status::Completed, 5),
+            StalePolicy::disabled()
+        );
+    }
+}
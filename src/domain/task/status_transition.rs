@@ -0,0 +1,152 @@
+use crate::domain::task::value_objects::Status;
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+
+/// ステータス遷移の可否
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionRule {
+    /// 常に許可する
+    Allowed,
+    /// 再開（reopen）を明示した場合のみ許可する
+    RequiresReopen,
+    /// 禁止する
+    Forbidden,
+}
+
+/// StatusTransitionPolicy - タスクのステータス遷移を検証する状態機械
+///
+/// 遷移元と遷移先の組ごとに`TransitionRule`を持ちます。
+/// 規則が設定されていない遷移は許可され、同じステータスへの変更は常に許可されます。
+///
+/// 既定では、完了済みのタスクを未完了に戻す遷移は再開の明示が必要です。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusTransitionPolicy {
+    rules: HashMap<(Status, Status), TransitionRule>,
+}
+
+impl Default for StatusTransitionPolicy {
+    fn default() -> Self {
+        Self::permissive()
+            .with_rule(
+                Status::Completed,
+                Status::Pending,
+                TransitionRule::RequiresReopen,
+            )
+            .with_rule(
+                Status::Completed,
+                Status::InProgress,
+                TransitionRule::RequiresReopen,
+            )
+    }
+}
+
+impl StatusTransitionPolicy {
+    /// すべての遷移を許可するポリシーを作成
+    pub fn permissive() -> Self {
+        Self {
+            rules: HashMap::new(),
+        }
+    }
+
+    /// 遷移の規則を設定したポリシーを返す
+    pub fn with_rule(mut self, from: Status, to: Status, rule: TransitionRule) -> Self {
+        self.rules.insert((from, to), rule);
+        self
+    }
+
+    /// 遷移に適用される規則
+    pub fn rule(&self, from: Status, to: Status) -> TransitionRule {
+        if from == to {
+            return TransitionRule::Allowed;
+        }
+        self.rules
+            .get(&(from, to))
+            .copied()
+            .unwrap_or(TransitionRule::Allowed)
+    }
+
+    /// 遷移できるかを検証する
+    ///
+    /// # Arguments
+    /// * `reopen` - 再開を明示しているか（`RequiresReopen`の遷移を許可する）
+    pub fn validate(&self, from: Status, to: Status, reopen: bool) -> Result<()> {
+        match self.rule(from, to) {
+            TransitionRule::Allowed => Ok(()),
+            TransitionRule::RequiresReopen if reopen => Ok(()),
+            TransitionRule::RequiresReopen => bail!(
+                "Changing status from {} to {} requires reopening the task (use --reopen)",
+                from,
+                to
+            ),
+            TransitionRule::Forbidden => {
+                bail!("Changing status from {} to {} is not allowed", from, to)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_allows_forward_transitions() {
+        let policy = StatusTransitionPolicy::default();
+
+        assert!(
+            policy
+                .validate(Status::Pending, Status::Completed, false)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .validate(Status::Pending, Status::InProgress, false)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .validate(Status::Completed, Status::Completed, false)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_default_policy_requires_reopen_from_completed() {
+        // Arrange
+        let policy = StatusTransitionPolicy::default();
+
+        // Act
+        let without_reopen = policy.validate(Status::Completed, Status::Pending, false);
+        let with_reopen = policy.validate(Status::Completed, Status::Pending, true);
+
+        // Assert
+        assert!(without_reopen.unwrap_err().to_string().contains("--reopen"));
+        assert!(with_reopen.is_ok());
+    }
+
+    #[test]
+    fn test_forbidden_rule_rejects_even_with_reopen() {
+        // Arrange
+        let policy = StatusTransitionPolicy::default().with_rule(
+            Status::Pending,
+            Status::Completed,
+            TransitionRule::Forbidden,
+        );
+
+        // Act
+        let result = policy.validate(Status::Pending, Status::Completed, true);
+
+        // Assert
+        assert!(result.unwrap_err().to_string().contains("not allowed"));
+    }
+
+    #[test]
+    fn test_permissive_policy_allows_reopen() {
+        let policy = StatusTransitionPolicy::permissive();
+
+        assert_eq!(
+            policy.rule(Status::Completed, Status::Pending),
+            TransitionRule::Allowed
+        );
+    }
+}
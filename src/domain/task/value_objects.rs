@@ -1,16 +1,38 @@
+pub mod assignee;
+pub mod checklist_item;
+pub mod completion_streak;
+pub mod custom_field;
 pub mod due_date;
 pub mod due_date_status;
+pub mod estimate;
+pub mod external_ref;
+pub mod flow_stats;
+pub mod git_link;
 pub mod priority;
+pub mod recurrence;
 pub mod status;
+pub mod status_change;
+pub mod task_context;
 pub mod task_description;
 pub mod task_id;
 pub mod task_stats;
 pub mod task_title;
 
-pub use due_date::DueDate;
-pub use due_date_status::DueDateStatus;
+pub use assignee::Assignee;
+pub use checklist_item::ChecklistItem;
+pub use completion_streak::CompletionStreak;
+pub use custom_field::CustomField;
+pub use due_date::{DueDate, PastDueDatePolicy};
+pub use due_date_status::{DueDateBuckets, DueDateStatus};
+pub use estimate::Estimate;
+pub use external_ref::ExternalRef;
+pub use flow_stats::{DurationSummary, FlowStats};
+pub use git_link::GitLink;
 pub use priority::Priority;
+pub use recurrence::Recurrence;
 pub use status::Status;
+pub use status_change::StatusChange;
+pub use task_context::TaskContext;
 pub use task_description::TaskDescription;
 pub use task_id::TaskId;
 pub use task_stats::TaskStats;
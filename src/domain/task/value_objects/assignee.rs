@@ -0,0 +1,71 @@
+use anyhow::Result;
+use std::{fmt, str::FromStr};
+
+/// タスクの担当者を表すValue Object
+///
+/// 1つのデータベースを複数人で共有する場合に、誰が担当するかを名前（`alice`など）で表します。
+/// 名前は自由な文字列で、前後の空白を除いて保持します（大文字小文字は区別します）。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Assignee(String);
+
+impl Assignee {
+    /// 名前の最大文字数
+    const MAX_LENGTH: usize = 50;
+
+    /// 新しいAssigneeを作成
+    pub fn new(value: impl AsRef<str>) -> Result<Self> {
+        let value = value.as_ref().trim();
+        if value.is_empty() {
+            anyhow::bail!("Assignee cannot be empty");
+        }
+        if value.chars().count() > Self::MAX_LENGTH {
+            anyhow::bail!("Assignee must be {} characters or less", Self::MAX_LENGTH);
+        }
+        if value.chars().any(char::is_control) {
+            anyhow::bail!("Assignee cannot contain control characters");
+        }
+        Ok(Self(value.to_string()))
+    }
+
+    /// 名前を取得
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Assignee {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Assignee {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assignee_trims_and_keeps_case() {
+        assert_eq!(Assignee::new(" alice ").unwrap().value(), "alice");
+        assert_eq!(Assignee::new("Bob Smith").unwrap().value(), "Bob Smith");
+        assert_eq!(Assignee::new("山田").unwrap().to_string(), "山田");
+        assert_ne!(
+            Assignee::new("alice").unwrap(),
+            Assignee::new("Alice").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_assignee_invalid() {
+        for input in ["", "   ", "a\tb", "a\nb", &"x".repeat(51)] {
+            assert!(Assignee::new(input).is_err(), "input: {:?}", input);
+        }
+    }
+}
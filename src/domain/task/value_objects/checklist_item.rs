@@ -0,0 +1,89 @@
+use anyhow::Result;
+use std::fmt;
+
+/// タスクのチェックリスト項目を表すValue Object
+///
+/// 項目の内容は1文字以上200文字以内で、完了済みかどうかの状態を持ちます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecklistItem {
+    text: String,
+    done: bool,
+}
+
+impl ChecklistItem {
+    /// 内容の最大文字数
+    const MAX_TEXT_LENGTH: usize = 200;
+
+    /// 新しいChecklistItemを作成
+    pub fn new(text: impl Into<String>, done: bool) -> Result<Self> {
+        let text = text.into().trim().to_string();
+
+        if text.is_empty() {
+            anyhow::bail!("Checklist item cannot be empty");
+        }
+        if text.chars().count() > Self::MAX_TEXT_LENGTH {
+            anyhow::bail!(
+                "Checklist item must be {} characters or less",
+                Self::MAX_TEXT_LENGTH
+            );
+        }
+
+        Ok(Self { text, done })
+    }
+
+    /// 内容を取得
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 完了済みか
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// 完了状態を反転した項目を返す
+    pub fn toggled(&self) -> Self {
+        Self {
+            text: self.text.clone(),
+            done: !self.done,
+        }
+    }
+}
+
+impl fmt::Display for ChecklistItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mark = if self.done { "x" } else { " " };
+        write!(f, "[{}] {}", mark, self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checklist_item_new() {
+        let item = ChecklistItem::new("  write tests ", false).unwrap();
+
+        assert_eq!(item.text(), "write tests");
+        assert!(!item.is_done());
+        assert_eq!(item.to_string(), "[ ] write tests");
+    }
+
+    #[test]
+    fn test_checklist_item_toggled() {
+        let item = ChecklistItem::new("write tests", false).unwrap();
+
+        let toggled = item.toggled();
+
+        assert!(toggled.is_done());
+        assert_eq!(toggled.to_string(), "[x] write tests");
+        assert!(!toggled.toggled().is_done());
+    }
+
+    #[test]
+    fn test_checklist_item_invalid_text() {
+        assert!(ChecklistItem::new("   ", false).is_err());
+        assert!(ChecklistItem::new("x".repeat(201), false).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+/// タスクを完了した日の連続記録を表すValue Object
+///
+/// 1件以上のタスクを完了した日が何日続いているかと、完了したタスクの件数を表します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CompletionStreak {
+    completed_today: usize,
+    completed_total: usize,
+    current_days: u32,
+    longest_days: u32,
+}
+
+impl CompletionStreak {
+    /// 新しいCompletionStreakを作成
+    pub fn new(
+        completed_today: usize,
+        completed_total: usize,
+        current_days: u32,
+        longest_days: u32,
+    ) -> Self {
+        Self {
+            completed_today,
+            completed_total,
+            current_days,
+            longest_days,
+        }
+    }
+
+    /// 今日完了したタスク数
+    pub fn completed_today(&self) -> usize {
+        self.completed_today
+    }
+
+    /// 完了したタスクの総数
+    pub fn completed_total(&self) -> usize {
+        self.completed_total
+    }
+
+    /// 現在の連続日数（今日まだ完了していない場合は昨日までの連続日数）
+    pub fn current_days(&self) -> u32 {
+        self.current_days
+    }
+
+    /// これまでの最長の連続日数
+    pub fn longest_days(&self) -> u32 {
+        self.longest_days
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use std::{fmt, str::FromStr};
+
+/// タスクのカスタムフィールド（キーと値の組）を表すValue Object
+///
+/// キーは英字で始まる32文字以内の英数字・`_`・`-`で、小文字に正規化されます。
+/// 値は1文字以上200文字以内の文字列です。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomField {
+    key: String,
+    value: String,
+}
+
+impl CustomField {
+    /// キーの最大文字数
+    const MAX_KEY_LENGTH: usize = 32;
+    /// 値の最大文字数
+    const MAX_VALUE_LENGTH: usize = 200;
+
+    /// 新しいCustomFieldを作成
+    pub fn new(key: impl AsRef<str>, value: impl Into<String>) -> Result<Self> {
+        let key = Self::normalize_key(key.as_ref())?;
+        let value = value.into().trim().to_string();
+
+        if value.is_empty() {
+            anyhow::bail!("Custom field '{}' must have a value", key);
+        }
+        if value.chars().count() > Self::MAX_VALUE_LENGTH {
+            anyhow::bail!(
+                "Custom field '{}' value must be {} characters or less",
+                key,
+                Self::MAX_VALUE_LENGTH
+            );
+        }
+
+        Ok(Self { key, value })
+    }
+
+    /// キーを検証して小文字に正規化する
+    pub fn normalize_key(key: &str) -> Result<String> {
+        let key = key.trim();
+        let starts_with_letter = key.chars().next().is_some_and(|c| c.is_ascii_alphabetic());
+        let valid_chars = key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+        if !starts_with_letter || !valid_chars || key.len() > Self::MAX_KEY_LENGTH {
+            anyhow::bail!(
+                "Invalid custom field key '{}'. Use a letter followed by letters, digits, '_' or '-' (up to {} characters)",
+                key,
+                Self::MAX_KEY_LENGTH
+            );
+        }
+
+        Ok(key.to_ascii_lowercase())
+    }
+
+    /// キーを取得
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 値を取得
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// 値が一致するか（大文字小文字を区別しない）
+    pub fn value_matches(&self, value: &str) -> bool {
+        self.value.to_lowercase() == value.trim().to_lowercase()
+    }
+}
+
+/// `key=value`形式の文字列から変換
+impl FromStr for CustomField {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid custom field '{}'. Expected 'key=value'", s))?;
+        Self::new(key, value)
+    }
+}
+
+impl fmt::Display for CustomField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_field_parse() {
+        let field: CustomField = "Estimate=3h".parse().unwrap();
+
+        assert_eq!(field.key(), "estimate");
+        assert_eq!(field.value(), "3h");
+        assert_eq!(field.to_string(), "estimate=3h");
+    }
+
+    #[test]
+    fn test_custom_field_value_may_contain_equals() {
+        let field: CustomField = "query=a=b".parse().unwrap();
+
+        assert_eq!(field.value(), "a=b");
+    }
+
+    #[test]
+    fn test_custom_field_invalid_key() {
+        assert!("=value".parse::<CustomField>().is_err());
+        assert!("1st=value".parse::<CustomField>().is_err());
+        assert!("my key=value".parse::<CustomField>().is_err());
+        assert!(CustomField::new("k".repeat(33), "v").is_err());
+    }
+
+    #[test]
+    fn test_custom_field_requires_value() {
+        assert!("client=".parse::<CustomField>().is_err());
+        assert!("client".parse::<CustomField>().is_err());
+        assert!(CustomField::new("client", "x".repeat(201)).is_err());
+    }
+
+    #[test]
+    fn test_custom_field_value_matches_ignores_case() {
+        let field = CustomField::new("client", "ACME").unwrap();
+
+        assert!(field.value_matches("acme"));
+        assert!(!field.value_matches("acme inc"));
+    }
+}
@@ -1,6 +1,7 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use strum::EnumString;
 
 /// タスクの期限を表すValue Object
 ///
@@ -20,23 +21,63 @@ impl DueDate {
     }
 
     /// 指定された日付より前かチェック
-    #[allow(dead_code)]
     pub fn is_before(&self, other: NaiveDate) -> bool {
         self.0 < other
     }
 
     /// 指定された日付より後かチェック
-    #[allow(dead_code)]
     pub fn is_after(&self, other: NaiveDate) -> bool {
         self.0 > other
     }
 }
 
+/// 新しいタスクに過去の期限日を指定した場合の扱い
+///
+/// 既存のタスクの編集やリポジトリからの再構築には適用されません。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumString)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum PastDueDatePolicy {
+    /// 許可する
+    #[default]
+    Allow,
+    /// 許可するが警告する
+    Warn,
+    /// エラーにする
+    Reject,
+}
+
+impl PastDueDatePolicy {
+    /// 新しいタスクの期限日を検証する
+    ///
+    /// 今日の日付は過去として扱いません。
+    ///
+    /// # Returns
+    /// * `Ok(true)` - 期限日が過去で、警告が必要な場合
+    /// * `Ok(false)` - 問題がない場合
+    /// * `Err` - 期限日が過去で、ポリシーが`Reject`の場合
+    pub fn check(&self, due_date: &DueDate, today: NaiveDate) -> Result<bool> {
+        if !due_date.is_before(today) {
+            return Ok(false);
+        }
+        match self {
+            PastDueDatePolicy::Allow => Ok(false),
+            PastDueDatePolicy::Warn => Ok(true),
+            PastDueDatePolicy::Reject => bail!(
+                "Due date {} is in the past (today is {})",
+                due_date.value(),
+                today
+            ),
+        }
+    }
+}
+
 // テストのみを先に作成（TDD）
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::task::arbitrary::due_date;
     use chrono::NaiveDate;
+    use proptest::prelude::*;
 
     #[test]
     fn test_due_date_valid() {
@@ -78,6 +119,42 @@ mod tests {
         assert!(!due_date.is_after(tomorrow));
     }
 
+    #[test]
+    fn test_past_due_date_policy_boundaries() {
+        // Arrange
+        let today = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let yesterday = DueDate::new(NaiveDate::from_ymd_opt(2026, 2, 28).unwrap()).unwrap();
+        let due_today = DueDate::new(today).unwrap();
+        let tomorrow = DueDate::new(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()).unwrap();
+
+        // Act & Assert: 今日と未来はどのポリシーでも問題なし
+        for policy in [
+            PastDueDatePolicy::Allow,
+            PastDueDatePolicy::Warn,
+            PastDueDatePolicy::Reject,
+        ] {
+            assert!(!policy.check(&due_today, today).unwrap());
+            assert!(!policy.check(&tomorrow, today).unwrap());
+        }
+        assert!(!PastDueDatePolicy::Allow.check(&yesterday, today).unwrap());
+        assert!(PastDueDatePolicy::Warn.check(&yesterday, today).unwrap());
+        assert!(PastDueDatePolicy::Reject.check(&yesterday, today).is_err());
+    }
+
+    #[test]
+    fn test_past_due_date_policy_from_str() {
+        assert_eq!(PastDueDatePolicy::default(), PastDueDatePolicy::Allow);
+        assert_eq!(
+            "warn".parse::<PastDueDatePolicy>().unwrap(),
+            PastDueDatePolicy::Warn
+        );
+        assert_eq!(
+            "Reject".parse::<PastDueDatePolicy>().unwrap(),
+            PastDueDatePolicy::Reject
+        );
+        assert!("deny".parse::<PastDueDatePolicy>().is_err());
+    }
+
     #[test]
     fn test_due_date_equality() {
         let date = NaiveDate::from_ymd_opt(2026, 6, 15).unwrap();
@@ -117,4 +194,18 @@ mod tests {
         let expected_date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
         assert_eq!(due_date.value(), expected_date);
     }
+
+    proptest! {
+        #[test]
+        fn test_due_date_comparisons_agree_with_ordering(a in due_date(), b in due_date()) {
+            // Act & Assert: is_before・is_afterは日付の順序と一致し、同時には成り立たない
+            prop_assert_eq!(a.is_before(b.value()), a < b);
+            prop_assert_eq!(a.is_after(b.value()), a > b);
+            prop_assert!(!(a.is_before(b.value()) && a.is_after(b.value())));
+
+            // シリアライズしても値が変わらない
+            let json = serde_json::to_string(&a).unwrap();
+            prop_assert_eq!(serde_json::from_str::<DueDate>(&json).unwrap(), a);
+        }
+    }
 }
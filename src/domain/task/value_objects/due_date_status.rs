@@ -1,3 +1,5 @@
+use anyhow::{Result, bail};
+use chrono::{Duration, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 /// 期限の状況を表すValue Object
@@ -7,20 +9,112 @@ pub enum DueDateStatus {
     Overdue,
     /// 今日期限
     DueToday,
-    /// 今週期限（7日以内）
-    DueThisWeek,
+    /// 期限が近い（`DueDateBuckets::soon_days`日以内）
+    #[serde(alias = "DueThisWeek")]
+    DueSoon,
+    /// 期限が迫りつつある（`DueDateBuckets::upcoming_days`日以内）
+    DueUpcoming,
+    /// 期限まで余裕がある
+    DueLater,
     /// 期限なし
     NoDueDate,
 }
 
+/// 期限の状況を分類する日数の境界を表すValue Object
+///
+/// 期限まで`soon_days`日以内なら`DueSoon`、`upcoming_days`日以内なら`DueUpcoming`に分類します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DueDateBuckets {
+    soon_days: u32,
+    upcoming_days: u32,
+}
+
+impl Default for DueDateBuckets {
+    fn default() -> Self {
+        Self {
+            soon_days: 7,
+            upcoming_days: 30,
+        }
+    }
+}
+
+impl DueDateBuckets {
+    /// 境界を検証して作成
+    ///
+    /// `soon_days`は1以上、`upcoming_days`は`soon_days`以上である必要があります。
+    pub fn new(soon_days: u32, upcoming_days: u32) -> Result<Self> {
+        if soon_days == 0 {
+            bail!("soon_days must be at least 1");
+        }
+        if upcoming_days < soon_days {
+            bail!(
+                "upcoming_days ({}) must not be less than soon_days ({})",
+                upcoming_days,
+                soon_days
+            );
+        }
+        Ok(Self {
+            soon_days,
+            upcoming_days,
+        })
+    }
+
+    /// 期限が近いとみなす日数を取得
+    pub fn soon_days(&self) -> u32 {
+        self.soon_days
+    }
+
+    /// 期限が迫りつつあるとみなす日数を取得
+    pub fn upcoming_days(&self) -> u32 {
+        self.upcoming_days
+    }
+}
+
 impl DueDateStatus {
+    /// 表示・集計で使用する並び順（期限が近い順）
+    pub const ORDER: [DueDateStatus; 6] = [
+        DueDateStatus::Overdue,
+        DueDateStatus::DueToday,
+        DueDateStatus::DueSoon,
+        DueDateStatus::DueUpcoming,
+        DueDateStatus::DueLater,
+        DueDateStatus::NoDueDate,
+    ];
+
+    /// 期限日と基準日から期限の状況を判定
+    ///
+    /// # Arguments
+    /// * `due_date` - 期限日（未設定の場合はNone）
+    /// * `today` - 基準日
+    /// * `buckets` - 分類の境界となる日数
+    pub fn from_due_date(
+        due_date: Option<NaiveDate>,
+        today: NaiveDate,
+        buckets: DueDateBuckets,
+    ) -> Self {
+        match due_date {
+            None => DueDateStatus::NoDueDate,
+            Some(due) if due < today => DueDateStatus::Overdue,
+            Some(due) if due == today => DueDateStatus::DueToday,
+            Some(due) if due <= today + Duration::days(buckets.soon_days.into()) => {
+                DueDateStatus::DueSoon
+            }
+            Some(due) if due <= today + Duration::days(buckets.upcoming_days.into()) => {
+                DueDateStatus::DueUpcoming
+            }
+            Some(_) => DueDateStatus::DueLater,
+        }
+    }
+
     /// Get display name
     #[allow(dead_code)]
     pub fn display_name(&self) -> &str {
         match self {
             DueDateStatus::Overdue => "Overdue",
             DueDateStatus::DueToday => "Due Today",
-            DueDateStatus::DueThisWeek => "Due This Week",
+            DueDateStatus::DueSoon => "Due Soon",
+            DueDateStatus::DueUpcoming => "Due Upcoming",
+            DueDateStatus::DueLater => "Due Later",
             DueDateStatus::NoDueDate => "No Due Date",
         }
     }
@@ -56,11 +150,82 @@ mod tests {
         assert_eq!(status, DueDateStatus::DueToday);
     }
 
+    #[test]
+    fn test_due_date_status_deserialize_legacy_due_this_week() {
+        let status: DueDateStatus = serde_json::from_str("\"DueThisWeek\"").unwrap();
+        assert_eq!(status, DueDateStatus::DueSoon);
+    }
+
     #[test]
     fn test_due_date_status_display() {
         assert_eq!(DueDateStatus::Overdue.display_name(), "Overdue");
         assert_eq!(DueDateStatus::DueToday.display_name(), "Due Today");
-        assert_eq!(DueDateStatus::DueThisWeek.display_name(), "Due This Week");
+        assert_eq!(DueDateStatus::DueSoon.display_name(), "Due Soon");
+        assert_eq!(DueDateStatus::DueUpcoming.display_name(), "Due Upcoming");
+        assert_eq!(DueDateStatus::DueLater.display_name(), "Due Later");
         assert_eq!(DueDateStatus::NoDueDate.display_name(), "No Due Date");
     }
+
+    #[test]
+    fn test_due_date_status_from_due_date() {
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let buckets = DueDateBuckets::default();
+
+        assert_eq!(
+            DueDateStatus::from_due_date(None, today, buckets),
+            DueDateStatus::NoDueDate
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today - Duration::days(1)), today, buckets),
+            DueDateStatus::Overdue
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today), today, buckets),
+            DueDateStatus::DueToday
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today + Duration::days(7)), today, buckets),
+            DueDateStatus::DueSoon
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today + Duration::days(8)), today, buckets),
+            DueDateStatus::DueUpcoming
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today + Duration::days(30)), today, buckets),
+            DueDateStatus::DueUpcoming
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today + Duration::days(31)), today, buckets),
+            DueDateStatus::DueLater
+        );
+    }
+
+    #[test]
+    fn test_due_date_status_from_due_date_with_custom_buckets() {
+        // Arrange
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+        let buckets = DueDateBuckets::new(3, 7).unwrap();
+
+        // Act & Assert
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today + Duration::days(3)), today, buckets),
+            DueDateStatus::DueSoon
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today + Duration::days(4)), today, buckets),
+            DueDateStatus::DueUpcoming
+        );
+        assert_eq!(
+            DueDateStatus::from_due_date(Some(today + Duration::days(8)), today, buckets),
+            DueDateStatus::DueLater
+        );
+    }
+
+    #[test]
+    fn test_due_date_buckets_new_rejects_invalid_boundaries() {
+        assert!(DueDateBuckets::new(0, 30).is_err());
+        assert!(DueDateBuckets::new(7, 3).is_err());
+        assert!(DueDateBuckets::new(7, 7).is_ok());
+    }
 }
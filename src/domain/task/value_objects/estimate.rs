@@ -0,0 +1,139 @@
+use anyhow::{Result, bail};
+use std::{fmt, iter::Sum, str::FromStr};
+
+/// タスクの見積もり時間を表すValue Object
+///
+/// 分単位で保持し、`2h30m`・`45m`・`3h`のような形式で表します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Estimate {
+    minutes: u32,
+}
+
+impl Estimate {
+    /// 1つのタスクに設定できる見積もりの上限（999時間）
+    const MAX_MINUTES: u32 = 999 * 60;
+
+    /// 1日の作業可能時間の既定値（8時間）
+    pub const DEFAULT_DAILY_CAPACITY: Self = Self { minutes: 8 * 60 };
+
+    /// 分数を指定してEstimateを作成
+    pub fn from_minutes(minutes: u32) -> Result<Self> {
+        if minutes == 0 || minutes > Self::MAX_MINUTES {
+            bail!(
+                "Estimate must be between 1m and {}h",
+                Self::MAX_MINUTES / 60
+            );
+        }
+        Ok(Self { minutes })
+    }
+
+    /// 見積もり時間（分）
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    /// 分数を`2h30m`の形式で表す（合計時間の表示などに使用、0分は`0m`）
+    pub fn format_minutes(minutes: u32) -> String {
+        match (minutes / 60, minutes % 60) {
+            (0, m) => format!("{}m", m),
+            (h, 0) => format!("{}h", h),
+            (h, m) => format!("{}h{}m", h, m),
+        }
+    }
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&Self::format_minutes(self.minutes))
+    }
+}
+
+/// `2h30m`・`2h`・`90m`・`90`（分）などの文字列から変換（大文字小文字・空白は無視）
+impl FromStr for Estimate {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        let invalid = || {
+            anyhow::anyhow!(
+                "Invalid estimate '{}'. Use hours and minutes such as 2h30m, 3h or 45m",
+                s.trim()
+            )
+        };
+
+        // 単位のない数値は分として扱う
+        if let Ok(minutes) = normalized.parse::<u32>() {
+            return Self::from_minutes(minutes);
+        }
+
+        let (hours, rest) = match normalized.split_once('h') {
+            Some((hours, rest)) => (hours.parse::<u32>().map_err(|_| invalid())?, rest),
+            None => (0, normalized.as_str()),
+        };
+        let minutes = match rest {
+            "" if normalized.ends_with('h') => 0,
+            "" => return Err(invalid()),
+            rest => rest
+                .strip_suffix('m')
+                .and_then(|m| m.parse::<u32>().ok())
+                .ok_or_else(invalid)?,
+        };
+        let total = hours
+            .checked_mul(60)
+            .and_then(|h| h.checked_add(minutes))
+            .ok_or_else(invalid)?;
+        Self::from_minutes(total)
+    }
+}
+
+/// 見積もりの合計（分）
+impl Sum<Estimate> for u32 {
+    fn sum<I: Iterator<Item = Estimate>>(iter: I) -> u32 {
+        iter.map(|estimate| estimate.minutes).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_parse_and_display() {
+        let cases = [
+            ("2h30m", 150, "2h30m"),
+            ("2H 30M", 150, "2h30m"),
+            ("3h", 180, "3h"),
+            ("45m", 45, "45m"),
+            ("90m", 90, "1h30m"),
+            ("90", 90, "1h30m"),
+        ];
+        for (input, minutes, expected) in cases {
+            let estimate: Estimate = input.parse().unwrap();
+            assert_eq!(estimate.minutes(), minutes, "input: {}", input);
+            assert_eq!(estimate.to_string(), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_estimate_parse_invalid() {
+        for input in [
+            "", "h", "m", "0m", "2x", "1.5h", "2h30", "-1h", "1000h", "m30",
+        ] {
+            assert!(input.parse::<Estimate>().is_err(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_estimate_sum() {
+        let estimates = ["1h", "30m", "2h15m"].map(|s| s.parse::<Estimate>().unwrap());
+
+        let total: u32 = estimates.into_iter().sum();
+
+        assert_eq!(Estimate::format_minutes(total), "3h45m");
+        assert_eq!(Estimate::format_minutes(0), "0m");
+    }
+}
@@ -0,0 +1,136 @@
+use crate::domain::task::value_objects::Status;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// タスクと外部サービスの項目（GitHubのIssueなど）の対応を表すValue Object
+///
+/// 外部サービスの種類（`github`など）と、サービス内で項目を一意に指す識別子
+/// （`owner/name#42`など）の組で項目を特定します。
+/// 差分同期のため、最後に同期した時点の外部の更新日時とタスクのステータスも持ちます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalRef {
+    source: String,
+    external_id: String,
+    url: Option<String>,
+    remote_updated_at: DateTime<Utc>,
+    synced_status: Status,
+}
+
+impl ExternalRef {
+    /// 新しいExternalRefを作成
+    pub fn new(
+        source: impl Into<String>,
+        external_id: impl Into<String>,
+        url: Option<String>,
+        remote_updated_at: DateTime<Utc>,
+        synced_status: Status,
+    ) -> Result<Self> {
+        let source = source.into().trim().to_ascii_lowercase();
+        if source.is_empty() {
+            anyhow::bail!("External source cannot be empty");
+        }
+        let external_id = external_id.into().trim().to_string();
+        if external_id.is_empty() {
+            anyhow::bail!("External ID cannot be empty");
+        }
+        let url = url
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty());
+
+        Ok(Self {
+            source,
+            external_id,
+            url,
+            remote_updated_at,
+            synced_status,
+        })
+    }
+
+    /// 外部サービスの種類を取得
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// 外部サービス内の識別子を取得
+    pub fn external_id(&self) -> &str {
+        &self.external_id
+    }
+
+    /// 外部の項目のURLを取得
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// 最後に同期した時点の外部の更新日時を取得
+    pub fn remote_updated_at(&self) -> &DateTime<Utc> {
+        &self.remote_updated_at
+    }
+
+    /// 最後に同期した時点のタスクのステータスを取得
+    pub fn synced_status(&self) -> Status {
+        self.synced_status
+    }
+
+    /// 同じ外部の項目を指しているか
+    pub fn points_to(&self, source: &str, external_id: &str) -> bool {
+        self.source.eq_ignore_ascii_case(source) && self.external_id == external_id
+    }
+
+    /// 同期した時点の状態を記録したExternalRefを返す
+    pub fn synced(self, remote_updated_at: DateTime<Utc>, synced_status: Status) -> Self {
+        Self {
+            remote_updated_at,
+            synced_status,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ExternalRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.source, self.external_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_ref_new() {
+        let external_ref = ExternalRef::new(
+            "GitHub",
+            " taktiks2/yaru#42 ",
+            Some("https://github.com/taktiks2/yaru/issues/42".to_string()),
+            Utc::now(),
+            Status::Pending,
+        )
+        .unwrap();
+
+        assert_eq!(external_ref.source(), "github");
+        assert_eq!(external_ref.external_id(), "taktiks2/yaru#42");
+        assert!(external_ref.points_to("github", "taktiks2/yaru#42"));
+        assert!(!external_ref.points_to("github", "taktiks2/yaru#4"));
+        assert_eq!(external_ref.to_string(), "github taktiks2/yaru#42");
+    }
+
+    #[test]
+    fn test_external_ref_invalid() {
+        assert!(ExternalRef::new("", "a#1", None, Utc::now(), Status::Pending).is_err());
+        assert!(ExternalRef::new("github", " ", None, Utc::now(), Status::Pending).is_err());
+    }
+
+    #[test]
+    fn test_external_ref_synced() {
+        let external_ref =
+            ExternalRef::new("github", "a/b#1", None, Utc::now(), Status::Pending).unwrap();
+        let later = Utc::now() + chrono::Duration::hours(1);
+
+        let synced = external_ref.synced(later, Status::Completed);
+
+        assert_eq!(synced.remote_updated_at(), &later);
+        assert_eq!(synced.synced_status(), Status::Completed);
+        assert_eq!(synced.external_id(), "a/b#1");
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::Duration;
+
+/// 期間の件数と合計を表すValue Object（平均の計算に使用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationSummary {
+    count: usize,
+    total: Duration,
+}
+
+impl Default for DurationSummary {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            total: Duration::zero(),
+        }
+    }
+}
+
+impl DurationSummary {
+    /// 期間を1件加える
+    pub fn add(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+    }
+
+    /// 件数を取得
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// 平均を取得（1件もない場合はNone）
+    pub fn average(&self) -> Option<Duration> {
+        (self.count > 0).then(|| self.total / self.count as i32)
+    }
+}
+
+/// タスクの流れ（ステータスごとの滞在時間・サイクルタイム・リードタイム）の統計を表すValue Object
+///
+/// - 滞在時間: タスクごとにそのステータスにいた時間の合計（未完了のタスクは現在までを含む）
+/// - サイクルタイム: 最初に進行中にしてから完了するまでの時間（完了済みのタスクのみ）
+/// - リードタイム: 作成してから完了するまでの時間（完了済みのタスクのみ）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FlowStats {
+    task_count: usize,
+    pending: DurationSummary,
+    in_progress: DurationSummary,
+    cycle_time: DurationSummary,
+    lead_time: DurationSummary,
+}
+
+impl FlowStats {
+    /// 新しいFlowStatsを作成
+    pub fn new(
+        task_count: usize,
+        pending: DurationSummary,
+        in_progress: DurationSummary,
+        cycle_time: DurationSummary,
+        lead_time: DurationSummary,
+    ) -> Self {
+        Self {
+            task_count,
+            pending,
+            in_progress,
+            cycle_time,
+            lead_time,
+        }
+    }
+
+    /// 集計したタスク数を取得
+    pub fn task_count(&self) -> usize {
+        self.task_count
+    }
+
+    /// 保留中の滞在時間を取得
+    pub fn pending(&self) -> &DurationSummary {
+        &self.pending
+    }
+
+    /// 進行中の滞在時間を取得
+    pub fn in_progress(&self) -> &DurationSummary {
+        &self.in_progress
+    }
+
+    /// サイクルタイムを取得
+    pub fn cycle_time(&self) -> &DurationSummary {
+        &self.cycle_time
+    }
+
+    /// リードタイムを取得
+    pub fn lead_time(&self) -> &DurationSummary {
+        &self.lead_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_summary_average() {
+        // Arrange
+        let mut summary = DurationSummary::default();
+
+        // Act
+        summary.add(Duration::hours(1));
+        summary.add(Duration::hours(3));
+
+        // Assert
+        assert_eq!(summary.count(), 2);
+        assert_eq!(summary.average(), Some(Duration::hours(2)));
+    }
+
+    #[test]
+    fn test_duration_summary_empty_has_no_average() {
+        assert_eq!(DurationSummary::default().average(), None);
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// タスクとgitのコミット・ブランチの紐づけを表すValue Object
+///
+/// リポジトリ（作業ツリーのルートパス）、ブランチ（detached HEADの場合はなし）、
+/// コミットハッシュ（小文字に正規化）と、紐づけた日時を持ちます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitLink {
+    repository: String,
+    branch: Option<String>,
+    commit: String,
+    linked_at: DateTime<Utc>,
+}
+
+impl GitLink {
+    /// 表示用の短縮コミットハッシュの文字数
+    const SHORT_COMMIT_LENGTH: usize = 7;
+
+    /// 新しいGitLinkを作成
+    pub fn new(
+        repository: impl Into<String>,
+        branch: Option<String>,
+        commit: impl AsRef<str>,
+        linked_at: DateTime<Utc>,
+    ) -> Result<Self> {
+        let repository = repository.into().trim().to_string();
+        if repository.is_empty() {
+            anyhow::bail!("Git repository cannot be empty");
+        }
+
+        let commit = commit.as_ref().trim().to_ascii_lowercase();
+        let is_hex = commit.chars().all(|c| c.is_ascii_hexdigit());
+        if !is_hex || !(Self::SHORT_COMMIT_LENGTH..=64).contains(&commit.len()) {
+            anyhow::bail!("Invalid git commit hash: '{}'", commit);
+        }
+
+        let branch = branch
+            .map(|branch| branch.trim().to_string())
+            .filter(|branch| !branch.is_empty());
+
+        Ok(Self {
+            repository,
+            branch,
+            commit,
+            linked_at,
+        })
+    }
+
+    /// リポジトリを取得
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// ブランチを取得
+    pub fn branch(&self) -> Option<&str> {
+        self.branch.as_deref()
+    }
+
+    /// コミットハッシュを取得
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+
+    /// 短縮したコミットハッシュを取得
+    pub fn short_commit(&self) -> &str {
+        &self.commit[..Self::SHORT_COMMIT_LENGTH]
+    }
+
+    /// 紐づけた日時を取得
+    pub fn linked_at(&self) -> &DateTime<Utc> {
+        &self.linked_at
+    }
+
+    /// 同じリポジトリ・ブランチ・コミットを指しているか（紐づけた日時は比較しない）
+    pub fn points_to_same(&self, other: &GitLink) -> bool {
+        self.repository == other.repository
+            && self.branch == other.branch
+            && self.commit == other.commit
+    }
+}
+
+impl fmt::Display for GitLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.branch {
+            Some(branch) => write!(
+                f,
+                "{} ({}) {}",
+                self.repository,
+                branch,
+                self.short_commit()
+            ),
+            None => write!(f, "{} {}", self.repository, self.short_commit()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMIT: &str = "3F2A9C1D0B8E7F6A5B4C3D2E1F0A9B8C7D6E5F4A";
+
+    #[test]
+    fn test_git_link_new() {
+        let link = GitLink::new("/src/yaru", Some("main".to_string()), COMMIT, Utc::now()).unwrap();
+
+        assert_eq!(link.commit(), COMMIT.to_ascii_lowercase());
+        assert_eq!(link.branch(), Some("main"));
+        assert_eq!(link.to_string(), "/src/yaru (main) 3f2a9c1");
+    }
+
+    #[test]
+    fn test_git_link_without_branch() {
+        let link = GitLink::new("/src/yaru", Some(" ".to_string()), COMMIT, Utc::now()).unwrap();
+
+        assert_eq!(link.branch(), None);
+        assert_eq!(link.to_string(), "/src/yaru 3f2a9c1");
+    }
+
+    #[test]
+    fn test_git_link_invalid() {
+        assert!(GitLink::new("", None, COMMIT, Utc::now()).is_err());
+        assert!(GitLink::new("/src/yaru", None, "abc", Utc::now()).is_err());
+        assert!(GitLink::new("/src/yaru", None, "not-a-hash-value", Utc::now()).is_err());
+    }
+}
@@ -1,59 +1,37 @@
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
-use strum::{Display, EnumIter, EnumString};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use strum::EnumIter;
 
 /// タスクの優先度を表すValue Object
-#[derive(
-    Debug,
-    Clone,
-    Copy,
-    PartialEq,
-    Eq,
-    Hash,
-    Serialize,
-    Deserialize,
-    PartialOrd,
-    Ord,
-    EnumIter,
-    EnumString,
-    Display,
-)]
+///
+/// 文字列では`low`・`medium`・`high`・`critical`で表します。
+/// `Display`・`FromStr`・serdeはすべてこの表記を使うため、文字列との相互変換で値が変わりません。
+/// 読み込み時は大文字小文字を区別しません（以前の形式の`High`なども読み込めます）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, EnumIter)]
 pub enum Priority {
     /// 低
-    #[strum(serialize = "Low", serialize = "low")]
     Low = 1,
     /// 中
-    #[strum(serialize = "Medium", serialize = "medium")]
     Medium = 2,
     /// 高
-    #[strum(serialize = "High", serialize = "high")]
     High = 3,
     /// 重大
-    #[strum(serialize = "Critical", serialize = "critical")]
     Critical = 4,
 }
 
 impl Priority {
-    /// 文字列から変換（anyhow::Result版）
-    #[allow(dead_code)]
-    pub fn from_str_anyhow(s: &str) -> Result<Self> {
-        s.parse()
-            .map_err(|_| anyhow::anyhow!("Invalid priority: {}", s))
-    }
-
-    /// 文字列表現を取得
-    #[allow(dead_code)]
-    pub fn as_str(&self) -> &str {
+    /// 文字列表現を取得（`low`・`medium`・`high`・`critical`）
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Priority::Low => "Low",
-            Priority::Medium => "Medium",
-            Priority::High => "High",
-            Priority::Critical => "Critical",
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+            Priority::Critical => "critical",
         }
     }
 
     /// 表示名を取得
-    #[allow(dead_code)]
     pub fn display_name(&self) -> &str {
         match self {
             Priority::Low => "Low",
@@ -64,47 +42,80 @@ impl Priority {
     }
 }
 
-// テストのみを先に作成（TDD）
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-    #[test]
-    fn test_priority_from_string_low() {
-        let priority = Priority::from_str_anyhow("Low").unwrap();
-        assert_eq!(priority, Priority::Low);
+impl FromStr for Priority {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(Priority::Low),
+            "medium" => Ok(Priority::Medium),
+            "high" => Ok(Priority::High),
+            "critical" => Ok(Priority::Critical),
+            _ => anyhow::bail!(
+                "Invalid priority: '{}'. Use low, medium, high or critical",
+                s
+            ),
+        }
     }
+}
 
-    #[test]
-    fn test_priority_from_string_medium() {
-        let priority = Priority::from_str_anyhow("Medium").unwrap();
-        assert_eq!(priority, Priority::Medium);
+impl Serialize for Priority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
     }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::arbitrary::priority;
+    use proptest::prelude::*;
+    use strum::IntoEnumIterator;
 
     #[test]
-    fn test_priority_from_string_high() {
-        let priority = Priority::from_str_anyhow("High").unwrap();
-        assert_eq!(priority, Priority::High);
+    fn test_priority_from_str() {
+        for (input, expected) in [
+            ("low", Priority::Low),
+            ("medium", Priority::Medium),
+            ("high", Priority::High),
+            ("critical", Priority::Critical),
+        ] {
+            assert_eq!(input.parse::<Priority>().unwrap(), expected);
+        }
     }
 
     #[test]
-    fn test_priority_from_string_critical() {
-        let priority = Priority::from_str_anyhow("Critical").unwrap();
-        assert_eq!(priority, Priority::Critical);
+    fn test_priority_from_str_ignores_case() {
+        assert_eq!("High".parse::<Priority>().unwrap(), Priority::High);
+        assert_eq!("CRITICAL".parse::<Priority>().unwrap(), Priority::Critical);
     }
 
     #[test]
-    fn test_priority_from_string_invalid() {
-        let result = Priority::from_str_anyhow("invalid");
-        assert!(result.is_err());
+    fn test_priority_from_str_invalid() {
+        let error = "urgent".parse::<Priority>().unwrap_err();
+        assert!(error.to_string().contains("Invalid priority: 'urgent'"));
+        assert!("".parse::<Priority>().is_err());
     }
 
     #[test]
     fn test_priority_to_string() {
-        assert_eq!(Priority::Low.as_str(), "Low");
-        assert_eq!(Priority::Medium.as_str(), "Medium");
-        assert_eq!(Priority::High.as_str(), "High");
-        assert_eq!(Priority::Critical.as_str(), "Critical");
+        assert_eq!(Priority::Low.to_string(), "low");
+        assert_eq!(Priority::Medium.to_string(), "medium");
+        assert_eq!(Priority::High.as_str(), "high");
+        assert_eq!(Priority::Critical.as_str(), "critical");
     }
 
     #[test]
@@ -136,15 +147,50 @@ mod tests {
     }
 
     #[test]
-    fn test_priority_serialize() {
-        let priority = Priority::Low;
-        let json = serde_json::to_string(&priority).unwrap();
-        assert_eq!(json, "\"Low\"");
+    fn test_priority_round_trip() {
+        for priority in Priority::iter() {
+            // Display ⇔ FromStr
+            assert_eq!(priority.to_string().parse::<Priority>().unwrap(), priority);
+            // Serialize ⇔ Deserialize（文字列はDisplayと同じ）
+            let json = serde_json::to_string(&priority).unwrap();
+            assert_eq!(json, format!("\"{}\"", priority));
+            assert_eq!(serde_json::from_str::<Priority>(&json).unwrap(), priority);
+        }
     }
 
     #[test]
     fn test_priority_deserialize() {
-        let priority: Priority = serde_json::from_str("\"High\"").unwrap();
+        let priority: Priority = serde_json::from_str("\"high\"").unwrap();
         assert_eq!(priority, Priority::High);
+        // 以前の形式も読み込める
+        let legacy: Priority = serde_json::from_str("\"High\"").unwrap();
+        assert_eq!(legacy, Priority::High);
+        assert!(serde_json::from_str::<Priority>("\"urgent\"").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_priority_parse_ignores_case_and_surrounding_whitespace(
+            priority in priority(),
+            upper in any::<bool>(),
+            padding in "[ \\t]{0,2}",
+        ) {
+            // Arrange
+            let text = if upper { priority.as_str().to_uppercase() } else { priority.to_string() };
+
+            // Act
+            let parsed = format!("{padding}{text}{padding}").parse::<Priority>().unwrap();
+
+            // Assert
+            prop_assert_eq!(parsed, priority);
+        }
+
+        #[test]
+        fn test_priority_parse_result_round_trips(value in "\\PC{0,12}") {
+            // Act & Assert: 読み込めた文字列は、表示形式に変換しても同じ値に戻る
+            if let Ok(parsed) = value.parse::<Priority>() {
+                prop_assert_eq!(parsed.to_string().parse::<Priority>().unwrap(), parsed);
+            }
+        }
     }
 }
@@ -0,0 +1,195 @@
+use anyhow::{Context, Result, bail};
+use chrono::{Days, Months, NaiveDate};
+use std::{fmt, str::FromStr};
+
+/// 繰り返しの単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecurrenceUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl RecurrenceUnit {
+    /// 単位名（英語、単数形）
+    fn name(&self) -> &'static str {
+        match self {
+            RecurrenceUnit::Day => "day",
+            RecurrenceUnit::Week => "week",
+            RecurrenceUnit::Month => "month",
+            RecurrenceUnit::Year => "year",
+        }
+    }
+}
+
+/// タスクの繰り返しルールを表すValue Object
+///
+/// `daily`・`weekly`・`monthly`・`yearly`、または`every 2 weeks`のように間隔を指定した形式で表します。
+/// 月単位・年単位で該当する日がない場合（1月31日の1か月後など）は、その月の末日になります。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Recurrence {
+    interval: u32,
+    unit: RecurrenceUnit,
+}
+
+impl Recurrence {
+    /// 間隔の上限
+    const MAX_INTERVAL: u32 = 999;
+
+    /// 新しいRecurrenceを作成
+    pub fn new(interval: u32, unit: RecurrenceUnit) -> Result<Self> {
+        if interval == 0 || interval > Self::MAX_INTERVAL {
+            bail!(
+                "Recurrence interval must be between 1 and {}",
+                Self::MAX_INTERVAL
+            );
+        }
+        Ok(Self { interval, unit })
+    }
+
+    /// 指定した日付の次の発生日
+    pub fn next_after(&self, date: NaiveDate) -> Result<NaiveDate> {
+        let next = match self.unit {
+            RecurrenceUnit::Day => date.checked_add_days(Days::new(self.interval.into())),
+            RecurrenceUnit::Week => date.checked_add_days(Days::new(7 * u64::from(self.interval))),
+            RecurrenceUnit::Month => date.checked_add_months(Months::new(self.interval)),
+            RecurrenceUnit::Year => date.checked_add_months(Months::new(12 * self.interval)),
+        };
+        next.with_context(|| format!("Next occurrence of {} after {} is out of range", self, date))
+    }
+
+    /// 指定した日付の次の発生日のうち、`not_before`以降で最初のもの
+    ///
+    /// 期限を過ぎてから完了した場合など、過ぎてしまった発生日を飛ばすために使います。
+    pub fn next_on_or_after(&self, date: NaiveDate, not_before: NaiveDate) -> Result<NaiveDate> {
+        let mut next = self.next_after(date)?;
+        while next < not_before {
+            next = self.next_after(next)?;
+        }
+        Ok(next)
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.interval == 1 {
+            let name = match self.unit {
+                RecurrenceUnit::Day => "daily",
+                RecurrenceUnit::Week => "weekly",
+                RecurrenceUnit::Month => "monthly",
+                RecurrenceUnit::Year => "yearly",
+            };
+            f.write_str(name)
+        } else {
+            write!(f, "every {} {}s", self.interval, self.unit.name())
+        }
+    }
+}
+
+/// `daily`・`weekly`・`monthly`・`yearly`・`every N days`などの文字列から変換
+impl FromStr for Recurrence {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let normalized = s.trim().to_ascii_lowercase();
+        let invalid = || {
+            anyhow::anyhow!(
+                "Invalid recurrence '{}'. Use daily, weekly, monthly, yearly or 'every N days|weeks|months|years'",
+                s.trim()
+            )
+        };
+
+        let unit_of = |word: &str| match word.strip_suffix('s').unwrap_or(word) {
+            "day" => Some(RecurrenceUnit::Day),
+            "week" => Some(RecurrenceUnit::Week),
+            "month" => Some(RecurrenceUnit::Month),
+            "year" => Some(RecurrenceUnit::Year),
+            _ => None,
+        };
+
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        match words.as_slice() {
+            ["daily"] => Self::new(1, RecurrenceUnit::Day),
+            ["weekly"] => Self::new(1, RecurrenceUnit::Week),
+            ["monthly"] => Self::new(1, RecurrenceUnit::Month),
+            ["yearly" | "annually"] => Self::new(1, RecurrenceUnit::Year),
+            ["every", unit] => Self::new(1, unit_of(unit).ok_or_else(invalid)?),
+            ["every", interval, unit] => {
+                let interval = interval.parse().map_err(|_| invalid())?;
+                Self::new(interval, unit_of(unit).ok_or_else(invalid)?)
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_recurrence_parse_and_display() {
+        let cases = [
+            ("daily", "daily"),
+            ("Weekly", "weekly"),
+            ("every month", "monthly"),
+            ("every 2 weeks", "every 2 weeks"),
+            ("every 3 day", "every 3 days"),
+            ("annually", "yearly"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                input.parse::<Recurrence>().unwrap().to_string(),
+                expected,
+                "input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_recurrence_parse_invalid() {
+        assert!("hourly".parse::<Recurrence>().is_err());
+        assert!("every 0 days".parse::<Recurrence>().is_err());
+        assert!("every two weeks".parse::<Recurrence>().is_err());
+        assert!("".parse::<Recurrence>().is_err());
+    }
+
+    #[test]
+    fn test_recurrence_next_after() {
+        let weekly: Recurrence = "weekly".parse().unwrap();
+        assert_eq!(
+            weekly.next_after(date(2026, 10, 30)).unwrap(),
+            date(2026, 11, 6)
+        );
+
+        // 該当する日がない場合は月末
+        let monthly: Recurrence = "monthly".parse().unwrap();
+        assert_eq!(
+            monthly.next_after(date(2026, 1, 31)).unwrap(),
+            date(2026, 2, 28)
+        );
+
+        let yearly: Recurrence = "yearly".parse().unwrap();
+        assert_eq!(
+            yearly.next_after(date(2028, 2, 29)).unwrap(),
+            date(2029, 2, 28)
+        );
+    }
+
+    #[test]
+    fn test_recurrence_next_on_or_after_skips_missed_occurrences() {
+        let weekly: Recurrence = "weekly".parse().unwrap();
+
+        let next = weekly
+            .next_on_or_after(date(2026, 10, 1), date(2026, 10, 20))
+            .unwrap();
+
+        assert_eq!(next, date(2026, 10, 22));
+    }
+}
@@ -1,56 +1,35 @@
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
-use strum::{Display, EnumIter, EnumString};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use strum::EnumIter;
 
 /// タスクのステータスを表すValue Object
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter, EnumString, Display,
-)]
+///
+/// 文字列では`pending`・`in_progress`・`completed`で表します。
+/// `Display`・`FromStr`・serdeはすべてこの表記を使うため、文字列との相互変換で値が変わりません。
+/// 読み込み時は大文字小文字を区別せず、別名（`todo`・`progress`・`done`）と
+/// 以前の形式（`InProgress`など）も受け付けます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
 pub enum Status {
     /// 保留中
-    #[strum(serialize = "Pending", serialize = "pending")]
     Pending,
     /// 進行中
-    #[strum(
-        serialize = "InProgress",
-        serialize = "inprogress",
-        serialize = "in_progress"
-    )]
     InProgress,
     /// 完了
-    #[strum(serialize = "Completed", serialize = "completed")]
     Completed,
 }
 
 impl Status {
-    /// 文字列から変換（anyhow::Result版）
-    pub fn from_str_anyhow(s: &str) -> Result<Self> {
-        s.parse()
-            .map_err(|_| anyhow::anyhow!("Invalid status: {}", s))
-    }
-
-    /// フィルタ値から変換
-    pub fn from_filter_value(s: &str) -> Result<Self> {
-        match s.to_lowercase().as_str() {
-            "pending" | "todo" => Ok(Status::Pending),
-            "in_progress" | "progress" => Ok(Status::InProgress),
-            "completed" | "done" => Ok(Status::Completed),
-            _ => anyhow::bail!("Invalid filter value: {}", s),
-        }
-    }
-
-    /// 文字列表現を取得
-    #[allow(dead_code)]
-    pub fn as_str(&self) -> &str {
+    /// 文字列表現を取得（`pending`・`in_progress`・`completed`）
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Status::Pending => "Pending",
-            Status::InProgress => "InProgress",
-            Status::Completed => "Completed",
+            Status::Pending => "pending",
+            Status::InProgress => "in_progress",
+            Status::Completed => "completed",
         }
     }
 
     /// 表示名を取得
-    #[allow(dead_code)]
     pub fn display_name(&self) -> &str {
         match self {
             Status::Pending => "Pending",
@@ -60,76 +39,81 @@ impl Status {
     }
 }
 
-// テストのみを先に作成（TDD）
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_status_from_string_pending() {
-        let status = Status::from_str_anyhow("Pending").unwrap();
-        assert_eq!(status, Status::Pending);
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
+}
 
-    #[test]
-    fn test_status_from_string_in_progress() {
-        let status = Status::from_str_anyhow("InProgress").unwrap();
-        assert_eq!(status, Status::InProgress);
-    }
+impl FromStr for Status {
+    type Err = anyhow::Error;
 
-    #[test]
-    fn test_status_from_string_completed() {
-        let status = Status::from_str_anyhow("Completed").unwrap();
-        assert_eq!(status, Status::Completed);
-    }
-
-    #[test]
-    fn test_status_from_filter_value_pending() {
-        let status = Status::from_filter_value("pending").unwrap();
-        assert_eq!(status, Status::Pending);
-    }
-
-    #[test]
-    fn test_status_from_filter_value_todo() {
-        let status = Status::from_filter_value("todo").unwrap();
-        assert_eq!(status, Status::Pending);
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "pending" | "todo" => Ok(Status::Pending),
+            "in_progress" | "inprogress" | "progress" => Ok(Status::InProgress),
+            "completed" | "done" => Ok(Status::Completed),
+            _ => anyhow::bail!(
+                "Invalid status: '{}'. Use pending, in_progress or completed",
+                s
+            ),
+        }
     }
+}
 
-    #[test]
-    fn test_status_from_filter_value_in_progress() {
-        let status = Status::from_filter_value("in_progress").unwrap();
-        assert_eq!(status, Status::InProgress);
+impl Serialize for Status {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
     }
+}
 
-    #[test]
-    fn test_status_from_filter_value_progress() {
-        let status = Status::from_filter_value("progress").unwrap();
-        assert_eq!(status, Status::InProgress);
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
     }
+}
 
-    #[test]
-    fn test_status_from_filter_value_completed() {
-        let status = Status::from_filter_value("completed").unwrap();
-        assert_eq!(status, Status::Completed);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::arbitrary::status;
+    use proptest::prelude::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_status_from_str() {
+        for (input, expected) in [
+            ("pending", Status::Pending),
+            ("todo", Status::Pending),
+            ("in_progress", Status::InProgress),
+            ("progress", Status::InProgress),
+            ("completed", Status::Completed),
+            ("done", Status::Completed),
+        ] {
+            assert_eq!(input.parse::<Status>().unwrap(), expected);
+        }
     }
 
     #[test]
-    fn test_status_from_filter_value_done() {
-        let status = Status::from_filter_value("done").unwrap();
-        assert_eq!(status, Status::Completed);
+    fn test_status_from_str_ignores_case_and_accepts_legacy_names() {
+        assert_eq!("Pending".parse::<Status>().unwrap(), Status::Pending);
+        assert_eq!("InProgress".parse::<Status>().unwrap(), Status::InProgress);
+        assert_eq!("DONE".parse::<Status>().unwrap(), Status::Completed);
     }
 
     #[test]
-    fn test_status_from_filter_value_invalid() {
-        let result = Status::from_filter_value("invalid");
-        assert!(result.is_err());
+    fn test_status_from_str_invalid() {
+        let error = "invalid".parse::<Status>().unwrap_err();
+        assert!(error.to_string().contains("Invalid status: 'invalid'"));
+        assert!("".parse::<Status>().is_err());
     }
 
     #[test]
     fn test_status_to_string() {
-        assert_eq!(Status::Pending.as_str(), "Pending");
-        assert_eq!(Status::InProgress.as_str(), "InProgress");
-        assert_eq!(Status::Completed.as_str(), "Completed");
+        assert_eq!(Status::Pending.to_string(), "pending");
+        assert_eq!(Status::InProgress.to_string(), "in_progress");
+        assert_eq!(Status::Completed.as_str(), "completed");
     }
 
     #[test]
@@ -153,15 +137,50 @@ mod tests {
     }
 
     #[test]
-    fn test_status_serialize() {
-        let status = Status::Pending;
-        let json = serde_json::to_string(&status).unwrap();
-        assert_eq!(json, "\"Pending\"");
+    fn test_status_round_trip() {
+        for status in Status::iter() {
+            // Display ⇔ FromStr
+            assert_eq!(status.to_string().parse::<Status>().unwrap(), status);
+            // Serialize ⇔ Deserialize（文字列はDisplayと同じ）
+            let json = serde_json::to_string(&status).unwrap();
+            assert_eq!(json, format!("\"{}\"", status));
+            assert_eq!(serde_json::from_str::<Status>(&json).unwrap(), status);
+        }
     }
 
     #[test]
     fn test_status_deserialize() {
-        let status: Status = serde_json::from_str("\"Pending\"").unwrap();
-        assert_eq!(status, Status::Pending);
+        let status: Status = serde_json::from_str("\"done\"").unwrap();
+        assert_eq!(status, Status::Completed);
+        // 以前の形式も読み込める
+        let legacy: Status = serde_json::from_str("\"Pending\"").unwrap();
+        assert_eq!(legacy, Status::Pending);
+        assert!(serde_json::from_str::<Status>("\"archived\"").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_status_parse_ignores_case_and_surrounding_whitespace(
+            status in status(),
+            upper in any::<bool>(),
+            padding in "[ \\t]{0,2}",
+        ) {
+            // Arrange
+            let text = if upper { status.as_str().to_uppercase() } else { status.to_string() };
+
+            // Act
+            let parsed = format!("{padding}{text}{padding}").parse::<Status>().unwrap();
+
+            // Assert
+            prop_assert_eq!(parsed, status);
+        }
+
+        #[test]
+        fn test_status_parse_result_round_trips(value in "\\PC{0,12}") {
+            // Act & Assert: 読み込めた文字列は、表示形式に変換しても同じ値に戻る
+            if let Ok(parsed) = value.parse::<Status>() {
+                prop_assert_eq!(parsed.to_string().parse::<Status>().unwrap(), parsed);
+            }
+        }
     }
 }
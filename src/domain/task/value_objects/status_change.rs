@@ -0,0 +1,39 @@
+use crate::domain::task::value_objects::Status;
+use chrono::{DateTime, Utc};
+
+/// タスクのステータスの変更1回分を表すValue Object
+///
+/// TaskStatusChangedイベントの発行時に集約が履歴として記録し、タスクと一緒に永続化されます。
+/// フロー統計（ステータスごとの滞在時間・サイクルタイム）の計算に使用します。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusChange {
+    from: Status,
+    to: Status,
+    changed_at: DateTime<Utc>,
+}
+
+impl StatusChange {
+    /// 新しいStatusChangeを作成
+    pub fn new(from: Status, to: Status, changed_at: DateTime<Utc>) -> Self {
+        Self {
+            from,
+            to,
+            changed_at,
+        }
+    }
+
+    /// 変更前のステータスを取得
+    pub fn from(&self) -> Status {
+        self.from
+    }
+
+    /// 変更後のステータスを取得
+    pub fn to(&self) -> Status {
+        self.to
+    }
+
+    /// 変更した日時を取得
+    pub fn changed_at(&self) -> &DateTime<Utc> {
+        &self.changed_at
+    }
+}
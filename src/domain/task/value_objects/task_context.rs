@@ -0,0 +1,90 @@
+use anyhow::Result;
+use std::{fmt, str::FromStr};
+
+/// タスクを実行できる状況（GTDのコンテキスト）を表すValue Object
+///
+/// `@home`・`@office`のように`@`で始まる名前で表します。
+/// 入力時の`@`は省略でき、大文字小文字は区別しません（小文字で保持します）。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TaskContext(String);
+
+impl TaskContext {
+    /// `@`を除いた名前の最大文字数
+    const MAX_LENGTH: usize = 30;
+
+    /// 新しいTaskContextを作成
+    pub fn new(value: impl AsRef<str>) -> Result<Self> {
+        let value = value.as_ref().trim();
+        let name = value.strip_prefix('@').unwrap_or(value).to_lowercase();
+        if name.is_empty() {
+            anyhow::bail!("Context cannot be empty");
+        }
+        if name.chars().count() > Self::MAX_LENGTH {
+            anyhow::bail!(
+                "Context must be {} characters or less (excluding '@')",
+                Self::MAX_LENGTH
+            );
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            anyhow::bail!(
+                "Invalid context '{}'. Use letters, digits, '-' and '_' only (e.g. @home)",
+                value
+            );
+        }
+        Ok(Self(format!("@{}", name)))
+    }
+
+    /// `@`を含む名前を取得
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TaskContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for TaskContext {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_context_normalizes_prefix_and_case() {
+        for input in ["@home", "home", " @Home ", "HOME"] {
+            assert_eq!(TaskContext::new(input).unwrap().value(), "@home");
+        }
+        assert_eq!(TaskContext::new("@自宅").unwrap().to_string(), "@自宅");
+        assert_eq!(
+            TaskContext::new("deep_work-1").unwrap().value(),
+            "@deep_work-1"
+        );
+    }
+
+    #[test]
+    fn test_task_context_invalid() {
+        for input in [
+            "",
+            "@",
+            "  ",
+            "@home office",
+            "@home@",
+            "a/b",
+            &"x".repeat(31),
+        ] {
+            assert!(TaskContext::new(input).is_err(), "input: {}", input);
+        }
+    }
+}
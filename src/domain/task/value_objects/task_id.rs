@@ -2,15 +2,16 @@ use anyhow::Result;
 
 /// タスクIDを表すValue Object
 ///
-/// タスクIDは正の整数として扱われます。
+/// タスクIDは正の整数として扱われます。IDはリポジトリが保存時に割り当てるため、
+/// 保存前のタスク（[`NewTask`](crate::domain::task::aggregate::NewTask)）はIDを持ちません。
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TaskId(i32);
 
 impl TaskId {
     /// 新しいTaskIdを作成
     pub fn new(value: i32) -> Result<Self> {
-        if value < 0 {
-            anyhow::bail!("Task ID must be 0 or greater");
+        if value <= 0 {
+            anyhow::bail!("Task ID must be greater than 0");
         }
         Ok(Self(value))
     }
@@ -34,8 +35,8 @@ mod tests {
 
     #[test]
     fn test_task_id_zero() {
-        let id = TaskId::new(0).unwrap();
-        assert_eq!(id.value(), 0);
+        let result = TaskId::new(0);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -46,7 +47,7 @@ mod tests {
             result
                 .unwrap_err()
                 .to_string()
-                .contains("Task ID must be 0 or greater")
+                .contains("Task ID must be greater than 0")
         );
     }
 
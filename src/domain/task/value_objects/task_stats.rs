@@ -1,5 +1,7 @@
 use crate::domain::tag::value_objects::TagId;
-use crate::domain::task::value_objects::{DueDateStatus, Priority, Status};
+use crate::domain::task::value_objects::{
+    Assignee, DueDateBuckets, DueDateStatus, Priority, Status,
+};
 use std::collections::HashMap;
 
 /// タスクの統計情報を表すValue Object
@@ -19,6 +21,10 @@ pub struct TaskStats {
     priority_status_matrix: HashMap<(Priority, Status), usize>,
     /// 全体統計
     total_count: usize,
+    /// 期限関連統計の分類に使用した境界
+    due_date_buckets: DueDateBuckets,
+    /// 担当者×ステータス クロス集計（None: 担当者なし）
+    assignee_status_matrix: HashMap<(Option<Assignee>, Status), usize>,
 }
 
 impl TaskStats {
@@ -38,9 +44,31 @@ impl TaskStats {
             tag_stats,
             priority_status_matrix,
             total_count,
+            due_date_buckets: DueDateBuckets::default(),
+            assignee_status_matrix: HashMap::new(),
         }
     }
 
+    /// 期限関連統計の分類に使用した境界を設定
+    pub fn with_due_date_buckets(mut self, buckets: DueDateBuckets) -> Self {
+        self.due_date_buckets = buckets;
+        self
+    }
+
+    /// 担当者×ステータスのクロス集計を設定
+    pub fn with_assignee_status_matrix(
+        mut self,
+        matrix: HashMap<(Option<Assignee>, Status), usize>,
+    ) -> Self {
+        self.assignee_status_matrix = matrix;
+        self
+    }
+
+    /// 期限関連統計の分類に使用した境界を取得
+    pub fn due_date_buckets(&self) -> DueDateBuckets {
+        self.due_date_buckets
+    }
+
     /// 総タスク数を取得
     pub fn total_count(&self) -> usize {
         self.total_count
@@ -76,6 +104,11 @@ impl TaskStats {
     pub fn tag_stats(&self) -> &HashMap<Option<TagId>, usize> {
         &self.tag_stats
     }
+
+    /// 担当者×ステータスのクロス集計の生のHashMapを取得
+    pub fn assignee_status_matrix(&self) -> &HashMap<(Option<Assignee>, Status), usize> {
+        &self.assignee_status_matrix
+    }
 }
 
 // テストのみを先に作成（TDD）
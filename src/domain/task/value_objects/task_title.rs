@@ -15,7 +15,8 @@ impl TaskTitle {
             anyhow::bail!("Title cannot be empty");
         }
 
-        if value.len() > 100 {
+        // バイト数ではなく文字数で数える（日本語のタイトルも100文字まで）
+        if value.chars().count() > 100 {
             anyhow::bail!("Title must be 100 characters or less");
         }
 
@@ -32,6 +33,7 @@ impl TaskTitle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_task_title_valid() {
@@ -74,6 +76,13 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("100 characters"));
     }
 
+    #[test]
+    fn test_task_title_max_length_counts_characters() {
+        // 日本語は1文字3バイトだが、文字数で上限を判定する
+        assert!(TaskTitle::new("あ".repeat(100)).is_ok());
+        assert!(TaskTitle::new("あ".repeat(101)).is_err());
+    }
+
     #[test]
     fn test_task_title_trim() {
         // 先頭・末尾の空白は保持される
@@ -94,4 +103,23 @@ mod tests {
         let title2 = title1.clone();
         assert_eq!(title1, title2);
     }
+
+    proptest! {
+        #[test]
+        fn test_task_title_accepts_exactly_non_blank_titles_up_to_100_characters(
+            value in prop_oneof!["\\PC{0,120}", "[ a]{0,3}", "[aあ]{98,102}"]
+        ) {
+            // Arrange
+            let expected_valid = !value.trim().is_empty() && value.chars().count() <= 100;
+
+            // Act
+            let result = TaskTitle::new(value.clone());
+
+            // Assert: 有効なタイトルは入力をそのまま保持する
+            prop_assert_eq!(result.is_ok(), expected_valid);
+            if let Ok(title) = result {
+                prop_assert_eq!(title.value(), value.as_str());
+            }
+        }
+    }
 }
@@ -0,0 +1,3 @@
+pub mod aggregate;
+pub mod repository;
+pub mod value_objects;
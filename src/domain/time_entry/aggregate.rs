@@ -0,0 +1,143 @@
+use crate::domain::{task::value_objects::TaskId, time_entry::value_objects::TimeEntryId};
+use anyhow::Result;
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// TimeEntryAggregate の再構築用パラメータ
+#[derive(Debug)]
+pub struct TimeEntryReconstructParams {
+    pub id: TimeEntryId,
+    pub task_id: TaskId,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+}
+
+/// NewTimeEntry - まだ保存されていない作業記録
+///
+/// IDはリポジトリ（データベース）が保存時に割り当てるため、保存前の作業記録はIDを持ちません。
+/// リポジトリは割り当てたIDで[`NewTimeEntry::into_aggregate`]を呼び出し、TimeEntryAggregateに変換します。
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewTimeEntry {
+    task_id: TaskId,
+    started_at: DateTime<Utc>,
+    ended_at: DateTime<Utc>,
+}
+
+impl NewTimeEntry {
+    /// 新しい作業記録を作成します
+    ///
+    /// 終了日時が開始日時より前の場合はエラーになります。
+    pub fn new(
+        task_id: TaskId,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+    ) -> Result<Self> {
+        if ended_at < started_at {
+            anyhow::bail!("Time entry must not end before it starts");
+        }
+
+        Ok(Self {
+            task_id,
+            started_at,
+            ended_at,
+        })
+    }
+
+    /// リポジトリが割り当てたIDでTimeEntryAggregateに変換します
+    pub fn into_aggregate(self, id: TimeEntryId) -> TimeEntryAggregate {
+        TimeEntryAggregate {
+            id,
+            task_id: self.task_id,
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+        }
+    }
+
+    pub fn task_id(&self) -> &TaskId {
+        &self.task_id
+    }
+
+    pub fn started_at(&self) -> &DateTime<Utc> {
+        &self.started_at
+    }
+
+    pub fn ended_at(&self) -> &DateTime<Utc> {
+        &self.ended_at
+    }
+}
+
+/// TimeEntryAggregate - タスクに費やした作業時間の記録
+///
+/// ポモドーロの作業フェーズなど、開始から終了までの1区間を表します。
+/// 新しい作業記録は[`NewTimeEntry`]として作成し、リポジトリで保存してIDを割り当てます。
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeEntryAggregate {
+    id: TimeEntryId,
+    task_id: TaskId,
+    started_at: DateTime<Utc>,
+    ended_at: DateTime<Utc>,
+}
+
+impl TimeEntryAggregate {
+    /// リポジトリからの再構築用ファクトリメソッド
+    pub fn reconstruct(params: TimeEntryReconstructParams) -> Self {
+        Self {
+            id: params.id,
+            task_id: params.task_id,
+            started_at: params.started_at,
+            ended_at: params.ended_at,
+        }
+    }
+
+    /// 作業時間
+    pub fn duration(&self) -> TimeDelta {
+        self.ended_at - self.started_at
+    }
+
+    pub fn id(&self) -> &TimeEntryId {
+        &self.id
+    }
+
+    pub fn task_id(&self) -> &TaskId {
+        &self.task_id
+    }
+
+    pub fn started_at(&self) -> &DateTime<Utc> {
+        &self.started_at
+    }
+
+    pub fn ended_at(&self) -> &DateTime<Utc> {
+        &self.ended_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_time_entry() {
+        // Arrange
+        let started_at = Utc::now();
+        let ended_at = started_at + TimeDelta::minutes(25);
+
+        // Act
+        let entry = NewTimeEntry::new(TaskId::new(1).unwrap(), started_at, ended_at)
+            .unwrap()
+            .into_aggregate(TimeEntryId::new(7).unwrap());
+
+        // Assert
+        assert_eq!(entry.id().value(), 7);
+        assert_eq!(entry.task_id().value(), 1);
+        assert_eq!(entry.duration(), TimeDelta::minutes(25));
+    }
+
+    #[test]
+    fn test_new_time_entry_rejects_end_before_start() {
+        let started_at = Utc::now();
+        let ended_at = started_at - TimeDelta::minutes(1);
+
+        let result = NewTimeEntry::new(TaskId::new(1).unwrap(), started_at, ended_at);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,41 @@
+use crate::domain::{
+    task::value_objects::TaskId,
+    time_entry::aggregate::{NewTimeEntry, TimeEntryAggregate},
+};
+use anyhow::Result;
+
+/// TimeEntryRepository trait - 作業記録の永続化を抽象化
+///
+/// DIP（依存性逆転の原則）に従い、ドメイン層にRepository traitを定義します。
+/// 実装はInterface層（src/interface/persistence/）で行います。
+#[async_trait::async_trait]
+pub trait TimeEntryRepository: Send + Sync {
+    /// 新しい作業記録を保存
+    ///
+    /// IDはリポジトリ（データベース）が割り当てます。
+    ///
+    /// # Arguments
+    /// * `entry` - 保存する作業記録
+    ///
+    /// # Returns
+    /// * `Ok(TimeEntryAggregate)` - 保存された作業記録（IDが割り当てられている）
+    /// * `Err` - エラーが発生した場合
+    async fn save(&self, entry: NewTimeEntry) -> Result<TimeEntryAggregate>;
+
+    /// タスクの作業記録を取得
+    ///
+    /// # Arguments
+    /// * `task_id` - 対象のタスクID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TimeEntryAggregate>)` - 作業記録のリスト（開始日時の古い順）
+    /// * `Err` - エラーが発生した場合
+    async fn find_by_task(&self, task_id: &TaskId) -> Result<Vec<TimeEntryAggregate>>;
+
+    /// すべての作業記録を取得
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TimeEntryAggregate>)` - 作業記録のリスト（開始日時の古い順）
+    /// * `Err` - エラーが発生した場合
+    async fn find_all(&self) -> Result<Vec<TimeEntryAggregate>>;
+}
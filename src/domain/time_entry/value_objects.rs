@@ -0,0 +1,3 @@
+pub mod time_entry_id;
+
+pub use time_entry_id::TimeEntryId;
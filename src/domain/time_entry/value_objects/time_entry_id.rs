@@ -0,0 +1,39 @@
+use anyhow::Result;
+
+/// 作業記録のIDを表すValue Object
+///
+/// IDは0以上の整数です。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeEntryId(i32);
+
+impl TimeEntryId {
+    /// 新しいTimeEntryIdを作成
+    pub fn new(value: i32) -> Result<Self> {
+        if value < 0 {
+            anyhow::bail!("Time entry ID must be 0 or greater");
+        }
+        Ok(Self(value))
+    }
+
+    /// IDの値を取得
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_entry_id_valid() {
+        let id = TimeEntryId::new(1).unwrap();
+        assert_eq!(id.value(), 1);
+    }
+
+    #[test]
+    fn test_time_entry_id_negative() {
+        let result = TimeEntryId::new(-1);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::domain::{tag::repository::TagRepository, task::repository::TaskRepository};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// UnitOfWork trait - 複数の集約にまたがる変更を1つのトランザクションにまとめる
+///
+/// `task_repository()`/`tag_repository()`で取得したリポジトリを通じた変更は、
+/// `commit()`で一括して確定し、`rollback()`で一括して取り消されます。
+/// コミットせずに破棄した場合もロールバックされます。
+#[async_trait::async_trait]
+pub trait UnitOfWork: Send + Sync {
+    /// このトランザクションに参加するTaskRepositoryを取得
+    fn task_repository(&self) -> Arc<dyn TaskRepository>;
+
+    /// このトランザクションに参加するTagRepositoryを取得
+    fn tag_repository(&self) -> Arc<dyn TagRepository>;
+
+    /// 変更を確定する
+    ///
+    /// # Returns
+    /// * `Ok(())` - コミットに成功した場合
+    /// * `Err` - 取得したリポジトリがまだ使用中の場合、またはコミットに失敗した場合
+    async fn commit(self: Box<Self>) -> Result<()>;
+
+    /// 変更を取り消す
+    async fn rollback(self: Box<Self>) -> Result<()>;
+}
+
+/// UnitOfWorkFactory trait - トランザクションを開始してUnitOfWorkを生成
+#[async_trait::async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    /// 新しいトランザクションを開始
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>>;
+}
+
+/// 処理結果に応じてコミットまたはロールバックする
+///
+/// 処理が成功した場合はコミットし、失敗した場合はロールバックして元のエラーを返します。
+///
+/// # Arguments
+/// * `uow` - 処理に使用したUnitOfWork
+/// * `result` - トランザクション内で実行した処理の結果
+pub async fn commit_or_rollback<T>(uow: Box<dyn UnitOfWork>, result: Result<T>) -> Result<T> {
+    match result {
+        Ok(value) => {
+            uow.commit().await?;
+            Ok(value)
+        }
+        Err(e) => {
+            if let Err(rollback_err) = uow.rollback().await {
+                return Err(e.context(format!("Rollback failed: {}", rollback_err)));
+            }
+            Err(e)
+        }
+    }
+}
@@ -1,5 +1,8 @@
+pub mod calendar;
 pub mod config;
 pub mod database;
+pub mod logging;
 
-pub use config::load_config;
+pub use config::{get_config_path, load_config};
 pub use database::DatabaseConnectionManager;
+pub use logging::{LogVerbosity, init_logging};
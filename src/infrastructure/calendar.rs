@@ -0,0 +1,3 @@
+pub mod holiday_file;
+
+pub use holiday_file::load_holidays;
@@ -0,0 +1,155 @@
+use anyhow::{Context, Result};
+use chrono::{Days, NaiveDate};
+use std::{fs, path::Path};
+
+/// iCalendar（.ics）ファイルから祝日の日付を読み込む
+///
+/// 各予定（`VEVENT`）の開始日を祝日として扱います。終日の予定で終了日（`DTEND`、その日を含まない）が
+/// 指定されている場合は、開始日から終了日の前日までのすべての日付を祝日にします。
+pub fn load_holidays(path: &Path) -> Result<Vec<NaiveDate>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read holidays file {}", path.display()))?;
+    parse_holidays(&content).with_context(|| format!("Invalid holidays file {}", path.display()))
+}
+
+/// iCalendarの文字列から祝日の日付を読み取る
+fn parse_holidays(content: &str) -> Result<Vec<NaiveDate>> {
+    let mut holidays = Vec::new();
+    let mut in_event = false;
+    let mut start: Option<NaiveDate> = None;
+    let mut end: Option<NaiveDate> = None;
+
+    for line in unfold_lines(content) {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        // `DTSTART;VALUE=DATE`のようなパラメータは読み飛ばす
+        let name = name
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        match name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VEVENT") => {
+                in_event = true;
+                start = None;
+                end = None;
+            }
+            "END" if value.eq_ignore_ascii_case("VEVENT") => {
+                in_event = false;
+                let Some(start) = start else {
+                    continue;
+                };
+                holidays.push(start);
+                if let Some(end) = end {
+                    let mut date = start;
+                    while let Some(next) = date.checked_add_days(Days::new(1))
+                        && next < end
+                    {
+                        holidays.push(next);
+                        date = next;
+                    }
+                }
+            }
+            "DTSTART" if in_event => start = Some(parse_date(value)?),
+            // 時刻付きの終了日時は、その日を含む1日の予定とみなす
+            "DTEND" if in_event && value.len() == 8 => end = Some(parse_date(value)?),
+            _ => {}
+        }
+    }
+
+    holidays.sort_unstable();
+    holidays.dedup();
+    Ok(holidays)
+}
+
+/// 折り返された行（次の行が空白で始まる）を1行につなげる
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(line.trim_end().to_string()),
+        }
+    }
+    lines
+}
+
+/// `20260101`または`20260101T090000Z`形式の値から日付を読み取る
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    value
+        .get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .with_context(|| format!("Invalid date in holidays file: {}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_holidays_all_day_events() {
+        // Arrange
+        let content = "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20260101\r\n\
+            DTEND;VALUE=DATE:20260102\r\n\
+            SUMMARY:New Year's Day\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20260429\r\n\
+            SUMMARY:Showa Day\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+
+        // Act
+        let holidays = parse_holidays(content).unwrap();
+
+        // Assert
+        assert_eq!(holidays, vec![date(1, 1), date(4, 29)]);
+    }
+
+    #[test]
+    fn test_parse_holidays_multi_day_event() {
+        let content = "BEGIN:VEVENT\n\
+            DTSTART;VALUE=DATE:20260503\n\
+            DTEND;VALUE=DATE:20260506\n\
+            END:VEVENT\n";
+
+        let holidays = parse_holidays(content).unwrap();
+
+        assert_eq!(holidays, vec![date(5, 3), date(5, 4), date(5, 5)]);
+    }
+
+    #[test]
+    fn test_parse_holidays_timed_event_and_folded_lines() {
+        let content = "BEGIN:VEVENT\n\
+            SUMMARY:Company\n  holiday\n\
+            DTSTART:20260814T000000Z\n\
+            DTEND:20260815T000000Z\n\
+            END:VEVENT\n";
+
+        let holidays = parse_holidays(content).unwrap();
+
+        assert_eq!(holidays, vec![date(8, 14)]);
+    }
+
+    #[test]
+    fn test_parse_holidays_invalid_date() {
+        let content = "BEGIN:VEVENT\nDTSTART;VALUE=DATE:2026-01-01\nEND:VEVENT\n";
+
+        assert!(parse_holidays(content).is_err());
+    }
+
+    #[test]
+    fn test_load_holidays_missing_file() {
+        let error = load_holidays(Path::new("/nonexistent/holidays.ics")).unwrap_err();
+
+        assert!(error.to_string().contains("Failed to read holidays file"));
+    }
+}
@@ -1,3 +1,7 @@
 pub mod app_config;
 
-pub use app_config::{Config, load_config};
+pub use app_config::{
+    CalendarConfig, Config, DefaultView, DisplayConfig, DueDateConfig, ListConfig, LoggingConfig,
+    OverdueConfig, RemoteKind, SqliteConfig, StaleConfig, StorageBackend, UrgencyConfig,
+    WorkflowConfig, get_config_path, load_config,
+};
@@ -1,25 +1,705 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     fs,
     path::{Path, PathBuf},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
+    #[serde(default)]
     pub storage: StorageConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub tags: TagsConfig,
+    #[serde(default)]
+    pub list: ListConfig,
+    #[serde(default)]
+    pub logging: LoggingConfig,
+    #[serde(default)]
+    pub workflow: WorkflowConfig,
+    #[serde(default)]
+    pub due_date: DueDateConfig,
+    #[serde(default)]
+    pub duplicates: DuplicatesConfig,
+    #[serde(default)]
+    pub daemon: DaemonConfig,
+    #[serde(default)]
+    pub github: GithubConfig,
+    #[serde(default)]
+    pub cron: CronConfig,
+    #[serde(default)]
+    pub workload: WorkloadConfig,
+    #[serde(default)]
+    pub urgency: UrgencyConfig,
+    #[serde(default)]
+    pub context: ContextConfig,
+    #[serde(default)]
+    pub stale: StaleConfig,
+    #[serde(default)]
+    pub calendar: CalendarConfig,
+    #[serde(default)]
+    pub overdue: OverdueConfig,
+    #[serde(default)]
+    pub focus: FocusConfig,
+    #[serde(default)]
+    pub mail: MailConfig,
+    #[serde(default)]
+    pub remote: RemoteConfig,
+    #[serde(default)]
+    pub audit: AuditConfig,
 }
 
+/// 保存先に関する設定（`[storage]`セクション）
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct StorageConfig {
+    /// 保存先のバックエンド
+    pub backend: StorageBackend,
+    /// SQLiteバックエンドのデータベースURL
     pub database_url: String,
+    /// JSONバックエンドのファイルパス
+    pub json_path: PathBuf,
+    /// 起動時に未適用のマイグレーションを自動で適用するか（falseの場合は`yaru db migrate`で適用）
+    pub auto_migrate: bool,
+    /// SQLiteの接続の設定（`[storage.sqlite]`）
+    pub sqlite: SqliteConfig,
+    /// 読み取り専用で開くか（trueの場合はデータベースを変更するコマンドをエラーにする。`--read-only`と同じ）
+    pub read_only: bool,
 }
 
 impl Default for StorageConfig {
     fn default() -> Self {
         Self {
+            backend: StorageBackend::default(),
             database_url: get_default_database_url()
                 .unwrap_or_else(|_| "sqlite://yaru.db?mode=rwc".to_string()),
+            json_path: get_default_json_path().unwrap_or_else(|_| PathBuf::from("yaru.json")),
+            auto_migrate: true,
+            sqlite: SqliteConfig::default(),
+            read_only: false,
+        }
+    }
+}
+
+/// SQLiteの接続に関する設定（`[storage.sqlite]`セクション）
+///
+/// 連続して実行するコマンドやTUIとCLIの同時使用でロックの競合が起きにくいよう、
+/// 既定ではWALモードを使い、ロック中は`busy_timeout_ms`まで待ちます。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SqliteConfig {
+    /// ジャーナルモード（`wal`, `delete`, `truncate`, `persist`, `memory`, `off`）
+    pub journal_mode: String,
+    /// 同期モード（`off`, `normal`, `full`, `extra`）
+    pub synchronous: String,
+    /// ロックの解除を待つ時間（ミリ秒）
+    pub busy_timeout_ms: u64,
+    /// コネクションプールの最大接続数
+    pub max_connections: u32,
+    /// 接続ごとにキャッシュするプリペアドステートメントの数
+    pub statement_cache_capacity: usize,
+}
+
+impl Default for SqliteConfig {
+    fn default() -> Self {
+        Self {
+            journal_mode: "wal".to_string(),
+            synchronous: "normal".to_string(),
+            busy_timeout_ms: 5000,
+            max_connections: 4,
+            statement_cache_capacity: 100,
+        }
+    }
+}
+
+/// 保存先のバックエンド
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// SQLiteデータベース
+    #[default]
+    Sqlite,
+    /// JSONファイル（Dropboxやgitでの同期向け）
+    Json,
+}
+
+/// 表示に関する設定（`[display]`セクション）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// 表示言語（`auto`, `en`, `ja`）。`auto`の場合は`LANG`などの環境変数から決定
+    pub language: String,
+    /// 日付の表示形式（strftime形式、例: `%Y/%m/%d`）
+    pub date_format: String,
+    /// 日時を表示するタイムゾーン（`local`, `utc`、または`+09:00`形式のオフセット）
+    pub timezone: String,
+    /// カレンダー（期限日の選択・TUIのカレンダー）の週の始まりの曜日（`monday`, `sunday`など）
+    pub week_start: String,
+    /// サブコマンドなしで起動した場合の表示（`tui`, `today`, `agenda`）
+    pub default_view: DefaultView,
+    pub theme: ThemeConfig,
+}
+
+/// サブコマンドなしで起動した場合の表示
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultView {
+    /// TUIを起動
+    #[default]
+    Tui,
+    /// `yaru today`と同じ表示
+    Today,
+    /// `yaru agenda`と同じ表示
+    Agenda,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            language: "auto".to_string(),
+            date_format: "%Y-%m-%d".to_string(),
+            timezone: "local".to_string(),
+            week_start: "monday".to_string(),
+            default_view: DefaultView::default(),
+            theme: ThemeConfig::default(),
+        }
+    }
+}
+
+/// テーブル表示の配色設定（`[display.theme]`セクション）
+///
+/// 色は`red`, `dark_yellow`, `orange`などの色名、または`#RRGGBB`形式で指定します。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// 色付け表示を有効にするか
+    pub enabled: bool,
+    /// 優先度Criticalの色
+    pub critical: String,
+    /// 優先度Highの色
+    pub high: String,
+    /// 優先度Mediumの色
+    pub medium: String,
+    /// 優先度Lowの色
+    pub low: String,
+    /// 期限切れの期限日の色
+    pub overdue: String,
+    /// 今日期限の期限日の色
+    pub due_today: String,
+    /// 期限が近い（`[due_date]`の`soon_days`日以内）期限日の色
+    pub due_soon: String,
+    /// 完了済みタスクの行を薄く表示するか
+    pub dim_completed: bool,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            critical: "red".to_string(),
+            high: "orange".to_string(),
+            medium: "yellow".to_string(),
+            low: "green".to_string(),
+            overdue: "red".to_string(),
+            due_today: "yellow".to_string(),
+            due_soon: "cyan".to_string(),
+            dim_completed: true,
+        }
+    }
+}
+
+/// タグに関する設定（`[tags]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TagsConfig {
+    /// タグ名指定時に存在しないタグを自動作成するか
+    pub auto_create: bool,
+}
+
+impl Default for TagsConfig {
+    fn default() -> Self {
+        Self { auto_create: true }
+    }
+}
+
+/// `task list`の既定の表示に関する設定（`[list]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListConfig {
+    /// 並び順（`id`, `title`, `priority`, `due`, `created`, `updated`, `urgency`）
+    pub sort: String,
+    /// 絞り込み条件（`--filter`と同じ`key:value`形式）
+    pub filters: Vec<String>,
+    /// 表示する列（空の場合は表示形式の既定の列）
+    pub columns: Vec<String>,
+    /// 完了済みタスクを既定で非表示にするか（`--all`で表示）
+    pub hide_completed: bool,
+    /// 名前を付けたテンプレート（`[list.templates]`、`--template`に名前で指定）
+    pub templates: BTreeMap<String, String>,
+}
+
+impl Default for ListConfig {
+    fn default() -> Self {
+        Self {
+            sort: "id".to_string(),
+            filters: vec![],
+            columns: vec![],
+            hide_completed: true,
+            templates: BTreeMap::new(),
+        }
+    }
+}
+
+/// ステータス遷移に関する設定（`[workflow]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkflowConfig {
+    /// 完了済みのタスクを未完了に戻すときに`--reopen`を必須にするか
+    pub require_reopen: bool,
+    /// 禁止するステータス遷移（`pending->completed`形式）
+    pub forbidden_transitions: Vec<String>,
+}
+
+impl Default for WorkflowConfig {
+    fn default() -> Self {
+        Self {
+            require_reopen: true,
+            forbidden_transitions: vec![],
+        }
+    }
+}
+
+/// 期限日に関する設定（`[due_date]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DueDateConfig {
+    /// 新しいタスクに過去の期限日を指定した場合の扱い（`allow`, `warn`, `reject`）
+    pub past_dates: String,
+    /// 期限が近いとみなす日数（一覧の色付けや統計の「期限が近い」の区切り）
+    pub soon_days: u32,
+    /// 期限が迫りつつあるとみなす日数（`soon_days`以上）
+    pub upcoming_days: u32,
+}
+
+impl Default for DueDateConfig {
+    fn default() -> Self {
+        Self {
+            past_dates: "allow".to_string(),
+            soon_days: 7,
+            upcoming_days: 30,
+        }
+    }
+}
+
+/// 重複タスクの検出に関する設定（`[duplicates]`セクション）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DuplicatesConfig {
+    /// タスクの追加時に、タイトルが似ている未完了タスクを確認するか
+    pub check: bool,
+    /// 似ていると判定する類似度（0.0〜1.0）
+    pub threshold: f64,
+}
+
+impl Default for DuplicatesConfig {
+    fn default() -> Self {
+        Self {
+            check: false,
+            threshold: 0.8,
+        }
+    }
+}
+
+/// 作業量の表示に関する設定（`[workload]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkloadConfig {
+    /// 1日の作業可能時間（`8h`, `6h30m`など。見積もりの合計がこれを超える日を過負荷とする）
+    pub daily_capacity: String,
+}
+
+impl Default for WorkloadConfig {
+    fn default() -> Self {
+        Self {
+            daily_capacity: "8h".to_string(),
+        }
+    }
+}
+
+/// 緊急度スコアの重みに関する設定（`[urgency]`セクション）
+///
+/// `task list --sort urgency`とTUIの並び順に使用します。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrgencyConfig {
+    /// 優先度の重み
+    pub priority: f64,
+    /// 期限日の近さの重み
+    pub due: f64,
+    /// 作成からの経過日数の重み
+    pub age: f64,
+    /// スヌーズ中（着手できない）のタスクに加える重み
+    pub blocked: f64,
+}
+
+impl Default for UrgencyConfig {
+    fn default() -> Self {
+        Self {
+            priority: 6.0,
+            due: 12.0,
+            age: 2.0,
+            blocked: -5.0,
+        }
+    }
+}
+
+/// 放置されているタスクの判定に関する設定（`[stale]`セクション）
+///
+/// `task stale`と一覧の`stale`列に使用します。0日を指定したステータスは判定しません。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StaleConfig {
+    /// 保留中のタスクを放置とみなす、更新されていない日数
+    pub pending_days: u32,
+    /// 進行中のタスクを放置とみなす、更新されていない日数
+    pub in_progress_days: u32,
+}
+
+impl Default for StaleConfig {
+    fn default() -> Self {
+        Self {
+            pending_days: 30,
+            in_progress_days: 14,
+        }
+    }
+}
+
+/// 営業日のカレンダーに関する設定（`[calendar]`セクション）
+///
+/// 土日と`holidays`・`holidays_file`の祝日を休日として扱います。
+/// 営業日単位の期限日（`@+3bd`）は`business_days`に関わらず使用できます。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalendarConfig {
+    /// 繰り返しのタスクの期限日と期限切れの判定で休日を飛ばすか
+    pub business_days: bool,
+    /// 祝日（YYYY-MM-DD形式）
+    pub holidays: Vec<String>,
+    /// 祝日を読み込むiCalendar（.ics）ファイル（終日の予定の日付を祝日として扱う）
+    pub holidays_file: Option<PathBuf>,
+}
+
+/// 期限切れのタスクに関する設定（`[overdue]`セクション）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverdueConfig {
+    /// 期限切れのタスクに自動で付けるタグ（Noneの場合は付けない）
+    ///
+    /// `task list`と`cron run`の実行時に付け外しします。
+    pub tag: Option<String>,
+}
+
+/// コンテキスト（`yaru context`）に関する設定（`[context]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContextConfig {
+    /// 現在のコンテキストを記録するファイル
+    pub state_path: PathBuf,
+}
+
+impl Default for ContextConfig {
+    fn default() -> Self {
+        Self {
+            state_path: get_yaru_dir()
+                .map(|dir| dir.join("context.json"))
+                .unwrap_or_else(|_| PathBuf::from("context.json")),
+        }
+    }
+}
+
+/// フォーカス（`yaru focus`）に関する設定（`[focus]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FocusConfig {
+    /// フォーカス中のタスクを記録するファイル
+    pub state_path: PathBuf,
+}
+
+impl Default for FocusConfig {
+    fn default() -> Self {
+        Self {
+            state_path: get_yaru_dir()
+                .map(|dir| dir.join("focus.json"))
+                .unwrap_or_else(|_| PathBuf::from("focus.json")),
+        }
+    }
+}
+
+/// 常駐プロセスに関する設定（`[daemon]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DaemonConfig {
+    /// CLIのコマンドを起動中のデーモンに転送するか（デーモンが起動していない場合は直接実行する）
+    pub enabled: bool,
+    /// デーモンが待ち受けるUnixソケットのパス
+    pub socket_path: PathBuf,
+    /// 最後のリクエストから自動で終了するまでの秒数（0の場合は終了しない）
+    pub idle_timeout: u64,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            socket_path: get_default_socket_path().unwrap_or_else(|_| PathBuf::from("yaru.sock")),
+            idle_timeout: 0,
+        }
+    }
+}
+
+/// GitHubとの同期に関する設定（`[github]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GithubConfig {
+    /// APIのアクセストークン（省略時は`GITHUB_TOKEN`環境変数を使う）
+    pub token: Option<String>,
+    /// APIのベースURL（GitHub Enterpriseの場合に変更する）
+    pub api_url: String,
+}
+
+impl Default for GithubConfig {
+    fn default() -> Self {
+        Self {
+            token: None,
+            api_url: "https://api.github.com".to_string(),
+        }
+    }
+}
+
+impl GithubConfig {
+    /// 使用するアクセストークン（設定ファイルを優先し、なければ`GITHUB_TOKEN`環境変数）
+    pub fn resolve_token(&self) -> Option<String> {
+        self.token
+            .clone()
+            .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+            .filter(|token| !token.trim().is_empty())
+    }
+}
+
+/// メールからのタスクの取り込み（`yaru ingest mail`）に関する設定（`[mail]`セクション）
+///
+/// `--maildir`・`--imap`を指定しない場合は、maildirを優先して読み込みます。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MailConfig {
+    /// 読み込むmaildirのフォルダ
+    pub maildir: Option<PathBuf>,
+    /// IMAPサーバーのホスト名（IMAPSで接続する）
+    pub imap_host: Option<String>,
+    /// IMAPサーバーのポート番号
+    pub imap_port: u16,
+    /// IMAPのユーザー名
+    pub imap_user: Option<String>,
+    /// IMAPのパスワード（省略時は`YARU_IMAP_PASSWORD`環境変数を使う）
+    pub imap_password: Option<String>,
+    /// IMAPで読み込むフォルダ
+    pub imap_folder: String,
+    /// 件名にこの文字列を含むメールだけを取り込む（大文字小文字を区別しない）
+    pub subject_filter: Option<String>,
+    /// 取り込んだタスクに付けるタグ
+    pub tag: Option<String>,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            maildir: None,
+            imap_host: None,
+            imap_port: 993,
+            imap_user: None,
+            imap_password: None,
+            imap_folder: "INBOX".to_string(),
+            subject_filter: None,
+            tag: None,
+        }
+    }
+}
+
+#[cfg(feature = "mail")]
+impl MailConfig {
+    /// 使用するIMAPのパスワード（設定ファイルを優先し、なければ`YARU_IMAP_PASSWORD`環境変数）
+    pub fn resolve_password(&self) -> Option<String> {
+        self.imap_password
+            .clone()
+            .or_else(|| std::env::var("YARU_IMAP_PASSWORD").ok())
+            .filter(|password| !password.is_empty())
+    }
+}
+
+/// リモートストレージとの同期（`yaru sync remote`）に関する設定（`[remote]`セクション）
+///
+/// `kind`が`s3`の場合は`bucket`と`key`、`webdav`の場合は`url`を使います。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteConfig {
+    /// ストレージの種類（省略時は同期しない）
+    pub kind: Option<RemoteKind>,
+    /// S3互換のストレージのエンドポイント（省略時はリージョンのAWSのエンドポイント）
+    pub endpoint: Option<String>,
+    /// S3のバケット
+    pub bucket: Option<String>,
+    /// S3のオブジェクトキー
+    pub key: String,
+    /// S3のリージョン
+    pub region: String,
+    /// S3のアクセスキーID（省略時は`AWS_ACCESS_KEY_ID`環境変数を使う）
+    pub access_key_id: Option<String>,
+    /// S3のシークレットアクセスキー（省略時は`AWS_SECRET_ACCESS_KEY`環境変数を使う）
+    pub secret_access_key: Option<String>,
+    /// WebDAVサーバー上のファイルのURL
+    pub url: Option<String>,
+    /// WebDAVのユーザー名
+    pub user: Option<String>,
+    /// WebDAVのパスワード（省略時は`YARU_WEBDAV_PASSWORD`環境変数を使う）
+    pub password: Option<String>,
+    /// 前回のpush・pullで確認したスナップショットのETagを記録するファイル
+    pub state_path: PathBuf,
+}
+
+/// リモートストレージの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RemoteKind {
+    /// S3互換のストレージ
+    S3,
+    /// WebDAVサーバー
+    Webdav,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        Self {
+            kind: None,
+            endpoint: None,
+            bucket: None,
+            key: "yaru.db.enc".to_string(),
+            region: "us-east-1".to_string(),
+            access_key_id: None,
+            secret_access_key: None,
+            url: None,
+            user: None,
+            password: None,
+            state_path: get_yaru_dir()
+                .map(|dir| dir.join("remote_state.json"))
+                .unwrap_or_else(|_| PathBuf::from("remote_state.json")),
+        }
+    }
+}
+
+impl RemoteConfig {
+    /// 使用するS3の認証情報（設定ファイルを優先し、なければ`AWS_ACCESS_KEY_ID`・`AWS_SECRET_ACCESS_KEY`環境変数）
+    pub fn resolve_s3_credentials(&self) -> (Option<String>, Option<String>) {
+        let resolve = |value: &Option<String>, env: &str| {
+            value
+                .clone()
+                .or_else(|| std::env::var(env).ok())
+                .filter(|value| !value.is_empty())
+        };
+        (
+            resolve(&self.access_key_id, "AWS_ACCESS_KEY_ID"),
+            resolve(&self.secret_access_key, "AWS_SECRET_ACCESS_KEY"),
+        )
+    }
+
+    /// 使用するWebDAVのパスワード（設定ファイルを優先し、なければ`YARU_WEBDAV_PASSWORD`環境変数）
+    pub fn resolve_password(&self) -> Option<String> {
+        self.password
+            .clone()
+            .or_else(|| std::env::var("YARU_WEBDAV_PASSWORD").ok())
+            .filter(|password| !password.is_empty())
+    }
+}
+
+/// 定期実行（`yaru cron run`）に関する設定（`[cron]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CronConfig {
+    /// 繰り返しのタスクの次の回を、期限日の何日前に作成するか
+    pub recurrence_lead_days: u32,
+    /// 前回の実行以降に期限切れになったタスクを通知するか
+    pub notify_overdue: bool,
+    /// 通知に使うコマンドと引数（`{title}`, `{body}`を置き換える。空の場合はOSの通知機能を使う）
+    pub notify_command: Vec<String>,
+    /// 完了してからこの日数が経ったタスクをアーカイブして削除する（省略時はアーカイブしない）
+    pub archive_after_days: Option<u32>,
+    /// アーカイブしたタスクを追記するファイル（JSON Lines形式）
+    pub archive_path: PathBuf,
+    /// 前回の実行日を記録するファイル
+    pub state_path: PathBuf,
+}
+
+impl Default for CronConfig {
+    fn default() -> Self {
+        Self {
+            recurrence_lead_days: 0,
+            notify_overdue: true,
+            notify_command: vec![],
+            archive_after_days: None,
+            archive_path: get_yaru_dir()
+                .map(|dir| dir.join("archive.jsonl"))
+                .unwrap_or_else(|_| PathBuf::from("archive.jsonl")),
+            state_path: get_yaru_dir()
+                .map(|dir| dir.join("cron_state.json"))
+                .unwrap_or_else(|_| PathBuf::from("cron_state.json")),
+        }
+    }
+}
+
+/// コマンドの実行記録（`yaru audit`）に関する設定（`[audit]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    /// 実行したコマンド・引数・所要時間・結果を記録するか
+    pub enabled: bool,
+    /// 実行記録を追記するファイル（JSON Lines形式）
+    pub path: PathBuf,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: get_yaru_dir()
+                .map(|dir| dir.join("audit.jsonl"))
+                .unwrap_or_else(|_| PathBuf::from("audit.jsonl")),
+        }
+    }
+}
+
+/// ログ出力に関する設定（`[logging]`セクション）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// ログの出力先ファイル（省略時はファイルに出力しない）
+    pub file: Option<PathBuf>,
+    /// ファイルに出力するログのフィルタ（`RUST_LOG`と同じ書式）
+    pub level: String,
+    /// ローテーションするファイルサイズ（バイト）
+    pub max_size: u64,
+    /// 保持する古いログファイルの数
+    pub max_files: usize,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            file: None,
+            level: "yaru=debug".to_string(),
+            max_size: 1024 * 1024,
+            max_files: 3,
         }
     }
 }
@@ -31,7 +711,7 @@ fn get_yaru_dir() -> Result<PathBuf> {
 }
 
 /// 設定ファイルのパスを取得
-fn get_config_path() -> Result<PathBuf> {
+pub fn get_config_path() -> Result<PathBuf> {
     Ok(get_yaru_dir()?.join("config.toml"))
 }
 
@@ -41,6 +721,16 @@ fn get_default_database_url() -> Result<String> {
     Ok(format!("sqlite://{}?mode=rwc", db_path.display()))
 }
 
+/// デフォルトのJSONファイルパスを取得
+fn get_default_json_path() -> Result<PathBuf> {
+    Ok(get_yaru_dir()?.join("yaru.json"))
+}
+
+/// デフォルトのデーモンのソケットパスを取得
+fn get_default_socket_path() -> Result<PathBuf> {
+    Ok(get_yaru_dir()?.join("yaru.sock"))
+}
+
 /// 設定を読み込む
 ///
 /// 設定ファイルが存在する場合はそれを読み込み、存在しない場合はデフォルト設定を返す
@@ -106,7 +796,9 @@ database_url = "sqlite:///custom/path/yaru.db?mode=rwc"
         let config = Config {
             storage: StorageConfig {
                 database_url: "sqlite://test.db?mode=rwc".to_string(),
+                ..Default::default()
             },
+            ..Default::default()
         };
         let toml_str = toml::to_string(&config).unwrap();
         assert!(toml_str.contains("database_url"));
@@ -218,4 +910,406 @@ database_url = "sqlite:///existing/path/yaru.db?mode=rwc"
             assert_eq!(config.storage.database_url, "sqlite://yaru.db?mode=rwc");
         }
     }
+
+    #[test]
+    fn test_config_without_display_section_uses_default_theme() {
+        let toml_str = r#"
+[storage]
+database_url = "sqlite://test.db?mode=rwc"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.display.theme, ThemeConfig::default());
+    }
+
+    #[test]
+    fn test_config_deserialize_partial_theme() {
+        // 一部のキーのみ指定した場合、残りはデフォルト値になる
+        let toml_str = r#"
+[display.theme]
+high = "magenta"
+dim_completed = false
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.display.theme.high, "magenta");
+        assert!(!config.display.theme.dim_completed);
+        assert_eq!(config.display.theme.critical, "red");
+        assert!(config.display.theme.enabled);
+        // storageセクション省略時はデフォルトのデータベースURL
+        assert_eq!(
+            config.storage.database_url,
+            StorageConfig::default().database_url
+        );
+    }
+
+    #[test]
+    fn test_config_display_language() {
+        // 省略時は環境変数から決定する
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.display.language, "auto");
+
+        let toml_str = r#"
+[display]
+language = "ja"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.display.language, "ja");
+        assert_eq!(config.display.theme, ThemeConfig::default());
+    }
+
+    #[test]
+    fn test_config_display_date_format_and_timezone() {
+        // 省略時はISO形式・システムのタイムゾーン
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.display.date_format, "%Y-%m-%d");
+        assert_eq!(config.display.timezone, "local");
+
+        let toml_str = r#"
+[display]
+date_format = "%d.%m.%Y"
+timezone = "+09:00"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.display.date_format, "%d.%m.%Y");
+        assert_eq!(config.display.timezone, "+09:00");
+        assert_eq!(config.display.language, "auto");
+    }
+
+    #[test]
+    fn test_config_display_week_start() {
+        // 省略時は月曜始まり
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.display.week_start, "monday");
+
+        let config: Config = toml::from_str("[display]\nweek_start = \"sunday\"").unwrap();
+        assert_eq!(config.display.week_start, "sunday");
+    }
+
+    #[test]
+    fn test_config_storage_backend_json() {
+        // 省略時はSQLite
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.storage.backend, StorageBackend::Sqlite);
+
+        let toml_str = r#"
+[storage]
+backend = "json"
+json_path = "/sync/yaru.json"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.storage.backend, StorageBackend::Json);
+        assert_eq!(config.storage.json_path, PathBuf::from("/sync/yaru.json"));
+        // auto_migrate省略時は自動適用が有効
+        assert!(config.storage.auto_migrate);
+        // read_only省略時は書き込みできる
+        assert!(!config.storage.read_only);
+        // database_url省略時はデフォルト値
+        assert_eq!(
+            config.storage.database_url,
+            StorageConfig::default().database_url
+        );
+    }
+
+    #[test]
+    fn test_config_logging() {
+        // 省略時はファイルに出力しない
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.logging, LoggingConfig::default());
+        assert!(config.logging.file.is_none());
+
+        let toml_str = r#"
+[logging]
+file = "/tmp/yaru.log"
+max_files = 5
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.logging.file, Some(PathBuf::from("/tmp/yaru.log")));
+        assert_eq!(config.logging.max_files, 5);
+        // 省略した項目はデフォルト値
+        assert_eq!(config.logging.level, "yaru=debug");
+        assert_eq!(config.logging.max_size, 1024 * 1024);
+    }
+
+    #[test]
+    fn test_config_tags_auto_create() {
+        // 省略時は自動作成が有効
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.tags.auto_create);
+
+        let config: Config = toml::from_str("[tags]\nauto_create = false\n").unwrap();
+        assert!(!config.tags.auto_create);
+    }
+
+    #[test]
+    fn test_config_workflow() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.workflow.require_reopen);
+        assert!(config.workflow.forbidden_transitions.is_empty());
+
+        let config: Config = toml::from_str(
+            "[workflow]\nrequire_reopen = false\nforbidden_transitions = [\"pending->completed\"]\n",
+        )
+        .unwrap();
+        assert!(!config.workflow.require_reopen);
+        assert_eq!(
+            config.workflow.forbidden_transitions,
+            vec!["pending->completed".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_config_due_date() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.due_date.past_dates, "allow");
+
+        let config: Config = toml::from_str("[due_date]\npast_dates = \"reject\"\n").unwrap();
+        assert_eq!(config.due_date.past_dates, "reject");
+    }
+
+    #[test]
+    fn test_config_due_date_buckets() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.due_date.soon_days, 7);
+        assert_eq!(config.due_date.upcoming_days, 30);
+
+        let config: Config =
+            toml::from_str("[due_date]\nsoon_days = 3\nupcoming_days = 14\n").unwrap();
+        assert_eq!(config.due_date.soon_days, 3);
+        assert_eq!(config.due_date.upcoming_days, 14);
+        assert_eq!(config.due_date.past_dates, "allow");
+    }
+
+    #[test]
+    fn test_config_workload() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.workload.daily_capacity, "8h");
+
+        let config: Config = toml::from_str("[workload]\ndaily_capacity = \"6h30m\"\n").unwrap();
+        assert_eq!(config.workload.daily_capacity, "6h30m");
+    }
+
+    #[test]
+    fn test_config_urgency() {
+        let config: Config = toml::from_str("[urgency]\ndue = 20.0\nblocked = 0\n").unwrap();
+        assert_eq!(config.urgency.priority, 6.0);
+        assert_eq!(config.urgency.due, 20.0);
+        assert_eq!(config.urgency.age, 2.0);
+        assert_eq!(config.urgency.blocked, 0.0);
+    }
+
+    #[test]
+    fn test_config_stale() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.stale, StaleConfig::default());
+
+        let config: Config = toml::from_str("[stale]\nin_progress_days = 3\n").unwrap();
+        assert_eq!(config.stale.pending_days, 30);
+        assert_eq!(config.stale.in_progress_days, 3);
+    }
+
+    #[test]
+    fn test_config_calendar() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.calendar, CalendarConfig::default());
+        assert!(!config.calendar.business_days);
+
+        let config: Config = toml::from_str(
+            "[calendar]\nbusiness_days = true\nholidays = [\"2026-01-01\"]\nholidays_file = \"/tmp/holidays.ics\"\n",
+        )
+        .unwrap();
+        assert!(config.calendar.business_days);
+        assert_eq!(config.calendar.holidays, vec!["2026-01-01".to_string()]);
+        assert_eq!(
+            config.calendar.holidays_file,
+            Some(PathBuf::from("/tmp/holidays.ics"))
+        );
+    }
+
+    #[test]
+    fn test_config_overdue() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.overdue.tag, None);
+
+        let config: Config = toml::from_str("[overdue]\ntag = \"期限切れ\"\n").unwrap();
+        assert_eq!(config.overdue.tag.as_deref(), Some("期限切れ"));
+    }
+
+    #[test]
+    fn test_config_focus() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.focus.state_path.ends_with("focus.json"));
+
+        let config: Config = toml::from_str("[focus]\nstate_path = \"/tmp/focus.json\"\n").unwrap();
+        assert_eq!(config.focus.state_path, PathBuf::from("/tmp/focus.json"));
+    }
+
+    #[test]
+    fn test_config_context() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.context.state_path.ends_with("context.json"));
+
+        let config: Config =
+            toml::from_str("[context]\nstate_path = \"/tmp/context.json\"\n").unwrap();
+        assert_eq!(
+            config.context.state_path,
+            PathBuf::from("/tmp/context.json")
+        );
+    }
+
+    #[test]
+    fn test_config_daemon() {
+        let config = Config::default();
+        assert!(!config.daemon.enabled);
+        assert!(config.daemon.socket_path.ends_with("yaru.sock"));
+
+        let config: Config =
+            toml::from_str("[daemon]\nenabled = true\nsocket_path = \"/tmp/y.sock\"\n").unwrap();
+        assert!(config.daemon.enabled);
+        assert_eq!(config.daemon.socket_path, PathBuf::from("/tmp/y.sock"));
+        assert_eq!(config.daemon.idle_timeout, 0);
+    }
+
+    #[test]
+    fn test_config_github() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.github.api_url, "https://api.github.com");
+        assert_eq!(config.github.token, None);
+
+        let config: Config = toml::from_str(
+            "[github]\ntoken = \"ghp_x\"\napi_url = \"https://ghe.example.com/api/v3\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.github.resolve_token().as_deref(), Some("ghp_x"));
+        assert_eq!(config.github.api_url, "https://ghe.example.com/api/v3");
+    }
+
+    #[test]
+    fn test_config_mail() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.mail.imap_port, 993);
+        assert_eq!(config.mail.imap_folder, "INBOX");
+        assert_eq!(config.mail.maildir, None);
+
+        let config: Config = toml::from_str(
+            "[mail]\nimap_host = \"imap.example.com\"\nimap_password = \"secret\"\ntag = \"email\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.mail.imap_host.as_deref(), Some("imap.example.com"));
+        assert_eq!(config.mail.imap_password.as_deref(), Some("secret"));
+        assert_eq!(config.mail.tag.as_deref(), Some("email"));
+    }
+
+    #[test]
+    fn test_config_remote() {
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.remote.kind, None);
+        assert_eq!(config.remote.key, "yaru.db.enc");
+        assert_eq!(config.remote.region, "us-east-1");
+        assert!(config.remote.state_path.ends_with("remote_state.json"));
+
+        let config: Config = toml::from_str(
+            "[remote]\nkind = \"s3\"\nendpoint = \"http://localhost:9000\"\nbucket = \"backup\"\nkey = \"laptop/yaru.db.enc\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.remote.kind, Some(RemoteKind::S3));
+        assert_eq!(config.remote.bucket.as_deref(), Some("backup"));
+        assert_eq!(config.remote.key, "laptop/yaru.db.enc");
+
+        let config: Config = toml::from_str(
+            "[remote]\nkind = \"webdav\"\nurl = \"https://dav.example.com/yaru.db.enc\"\nuser = \"me\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.remote.kind, Some(RemoteKind::Webdav));
+        assert_eq!(config.remote.user.as_deref(), Some("me"));
+    }
+
+    #[test]
+    fn test_config_audit() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.audit.enabled);
+        assert!(config.audit.path.ends_with("audit.jsonl"));
+
+        let config: Config =
+            toml::from_str("[audit]\nenabled = true\npath = \"/tmp/audit.jsonl\"\n").unwrap();
+        assert!(config.audit.enabled);
+        assert_eq!(config.audit.path, PathBuf::from("/tmp/audit.jsonl"));
+    }
+
+    #[test]
+    fn test_config_cron() {
+        let config = Config::default();
+        assert_eq!(config.cron.recurrence_lead_days, 0);
+        assert!(config.cron.notify_overdue);
+        assert!(config.cron.notify_command.is_empty());
+        assert_eq!(config.cron.archive_after_days, None);
+        assert!(config.cron.archive_path.ends_with("archive.jsonl"));
+
+        let toml_str = r#"
+[cron]
+recurrence_lead_days = 3
+notify_command = ["notify-send", "-u", "critical", "{title}", "{body}"]
+archive_after_days = 30
+archive_path = "/tmp/archive.jsonl"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.cron.recurrence_lead_days, 3);
+        assert_eq!(config.cron.notify_command.len(), 5);
+        assert_eq!(config.cron.archive_after_days, Some(30));
+        assert_eq!(
+            config.cron.archive_path,
+            PathBuf::from("/tmp/archive.jsonl")
+        );
+        assert!(config.cron.state_path.ends_with("cron_state.json"));
+    }
+
+    #[test]
+    fn test_config_storage_sqlite() {
+        let config = Config::default();
+        assert_eq!(config.storage.sqlite, SqliteConfig::default());
+        assert_eq!(config.storage.sqlite.journal_mode, "wal");
+
+        let toml_str = r#"
+[storage.sqlite]
+journal_mode = "delete"
+busy_timeout_ms = 10000
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.storage.sqlite.journal_mode, "delete");
+        assert_eq!(config.storage.sqlite.synchronous, "normal");
+        assert_eq!(config.storage.sqlite.busy_timeout_ms, 10000);
+        assert_eq!(config.storage.sqlite.max_connections, 4);
+    }
+
+    #[test]
+    fn test_config_duplicates() {
+        let config: Config = toml::from_str("").unwrap();
+        assert!(!config.duplicates.check);
+        assert_eq!(config.duplicates.threshold, 0.8);
+
+        let config: Config =
+            toml::from_str("[duplicates]\ncheck = true\nthreshold = 0.9\n").unwrap();
+        assert!(config.duplicates.check);
+        assert_eq!(config.duplicates.threshold, 0.9);
+    }
+
+    #[test]
+    fn test_config_list() {
+        // 省略時はID順・絞り込みなし・既定の列
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.list, ListConfig::default());
+
+        let toml_str = r#"
+[list]
+sort = "due"
+filters = ["status:pending"]
+columns = ["id", "title", "due"]
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.list.sort, "due");
+        assert_eq!(config.list.filters, vec!["status:pending"]);
+        assert_eq!(config.list.columns, vec!["id", "title", "due"]);
+        assert!(config.list.hide_completed);
+
+        let config: Config = toml::from_str("[list]\nhide_completed = false\n").unwrap();
+        assert!(!config.list.hide_completed);
+    }
 }
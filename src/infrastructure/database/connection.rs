@@ -1,7 +1,10 @@
-use crate::infrastructure::config::Config;
+use crate::infrastructure::config::{Config, SqliteConfig};
 use anyhow::{Context, Result};
-use sea_orm::{ConnectOptions, Database, DatabaseConnection};
-use std::time::Duration;
+use sea_orm::{
+    ConnectOptions, Database, DatabaseConnection,
+    sqlx::sqlite::{SqliteJournalMode, SqliteSynchronous},
+};
+use std::{path::Path, time::Duration};
 
 /// データベース接続マネージャー
 ///
@@ -9,21 +12,55 @@ use std::time::Duration;
 pub struct DatabaseConnectionManager;
 
 impl DatabaseConnectionManager {
-    /// データベース接続を作成
+    /// SQLiteの設定を指定してデータベース接続を作成
+    ///
+    /// 接続はプール内で使い回し、プリペアドステートメントは接続ごとにキャッシュされます。
+    /// デーモンやTUIのように長く動くプロセスでも再接続が起きないよう、接続の寿命は設けません。
     ///
     /// # 引数
     /// - `database_url`: データベース接続URL
+    /// - `sqlite`: ジャーナルモード・同期モード・ロックの待ち時間などの設定
     ///
     /// # 戻り値
     /// データベース接続オブジェクト
-    pub async fn connect(database_url: &str) -> Result<DatabaseConnection> {
+    pub async fn connect_with(
+        database_url: &str,
+        sqlite: &SqliteConfig,
+    ) -> Result<DatabaseConnection> {
+        let journal_mode: SqliteJournalMode = sqlite.journal_mode.parse().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid sqlite journal_mode in config: {}",
+                sqlite.journal_mode
+            )
+        })?;
+        let synchronous: SqliteSynchronous = sqlite.synchronous.parse().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid sqlite synchronous in config: {}",
+                sqlite.synchronous
+            )
+        })?;
+        // 初回起動時など、作成するデータベースファイルのディレクトリがない場合は作成する
+        if let Some(dir) = creatable_sqlite_dir(database_url) {
+            std::fs::create_dir_all(dir).with_context(|| {
+                format!("Failed to create database directory: {}", dir.display())
+            })?;
+        }
+        let busy_timeout = Duration::from_millis(sqlite.busy_timeout_ms);
+        let statement_cache_capacity = sqlite.statement_cache_capacity;
+
         let mut opt = ConnectOptions::new(database_url);
-        opt.max_connections(100)
-            .min_connections(5)
+        opt.max_connections(sqlite.max_connections.max(1))
+            .min_connections(1)
             .connect_timeout(Duration::from_secs(8))
-            .idle_timeout(Duration::from_secs(8))
-            .max_lifetime(Duration::from_secs(8))
-            .sqlx_logging(false); // SQLログを無効化（必要に応じて有効化）
+            .acquire_timeout(busy_timeout.max(Duration::from_secs(8)))
+            .idle_timeout(Duration::from_secs(600))
+            .sqlx_logging(true) // SQLログはtracingに出力され、-vvまたはRUST_LOGで表示される
+            .map_sqlx_sqlite_opts(move |opts| {
+                opts.journal_mode(journal_mode)
+                    .synchronous(synchronous)
+                    .busy_timeout(busy_timeout)
+                    .statement_cache_capacity(statement_cache_capacity)
+            });
 
         Database::connect(opt)
             .await
@@ -38,21 +75,39 @@ impl DatabaseConnectionManager {
     /// # 戻り値
     /// データベース接続オブジェクト
     pub async fn connect_from_config(config: &Config) -> Result<DatabaseConnection> {
-        Self::connect(&config.storage.database_url).await
+        Self::connect_with(&config.storage.database_url, &config.storage.sqlite).await
     }
 }
 
+/// 作成が許可された（`mode=rwc`）SQLiteファイルのディレクトリを取得
+///
+/// メモリ上のデータベースや、ディレクトリを含まないパスの場合はNoneを返します。
+fn creatable_sqlite_dir(database_url: &str) -> Option<&Path> {
+    let rest = database_url
+        .strip_prefix("sqlite://")
+        .or_else(|| database_url.strip_prefix("sqlite:"))?;
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    if path.is_empty() || path == ":memory:" || !query.split('&').any(|p| p == "mode=rwc") {
+        return None;
+    }
+    Path::new(path)
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::infrastructure::config::{Config, app_config::StorageConfig};
+    use sea_orm::{ConnectionTrait, DbBackend, Statement, TryGetable};
 
     #[tokio::test]
     async fn test_connect_with_valid_url() {
         // SQLiteのインメモリデータベースに接続
-        let db = DatabaseConnectionManager::connect("sqlite::memory:")
-            .await
-            .unwrap();
+        let db =
+            DatabaseConnectionManager::connect_with("sqlite::memory:", &SqliteConfig::default())
+                .await
+                .unwrap();
 
         // 接続が成功することを確認
         assert!(db.ping().await.is_ok());
@@ -64,7 +119,9 @@ mod tests {
         let config = Config {
             storage: StorageConfig {
                 database_url: "sqlite::memory:".to_string(),
+                ..Default::default()
             },
+            ..Default::default()
         };
 
         // 設定から接続を作成
@@ -76,12 +133,95 @@ mod tests {
         assert!(db.ping().await.is_ok());
     }
 
+    /// PRAGMAの現在の値を取得
+    async fn pragma<T: TryGetable>(db: &DatabaseConnection, name: &str) -> T {
+        db.query_one(Statement::from_string(
+            DbBackend::Sqlite,
+            format!("PRAGMA {}", name),
+        ))
+        .await
+        .unwrap()
+        .unwrap()
+        .try_get_by_index(0)
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_applies_sqlite_pragmas() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", dir.path().join("test.db").display());
+        let sqlite = SqliteConfig {
+            busy_timeout_ms: 1234,
+            ..Default::default()
+        };
+
+        // Act
+        let db = DatabaseConnectionManager::connect_with(&url, &sqlite)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(pragma::<String>(&db, "journal_mode").await, "wal");
+        // NORMALは1
+        assert_eq!(pragma::<i32>(&db, "synchronous").await, 1);
+        assert_eq!(pragma::<i32>(&db, "busy_timeout").await, 1234);
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_invalid_sqlite_config() {
+        let sqlite = SqliteConfig {
+            journal_mode: "fast".to_string(),
+            ..Default::default()
+        };
+
+        let error = DatabaseConnectionManager::connect_with("sqlite::memory:", &sqlite)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid sqlite journal_mode in config: fast"
+        );
+    }
+
     #[tokio::test]
     async fn test_connect_with_invalid_url() {
         // 不正なURLで接続を試みる
-        let result = DatabaseConnectionManager::connect("invalid://url").await;
+        let result =
+            DatabaseConnectionManager::connect_with("invalid://url", &SqliteConfig::default())
+                .await;
 
         // エラーが返されることを確認
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_creatable_sqlite_dir() {
+        assert_eq!(
+            creatable_sqlite_dir("sqlite:///home/user/.config/yaru/yaru.db?mode=rwc"),
+            Some(Path::new("/home/user/.config/yaru"))
+        );
+        // 作成を許可していない場合・メモリ上・ディレクトリなしは対象外
+        assert_eq!(creatable_sqlite_dir("sqlite:///data/yaru.db"), None);
+        assert_eq!(creatable_sqlite_dir("sqlite::memory:"), None);
+        assert_eq!(creatable_sqlite_dir("sqlite://yaru.db?mode=rwc"), None);
+    }
+
+    #[tokio::test]
+    async fn test_connect_creates_missing_directory() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("new").join("yaru.db");
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+
+        // Act
+        let db = DatabaseConnectionManager::connect_with(&url, &SqliteConfig::default())
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(db.ping().await.is_ok());
+        assert!(path.exists());
+    }
 }
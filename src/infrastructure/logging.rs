@@ -0,0 +1,7 @@
+pub mod query_metrics;
+pub mod rotating_file;
+pub mod subscriber;
+
+pub use query_metrics::{QueryMetrics, QueryMetricsSnapshot};
+pub use rotating_file::RotatingFileWriter;
+pub use subscriber::{LogVerbosity, init_logging};
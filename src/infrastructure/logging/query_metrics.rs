@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tracing::{
+    Event, Subscriber,
+    field::{Field, Visit},
+};
+use tracing_subscriber::{Layer, filter::Targets, layer::Context, registry::LookupSpan};
+
+/// sqlxがクエリの実行ごとにイベントを出力するターゲット
+const SQLX_QUERY_TARGET: &str = "sqlx::query";
+
+/// SQLクエリの実行回数・取得行数の集計（`--profile`）
+///
+/// sqlxが出力するクエリのイベントを[`QueryMetrics::layer`]で受け取り、集計します。
+/// 複製したものは同じ集計を共有します。
+#[derive(Debug, Clone, Default)]
+pub struct QueryMetrics {
+    inner: Arc<Mutex<QueryMetricsSnapshot>>,
+}
+
+/// ある時点のSQLクエリの集計結果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryMetricsSnapshot {
+    /// 実行したクエリの数
+    pub queries: u64,
+    /// 取得した行数
+    pub rows_returned: u64,
+    /// 変更した行数
+    pub rows_affected: u64,
+    /// クエリの実行時間の合計
+    pub elapsed: Duration,
+    /// SQL文ごとの実行回数
+    pub statements: HashMap<String, u64>,
+}
+
+impl QueryMetricsSnapshot {
+    /// 2回以上実行されたSQL文と実行回数（回数の多い順、最大`limit`件）
+    ///
+    /// 同じSQL文が繰り返し実行されている場合は、N+1クエリの可能性があります。
+    /// 接続ごとに実行される`PRAGMA`は含めません。
+    pub fn repeated_statements(&self, limit: usize) -> Vec<(&str, u64)> {
+        let mut repeated: Vec<(&str, u64)> = self
+            .statements
+            .iter()
+            .filter(|(statement, count)| **count > 1 && !statement.starts_with("PRAGMA"))
+            .map(|(statement, count)| (statement.as_str(), *count))
+            .collect();
+        repeated.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        repeated.truncate(limit);
+        repeated
+    }
+}
+
+impl QueryMetrics {
+    /// 新しいQueryMetricsを作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 現在の集計結果
+    pub fn snapshot(&self) -> QueryMetricsSnapshot {
+        self.inner.lock().unwrap().clone()
+    }
+
+    /// sqlxのクエリのイベントを集計するレイヤー
+    pub fn layer<S>(&self) -> impl Layer<S> + Send + Sync + 'static
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        QueryMetricsLayer {
+            metrics: self.clone(),
+        }
+        .with_filter(Targets::new().with_target(SQLX_QUERY_TARGET, tracing::Level::TRACE))
+    }
+}
+
+/// sqlxのクエリのイベントを集計するレイヤー
+struct QueryMetricsLayer {
+    metrics: QueryMetrics,
+}
+
+impl<S: Subscriber> Layer<S> for QueryMetricsLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = QueryEventVisitor::default();
+        event.record(&mut visitor);
+
+        let mut snapshot = self.metrics.inner.lock().unwrap();
+        snapshot.queries += 1;
+        snapshot.rows_returned += visitor.rows_returned;
+        snapshot.rows_affected += visitor.rows_affected;
+        snapshot.elapsed += Duration::from_secs_f64(visitor.elapsed_secs.max(0.0));
+        *snapshot.statements.entry(visitor.statement()).or_default() += 1;
+    }
+}
+
+/// sqlxのクエリのイベントのフィールド
+#[derive(Default)]
+struct QueryEventVisitor {
+    summary: String,
+    statement: String,
+    rows_returned: u64,
+    rows_affected: u64,
+    elapsed_secs: f64,
+}
+
+impl QueryEventVisitor {
+    /// SQL文（長いSQL文は`summary`に先頭部分だけが入るため、全文を優先する）
+    fn statement(self) -> String {
+        let statement = self.statement.trim();
+        if statement.is_empty() {
+            self.summary
+        } else {
+            statement.to_string()
+        }
+    }
+}
+
+impl Visit for QueryEventVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "summary" => self.summary = value.to_string(),
+            "db.statement" => self.statement = value.to_string(),
+            _ => {}
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "rows_returned" => self.rows_returned = value,
+            "rows_affected" => self.rows_affected = value,
+            _ => {}
+        }
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = value;
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_query_metrics_counts_sqlx_query_events() {
+        // Arrange
+        let metrics = QueryMetrics::new();
+        let subscriber = tracing_subscriber::registry().with(metrics.layer());
+
+        // Act
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..3 {
+                tracing::info!(
+                    target: "sqlx::query",
+                    summary = "SELECT * FROM tags WHERE id = ?",
+                    db.statement = "",
+                    rows_affected = 0u64,
+                    rows_returned = 2u64,
+                    elapsed_secs = 0.001,
+                );
+            }
+            tracing::info!(
+                target: "sqlx::query",
+                summary = "UPDATE tasks SET …",
+                db.statement = "\n\nUPDATE tasks SET title = ? WHERE id = ?\n",
+                rows_affected = 1u64,
+                rows_returned = 0u64,
+                elapsed_secs = 0.002,
+            );
+            for _ in 0..2 {
+                tracing::info!(
+                    target: "sqlx::query",
+                    summary = "PRAGMA foreign_keys = ON;",
+                    db.statement = "",
+                    rows_affected = 0u64,
+                    rows_returned = 0u64,
+                    elapsed_secs = 0.0,
+                );
+            }
+            // sqlx以外のイベントは集計しない
+            tracing::info!(rows_returned = 100u64, "unrelated");
+        });
+
+        // Assert
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.queries, 6);
+        assert_eq!(snapshot.rows_returned, 6);
+        assert_eq!(snapshot.rows_affected, 1);
+        assert_eq!(snapshot.elapsed, Duration::from_secs_f64(0.005));
+        assert_eq!(
+            snapshot.repeated_statements(5),
+            vec![("SELECT * FROM tags WHERE id = ?", 3)]
+        );
+        assert_eq!(
+            snapshot.statements["UPDATE tasks SET title = ? WHERE id = ?"],
+            1
+        );
+    }
+}
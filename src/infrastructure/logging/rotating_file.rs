@@ -0,0 +1,156 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// RotatingFileWriter - サイズ上限でローテーションするログファイル
+///
+/// 書き込みでファイルサイズが上限を超える場合、`yaru.log` → `yaru.log.1` → `yaru.log.2` …の順に
+/// 古いファイルへ繰り下げ、`max_files`を超えたものは削除します。
+pub struct RotatingFileWriter {
+    path: PathBuf,
+    max_size: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFileWriter {
+    /// ログファイルを追記モードで開く
+    ///
+    /// 親ディレクトリが存在しない場合は作成します。
+    pub fn open(path: impl Into<PathBuf>, max_size: u64, max_files: usize) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = open_append(&path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            path,
+            max_size,
+            max_files,
+            file,
+            size,
+        })
+    }
+
+    /// 古いファイルを繰り下げ、新しいファイルを開き直す
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        if self.max_files == 0 {
+            // 古いファイルを残さない場合は切り詰めるだけ
+            self.file = File::create(&self.path)?;
+        } else {
+            let _ = fs::remove_file(rotated_path(&self.path, self.max_files));
+            for index in (1..self.max_files).rev() {
+                let from = rotated_path(&self.path, index);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, index + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+            self.file = open_append(&self.path)?;
+        }
+
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // 1行が上限を超える場合でも、空のファイルには書き込む
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_size {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// 追記モードでファイルを開く
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// ローテーション後のファイルパス（`yaru.log.1`など）
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{}", index));
+    PathBuf::from(rotated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_appends_to_existing_file() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.log");
+        fs::write(&path, "first\n").unwrap();
+
+        // Act
+        let mut writer = RotatingFileWriter::open(&path, 1024, 3).unwrap();
+        writer.write_all(b"second\n").unwrap();
+
+        // Assert
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+    }
+
+    #[test]
+    fn test_rotates_when_size_exceeded() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("yaru.log");
+        let mut writer = RotatingFileWriter::open(&path, 10, 2).unwrap();
+
+        // Act: 各行が上限に近いため、書き込みごとにローテーションされる
+        writer.write_all(b"line-1\n").unwrap();
+        writer.write_all(b"line-2\n").unwrap();
+        writer.write_all(b"line-3\n").unwrap();
+        writer.write_all(b"line-4\n").unwrap();
+
+        // Assert: 最新の2世代のみ残る
+        assert_eq!(fs::read_to_string(&path).unwrap(), "line-4\n");
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 1)).unwrap(),
+            "line-3\n"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 2)).unwrap(),
+            "line-2\n"
+        );
+        assert!(!rotated_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_rotate_without_backups_truncates() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.log");
+        let mut writer = RotatingFileWriter::open(&path, 10, 0).unwrap();
+
+        // Act
+        writer.write_all(b"line-1\n").unwrap();
+        writer.write_all(b"line-2\n").unwrap();
+
+        // Assert
+        assert_eq!(fs::read_to_string(&path).unwrap(), "line-2\n");
+        assert!(!rotated_path(&path, 1).exists());
+    }
+}
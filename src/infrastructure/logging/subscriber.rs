@@ -0,0 +1,133 @@
+use crate::infrastructure::{
+    config::LoggingConfig,
+    logging::{QueryMetrics, RotatingFileWriter},
+};
+use anyhow::{Context, Result};
+use std::sync::Mutex;
+use tracing_subscriber::{
+    EnvFilter, Layer, fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt,
+};
+
+/// 標準エラー出力に表示するログの詳細度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogVerbosity {
+    /// エラーのみ（`--quiet`）
+    Quiet,
+    /// 警告以上（デフォルト）
+    Normal,
+    /// yaruの処理の流れ（`-v`）
+    Verbose,
+    /// ユースケース・リポジトリの所要時間とSQL（`-vv`）
+    Debug,
+    /// 依存クレートを含むすべてのログ（`-vvv`）
+    Trace,
+}
+
+impl LogVerbosity {
+    /// `-v`の指定回数と`--quiet`から詳細度を決定
+    pub fn from_flags(verbose: u8, quiet: bool) -> Self {
+        if quiet {
+            return Self::Quiet;
+        }
+        match verbose {
+            0 => Self::Normal,
+            1 => Self::Verbose,
+            2 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+
+    /// 詳細度に対応するフィルタ
+    fn directives(self) -> &'static str {
+        match self {
+            Self::Quiet => "error",
+            Self::Normal => "warn",
+            Self::Verbose => "warn,yaru=info",
+            Self::Debug => "warn,yaru=debug,sqlx::query=info",
+            Self::Trace => "trace",
+        }
+    }
+}
+
+/// ログ出力を初期化
+///
+/// 標準エラー出力のフィルタは`RUST_LOG`環境変数が設定されていればそれを優先します。
+/// 設定ファイルでログファイルが指定されている場合は、詳細度にかかわらず
+/// `[logging] level`のフィルタでファイルにも出力します。
+///
+/// # Arguments
+/// * `verbosity` - 標準エラー出力の詳細度
+/// * `config` - `[logging]`セクションの設定
+/// * `stderr` - 標準エラー出力に表示するか（TUIでは画面が崩れるためfalseにする）
+/// * `metrics` - SQLクエリを集計する場合の集計先（`--profile`）
+pub fn init_logging(
+    verbosity: LogVerbosity,
+    config: &LoggingConfig,
+    stderr: bool,
+    metrics: Option<&QueryMetrics>,
+) -> Result<()> {
+    let stderr_layer = stderr.then(|| {
+        let filter = EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| EnvFilter::new(verbosity.directives()));
+        let span_events = if verbosity >= LogVerbosity::Debug {
+            FmtSpan::CLOSE
+        } else {
+            FmtSpan::NONE
+        };
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_span_events(span_events)
+            .with_filter(filter)
+    });
+
+    let file_layer = match &config.file {
+        Some(path) => {
+            let filter = EnvFilter::try_new(&config.level)
+                .with_context(|| format!("Invalid log level: {}", config.level))?;
+            let writer = RotatingFileWriter::open(path, config.max_size, config.max_files)
+                .with_context(|| format!("Failed to open log file: {}", path.display()))?;
+            Some(
+                tracing_subscriber::fmt::layer()
+                    .with_writer(Mutex::new(writer))
+                    .with_span_events(FmtSpan::CLOSE)
+                    .with_filter(filter),
+            )
+        }
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(metrics.map(|metrics| metrics.layer()))
+        .with(stderr_layer)
+        .with(file_layer)
+        .try_init()
+        .context("Failed to initialize logging")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_from_flags() {
+        assert_eq!(LogVerbosity::from_flags(0, false), LogVerbosity::Normal);
+        assert_eq!(LogVerbosity::from_flags(1, false), LogVerbosity::Verbose);
+        assert_eq!(LogVerbosity::from_flags(2, false), LogVerbosity::Debug);
+        assert_eq!(LogVerbosity::from_flags(5, false), LogVerbosity::Trace);
+        // --quietが優先される
+        assert_eq!(LogVerbosity::from_flags(2, true), LogVerbosity::Quiet);
+    }
+
+    #[test]
+    fn test_verbosity_directives_are_valid() {
+        for verbosity in [
+            LogVerbosity::Quiet,
+            LogVerbosity::Normal,
+            LogVerbosity::Verbose,
+            LogVerbosity::Debug,
+            LogVerbosity::Trace,
+        ] {
+            assert!(EnvFilter::try_new(verbosity.directives()).is_ok());
+        }
+    }
+}
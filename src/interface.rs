@@ -1,4 +1,17 @@
 pub mod cli;
+pub mod clipboard;
+#[cfg(unix)]
+pub mod daemon;
+pub mod git;
+pub mod github;
+pub mod http;
+pub mod i18n;
+pub mod import;
+#[cfg(feature = "mail")]
+pub mod mail;
+pub mod notify;
 pub mod persistence;
 pub mod presentation;
+pub mod remote;
 pub mod tui;
+pub mod url;
@@ -1,4 +1,29 @@
+pub mod agenda_handler;
+pub mod apply_handler;
 pub mod args;
+pub mod audit_handler;
+pub mod context_handler;
+pub mod cron_handler;
+#[cfg(unix)]
+pub mod daemon_handler;
+pub mod db_handler;
 pub mod display;
+pub mod doctor_handler;
+pub mod focus_handler;
+pub mod git_handler;
+pub mod id_resolver;
+pub mod import_handler;
+#[cfg(feature = "mail")]
+pub mod ingest_handler;
+pub mod picker;
+pub mod purge_handler;
+pub mod query_handler;
+pub mod quick_add;
+pub mod relative_date;
+pub mod report_handler;
+pub mod settings;
+pub mod summary_handler;
+pub mod sync_handler;
 pub mod tag_handler;
 pub mod task_handler;
+pub mod workload_handler;
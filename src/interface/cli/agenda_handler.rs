@@ -0,0 +1,31 @@
+use crate::{
+    application::use_cases::task::show_agenda::ShowAgendaUseCase,
+    domain::{
+        tag::repository::TagRepository,
+        task::{repository::TaskRepository, value_objects::TaskContext},
+    },
+    interface::{cli::settings::CliSettings, presentation::Presenter},
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// today・agendaコマンドを処理
+///
+/// `days`が0の場合（`yaru today`）は、今日の分のみを表示します。
+/// `context`を指定した場合は、そのコンテキストのタスクとコンテキストのないタスクのみを表示します。
+pub async fn handle_agenda(
+    days: u32,
+    context: Option<TaskContext>,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let agenda = ShowAgendaUseCase::new(task_repo, tag_repo)
+        .with_context(context)
+        .with_clock(Arc::new(settings.clock))
+        .execute(days)
+        .await?;
+    presenter.present_agenda(&agenda)?;
+    Ok(())
+}
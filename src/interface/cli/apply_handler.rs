@@ -0,0 +1,183 @@
+use crate::{
+    application::{
+        dto::{ApplyOperation, OperationStatus},
+        use_cases::apply_operations::ApplyOperationsUseCase,
+    },
+    domain::unit_of_work::UnitOfWorkFactory,
+    interface::{
+        cli::{args::ApplyFormat, settings::CliSettings},
+        i18n::Msg,
+        presentation::Presenter,
+    },
+};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{fs, io::Read, sync::Arc};
+
+/// 標準入力から読み込むことを表す入力の指定
+const STDIN: &str = "-";
+
+/// applyコマンドを処理
+///
+/// 結果は成否にかかわらずJSONで標準出力に表示し、失敗した場合はエラーで終了します。
+/// 削除の操作を含む場合は、実行前に確認します（複数件の削除は件数の入力を求めます。`--yes`で省略）。
+pub async fn handle_apply(
+    input: String,
+    format: Option<ApplyFormat>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let content = if input == STDIN {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .context(Msg::ErrorReadOperationsFromStdin.text())?;
+        content
+    } else {
+        fs::read_to_string(&input).with_context(|| Msg::ErrorReadFile.format(&[&input]))?
+    };
+    let operations = parse_operations(&content, format)?;
+
+    let deletions = operations.iter().filter(|op| op.is_deletion()).count();
+    if deletions > 0
+        && !presenter.confirm_count(
+            &Msg::ConfirmApplyDeletions.format(&[&operations.len(), &deletions]),
+            deletions,
+        )?
+    {
+        return presenter.present_info(Msg::ApplyCancelled.text());
+    }
+
+    let report = ApplyOperationsUseCase::new(unit_of_work)
+        .with_transition_policy(settings.transition_policy.clone())
+        .with_past_due_date_policy(settings.past_due_date)
+        .with_create_missing_tags(settings.auto_create_tags)
+        .with_clock(Arc::new(settings.clock))
+        .execute(operations)
+        .await?;
+    presenter.present_data(&serde_json::to_string_pretty(&report)?)?;
+
+    if let Some(failed) = report
+        .results
+        .iter()
+        .find(|result| result.status == OperationStatus::Error)
+    {
+        anyhow::bail!(Msg::ErrorApplyOperationFailed.format(&[&failed.index, &failed.op]));
+    }
+    Ok(())
+}
+
+/// 入力を操作の一覧に変換する
+///
+/// JSONは配列・オブジェクト、またはそれらを空白・改行で並べたもの（JSON Lines）を、
+/// YAMLは操作の配列・オブジェクトを`---`で区切って並べたものを受け付けます。
+/// 形式の指定がない場合は、先頭が`[`・`{`ならJSON、それ以外はYAMLとして読みます。
+fn parse_operations(content: &str, format: Option<ApplyFormat>) -> Result<Vec<ApplyOperation>> {
+    let format = format.unwrap_or_else(|| {
+        if content.trim_start().starts_with(['[', '{']) {
+            ApplyFormat::Json
+        } else {
+            ApplyFormat::Yaml
+        }
+    });
+    let documents: Vec<Value> = match format {
+        ApplyFormat::Json => serde_json::Deserializer::from_str(content)
+            .into_iter::<Value>()
+            .collect::<Result<_, _>>()
+            .context(Msg::ErrorInvalidJsonInput.text())?,
+        ApplyFormat::Yaml => serde_norway::Deserializer::from_str(content)
+            .map(Value::deserialize)
+            .collect::<Result<_, _>>()
+            .context(Msg::ErrorInvalidYamlInput.text())?,
+    };
+
+    let mut values = Vec::new();
+    for document in documents {
+        match document {
+            Value::Array(items) => values.extend(items),
+            Value::Null => {}
+            other => values.push(other),
+        }
+    }
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            serde_json::from_value(value)
+                .with_context(|| Msg::ErrorInvalidOperationAtIndex.format(&[&index]))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_operations_json_stream() {
+        let content = r#"{"op": "create_tag", "name": "work"}
+{"op": "delete_task", "id": 3}
+[{"op": "update_task", "id": 1, "title": "Renamed"}]"#;
+
+        let operations = parse_operations(content, None).unwrap();
+
+        let names: Vec<&str> = operations.iter().map(ApplyOperation::name).collect();
+        assert_eq!(names, vec!["create_tag", "delete_task", "update_task"]);
+    }
+
+    #[test]
+    fn test_parse_operations_yaml() {
+        let content = "- op: create_task\n  title: Write report\n  tag_names: [work]\n  due_date: 2026-11-01\n---\nop: delete_tag\nid: 2\n";
+
+        let operations = parse_operations(content, None).unwrap();
+
+        assert_eq!(operations.len(), 2);
+        let ApplyOperation::CreateTask { task, tag_names } = &operations[0] else {
+            panic!("Expected create_task");
+        };
+        assert_eq!(task.title, "Write report");
+        assert_eq!(task.due_date.unwrap().to_string(), "2026-11-01");
+        assert_eq!(tag_names, &vec!["work".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_operations_invalid() {
+        let error = parse_operations(r#"[{"op": "create_task"}, {"op": "explode"}]"#, None)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "Invalid operation at index 0");
+
+        assert!(parse_operations("{", Some(ApplyFormat::Json)).is_err());
+    }
+
+    #[test]
+    fn test_parse_operations_yaml_documents() {
+        // Arrange: ブロックスカラー・フロー形式・空の文書を含む
+        let content = "# 操作の一覧\n---\n- op: update_task\n  id: 3\n  title: \"Write: report\"\n  description: |\n    line 1\n      indented\n---\n---\nop: create_tag\nname: work\n";
+
+        // Act
+        let operations = parse_operations(content, None).unwrap();
+
+        // Assert
+        let names: Vec<&str> = operations.iter().map(ApplyOperation::name).collect();
+        assert_eq!(names, vec!["update_task", "create_tag"]);
+    }
+
+    #[test]
+    fn test_parse_operations_invalid_yaml() {
+        let error = parse_operations("op: create_tag\n  name: work\n", None)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "Invalid YAML input");
+
+        assert!(parse_operations("op: delete_tag\nop: delete_tag\n", None).is_err());
+        assert!(parse_operations("just text\n", None).is_err());
+        assert!(
+            parse_operations("# only comments\n", None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,22 +1,31 @@
-use chrono::NaiveDate;
-use clap::{Parser, Subcommand, ValueEnum};
-use std::str::FromStr;
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use std::{path::PathBuf, str::FromStr};
 
+use crate::application::use_cases::task::list_tasks::{TaskGroupBy, TaskSortKey};
+use crate::application::use_cases::task::search_tasks::SearchSort;
+use crate::application::use_cases::time_entry::report_estimate_accuracy::AccuracyGroupBy;
 use crate::domain::task::specification::SearchField;
-use crate::domain::task::value_objects::{Priority, Status};
+use crate::domain::task::value_objects::{
+    Assignee, CustomField, Estimate, Priority, Recurrence, Status, TaskContext,
+};
+use crate::interface::cli::display::{TaskColumn, TaskListFormat};
+use crate::interface::cli::relative_date::RelativeDate;
+use crate::interface::presentation::MessageFormat;
 
 /// フィルタ条件を表す構造体
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Filter {
     pub key: FilterKey,
     pub value: String,
 }
 
 /// フィルタキーの種類
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FilterKey {
     Status,
+    Tag,
+    Assignee,
 }
 
 impl FromStr for Filter {
@@ -33,6 +42,8 @@ impl FromStr for Filter {
 
         let key = match parts[0].to_lowercase().as_str() {
             "status" => FilterKey::Status,
+            "tag" => FilterKey::Tag,
+            "assignee" => FilterKey::Assignee,
             _ => return Err(format!("Unknown filter key: '{}'", parts[0])),
         };
 
@@ -56,24 +67,145 @@ fn parse_date(s: &str) -> Result<NaiveDate, String> {
         .map_err(|e| format!("Failed to parse date: {}. Please use YYYY-MM-DD format", e))
 }
 
-/// 自然数（正の整数）をパースする関数
+/// 日時の起点をパースする関数
 ///
 /// # 引数
-/// - `s`: 整数を表す文字列
+/// - `s`: YYYY-MM-DD形式の日付（ローカル時刻の0時）、または`7d`のような現在からの期間
+///   （単位は`h`=時間、`d`=日、`w`=週）
 ///
 /// # 戻り値
-/// - `Ok(i32)`: パースに成功し、1以上の整数の場合
-/// - `Err(String)`: パースに失敗した場合、または0以下の場合、エラーメッセージを返す
-fn parse_positive_id(s: &str) -> Result<i32, String> {
-    let id: i32 = s
-        .parse()
-        .map_err(|_| format!("ID must be an integer: {}", s))?;
+/// - `Ok(DateTime<Utc>)`: パースに成功した場合
+/// - `Err(String)`: パースに失敗した場合、エラーメッセージを返す
+fn parse_since(s: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+            .map(|at| at.with_timezone(&Utc))
+            .ok_or_else(|| format!("Invalid local date: {}", s));
+    }
+
+    let invalid = || {
+        format!(
+            "Invalid date or period: '{}'. Use YYYY-MM-DD or a period such as 12h, 7d or 2w",
+            s
+        )
+    };
+    let (amount, unit) = s.split_at(s.len().saturating_sub(1));
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let period = match unit {
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }
+    .filter(|_| amount >= 0)
+    .ok_or_else(invalid)?;
+    Utc::now()
+        .checked_sub_signed(period)
+        .ok_or_else(|| format!("Period is too long: {}", s))
+}
+
+/// 日付、または今日を基準にした相対的な日付をパースする関数
+///
+/// # 引数
+/// - `s`: YYYY-MM-DD形式の日付、`today`・`tomorrow`・曜日（`friday`・`fri`）、
+///   または`+3d`のような今日からの期間（単位は`d`=日、`w`=週、`bd`=営業日）
+///
+/// # 戻り値
+/// - `Ok(RelativeDate)`: パースに成功した場合（日付はコマンドの実行時に求める）
+/// - `Err(String)`: パースに失敗した場合、エラーメッセージを返す
+fn parse_relative_date(s: &str) -> Result<RelativeDate, String> {
+    match RelativeDate::parse(s) {
+        Ok(Some(date)) => Ok(date),
+        Ok(None) => Err(format!(
+            "Invalid date: '{}'. Use YYYY-MM-DD, today, tomorrow, a weekday or a period such as +3d, +2w or +3bd",
+            s
+        )),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// IDまたはIDの先頭部分をパースする関数
+///
+/// 実際のIDへの解決（前方一致）はハンドラで行います。
+///
+/// # 引数
+/// - `s`: 正の整数、またはその先頭部分を表す文字列
+///
+/// # 戻り値
+/// - `Ok(String)`: 数字のみで構成され、先頭が0でない場合
+/// - `Err(String)`: それ以外の場合、エラーメッセージを返す
+fn parse_id_prefix(s: &str) -> Result<String, String> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("ID must be a positive integer: {}", s));
+    }
 
-    if id <= 0 {
-        return Err(format!("ID must be a positive integer (>= 1): {}", id));
+    if s.starts_with('0') {
+        return Err(format!("ID must be a positive integer (>= 1): {}", s));
     }
 
-    Ok(id)
+    Ok(s.to_string())
+}
+
+/// `key=value`形式のカスタムフィールドをパースする関数
+///
+/// # 引数
+/// - `s`: `key=value`形式の文字列（値に`=`を含めることも可能）
+///
+/// # 戻り値
+/// - `Ok(CustomField)`: キーと値が有効な場合
+/// - `Err(String)`: 形式が不正な場合、エラーメッセージを返す
+fn parse_custom_field(s: &str) -> Result<CustomField, String> {
+    s.parse().map_err(|e: anyhow::Error| e.to_string())
+}
+
+/// 繰り返しルールをパースする関数
+///
+/// # 引数
+/// - `s`: `daily`, `weekly`, `every 2 weeks`などの文字列
+///
+/// # 戻り値
+/// - `Ok(Recurrence)`: ルールが有効な場合
+/// - `Err(String)`: 形式が不正な場合、エラーメッセージを返す
+fn parse_recurrence(s: &str) -> Result<Recurrence, String> {
+    s.parse().map_err(|e: anyhow::Error| e.to_string())
+}
+
+/// 見積もり時間をパースする関数
+///
+/// # 引数
+/// - `s`: `2h30m`, `3h`, `45m`などの文字列
+///
+/// # 戻り値
+/// - `Ok(Estimate)`: 見積もり時間が有効な場合
+/// - `Err(String)`: 形式が不正な場合、エラーメッセージを返す
+fn parse_estimate(s: &str) -> Result<Estimate, String> {
+    s.parse().map_err(|e: anyhow::Error| e.to_string())
+}
+
+/// コンテキストをパースする関数
+///
+/// # 引数
+/// - `s`: `@home`などの文字列（`@`は省略可）
+///
+/// # 戻り値
+/// - `Ok(TaskContext)`: コンテキストが有効な場合
+/// - `Err(String)`: 形式が不正な場合、エラーメッセージを返す
+fn parse_context(s: &str) -> Result<TaskContext, String> {
+    TaskContext::new(s).map_err(|e| e.to_string())
+}
+
+/// 担当者をパースする関数
+///
+/// # 引数
+/// - `s`: 担当者の名前（`alice`など）
+///
+/// # 戻り値
+/// - `Ok(Assignee)`: 名前が有効な場合
+/// - `Err(String)`: 空や長すぎる場合、エラーメッセージを返す
+fn parse_assignee(s: &str) -> Result<Assignee, String> {
+    Assignee::new(s).map_err(|e| e.to_string())
 }
 
 /// 空でない文字列をパースする関数
@@ -103,6 +235,46 @@ fn parse_non_empty_string(s: &str) -> Result<String, String> {
 pub struct Args {
     #[command(subcommand)]
     pub command: Option<Commands>,
+
+    /// Disable colored output (also respects the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Run against an in-memory store instead of the database (also enabled by YARU_DB=memory)
+    #[arg(long, global = true)]
+    pub ephemeral: bool,
+
+    /// JSON snapshot for ephemeral mode: loaded on start if it exists, written on exit
+    #[arg(long, global = true, value_name = "FILE")]
+    pub snapshot: Option<PathBuf>,
+
+    /// Skip the schema check and automatic migrations on startup
+    #[arg(long, global = true)]
+    pub no_migrate: bool,
+
+    /// Show more log output (-v: info, -vv: debug with SQL and timings, -vvv: trace)
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
+
+    /// Only show errors
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Report wall time, SQL query count and rows fetched after the command (bypasses the daemon)
+    #[arg(long, global = true)]
+    pub profile: bool,
+
+    /// Format of messages, warnings and errors (json: one {"level", "message"} object per line)
+    #[arg(long, global = true, value_enum, default_value_t = MessageFormatArg::Text)]
+    pub message_format: MessageFormatArg,
+
+    /// Answer yes to every confirmation prompt (for scripts; prompts fail without a terminal otherwise)
+    #[arg(short, long, global = true)]
+    pub yes: bool,
+
+    /// Open the database read-only: commands that would modify it fail (also [storage] read_only)
+    #[arg(long, global = true)]
+    pub read_only: bool,
 }
 
 /// 実行可能なコマンド
@@ -113,11 +285,167 @@ pub enum Commands {
         #[command(subcommand)]
         command: TaskCommands,
     },
+    /// Quickly add a task with inline syntax (e.g., add "Pay rent #finance !high @friday")
+    Add {
+        /// Title words plus !priority, #tag, @due (YYYY-MM-DD, today, tomorrow, friday, +3d) and @context
+        #[arg(required = true, num_args = 1..)]
+        text: Vec<String>,
+        /// Add without checking for similar open tasks
+        #[arg(long)]
+        force: bool,
+    },
     /// Tag management commands
     Tag {
         #[command(subcommand)]
         command: TagCommands,
     },
+    /// Database management commands (SQLite backend only)
+    Db {
+        #[command(subcommand)]
+        command: DbCommands,
+    },
+    /// Background daemon that keeps the database connection warm (SQLite backend, Unix only)
+    Daemon {
+        #[command(subcommand)]
+        command: DaemonCommands,
+    },
+    /// Git integration (commit trailers and auto-completion on merge)
+    Git {
+        #[command(subcommand)]
+        command: GitCommands,
+    },
+    /// Synchronize tasks with external issue trackers or another yaru database
+    Sync {
+        #[command(subcommand)]
+        command: SyncCommands,
+    },
+    /// Import tasks exported from other task managers
+    Import {
+        #[command(subcommand)]
+        command: ImportCommands,
+    },
+    /// Create tasks from incoming messages (built with the `mail` feature)
+    #[cfg(feature = "mail")]
+    Ingest {
+        #[command(subcommand)]
+        command: IngestCommands,
+    },
+    /// Apply a batch of task/tag operations (JSON or YAML) in one transaction and report results as JSON
+    Apply {
+        /// File to read the operations from (`-` for stdin)
+        #[arg(value_name = "FILE")]
+        input: String,
+        /// Input format (detected from the content if omitted)
+        #[arg(long, value_enum)]
+        format: Option<ApplyFormat>,
+    },
+    /// Switch the current context (@home, @office); lists and agendas show only its tasks and tasks without a context
+    Context {
+        #[command(subcommand)]
+        command: ContextCommands,
+    },
+    /// Scheduled maintenance for a system scheduler (cron, systemd timers)
+    Cron {
+        #[command(subcommand)]
+        command: CronCommands,
+    },
+    /// Find tasks with a query (e.g., "status = pending AND (priority >= high OR due < today) ORDER BY due LIMIT 20")
+    Query {
+        /// Conditions on id, status, priority, tag, due, created, updated, title and description
+        /// joined with AND/OR/NOT, followed by optional ORDER BY <key> [ASC|DESC] and LIMIT <n>
+        #[arg(allow_hyphen_values = true)]
+        query: String,
+        /// Output format (compact: ID/title/status/due only, wide: all fields)
+        #[arg(long)]
+        format: Option<ListFormatArg>,
+        /// Columns to show, comma-separated (e.g., id,title,priority,due; overrides [list] columns)
+        #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(TaskColumn))]
+        columns: Option<Vec<TaskColumn>>,
+    },
+    /// Show overdue, due-today and in-progress tasks, highest priority first
+    Today {
+        /// Include tasks in contexts other than the current one
+        #[arg(long)]
+        all_contexts: bool,
+    },
+    /// Show today's tasks plus tasks due in the coming days, grouped by due date
+    Agenda {
+        /// Number of days after today to include
+        #[arg(long, default_value_t = 7)]
+        days: u32,
+        /// Include tasks in contexts other than the current one
+        #[arg(long)]
+        all_contexts: bool,
+    },
+    /// Show counts of overdue, due-today and open tasks (counts only, fast enough for shell prompts)
+    Summary {
+        /// Print a single line (e.g., "3 overdue · 5 today · 12 open") for status bars such as tmux or starship
+        #[arg(long)]
+        oneline: bool,
+    },
+    /// Focus on one task for deep work: it is shown in `summary` until you finish it with `focus done`
+    #[command(args_conflicts_with_subcommands = true)]
+    Focus {
+        #[command(subcommand)]
+        command: Option<FocusCommands>,
+        /// Task ID to focus on (unique prefix allowed, shows the current focus if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
+        /// Start a timer and show the remaining time (e.g., 25m, 1h30m)
+        #[arg(long, value_name = "DURATION", value_parser = parse_estimate, requires = "id")]
+        timer: Option<Estimate>,
+    },
+    /// Check the config, database, terminal and hooks, and suggest fixes for problems (attach the output to bug reports)
+    Doctor,
+    /// Show estimated effort per day from due dates to spot overcommitted days ([workload] daily_capacity)
+    Workload {
+        /// Show the current calendar week (Monday to Sunday) instead of the next 7 days
+        #[arg(long)]
+        week: bool,
+    },
+    /// Show reports for reviewing how you plan and work
+    Report {
+        #[command(subcommand)]
+        command: ReportCommands,
+    },
+    /// Show the log of past invocations (recorded when [audit] enabled = true)
+    Audit {
+        #[command(subcommand)]
+        command: AuditCommands,
+    },
+    /// Securely delete all yaru data (database, backups, config, logs and state files); lists them without --confirm
+    PurgeAll {
+        /// Actually delete the listed files (asks to type the file count unless --yes)
+        #[arg(long)]
+        confirm: bool,
+    },
+}
+
+/// メッセージの出力形式（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MessageFormatArg {
+    /// 人が読むテキスト
+    Text,
+    /// 1メッセージ1行のJSON
+    Json,
+}
+
+impl From<MessageFormatArg> for MessageFormat {
+    fn from(arg: MessageFormatArg) -> Self {
+        match arg {
+            MessageFormatArg::Text => Self::Text,
+            MessageFormatArg::Json => Self::Json,
+        }
+    }
+}
+
+/// 一括実行の入力形式（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ApplyFormat {
+    /// JSON（配列・オブジェクト、またはそれらを並べたもの）
+    Json,
+    /// YAML（`---`で区切った複数の文書も可）
+    Yaml,
 }
 
 /// 検索対象フィールド（CLI引数用）
@@ -141,20 +469,173 @@ impl From<SearchFieldArg> for SearchField {
     }
 }
 
+/// 検索結果の並び順（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchSortArg {
+    /// 関連度の高い順
+    Relevance,
+    /// 期限日の近い順
+    Due,
+    /// 優先度の高い順
+    Priority,
+}
+
+impl From<SearchSortArg> for SearchSort {
+    fn from(arg: SearchSortArg) -> Self {
+        match arg {
+            SearchSortArg::Relevance => Self::Relevance,
+            SearchSortArg::Due => Self::Due,
+            SearchSortArg::Priority => Self::Priority,
+        }
+    }
+}
+
+/// タスク一覧のグループ化基準（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupByArg {
+    /// ステータス別
+    Status,
+    /// 優先度別
+    Priority,
+    /// タグ別
+    Tag,
+    /// 期限の状況別
+    Due,
+}
+
+impl From<GroupByArg> for TaskGroupBy {
+    fn from(arg: GroupByArg) -> Self {
+        match arg {
+            GroupByArg::Status => Self::Status,
+            GroupByArg::Priority => Self::Priority,
+            GroupByArg::Tag => Self::Tag,
+            GroupByArg::Due => Self::Due,
+        }
+    }
+}
+
+/// 見積もりの精度レポートの集計単位（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AccuracyByArg {
+    /// タスクごと
+    Task,
+    /// タグごと
+    Tag,
+}
+
+impl From<AccuracyByArg> for AccuracyGroupBy {
+    fn from(arg: AccuracyByArg) -> Self {
+        match arg {
+            AccuracyByArg::Task => Self::Task,
+            AccuracyByArg::Tag => Self::Tag,
+        }
+    }
+}
+
+/// タスク一覧の並び順（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SortArg {
+    /// ID順
+    Id,
+    /// タイトル順
+    Title,
+    /// 優先度の高い順
+    Priority,
+    /// 期限日の近い順
+    Due,
+    /// 作成日時の新しい順
+    Created,
+    /// 更新日時の新しい順
+    Updated,
+    /// 緊急度の高い順（優先度・期限日の近さ・経過日数などから計算、[urgency]で重みを設定）
+    Urgency,
+}
+
+impl From<SortArg> for TaskSortKey {
+    fn from(arg: SortArg) -> Self {
+        match arg {
+            SortArg::Id => Self::Id,
+            SortArg::Title => Self::Title,
+            SortArg::Priority => Self::Priority,
+            SortArg::Due => Self::Due,
+            SortArg::Created => Self::Created,
+            SortArg::Updated => Self::Updated,
+            SortArg::Urgency => Self::Urgency,
+        }
+    }
+}
+
+/// タスク一覧の表示形式（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ListFormatArg {
+    /// ID・タイトル・ステータス・期限日のみ
+    Compact,
+    /// 全項目を切り詰めずに表示
+    Wide,
+}
+
+impl From<ListFormatArg> for TaskListFormat {
+    fn from(arg: ListFormatArg) -> Self {
+        match arg {
+            ListFormatArg::Compact => Self::Compact,
+            ListFormatArg::Wide => Self::Wide,
+        }
+    }
+}
+
 /// タスク管理用のサブコマンド
 #[derive(Subcommand, Debug)]
 pub enum TaskCommands {
     /// List all tasks
     List {
-        /// Filter conditions (e.g., status:done, status:pending)
+        /// Filter conditions (e.g., status:done, status:pending, tag:仕事, assignee:alice; overrides [list] filters)
         #[arg(short, long, value_parser = clap::value_parser!(Filter))]
         filter: Option<Vec<Filter>>,
+        /// Match tag filters by partial name instead of the full name
+        #[arg(long)]
+        partial_tag: bool,
+        /// Group tasks into sections (status, priority, tag, due)
+        #[arg(short, long)]
+        group_by: Option<GroupByArg>,
+        /// Output format (compact: ID/title/status/due only, wide: all fields)
+        #[arg(long)]
+        format: Option<ListFormatArg>,
+        /// Sort order (overrides [list] sort)
+        #[arg(long)]
+        sort: Option<SortArg>,
+        /// Columns to show, comma-separated (e.g., id,title,priority,due; overrides [list] columns)
+        #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(TaskColumn))]
+        columns: Option<Vec<TaskColumn>>,
+        /// Print one line per task from a template (e.g., "{{id}} {{title}} ({{due_date}})") or the name of a template in [list.templates]
+        #[arg(long, conflicts_with_all = ["group_by", "format", "columns"])]
+        template: Option<String>,
+        /// Include completed and snoozed tasks (completed tasks are hidden by default unless [list] hide_completed = false)
+        #[arg(short, long)]
+        all: bool,
+        /// Include tasks in contexts other than the current one
+        #[arg(long)]
+        all_contexts: bool,
+        /// Only tasks due before this date (YYYY-MM-DD format)
+        #[arg(long, value_parser = parse_date)]
+        due_before: Option<NaiveDate>,
+        /// Only tasks due after this date (YYYY-MM-DD format)
+        #[arg(long, value_parser = parse_date)]
+        due_after: Option<NaiveDate>,
+        /// Only tasks created on or after this date (YYYY-MM-DD) or within a period (e.g., 7d, 12h, 2w)
+        #[arg(long, value_name = "DATE|PERIOD", value_parser = parse_since)]
+        created_after: Option<DateTime<Utc>>,
+        /// Only tasks updated on or after this date (YYYY-MM-DD) or within a period (e.g., 7d, 12h, 2w)
+        #[arg(long, value_name = "DATE|PERIOD", value_parser = parse_since)]
+        updated_since: Option<DateTime<Utc>>,
     },
     /// Show task details
     Show {
-        /// Task ID to show
-        #[arg(value_parser = parse_positive_id)]
-        id: i32,
+        /// Task ID to show (unique prefix allowed, select interactively if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
+        /// Show the description as written instead of rendering it as Markdown
+        #[arg(long)]
+        raw: bool,
     },
     /// Add a new task
     Add {
@@ -170,24 +651,66 @@ pub enum TaskCommands {
         /// Task priority
         #[arg(short, long)]
         priority: Option<Priority>,
-        /// Tag IDs to attach (comma-separated)
-        #[arg(long, value_delimiter = ',')]
-        tags: Option<Vec<i32>>,
-        /// Task due date (YYYY-MM-DD format)
-        #[arg(long, value_parser = parse_date)]
-        due_date: Option<NaiveDate>,
+        /// Tag IDs to attach (comma-separated, unique prefix allowed)
+        #[arg(long, value_delimiter = ',', value_parser = parse_id_prefix)]
+        tags: Option<Vec<String>>,
+        /// Tag names to attach (comma-separated, missing tags are created)
+        #[arg(long, value_delimiter = ',', value_parser = parse_non_empty_string)]
+        tag_names: Option<Vec<String>>,
+        /// Task due date (YYYY-MM-DD, today, tomorrow, a weekday or a period such as +3d, +2w, +3bd)
+        #[arg(long, value_name = "DATE|PERIOD", value_parser = parse_relative_date)]
+        due_date: Option<RelativeDate>,
+        /// Add without checking for similar open tasks
+        #[arg(long)]
+        force: bool,
+        /// Custom field to set (KEY=VALUE, repeatable)
+        #[arg(long = "field", value_name = "KEY=VALUE", value_parser = parse_custom_field)]
+        fields: Vec<CustomField>,
+        /// Repeat the task after completion (daily, weekly, monthly, yearly, "every N days|weeks|months|years")
+        #[arg(long, value_name = "RULE", value_parser = parse_recurrence)]
+        repeat: Option<Recurrence>,
+        /// Estimated effort (e.g., 2h30m, 3h, 45m)
+        #[arg(long, value_name = "DURATION", value_parser = parse_estimate)]
+        estimate: Option<Estimate>,
+        /// Context where the task can be done (e.g., @home, @office)
+        #[arg(long, value_parser = parse_context)]
+        context: Option<TaskContext>,
+        /// Person responsible for the task (e.g., alice)
+        #[arg(long, value_parser = parse_assignee)]
+        assignee: Option<Assignee>,
+        /// Add one task per non-empty line of a file (inline !priority, #tag, @YYYY-MM-DD, @context)
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["title", "stdin"])]
+        from_file: Option<PathBuf>,
+        /// Add one task per non-empty line of standard input (same syntax as --from-file)
+        #[arg(long, conflicts_with = "title")]
+        stdin: bool,
+        /// Add a task from the system clipboard (first line = title, the rest = description)
+        #[arg(long, conflicts_with_all = ["title", "description", "from_file", "stdin"])]
+        from_clipboard: bool,
     },
     /// Delete a task by ID
     Delete {
-        /// Task ID to delete
-        #[arg(value_parser = parse_positive_id)]
-        id: i32,
+        /// Task ID to delete (full ID, select interactively if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
+    },
+    /// Mark a task as completed
+    Done {
+        /// Task ID to complete (full ID, select interactively if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
+        /// Create a follow-up task with the same priority, tags and context
+        #[arg(long)]
+        follow_up: bool,
+        /// Title of the follow-up task (implies --follow-up)
+        #[arg(long, value_name = "TITLE", value_parser = parse_non_empty_string)]
+        follow_up_title: Option<String>,
     },
     /// Edit a task
     Edit {
-        /// Task ID to edit
-        #[arg(value_parser = parse_positive_id)]
-        id: i32,
+        /// Task ID to edit (full ID, select interactively if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
         /// Task title
         #[arg(short, long, value_parser = parse_non_empty_string)]
         title: Option<String>,
@@ -200,27 +723,183 @@ pub enum TaskCommands {
         /// Task priority
         #[arg(short, long)]
         priority: Option<Priority>,
-        /// Tag IDs to attach (comma-separated, replaces existing)
-        #[arg(long, value_delimiter = ',')]
-        tags: Option<Vec<i32>>,
-        /// Task due date (YYYY-MM-DD format)
-        #[arg(long, value_parser = parse_date)]
-        due_date: Option<NaiveDate>,
+        /// Tag IDs to attach (comma-separated, unique prefix allowed, replaces existing)
+        #[arg(long, value_delimiter = ',', value_parser = parse_id_prefix)]
+        tags: Option<Vec<String>>,
+        /// Task due date (YYYY-MM-DD, today, tomorrow, a weekday or a period such as +3d, +2w, +3bd)
+        #[arg(long, value_name = "DATE|PERIOD", value_parser = parse_relative_date)]
+        due_date: Option<RelativeDate>,
         /// Clear due date
         #[arg(long, conflicts_with = "due_date")]
         clear_due_date: bool,
+        /// Allow moving a completed task back to an open status
+        #[arg(long, requires = "status")]
+        reopen: bool,
+        /// Custom field to set or replace (KEY=VALUE, repeatable)
+        #[arg(long = "field", value_name = "KEY=VALUE", value_parser = parse_custom_field)]
+        fields: Vec<CustomField>,
+        /// Custom field key to remove (repeatable)
+        #[arg(long = "unset-field", value_name = "KEY")]
+        unset_fields: Vec<String>,
+        /// Repeat the task after completion (daily, weekly, monthly, yearly, "every N days|weeks|months|years")
+        #[arg(long, value_name = "RULE", value_parser = parse_recurrence)]
+        repeat: Option<Recurrence>,
+        /// Stop repeating the task
+        #[arg(long, conflicts_with = "repeat")]
+        no_repeat: bool,
+        /// Estimated effort (e.g., 2h30m, 3h, 45m)
+        #[arg(long, value_name = "DURATION", value_parser = parse_estimate)]
+        estimate: Option<Estimate>,
+        /// Clear the estimated effort
+        #[arg(long, conflicts_with = "estimate")]
+        clear_estimate: bool,
+        /// Context where the task can be done (e.g., @home, @office)
+        #[arg(long, value_parser = parse_context)]
+        context: Option<TaskContext>,
+        /// Clear the context
+        #[arg(long, conflicts_with = "context")]
+        clear_context: bool,
+        /// Person responsible for the task (e.g., alice)
+        #[arg(long, value_parser = parse_assignee)]
+        assignee: Option<Assignee>,
+        /// Unassign the task
+        #[arg(long, conflicts_with = "assignee")]
+        clear_assignee: bool,
+    },
+    /// Manage checklist items of a task
+    Check {
+        /// Task ID (unique prefix allowed for list, full ID to change items)
+        #[arg(value_parser = parse_id_prefix)]
+        id: String,
+        #[command(subcommand)]
+        command: ChecklistCommands,
+    },
+    /// Hide a task from the default list and agenda until a date
+    Snooze {
+        /// Task ID (full ID)
+        #[arg(value_parser = parse_id_prefix)]
+        id: String,
+        /// Date to show the task again (YYYY-MM-DD, a weekday, ...) or a period from today (e.g., +3d, +2w, +3bd)
+        #[arg(long, value_name = "DATE|PERIOD", allow_hyphen_values = true, value_parser = parse_relative_date)]
+        until: RelativeDate,
+    },
+    /// Show a snoozed task again
+    Unsnooze {
+        /// Task ID (full ID)
+        #[arg(value_parser = parse_id_prefix)]
+        id: String,
+    },
+    /// Open a link (URL) found in the task's title or description in the browser
+    Open {
+        /// Task ID (unique prefix allowed)
+        #[arg(value_parser = parse_id_prefix)]
+        id: String,
+        /// Which link to open, in the order listed by `task show` (1 = first)
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        nth: u32,
+    },
+    /// Link a task to the current git repository, branch and commit
+    LinkGit {
+        /// Task ID (full ID)
+        #[arg(value_parser = parse_id_prefix)]
+        id: String,
     },
     /// Show task statistics
-    Stats,
+    Stats {
+        /// Show flow metrics instead: average time in Pending and In Progress, cycle time and lead time
+        #[arg(long)]
+        flow: bool,
+    },
+    /// Show open tasks that have not been updated for a while, least recently updated first
+    Stale {
+        /// Treat tasks not updated for this many days as stale, regardless of status (defaults to [stale] in the config)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        days: Option<u32>,
+    },
+    /// Pick a random open task to work on, then offer to start or focus on it
+    Pick {
+        /// Filter conditions for the candidates (e.g., tag:仕事, status:pending)
+        #[arg(short, long, value_parser = clap::value_parser!(Filter))]
+        filter: Option<Vec<Filter>>,
+        /// Match tag filters by partial name instead of the full name
+        #[arg(long)]
+        partial_tag: bool,
+        /// Favor urgent tasks: the chance of each task grows with its urgency score
+        #[arg(short, long)]
+        weighted: bool,
+        /// Include tasks in contexts other than the current one
+        #[arg(long)]
+        all_contexts: bool,
+        /// Mark the picked task as In Progress without asking
+        #[arg(long, conflicts_with = "focus")]
+        start: bool,
+        /// Focus on the picked task without asking (see yaru focus)
+        #[arg(long)]
+        focus: bool,
+        /// Seed for the random choice, to make picks reproducible
+        #[arg(long, hide = true)]
+        seed: Option<u64>,
+    },
     /// Search tasks by keyword
     Search {
-        /// Search keywords (space-separated for AND condition)
+        /// Search query: space-separated terms (AND), `OR`, "quoted phrases" and -excluded terms
         /// Will prompt in interactive mode if omitted
+        #[arg(allow_hyphen_values = true)]
         keywords: Option<String>,
 
         /// Search target field (title, description, all)
         #[arg(short, long, default_value = "all")]
         field: SearchFieldArg,
+        /// Order of results (relevance: title matches and close terms first)
+        #[arg(long, value_enum, default_value_t = SearchSortArg::Relevance)]
+        sort: SearchSortArg,
+        /// Only tasks with this status
+        #[arg(short, long)]
+        status: Option<Status>,
+        /// Only tasks with this priority
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// Only tasks with all of these tag IDs (comma-separated, unique prefix allowed)
+        #[arg(long, value_delimiter = ',', value_parser = parse_id_prefix)]
+        tag: Option<Vec<String>>,
+        /// Only tasks due before this date (YYYY-MM-DD format)
+        #[arg(long, value_parser = parse_date)]
+        due_before: Option<NaiveDate>,
+        /// Only tasks due after this date (YYYY-MM-DD format)
+        #[arg(long, value_parser = parse_date)]
+        due_after: Option<NaiveDate>,
+        /// Only tasks created on or after this date (YYYY-MM-DD) or within a period (e.g., 7d, 12h, 2w)
+        #[arg(long, value_name = "DATE|PERIOD", value_parser = parse_since)]
+        created_after: Option<DateTime<Utc>>,
+        /// Only tasks updated on or after this date (YYYY-MM-DD) or within a period (e.g., 7d, 12h, 2w)
+        #[arg(long, value_name = "DATE|PERIOD", value_parser = parse_since)]
+        updated_since: Option<DateTime<Utc>>,
+        /// Only tasks whose custom field has this value (KEY=VALUE, repeatable)
+        #[arg(long = "custom-field", value_name = "KEY=VALUE", value_parser = parse_custom_field)]
+        custom_fields: Vec<CustomField>,
+    },
+}
+
+/// チェックリスト操作用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum ChecklistCommands {
+    /// List checklist items with progress
+    List,
+    /// Add a checklist item
+    Add {
+        /// Item text
+        #[arg(value_parser = parse_non_empty_string)]
+        text: String,
+    },
+    /// Toggle the done flag of a checklist item
+    Toggle {
+        /// Item number shown by `task check <id> list`
+        number: usize,
+    },
+    /// Remove a checklist item
+    Remove {
+        /// Item number shown by `task check <id> list`
+        number: usize,
     },
 }
 
@@ -231,9 +910,9 @@ pub enum TagCommands {
     List,
     /// Show tag details
     Show {
-        /// Tag ID to show
-        #[arg(value_parser = parse_positive_id)]
-        id: i32,
+        /// Tag ID to show (unique prefix allowed, select interactively if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
     },
     /// Add a new tag
     Add {
@@ -243,27 +922,282 @@ pub enum TagCommands {
         /// Tag description
         #[arg(short, long, value_parser = parse_non_empty_string)]
         description: Option<String>,
+        /// Parent tag ID to nest the new tag under (unique prefix allowed)
+        #[arg(short, long, value_parser = parse_id_prefix)]
+        parent: Option<String>,
+        /// Print the existing tag instead of failing when the name is taken
+        #[arg(long, requires = "name")]
+        get_or_create: bool,
     },
     /// Delete a tag by ID
     Delete {
-        /// Tag ID to delete
-        #[arg(value_parser = parse_positive_id)]
-        id: i32,
+        /// Tag ID to delete (full ID, select interactively if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
     },
     /// Edit a tag
     Edit {
-        /// Tag ID to edit
-        #[arg(value_parser = parse_positive_id)]
-        id: i32,
+        /// Tag ID to edit (full ID, select interactively if omitted)
+        #[arg(value_parser = parse_id_prefix)]
+        id: Option<String>,
         /// Tag name
         #[arg(short, long, value_parser = parse_non_empty_string)]
         name: Option<String>,
         /// Tag description
         #[arg(short, long, value_parser = parse_non_empty_string)]
         description: Option<String>,
+        /// Move the tag under this parent tag ID (unique prefix allowed)
+        #[arg(short, long, value_parser = parse_id_prefix)]
+        parent: Option<String>,
+        /// Make the tag a top-level tag
+        #[arg(long, conflicts_with = "parent")]
+        no_parent: bool,
+    },
+}
+
+/// データベース管理用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum DbCommands {
+    /// Encrypt task titles and descriptions with a passphrase
+    Encrypt,
+    /// Decrypt the database and remove the passphrase
+    Decrypt,
+    /// Show the database path, size, migration status, row counts and integrity check
+    Status,
+    /// Apply pending migrations
+    Migrate,
+}
+
+/// デーモン管理用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum DaemonCommands {
+    /// Run the daemon in the foreground (uses a systemd-activated socket if one is passed)
+    Start {
+        /// Exit after this many seconds without requests (overrides [daemon] idle_timeout)
+        #[arg(long, value_name = "SECS")]
+        idle_timeout: Option<u64>,
+    },
+    /// Show whether the daemon is running
+    Status,
+    /// Stop the running daemon
+    Stop,
+}
+
+/// git連携用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum GitCommands {
+    /// Manage git hooks of the current repository
+    Hook {
+        #[command(subcommand)]
+        command: GitHookCommands,
+    },
+    /// Append `yaru:#<id>` trailers for tasks linked to the current branch (run by the hook)
+    #[command(hide = true)]
+    PrepareCommitMsg {
+        /// Commit message file passed by git
+        file: PathBuf,
+        /// Source of the commit message passed by git
+        source: Option<String>,
+    },
+    /// Complete tasks referenced by merged commits (run by the hook)
+    #[command(hide = true)]
+    PostMerge,
+}
+
+/// gitフック管理用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum GitHookCommands {
+    /// Install the prepare-commit-msg and post-merge hooks into the current repository
+    Install,
+}
+
+/// 外部のIssueトラッカーや他のデータベースとの同期用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum SyncCommands {
+    /// Import open GitHub issues as tasks and pull changes since the last sync
+    Github {
+        /// Repository in OWNER/NAME form
+        #[arg(long, value_name = "OWNER/NAME")]
+        repo: String,
+        /// Also close or reopen issues for tasks completed or reopened locally
+        #[arg(long)]
+        push: bool,
+    },
+    /// Sync tasks both ways with another yaru database file (e.g., a copy on another machine)
+    File {
+        /// Path to the other database file
+        path: PathBuf,
+        /// Resolve tasks changed in both databases without asking
+        #[arg(long, value_enum)]
+        prefer: Option<SyncPreference>,
+        /// Show what would change without writing to either database
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Push or pull an encrypted snapshot of the database to the storage in [remote] (S3 or WebDAV)
+    Remote {
+        #[command(subcommand)]
+        command: RemoteCommands,
+    },
+}
+
+/// リモートストレージとの同期用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum RemoteCommands {
+    /// Upload an encrypted snapshot (refused if the remote changed since the last push or pull)
+    Push {
+        /// Overwrite the remote snapshot even if it changed since the last push or pull
+        #[arg(long)]
+        force: bool,
+    },
+    /// Replace the database with the remote snapshot (the current database is backed up first)
+    Pull {
+        /// Download the snapshot even if it has not changed since the last push or pull
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// データベース間の同期で、両方で変更されたタスクの解決方法（CLI引数用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SyncPreference {
+    /// 使用中のデータベースの内容
+    Local,
+    /// 相手のデータベースの内容
+    Other,
+    /// 更新日時が新しい方の内容（片方で削除されている場合は残っている方）
+    Newer,
+}
+
+/// 他のタスク管理ツールからの取り込み用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum ImportCommands {
+    /// Import a Todoist export (Sync API dump or REST API task list)
+    Todoist {
+        /// Path to the exported JSON file
+        file: PathBuf,
+        /// Show what would be created without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import the output of `task export`
+    Taskwarrior {
+        /// Path to the exported JSON file
+        file: PathBuf,
+        /// Show what would be created without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import events (VEVENT) and to-dos (VTODO) from an iCalendar file
+    Ics {
+        /// Path to the .ics file
+        file: PathBuf,
+        /// Only import entries dated on or after this date (YYYY-MM-DD)
+        #[arg(long, value_parser = parse_date)]
+        from: Option<NaiveDate>,
+        /// Only import entries dated on or before this date (YYYY-MM-DD)
+        #[arg(long, value_parser = parse_date)]
+        to: Option<NaiveDate>,
+        /// Show what would be created without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// 受信したメッセージからのタスクの作成用のサブコマンド
+#[cfg(feature = "mail")]
+#[derive(Subcommand, Debug)]
+pub enum IngestCommands {
+    /// Create tasks from mail in a maildir folder or an IMAP mailbox (subject → title, body → description)
+    Mail {
+        /// Read messages from this maildir folder (defaults to [mail] maildir)
+        #[arg(long, conflicts_with = "imap")]
+        maildir: Option<PathBuf>,
+        /// Read messages from this IMAP server over TLS (defaults to [mail] imap_host)
+        #[arg(long)]
+        imap: Option<String>,
+        /// IMAP user name (defaults to [mail] imap_user)
+        #[arg(long)]
+        user: Option<String>,
+        /// IMAP folder to read (defaults to [mail] imap_folder, usually INBOX)
+        #[arg(long)]
+        folder: Option<String>,
+        /// Only messages whose subject contains this text (case-insensitive)
+        #[arg(long = "match", value_name = "TEXT")]
+        subject: Option<String>,
+        /// Only messages whose sender contains this text (case-insensitive)
+        #[arg(long)]
+        from: Option<String>,
+        /// Tag to add to the created tasks (defaults to [mail] tag)
+        #[arg(long)]
+        tag: Option<String>,
+        /// Show what would be created without changing anything
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
+/// コンテキストの切り替え用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum ContextCommands {
+    /// Switch to a context (e.g., @home)
+    Set {
+        /// Context name (the leading @ is optional)
+        #[arg(value_parser = parse_context)]
+        context: TaskContext,
+    },
+    /// Clear the current context and show tasks in all contexts
+    Clear,
+    /// Show the current context
+    Show,
+    /// List contexts used by open tasks
+    List,
+}
+
+/// フォーカスの操作用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum FocusCommands {
+    /// Complete the focused task and stop focusing
+    Done,
+    /// Stop focusing without completing the task
+    Clear,
+}
+
+/// 実行記録の操作用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum AuditCommands {
+    /// Show the most recent invocations (command, arguments, duration and result), oldest first
+    Tail {
+        /// Number of invocations to show
+        #[arg(short = 'n', long, default_value_t = 20)]
+        lines: usize,
+        /// Print the raw JSON lines
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// レポート用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum ReportCommands {
+    /// Compare estimated and actual (recorded) time per task or tag, with the variance
+    Accuracy {
+        /// Group the report by task or by tag
+        #[arg(long, value_enum, default_value = "task")]
+        by: AccuracyByArg,
+        /// Include tasks that are not completed yet (their actual time may still grow)
+        #[arg(short, long)]
+        all: bool,
+    },
+}
+
+/// 定期実行用のサブコマンド
+#[derive(Subcommand, Debug)]
+pub enum CronCommands {
+    /// Create due recurring tasks, notify newly overdue tasks and archive old completed tasks
+    Run,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,18 +1220,62 @@ mod tests {
         assert!(args.command.is_some());
     }
 
-    // SearchCommand のテストケース
+    #[test]
+    fn test_args_no_color_flag() {
+        // --no-colorはサブコマンドの後ろにも指定可能
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "--no-color"]).unwrap();
+        assert!(args.no_color);
+
+        let args = Args::try_parse_from(vec!["yaru", "task", "list"]).unwrap();
+        assert!(!args.no_color);
+    }
 
     #[test]
-    fn test_task_search_basic() {
-        // 基本的な検索のパース
-        let args = Args::try_parse_from(vec!["yaru", "task", "search", "買い物"]);
-        assert!(args.is_ok());
-        let args = args.unwrap();
-        if let Some(Commands::Task {
-            command: TaskCommands::Search { keywords, field },
-        }) = args.command
-        {
+    fn test_args_verbosity_flags() {
+        let args = Args::try_parse_from(vec!["yaru", "-vv", "task", "list"]).unwrap();
+        assert_eq!(args.verbose, 2);
+        assert!(!args.quiet);
+
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "--quiet"]).unwrap();
+        assert_eq!(args.verbose, 0);
+        assert!(args.quiet);
+
+        // -vと--quietは同時に指定できない
+        assert!(Args::try_parse_from(vec!["yaru", "-v", "-q", "task", "list"]).is_err());
+    }
+
+    #[test]
+    fn test_args_profile_flag() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "--profile"]).unwrap();
+        assert!(args.profile);
+
+        let args = Args::try_parse_from(vec!["yaru", "task", "list"]).unwrap();
+        assert!(!args.profile);
+    }
+
+    #[test]
+    fn test_args_no_migrate_flag() {
+        let args = Args::try_parse_from(vec!["yaru", "--no-migrate", "task", "list"]).unwrap();
+        assert!(args.no_migrate);
+
+        let args = Args::try_parse_from(vec!["yaru", "task", "list"]).unwrap();
+        assert!(!args.no_migrate);
+    }
+
+    // SearchCommand のテストケース
+
+    #[test]
+    fn test_task_search_basic() {
+        // 基本的な検索のパース
+        let args = Args::try_parse_from(vec!["yaru", "task", "search", "買い物"]);
+        assert!(args.is_ok());
+        let args = args.unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Search {
+                keywords, field, ..
+            },
+        }) = args.command
+        {
             assert_eq!(keywords, Some("買い物".to_string()));
             assert_eq!(field, SearchFieldArg::All); // デフォルト
         } else {
@@ -312,7 +1290,9 @@ mod tests {
         assert!(args.is_ok());
         let args = args.unwrap();
         if let Some(Commands::Task {
-            command: TaskCommands::Search { keywords, field },
+            command: TaskCommands::Search {
+                keywords, field, ..
+            },
         }) = args.command
         {
             assert_eq!(keywords, Some("レポート 作成".to_string()));
@@ -322,6 +1302,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_task_search_with_filters() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "search",
+            "レポート",
+            "--status",
+            "pending",
+            "--priority",
+            "high",
+            "--tag",
+            "3,5",
+            "--due-before",
+            "2026-01-01",
+        ])
+        .unwrap();
+        if let Some(Commands::Task {
+            command:
+                TaskCommands::Search {
+                    keywords,
+                    status,
+                    priority,
+                    tag,
+                    due_before,
+                    due_after,
+                    ..
+                },
+        }) = args.command
+        {
+            assert_eq!(keywords, Some("レポート".to_string()));
+            assert_eq!(status, Some(Status::Pending));
+            assert_eq!(priority, Some(Priority::High));
+            assert_eq!(tag, Some(vec!["3".to_string(), "5".to_string()]));
+            assert_eq!(due_before, NaiveDate::from_ymd_opt(2026, 1, 1));
+            assert_eq!(due_after, None);
+        } else {
+            panic!("Expected Task::Search command");
+        }
+    }
+
+    #[test]
+    fn test_task_search_query_starting_with_exclude() {
+        // 除外語から始まるクエリもオプションとして解釈しない
+        let args = Args::try_parse_from(vec!["yaru", "task", "search", "-下書き"]).unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Search { keywords, .. },
+        }) = args.command
+        {
+            assert_eq!(keywords, Some("-下書き".to_string()));
+        } else {
+            panic!("Expected Task::Search command");
+        }
+    }
+
     #[test]
     fn test_task_search_field_title() {
         // フィールドオプション付きのパース
@@ -330,7 +1365,9 @@ mod tests {
         assert!(args.is_ok());
         let args = args.unwrap();
         if let Some(Commands::Task {
-            command: TaskCommands::Search { keywords, field },
+            command: TaskCommands::Search {
+                keywords, field, ..
+            },
         }) = args.command
         {
             assert_eq!(keywords, Some("買い物".to_string()));
@@ -340,6 +1377,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_task_search_sort() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "search", "report", "--sort", "due"])
+            .unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Search { sort, .. },
+        }) = args.command
+        {
+            assert_eq!(sort, SearchSortArg::Due);
+        } else {
+            panic!("Expected Task::Search command");
+        }
+
+        let args = Args::try_parse_from(vec!["yaru", "task", "search", "report"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Task {
+                command: TaskCommands::Search {
+                    sort: SearchSortArg::Relevance,
+                    ..
+                },
+            })
+        ));
+        assert!(
+            Args::try_parse_from(vec!["yaru", "task", "search", "x", "--sort", "title"]).is_err()
+        );
+    }
+
     #[test]
     fn test_task_search_no_keywords() {
         // キーワード省略時（対話モード）のパース
@@ -347,7 +1412,9 @@ mod tests {
         assert!(args.is_ok());
         let args = args.unwrap();
         if let Some(Commands::Task {
-            command: TaskCommands::Search { keywords, field },
+            command: TaskCommands::Search {
+                keywords, field, ..
+            },
         }) = args.command
         {
             assert_eq!(keywords, None); // キーワードなし
@@ -356,4 +1423,1053 @@ mod tests {
             panic!("Expected Task::Search command");
         }
     }
+
+    #[test]
+    fn test_task_show_without_id() {
+        // ID省略時（対話的に選択）のパース
+        let args = Args::try_parse_from(vec!["yaru", "task", "show"]).unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Show { id, raw },
+        }) = args.command
+        {
+            assert_eq!(id, None);
+            assert!(!raw);
+        } else {
+            panic!("Expected Task::Show command");
+        }
+    }
+
+    #[test]
+    fn test_task_open_nth() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "open", "12", "--nth", "2"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Task {
+                command: TaskCommands::Open { id, nth: 2 },
+            }) if id == "12"
+        ));
+
+        // 番号は1始まり
+        assert!(Args::try_parse_from(vec!["yaru", "task", "open", "12", "--nth", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_task_edit_without_id_with_options() {
+        // ID省略時でも編集オプションを指定できる
+        let args =
+            Args::try_parse_from(vec!["yaru", "task", "edit", "--title", "新タイトル"]).unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Edit { id, title, .. },
+        }) = args.command
+        {
+            assert_eq!(id, None);
+            assert_eq!(title, Some("新タイトル".to_string()));
+        } else {
+            panic!("Expected Task::Edit command");
+        }
+    }
+
+    #[test]
+    fn test_task_add_with_custom_fields() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "add",
+            "見積もり",
+            "--field",
+            "estimate=3h",
+            "--field",
+            "client=ACME",
+        ])
+        .unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Add { fields, .. },
+        }) = args.command
+        {
+            let fields: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+            assert_eq!(fields, vec!["estimate=3h", "client=ACME"]);
+        } else {
+            panic!("Expected Task::Add command");
+        }
+    }
+
+    #[test]
+    fn test_task_add_with_invalid_custom_field() {
+        let result = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "add",
+            "見積もり",
+            "--field",
+            "estimate",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_task_check_subcommands() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "check", "12", "add", "write tests"])
+            .unwrap();
+        if let Some(Commands::Task {
+            command:
+                TaskCommands::Check {
+                    id,
+                    command: ChecklistCommands::Add { text },
+                },
+        }) = args.command
+        {
+            assert_eq!(id, "12");
+            assert_eq!(text, "write tests");
+        } else {
+            panic!("Expected Task::Check add command");
+        }
+
+        let args =
+            Args::try_parse_from(vec!["yaru", "task", "check", "12", "toggle", "2"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Task {
+                command: TaskCommands::Check {
+                    command: ChecklistCommands::Toggle { number: 2 },
+                    ..
+                },
+            })
+        ));
+    }
+
+    #[test]
+    fn test_git_subcommands() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "link-git", "12"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Task {
+                command: TaskCommands::LinkGit { id },
+            }) if id == "12"
+        ));
+
+        let args = Args::try_parse_from(vec!["yaru", "git", "hook", "install"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Git {
+                command: GitCommands::Hook {
+                    command: GitHookCommands::Install
+                },
+            })
+        ));
+
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "git",
+            "prepare-commit-msg",
+            ".git/COMMIT_EDITMSG",
+            "message",
+        ])
+        .unwrap();
+        if let Some(Commands::Git {
+            command: GitCommands::PrepareCommitMsg { file, source },
+        }) = args.command
+        {
+            assert_eq!(file, PathBuf::from(".git/COMMIT_EDITMSG"));
+            assert_eq!(source.as_deref(), Some("message"));
+        } else {
+            panic!("Expected Git::PrepareCommitMsg command");
+        }
+    }
+
+    #[test]
+    fn test_sync_github() {
+        let args = Args::try_parse_from(vec![
+            "yaru", "sync", "github", "--repo", "acme/app", "--push",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Sync {
+                command: SyncCommands::Github { repo, push },
+            }) if repo == "acme/app" && push
+        ));
+
+        assert!(Args::try_parse_from(vec!["yaru", "sync", "github"]).is_err());
+    }
+
+    #[test]
+    fn test_sync_file() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "sync",
+            "file",
+            "laptop.db",
+            "--prefer",
+            "newer",
+            "--dry-run",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Sync {
+                command: SyncCommands::File {
+                    path,
+                    prefer: Some(SyncPreference::Newer),
+                    dry_run: true,
+                },
+            }) if path == std::path::Path::new("laptop.db")
+        ));
+
+        assert!(Args::try_parse_from(vec!["yaru", "sync", "file"]).is_err());
+    }
+
+    #[test]
+    fn test_sync_remote() {
+        let args = Args::try_parse_from(vec!["yaru", "sync", "remote", "push", "--force"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Sync {
+                command: SyncCommands::Remote {
+                    command: RemoteCommands::Push { force: true },
+                },
+            })
+        ));
+
+        let args = Args::try_parse_from(vec!["yaru", "sync", "remote", "pull"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Sync {
+                command: SyncCommands::Remote {
+                    command: RemoteCommands::Pull { force: false },
+                },
+            })
+        ));
+    }
+
+    #[test]
+    fn test_apply_command() {
+        let args = Args::try_parse_from(vec!["yaru", "apply", "-", "--format", "yaml"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Apply {
+                input,
+                format: Some(ApplyFormat::Yaml),
+            }) if input == "-"
+        ));
+    }
+
+    #[test]
+    fn test_cron_run_and_repeat_options() {
+        let args = Args::try_parse_from(vec!["yaru", "cron", "run"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Cron {
+                command: CronCommands::Run
+            })
+        ));
+
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "add",
+            "Review",
+            "--repeat",
+            "every 2 weeks",
+        ])
+        .unwrap();
+        let Some(Commands::Task {
+            command: TaskCommands::Add { repeat, .. },
+        }) = args.command
+        else {
+            panic!("Expected task add");
+        };
+        assert_eq!(repeat.unwrap().to_string(), "every 2 weeks");
+
+        assert!(
+            Args::try_parse_from(vec!["yaru", "task", "add", "x", "--repeat", "hourly"]).is_err()
+        );
+        assert!(
+            Args::try_parse_from(vec![
+                "yaru",
+                "task",
+                "edit",
+                "1",
+                "--repeat",
+                "daily",
+                "--no-repeat"
+            ])
+            .is_err()
+        );
+    }
+
+    #[cfg(feature = "mail")]
+    #[test]
+    fn test_ingest_mail() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "ingest",
+            "mail",
+            "--imap",
+            "imap.example.com",
+            "--match",
+            "[todo]",
+            "--tag",
+            "email",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Ingest {
+                command: IngestCommands::Mail { imap: Some(host), subject: Some(subject), tag: Some(tag), maildir: None, dry_run: false, .. },
+            }) if host == "imap.example.com" && subject == "[todo]" && tag == "email"
+        ));
+
+        assert!(
+            Args::try_parse_from(vec![
+                "yaru",
+                "ingest",
+                "mail",
+                "--maildir",
+                "Mail",
+                "--imap",
+                "imap.example.com"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_import_subcommands() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "import",
+            "todoist",
+            "export.json",
+            "--dry-run",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Import {
+                command: ImportCommands::Todoist { file, dry_run },
+            }) if file == std::path::Path::new("export.json") && dry_run
+        ));
+
+        let args = Args::try_parse_from(vec!["yaru", "import", "taskwarrior", "tw.json"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Import {
+                command: ImportCommands::Taskwarrior { dry_run: false, .. },
+            })
+        ));
+
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "import",
+            "ics",
+            "calendar.ics",
+            "--from",
+            "2026-10-01",
+            "--to",
+            "2026-10-31",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.command,
+            Some(Commands::Import {
+                command: ImportCommands::Ics { from: Some(from), to: Some(to), .. },
+            }) if from == NaiveDate::from_ymd_opt(2026, 10, 1).unwrap()
+                && to == NaiveDate::from_ymd_opt(2026, 10, 31).unwrap()
+        ));
+        assert!(
+            Args::try_parse_from(vec!["yaru", "import", "ics", "c.ics", "--from", "10/01"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_tag_delete_with_id() {
+        let args = Args::try_parse_from(vec!["yaru", "tag", "delete", "3"]).unwrap();
+        if let Some(Commands::Tag {
+            command: TagCommands::Delete { id },
+        }) = args.command
+        {
+            assert_eq!(id, Some("3".to_string()));
+        } else {
+            panic!("Expected Tag::Delete command");
+        }
+    }
+
+    #[test]
+    fn test_tag_delete_with_invalid_id() {
+        let args = Args::try_parse_from(vec!["yaru", "tag", "delete", "0"]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn test_task_show_with_id_prefix() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "show", "12"]).unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Show { id, .. },
+        }) = args.command
+        {
+            assert_eq!(id, Some("12".to_string()));
+        } else {
+            panic!("Expected Task::Show command");
+        }
+
+        assert!(Args::try_parse_from(vec!["yaru", "task", "show", "1a"]).is_err());
+        assert!(Args::try_parse_from(vec!["yaru", "task", "show", "-1"]).is_err());
+    }
+
+    #[test]
+    fn test_task_list_group_by() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "--group-by", "tag"]).unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::List { group_by, .. },
+        }) = args.command
+        {
+            assert_eq!(group_by, Some(GroupByArg::Tag));
+        } else {
+            panic!("Expected Task::List command");
+        }
+    }
+
+    #[test]
+    fn test_task_list_group_by_invalid() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "--group-by", "color"]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn test_task_add_tag_names() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "add",
+            "タスク",
+            "--tag-names",
+            "仕事,緊急",
+        ])
+        .unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::Add { tag_names, .. },
+        }) = args.command
+        {
+            assert_eq!(
+                tag_names,
+                Some(vec!["仕事".to_string(), "緊急".to_string()])
+            );
+        } else {
+            panic!("Expected Task::Add command");
+        }
+    }
+
+    #[test]
+    fn test_filter_parse_tag() {
+        let filter: Filter = "Tag:仕事".parse().unwrap();
+        assert_eq!(filter.key, FilterKey::Tag);
+        assert_eq!(filter.value, "仕事");
+    }
+
+    #[test]
+    fn test_task_list_filter_partial_tag() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "list",
+            "--filter",
+            "tag:仕",
+            "--partial-tag",
+        ])
+        .unwrap();
+        if let Some(Commands::Task {
+            command:
+                TaskCommands::List {
+                    filter,
+                    partial_tag,
+                    ..
+                },
+        }) = args.command
+        {
+            let filter = filter.unwrap();
+            assert_eq!(filter[0].key, FilterKey::Tag);
+            assert!(partial_tag);
+        } else {
+            panic!("Expected Task::List command");
+        }
+    }
+
+    #[test]
+    fn test_task_list_format() {
+        let args =
+            Args::try_parse_from(vec!["yaru", "task", "list", "--format", "compact"]).unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::List { format, .. },
+        }) = args.command
+        {
+            assert_eq!(format, Some(ListFormatArg::Compact));
+            assert_eq!(
+                TaskListFormat::from(ListFormatArg::Wide),
+                TaskListFormat::Wide
+            );
+        } else {
+            panic!("Expected Task::List command");
+        }
+    }
+
+    #[test]
+    fn test_task_list_format_invalid() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "--format", "json"]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn test_task_list_sort_and_columns() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "list",
+            "--sort",
+            "due",
+            "--columns",
+            "id,title,due",
+        ])
+        .unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::List { sort, columns, .. },
+        }) = args.command
+        {
+            assert_eq!(sort, Some(SortArg::Due));
+            assert_eq!(
+                columns,
+                Some(vec![TaskColumn::Id, TaskColumn::Title, TaskColumn::DueDate])
+            );
+        } else {
+            panic!("Expected Task::List command");
+        }
+    }
+
+    #[test]
+    fn test_task_list_template() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "list",
+            "--template",
+            "{{id}} {{title}}",
+        ])
+        .unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::List { template, .. },
+        }) = args.command
+        {
+            assert_eq!(template.as_deref(), Some("{{id}} {{title}}"));
+        } else {
+            panic!("Expected Task::List command");
+        }
+
+        // テーブル表示・グループ化のオプションとは併用できない
+        for conflicting in [["--format", "compact"], ["--group-by", "tag"]] {
+            let mut argv = vec!["yaru", "task", "list", "--template", "{{id}}"];
+            argv.extend(conflicting);
+            assert!(Args::try_parse_from(argv).is_err());
+        }
+    }
+
+    #[test]
+    fn test_task_list_columns_invalid() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "--columns", "id,color"]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn test_task_list_all() {
+        let args = Args::try_parse_from(vec!["yaru", "task", "list", "-a"]).unwrap();
+        if let Some(Commands::Task {
+            command: TaskCommands::List { all, .. },
+        }) = args.command
+        {
+            assert!(all);
+        } else {
+            panic!("Expected Task::List command");
+        }
+    }
+
+    #[test]
+    fn test_task_list_date_filters() {
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "list",
+            "--due-before",
+            "2026-11-01",
+            "--created-after",
+            "2026-10-01",
+            "--updated-since",
+            "7d",
+        ])
+        .unwrap();
+        if let Some(Commands::Task {
+            command:
+                TaskCommands::List {
+                    due_before,
+                    due_after,
+                    created_after,
+                    updated_since,
+                    ..
+                },
+        }) = args.command
+        {
+            assert_eq!(due_before, NaiveDate::from_ymd_opt(2026, 11, 1));
+            assert_eq!(due_after, None);
+            let created_after = created_after.unwrap().with_timezone(&Local);
+            assert_eq!(
+                created_after.date_naive(),
+                NaiveDate::from_ymd_opt(2026, 10, 1).unwrap()
+            );
+            assert_eq!(created_after.time(), chrono::NaiveTime::MIN);
+            let elapsed = Utc::now() - updated_since.unwrap();
+            assert!((elapsed - Duration::days(7)).num_seconds().abs() < 60);
+        } else {
+            panic!("Expected Task::List command");
+        }
+    }
+
+    #[test]
+    fn test_parse_since_periods() {
+        // Arrange
+        let now = Utc::now();
+
+        // Act & Assert
+        for (input, expected) in [
+            ("12h", Duration::hours(12)),
+            ("3d", Duration::days(3)),
+            ("2w", Duration::weeks(2)),
+        ] {
+            let elapsed = now - parse_since(input).unwrap();
+            assert!((elapsed - expected).num_seconds().abs() < 60, "{input}");
+        }
+        for invalid in ["", "d", "7", "7m", "-1d", "2026/10/01"] {
+            assert!(parse_since(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_task_snooze_until() {
+        // Act
+        let args =
+            Args::try_parse_from(vec!["yaru", "task", "snooze", "12", "--until", "+3d"]).unwrap();
+
+        // Assert
+        if let Some(Commands::Task {
+            command: TaskCommands::Snooze { id, until },
+        }) = args.command
+        {
+            assert_eq!(id, "12");
+            assert_eq!(until, RelativeDate::FromToday(Duration::days(3)));
+        } else {
+            panic!("Expected Task::Snooze command");
+        }
+        let date = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        assert_eq!(
+            parse_relative_date("2026-02-01").unwrap(),
+            RelativeDate::Date(date)
+        );
+        assert_eq!(
+            parse_relative_date("+3bd").unwrap(),
+            RelativeDate::BusinessDays(3)
+        );
+        for invalid in ["", "+", "+d", "+0d", "+-1d", "+3h", "3d", "2026/02/01"] {
+            assert!(parse_relative_date(invalid).is_err(), "{invalid}");
+        }
+        assert!(Args::try_parse_from(vec!["yaru", "task", "snooze", "12"]).is_err());
+    }
+
+    #[test]
+    fn test_task_estimate_and_workload() {
+        // Arrange & Act
+        let add = Args::try_parse_from(vec!["yaru", "task", "add", "t", "--estimate", "2h30m"]);
+        let clear = Args::try_parse_from(vec!["yaru", "task", "edit", "1", "--clear-estimate"]);
+        let conflict = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "edit",
+            "1",
+            "--estimate",
+            "1h",
+            "--clear-estimate",
+        ]);
+        let workload = Args::try_parse_from(vec!["yaru", "workload", "--week"]).unwrap();
+
+        // Assert
+        assert!(add.is_ok());
+        assert!(clear.is_ok());
+        assert!(conflict.is_err());
+        assert!(
+            Args::try_parse_from(vec!["yaru", "task", "add", "t", "--estimate", "2x"]).is_err()
+        );
+        assert!(matches!(
+            workload.command,
+            Some(Commands::Workload { week: true })
+        ));
+    }
+
+    #[test]
+    fn test_report_accuracy() {
+        // Arrange & Act
+        let default = Args::try_parse_from(vec!["yaru", "report", "accuracy"]).unwrap();
+        let by_tag =
+            Args::try_parse_from(vec!["yaru", "report", "accuracy", "--by", "tag", "--all"])
+                .unwrap();
+
+        // Assert
+        assert!(matches!(
+            default.command,
+            Some(Commands::Report {
+                command: ReportCommands::Accuracy {
+                    by: AccuracyByArg::Task,
+                    all: false,
+                },
+            })
+        ));
+        assert!(matches!(
+            by_tag.command,
+            Some(Commands::Report {
+                command: ReportCommands::Accuracy {
+                    by: AccuracyByArg::Tag,
+                    all: true,
+                },
+            })
+        ));
+    }
+
+    #[test]
+    fn test_context_args() {
+        // Arrange & Act
+        let add =
+            Args::try_parse_from(vec!["yaru", "task", "add", "t", "--context", "Home"]).unwrap();
+        let conflict = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "edit",
+            "1",
+            "--context",
+            "@home",
+            "--clear-context",
+        ]);
+        let set = Args::try_parse_from(vec!["yaru", "context", "set", "@office"]).unwrap();
+        let today = Args::try_parse_from(vec!["yaru", "today", "--all-contexts"]).unwrap();
+
+        // Assert
+        match add.command {
+            Some(Commands::Task {
+                command: TaskCommands::Add { context, .. },
+            }) => assert_eq!(context, Some(TaskContext::new("@home").unwrap())),
+            other => panic!("unexpected command: {:?}", other),
+        }
+        assert!(conflict.is_err());
+        assert!(matches!(
+            set.command,
+            Some(Commands::Context {
+                command: ContextCommands::Set { context },
+            }) if context.value() == "@office"
+        ));
+        assert!(matches!(
+            today.command,
+            Some(Commands::Today { all_contexts: true })
+        ));
+        assert!(Args::try_parse_from(vec!["yaru", "context", "set", "@a b"]).is_err());
+        assert!(Args::try_parse_from(vec!["yaru", "task", "list", "--all-contexts"]).is_ok());
+    }
+
+    #[test]
+    fn test_assignee_args() {
+        // Arrange & Act
+        let add = Args::try_parse_from(vec!["yaru", "task", "add", "t", "--assignee", " alice "])
+            .unwrap();
+        let conflict = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "edit",
+            "1",
+            "--assignee",
+            "bob",
+            "--clear-assignee",
+        ]);
+        let filter: Filter = "assignee:alice".parse().unwrap();
+
+        // Assert
+        match add.command {
+            Some(Commands::Task {
+                command: TaskCommands::Add { assignee, .. },
+            }) => assert_eq!(assignee, Some(Assignee::new("alice").unwrap())),
+            other => panic!("unexpected command: {:?}", other),
+        }
+        assert!(conflict.is_err());
+        assert_eq!(filter.key, FilterKey::Assignee);
+        assert_eq!(filter.value, "alice");
+        assert!(Args::try_parse_from(vec!["yaru", "task", "add", "t", "--assignee", " "]).is_err());
+    }
+
+    #[test]
+    fn test_focus() {
+        // Arrange & Act
+        let focus = Args::try_parse_from(vec!["yaru", "focus", "12", "--timer", "25m"]).unwrap();
+        let done = Args::try_parse_from(vec!["yaru", "focus", "done"]).unwrap();
+        let show = Args::try_parse_from(vec!["yaru", "focus"]).unwrap();
+
+        // Assert
+        assert!(matches!(
+            focus.command,
+            Some(Commands::Focus {
+                command: None,
+                id: Some(id),
+                timer: Some(timer),
+            }) if id == "12" && timer.minutes() == 25
+        ));
+        assert!(matches!(
+            done.command,
+            Some(Commands::Focus {
+                command: Some(FocusCommands::Done),
+                id: None,
+                ..
+            })
+        ));
+        assert!(matches!(
+            show.command,
+            Some(Commands::Focus {
+                command: None,
+                id: None,
+                timer: None,
+            })
+        ));
+        assert!(Args::try_parse_from(vec!["yaru", "focus", "--timer", "25m"]).is_err());
+    }
+
+    #[test]
+    fn test_task_stale() {
+        // Arrange & Act
+        let stale = Args::try_parse_from(vec!["yaru", "task", "stale", "--days", "10"]).unwrap();
+        let columns =
+            Args::try_parse_from(vec!["yaru", "task", "list", "--columns", "id,title,stale"]);
+
+        // Assert
+        assert!(matches!(
+            stale.command,
+            Some(Commands::Task {
+                command: TaskCommands::Stale { days: Some(10) },
+            })
+        ));
+        assert!(columns.is_ok());
+        assert!(Args::try_parse_from(vec!["yaru", "task", "stale", "--days", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_task_pick() {
+        // Arrange & Act
+        let pick = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "pick",
+            "-f",
+            "tag:仕事",
+            "--weighted",
+            "--focus",
+        ])
+        .unwrap();
+
+        // Assert
+        assert!(matches!(
+            pick.command,
+            Some(Commands::Task {
+                command: TaskCommands::Pick {
+                    filter: Some(filter),
+                    weighted: true,
+                    start: false,
+                    focus: true,
+                    ..
+                },
+            }) if filter.len() == 1
+        ));
+        assert!(Args::try_parse_from(vec!["yaru", "task", "pick", "--start", "--focus"]).is_err());
+    }
+
+    #[test]
+    fn test_task_done() {
+        // Arrange & Act
+        let done = Args::try_parse_from(vec!["yaru", "task", "done", "12", "--follow-up"]).unwrap();
+        let titled = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "done",
+            "--follow-up-title",
+            "Write release notes",
+        ])
+        .unwrap();
+
+        // Assert
+        assert!(matches!(
+            done.command,
+            Some(Commands::Task {
+                command: TaskCommands::Done {
+                    id: Some(ref id),
+                    follow_up: true,
+                    follow_up_title: None,
+                },
+            }) if id == "12"
+        ));
+        assert!(matches!(
+            titled.command,
+            Some(Commands::Task {
+                command: TaskCommands::Done {
+                    id: None,
+                    follow_up: false,
+                    follow_up_title: Some(ref title),
+                },
+            }) if title == "Write release notes"
+        ));
+    }
+
+    #[test]
+    fn test_task_add_batch_input() {
+        // Arrange & Act
+        let from_file =
+            Args::try_parse_from(vec!["yaru", "task", "add", "--from-file", "todo.txt"]).unwrap();
+        let stdin = Args::try_parse_from(vec!["yaru", "task", "add", "--stdin", "-p", "high"]);
+
+        // Assert
+        assert!(matches!(
+            from_file.command,
+            Some(Commands::Task {
+                command: TaskCommands::Add {
+                    from_file: Some(ref path),
+                    stdin: false,
+                    ..
+                },
+            }) if path == &PathBuf::from("todo.txt")
+        ));
+        assert!(stdin.is_ok());
+        assert!(Args::try_parse_from(vec!["yaru", "task", "add", "Title", "--stdin"]).is_err());
+        assert!(
+            Args::try_parse_from(vec![
+                "yaru",
+                "task",
+                "add",
+                "--stdin",
+                "--from-file",
+                "todo.txt"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_task_add_from_clipboard() {
+        // Arrange & Act
+        let args = Args::try_parse_from(vec![
+            "yaru",
+            "task",
+            "add",
+            "--from-clipboard",
+            "-p",
+            "high",
+        ])
+        .unwrap();
+
+        // Assert: タイトル・説明はクリップボードから読み取るため同時に指定できない
+        assert!(matches!(
+            args.command,
+            Some(Commands::Task {
+                command: TaskCommands::Add {
+                    from_clipboard: true,
+                    ..
+                },
+            })
+        ));
+        assert!(
+            Args::try_parse_from(vec!["yaru", "task", "add", "Title", "--from-clipboard"]).is_err()
+        );
+        assert!(
+            Args::try_parse_from(vec!["yaru", "task", "add", "--from-clipboard", "--stdin"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_quick_add() {
+        // Arrange & Act
+        let quoted =
+            Args::try_parse_from(vec!["yaru", "add", "Pay rent #finance !high @friday"]).unwrap();
+        let words = Args::try_parse_from(vec!["yaru", "add", "Pay", "rent", "#finance", "--force"])
+            .unwrap();
+
+        // Assert
+        assert!(matches!(
+            quoted.command,
+            Some(Commands::Add { ref text, force: false }) if text == &["Pay rent #finance !high @friday"]
+        ));
+        assert!(matches!(
+            words.command,
+            Some(Commands::Add { ref text, force: true }) if text == &["Pay", "rent", "#finance"]
+        ));
+        assert!(Args::try_parse_from(vec!["yaru", "add"]).is_err());
+    }
+
+    #[test]
+    fn test_global_yes_flag() {
+        // Arrange & Act
+        let short = Args::try_parse_from(vec!["yaru", "task", "delete", "3", "-y"]).unwrap();
+        let long = Args::try_parse_from(vec!["yaru", "--yes", "apply", "-"]).unwrap();
+        let default = Args::try_parse_from(vec!["yaru", "tag", "delete", "3"]).unwrap();
+
+        // Assert
+        assert!(short.yes);
+        assert!(long.yes);
+        assert!(!default.yes);
+    }
+
+    #[test]
+    fn test_global_message_format() {
+        // Arrange & Act
+        let json =
+            Args::try_parse_from(vec!["yaru", "task", "list", "--message-format", "json"]).unwrap();
+        let default = Args::try_parse_from(vec!["yaru", "task", "list"]).unwrap();
+
+        // Assert
+        assert_eq!(
+            MessageFormat::from(json.message_format),
+            MessageFormat::Json
+        );
+        assert_eq!(
+            MessageFormat::from(default.message_format),
+            MessageFormat::Text
+        );
+        assert!(
+            Args::try_parse_from(vec!["yaru", "--message-format", "yaml", "task", "list"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_audit_tail_args() {
+        // Arrange & Act
+        let default = Args::try_parse_from(vec!["yaru", "audit", "tail"]).unwrap();
+        let custom =
+            Args::try_parse_from(vec!["yaru", "audit", "tail", "-n", "5", "--json"]).unwrap();
+
+        // Assert
+        assert!(matches!(
+            default.command,
+            Some(Commands::Audit {
+                command: AuditCommands::Tail {
+                    lines: 20,
+                    json: false
+                }
+            })
+        ));
+        assert!(matches!(
+            custom.command,
+            Some(Commands::Audit {
+                command: AuditCommands::Tail {
+                    lines: 5,
+                    json: true
+                }
+            })
+        ));
+    }
 }
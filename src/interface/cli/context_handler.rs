@@ -0,0 +1,138 @@
+use crate::{
+    application::use_cases::task::list_contexts::ListContextsUseCase,
+    domain::task::{repository::TaskRepository, value_objects::TaskContext},
+    interface::{
+        cli::{args::ContextCommands, settings::CliSettings},
+        i18n::Msg,
+        presentation::Presenter,
+    },
+};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{fs, io::ErrorKind, path::Path, sync::Arc};
+
+/// 現在のコンテキストを記録する状態ファイルの内容
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+struct ContextState {
+    /// 現在のコンテキスト（Noneの場合はすべてのコンテキストを表示）
+    current: Option<String>,
+}
+
+/// contextコマンドを処理
+pub async fn handle_context_command(
+    command: ContextCommands,
+    task_repo: Arc<dyn TaskRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let path = &settings.context_state_path;
+    match command {
+        ContextCommands::Set { context } => {
+            save_state(
+                path,
+                &ContextState {
+                    current: Some(context.to_string()),
+                },
+            )?;
+            presenter.present_success(&Msg::ContextSet.format(&[&context]))
+        }
+        ContextCommands::Clear => {
+            save_state(path, &ContextState::default())?;
+            presenter.present_success(Msg::ContextCleared.text())
+        }
+        ContextCommands::Show => match load_current_context(path)? {
+            Some(context) => presenter.present_info(&Msg::ContextCurrent.format(&[&context])),
+            None => presenter.present_info(Msg::ContextNotSet.text()),
+        },
+        ContextCommands::List => {
+            let current = load_current_context(path)?;
+            let contexts = ListContextsUseCase::new(task_repo).execute().await?;
+            if contexts.is_empty() {
+                return presenter.present_info(Msg::NoContexts.text());
+            }
+            for usage in &contexts {
+                let is_current = current
+                    .as_ref()
+                    .is_some_and(|current| current.value() == usage.context);
+                let marker = if is_current { "*" } else { " " };
+                presenter.present_data(&Msg::ContextListItem.format(&[
+                    &marker,
+                    &usage.context,
+                    &usage.open_count,
+                ]))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// 現在のコンテキストを読み込む（状態ファイルがない場合や未設定の場合はNone）
+pub fn load_current_context(path: &Path) -> Result<Option<TaskContext>> {
+    load_state(path)?
+        .current
+        .map(|context| {
+            TaskContext::new(&context)
+                .with_context(|| Msg::ErrorInvalidStateFile.format(&[&path.display()]))
+        })
+        .transpose()
+}
+
+/// 一覧・アジェンダの絞り込みに使うコンテキスト（`--all-contexts`指定時はNone）
+pub fn current_context(settings: &CliSettings, all_contexts: bool) -> Result<Option<TaskContext>> {
+    if all_contexts {
+        return Ok(None);
+    }
+    load_current_context(&settings.context_state_path)
+}
+
+/// 状態ファイルを読み込む（ファイルがない場合は未設定として扱う）
+fn load_state(path: &Path) -> Result<ContextState> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .with_context(|| Msg::ErrorInvalidStateFile.format(&[&path.display()])),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(ContextState::default()),
+        Err(e) => Err(e).with_context(|| Msg::ErrorReadStateFile.format(&[&path.display()])),
+    }
+}
+
+/// 状態ファイルを書き込む
+fn save_state(path: &Path, state: &ContextState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| Msg::ErrorCreateDirectory.format(&[&parent.display()]))?;
+    }
+    fs::write(path, serde_json::to_string(state)?)
+        .with_context(|| Msg::ErrorWriteStateFile.format(&[&path.display()]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_context_state_round_trip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state").join("context.json");
+
+        // Act
+        let initial = load_current_context(&path).unwrap();
+        save_state(
+            &path,
+            &ContextState {
+                current: Some("@home".to_string()),
+            },
+        )
+        .unwrap();
+        let loaded = load_current_context(&path).unwrap();
+        save_state(&path, &ContextState::default()).unwrap();
+        let cleared = load_current_context(&path).unwrap();
+
+        // Assert
+        assert_eq!(initial, None);
+        assert_eq!(loaded, Some(TaskContext::new("@home").unwrap()));
+        assert_eq!(cleared, None);
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"{"current":null}"#);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{
+    application::use_cases::task::{
+        apply_overdue_tag::ApplyOverdueTagUseCase,
+        run_scheduled_jobs::{Notifier, RunScheduledJobsUseCase},
+    },
+    domain::{
+        clock::Clock, tag::repository::TagRepository, task::repository::TaskRepository,
+        unit_of_work::UnitOfWorkFactory,
+    },
+    interface::{
+        cli::{args::CronCommands, display::format::format_date, settings::CliSettings},
+        i18n::Msg,
+        notify::CommandNotifier,
+        persistence::archive::JsonLinesArchive,
+        presentation::Presenter,
+    },
+};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::{fs, io::ErrorKind, path::Path, sync::Arc};
+
+/// 定期実行の状態（状態ファイルにJSONで保存する）
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct CronState {
+    /// 前回の実行日
+    last_run: Option<NaiveDate>,
+}
+
+/// cronコマンドを処理
+pub async fn handle_cron_command(
+    command: CronCommands,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    match command {
+        CronCommands::Run => {
+            let state = load_state(&settings.cron.state_path)?;
+            let today = settings.clock.today();
+            let notifier = settings
+                .cron
+                .notify_command
+                .clone()
+                .map(CommandNotifier::new);
+            let archive = JsonLinesArchive::new(&settings.cron.archive_path);
+            let business_calendar = settings
+                .cron
+                .business_days
+                .then(|| settings.calendar.clone());
+
+            let report = RunScheduledJobsUseCase::new(unit_of_work)
+                .with_recurrence_lead_days(settings.cron.recurrence_lead_days)
+                .with_archive_after_days(settings.cron.archive_after_days)
+                .with_business_calendar(business_calendar.clone())
+                .with_clock(Arc::new(settings.clock))
+                .run(
+                    today,
+                    state.last_run,
+                    notifier.as_ref().map(|n| n as &dyn Notifier),
+                    &archive,
+                )
+                .await?;
+
+            for generated in &report.generated {
+                presenter.present_success(&Msg::CronTaskGenerated.format(&[
+                    &generated.task.id,
+                    &generated.task.title,
+                    &format_date(&generated.task.due_date),
+                    &generated.source_id,
+                ]))?;
+            }
+            for task in &report.notified {
+                presenter
+                    .present_success(&Msg::CronTaskNotified.format(&[&task.id, &task.title]))?;
+            }
+            for task in &report.archived {
+                presenter
+                    .present_success(&Msg::CronTaskArchived.format(&[&task.id, &task.title]))?;
+            }
+            for warning in &report.warnings {
+                presenter.present_warning(warning)?;
+            }
+            if let Some(tag) = &settings.overdue_tag {
+                let overdue = ApplyOverdueTagUseCase::new(task_repo, tag_repo)
+                    .with_clock(Arc::new(settings.clock))
+                    .with_business_calendar(business_calendar)
+                    .execute(tag)
+                    .await?;
+                for task in &overdue.tagged {
+                    presenter.present_success(&Msg::CronOverdueTagged.format(&[
+                        &task.id,
+                        &task.title,
+                        tag,
+                    ]))?;
+                }
+                for task in &overdue.untagged {
+                    presenter.present_success(&Msg::CronOverdueUntagged.format(&[
+                        &task.id,
+                        &task.title,
+                        tag,
+                    ]))?;
+                }
+            }
+            presenter.present_success(&Msg::CronSummary.format(&[
+                &report.generated.len(),
+                &report.notified.len(),
+                &report.archived.len(),
+            ]))?;
+
+            save_state(
+                &settings.cron.state_path,
+                &CronState {
+                    last_run: Some(today),
+                },
+            )
+        }
+    }
+}
+
+/// 状態ファイルを読み込む（ファイルがない場合は初回として扱う）
+fn load_state(path: &Path) -> Result<CronState> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .with_context(|| Msg::ErrorInvalidStateFile.format(&[&path.display()])),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(CronState::default()),
+        Err(e) => Err(e).with_context(|| Msg::ErrorReadStateFile.format(&[&path.display()])),
+    }
+}
+
+/// 状態ファイルを書き込む
+fn save_state(path: &Path, state: &CronState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| Msg::ErrorCreateDirectory.format(&[&parent.display()]))?;
+    }
+    fs::write(path, serde_json::to_string(state)?)
+        .with_context(|| Msg::ErrorWriteStateFile.format(&[&path.display()]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_cron_state_round_trip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state").join("cron_state.json");
+        let state = CronState {
+            last_run: NaiveDate::from_ymd_opt(2026, 10, 25),
+        };
+
+        // Act
+        let initial = load_state(&path).unwrap();
+        save_state(&path, &state).unwrap();
+        let loaded = load_state(&path).unwrap();
+
+        // Assert
+        assert_eq!(initial, CronState::default());
+        assert_eq!(loaded, state);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            r#"{"last_run":"2026-10-25"}"#
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use crate::interface::{daemon::client, i18n::Msg, presentation::Presenter};
+use anyhow::Result;
+use std::{path::Path, sync::Arc};
+
+/// デーモンが起動しているか表示
+pub async fn handle_status(socket_path: &Path, presenter: Arc<dyn Presenter>) -> Result<()> {
+    match client::ping(socket_path).await {
+        Ok(pong) => presenter.present_info(&Msg::DaemonRunning.format(&[
+            &pong.pid,
+            &pong.version,
+            &socket_path.display(),
+        ])),
+        Err(e) => {
+            tracing::debug!(error = %e, "daemon ping failed");
+            presenter.present_info(&Msg::DaemonNotRunning.format(&[&socket_path.display()]))
+        }
+    }
+}
+
+/// 起動中のデーモンを停止
+pub async fn handle_stop(socket_path: &Path, presenter: Arc<dyn Presenter>) -> Result<()> {
+    if let Err(e) = client::shutdown(socket_path).await {
+        tracing::debug!(error = %e, "daemon shutdown failed");
+        anyhow::bail!(Msg::DaemonNotRunning.format(&[&socket_path.display()]));
+    }
+    presenter.present_success(Msg::DaemonStopped.text())
+}
@@ -0,0 +1,117 @@
+use crate::interface::{
+    cli::args::DbCommands,
+    i18n::Msg,
+    persistence::sea_orm::{SeaOrmDatabaseAdmin, SeaOrmEncryptionManager},
+    presentation::Presenter,
+};
+use anyhow::Result;
+use inquire::{Password, PasswordDisplayMode};
+use std::sync::Arc;
+
+/// パスフレーズを指定する環境変数
+const PASSPHRASE_ENV: &str = "YARU_PASSPHRASE";
+
+/// データベースコマンドを処理
+///
+/// # Arguments
+/// * `database_url` - 接続に使用したデータベースURL（`db status`での表示用）
+pub async fn handle_db_command(
+    command: DbCommands,
+    admin: &SeaOrmDatabaseAdmin,
+    encryption: &SeaOrmEncryptionManager,
+    database_url: &str,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    match command {
+        DbCommands::Encrypt => handle_encrypt(encryption, presenter).await,
+        DbCommands::Decrypt => handle_decrypt(encryption, presenter).await,
+        DbCommands::Status => handle_status(admin, database_url, presenter).await,
+        DbCommands::Migrate => handle_migrate(admin, presenter).await,
+    }
+}
+
+/// データベースの状態を表示
+async fn handle_status(
+    admin: &SeaOrmDatabaseAdmin,
+    database_url: &str,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    let status = admin.status(database_url).await?;
+    presenter.present_db_status(&status)?;
+
+    if !status.is_healthy() {
+        anyhow::bail!(Msg::ErrorIntegrityCheckFailed.text());
+    }
+    Ok(())
+}
+
+/// 未適用のマイグレーションを適用
+async fn handle_migrate(admin: &SeaOrmDatabaseAdmin, presenter: Arc<dyn Presenter>) -> Result<()> {
+    let applied = admin.migrate().await?;
+    if applied.is_empty() {
+        return presenter.present_info(Msg::DbUpToDate.text());
+    }
+
+    presenter
+        .present_success(&Msg::DbMigrationsApplied.format(&[&applied.len(), &applied.join("\n  ")]))
+}
+
+/// データベースを暗号化
+async fn handle_encrypt(
+    encryption: &SeaOrmEncryptionManager,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    if encryption.is_encrypted().await? {
+        anyhow::bail!(Msg::ErrorAlreadyEncrypted.text());
+    }
+
+    let passphrase = read_passphrase(true)?;
+    let count = encryption.encrypt(&passphrase).await?;
+    presenter.present_success(&Msg::DbEncryptedDone.format(&[&count]))
+}
+
+/// データベースの暗号化を解除
+async fn handle_decrypt(
+    encryption: &SeaOrmEncryptionManager,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    if !encryption.is_encrypted().await? {
+        anyhow::bail!(Msg::ErrorNotEncrypted.text());
+    }
+    if !presenter.confirm(Msg::ConfirmDecrypt.text(), false)? {
+        return presenter.present_info(Msg::DecryptCancelled.text());
+    }
+
+    let passphrase = read_passphrase(false)?;
+    let count = encryption.decrypt(&passphrase).await?;
+    presenter.present_success(&Msg::DbDecryptedDone.format(&[&count]))
+}
+
+/// パスフレーズを取得
+///
+/// YARU_PASSPHRASE環境変数が設定されていればその値を使い、なければ対話的に入力します。
+///
+/// # Arguments
+/// * `confirm` - 対話入力時に確認のため再入力させるか（新しいパスフレーズの設定時）
+pub fn read_passphrase(confirm: bool) -> Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV)
+        && !passphrase.is_empty()
+    {
+        return Ok(passphrase);
+    }
+
+    let prompt =
+        Password::new(Msg::PromptPassphrase.text()).with_display_mode(PasswordDisplayMode::Masked);
+    let prompt = if confirm {
+        prompt.with_custom_confirmation_message(Msg::PromptConfirmPassphrase.text())
+    } else {
+        prompt.without_confirmation()
+    };
+
+    let passphrase = prompt.prompt()?;
+    if passphrase.is_empty() {
+        anyhow::bail!(Msg::ErrorEmptyPassphrase.text());
+    }
+
+    Ok(passphrase)
+}
@@ -1,8 +1,23 @@
+pub mod accuracy_table;
+pub mod date_format;
+pub mod db_status_table;
 pub mod format;
+pub mod markdown;
 pub mod stats_table;
 pub mod tag_table;
 pub mod task_table;
+pub mod template;
+pub mod theme;
+pub mod workload_table;
 
-pub use stats_table::create_rich_stats_display;
+pub use accuracy_table::create_accuracy_table;
+pub use date_format::{DateFormat, set_date_format};
+pub use db_status_table::create_db_status_table;
+pub use stats_table::{create_flow_stats_display, create_rich_stats_display};
 pub use tag_table::{create_tag_detail_table, create_tag_table};
-pub use task_table::{create_task_detail_table, create_task_table};
+pub use task_table::{
+    TaskColumn, TaskListFormat, TaskListView, apply_highlights, create_task_detail_table,
+    create_task_related_table, create_task_table,
+};
+pub use template::TaskTemplate;
+pub use workload_table::create_workload_table;
@@ -0,0 +1,102 @@
+use crate::{
+    application::dto::{EstimateAccuracyDTO, EstimateAccuracyRowDTO},
+    domain::task::value_objects::Estimate,
+    interface::{cli::display::theme::Theme, i18n::Msg},
+};
+use comfy_table::{Cell, CellAlignment, Table, presets::UTF8_FULL};
+
+/// 見積もりと実績を比べたテーブルを作成
+///
+/// 最後の行に全体の集計を表示し、見積もりを超えた行の差を強調表示します。
+pub fn create_accuracy_table(report: &EstimateAccuracyDTO, theme: &Theme) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    // タスクごとの場合、タスク数は常に1のため表示しない
+    let mut header = vec![Msg::FieldTitle.text()];
+    if report.by_tag {
+        header = vec![Msg::FieldTags.text(), Msg::WorkloadTasks.text()];
+    }
+    header.extend([
+        Msg::FieldEstimate.text(),
+        Msg::AccuracyActual.text(),
+        Msg::AccuracyVariance.text(),
+        Msg::AccuracyVariancePercent.text(),
+    ]);
+    table.set_header(header);
+
+    for row in &report.rows {
+        table.add_row(accuracy_row(&row.label, row, report.by_tag, theme));
+    }
+    table.add_row(accuracy_row(
+        Msg::StatsTotal.text(),
+        &report.total,
+        report.by_tag,
+        theme,
+    ));
+
+    table
+}
+
+/// 1行分のセル（`with_count`がtrueの場合はタスク数の列を含む）
+fn accuracy_row(
+    label: &str,
+    row: &EstimateAccuracyRowDTO,
+    with_count: bool,
+    theme: &Theme,
+) -> Vec<Cell> {
+    let over = row.variance_minutes() > 0;
+    let mut cells = vec![Cell::new(label)];
+    if with_count {
+        cells.push(Cell::new(row.task_count).set_alignment(CellAlignment::Right));
+    }
+    cells.extend([
+        Cell::new(Estimate::format_minutes(row.estimated_minutes))
+            .set_alignment(CellAlignment::Right),
+        Cell::new(Estimate::format_minutes(row.actual_minutes)).set_alignment(CellAlignment::Right),
+        theme
+            .alert_cell(&format_variance(row.variance_minutes()), over)
+            .set_alignment(CellAlignment::Right),
+        theme
+            .alert_cell(&format_variance_percent(row.variance_percent()), over)
+            .set_alignment(CellAlignment::Right),
+    ]);
+    cells
+}
+
+/// 差を符号付きで表示（例: "+15m"、"-1h30m"）
+fn format_variance(minutes: i64) -> String {
+    let sign = match minutes {
+        m if m > 0 => "+",
+        m if m < 0 => "-",
+        _ => "",
+    };
+    format!(
+        "{}{}",
+        sign,
+        Estimate::format_minutes(u32::try_from(minutes.unsigned_abs()).unwrap_or(u32::MAX))
+    )
+}
+
+/// 差の割合を符号付きで表示（見積もりが0分の場合は"-"）
+fn format_variance_percent(percent: Option<i64>) -> String {
+    match percent {
+        Some(percent) if percent > 0 => format!("+{}%", percent),
+        Some(percent) => format!("{}%", percent),
+        None => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_variance() {
+        assert_eq!(format_variance(15), "+15m");
+        assert_eq!(format_variance(-90), "-1h30m");
+        assert_eq!(format_variance(0), "0m");
+        assert_eq!(format_variance_percent(Some(25)), "+25%");
+        assert_eq!(format_variance_percent(Some(-33)), "-33%");
+        assert_eq!(format_variance_percent(None), "-");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::interface::i18n::Msg;
+use anyhow::{Context, Result};
+use chrono::{
+    DateTime, Local, NaiveDate, Utc,
+    format::{Item, StrftimeItems},
+};
+use std::sync::OnceLock;
+
+/// 日時を表示するタイムゾーン
+pub use crate::domain::clock::Timezone as DisplayTimezone;
+
+/// 日付・日時の表示形式
+///
+/// 設定ファイルの`[display] date_format`と`[display] timezone`から構築します。
+/// 日時は日付の形式に続けて`HH:MM`で表示します。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateFormat {
+    date_format: String,
+    timezone: DisplayTimezone,
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self {
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            timezone: DisplayTimezone::Local,
+        }
+    }
+}
+
+/// デフォルトの日付形式
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+impl DateFormat {
+    /// 設定値から表示形式を作成
+    ///
+    /// # Arguments
+    /// * `date_format` - strftime形式の日付フォーマット（例: `%Y/%m/%d`）
+    /// * `timezone` - `local`, `utc`、または`+09:00`形式のオフセット
+    pub fn new(date_format: &str, timezone: &str) -> Result<Self> {
+        let valid = !date_format.is_empty()
+            && StrftimeItems::new(date_format).all(|item| !matches!(item, Item::Error));
+        if !valid {
+            anyhow::bail!(Msg::ErrorInvalidDateFormat.format(&[&date_format]));
+        }
+
+        let timezone = timezone
+            .parse()
+            .with_context(|| Msg::ErrorInvalidTimezone.format(&[&timezone]))?;
+
+        Ok(Self {
+            date_format: date_format.to_string(),
+            timezone,
+        })
+    }
+
+    /// 日時を表示するタイムゾーン（「今日」の判定にも使用する）
+    pub fn timezone(&self) -> DisplayTimezone {
+        self.timezone
+    }
+
+    /// 日付をフォーマット
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        date.format(&self.date_format).to_string()
+    }
+
+    /// UTCの日時を表示用のタイムゾーンに変換してフォーマット
+    pub fn format_datetime(&self, utc_time: &DateTime<Utc>) -> String {
+        let format = format!("{} %H:%M", self.date_format);
+        match self.timezone {
+            DisplayTimezone::Local => utc_time.with_timezone(&Local).format(&format).to_string(),
+            DisplayTimezone::Utc => utc_time.format(&format).to_string(),
+            DisplayTimezone::Fixed(offset) => {
+                utc_time.with_timezone(&offset).format(&format).to_string()
+            }
+        }
+    }
+}
+
+/// プロセス全体で使用する表示形式
+static CURRENT_DATE_FORMAT: OnceLock<DateFormat> = OnceLock::new();
+
+/// 表示形式を設定
+///
+/// 起動時に1度だけ呼び出します。2回目以降の呼び出しは無視されます。
+pub fn set_date_format(format: DateFormat) {
+    let _ = CURRENT_DATE_FORMAT.set(format);
+}
+
+/// 現在の表示形式を取得（未設定の場合はデフォルト）
+pub fn current_date_format() -> &'static DateFormat {
+    static DEFAULT: OnceLock<DateFormat> = OnceLock::new();
+    CURRENT_DATE_FORMAT
+        .get()
+        .unwrap_or_else(|| DEFAULT.get_or_init(DateFormat::default))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_format_with_custom_format_and_offset() {
+        // Arrange
+        let format = DateFormat::new("%Y/%m/%d", "+09:00").unwrap();
+        let utc_time = Utc.with_ymd_and_hms(2025, 1, 10, 20, 30, 0).unwrap();
+
+        // Act & Assert: UTC 20:30は日本時間で翌日5:30
+        assert_eq!(format.format_datetime(&utc_time), "2025/01/11 05:30");
+        assert_eq!(
+            format.format_date(NaiveDate::from_ymd_opt(2025, 1, 10).unwrap()),
+            "2025/01/10"
+        );
+    }
+
+    #[test]
+    fn test_format_in_utc() {
+        let format = DateFormat::new(DEFAULT_DATE_FORMAT, "utc").unwrap();
+        let utc_time = Utc.with_ymd_and_hms(2025, 1, 10, 20, 30, 0).unwrap();
+
+        assert_eq!(format.format_datetime(&utc_time), "2025-01-10 20:30");
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_format() {
+        assert!(DateFormat::new("%Q", "local").is_err());
+        assert!(DateFormat::new("", "local").is_err());
+        assert!(DateFormat::new("%d.%m.%Y", "local").is_ok());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::interface::{i18n::Msg, persistence::sea_orm::DatabaseStatus};
+use comfy_table::{Table, presets::UTF8_FULL};
+
+/// データベースの状態をテーブル形式で作成
+///
+/// # 引数
+/// - `status`: データベースの状態
+///
+/// # 戻り値
+/// 項目名と値の2列からなるテーブル
+pub fn create_db_status_table(status: &DatabaseStatus) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+
+    let path = status
+        .path
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|| Msg::DbInMemory.text().to_string());
+    let size = status
+        .size_bytes
+        .map(format_size)
+        .unwrap_or_else(|| "-".to_string());
+    let encrypted = match status.encrypted {
+        Some(true) => Msg::Yes,
+        Some(false) => Msg::No,
+        None => Msg::Unknown,
+    };
+
+    table.add_row(vec![Msg::DbPath.text().to_string(), path]);
+    table.add_row(vec![Msg::DbSize.text().to_string(), size]);
+    table.add_row(vec![
+        Msg::DbAppliedMigrations.text().to_string(),
+        format_names(&status.applied_migrations),
+    ]);
+    table.add_row(vec![
+        Msg::DbPendingMigrations.text().to_string(),
+        format_names(&status.pending_migrations),
+    ]);
+    for (name, count) in &status.table_counts {
+        let count = count
+            .map(|count| count.to_string())
+            .unwrap_or_else(|| Msg::DbTableMissing.text().to_string());
+        table.add_row(vec![Msg::DbRows.format(&[name]), count]);
+    }
+    table.add_row(vec![
+        Msg::DbEncrypted.text().to_string(),
+        encrypted.text().to_string(),
+    ]);
+    table.add_row(vec![
+        Msg::DbIntegrityCheck.text().to_string(),
+        status.integrity_check.join("\n"),
+    ]);
+
+    table
+}
+
+/// 名前の一覧を改行区切りで整形（空の場合は"none"）
+fn format_names(names: &[String]) -> String {
+    if names.is_empty() {
+        Msg::DbNone.text().to_string()
+    } else {
+        names.join("\n")
+    }
+}
+
+/// バイト数を読みやすい単位に整形
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_create_db_status_table() {
+        // Arrange
+        let status = DatabaseStatus {
+            path: Some(PathBuf::from("/tmp/yaru.db")),
+            size_bytes: Some(4096),
+            applied_migrations: vec!["m1".to_string()],
+            pending_migrations: vec![],
+            table_counts: vec![("tasks", Some(3)), ("settings", None)],
+            integrity_check: vec!["ok".to_string()],
+            encrypted: Some(false),
+        };
+
+        // Act
+        let rendered = create_db_status_table(&status).to_string();
+
+        // Assert
+        assert!(rendered.contains("/tmp/yaru.db"));
+        assert!(rendered.contains("4.0 KiB"));
+        assert!(rendered.contains("Rows: tasks"));
+        assert!(rendered.contains("(missing)"));
+        assert!(rendered.contains("none"));
+    }
+}
@@ -1,12 +1,15 @@
-use crate::application::dto::task_dto::TagInfo;
-use chrono::{DateTime, Local, NaiveDate, Utc};
+use crate::{
+    application::dto::task_dto::{ChecklistItemDTO, ExternalRefDTO, GitLinkDTO, TagInfo},
+    domain::task::value_objects::{DueDateStatus, Priority, Status},
+    interface::{cli::display::date_format::current_date_format, i18n::Msg},
+};
+use chrono::{DateTime, NaiveDate, Utc};
 
-/// UTC時間を現地時間に変換してフォーマット
+/// UTC時間を表示用のタイムゾーンに変換してフォーマット
+///
+/// 形式とタイムゾーンは設定ファイルの`[display]`セクションに従います。
 pub fn format_local_time(utc_time: &DateTime<Utc>) -> String {
-    utc_time
-        .with_timezone(&Local)
-        .format("%Y-%m-%d %H:%M")
-        .to_string()
+    current_date_format().format_datetime(utc_time)
 }
 
 /// 日付をフォーマット
@@ -15,13 +18,100 @@ pub fn format_local_time(utc_time: &DateTime<Utc>) -> String {
 /// - `date`: フォーマットする日付（Option型）
 ///
 /// # 戻り値
-/// - 日付が存在する場合: "YYYY-MM-DD" 形式の文字列
+/// - 日付が存在する場合: 設定の日付形式（デフォルトは"YYYY-MM-DD"）の文字列
 /// - 日付が存在しない場合: "-"
 pub fn format_date(date: &Option<NaiveDate>) -> String {
-    date.map(|d| d.format("%Y-%m-%d").to_string())
+    date.map(|d| current_date_format().format_date(d))
         .unwrap_or_else(|| "-".to_string())
 }
 
+/// ステータスを表示言語に合わせてフォーマット
+pub fn format_status(status: Status) -> &'static str {
+    match status {
+        Status::Pending => Msg::ValuePending.text(),
+        Status::InProgress => Msg::ValueInProgress.text(),
+        Status::Completed => Msg::ValueCompleted.text(),
+    }
+}
+
+/// 優先度を表示言語に合わせてフォーマット
+pub fn format_priority(priority: Priority) -> &'static str {
+    match priority {
+        Priority::Critical => Msg::ValueCritical.text(),
+        Priority::High => Msg::ValueHigh.text(),
+        Priority::Medium => Msg::ValueMedium.text(),
+        Priority::Low => Msg::ValueLow.text(),
+    }
+}
+
+/// 期限日を相対表現付きでフォーマット
+///
+/// # 引数
+/// - `date`: 期限日（Option型）
+/// - `today`: 基準日
+///
+/// # 戻り値
+/// - 期限日が存在する場合: "YYYY-MM-DD (in 3 days)" や "YYYY-MM-DD (2 days overdue)" 形式の文字列
+/// - 期限日が存在しない場合: "-"
+pub fn format_relative_due_date(date: &Option<NaiveDate>, today: NaiveDate) -> String {
+    match date {
+        Some(due) => format!(
+            "{} ({})",
+            current_date_format().format_date(*due),
+            format_relative_days((*due - today).num_days())
+        ),
+        None => "-".to_string(),
+    }
+}
+
+/// 基準日からの日数差を相対表現に変換
+///
+/// # 戻り値
+/// "today", "in 3 days", "2 days overdue"などの文字列
+pub fn format_relative_days(days: i64) -> String {
+    match days {
+        0 => Msg::DueToday.text().to_string(),
+        1 => Msg::DueTomorrow.text().to_string(),
+        -1 => Msg::DueOneDayOverdue.text().to_string(),
+        d if d > 1 => Msg::DueInDays.format(&[&d]),
+        d => Msg::DueDaysOverdue.format(&[&-d]),
+    }
+}
+
+/// 日数をフォーマット（"1 day", "3 days"）
+pub fn format_day_count(days: i64) -> String {
+    match days {
+        1 => Msg::OneDay.text().to_string(),
+        d => Msg::DayCount.format(&[&d]),
+    }
+}
+
+/// 経過日数を"何日前"の形式でフォーマット
+///
+/// # 戻り値
+/// "today", "1 day ago", "3 days ago"などの文字列
+pub fn format_days_ago(days: i64) -> String {
+    match days {
+        d if d <= 0 => Msg::DueToday.text().to_string(),
+        1 => Msg::OneDayAgo.text().to_string(),
+        d => Msg::DaysAgo.format(&[&d]),
+    }
+}
+
+/// 期限の状況に応じたバッジを取得
+///
+/// # 戻り値
+/// - 期限切れの場合: "[OVERDUE]"
+/// - 今日期限の場合: "[TODAY]"
+/// - それ以外の場合: None
+pub fn due_date_badge(status: DueDateStatus) -> Option<&'static str> {
+    match status {
+        DueDateStatus::Overdue => Some(Msg::BadgeOverdue.text()),
+        DueDateStatus::DueToday => Some(Msg::BadgeToday.text()),
+        _ => None,
+    }
+}
+
 /// 空文字列を"-"に変換
 ///
 /// # 引数
@@ -71,6 +161,56 @@ pub fn format_tags(tags: &[TagInfo], separator: &str) -> String {
     }
 }
 
+/// チェックリストの進捗を"完了数/項目数"の形式でフォーマット
+pub fn format_checklist_progress(items: &[ChecklistItemDTO]) -> String {
+    let done = items.iter().filter(|item| item.done).count();
+    format!("{}/{}", done, items.len())
+}
+
+/// チェックリストの項目を番号付きの行に変換
+///
+/// # 戻り値
+/// "1. [x] write tests" 形式の行。番号は`task check`で項目を指定する番号と一致する。
+pub fn format_checklist_items(items: &[ChecklistItemDTO]) -> Vec<String> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let mark = if item.done { "x" } else { " " };
+            format!("{}. [{}] {}", i + 1, mark, item.text)
+        })
+        .collect()
+}
+
+/// 短縮表示するコミットハッシュの文字数
+const SHORT_COMMIT_LENGTH: usize = 7;
+
+/// gitの紐づけを1行の文字列に変換
+///
+/// # 戻り値
+/// "/src/yaru (main) 3f2a9c1" 形式の文字列。ブランチがない場合は省略する。
+pub fn format_git_link(link: &GitLinkDTO) -> String {
+    let commit: String = link.commit.chars().take(SHORT_COMMIT_LENGTH).collect();
+    match &link.branch {
+        Some(branch) => format!("{} ({}) {}", link.repository, branch, commit),
+        None => format!("{} {}", link.repository, commit),
+    }
+}
+
+/// 外部の項目との対応を1行の文字列に変換
+///
+/// # 戻り値
+/// "github taktiks2/yaru#42 https://..." 形式の文字列。URLがない場合は省略する。
+pub fn format_external_ref(external_ref: &ExternalRefDTO) -> String {
+    match &external_ref.url {
+        Some(url) => format!(
+            "{} {} {}",
+            external_ref.source, external_ref.external_id, url
+        ),
+        None => format!("{} {}", external_ref.source, external_ref.external_id),
+    }
+}
+
 /// 説明文を指定された最大長に切り詰める
 ///
 /// # 引数
@@ -95,3 +235,125 @@ pub fn truncate_text(desc: &str, max_len: usize) -> String {
         normalized
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_format_relative_due_date_future() {
+        let today = date(2025, 1, 10);
+        assert_eq!(
+            format_relative_due_date(&Some(date(2025, 1, 13)), today),
+            "2025-01-13 (in 3 days)"
+        );
+        assert_eq!(
+            format_relative_due_date(&Some(date(2025, 1, 11)), today),
+            "2025-01-11 (tomorrow)"
+        );
+    }
+
+    #[test]
+    fn test_format_relative_due_date_today_and_past() {
+        let today = date(2025, 1, 10);
+        assert_eq!(
+            format_relative_due_date(&Some(today), today),
+            "2025-01-10 (today)"
+        );
+        assert_eq!(
+            format_relative_due_date(&Some(date(2025, 1, 9)), today),
+            "2025-01-09 (1 day overdue)"
+        );
+        assert_eq!(
+            format_relative_due_date(&Some(date(2025, 1, 8)), today),
+            "2025-01-08 (2 days overdue)"
+        );
+    }
+
+    #[test]
+    fn test_format_relative_due_date_none() {
+        assert_eq!(format_relative_due_date(&None, date(2025, 1, 10)), "-");
+    }
+
+    #[test]
+    fn test_due_date_badge() {
+        assert_eq!(due_date_badge(DueDateStatus::Overdue), Some("[OVERDUE]"));
+        assert_eq!(due_date_badge(DueDateStatus::DueToday), Some("[TODAY]"));
+        assert_eq!(due_date_badge(DueDateStatus::DueSoon), None);
+        assert_eq!(due_date_badge(DueDateStatus::NoDueDate), None);
+    }
+
+    #[test]
+    fn test_format_status_and_priority() {
+        // 英語ではコマンド引数と同じ表記
+        assert_eq!(format_status(Status::InProgress), "in_progress");
+        assert_eq!(format_status(Status::Completed), "completed");
+        assert_eq!(format_priority(Priority::Critical), "critical");
+        assert_eq!(format_priority(Priority::Low), "low");
+    }
+
+    #[test]
+    fn test_format_day_count_and_days_ago() {
+        assert_eq!(format_day_count(0), "0 days");
+        assert_eq!(format_day_count(1), "1 day");
+        assert_eq!(format_day_count(12), "12 days");
+        assert_eq!(format_days_ago(0), "today");
+        assert_eq!(format_days_ago(1), "1 day ago");
+        assert_eq!(format_days_ago(5), "5 days ago");
+    }
+
+    #[test]
+    fn test_format_checklist() {
+        let items = vec![
+            ChecklistItemDTO {
+                text: "write tests".to_string(),
+                done: true,
+            },
+            ChecklistItemDTO {
+                text: "release".to_string(),
+                done: false,
+            },
+        ];
+
+        assert_eq!(format_checklist_progress(&items), "1/2");
+        assert_eq!(format_checklist_progress(&[]), "0/0");
+        assert_eq!(
+            format_checklist_items(&items),
+            vec!["1. [x] write tests", "2. [ ] release"]
+        );
+    }
+
+    #[test]
+    fn test_format_git_link() {
+        let mut link = GitLinkDTO {
+            repository: "/src/yaru".to_string(),
+            branch: Some("main".to_string()),
+            commit: "3f2a9c1d0b8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a".to_string(),
+            linked_at: Utc::now(),
+        };
+        assert_eq!(format_git_link(&link), "/src/yaru (main) 3f2a9c1");
+
+        link.branch = None;
+        assert_eq!(format_git_link(&link), "/src/yaru 3f2a9c1");
+    }
+
+    #[test]
+    fn test_format_external_ref() {
+        let mut external_ref = ExternalRefDTO {
+            source: "github".to_string(),
+            external_id: "acme/app#42".to_string(),
+            url: Some("https://github.com/acme/app/issues/42".to_string()),
+        };
+        assert_eq!(
+            format_external_ref(&external_ref),
+            "github acme/app#42 https://github.com/acme/app/issues/42"
+        );
+
+        external_ref.url = None;
+        assert_eq!(format_external_ref(&external_ref), "github acme/app#42");
+    }
+}
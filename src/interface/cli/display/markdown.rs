@@ -0,0 +1,490 @@
+use console::Style;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use std::ops::Range;
+
+/// 行内の要素
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    Text(String),
+    /// `**太字**`または`__太字__`
+    Bold(String),
+    /// `*斜体*`または`_斜体_`
+    Italic(String),
+    /// `` `コード` ``
+    Code(String),
+    /// `[テキスト](URL)`または`<URL>`
+    Link {
+        text: String,
+        url: String,
+    },
+}
+
+/// Markdownの1行分のブロック
+///
+/// 説明は短い文章がほとんどのため、段落をつなげずに元の行ごとに扱います。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkdownBlock {
+    /// 通常の行（空行は要素なし）
+    Paragraph(Vec<Inline>),
+    /// `# 見出し`
+    Heading(Vec<Inline>),
+    /// `- 項目`や`1. 項目`（`depth`は字下げの深さ、`marker`は表示する記号）
+    ListItem {
+        depth: usize,
+        marker: String,
+        content: Vec<Inline>,
+    },
+    /// `> 引用`
+    Quote(Vec<Inline>),
+    /// ```` ``` ````で囲まれたコードブロックの1行
+    CodeLine(String),
+    /// `---`などの区切り線
+    Rule,
+}
+
+/// 区切り線として表示する文字列
+const RULE: &str = "────────────────────";
+
+/// Markdownの文字列を行ごとのブロックに分解する
+///
+/// 解析は[`pulldown_cmark`]（CommonMarkとタスクリスト）で行い、元の改行・空行は行として残します。
+pub fn parse_markdown(text: &str) -> Vec<MarkdownBlock> {
+    let mut builder = BlockBuilder::new(text);
+    for (event, range) in Parser::new_ext(text, Options::ENABLE_TASKLISTS).into_offset_iter() {
+        builder.handle(event, range);
+    }
+    builder.blocks
+}
+
+/// 組み立て中の行の種類
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LineKind {
+    Paragraph,
+    Heading,
+    ListItem { depth: usize, marker: String },
+    Quote,
+}
+
+/// 解析のイベントから行ごとのブロックを組み立てる
+struct BlockBuilder<'a> {
+    source: &'a str,
+    blocks: Vec<MarkdownBlock>,
+    /// 次に出力する行の元の文字列での行番号（0始まり）
+    line: usize,
+    kind: LineKind,
+    inlines: Vec<Inline>,
+    /// 入れ子のリストの次の番号（箇条書きはNone）
+    lists: Vec<Option<u64>>,
+    quote_depth: usize,
+    strong_depth: usize,
+    emphasis_depth: usize,
+    /// 組み立て中のリンクのURLとテキスト
+    link: Option<(String, String)>,
+    in_code_block: bool,
+}
+
+impl<'a> BlockBuilder<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            blocks: Vec::new(),
+            line: 0,
+            kind: LineKind::Paragraph,
+            inlines: Vec::new(),
+            lists: Vec::new(),
+            quote_depth: 0,
+            strong_depth: 0,
+            emphasis_depth: 0,
+            link: None,
+            in_code_block: false,
+        }
+    }
+
+    fn handle(&mut self, event: Event, range: Range<usize>) {
+        match event {
+            Event::Start(tag) => self.start(tag, range),
+            Event::End(tag) => self.end(tag, range),
+            Event::Text(text) if self.in_code_block => {
+                for line in text.lines() {
+                    self.push_block(MarkdownBlock::CodeLine(line.to_string()));
+                }
+            }
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                self.push_text(&text)
+            }
+            Event::Code(code) => match &mut self.link {
+                Some((_, text)) => text.push_str(&code),
+                None => self.inlines.push(Inline::Code(code.into_string())),
+            },
+            Event::SoftBreak | Event::HardBreak => self.flush_line(),
+            Event::Rule => {
+                self.skip_to(range.start);
+                self.push_block(MarkdownBlock::Rule);
+            }
+            Event::TaskListMarker(checked) => {
+                if let LineKind::ListItem { marker, .. } = &mut self.kind {
+                    *marker = if checked { "☑" } else { "☐" }.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag, range: Range<usize>) {
+        match tag {
+            Tag::Paragraph => {
+                self.skip_to(range.start);
+                if !matches!(self.kind, LineKind::ListItem { .. }) {
+                    self.kind = self.text_kind();
+                }
+            }
+            Tag::Heading { .. } => {
+                self.skip_to(range.start);
+                self.kind = LineKind::Heading;
+            }
+            Tag::BlockQuote(_) => self.quote_depth += 1,
+            Tag::CodeBlock(kind) => {
+                self.skip_to(range.start);
+                // フェンスの行は表示しない
+                if matches!(kind, CodeBlockKind::Fenced(_)) {
+                    self.line += 1;
+                }
+                self.in_code_block = true;
+            }
+            Tag::List(start) => {
+                // 項目の中のリストの前に、項目の文字を出力する
+                self.flush_item();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.skip_to(range.start);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}.", *number - 1)
+                    }
+                    _ => "•".to_string(),
+                };
+                self.kind = LineKind::ListItem {
+                    depth: self.lists.len().saturating_sub(1),
+                    marker,
+                };
+            }
+            Tag::Strong => self.strong_depth += 1,
+            Tag::Emphasis => self.emphasis_depth += 1,
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                self.link = Some((dest_url.into_string(), String::new()));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd, range: Range<usize>) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Heading(_) => self.flush_line(),
+            TagEnd::Item => self.flush_item(),
+            TagEnd::BlockQuote(_) => self.quote_depth -= 1,
+            TagEnd::CodeBlock => self.in_code_block = false,
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::Strong => self.strong_depth -= 1,
+            TagEnd::Emphasis => self.emphasis_depth -= 1,
+            TagEnd::Link | TagEnd::Image => {
+                if let Some((url, text)) = self.link.take() {
+                    self.inlines.push(Inline::Link { text, url });
+                }
+            }
+            _ => {}
+        }
+        if matches!(
+            tag,
+            TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::CodeBlock
+        ) {
+            // 複数行の要素（見出しの下線や閉じるフェンスなど）の残りの行を飛ばす
+            let last_line = self.line_of(range.end.saturating_sub(1).max(range.start));
+            self.line = self.line.max(last_line + 1);
+        }
+    }
+
+    /// 文字を現在の装飾で追加する（同じ装飾の文字が続く場合はつなげる）
+    fn push_text(&mut self, text: &str) {
+        if let Some((_, link_text)) = &mut self.link {
+            link_text.push_str(text);
+            return;
+        }
+        let inline = if self.strong_depth > 0 {
+            Inline::Bold(text.to_string())
+        } else if self.emphasis_depth > 0 {
+            Inline::Italic(text.to_string())
+        } else {
+            Inline::Text(text.to_string())
+        };
+        match (self.inlines.last_mut(), inline) {
+            (Some(Inline::Text(last)), Inline::Text(text))
+            | (Some(Inline::Bold(last)), Inline::Bold(text))
+            | (Some(Inline::Italic(last)), Inline::Italic(text)) => last.push_str(&text),
+            (_, inline) => self.inlines.push(inline),
+        }
+    }
+
+    /// 組み立て中の行を出力する（リストの項目の続きの行は通常の行にする）
+    fn flush_line(&mut self) {
+        let content = std::mem::take(&mut self.inlines);
+        let next = self.text_kind();
+        let block = match std::mem::replace(&mut self.kind, next) {
+            LineKind::Paragraph => MarkdownBlock::Paragraph(content),
+            LineKind::Heading => MarkdownBlock::Heading(content),
+            LineKind::ListItem { depth, marker } => MarkdownBlock::ListItem {
+                depth,
+                marker,
+                content,
+            },
+            LineKind::Quote => MarkdownBlock::Quote(content),
+        };
+        self.push_block(block);
+    }
+
+    /// 項目の文字（続きの行を含む）をまだ出力していなければ出力する
+    fn flush_item(&mut self) {
+        if matches!(self.kind, LineKind::ListItem { .. }) || !self.inlines.is_empty() {
+            self.flush_line();
+        }
+    }
+
+    /// 引用の中かどうかに応じた通常の行の種類
+    fn text_kind(&self) -> LineKind {
+        if self.quote_depth > 0 {
+            LineKind::Quote
+        } else {
+            LineKind::Paragraph
+        }
+    }
+
+    fn push_block(&mut self, block: MarkdownBlock) {
+        self.blocks.push(block);
+        self.line += 1;
+    }
+
+    /// `offset`の行までの空行を出力する
+    fn skip_to(&mut self, offset: usize) {
+        while self.line < self.line_of(offset) {
+            self.push_block(MarkdownBlock::Paragraph(Vec::new()));
+        }
+    }
+
+    /// 元の文字列での位置の行番号（0始まり）
+    fn line_of(&self, offset: usize) -> usize {
+        self.source[..offset].matches('\n').count()
+    }
+}
+
+/// Markdownを端末に表示する行に変換する
+///
+/// `styled`がfalseの場合は、記号を取り除いた文字だけで表示します（パイプやファイルへの出力用）。
+pub fn render_markdown(text: &str, styled: bool) -> Vec<String> {
+    let style = |style: Style, text: &str| {
+        if styled {
+            style.force_styling(true).apply_to(text).to_string()
+        } else {
+            text.to_string()
+        }
+    };
+    let inlines = |inlines: &[Inline], base: Style| {
+        inlines
+            .iter()
+            .map(|inline| match inline {
+                Inline::Text(text) => style(base.clone(), text),
+                Inline::Bold(text) => style(base.clone().bold(), text),
+                Inline::Italic(text) => style(base.clone().italic(), text),
+                Inline::Code(text) => style(base.clone().cyan(), text),
+                Inline::Link { text, url } if text == url => style(base.clone().underlined(), url),
+                Inline::Link { text, url } => format!(
+                    "{} {}",
+                    style(base.clone().underlined(), text),
+                    style(base.clone().dim(), &format!("({})", url))
+                ),
+            })
+            .collect::<String>()
+    };
+
+    parse_markdown(text)
+        .iter()
+        .map(|block| match block {
+            MarkdownBlock::Paragraph(content) => inlines(content, Style::new()),
+            MarkdownBlock::Heading(content) => inlines(content, Style::new().bold().underlined()),
+            MarkdownBlock::ListItem {
+                depth,
+                marker,
+                content,
+            } => format!(
+                "{}{} {}",
+                "  ".repeat(*depth),
+                marker,
+                inlines(content, Style::new())
+            ),
+            MarkdownBlock::Quote(content) => format!(
+                "{} {}",
+                style(Style::new().dim(), "│"),
+                inlines(content, Style::new().italic())
+            ),
+            MarkdownBlock::CodeLine(line) => format!("    {}", style(Style::new().cyan(), line)),
+            MarkdownBlock::Rule => style(Style::new().dim(), RULE),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> Inline {
+        Inline::Text(s.to_string())
+    }
+
+    /// 1行の段落の行内の要素
+    fn inlines(markdown: &str) -> Vec<Inline> {
+        match parse_markdown(markdown).as_slice() {
+            [MarkdownBlock::Paragraph(content)] => content.clone(),
+            blocks => panic!("Expected a single paragraph: {:?}", blocks),
+        }
+    }
+
+    #[test]
+    fn test_parse_inline_styles() {
+        // Act
+        let inlines = inlines("**Bold**, *it*, `code` and [docs](https://example.com)");
+
+        // Assert
+        assert_eq!(
+            inlines,
+            vec![
+                Inline::Bold("Bold".to_string()),
+                text(", "),
+                Inline::Italic("it".to_string()),
+                text(", "),
+                Inline::Code("code".to_string()),
+                text(" and "),
+                Inline::Link {
+                    text: "docs".to_string(),
+                    url: "https://example.com".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline_keeps_unmatched_markers() {
+        // 閉じる記号がない・単語の途中の`_`・エスケープは文字として扱う
+        assert_eq!(inlines("2 * 3 = 6"), vec![text("2 * 3 = 6")]);
+        assert_eq!(
+            inlines("use snake_case_names"),
+            vec![text("use snake_case_names")]
+        );
+        assert_eq!(inlines(r"\*not italic\*"), vec![text("*not italic*")]);
+        assert_eq!(inlines("[not a link]"), vec![text("[not a link]")]);
+    }
+
+    #[test]
+    fn test_parse_markdown_blocks() {
+        // Arrange
+        let markdown = "# Plan\n- first\n  - nested\n2. second\n- [x] done\n> note\n---\n```\nlet x = *y*;\n```\nplain";
+
+        // Act
+        let blocks = parse_markdown(markdown);
+
+        // Assert
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Heading(vec![text("Plan")]),
+                MarkdownBlock::ListItem {
+                    depth: 0,
+                    marker: "•".to_string(),
+                    content: vec![text("first")]
+                },
+                MarkdownBlock::ListItem {
+                    depth: 1,
+                    marker: "•".to_string(),
+                    content: vec![text("nested")]
+                },
+                MarkdownBlock::ListItem {
+                    depth: 0,
+                    marker: "2.".to_string(),
+                    content: vec![text("second")]
+                },
+                MarkdownBlock::ListItem {
+                    depth: 0,
+                    marker: "☑".to_string(),
+                    content: vec![text("done")]
+                },
+                MarkdownBlock::Quote(vec![text("note")]),
+                MarkdownBlock::Rule,
+                MarkdownBlock::CodeLine("let x = *y*;".to_string()),
+                MarkdownBlock::Paragraph(vec![text("plain")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_markdown_keeps_lines() {
+        // Arrange: 空行・段落内の改行・複数行の引用・空行を挟むリスト・下線の見出し
+        let markdown = "first\nsecond\n\n> a\n> b\n\n1. one\n\n2. two\n\nTitle\n=====\n\n    code";
+
+        // Act
+        let blocks = parse_markdown(markdown);
+
+        // Assert
+        let item = |marker: &str, content: &str| MarkdownBlock::ListItem {
+            depth: 0,
+            marker: marker.to_string(),
+            content: vec![text(content)],
+        };
+        assert_eq!(
+            blocks,
+            vec![
+                MarkdownBlock::Paragraph(vec![text("first")]),
+                MarkdownBlock::Paragraph(vec![text("second")]),
+                MarkdownBlock::Paragraph(vec![]),
+                MarkdownBlock::Quote(vec![text("a")]),
+                MarkdownBlock::Quote(vec![text("b")]),
+                MarkdownBlock::Paragraph(vec![]),
+                item("1.", "one"),
+                MarkdownBlock::Paragraph(vec![]),
+                item("2.", "two"),
+                MarkdownBlock::Paragraph(vec![]),
+                MarkdownBlock::Heading(vec![text("Title")]),
+                MarkdownBlock::Paragraph(vec![]),
+                MarkdownBlock::CodeLine("code".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_plain() {
+        // Arrange
+        let markdown = "## Steps\n* run `cargo test`\nSee [guide](https://example.com/guide) or <https://example.com>";
+
+        // Act
+        let lines = render_markdown(markdown, false);
+
+        // Assert
+        assert_eq!(
+            lines,
+            vec![
+                "Steps",
+                "• run cargo test",
+                "See guide (https://example.com/guide) or https://example.com",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_styled() {
+        // Act
+        let lines = render_markdown("**done**", true);
+
+        // Assert
+        assert_eq!(lines, vec!["\u{1b}[1mdone\u{1b}[0m"]);
+    }
+}
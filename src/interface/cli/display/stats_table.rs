@@ -1,6 +1,16 @@
-use crate::application::dto::stats_dto::StatsDTO;
+use crate::{
+    application::dto::{
+        flow_stats_dto::{DurationSummaryDTO, FlowStatsDTO},
+        stats_dto::{StatsDTO, UNASSIGNED_LABEL},
+    },
+    domain::task::value_objects::Priority,
+    interface::{cli::display::theme::Theme, i18n::Msg},
+};
 use comfy_table::{Attribute, Cell, CellAlignment, Table, presets::UTF8_FULL};
 
+/// トップタグとして表示する最大件数
+const TOP_TAGS_LIMIT: usize = 5;
+
 /// プログレスバーを作成
 ///
 /// # 引数
@@ -29,20 +39,18 @@ fn create_title(title: &str) -> String {
 
 /// 期限関連のサマリーを作成
 ///
-/// コンパクトな1行表示
+/// コンパクトな1行表示。期限が近いタスクの区切りは設定された日数で表示します。
 fn create_due_date_summary(stats: &StatsDTO) -> String {
-    let overdue = stats.due_date_stats.get("overdue").copied().unwrap_or(0);
-    let due_today = stats.due_date_stats.get("due_today").copied().unwrap_or(0);
-    let due_this_week = stats
-        .due_date_stats
-        .get("due_this_week")
-        .copied()
-        .unwrap_or(0);
-
-    format!(
-        "Overdue: {} tasks, Due today: {} tasks, Due this week: {} tasks",
-        overdue, due_today, due_this_week
-    )
+    let count = |key: &str| stats.due_date_stats.get(key).copied().unwrap_or(0);
+
+    Msg::StatsDueSummary.format(&[
+        &count("overdue"),
+        &count("due_today"),
+        &stats.due_soon_days,
+        &count("due_soon"),
+        &stats.due_upcoming_days,
+        &count("due_upcoming"),
+    ])
 }
 
 /// priority_status_matrixにデータがあるかチェック
@@ -57,19 +65,23 @@ fn create_status_detail_table(stats: &StatsDTO) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_header(vec![
-        Cell::new("Status").add_attribute(Attribute::Bold),
-        Cell::new("Count")
+        Cell::new(Msg::FieldStatus.text()).add_attribute(Attribute::Bold),
+        Cell::new(Msg::StatsCount.text())
             .add_attribute(Attribute::Bold)
             .set_alignment(CellAlignment::Right),
-        Cell::new("Percentage (%)")
+        Cell::new(Msg::StatsPercentage.text())
             .add_attribute(Attribute::Bold)
             .set_alignment(CellAlignment::Right),
-        Cell::new("Progress").add_attribute(Attribute::Bold),
+        Cell::new(Msg::StatsProgress.text()).add_attribute(Attribute::Bold),
     ]);
 
     // 定義済みの順序でステータスを表示
     let status_order = ["pending", "in_progress", "completed"];
-    let status_labels = ["Pending", "In Progress", "Completed"];
+    let status_labels = [
+        Msg::StatusPending,
+        Msg::StatusInProgress,
+        Msg::StatusCompleted,
+    ];
 
     for (i, status_key) in status_order.iter().enumerate() {
         if let Some(&count) = stats.status_stats.get(*status_key) {
@@ -82,7 +94,7 @@ fn create_status_detail_table(stats: &StatsDTO) -> Table {
             let progress_bar = create_progress_bar(percentage);
 
             table.add_row(vec![
-                Cell::new(status_labels[i]),
+                Cell::new(status_labels[i].text()),
                 Cell::new(count.to_string()).set_alignment(CellAlignment::Right),
                 Cell::new(format!("{:.1}%", percentage)).set_alignment(CellAlignment::Right),
                 Cell::new(&progress_bar),
@@ -96,37 +108,46 @@ fn create_status_detail_table(stats: &StatsDTO) -> Table {
 /// 優先度×ステータスのマトリックステーブルを作成
 ///
 /// クロス集計により、各優先度のタスクがどのステータスにあるかを一覧表示
-fn create_priority_status_matrix_table(stats: &StatsDTO) -> Table {
+/// 優先度の行見出しはテーマの優先度色で表示します。
+fn create_priority_status_matrix_table(stats: &StatsDTO, theme: &Theme) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
 
     // ヘッダー行（ステータス）
     table.set_header(vec![
         Cell::new("").add_attribute(Attribute::Bold),
-        Cell::new("Pending")
+        Cell::new(Msg::StatusPending.text())
             .add_attribute(Attribute::Bold)
             .set_alignment(CellAlignment::Center),
-        Cell::new("In Progress")
+        Cell::new(Msg::StatusInProgress.text())
             .add_attribute(Attribute::Bold)
             .set_alignment(CellAlignment::Center),
-        Cell::new("Completed")
+        Cell::new(Msg::StatusCompleted.text())
             .add_attribute(Attribute::Bold)
             .set_alignment(CellAlignment::Center),
-        Cell::new("Total")
+        Cell::new(Msg::StatsTotal.text())
             .add_attribute(Attribute::Bold)
             .set_alignment(CellAlignment::Center),
     ]);
 
-    // 優先度の順序と表示ラベル
-    let priority_order = ["critical", "high", "medium", "low"];
-    let priority_labels = ["Critical", "High", "Medium", "Low"];
+    // 優先度の順序（集計のキー）と表示ラベル
+    let priority_order = [
+        (Priority::Critical, "critical", Msg::PriorityCritical),
+        (Priority::High, "high", Msg::PriorityHigh),
+        (Priority::Medium, "medium", Msg::PriorityMedium),
+        (Priority::Low, "low", Msg::PriorityLow),
+    ];
     let status_order = ["pending", "in_progress", "completed"];
 
     let mut col_totals = vec![0, 0, 0]; // 各ステータスの合計
 
     // 各優先度の行を追加
-    for (i, priority_key) in priority_order.iter().enumerate() {
-        let mut row_cells = vec![Cell::new(priority_labels[i]).add_attribute(Attribute::Bold)];
+    for (priority, priority_key, label) in priority_order {
+        let mut row_cells = vec![
+            theme
+                .priority_label_cell(priority, label.text())
+                .add_attribute(Attribute::Bold),
+        ];
 
         let mut row_total = 0;
 
@@ -151,7 +172,7 @@ fn create_priority_status_matrix_table(stats: &StatsDTO) -> Table {
     }
 
     // 合計行を追加
-    let mut total_row = vec![Cell::new("Total").add_attribute(Attribute::Bold)];
+    let mut total_row = vec![Cell::new(Msg::StatsTotal.text()).add_attribute(Attribute::Bold)];
     for total in &col_totals {
         total_row.push(
             Cell::new(total.to_string())
@@ -169,6 +190,76 @@ fn create_priority_status_matrix_table(stats: &StatsDTO) -> Table {
     table
 }
 
+/// 担当者×ステータスのテーブルを作成
+///
+/// 担当者を名前順に並べ、担当者なしのタスクは最後の行にまとめて表示
+fn create_assignee_status_table(stats: &StatsDTO) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        Cell::new(Msg::FieldAssignee.text()).add_attribute(Attribute::Bold),
+        Cell::new(Msg::StatusPending.text())
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new(Msg::StatusInProgress.text())
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new(Msg::StatusCompleted.text())
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new(Msg::StatsTotal.text())
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+    ]);
+
+    // キーは "assignee:status" 形式（ステータスに`:`は含まれないため末尾で分割する）
+    let mut assignees: Vec<&str> = stats
+        .assignee_status_matrix
+        .keys()
+        .filter_map(|key| key.rsplit_once(':').map(|(assignee, _)| assignee))
+        .filter(|assignee| *assignee != UNASSIGNED_LABEL)
+        .collect();
+    assignees.sort_unstable();
+    assignees.dedup();
+
+    let status_order = ["pending", "in_progress", "completed"];
+    let rows = assignees
+        .into_iter()
+        .map(|assignee| (assignee, assignee.to_string()))
+        .chain(std::iter::once((
+            UNASSIGNED_LABEL,
+            Msg::StatsUnassigned.text().to_string(),
+        )));
+    for (assignee, label) in rows {
+        let counts: Vec<usize> = status_order
+            .iter()
+            .map(|status_key| {
+                let key = format!("{}:{}", assignee, status_key);
+                stats.assignee_status_matrix.get(&key).copied().unwrap_or(0)
+            })
+            .collect();
+        let total: usize = counts.iter().sum();
+        if total == 0 {
+            continue;
+        }
+
+        let mut row_cells = vec![Cell::new(label)];
+        row_cells.extend(
+            counts
+                .iter()
+                .map(|count| Cell::new(count.to_string()).set_alignment(CellAlignment::Right)),
+        );
+        row_cells.push(
+            Cell::new(total.to_string())
+                .set_alignment(CellAlignment::Right)
+                .add_attribute(Attribute::Bold),
+        );
+        table.add_row(row_cells);
+    }
+
+    table
+}
+
 /// トップタグのテーブルを作成
 ///
 /// タグを件数で降順ソートし、上位N件のみを表示
@@ -180,8 +271,8 @@ fn create_top_tags_table(stats: &StatsDTO, limit: usize) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_header(vec![
-        Cell::new("Tag Name").add_attribute(Attribute::Bold),
-        Cell::new("Count")
+        Cell::new(Msg::StatsTagName.text()).add_attribute(Attribute::Bold),
+        Cell::new(Msg::StatsCount.text())
             .add_attribute(Attribute::Bold)
             .set_alignment(CellAlignment::Right),
     ]);
@@ -204,28 +295,31 @@ fn create_top_tags_table(stats: &StatsDTO, limit: usize) -> Table {
 /// 統計情報のリッチ表示を作成
 ///
 /// サマリー、ステータス別詳細、優先度×ステータスマトリックス、
-/// 期限関連、トップタグを含む包括的な統計情報を表示します。
+/// 期限関連、担当者別（担当者のいるタスクがある場合）、トップタグを含む包括的な統計情報を表示します。
 ///
 /// # 引数
 /// - `stats`: 統計情報DTO
+/// - `theme`: 配色テーマ
 ///
 /// # 戻り値
 /// フォーマットされた文字列（複数のテーブルを含む）
-pub fn create_rich_stats_display(stats: &StatsDTO) -> String {
+pub fn create_rich_stats_display(stats: &StatsDTO, theme: &Theme) -> String {
     let mut output = String::new();
 
     // タイトル
-    output.push_str(&create_title("Task Statistics Summary"));
+    output.push_str(&create_title(Msg::StatsTitle.text()));
     output.push('\n');
     output.push('\n');
 
     // サマリーセクション
-    output.push_str(&format!("Total tasks: {}\n", stats.total_count));
+    output.push_str(&Msg::StatsTotalTasks.format(&[&stats.total_count]));
+    output.push('\n');
     output.push('\n');
 
     // ステータス別詳細テーブル（パーセンテージとプログレスバー付き）
     if !stats.status_stats.is_empty() {
-        output.push_str("[By Status]\n");
+        output.push_str(Msg::StatsByStatus.text());
+        output.push('\n');
         output.push_str(&create_status_detail_table(stats).to_string());
         output.push('\n');
         output.push('\n');
@@ -233,30 +327,95 @@ pub fn create_rich_stats_display(stats: &StatsDTO) -> String {
 
     // 優先度×ステータス マトリックステーブル
     if has_priority_status_data(stats) {
-        output.push_str("[Priority × Status Matrix]\n");
-        output.push_str(&create_priority_status_matrix_table(stats).to_string());
+        output.push_str(Msg::StatsPriorityStatusMatrix.text());
+        output.push('\n');
+        output.push_str(&create_priority_status_matrix_table(stats, theme).to_string());
         output.push('\n');
         output.push('\n');
     }
 
     // 期限関連（コンパクト表示）
     if !stats.due_date_stats.is_empty() {
-        output.push_str("[Due Dates]\n");
+        output.push_str(Msg::StatsDueDates.text());
+        output.push('\n');
         output.push_str(&create_due_date_summary(stats));
         output.push('\n');
         output.push('\n');
     }
 
+    // 担当者×ステータス
+    if !stats.assignee_status_matrix.is_empty() {
+        output.push_str(Msg::StatsByAssignee.text());
+        output.push('\n');
+        output.push_str(&create_assignee_status_table(stats).to_string());
+        output.push('\n');
+        output.push('\n');
+    }
+
     // トップタグ
     if !stats.tag_stats.is_empty() {
-        output.push_str("[Top Tags (Top 5)]\n");
-        output.push_str(&create_top_tags_table(stats, 5).to_string());
+        output.push_str(&Msg::StatsTopTags.format(&[&TOP_TAGS_LIMIT]));
+        output.push('\n');
+        output.push_str(&create_top_tags_table(stats, TOP_TAGS_LIMIT).to_string());
         output.push('\n');
     }
 
     output
 }
 
+/// 分数を`2d 3h`・`5h 20m`・`15m`の形式で表す（上位2つの単位まで）
+fn format_flow_duration(minutes: i64) -> String {
+    let (days, hours, minutes) = (minutes / (24 * 60), minutes / 60 % 24, minutes % 60);
+    match (days, hours, minutes) {
+        (0, 0, m) => format!("{}m", m),
+        (0, h, 0) => format!("{}h", h),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, 0, _) => format!("{}d", d),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
+}
+
+/// タスクの流れの統計を表示用の文字列に変換
+///
+/// 指標ごとに平均と、平均に含めたタスク数を表示します（対象のタスクがない指標は`-`）。
+pub fn create_flow_stats_display(stats: &FlowStatsDTO) -> String {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        Cell::new(Msg::StatsFlowMetric.text()).add_attribute(Attribute::Bold),
+        Cell::new(Msg::StatsFlowAverage.text())
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Right),
+        Cell::new(Msg::StatsCount.text())
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Right),
+    ]);
+
+    let rows: [(Msg, &DurationSummaryDTO); 4] = [
+        (Msg::StatsFlowTimeInPending, &stats.pending),
+        (Msg::StatsFlowTimeInProgress, &stats.in_progress),
+        (Msg::StatsFlowCycleTime, &stats.cycle_time),
+        (Msg::StatsFlowLeadTime, &stats.lead_time),
+    ];
+    for (label, summary) in rows {
+        let average = summary
+            .average_minutes
+            .map_or_else(|| "-".to_string(), format_flow_duration);
+        table.add_row(vec![
+            Cell::new(label.text()),
+            Cell::new(average).set_alignment(CellAlignment::Right),
+            Cell::new(summary.count).set_alignment(CellAlignment::Right),
+        ]);
+    }
+
+    format!(
+        "{}\n\n{}\n\n{}\n",
+        create_title(Msg::StatsFlowTitle.text()),
+        Msg::StatsFlowTasks.format(&[&stats.task_count]),
+        table
+    )
+}
+
 // テストのみを先に作成（TDD）
 #[cfg(test)]
 mod tests {
@@ -294,21 +453,25 @@ mod tests {
         let mut due_date_stats = HashMap::new();
         due_date_stats.insert("overdue".to_string(), 5);
         due_date_stats.insert("due_today".to_string(), 2);
-        due_date_stats.insert("due_this_week".to_string(), 8);
+        due_date_stats.insert("due_soon".to_string(), 8);
+        due_date_stats.insert("due_upcoming".to_string(), 3);
 
         let stats = StatsDTO {
             total_count: 42,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats,
             tag_stats: HashMap::new(),
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
         let summary = create_due_date_summary(&stats);
         assert_eq!(
             summary,
-            "Overdue: 5 tasks, Due today: 2 tasks, Due this week: 8 tasks"
+            "Overdue: 5 tasks, Due today: 2 tasks, Due within 7 days: 8 tasks, Due within 30 days: 3 tasks"
         );
     }
 
@@ -316,17 +479,20 @@ mod tests {
     fn test_create_due_date_summary_empty() {
         let stats = StatsDTO {
             total_count: 42,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
         let summary = create_due_date_summary(&stats);
         assert_eq!(
             summary,
-            "Overdue: 0 tasks, Due today: 0 tasks, Due this week: 0 tasks"
+            "Overdue: 0 tasks, Due today: 0 tasks, Due within 7 days: 0 tasks, Due within 30 days: 0 tasks"
         );
     }
 
@@ -337,11 +503,14 @@ mod tests {
 
         let stats = StatsDTO {
             total_count: 5,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix,
+            assignee_status_matrix: HashMap::new(),
         };
 
         assert!(has_priority_status_data(&stats));
@@ -351,11 +520,14 @@ mod tests {
     fn test_has_priority_status_data_false() {
         let stats = StatsDTO {
             total_count: 0,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
         assert!(!has_priority_status_data(&stats));
@@ -372,11 +544,14 @@ mod tests {
 
         let stats = StatsDTO {
             total_count: 42,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats,
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
         let table = create_status_detail_table(&stats);
@@ -403,11 +578,14 @@ mod tests {
     fn test_create_status_detail_table_empty() {
         let stats = StatsDTO {
             total_count: 0,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
         let table = create_status_detail_table(&stats);
@@ -429,14 +607,17 @@ mod tests {
 
         let stats = StatsDTO {
             total_count: 18,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix,
+            assignee_status_matrix: HashMap::new(),
         };
 
-        let table = create_priority_status_matrix_table(&stats);
+        let table = create_priority_status_matrix_table(&stats, &Theme::default());
         let output = table.to_string();
 
         // ヘッダーが含まれることを確認
@@ -464,14 +645,17 @@ mod tests {
 
         let stats = StatsDTO {
             total_count: 5,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix,
+            assignee_status_matrix: HashMap::new(),
         };
 
-        let table = create_priority_status_matrix_table(&stats);
+        let table = create_priority_status_matrix_table(&stats, &Theme::default());
         let output = table.to_string();
 
         // ヘッダーと合計行が含まれることを確認
@@ -491,11 +675,14 @@ mod tests {
 
         let stats = StatsDTO {
             total_count: 53,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats,
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
         let table = create_top_tags_table(&stats, 3);
@@ -516,11 +703,14 @@ mod tests {
 
         let stats = StatsDTO {
             total_count: 27,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats,
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
         let table = create_top_tags_table(&stats, 5);
@@ -531,6 +721,37 @@ mod tests {
         assert!(output.contains("バグ"));
     }
 
+    #[test]
+    fn test_create_assignee_status_table() {
+        let mut assignee_status_matrix = HashMap::new();
+        assignee_status_matrix.insert("bob:pending".to_string(), 1);
+        assignee_status_matrix.insert("alice:pending".to_string(), 2);
+        assignee_status_matrix.insert("alice:completed".to_string(), 3);
+        assignee_status_matrix.insert("(Unassigned):in_progress".to_string(), 4);
+
+        let stats = StatsDTO {
+            total_count: 10,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
+            status_stats: HashMap::new(),
+            priority_stats: HashMap::new(),
+            due_date_stats: HashMap::new(),
+            tag_stats: HashMap::new(),
+            priority_status_matrix: HashMap::new(),
+            assignee_status_matrix,
+        };
+
+        let output = create_assignee_status_table(&stats).to_string();
+
+        // 名前順に並び、担当者なしは最後の行になることを確認
+        let alice = output.find("alice").unwrap();
+        let bob = output.find("bob").unwrap();
+        let unassigned = output.find("(Unassigned)").unwrap();
+        assert!(alice < bob && bob < unassigned);
+        let alice_row = output.lines().find(|line| line.contains("alice")).unwrap();
+        assert!(alice_row.contains(" 5 "));
+    }
+
     // 統合テスト
 
     #[test]
@@ -548,7 +769,8 @@ mod tests {
         let mut due_date_stats = HashMap::new();
         due_date_stats.insert("overdue".to_string(), 5);
         due_date_stats.insert("due_today".to_string(), 2);
-        due_date_stats.insert("due_this_week".to_string(), 8);
+        due_date_stats.insert("due_soon".to_string(), 8);
+        due_date_stats.insert("due_upcoming".to_string(), 3);
 
         let mut tag_stats = HashMap::new();
         tag_stats.insert("重要".to_string(), 15);
@@ -556,14 +778,17 @@ mod tests {
 
         let stats = StatsDTO {
             total_count: 42,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats,
             priority_stats: HashMap::new(),
             due_date_stats,
             tag_stats,
             priority_status_matrix,
+            assignee_status_matrix: HashMap::new(),
         };
 
-        let display = create_rich_stats_display(&stats);
+        let display = create_rich_stats_display(&stats, &Theme::default());
 
         // タイトルが含まれることを確認
         assert!(display.contains("Task Statistics Summary"));
@@ -574,6 +799,8 @@ mod tests {
         assert!(display.contains("[Priority × Status Matrix]"));
         assert!(display.contains("[Due Dates]"));
         assert!(display.contains("[Top Tags (Top 5)]"));
+        // 担当者のデータがない場合は担当者別のセクションを表示しない
+        assert!(!display.contains("[Assignee × Status]"));
 
         // プログレスバーが含まれることを確認
         assert!(display.contains("█"));
@@ -584,17 +811,67 @@ mod tests {
     fn test_create_rich_stats_display_minimal_data() {
         let stats = StatsDTO {
             total_count: 0,
+            due_soon_days: 7,
+            due_upcoming_days: 30,
             status_stats: HashMap::new(),
             priority_stats: HashMap::new(),
             due_date_stats: HashMap::new(),
             tag_stats: HashMap::new(),
             priority_status_matrix: HashMap::new(),
+            assignee_status_matrix: HashMap::new(),
         };
 
-        let display = create_rich_stats_display(&stats);
+        let display = create_rich_stats_display(&stats, &Theme::default());
 
         // 最小限のセクションが含まれることを確認
         assert!(display.contains("Task Statistics Summary"));
         assert!(display.contains("Total tasks: 0"));
     }
+
+    #[test]
+    fn test_format_flow_duration() {
+        assert_eq!(format_flow_duration(15), "15m");
+        assert_eq!(format_flow_duration(120), "2h");
+        assert_eq!(format_flow_duration(320), "5h 20m");
+        assert_eq!(format_flow_duration(2 * 24 * 60 + 10), "2d");
+        assert_eq!(format_flow_duration(3 * 24 * 60 + 4 * 60 + 30), "3d 4h");
+    }
+
+    #[test]
+    fn test_create_flow_stats_display() {
+        // Arrange
+        let stats = FlowStatsDTO {
+            task_count: 3,
+            pending: DurationSummaryDTO {
+                count: 3,
+                average_minutes: Some(90),
+            },
+            in_progress: DurationSummaryDTO {
+                count: 1,
+                average_minutes: Some(26 * 60),
+            },
+            cycle_time: DurationSummaryDTO {
+                count: 0,
+                average_minutes: None,
+            },
+            lead_time: DurationSummaryDTO {
+                count: 1,
+                average_minutes: Some(30),
+            },
+        };
+
+        // Act
+        let display = create_flow_stats_display(&stats);
+
+        // Assert
+        assert!(display.contains("Task Flow Statistics"));
+        assert!(display.contains("Tasks analysed: 3"));
+        assert!(display.contains("1h 30m"));
+        assert!(display.contains("1d 2h"));
+        let cycle_row = display
+            .lines()
+            .find(|line| line.contains("Cycle time"))
+            .unwrap();
+        assert!(cycle_row.contains(" - "));
+    }
 }
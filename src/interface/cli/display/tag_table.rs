@@ -1,6 +1,9 @@
 use crate::{
     application::dto::tag_dto::TagDTO,
-    interface::cli::display::format::{format_local_time, format_optional_text, truncate_text},
+    interface::{
+        cli::display::format::{format_local_time, format_optional_text, truncate_text},
+        i18n::Msg,
+    },
 };
 use comfy_table::{Table, presets::UTF8_FULL};
 
@@ -12,7 +15,13 @@ use comfy_table::{Table, presets::UTF8_FULL};
 /// # 戻り値
 /// フォーマットされたテーブル
 pub fn create_tag_table(tags: &[TagDTO]) -> Table {
-    let headers = vec!["ID", "Name", "Description", "Created At", "Updated At"];
+    let headers = vec![
+        Msg::FieldId.text(),
+        Msg::FieldName.text(),
+        Msg::FieldDescription.text(),
+        Msg::FieldCreatedAt.text(),
+        Msg::FieldUpdatedAt.text(),
+    ];
 
     let rows: Vec<Vec<String>> = tags.iter().map(create_tag_row).collect();
 
@@ -24,11 +33,21 @@ pub fn create_tag_detail_table(tag: &TagDTO) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
 
-    table.add_row(vec!["ID", &tag.id.to_string()]);
-    table.add_row(vec!["Name", &tag.name]);
-    table.add_row(vec!["Description", &format_optional_text(&tag.description)]);
-    table.add_row(vec!["Created At", &format_local_time(&tag.created_at)]);
-    table.add_row(vec!["Updated At", &format_local_time(&tag.updated_at)]);
+    table.add_row(vec![Msg::FieldId.text(), &tag.id.to_string()]);
+    table.add_row(vec![Msg::FieldName.text(), &tag.name]);
+    table.add_row(vec![Msg::FieldPath.text(), &tag.path]);
+    table.add_row(vec![
+        Msg::FieldDescription.text(),
+        &format_optional_text(&tag.description),
+    ]);
+    table.add_row(vec![
+        Msg::FieldCreatedAt.text(),
+        &format_local_time(&tag.created_at),
+    ]);
+    table.add_row(vec![
+        Msg::FieldUpdatedAt.text(),
+        &format_local_time(&tag.updated_at),
+    ]);
 
     table
 }
@@ -63,7 +82,7 @@ fn create_tag_row(tag: &TagDTO) -> Vec<String> {
 
     vec![
         tag.id.to_string(),
-        truncate_text(&tag.name, 20),
+        truncate_text(&tag.path, 30),
         description,
         format_local_time(&tag.created_at),
         format_local_time(&tag.updated_at),
@@ -1,53 +1,387 @@
 use crate::{
-    application::dto::task_dto::TaskDTO,
-    interface::cli::display::format::{
-        format_date, format_local_time, format_optional_datetime, format_optional_text,
-        format_tags, truncate_text,
+    application::{
+        dto::{
+            task_detail_dto::{TaskDetailDTO, TaskHistoryKind},
+            task_dto::TaskDTO,
+        },
+        use_cases::task::search_tasks::match_ranges,
+    },
+    domain::{
+        clock::{Clock, SystemClock},
+        task::{
+            specification::SearchField,
+            stale_policy::StalePolicy,
+            value_objects::{DueDateStatus, Estimate, Status},
+        },
+    },
+    interface::{
+        cli::display::{
+            date_format::current_date_format,
+            format::{
+                due_date_badge, format_checklist_items, format_checklist_progress, format_date,
+                format_day_count, format_days_ago, format_external_ref, format_git_link,
+                format_local_time, format_optional_datetime, format_optional_text,
+                format_relative_days, format_relative_due_date, format_status, format_tags,
+                truncate_text,
+            },
+            theme::Theme,
+        },
+        i18n::Msg,
+        url::find_task_urls,
     },
 };
-use comfy_table::{Table, presets::UTF8_FULL};
+use chrono::{DateTime, NaiveDate, Utc};
+use comfy_table::{Cell, ContentArrangement, Table, presets::UTF8_FULL};
+use std::str::FromStr;
+use strum::{EnumIter, IntoEnumIterator};
+
+/// タスク一覧の表示形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskListFormat {
+    /// 標準表示（全項目、タイトル・説明は20文字で切り詰め）
+    #[default]
+    Standard,
+    /// コンパクト表示（ID・タイトル・ステータス・期限日のみ）
+    Compact,
+    /// ワイド表示（全項目を切り詰めずに表示）
+    Wide,
+}
+
+/// タスク一覧に表示する列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum TaskColumn {
+    Id,
+    Title,
+    Description,
+    Status,
+    Priority,
+    Tags,
+    DueDate,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+    /// 放置されている場合の経過日数（標準・ワイド表示の既定の列には含めない）
+    Stale,
+    /// 担当者（標準・ワイド表示の既定の列には含めない）
+    Assignee,
+}
+
+impl TaskColumn {
+    /// 列の見出し
+    fn header(self) -> Msg {
+        match self {
+            Self::Id => Msg::FieldId,
+            Self::Title => Msg::FieldTitle,
+            Self::Description => Msg::FieldDescription,
+            Self::Status => Msg::FieldStatus,
+            Self::Priority => Msg::FieldPriority,
+            Self::Tags => Msg::FieldTags,
+            Self::DueDate => Msg::FieldDueDate,
+            Self::CompletedAt => Msg::FieldCompletedAt,
+            Self::CreatedAt => Msg::FieldCreatedAt,
+            Self::UpdatedAt => Msg::FieldUpdatedAt,
+            Self::Stale => Msg::FieldStale,
+            Self::Assignee => Msg::FieldAssignee,
+        }
+    }
+}
+
+impl FromStr for TaskColumn {
+    type Err = String;
+
+    /// `id`, `title`, `description`, `status`, `priority`, `tags`, `due`, `completed`,
+    /// `created`, `updated`, `stale`, `assignee`を受け付ける
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "id" => Ok(Self::Id),
+            "title" => Ok(Self::Title),
+            "description" => Ok(Self::Description),
+            "status" => Ok(Self::Status),
+            "priority" => Ok(Self::Priority),
+            "tags" => Ok(Self::Tags),
+            "due" | "due_date" => Ok(Self::DueDate),
+            "completed" | "completed_at" => Ok(Self::CompletedAt),
+            "created" | "created_at" => Ok(Self::CreatedAt),
+            "updated" | "updated_at" => Ok(Self::UpdatedAt),
+            "stale" => Ok(Self::Stale),
+            "assignee" => Ok(Self::Assignee),
+            _ => Err(format!("Unknown column: '{}'", s)),
+        }
+    }
+}
+
+/// コンパクト表示時の列
+const COMPACT_COLUMNS: [TaskColumn; 4] = [
+    TaskColumn::Id,
+    TaskColumn::Title,
+    TaskColumn::Status,
+    TaskColumn::DueDate,
+];
+
+/// タスク一覧の表示設定（表示形式と表示する列）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskListView {
+    pub format: TaskListFormat,
+    pub columns: Vec<TaskColumn>,
+    /// `stale`列で放置を判定するポリシー
+    pub stale_policy: StalePolicy,
+    /// 放置の経過日数の基準にする現在日時
+    pub now: DateTime<Utc>,
+    /// タイトル・説明で強調表示する検索語（小文字）
+    pub highlight_terms: Vec<String>,
+    /// 強調表示する対象の項目
+    pub highlight_field: SearchField,
+}
+
+impl TaskListView {
+    /// 表示する列を指定して作成（空の場合は表示形式の既定の列）
+    pub fn new(format: TaskListFormat, columns: Vec<TaskColumn>) -> Self {
+        if columns.is_empty() {
+            return format.into();
+        }
+        Self {
+            format,
+            columns,
+            stale_policy: StalePolicy::default(),
+            now: Utc::now(),
+            highlight_terms: vec![],
+            highlight_field: SearchField::All,
+        }
+    }
+
+    /// `stale`列で放置を判定するポリシーを指定する
+    pub fn with_stale_policy(mut self, policy: StalePolicy) -> Self {
+        self.stale_policy = policy;
+        self
+    }
+
+    /// 放置の経過日数の基準にする現在日時を指定する
+    pub fn with_now(mut self, now: DateTime<Utc>) -> Self {
+        self.now = now;
+        self
+    }
+
+    /// 検索語に一致する部分を強調表示する（`field`はタイトル・説明のどちらを対象にするか）
+    pub fn with_highlight(mut self, terms: Vec<String>, field: SearchField) -> Self {
+        self.highlight_terms = terms;
+        self.highlight_field = field;
+        self
+    }
+}
+
+impl From<TaskListFormat> for TaskListView {
+    fn from(format: TaskListFormat) -> Self {
+        let columns = match format {
+            TaskListFormat::Compact => COMPACT_COLUMNS.to_vec(),
+            TaskListFormat::Standard | TaskListFormat::Wide => TaskColumn::iter()
+                .filter(|column| !matches!(column, TaskColumn::Stale | TaskColumn::Assignee))
+                .collect(),
+        };
+        Self {
+            format,
+            columns,
+            stale_policy: StalePolicy::default(),
+            now: Utc::now(),
+            highlight_terms: vec![],
+            highlight_field: SearchField::All,
+        }
+    }
+}
+
+/// 強調表示の開始位置の目印（幅0の文字のため、テーブルの列幅の計算に影響しない）
+const HIGHLIGHT_START: char = '\u{200B}';
+/// 強調表示の終了位置の目印
+const HIGHLIGHT_END: char = '\u{2060}';
+/// 強調表示の開始（反転表示）
+const HIGHLIGHT_ON: &str = "\x1b[7m";
+/// 強調表示の終了（反転表示のみ解除し、行の色や減光は保つ）
+const HIGHLIGHT_OFF: &str = "\x1b[27m";
 
 /// タスクのテーブルを作成
-pub fn create_task_table(tasks: &[TaskDTO]) -> Table {
-    let headers = vec![
-        "ID",
-        "Title",
-        "Description",
-        "Status",
-        "Priority",
-        "Tags",
-        "Due Date",
-        "Completed At",
-        "Created At",
-        "Updated At",
-    ];
+///
+/// コンパクト表示・ワイド表示では、端末幅に収まるよう各セルを1行に切り詰めます。
+pub fn create_task_table(tasks: &[TaskDTO], theme: &Theme, view: &TaskListView) -> Table {
+    let headers = view
+        .columns
+        .iter()
+        .map(|column| column.header().text())
+        .collect();
+
+    let rows: Vec<Vec<Cell>> = tasks
+        .iter()
+        .map(|task| create_task_row(task, theme, view))
+        .collect();
 
-    let rows: Vec<Vec<String>> = tasks.iter().map(create_task_row).collect();
+    let mut table = build_table_with_preset(headers, rows);
+    if view.format != TaskListFormat::Standard {
+        fit_to_terminal_width(&mut table);
+    }
 
-    build_table_with_preset(headers, rows)
+    table
+}
+
+/// テーブルを端末幅に合わせ、収まらないセルを1行に切り詰める
+///
+/// 端末幅が取得できない場合（パイプ出力時など）は切り詰めを行いません。
+fn fit_to_terminal_width(table: &mut Table) {
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_truncation_indicator("…");
+
+    for row in table.row_iter_mut() {
+        row.max_height(1);
+    }
 }
 
 /// タスクの詳細テーブルを作成
-pub fn create_task_detail_table(task: &TaskDTO) -> Table {
+///
+/// `include_description`がfalseの場合は説明の行を含めません（説明をMarkdownとして表の外に表示する場合）。
+pub fn create_task_detail_table(task: &TaskDTO, theme: &Theme, include_description: bool) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
 
-    table.add_row(vec!["ID", &task.id.to_string()]);
-    table.add_row(vec!["Title", &task.title]);
+    table.add_row(vec![Msg::FieldId.text(), &task.id.to_string()]);
+    table.add_row(vec![Msg::FieldTitle.text(), &task.title]);
+    if include_description {
+        table.add_row(vec![
+            Msg::FieldDescription.text(),
+            &format_optional_text(&task.description),
+        ]);
+    }
+    table.add_row(vec![Msg::FieldStatus.text(), format_status(task.status)]);
     table.add_row(vec![
-        "Description",
-        &format_optional_text(&task.description),
+        Cell::new(Msg::FieldPriority.text()),
+        theme.priority_cell(task.priority),
     ]);
-    table.add_row(vec!["Status", &task.status]);
-    table.add_row(vec!["Priority", &task.priority]);
-    table.add_row(vec!["Tags", &format_tags(&task.tags, ", ")]);
-    table.add_row(vec!["Due Date", &format_date(&task.due_date)]);
+    table.add_row(vec![Msg::FieldTags.text(), &format_tags(&task.tags, ", ")]);
     table.add_row(vec![
-        "Completed At",
+        Cell::new(Msg::FieldDueDate.text()),
+        create_due_date_cell(task, theme, today()),
+    ]);
+    if let Some(recurrence) = &task.recurrence {
+        table.add_row(vec![Msg::FieldRecurrence.text(), recurrence]);
+    }
+    if let Some(minutes) = task.estimate_minutes {
+        table.add_row(vec![
+            Msg::FieldEstimate.text(),
+            &Estimate::format_minutes(minutes),
+        ]);
+    }
+    if let Some(context) = &task.context {
+        table.add_row(vec![Msg::FieldContext.text(), context]);
+    }
+    if let Some(assignee) = &task.assignee {
+        table.add_row(vec![Msg::FieldAssignee.text(), assignee]);
+    }
+    // 期限を過ぎたスヌーズは自動的に解除されているため表示しない
+    if let Some(until) = task.snoozed_until.filter(|until| *until > today()) {
+        table.add_row(vec![
+            Msg::FieldSnoozedUntil.text(),
+            &format_relative_due_date(&Some(until), today()),
+        ]);
+    }
+    if !task.custom_fields.is_empty() {
+        let fields = task
+            .custom_fields
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+        table.add_row(vec![Msg::FieldCustomFields.text(), &fields]);
+    }
+    if !task.checklist.is_empty() {
+        let mut lines = vec![format!("({})", format_checklist_progress(&task.checklist))];
+        lines.extend(format_checklist_items(&task.checklist));
+        table.add_row(vec![Msg::FieldChecklist.text(), &lines.join("\n")]);
+    }
+    if !task.git_links.is_empty() {
+        let links: Vec<String> = task.git_links.iter().map(format_git_link).collect();
+        table.add_row(vec![Msg::FieldGitLinks.text(), &links.join("\n")]);
+    }
+    if !task.external_refs.is_empty() {
+        let refs: Vec<String> = task.external_refs.iter().map(format_external_ref).collect();
+        table.add_row(vec![Msg::FieldExternalRefs.text(), &refs.join("\n")]);
+    }
+    // `task open --nth`で指定する番号を付けて表示する
+    let urls = find_task_urls(&task.title, task.description.as_deref());
+    if !urls.is_empty() {
+        let links: Vec<String> = urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| format!("{}. {}", i + 1, url))
+            .collect();
+        table.add_row(vec![Msg::FieldLinks.text(), &links.join("\n")]);
+    }
+    table.add_row(vec![
+        Msg::FieldCompletedAt.text(),
         &format_optional_datetime(&task.completed_at),
     ]);
-    table.add_row(vec!["Created At", &format_local_time(&task.created_at)]);
-    table.add_row(vec!["Updated At", &format_local_time(&task.updated_at)]);
+    table.add_row(vec![
+        Msg::FieldCreatedAt.text(),
+        &format_local_time(&task.created_at),
+    ]);
+    table.add_row(vec![
+        Msg::FieldUpdatedAt.text(),
+        &format_local_time(&task.updated_at),
+    ]);
+
+    table
+}
+
+/// タスクの関連情報テーブルを作成
+///
+/// 期限までの日数、経過日数、同じタグを持つタスク、変更履歴の概要を表示します。
+pub fn create_task_related_table(detail: &TaskDetailDTO) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+
+    let due_in = detail
+        .days_until_due
+        .map(format_relative_days)
+        .unwrap_or_else(|| "-".to_string());
+    let related_tasks = if detail.related_tasks.is_empty() {
+        "-".to_string()
+    } else {
+        detail
+            .related_tasks
+            .iter()
+            .map(|task| {
+                format!(
+                    "[{}] {} ({}) {}",
+                    task.id,
+                    task.title,
+                    format_status(task.status),
+                    task.shared_tags.join(", ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let history = detail
+        .history
+        .iter()
+        .map(|entry| {
+            let label = match entry.kind {
+                TaskHistoryKind::Created => Msg::HistoryCreated,
+                TaskHistoryKind::Updated => Msg::HistoryUpdated,
+                TaskHistoryKind::Completed => Msg::HistoryCompleted,
+            };
+            format!("{} {}", format_local_time(&entry.occurred_at), label.text())
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    table.add_row(vec![Msg::FieldDueIn.text(), &due_in]);
+    table.add_row(vec![
+        Msg::FieldAge.text(),
+        &format_day_count(detail.age_days),
+    ]);
+    table.add_row(vec![
+        Msg::FieldLastUpdated.text(),
+        &format_days_ago(detail.days_since_update),
+    ]);
+    table.add_row(vec![Msg::FieldRelatedTasks.text(), &related_tasks]);
+    table.add_row(vec![Msg::FieldHistory.text(), &history]);
 
     table
 }
@@ -60,7 +394,7 @@ pub fn create_task_detail_table(task: &TaskDTO) -> Table {
 ///
 /// # 戻り値
 /// UTF8_FULLプリセットが適用されたテーブル
-fn build_table_with_preset(headers: Vec<&str>, rows: Vec<Vec<String>>) -> Table {
+fn build_table_with_preset(headers: Vec<&str>, rows: Vec<Vec<Cell>>) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_header(headers);
@@ -74,25 +408,489 @@ fn build_table_with_preset(headers: Vec<&str>, rows: Vec<Vec<String>>) -> Table
 ///
 /// # 引数
 /// - `task`: タスクDTO
+/// - `theme`: 配色テーマ
+/// - `view`: 表示設定
 ///
 /// # 戻り値
-/// タスクの1行分のデータ（文字列のベクタ）
-fn create_task_row(task: &TaskDTO) -> Vec<String> {
-    let tags_str = format_tags(&task.tags, ",");
-    let description = truncate_text(&format_optional_text(&task.description), 20);
-    let due_date_str = format_date(&task.due_date);
-    let completed_at_str = format_optional_datetime(&task.completed_at);
-
-    vec![
-        task.id.to_string(),
-        truncate_text(&task.title, 20),
-        description,
-        task.status.clone(),
-        task.priority.clone(),
-        tags_str,
-        due_date_str,
-        completed_at_str,
-        format_local_time(&task.created_at),
-        format_local_time(&task.updated_at),
-    ]
+/// タスクの1行分のデータ（セルのベクタ）
+fn create_task_row(task: &TaskDTO, theme: &Theme, view: &TaskListView) -> Vec<Cell> {
+    let completed = task.status == Status::Completed;
+
+    // 標準表示のみ固定長で切り詰め、それ以外は端末幅に応じた切り詰めに任せる
+    let max_len = match view.format {
+        TaskListFormat::Standard => 20,
+        TaskListFormat::Compact | TaskListFormat::Wide => usize::MAX,
+    };
+
+    let cells = view
+        .columns
+        .iter()
+        .map(|column| match column {
+            TaskColumn::Id => Cell::new(task.id),
+            TaskColumn::Title => Cell::new(mark_matches(
+                &truncate_text(&task.title, max_len),
+                theme,
+                view,
+                SearchField::Title,
+            )),
+            TaskColumn::Description => Cell::new(mark_matches(
+                &truncate_text(&format_optional_text(&task.description), max_len),
+                theme,
+                view,
+                SearchField::Description,
+            )),
+            TaskColumn::Status => Cell::new(format_status(task.status)),
+            TaskColumn::Priority => theme.priority_cell(task.priority),
+            TaskColumn::Tags => Cell::new(format_tags(&task.tags, ",")),
+            TaskColumn::DueDate => create_due_date_cell(task, theme, today()),
+            TaskColumn::CompletedAt => Cell::new(format_optional_datetime(&task.completed_at)),
+            TaskColumn::CreatedAt => Cell::new(format_local_time(&task.created_at)),
+            TaskColumn::UpdatedAt => Cell::new(format_local_time(&task.updated_at)),
+            TaskColumn::Stale => create_stale_cell(task, theme, &view.stale_policy, view.now),
+            TaskColumn::Assignee => Cell::new(format_optional_text(&task.assignee)),
+        })
+        .collect();
+
+    theme.dim_row(cells, completed)
+}
+
+/// 検索語に一致する部分を目印で囲む（色付けしない場合や強調表示の対象でない項目はそのまま）
+///
+/// テーブルの列幅は目印を除いた文字で決まり、表示する直前に[`apply_highlights`]で反転表示に置き換えます。
+fn mark_matches(text: &str, theme: &Theme, view: &TaskListView, field: SearchField) -> String {
+    let target = view.highlight_field == SearchField::All || view.highlight_field == field;
+    if !theme.enabled || !target || view.highlight_terms.is_empty() {
+        return text.to_string();
+    }
+
+    let mut marked = String::with_capacity(text.len());
+    let mut last = 0;
+    for range in match_ranges(text, &view.highlight_terms) {
+        marked.push_str(&text[last..range.start]);
+        marked.push(HIGHLIGHT_START);
+        marked.push_str(&text[range.clone()]);
+        marked.push(HIGHLIGHT_END);
+        last = range.end;
+    }
+    marked.push_str(&text[last..]);
+    marked
+}
+
+/// 表示用の文字列にしたテーブルの強調表示の目印を、反転表示のエスケープシーケンスに置き換える
+///
+/// 端末幅に合わせた切り詰めで終了の目印がなくなった場合も、行末で反転表示を解除します。
+/// `styled`がfalseの場合（パイプやファイルへの出力など）は目印を取り除くだけにします。
+pub fn apply_highlights(rendered: &str, styled: bool) -> String {
+    if !rendered.contains(HIGHLIGHT_START) {
+        return rendered.to_string();
+    }
+    if !styled {
+        return rendered
+            .chars()
+            .filter(|c| ![HIGHLIGHT_START, HIGHLIGHT_END].contains(c))
+            .collect();
+    }
+    rendered
+        .lines()
+        .map(|line| {
+            let mut output = String::with_capacity(line.len());
+            let mut open = false;
+            for c in line.chars() {
+                match c {
+                    HIGHLIGHT_START => {
+                        output.push_str(HIGHLIGHT_ON);
+                        open = true;
+                    }
+                    HIGHLIGHT_END => {
+                        output.push_str(HIGHLIGHT_OFF);
+                        open = false;
+                    }
+                    c => output.push(c),
+                }
+            }
+            if open {
+                output.push_str(HIGHLIGHT_OFF);
+            }
+            output
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 期限日のセルを作成
+///
+/// 未完了タスクは相対表現（"in 3 days"など）と、期限切れ・今日期限のバッジを付けて表示します。
+/// 完了済みタスクは期限日のみを表示します。
+fn create_due_date_cell(task: &TaskDTO, theme: &Theme, today: NaiveDate) -> Cell {
+    if task.status == Status::Completed {
+        return Cell::new(format_date(&task.due_date));
+    }
+
+    let status = DueDateStatus::from_due_date(task.due_date, today, theme.due_date_buckets);
+    let text = format_relative_due_date(&task.due_date, today);
+    let text = match due_date_badge(status) {
+        Some(badge) => format!("{} {}", badge, text),
+        None => text,
+    };
+
+    theme.due_date_cell(&text, status)
+}
+
+/// 放置の経過日数のセルを作成
+///
+/// 放置されているタスクは"[STALE] 21 days ago"のように強調表示し、それ以外は"-"を表示します。
+fn create_stale_cell(
+    task: &TaskDTO,
+    theme: &Theme,
+    policy: &StalePolicy,
+    now: DateTime<Utc>,
+) -> Cell {
+    let idle_days = policy.idle_days(task.status, task.updated_at, now);
+    match idle_days {
+        Some(days) => theme.alert_cell(
+            &format!("{} {}", Msg::BadgeStale.text(), format_days_ago(days)),
+            true,
+        ),
+        None => Cell::new("-"),
+    }
+}
+
+/// 期限判定の基準日（表示のタイムゾーンでの今日）を取得
+fn today() -> NaiveDate {
+    SystemClock::new(current_date_format().timezone()).today()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::task_detail_dto::{RelatedTaskDTO, TaskHistoryEntryDTO};
+    use crate::domain::task::value_objects::{DueDateBuckets, Priority};
+    use chrono::TimeZone;
+
+    fn task_dto(status: Status, due_date: Option<NaiveDate>) -> TaskDTO {
+        TaskDTO {
+            id: 1,
+            title: "タスク".to_string(),
+            description: None,
+            status,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date,
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_create_task_table_compact_columns() {
+        // Arrange
+        let tasks = vec![task_dto(Status::Pending, None)];
+
+        // Act
+        let table = create_task_table(&tasks, &Theme::plain(), &TaskListFormat::Compact.into());
+
+        // Assert
+        let header: Vec<String> = table
+            .header()
+            .unwrap()
+            .cell_iter()
+            .map(|c| c.content())
+            .collect();
+        assert_eq!(header, vec!["ID", "Title", "Status", "Due Date"]);
+        assert_eq!(header.len(), 4);
+    }
+
+    #[test]
+    fn test_create_task_table_custom_columns() {
+        // Arrange
+        let tasks = vec![task_dto(Status::Pending, None)];
+        let view = TaskListView::new(
+            TaskListFormat::Standard,
+            vec![TaskColumn::Id, TaskColumn::Priority, TaskColumn::Title],
+        );
+
+        // Act
+        let table = create_task_table(&tasks, &Theme::plain(), &view);
+
+        // Assert
+        let header: Vec<String> = table
+            .header()
+            .unwrap()
+            .cell_iter()
+            .map(|c| c.content())
+            .collect();
+        assert_eq!(header, vec!["ID", "Priority", "Title"]);
+    }
+
+    #[test]
+    fn test_task_list_view_without_columns_uses_format_defaults() {
+        let view = TaskListView::new(TaskListFormat::Compact, vec![]);
+
+        assert_eq!(view.columns, COMPACT_COLUMNS.to_vec());
+        assert_eq!(TaskListView::from(TaskListFormat::Wide).columns.len(), 10);
+    }
+
+    #[test]
+    fn test_task_column_from_str() {
+        assert_eq!("due".parse::<TaskColumn>().unwrap(), TaskColumn::DueDate);
+        assert_eq!(" Title ".parse::<TaskColumn>().unwrap(), TaskColumn::Title);
+        assert_eq!(
+            "updated_at".parse::<TaskColumn>().unwrap(),
+            TaskColumn::UpdatedAt
+        );
+        assert!("color".parse::<TaskColumn>().is_err());
+    }
+
+    #[test]
+    fn test_create_task_table_wide_does_not_truncate_fixed_length() {
+        // Arrange
+        let mut task = task_dto(Status::Pending, None);
+        task.title = "とても長いタイトルのタスクで二十文字を超えています".to_string();
+        let tasks = vec![task.clone()];
+
+        // Act
+        let mut table = create_task_table(&tasks, &Theme::plain(), &TaskListFormat::Wide.into());
+        table.force_no_tty();
+        let output = table.to_string();
+
+        // Assert
+        assert_eq!(table.header().unwrap().cell_count(), 10);
+        assert!(output.contains(&task.title));
+    }
+
+    #[test]
+    fn test_create_task_table_standard_truncates_title() {
+        let mut task = task_dto(Status::Pending, None);
+        task.title = "a".repeat(30);
+
+        let mut table =
+            create_task_table(&[task], &Theme::plain(), &TaskListFormat::Standard.into());
+        table.force_no_tty();
+        let output = table.to_string();
+
+        assert!(output.contains(&format!("{}...", "a".repeat(20))));
+        assert!(!output.contains(&"a".repeat(21)));
+    }
+
+    #[test]
+    fn test_create_task_table_wide_truncates_to_width() {
+        // Arrange
+        let mut task = task_dto(Status::Pending, None);
+        task.description = Some("word ".repeat(50));
+
+        // Act
+        let mut table = create_task_table(&[task], &Theme::plain(), &TaskListFormat::Wide.into());
+        table.force_no_tty().set_width(120);
+        let output = table.to_string();
+
+        // Assert: 各行が指定幅に収まり、1行に切り詰められている
+        assert!(output.lines().all(|line| line.chars().count() <= 120));
+        assert!(output.contains('…'));
+    }
+
+    #[test]
+    fn test_create_task_table_highlights_matches() {
+        // Arrange
+        let mut task = task_dto(Status::Pending, None);
+        task.title = "Fix login bug".to_string();
+        task.description = Some("login page".to_string());
+        let tasks = vec![task];
+        let view = TaskListView::new(
+            TaskListFormat::Standard,
+            vec![TaskColumn::Id, TaskColumn::Title, TaskColumn::Description],
+        )
+        .with_highlight(vec!["login".to_string()], SearchField::Title);
+
+        // Act
+        let mut table = create_task_table(&tasks, &Theme::default(), &view);
+        table.force_no_tty();
+        let rendered = table.to_string();
+        let output = apply_highlights(&rendered, true);
+        let mut plain = create_task_table(&tasks, &Theme::plain(), &view);
+        plain.force_no_tty();
+
+        // Assert: 目印は幅0のため列幅は変わらず、タイトルだけが反転表示になる
+        let unmarked: String = rendered
+            .chars()
+            .filter(|c| ![HIGHLIGHT_START, HIGHLIGHT_END].contains(c))
+            .collect();
+        assert_eq!(unmarked, plain.to_string());
+        assert!(output.contains("Fix \x1b[7mlogin\x1b[27m bug"));
+        assert!(output.contains("login page"));
+        assert!(!output.contains(HIGHLIGHT_START));
+    }
+
+    #[test]
+    fn test_create_task_table_plain_theme_has_no_highlight() {
+        // Arrange
+        let mut task = task_dto(Status::Pending, None);
+        task.title = "Fix login bug".to_string();
+        let view = TaskListView::from(TaskListFormat::Standard)
+            .with_highlight(vec!["login".to_string()], SearchField::All);
+
+        // Act
+        let mut table = create_task_table(&[task], &Theme::plain(), &view);
+        table.force_no_tty();
+        let output = table.to_string();
+
+        // Assert
+        assert!(output.contains("Fix login bug"));
+        assert_eq!(apply_highlights(&output, true), output);
+    }
+
+    #[test]
+    fn test_apply_highlights_closes_cut_highlight() {
+        let rendered = "| a\u{200B}bc |\n| d\u{200B}e\u{2060}f |";
+
+        assert_eq!(
+            apply_highlights(rendered, true),
+            "| a\x1b[7mbc |\x1b[27m\n| d\x1b[7me\x1b[27mf |"
+        );
+    }
+
+    #[test]
+    fn test_apply_highlights_without_styling_removes_marks() {
+        let rendered = "| a\u{200B}bc |\n| d\u{200B}e\u{2060}f |";
+
+        assert_eq!(apply_highlights(rendered, false), "| abc |\n| def |");
+    }
+
+    #[test]
+    fn test_due_date_cell_overdue_has_badge() {
+        // Arrange
+        let task = task_dto(Status::Pending, Some(date(2025, 1, 8)));
+
+        // Act
+        let cell = create_due_date_cell(&task, &Theme::plain(), date(2025, 1, 10));
+
+        // Assert
+        assert_eq!(cell.content(), "[OVERDUE] 2025-01-08 (2 days overdue)");
+    }
+
+    #[test]
+    fn test_due_date_cell_due_today_has_badge() {
+        let task = task_dto(Status::InProgress, Some(date(2025, 1, 10)));
+
+        let cell = create_due_date_cell(&task, &Theme::plain(), date(2025, 1, 10));
+
+        assert_eq!(cell.content(), "[TODAY] 2025-01-10 (today)");
+    }
+
+    #[test]
+    fn test_due_date_cell_future_without_badge() {
+        let task = task_dto(Status::Pending, Some(date(2025, 1, 13)));
+
+        let cell = create_due_date_cell(&task, &Theme::plain(), date(2025, 1, 10));
+
+        assert_eq!(cell.content(), "2025-01-13 (in 3 days)");
+    }
+
+    #[test]
+    fn test_due_date_cell_colored_by_configured_buckets() {
+        // Arrange
+        let task = task_dto(Status::Pending, Some(date(2025, 1, 13)));
+        let theme = Theme {
+            due_date_buckets: DueDateBuckets::new(2, 30).unwrap(),
+            ..Theme::default()
+        };
+
+        // Act
+        let outside = create_due_date_cell(&task, &theme, date(2025, 1, 10));
+        let inside = create_due_date_cell(&task, &theme, date(2025, 1, 11));
+
+        // Assert
+        assert_eq!(outside, Cell::new("2025-01-13 (in 3 days)"));
+        assert_eq!(
+            inside,
+            Cell::new("2025-01-13 (in 2 days)").fg(theme.due_soon)
+        );
+    }
+
+    #[test]
+    fn test_due_date_cell_completed_shows_date_only() {
+        let task = task_dto(Status::Completed, Some(date(2025, 1, 8)));
+
+        let cell = create_due_date_cell(&task, &Theme::plain(), date(2025, 1, 10));
+
+        assert_eq!(cell.content(), "2025-01-08");
+    }
+
+    #[test]
+    fn test_due_date_cell_no_due_date() {
+        let task = task_dto(Status::Pending, None);
+
+        let cell = create_due_date_cell(&task, &Theme::plain(), date(2025, 1, 10));
+
+        assert_eq!(cell.content(), "-");
+    }
+
+    #[test]
+    fn test_stale_cell_by_status_threshold() {
+        // Arrange
+        let policy = StalePolicy::disabled().with_threshold(Status::InProgress, 7);
+        let now = Utc.with_ymd_and_hms(2025, 1, 10, 12, 0, 0).unwrap();
+        let mut stale = task_dto(Status::InProgress, None);
+        stale.updated_at = now - chrono::Duration::days(9);
+        let mut pending = task_dto(Status::Pending, None);
+        pending.updated_at = now - chrono::Duration::days(90);
+
+        // Act
+        let stale_cell = create_stale_cell(&stale, &Theme::plain(), &policy, now);
+        let pending_cell = create_stale_cell(&pending, &Theme::plain(), &policy, now);
+
+        // Assert
+        assert_eq!(stale_cell.content(), "[STALE] 9 days ago");
+        assert_eq!(pending_cell.content(), "-");
+        assert!(
+            !TaskListView::from(TaskListFormat::Standard)
+                .columns
+                .contains(&TaskColumn::Stale)
+        );
+    }
+
+    #[test]
+    fn test_create_task_related_table() {
+        // Arrange
+        let task = task_dto(Status::Pending, Some(date(2025, 1, 13)));
+        let detail = TaskDetailDTO {
+            history: vec![TaskHistoryEntryDTO {
+                kind: TaskHistoryKind::Created,
+                occurred_at: task.created_at,
+            }],
+            task,
+            days_until_due: Some(3),
+            age_days: 1,
+            days_since_update: 0,
+            related_tasks: vec![RelatedTaskDTO {
+                id: 7,
+                title: "関連タスク".to_string(),
+                status: Status::InProgress,
+                shared_tags: vec!["仕事".to_string()],
+            }],
+        };
+
+        // Act
+        let output = create_task_related_table(&detail).to_string();
+
+        // Assert
+        assert!(output.contains("in 3 days"));
+        assert!(output.contains("1 day"));
+        assert!(output.contains("today"));
+        assert!(output.contains("[7] 関連タスク (in_progress) 仕事"));
+        assert!(output.contains("Created"));
+    }
 }
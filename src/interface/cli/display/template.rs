@@ -0,0 +1,257 @@
+use crate::{
+    application::dto::task_dto::TaskDTO,
+    domain::task::value_objects::Estimate,
+    interface::{
+        cli::display::{
+            date_format::current_date_format, format::format_checklist_progress,
+            task_table::TaskColumn,
+        },
+        i18n::Msg,
+    },
+};
+use anyhow::Result;
+use std::str::FromStr;
+
+/// テンプレートに埋め込む項目
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplateField {
+    /// 一覧の列と同じ項目（`id`、`title`、`due_date`など）
+    Column(TaskColumn),
+    /// チェックリストの進捗（"完了数/項目数"）
+    Checklist,
+    /// 繰り返しルール
+    Repeat,
+    /// 見積もり時間（`2h30m`など）
+    Estimate,
+    /// コンテキスト（`@home`など）
+    Context,
+    /// カスタムフィールドの値（`field.KEY`）
+    CustomField(String),
+}
+
+impl FromStr for TemplateField {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let name = s.trim();
+        if let Some(key) = name.strip_prefix("field.") {
+            return Ok(Self::CustomField(key.to_ascii_lowercase()));
+        }
+        match name.to_lowercase().as_str() {
+            // 放置の判定には設定のしきい値が必要なため、テンプレートでは扱わない
+            "stale" => anyhow::bail!(Msg::ErrorTemplateStale.text()),
+            "checklist" => Ok(Self::Checklist),
+            "repeat" | "recurrence" => Ok(Self::Repeat),
+            "estimate" => Ok(Self::Estimate),
+            "context" => Ok(Self::Context),
+            _ => name
+                .parse()
+                .map(Self::Column)
+                .map_err(|_| anyhow::anyhow!(Msg::ErrorUnknownTemplateField.format(&[&name]))),
+        }
+    }
+}
+
+/// テンプレートの構成要素
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Field(TemplateField),
+}
+
+/// タスクを1行ずつ出力するためのテンプレート
+///
+/// `{{id}} {{title}} ({{due_date}})`のように`{{項目名}}`を値に置き換えます。
+/// 値のない項目は空文字列になり、日付は`[display]`の表示形式に従います。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskTemplate {
+    segments: Vec<Segment>,
+}
+
+impl FromStr for TaskTemplate {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut segments = Vec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Literal(rest[..start].to_string()));
+            }
+            let after = &rest[start + 2..];
+            let end = after
+                .find("}}")
+                .ok_or_else(|| anyhow::anyhow!(Msg::ErrorUnclosedTemplate.format(&[&s])))?;
+            segments.push(Segment::Field(after[..end].parse()?));
+            rest = &after[end + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(rest.to_string()));
+        }
+        Ok(Self { segments })
+    }
+}
+
+impl TaskTemplate {
+    /// タスクの値を埋め込んだ文字列を作成
+    pub fn render(&self, task: &TaskDTO) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.clone(),
+                Segment::Field(field) => render_field(field, task),
+            })
+            .collect()
+    }
+}
+
+/// 項目の値を文字列に変換（値がない場合は空文字列）
+fn render_field(field: &TemplateField, task: &TaskDTO) -> String {
+    let date_format = current_date_format();
+    match field {
+        TemplateField::Column(column) => match column {
+            TaskColumn::Id => task.id.to_string(),
+            TaskColumn::Title => task.title.clone(),
+            TaskColumn::Description => task.description.clone().unwrap_or_default(),
+            TaskColumn::Status => task.status.to_string(),
+            TaskColumn::Priority => task.priority.to_string(),
+            TaskColumn::Tags => task
+                .tags
+                .iter()
+                .map(|tag| tag.name.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+            TaskColumn::DueDate => task
+                .due_date
+                .map(|date| date_format.format_date(date))
+                .unwrap_or_default(),
+            TaskColumn::CompletedAt => task
+                .completed_at
+                .map(|at| date_format.format_datetime(&at))
+                .unwrap_or_default(),
+            TaskColumn::CreatedAt => date_format.format_datetime(&task.created_at),
+            TaskColumn::UpdatedAt => date_format.format_datetime(&task.updated_at),
+            TaskColumn::Assignee => task.assignee.clone().unwrap_or_default(),
+            // 放置の判定にはしきい値が必要なため、テンプレートでは値を持たない（解析時にも拒否する）
+            TaskColumn::Stale => String::new(),
+        },
+        TemplateField::Checklist if task.checklist.is_empty() => String::new(),
+        TemplateField::Checklist => format_checklist_progress(&task.checklist),
+        TemplateField::Repeat => task.recurrence.clone().unwrap_or_default(),
+        TemplateField::Estimate => task
+            .estimate_minutes
+            .map(Estimate::format_minutes)
+            .unwrap_or_default(),
+        TemplateField::Context => task.context.clone().unwrap_or_default(),
+        TemplateField::CustomField(key) => task.custom_fields.get(key).cloned().unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{TagInfo, task_dto::ChecklistItemDTO};
+    use crate::domain::task::value_objects::{Priority, Status};
+    use chrono::{NaiveDate, Utc};
+
+    fn sample_task() -> TaskDTO {
+        TaskDTO {
+            id: 7,
+            title: "請求書を送る".to_string(),
+            description: None,
+            status: Status::Pending,
+            priority: Priority::High,
+            tags: vec![
+                TagInfo {
+                    id: 1,
+                    name: "仕事".to_string(),
+                },
+                TagInfo {
+                    id: 2,
+                    name: "経理".to_string(),
+                },
+            ],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date: NaiveDate::from_ymd_opt(2025, 3, 31),
+            completed_at: None,
+            custom_fields: [("client".to_string(), "ACME".to_string())].into(),
+            checklist: vec![
+                ChecklistItemDTO {
+                    text: "作成".to_string(),
+                    done: true,
+                },
+                ChecklistItemDTO {
+                    text: "送付".to_string(),
+                    done: false,
+                },
+            ],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: Some(90),
+            context: Some("@office".to_string()),
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_render_replaces_fields() {
+        // Arrange
+        let template: TaskTemplate =
+            "{{id}} {{ title }} ({{due_date}}) [{{tags}}] {{checklist}} {{field.Client}} {{estimate}} {{context}}"
+                .parse()
+                .unwrap();
+
+        // Act
+        let line = template.render(&sample_task());
+
+        // Assert
+        assert_eq!(
+            line,
+            "7 請求書を送る (2025-03-31) [仕事,経理] 1/2 ACME 1h30m @office"
+        );
+    }
+
+    #[test]
+    fn test_render_missing_values_as_empty() {
+        // Arrange
+        let template: TaskTemplate = "{{title}}|{{description}}|{{completed_at}}|{{repeat}}"
+            .parse()
+            .unwrap();
+
+        // Act
+        let line = template.render(&sample_task());
+
+        // Assert
+        assert_eq!(line, "請求書を送る|||");
+    }
+
+    #[test]
+    fn test_render_stale_column_as_empty() {
+        // Arrange
+        let template = TaskTemplate {
+            segments: vec![
+                Segment::Field(TemplateField::Column(TaskColumn::Id)),
+                Segment::Literal("|".to_string()),
+                Segment::Field(TemplateField::Column(TaskColumn::Stale)),
+            ],
+        };
+
+        // Act
+        let line = template.render(&sample_task());
+
+        // Assert
+        assert_eq!(line, "7|");
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_or_unclosed_fields() {
+        let unknown = "{{id}} {{owner}}".parse::<TaskTemplate>().unwrap_err();
+        assert!(unknown.to_string().contains("{{owner}}"));
+        assert!("{{id} {{title}}".parse::<TaskTemplate>().is_err());
+        assert!("{{id}} {{stale}}".parse::<TaskTemplate>().is_err());
+        assert!("plain text".parse::<TaskTemplate>().is_ok());
+    }
+}
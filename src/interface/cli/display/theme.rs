@@ -0,0 +1,276 @@
+use crate::{
+    domain::task::value_objects::{DueDateBuckets, DueDateStatus, Priority},
+    interface::{cli::display::format::format_priority, i18n::Msg},
+};
+use anyhow::Result;
+use comfy_table::{Attribute, Cell, Color};
+
+/// テーブル表示の配色テーマ
+///
+/// 優先度の色分け、期限切れ・期限が近いタスクの強調、完了済みタスクの減光を制御します。
+/// `enabled`がfalseの場合は一切の装飾を行いません。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub enabled: bool,
+    pub critical: Color,
+    pub high: Color,
+    pub medium: Color,
+    pub low: Color,
+    pub overdue: Color,
+    pub due_today: Color,
+    pub due_soon: Color,
+    /// 期限が近いとみなす日数などの期限日の分類の境界
+    pub due_date_buckets: DueDateBuckets,
+    pub dim_completed: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            critical: Color::Red,
+            high: Color::AnsiValue(208), // オレンジ
+            medium: Color::Yellow,
+            low: Color::Green,
+            overdue: Color::Red,
+            due_today: Color::Yellow,
+            due_soon: Color::Cyan,
+            due_date_buckets: DueDateBuckets::default(),
+            dim_completed: true,
+        }
+    }
+}
+
+impl Theme {
+    /// 装飾なしのテーマを作成
+    pub fn plain() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// 優先度に対応する色を取得
+    fn priority_color(&self, priority: Priority) -> Color {
+        match priority {
+            Priority::Critical => self.critical,
+            Priority::High => self.high,
+            Priority::Medium => self.medium,
+            Priority::Low => self.low,
+        }
+    }
+
+    /// 優先度のセルを作成
+    pub fn priority_cell(&self, priority: Priority) -> Cell {
+        self.priority_label_cell(priority, format_priority(priority))
+    }
+
+    /// 優先度に対応する色で任意のラベルのセルを作成（統計表示の行見出しなど）
+    pub fn priority_label_cell(&self, priority: Priority, label: &str) -> Cell {
+        let cell = Cell::new(label);
+        if self.enabled {
+            cell.fg(self.priority_color(priority))
+        } else {
+            cell
+        }
+    }
+
+    /// 期限日のセルを作成（期限切れ・今日期限・期限が近い場合は強調表示）
+    pub fn due_date_cell(&self, text: &str, status: DueDateStatus) -> Cell {
+        let cell = Cell::new(text);
+        if !self.enabled {
+            return cell;
+        }
+
+        match status {
+            DueDateStatus::Overdue => cell.fg(self.overdue).add_attribute(Attribute::Bold),
+            DueDateStatus::DueToday => cell.fg(self.due_today),
+            DueDateStatus::DueSoon => cell.fg(self.due_soon),
+            _ => cell,
+        }
+    }
+
+    /// 注意を促すセルを作成（`alert`がtrueの場合は期限切れと同じ色で強調表示）
+    pub fn alert_cell(&self, text: &str, alert: bool) -> Cell {
+        let cell = Cell::new(text);
+        if self.enabled && alert {
+            cell.fg(self.overdue).add_attribute(Attribute::Bold)
+        } else {
+            cell
+        }
+    }
+
+    /// 完了済みタスクの行のセルを減光
+    pub fn dim_row(&self, cells: Vec<Cell>, completed: bool) -> Vec<Cell> {
+        if self.enabled && self.dim_completed && completed {
+            cells
+                .into_iter()
+                .map(|cell| cell.add_attribute(Attribute::Dim))
+                .collect()
+        } else {
+            cells
+        }
+    }
+}
+
+/// NO_COLOR環境変数により色付けが無効化されているか判定
+///
+/// <https://no-color.org/> の規約に従い、空でない値が設定されている場合に無効とみなします。
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// 色名をcomfy_tableの色に変換
+///
+/// # 引数
+/// - `name`: 色名（`red`, `dark_yellow`, `orange`など）または`#RRGGBB`形式
+///
+/// # 戻り値
+/// - `Ok(Color)`: 変換に成功した場合
+/// - `Err`: 未知の色名の場合
+pub fn parse_color(name: &str) -> Result<Color> {
+    let normalized = name.trim().to_lowercase().replace(['-', ' '], "_");
+
+    if let Some(hex) = normalized.strip_prefix('#') {
+        if hex.len() == 6
+            && let Ok(rgb) = u32::from_str_radix(hex, 16)
+        {
+            return Ok(Color::Rgb {
+                r: (rgb >> 16) as u8,
+                g: (rgb >> 8) as u8,
+                b: rgb as u8,
+            });
+        }
+        anyhow::bail!(Msg::ErrorInvalidColor.format(&[&name]));
+    }
+
+    let color = match normalized.as_str() {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "dark_red" => Color::DarkRed,
+        "green" => Color::Green,
+        "dark_green" => Color::DarkGreen,
+        "yellow" => Color::Yellow,
+        "dark_yellow" => Color::DarkYellow,
+        "orange" => Color::AnsiValue(208),
+        "blue" => Color::Blue,
+        "dark_blue" => Color::DarkBlue,
+        "magenta" => Color::Magenta,
+        "dark_magenta" => Color::DarkMagenta,
+        "cyan" => Color::Cyan,
+        "dark_cyan" => Color::DarkCyan,
+        "white" => Color::White,
+        "grey" | "gray" => Color::Grey,
+        "dark_grey" | "dark_gray" => Color::DarkGrey,
+        _ => anyhow::bail!(Msg::ErrorInvalidColor.format(&[&name])),
+    };
+
+    Ok(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_names() {
+        assert_eq!(parse_color("red").unwrap(), Color::Red);
+        assert_eq!(parse_color("Dark-Yellow").unwrap(), Color::DarkYellow);
+        assert_eq!(parse_color("orange").unwrap(), Color::AnsiValue(208));
+        assert_eq!(parse_color("gray").unwrap(), Color::Grey);
+    }
+
+    #[test]
+    fn test_parse_color_hex() {
+        assert_eq!(
+            parse_color("#FF8000").unwrap(),
+            Color::Rgb {
+                r: 255,
+                g: 128,
+                b: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_color_invalid() {
+        assert!(parse_color("rainbow").is_err());
+        assert!(parse_color("#12345").is_err());
+        assert!(parse_color("#GGGGGG").is_err());
+    }
+
+    #[test]
+    fn test_priority_cell_colored() {
+        let theme = Theme::default();
+        let cell = theme.priority_cell(Priority::Critical);
+        assert_eq!(cell.content(), "critical");
+        assert_eq!(cell, Cell::new("critical").fg(Color::Red));
+    }
+
+    #[test]
+    fn test_priority_label_cell_uses_priority_color() {
+        let theme = Theme::default();
+        assert_eq!(
+            theme.priority_label_cell(Priority::Low, "Low"),
+            Cell::new("Low").fg(Color::Green)
+        );
+        assert_eq!(
+            Theme::plain().priority_label_cell(Priority::Low, "Low"),
+            Cell::new("Low")
+        );
+    }
+
+    #[test]
+    fn test_plain_theme_has_no_style() {
+        let theme = Theme::plain();
+        assert_eq!(theme.priority_cell(Priority::High), Cell::new("high"));
+        assert_eq!(
+            theme.due_date_cell("2026-01-01", DueDateStatus::Overdue),
+            Cell::new("2026-01-01")
+        );
+        assert_eq!(
+            theme.dim_row(vec![Cell::new("x")], true),
+            vec![Cell::new("x")]
+        );
+    }
+
+    #[test]
+    fn test_due_date_cell_overdue_highlighted() {
+        let theme = Theme::default();
+        assert_eq!(
+            theme.due_date_cell("2026-01-01", DueDateStatus::Overdue),
+            Cell::new("2026-01-01")
+                .fg(Color::Red)
+                .add_attribute(Attribute::Bold)
+        );
+        assert_eq!(
+            theme.due_date_cell("2026-01-01", DueDateStatus::DueToday),
+            Cell::new("2026-01-01").fg(Color::Yellow)
+        );
+        assert_eq!(
+            theme.due_date_cell("2026-01-01", DueDateStatus::DueSoon),
+            Cell::new("2026-01-01").fg(Color::Cyan)
+        );
+        assert_eq!(
+            theme.due_date_cell("2026-01-01", DueDateStatus::DueUpcoming),
+            Cell::new("2026-01-01")
+        );
+        assert_eq!(
+            theme.due_date_cell("2026-01-01", DueDateStatus::DueLater),
+            Cell::new("2026-01-01")
+        );
+    }
+
+    #[test]
+    fn test_dim_row_only_completed() {
+        let theme = Theme::default();
+        assert_eq!(
+            theme.dim_row(vec![Cell::new("x")], true),
+            vec![Cell::new("x").add_attribute(Attribute::Dim)]
+        );
+        assert_eq!(
+            theme.dim_row(vec![Cell::new("x")], false),
+            vec![Cell::new("x")]
+        );
+    }
+}
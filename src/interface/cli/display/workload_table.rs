@@ -0,0 +1,112 @@
+use crate::{
+    application::dto::{WorkloadDTO, WorkloadDayDTO},
+    domain::task::value_objects::Estimate,
+    interface::{
+        cli::display::{format::format_date, theme::Theme},
+        i18n::Msg,
+    },
+};
+use chrono::Datelike;
+use comfy_table::{Cell, CellAlignment, Table, presets::UTF8_FULL};
+
+/// 負荷のバーの長さ（1日の作業可能時間に相当）
+const LOAD_BAR_WIDTH: u32 = 10;
+
+/// 日ごとの作業量のテーブルを作成
+///
+/// 見積もりの合計が1日の作業可能時間を超える日は、負荷の列を強調表示します。
+pub fn create_workload_table(workload: &WorkloadDTO, theme: &Theme) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL);
+    table.set_header(vec![
+        Msg::WorkloadDate.text(),
+        Msg::WorkloadTasks.text(),
+        Msg::FieldEstimate.text(),
+        Msg::WorkloadLoad.text(),
+    ]);
+
+    for day in &workload.days {
+        table.add_row(vec![
+            Cell::new(format_day(day)),
+            Cell::new(day.task_count).set_alignment(CellAlignment::Right),
+            Cell::new(format_estimate(day)),
+            theme.alert_cell(
+                &format_load(day, workload.daily_capacity_minutes),
+                day.overcommitted,
+            ),
+        ]);
+    }
+
+    table
+}
+
+/// 日付を曜日付きで表示（例: "2026-10-19 月"）
+fn format_day(day: &WorkloadDayDTO) -> String {
+    let weekday = Msg::TuiCalendarWeekdays
+        .text()
+        .split_whitespace()
+        .nth(day.date.weekday().num_days_from_monday() as usize)
+        .unwrap_or_default();
+    format!("{} {}", format_date(&Some(day.date)), weekday)
+}
+
+/// 見積もりの合計（見積もりのないタスクがある場合はその件数も表示）
+fn format_estimate(day: &WorkloadDayDTO) -> String {
+    if day.task_count == 0 {
+        return "-".to_string();
+    }
+    let total = Estimate::format_minutes(day.estimated_minutes);
+    match day.unestimated_count {
+        0 => total,
+        count => format!("{} {}", total, Msg::WorkloadUnestimated.format(&[&count])),
+    }
+}
+
+/// 作業可能時間に対する負荷のバーと割合（超過した場合は超過時間も表示）
+fn format_load(day: &WorkloadDayDTO, capacity_minutes: u32) -> String {
+    let capacity = capacity_minutes.max(1);
+    let filled = (day.estimated_minutes * LOAD_BAR_WIDTH)
+        .div_ceil(capacity)
+        .min(LOAD_BAR_WIDTH);
+    let bar = format!(
+        "{}{}",
+        "█".repeat(filled as usize),
+        "░".repeat((LOAD_BAR_WIDTH - filled) as usize)
+    );
+    let percentage = day.estimated_minutes * 100 / capacity;
+    if day.overcommitted {
+        let over = Estimate::format_minutes(day.estimated_minutes - capacity_minutes);
+        format!(
+            "{} {}% {}",
+            bar,
+            percentage,
+            Msg::WorkloadOver.format(&[&over])
+        )
+    } else {
+        format!("{} {}%", bar, percentage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn day(estimated_minutes: u32, overcommitted: bool) -> WorkloadDayDTO {
+        WorkloadDayDTO {
+            date: NaiveDate::from_ymd_opt(2026, 10, 19).unwrap(),
+            task_count: 2,
+            estimated_minutes,
+            unestimated_count: 0,
+            overcommitted,
+        }
+    }
+
+    #[test]
+    fn test_format_load() {
+        assert_eq!(format_load(&day(0, false), 480), "░░░░░░░░░░ 0%");
+        assert_eq!(format_load(&day(240, false), 480), "█████░░░░░ 50%");
+        assert!(format_load(&day(600, true), 480).starts_with("██████████ 125% "));
+        assert!(format_load(&day(600, true), 480).contains("2h"));
+    }
+}
@@ -0,0 +1,416 @@
+//! 環境の診断（`yaru doctor`）
+//!
+//! 設定・データベース・端末・フックを確認し、問題があれば対処方法とともに表示します。
+//! 設定の読み込みとデータベースへの接続はエントリーポイントで行い、その結果をここで診断結果に変換します。
+
+use crate::interface::{
+    git::{GitRepository, HookState, inspect_hook},
+    i18n::Msg,
+    persistence::sea_orm::DatabaseStatus,
+    presentation::Presenter,
+};
+use anyhow::Result;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// `yaru git hook install`が導入するフック
+const GIT_HOOKS: [&str; 2] = ["prepare-commit-msg", "post-merge"];
+
+/// 診断結果の重要度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// 問題なし
+    Ok,
+    /// 参考情報（問題ではない）
+    Info,
+    /// 動作はするが対処を推奨する
+    Warning,
+    /// 対処が必要
+    Error,
+}
+
+impl CheckStatus {
+    /// 表示用のラベル
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Info => "info",
+            Self::Warning => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// 1項目の診断結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorCheck {
+    /// 確認した項目
+    pub name: String,
+    pub status: CheckStatus,
+    /// 確認結果の詳細
+    pub detail: String,
+    /// 対処方法（警告・エラーの場合）
+    pub fix: Option<String>,
+}
+
+impl DoctorCheck {
+    pub fn ok(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Ok, detail, None)
+    }
+
+    pub fn info(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Info, detail, None)
+    }
+
+    pub fn warning(
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self::new(name, CheckStatus::Warning, detail, Some(fix.into()))
+    }
+
+    pub fn error(
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        fix: impl Into<String>,
+    ) -> Self {
+        Self::new(name, CheckStatus::Error, detail, Some(fix.into()))
+    }
+
+    fn new(
+        name: impl Into<String>,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        fix: Option<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            detail: detail.into(),
+            fix,
+        }
+    }
+}
+
+/// データベースの状態からスキーマと整合性を診断する
+pub fn check_database_status(status: &DatabaseStatus) -> Vec<DoctorCheck> {
+    let schema_name = Msg::DoctorCheckSchema.text();
+    let schema = if status.pending_migrations.is_empty() {
+        DoctorCheck::ok(
+            schema_name,
+            Msg::DoctorSchemaUpToDate.format(&[&status.applied_migrations.len()]),
+        )
+    } else {
+        DoctorCheck::warning(
+            schema_name,
+            Msg::DoctorSchemaPending.format(&[
+                &status.pending_migrations.len(),
+                &status.pending_migrations.join(", "),
+            ]),
+            Msg::DoctorFixMigrate.text(),
+        )
+    };
+
+    let integrity_name = Msg::DoctorCheckIntegrity.text();
+    let integrity = if status.is_healthy() {
+        DoctorCheck::ok(integrity_name, "ok")
+    } else {
+        DoctorCheck::error(
+            integrity_name,
+            status.integrity_check.join("; "),
+            Msg::DoctorFixIntegrity.text(),
+        )
+    };
+
+    vec![schema, integrity]
+}
+
+/// 色付け表示が有効になるかを診断する
+///
+/// # Arguments
+/// * `no_color` - `--no-color`またはNO_COLOR環境変数が指定されているか
+/// * `theme_enabled` - 設定の`[display.theme] enabled`
+/// * `is_terminal` - 標準出力が端末か
+pub fn check_color(no_color: bool, theme_enabled: bool, is_terminal: bool) -> DoctorCheck {
+    let detail = if no_color {
+        Msg::DoctorColorNoColor
+    } else if !theme_enabled {
+        Msg::DoctorColorTheme
+    } else if !is_terminal {
+        Msg::DoctorColorNotTerminal
+    } else {
+        return DoctorCheck::ok(Msg::DoctorCheckColor.text(), Msg::DoctorColorEnabled.text());
+    };
+    DoctorCheck::info(Msg::DoctorCheckColor.text(), detail.text())
+}
+
+/// 文字の種類を決めるロケール（LC_ALL・LC_CTYPE・LANGの順に最初に設定されているもの）
+pub fn ctype_locale() -> Option<String> {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+}
+
+/// ロケールがUTF-8かを診断する
+///
+/// 罫線・スピナー・日本語の表示幅はUTF-8のロケールを前提にしています。
+pub fn check_unicode(locale: Option<&str>) -> DoctorCheck {
+    let name = Msg::DoctorCheckUnicode.text();
+    match locale {
+        Some(locale) if is_utf8_locale(locale) => {
+            DoctorCheck::ok(name, Msg::DoctorUnicodeOk.format(&[&locale]))
+        }
+        _ => DoctorCheck::warning(
+            name,
+            Msg::DoctorUnicodeMissing.format(&[&locale.unwrap_or(Msg::DoctorLocaleUnset.text())]),
+            Msg::DoctorFixUnicode.text(),
+        ),
+    }
+}
+
+/// ロケール名がUTF-8を指定しているか（`en_US.UTF-8`、`ja_JP.utf8`など）
+fn is_utf8_locale(locale: &str) -> bool {
+    let locale = locale.to_ascii_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
+}
+
+/// 指定したディレクトリを含むgitリポジトリのフックを診断する
+///
+/// gitリポジトリの外の場合と、フックを導入していない場合は何も返しません。
+pub fn check_git_hooks(dir: &Path) -> Vec<DoctorCheck> {
+    let Ok(hooks_dir) = GitRepository::discover(dir).and_then(|repo| repo.hooks_dir()) else {
+        return Vec::new();
+    };
+
+    GIT_HOOKS
+        .iter()
+        .filter_map(|hook| {
+            let name = Msg::DoctorCheckGitHook.format(&[hook]);
+            let path = hooks_dir.join(hook);
+            match inspect_hook(&hooks_dir, hook) {
+                Ok(HookState::Missing) => None,
+                Ok(HookState::Installed) => Some(DoctorCheck::ok(
+                    name,
+                    Msg::DoctorHookInstalled.format(&[&path.display()]),
+                )),
+                Ok(HookState::NotExecutable) => Some(DoctorCheck::error(
+                    name,
+                    Msg::DoctorHookNotExecutable.format(&[&path.display()]),
+                    Msg::DoctorFixChmod.format(&[&path.display()]),
+                )),
+                Err(e) => Some(DoctorCheck::error(
+                    name,
+                    format!("{:#}", e),
+                    Msg::DoctorFixChmod.format(&[&path.display()]),
+                )),
+            }
+        })
+        .collect()
+}
+
+/// 期限切れの通知に使うコマンドを実行できるかを診断する
+///
+/// コマンドが設定されていない場合は何も返しません。
+pub fn check_notify_command(command: &[String]) -> Option<DoctorCheck> {
+    let program = command.first()?;
+    let name = Msg::DoctorCheckNotifyCommand.text();
+    Some(match find_executable(program) {
+        Some(path) if is_executable(&path) => DoctorCheck::ok(name, path.display().to_string()),
+        Some(path) => DoctorCheck::error(
+            name,
+            Msg::DoctorNotExecutable.format(&[&path.display()]),
+            Msg::DoctorFixChmod.format(&[&path.display()]),
+        ),
+        None => DoctorCheck::error(
+            name,
+            Msg::DoctorNotifyNotFound.format(&[program]),
+            Msg::DoctorFixNotify.text(),
+        ),
+    })
+}
+
+/// コマンド名を実行ファイルのパスに解決する（`/`を含む場合はそのままのパス、それ以外はPATHから探す）
+fn find_executable(program: &str) -> Option<PathBuf> {
+    if program.contains(std::path::MAIN_SEPARATOR) || program.contains('/') {
+        let path = PathBuf::from(program);
+        return path.is_file().then_some(path);
+    }
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(program))
+        .find(|path| path.is_file())
+}
+
+/// 実行権限があるか（Unix以外では常にtrue）
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path).is_ok_and(|metadata| metadata.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        true
+    }
+}
+
+/// 診断結果を表示する
+///
+/// # Returns
+/// * `Err` - エラーの項目がある場合（終了コードで問題を伝えるため）
+pub fn report(checks: &[DoctorCheck], presenter: Arc<dyn Presenter>) -> Result<()> {
+    presenter.present_doctor_report(checks)?;
+
+    let count = |status| checks.iter().filter(|check| check.status == status).count();
+    let errors = count(CheckStatus::Error);
+    let warnings = count(CheckStatus::Warning);
+    if errors > 0 {
+        anyhow::bail!(Msg::DoctorProblemsFound.format(&[&errors]));
+    }
+    if warnings > 0 {
+        return presenter.present_warning(&Msg::DoctorWarningsFound.format(&[&warnings]));
+    }
+    presenter.present_success(Msg::DoctorNoProblems.text())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::{cli::display::theme::Theme, presentation::CliPresenter};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn database_status(pending: &[&str], integrity: &[&str]) -> DatabaseStatus {
+        DatabaseStatus {
+            path: None,
+            size_bytes: None,
+            applied_migrations: vec!["m20250101_000001_create_tasks".to_string()],
+            pending_migrations: pending.iter().map(|name| name.to_string()).collect(),
+            table_counts: vec![],
+            integrity_check: integrity.iter().map(|line| line.to_string()).collect(),
+            encrypted: Some(false),
+        }
+    }
+
+    #[test]
+    fn test_check_database_status_healthy() {
+        let checks = check_database_status(&database_status(&[], &["ok"]));
+
+        assert!(checks.iter().all(|check| check.status == CheckStatus::Ok));
+        assert_eq!(checks[0].detail, "Up to date (1 migrations applied)");
+    }
+
+    #[test]
+    fn test_check_database_status_pending_and_corrupted() {
+        // Act
+        let checks = check_database_status(&database_status(
+            &["m20260101_000001_add_column"],
+            &["row 3 missing from index"],
+        ));
+
+        // Assert
+        assert_eq!(checks[0].status, CheckStatus::Warning);
+        assert!(checks[0].fix.as_ref().unwrap().contains("yaru db migrate"));
+        assert_eq!(checks[1].status, CheckStatus::Error);
+        assert_eq!(checks[1].detail, "row 3 missing from index");
+    }
+
+    #[test]
+    fn test_check_color() {
+        assert_eq!(check_color(false, true, true).status, CheckStatus::Ok);
+        assert_eq!(
+            check_color(true, true, true).detail,
+            Msg::DoctorColorNoColor.text()
+        );
+        assert_eq!(
+            check_color(false, false, true).detail,
+            Msg::DoctorColorTheme.text()
+        );
+        assert_eq!(
+            check_color(false, true, false).detail,
+            Msg::DoctorColorNotTerminal.text()
+        );
+    }
+
+    #[test]
+    fn test_check_unicode() {
+        assert_eq!(check_unicode(Some("ja_JP.UTF-8")).status, CheckStatus::Ok);
+        assert_eq!(check_unicode(Some("C.utf8")).status, CheckStatus::Ok);
+
+        let check = check_unicode(Some("C"));
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert!(check.fix.unwrap().contains("UTF-8"));
+        assert_eq!(check_unicode(None).status, CheckStatus::Warning);
+    }
+
+    #[test]
+    fn test_check_git_hooks_outside_repository() {
+        let dir = TempDir::new().unwrap();
+
+        assert!(check_git_hooks(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_check_notify_command() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let script = dir.path().join("notify.sh");
+        fs::write(&script, "#!/bin/sh\n").unwrap();
+        let command = |program: &Path| vec![program.display().to_string(), "{title}".to_string()];
+
+        // Act & Assert
+        assert!(check_notify_command(&[]).is_none());
+        let missing = check_notify_command(&["yaru-no-such-notifier".to_string()]).unwrap();
+        assert_eq!(missing.status, CheckStatus::Error);
+        assert_eq!(
+            missing.detail,
+            "yaru-no-such-notifier was not found in PATH"
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&script, fs::Permissions::from_mode(0o644)).unwrap();
+            let not_executable = check_notify_command(&command(&script)).unwrap();
+            assert_eq!(not_executable.status, CheckStatus::Error);
+            assert!(not_executable.fix.unwrap().starts_with("Run `chmod +x"));
+            fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        let found = check_notify_command(&command(&script)).unwrap();
+        assert_eq!(found.status, CheckStatus::Ok);
+    }
+
+    #[test]
+    fn test_report_fails_only_on_errors() {
+        // Arrange
+        let presenter = Arc::new(CliPresenter::capturing(Theme::plain()));
+        let warning = DoctorCheck::warning("Unicode", "Locale C is not UTF-8", "Set LANG");
+        let error = DoctorCheck::error("Config", "invalid", "Fix it");
+
+        // Act
+        let warned = report(
+            &[DoctorCheck::ok("Database", "ok"), warning],
+            presenter.clone(),
+        );
+        let failed = report(&[error], presenter.clone());
+
+        // Assert
+        assert!(warned.is_ok());
+        assert_eq!(
+            failed.unwrap_err().to_string(),
+            "1 problem(s) found; see the fixes above"
+        );
+        let output = presenter.take_output();
+        assert!(
+            output
+                .stdout
+                .contains("[warn]  Unicode: Locale C is not UTF-8")
+        );
+        assert!(output.stdout.contains("        Fix: Set LANG"));
+        assert!(output.stderr.contains("1 warning(s), no errors"));
+    }
+}
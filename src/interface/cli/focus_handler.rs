@@ -0,0 +1,238 @@
+use crate::{
+    application::{
+        dto::task_dto::TaskDTO,
+        use_cases::task::{complete_task::CompleteTaskUseCase, show_task::ShowTaskUseCase},
+    },
+    domain::{
+        clock::Clock,
+        tag::repository::TagRepository,
+        task::{
+            repository::TaskRepository,
+            value_objects::{Estimate, Status},
+        },
+        unit_of_work::UnitOfWorkFactory,
+    },
+    interface::{
+        cli::{args::FocusCommands, id_resolver::resolve_task_id, settings::CliSettings},
+        i18n::Msg,
+        presentation::Presenter,
+    },
+};
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fs, io::ErrorKind, path::Path, sync::Arc};
+
+/// フォーカス中のタスクを記録する状態ファイルの内容
+///
+/// `summary`をプロンプトから毎回呼び出しても速いように、タイトルも記録しておきます。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FocusState {
+    /// フォーカス中のタスクのID
+    task_id: i32,
+    /// フォーカスを始めたときのタスクのタイトル
+    title: String,
+    /// フォーカスを始めた日時
+    started_at: DateTime<Utc>,
+    /// タイマーの長さ（分、Noneの場合はタイマーなし）
+    timer_minutes: Option<u32>,
+}
+
+impl FocusState {
+    /// `now`の時点の状況（`フォーカス: [12] タイトル（残り 12m）`）
+    pub fn status_text(&self, now: DateTime<Utc>) -> String {
+        let elapsed = now - self.started_at;
+        let progress = match self.timer_minutes {
+            Some(timer) => {
+                let remaining = Duration::minutes(timer.into()) - elapsed;
+                if remaining > Duration::zero() {
+                    // 残り1分未満でも「残り0m」にならないよう切り上げる
+                    let minutes = (remaining.num_seconds() + 59) / 60;
+                    Msg::FocusRemaining.format(&[&Estimate::format_minutes(minutes as u32)])
+                } else {
+                    Msg::FocusTimeUp.text().to_string()
+                }
+            }
+            None => Msg::FocusElapsed.format(&[&format_elapsed(elapsed)]),
+        };
+        Msg::FocusStatus.format(&[&self.task_id, &self.title, &progress])
+    }
+}
+
+/// 経過時間を`1h5m`の形式で表す（負の場合は0分）
+fn format_elapsed(elapsed: Duration) -> String {
+    Estimate::format_minutes(elapsed.num_minutes().max(0) as u32)
+}
+
+/// focusコマンドの引数
+pub struct FocusParams {
+    pub command: Option<FocusCommands>,
+    /// フォーカスを始めるタスクのID（IDの前方一致も可）
+    pub id: Option<String>,
+    pub timer: Option<Estimate>,
+}
+
+/// focusコマンドを処理
+pub async fn handle_focus_command(
+    params: FocusParams,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let path = &settings.focus_state_path;
+    let now = settings.clock.now();
+    let FocusParams { command, id, timer } = params;
+    match (command, id) {
+        (Some(FocusCommands::Done), _) => {
+            let state = require_focus(path)?;
+            CompleteTaskUseCase::new(unit_of_work)
+                .with_transition_policy(settings.transition_policy.clone())
+                .with_clock(Arc::new(settings.clock))
+                .execute(state.task_id, None)
+                .await?;
+            clear_focus(path)?;
+            presenter.present_success(&Msg::FocusDone.format(&[
+                &state.task_id,
+                &state.title,
+                &format_elapsed(now - state.started_at),
+            ]))
+        }
+        (Some(FocusCommands::Clear), _) => {
+            let state = require_focus(path)?;
+            clear_focus(path)?;
+            presenter.present_success(&Msg::FocusCleared.format(&[&state.task_id, &state.title]))
+        }
+        (None, Some(id)) => {
+            let task_id = resolve_task_id(task_repo.clone(), &id).await?;
+            let task = ShowTaskUseCase::new(task_repo, tag_repo)
+                .execute(task_id)
+                .await?;
+            start_focus(task, timer, presenter, settings)
+        }
+        (None, None) => match load_focus(path)? {
+            Some(state) => presenter.present_info(&state.status_text(now)),
+            None => presenter.present_info(Msg::FocusNotSet.text()),
+        },
+    }
+}
+
+/// タスクへのフォーカスを始める（フォーカス中のタスクがある場合は置き換える）
+///
+/// # Arguments
+/// * `task` - フォーカスするタスク（完了済みの場合はエラー）
+/// * `timer` - タイマーの長さ（Noneの場合はタイマーなし）
+pub fn start_focus(
+    task: TaskDTO,
+    timer: Option<Estimate>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    if task.status == Status::Completed {
+        bail!(Msg::ErrorTaskAlreadyCompleted.format(&[&task.id]));
+    }
+    let state = FocusState {
+        task_id: task.id,
+        title: task.title,
+        started_at: settings.clock.now(),
+        timer_minutes: timer.map(|timer| timer.minutes()),
+    };
+    save_state(&settings.focus_state_path, &state)?;
+    let message = match timer {
+        Some(timer) => Msg::FocusStartedWithTimer.format(&[&state.task_id, &state.title, &timer]),
+        None => Msg::FocusStarted.format(&[&state.task_id, &state.title]),
+    };
+    presenter.present_success(&message)
+}
+
+/// フォーカス中のタスクを読み込む（状態ファイルがない場合はNone）
+pub fn load_focus(path: &Path) -> Result<Option<FocusState>> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .with_context(|| Msg::ErrorInvalidStateFile.format(&[&path.display()])),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| Msg::ErrorReadStateFile.format(&[&path.display()])),
+    }
+}
+
+/// フォーカス中のタスクを読み込む（フォーカスしていない場合はエラー）
+fn require_focus(path: &Path) -> Result<FocusState> {
+    load_focus(path)?.context(Msg::FocusNotSet.text())
+}
+
+/// 状態ファイルを書き込む
+fn save_state(path: &Path, state: &FocusState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| Msg::ErrorCreateDirectory.format(&[&parent.display()]))?;
+    }
+    fs::write(path, serde_json::to_string(state)?)
+        .with_context(|| Msg::ErrorWriteStateFile.format(&[&path.display()]))
+}
+
+/// 状態ファイルを削除してフォーカスを終える
+fn clear_focus(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != ErrorKind::NotFound => {
+            Err(e).with_context(|| Msg::ErrorRemoveStateFile.format(&[&path.display()]))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    fn state(timer_minutes: Option<u32>) -> FocusState {
+        FocusState {
+            task_id: 12,
+            title: "Write report".to_string(),
+            started_at: Utc.with_ymd_and_hms(2026, 10, 18, 9, 0, 0).unwrap(),
+            timer_minutes,
+        }
+    }
+
+    #[test]
+    fn test_focus_state_round_trip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state").join("focus.json");
+
+        // Act
+        let initial = load_focus(&path).unwrap();
+        save_state(&path, &state(Some(25))).unwrap();
+        let loaded = load_focus(&path).unwrap();
+        clear_focus(&path).unwrap();
+
+        // Assert
+        assert_eq!(initial, None);
+        assert_eq!(loaded, Some(state(Some(25))));
+        assert_eq!(load_focus(&path).unwrap(), None);
+        assert!(clear_focus(&path).is_ok());
+    }
+
+    #[test]
+    fn test_focus_status_text() {
+        let started_at = state(None).started_at;
+        let at = |minutes: i64, seconds: i64| {
+            started_at + Duration::minutes(minutes) + Duration::seconds(seconds)
+        };
+
+        assert_eq!(
+            state(None).status_text(at(65, 30)),
+            "Focus: [12] Write report (1h5m elapsed)"
+        );
+        assert_eq!(
+            state(Some(25)).status_text(at(12, 30)),
+            "Focus: [12] Write report (13m left)"
+        );
+        assert_eq!(
+            state(Some(25)).status_text(at(25, 0)),
+            "Focus: [12] Write report (time's up)"
+        );
+    }
+}
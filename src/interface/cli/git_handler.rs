@@ -0,0 +1,126 @@
+use crate::{
+    application::use_cases::task::manage_git_links::ManageGitLinksUseCase,
+    domain::task::repository::TaskRepository,
+    interface::{
+        cli::{
+            args::{GitCommands, GitHookCommands},
+            settings::CliSettings,
+        },
+        git::{
+            GitRepository, HookInstall, append_task_trailers, install_hook, parse_task_trailers,
+            shell_quote,
+        },
+        i18n::Msg,
+        presentation::Presenter,
+    },
+};
+use anyhow::{Context, Result};
+use std::{fs, path::Path, sync::Arc};
+
+/// マージで取り込まれたコミットの範囲
+const MERGED_RANGE: &str = "ORIG_HEAD..HEAD";
+
+/// gitコマンドを処理
+pub async fn handle_git_command(
+    command: GitCommands,
+    task_repo: Arc<dyn TaskRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let repository = GitRepository::discover(&std::env::current_dir()?)?;
+    match command {
+        GitCommands::Hook {
+            command: GitHookCommands::Install,
+        } => handle_hook_install(&repository, presenter),
+        GitCommands::PrepareCommitMsg { file, .. } => {
+            handle_prepare_commit_msg(&repository, task_repo, &file).await
+        }
+        GitCommands::PostMerge => {
+            let use_case = ManageGitLinksUseCase::new(task_repo)
+                .with_transition_policy(settings.transition_policy.clone())
+                .with_clock(Arc::new(settings.clock));
+            handle_post_merge(&repository, use_case, presenter).await
+        }
+    }
+}
+
+/// prepare-commit-msgフックとpost-mergeフックを導入
+fn handle_hook_install(repository: &GitRepository, presenter: Arc<dyn Presenter>) -> Result<()> {
+    let hooks_dir = repository.hooks_dir()?;
+    let exe = std::env::current_exe().context(Msg::ErrorLocateExecutable.text())?;
+    let exe = shell_quote(&exe.display().to_string());
+
+    let hooks = [
+        (
+            "prepare-commit-msg",
+            format!("{} git prepare-commit-msg \"$1\" \"$2\" || true", exe),
+        ),
+        ("post-merge", format!("{} git post-merge || true", exe)),
+    ];
+    for (name, command) in hooks {
+        let path = hooks_dir.join(name);
+        let message = match install_hook(&hooks_dir, name, &command)? {
+            HookInstall::Created => Msg::GitHookCreated,
+            HookInstall::Appended => Msg::GitHookAppended,
+            HookInstall::AlreadyInstalled => Msg::GitHookAlreadyInstalled,
+        };
+        presenter.present_success(&message.format(&[&name, &path.display()]))?;
+    }
+    Ok(())
+}
+
+/// 現在のブランチに紐づく未完了タスクのトレーラーをコミットメッセージに追加
+async fn handle_prepare_commit_msg(
+    repository: &GitRepository,
+    task_repo: Arc<dyn TaskRepository>,
+    file: &Path,
+) -> Result<()> {
+    let Some(branch) = repository.current_branch() else {
+        return Ok(());
+    };
+    let tasks = ManageGitLinksUseCase::new(task_repo)
+        .open_tasks_on_branch(&repository.root().display().to_string(), &branch)
+        .await?;
+    if tasks.is_empty() {
+        return Ok(());
+    }
+
+    // フックの引数はリポジトリのルートからの相対パスで渡される
+    let path = repository.root().join(file);
+    let message = fs::read_to_string(&path)
+        .with_context(|| Msg::ErrorReadCommitMessage.format(&[&path.display()]))?;
+    let ids: Vec<i32> = tasks.iter().map(|task| task.id).collect();
+    let updated = append_task_trailers(&message, &ids);
+    if updated != message {
+        fs::write(&path, updated)?;
+    }
+    Ok(())
+}
+
+/// マージされたコミットのトレーラーで参照されているタスクを完了にする
+///
+/// 1件の失敗でマージ後の処理全体を止めないよう、失敗は警告として表示します。
+async fn handle_post_merge(
+    repository: &GitRepository,
+    use_case: ManageGitLinksUseCase,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    let mut ids: Vec<i32> = Vec::new();
+    for message in repository.commit_messages(MERGED_RANGE)? {
+        for id in parse_task_trailers(&message) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+
+    for id in ids {
+        match use_case.complete(id).await {
+            Ok(Some(task)) => presenter
+                .present_success(&Msg::GitTaskCompleted.format(&[&task.id, &task.title]))?,
+            Ok(None) => {}
+            Err(e) => presenter.present_error(&format!("{:#}", e))?,
+        }
+    }
+    Ok(())
+}
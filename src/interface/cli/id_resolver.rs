@@ -0,0 +1,293 @@
+use crate::{
+    domain::{
+        tag::{repository::TagRepository, value_objects::TagId},
+        task::{repository::TaskRepository, value_objects::TaskId},
+    },
+    interface::i18n::Msg,
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// 曖昧な場合にエラーメッセージへ列挙する候補の最大数
+const MAX_AMBIGUOUS_CANDIDATES: usize = 10;
+
+/// ID（またはIDの前方一致）からタスクIDを解決
+///
+/// 完全一致するIDが存在すればそれを優先し、存在しない場合は前方一致で一意に決まるIDを返します。
+/// 例えば`12`は、ID 12が存在しなければID 120や123に前方一致します。
+pub async fn resolve_task_id(task_repo: Arc<dyn TaskRepository>, input: &str) -> Result<i32> {
+    if let Ok(id) = input.parse::<i32>()
+        && task_repo.find_by_id(&TaskId::new(id)?).await?.is_some()
+    {
+        return Ok(id);
+    }
+
+    let ids: Vec<i32> = task_repo
+        .find_all()
+        .await?
+        .iter()
+        .map(|task| task.id().value())
+        .collect();
+
+    resolve_id_prefix(input, &ids, Msg::KindTask.text())
+}
+
+/// ID（またはIDの前方一致）からタグIDを解決
+///
+/// 解決ルールは[`resolve_task_id`]と同じです。
+pub async fn resolve_tag_id(tag_repo: Arc<dyn TagRepository>, input: &str) -> Result<i32> {
+    if let Ok(id) = input.parse::<i32>()
+        && tag_repo.find_by_id(&TagId::new(id)?).await?.is_some()
+    {
+        return Ok(id);
+    }
+
+    let ids: Vec<i32> = tag_repo
+        .find_all()
+        .await?
+        .iter()
+        .map(|tag| tag.id().value())
+        .collect();
+
+    resolve_id_prefix(input, &ids, Msg::KindTag.text())
+}
+
+/// 完全一致するタスクIDだけを受け付けて解決
+///
+/// 削除・完了・編集などタスクを変更するコマンドで使います。
+/// 前方一致を許すと、削除済みのID 12を指定した場合にID 123を変更してしまうためです。
+/// 入力が他のIDの前方一致になっている場合は、その旨をエラーメッセージに含めます。
+pub async fn resolve_exact_task_id(task_repo: Arc<dyn TaskRepository>, input: &str) -> Result<i32> {
+    if let Ok(id) = input.parse::<i32>()
+        && task_repo.find_by_id(&TaskId::new(id)?).await?.is_some()
+    {
+        return Ok(id);
+    }
+
+    let ids: Vec<i32> = task_repo
+        .find_all()
+        .await?
+        .iter()
+        .map(|task| task.id().value())
+        .collect();
+
+    resolve_exact_id(input, &ids, Msg::KindTask.text())
+}
+
+/// 完全一致するタグIDだけを受け付けて解決
+///
+/// 解決ルールは[`resolve_exact_task_id`]と同じです。
+pub async fn resolve_exact_tag_id(tag_repo: Arc<dyn TagRepository>, input: &str) -> Result<i32> {
+    if let Ok(id) = input.parse::<i32>()
+        && tag_repo.find_by_id(&TagId::new(id)?).await?.is_some()
+    {
+        return Ok(id);
+    }
+
+    let ids: Vec<i32> = tag_repo
+        .find_all()
+        .await?
+        .iter()
+        .map(|tag| tag.id().value())
+        .collect();
+
+    resolve_exact_id(input, &ids, Msg::KindTag.text())
+}
+
+/// 複数のタグID（またはIDの前方一致）をまとめて解決
+///
+/// タグの一覧は1回だけ読み込みます。解決ルールは[`resolve_task_id`]と同じです。
+pub async fn resolve_tag_ids(
+    tag_repo: Arc<dyn TagRepository>,
+    inputs: &[String],
+) -> Result<Vec<i32>> {
+    if inputs.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let ids: Vec<i32> = tag_repo
+        .find_all()
+        .await?
+        .iter()
+        .map(|tag| tag.id().value())
+        .collect();
+
+    inputs
+        .iter()
+        .map(|input| resolve_id_prefix(input.trim(), &ids, Msg::KindTag.text()))
+        .collect()
+}
+
+/// 候補のIDから、入力に完全一致するIDを選ぶ（前方一致は受け付けない）
+fn resolve_exact_id(input: &str, ids: &[i32], kind: &str) -> Result<i32> {
+    if let Ok(id) = input.parse::<i32>()
+        && ids.contains(&id)
+    {
+        return Ok(id);
+    }
+
+    match resolve_id_prefix(input, ids, kind) {
+        Ok(id) => anyhow::bail!(Msg::ErrorIdPrefixNotAllowed.format(&[&kind, &input, &id])),
+        Err(_) => anyhow::bail!(Msg::ErrorIdNotExist.format(&[&kind, &input])),
+    }
+}
+
+/// 候補のIDから、入力に完全一致または前方一致する一意のIDを選ぶ
+///
+/// # 引数
+/// - `input`: 入力されたIDまたはIDの先頭部分
+/// - `ids`: 候補となるIDの一覧
+/// - `kind`: エラーメッセージに表示する種別（表示言語に応じた"Task"や"Tag"）
+///
+/// # 戻り値
+/// - `Ok(i32)`: 一意に解決できた場合
+/// - `Err`: 一致するIDがない場合、または複数のIDに前方一致する場合
+fn resolve_id_prefix(input: &str, ids: &[i32], kind: &str) -> Result<i32> {
+    if let Ok(id) = input.parse::<i32>()
+        && ids.contains(&id)
+    {
+        return Ok(id);
+    }
+
+    let mut matches: Vec<i32> = ids
+        .iter()
+        .copied()
+        .filter(|id| id.to_string().starts_with(input))
+        .collect();
+    matches.sort_unstable();
+
+    match matches.as_slice() {
+        [] => anyhow::bail!(Msg::ErrorIdNotExist.format(&[&kind, &input])),
+        [id] => Ok(*id),
+        _ => {
+            let mut candidates: Vec<String> = matches
+                .iter()
+                .take(MAX_AMBIGUOUS_CANDIDATES)
+                .map(|id| id.to_string())
+                .collect();
+            if matches.len() > MAX_AMBIGUOUS_CANDIDATES {
+                candidates.push("...".to_string());
+            }
+            anyhow::bail!(Msg::ErrorIdAmbiguous.format(&[
+                &kind,
+                &input,
+                &candidates.join(", "),
+                &matches.len(),
+            ]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        tag::{
+            aggregate::NewTag,
+            value_objects::{TagDescription, TagName},
+        },
+        task::{builder::TaskBuilder, value_objects::TaskTitle},
+    };
+    use crate::interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository};
+
+    #[test]
+    fn test_resolve_id_prefix_exact_match_wins() {
+        // 12が存在すれば、120や123があっても12に解決される
+        assert_eq!(
+            resolve_id_prefix("12", &[12, 120, 123], "Task").unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_resolve_id_prefix_unique_prefix() {
+        assert_eq!(resolve_id_prefix("45", &[1, 2, 456], "Task").unwrap(), 456);
+    }
+
+    #[test]
+    fn test_resolve_id_prefix_ambiguous() {
+        let err = resolve_id_prefix("1", &[2, 123, 10, 11], "Task").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Task ID prefix '1' is ambiguous: matches 10, 11, 123 (3 candidates)"
+        );
+    }
+
+    #[test]
+    fn test_resolve_id_prefix_ambiguous_truncates_candidates() {
+        let ids: Vec<i32> = (100..=120).collect();
+        let err = resolve_id_prefix("1", &ids, "Tag").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Tag ID prefix '1' is ambiguous: matches 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, ... (21 candidates)"
+        );
+    }
+
+    #[test]
+    fn test_resolve_id_prefix_not_found() {
+        let err = resolve_id_prefix("9", &[1, 2], "Tag").unwrap_err();
+        assert_eq!(err.to_string(), "Tag ID 9 does not exist");
+    }
+
+    #[test]
+    fn test_resolve_exact_id_rejects_prefix() {
+        assert_eq!(resolve_exact_id("12", &[12, 123], "Task").unwrap(), 12);
+        assert_eq!(
+            resolve_exact_id("12", &[123], "Task")
+                .unwrap_err()
+                .to_string(),
+            "Task ID 12 does not exist (it is a prefix of 123; commands that change or delete need the full ID)"
+        );
+        assert_eq!(
+            resolve_exact_id("1", &[10, 11], "Task")
+                .unwrap_err()
+                .to_string(),
+            "Task ID 1 does not exist"
+        );
+        assert!(resolve_exact_id("x", &[1], "Tag").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_tag_ids_with_prefix() {
+        // Arrange
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        for name in ["a", "b"] {
+            tag_repo
+                .save(NewTag::new(
+                    TagName::new(name).unwrap(),
+                    TagDescription::new("").unwrap(),
+                ))
+                .await
+                .unwrap();
+        }
+        let inputs = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        // Act & Assert
+        assert_eq!(
+            resolve_tag_ids(tag_repo.clone(), &inputs(&["2", " 1"]))
+                .await
+                .unwrap(),
+            vec![2, 1]
+        );
+        assert!(
+            resolve_tag_ids(tag_repo.clone(), &inputs(&["3"]))
+                .await
+                .is_err()
+        );
+        assert!(resolve_tag_ids(tag_repo, &[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_task_id_with_repository() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        for _ in 0..3 {
+            let task = TaskBuilder::new(TaskTitle::new("タスク").unwrap()).build();
+            task_repo.save(task).await.unwrap();
+        }
+
+        // Act & Assert
+        assert_eq!(resolve_task_id(task_repo.clone(), "2").await.unwrap(), 2);
+        assert!(resolve_task_id(task_repo, "4").await.is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    application::use_cases::task::import_tasks::{ImportTasksUseCase, ImportedTask},
+    domain::{
+        task::value_objects::{Priority, Status},
+        unit_of_work::UnitOfWorkFactory,
+    },
+    interface::{
+        cli::{args::ImportCommands, display::format::format_date},
+        i18n::Msg,
+        import::{
+            ics::{self, DateRange, ICS_SOURCE},
+            taskwarrior::{self, TASKWARRIOR_SOURCE},
+            todoist::{self, TODOIST_SOURCE},
+        },
+        presentation::Presenter,
+    },
+};
+use anyhow::{Context, Result};
+use std::{fs, path::Path, sync::Arc};
+
+/// importコマンドを処理
+pub async fn handle_import_command(
+    command: ImportCommands,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    let (source, tasks, dry_run) = match command {
+        ImportCommands::Todoist { file, dry_run } => (
+            TODOIST_SOURCE,
+            todoist::parse(&read_export(&file)?)?,
+            dry_run,
+        ),
+        ImportCommands::Taskwarrior { file, dry_run } => (
+            TASKWARRIOR_SOURCE,
+            taskwarrior::parse(&read_export(&file)?)?,
+            dry_run,
+        ),
+        ImportCommands::Ics {
+            file,
+            from,
+            to,
+            dry_run,
+        } => (
+            ICS_SOURCE,
+            ics::parse(&read_export(&file)?, DateRange { from, to })?,
+            dry_run,
+        ),
+    };
+    import_tasks(source, tasks, dry_run, unit_of_work, presenter).await
+}
+
+/// 読み込んだタスクを取り込み、結果を表示する（`dry_run`の場合は作成されるタスクとタグを表示するだけ）
+pub async fn import_tasks(
+    source: &str,
+    tasks: Vec<ImportedTask>,
+    dry_run: bool,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    let use_case = ImportTasksUseCase::new(unit_of_work);
+    if dry_run {
+        let plan = use_case.plan(source, tasks).await?;
+        for name in &plan.new_tags {
+            presenter.present_info(&Msg::ImportPlanTag.format(&[name]))?;
+        }
+        for task in &plan.create {
+            presenter.present_info(&Msg::ImportPlanTask.format(&[&task.title, &describe(task)]))?;
+        }
+        for task in &plan.skip {
+            presenter.present_info(&Msg::ImportPlanSkip.format(&[&task.title]))?;
+        }
+        presenter.present_info(&Msg::ImportDryRunSummary.format(&[
+            &plan.create.len(),
+            &plan.new_tags.len(),
+            &plan.skip.len(),
+        ]))?;
+        return Ok(());
+    }
+
+    let report = use_case.execute(source, tasks).await?;
+    for task in &report.created {
+        presenter.present_success(&Msg::SyncTaskImported.format(&[&task.id, &task.title]))?;
+    }
+    presenter.present_success(&Msg::ImportSummary.format(&[
+        &report.created.len(),
+        &report.new_tags.len(),
+        &report.skipped,
+    ]))
+}
+
+/// エクスポートファイルを読み込む
+fn read_export(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| Msg::ErrorReadFile.format(&[&path.display()]))
+}
+
+/// 作成するタスクのステータス・優先度・期限日・タグを1行にまとめる
+fn describe(task: &ImportedTask) -> String {
+    let status = match task.status {
+        Status::Pending => Msg::ValuePending,
+        Status::InProgress => Msg::ValueInProgress,
+        Status::Completed => Msg::ValueCompleted,
+    };
+    let priority = match task.priority {
+        Priority::Low => Msg::ValueLow,
+        Priority::Medium => Msg::ValueMedium,
+        Priority::High => Msg::ValueHigh,
+        Priority::Critical => Msg::ValueCritical,
+    };
+    let mut parts = vec![status.text().to_string(), priority.text().to_string()];
+    if task.due_date.is_some() {
+        parts.push(format_date(&task.due_date));
+    }
+    if !task.tags.is_empty() {
+        parts.push(task.tags.join(", "));
+    }
+    parts.join(", ")
+}
@@ -0,0 +1,98 @@
+use crate::{
+    domain::unit_of_work::UnitOfWorkFactory,
+    interface::{
+        cli::{args::IngestCommands, import_handler::import_tasks, settings::CliSettings},
+        i18n::Msg,
+        mail::{
+            self, MAIL_SOURCE, MailFilter,
+            imap::{self, ImapAccount},
+            maildir,
+        },
+        presentation::Presenter,
+    },
+};
+use anyhow::{Context, Result, bail};
+use std::sync::Arc;
+
+/// ingestコマンドを処理
+pub async fn handle_ingest_command(
+    command: IngestCommands,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    match command {
+        IngestCommands::Mail {
+            maildir,
+            imap,
+            user,
+            folder,
+            subject,
+            from,
+            tag,
+            dry_run,
+        } => {
+            let config = &settings.mail;
+            let filter = MailFilter {
+                subject: subject.or_else(|| config.subject_filter.clone()),
+                from,
+            };
+            // CLIフラグを優先し、どちらもなければ設定のmaildir、IMAPサーバーの順に使う
+            let raw_messages = match (maildir, imap) {
+                (Some(dir), _) => maildir::read_messages(&dir)?,
+                (None, Some(host)) => {
+                    let account = imap_account(host, user, folder, settings)?;
+                    imap::fetch_messages(account, filter.clone()).await?
+                }
+                (None, None) => match (&config.maildir, &config.imap_host) {
+                    (Some(dir), _) => maildir::read_messages(dir)?,
+                    (None, Some(host)) => {
+                        let account = imap_account(host.clone(), user, folder, settings)?;
+                        imap::fetch_messages(account, filter.clone()).await?
+                    }
+                    (None, None) => bail!(Msg::ErrorNoMailbox.text()),
+                },
+            };
+
+            let tag = tag.or_else(|| config.tag.clone());
+            let mut without_id = 0;
+            let mut tasks = Vec::new();
+            for message in raw_messages.iter().map(|raw| mail::parse_message(raw)) {
+                if !filter.matches(&message) {
+                    continue;
+                }
+                match message.to_imported_task(tag.as_deref()) {
+                    Some(task) => tasks.push(task),
+                    None => without_id += 1,
+                }
+            }
+            if without_id > 0 {
+                presenter.present_warning(&Msg::IngestNoMessageId.format(&[&without_id]))?;
+            }
+
+            import_tasks(MAIL_SOURCE, tasks, dry_run, unit_of_work, presenter).await
+        }
+    }
+}
+
+/// CLIフラグと設定からIMAPのアカウントを組み立てる
+fn imap_account(
+    host: String,
+    user: Option<String>,
+    folder: Option<String>,
+    settings: &CliSettings,
+) -> Result<ImapAccount> {
+    let config = &settings.mail;
+    Ok(ImapAccount {
+        host,
+        port: config.imap_port,
+        user: user
+            .or_else(|| config.imap_user.clone())
+            .context(Msg::ErrorImapUserRequired.text())?,
+        password: config
+            .imap_password
+            .clone()
+            .context(Msg::ErrorImapPasswordRequired.text())?,
+        folder: folder.unwrap_or_else(|| config.imap_folder.clone()),
+    })
+}
@@ -0,0 +1,180 @@
+use crate::{
+    application::{
+        dto::{TagDTO, TaskDTO},
+        use_cases::{tag::list_tags::ListTagsUseCase, task::list_tasks::ListTasksUseCase},
+    },
+    domain::{tag::repository::TagRepository, task::repository::TaskRepository},
+    interface::{cli::display::format::format_status, i18n::Msg},
+};
+use anyhow::{Context, Result};
+use inquire::Select;
+use std::sync::Arc;
+
+/// ID選択用のラッパー型
+///
+/// `inquire::Select`/`inquire::MultiSelect`で使用するために、IDと表示文字列をペアで保持します。
+/// 文字列パースに依存せず、型安全にIDを取得できます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdOption {
+    pub id: i32,
+    pub display: String,
+}
+
+impl std::fmt::Display for IdOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display)
+    }
+}
+
+/// タスク一覧から選択肢を作成
+///
+/// 表示形式: `[ID] タイトル (ステータス)`
+pub fn task_options(tasks: &[TaskDTO]) -> Vec<IdOption> {
+    tasks
+        .iter()
+        .map(|task| IdOption {
+            id: task.id,
+            display: format!(
+                "[{}] {} ({})",
+                task.id,
+                task.title,
+                format_status(task.status)
+            ),
+        })
+        .collect()
+}
+
+/// タグ一覧から選択肢を作成
+///
+/// 表示形式: `[ID] タグ名`
+pub fn tag_options(tags: &[TagDTO]) -> Vec<IdOption> {
+    tags.iter()
+        .map(|tag| IdOption {
+            id: tag.id,
+            display: format!("[{}] {}", tag.id, tag.path),
+        })
+        .collect()
+}
+
+/// 選択肢からIDを1つ選択する
+///
+/// 入力した文字列で候補が絞り込まれる（ファジー検索）ため、IDを覚えていなくても選択できます。
+/// 絞り込みの入力と競合するため、vimモードは有効にしません。
+///
+/// # Arguments
+/// * `message` - プロンプトに表示するメッセージ
+/// * `options` - 選択肢
+///
+/// # Returns
+/// * `Ok(i32)` - 選択されたID
+/// * `Err` - 選択肢が空の場合、または選択がキャンセルされた場合
+fn pick_id(message: &str, options: Vec<IdOption>) -> Result<i32> {
+    let selected = Select::new(message, options)
+        .with_help_message(Msg::PickerHelp.text())
+        .prompt()
+        .context(Msg::ErrorSelectionCancelled.text())?;
+
+    Ok(selected.id)
+}
+
+/// タスクを対話的に選択し、そのIDを返す
+pub async fn select_task_id(
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    message: &str,
+) -> Result<i32> {
+    let use_case = ListTasksUseCase::new(task_repo, tag_repo);
+    let mut tasks = use_case.execute().await?;
+
+    if tasks.is_empty() {
+        anyhow::bail!(Msg::NoTasksFound.text());
+    }
+
+    tasks.sort_by_key(|task| task.id);
+    pick_id(message, task_options(&tasks))
+}
+
+/// タグを対話的に選択し、そのIDを返す
+pub async fn select_tag_id(tag_repo: Arc<dyn TagRepository>, message: &str) -> Result<i32> {
+    let use_case = ListTagsUseCase::new(tag_repo);
+    let mut tags = use_case.execute().await?;
+
+    if tags.is_empty() {
+        anyhow::bail!(Msg::NoTagsFound.text());
+    }
+
+    tags.sort_by_key(|tag| tag.id);
+    pick_id(message, tag_options(&tags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::value_objects::{Priority, Status};
+    use chrono::Utc;
+
+    fn task_dto(id: i32, title: &str, status: Status) -> TaskDTO {
+        TaskDTO {
+            id,
+            title: title.to_string(),
+            description: None,
+            status,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date: None,
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_task_options_display() {
+        let tasks = vec![
+            task_dto(1, "買い物", Status::Pending),
+            task_dto(12, "レポート作成", Status::InProgress),
+        ];
+
+        let options = task_options(&tasks);
+
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].id, 1);
+        assert_eq!(options[0].to_string(), "[1] 買い物 (pending)");
+        assert_eq!(options[1].id, 12);
+        assert_eq!(options[1].to_string(), "[12] レポート作成 (in_progress)");
+    }
+
+    #[test]
+    fn test_tag_options_display() {
+        let tags = vec![TagDTO {
+            id: 3,
+            name: "仕事".to_string(),
+            description: None,
+            parent_id: None,
+            path: "仕事".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }];
+
+        let options = tag_options(&tags);
+
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].id, 3);
+        assert_eq!(options[0].to_string(), "[3] 仕事");
+    }
+
+    #[test]
+    fn test_options_empty() {
+        assert!(task_options(&[]).is_empty());
+        assert!(tag_options(&[]).is_empty());
+    }
+}
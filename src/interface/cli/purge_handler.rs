@@ -0,0 +1,258 @@
+use crate::interface::{i18n::Msg, presentation::Presenter};
+use anyhow::{Context, Result};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// 上書きに使うバッファのバイト数
+const ERASE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// 消去の対象になるyaruのファイルの場所
+#[derive(Debug, Clone, Default)]
+pub struct PurgePaths {
+    /// SQLiteのデータベースファイル（ジャーナルと`<ファイル名>.<日時>.bak`のバックアップも含める）
+    pub database: Option<PathBuf>,
+    /// ログファイル（ローテーションした`<ファイル名>.1`なども含める）
+    pub log_file: Option<PathBuf>,
+    /// その他のファイル（設定ファイル・JSONの保存先・状態ファイル・実行記録など）
+    pub files: Vec<PathBuf>,
+    /// 消去後に空になっていれば削除するディレクトリ（`~/.config/yaru`）
+    pub config_dir: Option<PathBuf>,
+}
+
+/// purge-allコマンドを処理
+///
+/// 削除するファイルを一覧で表示し、`confirm`の場合だけ件数の入力で確認してから削除します。
+pub fn handle_purge_all(
+    paths: &PurgePaths,
+    confirm: bool,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    let targets = collect_targets(paths)?;
+    if targets.is_empty() {
+        return presenter.present_info(Msg::PurgeAllNothing.text());
+    }
+
+    presenter.present_info(&Msg::PurgeAllTargets.format(&[&targets.len()]))?;
+    for target in &targets {
+        presenter.present_data(&format!("  {}", target.display()))?;
+    }
+    if !confirm {
+        return presenter.present_info(Msg::PurgeAllDryRun.text());
+    }
+    if !presenter.confirm_count(
+        &Msg::ConfirmPurgeAll.format(&[&targets.len()]),
+        targets.len(),
+    )? {
+        return presenter.present_info(Msg::PurgeAllCancelled.text());
+    }
+
+    for target in &targets {
+        erase_file(target)?;
+    }
+    if let Some(dir) = &paths.config_dir {
+        // 他のファイルが残っている場合は削除できないため、結果は無視する
+        let _ = fs::remove_dir(dir);
+    }
+    presenter.present_success(&Msg::PurgedAll.format(&[&targets.len()]))
+}
+
+/// 存在する消去の対象のファイルを、重複を除いてパスの順に求める
+fn collect_targets(paths: &PurgePaths) -> Result<Vec<PathBuf>> {
+    let mut candidates = paths.files.clone();
+    if let Some(database) = &paths.database {
+        candidates.push(database.clone());
+        for suffix in ["-wal", "-shm", "-journal", ".wal", ".shm", ".pull"] {
+            candidates.push(append_to_name(database, suffix));
+        }
+        candidates.extend(related_files(database, |rest| rest.ends_with(".bak"))?);
+    }
+    if let Some(log_file) = &paths.log_file {
+        candidates.push(log_file.clone());
+        candidates.extend(related_files(log_file, |rest| {
+            !rest.is_empty() && rest.bytes().all(|byte| byte.is_ascii_digit())
+        })?);
+    }
+
+    let mut targets: Vec<PathBuf> = candidates
+        .into_iter()
+        .filter(|path| fs::symlink_metadata(path).is_ok_and(|metadata| !metadata.is_dir()))
+        .collect();
+    targets.sort();
+    targets.dedup();
+    Ok(targets)
+}
+
+/// 同じディレクトリにある`<ファイル名>.<rest>`のうち、`rest`が条件を満たすファイル
+fn related_files(path: &Path, matches: impl Fn(&str) -> bool) -> Result<Vec<PathBuf>> {
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else {
+        return Ok(vec![]);
+    };
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    let prefix = format!("{}.", name.to_string_lossy());
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => {
+            return Err(e).with_context(|| Msg::ErrorReadDirectory.format(&[&dir.display()]));
+        }
+    };
+
+    let mut files = vec![];
+    for entry in entries {
+        let entry = entry.with_context(|| Msg::ErrorReadDirectory.format(&[&dir.display()]))?;
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if file_name.strip_prefix(&prefix).is_some_and(&matches) {
+            files.push(dir.join(file_name));
+        }
+    }
+    Ok(files)
+}
+
+/// ファイル名の末尾に文字列を付けたパス
+fn append_to_name(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// ファイルの内容を0で上書きしてから削除する
+///
+/// シンボリックリンクやソケットは、リンク先を変更せずにそのものだけを削除します。
+/// SSDやコピーオンライトのファイルシステムでは、上書きしても元のデータが残る場合があります。
+fn erase_file(path: &Path) -> Result<()> {
+    let metadata = fs::symlink_metadata(path)
+        .with_context(|| Msg::ErrorReadFile.format(&[&path.display()]))?;
+    if metadata.is_file() {
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .open(path)
+            .with_context(|| Msg::ErrorOpenFile.format(&[&path.display()]))?;
+        let zeros = vec![0u8; ERASE_CHUNK_SIZE];
+        let mut remaining = metadata.len();
+        while remaining > 0 {
+            let len = remaining.min(ERASE_CHUNK_SIZE as u64) as usize;
+            file.write_all(&zeros[..len])
+                .with_context(|| Msg::ErrorOverwriteFile.format(&[&path.display()]))?;
+            remaining -= len as u64;
+        }
+        file.sync_all()
+            .with_context(|| Msg::ErrorOverwriteFile.format(&[&path.display()]))?;
+    }
+    fs::remove_file(path).with_context(|| Msg::ErrorRemoveFile.format(&[&path.display()]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::{cli::display::theme::Theme, presentation::CliPresenter};
+    use tempfile::TempDir;
+
+    /// テスト用のファイルを作成してパスを返す
+    fn touch(dir: &Path, name: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, name).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_collect_targets_includes_related_files() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let database = touch(dir.path(), "yaru.db");
+        let wal = touch(dir.path(), "yaru.db-wal");
+        let backup = touch(dir.path(), "yaru.db.20261018-091500.bak");
+        let log = touch(dir.path(), "yaru.log");
+        let rotated = touch(dir.path(), "yaru.log.1");
+        let config = touch(dir.path(), "config.toml");
+        touch(dir.path(), "yaru.log.old");
+        touch(dir.path(), "notes.txt");
+        let paths = PurgePaths {
+            database: Some(database.clone()),
+            log_file: Some(log.clone()),
+            files: vec![
+                config.clone(),
+                config.clone(),
+                dir.path().join("missing.json"),
+            ],
+            config_dir: None,
+        };
+
+        // Act
+        let targets = collect_targets(&paths).unwrap();
+
+        // Assert
+        let mut expected = vec![database, wal, backup, log, rotated, config];
+        expected.sort();
+        assert_eq!(targets, expected);
+    }
+
+    #[test]
+    fn test_purge_all_requires_confirm() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let config_dir = dir.path().join("yaru");
+        fs::create_dir_all(&config_dir).unwrap();
+        let paths = PurgePaths {
+            database: Some(touch(&config_dir, "yaru.db")),
+            log_file: None,
+            files: vec![touch(&config_dir, "config.toml")],
+            config_dir: Some(config_dir.clone()),
+        };
+        let presenter = Arc::new(CliPresenter::capturing(Theme::plain()).with_assume_yes(true));
+
+        // Act & Assert: --confirmがない場合は一覧を表示するだけ
+        handle_purge_all(&paths, false, presenter.clone()).unwrap();
+        assert!(config_dir.join("yaru.db").exists());
+
+        // Act & Assert: --confirmの場合は削除し、空になったディレクトリも削除する
+        handle_purge_all(&paths, true, presenter.clone()).unwrap();
+        assert!(!config_dir.exists());
+    }
+
+    #[test]
+    fn test_purge_all_cancelled_without_terminal() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let paths = PurgePaths {
+            files: vec![touch(dir.path(), "a.json"), touch(dir.path(), "b.json")],
+            ..PurgePaths::default()
+        };
+
+        // Act: 件数を入力できないため、削除しない
+        handle_purge_all(
+            &paths,
+            true,
+            Arc::new(CliPresenter::capturing(Theme::plain())),
+        )
+        .unwrap();
+
+        // Assert
+        assert!(dir.path().join("a.json").exists());
+        assert!(dir.path().join("b.json").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_erase_file_removes_symlink_only() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let original = touch(dir.path(), "original.txt");
+        let link = dir.path().join("link.txt");
+        std::os::unix::fs::symlink(&original, &link).unwrap();
+
+        // Act
+        erase_file(&link).unwrap();
+
+        // Assert
+        assert!(!link.exists());
+        assert_eq!(fs::read_to_string(&original).unwrap(), "original.txt");
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    application::use_cases::task::query_tasks::QueryTasksUseCase,
+    domain::{tag::repository::TagRepository, task::repository::TaskRepository},
+    interface::{
+        cli::{
+            args::ListFormatArg, display::TaskColumn, settings::CliSettings,
+            task_handler::build_list_view,
+        },
+        presentation::Presenter,
+    },
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// queryコマンドを処理
+///
+/// クエリに一致するタスクを、`task list`と同じ表示形式で表示します。
+pub async fn handle_query(
+    query: String,
+    format: Option<ListFormatArg>,
+    columns: Option<Vec<TaskColumn>>,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let tasks = QueryTasksUseCase::new(task_repo, tag_repo)
+        .with_clock(Arc::new(settings.clock))
+        .execute(&query)
+        .await?;
+    presenter.present_task_list(&tasks, &build_list_view(format, columns, settings))?;
+    Ok(())
+}
@@ -0,0 +1,276 @@
+use crate::{
+    domain::{
+        services::BusinessCalendar,
+        task::value_objects::{Priority, TaskContext},
+    },
+    interface::{cli::relative_date::RelativeDate, i18n::Msg},
+};
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+
+/// インライン記法で入力したタスク
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QuickAdd {
+    pub title: String,
+    pub priority: Option<Priority>,
+    /// タグ名（重複は除く）
+    pub tag_names: Vec<String>,
+    pub due_date: Option<NaiveDate>,
+    pub context: Option<TaskContext>,
+}
+
+/// QuickAddParser - インライン記法のパーサー
+///
+/// `add "Pay rent #finance !high @friday"`のように、1行のテキストからタスクを読み取ります。
+/// 空白で区切った語のうち、次の語をタイトルから除いて読み取ります。
+/// - `!high`: 優先度（`low`・`medium`・`high`・`critical`、大文字小文字は区別しない）
+/// - `#work`: タグ名
+/// - `@2026-02-01`・`@today`・`@tomorrow`・`@friday`・`@fri`・`@+3d`・`@+2w`・`@+3bd`: 期限日
+///   （`bd`は土日と設定した祝日を除く営業日数）
+/// - `@home`: コンテキスト（期限日として読めない場合）
+///
+/// 曜日は今日より後で最も近い日を表します（今日が金曜日の場合、`@friday`は次の金曜日）。
+/// 記号だけの語（`#`など）はタイトルの一部として扱います。
+/// 同じ種類の記法が複数ある場合は、後の語を優先します（タグはすべて付与します）。
+pub struct QuickAddParser {
+    today: NaiveDate,
+    calendar: BusinessCalendar,
+}
+
+impl QuickAddParser {
+    /// 新しいQuickAddParserを作成
+    ///
+    /// # Arguments
+    /// * `today` - 相対的な期限日（`@tomorrow`など）の基準日
+    pub fn new(today: NaiveDate) -> Self {
+        Self {
+            today,
+            calendar: BusinessCalendar::default(),
+        }
+    }
+
+    /// 営業日単位の期限日（`@+3bd`）で休日とする祝日のカレンダーを設定
+    pub fn with_business_calendar(mut self, calendar: BusinessCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// 1行をタスクとして読み取る
+    ///
+    /// # Returns
+    /// * `Err` - 優先度・期限日・コンテキストが不正な場合、またはタイトルが空の場合
+    pub fn parse(&self, input: &str) -> Result<QuickAdd> {
+        let mut task = QuickAdd::default();
+        let mut title = Vec::new();
+
+        for word in input.split_whitespace() {
+            if let Some(priority) = word.strip_prefix('!').filter(|s| !s.is_empty()) {
+                task.priority = Some(priority.parse()?);
+            } else if let Some(tag) = word.strip_prefix('#').filter(|s| !s.is_empty()) {
+                if !task.tag_names.iter().any(|name| name == tag) {
+                    task.tag_names.push(tag.to_string());
+                }
+            } else if let Some(value) = word.strip_prefix('@').filter(|s| !s.is_empty()) {
+                match self.parse_due_date(value)? {
+                    Some(date) => task.due_date = Some(date),
+                    None => task.context = Some(TaskContext::new(value)?),
+                }
+            } else {
+                title.push(word);
+            }
+        }
+
+        if title.is_empty() {
+            bail!(Msg::ErrorTaskTitleMissing.text());
+        }
+        task.title = title.join(" ");
+        Ok(task)
+    }
+
+    /// 複数行の入力を、1行を1つのタスクとして読み取る（空行は無視する）
+    ///
+    /// # Returns
+    /// * `Err` - 読み取れない行がある場合（エラーには行番号を含む）
+    pub fn parse_lines(&self, input: &str) -> Result<Vec<QuickAdd>> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                self.parse(line)
+                    .with_context(|| Msg::ErrorInvalidTaskOnLine.format(&[&(index + 1)]))
+            })
+            .collect()
+    }
+
+    /// `@`の後の値を期限日として読み取る（期限日の記法でない場合はNone）
+    fn parse_due_date(&self, value: &str) -> Result<Option<NaiveDate>> {
+        RelativeDate::parse(value)?
+            .map(|date| date.resolve(self.today, &self.calendar))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2026-01-14（水曜日）を今日とするパーサー
+    fn parser() -> QuickAddParser {
+        QuickAddParser::new(date(2026, 1, 14))
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_all_inline_syntax() {
+        // Act
+        let task = parser()
+            .parse("Pay rent #finance !high @friday @home")
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            task,
+            QuickAdd {
+                title: "Pay rent".to_string(),
+                priority: Some(Priority::High),
+                tag_names: vec!["finance".to_string()],
+                due_date: Some(date(2026, 1, 16)),
+                context: Some(TaskContext::new("@home").unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_title_only() {
+        let task = parser().parse("  Buy   milk ").unwrap();
+
+        assert_eq!(
+            task,
+            QuickAdd {
+                title: "Buy milk".to_string(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_keeps_bare_symbols_in_title() {
+        let task = parser().parse("Buy milk # 2 ! @").unwrap();
+
+        assert_eq!(task.title, "Buy milk # 2 ! @");
+        assert_eq!(task.priority, None);
+        assert!(task.tag_names.is_empty());
+        assert_eq!(task.due_date, None);
+    }
+
+    #[test]
+    fn test_parse_words_in_any_order() {
+        let task = parser().parse("!low #a Call #b mom @tomorrow").unwrap();
+
+        assert_eq!(task.title, "Call mom");
+        assert_eq!(task.priority, Some(Priority::Low));
+        assert_eq!(task.tag_names, vec!["a", "b"]);
+        assert_eq!(task.due_date, Some(date(2026, 1, 15)));
+    }
+
+    #[test]
+    fn test_parse_priority_case_insensitive() {
+        for (input, expected) in [
+            ("x !low", Priority::Low),
+            ("x !Medium", Priority::Medium),
+            ("x !HIGH", Priority::High),
+            ("x !critical", Priority::Critical),
+        ] {
+            assert_eq!(parser().parse(input).unwrap().priority, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_parse_later_words_take_precedence() {
+        let task = parser()
+            .parse("Report !low !high @today @2026-03-01 #work #work")
+            .unwrap();
+
+        assert_eq!(task.priority, Some(Priority::High));
+        assert_eq!(task.due_date, Some(date(2026, 3, 1)));
+        assert_eq!(task.tag_names, vec!["work"]);
+    }
+
+    #[test]
+    fn test_parse_relative_due_dates() {
+        let due = |input: &str| parser().parse(input).unwrap().due_date.unwrap();
+
+        assert_eq!(due("x @today"), date(2026, 1, 14));
+        assert_eq!(due("x @Tomorrow"), date(2026, 1, 15));
+        assert_eq!(due("x @+3d"), date(2026, 1, 17));
+        assert_eq!(due("x @+2w"), date(2026, 1, 28));
+        assert_eq!(due("x @2026-02-01"), date(2026, 2, 1));
+    }
+
+    #[test]
+    fn test_parse_business_day_due_dates() {
+        // 2026-01-14は水曜日。1-19（月）を祝日とする
+        let parser =
+            parser().with_business_calendar(BusinessCalendar::with_holidays([date(2026, 1, 19)]));
+        let due = |input: &str| parser.parse(input).unwrap().due_date.unwrap();
+
+        assert_eq!(due("x @+1bd"), date(2026, 1, 15));
+        assert_eq!(due("x @+2bd"), date(2026, 1, 16));
+        assert_eq!(due("x @+3bd"), date(2026, 1, 20));
+        assert!(parser.parse("x @+0bd").is_err());
+        assert!(parser.parse("x @+-1bd").is_err());
+    }
+
+    #[test]
+    fn test_parse_weekday_is_next_occurrence_after_today() {
+        let due = |input: &str| parser().parse(input).unwrap().due_date.unwrap();
+
+        assert_eq!(due("x @thursday"), date(2026, 1, 15));
+        assert_eq!(due("x @fri"), date(2026, 1, 16));
+        assert_eq!(due("x @SUN"), date(2026, 1, 18));
+        assert_eq!(due("x @monday"), date(2026, 1, 19));
+        // 今日と同じ曜日は翌週
+        assert_eq!(due("x @wednesday"), date(2026, 1, 21));
+    }
+
+    #[test]
+    fn test_parse_non_date_is_context() {
+        let task = parser().parse("Call @Office").unwrap();
+
+        assert_eq!(task.context, Some(TaskContext::new("@office").unwrap()));
+        assert_eq!(task.due_date, None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // 不正な優先度
+        assert!(parser().parse("Fix bug !urgent").is_err());
+        // 期限日にもコンテキストにもならない値
+        assert!(parser().parse("Meet @2026/02/01").is_err());
+        // 不正な期間
+        assert!(parser().parse("Meet @+0d").is_err());
+        assert!(parser().parse("Meet @+3m").is_err());
+        assert!(parser().parse("Meet @+d").is_err());
+        // タイトルがない
+        assert!(parser().parse("#work !low @today").is_err());
+        assert!(parser().parse("   ").is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_skips_blank_lines() {
+        // Act
+        let tasks = parser()
+            .parse_lines("First\n\n   \nSecond #home\n")
+            .unwrap();
+        let error = parser().parse_lines("First\n\nSecond !soon").unwrap_err();
+
+        // Assert
+        let titles: Vec<_> = tasks.iter().map(|task| task.title.as_str()).collect();
+        assert_eq!(titles, vec!["First", "Second"]);
+        assert_eq!(error.to_string(), "Invalid task on line 3");
+    }
+}
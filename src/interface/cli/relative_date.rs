@@ -0,0 +1,149 @@
+use crate::{domain::services::BusinessCalendar, interface::i18n::Msg};
+use anyhow::{Result, anyhow};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// 日付の指定（日付、または今日を基準にした相対的な日付）
+///
+/// 今日の日付は設定のタイムゾーン、営業日は設定の祝日に依存するため、
+/// コマンドの実行時に`resolve`で日付にします。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeDate {
+    /// 日付を直接指定（`2026-02-01`）
+    Date(NaiveDate),
+    /// 今日（`today`）
+    Today,
+    /// 明日（`tomorrow`）
+    Tomorrow,
+    /// 今日より後で最も近い曜日（`friday`・`fri`、今日が金曜日の場合は次の金曜日）
+    Weekday(Weekday),
+    /// 今日からの期間（`+3d`・`+2w`）
+    FromToday(Duration),
+    /// 今日からの営業日数（`+3bd`、土日と設定した祝日を除く）
+    BusinessDays(u32),
+}
+
+impl RelativeDate {
+    /// 値を日付の指定として読み取る（日付の記法でない場合はNone）
+    ///
+    /// # Returns
+    /// * `Err` - `+`で始まるが、期間として不正な場合
+    pub fn parse(value: &str) -> Result<Option<Self>> {
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Ok(Some(Self::Date(date)));
+        }
+        if let Some(period) = value.strip_prefix('+') {
+            return Self::parse_period(value, period).map(Some);
+        }
+
+        let date = match value.to_lowercase().as_str() {
+            "today" => Self::Today,
+            "tomorrow" => Self::Tomorrow,
+            name => match name.parse::<Weekday>() {
+                Ok(weekday) => Self::Weekday(weekday),
+                Err(_) => return Ok(None),
+            },
+        };
+        Ok(Some(date))
+    }
+
+    /// `3d`・`2w`・`3bd`のような今日からの期間を読み取る
+    fn parse_period(value: &str, period: &str) -> Result<Self> {
+        let invalid = || anyhow!(Msg::ErrorInvalidPeriod.format(&[&value]));
+        if let Some(amount) = period.strip_suffix("bd") {
+            let amount: u32 = amount.parse().map_err(|_| invalid())?;
+            if amount == 0 {
+                return Err(invalid());
+            }
+            return Ok(Self::BusinessDays(amount));
+        }
+        let (amount, unit) = period.split_at(period.len().saturating_sub(1));
+        let amount: i64 = amount.parse().map_err(|_| invalid())?;
+        match unit {
+            "d" => Duration::try_days(amount),
+            "w" => Duration::try_weeks(amount),
+            _ => None,
+        }
+        .filter(|_| amount > 0)
+        .map(Self::FromToday)
+        .ok_or_else(invalid)
+    }
+
+    /// 今日の日付を基準に日付を求める
+    ///
+    /// # Arguments
+    /// * `today` - 相対的な日付の基準日
+    /// * `calendar` - 営業日数（`+3bd`）で休日とする祝日のカレンダー
+    pub fn resolve(self, today: NaiveDate, calendar: &BusinessCalendar) -> Result<NaiveDate> {
+        match self {
+            Self::Date(date) => Ok(date),
+            Self::Today => Ok(today),
+            Self::Tomorrow => Ok(today + Duration::days(1)),
+            Self::Weekday(weekday) => {
+                let days_ahead = (weekday.num_days_from_monday() + 6
+                    - today.weekday().num_days_from_monday())
+                    % 7
+                    + 1;
+                Ok(today + Duration::days(i64::from(days_ahead)))
+            }
+            Self::FromToday(period) => today.checked_add_signed(period).ok_or_else(|| {
+                anyhow!(Msg::ErrorPeriodTooLong.format(&[&format!("+{}d", period.num_days())]))
+            }),
+            Self::BusinessDays(days) => calendar.add_business_days(today, days),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// 2026-01-14（水曜日）を今日として日付を求める
+    fn resolve(value: &str) -> NaiveDate {
+        RelativeDate::parse(value)
+            .unwrap()
+            .unwrap()
+            .resolve(date(2026, 1, 14), &BusinessCalendar::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_relative_dates() {
+        assert_eq!(resolve("2026-02-01"), date(2026, 2, 1));
+        assert_eq!(resolve("today"), date(2026, 1, 14));
+        assert_eq!(resolve("Tomorrow"), date(2026, 1, 15));
+        assert_eq!(resolve("fri"), date(2026, 1, 16));
+        assert_eq!(resolve("wednesday"), date(2026, 1, 21));
+        assert_eq!(resolve("+3d"), date(2026, 1, 17));
+        assert_eq!(resolve("+2w"), date(2026, 1, 28));
+        assert_eq!(resolve("+3bd"), date(2026, 1, 19));
+    }
+
+    #[test]
+    fn test_resolve_business_days_skips_holidays() {
+        // Arrange
+        let calendar = BusinessCalendar::with_holidays([date(2026, 1, 19)]);
+
+        // Act
+        let due = RelativeDate::BusinessDays(3).resolve(date(2026, 1, 14), &calendar);
+
+        // Assert
+        assert_eq!(due.unwrap(), date(2026, 1, 20));
+    }
+
+    #[test]
+    fn test_parse_non_date_is_none() {
+        assert_eq!(RelativeDate::parse("home").unwrap(), None);
+        assert_eq!(RelativeDate::parse("2026/02/01").unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_periods() {
+        for invalid in ["+", "+d", "+0d", "+-1d", "+3h", "+0bd", "+-1bd", "+bd"] {
+            assert!(RelativeDate::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    application::use_cases::time_entry::report_estimate_accuracy::ReportEstimateAccuracyUseCase,
+    domain::{
+        tag::repository::TagRepository, task::repository::TaskRepository,
+        time_entry::repository::TimeEntryRepository,
+    },
+    interface::{cli::args::ReportCommands, presentation::Presenter},
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// reportコマンドを処理
+pub async fn handle_report_command(
+    command: ReportCommands,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    time_entry_repo: Arc<dyn TimeEntryRepository>,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    match command {
+        ReportCommands::Accuracy { by, all } => {
+            let report = ReportEstimateAccuracyUseCase::new(task_repo, tag_repo, time_entry_repo)
+                .execute(by.into(), all)
+                .await?;
+            presenter.present_estimate_accuracy(&report)
+        }
+    }
+}
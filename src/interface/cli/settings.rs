@@ -0,0 +1,256 @@
+use crate::{
+    application::use_cases::task::list_tasks::TaskSortKey,
+    domain::{
+        clock::SystemClock,
+        services::{BusinessCalendar, UrgencyWeights},
+        task::{
+            stale_policy::StalePolicy,
+            status_transition::StatusTransitionPolicy,
+            value_objects::{DueDateBuckets, Estimate, PastDueDatePolicy},
+        },
+    },
+    interface::cli::{
+        args::Filter,
+        display::{TaskColumn, TaskTemplate},
+    },
+};
+use anyhow::Result;
+use chrono::Weekday;
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// CLIコマンドの動作設定
+///
+/// 設定ファイルの内容をもとにエントリーポイントで構築され、各ハンドラに渡されます。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliSettings {
+    /// タグ名指定時に存在しないタグを自動作成するか
+    pub auto_create_tags: bool,
+    /// `task list`の既定の表示設定
+    pub list: ListSettings,
+    /// タスクのステータス遷移のポリシー
+    pub transition_policy: StatusTransitionPolicy,
+    /// 新しいタスクに過去の期限日を指定した場合の扱い
+    pub past_due_date: PastDueDatePolicy,
+    /// タスクの追加時に似ているタスクを確認する類似度のしきい値（Noneの場合は確認しない）
+    pub duplicate_threshold: Option<f64>,
+    /// GitHubとの同期の設定
+    pub github: GithubSettings,
+    /// 定期実行の設定
+    pub cron: CronSettings,
+    /// 1日の作業可能時間（`yaru workload`で過負荷を判定する基準）
+    pub daily_capacity: Estimate,
+    /// 緊急度順で並べるときの重み
+    pub urgency: UrgencyWeights,
+    /// 現在のコンテキストを記録するファイル
+    pub context_state_path: PathBuf,
+    /// フォーカス中のタスクを記録するファイル
+    pub focus_state_path: PathBuf,
+    /// 放置されているタスクを判定するポリシー
+    pub stale: StalePolicy,
+    /// 期限日の分類の境界（統計や期限日でのグループ化に使用）
+    pub due_date_buckets: DueDateBuckets,
+    /// 営業日のカレンダー（土日と設定した祝日を休日とする）
+    pub calendar: BusinessCalendar,
+    /// 「今日」の判定に使用するClock（`[display] timezone`のタイムゾーン）
+    pub clock: SystemClock,
+    /// 期限日を選択するカレンダーの週の始まりの曜日
+    pub week_start: Weekday,
+    /// 期限切れのタスクに自動で付けるタグの名前（Noneの場合は付けない）
+    pub overdue_tag: Option<String>,
+    /// リモートストレージとの同期の設定
+    pub remote: RemoteSettings,
+    /// メールからの取り込みの設定
+    #[cfg(feature = "mail")]
+    pub mail: MailSettings,
+    /// データベースへの書き込みを禁止するか（`--read-only`・`[storage] read_only`）
+    pub read_only: bool,
+}
+
+impl Default for CliSettings {
+    fn default() -> Self {
+        Self {
+            auto_create_tags: true,
+            list: ListSettings::default(),
+            transition_policy: StatusTransitionPolicy::default(),
+            past_due_date: PastDueDatePolicy::default(),
+            duplicate_threshold: None,
+            github: GithubSettings::default(),
+            cron: CronSettings::default(),
+            daily_capacity: Estimate::DEFAULT_DAILY_CAPACITY,
+            urgency: UrgencyWeights::default(),
+            context_state_path: PathBuf::from("context.json"),
+            focus_state_path: PathBuf::from("focus.json"),
+            stale: StalePolicy::default(),
+            due_date_buckets: DueDateBuckets::default(),
+            calendar: BusinessCalendar::default(),
+            clock: SystemClock::default(),
+            week_start: Weekday::Mon,
+            overdue_tag: None,
+            remote: RemoteSettings::default(),
+            #[cfg(feature = "mail")]
+            mail: MailSettings::default(),
+            read_only: false,
+        }
+    }
+}
+
+/// `task list`の既定の表示設定
+///
+/// 設定ファイルの`[list]`セクションから構築されます。CLIフラグが指定された場合はそちらが優先されます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListSettings {
+    /// 並び順
+    pub sort: TaskSortKey,
+    /// 絞り込み条件
+    pub filters: Vec<Filter>,
+    /// 表示する列（空の場合は表示形式の既定の列）
+    pub columns: Vec<TaskColumn>,
+    /// 完了済みタスクを既定で非表示にするか
+    pub hide_completed: bool,
+    /// 名前を付けて保存したテンプレート（`--template`に名前で指定）
+    pub templates: BTreeMap<String, TaskTemplate>,
+}
+
+impl ListSettings {
+    /// `--template`の値からテンプレートを取得
+    ///
+    /// 保存したテンプレートの名前に一致する場合はそのテンプレートを、それ以外は値そのものをテンプレートとして使います。
+    pub fn template(&self, name_or_template: &str) -> Result<TaskTemplate> {
+        match self.templates.get(name_or_template) {
+            Some(template) => Ok(template.clone()),
+            None => name_or_template.parse(),
+        }
+    }
+}
+
+impl Default for ListSettings {
+    fn default() -> Self {
+        Self {
+            sort: TaskSortKey::default(),
+            filters: vec![],
+            columns: vec![],
+            hide_completed: true,
+            templates: BTreeMap::new(),
+        }
+    }
+}
+
+/// GitHubとの同期の設定
+///
+/// 設定ファイルの`[github]`セクション（トークンは`GITHUB_TOKEN`環境変数も可）から構築されます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GithubSettings {
+    /// アクセストークン
+    pub token: Option<String>,
+    /// APIのベースURL
+    pub api_url: String,
+}
+
+impl Default for GithubSettings {
+    fn default() -> Self {
+        Self {
+            token: None,
+            api_url: "https://api.github.com".to_string(),
+        }
+    }
+}
+
+/// リモートストレージとの同期（`yaru sync remote`）の設定
+///
+/// 設定ファイルの`[remote]`セクション（認証情報は環境変数も可）から構築されます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSettings {
+    /// スナップショットの保存先（Noneの場合は設定されていない）
+    pub target: Option<RemoteTarget>,
+    /// 前回のpush・pullで確認したスナップショットのETagを記録するファイル
+    pub state_path: PathBuf,
+}
+
+impl Default for RemoteSettings {
+    fn default() -> Self {
+        Self {
+            target: None,
+            state_path: PathBuf::from("remote_state.json"),
+        }
+    }
+}
+
+/// スナップショットの保存先（必須の項目は使用時に確認する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteTarget {
+    /// S3互換のストレージ
+    S3 {
+        endpoint: String,
+        bucket: Option<String>,
+        key: String,
+        region: String,
+        access_key_id: Option<String>,
+        secret_access_key: Option<String>,
+    },
+    /// WebDAVサーバー
+    WebDav {
+        url: Option<String>,
+        user: Option<String>,
+        password: Option<String>,
+    },
+}
+
+/// メールからのタスクの取り込み（`yaru ingest mail`）の設定
+///
+/// 設定ファイルの`[mail]`セクション（パスワードは`YARU_IMAP_PASSWORD`環境変数も可）から構築されます。
+/// CLIフラグが指定された場合はそちらが優先されます。
+#[cfg(feature = "mail")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailSettings {
+    /// 読み込むmaildirのフォルダ
+    pub maildir: Option<PathBuf>,
+    /// IMAPサーバーのホスト名
+    pub imap_host: Option<String>,
+    /// IMAPサーバーのポート番号
+    pub imap_port: u16,
+    /// IMAPのユーザー名
+    pub imap_user: Option<String>,
+    /// IMAPのパスワード
+    pub imap_password: Option<String>,
+    /// IMAPで読み込むフォルダ
+    pub imap_folder: String,
+    /// 件名にこの文字列を含むメールだけを取り込む
+    pub subject_filter: Option<String>,
+    /// 取り込んだタスクに付けるタグ
+    pub tag: Option<String>,
+}
+
+#[cfg(feature = "mail")]
+impl Default for MailSettings {
+    fn default() -> Self {
+        Self {
+            maildir: None,
+            imap_host: None,
+            imap_port: 993,
+            imap_user: None,
+            imap_password: None,
+            imap_folder: "INBOX".to_string(),
+            subject_filter: None,
+            tag: None,
+        }
+    }
+}
+
+/// 定期実行（`yaru cron run`）の設定
+///
+/// 設定ファイルの`[cron]`セクションから構築されます。
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CronSettings {
+    /// 繰り返しのタスクの次の回を、期限日の何日前に作成するか
+    pub recurrence_lead_days: u32,
+    /// 期限切れの通知に使うコマンド（Noneの場合は通知しない、空の場合はOSの通知機能を使う）
+    pub notify_command: Option<Vec<String>>,
+    /// 完了してからこの日数が経ったタスクをアーカイブする（Noneの場合はアーカイブしない）
+    pub archive_after_days: Option<u32>,
+    /// アーカイブしたタスクを追記するファイル
+    pub archive_path: PathBuf,
+    /// 前回の実行日を記録するファイル
+    pub state_path: PathBuf,
+    /// 繰り返しのタスクの期限日と期限切れの判定で休日を飛ばすか（`[calendar]`の`business_days`）
+    pub business_days: bool,
+}
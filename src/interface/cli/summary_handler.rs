@@ -0,0 +1,33 @@
+use crate::{
+    application::use_cases::task::summarize_tasks::SummarizeTasksUseCase,
+    domain::{clock::Clock, task::repository::TaskRepository},
+    interface::{
+        cli::{focus_handler::load_focus, settings::CliSettings},
+        presentation::Presenter,
+    },
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// summaryコマンドを処理
+///
+/// 期限切れ・今日期限・未完了のタスク数と、フォーカス中のタスク（`yaru focus`）を表示します。
+/// 1行の表示はプロンプトから毎回呼び出すため、完了の連続記録は計算しません。
+pub async fn handle_summary(
+    oneline: bool,
+    task_repo: Arc<dyn TaskRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let use_case = SummarizeTasksUseCase::new(task_repo).with_clock(Arc::new(settings.clock));
+    let use_case = if oneline {
+        use_case
+    } else {
+        use_case.with_completion_streak()
+    };
+    let summary = use_case.execute().await?;
+    let focus = load_focus(&settings.focus_state_path)?
+        .map(|state| state.status_text(settings.clock.now()));
+    presenter.present_summary(&summary, focus.as_deref(), oneline)?;
+    Ok(())
+}
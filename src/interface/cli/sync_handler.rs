@@ -0,0 +1,520 @@
+use crate::{
+    application::{
+        dto::TaskDTO,
+        use_cases::task::{
+            sync_databases::{
+                ConflictResolution, ConflictResolver, Replica, SyncChangeKind, SyncConflict,
+                SyncDatabasesUseCase, SyncSide,
+            },
+            sync_issues::SyncIssuesUseCase,
+        },
+    },
+    domain::{
+        clock::SystemClock, tag::repository::TagRepository, task::repository::TaskRepository,
+    },
+    interface::{
+        cli::{
+            args::{RemoteCommands, SyncCommands, SyncPreference},
+            db_handler::read_passphrase,
+            display::{
+                db_status_table::format_size,
+                format::{format_local_time, format_status},
+            },
+            settings::{CliSettings, RemoteSettings, RemoteTarget},
+        },
+        github::GithubClient,
+        i18n::Msg,
+        persistence::sea_orm::SeaOrmDatabaseAdmin,
+        presentation::Presenter,
+        remote::{
+            PutCondition, PutOutcome, RemoteStore, open_snapshot, s3::S3Store, seal_snapshot,
+            webdav::WebDavStore,
+        },
+    },
+};
+use anyhow::{Context, Result};
+use inquire::Select;
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::{self, ErrorKind, IsTerminal},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use strum::{EnumIter, IntoEnumIterator};
+
+/// syncコマンドを処理
+pub async fn handle_sync_command(
+    command: SyncCommands,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let use_case = SyncIssuesUseCase::new(task_repo, tag_repo)
+        .with_transition_policy(settings.transition_policy.clone())
+        .with_clock(Arc::new(settings.clock));
+    match command {
+        SyncCommands::Github { repo, push } => {
+            let client = GithubClient::new(
+                &repo,
+                &settings.github.api_url,
+                settings.github.token.clone(),
+            )?;
+            let report = use_case.sync(&client, push).await?;
+
+            for task in &report.pushed {
+                presenter.present_success(&Msg::SyncTaskPushed.format(&[&task.id, &task.title]))?;
+            }
+            for task in &report.imported {
+                presenter
+                    .present_success(&Msg::SyncTaskImported.format(&[&task.id, &task.title]))?;
+            }
+            for task in &report.updated {
+                presenter
+                    .present_success(&Msg::SyncTaskUpdated.format(&[&task.id, &task.title]))?;
+            }
+            for warning in &report.warnings {
+                presenter.present_warning(warning)?;
+            }
+            presenter.present_success(&Msg::SyncSummary.format(&[
+                &report.imported.len(),
+                &report.updated.len(),
+                &report.pushed.len(),
+            ]))?;
+            Ok(())
+        }
+        SyncCommands::File { .. } => {
+            anyhow::bail!(Msg::ErrorFileSyncRequiresSqlite.text())
+        }
+        SyncCommands::Remote { .. } => {
+            anyhow::bail!(Msg::ErrorRemoteSyncRequiresSqlite.text())
+        }
+    }
+}
+
+/// 他のデータベースファイルとタスクを同期する
+///
+/// 両方のデータベースで変更されたタスクは、`--prefer`が指定されていればそれに従い、
+/// なければ端末から実行したときだけどちらを残すか選択します（それ以外はスキップします）。
+pub async fn handle_sync_file(
+    local: Replica,
+    remote: Replica,
+    prefer: Option<SyncPreference>,
+    dry_run: bool,
+    clock: SystemClock,
+    presenter: Arc<dyn Presenter>,
+) -> Result<()> {
+    let resolver = CliConflictResolver {
+        prefer,
+        interactive: io::stdin().is_terminal() && io::stdout().is_terminal(),
+        presenter: presenter.clone(),
+    };
+    let report = SyncDatabasesUseCase::new(local, remote)
+        .with_clock(Arc::new(clock))
+        .sync(&resolver, dry_run)
+        .await?;
+
+    for change in &report.changes {
+        let msg = match change.kind {
+            SyncChangeKind::Created => Msg::SyncFileCreated,
+            SyncChangeKind::Updated => Msg::SyncFileUpdated,
+            SyncChangeKind::Deleted => Msg::SyncFileDeleted,
+        };
+        presenter.present_info(&msg.format(&[&change.title, &side_name(change.side)]))?;
+    }
+    for title in &report.skipped {
+        presenter.present_warning(&Msg::SyncFileConflictSkipped.format(&[title]))?;
+    }
+
+    let count = |side: SyncSide| {
+        report
+            .changes
+            .iter()
+            .filter(|change| change.side == side)
+            .count()
+    };
+    let (local_count, remote_count) = (count(SyncSide::Local), count(SyncSide::Remote));
+    if dry_run {
+        presenter.present_info(&Msg::SyncFileDryRunSummary.format(&[&local_count, &remote_count]))
+    } else {
+        presenter.present_success(&Msg::SyncFileSummary.format(&[
+            &local_count,
+            &remote_count,
+            &report.skipped.len(),
+        ]))
+    }
+}
+
+/// リモートストレージとスナップショットをやり取りする
+///
+/// データベースファイル全体を扱うため、接続は受け取って閉じてから終了します
+/// （pullではファイルを置き換える前に閉じる必要があるため）。
+pub async fn handle_sync_remote(
+    command: RemoteCommands,
+    db: DatabaseConnection,
+    database_path: &Path,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let store = remote_store(&settings.remote)?;
+    match command {
+        RemoteCommands::Push { force } => {
+            push_snapshot(store.as_ref(), db, presenter, settings, force).await
+        }
+        RemoteCommands::Pull { force } => {
+            pull_snapshot(
+                store.as_ref(),
+                db,
+                database_path,
+                presenter,
+                settings,
+                force,
+            )
+            .await
+        }
+    }
+}
+
+/// データベースのスナップショットを暗号化して送信する
+///
+/// 前回のpush・pull以降にリモートのスナップショットが変更されていれば（`--force`なしでは）送信しません。
+async fn push_snapshot(
+    store: &dyn RemoteStore,
+    db: DatabaseConnection,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+    force: bool,
+) -> Result<()> {
+    let state_path = &settings.remote.state_path;
+    let state = load_remote_state(state_path)?;
+
+    // 書き込み中の変更も含めるため、一度別のファイルに書き出してから読み込む
+    let snapshot_path =
+        std::env::temp_dir().join(format!("yaru-snapshot-{:016x}.db", fastrand::u64(..)));
+    let result = SeaOrmDatabaseAdmin::new(db.clone())
+        .back_up_to(&snapshot_path)
+        .await;
+    db.close().await?;
+    result?;
+    let database = fs::read(&snapshot_path);
+    let _ = fs::remove_file(&snapshot_path);
+    let database =
+        database.with_context(|| Msg::ErrorReadSnapshot.format(&[&snapshot_path.display()]))?;
+
+    let sealed = seal_snapshot(&read_passphrase(true)?, &database)?;
+    let size = sealed.len();
+    let condition = match (force, state.etag) {
+        (true, _) => PutCondition::Always,
+        (false, Some(etag)) => PutCondition::Matches(etag),
+        (false, None) => PutCondition::Absent,
+    };
+    let etag = match store.put(sealed, condition).await? {
+        PutOutcome::Stored(Some(etag)) => Some(etag),
+        PutOutcome::Stored(None) => store.etag().await?,
+        PutOutcome::PreconditionFailed => {
+            anyhow::bail!(Msg::ErrorRemoteChanged.format(&[&store.location()]))
+        }
+    };
+    save_remote_state(state_path, &RemoteState { etag })?;
+
+    presenter
+        .present_success(&Msg::RemotePushed.format(&[&format_size(size as u64), &store.location()]))
+}
+
+/// リモートのスナップショットでデータベースを置き換える
+///
+/// 置き換える前に、現在のデータベースを`<ファイル名>.<日時>.bak`に書き出します。
+async fn pull_snapshot(
+    store: &dyn RemoteStore,
+    db: DatabaseConnection,
+    database_path: &Path,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+    force: bool,
+) -> Result<()> {
+    let state_path = &settings.remote.state_path;
+    let state = load_remote_state(state_path)?;
+    let object = store
+        .get()
+        .await?
+        .with_context(|| Msg::ErrorSnapshotNotFound.format(&[&store.location()]))?;
+    if !force && object.etag.is_some() && object.etag == state.etag {
+        db.close().await?;
+        return presenter.present_info(&Msg::RemoteUpToDate.format(&[&store.location()]));
+    }
+    let database = open_snapshot(&read_passphrase(false)?, &object.body)?;
+
+    // データベース全体を置き換えるため、ファイル名を入力させて確認する
+    let file_name = database_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| database_path.display().to_string());
+    let confirmed = presenter.confirm_typed(
+        &Msg::ConfirmRemotePull.format(&[&database_path.display(), &store.location()]),
+        &file_name,
+    );
+    if !matches!(confirmed, Ok(true)) {
+        db.close().await?;
+        confirmed?;
+        return presenter.present_info(Msg::RemotePullCancelled.text());
+    }
+
+    let backup_path = sibling_path(
+        database_path,
+        &format!("{}.bak", chrono::Local::now().format("%Y%m%d-%H%M%S")),
+    );
+    let result = SeaOrmDatabaseAdmin::new(db.clone())
+        .back_up_to(&backup_path)
+        .await;
+    db.close().await?;
+    result?;
+
+    // 書き込みが途中で失敗しても元のファイルが壊れないよう、隣に書いてから置き換える
+    let pulled_path = sibling_path(database_path, "pull");
+    fs::write(&pulled_path, &database)
+        .with_context(|| Msg::ErrorWriteFile.format(&[&pulled_path.display()]))?;
+    for suffix in ["wal", "shm"] {
+        match fs::remove_file(sibling_path(database_path, suffix)) {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                return Err(e).context(Msg::ErrorRemoveJournal.text());
+            }
+            _ => {}
+        }
+    }
+    fs::rename(&pulled_path, database_path)
+        .with_context(|| Msg::ErrorReplaceFile.format(&[&database_path.display()]))?;
+    save_remote_state(state_path, &RemoteState { etag: object.etag })?;
+
+    presenter
+        .present_success(&Msg::RemotePulled.format(&[&store.location(), &backup_path.display()]))
+}
+
+/// 設定からスナップショットの保存先を作成する
+fn remote_store(settings: &RemoteSettings) -> Result<Box<dyn RemoteStore>> {
+    match &settings.target {
+        None => anyhow::bail!(Msg::ErrorRemoteNotConfigured.text()),
+        Some(RemoteTarget::S3 {
+            endpoint,
+            bucket,
+            key,
+            region,
+            access_key_id,
+            secret_access_key,
+        }) => {
+            let bucket = bucket
+                .as_deref()
+                .context(Msg::ErrorS3BucketRequired.text())?;
+            let (Some(access_key_id), Some(secret_access_key)) = (access_key_id, secret_access_key)
+            else {
+                anyhow::bail!(Msg::ErrorS3CredentialsMissing.text());
+            };
+            Ok(Box::new(S3Store::new(
+                endpoint,
+                bucket,
+                key,
+                region,
+                access_key_id.clone(),
+                secret_access_key.clone(),
+            )?))
+        }
+        Some(RemoteTarget::WebDav {
+            url,
+            user,
+            password,
+        }) => {
+            let url = url.as_deref().context(Msg::ErrorWebDavUrlRequired.text())?;
+            Ok(Box::new(WebDavStore::new(
+                url,
+                user.clone(),
+                password.clone(),
+            )?))
+        }
+    }
+}
+
+/// データベースファイルと同じ場所にある、名前の末尾に`.{suffix}`を付けたファイル
+fn sibling_path(database_path: &Path, suffix: &str) -> PathBuf {
+    let mut name = database_path.as_os_str().to_os_string();
+    name.push(format!(".{}", suffix));
+    PathBuf::from(name)
+}
+
+/// 前回のpush・pullの状態（状態ファイルに保存）
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct RemoteState {
+    /// 前回確認したスナップショットのETag
+    etag: Option<String>,
+}
+
+/// 状態ファイルを読み込む（存在しない場合は初期状態）
+fn load_remote_state(path: &Path) -> Result<RemoteState> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .with_context(|| Msg::ErrorInvalidStateFile.format(&[&path.display()])),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(RemoteState::default()),
+        Err(e) => Err(e).with_context(|| Msg::ErrorReadStateFile.format(&[&path.display()])),
+    }
+}
+
+/// 状態ファイルを書き込む
+fn save_remote_state(path: &Path, state: &RemoteState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| Msg::ErrorCreateDirectory.format(&[&parent.display()]))?;
+    }
+    fs::write(path, serde_json::to_string(state)?)
+        .with_context(|| Msg::ErrorWriteStateFile.format(&[&path.display()]))
+}
+
+/// データベースの表示名
+fn side_name(side: SyncSide) -> &'static str {
+    match side {
+        SyncSide::Local => Msg::SyncSideLocal.text(),
+        SyncSide::Remote => Msg::SyncSideOther.text(),
+    }
+}
+
+/// 競合を`--prefer`の指定、または対話的な選択で解決する
+struct CliConflictResolver {
+    prefer: Option<SyncPreference>,
+    interactive: bool,
+    presenter: Arc<dyn Presenter>,
+}
+
+impl ConflictResolver for CliConflictResolver {
+    fn resolve(&self, conflict: &SyncConflict) -> Result<ConflictResolution> {
+        match self.prefer {
+            Some(SyncPreference::Local) => return Ok(ConflictResolution::KeepLocal),
+            Some(SyncPreference::Other) => return Ok(ConflictResolution::KeepRemote),
+            Some(SyncPreference::Newer) => return Ok(newer(conflict)),
+            None if !self.interactive => return Ok(ConflictResolution::Skip),
+            None => {}
+        }
+
+        self.presenter
+            .present_warning(&Msg::SyncFileConflict.format(&[&conflict.title()]))?;
+        for (side, task) in [
+            (SyncSide::Local, &conflict.local),
+            (SyncSide::Remote, &conflict.remote),
+        ] {
+            self.presenter
+                .present_info(&describe_version(side, task.as_ref()))?;
+        }
+        let choice = Select::new(
+            Msg::PromptSyncConflict.text(),
+            ConflictChoice::iter().collect(),
+        )
+        .with_vim_mode(true)
+        .prompt()
+        .unwrap_or(ConflictChoice::Skip);
+        Ok(match choice {
+            ConflictChoice::KeepLocal => ConflictResolution::KeepLocal,
+            ConflictChoice::KeepOther => ConflictResolution::KeepRemote,
+            ConflictChoice::Skip => ConflictResolution::Skip,
+        })
+    }
+}
+
+/// 更新日時が新しい方を残す（片方で削除されている場合は残っている方）
+fn newer(conflict: &SyncConflict) -> ConflictResolution {
+    match (&conflict.local, &conflict.remote) {
+        (Some(local), Some(remote)) if remote.updated_at > local.updated_at => {
+            ConflictResolution::KeepRemote
+        }
+        (None, Some(_)) => ConflictResolution::KeepRemote,
+        _ => ConflictResolution::KeepLocal,
+    }
+}
+
+/// 競合しているタスクの片方の内容を1行で表す
+fn describe_version(side: SyncSide, task: Option<&TaskDTO>) -> String {
+    match task {
+        Some(task) => Msg::SyncFileConflictVersion.format(&[
+            &side_name(side),
+            &task.title,
+            &format_status(task.status),
+            &format_local_time(&task.updated_at),
+        ]),
+        None => Msg::SyncFileConflictDeleted.format(&[&side_name(side)]),
+    }
+}
+
+/// 競合の解決方法の選択肢
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+enum ConflictChoice {
+    KeepLocal,
+    KeepOther,
+    Skip,
+}
+
+impl std::fmt::Display for ConflictChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            Self::KeepLocal => Msg::SyncFileKeepLocal,
+            Self::KeepOther => Msg::SyncFileKeepOther,
+            Self::Skip => Msg::SyncFileSkip,
+        };
+        write!(f, "{}", msg.text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_remote_state_round_trip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("state").join("remote_state.json");
+        let state = RemoteState {
+            etag: Some("\"v1\"".to_string()),
+        };
+
+        // Act
+        let initial = load_remote_state(&path).unwrap();
+        save_remote_state(&path, &state).unwrap();
+
+        // Assert
+        assert_eq!(initial, RemoteState::default());
+        assert_eq!(load_remote_state(&path).unwrap(), state);
+    }
+
+    #[test]
+    fn test_remote_store_requires_configuration() {
+        let unconfigured = RemoteSettings::default();
+        let without_credentials = RemoteSettings {
+            target: Some(RemoteTarget::S3 {
+                endpoint: "https://s3.us-east-1.amazonaws.com".to_string(),
+                bucket: Some("backup".to_string()),
+                key: "yaru.db.enc".to_string(),
+                region: "us-east-1".to_string(),
+                access_key_id: Some("id".to_string()),
+                secret_access_key: None,
+            }),
+            ..RemoteSettings::default()
+        };
+        let webdav = RemoteSettings {
+            target: Some(RemoteTarget::WebDav {
+                url: Some("https://dav.example.com/yaru.db.enc".to_string()),
+                user: None,
+                password: None,
+            }),
+            ..RemoteSettings::default()
+        };
+
+        assert!(remote_store(&unconfigured).is_err());
+        assert!(remote_store(&without_credentials).is_err());
+        assert_eq!(
+            remote_store(&webdav).unwrap().location(),
+            "https://dav.example.com/yaru.db.enc"
+        );
+        assert_eq!(
+            sibling_path(Path::new("/data/yaru.db"), "pull"),
+            Path::new("/data/yaru.db.pull")
+        );
+    }
+}
@@ -7,7 +7,15 @@ use crate::{
         },
     },
     domain::tag::repository::TagRepository,
-    interface::{cli::args::TagCommands, presentation::Presenter},
+    interface::{
+        cli::{
+            args::TagCommands,
+            id_resolver::{resolve_exact_tag_id, resolve_tag_id},
+            picker::select_tag_id,
+        },
+        i18n::Msg,
+        presentation::Presenter,
+    },
 };
 use anyhow::{Context, Result};
 use inquire::{Editor, MultiSelect, Text, validator};
@@ -17,12 +25,16 @@ use std::sync::Arc;
 struct AddTagParams {
     name: Option<String>,
     description: Option<String>,
+    parent: Option<String>,
+    get_or_create: bool,
 }
 
 /// タグ編集のパラメータ
 struct EditTagParams {
     name: Option<String>,
     description: Option<String>,
+    parent: Option<String>,
+    no_parent: bool,
 }
 
 /// タグコマンドを処理
@@ -34,8 +46,18 @@ pub async fn handle_tag_command(
     match command {
         TagCommands::List => handle_list(tag_repo, presenter).await,
         TagCommands::Show { id } => handle_show(tag_repo, presenter, id).await,
-        TagCommands::Add { name, description } => {
-            let params = AddTagParams { name, description };
+        TagCommands::Add {
+            name,
+            description,
+            parent,
+            get_or_create,
+        } => {
+            let params = AddTagParams {
+                name,
+                description,
+                parent,
+                get_or_create,
+            };
             handle_add(tag_repo, presenter, params).await
         }
         TagCommands::Delete { id } => handle_delete(tag_repo, presenter, id).await,
@@ -43,8 +65,15 @@ pub async fn handle_tag_command(
             id,
             name,
             description,
+            parent,
+            no_parent,
         } => {
-            let params = EditTagParams { name, description };
+            let params = EditTagParams {
+                name,
+                description,
+                parent,
+                no_parent,
+            };
             handle_edit(tag_repo, presenter, id, params).await
         }
     }
@@ -67,8 +96,13 @@ async fn handle_list(
 async fn handle_show(
     tag_repo: Arc<dyn TagRepository>,
     presenter: Arc<dyn Presenter>,
-    id: i32,
+    id: Option<String>,
 ) -> Result<()> {
+    let id = match id {
+        Some(id) => resolve_tag_id(tag_repo.clone(), &id).await?,
+        None => select_tag_id(tag_repo.clone(), Msg::SelectTagToShow.text()).await?,
+    };
+
     let use_case = ShowTagUseCase::new(tag_repo);
     let tag = use_case.execute(id).await?;
 
@@ -88,13 +122,13 @@ async fn handle_add(
 
     let (final_name, final_description) = if is_interactive {
         // 対話モード
-        let n = Text::new("Enter tag name")
+        let n = Text::new(Msg::PromptTagName.text())
             .with_validator(validator::MinLengthValidator::new(1))
             .prompt()
-            .context("Failed to input tag name")?;
+            .context(Msg::ErrorInputTagName.text())?;
 
         let d = params.description.unwrap_or_else(|| {
-            Editor::new("Enter tag description")
+            Editor::new(Msg::PromptTagDescription.text())
                 .prompt()
                 .unwrap_or_default()
         });
@@ -106,6 +140,11 @@ async fn handle_add(
         (params.name.unwrap(), params.description.unwrap_or_default())
     };
 
+    let parent_id = match params.parent {
+        Some(parent) => Some(resolve_tag_id(tag_repo.clone(), &parent).await?),
+        None => None,
+    };
+
     // DTOを構築
     let dto = CreateTagDTO {
         name: final_name,
@@ -114,16 +153,24 @@ async fn handle_add(
         } else {
             Some(final_description)
         },
+        parent_id,
     };
 
     // Use Caseを実行
     let use_case = AddTagUseCase::new(tag_repo);
+    if params.get_or_create {
+        let (tag, created) = use_case.get_or_create(dto).await?;
+        let msg = if created {
+            Msg::TagAdded
+        } else {
+            Msg::TagExists
+        };
+        presenter.present_success(&msg.format(&[&tag.id, &tag.path]))?;
+        return Ok(());
+    }
     let created_tag = use_case.execute(dto).await?;
 
-    presenter.present_success(&format!(
-        "Tag added: [{}] {}",
-        created_tag.id, created_tag.name
-    ))?;
+    presenter.present_success(&Msg::TagAdded.format(&[&created_tag.id, &created_tag.path]))?;
 
     Ok(())
 }
@@ -132,20 +179,25 @@ async fn handle_add(
 async fn handle_delete(
     tag_repo: Arc<dyn TagRepository>,
     presenter: Arc<dyn Presenter>,
-    id: i32,
+    id: Option<String>,
 ) -> Result<()> {
+    let id = match id {
+        Some(id) => resolve_exact_tag_id(tag_repo.clone(), &id).await?,
+        None => select_tag_id(tag_repo.clone(), Msg::SelectTagToDelete.text()).await?,
+    };
+
     // 確認
-    let confirm = presenter.confirm(&format!("Delete tag ID {}?", id), false)?;
+    let confirm = presenter.confirm(&Msg::ConfirmDeleteTag.format(&[&id]), false)?;
 
     if !confirm {
-        presenter.present_success("Deletion cancelled")?;
+        presenter.present_info(Msg::DeletionCancelled.text())?;
         return Ok(());
     }
 
     let use_case = DeleteTagUseCase::new(tag_repo);
     use_case.execute(id).await?;
 
-    presenter.present_success(&format!("Tag ID {} deleted", id))?;
+    presenter.present_success(&Msg::TagDeleted.format(&[&id]))?;
 
     Ok(())
 }
@@ -153,11 +205,25 @@ async fn handle_delete(
 async fn handle_edit(
     tag_repo: Arc<dyn TagRepository>,
     presenter: Arc<dyn Presenter>,
-    id: i32,
+    id: Option<String>,
     params: EditTagParams,
 ) -> Result<()> {
+    let id = match id {
+        Some(id) => resolve_exact_tag_id(tag_repo.clone(), &id).await?,
+        None => select_tag_id(tag_repo.clone(), Msg::SelectTagToEdit.text()).await?,
+    };
+
     // 引数モードか対話モードか判定
-    let is_interactive = params.name.is_none() && params.description.is_none();
+    let is_interactive = params.name.is_none()
+        && params.description.is_none()
+        && params.parent.is_none()
+        && !params.no_parent;
+
+    let parent_id = match params.parent {
+        Some(parent) => Some(Some(resolve_tag_id(tag_repo.clone(), &parent).await?)),
+        None if params.no_parent => Some(None),
+        None => None,
+    };
 
     let (final_name, final_description) = if is_interactive {
         // 対話モード: 既存のタグ情報を取得
@@ -168,32 +234,29 @@ async fn handle_edit(
         println!(); // 空行を追加
 
         // 編集するフィールドを選択
-        let field_options = vec!["Name", "Description"];
+        let field_options = vec![Msg::FieldName.text(), Msg::FieldDescription.text()];
 
-        let selected_fields = MultiSelect::new(
-            "Select fields to edit (Space to select, Enter to confirm)",
-            field_options,
-        )
-        .with_vim_mode(true)
-        .prompt()
-        .unwrap_or_default();
+        let selected_fields = MultiSelect::new(Msg::PromptSelectFields.text(), field_options)
+            .with_vim_mode(true)
+            .prompt()
+            .unwrap_or_default();
 
         // 選択されたフィールドのみ編集
-        let new_name = if selected_fields.contains(&"Name") {
+        let new_name = if selected_fields.contains(&Msg::FieldName.text()) {
             Some(
-                Text::new("Name:")
+                Text::new(Msg::PromptName.text())
                     .with_default(&current_tag.name)
                     .with_validator(validator::MinLengthValidator::new(1))
                     .prompt()
-                    .context("Failed to input name")?,
+                    .context(Msg::ErrorInputName.text())?,
             )
         } else {
             None
         };
 
-        let new_description = if selected_fields.contains(&"Description") {
+        let new_description = if selected_fields.contains(&Msg::FieldDescription.text()) {
             Some(
-                Editor::new("Enter description")
+                Editor::new(Msg::PromptDescription.text())
                     .with_predefined_text(current_tag.description.as_deref().unwrap_or(""))
                     .prompt()
                     .unwrap_or_default(),
@@ -212,16 +275,14 @@ async fn handle_edit(
     let dto = UpdateTagDTO {
         name: final_name,
         description: final_description,
+        parent_id,
     };
 
     // Use Caseを実行
     let use_case = EditTagUseCase::new(tag_repo);
     let updated_tag = use_case.execute(id, dto).await?;
 
-    presenter.present_success(&format!(
-        "Tag updated: [{}] {}",
-        updated_tag.id, updated_tag.name
-    ))?;
+    presenter.present_success(&Msg::TagUpdated.format(&[&updated_tag.id, &updated_tag.path]))?;
 
     Ok(())
 }
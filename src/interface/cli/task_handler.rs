@@ -2,28 +2,71 @@ use crate::{
     application::{
         dto::task_dto::{CreateTaskDTO, UpdateTaskDTO},
         use_cases::task::{
-            add_task::AddTaskUseCase, delete_task::DeleteTaskUseCase, edit_task::EditTaskUseCase,
-            list_tasks::ListTasksUseCase, search_tasks::SearchTasksUseCase,
-            show_stats::ShowStatsUseCase, show_task::ShowTaskUseCase,
+            add_task::AddTaskUseCase,
+            complete_task::CompleteTaskUseCase,
+            delete_task::DeleteTaskUseCase,
+            edit_task::EditTaskUseCase,
+            find_similar_tasks::FindSimilarTasksUseCase,
+            list_stale_tasks::ListStaleTasksUseCase,
+            list_tasks::{ListTasksUseCase, TaskFilter, TaskSortKey},
+            manage_checklist::ManageChecklistUseCase,
+            manage_git_links::ManageGitLinksUseCase,
+            pick_task::PickTaskUseCase,
+            search_tasks::{SearchFilter, SearchTasksUseCase, highlight_terms},
+            show_stats::ShowStatsUseCase,
+            show_task::ShowTaskUseCase,
+            snooze_task::SnoozeTaskUseCase,
         },
     },
     domain::{
+        clock::Clock,
         tag::{repository::TagRepository, value_objects::TagId},
         task::{
             repository::TaskRepository,
-            value_objects::{Priority, Status},
+            stale_policy::StalePolicy,
+            value_objects::{
+                Assignee, CustomField, DueDate, Estimate, GitLink, Priority, Recurrence, Status,
+                TaskContext, TaskTitle,
+            },
         },
+        unit_of_work::UnitOfWorkFactory,
     },
     interface::{
-        cli::args::{Filter, SearchFieldArg, TaskCommands},
+        cli::{
+            args::{
+                ChecklistCommands, Filter, FilterKey, GroupByArg, ListFormatArg, SearchFieldArg,
+                SearchSortArg, TaskCommands,
+            },
+            context_handler::current_context,
+            display::{
+                TaskColumn, TaskListFormat, TaskListView, TaskTemplate,
+                format::{format_checklist_items, format_date, format_git_link, format_priority},
+            },
+            focus_handler::start_focus,
+            id_resolver::{resolve_exact_task_id, resolve_tag_ids, resolve_task_id},
+            picker::{IdOption, select_task_id},
+            quick_add::QuickAddParser,
+            relative_date::RelativeDate,
+            settings::CliSettings,
+        },
+        clipboard::{read_clipboard, split_title_and_description},
+        git::GitRepository,
+        i18n::Msg,
         presentation::Presenter,
+        url::{find_task_urls, open_url},
     },
 };
 use anyhow::{Context, Result};
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use inquire::{DateSelect, Editor, MultiSelect, Select, Text, validator};
-use std::{collections::HashSet, sync::Arc};
-use strum::IntoEnumIterator;
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    io::{self, IsTerminal, Read},
+    path::PathBuf,
+    sync::Arc,
+};
+use strum::{EnumIter, IntoEnumIterator};
 
 /// タスク追加のパラメータ
 struct AddTaskParams {
@@ -32,7 +75,20 @@ struct AddTaskParams {
     status: Option<Status>,
     priority: Option<Priority>,
     tags: Option<Vec<i32>>,
+    tag_names: Option<Vec<String>>,
     due_date: Option<NaiveDate>,
+    force: bool,
+    fields: Vec<CustomField>,
+    repeat: Option<Recurrence>,
+    estimate: Option<Estimate>,
+    context: Option<TaskContext>,
+    assignee: Option<Assignee>,
+}
+
+/// 1行ごとにタスクを追加する入力
+enum BatchInput {
+    File(PathBuf),
+    Stdin,
 }
 
 /// タスク編集のパラメータ
@@ -44,27 +100,49 @@ struct EditTaskParams {
     tags: Option<Vec<i32>>,
     due_date: Option<NaiveDate>,
     clear_due_date: bool,
+    reopen: bool,
+    fields: Vec<CustomField>,
+    unset_fields: Vec<String>,
+    repeat: Option<Recurrence>,
+    no_repeat: bool,
+    estimate: Option<Estimate>,
+    clear_estimate: bool,
+    context: Option<TaskContext>,
+    clear_context: bool,
+    assignee: Option<Assignee>,
+    clear_assignee: bool,
+}
+
+/// タスク完了のパラメータ
+struct DoneTaskParams {
+    id: Option<String>,
+    follow_up: bool,
+    follow_up_title: Option<String>,
 }
 
 /// タスク検索のパラメータ
 struct SearchParams {
     keywords: Option<String>,
     field: SearchFieldArg,
+    sort: SearchSortArg,
+    status: Option<Status>,
+    priority: Option<Priority>,
+    tag: Option<Vec<i32>>,
+    due_before: Option<NaiveDate>,
+    due_after: Option<NaiveDate>,
+    created_after: Option<DateTime<Utc>>,
+    updated_since: Option<DateTime<Utc>>,
+    custom_fields: Vec<CustomField>,
 }
 
-/// タグ選択用のラッパー型
-///
-/// `inquire::MultiSelect`で使用するために、タグIDと表示文字列をペアで保持します。
-/// 文字列パースに依存せず、型安全にタグIDを取得できます。
-#[derive(Debug, Clone)]
-struct TagOption {
-    id: i32,
-    display: String,
-}
-
-impl std::fmt::Display for TagOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display)
+/// `--tags`で指定したタグID（前方一致も可）を解決する
+async fn resolve_tag_option(
+    tag_repo: &Arc<dyn TagRepository>,
+    ids: Option<Vec<String>>,
+) -> Result<Option<Vec<i32>>> {
+    match ids {
+        Some(ids) => Ok(Some(resolve_tag_ids(tag_repo.clone(), &ids).await?)),
+        None => Ok(None),
     }
 }
 
@@ -103,42 +181,160 @@ async fn validate_tag_ids(tag_repo: &Arc<dyn TagRepository>, tag_ids: &[i32]) ->
     // 存在しないIDを検出
     for id in tag_ids {
         if !found_ids.contains(id) {
-            anyhow::bail!("Tag ID does not exist: {}", id);
+            anyhow::bail!(Msg::ErrorTagIdNotExist.format(&[id]));
         }
     }
 
     Ok(())
 }
 
+/// カスタムフィールドをDTOのキーと値の対応に変換
+fn custom_field_map(fields: &[CustomField]) -> BTreeMap<String, String> {
+    fields
+        .iter()
+        .map(|field| (field.key().to_string(), field.value().to_string()))
+        .collect()
+}
+
 /// タスクコマンドを処理
 pub async fn handle_task_command(
     command: TaskCommands,
     task_repo: Arc<dyn TaskRepository>,
     tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
     presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
 ) -> Result<()> {
     match command {
-        TaskCommands::List { filter } => handle_list(task_repo, tag_repo, presenter, filter).await,
-        TaskCommands::Show { id } => handle_show(task_repo, tag_repo, presenter, id).await,
+        TaskCommands::List {
+            filter,
+            partial_tag,
+            group_by,
+            format,
+            sort,
+            columns,
+            template,
+            all,
+            all_contexts,
+            due_before,
+            due_after,
+            created_after,
+            updated_since,
+        } => {
+            // CLIフラグが指定されていない項目は設定ファイルの値を使う
+            let filters = filter.unwrap_or_else(|| settings.list.filters.clone());
+            let mut filters = to_task_filters(filters, partial_tag)?;
+            filters.extend(due_before.map(TaskFilter::DueBefore));
+            filters.extend(due_after.map(TaskFilter::DueAfter));
+            filters.extend(created_after.map(TaskFilter::CreatedAfter));
+            filters.extend(updated_since.map(TaskFilter::UpdatedSince));
+            // ステータスを明示的に絞り込んでいない場合のみ、完了済みタスクを除外する
+            let has_status_filter = filters
+                .iter()
+                .any(|filter| matches!(filter, TaskFilter::Status(_)));
+            if settings.list.hide_completed && !all && !has_status_filter {
+                filters.push(TaskFilter::ExcludeStatus(Status::Completed));
+            }
+            if !all {
+                filters.push(TaskFilter::ExcludeSnoozed);
+            }
+            filters.extend(current_context(settings, all_contexts)?.map(TaskFilter::Context));
+            let sort = sort.map(Into::into).unwrap_or(settings.list.sort);
+            let output = match template {
+                Some(template) => ListOutput::Template(settings.list.template(&template)?),
+                None => ListOutput::Table(build_list_view(format, columns, settings)),
+            };
+            let use_case = ListTasksUseCase::new(task_repo, tag_repo)
+                .with_urgency_weights(settings.urgency)
+                .with_due_date_buckets(settings.due_date_buckets)
+                .with_clock(Arc::new(settings.clock));
+            handle_list(use_case, presenter, filters, group_by, sort, output).await
+        }
+        TaskCommands::Show { id, raw } => {
+            handle_show(task_repo, tag_repo, presenter, settings, id, raw).await
+        }
         TaskCommands::Add {
             title,
             description,
             status,
             priority,
             tags,
+            tag_names,
             due_date,
+            force,
+            fields,
+            repeat,
+            estimate,
+            context,
+            assignee,
+            from_file,
+            stdin,
+            from_clipboard,
         } => {
+            let batch = match from_file {
+                Some(path) => Some(BatchInput::File(path)),
+                None => stdin.then_some(BatchInput::Stdin),
+            };
+            let (title, description) = if from_clipboard {
+                let (title, description) = split_title_and_description(&read_clipboard()?)?;
+                (Some(title), description)
+            } else {
+                (title, description)
+            };
             let params = AddTaskParams {
                 title,
                 description,
                 status,
                 priority,
-                tags,
-                due_date,
+                tags: resolve_tag_option(&tag_repo, tags).await?,
+                tag_names,
+                due_date: resolve_due_date(settings, due_date)?,
+                force,
+                fields,
+                repeat,
+                estimate,
+                context,
+                assignee,
             };
-            handle_add(task_repo, tag_repo, presenter, params).await
+            match batch {
+                Some(input) => {
+                    handle_add_batch(tag_repo, unit_of_work, presenter, params, input, settings)
+                        .await
+                }
+                None => {
+                    handle_add(
+                        task_repo,
+                        tag_repo,
+                        unit_of_work,
+                        presenter,
+                        params,
+                        settings,
+                    )
+                    .await
+                }
+            }
+        }
+        TaskCommands::Delete { id } => handle_delete(task_repo, tag_repo, presenter, id).await,
+        TaskCommands::Done {
+            id,
+            follow_up,
+            follow_up_title,
+        } => {
+            let params = DoneTaskParams {
+                id,
+                follow_up,
+                follow_up_title,
+            };
+            handle_done(
+                task_repo,
+                tag_repo,
+                unit_of_work,
+                presenter,
+                settings,
+                params,
+            )
+            .await
         }
-        TaskCommands::Delete { id } => handle_delete(task_repo, presenter, id).await,
         TaskCommands::Edit {
             id,
             title,
@@ -148,63 +344,265 @@ pub async fn handle_task_command(
             tags,
             due_date,
             clear_due_date,
+            reopen,
+            fields,
+            unset_fields,
+            repeat,
+            no_repeat,
+            estimate,
+            clear_estimate,
+            context,
+            clear_context,
+            assignee,
+            clear_assignee,
         } => {
             let params = EditTaskParams {
                 title,
                 description,
                 status,
                 priority,
-                tags,
-                due_date,
+                tags: resolve_tag_option(&tag_repo, tags).await?,
+                due_date: resolve_due_date(settings, due_date)?,
                 clear_due_date,
+                reopen,
+                fields,
+                unset_fields,
+                repeat,
+                no_repeat,
+                estimate,
+                clear_estimate,
+                context,
+                clear_context,
+                assignee,
+                clear_assignee,
             };
-            handle_edit(task_repo, tag_repo, presenter, id, params).await
+            handle_edit(
+                task_repo,
+                tag_repo,
+                unit_of_work,
+                presenter,
+                settings,
+                id,
+                params,
+            )
+            .await
+        }
+        TaskCommands::Check { id, command } => {
+            handle_checklist(task_repo, presenter, settings, &id, command).await
+        }
+        TaskCommands::Snooze { id, until } => {
+            handle_snooze(task_repo, presenter, settings, &id, Some(until)).await
+        }
+        TaskCommands::Unsnooze { id } => {
+            handle_snooze(task_repo, presenter, settings, &id, None).await
+        }
+        TaskCommands::Open { id, nth } => {
+            handle_open(task_repo, tag_repo, presenter, &id, nth).await
+        }
+        TaskCommands::LinkGit { id } => handle_link_git(task_repo, presenter, settings, &id).await,
+        TaskCommands::Stats { flow } => {
+            let use_case = ShowStatsUseCase::new(task_repo, tag_repo)
+                .with_due_date_buckets(settings.due_date_buckets)
+                .with_clock(Arc::new(settings.clock));
+            handle_stats(use_case, presenter, flow).await
         }
-        TaskCommands::Stats => handle_stats(task_repo, tag_repo, presenter).await,
-        TaskCommands::Search { keywords, field } => {
-            let params = SearchParams { keywords, field };
+        TaskCommands::Stale { days } => {
+            let policy = days
+                .map(StalePolicy::uniform)
+                .unwrap_or(settings.stale.clone());
+            handle_stale(task_repo, tag_repo, presenter, policy, settings.clock.now()).await
+        }
+        TaskCommands::Pick {
+            filter,
+            partial_tag,
+            weighted,
+            all_contexts,
+            start,
+            focus,
+            seed,
+        } => {
+            let mut filters = to_task_filters(filter.unwrap_or_default(), partial_tag)?;
+            filters.extend(current_context(settings, all_contexts)?.map(TaskFilter::Context));
+            let use_case = PickTaskUseCase::new(task_repo.clone(), tag_repo.clone())
+                .with_urgency_weighting(weighted)
+                .with_urgency_weights(settings.urgency)
+                .with_clock(Arc::new(settings.clock))
+                .with_seed(seed);
+            let action = if start {
+                Some(PickAction::Start)
+            } else if focus {
+                Some(PickAction::Focus)
+            } else {
+                None
+            };
+            let repos = (task_repo, tag_repo);
+            handle_pick(use_case, repos, presenter, settings, &filters, action).await
+        }
+        TaskCommands::Search {
+            keywords,
+            field,
+            sort,
+            status,
+            priority,
+            tag,
+            due_before,
+            due_after,
+            created_after,
+            updated_since,
+            custom_fields,
+        } => {
+            let params = SearchParams {
+                keywords,
+                field,
+                sort,
+                status,
+                priority,
+                tag: resolve_tag_option(&tag_repo, tag).await?,
+                due_before,
+                due_after,
+                created_after,
+                updated_since,
+                custom_fields,
+            };
             handle_search(task_repo, tag_repo, presenter, params).await
         }
     }
 }
 
+/// タスク一覧の出力方法
+enum ListOutput {
+    /// テーブルで表示
+    Table(TaskListView),
+    /// テンプレートに従って1タスク1行で表示（グループ化はしない）
+    Template(TaskTemplate),
+}
+
 /// タスク一覧を表示
 async fn handle_list(
-    task_repo: Arc<dyn TaskRepository>,
-    tag_repo: Arc<dyn TagRepository>,
+    use_case: ListTasksUseCase,
     presenter: Arc<dyn Presenter>,
-    _filter: Option<Vec<Filter>>,
+    filters: Vec<TaskFilter>,
+    group_by: Option<GroupByArg>,
+    sort: TaskSortKey,
+    output: ListOutput,
 ) -> Result<()> {
-    let use_case = ListTasksUseCase::new(task_repo, tag_repo);
-    let tasks = use_case.execute().await?;
+    let view = match output {
+        ListOutput::Table(view) => view,
+        ListOutput::Template(template) => {
+            let tasks = use_case.execute_filtered(&filters, sort).await?;
+            return presenter.present_task_lines(&tasks, &template);
+        }
+    };
 
-    // TODO: フィルタ処理を実装
-    presenter.present_task_list(&tasks)?;
+    if let Some(group_by) = group_by {
+        let groups = use_case
+            .execute_grouped(group_by.into(), &filters, sort)
+            .await?;
+        presenter.present_task_groups(&groups, &view)?;
+        return Ok(());
+    }
+
+    let tasks = use_case.execute_filtered(&filters, sort).await?;
+    presenter.present_task_list(&tasks, &view)?;
 
     Ok(())
 }
 
+/// 表示形式と列の指定から一覧の表示方法を決める（列の指定がない場合は設定の列）
+pub fn build_list_view(
+    format: Option<ListFormatArg>,
+    columns: Option<Vec<TaskColumn>>,
+    settings: &CliSettings,
+) -> TaskListView {
+    let columns = match (columns, format) {
+        (Some(columns), _) => columns,
+        // コンパクト表示は列が決まっているため設定の列は使わない
+        (None, Some(ListFormatArg::Compact)) => vec![],
+        (None, _) => settings.list.columns.clone(),
+    };
+    TaskListView::new(format.map(Into::into).unwrap_or_default(), columns)
+        .with_stale_policy(settings.stale.clone())
+        .with_now(settings.clock.now())
+}
+
+/// CLIのフィルタ引数をユースケースの絞り込み条件に変換
+///
+/// # Arguments
+/// * `filters` - `key:value`形式でパース済みのフィルタ
+/// * `partial_tag` - タグ名を部分一致で扱うか
+fn to_task_filters(filters: Vec<Filter>, partial_tag: bool) -> Result<Vec<TaskFilter>> {
+    filters
+        .into_iter()
+        .map(|filter| match filter.key {
+            FilterKey::Status => Ok(TaskFilter::Status(filter.value.parse()?)),
+            FilterKey::Tag => Ok(TaskFilter::TagName {
+                name: filter.value,
+                partial: partial_tag,
+            }),
+            FilterKey::Assignee => Ok(TaskFilter::Assignee(filter.value.parse()?)),
+        })
+        .collect()
+}
+
 /// タスクの詳細を表示
 async fn handle_show(
     task_repo: Arc<dyn TaskRepository>,
     tag_repo: Arc<dyn TagRepository>,
     presenter: Arc<dyn Presenter>,
-    id: i32,
+    settings: &CliSettings,
+    id: Option<String>,
+    raw: bool,
 ) -> Result<()> {
-    let use_case = ShowTaskUseCase::new(task_repo, tag_repo);
-    let task = use_case.execute(id).await?;
+    let id = match id {
+        Some(id) => resolve_task_id(task_repo.clone(), &id).await?,
+        None => {
+            select_task_id(
+                task_repo.clone(),
+                tag_repo.clone(),
+                Msg::SelectTaskToShow.text(),
+            )
+            .await?
+        }
+    };
 
-    presenter.present_task_detail(&task)?;
+    let use_case = ShowTaskUseCase::new(task_repo, tag_repo).with_clock(Arc::new(settings.clock));
+    let detail = use_case.execute_with_related(id).await?;
+
+    presenter.present_task_detail_with_related(&detail, raw)?;
 
     Ok(())
 }
 
+/// `--due-date`で指定した日付を、設定の今日の日付と祝日を基準に求める
+fn resolve_due_date(
+    settings: &CliSettings,
+    due_date: Option<RelativeDate>,
+) -> Result<Option<NaiveDate>> {
+    due_date
+        .map(|date| date.resolve(settings.clock.today(), &settings.calendar))
+        .transpose()
+}
+
+/// 期限日を選択するカレンダー
+///
+/// 週の始まりの曜日は`[display] week_start`、選択した日付の表示は`[display] date_format`に従い、
+/// 今日は`[display] timezone`の日付から始めます。
+fn due_date_select(settings: &CliSettings) -> DateSelect<'static> {
+    DateSelect::new(Msg::PromptSelectDueDate.text())
+        .with_starting_date(settings.clock.today())
+        .with_week_start(settings.week_start)
+        .with_formatter(&|date| format_date(&Some(date)))
+        .with_help_message(Msg::DateSelectHelp.text())
+}
+
 /// 新しいタスクを追加
 async fn handle_add(
     task_repo: Arc<dyn TaskRepository>,
     tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
     presenter: Arc<dyn Presenter>,
     params: AddTaskParams,
+    settings: &CliSettings,
 ) -> Result<()> {
     // 引数モードか対話モードか判定
     let is_interactive = params.title.is_none();
@@ -217,53 +615,56 @@ async fn handle_add(
     let (final_title, final_description, final_status, final_priority, final_tags, final_due_date) =
         if is_interactive {
             // 対話モード
-            let t = Text::new("Enter task title")
+            let t = Text::new(Msg::PromptTaskTitle.text())
                 .with_validator(validator::MinLengthValidator::new(1))
                 .prompt()
-                .context("Failed to input task title")?;
+                .context(Msg::ErrorInputTaskTitle.text())?;
 
             let d = params.description.unwrap_or_else(|| {
-                Editor::new("Enter task description")
+                Editor::new(Msg::PromptTaskDescription.text())
                     .prompt()
                     .unwrap_or_default()
             });
 
             let s = params.status.unwrap_or_else(|| {
-                Select::new("Select status", Status::iter().collect::<Vec<_>>())
-                    .with_vim_mode(true)
-                    .prompt()
-                    .unwrap_or(Status::Pending)
+                Select::new(
+                    Msg::PromptSelectStatus.text(),
+                    Status::iter().collect::<Vec<_>>(),
+                )
+                .with_vim_mode(true)
+                .prompt()
+                .unwrap_or(Status::Pending)
             });
 
             let p = params.priority.unwrap_or_else(|| {
-                Select::new("Select priority", Priority::iter().collect::<Vec<_>>())
-                    .with_vim_mode(true)
-                    .prompt()
-                    .unwrap_or(Priority::Medium)
+                Select::new(
+                    Msg::PromptSelectPriority.text(),
+                    Priority::iter().collect::<Vec<_>>(),
+                )
+                .with_vim_mode(true)
+                .prompt()
+                .unwrap_or(Priority::Medium)
             });
 
-            // タグ選択（対話モード）
-            let tags = if params.tags.is_some() {
+            // タグ選択（対話モード、タグIDまたはタグ名が指定されていない場合のみ）
+            let tags = if params.tags.is_some() || params.tag_names.is_some() {
                 params.tags.unwrap_or_default()
             } else {
                 // 利用可能なタグを取得
                 let available_tags = tag_repo.find_all().await?;
                 if !available_tags.is_empty() {
-                    let tag_options: Vec<TagOption> = available_tags
+                    let tag_options: Vec<IdOption> = available_tags
                         .iter()
-                        .map(|t| TagOption {
+                        .map(|t| IdOption {
                             id: t.id().value(),
                             display: format!("[{}] {}", t.id().value(), t.name().value()),
                         })
                         .collect();
 
-                    let selected = MultiSelect::new(
-                        "Select tags (Space to select, Enter to confirm)",
-                        tag_options,
-                    )
-                    .with_vim_mode(true)
-                    .prompt()
-                    .unwrap_or_default();
+                    let selected = MultiSelect::new(Msg::PromptSelectTags.text(), tag_options)
+                        .with_vim_mode(true)
+                        .prompt()
+                        .unwrap_or_default();
 
                     // 直接IDを取得（文字列パース不要）
                     selected.iter().map(|opt| opt.id).collect()
@@ -274,12 +675,12 @@ async fn handle_add(
 
             // 期限選択
             let dd = params.due_date.or_else(|| {
-                if inquire::Confirm::new("Set due date?")
+                if inquire::Confirm::new(Msg::PromptSetDueDate.text())
                     .with_default(false)
                     .prompt()
                     .unwrap_or(false)
                 {
-                    DateSelect::new("Select due date").prompt().ok()
+                    due_date_select(settings).prompt().ok()
                 } else {
                     None
                 }
@@ -299,24 +700,184 @@ async fn handle_add(
             )
         };
 
+    // タイトルが似ている未完了タスクがあれば、追加してよいか確認する
+    if let Some(threshold) = settings.duplicate_threshold
+        && !params.force
+    {
+        let similar = FindSimilarTasksUseCase::new(task_repo, tag_repo.clone())
+            .with_threshold(threshold)
+            .execute(&final_title)
+            .await?;
+        if !similar.is_empty() {
+            let list = similar
+                .iter()
+                .map(|task| format!("  [{}] {}", task.id, task.title))
+                .collect::<Vec<_>>()
+                .join("\n");
+            presenter.present_warning(&Msg::WarningSimilarTasks.format(&[&list]))?;
+            if !presenter.confirm(Msg::ConfirmAddDuplicate.text(), false)? {
+                presenter.present_info(Msg::AddCancelled.text())?;
+                return Ok(());
+            }
+        }
+    }
+
     // DTOを構築
     let dto = CreateTaskDTO {
         title: final_title,
         description: Some(final_description),
-        status: Some(final_status.to_string()),
-        priority: Some(final_priority.to_string()),
+        status: Some(final_status),
+        priority: Some(final_priority),
         tags: final_tags,
         due_date: final_due_date,
+        custom_fields: custom_field_map(&params.fields),
+        recurrence: params.repeat.map(|rule| rule.to_string()),
+        estimate: params.estimate.map(|estimate| estimate.to_string()),
+        context: params.context.map(|context| context.to_string()),
+        assignee: params.assignee.map(|assignee| assignee.to_string()),
     };
 
     // Use Caseを実行
-    let use_case = AddTaskUseCase::new(task_repo, tag_repo);
-    let created_task = use_case.execute(dto).await?;
+    let use_case = AddTaskUseCase::new(unit_of_work)
+        .with_past_due_date_policy(settings.past_due_date)
+        .with_clock(Arc::new(settings.clock));
+    let created_task = match params.tag_names {
+        Some(tag_names) => {
+            use_case
+                .execute_with_tag_names(dto, &tag_names, settings.auto_create_tags)
+                .await?
+        }
+        None => use_case.execute(dto).await?,
+    };
+
+    presenter.present_success(&Msg::TaskAdded.format(&[&created_task.id, &created_task.title]))?;
+    if let Some(date) = created_task.due_date
+        && settings
+            .past_due_date
+            .check(&DueDate::new(date)?, settings.clock.today())?
+    {
+        presenter.present_warning(&Msg::WarningPastDueDate.format(&[&date]))?;
+    }
+
+    Ok(())
+}
+
+/// インライン記法（`QuickAddParser`）で書いたテキストからタスクを追加する
+///
+/// `#tag`のタグは`[tags] auto_create`に従って作成します。
+pub async fn handle_quick_add(
+    text: &str,
+    force: bool,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let task = QuickAddParser::new(settings.clock.today())
+        .with_business_calendar(settings.calendar.clone())
+        .parse(text)?;
+    let params = AddTaskParams {
+        title: Some(task.title),
+        description: None,
+        status: None,
+        priority: task.priority,
+        tags: None,
+        tag_names: (!task.tag_names.is_empty()).then_some(task.tag_names),
+        due_date: task.due_date,
+        force,
+        fields: Vec::new(),
+        repeat: None,
+        estimate: None,
+        context: task.context,
+        assignee: None,
+    };
+    handle_add(
+        task_repo,
+        tag_repo,
+        unit_of_work,
+        presenter,
+        params,
+        settings,
+    )
+    .await
+}
 
-    presenter.present_success(&format!(
-        "Task added: [{}] {}",
-        created_task.id, created_task.title
-    ))?;
+/// 1行を1つのタスクとして、複数のタスクを一括で追加する
+///
+/// 行ごとのインライン記法（`QuickAddParser`）で指定した優先度・期限日・コンテキストは、
+/// 引数で指定した値より優先します（タグは両方を付与します）。
+/// すべてのタスクを1つのトランザクションで追加し、似たタスクの確認は行いません。
+async fn handle_add_batch(
+    tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    params: AddTaskParams,
+    input: BatchInput,
+    settings: &CliSettings,
+) -> Result<()> {
+    let content = match input {
+        BatchInput::File(path) => fs::read_to_string(&path)
+            .with_context(|| Msg::ErrorReadFile.format(&[&path.display()]))?,
+        BatchInput::Stdin => {
+            let mut content = String::new();
+            io::stdin()
+                .read_to_string(&mut content)
+                .context(Msg::ErrorReadTasksFromStdin.text())?;
+            content
+        }
+    };
+    let lines = QuickAddParser::new(settings.clock.today())
+        .with_business_calendar(settings.calendar.clone())
+        .parse_lines(&content)?;
+    if lines.is_empty() {
+        return presenter.present_info(Msg::NoTasksToAdd.text());
+    }
+
+    // タグIDの検証（指定されている場合）
+    let tags = params.tags.unwrap_or_default();
+    validate_tag_ids(&tag_repo, &tags).await?;
+
+    let default_tag_names = params.tag_names.unwrap_or_default();
+    let tasks = lines
+        .into_iter()
+        .map(|line| {
+            let dto = CreateTaskDTO {
+                title: line.title,
+                description: params.description.clone(),
+                status: params.status,
+                priority: line.priority.or(params.priority),
+                tags: tags.clone(),
+                due_date: line.due_date.or(params.due_date),
+                custom_fields: custom_field_map(&params.fields),
+                recurrence: params.repeat.as_ref().map(|rule| rule.to_string()),
+                estimate: params
+                    .estimate
+                    .as_ref()
+                    .map(|estimate| estimate.to_string()),
+                context: line
+                    .context
+                    .or_else(|| params.context.clone())
+                    .map(|context| context.to_string()),
+                assignee: params.assignee.as_ref().map(ToString::to_string),
+            };
+            let mut tag_names = default_tag_names.clone();
+            tag_names.extend(line.tag_names);
+            (dto, tag_names)
+        })
+        .collect();
+
+    let use_case = AddTaskUseCase::new(unit_of_work)
+        .with_past_due_date_policy(settings.past_due_date)
+        .with_clock(Arc::new(settings.clock));
+    let created = use_case
+        .execute_batch(tasks, settings.auto_create_tags)
+        .await?;
+
+    for task in &created {
+        presenter.present_success(&Msg::TaskAdded.format(&[&task.id, &task.title]))?;
+    }
+    presenter.present_success(&Msg::TasksAdded.format(&[&created.len()]))?;
 
     Ok(())
 }
@@ -324,32 +885,126 @@ async fn handle_add(
 /// タスクを削除
 async fn handle_delete(
     task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
     presenter: Arc<dyn Presenter>,
-    id: i32,
+    id: Option<String>,
 ) -> Result<()> {
+    let id = match id {
+        Some(id) => resolve_exact_task_id(task_repo.clone(), &id).await?,
+        None => select_task_id(task_repo.clone(), tag_repo, Msg::SelectTaskToDelete.text()).await?,
+    };
+
     // 確認
-    let confirm = presenter.confirm(&format!("Delete task ID {}?", id), false)?;
+    let confirm = presenter.confirm(&Msg::ConfirmDeleteTask.format(&[&id]), false)?;
 
     if !confirm {
-        presenter.present_success("Deletion cancelled")?;
+        presenter.present_info(Msg::DeletionCancelled.text())?;
         return Ok(());
     }
 
     let use_case = DeleteTaskUseCase::new(task_repo);
     use_case.execute(id).await?;
 
-    presenter.present_success(&format!("Task ID {id} deleted"))?;
+    presenter.present_success(&Msg::TaskDeleted.format(&[&id]))?;
+
+    Ok(())
+}
+
+/// タスクを完了にする
+///
+/// `--follow-up`または`--follow-up-title`を指定した場合は、フォローアップのタスクも作成します。
+/// どちらも指定せずに対話的にタスクを選んだ場合は、フォローアップを作成するか確認します。
+async fn handle_done(
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+    params: DoneTaskParams,
+) -> Result<()> {
+    let is_interactive = params.id.is_none();
+    let id = match params.id {
+        Some(id) => resolve_exact_task_id(task_repo.clone(), &id).await?,
+        None => {
+            select_task_id(
+                task_repo.clone(),
+                tag_repo.clone(),
+                Msg::SelectTaskToComplete.text(),
+            )
+            .await?
+        }
+    };
+    let task = ShowTaskUseCase::new(task_repo, tag_repo)
+        .execute(id)
+        .await?;
+
+    let follow_up_title = match params.follow_up_title {
+        Some(title) => Some(title),
+        None if params.follow_up => Some(default_follow_up_title(&task.title)),
+        None if is_interactive => prompt_follow_up(presenter.as_ref(), &task.title)?,
+        None => None,
+    };
+
+    let use_case = CompleteTaskUseCase::new(unit_of_work)
+        .with_transition_policy(settings.transition_policy.clone())
+        .with_clock(Arc::new(settings.clock));
+    let completion = use_case.execute(id, follow_up_title).await?;
+
+    presenter.present_success(
+        &Msg::TaskCompleted.format(&[&completion.completed.id, &completion.completed.title]),
+    )?;
+    if let Some(follow_up) = completion.follow_up {
+        presenter
+            .present_success(&Msg::FollowUpAdded.format(&[&follow_up.id, &follow_up.title]))?;
+    }
 
     Ok(())
 }
 
+/// フォローアップのタスクの既定のタイトル（長すぎる場合は元のタイトルのまま）
+fn default_follow_up_title(title: &str) -> String {
+    let suffixed = Msg::FollowUpTitle.format(&[&title]);
+    if TaskTitle::new(&suffixed).is_ok() {
+        suffixed
+    } else {
+        title.to_string()
+    }
+}
+
+/// フォローアップのタスクを作成するか確認し、作成する場合はタイトルを入力させる
+fn prompt_follow_up(presenter: &dyn Presenter, title: &str) -> Result<Option<String>> {
+    if !presenter.confirm(Msg::ConfirmFollowUp.text(), false)? {
+        return Ok(None);
+    }
+    let title = Text::new(Msg::PromptFollowUpTitle.text())
+        .with_default(&default_follow_up_title(title))
+        .with_validator(validator::MinLengthValidator::new(1))
+        .prompt()
+        .context(Msg::ErrorInputTitle.text())?;
+    Ok(Some(title))
+}
+
 async fn handle_edit(
     task_repo: Arc<dyn TaskRepository>,
     tag_repo: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
     presenter: Arc<dyn Presenter>,
-    id: i32,
+    settings: &CliSettings,
+    id: Option<String>,
     params: EditTaskParams,
 ) -> Result<()> {
+    let id = match id {
+        Some(id) => resolve_exact_task_id(task_repo.clone(), &id).await?,
+        None => {
+            select_task_id(
+                task_repo.clone(),
+                tag_repo.clone(),
+                Msg::SelectTaskToEdit.text(),
+            )
+            .await?
+        }
+    };
+
     // タグIDの検証（指定されている場合）
     if let Some(ref ids) = params.tags {
         validate_tag_ids(&tag_repo, ids).await?;
@@ -362,8 +1017,20 @@ async fn handle_edit(
         && params.priority.is_none()
         && params.tags.is_none()
         && params.due_date.is_none()
-        && !params.clear_due_date;
-
+        && !params.clear_due_date
+        && params.fields.is_empty()
+        && params.unset_fields.is_empty()
+        && params.repeat.is_none()
+        && !params.no_repeat
+        && params.estimate.is_none()
+        && !params.clear_estimate
+        && params.context.is_none()
+        && !params.clear_context
+        && params.assignee.is_none()
+        && !params.clear_assignee;
+
+    // 対話モードでタスクを完了にした場合のみ、フォローアップの作成を確認する
+    let mut was_completed = true;
     let (
         final_title,
         final_description,
@@ -376,44 +1043,42 @@ async fn handle_edit(
         // 対話モード: 既存のタスク情報を取得
         let use_case = ShowTaskUseCase::new(task_repo.clone(), tag_repo.clone());
         let current_task = use_case.execute(id).await?;
+        was_completed = current_task.status == Status::Completed;
 
         presenter.present_task_detail(&current_task)?;
         println!(); // 空行を追加
 
         // 編集するフィールドを選択
         let field_options = vec![
-            "Title",
-            "Description",
-            "Status",
-            "Priority",
-            "Tags",
-            "Due Date",
+            Msg::FieldTitle.text(),
+            Msg::FieldDescription.text(),
+            Msg::FieldStatus.text(),
+            Msg::FieldPriority.text(),
+            Msg::FieldTags.text(),
+            Msg::FieldDueDate.text(),
         ];
 
-        let selected_fields = MultiSelect::new(
-            "Select fields to edit (Space to select, Enter to confirm)",
-            field_options,
-        )
-        .with_vim_mode(true)
-        .prompt()
-        .unwrap_or_default();
+        let selected_fields = MultiSelect::new(Msg::PromptSelectFields.text(), field_options)
+            .with_vim_mode(true)
+            .prompt()
+            .unwrap_or_default();
 
         // 選択されたフィールドのみ編集
-        let new_title = if selected_fields.contains(&"Title") {
+        let new_title = if selected_fields.contains(&Msg::FieldTitle.text()) {
             Some(
-                Text::new("Title:")
+                Text::new(Msg::PromptTitle.text())
                     .with_default(&current_task.title)
                     .with_validator(validator::MinLengthValidator::new(1))
                     .prompt()
-                    .context("Failed to input title")?,
+                    .context(Msg::ErrorInputTitle.text())?,
             )
         } else {
             None
         };
 
-        let new_description = if selected_fields.contains(&"Description") {
+        let new_description = if selected_fields.contains(&Msg::FieldDescription.text()) {
             Some(
-                Editor::new("Enter description")
+                Editor::new(Msg::PromptDescription.text())
                     .with_predefined_text(current_task.description.as_deref().unwrap_or(""))
                     .prompt()
                     .unwrap_or_default(),
@@ -422,11 +1087,10 @@ async fn handle_edit(
             None
         };
 
-        let new_status = if selected_fields.contains(&"Status") {
-            let current_status =
-                Status::try_from(current_task.status.as_str()).unwrap_or(Status::Pending);
+        let new_status = if selected_fields.contains(&Msg::FieldStatus.text()) {
+            let current_status = current_task.status;
             Some(
-                Select::new("Status:", Status::iter().collect::<Vec<_>>())
+                Select::new(Msg::PromptStatus.text(), Status::iter().collect::<Vec<_>>())
                     .with_starting_cursor(
                         Status::iter()
                             .position(|s| s == current_status)
@@ -440,30 +1104,32 @@ async fn handle_edit(
             None
         };
 
-        let new_priority = if selected_fields.contains(&"Priority") {
-            let current_priority =
-                Priority::try_from(current_task.priority.as_str()).unwrap_or(Priority::Medium);
+        let new_priority = if selected_fields.contains(&Msg::FieldPriority.text()) {
+            let current_priority = current_task.priority;
             Some(
-                Select::new("Priority:", Priority::iter().collect::<Vec<_>>())
-                    .with_starting_cursor(
-                        Priority::iter()
-                            .position(|p| p == current_priority)
-                            .unwrap_or(1),
-                    )
-                    .with_vim_mode(true)
-                    .prompt()
-                    .unwrap_or(current_priority),
+                Select::new(
+                    Msg::PromptPriority.text(),
+                    Priority::iter().collect::<Vec<_>>(),
+                )
+                .with_starting_cursor(
+                    Priority::iter()
+                        .position(|p| p == current_priority)
+                        .unwrap_or(1),
+                )
+                .with_vim_mode(true)
+                .prompt()
+                .unwrap_or(current_priority),
             )
         } else {
             None
         };
 
-        let new_tags = if selected_fields.contains(&"Tags") {
+        let new_tags = if selected_fields.contains(&Msg::FieldTags.text()) {
             let available_tags = tag_repo.find_all().await?;
             if !available_tags.is_empty() {
-                let tag_options: Vec<TagOption> = available_tags
+                let tag_options: Vec<IdOption> = available_tags
                     .iter()
-                    .map(|t| TagOption {
+                    .map(|t| IdOption {
                         id: t.id().value(),
                         display: format!("[{}] {}", t.id().value(), t.name().value()),
                     })
@@ -480,14 +1146,11 @@ async fn handle_edit(
                     .map(|(idx, _)| idx)
                     .collect();
 
-                let selected = MultiSelect::new(
-                    "Select tags (Space to select, Enter to confirm)",
-                    tag_options,
-                )
-                .with_default(&default_indices)
-                .with_vim_mode(true)
-                .prompt()
-                .ok();
+                let selected = MultiSelect::new(Msg::PromptSelectTags.text(), tag_options)
+                    .with_default(&default_indices)
+                    .with_vim_mode(true)
+                    .prompt()
+                    .ok();
 
                 // キャンセルされた場合はNoneを返し、既存のタグを保持
                 selected.map(|tags| tags.iter().map(|opt| opt.id).collect())
@@ -498,29 +1161,30 @@ async fn handle_edit(
             None
         };
 
-        let (new_due_date, clear_due_date) = if selected_fields.contains(&"Due Date") {
-            if current_task.due_date.is_some() {
+        let (new_due_date, clear_due_date) = if selected_fields.contains(&Msg::FieldDueDate.text())
+        {
+            if let Some(current_due_date) = current_task.due_date {
                 // 既存の期限がある場合、クリアするか新しい値を設定するか選択
-                let options = vec!["Clear due date", "Set new due date"];
-                let choice = Select::new("Due date:", options)
+                let clear_option = Msg::OptionClearDueDate.text();
+                let set_option = Msg::OptionSetDueDate.text();
+                let choice = Select::new(Msg::PromptDueDate.text(), vec![clear_option, set_option])
                     .with_starting_cursor(1) // デフォルトは「新しい期限を設定」
                     .with_vim_mode(true)
                     .prompt()
-                    .unwrap_or("Set new due date");
+                    .unwrap_or(set_option);
 
-                if choice == "Clear due date" {
+                if choice == clear_option {
                     (None, true)
                 } else {
-                    // SAFETY: このブロックに入るのはcurrent_task.due_date.is_some()の時のみ
-                    let new_date = DateSelect::new("Select due date")
-                        .with_default(current_task.due_date.unwrap())
+                    let new_date = due_date_select(settings)
+                        .with_default(current_due_date)
                         .prompt()
                         .ok();
                     (new_date, false)
                 }
             } else {
                 // 既存の期限がない場合、新しく設定
-                let new_date = DateSelect::new("Select due date").prompt().ok();
+                let new_date = due_date_select(settings).prompt().ok();
                 (new_date, false)
             }
         } else {
@@ -553,8 +1217,8 @@ async fn handle_edit(
     let dto = UpdateTaskDTO {
         title: final_title,
         description: final_description,
-        status: final_status.map(|s| s.to_string()),
-        priority: final_priority.map(|p| p.to_string()),
+        status: final_status,
+        priority: final_priority,
         tags: final_tags,
         due_date: if final_clear_due_date {
             // 期限をクリアする場合は、use case側で処理する必要がある
@@ -563,27 +1227,176 @@ async fn handle_edit(
         } else {
             final_due_date
         },
+        // 対話モードでステータスを選び直した場合は、再開を明示したものとして扱う
+        reopen: params.reopen || is_interactive,
+        custom_fields: custom_field_map(&params.fields),
+        remove_custom_fields: params.unset_fields,
+        recurrence: params.repeat.map(|rule| rule.to_string()),
+        clear_recurrence: params.no_repeat,
+        estimate: params.estimate.map(|estimate| estimate.to_string()),
+        clear_estimate: params.clear_estimate,
+        context: params.context.map(|context| context.to_string()),
+        clear_context: params.clear_context,
+        assignee: params.assignee.map(|assignee| assignee.to_string()),
+        clear_assignee: params.clear_assignee,
     };
 
     // Use Caseを実行
-    let use_case = EditTaskUseCase::new(task_repo, tag_repo);
+    let use_case = EditTaskUseCase::new(task_repo, tag_repo)
+        .with_transition_policy(settings.transition_policy.clone())
+        .with_clock(Arc::new(settings.clock));
     let updated_task = use_case.execute(id, dto).await?;
 
-    presenter.present_success(&format!(
-        "Task updated: [{}] {}",
-        updated_task.id, updated_task.title
-    ))?;
+    presenter
+        .present_success(&Msg::TaskUpdated.format(&[&updated_task.id, &updated_task.title]))?;
+
+    if !was_completed
+        && updated_task.status == Status::Completed
+        && let Some(title) = prompt_follow_up(presenter.as_ref(), &updated_task.title)?
+    {
+        let follow_up = CompleteTaskUseCase::new(unit_of_work)
+            .add_follow_up(updated_task.id, title)
+            .await?;
+        presenter
+            .present_success(&Msg::FollowUpAdded.format(&[&follow_up.id, &follow_up.title]))?;
+    }
 
     Ok(())
 }
 
-/// タスクの統計情報を表示
-async fn handle_stats(
+/// タスクのチェックリストを操作
+async fn handle_checklist(
+    task_repo: Arc<dyn TaskRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+    id: &str,
+    command: ChecklistCommands,
+) -> Result<()> {
+    // 一覧の表示以外はチェックリストを変更するため、完全なIDだけを受け付ける
+    let id = match command {
+        ChecklistCommands::List => resolve_task_id(task_repo.clone(), id).await?,
+        _ => resolve_exact_task_id(task_repo.clone(), id).await?,
+    };
+    let use_case = ManageChecklistUseCase::new(task_repo).with_clock(Arc::new(settings.clock));
+
+    let task = match command {
+        ChecklistCommands::List => use_case.list(id).await?,
+        ChecklistCommands::Add { text } => {
+            let task = use_case.add(id, &text).await?;
+            if let Some(line) = format_checklist_items(&task.checklist).last() {
+                presenter.present_success(&Msg::ChecklistItemAdded.format(&[line]))?;
+            }
+            task
+        }
+        ChecklistCommands::Toggle { number } => {
+            let task = use_case.toggle(id, number).await?;
+            if let Some(line) = format_checklist_items(&task.checklist).get(number - 1) {
+                presenter.present_success(&Msg::ChecklistItemToggled.format(&[line]))?;
+            }
+            task
+        }
+        ChecklistCommands::Remove { number } => {
+            let task = use_case.remove(id, number).await?;
+            presenter.present_success(&Msg::ChecklistItemRemoved.format(&[&number]))?;
+            task
+        }
+    };
+
+    presenter.present_checklist(&task)
+}
+
+/// タスクを現在のgitリポジトリのブランチ・コミットに紐づける
+async fn handle_link_git(
+    task_repo: Arc<dyn TaskRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+    id: &str,
+) -> Result<()> {
+    let id = resolve_exact_task_id(task_repo.clone(), id).await?;
+    let repository = GitRepository::discover(&std::env::current_dir()?)?;
+    let link = GitLink::new(
+        repository.root().display().to_string(),
+        repository.current_branch(),
+        repository.head_commit()?,
+        settings.clock.now(),
+    )?;
+
+    let task = ManageGitLinksUseCase::new(task_repo)
+        .with_clock(Arc::new(settings.clock))
+        .link(id, link)
+        .await?;
+    if let Some(link) = task.git_links.last() {
+        presenter.present_success(&Msg::GitLinked.format(&[
+            &task.id,
+            &task.title,
+            &format_git_link(link),
+        ]))?;
+    }
+    Ok(())
+}
+
+/// タスクのタイトル・説明に含まれる`nth`番目（1始まり）のURLをブラウザで開く
+async fn handle_open(
     task_repo: Arc<dyn TaskRepository>,
     tag_repo: Arc<dyn TagRepository>,
     presenter: Arc<dyn Presenter>,
+    id: &str,
+    nth: u32,
+) -> Result<()> {
+    let id = resolve_task_id(task_repo.clone(), id).await?;
+    let task = ShowTaskUseCase::new(task_repo, tag_repo)
+        .execute(id)
+        .await?;
+    let urls = find_task_urls(&task.title, task.description.as_deref());
+    if urls.is_empty() {
+        anyhow::bail!(Msg::ErrorTaskHasNoLinks.format(&[&task.id]));
+    }
+    let url = urls
+        .get(nth as usize - 1)
+        .with_context(|| Msg::ErrorTaskLinkOutOfRange.format(&[&task.id, &urls.len()]))?;
+
+    open_url(url)?;
+    presenter.present_success(&Msg::LinkOpened.format(&[url]))?;
+    Ok(())
+}
+
+/// タスクをスヌーズ（`until`がNoneの場合は解除）
+async fn handle_snooze(
+    task_repo: Arc<dyn TaskRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+    id: &str,
+    until: Option<RelativeDate>,
+) -> Result<()> {
+    let id = resolve_exact_task_id(task_repo.clone(), id).await?;
+    let use_case = SnoozeTaskUseCase::new(task_repo).with_clock(Arc::new(settings.clock));
+    match until {
+        Some(until) => {
+            let until = until.resolve(settings.clock.today(), &settings.calendar)?;
+            let task = use_case.snooze(id, until).await?;
+            presenter.present_success(&Msg::TaskSnoozed.format(&[
+                &task.id,
+                &task.title,
+                &format_date(&task.snoozed_until),
+            ]))
+        }
+        None => {
+            let task = use_case.unsnooze(id).await?;
+            presenter.present_success(&Msg::TaskUnsnoozed.format(&[&task.id, &task.title]))
+        }
+    }
+}
+
+/// タスクの統計情報を表示（`flow`の場合はタスクの流れの統計）
+async fn handle_stats(
+    use_case: ShowStatsUseCase,
+    presenter: Arc<dyn Presenter>,
+    flow: bool,
 ) -> Result<()> {
-    let use_case = ShowStatsUseCase::new(task_repo, tag_repo);
+    if flow {
+        let stats = use_case.execute_flow(Utc::now()).await?;
+        return presenter.present_flow_stats(&stats);
+    }
     let stats = use_case.execute().await?;
 
     presenter.present_stats(&stats)?;
@@ -591,6 +1404,124 @@ async fn handle_stats(
     Ok(())
 }
 
+/// 放置されているタスクを表示
+async fn handle_stale(
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    presenter: Arc<dyn Presenter>,
+    policy: StalePolicy,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let tasks = ListStaleTasksUseCase::new(task_repo, tag_repo)
+        .with_policy(policy.clone())
+        .execute(now)
+        .await?;
+    if tasks.is_empty() {
+        return presenter.present_info(Msg::NoStaleTasks.text());
+    }
+
+    let view = TaskListView::new(
+        TaskListFormat::Compact,
+        vec![
+            TaskColumn::Id,
+            TaskColumn::Title,
+            TaskColumn::Status,
+            TaskColumn::Priority,
+            TaskColumn::DueDate,
+            TaskColumn::Stale,
+        ],
+    )
+    .with_stale_policy(policy)
+    .with_now(now);
+    presenter.present_task_list(&tasks, &view)
+}
+
+/// ランダムに選んだタスクに対して行う操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+enum PickAction {
+    /// 進行中にする
+    Start,
+    /// フォーカスする
+    Focus,
+    /// 別のタスクを選び直す
+    Another,
+    /// 何もしない
+    Skip,
+}
+
+impl std::fmt::Display for PickAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            Self::Start => Msg::PickActionStart,
+            Self::Focus => Msg::PickActionFocus,
+            Self::Another => Msg::PickActionAnother,
+            Self::Skip => Msg::PickActionSkip,
+        };
+        write!(f, "{}", msg.text())
+    }
+}
+
+/// 未完了のタスクをランダムに選び、着手・フォーカスを提案する
+///
+/// 操作が指定されていない場合は、端末から実行したときだけ操作を選択します
+/// （パイプなどでは選んだタスクを表示するだけです）。
+async fn handle_pick(
+    use_case: PickTaskUseCase,
+    (task_repo, tag_repo): (Arc<dyn TaskRepository>, Arc<dyn TagRepository>),
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+    filters: &[TaskFilter],
+    action: Option<PickAction>,
+) -> Result<()> {
+    let interactive = io::stdin().is_terminal() && io::stdout().is_terminal();
+    let mut picked = Vec::new();
+    loop {
+        let Some(task) = use_case.execute(filters, &picked).await? else {
+            let msg = if picked.is_empty() {
+                Msg::PickNoTasks
+            } else {
+                Msg::PickNoMoreTasks
+            };
+            return presenter.present_info(msg.text());
+        };
+        presenter.present_info(&Msg::PickSuggestion.format(&[
+            &task.id,
+            &task.title,
+            &format_priority(task.priority),
+            &format_date(&task.due_date),
+        ]))?;
+
+        let action = match action {
+            Some(action) => action,
+            None if interactive => {
+                Select::new(Msg::PromptPickAction.text(), PickAction::iter().collect())
+                    .with_vim_mode(true)
+                    .prompt()
+                    .unwrap_or(PickAction::Skip)
+            }
+            None => PickAction::Skip,
+        };
+        match action {
+            PickAction::Start => {
+                let dto = UpdateTaskDTO {
+                    status: Some(Status::InProgress),
+                    ..Default::default()
+                };
+                let started = EditTaskUseCase::new(task_repo, tag_repo)
+                    .with_transition_policy(settings.transition_policy.clone())
+                    .with_clock(Arc::new(settings.clock))
+                    .execute(task.id, dto)
+                    .await?;
+                return presenter
+                    .present_success(&Msg::PickStarted.format(&[&started.id, &started.title]));
+            }
+            PickAction::Focus => return start_focus(task, None, presenter, settings),
+            PickAction::Another => picked.push(task.id),
+            PickAction::Skip => return Ok(()),
+        }
+    }
+}
+
 /// タスクをキーワードで検索
 async fn handle_search(
     task_repo: Arc<dyn TaskRepository>,
@@ -598,44 +1529,62 @@ async fn handle_search(
     presenter: Arc<dyn Presenter>,
     params: SearchParams,
 ) -> Result<()> {
-    // 引数モードか対話モードか判定
-    let is_interactive = params.keywords.is_none();
+    // タグIDの事前検証（UX向上のため）
+    let tag_ids = params.tag.unwrap_or_default();
+    validate_tag_ids(&tag_repo, &tag_ids).await?;
+
+    let filter = SearchFilter {
+        status: params.status,
+        priority: params.priority,
+        tag_ids: tag_ids
+            .into_iter()
+            .map(TagId::new)
+            .collect::<Result<Vec<_>>>()?,
+        due_before: params.due_before,
+        due_after: params.due_after,
+        created_after: params.created_after,
+        updated_since: params.updated_since,
+        custom_fields: params.custom_fields,
+    };
+
+    // 引数モードか対話モードか判定（絞り込み条件のみの場合はキーワードを入力させない）
+    let is_interactive = params.keywords.is_none() && filter.is_empty();
 
     let final_keywords = if is_interactive {
         // 対話モード: キーワードを入力
-        inquire::Text::new("Search keyword:")
-            .with_help_message(
-                "Multiple keywords can be specified separated by spaces (AND condition)",
-            )
+        inquire::Text::new(Msg::PromptSearchKeyword.text())
+            .with_help_message(Msg::HelpSearchKeyword.text())
             .with_validator(|input: &str| {
                 if input.trim().is_empty() {
                     Ok(validator::Validation::Invalid(
-                        "Please enter at least one character.".into(),
+                        Msg::ErrorEmptyKeyword.text().into(),
                     ))
                 } else {
                     Ok(validator::Validation::Valid)
                 }
             })
             .prompt()
-            .context("Keyword input was cancelled")?
+            .context(Msg::ErrorKeywordCancelled.text())?
     } else {
         // 引数モード
-        // SAFETY: is_interactive=falseの場合、params.keywordsはSomeであることが保証されている
-        params.keywords.unwrap()
+        params.keywords.unwrap_or_default()
     };
 
     let search_field = params.field.into();
-    let use_case = SearchTasksUseCase::new(task_repo, tag_repo);
-    let tasks = use_case.execute(&final_keywords, search_field).await?;
-
-    if tasks.is_empty() {
-        println!(
-            "No tasks found matching search keyword \"{}\"",
-            final_keywords
-        );
+    let use_case = SearchTasksUseCase::new(task_repo, tag_repo).with_sort(params.sort.into());
+    let tasks = use_case
+        .execute(&final_keywords, search_field, &filter)
+        .await?;
+
+    if tasks.is_empty() && final_keywords.trim().is_empty() {
+        presenter.present_info(Msg::NoTasksFound.text())?;
+    } else if tasks.is_empty() {
+        presenter.present_info(&Msg::SearchNoResults.format(&[&final_keywords]))?;
     } else {
-        println!("Search results ({} items):", tasks.len());
-        presenter.present_task_list(&tasks)?;
+        presenter.present_info(&Msg::SearchResultsHeader.format(&[&tasks.len()]))?;
+        let view = TaskListView::from(TaskListFormat::Standard)
+            .with_highlight(highlight_terms(&final_keywords), search_field);
+        presenter.present_task_list(&tasks, &view)?;
     }
 
     Ok(())
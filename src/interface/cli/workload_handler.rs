@@ -0,0 +1,32 @@
+use crate::{
+    application::use_cases::task::show_workload::ShowWorkloadUseCase,
+    domain::{clock::Clock, task::repository::TaskRepository},
+    interface::{cli::settings::CliSettings, presentation::Presenter},
+};
+use anyhow::Result;
+use chrono::{Datelike, Duration};
+use std::sync::Arc;
+
+/// workloadコマンドを処理
+///
+/// `week`がtrueの場合は今週（月曜日から日曜日）、それ以外は今日から7日分の作業量を表示します。
+/// 今日は`[display] timezone`のタイムゾーンの日付を基準にします。
+pub async fn handle_workload(
+    week: bool,
+    task_repo: Arc<dyn TaskRepository>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let today = settings.clock.today();
+    let from = if week {
+        today - Duration::days(i64::from(today.weekday().num_days_from_monday()))
+    } else {
+        today
+    };
+    let workload = ShowWorkloadUseCase::new(task_repo)
+        .with_clock(Arc::new(settings.clock))
+        .execute(from, from + Duration::days(6), settings.daily_capacity)
+        .await?;
+    presenter.present_workload(&workload)?;
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! システムのクリップボード
+//!
+//! OSのクリップボードのコマンド（macOSの`pbpaste`、Linuxの`wl-paste`・`xclip`・`xsel`、
+//! Windowsの`Get-Clipboard`）を実行して、クリップボードの文字列を読み取ります。
+
+use crate::interface::i18n::Msg;
+use anyhow::{Result, bail};
+use std::process::Command;
+
+/// クリップボードの文字列を読み取る
+///
+/// 使用できるコマンドを順に試し、最初に成功したコマンドの出力を返します。
+pub fn read_clipboard() -> Result<String> {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    let mut failures = Vec::new();
+    for command_line in clipboard_commands(wayland) {
+        match Command::new(command_line[0])
+            .args(&command_line[1..])
+            .output()
+        {
+            Ok(output) if output.status.success() => {
+                return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+            }
+            Ok(output) => failures
+                .push(Msg::ErrorClipboardCommandExited.format(&[&command_line[0], &output.status])),
+            // コマンドがない場合は次のコマンドを試す
+            Err(_) => {}
+        }
+    }
+
+    if failures.is_empty() {
+        bail!(Msg::ErrorClipboardCommandNotFound.text());
+    }
+    bail!(Msg::ErrorReadClipboard.format(&[&failures.join(", ")]))
+}
+
+/// クリップボードを読み取るコマンドの候補（試す順）
+fn clipboard_commands(wayland: bool) -> Vec<Vec<&'static str>> {
+    if cfg!(target_os = "macos") {
+        return vec![vec!["pbpaste"]];
+    }
+    if cfg!(windows) {
+        return vec![vec![
+            "powershell",
+            "-NoProfile",
+            "-Command",
+            "Get-Clipboard -Raw",
+        ]];
+    }
+    let wl_paste = vec!["wl-paste", "--no-newline"];
+    let x11 = [
+        vec!["xclip", "-selection", "clipboard", "-out"],
+        vec!["xsel", "--clipboard", "--output"],
+    ];
+    if wayland {
+        std::iter::once(wl_paste).chain(x11).collect()
+    } else {
+        x11.into_iter().chain(std::iter::once(wl_paste)).collect()
+    }
+}
+
+/// 取り込んだ文字列を、タイトル（最初の空でない行）と説明（残りの行）に分ける
+///
+/// 説明の前後の空行は取り除き、残りが空の場合は説明なしとします。
+pub fn split_title_and_description(text: &str) -> Result<(String, Option<String>)> {
+    let text = text.trim_start();
+    let (title, rest) = text.split_once('\n').unwrap_or((text, ""));
+    let title = title.trim();
+    if title.is_empty() {
+        bail!(Msg::ErrorClipboardEmpty.text());
+    }
+    let description = rest.trim();
+    let description = (!description.is_empty()).then(|| description.replace("\r\n", "\n"));
+    Ok((title.to_string(), description))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_title_and_description() {
+        // Act
+        let (title, description) =
+            split_title_and_description("\n  Fix login bug \r\n\r\nSteps:\r\n1. open\n\n").unwrap();
+
+        // Assert
+        assert_eq!(title, "Fix login bug");
+        assert_eq!(description.as_deref(), Some("Steps:\n1. open"));
+    }
+
+    #[test]
+    fn test_split_title_only() {
+        let (title, description) = split_title_and_description("Buy milk").unwrap();
+
+        assert_eq!(title, "Buy milk");
+        assert_eq!(description, None);
+    }
+
+    #[test]
+    fn test_split_empty_clipboard() {
+        let error = split_title_and_description(" \n\t\n").unwrap_err();
+
+        assert_eq!(error.to_string(), "Clipboard is empty");
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_clipboard_commands_prefer_wayland() {
+        assert_eq!(clipboard_commands(true)[0][0], "wl-paste");
+        assert_eq!(clipboard_commands(false)[0][0], "xclip");
+    }
+}
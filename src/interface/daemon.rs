@@ -0,0 +1,8 @@
+//! 常駐デーモン
+//!
+//! データベース接続とキャッシュを保持したまま常駐し、Unixソケット経由で
+//! CLIのコマンドを実行します。プロトコルは改行区切りのJSON-RPC 2.0です。
+
+pub mod client;
+pub mod protocol;
+pub mod server;
@@ -0,0 +1,245 @@
+use crate::interface::{
+    cli::args::{ChecklistCommands, Commands, ContextCommands, TagCommands, TaskCommands},
+    daemon::protocol::{Call, PingResult, Request, Response, RunParams, RunResult},
+};
+use anyhow::{Context, Result};
+use std::{path::Path, time::Duration};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::UnixStream,
+};
+
+/// ソケットへの接続の待ち時間（超えた場合はデーモンが起動していないとみなす）
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// レスポンスの待ち時間
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// デーモンに転送できるコマンドか判定
+///
+/// 転送に失敗した場合は直接実行し直すため、二重に実行しても問題のない
+/// 読み取り専用で、対話的な入力を必要としないコマンドに限ります。
+pub fn is_forwardable(command: &Commands) -> bool {
+    match command {
+        Commands::Task { command } => match command {
+            TaskCommands::List { .. } | TaskCommands::Stats { .. } | TaskCommands::Stale { .. } => {
+                true
+            }
+            TaskCommands::Show { id, .. } => id.is_some(),
+            TaskCommands::Search { keywords, .. } => keywords.is_some(),
+            TaskCommands::Check { command, .. } => matches!(command, ChecklistCommands::List),
+            TaskCommands::Add { .. }
+            | TaskCommands::Delete { .. }
+            | TaskCommands::Done { .. }
+            | TaskCommands::Edit { .. }
+            | TaskCommands::Snooze { .. }
+            | TaskCommands::Unsnooze { .. }
+            | TaskCommands::Pick { .. }
+            | TaskCommands::Open { .. }
+            | TaskCommands::LinkGit { .. } => false,
+        },
+        Commands::Tag { command } => match command {
+            TagCommands::List => true,
+            TagCommands::Show { id } => id.is_some(),
+            TagCommands::Add { .. } | TagCommands::Delete { .. } | TagCommands::Edit { .. } => {
+                false
+            }
+        },
+        // 現在のコンテキストの切り替えはクライアント側で状態ファイルに書き込む
+        Commands::Context { command } => matches!(command, ContextCommands::List),
+        Commands::Query { .. }
+        | Commands::Summary { .. }
+        | Commands::Workload { .. }
+        | Commands::Report { .. }
+        | Commands::Today { .. }
+        | Commands::Agenda { .. } => true,
+        Commands::Add { .. }
+        | Commands::Db { .. }
+        | Commands::Daemon { .. }
+        | Commands::Git { .. }
+        | Commands::Sync { .. }
+        | Commands::Import { .. }
+        | Commands::Apply { .. }
+        | Commands::Cron { .. }
+        | Commands::Focus { .. }
+        | Commands::Audit { .. }
+        | Commands::PurgeAll { .. }
+        | Commands::Doctor => false,
+        #[cfg(feature = "mail")]
+        Commands::Ingest { .. } => false,
+    }
+}
+
+/// デーモンでコマンドを実行する
+///
+/// # Returns
+/// * `Err` - デーモンが起動していない場合や、デーモンでは実行できない場合（直接実行に切り替える）
+pub async fn run(socket_path: &Path, params: RunParams) -> Result<RunResult> {
+    call(socket_path, Call::Run(params)).await?.into_result()
+}
+
+/// デーモンの起動を確認する
+pub async fn ping(socket_path: &Path) -> Result<PingResult> {
+    call(socket_path, Call::Ping).await?.into_result()
+}
+
+/// デーモンに終了を要求する
+pub async fn shutdown(socket_path: &Path) -> Result<()> {
+    call(socket_path, Call::Shutdown).await?.into_result()
+}
+
+/// リクエストを1件送り、レスポンスを受け取る
+async fn call(socket_path: &Path, call: Call) -> Result<Response> {
+    let stream = tokio::time::timeout(CONNECT_TIMEOUT, UnixStream::connect(socket_path))
+        .await
+        .context("Timed out connecting to the daemon")?
+        .with_context(|| format!("No daemon is listening on {}", socket_path.display()))?;
+    let (reader, mut writer) = stream.into_split();
+
+    let mut body = serde_json::to_string(&Request::new(1, call))?;
+    body.push('\n');
+    writer.write_all(body.as_bytes()).await?;
+    writer.flush().await?;
+
+    let mut line = String::new();
+    tokio::time::timeout(
+        RESPONSE_TIMEOUT,
+        BufReader::new(reader).read_line(&mut line),
+    )
+    .await
+    .context("Timed out waiting for the daemon")??;
+    if line.is_empty() {
+        anyhow::bail!("The daemon closed the connection without a response");
+    }
+    Ok(serde_json::from_str(&line)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::{
+        cli::args::Args,
+        daemon::{
+            protocol::{RpcError, UNAVAILABLE},
+            server::{DaemonListener, RequestHandler, serve},
+        },
+    };
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    fn command(args: &[&str]) -> Commands {
+        let mut argv = vec!["yaru"];
+        argv.extend_from_slice(args);
+        Args::try_parse_from(argv).unwrap().command.unwrap()
+    }
+
+    #[test]
+    fn test_is_forwardable() {
+        assert!(is_forwardable(&command(&["task", "list", "-a"])));
+        assert!(is_forwardable(&command(&["task", "show", "3"])));
+        assert!(is_forwardable(&command(&["task", "search", "report"])));
+        assert!(is_forwardable(&command(&["task", "check", "3", "list"])));
+        assert!(is_forwardable(&command(&["tag", "list"])));
+
+        // 対話的な入力が必要なコマンドや、書き込みを行うコマンドは転送しない
+        assert!(!is_forwardable(&command(&["task", "show"])));
+        assert!(!is_forwardable(&command(&["task", "search"])));
+        assert!(!is_forwardable(&command(&[
+            "task", "add", "write", "--force"
+        ])));
+        assert!(!is_forwardable(&command(&[
+            "task", "check", "3", "toggle", "1"
+        ])));
+        assert!(!is_forwardable(&command(&["db", "status"])));
+        assert!(!is_forwardable(&command(&["daemon", "status"])));
+    }
+
+    struct EchoHandler;
+
+    #[async_trait::async_trait]
+    impl RequestHandler for EchoHandler {
+        fn ping(&self) -> PingResult {
+            PingResult {
+                version: "test".to_string(),
+                database_url: "sqlite::memory:".to_string(),
+                pid: 1,
+            }
+        }
+
+        async fn run(&self, params: RunParams) -> Result<RunResult, RpcError> {
+            if params.database_url != "sqlite::memory:" {
+                return Err(RpcError {
+                    code: UNAVAILABLE,
+                    message: "different database".to_string(),
+                });
+            }
+            Ok(RunResult {
+                stdout: params.args.join(" "),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn params(database_url: &str) -> RunParams {
+        RunParams {
+            args: vec!["task".to_string(), "list".to_string()],
+            database_url: database_url.to_string(),
+            version: "test".to_string(),
+            no_color: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_server_round_trip() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let socket_path = dir.path().join("yaru.sock");
+        let listener = DaemonListener::bind(&socket_path).unwrap();
+        let server = tokio::spawn(async move { serve(listener, &EchoHandler, None).await });
+
+        // Act
+        let pong = ping(&socket_path).await.unwrap();
+        let output = run(&socket_path, params("sqlite::memory:")).await.unwrap();
+        let rejected = run(&socket_path, params("sqlite://other.db")).await;
+        shutdown(&socket_path).await.unwrap();
+
+        // Assert
+        assert_eq!(pong.version, "test");
+        assert_eq!(output.stdout, "task list");
+        assert!(
+            rejected
+                .unwrap_err()
+                .to_string()
+                .contains("different database")
+        );
+        server.await.unwrap().unwrap();
+        assert!(!socket_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_client_fails_without_daemon() {
+        let dir = TempDir::new().unwrap();
+
+        let result = ping(&dir.path().join("missing.sock")).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bind_rejects_running_daemon_and_replaces_stale_socket() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let socket_path = dir.path().join("yaru.sock");
+        let listener = DaemonListener::bind(&socket_path).unwrap();
+
+        // Act & Assert
+        assert!(DaemonListener::bind(&socket_path).is_err());
+        // 異常終了で残ったソケットファイルは置き換える
+        drop(listener);
+        std::os::unix::net::UnixListener::bind(&socket_path)
+            .map(drop)
+            .unwrap();
+        assert!(socket_path.exists());
+        assert!(DaemonListener::bind(&socket_path).is_ok());
+    }
+}
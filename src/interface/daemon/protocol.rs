@@ -0,0 +1,201 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// JSON-RPCのバージョン
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// リクエストを解釈できない場合のエラーコード
+pub const INVALID_REQUEST: i64 = -32600;
+/// デーモンがリクエストを処理できない場合のエラーコード（別のデータベースやバージョンなど）
+///
+/// クライアントはこのエラーを受け取った場合、直接データベースにアクセスして実行します。
+pub const UNAVAILABLE: i64 = -32000;
+
+/// JSON-RPCのリクエスト
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Request {
+    pub jsonrpc: String,
+    pub id: u64,
+    #[serde(flatten)]
+    pub call: Call,
+}
+
+impl Request {
+    /// 新しいRequestを作成
+    pub fn new(id: u64, call: Call) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            call,
+        }
+    }
+}
+
+/// 呼び出すメソッドとその引数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum Call {
+    /// 起動確認（結果は[`PingResult`]）
+    Ping,
+    /// CLIのコマンドを実行（結果は[`RunResult`]）
+    Run(RunParams),
+    /// デーモンを終了
+    Shutdown,
+}
+
+/// `run`メソッドの引数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunParams {
+    /// プログラム名を除いたコマンドライン引数
+    pub args: Vec<String>,
+    /// クライアントが使うデータベースURL（デーモンと異なる場合は実行しない）
+    pub database_url: String,
+    /// クライアントのバージョン（デーモンと異なる場合は実行しない）
+    pub version: String,
+    /// 装飾なしで出力するか（`--no-color`やNO_COLOR環境変数）
+    pub no_color: bool,
+}
+
+/// `ping`メソッドの結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PingResult {
+    pub version: String,
+    pub database_url: String,
+    pub pid: u32,
+}
+
+/// `run`メソッドの結果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunResult {
+    pub stdout: String,
+    pub stderr: String,
+    /// コマンドが失敗した場合のエラーメッセージ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// JSON-RPCのエラー
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// JSON-RPCのレスポンス
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    pub jsonrpc: String,
+    /// リクエストを解釈できなかった場合は`null`
+    pub id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl Response {
+    /// 成功のレスポンスを作成
+    pub fn success(id: u64, result: impl Serialize) -> Result<Self> {
+        Ok(Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Some(id),
+            result: Some(serde_json::to_value(result)?),
+            error: None,
+        })
+    }
+
+    /// 失敗のレスポンスを作成
+    pub fn failure(id: Option<u64>, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: None,
+            error: Some(RpcError {
+                code,
+                message: message.into(),
+            }),
+        }
+    }
+
+    /// 結果を指定した型で取り出す
+    ///
+    /// # Returns
+    /// * `Err` - エラーのレスポンスの場合、または結果の形式が合わない場合
+    pub fn into_result<T: DeserializeOwned>(self) -> Result<T> {
+        if let Some(error) = self.error {
+            anyhow::bail!("Daemon error {}: {}", error.code, error.message);
+        }
+        Ok(serde_json::from_value(self.result.unwrap_or(Value::Null))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_serializes_as_json_rpc() {
+        // Arrange
+        let request = Request::new(
+            1,
+            Call::Run(RunParams {
+                args: vec!["task".to_string(), "list".to_string()],
+                database_url: "sqlite://yaru.db".to_string(),
+                version: "0.1.0".to_string(),
+                no_color: true,
+            }),
+        );
+
+        // Act
+        let json = serde_json::to_value(&request).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "run",
+                "params": {
+                    "args": ["task", "list"],
+                    "database_url": "sqlite://yaru.db",
+                    "version": "0.1.0",
+                    "no_color": true
+                }
+            })
+        );
+        let parsed: Request = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, request);
+    }
+
+    #[test]
+    fn test_request_without_params() {
+        let parsed: Request =
+            serde_json::from_str(r#"{"jsonrpc":"2.0","id":7,"method":"ping"}"#).unwrap();
+
+        assert_eq!(parsed, Request::new(7, Call::Ping));
+        assert!(
+            serde_json::from_str::<Request>(r#"{"jsonrpc":"2.0","id":7,"method":"drop"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_response_into_result() {
+        let response = Response::success(
+            3,
+            RunResult {
+                stdout: "ok\n".to_string(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let result: RunResult = response.into_result().unwrap();
+        assert_eq!(result.stdout, "ok\n");
+
+        let error = Response::failure(Some(3), UNAVAILABLE, "different database")
+            .into_result::<RunResult>()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Daemon error -32000: different database");
+    }
+}
@@ -0,0 +1,188 @@
+use crate::interface::daemon::protocol::{
+    Call, INVALID_REQUEST, PingResult, Request, Response, RpcError, RunParams, RunResult,
+};
+use anyhow::{Context, Result};
+use std::{
+    os::unix::{fs::PermissionsExt, io::FromRawFd},
+    path::Path,
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{UnixListener, UnixStream},
+};
+
+/// systemdのソケットアクティベーションで渡される最初のファイルディスクリプタ
+const SD_LISTEN_FDS_START: i32 = 3;
+
+/// 接続してからリクエストを受け取るまでの待ち時間
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// デーモンが受け付けたリクエストを処理するハンドラ
+#[async_trait::async_trait]
+pub trait RequestHandler: Send + Sync {
+    /// 起動確認に応答する
+    fn ping(&self) -> PingResult;
+
+    /// CLIのコマンドを実行し、出力を返す
+    ///
+    /// # Returns
+    /// * `Err(RpcError)` - このデーモンでは実行できない場合（クライアントは直接実行する）
+    async fn run(&self, params: RunParams) -> Result<RunResult, RpcError>;
+}
+
+/// 待ち受けるソケット
+pub struct DaemonListener {
+    listener: UnixListener,
+    /// 自身で作成したソケットファイル（終了時に削除する）
+    owned_path: Option<std::path::PathBuf>,
+}
+
+impl DaemonListener {
+    /// systemdから渡されたソケットがあればそれを使い、なければ指定したパスに作成する
+    pub fn open(socket_path: &Path) -> Result<Self> {
+        if let Some(listener) = Self::from_socket_activation()? {
+            tracing::info!("using socket passed by socket activation");
+            return Ok(Self {
+                listener,
+                owned_path: None,
+            });
+        }
+        Self::bind(socket_path)
+    }
+
+    /// 指定したパスにソケットを作成する
+    ///
+    /// 既存のソケットに接続できる場合は、デーモンが起動済みとしてエラーを返します。
+    /// 接続できない場合は前回の異常終了で残ったファイルとみなして削除します。
+    pub fn bind(socket_path: &Path) -> Result<Self> {
+        if socket_path.exists() {
+            if std::os::unix::net::UnixStream::connect(socket_path).is_ok() {
+                anyhow::bail!("A daemon is already listening on {}", socket_path.display());
+            }
+            std::fs::remove_file(socket_path).with_context(|| {
+                format!("Failed to remove stale socket: {}", socket_path.display())
+            })?;
+        }
+        if let Some(parent) = socket_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let listener = UnixListener::bind(socket_path)
+            .with_context(|| format!("Failed to listen on {}", socket_path.display()))?;
+        // タスクの内容を返すため、所有者以外は接続できないようにする
+        std::fs::set_permissions(socket_path, std::fs::Permissions::from_mode(0o600))?;
+
+        Ok(Self {
+            listener,
+            owned_path: Some(socket_path.to_path_buf()),
+        })
+    }
+
+    /// LISTEN_PID/LISTEN_FDS環境変数で渡されたソケットを取得する
+    fn from_socket_activation() -> Result<Option<UnixListener>> {
+        let for_this_process = std::env::var("LISTEN_PID")
+            .ok()
+            .and_then(|pid| pid.parse::<u32>().ok())
+            .is_some_and(|pid| pid == std::process::id());
+        let fds = std::env::var("LISTEN_FDS")
+            .ok()
+            .and_then(|fds| fds.parse::<i32>().ok())
+            .unwrap_or(0);
+        if !for_this_process || fds < 1 {
+            return Ok(None);
+        }
+
+        // SAFETY: LISTEN_PIDがこのプロセスを指している場合、systemdは
+        // ファイルディスクリプタ3に待ち受け中のソケットを渡しており、他からは使われない
+        let listener =
+            unsafe { std::os::unix::net::UnixListener::from_raw_fd(SD_LISTEN_FDS_START) };
+        listener.set_nonblocking(true)?;
+        Ok(Some(UnixListener::from_std(listener)?))
+    }
+}
+
+impl Drop for DaemonListener {
+    fn drop(&mut self) {
+        if let Some(path) = &self.owned_path {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// リクエストを順番に処理する
+///
+/// `shutdown`を受け取るか、`idle_timeout`の間リクエストがなければ終了します。
+/// SQLiteへの書き込みが競合しないよう、リクエストは1件ずつ処理します。
+pub async fn serve(
+    listener: DaemonListener,
+    handler: &dyn RequestHandler,
+    idle_timeout: Option<Duration>,
+) -> Result<()> {
+    loop {
+        let accepted = match idle_timeout {
+            Some(timeout) => {
+                match tokio::time::timeout(timeout, listener.listener.accept()).await {
+                    Ok(accepted) => accepted,
+                    Err(_) => {
+                        tracing::info!("daemon idle timeout reached");
+                        return Ok(());
+                    }
+                }
+            }
+            None => listener.listener.accept().await,
+        };
+        let (stream, _) = accepted.context("Failed to accept connection")?;
+
+        match handle_connection(stream, handler).await {
+            Ok(true) => {
+                tracing::info!("daemon shutdown requested");
+                return Ok(());
+            }
+            Ok(false) => {}
+            Err(e) => tracing::warn!(error = %e, "daemon connection failed"),
+        }
+    }
+}
+
+/// 1つの接続から1件のリクエストを読み取り、レスポンスを返す
+///
+/// # Returns
+/// * `Ok(true)` - 終了が要求された場合
+async fn handle_connection(stream: UnixStream, handler: &dyn RequestHandler) -> Result<bool> {
+    let (reader, mut writer) = stream.into_split();
+    let mut line = String::new();
+    tokio::time::timeout(READ_TIMEOUT, BufReader::new(reader).read_line(&mut line))
+        .await
+        .context("Timed out waiting for a request")??;
+
+    let (response, shutdown) = match serde_json::from_str::<Request>(&line) {
+        Ok(request) => dispatch(request, handler).await?,
+        Err(e) => (
+            Response::failure(None, INVALID_REQUEST, format!("Invalid request: {}", e)),
+            false,
+        ),
+    };
+
+    let mut body = serde_json::to_string(&response)?;
+    body.push('\n');
+    writer.write_all(body.as_bytes()).await?;
+    writer.flush().await?;
+    Ok(shutdown)
+}
+
+/// メソッドに応じてハンドラを呼び出す
+async fn dispatch(request: Request, handler: &dyn RequestHandler) -> Result<(Response, bool)> {
+    let id = request.id;
+    match request.call {
+        Call::Ping => Ok((Response::success(id, handler.ping())?, false)),
+        Call::Run(params) => {
+            let response = match handler.run(params).await {
+                Ok(result) => Response::success(id, result)?,
+                Err(error) => Response::failure(Some(id), error.code, error.message),
+            };
+            Ok((response, false))
+        }
+        Call::Shutdown => Ok((Response::success(id, ())?, true)),
+    }
+}
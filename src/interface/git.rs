@@ -0,0 +1,366 @@
+//! gitとの連携
+//!
+//! gitコマンドを実行して作業ツリーの情報を取得し、フックの導入と
+//! コミットメッセージのトレーラー（`yaru:#<id>`）の読み書きを行います。
+
+use anyhow::{Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// タスクを参照するトレーラーの接頭辞
+const TRAILER_PREFIX: &str = "yaru:";
+
+/// yaruが追加したフックの行に付ける目印
+const HOOK_MARKER: &str = "# yaru git hook";
+
+/// gitの作業ツリー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRepository {
+    root: PathBuf,
+}
+
+impl GitRepository {
+    /// 指定したディレクトリを含む作業ツリーを探す
+    ///
+    /// # Returns
+    /// * `Err` - gitが見つからない場合、または作業ツリーの外の場合
+    pub fn discover(dir: &Path) -> Result<Self> {
+        let root = run_git(dir, &["rev-parse", "--show-toplevel"])
+            .with_context(|| format!("Not inside a git repository: {}", dir.display()))?;
+        Ok(Self {
+            root: PathBuf::from(root),
+        })
+    }
+
+    /// 作業ツリーのルートパス
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// 現在のブランチ名（detached HEADの場合はNone）
+    pub fn current_branch(&self) -> Option<String> {
+        run_git(&self.root, &["symbolic-ref", "--quiet", "--short", "HEAD"]).ok()
+    }
+
+    /// HEADのコミットハッシュ
+    pub fn head_commit(&self) -> Result<String> {
+        run_git(&self.root, &["rev-parse", "HEAD"]).context("The repository has no commits yet")
+    }
+
+    /// フックを置くディレクトリ（`core.hooksPath`の設定を反映する）
+    pub fn hooks_dir(&self) -> Result<PathBuf> {
+        let path = PathBuf::from(run_git(&self.root, &["rev-parse", "--git-path", "hooks"])?);
+        Ok(if path.is_absolute() {
+            path
+        } else {
+            self.root.join(path)
+        })
+    }
+
+    /// 範囲内（例: `ORIG_HEAD..HEAD`）のコミットメッセージ
+    pub fn commit_messages(&self, range: &str) -> Result<Vec<String>> {
+        let output = run_git(&self.root, &["log", "--format=%B%x00", range])?;
+        Ok(output
+            .split('\0')
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+/// gitコマンドを実行し、標準出力の内容を返す
+fn run_git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git. Is git installed?")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// フックを導入した結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookInstall {
+    /// フックファイルを新しく作成した
+    Created,
+    /// 既存のフックファイルに追記した
+    Appended,
+    /// 導入済みだった
+    AlreadyInstalled,
+}
+
+/// yaruが導入したフックの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookState {
+    /// 導入されていない（ファイルがないか、yaruの行を含まない）
+    Missing,
+    /// 導入済みで実行できる
+    Installed,
+    /// 導入済みだが実行権限がない
+    NotExecutable,
+}
+
+/// フックファイルにyaruのコマンドが導入されているかを調べる
+pub fn inspect_hook(hooks_dir: &Path, name: &str) -> Result<HookState> {
+    let path = hooks_dir.join(name);
+    if !path.exists() {
+        return Ok(HookState::Missing);
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read hook: {}", path.display()))?;
+    if !content.contains(HOOK_MARKER) {
+        return Ok(HookState::Missing);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if fs::metadata(&path)?.permissions().mode() & 0o111 == 0 {
+            return Ok(HookState::NotExecutable);
+        }
+    }
+    Ok(HookState::Installed)
+}
+
+/// フックファイルにコマンドを追加する
+///
+/// フックファイルがなければ作成し、あれば末尾に追記します（実行権限も付与します）。
+/// 導入済みの場合は何もしません。
+pub fn install_hook(hooks_dir: &Path, name: &str, command: &str) -> Result<HookInstall> {
+    let path = hooks_dir.join(name);
+    let line = format!("{}\n{}\n", HOOK_MARKER, command);
+
+    let result = if path.exists() {
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read hook: {}", path.display()))?;
+        if content.contains(HOOK_MARKER) {
+            return Ok(HookInstall::AlreadyInstalled);
+        }
+        let separator = if content.ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        };
+        fs::write(&path, format!("{}{}{}", content, separator, line))?;
+        HookInstall::Appended
+    } else {
+        fs::create_dir_all(hooks_dir)?;
+        fs::write(&path, format!("#!/bin/sh\n{}", line))?;
+        HookInstall::Created
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(&path)?.permissions();
+        permissions.set_mode(permissions.mode() | 0o755);
+        fs::set_permissions(&path, permissions)?;
+    }
+
+    Ok(result)
+}
+
+/// シェルのコマンドラインで使えるようにシングルクォートで囲む
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// タスクを参照するトレーラー（`yaru:#<id>`）
+pub fn format_task_trailer(task_id: i32) -> String {
+    format!("{}#{}", TRAILER_PREFIX, task_id)
+}
+
+/// コミットメッセージからトレーラーで参照されたタスクIDを出現順に取り出す
+///
+/// `yaru:#12`と`yaru: #12`の両方の書き方を受け付けます。
+pub fn parse_task_trailers(message: &str) -> Vec<i32> {
+    let mut ids = Vec::new();
+    for line in message.lines() {
+        let Some(value) = line.trim().strip_prefix(TRAILER_PREFIX) else {
+            continue;
+        };
+        let Some(id) = value
+            .trim()
+            .strip_prefix('#')
+            .and_then(|id| id.parse::<i32>().ok())
+            .filter(|id| *id > 0)
+        else {
+            continue;
+        };
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// コミットメッセージの末尾にタスクのトレーラーを追加する
+///
+/// gitのコメント行（`#`で始まる行）より前に追加し、すでに参照しているタスクは追加しません。
+/// 本文が空の場合は、1行目を件名として入力できるよう空行を空けて追加します。
+pub fn append_task_trailers(message: &str, task_ids: &[i32]) -> String {
+    let lines: Vec<&str> = message.lines().collect();
+    let comment_start = lines
+        .iter()
+        .position(|line| line.starts_with('#'))
+        .unwrap_or(lines.len());
+    let (body, comments) = lines.split_at(comment_start);
+
+    let existing = parse_task_trailers(&body.join("\n"));
+    let missing: Vec<String> = task_ids
+        .iter()
+        .filter(|id| !existing.contains(id))
+        .map(|id| format_task_trailer(*id))
+        .collect();
+    if missing.is_empty() {
+        return message.to_string();
+    }
+
+    let mut body: Vec<&str> = body.to_vec();
+    while body.last().is_some_and(|line| line.trim().is_empty()) {
+        body.pop();
+    }
+
+    let mut output = String::new();
+    match body.last() {
+        None => output.push_str("\n\n"),
+        Some(last) => {
+            output.push_str(&body.join("\n"));
+            output.push('\n');
+            // 既存のトレーラーの段落には続けて追加する
+            if !last.trim().starts_with(TRAILER_PREFIX) {
+                output.push('\n');
+            }
+        }
+    }
+    output.push_str(&missing.join("\n"));
+    output.push('\n');
+    if !comments.is_empty() {
+        output.push('\n');
+        output.push_str(&comments.join("\n"));
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_task_trailers() {
+        let message = "Fix login\n\nyaru:#12\nyaru: #7\nyaru:#12\nyaru:#abc\nSigned-off-by: a";
+
+        assert_eq!(parse_task_trailers(message), vec![12, 7]);
+    }
+
+    #[test]
+    fn test_append_task_trailers_before_comments() {
+        // Arrange
+        let message = "Fix login\n\nDetails\n\n# Please enter the commit message\n# On branch x\n";
+
+        // Act
+        let result = append_task_trailers(message, &[12, 7]);
+
+        // Assert
+        assert_eq!(
+            result,
+            "Fix login\n\nDetails\n\nyaru:#12\nyaru:#7\n\n# Please enter the commit message\n# On branch x\n"
+        );
+        assert_eq!(append_task_trailers(&result, &[12, 7]), result);
+    }
+
+    #[test]
+    fn test_append_task_trailers_continues_trailer_block() {
+        let result = append_task_trailers("Fix login\n\nyaru:#12\n", &[12, 7]);
+
+        assert_eq!(result, "Fix login\n\nyaru:#12\nyaru:#7\n");
+    }
+
+    #[test]
+    fn test_append_task_trailers_to_empty_message() {
+        let result = append_task_trailers("\n# comment\n", &[3]);
+
+        assert_eq!(result, "\n\nyaru:#3\n\n# comment\n");
+    }
+
+    #[test]
+    fn test_install_hook_creates_and_appends_once() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("post-merge"), "#!/bin/sh\necho merged").unwrap();
+
+        // Act
+        let created = install_hook(dir.path(), "prepare-commit-msg", "yaru git x").unwrap();
+        let appended = install_hook(dir.path(), "post-merge", "yaru git y").unwrap();
+        let again = install_hook(dir.path(), "post-merge", "yaru git y").unwrap();
+
+        // Assert
+        assert_eq!(created, HookInstall::Created);
+        assert_eq!(appended, HookInstall::Appended);
+        assert_eq!(again, HookInstall::AlreadyInstalled);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("post-merge")).unwrap(),
+            format!("#!/bin/sh\necho merged\n\n{}\nyaru git y\n", HOOK_MARKER)
+        );
+    }
+
+    #[test]
+    fn test_inspect_hook() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("pre-commit"), "#!/bin/sh\necho lint").unwrap();
+        install_hook(dir.path(), "post-merge", "yaru git y").unwrap();
+
+        // Act & Assert
+        assert_eq!(
+            inspect_hook(dir.path(), "prepare-commit-msg").unwrap(),
+            HookState::Missing
+        );
+        assert_eq!(
+            inspect_hook(dir.path(), "pre-commit").unwrap(),
+            HookState::Missing
+        );
+        assert_eq!(
+            inspect_hook(dir.path(), "post-merge").unwrap(),
+            HookState::Installed
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_inspect_hook_without_execute_permission() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        install_hook(dir.path(), "post-merge", "yaru git y").unwrap();
+        let path = dir.path().join("post-merge");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        // Act
+        let state = inspect_hook(dir.path(), "post-merge").unwrap();
+
+        // Assert
+        assert_eq!(state, HookState::NotExecutable);
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("/usr/bin/yaru"), "'/usr/bin/yaru'");
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+    }
+}
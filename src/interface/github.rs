@@ -0,0 +1,317 @@
+//! GitHubとの連携
+//!
+//! GitHubのREST APIでリポジトリのIssueを取得し、クローズ・再オープンを反映します。
+
+use crate::{
+    application::use_cases::task::sync_issues::{IssueTracker, RemoteIssue},
+    interface::http::{HttpRequest, HttpResponse},
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Deserialize;
+
+/// 外部の項目との対応に記録するサービスの種類
+pub const GITHUB_SOURCE: &str = "github";
+
+/// 1ページで取得するIssueの件数（APIの上限）
+const PER_PAGE: usize = 100;
+
+/// 使用するAPIのバージョン
+const API_VERSION: &str = "2022-11-28";
+
+/// GitHubのリポジトリのIssueにアクセスするクライアント
+#[derive(Debug, Clone)]
+pub struct GithubClient {
+    /// `owner/name`形式のリポジトリ
+    repo: String,
+    api_url: String,
+    token: Option<String>,
+}
+
+impl GithubClient {
+    /// 新しいGithubClientを作成
+    ///
+    /// # Returns
+    /// * `Err` - リポジトリが`owner/name`形式でない場合
+    pub fn new(repo: &str, api_url: &str, token: Option<String>) -> Result<Self> {
+        let repo = repo.trim();
+        let valid = repo.split_once('/').is_some_and(|(owner, name)| {
+            let valid_part = |part: &str| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            };
+            valid_part(owner) && valid_part(name)
+        });
+        if !valid {
+            anyhow::bail!("Invalid GitHub repository (expected OWNER/NAME): {}", repo);
+        }
+        Ok(Self {
+            repo: repo.to_string(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token,
+        })
+    }
+
+    /// APIのリクエストを作成する
+    fn request(&self, method: &str, path: &str) -> HttpRequest {
+        let request = HttpRequest::new(
+            method,
+            format!("{}/repos/{}{}", self.api_url, self.repo, path),
+        )
+        .header("Accept", "application/vnd.github+json")
+        .header("X-GitHub-Api-Version", API_VERSION);
+        match &self.token {
+            Some(token) => request.header("Authorization", format!("Bearer {}", token)),
+            None => request,
+        }
+    }
+
+    /// Issueの一覧を1ページ取得する
+    async fn fetch_page(
+        &self,
+        since: Option<DateTime<Utc>>,
+        page: usize,
+    ) -> Result<Vec<IssueItem>> {
+        let mut query = format!(
+            "?state={}&per_page={}&page={}&sort=updated&direction=asc",
+            if since.is_some() { "all" } else { "open" },
+            PER_PAGE,
+            page
+        );
+        if let Some(since) = since {
+            query.push_str(&format!(
+                "&since={}",
+                since.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        }
+        let response = check(
+            self.request("GET", &format!("/issues{}", query))
+                .send()
+                .await?,
+        )?;
+        serde_json::from_slice(response.body()).context("Failed to parse GitHub issues")
+    }
+}
+
+#[async_trait]
+impl IssueTracker for GithubClient {
+    fn source(&self) -> &str {
+        GITHUB_SOURCE
+    }
+
+    fn external_id(&self, number: u64) -> String {
+        format!("{}#{}", self.repo, number)
+    }
+
+    fn issue_number(&self, external_id: &str) -> Option<u64> {
+        let (repo, number) = external_id.rsplit_once('#')?;
+        if !repo.eq_ignore_ascii_case(&self.repo) {
+            return None;
+        }
+        number.parse().ok()
+    }
+
+    async fn fetch_issues(&self, since: Option<DateTime<Utc>>) -> Result<Vec<RemoteIssue>> {
+        let mut issues = Vec::new();
+        for page in 1.. {
+            let items = self.fetch_page(since, page).await?;
+            let last_page = items.len() < PER_PAGE;
+            // Issueの一覧にはプルリクエストも含まれる
+            issues.extend(
+                items
+                    .into_iter()
+                    .filter(|item| item.pull_request.is_none())
+                    .map(RemoteIssue::from),
+            );
+            if last_page {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    async fn set_closed(&self, number: u64, closed: bool) -> Result<DateTime<Utc>> {
+        if self.token.is_none() {
+            anyhow::bail!(
+                "A GitHub token is required to push status changes (set [github] token or GITHUB_TOKEN)"
+            );
+        }
+        let body = serde_json::json!({ "state": if closed { "closed" } else { "open" } });
+        let response = check(
+            self.request("PATCH", &format!("/issues/{}", number))
+                .header("Content-Type", "application/json")
+                .body(body.to_string())
+                .send()
+                .await?,
+        )?;
+        let item: IssueItem =
+            serde_json::from_slice(response.body()).context("Failed to parse GitHub issue")?;
+        Ok(item.updated_at)
+    }
+}
+
+/// エラーのレスポンスをエラーに変換する
+fn check(response: HttpResponse) -> Result<HttpResponse> {
+    if response.is_success() {
+        return Ok(response);
+    }
+    let message = serde_json::from_slice::<serde_json::Value>(response.body())
+        .ok()
+        .and_then(|value| value.get("message")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| response.text());
+    anyhow::bail!(
+        "GitHub API error ({}): {}",
+        response.status(),
+        message.trim()
+    )
+}
+
+/// APIが返すIssue
+#[derive(Debug, Deserialize)]
+struct IssueItem {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    #[serde(default)]
+    labels: Vec<LabelItem>,
+    milestone: Option<MilestoneItem>,
+    state: String,
+    html_url: Option<String>,
+    updated_at: DateTime<Utc>,
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LabelItem {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MilestoneItem {
+    due_on: Option<DateTime<Utc>>,
+}
+
+impl From<IssueItem> for RemoteIssue {
+    fn from(item: IssueItem) -> Self {
+        Self {
+            number: item.number,
+            title: item.title,
+            body: item.body.unwrap_or_default(),
+            labels: item.labels.into_iter().map(|label| label.name).collect(),
+            due_date: item
+                .milestone
+                .and_then(|milestone| milestone.due_on)
+                .map(|due_on| due_on.date_naive()),
+            closed: item.state == "closed",
+            url: item.html_url,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    /// 受け取ったリクエストの1行目を記録し、用意したレスポンスを順に返すサーバー
+    fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for body in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut received = Vec::new();
+                let mut buffer = [0u8; 4096];
+                loop {
+                    let n = stream.read(&mut buffer).unwrap();
+                    received.extend_from_slice(&buffer[..n]);
+                    let text = String::from_utf8_lossy(&received);
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let length = text
+                            .lines()
+                            .find_map(|line| line.strip_prefix("Content-Length: "))
+                            .map_or(0, |n| n.trim().parse::<usize>().unwrap());
+                        if received.len() >= end + 4 + length {
+                            break;
+                        }
+                    }
+                }
+                let text = String::from_utf8(received).unwrap();
+                requests.push(text.lines().next().unwrap().to_string());
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_github_client_repo_validation() {
+        assert!(GithubClient::new("taktiks2/yaru", "https://api.github.com", None).is_ok());
+        assert!(GithubClient::new("yaru", "https://api.github.com", None).is_err());
+        assert!(GithubClient::new("a/b/c", "https://api.github.com", None).is_err());
+    }
+
+    #[test]
+    fn test_github_client_external_id() {
+        let client = GithubClient::new("acme/app", "https://api.github.com", None).unwrap();
+
+        assert_eq!(client.external_id(42), "acme/app#42");
+        assert_eq!(client.issue_number("acme/app#42"), Some(42));
+        assert_eq!(client.issue_number("acme/other#42"), None);
+    }
+
+    #[tokio::test]
+    async fn test_github_client_fetch_issues() {
+        // Arrange
+        let issue = r#"{"number":7,"title":"Fix login","body":null,"labels":[{"name":"bug"}],
+            "milestone":{"due_on":"2026-12-01T08:00:00Z"},"state":"open",
+            "html_url":"https://github.com/acme/app/issues/7","updated_at":"2026-10-01T00:00:00Z"}"#;
+        let pull = r#"{"number":8,"title":"PR","body":"","labels":[],"milestone":null,"state":"open",
+            "html_url":null,"updated_at":"2026-10-01T00:00:00Z","pull_request":{}}"#;
+        let (url, server) = serve(vec![format!("[{},{}]", issue, pull)]);
+        let client = GithubClient::new("acme/app", &url, Some("secret".to_string())).unwrap();
+        let since = DateTime::parse_from_rfc3339("2026-09-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        // Act
+        let issues = client.fetch_issues(Some(since)).await.unwrap();
+
+        // Assert
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0],
+            "GET /repos/acme/app/issues?state=all&per_page=100&page=1&sort=updated&direction=asc&since=2026-09-01T00:00:00Z HTTP/1.1"
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].number, 7);
+        assert_eq!(issues[0].labels, vec!["bug"]);
+        assert_eq!(issues[0].due_date.unwrap().to_string(), "2026-12-01");
+        assert!(!issues[0].closed);
+    }
+
+    #[tokio::test]
+    async fn test_github_client_set_closed_requires_token() {
+        let client = GithubClient::new("acme/app", "http://127.0.0.1:1", None).unwrap();
+
+        let result = client.set_closed(1, true).await;
+
+        assert!(result.unwrap_err().to_string().contains("token"));
+    }
+}
@@ -0,0 +1,234 @@
+//! HTTP通信
+//!
+//! 外部サービスのAPIを呼び出すためのHTTPクライアントです。
+//! 通信は[`ureq`]で行い、httpsの場合はrustlsで暗号化します。
+
+use anyhow::{Context, Result};
+use std::{sync::LazyLock, time::Duration};
+use ureq::{Agent, http};
+
+/// 接続から受信完了までのタイムアウト
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// すべてのリクエストで共有するクライアント（接続を使い回す）
+static AGENT: LazyLock<Agent> = LazyLock::new(|| {
+    Agent::config_builder()
+        .timeout_global(Some(TIMEOUT))
+        // 4xx・5xxもレスポンスとして返し、呼び出し元でサービスごとのエラーにする
+        .http_status_as_error(false)
+        .user_agent(format!("yaru/{}", env!("CARGO_PKG_VERSION")))
+        .build()
+        .into()
+});
+
+/// HTTPリクエスト
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    /// 新しいHttpRequestを作成
+    pub fn new(method: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// ヘッダーを追加する
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 本文を設定する
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// リクエストを送信し、レスポンスを受け取る
+    ///
+    /// 通信はブロッキングで行うため、非同期の処理からはブロッキング用のスレッドで実行します。
+    pub async fn send(self) -> Result<HttpResponse> {
+        tokio::task::spawn_blocking(move || self.send_blocking())
+            .await
+            .context("HTTP request task panicked")?
+    }
+
+    /// リクエストを送信し、レスポンスを受け取る（ブロッキング）
+    pub fn send_blocking(&self) -> Result<HttpResponse> {
+        let mut builder = http::Request::builder()
+            .method(self.method.as_str())
+            .uri(&self.url);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = match &self.body {
+            Some(body) => AGENT.run(builder.body(body.as_slice())?),
+            None => AGENT.run(builder.body(())?),
+        }
+        .with_context(|| format!("{} {} failed", self.method, self.url))?;
+        HttpResponse::read(response).with_context(|| format!("Invalid response from {}", self.url))
+    }
+}
+
+/// HTTPレスポンス
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// ステータスコードを取得
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// 成功（2xx）したか
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// ヘッダーの値を取得（名前の大文字・小文字は区別しない）
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 本文を取得
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// 本文を文字列として取得
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// 受信したレスポンスの本文を最後まで読み込む
+    fn read(mut response: http::Response<ureq::Body>) -> Result<Self> {
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        // データベースのスナップショットも受け取るため、本文の大きさは制限しない
+        let body = response
+            .body_mut()
+            .with_config()
+            .limit(u64::MAX)
+            .read_to_vec()?;
+        Ok(Self {
+            status: response.status().as_u16(),
+            headers,
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    /// 受信したリクエストのヘッダーの値（名前の大文字・小文字は区別しない）
+    fn header<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+        request.lines().find_map(|line| {
+            line.split_once(": ")
+                .filter(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_send_round_trip() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !String::from_utf8_lossy(&received).contains("hello") {
+                let n = stream.read(&mut buffer).unwrap();
+                received.extend_from_slice(&buffer[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 201 Created\r\nContent-Length: 2\r\nX-Test: a:b\r\n\r\nok")
+                .unwrap();
+            String::from_utf8(received).unwrap()
+        });
+
+        // Act
+        let response = HttpRequest::new("POST", format!("http://127.0.0.1:{}/items?a=1", port))
+            .header("Accept", "application/json")
+            .body("hello")
+            .send()
+            .await
+            .unwrap();
+
+        // Assert
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /items?a=1 HTTP/1.1\r\n"));
+        assert_eq!(
+            header(&request, "Host"),
+            Some(&*format!("127.0.0.1:{}", port))
+        );
+        assert_eq!(header(&request, "Accept"), Some("application/json"));
+        assert_eq!(header(&request, "Content-Length"), Some("5"));
+        assert!(header(&request, "User-Agent").is_some_and(|agent| agent.starts_with("yaru/")));
+        assert_eq!(response.status(), 201);
+        assert_eq!(response.header("x-test"), Some("a:b"));
+        assert_eq!(response.text(), "ok");
+    }
+
+    #[tokio::test]
+    async fn test_send_returns_error_status_as_response() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0u8; 1024];
+            let _ = stream.read(&mut buffer).unwrap();
+            stream
+                .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 2\r\n\r\nno")
+                .unwrap();
+        });
+
+        // Act
+        let response = HttpRequest::new("GET", format!("http://127.0.0.1:{}/", port))
+            .send()
+            .await
+            .unwrap();
+
+        // Assert: 失敗のステータスもエラーにせず、呼び出し元で扱えるようにする
+        server.join().unwrap();
+        assert_eq!(response.status(), 404);
+        assert!(!response.is_success());
+        assert_eq!(response.body(), b"no");
+    }
+
+    #[test]
+    fn test_send_rejects_unsupported_url() {
+        assert!(
+            HttpRequest::new("GET", "ftp://example.com/")
+                .send_blocking()
+                .is_err()
+        );
+    }
+}
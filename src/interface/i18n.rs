@@ -0,0 +1,5 @@
+pub mod catalog;
+pub mod locale;
+
+pub use catalog::Msg;
+pub use locale::{Locale, set_locale};
@@ -0,0 +1,612 @@
+use crate::interface::i18n::locale::{Locale, current_locale};
+use std::fmt::Display;
+
+/// メッセージカタログを定義する
+///
+/// 各メッセージに英語と日本語の文言を対応付け、`Msg`列挙型を生成します。
+/// 文言中の`{0}`, `{1}`…は[`Msg::format`]で引数に置き換えられます。
+/// メッセージの前に書いた属性は`Msg`の列挙子に付けられます。
+macro_rules! messages {
+    ($($(#[$attr:meta])* $name:ident => $en:literal, $ja:literal;)*) => {
+        /// ユーザーに表示するメッセージ
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Msg {
+            $($(#[$attr])* $name,)*
+        }
+
+        impl Msg {
+            /// カタログに登録されたすべてのメッセージ
+            #[cfg(test)]
+            const ALL: &'static [Msg] = &[$(Msg::$name,)*];
+
+            /// 指定した言語の文言を取得
+            pub fn text_in(self, locale: Locale) -> &'static str {
+                match locale {
+                    Locale::En => match self {
+                        $(Msg::$name => $en,)*
+                    },
+                    Locale::Ja => match self {
+                        $(Msg::$name => $ja,)*
+                    },
+                }
+            }
+        }
+    };
+}
+
+messages! {
+    // 一覧・詳細
+    NoTasksFound => "No tasks found", "タスクが見つかりません";
+    NoTagsFound => "No tags found", "タグが見つかりません";
+    TaskListHeader => "Task list ({0} tasks):", "タスク一覧（{0}件）:";
+    TaskGroupHeader => "■ {0} ({1} tasks)", "■ {0}（{1}件）";
+    TagListHeader => "Tag list ({0} tags):", "タグ一覧（{0}件）:";
+    SearchResultsHeader => "Search results ({0} items):", "検索結果（{0}件）:";
+    SearchNoResults => "No tasks found matching search keyword \"{0}\"", "検索キーワード「{0}」に一致するタスクが見つかりません";
+
+    // 項目名
+    FieldId => "ID", "ID";
+    FieldTitle => "Title", "タイトル";
+    FieldName => "Name", "名前";
+    FieldPath => "Path", "パス";
+    FieldDescription => "Description", "説明";
+    FieldStatus => "Status", "ステータス";
+    FieldPriority => "Priority", "優先度";
+    FieldTags => "Tags", "タグ";
+    FieldDueDate => "Due Date", "期限日";
+    FieldCustomFields => "Custom Fields", "カスタムフィールド";
+    FieldChecklist => "Checklist", "チェックリスト";
+    FieldGitLinks => "Git", "Git";
+    FieldExternalRefs => "External", "連携先";
+    FieldLinks => "Links", "リンク";
+    FieldRecurrence => "Repeat", "繰り返し";
+    FieldSnoozedUntil => "Snoozed Until", "スヌーズ期限";
+    FieldEstimate => "Estimate", "見積もり";
+    FieldContext => "Context", "コンテキスト";
+    FieldAssignee => "Assignee", "担当者";
+    FieldStale => "Stale", "放置";
+    FieldCompletedAt => "Completed At", "完了日時";
+    FieldCreatedAt => "Created At", "作成日時";
+    FieldUpdatedAt => "Updated At", "更新日時";
+
+    // タスクの関連情報
+    RelatedInfoHeader => "Related info:", "関連情報:";
+    FieldDueIn => "Due In", "期限まで";
+    FieldAge => "Age", "経過日数";
+    FieldLastUpdated => "Last Updated", "最終更新";
+    FieldRelatedTasks => "Related Tasks", "関連タスク";
+    FieldHistory => "History", "履歴";
+    OneDay => "1 day", "1日";
+    DayCount => "{0} days", "{0}日";
+    OneDayAgo => "1 day ago", "1日前";
+    DaysAgo => "{0} days ago", "{0}日前";
+    HistoryCreated => "Created", "作成";
+    HistoryUpdated => "Updated", "更新";
+    HistoryCompleted => "Completed", "完了";
+
+    // 期限
+    DueToday => "today", "今日";
+    DueTomorrow => "tomorrow", "明日";
+    DueInDays => "in {0} days", "あと{0}日";
+    DueOneDayOverdue => "1 day overdue", "1日超過";
+    DueDaysOverdue => "{0} days overdue", "{0}日超過";
+    BadgeOverdue => "[OVERDUE]", "[期限切れ]";
+    BadgeStale => "[STALE]", "[放置]";
+    NoStaleTasks => "No stale tasks", "放置されているタスクはありません";
+    BadgeToday => "[TODAY]", "[今日]";
+
+    // ステータス・優先度
+    StatusPending => "Pending", "未着手";
+    StatusInProgress => "In Progress", "進行中";
+    StatusCompleted => "Completed", "完了";
+    PriorityCritical => "Critical", "緊急";
+    PriorityHigh => "High", "高";
+    PriorityMedium => "Medium", "中";
+    PriorityLow => "Low", "低";
+    // 一覧・詳細に表示する値（英語ではコマンド引数と同じ表記）
+    ValuePending => "pending", "未着手";
+    ValueInProgress => "in_progress", "進行中";
+    ValueCompleted => "completed", "完了";
+    ValueCritical => "critical", "緊急";
+    ValueHigh => "high", "高";
+    ValueMedium => "medium", "中";
+    ValueLow => "low", "低";
+
+    // 今日の予定
+    AgendaOverdue => "Overdue", "期限切れ";
+    AgendaDueToday => "Due today", "今日期限";
+    AgendaInProgress => "In progress", "進行中";
+    AgendaEmpty => "Nothing overdue or due, and no tasks in progress", "期限切れ・期限が近い・進行中のタスクはありません";
+
+    // 作業量
+    WorkloadHeader => "Workload {0} - {1} (capacity {2} per day)", "作業量 {0} ～ {1}（1日の作業可能時間: {2}）";
+    WorkloadDate => "Date", "日付";
+    WorkloadTasks => "Tasks", "タスク数";
+    WorkloadLoad => "Load", "負荷";
+    WorkloadUnestimated => "(+{0} unestimated)", "（見積もりなし {0}件）";
+    WorkloadOver => "over by {0}", "{0} 超過";
+    WorkloadTotal => "Total: {0}", "合計: {0}";
+    WorkloadOvercommitted => "{0} day(s) over capacity", "作業可能時間を超える日が {0} 日あります";
+    AccuracyActual => "Actual", "実績";
+    AccuracyVariance => "Variance", "差";
+    AccuracyVariancePercent => "Variance %", "差（%）";
+    AccuracyOver => "Overall, tasks took {0}% longer than estimated", "全体で見積もりより {0}% 長くかかっています";
+    AccuracyUnder => "Overall, tasks took {0}% less time than estimated", "全体で見積もりより {0}% 短く終わっています";
+    AccuracyOnTarget => "Overall, tasks took as long as estimated", "全体で見積もりどおりの時間で終わっています";
+    AccuracyNoData => "No tasks with both an estimate and recorded time", "見積もりと作業記録の両方があるタスクはありません";
+    AccuracyUntracked => "{0} estimated task(s) have no recorded time and are not included", "見積もりがあり作業記録のないタスク {0} 件は含まれていません";
+
+    // 統計
+    StatsTitle => "Task Statistics Summary", "タスク統計サマリー";
+    StatsTotalTasks => "Total tasks: {0}", "タスク総数: {0}";
+    StatsByStatus => "[By Status]", "[ステータス別]";
+    StatsPriorityStatusMatrix => "[Priority × Status Matrix]", "[優先度 × ステータス]";
+    StatsDueDates => "[Due Dates]", "[期限]";
+    StatsTopTags => "[Top Tags (Top {0})]", "[タグ別（上位{0}件）]";
+    StatsDueSummary => "Overdue: {0} tasks, Due today: {1} tasks, Due within {2} days: {3} tasks, Due within {4} days: {5} tasks", "期限切れ: {0}件、今日期限: {1}件、{2}日以内: {3}件、{4}日以内: {5}件";
+    StatsCount => "Count", "件数";
+    StatsPercentage => "Percentage (%)", "割合 (%)";
+    StatsProgress => "Progress", "進捗";
+    StatsTotal => "Total", "合計";
+    StatsTagName => "Tag Name", "タグ名";
+    StatsByAssignee => "[Assignee × Status]", "[担当者 × ステータス]";
+    StatsUnassigned => "(Unassigned)", "（担当者なし）";
+    StatsFlowTitle => "Task Flow Statistics", "タスクの流れの統計";
+    StatsFlowTasks => "Tasks analysed: {0}", "集計したタスク: {0}件";
+    StatsFlowMetric => "Metric", "指標";
+    StatsFlowAverage => "Average", "平均";
+    StatsFlowTimeInPending => "Time in Pending", "保留中の滞在時間";
+    StatsFlowTimeInProgress => "Time in In Progress", "進行中の滞在時間";
+    StatsFlowCycleTime => "Cycle time (started → completed)", "サイクルタイム（着手→完了）";
+    StatsFlowLeadTime => "Lead time (created → completed)", "リードタイム（作成→完了）";
+    SummaryOneline => "{0} overdue · {1} today · {2} open", "期限切れ {0} · 今日 {1} · 未完了 {2}";
+    SummaryDetail => "Overdue: {0}\nDue today: {1}\nOpen: {2}", "期限切れ: {0}件\n今日期限: {1}件\n未完了: {2}件";
+    SummaryStreak => "Completed today: {0}\nStreak: {1} days (longest {2} days)\nCompleted in total: {3}", "今日の完了: {0}件\n連続: {1}日（最長 {2}日）\n完了の合計: {3}件";
+
+    // データベースの状態
+    DbPath => "Path", "パス";
+    DbSize => "Size", "サイズ";
+    DbAppliedMigrations => "Applied Migrations", "適用済みマイグレーション";
+    DbPendingMigrations => "Pending Migrations", "未適用マイグレーション";
+    DbRows => "Rows: {0}", "行数: {0}";
+    DbEncrypted => "Encrypted", "暗号化";
+    DbIntegrityCheck => "Integrity Check", "整合性チェック";
+    DbInMemory => "(in-memory)", "(メモリ上)";
+    DbTableMissing => "(missing)", "(未作成)";
+    DbNone => "none", "なし";
+    Yes => "yes", "はい";
+    No => "no", "いいえ";
+    Unknown => "unknown", "不明";
+
+    // タスク操作
+    TaskAdded => "Task added: [{0}] {1}", "タスクを追加しました: [{0}] {1}";
+    TasksAdded => "Added {0} tasks", "{0}件のタスクを追加しました";
+    NoTasksToAdd => "No tasks to add", "追加するタスクはありません";
+    WarningPastDueDate => "The due date {0} is already in the past", "期限日 {0} は既に過ぎています";
+    TaskUpdated => "Task updated: [{0}] {1}", "タスクを更新しました: [{0}] {1}";
+    TaskDeleted => "Task ID {0} deleted", "タスクID {0} を削除しました";
+    ConfirmDeleteTask => "Delete task ID {0}?", "タスクID {0} を削除しますか？";
+    TaskCompleted => "Task completed: [{0}] {1}", "タスクを完了しました: [{0}] {1}";
+    FollowUpAdded => "Follow-up task added: [{0}] {1}", "フォローアップのタスクを追加しました: [{0}] {1}";
+    ConfirmFollowUp => "Create a follow-up task?", "フォローアップのタスクを作成しますか？";
+    PromptFollowUpTitle => "Follow-up title:", "フォローアップのタイトル:";
+    FollowUpTitle => "{0} (follow-up)", "{0}（フォローアップ）";
+    TagAdded => "Tag added: [{0}] {1}", "タグを追加しました: [{0}] {1}";
+    TagExists => "Tag already exists: [{0}] {1}", "タグは既に存在します: [{0}] {1}";
+    TagUpdated => "Tag updated: [{0}] {1}", "タグを更新しました: [{0}] {1}";
+    TagDeleted => "Tag ID {0} deleted", "タグID {0} を削除しました";
+    ConfirmDeleteTag => "Delete tag ID {0}?", "タグID {0} を削除しますか？";
+    AddCancelled => "Task was not added", "タスクを追加しませんでした";
+    ConfirmAddDuplicate => "Add the task anyway?", "このままタスクを追加しますか？";
+    WarningSimilarTasks => "Similar open tasks already exist:\n{0}", "似ている未完了のタスクがあります:\n{0}";
+    DeletionCancelled => "Deletion cancelled", "削除を取り消しました";
+    ConfirmApplyDeletions => "Apply {0} operations including {1} deletion(s)?", "{1}件の削除を含む{0}件の操作を実行しますか？";
+    ApplyCancelled => "No operations were applied", "操作を実行しませんでした";
+    ConfirmDecrypt => "Decrypt the database? Titles and descriptions will be stored in plain text", "データベースの暗号化を解除しますか？タイトルと説明が平文で保存されます";
+    DecryptCancelled => "The database was not decrypted", "暗号化を解除しませんでした";
+    ChecklistHeader => "Checklist of [{0}] {1} ({2}):", "[{0}] {1} のチェックリスト（{2}）:";
+    NoChecklistItems => "No checklist items", "チェックリストの項目はありません";
+    ChecklistItemAdded => "Checklist item added: {0}", "チェックリストに項目を追加しました: {0}";
+    ChecklistItemToggled => "Checklist item updated: {0}", "チェックリストの項目を更新しました: {0}";
+    ChecklistItemRemoved => "Checklist item {0} removed", "チェックリストの項目 {0} を削除しました";
+    TaskSnoozed => "Snoozed task [{0}] {1} until {2}", "タスク [{0}] {1} を {2} までスヌーズしました";
+    TaskUnsnoozed => "Unsnoozed task [{0}] {1}", "タスク [{0}] {1} のスヌーズを解除しました";
+    FocusStarted => "Focusing on [{0}] {1}", "[{0}] {1} にフォーカスしました";
+    FocusStartedWithTimer => "Focusing on [{0}] {1} for {2}", "[{0}] {1} に{2}フォーカスします";
+    FocusStatus => "Focus: [{0}] {1} ({2})", "フォーカス: [{0}] {1}（{2}）";
+    FocusElapsed => "{0} elapsed", "経過 {0}";
+    FocusRemaining => "{0} left", "残り {0}";
+    FocusTimeUp => "time's up", "時間切れ";
+    FocusNotSet => "No focus set (start one with yaru focus <id>)", "フォーカス中のタスクはありません（yaru focus <id> で始めます）";
+    FocusDone => "Completed [{0}] {1} after {2} of focus", "[{0}] {1} を完了しました（フォーカス {2}）";
+    FocusCleared => "Stopped focusing on [{0}] {1}", "[{0}] {1} のフォーカスを終えました";
+    PickSuggestion => "How about [{0}] {1}? (priority: {2}, due: {3})", "[{0}] {1} はどうでしょう？（優先度: {2}、期限: {3}）";
+    PickNoTasks => "No open tasks to pick from", "選べる未完了のタスクがありません";
+    PickNoMoreTasks => "No other tasks to pick from", "ほかに選べるタスクがありません";
+    PickStarted => "Started [{0}] {1}", "[{0}] {1} に着手しました";
+    PromptPickAction => "What do you want to do?", "どうしますか？";
+    PickActionStart => "Start it (mark as In Progress)", "着手する（進行中にする）";
+    PickActionFocus => "Focus on it", "フォーカスする";
+    PickActionAnother => "Pick another", "別のタスクを選ぶ";
+    PickActionSkip => "Not now", "今はやめておく";
+    ContextSet => "Switched to context {0} (tasks in other contexts are hidden; use --all-contexts to show them)", "コンテキストを {0} に切り替えました（ほかのコンテキストのタスクは表示されません。--all-contexts ですべて表示します）";
+    ContextCleared => "Context cleared (showing tasks in all contexts)", "コンテキストを解除しました（すべてのコンテキストのタスクを表示します）";
+    ContextCurrent => "Current context: {0}", "現在のコンテキスト: {0}";
+    ContextNotSet => "No context set (showing tasks in all contexts)", "コンテキストは設定されていません（すべてのコンテキストのタスクを表示します）";
+    ContextListItem => "{0} {1} ({2} open)", "{0} {1}（未完了 {2}件）";
+    NoContexts => "No open tasks have a context", "コンテキストが設定された未完了のタスクはありません";
+    NoAuditEntries => "No invocations recorded in {0} (set enabled = true in the [audit] section of config.toml)", "{0} に実行記録はありません（config.toml の [audit] セクションで enabled = true を設定してください）";
+    AuditEntryLine => "{0}  {1}  {2} ms  yaru {3}", "{0}  {1}  {2} ms  yaru {3}";
+    AuditResultOk => "ok   ", "成功";
+    AuditResultError => "error", "失敗";
+    PurgeAllNothing => "No yaru data found to remove", "削除するyaruのデータはありません";
+    PurgeAllTargets => "The following {0} file(s) will be removed:", "次の{0}件のファイルを削除します:";
+    PurgeAllDryRun => "Nothing was removed; run again with --confirm to delete these files", "まだ削除していません。削除するには --confirm を付けて実行してください";
+    ConfirmPurgeAll => "Permanently delete {0} file(s) including the database, backups and config?", "データベース・バックアップ・設定を含む{0}件のファイルを完全に削除しますか？";
+    PurgeAllCancelled => "Nothing was removed", "何も削除しませんでした";
+    PurgedAll => "Removed {0} file(s)", "{0}件のファイルを削除しました";
+    GitLinked => "Linked task [{0}] {1} to {2}", "タスク [{0}] {1} を {2} に紐づけました";
+    LinkOpened => "Opened {0}", "{0} を開きました";
+    GitTaskCompleted => "Completed task [{0}] {1} (merged)", "タスク [{0}] {1} を完了にしました（マージ済み）";
+
+    // 対話入力
+    SelectTaskToShow => "Select task to show", "表示するタスクを選択";
+    SelectTaskToEdit => "Select task to edit", "編集するタスクを選択";
+    SelectTaskToDelete => "Select task to delete", "削除するタスクを選択";
+    SelectTaskToComplete => "Select task to complete", "完了するタスクを選択";
+    SelectTagToShow => "Select tag to show", "表示するタグを選択";
+    SelectTagToEdit => "Select tag to edit", "編集するタグを選択";
+    SelectTagToDelete => "Select tag to delete", "削除するタグを選択";
+    DateSelectHelp => "Arrows to move, [ ] to change month, { } to change year, Enter to select", "矢印キーで移動、[ ]で月、{ }で年を切り替え、Enterで選択";
+    PickerHelp => "Type to filter, ↑↓ to move, Enter to select", "入力で絞り込み、↑↓で移動、Enterで選択";
+    PromptTaskTitle => "Enter task title", "タスクのタイトルを入力";
+    PromptTaskDescription => "Enter task description", "タスクの説明を入力";
+    PromptTagName => "Enter tag name", "タグ名を入力";
+    PromptTagDescription => "Enter tag description", "タグの説明を入力";
+    PromptDescription => "Enter description", "説明を入力";
+    PromptSelectStatus => "Select status", "ステータスを選択";
+    PromptSelectPriority => "Select priority", "優先度を選択";
+    PromptSelectTags => "Select tags (Space to select, Enter to confirm)", "タグを選択（Spaceで選択、Enterで確定）";
+    PromptSelectFields => "Select fields to edit (Space to select, Enter to confirm)", "編集する項目を選択（Spaceで選択、Enterで確定）";
+    PromptSetDueDate => "Set due date?", "期限日を設定しますか？";
+    PromptSelectDueDate => "Select due date", "期限日を選択";
+    PromptTitle => "Title:", "タイトル:";
+    PromptName => "Name:", "名前:";
+    PromptStatus => "Status:", "ステータス:";
+    PromptPriority => "Priority:", "優先度:";
+    PromptDueDate => "Due date:", "期限日:";
+    OptionClearDueDate => "Clear due date", "期限日を削除";
+    OptionSetDueDate => "Set new due date", "新しい期限日を設定";
+    PromptSearchKeyword => "Search keyword:", "検索キーワード:";
+    HelpSearchKeyword => "Multiple keywords can be specified separated by spaces (AND condition)", "スペース区切りで複数のキーワードを指定できます（AND条件）";
+    ErrorEmptyKeyword => "Please enter at least one character.", "1文字以上入力してください。";
+    PromptPassphrase => "Passphrase:", "パスフレーズ:";
+    PromptConfirmPassphrase => "Confirm passphrase:", "パスフレーズ（確認）:";
+    PromptTypeToConfirm => "{0} Type {1} to confirm:", "{0} 確認のため {1} と入力してください:";
+
+    // データベース管理
+    DbUpToDate => "Database is up to date", "データベースは最新です";
+    DbMigrationsApplied => "Applied {0} migration(s):\n  {1}", "{0}件のマイグレーションを適用しました:\n  {1}";
+    DbEncryptedDone => "Database encrypted ({0} tasks)", "データベースを暗号化しました（{0}件のタスク）";
+    DbDecryptedDone => "Database decrypted ({0} tasks)", "データベースの暗号化を解除しました（{0}件のタスク）";
+
+    // デーモン
+    DaemonListening => "Daemon listening on {0}", "デーモンが{0}で待ち受けています";
+    DaemonRunning => "Daemon is running (pid {0}, version {1}) on {2}", "デーモンは起動しています（pid {0}、バージョン {1}）: {2}";
+    DaemonNotRunning => "Daemon is not running on {0}", "デーモンは起動していません: {0}";
+    DaemonStopped => "Daemon stopped", "デーモンを停止しました";
+
+    // git連携
+    GitHookCreated => "Installed {0} hook: {1}", "{0}フックを作成しました: {1}";
+    GitHookAppended => "Added yaru to existing {0} hook: {1}", "既存の{0}フックにyaruを追加しました: {1}";
+    GitHookAlreadyInstalled => "{0} hook is already installed: {1}", "{0}フックは導入済みです: {1}";
+
+    // 外部サービスとの同期
+    SyncTaskImported => "Imported [{0}] {1}", "[{0}] {1} を取り込みました";
+    SyncTaskUpdated => "Updated [{0}] {1}", "[{0}] {1} を更新しました";
+    SyncTaskPushed => "Pushed status of [{0}] {1}", "[{0}] {1} のステータスを反映しました";
+    SyncSummary => "Sync finished: {0} imported, {1} updated, {2} pushed", "同期しました: 取り込み {0}件、更新 {1}件、反映 {2}件";
+    SyncSideLocal => "this database", "このデータベース";
+    SyncSideOther => "other database", "相手のデータベース";
+    SyncFileCreated => "+ {0} ({1})", "+ {0}（{1}）";
+    SyncFileUpdated => "~ {0} ({1})", "~ {0}（{1}）";
+    SyncFileDeleted => "- {0} ({1})", "- {0}（{1}）";
+    SyncFileConflict => "{0} was changed in both databases", "{0} は両方のデータベースで変更されています";
+    SyncFileConflictVersion => "  {0}: {1} ({2}, updated {3})", "  {0}: {1}（{2}、更新 {3}）";
+    SyncFileConflictDeleted => "  {0}: deleted", "  {0}: 削除済み";
+    PromptSyncConflict => "Which version do you want to keep?", "どちらの内容を残しますか？";
+    SyncFileKeepLocal => "Keep this database's version", "このデータベースの内容を残す";
+    SyncFileKeepOther => "Keep the other database's version", "相手のデータベースの内容を残す";
+    SyncFileSkip => "Decide later (leave both as they are)", "あとで決める（両方ともそのままにする）";
+    SyncFileConflictSkipped => "Skipped conflicting task {0} (resolve it interactively or with --prefer)", "競合しているタスク {0} をスキップしました（対話的に、または --prefer で解決します）";
+    SyncFileDryRunSummary => "Dry run: {0} changes to this database and {1} to the other database", "ドライラン: このデータベースを {0}件、相手のデータベースを {1}件変更します";
+    SyncFileSummary => "Sync finished: {0} changes to this database, {1} to the other database, {2} conflicts skipped", "同期しました: このデータベースの変更 {0}件、相手のデータベースの変更 {1}件、スキップした競合 {2}件";
+    RemotePushed => "Pushed an encrypted snapshot ({0}) to {1}", "暗号化したスナップショット（{0}）を {1} に送信しました";
+    RemotePulled => "Replaced the database with the snapshot from {0} (previous database backed up to {1})", "{0} のスナップショットでデータベースを置き換えました（元のデータベースは {1} に退避しました）";
+    RemoteUpToDate => "Already up to date with {0}", "{0} と同じ内容です（変更はありません）";
+    ConfirmRemotePull => "Replace the database {0} with the snapshot from {1}? (the current database is backed up)", "データベース {0} を {1} のスナップショットで置き換えますか？（元のデータベースは退避されます）";
+    RemotePullCancelled => "The database was not replaced", "データベースを置き換えませんでした";
+
+    // 他のツールからの取り込み
+    ImportPlanTag => "+ tag {0}", "+ タグ {0}";
+    ImportPlanTask => "+ {0} ({1})", "+ {0}（{1}）";
+    ImportPlanSkip => "= {0} (already imported)", "= {0}（取り込み済み）";
+    ImportDryRunSummary => "Dry run: {0} tasks and {1} tags would be created, {2} already imported", "ドライラン: タスク {0}件とタグ {1}件を作成します（取り込み済み {2}件）";
+    ImportSummary => "Imported {0} tasks and created {1} tags ({2} already imported)", "タスク {0}件を取り込み、タグ {1}件を作成しました（取り込み済み {2}件）";
+    #[cfg_attr(not(feature = "mail"), allow(dead_code))]
+    IngestNoMessageId => "Skipped {0} messages without a Message-ID", "Message-IDのないメール {0}件を取り込みませんでした";
+
+    // 定期実行
+    CronTaskGenerated => "Created [{0}] {1} due {2} (next occurrence of [{3}])", "[{0}] {1}（期限: {2}）を作成しました（[{3}] の次の回）";
+    CronTaskNotified => "Notified overdue [{0}] {1}", "期限切れの [{0}] {1} を通知しました";
+    CronTaskArchived => "Archived [{0}] {1}", "[{0}] {1} をアーカイブしました";
+    CronOverdueTagged => "Tagged [{0}] {1} with {2} (overdue)", "期限切れの [{0}] {1} にタグ {2} を付けました";
+    CronOverdueUntagged => "Removed tag {2} from [{0}] {1} (no longer overdue)", "期限切れでなくなった [{0}] {1} からタグ {2} を外しました";
+    CronSummary => "Cron finished: {0} recurring tasks created, {1} overdue notified, {2} archived", "定期実行が完了しました: 繰り返しのタスクの作成 {0}件、期限切れの通知 {1}件、アーカイブ {2}件";
+    NotifyOverdueTitle => "yaru: task overdue", "yaru: タスクの期限切れ";
+    NotifyOverdueBody => "[{0}] {1} (due {2})", "[{0}] {1}（期限: {2}）";
+
+    // プロファイル
+    ProfileSummary => "Profile: {0} ms total, {1} SQL queries ({2} ms), {3} rows fetched, {4} rows affected", "プロファイル: 合計 {0} ms、SQLクエリ {1}件（{2} ms）、取得 {3}行、変更 {4}行";
+    ProfileRepeatedQueries => "Queries run more than once (possible N+1):", "2回以上実行されたクエリ（N+1の可能性）:";
+    ProfileRepeatedQuery => "  {0}x {1}", "  {0}回 {1}";
+
+    // エラー
+    ErrorTagIdNotExist => "Tag ID does not exist: {0}", "タグIDが存在しません: {0}";
+    ErrorIdNotExist => "{0} ID {1} does not exist", "{0}ID {1} は存在しません";
+    ErrorIdAmbiguous => "{0} ID prefix '{1}' is ambiguous: matches {2} ({3} candidates)", "{0}IDの前方一致 '{1}' は曖昧です: {2} に一致します（候補{3}件）";
+    ErrorIdPrefixNotAllowed => "{0} ID {1} does not exist (it is a prefix of {2}; commands that change or delete need the full ID)", "{0}ID {1} は存在しません（{2} の前方一致ですが、変更・削除するコマンドでは完全なIDを指定してください）";
+    ErrorConfirmationRequired => "Confirmation required: {0} (pass --yes to confirm without a terminal)", "確認が必要です: {0}（端末がない場合は --yes を指定してください）";
+    ErrorSelectionCancelled => "Selection was cancelled", "選択がキャンセルされました";
+    ErrorKeywordCancelled => "Keyword input was cancelled", "キーワードの入力がキャンセルされました";
+    ErrorInputTaskTitle => "Failed to input task title", "タスクのタイトルを入力できませんでした";
+    WarningPrefix => "Warning: {0}", "警告: {0}";
+    ErrorPrefix => "Error: {0}", "エラー: {0}";
+    ErrorInputTitle => "Failed to input title", "タイトルを入力できませんでした";
+    ErrorInputTagName => "Failed to input tag name", "タグ名を入力できませんでした";
+    ErrorInputName => "Failed to input name", "名前を入力できませんでした";
+    ErrorIntegrityCheckFailed => "Database integrity check failed", "データベースの整合性チェックに失敗しました";
+    ErrorAlreadyEncrypted => "Database is already encrypted", "データベースはすでに暗号化されています";
+    ErrorNotEncrypted => "Database is not encrypted", "データベースは暗号化されていません";
+    ErrorDaemonRequiresSqlite => "The daemon is only available with the SQLite backend", "デーモンはSQLiteバックエンドでのみ使用できます";
+    ErrorEmptyPassphrase => "Passphrase must not be empty", "パスフレーズを入力してください";
+    ErrorDbRequiresSqlite => "The db command is only available with the SQLite backend", "dbコマンドはSQLiteバックエンドでのみ使用できます";
+    ErrorFileSyncRequiresSqlite => "Syncing with a database file is only available with the SQLite backend", "データベースファイルとの同期はSQLiteバックエンドでのみ使用できます";
+    ErrorRemoteSyncRequiresSqlite => "Syncing with remote storage is only available with the SQLite backend", "リモートストレージとの同期はSQLiteバックエンドでのみ使用できます";
+    ErrorSnapshotRequiresEphemeral => "--snapshot requires --ephemeral (or YARU_DB=memory)", "--snapshot には --ephemeral（または YARU_DB=memory）が必要です";
+    ErrorDatabaseFileNotFound => "Database file not found: {0}", "データベースファイルが見つかりません: {0}";
+    ErrorSyncWithItself => "Cannot sync a database with itself: {0}", "データベースをそれ自身と同期することはできません: {0}";
+    ErrorRemoteChanged => "The snapshot at {0} changed since the last push or pull; run `yaru sync remote pull` first, or push with --force to overwrite it", "{0} のスナップショットは前回のpush・pullの後に変更されています。先に `yaru sync remote pull` を実行するか、--force を指定して上書きしてください";
+    ErrorRemoteNotConfigured => "Remote storage is not configured (set kind = \"s3\" or \"webdav\" in the [remote] section of config.toml)", "リモートストレージが設定されていません（config.toml の [remote] セクションで kind = \"s3\" または \"webdav\" を指定してください）";
+    ErrorS3BucketRequired => "[remote] bucket is required for S3 storage", "S3に保存するには [remote] の bucket が必要です";
+    ErrorS3CredentialsMissing => "S3 credentials are not configured (set [remote] access_key_id and secret_access_key, or AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY)", "S3の認証情報が設定されていません（[remote] の access_key_id と secret_access_key、または AWS_ACCESS_KEY_ID と AWS_SECRET_ACCESS_KEY を指定してください）";
+    #[cfg_attr(not(feature = "mail"), allow(dead_code))]
+    ErrorNoMailbox => "No mailbox to read: pass --maildir or --imap, or set maildir or imap_host in the [mail] section", "読み込むメールボックスがありません。--maildir か --imap を指定するか、[mail] セクションで maildir か imap_host を設定してください";
+    ErrorApplyOperationFailed => "Operation {0} ({1}) failed; no changes were made", "操作 {0}（{1}）が失敗したため、何も変更していません";
+    ErrorTaskAlreadyCompleted => "Task {0} is already completed", "タスク {0} はすでに完了しています";
+    ErrorTaskHasNoLinks => "Task {0} has no links in its title or description", "タスク {0} のタイトルと説明にリンクがありません";
+    ErrorTaskLinkOutOfRange => "Task {0} has only {1} link(s); choose --nth between 1 and {1}", "タスク {0} のリンクは{1}件です。--nth には1から{1}を指定してください";
+    ErrorTaskTitleMissing => "Task title is missing", "タスクのタイトルがありません";
+    ErrorInvalidTaskOnLine => "Invalid task on line {0}", "{0}行目のタスクが不正です";
+    ErrorInvalidPeriod => "Invalid period: '{0}'. Use a period such as +3d, +2w or +3bd", "期間が不正です: '{0}'。+3d・+2w・+3bd のように指定してください";
+    ErrorPeriodTooLong => "Period is too long: {0}", "期間が長すぎます: {0}";
+    ErrorTemplateStale => "'{{stale}}' is not available in templates; use --columns stale instead", "テンプレートでは '{{stale}}' を使用できません。--columns stale を使用してください";
+    ErrorUnknownTemplateField => "Unknown template field: '{{{0}}}' (available: id, title, description, status, priority, tags, due_date, completed_at, created_at, updated_at, checklist, repeat, estimate, context, assignee, field.KEY)", "テンプレートの項目が不明です: '{{{0}}}'（使用できる項目: id, title, description, status, priority, tags, due_date, completed_at, created_at, updated_at, checklist, repeat, estimate, context, assignee, field.KEY）";
+    ErrorUnclosedTemplate => "Unclosed '{{' in template: {0}", "テンプレートの '{{' が閉じられていません: {0}";
+    ErrorInvalidColor => "Invalid color: {0}", "色が不正です: {0}";
+    ErrorInvalidDateFormat => "Invalid date format: {0}", "日付の形式が不正です: {0}";
+    ErrorInvalidTimezone => "Failed to parse display timezone: {0}", "表示のタイムゾーンを読み取れません: {0}";
+    ErrorInvalidConfig => "Invalid {0} in config: {1}", "設定の {0} が不正です: {1}";
+    ErrorInvalidWeekStart => "Invalid display week_start in config: {0} (expected a weekday such as monday or sunday)", "設定の display week_start が不正です: {0}（monday や sunday のような曜日を指定してください）";
+    ErrorConnectDatabase => "Failed to connect to database", "データベースに接続できませんでした";
+    ErrorRemoteSyncRequiresFile => "Remote sync requires a database file", "リモートストレージとの同期にはデータベースファイルが必要です";
+    ErrorWebDavUrlRequired => "[remote] url is required for WebDAV storage", "WebDAVに保存するには [remote] の url が必要です";
+    #[cfg_attr(not(feature = "mail"), allow(dead_code))]
+    ErrorImapUserRequired => "An IMAP user is required (pass --user or set [mail] imap_user)", "IMAPのユーザー名が必要です（--user を指定するか、[mail] の imap_user を設定してください）";
+    #[cfg_attr(not(feature = "mail"), allow(dead_code))]
+    ErrorImapPasswordRequired => "An IMAP password is required (set [mail] imap_password or YARU_IMAP_PASSWORD)", "IMAPのパスワードが必要です（[mail] の imap_password か YARU_IMAP_PASSWORD を設定してください）";
+    ErrorReadOperationsFromStdin => "Failed to read operations from stdin", "標準入力から操作を読み込めませんでした";
+    ErrorReadTasksFromStdin => "Failed to read tasks from stdin", "標準入力からタスクを読み込めませんでした";
+    ErrorInvalidJsonInput => "Invalid JSON input", "JSONの入力が不正です";
+    ErrorInvalidYamlInput => "Invalid YAML input", "YAMLの入力が不正です";
+    ErrorInvalidOperationAtIndex => "Invalid operation at index {0}", "{0}番目の操作が不正です";
+    ErrorAddTaskFailed => "Failed to add task '{0}'; no tasks were added", "タスク「{0}」を追加できなかったため、どのタスクも追加していません";
+    ErrorAddTasksFailed => "Failed to add tasks; no tasks were added", "タスクを保存できなかったため、どのタスクも追加していません";
+    ErrorTagNameNotExist => "Tag '{0}' does not exist", "タグ「{0}」は存在しません";
+    ErrorReadFile => "Failed to read {0}", "{0} を読み込めませんでした";
+    ErrorWriteFile => "Failed to write {0}", "{0} に書き込めませんでした";
+    ErrorOpenFile => "Failed to open {0}", "{0} を開けませんでした";
+    ErrorOverwriteFile => "Failed to overwrite {0}", "{0} を上書きできませんでした";
+    ErrorReplaceFile => "Failed to replace {0}", "{0} を置き換えられませんでした";
+    ErrorRemoveFile => "Failed to remove {0}", "{0} を削除できませんでした";
+    ErrorCreateDirectory => "Failed to create directory: {0}", "ディレクトリを作成できませんでした: {0}";
+    ErrorReadDirectory => "Failed to read directory: {0}", "ディレクトリを読み込めませんでした: {0}";
+    ErrorInvalidStateFile => "Invalid state file: {0}", "状態ファイルが不正です: {0}";
+    ErrorReadStateFile => "Failed to read state file: {0}", "状態ファイルを読み込めませんでした: {0}";
+    ErrorWriteStateFile => "Failed to write state file: {0}", "状態ファイルに書き込めませんでした: {0}";
+    ErrorRemoveStateFile => "Failed to remove state file: {0}", "状態ファイルを削除できませんでした: {0}";
+    ErrorReadAuditLog => "Failed to read audit log: {0}", "実行記録を読み込めませんでした: {0}";
+    ErrorWriteAuditLog => "Failed to write audit log: {0}", "実行記録に書き込めませんでした: {0}";
+    ErrorInvalidAuditLogEntry => "Invalid audit log entry at {0}:{1}", "実行記録の {0}:{1} が不正です";
+    ErrorReadSnapshot => "Failed to read snapshot: {0}", "スナップショットを読み込めませんでした: {0}";
+    ErrorSnapshotNotFound => "No snapshot found at {0}", "{0} にスナップショットがありません";
+    ErrorRemoveJournal => "Failed to remove the database journal", "データベースのジャーナルを削除できませんでした";
+    ErrorLocateExecutable => "Failed to locate the yaru executable", "yaruの実行ファイルが見つかりません";
+    ErrorClipboardCommandNotFound => "No clipboard command found (install wl-clipboard, xclip or xsel)", "クリップボードのコマンドが見つかりません（wl-clipboard・xclip・xsel のいずれかをインストールしてください）";
+    ErrorClipboardCommandExited => "{0} exited with {1}", "{0} が失敗しました（{1}）";
+    ErrorReadClipboard => "Failed to read the clipboard: {0}", "クリップボードを読み取れませんでした: {0}";
+    ErrorClipboardEmpty => "Clipboard is empty", "クリップボードが空です";
+    ErrorReadCommitMessage => "Failed to read commit message: {0}", "コミットメッセージを読み込めませんでした: {0}";
+    KindTask => "Task", "タスク";
+    KindTag => "Tag", "タグ";
+
+    // TUI
+    TuiTaskListTitle => "Tasks", "タスク";
+    TuiTaskListMarkedTitle => "Tasks ({0} marked)", "タスク（{0}件マーク中）";
+    TuiStreak => "Done today: {0} · Streak: {1}d", "今日の完了 {0}件 · 連続 {1}日";
+    TuiKeyHelp => "↑/↓: Select | Space: Mark | c: Complete | d: Delete | P: Priority | t: Add tag | v: Details | C: Calendar | u/Ctrl+r: Undo/Redo | p: Pomodoro | q: Quit", "↑/↓: 選択 | Space: マーク | c: 完了 | d: 削除 | P: 優先度 | t: タグ追加 | v: 詳細 | C: カレンダー | u/Ctrl+r: 取消/やり直し | p: ポモドーロ | q: 終了";
+    TuiTaskDetailTitle => "Details", "詳細";
+    TuiCalendarTitle => "Due dates {0}", "期限カレンダー {0}";
+    TuiCalendarWeekdays => "Mo Tu We Th Fr Sa Su", "月 火 水 木 金 土 日";
+    TuiCalendarDayTitle => "Due {0}", "{0} が期限";
+    TuiCalendarKeyHelp => "←/→: Day | ↑/↓: Week | [/]: Month | Enter: Tasks of the day | Esc: Back | q: Quit", "←/→: 日 | ↑/↓: 週 | [/]: 月 | Enter: その日のタスク | Esc: 戻る | q: 終了";
+    TuiCalendarDayKeyHelp => "↑/↓: Select | Enter: Show in list | Esc: Back to calendar", "↑/↓: 選択 | Enter: 一覧で表示 | Esc: カレンダーに戻る";
+    TuiPickerKeyHelp => "↑/↓: Select | Enter: Apply | Esc: Cancel", "↑/↓: 選択 | Enter: 決定 | Esc: キャンセル";
+    TuiConfirmKeyHelp => "y: Yes | n: No", "y: はい | n: いいえ";
+    TuiConfirmTypedKeyHelp => "Enter: Confirm | Esc: Cancel", "Enter: 決定 | Esc: キャンセル";
+    TuiSelectPriorityTitle => "Priority", "優先度";
+    TuiSelectTagTitle => "Add tag", "タグを追加";
+    TuiConfirmBulkComplete => "Complete {0} task(s)?", "{0}件のタスクを完了にしますか？";
+    TuiConfirmBulkDelete => "Delete {0} task(s)?", "{0}件のタスクを削除しますか？";
+    TuiConfirmBulkPriority => "Set priority of {0} task(s) to {1}?", "{0}件のタスクの優先度を{1}にしますか？";
+    TuiConfirmBulkTag => "Add tag '{1}' to {0} task(s)?", "{0}件のタスクにタグ「{1}」を追加しますか？";
+    TuiCannotRestoreTask => "Task {0} can no longer be restored", "タスク{0}は復元できません";
+    TuiBulkProgress => "Processing {0}/{1}...", "処理中 {0}/{1}...";
+    TuiLoading => "Loading...", "読み込み中...";
+    TuiBulkDone => "{0} task(s) updated", "{0}件のタスクを更新しました";
+    TuiUndone => "Undid changes to {0} task(s)", "{0}件のタスクの変更を取り消しました";
+    TuiRedone => "Redid changes to {0} task(s)", "{0}件のタスクの変更をやり直しました";
+    TuiNothingToUndo => "Nothing to undo", "取り消せる変更はありません";
+    TuiNothingToRedo => "Nothing to redo", "やり直せる変更はありません";
+    TuiHistoryFailed => "Failed to undo/redo: {0}", "取り消し・やり直しに失敗しました: {0}";
+    TuiBulkFailed => "{0} task(s) updated, {1} failed: {2}", "{0}件を更新、{1}件が失敗しました: {2}";
+    TuiPomodoroKeyHelp => "Esc: Stop pomodoro | q: Quit", "Esc: ポモドーロ終了 | q: 終了";
+    PomodoroWork => "Work", "作業";
+    PomodoroBreak => "Break", "休憩";
+    PomodoroCompletedCount => "Completed pomodoros: {0}", "完了したポモドーロ: {0}";
+    PomodoroWorkFinished => "Pomodoro finished, take a {0}-minute break", "ポモドーロ完了。{0}分休憩しましょう";
+    PomodoroBreakFinished => "Break is over, back to work", "休憩終了。作業を再開しましょう";
+    DoctorCheckConfig => "Config", "設定";
+    DoctorCheckDatabase => "Database", "データベース";
+    DoctorCheckSchema => "Schema", "スキーマ";
+    DoctorCheckIntegrity => "Integrity", "整合性";
+    DoctorCheckColor => "Color", "色";
+    DoctorCheckUnicode => "Unicode", "Unicode";
+    DoctorCheckGitHook => "Git hook {0}", "gitフック {0}";
+    DoctorCheckNotifyCommand => "Notify command", "通知コマンド";
+    DoctorFix => "Fix: {0}", "対処: {0}";
+    DoctorConfigLoaded => "Loaded {0}", "{0}を読み込みました";
+    DoctorConfigDefault => "{0} not found, using defaults", "{0}がないため既定の設定を使用します";
+    DoctorFixConfig => "Correct the setting in {0}, or move the file aside to use the defaults", "{0}の設定を修正するか、ファイルを移動して既定の設定を使用してください";
+    DoctorSkipped => "Skipped because the config could not be loaded", "設定を読み込めないため確認しませんでした";
+    DoctorDatabaseInMemory => "In-memory database (YARU_DB=memory), nothing is saved", "メモリ上のデータベース（YARU_DB=memory）のため保存されません";
+    DoctorDatabaseMissing => "{0} does not exist yet and will be created on first use", "{0}はまだ存在しません（初回の実行時に作成されます）";
+    DoctorDatabaseConnected => "Connected to {0}", "{0}に接続しました";
+    DoctorJsonLoaded => "Loaded JSON storage {0}", "JSONの保存先{0}を読み込みました";
+    DoctorFixDatabase => "Check [storage] in the config and that the directory is writable", "設定の[storage]と、保存先のディレクトリに書き込めるかを確認してください";
+    DoctorSchemaUpToDate => "Up to date ({0} migrations applied)", "最新です（{0}件のマイグレーションを適用済み）";
+    DoctorSchemaPending => "{0} pending migration(s): {1}", "未適用のマイグレーションが{0}件あります: {1}";
+    DoctorFixMigrate => "Run `yaru db migrate` (or set [storage] auto_migrate = true)", "`yaru db migrate`を実行してください（または[storage] auto_migrate = trueを設定）";
+    DoctorFixIntegrity => "Restore the database from a backup; see `yaru db status` for details", "バックアップからデータベースを復元してください（詳細は`yaru db status`）";
+    DoctorColorEnabled => "Enabled", "有効";
+    DoctorColorNoColor => "Disabled by NO_COLOR or --no-color", "NO_COLORまたは--no-colorにより無効";
+    DoctorColorNotTerminal => "Disabled because the output is not a terminal", "出力先が端末ではないため無効";
+    DoctorColorTheme => "Disabled by [display.theme] enabled = false", "[display.theme] enabled = falseにより無効";
+    DoctorUnicodeOk => "UTF-8 locale ({0})", "UTF-8のロケール（{0}）";
+    DoctorUnicodeMissing => "Locale {0} is not UTF-8; tables, the TUI and non-ASCII text may be garbled", "ロケール{0}がUTF-8ではないため、表・TUI・ASCII以外の文字が崩れる可能性があります";
+    DoctorFixUnicode => "Set a UTF-8 locale, e.g. `export LANG=en_US.UTF-8`", "UTF-8のロケールを設定してください（例: `export LANG=ja_JP.UTF-8`）";
+    DoctorLocaleUnset => "(unset)", "（未設定）";
+    DoctorHookInstalled => "Installed: {0}", "導入済み: {0}";
+    DoctorHookNotExecutable => "{0} is not executable, so git skips it", "{0}に実行権限がないため、gitが実行しません";
+    DoctorFixChmod => "Run `chmod +x {0}`", "`chmod +x {0}`を実行してください";
+    DoctorNotExecutable => "{0} is not executable", "{0}に実行権限がありません";
+    DoctorNotifyNotFound => "{0} was not found in PATH", "{0}がPATHに見つかりません";
+    DoctorFixNotify => "Install the command or change [cron] notify_command", "コマンドをインストールするか、[cron] notify_commandを変更してください";
+    DoctorNoProblems => "No problems found", "問題は見つかりませんでした";
+    DoctorWarningsFound => "{0} warning(s), no errors", "警告{0}件（エラーなし）";
+    DoctorProblemsFound => "{0} problem(s) found; see the fixes above", "{0}件の問題が見つかりました（上記の対処を参照してください）";
+}
+
+impl Msg {
+    /// 現在の表示言語の文言を取得
+    pub fn text(self) -> &'static str {
+        self.text_in(current_locale())
+    }
+
+    /// 現在の表示言語の文言の`{0}`, `{1}`…を引数で置き換える
+    pub fn format(self, args: &[&dyn Display]) -> String {
+        fill_placeholders(self.text(), args)
+    }
+}
+
+/// 文言中の`{0}`, `{1}`…を引数で置き換える
+///
+/// 引数の値に含まれる`{0}`などは置き換えません。対応する引数がない場合はそのまま残します。
+fn fill_placeholders(template: &str, args: &[&dyn Display]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let replaced = after.find('}').and_then(|end| {
+            let arg = after[..end]
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| args.get(i))?;
+            Some((arg, end))
+        });
+
+        match replaced {
+            Some((arg, end)) => {
+                output.push_str(&arg.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                output.push('{');
+                rest = after;
+            }
+        }
+    }
+    output.push_str(rest);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// 文言に含まれるプレースホルダーの番号を取得
+    fn placeholders(text: &str) -> BTreeSet<usize> {
+        text.split('{')
+            .skip(1)
+            .filter_map(|part| part.split('}').next()?.parse().ok())
+            .collect()
+    }
+
+    #[test]
+    fn test_fill_placeholders() {
+        assert_eq!(
+            fill_placeholders("■ {0} ({1} tasks)", &[&"仕事", &3]),
+            "■ 仕事 (3 tasks)"
+        );
+        // 引数に含まれるプレースホルダーは置き換えない
+        assert_eq!(fill_placeholders("{0}-{1}", &[&"{1}", &2]), "{1}-2");
+        // 対応する引数がない場合はそのまま
+        assert_eq!(fill_placeholders("{0} {x} {", &[&1]), "1 {x} {");
+    }
+
+    #[test]
+    fn test_text_in_each_locale() {
+        assert_eq!(Msg::NoTasksFound.text_in(Locale::En), "No tasks found");
+        assert_eq!(
+            Msg::NoTasksFound.text_in(Locale::Ja),
+            "タスクが見つかりません"
+        );
+    }
+
+    #[test]
+    fn test_catalog_placeholders_match_between_locales() {
+        for msg in Msg::ALL {
+            assert_eq!(
+                placeholders(msg.text_in(Locale::En)),
+                placeholders(msg.text_in(Locale::Ja)),
+                "{:?}",
+                msg
+            );
+            assert!(!msg.text_in(Locale::Ja).is_empty(), "{:?}", msg);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use std::{str::FromStr, sync::OnceLock};
+
+/// 表示言語
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// 英語
+    #[default]
+    En,
+    /// 日本語
+    Ja,
+}
+
+impl FromStr for Locale {
+    type Err = anyhow::Error;
+
+    /// `en`, `ja`のほか、`ja_JP.UTF-8`のようなロケール名も受け付ける
+    fn from_str(s: &str) -> Result<Self> {
+        let language = s
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "en" | "c" | "posix" => Ok(Self::En),
+            "ja" => Ok(Self::Ja),
+            _ => anyhow::bail!("Unsupported language: {}", s),
+        }
+    }
+}
+
+impl Locale {
+    /// 設定値または環境変数から表示言語を決定
+    ///
+    /// 設定値が`auto`（または未指定）の場合は、`LC_ALL` → `LC_MESSAGES` → `LANG`の順に
+    /// 環境変数を参照します。対応していない言語の場合は英語になります。
+    ///
+    /// # Arguments
+    /// * `configured` - 設定ファイルの`[display] language`の値
+    pub fn detect(configured: &str) -> Result<Self> {
+        if !configured.eq_ignore_ascii_case("auto") {
+            return configured.parse();
+        }
+
+        Ok(["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default())
+    }
+}
+
+/// プロセス全体で使用する表示言語
+static CURRENT_LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// 表示言語を設定
+///
+/// 起動時に1度だけ呼び出します。2回目以降の呼び出しは無視されます。
+pub fn set_locale(locale: Locale) {
+    let _ = CURRENT_LOCALE.set(locale);
+}
+
+/// 現在の表示言語を取得（未設定の場合は英語）
+pub fn current_locale() -> Locale {
+    CURRENT_LOCALE.get().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_from_str() {
+        assert_eq!("en".parse::<Locale>().unwrap(), Locale::En);
+        assert_eq!("JA".parse::<Locale>().unwrap(), Locale::Ja);
+        assert_eq!("ja_JP.UTF-8".parse::<Locale>().unwrap(), Locale::Ja);
+        assert_eq!("en-US".parse::<Locale>().unwrap(), Locale::En);
+        assert_eq!("C.UTF-8".parse::<Locale>().unwrap(), Locale::En);
+        assert!("fr_FR.UTF-8".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn test_detect_uses_configured_language() {
+        assert_eq!(Locale::detect("ja").unwrap(), Locale::Ja);
+        assert_eq!(Locale::detect("en").unwrap(), Locale::En);
+        assert!(Locale::detect("klingon").is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! 他のタスク管理ツールのエクスポートファイルの読み込み
+//!
+//! 各ツールのJSON形式のエクスポートやiCalendarファイルを、取り込み用のタスク（`ImportedTask`）に変換します。
+
+pub mod ics;
+pub mod taskwarrior;
+pub mod todoist;
@@ -0,0 +1,372 @@
+//! iCalendar（.ics）ファイルの読み込み
+//!
+//! 予定（`VEVENT`）とToDo（`VTODO`）を取り込み用のタスクに変換します。
+//! 繰り返しの予定（`RRULE`）は展開せず、最初の回だけを1件のタスクとして扱います。
+
+use crate::{
+    application::use_cases::task::import_tasks::ImportedTask,
+    domain::task::value_objects::{Priority, Status},
+};
+use anyhow::{Context, Result, bail};
+use chrono::{Local, NaiveDate, NaiveDateTime};
+
+/// 取り込み済みのタスクとの対応に記録する読み込み元の種類
+pub const ICS_SOURCE: &str = "ics";
+
+/// 取り込む期間（両端を含む、Noneの場合は制限しない）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateRange {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl DateRange {
+    /// 期間が指定されているか
+    fn is_bounded(&self) -> bool {
+        self.from.is_some() || self.to.is_some()
+    }
+
+    /// 日付が期間内か
+    fn contains(&self, date: NaiveDate) -> bool {
+        self.from.is_none_or(|from| from <= date) && self.to.is_none_or(|to| date <= to)
+    }
+}
+
+/// 予定・ToDoの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Event,
+    Todo,
+}
+
+/// 読み込み中の予定・ToDoのプロパティ（名前は大文字、値はエスケープを解除前）
+#[derive(Debug)]
+struct Component {
+    kind: Kind,
+    properties: Vec<(String, String)>,
+}
+
+impl Component {
+    /// プロパティの値（同じ名前が複数ある場合は最初の値）
+    fn get(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// iCalendarの文字列を取り込み用のタスクに変換する
+///
+/// `SUMMARY`をタイトル、`DESCRIPTION`を説明、`CATEGORIES`をタグに対応させます。
+/// 期限日は、予定の場合は開始日（`DTSTART`）、ToDoの場合は期限（`DUE`、なければ`DTSTART`）です。
+/// 期間を指定した場合は、期限日が期間内のものだけを取り込みます（日付のないものは取り込まない）。
+/// キャンセルされた予定・ToDoは取り込みません。
+pub fn parse(content: &str, range: DateRange) -> Result<Vec<ImportedTask>> {
+    let mut tasks = Vec::new();
+    let mut current: Option<Component> = None;
+    // 予定の中のアラーム（VALARM）などの入れ子の深さ
+    let mut nested = 0;
+
+    for line in unfold_lines(content) {
+        let Some((name, value)) = split_property(&line) else {
+            continue;
+        };
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", None) => {
+                current = match value.to_ascii_uppercase().as_str() {
+                    "VEVENT" => Some(Component {
+                        kind: Kind::Event,
+                        properties: Vec::new(),
+                    }),
+                    "VTODO" => Some(Component {
+                        kind: Kind::Todo,
+                        properties: Vec::new(),
+                    }),
+                    _ => None,
+                };
+            }
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) if nested > 0 => nested -= 1,
+            ("END", Some(_)) => {
+                if let Some(component) = current.take()
+                    && let Some(task) = to_imported_task(&component)?
+                    && task
+                        .due_date
+                        .map_or(!range.is_bounded(), |d| range.contains(d))
+                {
+                    tasks.push(task);
+                }
+            }
+            (_, Some(component)) if nested == 0 => {
+                component.properties.push((name, value.to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(tasks)
+}
+
+/// 予定・ToDoを取り込み用のタスクに変換する（キャンセルされている場合はNone）
+fn to_imported_task(component: &Component) -> Result<Option<ImportedTask>> {
+    let state = component
+        .get("STATUS")
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    if state == "CANCELLED" {
+        return Ok(None);
+    }
+    let Some(uid) = component.get("UID").map(unescape) else {
+        bail!(
+            "Calendar entry without a UID: {}",
+            component.get("SUMMARY").map(unescape).unwrap_or_default()
+        );
+    };
+    let title = component
+        .get("SUMMARY")
+        .map(unescape)
+        .with_context(|| format!("Calendar entry {} has no SUMMARY", uid))?;
+
+    let date = match component.kind {
+        Kind::Event => component.get("DTSTART"),
+        Kind::Todo => component.get("DUE").or(component.get("DTSTART")),
+    };
+    let due_date = date
+        .map(|value| {
+            parse_date(value)
+                .with_context(|| format!("Invalid date in calendar entry {}: {}", uid, value))
+        })
+        .transpose()?;
+    let status = match (component.kind, state.as_str()) {
+        (Kind::Todo, "COMPLETED") => Status::Completed,
+        (Kind::Todo, _) if component.get("COMPLETED").is_some() => Status::Completed,
+        (Kind::Todo, "IN-PROCESS") => Status::InProgress,
+        _ => Status::Pending,
+    };
+    // 1が最も高く9が最も低い（0は未設定）
+    let priority = match component
+        .get("PRIORITY")
+        .and_then(|p| p.trim().parse().ok())
+    {
+        Some(1) => Priority::Critical,
+        Some(2..=4) => Priority::High,
+        Some(6..=9) => Priority::Low,
+        _ => Priority::Medium,
+    };
+    let tags = component
+        .properties
+        .iter()
+        .filter(|(name, _)| name == "CATEGORIES")
+        .flat_map(|(_, value)| split_list(value))
+        .collect();
+
+    Ok(Some(ImportedTask {
+        source_id: uid,
+        title,
+        description: component
+            .get("DESCRIPTION")
+            .map(unescape)
+            .unwrap_or_default(),
+        status,
+        priority,
+        tags,
+        due_date,
+        created_at: None,
+        completed_at: None,
+    }))
+}
+
+/// 折り返された行（次の行が空白で始まる）を1行につなげる
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(line.trim_end().to_string()),
+        }
+    }
+    lines
+}
+
+/// 行をプロパティ名（大文字、パラメータを除く）と値に分ける
+///
+/// パラメータの値は`"`で囲むと`:`を含められるため、引用符の外の最初の`:`で分けます。
+fn split_property(line: &str) -> Option<(String, &str)> {
+    let mut quoted = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        ':' if !quoted => Some(i),
+        _ => None,
+    })?;
+    let name = line[..colon].split(';').next().unwrap_or_default();
+    Some((name.to_ascii_uppercase(), &line[colon + 1..]))
+}
+
+/// テキストの値のエスケープ（`\n`・`\,`・`\;`・`\\`）を解除する
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => result.push('\n'),
+            Some(escaped) => result.push(escaped),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// カンマ区切りの値（エスケープされたカンマは区切りとしない）を分ける
+fn split_list(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            ',' if !escaped => {
+                items.push(unescape(&value[start..i]));
+                start = i + 1;
+            }
+            _ => escaped = false,
+        }
+    }
+    items.push(unescape(&value[start..]));
+    items
+        .into_iter()
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// `20261020`・`20261020T090000`・`20261020T090000Z`形式の値から日付を読み取る
+///
+/// UTCの日時はローカルの日付に変換し、タイムゾーンのない日時（`TZID`指定を含む）はその日付を使います。
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix(['Z', 'z']) {
+        let utc = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")?.and_utc();
+        return Ok(utc.with_timezone(&Local).date_naive());
+    }
+    let date = value.split(['T', 't']).next().unwrap_or_default();
+    Ok(NaiveDate::parse_from_str(date, "%Y%m%d")?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALENDAR: &str = "BEGIN:VCALENDAR\r\n\
+        VERSION:2.0\r\n\
+        BEGIN:VEVENT\r\n\
+        UID:event-1@example.com\r\n\
+        DTSTART;VALUE=DATE:20261020\r\n\
+        SUMMARY:Dentist\\, downtown\r\n\
+        DESCRIPTION:Bring the\\ninsurance card\r\n\
+        CATEGORIES:Health,Personal\r\n\
+        BEGIN:VALARM\r\n\
+        DESCRIPTION:Reminder\r\n\
+        END:VALARM\r\n\
+        END:VEVENT\r\n\
+        BEGIN:VTODO\r\n\
+        UID:todo-1\r\n\
+        SUMMARY:Submit\r\n  expenses\r\n\
+        DUE;TZID=\"Asia/Tokyo\":20261025T180000\r\n\
+        PRIORITY:1\r\n\
+        STATUS:IN-PROCESS\r\n\
+        END:VTODO\r\n\
+        BEGIN:VTODO\r\n\
+        UID:todo-2\r\n\
+        SUMMARY:Someday\r\n\
+        END:VTODO\r\n\
+        BEGIN:VEVENT\r\n\
+        UID:event-2\r\n\
+        DTSTART;VALUE=DATE:20261021\r\n\
+        SUMMARY:Cancelled meeting\r\n\
+        STATUS:CANCELLED\r\n\
+        END:VEVENT\r\n\
+        END:VCALENDAR\r\n";
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_events_and_todos() {
+        // Act
+        let tasks = parse(CALENDAR, DateRange::default()).unwrap();
+
+        // Assert
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].source_id, "event-1@example.com");
+        assert_eq!(tasks[0].title, "Dentist, downtown");
+        assert_eq!(tasks[0].description, "Bring the\ninsurance card");
+        assert_eq!(tasks[0].tags, vec!["Health", "Personal"]);
+        assert_eq!(tasks[0].due_date, Some(date(20)));
+        assert_eq!(tasks[0].status, Status::Pending);
+        assert_eq!(tasks[1].title, "Submit expenses");
+        assert_eq!(tasks[1].due_date, Some(date(25)));
+        assert_eq!(tasks[1].priority, Priority::Critical);
+        assert_eq!(tasks[1].status, Status::InProgress);
+        assert_eq!(tasks[2].due_date, None);
+    }
+
+    #[test]
+    fn test_parse_within_date_range() {
+        // Arrange
+        let range = DateRange {
+            from: Some(date(21)),
+            to: Some(date(31)),
+        };
+
+        // Act
+        let tasks = parse(CALENDAR, range).unwrap();
+
+        // Assert: 期間外と日付のないものは取り込まない
+        let ids: Vec<_> = tasks.iter().map(|task| task.source_id.as_str()).collect();
+        assert_eq!(ids, vec!["todo-1"]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(
+            parse(
+                "BEGIN:VEVENT\nSUMMARY:No UID\nEND:VEVENT\n",
+                DateRange::default()
+            )
+            .is_err()
+        );
+        assert!(
+            parse(
+                "BEGIN:VEVENT\nUID:x\nSUMMARY:Bad\nDTSTART:2026-10-20\nEND:VEVENT\n",
+                DateRange::default()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_utc_date_time_and_quoted_parameters() {
+        assert_eq!(
+            parse_date("20261020T120000Z").unwrap(),
+            NaiveDateTime::parse_from_str("20261020T120000", "%Y%m%dT%H%M%S")
+                .unwrap()
+                .and_utc()
+                .with_timezone(&Local)
+                .date_naive()
+        );
+        assert_eq!(
+            split_property("DESCRIPTION;ALTREP=\"http://example.com\":Text"),
+            Some(("DESCRIPTION".to_string(), "Text"))
+        );
+        assert_eq!(split_list("a\\,b, c,,"), vec!["a,b", "c"]);
+    }
+}
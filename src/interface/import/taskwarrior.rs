@@ -0,0 +1,177 @@
+//! Taskwarriorのエクスポートの読み込み
+//!
+//! `task export`が出力するJSON（タスクの配列）を読み込めます。
+
+use crate::{
+    application::use_cases::task::import_tasks::ImportedTask,
+    domain::task::value_objects::{Priority, Status},
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use serde::Deserialize;
+
+/// 取り込み済みのタスクとの対応に記録する読み込み元の種類
+pub const TASKWARRIOR_SOURCE: &str = "taskwarrior";
+
+#[derive(Debug, Deserialize)]
+struct Task {
+    uuid: String,
+    description: String,
+    status: String,
+    project: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    priority: Option<String>,
+    due: Option<String>,
+    start: Option<String>,
+    entry: Option<String>,
+    end: Option<String>,
+    #[serde(default)]
+    annotations: Vec<Annotation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Annotation {
+    description: String,
+}
+
+/// Taskwarriorのエクスポート（JSON）を取り込み用のタスクに変換する
+///
+/// プロジェクトとタグはタグに、注釈は説明に、優先度はH/M/LをHigh/Medium/Lowに
+/// （未設定はMedium）対応させます。開始済み（`start`あり）のタスクは進行中になります。
+/// 作成日時（`entry`）と完了日時（`end`）は引き継ぎます。
+/// 削除済みのタスクと繰り返しの親タスクは取り込みません。
+pub fn parse(json: &str) -> Result<Vec<ImportedTask>> {
+    let tasks: Vec<Task> = serde_json::from_str(json).context("Invalid Taskwarrior export")?;
+
+    tasks
+        .into_iter()
+        .filter(|task| !matches!(task.status.as_str(), "deleted" | "recurring"))
+        .map(|task| {
+            let status = match task.status.as_str() {
+                "completed" => Status::Completed,
+                _ if task.start.is_some() => Status::InProgress,
+                _ => Status::Pending,
+            };
+            let priority = match task.priority.as_deref() {
+                Some("H") => Priority::High,
+                Some("L") => Priority::Low,
+                _ => Priority::Medium,
+            };
+            let due_date = task
+                .due
+                .as_deref()
+                .map(|due| {
+                    parse_date(due)
+                        .with_context(|| format!("Invalid due date in task {}: {}", task.uuid, due))
+                })
+                .transpose()?;
+            let created_at = task
+                .entry
+                .as_deref()
+                .map(|entry| {
+                    parse_datetime(entry).with_context(|| {
+                        format!("Invalid entry date in task {}: {}", task.uuid, entry)
+                    })
+                })
+                .transpose()?;
+            let completed_at = task
+                .end
+                .as_deref()
+                .map(|end| {
+                    parse_datetime(end)
+                        .with_context(|| format!("Invalid end date in task {}: {}", task.uuid, end))
+                })
+                .transpose()?;
+            let mut tags: Vec<String> = task.project.into_iter().collect();
+            tags.extend(task.tags);
+
+            Ok(ImportedTask {
+                title: task.description,
+                description: task
+                    .annotations
+                    .into_iter()
+                    .map(|annotation| annotation.description)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                status,
+                priority,
+                tags,
+                due_date,
+                created_at,
+                completed_at,
+                source_id: task.uuid,
+            })
+        })
+        .collect()
+}
+
+/// Taskwarriorの日時（`20261020T150000Z`、UTC）を読み込む
+fn parse_datetime(value: &str) -> Result<DateTime<Utc>> {
+    Ok(NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")?.and_utc())
+}
+
+/// Taskwarriorの日時（`20261020T150000Z`、UTC）をローカルの日付に変換する
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    Ok(parse_datetime(value)?.with_timezone(&Local).date_naive())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_export() {
+        // Arrange
+        let json = r#"[
+            {"uuid": "a-1", "description": "Plant tomatoes", "status": "pending", "project": "Home.Garden",
+             "tags": ["outdoor"], "priority": "H", "due": "20261020T120000Z", "start": "20261001T000000Z",
+             "annotations": [{"entry": "20261001T000000Z", "description": "buy seeds"}, {"entry": "x", "description": "water"}]},
+            {"uuid": "a-2", "description": "Old", "status": "deleted"},
+            {"uuid": "a-3", "description": "Template", "status": "recurring"},
+            {"uuid": "a-4", "description": "Done", "status": "completed", "priority": "L",
+             "entry": "20260105T090000Z", "end": "20260106T183000Z"}
+        ]"#;
+
+        // Act
+        let tasks = parse(json).unwrap();
+
+        // Assert
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].source_id, "a-1");
+        assert_eq!(tasks[0].tags, vec!["Home.Garden", "outdoor"]);
+        assert_eq!(tasks[0].status, Status::InProgress);
+        assert_eq!(tasks[0].priority, Priority::High);
+        assert_eq!(tasks[0].description, "buy seeds\nwater");
+        assert_eq!(
+            tasks[0].due_date,
+            Some(parse_date("20261020T120000Z").unwrap())
+        );
+        assert_eq!(tasks[1].status, Status::Completed);
+        assert_eq!(tasks[1].priority, Priority::Low);
+        assert_eq!(
+            tasks[1].created_at,
+            Some(parse_datetime("20260105T090000Z").unwrap())
+        );
+        assert_eq!(
+            tasks[1].completed_at,
+            Some(parse_datetime("20260106T183000Z").unwrap())
+        );
+        assert_eq!(tasks[0].created_at, None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("{}").is_err());
+        assert!(
+            parse(r#"[{"uuid": "x", "description": "d", "status": "pending", "due": "tomorrow"}]"#)
+                .is_err()
+        );
+        assert!(
+            parse(
+                r#"[{"uuid": "x", "description": "d", "status": "completed", "end": "yesterday"}]"#
+            )
+            .is_err()
+        );
+    }
+}
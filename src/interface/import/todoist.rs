@@ -0,0 +1,185 @@
+//! Todoistのエクスポートの読み込み
+//!
+//! Sync APIの全件取得の結果（`projects`と`items`を持つオブジェクト）、REST APIの
+//! タスク一覧（`tasks`を持つオブジェクトまたはタスクの配列）を読み込めます。
+
+use crate::{
+    application::use_cases::task::import_tasks::ImportedTask,
+    domain::task::value_objects::{Priority, Status},
+};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// 取り込み済みのタスクとの対応に記録する読み込み元の種類
+pub const TODOIST_SOURCE: &str = "todoist";
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Export {
+    Full {
+        #[serde(default)]
+        projects: Vec<Project>,
+        #[serde(alias = "tasks")]
+        items: Vec<Item>,
+    },
+    Tasks(Vec<Item>),
+}
+
+#[derive(Debug, Deserialize)]
+struct Project {
+    id: Value,
+    name: String,
+    #[serde(default)]
+    inbox_project: bool,
+    #[serde(default)]
+    is_inbox_project: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct Item {
+    id: Value,
+    content: String,
+    #[serde(default)]
+    description: String,
+    project_id: Option<Value>,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default = "default_priority")]
+    priority: u8,
+    due: Option<Due>,
+    #[serde(default, alias = "is_completed")]
+    checked: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct Due {
+    date: String,
+}
+
+fn default_priority() -> u8 {
+    1
+}
+
+/// Todoistのエクスポート（JSON）を取り込み用のタスクに変換する
+///
+/// プロジェクトとラベルはタグに（受信箱は除く）、優先度は4段階をそのまま対応させます
+/// （p1→Critical、p2→High、p3→Medium、p4→Low）。
+pub fn parse(json: &str) -> Result<Vec<ImportedTask>> {
+    let export: Export = serde_json::from_str(json).context("Invalid Todoist export")?;
+    let (projects, items) = match export {
+        Export::Full { projects, items } => (projects, items),
+        Export::Tasks(items) => (vec![], items),
+    };
+
+    items
+        .into_iter()
+        .map(|item| {
+            let source_id = id_string(&item.id);
+            let mut tags: Vec<String> = item
+                .project_id
+                .as_ref()
+                .and_then(|project_id| {
+                    projects
+                        .iter()
+                        .find(|project| id_string(&project.id) == id_string(project_id))
+                })
+                .filter(|project| !project.inbox_project && !project.is_inbox_project)
+                .map(|project| vec![project.name.clone()])
+                .unwrap_or_default();
+            tags.extend(item.labels);
+
+            let due_date = item
+                .due
+                .map(|due| {
+                    let date = due.date.get(..10).unwrap_or(&due.date);
+                    NaiveDate::parse_from_str(date, "%Y-%m-%d").with_context(|| {
+                        format!("Invalid due date in item {}: {}", source_id, due.date)
+                    })
+                })
+                .transpose()?;
+
+            Ok(ImportedTask {
+                title: item.content,
+                description: item.description,
+                status: if item.checked {
+                    Status::Completed
+                } else {
+                    Status::Pending
+                },
+                priority: match item.priority {
+                    4 => Priority::Critical,
+                    3 => Priority::High,
+                    2 => Priority::Medium,
+                    _ => Priority::Low,
+                },
+                tags,
+                due_date,
+                created_at: None,
+                completed_at: None,
+                source_id,
+            })
+        })
+        .collect()
+}
+
+/// 数値・文字列のどちらでも表されるIDを文字列にする
+fn id_string(id: &Value) -> String {
+    match id {
+        Value::String(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sync_export() {
+        // Arrange
+        let json = r#"{
+            "projects": [
+                {"id": "100", "name": "Inbox", "inbox_project": true},
+                {"id": "200", "name": "Work"}
+            ],
+            "items": [
+                {"id": "1", "content": "Write report", "description": "Q3", "project_id": "200",
+                 "labels": ["urgent"], "priority": 4, "due": {"date": "2026-11-01T10:00:00"}, "checked": false},
+                {"id": "2", "content": "Buy milk", "project_id": "100", "priority": 1, "due": null, "checked": true}
+            ]
+        }"#;
+
+        // Act
+        let tasks = parse(json).unwrap();
+
+        // Assert
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].source_id, "1");
+        assert_eq!(tasks[0].tags, vec!["Work", "urgent"]);
+        assert_eq!(tasks[0].priority, Priority::Critical);
+        assert_eq!(tasks[0].due_date, NaiveDate::from_ymd_opt(2026, 11, 1));
+        assert_eq!(tasks[0].description, "Q3");
+        assert!(tasks[1].tags.is_empty());
+        assert_eq!(tasks[1].priority, Priority::Low);
+        assert_eq!(tasks[1].status, Status::Completed);
+    }
+
+    #[test]
+    fn test_parse_task_array() {
+        let json = r#"[{"id": 7, "content": "Call", "priority": 2, "is_completed": false}]"#;
+
+        let tasks = parse(json).unwrap();
+
+        assert_eq!(tasks[0].source_id, "7");
+        assert_eq!(tasks[0].priority, Priority::Medium);
+        assert_eq!(tasks[0].status, Status::Pending);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(r#"{"foo": 1}"#).is_err());
+        assert!(parse(r#"[{"id": 1, "content": "x", "due": {"date": "soon"}}]"#).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+//! メールからのタスクの取り込み
+//!
+//! maildirのフォルダやIMAPサーバーのメールを読み込み、件名をタイトル、本文を説明とする
+//! 取り込み用のタスク（`ImportedTask`）に変換します。`mail`フィーチャーを有効にした場合だけ使えます。
+//! メールの解析（MIME・エンコードされたヘッダー・文字コード）は[`mail_parser`]で行います。
+
+pub mod imap;
+pub mod maildir;
+
+use crate::{
+    application::use_cases::task::import_tasks::ImportedTask,
+    domain::task::value_objects::{Priority, Status},
+};
+use mail_parser::{MessageParser, PartType};
+
+/// 取り込み済みのタスクとの対応に記録する読み込み元の種類（識別子はMessage-ID）
+pub const MAIL_SOURCE: &str = "mail";
+
+/// 件名のないメールのタイトル
+const NO_SUBJECT: &str = "(no subject)";
+
+/// 読み込んだメール
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailMessage {
+    /// Message-ID（`<`と`>`を除く）
+    pub message_id: Option<String>,
+    pub subject: String,
+    pub from: String,
+    /// 本文（テキスト形式の部分、HTMLだけのメールは空）
+    pub body: String,
+}
+
+/// 取り込むメールの条件（大文字小文字を区別しない部分一致、Noneの場合は制限しない）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MailFilter {
+    pub subject: Option<String>,
+    pub from: Option<String>,
+}
+
+impl MailFilter {
+    /// メールが条件に一致するか
+    pub fn matches(&self, message: &MailMessage) -> bool {
+        let contains = |text: &str, pattern: &Option<String>| {
+            pattern
+                .as_ref()
+                .is_none_or(|p| text.to_lowercase().contains(&p.to_lowercase()))
+        };
+        contains(&message.subject, &self.subject) && contains(&message.from, &self.from)
+    }
+}
+
+impl MailMessage {
+    /// 取り込み用のタスクに変換する（Message-IDがない場合は重複を判定できないためNone）
+    pub fn to_imported_task(&self, tag: Option<&str>) -> Option<ImportedTask> {
+        let message_id = self.message_id.clone()?;
+        let title = match self.subject.trim() {
+            "" => NO_SUBJECT.to_string(),
+            subject => subject.to_string(),
+        };
+        Some(ImportedTask {
+            source_id: message_id,
+            title,
+            description: self.body.clone(),
+            status: Status::Pending,
+            priority: Priority::Medium,
+            tags: tag.map(str::to_string).into_iter().collect(),
+            due_date: None,
+            created_at: None,
+            completed_at: None,
+        })
+    }
+}
+
+/// RFC 5322形式のメールを読み込む
+///
+/// 形式に誤りがあっても読み取れた範囲で変換します。
+pub fn parse_message(raw: &[u8]) -> MailMessage {
+    let Some(message) = MessageParser::default().parse(raw) else {
+        return MailMessage {
+            message_id: None,
+            subject: String::new(),
+            from: String::new(),
+            body: String::new(),
+        };
+    };
+    let from = message
+        .from()
+        .and_then(|from| from.first())
+        .map(|addr| match (addr.name(), addr.address()) {
+            (Some(name), Some(address)) => format!("{} <{}>", name, address),
+            (name, address) => name.or(address).unwrap_or_default().to_string(),
+        })
+        .unwrap_or_default();
+    // HTMLだけのメールはテキストに変換せず、本文を空にする
+    let body = message
+        .text_bodies()
+        .find_map(|part| match &part.body {
+            PartType::Text(text) => Some(text.replace("\r\n", "\n").trim().to_string()),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    MailMessage {
+        message_id: message
+            .message_id()
+            .filter(|id| !id.is_empty())
+            .map(str::to_string),
+        subject: message.subject().unwrap_or_default().trim().to_string(),
+        from,
+        body,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_message() {
+        // Arrange
+        let raw = b"From: Alice <alice@example.com>\r\n\
+            Subject: =?UTF-8?B?6KaL56mN44KC44KK?=\r\n =?UTF-8?Q?=E3=81=AE=E4=BD=9C=E6=88=90?=\r\n\
+            Message-ID: <abc@example.com>\r\n\
+            Content-Type: text/plain; charset=\"utf-8\"\r\n\
+            Content-Transfer-Encoding: quoted-printable\r\n\
+            \r\n\
+            =E6=9D=A5=E9=80=B1=E3=81=BE=E3=81=A7=E3=81=AB=\r\n\
+            =E3=81=8A=E9=A1=98=E3=81=84=E3=81=97=E3=81=BE=E3=81=99\r\n";
+
+        // Act
+        let message = parse_message(raw);
+
+        // Assert
+        assert_eq!(message.message_id.as_deref(), Some("abc@example.com"));
+        assert_eq!(message.subject, "見積もりの作成");
+        assert_eq!(message.from, "Alice <alice@example.com>");
+        assert_eq!(message.body, "来週までにお願いします");
+    }
+
+    #[test]
+    fn test_parse_multipart_message() {
+        // Arrange
+        let raw = b"Subject: Invoice\n\
+            Content-Type: multipart/alternative; boundary=\"xyz\"\n\
+            \n\
+            preamble\n\
+            --xyz\n\
+            Content-Type: text/html\n\
+            \n\
+            <p>html</p>\n\
+            --xyz\n\
+            Content-Type: text/plain; charset=utf-8\n\
+            Content-Transfer-Encoding: base64\n\
+            \n\
+            UGxlYXNlIHBheQ==\n\
+            --xyz--\n";
+
+        // Act
+        let message = parse_message(raw);
+
+        // Assert
+        assert_eq!(message.subject, "Invoice");
+        assert_eq!(message.body, "Please pay");
+        assert_eq!(message.message_id, None);
+        assert_eq!(message.to_imported_task(None), None);
+    }
+
+    #[test]
+    fn test_filter_and_convert_to_task() {
+        // Arrange
+        let message = MailMessage {
+            message_id: Some("1@example.com".to_string()),
+            subject: " ".to_string(),
+            from: "Bob <bob@example.com>".to_string(),
+            body: "本文".to_string(),
+        };
+        let filter = MailFilter {
+            subject: None,
+            from: Some("BOB@".to_string()),
+        };
+
+        // Act
+        let task = message.to_imported_task(Some("email")).unwrap();
+
+        // Assert
+        assert!(filter.matches(&message));
+        assert!(
+            !MailFilter {
+                subject: Some("todo".to_string()),
+                from: None
+            }
+            .matches(&message)
+        );
+        assert_eq!(task.source_id, "1@example.com");
+        assert_eq!(task.title, NO_SUBJECT);
+        assert_eq!(task.description, "本文");
+        assert_eq!(task.tags, vec!["email"]);
+    }
+
+    #[test]
+    fn test_parse_encoded_headers() {
+        // Arrange
+        let raw = b"From: =?ISO-8859-1?Q?Andr=E9?= <andre@example.com>\n\
+            Subject: Re: =?ISO-2022-JP?B?GyRCJCIbKEI=?= =?ISO-8859-1?Q?Caf=E9_au_lait?=\n\
+            Message-ID: <x@example.com>\n\
+            Content-Type: text/plain; charset=iso-8859-1\n\
+            \n\
+            d\xe9j\xe0 vu\n";
+
+        // Act
+        let message = parse_message(raw);
+
+        // Assert
+        assert_eq!(message.subject, "Re: あCafé au lait");
+        assert_eq!(message.from, "André <andre@example.com>");
+        assert_eq!(message.body, "déjà vu");
+    }
+}
@@ -0,0 +1,359 @@
+//! IMAPサーバーからのメールの読み込み
+//!
+//! IMAPS（TLS）で接続し、フォルダを読み取り専用で開いて条件に一致するメールを取得します。
+//! サーバーからの応答は[`imap_proto`]で解析します。
+//! 既読などのフラグは変更しません。
+
+use super::MailFilter;
+use anyhow::{Context, Result, bail};
+use imap_proto::{AttributeValue, MailboxDatum, RequestId, Response, Status, parser};
+use std::{
+    io::{Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    sync::Arc,
+    time::Duration,
+};
+
+/// 接続・送受信のタイムアウト
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// IMAPサーバーのアカウント
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImapAccount {
+    pub host: String,
+    pub port: u16,
+    pub user: String,
+    pub password: String,
+    /// 読み込むフォルダ（`INBOX`など）
+    pub folder: String,
+}
+
+/// 条件に一致するメールを取得する
+///
+/// 通信はブロッキングで行うため、ブロッキング用のスレッドで実行します。
+pub async fn fetch_messages(account: ImapAccount, filter: MailFilter) -> Result<Vec<Vec<u8>>> {
+    tokio::task::spawn_blocking(move || fetch_messages_blocking(&account, &filter))
+        .await
+        .context("IMAP task panicked")?
+}
+
+/// 条件に一致するメールを取得する（ブロッキング）
+fn fetch_messages_blocking(account: &ImapAccount, filter: &MailFilter) -> Result<Vec<Vec<u8>>> {
+    let tcp = connect(&account.host, account.port)
+        .with_context(|| format!("Failed to connect to {}:{}", account.host, account.port))?;
+    let server_name = rustls::pki_types::ServerName::try_from(account.host.clone())
+        .with_context(|| format!("Invalid host name: {}", account.host))?;
+    let connection = rustls::ClientConnection::new(tls_config()?, server_name)?;
+    let mut session = Session::new(rustls::StreamOwned::new(connection, tcp));
+    session
+        .fetch(account, filter)
+        .with_context(|| format!("IMAP error from {}", account.host))
+}
+
+/// タイムアウトを設定して接続する
+fn connect(host: &str, port: u16) -> Result<TcpStream> {
+    let mut last_error = None;
+    for address in (host, port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, TIMEOUT) {
+            Ok(stream) => {
+                stream.set_read_timeout(Some(TIMEOUT))?;
+                stream.set_write_timeout(Some(TIMEOUT))?;
+                return Ok(stream);
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(match last_error {
+        Some(e) => e.into(),
+        None => anyhow::anyhow!("No address found for {}", host),
+    })
+}
+
+/// 信頼するルート証明書を設定したTLSの設定
+fn tls_config() -> Result<Arc<rustls::ClientConfig>> {
+    let roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let config = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    Ok(Arc::new(config))
+}
+
+/// IMAPのセッション
+struct Session<S: Read + Write> {
+    stream: S,
+    /// 受信して、まだ応答として読み込んでいないデータ
+    buffer: Vec<u8>,
+    next_tag: usize,
+}
+
+impl<S: Read + Write> Session<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream,
+            buffer: Vec::new(),
+            next_tag: 1,
+        }
+    }
+
+    /// ログインしてメールを取得し、ログアウトする
+    fn fetch(&mut self, account: &ImapAccount, filter: &MailFilter) -> Result<Vec<Vec<u8>>> {
+        match self.read_response()? {
+            Response::Data {
+                status: Status::Ok | Status::PreAuth,
+                ..
+            } => {}
+            greeting => bail!("Unexpected greeting: {:?}", greeting),
+        }
+        self.command(&format!(
+            "LOGIN {} {}",
+            quote(&account.user)?,
+            quote(&account.password)?
+        ))?;
+        self.command(&format!("EXAMINE {}", quote(&account.folder)?))?;
+
+        let uids: Vec<String> = self
+            .command(&format!("UID SEARCH {}", search_criteria(filter)?))?
+            .into_iter()
+            .filter_map(|response| match response {
+                Response::MailboxData(MailboxDatum::Search(uids)) => Some(uids),
+                _ => None,
+            })
+            .flatten()
+            .map(|uid| uid.to_string())
+            .collect();
+        let messages = if uids.is_empty() {
+            Vec::new()
+        } else {
+            self.command(&format!("UID FETCH {} BODY.PEEK[]", uids.join(",")))?
+                .into_iter()
+                .filter_map(|response| match response {
+                    Response::Fetch(_, attributes) => {
+                        attributes
+                            .into_iter()
+                            .find_map(|attribute| match attribute {
+                                AttributeValue::BodySection {
+                                    data: Some(data), ..
+                                } => Some(data.into_owned()),
+                                _ => None,
+                            })
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // 取得できていればログアウトの失敗は無視する
+        let _ = self.command("LOGOUT");
+        Ok(messages)
+    }
+
+    /// コマンドを送信し、完了の応答までのタグのない応答を返す
+    fn command(&mut self, command: &str) -> Result<Vec<Response<'static>>> {
+        let tag = format!("a{}", self.next_tag);
+        self.next_tag += 1;
+        self.stream
+            .write_all(format!("{} {}\r\n", tag, command).as_bytes())?;
+        self.stream.flush()?;
+
+        let mut responses = Vec::new();
+        loop {
+            match self.read_response()? {
+                Response::Done {
+                    tag: RequestId(done),
+                    status,
+                    information,
+                    ..
+                } if done == tag => {
+                    if status != Status::Ok {
+                        // パスワードを表示しないよう、コマンドの名前だけを含める
+                        let name = command.split(' ').next().unwrap_or_default();
+                        bail!("{} failed: {}", name, information.unwrap_or_default());
+                    }
+                    return Ok(responses);
+                }
+                response => responses.push(response),
+            }
+        }
+    }
+
+    /// 応答を1件読み込む（応答の途中までしか受信していない場合は続きを受信する）
+    fn read_response(&mut self) -> Result<Response<'static>> {
+        loop {
+            match parser::parse_response(&self.buffer) {
+                Ok((rest, response)) => {
+                    let consumed = self.buffer.len() - rest.len();
+                    let response = response.into_owned();
+                    self.buffer.drain(..consumed);
+                    return Ok(response);
+                }
+                Err(e) if e.is_incomplete() => {}
+                Err(_) => {
+                    let line = self
+                        .buffer
+                        .split(|&b| b == b'\n')
+                        .next()
+                        .unwrap_or_default();
+                    bail!(
+                        "Invalid response: {}",
+                        String::from_utf8_lossy(line).trim_end()
+                    );
+                }
+            }
+            let mut chunk = [0; 8192];
+            let n = self.stream.read(&mut chunk)?;
+            if n == 0 {
+                bail!("Connection closed by server");
+            }
+            self.buffer.extend_from_slice(&chunk[..n]);
+        }
+    }
+}
+
+/// 文字列を引用符で囲む（改行を含む場合はエラー）
+fn quote(value: &str) -> Result<String> {
+    if value.contains(['\r', '\n']) {
+        bail!("IMAP strings must not contain line breaks");
+    }
+    Ok(format!(
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+/// 取り込む条件をSEARCHの条件に変換する（削除済みのメールは除く）
+fn search_criteria(filter: &MailFilter) -> Result<String> {
+    let mut criteria = vec!["UNDELETED".to_string()];
+    if let Some(subject) = &filter.subject {
+        criteria.push(format!("SUBJECT {}", quote(subject)?));
+    }
+    if let Some(from) = &filter.from {
+        criteria.push(format!("FROM {}", quote(from)?));
+    }
+    let criteria = criteria.join(" ");
+    Ok(if criteria.is_ascii() {
+        criteria
+    } else {
+        format!("CHARSET UTF-8 {}", criteria)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// 決まった応答を返し、送信した内容を記録するストリーム
+    struct ScriptedStream {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>,
+    }
+
+    impl Read for ScriptedStream {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.input.read(buf)
+        }
+    }
+
+    impl Write for ScriptedStream {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.output.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn account() -> ImapAccount {
+        ImapAccount {
+            host: "imap.example.com".to_string(),
+            port: 993,
+            user: "me@example.com".to_string(),
+            password: "p\"w".to_string(),
+            folder: "INBOX".to_string(),
+        }
+    }
+
+    fn session(script: &str) -> Session<ScriptedStream> {
+        Session::new(ScriptedStream {
+            input: Cursor::new(script.as_bytes().to_vec()),
+            output: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn test_fetch_messages() {
+        // Arrange
+        let mut session = session(
+            "* OK ready\r\n\
+             a1 OK logged in\r\n\
+             * 2 EXISTS\r\n\
+             a2 OK [READ-ONLY] done\r\n\
+             * SEARCH 7 9\r\n\
+             a3 OK done\r\n\
+             * 1 FETCH (UID 7 BODY[] {11}\r\nSubject: a\n)\r\n\
+             * 2 FETCH (UID 9 BODY[] {11}\r\nSubject: b\n)\r\n\
+             a4 OK done\r\n\
+             * BYE logging out\r\n\
+             a5 OK bye\r\n",
+        );
+        let filter = MailFilter {
+            subject: Some("[todo]".to_string()),
+            from: None,
+        };
+
+        // Act
+        let messages = session.fetch(&account(), &filter).unwrap();
+
+        // Assert
+        assert_eq!(
+            messages,
+            vec![b"Subject: a\n".to_vec(), b"Subject: b\n".to_vec()]
+        );
+        let sent = String::from_utf8(session.stream.output).unwrap();
+        assert_eq!(
+            sent,
+            "a1 LOGIN \"me@example.com\" \"p\\\"w\"\r\n\
+             a2 EXAMINE \"INBOX\"\r\n\
+             a3 UID SEARCH UNDELETED SUBJECT \"[todo]\"\r\n\
+             a4 UID FETCH 7,9 BODY.PEEK[]\r\n\
+             a5 LOGOUT\r\n"
+        );
+    }
+
+    #[test]
+    fn test_fetch_messages_login_failure() {
+        // Arrange
+        let mut session = session("* OK ready\r\na1 NO [AUTHENTICATIONFAILED] invalid\r\n");
+
+        // Act
+        let result = session.fetch(&account(), &MailFilter::default());
+
+        // Assert
+        let message = result.unwrap_err().to_string();
+        assert!(message.starts_with("LOGIN failed"), "{message}");
+        assert!(!message.contains("p\"w"));
+    }
+
+    #[test]
+    fn test_search_criteria() {
+        assert_eq!(
+            search_criteria(&MailFilter::default()).unwrap(),
+            "UNDELETED"
+        );
+        assert_eq!(
+            search_criteria(&MailFilter {
+                subject: Some("依頼".to_string()),
+                from: Some("boss".to_string()),
+            })
+            .unwrap(),
+            "CHARSET UTF-8 UNDELETED SUBJECT \"依頼\" FROM \"boss\""
+        );
+        assert!(quote("a\r\nb").is_err());
+    }
+}
@@ -0,0 +1,67 @@
+//! maildirの読み込み
+//!
+//! フォルダの`new`と`cur`にあるメールを読み込みます。メールの移動やフラグの変更は行いません。
+
+use anyhow::{Context, Result, bail};
+use std::{fs, path::Path};
+
+/// maildirのフォルダのメールを読み込む（ファイル名の順、受信日時の順になる）
+///
+/// # Returns
+/// * `Err` - `new`・`cur`のどちらもない場合（maildirでない場合）や、読み込みに失敗した場合
+pub fn read_messages(dir: &Path) -> Result<Vec<Vec<u8>>> {
+    let mut paths = Vec::new();
+    let mut found = false;
+    for sub in ["new", "cur"] {
+        let sub_dir = dir.join(sub);
+        if !sub_dir.is_dir() {
+            continue;
+        }
+        found = true;
+        for entry in fs::read_dir(&sub_dir)
+            .with_context(|| format!("Failed to read {}", sub_dir.display()))?
+        {
+            let entry = entry?;
+            // 隠しファイルは配送中のメールなどのため除く
+            if entry.file_type()?.is_file() && !entry.file_name().to_string_lossy().starts_with('.')
+            {
+                paths.push(entry.path());
+            }
+        }
+    }
+    if !found {
+        bail!("Not a maildir (no new or cur folder): {}", dir.display());
+    }
+    paths.sort_by_key(|path| path.file_name().map(|name| name.to_os_string()));
+
+    paths
+        .iter()
+        .map(|path| fs::read(path).with_context(|| format!("Failed to read {}", path.display())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_messages() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("new")).unwrap();
+        fs::create_dir(dir.path().join("cur")).unwrap();
+        fs::write(dir.path().join("new/2.host"), "Subject: b\n\n").unwrap();
+        fs::write(dir.path().join("cur/1.host:2,S"), "Subject: a\n\n").unwrap();
+        fs::write(dir.path().join("new/.tmp"), "").unwrap();
+
+        // Act
+        let messages = read_messages(dir.path()).unwrap();
+
+        // Assert
+        assert_eq!(
+            messages,
+            vec![b"Subject: a\n\n".to_vec(), b"Subject: b\n\n".to_vec()]
+        );
+        assert!(read_messages(&dir.path().join("new")).is_err());
+    }
+}
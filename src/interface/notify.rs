@@ -0,0 +1,119 @@
+//! デスクトップ通知
+//!
+//! 外部コマンド（既定はLinuxの`notify-send`、macOSの`osascript`）を実行して通知を表示します。
+
+use crate::{
+    application::{dto::TaskDTO, use_cases::task::run_scheduled_jobs::Notifier},
+    interface::{cli::display::format::format_date, i18n::Msg},
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::process::Command;
+
+/// コマンドを実行して通知する
+///
+/// コマンドの引数中の`{title}`と`{body}`は通知のタイトルと本文に置き換えます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandNotifier {
+    command: Vec<String>,
+}
+
+impl CommandNotifier {
+    /// 新しいCommandNotifierを作成（コマンドが空の場合はOSの通知機能を使う）
+    pub fn new(command: Vec<String>) -> Self {
+        Self { command }
+    }
+
+    /// 通知に実行するコマンドと引数
+    fn command_line(&self, title: &str, body: &str) -> Vec<String> {
+        if !self.command.is_empty() {
+            return self
+                .command
+                .iter()
+                .map(|arg| arg.replace("{title}", title).replace("{body}", body))
+                .collect();
+        }
+        if cfg!(target_os = "macos") {
+            vec![
+                "osascript".to_string(),
+                "-e".to_string(),
+                format!(
+                    "display notification {} with title {}",
+                    applescript_string(body),
+                    applescript_string(title)
+                ),
+            ]
+        } else {
+            vec![
+                "notify-send".to_string(),
+                "--app-name=yaru".to_string(),
+                title.to_string(),
+                body.to_string(),
+            ]
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for CommandNotifier {
+    async fn notify_overdue(&self, task: &TaskDTO) -> Result<()> {
+        let title = Msg::NotifyOverdueTitle.text();
+        let body =
+            Msg::NotifyOverdueBody.format(&[&task.id, &task.title, &format_date(&task.due_date)]);
+        let command_line = self.command_line(title, &body);
+
+        let status = tokio::task::spawn_blocking(move || {
+            Command::new(&command_line[0])
+                .args(&command_line[1..])
+                .status()
+                .with_context(|| format!("Failed to run notification command: {}", command_line[0]))
+        })
+        .await??;
+        if !status.success() {
+            anyhow::bail!("Notification command exited with {}", status);
+        }
+        Ok(())
+    }
+}
+
+/// AppleScriptの文字列リテラルにする
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_line_replaces_placeholders() {
+        let notifier = CommandNotifier::new(vec![
+            "notify-send".to_string(),
+            "-u".to_string(),
+            "critical".to_string(),
+            "{title}".to_string(),
+            "yaru: {body}".to_string(),
+        ]);
+
+        let command_line = notifier.command_line("Task overdue", "[3] Write report");
+
+        assert_eq!(
+            command_line,
+            vec![
+                "notify-send",
+                "-u",
+                "critical",
+                "Task overdue",
+                "yaru: [3] Write report"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_applescript_string_escapes_quotes() {
+        assert_eq!(
+            applescript_string(r#"say "hi" \ o/"#),
+            r#""say \"hi\" \\ o/""#
+        );
+    }
+}
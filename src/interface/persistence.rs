@@ -1,2 +1,10 @@
+pub mod archive;
+#[cfg(test)]
+pub(crate) mod conformance;
+pub mod encryption;
+#[cfg(test)]
+pub(crate) mod failing;
 pub mod in_memory;
+pub mod json;
+pub mod read_only;
 pub mod sea_orm;
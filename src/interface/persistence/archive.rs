@@ -0,0 +1,99 @@
+use crate::application::{dto::TaskDTO, use_cases::task::run_scheduled_jobs::TaskArchive};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::{fs, io::Write, path::PathBuf};
+
+/// JsonLinesArchive - アーカイブしたタスクをJSON Lines形式のファイルに追記する保存先
+///
+/// 1行に1タスクのTaskDTOを書き出します。ファイルやディレクトリがない場合は作成します。
+pub struct JsonLinesArchive {
+    path: PathBuf,
+}
+
+impl JsonLinesArchive {
+    /// 新しいJsonLinesArchiveを作成
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl TaskArchive for JsonLinesArchive {
+    async fn store(&self, tasks: &[TaskDTO]) -> Result<()> {
+        let mut lines = String::new();
+        for task in tasks {
+            lines.push_str(&serde_json::to_string(task)?);
+            lines.push('\n');
+        }
+
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open archive: {}", self.path.display()))?;
+        file.write_all(lines.as_bytes())
+            .and_then(|()| file.sync_all())
+            .with_context(|| format!("Failed to write archive: {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::value_objects::{Priority, Status};
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    fn task(id: i32, title: &str) -> TaskDTO {
+        TaskDTO {
+            id,
+            title: title.to_string(),
+            description: None,
+            status: Status::Completed,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date: None,
+            completed_at: Some(Utc::now()),
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_appends_json_lines() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("archive.jsonl");
+        let archive = JsonLinesArchive::new(&path);
+
+        // Act
+        archive.store(&[task(1, "first")]).await.unwrap();
+        archive
+            .store(&[task(2, "second"), task(3, "third")])
+            .await
+            .unwrap();
+
+        // Assert
+        let content = fs::read_to_string(&path).unwrap();
+        let titles: Vec<String> = content
+            .lines()
+            .map(|line| serde_json::from_str::<TaskDTO>(line).unwrap().title)
+            .collect();
+        assert_eq!(titles, vec!["first", "second", "third"]);
+    }
+}
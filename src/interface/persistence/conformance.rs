@@ -0,0 +1,382 @@
+//! リポジトリ実装の適合テスト
+//!
+//! 同じ振る舞いのテストをすべてのTaskRepository実装（メモリ・JSONファイル・SeaORM）に対して実行し、
+//! 実装ごとの挙動のずれ（更新・保存の違いなど）を検出します。
+//! 新しい実装を追加した場合は、そのテストモジュールで
+//! [`task_repository_conformance_tests!`]を呼び出してください。
+
+use crate::domain::clock::{Clock, FixedClock, SystemClock};
+use crate::domain::task::{
+    aggregate::{NewTask, TaskAggregate},
+    builder::TaskBuilder,
+    repository::TaskRepository,
+    specification::{
+        NotSpecification, SearchField, TaskByAssignee, TaskByKeyword, TaskByPriority, TaskByStatus,
+        TaskDueBetween, TaskHasDueDate, TaskInContext, TaskSpecification,
+    },
+    status_transition::StatusTransitionPolicy,
+    value_objects::{
+        Assignee, ChecklistItem, CustomField, DueDate, Estimate, Priority, Status, TaskContext,
+        TaskDescription, TaskId, TaskTitle,
+    },
+};
+use chrono::NaiveDate;
+use futures_util::TryStreamExt;
+
+/// TaskRepositoryの適合テストを生成する
+///
+/// 引数には、空のリポジトリを作成するFutureを渡します。Futureの結果は`(後片付け用の値, リポジトリ)`で、
+/// 1つ目の値（一時ディレクトリなど）はテストの終了まで保持されます。
+///
+/// ```rust,ignore
+/// task_repository_conformance_tests!(async { ((), InMemoryTaskRepository::new()) });
+/// ```
+macro_rules! task_repository_conformance_tests {
+    ($setup:expr) => {
+        mod task_repository_conformance {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::interface::persistence::conformance::task_repository_conformance_tests!(
+                @cases $setup;
+                test_save_assigns_ids_and_round_trips_fields,
+                test_find_by_id_returns_none_for_missing_task,
+                test_find_all_returns_tasks_in_id_order,
+                test_save_all_preserves_order,
+                test_update_persists_changes,
+                test_update_appends_status_history,
+                test_update_missing_task_fails,
+                test_delete_reports_whether_task_existed,
+                test_ids_are_not_reused_after_delete,
+                test_find_by_specification_matches_in_memory_evaluation,
+                test_find_by_due_date_range_is_inclusive,
+            );
+        }
+    };
+    (@cases $setup:expr; $($case:ident),* $(,)?) => {
+        $(
+            #[tokio::test]
+            async fn $case() {
+                let (_guard, repository) = $setup.await;
+                $crate::interface::persistence::conformance::$case(&repository).await;
+            }
+        )*
+    };
+}
+
+pub(crate) use task_repository_conformance_tests;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn new_task(title: &str) -> NewTask {
+    TaskBuilder::new(TaskTitle::new(title).unwrap()).build()
+}
+
+fn due_task(title: &str, due: NaiveDate) -> NewTask {
+    TaskBuilder::new(TaskTitle::new(title).unwrap())
+        .due_date(Some(DueDate::new(due).unwrap()))
+        .build()
+}
+
+/// チェックリストのある保存前のタスク（削除したタスクを作り直す場合と同じく、TaskAggregateから変換する）
+pub fn with_checklist(task: NewTask, items: Vec<ChecklistItem>) -> NewTask {
+    let mut task = task.into_aggregate(TaskId::new(1).unwrap());
+    for item in items {
+        task.add_checklist_item(item, &SystemClock::default())
+            .unwrap();
+    }
+    task.into_new_task()
+}
+
+fn titles(tasks: &[TaskAggregate]) -> Vec<&str> {
+    tasks.iter().map(|task| task.title().value()).collect()
+}
+
+/// すべての項目を設定したタスクを保存し、読み直しても同じ内容になる
+pub async fn test_save_assigns_ids_and_round_trips_fields(repository: &dyn TaskRepository) {
+    // Arrange
+    let task = TaskBuilder::new(TaskTitle::new("週次レポート").unwrap())
+        .description(TaskDescription::new("先週の進捗をまとめる").unwrap())
+        .status(Status::InProgress)
+        .priority(Priority::Critical)
+        .due_date(Some(DueDate::new(date(2026, 11, 2)).unwrap()))
+        .custom_fields(vec![CustomField::new("client", "ACME").unwrap()])
+        .recurrence(Some("every 2 weeks".parse().unwrap()))
+        .estimate(Some(Estimate::from_minutes(90).unwrap()))
+        .context(Some(TaskContext::new("@office").unwrap()))
+        .assignee(Some(Assignee::new("alice").unwrap()))
+        .build();
+    let task = with_checklist(
+        task,
+        vec![
+            ChecklistItem::new("数値を集計", true).unwrap(),
+            ChecklistItem::new("送信", false).unwrap(),
+        ],
+    );
+
+    // Act
+    let first = repository.save(task).await.unwrap();
+    let second = repository.save(new_task("2件目")).await.unwrap();
+    let found = repository.find_by_id(first.id()).await.unwrap();
+
+    // Assert
+    assert_eq!(first.id().value(), 1);
+    assert_eq!(second.id().value(), 2);
+    assert_eq!(found.as_ref(), Some(&first));
+    let found = found.unwrap();
+    assert_eq!(found.description().value(), "先週の進捗をまとめる");
+    assert_eq!(*found.status(), Status::InProgress);
+    assert_eq!(*found.priority(), Priority::Critical);
+    assert_eq!(found.custom_field("client").unwrap().value(), "ACME");
+    assert_eq!(found.checklist().len(), 2);
+    assert!(found.checklist()[0].is_done());
+    assert_eq!(found.recurrence().unwrap().to_string(), "every 2 weeks");
+    assert_eq!(found.estimate().unwrap().minutes(), 90);
+    assert_eq!(found.context().unwrap().value(), "@office");
+    assert_eq!(found.assignee().unwrap().value(), "alice");
+}
+
+/// 存在しないIDの検索はエラーではなくNoneを返す
+pub async fn test_find_by_id_returns_none_for_missing_task(repository: &dyn TaskRepository) {
+    repository.save(new_task("タスク")).await.unwrap();
+
+    let found = repository
+        .find_by_id(&TaskId::new(99).unwrap())
+        .await
+        .unwrap();
+
+    assert!(found.is_none());
+}
+
+/// 一覧はIDの昇順で、ストリームでの取得とも一致する
+pub async fn test_find_all_returns_tasks_in_id_order(repository: &dyn TaskRepository) {
+    // Arrange
+    for title in ["A", "B", "C"] {
+        repository.save(new_task(title)).await.unwrap();
+    }
+
+    // Act
+    let tasks = repository.find_all().await.unwrap();
+    let streamed: Vec<TaskAggregate> = repository.find_all_stream().try_collect().await.unwrap();
+
+    // Assert
+    assert_eq!(titles(&tasks), vec!["A", "B", "C"]);
+    assert_eq!(streamed, tasks);
+}
+
+/// 一括保存は入力の順にIDを割り当てて返す
+pub async fn test_save_all_preserves_order(repository: &dyn TaskRepository) {
+    let saved = repository
+        .save_all(vec![new_task("1"), new_task("2"), new_task("3")])
+        .await
+        .unwrap();
+
+    let ids: Vec<i32> = saved.iter().map(|task| task.id().value()).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert_eq!(titles(&saved), vec!["1", "2", "3"]);
+    assert_eq!(repository.find_all().await.unwrap(), saved);
+}
+
+/// 更新結果は返り値と読み直した値が一致し、子要素（チェックリストなど）は置き換えられる
+pub async fn test_update_persists_changes(repository: &dyn TaskRepository) {
+    // Arrange
+    let task = TaskBuilder::new(TaskTitle::new("変更前").unwrap())
+        .custom_fields(vec![CustomField::new("client", "ACME").unwrap()])
+        .build();
+    let task = with_checklist(task, vec![ChecklistItem::new("古い項目", false).unwrap()]);
+    let mut task = repository.save(task).await.unwrap();
+
+    task.change_title(TaskTitle::new("変更後").unwrap(), &SystemClock::default())
+        .unwrap();
+    task.change_priority(Priority::High, &SystemClock::default())
+        .unwrap();
+    task.remove_custom_field("client", &SystemClock::default())
+        .unwrap();
+    task.remove_checklist_item(1, &SystemClock::default())
+        .unwrap();
+    task.add_checklist_item(
+        ChecklistItem::new("新しい項目", false).unwrap(),
+        &SystemClock::default(),
+    )
+    .unwrap();
+    task.complete(&FixedClock::at(2026, 1, 10, 9, 0)).unwrap();
+
+    // Act
+    let updated = repository.update(task).await.unwrap();
+    let found = repository.find_by_id(updated.id()).await.unwrap().unwrap();
+
+    // Assert
+    assert_eq!(found, updated);
+    assert_eq!(found.title().value(), "変更後");
+    assert_eq!(*found.priority(), Priority::High);
+    assert_eq!(*found.status(), Status::Completed);
+    assert!(found.completed_at().is_some());
+    assert!(found.custom_fields().is_empty());
+    let items: Vec<&str> = found.checklist().iter().map(|item| item.text()).collect();
+    assert_eq!(items, vec!["新しい項目"]);
+    assert_eq!(repository.find_all().await.unwrap().len(), 1);
+}
+
+/// ステータスの変更履歴は更新のたびに追記され、変更した順に読み込まれる
+pub async fn test_update_appends_status_history(repository: &dyn TaskRepository) {
+    // Arrange
+    let policy = StatusTransitionPolicy::default();
+    let started = FixedClock::at(2026, 1, 10, 9, 0);
+    let completed = started.advanced(chrono::TimeDelta::hours(3));
+    let mut task = repository.save(new_task("タスク")).await.unwrap();
+    task.change_status(Status::InProgress, &policy, &started)
+        .unwrap();
+    let mut task = repository.update(task).await.unwrap();
+    task.change_status(Status::Completed, &policy, &completed)
+        .unwrap();
+
+    // Act
+    let updated = repository.update(task).await.unwrap();
+    let found = repository.find_by_id(updated.id()).await.unwrap().unwrap();
+
+    // Assert
+    assert_eq!(found, updated);
+    let history: Vec<(Status, Status)> = found
+        .status_history()
+        .iter()
+        .map(|change| (change.from(), change.to()))
+        .collect();
+    assert_eq!(
+        history,
+        vec![
+            (Status::Pending, Status::InProgress),
+            (Status::InProgress, Status::Completed),
+        ]
+    );
+    assert_eq!(*found.status_history()[0].changed_at(), started.now());
+    assert_eq!(*found.status_history()[1].changed_at(), completed.now());
+    assert_eq!(*found.completed_at(), Some(completed.now()));
+}
+
+/// 保存されていないタスクの更新はエラーになり、新しく作成しない
+pub async fn test_update_missing_task_fails(repository: &dyn TaskRepository) {
+    let task = repository.save(new_task("タスク")).await.unwrap();
+    repository.delete(task.id()).await.unwrap();
+
+    let result = repository.update(task).await;
+
+    assert!(result.is_err());
+    assert!(repository.find_all().await.unwrap().is_empty());
+}
+
+/// 削除は対象が存在したかを返す
+pub async fn test_delete_reports_whether_task_existed(repository: &dyn TaskRepository) {
+    let task = repository.save(new_task("タスク")).await.unwrap();
+    let other = repository.save(new_task("残るタスク")).await.unwrap();
+
+    assert!(repository.delete(task.id()).await.unwrap());
+    assert!(!repository.delete(task.id()).await.unwrap());
+    assert!(repository.find_by_id(task.id()).await.unwrap().is_none());
+    assert_eq!(repository.find_all().await.unwrap(), vec![other]);
+}
+
+/// 削除したタスクのIDは再利用しない（最後のタスクを削除した場合も含む）
+pub async fn test_ids_are_not_reused_after_delete(repository: &dyn TaskRepository) {
+    repository.save(new_task("1")).await.unwrap();
+    let last = repository.save(new_task("2")).await.unwrap();
+    repository.delete(last.id()).await.unwrap();
+
+    let saved = repository.save(new_task("3")).await.unwrap();
+
+    assert_eq!(saved.id().value(), 3);
+}
+
+/// 条件での検索・件数は、条件をメモリ上で評価した結果と一致する
+pub async fn test_find_by_specification_matches_in_memory_evaluation(
+    repository: &dyn TaskRepository,
+) {
+    // Arrange
+    let tasks = vec![
+        TaskBuilder::new(TaskTitle::new("請求書を送る").unwrap())
+            .priority(Priority::High)
+            .due_date(Some(DueDate::new(date(2026, 11, 1)).unwrap()))
+            .context(Some(TaskContext::new("@office").unwrap()))
+            .assignee(Some(Assignee::new("alice").unwrap()))
+            .build(),
+        TaskBuilder::new(TaskTitle::new("牛乳を買う").unwrap())
+            .description(TaskDescription::new("請求書の封筒も").unwrap())
+            .status(Status::Completed)
+            .build(),
+        TaskBuilder::new(TaskTitle::new("掃除").unwrap())
+            .status(Status::InProgress)
+            .priority(Priority::Low)
+            .due_date(Some(DueDate::new(date(2026, 12, 24)).unwrap()))
+            .context(Some(TaskContext::new("@home").unwrap()))
+            .assignee(Some(Assignee::new("bob").unwrap()))
+            .build(),
+    ];
+    repository.save_all(tasks).await.unwrap();
+    let all = repository.find_all().await.unwrap();
+
+    let specifications: Vec<fn() -> Box<dyn TaskSpecification>> = vec![
+        || Box::new(TaskByStatus::new(Status::Completed)),
+        || Box::new(TaskByPriority::new(Priority::Low)),
+        || Box::new(TaskHasDueDate),
+        || Box::new(TaskDueBetween::new(date(2026, 10, 1), date(2026, 11, 30))),
+        || Box::new(NotSpecification::new(Box::new(TaskHasDueDate))),
+        || Box::new(TaskByAssignee::new(Assignee::new("alice").unwrap())),
+        || {
+            Box::new(NotSpecification::new(Box::new(TaskByAssignee::new(
+                Assignee::new("bob").unwrap(),
+            ))))
+        },
+        || {
+            Box::new(TaskInContext::new(TaskContext::new("@home").unwrap()))
+                .and(Box::new(TaskByStatus::new(Status::InProgress)))
+        },
+        || {
+            Box::new(TaskByKeyword::new(
+                vec!["請求書".to_string()],
+                SearchField::All,
+            ))
+        },
+    ];
+
+    for specification in specifications {
+        let expected: Vec<&TaskAggregate> = all
+            .iter()
+            .filter(|task| specification().is_satisfied_by(task))
+            .collect();
+
+        // Act
+        let found = repository
+            .find_by_specification(specification())
+            .await
+            .unwrap();
+        let count = repository
+            .count_by_specification(specification())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(found.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(count, expected.len() as u64);
+    }
+}
+
+/// 期限日の範囲検索は両端の日付を含む
+pub async fn test_find_by_due_date_range_is_inclusive(repository: &dyn TaskRepository) {
+    for (title, due) in [
+        ("前日", date(2026, 10, 31)),
+        ("初日", date(2026, 11, 1)),
+        ("最終日", date(2026, 11, 7)),
+        ("翌日", date(2026, 11, 8)),
+    ] {
+        repository.save(due_task(title, due)).await.unwrap();
+    }
+    repository.save(new_task("期限なし")).await.unwrap();
+
+    let tasks = repository
+        .find_by_due_date_range(date(2026, 11, 1), date(2026, 11, 7))
+        .await
+        .unwrap();
+
+    assert_eq!(titles(&tasks), vec!["初日", "最終日"]);
+}
@@ -0,0 +1,170 @@
+use anyhow::{Context, Result};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use ring::{
+    aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use std::num::NonZeroU32;
+
+/// 暗号化された値の接頭辞（平文との判別とフォーマットのバージョン管理に使用）
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+/// パスフレーズから鍵を導出する際のPBKDF2の反復回数
+const PBKDF2_ITERATIONS: NonZeroU32 = NonZeroU32::new(100_000).unwrap();
+
+/// ソルトのバイト数
+const SALT_LEN: usize = 16;
+
+/// FieldCipher - パスフレーズから導出した鍵でフィールド単位の暗号化を行う
+///
+/// AES-256-GCMで暗号化し、`enc:v1:`に続けてnonceと暗号文をBase64で格納します。
+/// 接頭辞のない値は平文として扱うため、暗号化前のデータと混在していても読み込めます。
+pub struct FieldCipher {
+    key: LessSafeKey,
+}
+
+impl FieldCipher {
+    /// パスフレーズとソルトから鍵を導出してFieldCipherを作成
+    pub fn derive(passphrase: &str, salt: &[u8]) -> Result<Self> {
+        let mut key_bytes = [0u8; 32];
+        pbkdf2::derive(
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            PBKDF2_ITERATIONS,
+            salt,
+            passphrase.as_bytes(),
+            &mut key_bytes,
+        );
+        let key = UnboundKey::new(&AES_256_GCM, &key_bytes)
+            .map_err(|_| anyhow::anyhow!("Failed to create encryption key"))?;
+
+        Ok(Self {
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    /// 新しいランダムなソルトを生成
+    pub fn generate_salt() -> Result<Vec<u8>> {
+        let mut salt = vec![0u8; SALT_LEN];
+        SystemRandom::new()
+            .fill(&mut salt)
+            .map_err(|_| anyhow::anyhow!("Failed to generate salt"))?;
+        Ok(salt)
+    }
+
+    /// 値が暗号化されているか判定
+    pub fn is_encrypted(value: &str) -> bool {
+        value.starts_with(ENCRYPTED_PREFIX)
+    }
+
+    /// 平文を暗号化
+    pub fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let payload = self.encrypt_bytes(plaintext.as_bytes())?;
+        Ok(format!("{}{}", ENCRYPTED_PREFIX, STANDARD.encode(payload)))
+    }
+
+    /// バイト列を暗号化（nonceに続けて暗号文を返す）
+    pub fn encrypt_bytes(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce_bytes)
+            .map_err(|_| anyhow::anyhow!("Failed to generate nonce"))?;
+
+        let mut in_out = plaintext.to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce_bytes),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt value"))?;
+
+        let mut payload = nonce_bytes.to_vec();
+        payload.extend_from_slice(&in_out);
+        Ok(payload)
+    }
+
+    /// 暗号化された値を復号（接頭辞のない値はそのまま返す）
+    pub fn decrypt(&self, value: &str) -> Result<String> {
+        let Some(encoded) = value.strip_prefix(ENCRYPTED_PREFIX) else {
+            return Ok(value.to_string());
+        };
+
+        let payload = STANDARD
+            .decode(encoded)
+            .context("Failed to decode encrypted value")?;
+        let plaintext = self.decrypt_bytes(&payload)?;
+
+        String::from_utf8(plaintext).context("Decrypted value is not valid UTF-8")
+    }
+
+    /// `encrypt_bytes`で暗号化したバイト列を復号
+    pub fn decrypt_bytes(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        if payload.len() < NONCE_LEN {
+            anyhow::bail!("Encrypted value is too short");
+        }
+
+        let (nonce_bytes, ciphertext) = payload.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce_bytes)
+            .map_err(|_| anyhow::anyhow!("Invalid nonce in encrypted value"))?;
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| anyhow::anyhow!("Failed to decrypt value (wrong passphrase?)"))?;
+        Ok(plaintext.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        // Arrange
+        let salt = FieldCipher::generate_salt().unwrap();
+        let cipher = FieldCipher::derive("secret", &salt).unwrap();
+
+        // Act
+        let encrypted = cipher.encrypt("資料作成").unwrap();
+
+        // Assert
+        assert!(FieldCipher::is_encrypted(&encrypted));
+        assert!(!encrypted.contains("資料作成"));
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "資料作成");
+    }
+
+    #[test]
+    fn test_encrypt_uses_random_nonce() {
+        let cipher = FieldCipher::derive("secret", b"0123456789abcdef").unwrap();
+        assert_ne!(
+            cipher.encrypt("same").unwrap(),
+            cipher.encrypt("same").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decrypt_plaintext_passthrough() {
+        let cipher = FieldCipher::derive("secret", b"0123456789abcdef").unwrap();
+        assert_eq!(cipher.decrypt("plain").unwrap(), "plain");
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_passphrase_fails() {
+        // Arrange
+        let salt = FieldCipher::generate_salt().unwrap();
+        let encrypted = FieldCipher::derive("secret", &salt)
+            .unwrap()
+            .encrypt("value")
+            .unwrap();
+
+        // Act
+        let result = FieldCipher::derive("wrong", &salt)
+            .unwrap()
+            .decrypt(&encrypted);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,467 @@
+//! 障害を注入するリポジトリ（エラー処理のテスト用）
+//!
+//! 既存のリポジトリ・UnitOfWorkをラップし、指定した呼び出しでエラーを返します。
+//! ユースケースが途中の失敗でロールバックすること、分かりやすいエラーを返すことの確認に使います。
+//!
+//! 呼び出しは`"task.save"`・`"tag.find_all"`・`"commit"`のように`リポジトリ.メソッド名`で指定します。
+//!
+//! ```rust,ignore
+//! // 2回目のタスク保存を、保存した後に失敗させる
+//! let faults = Arc::new(FaultInjector::fail_on("task.save").nth(2).after_call());
+//! let factory = FailingUnitOfWorkFactory::new(Arc::new(SeaOrmUnitOfWorkFactory::new(db)), faults);
+//! ```
+
+use crate::domain::{
+    tag::{
+        aggregate::{NewTag, TagAggregate},
+        repository::TagRepository,
+        value_objects::TagId,
+    },
+    task::{
+        aggregate::{NewTask, TaskAggregate},
+        repository::{TaskRepository, TaskStream},
+        specification::TaskSpecification,
+        value_objects::TaskId,
+    },
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use anyhow::Result;
+use chrono::NaiveDate;
+use futures_util::stream;
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+/// 障害を発生させるタイミング
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultTiming {
+    /// 元のリポジトリを呼び出さずに失敗する
+    Before,
+    /// 元のリポジトリの処理を実行した後に失敗する（書き込み後の失敗を再現する）
+    After,
+}
+
+/// どの呼び出しで失敗させるかの設定
+///
+/// 条件に一致したn回目の呼び出しだけが失敗し、それ以外の呼び出しは元のリポジトリに委譲します。
+#[derive(Debug)]
+pub struct FaultInjector {
+    /// 対象の呼び出し（Noneの場合はすべての呼び出し）
+    method: Option<&'static str>,
+    /// 何回目の呼び出しで失敗させるか（1始まり）
+    nth: usize,
+    timing: FaultTiming,
+    calls: AtomicUsize,
+}
+
+impl FaultInjector {
+    /// 指定した呼び出しの1回目で失敗させる
+    pub fn fail_on(method: &'static str) -> Self {
+        Self {
+            method: Some(method),
+            nth: 1,
+            timing: FaultTiming::Before,
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    /// 種類を問わず、n回目の呼び出しで失敗させる
+    pub fn fail_on_call(nth: usize) -> Self {
+        Self {
+            method: None,
+            nth,
+            timing: FaultTiming::Before,
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    /// 条件に一致したn回目の呼び出しで失敗させる（1始まり）
+    pub fn nth(mut self, nth: usize) -> Self {
+        self.nth = nth;
+        self
+    }
+
+    /// 元のリポジトリの処理を実行した後に失敗させる
+    pub fn after_call(mut self) -> Self {
+        self.timing = FaultTiming::After;
+        self
+    }
+
+    /// 条件に一致した呼び出しの回数
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    /// 呼び出しを記録し、失敗させる場合はそのタイミングを返す
+    fn check(&self, method: &'static str) -> Option<FaultTiming> {
+        if self.method.is_some_and(|target| target != method) {
+            return None;
+        }
+        let count = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        (count == self.nth).then_some(self.timing)
+    }
+
+    fn error(method: &str) -> anyhow::Error {
+        anyhow::anyhow!("Injected failure in {}", method)
+    }
+
+    /// 設定に従って呼び出しを実行する
+    async fn run<T>(
+        &self,
+        method: &'static str,
+        call: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        match self.check(method) {
+            None => call.await,
+            Some(FaultTiming::Before) => Err(Self::error(method)),
+            Some(FaultTiming::After) => {
+                call.await?;
+                Err(Self::error(method))
+            }
+        }
+    }
+}
+
+/// 障害を注入するTaskRepository
+pub struct FailingTaskRepository {
+    inner: Arc<dyn TaskRepository>,
+    faults: Arc<FaultInjector>,
+}
+
+impl FailingTaskRepository {
+    pub fn new(inner: Arc<dyn TaskRepository>, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskRepository for FailingTaskRepository {
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<TaskAggregate>> {
+        self.faults
+            .run("task.find_by_id", self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TaskAggregate>> {
+        self.faults
+            .run("task.find_all", self.inner.find_all())
+            .await
+    }
+
+    /// ストリームは作成時に判定し、失敗する場合は最初の要素としてエラーを返す
+    fn find_all_stream(&self) -> TaskStream<'_> {
+        match self.faults.check("task.find_all_stream") {
+            None => self.inner.find_all_stream(),
+            Some(_) => Box::pin(stream::once(async {
+                Err(FaultInjector::error("task.find_all_stream"))
+            })),
+        }
+    }
+
+    async fn find_by_specification(
+        &self,
+        spec: Box<dyn TaskSpecification>,
+    ) -> Result<Vec<TaskAggregate>> {
+        self.faults
+            .run(
+                "task.find_by_specification",
+                self.inner.find_by_specification(spec),
+            )
+            .await
+    }
+
+    async fn count_by_specification(&self, spec: Box<dyn TaskSpecification>) -> Result<u64> {
+        self.faults
+            .run(
+                "task.count_by_specification",
+                self.inner.count_by_specification(spec),
+            )
+            .await
+    }
+
+    async fn find_by_due_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<TaskAggregate>> {
+        self.faults
+            .run(
+                "task.find_by_due_date_range",
+                self.inner.find_by_due_date_range(from, to),
+            )
+            .await
+    }
+
+    async fn save(&self, task: NewTask) -> Result<TaskAggregate> {
+        self.faults.run("task.save", self.inner.save(task)).await
+    }
+
+    async fn save_all(&self, tasks: Vec<NewTask>) -> Result<Vec<TaskAggregate>> {
+        self.faults
+            .run("task.save_all", self.inner.save_all(tasks))
+            .await
+    }
+
+    async fn update(&self, task: TaskAggregate) -> Result<TaskAggregate> {
+        self.faults
+            .run("task.update", self.inner.update(task))
+            .await
+    }
+
+    async fn delete(&self, id: &TaskId) -> Result<bool> {
+        self.faults.run("task.delete", self.inner.delete(id)).await
+    }
+}
+
+/// 障害を注入するTagRepository
+pub struct FailingTagRepository {
+    inner: Arc<dyn TagRepository>,
+    faults: Arc<FaultInjector>,
+}
+
+impl FailingTagRepository {
+    pub fn new(inner: Arc<dyn TagRepository>, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+}
+
+#[async_trait::async_trait]
+impl TagRepository for FailingTagRepository {
+    async fn find_by_id(&self, id: &TagId) -> Result<Option<TagAggregate>> {
+        self.faults
+            .run("tag.find_by_id", self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TagAggregate>> {
+        self.faults.run("tag.find_all", self.inner.find_all()).await
+    }
+
+    async fn save(&self, tag: NewTag) -> Result<TagAggregate> {
+        self.faults.run("tag.save", self.inner.save(tag)).await
+    }
+
+    async fn update(&self, tag: TagAggregate) -> Result<TagAggregate> {
+        self.faults.run("tag.update", self.inner.update(tag)).await
+    }
+
+    async fn delete(&self, id: &TagId) -> Result<bool> {
+        self.faults.run("tag.delete", self.inner.delete(id)).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>> {
+        self.faults
+            .run("tag.find_by_name", self.inner.find_by_name(name))
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[TagId]) -> Result<Vec<TagAggregate>> {
+        self.faults
+            .run("tag.find_by_ids", self.inner.find_by_ids(ids))
+            .await
+    }
+}
+
+/// 障害を注入するUnitOfWorkFactory
+///
+/// 開始したUnitOfWorkのリポジトリとコミット（`"commit"`）に障害を注入します。
+pub struct FailingUnitOfWorkFactory {
+    inner: Arc<dyn UnitOfWorkFactory>,
+    faults: Arc<FaultInjector>,
+}
+
+impl FailingUnitOfWorkFactory {
+    pub fn new(inner: Arc<dyn UnitOfWorkFactory>, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWorkFactory for FailingUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>> {
+        let inner = self.faults.run("begin", self.inner.begin()).await?;
+        Ok(Box::new(FailingUnitOfWork {
+            inner,
+            faults: self.faults.clone(),
+        }))
+    }
+}
+
+/// 障害を注入するUnitOfWork
+struct FailingUnitOfWork {
+    inner: Box<dyn UnitOfWork>,
+    faults: Arc<FaultInjector>,
+}
+
+#[async_trait::async_trait]
+impl UnitOfWork for FailingUnitOfWork {
+    fn task_repository(&self) -> Arc<dyn TaskRepository> {
+        Arc::new(FailingTaskRepository::new(
+            self.inner.task_repository(),
+            self.faults.clone(),
+        ))
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        Arc::new(FailingTagRepository::new(
+            self.inner.tag_repository(),
+            self.faults.clone(),
+        ))
+    }
+
+    /// コミット前に失敗した場合、元のUnitOfWorkは破棄されてロールバックされる
+    async fn commit(self: Box<Self>) -> Result<()> {
+        let faults = self.faults.clone();
+        faults.run("commit", self.inner.commit()).await
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<()> {
+        let faults = self.faults.clone();
+        faults.run("rollback", self.inner.rollback()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::{
+        domain::{
+            tag::value_objects::{TagDescription, TagName},
+            task::{builder::TaskBuilder, value_objects::TaskTitle},
+            unit_of_work::commit_or_rollback,
+        },
+        interface::persistence::{
+            in_memory::{InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory},
+            sea_orm::{SeaOrmTagRepository, SeaOrmUnitOfWorkFactory},
+        },
+    };
+    use futures_util::TryStreamExt;
+
+    fn new_task(title: &str) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap()).build()
+    }
+
+    fn new_tag(name: &str) -> NewTag {
+        NewTag::new(
+            TagName::new(name).unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fails_only_on_nth_matching_call() {
+        // Arrange
+        let inner = Arc::new(InMemoryTaskRepository::new());
+        let faults = Arc::new(FaultInjector::fail_on("task.save").nth(2));
+        let repository = FailingTaskRepository::new(inner.clone(), faults.clone());
+
+        // Act
+        let first = repository.save(new_task("1つ目")).await;
+        let second = repository.save(new_task("2つ目")).await;
+        let third = repository.save(new_task("3つ目")).await;
+
+        // Assert: 2回目だけが元のリポジトリを呼ばずに失敗し、他の呼び出しは数えない
+        assert!(first.is_ok());
+        assert_eq!(
+            second.unwrap_err().to_string(),
+            "Injected failure in task.save"
+        );
+        assert!(third.is_ok());
+        assert_eq!(repository.find_all().await.unwrap().len(), 2);
+        assert_eq!(inner.find_all().await.unwrap().len(), 2);
+        assert_eq!(faults.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_after_call_fails_after_inner_operation() {
+        // Arrange
+        let inner = Arc::new(InMemoryTaskRepository::new());
+        let faults = Arc::new(FaultInjector::fail_on("task.save").after_call());
+        let repository = FailingTaskRepository::new(inner.clone(), faults);
+
+        // Act
+        let result = repository.save(new_task("保存される")).await;
+
+        // Assert: エラーを返すが、元のリポジトリには保存されている
+        assert!(result.is_err());
+        assert_eq!(inner.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fail_on_call_counts_every_method() {
+        // Arrange
+        let inner = Arc::new(InMemoryTaskRepository::new());
+        let repository =
+            FailingTaskRepository::new(inner, Arc::new(FaultInjector::fail_on_call(2)));
+
+        // Act
+        let find_all = repository.find_all().await;
+        let stream = repository.find_all_stream().try_collect::<Vec<_>>().await;
+
+        // Assert
+        assert!(find_all.is_ok());
+        assert_eq!(
+            stream.unwrap_err().to_string(),
+            "Injected failure in task.find_all_stream"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_repositories_share_faults() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let factory = FailingUnitOfWorkFactory::new(
+            Arc::new(InMemoryUnitOfWorkFactory::new(
+                task_repo.clone(),
+                tag_repo.clone(),
+            )),
+            Arc::new(FaultInjector::fail_on("tag.save")),
+        );
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let result: Result<()> = async {
+            uow.task_repository().save(new_task("タスク")).await?;
+            uow.tag_repository().save(new_tag("仕事")).await?;
+            Ok(())
+        }
+        .await;
+        let result = commit_or_rollback(uow, result).await;
+
+        // Assert: タグの保存が失敗し、先に保存したタスクも取り消される
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Injected failure in tag.save"
+        );
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_commit_failure_discards_transaction() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let factory = FailingUnitOfWorkFactory::new(
+            Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone())),
+            Arc::new(FaultInjector::fail_on("commit")),
+        );
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let result = uow.tag_repository().save(new_tag("仕事")).await.map(|_| ());
+        let result = commit_or_rollback(uow, result).await;
+
+        // Assert: コミットされなかったトランザクションは破棄される
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Injected failure in commit"
+        );
+        let tags = SeaOrmTagRepository::new(db).find_all().await.unwrap();
+        assert!(tags.is_empty());
+    }
+}
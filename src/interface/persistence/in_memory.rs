@@ -1,11 +1,12 @@
-// テスト専用のモジュール
-#[cfg(test)]
+// テストおよびエフェメラルモード（--ephemeral）で使用するモジュール
+pub mod snapshot;
 pub mod tag_repository;
-#[cfg(test)]
 pub mod task_repository;
+pub mod time_entry_repository;
+pub mod unit_of_work;
 
-// テスト専用の公開エクスポート（テストコードから使用）
-#[cfg(test)]
+pub use snapshot::InMemorySnapshot;
 pub use tag_repository::InMemoryTagRepository;
-#[cfg(test)]
 pub use task_repository::InMemoryTaskRepository;
+pub use time_entry_repository::InMemoryTimeEntryRepository;
+pub use unit_of_work::InMemoryUnitOfWorkFactory;
@@ -0,0 +1,601 @@
+use crate::{
+    domain::{
+        tag::{
+            aggregate::{TagAggregate, TagReconstructParams},
+            value_objects::{TagDescription, TagId, TagName},
+        },
+        task::{
+            aggregate::{TaskAggregate, TaskReconstructParams},
+            value_objects::{
+                Assignee, ChecklistItem, CustomField, DueDate, Estimate, ExternalRef, GitLink,
+                Priority, Recurrence, Status, StatusChange, TaskContext, TaskDescription, TaskId,
+                TaskTitle,
+            },
+        },
+        time_entry::{
+            aggregate::{TimeEntryAggregate, TimeEntryReconstructParams},
+            value_objects::TimeEntryId,
+        },
+    },
+    interface::persistence::in_memory::{
+        InMemoryTagRepository, InMemoryTaskRepository, InMemoryTimeEntryRepository,
+    },
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// InMemorySnapshot - メモリ上のリポジトリの内容をJSONで保存・復元するためのスナップショット
+///
+/// エフェメラルモードで、実行結果をファイルに書き出したり次回の実行に引き継いだりするために使用します。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InMemorySnapshot {
+    pub tasks: Vec<TaskRecord>,
+    pub tags: Vec<TagRecord>,
+    /// 作業記録（作業記録に対応する前のファイルには存在しない）
+    #[serde(default)]
+    pub time_entries: Vec<TimeEntryRecord>,
+    /// 次に割り当てるID（ID採番に対応する前のファイルには存在しない）
+    #[serde(default)]
+    pub next_ids: NextIds,
+}
+
+/// スナップショット内の次に割り当てるID
+///
+/// 削除したデータのIDを再利用しないよう、最大IDとは別に保存します（SQLiteのAUTOINCREMENTに相当）。
+/// 0は未保存を表し、復元時は最大ID + 1を使用します。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NextIds {
+    pub task: i32,
+    pub tag: i32,
+    pub time_entry: i32,
+}
+
+/// スナップショット内のタスク
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRecord {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+    pub status: Status,
+    pub priority: Priority,
+    pub tags: Vec<i32>,
+    pub due_date: Option<NaiveDate>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// カスタムフィールド（カスタムフィールドに対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom_fields: BTreeMap<String, String>,
+    /// チェックリスト（チェックリストに対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checklist: Vec<ChecklistItemRecord>,
+    /// gitの紐づけ（gitの紐づけに対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub git_links: Vec<GitLinkRecord>,
+    /// 外部の項目との対応（外部サービスとの同期に対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_refs: Vec<ExternalRefRecord>,
+    /// 繰り返しルール（繰り返しに対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<String>,
+    /// スヌーズの期限（スヌーズに対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_until: Option<NaiveDate>,
+    /// 見積もり時間（分、見積もりに対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate_minutes: Option<u32>,
+    /// コンテキスト（コンテキストに対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// 担当者（担当者に対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// ステータスの変更履歴（フロー統計に対応する前のファイルには存在しない）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub status_history: Vec<StatusChangeRecord>,
+}
+
+/// スナップショット内のチェックリスト項目
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItemRecord {
+    pub text: String,
+    pub done: bool,
+}
+
+/// スナップショット内のgitの紐づけ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitLinkRecord {
+    pub repository: String,
+    pub branch: Option<String>,
+    pub commit: String,
+    pub linked_at: DateTime<Utc>,
+}
+
+/// スナップショット内の外部の項目との対応
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalRefRecord {
+    pub source: String,
+    pub external_id: String,
+    pub url: Option<String>,
+    pub remote_updated_at: DateTime<Utc>,
+    pub synced_status: Status,
+}
+
+/// スナップショット内のステータスの変更
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusChangeRecord {
+    pub from: Status,
+    pub to: Status,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// スナップショット内のタグ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagRecord {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    /// 親タグのID（タグの階層に対応する前のファイルには存在しない）
+    #[serde(default)]
+    pub parent_id: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// スナップショット内の作業記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeEntryRecord {
+    pub id: i32,
+    pub task_id: i32,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+}
+
+impl TaskRecord {
+    /// TaskAggregateから変換
+    pub fn from_domain(task: &TaskAggregate) -> Self {
+        Self {
+            id: task.id().value(),
+            title: task.title().value().to_string(),
+            description: task.description().value().to_string(),
+            status: *task.status(),
+            priority: *task.priority(),
+            tags: task.tags().iter().map(|tag_id| tag_id.value()).collect(),
+            due_date: task.due_date().as_ref().map(|due_date| due_date.value()),
+            completed_at: *task.completed_at(),
+            created_at: *task.created_at(),
+            updated_at: *task.updated_at(),
+            custom_fields: task
+                .custom_fields()
+                .iter()
+                .map(|field| (field.key().to_string(), field.value().to_string()))
+                .collect(),
+            checklist: task
+                .checklist()
+                .iter()
+                .map(|item| ChecklistItemRecord {
+                    text: item.text().to_string(),
+                    done: item.is_done(),
+                })
+                .collect(),
+            git_links: task
+                .git_links()
+                .iter()
+                .map(|link| GitLinkRecord {
+                    repository: link.repository().to_string(),
+                    branch: link.branch().map(str::to_string),
+                    commit: link.commit().to_string(),
+                    linked_at: *link.linked_at(),
+                })
+                .collect(),
+            external_refs: task
+                .external_refs()
+                .iter()
+                .map(|external_ref| ExternalRefRecord {
+                    source: external_ref.source().to_string(),
+                    external_id: external_ref.external_id().to_string(),
+                    url: external_ref.url().map(str::to_string),
+                    remote_updated_at: *external_ref.remote_updated_at(),
+                    synced_status: external_ref.synced_status(),
+                })
+                .collect(),
+            recurrence: task.recurrence().map(ToString::to_string),
+            snoozed_until: task.snoozed_until(),
+            estimate_minutes: task.estimate().map(|estimate| estimate.minutes()),
+            context: task.context().map(ToString::to_string),
+            assignee: task.assignee().map(ToString::to_string),
+            status_history: task
+                .status_history()
+                .iter()
+                .map(|change| StatusChangeRecord {
+                    from: change.from(),
+                    to: change.to(),
+                    changed_at: *change.changed_at(),
+                })
+                .collect(),
+        }
+    }
+
+    /// TaskAggregateに変換
+    pub fn into_domain(self) -> Result<TaskAggregate> {
+        let params = TaskReconstructParams {
+            id: TaskId::new(self.id)?,
+            title: TaskTitle::new(self.title)?,
+            description: TaskDescription::new(self.description)?,
+            status: self.status,
+            priority: self.priority,
+            tags: self
+                .tags
+                .into_iter()
+                .map(TagId::new)
+                .collect::<Result<Vec<_>>>()?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            due_date: self.due_date.map(DueDate::new).transpose()?,
+            completed_at: self.completed_at,
+            custom_fields: self
+                .custom_fields
+                .into_iter()
+                .map(|(key, value)| CustomField::new(key, value))
+                .collect::<Result<Vec<_>>>()?,
+            checklist: self
+                .checklist
+                .into_iter()
+                .map(|item| ChecklistItem::new(item.text, item.done))
+                .collect::<Result<Vec<_>>>()?,
+            git_links: self
+                .git_links
+                .into_iter()
+                .map(|link| GitLink::new(link.repository, link.branch, link.commit, link.linked_at))
+                .collect::<Result<Vec<_>>>()?,
+            external_refs: self
+                .external_refs
+                .into_iter()
+                .map(|external_ref| {
+                    ExternalRef::new(
+                        external_ref.source,
+                        external_ref.external_id,
+                        external_ref.url,
+                        external_ref.remote_updated_at,
+                        external_ref.synced_status,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?,
+            recurrence: self
+                .recurrence
+                .as_deref()
+                .map(str::parse::<Recurrence>)
+                .transpose()?,
+            snoozed_until: self.snoozed_until,
+            estimate: self
+                .estimate_minutes
+                .map(Estimate::from_minutes)
+                .transpose()?,
+            context: self.context.map(TaskContext::new).transpose()?,
+            assignee: self.assignee.map(Assignee::new).transpose()?,
+            status_history: self
+                .status_history
+                .into_iter()
+                .map(|change| StatusChange::new(change.from, change.to, change.changed_at))
+                .collect(),
+        };
+
+        Ok(TaskAggregate::reconstruct(params))
+    }
+}
+
+impl TagRecord {
+    fn from_domain(tag: &TagAggregate) -> Self {
+        Self {
+            id: tag.id().value(),
+            name: tag.name().value().to_string(),
+            description: tag.description().value().to_string(),
+            parent_id: tag.parent_id().map(|id| id.value()),
+            created_at: *tag.created_at(),
+            updated_at: *tag.updated_at(),
+        }
+    }
+
+    fn into_domain(self) -> Result<TagAggregate> {
+        let params = TagReconstructParams {
+            id: TagId::new(self.id)?,
+            name: TagName::new(self.name)?,
+            description: TagDescription::new(self.description)?,
+            parent_id: self.parent_id.map(TagId::new).transpose()?,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        };
+
+        Ok(TagAggregate::reconstruct(params))
+    }
+}
+
+impl TimeEntryRecord {
+    fn from_domain(entry: &TimeEntryAggregate) -> Self {
+        Self {
+            id: entry.id().value(),
+            task_id: entry.task_id().value(),
+            started_at: *entry.started_at(),
+            ended_at: *entry.ended_at(),
+        }
+    }
+
+    fn into_domain(self) -> Result<TimeEntryAggregate> {
+        let params = TimeEntryReconstructParams {
+            id: TimeEntryId::new(self.id)?,
+            task_id: TaskId::new(self.task_id)?,
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+        };
+
+        Ok(TimeEntryAggregate::reconstruct(params))
+    }
+}
+
+impl InMemorySnapshot {
+    /// リポジトリの現在の内容からスナップショットを作成
+    pub fn capture(
+        task_repository: &InMemoryTaskRepository,
+        tag_repository: &InMemoryTagRepository,
+        time_entry_repository: &InMemoryTimeEntryRepository,
+    ) -> Self {
+        let (tasks, next_task_id) = task_repository.snapshot();
+        let (tags, next_tag_id) = tag_repository.snapshot();
+        let (time_entries, next_time_entry_id) = time_entry_repository.snapshot();
+
+        Self {
+            tasks: tasks.iter().map(TaskRecord::from_domain).collect(),
+            tags: tags.iter().map(TagRecord::from_domain).collect(),
+            time_entries: time_entries
+                .iter()
+                .map(TimeEntryRecord::from_domain)
+                .collect(),
+            next_ids: NextIds {
+                task: next_task_id,
+                tag: next_tag_id,
+                time_entry: next_time_entry_id,
+            },
+        }
+    }
+
+    /// スナップショットの内容をリポジトリに復元
+    ///
+    /// 次に割り当てるIDは、保存されていた次のIDと復元したデータの最大ID + 1の大きい方になります。
+    pub fn restore(
+        self,
+        task_repository: &InMemoryTaskRepository,
+        tag_repository: &InMemoryTagRepository,
+        time_entry_repository: &InMemoryTimeEntryRepository,
+    ) -> Result<()> {
+        let tasks = self
+            .tasks
+            .into_iter()
+            .map(TaskRecord::into_domain)
+            .collect::<Result<Vec<_>>>()?;
+        let tags = self
+            .tags
+            .into_iter()
+            .map(TagRecord::into_domain)
+            .collect::<Result<Vec<_>>>()?;
+        let time_entries = self
+            .time_entries
+            .into_iter()
+            .map(TimeEntryRecord::into_domain)
+            .collect::<Result<Vec<_>>>()?;
+
+        let next_task_id = (tasks
+            .iter()
+            .map(|task| task.id().value())
+            .max()
+            .unwrap_or(0)
+            + 1)
+        .max(self.next_ids.task);
+        let next_tag_id =
+            (tags.iter().map(|tag| tag.id().value()).max().unwrap_or(0) + 1).max(self.next_ids.tag);
+        let next_time_entry_id = (time_entries
+            .iter()
+            .map(|entry| entry.id().value())
+            .max()
+            .unwrap_or(0)
+            + 1)
+        .max(self.next_ids.time_entry);
+
+        task_repository.restore((tasks, next_task_id));
+        tag_repository.restore((tags, next_tag_id));
+        time_entry_repository.restore((time_entries, next_time_entry_id));
+
+        Ok(())
+    }
+
+    /// JSONファイルからスナップショットを読み込む
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read snapshot file: {}", path.display()))?;
+        serde_json::from_str(&content).context("Failed to parse snapshot file")
+    }
+
+    /// スナップショットをJSONファイルに書き出す
+    ///
+    /// 書き込み途中で中断してもファイルが壊れないよう、一時ファイルに書き出してから置き換えます。
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        fs::write(&temp_path, content)
+            .and_then(|_| fs::rename(&temp_path, path))
+            .with_context(|| format!("Failed to write snapshot file: {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tag::aggregate::NewTag;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::time_entry::aggregate::NewTimeEntry;
+    use crate::domain::{
+        tag::repository::TagRepository, task::repository::TaskRepository,
+        time_entry::repository::TimeEntryRepository,
+    };
+
+    #[tokio::test]
+    async fn test_snapshot_round_trip_through_file() {
+        // Arrange
+        let task_repo = InMemoryTaskRepository::new();
+        let tag_repo = InMemoryTagRepository::new();
+        let tag = tag_repo
+            .save(NewTag::new(
+                TagName::new("仕事").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        let task = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("資料作成").unwrap())
+                    .description(TaskDescription::new("説明").unwrap())
+                    .status(Status::InProgress)
+                    .priority(Priority::High)
+                    .tags(vec![*tag.id()])
+                    .due_date(Some(
+                        DueDate::new(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()).unwrap(),
+                    ))
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let now = Utc::now();
+        time_entry_repo
+            .save(NewTimeEntry::new(*task.id(), now, now).unwrap())
+            .await
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.json");
+
+        // Act
+        let snapshot = InMemorySnapshot::capture(&task_repo, &tag_repo, &time_entry_repo);
+        snapshot.save(&path).unwrap();
+        let restored_task_repo = InMemoryTaskRepository::new();
+        let restored_tag_repo = InMemoryTagRepository::new();
+        let restored_time_entry_repo = InMemoryTimeEntryRepository::new();
+        InMemorySnapshot::load(&path)
+            .unwrap()
+            .restore(
+                &restored_task_repo,
+                &restored_tag_repo,
+                &restored_time_entry_repo,
+            )
+            .unwrap();
+
+        // Assert
+        let tasks = restored_task_repo.find_all().await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title().value(), "資料作成");
+        assert_eq!(tasks[0].status(), &Status::InProgress);
+        assert_eq!(tasks[0].tags(), &vec![*tag.id()]);
+        assert_eq!(
+            restored_time_entry_repo
+                .find_by_task(task.id())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            InMemorySnapshot::capture(
+                &restored_task_repo,
+                &restored_tag_repo,
+                &restored_time_entry_repo
+            ),
+            snapshot
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restore_continues_ids_after_max() {
+        // Arrange
+        let task_repo = InMemoryTaskRepository::new();
+        let tag_repo = InMemoryTagRepository::new();
+        let now = Utc::now();
+        let snapshot = InMemorySnapshot {
+            tasks: vec![],
+            tags: vec![TagRecord {
+                id: 7,
+                name: "既存".to_string(),
+                description: String::new(),
+                parent_id: None,
+                created_at: now,
+                updated_at: now,
+            }],
+            time_entries: vec![],
+            next_ids: NextIds::default(),
+        };
+
+        // Act
+        snapshot
+            .restore(&task_repo, &tag_repo, &InMemoryTimeEntryRepository::new())
+            .unwrap();
+        let saved = tag_repo
+            .save(NewTag::new(
+                TagName::new("新規").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(saved.id().value(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_restore_does_not_reuse_deleted_task_ids() {
+        // Arrange: 最大IDのタスクを削除してから保存する
+        let task_repo = InMemoryTaskRepository::new();
+        let tag_repo = InMemoryTagRepository::new();
+        let time_entry_repo = InMemoryTimeEntryRepository::new();
+        for title in ["1件目", "2件目"] {
+            task_repo
+                .save(TaskBuilder::new(TaskTitle::new(title).unwrap()).build())
+                .await
+                .unwrap();
+        }
+        task_repo.delete(&TaskId::new(2).unwrap()).await.unwrap();
+        let snapshot = InMemorySnapshot::capture(&task_repo, &tag_repo, &time_entry_repo);
+
+        // Act
+        let restored_task_repo = InMemoryTaskRepository::new();
+        snapshot
+            .restore(&restored_task_repo, &tag_repo, &time_entry_repo)
+            .unwrap();
+        let saved = restored_task_repo
+            .save(TaskBuilder::new(TaskTitle::new("3件目").unwrap()).build())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(saved.id().value(), 3);
+    }
+
+    #[test]
+    fn test_load_snapshot_without_time_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.json");
+        fs::write(&path, r#"{ "tasks": [], "tags": [] }"#).unwrap();
+
+        let snapshot = InMemorySnapshot::load(&path).unwrap();
+
+        assert!(snapshot.time_entries.is_empty());
+    }
+
+    #[test]
+    fn test_load_invalid_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.json");
+        fs::write(&path, "{ invalid").unwrap();
+
+        assert!(InMemorySnapshot::load(&path).is_err());
+    }
+}
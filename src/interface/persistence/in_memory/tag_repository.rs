@@ -1,23 +1,20 @@
-#[cfg(test)]
 use crate::domain::tag::{
-    aggregate::TagAggregate, repository::TagRepository, value_objects::TagId,
+    aggregate::{NewTag, TagAggregate},
+    repository::TagRepository,
+    value_objects::{TagId, TagName},
 };
-#[cfg(test)]
 use anyhow::{Result, bail};
-#[cfg(test)]
 use std::sync::{Arc, RwLock};
 
-/// InMemoryTagRepository - テスト用のタグリポジトリ実装
+/// InMemoryTagRepository - メモリ上のタグリポジトリ実装
 ///
-/// メモリ上にタグを保持します。本番環境では使用しないでください。
+/// メモリ上にタグを保持します。テストとエフェメラルモードで使用し、内容はプロセス終了時に失われます。
 #[derive(Clone)]
-#[cfg(test)]
 pub struct InMemoryTagRepository {
     tags: Arc<RwLock<Vec<TagAggregate>>>,
     next_id: Arc<RwLock<i32>>,
 }
 
-#[cfg(test)]
 impl InMemoryTagRepository {
     /// 新しいInMemoryTagRepositoryを作成
     pub fn new() -> Self {
@@ -27,6 +24,38 @@ impl InMemoryTagRepository {
         }
     }
 
+    /// 現在の状態（保持しているタグと次のID）を取得
+    pub fn snapshot(&self) -> (Vec<TagAggregate>, i32) {
+        (
+            self.tags.read().unwrap().clone(),
+            *self.next_id.read().unwrap(),
+        )
+    }
+
+    /// snapshot()で取得した状態に戻す
+    pub fn restore(&self, snapshot: (Vec<TagAggregate>, i32)) {
+        let (tags, next_id) = snapshot;
+        *self.tags.write().unwrap() = tags;
+        *self.next_id.write().unwrap() = next_id;
+    }
+
+    /// 同じ名前（大文字小文字を区別しない）の別のタグがあればエラーにする
+    ///
+    /// `id`には更新するタグのIDを指定します（新しいタグはNone）。
+    fn ensure_unique_name(tags: &[TagAggregate], name: &TagName, id: Option<&TagId>) -> Result<()> {
+        if let Some(existing) = tags
+            .iter()
+            .find(|t| Some(t.id()) != id && t.name().matches(name.value()))
+        {
+            bail!(
+                "Tag '{}' already exists (id {})",
+                existing.name().value(),
+                existing.id().value()
+            );
+        }
+        Ok(())
+    }
+
     /// 次のIDを生成
     fn generate_id(&self) -> Result<i32> {
         let mut next_id = self.next_id.write().unwrap();
@@ -36,7 +65,6 @@ impl InMemoryTagRepository {
     }
 }
 
-#[cfg(test)]
 impl Default for InMemoryTagRepository {
     fn default() -> Self {
         Self::new()
@@ -44,7 +72,6 @@ impl Default for InMemoryTagRepository {
 }
 
 #[async_trait::async_trait]
-#[cfg(test)]
 impl TagRepository for InMemoryTagRepository {
     async fn find_by_id(&self, id: &TagId) -> Result<Option<TagAggregate>> {
         let tags = self.tags.read().unwrap();
@@ -56,33 +83,23 @@ impl TagRepository for InMemoryTagRepository {
         Ok(tags.clone())
     }
 
-    async fn save(&self, tag: TagAggregate) -> Result<TagAggregate> {
-        let tag_to_save = if tag.id().value() == 0 {
-            let new_id = self.generate_id()?;
-            tag.with_id(TagId::new(new_id)?)
-        } else {
-            tag
-        };
+    async fn save(&self, tag: NewTag) -> Result<TagAggregate> {
+        Self::ensure_unique_name(&self.tags.read().unwrap(), tag.name(), None)?;
+        let saved = tag.into_aggregate(TagId::new(self.generate_id()?)?);
+        self.tags.write().unwrap().push(saved.clone());
 
-        let mut tags = self.tags.write().unwrap();
-
-        if let Some(index) = tags.iter().position(|t| t.id() == tag_to_save.id()) {
-            tags[index] = tag_to_save.clone();
-        } else {
-            tags.push(tag_to_save.clone());
-        }
-
-        Ok(tag_to_save)
+        Ok(saved)
     }
 
     async fn update(&self, tag: TagAggregate) -> Result<TagAggregate> {
         let mut tags = self.tags.write().unwrap();
+        Self::ensure_unique_name(&tags, tag.name(), Some(tag.id()))?;
 
         if let Some(index) = tags.iter().position(|t| t.id() == tag.id()) {
             tags[index] = tag.clone();
             Ok(tag)
         } else {
-            bail!("タグが見つかりません: {}", tag.id().value())
+            bail!("Tag ID {} does not exist", tag.id().value())
         }
     }
 
@@ -99,7 +116,7 @@ impl TagRepository for InMemoryTagRepository {
 
     async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>> {
         let tags = self.tags.read().unwrap();
-        Ok(tags.iter().find(|t| t.name().value() == name).cloned())
+        Ok(tags.iter().find(|t| t.name().matches(name)).cloned())
     }
 
     async fn find_by_ids(&self, ids: &[TagId]) -> Result<Vec<TagAggregate>> {
@@ -123,11 +140,11 @@ mod tests {
     async fn test_find_by_ids_multiple_tags_found() {
         // Arrange
         let repo = InMemoryTagRepository::new();
-        let tag1 = TagAggregate::new(
+        let tag1 = NewTag::new(
             TagName::new("タグ1").unwrap(),
             TagDescription::new("説明1").unwrap(),
         );
-        let tag2 = TagAggregate::new(
+        let tag2 = NewTag::new(
             TagName::new("タグ2").unwrap(),
             TagDescription::new("説明2").unwrap(),
         );
@@ -149,7 +166,7 @@ mod tests {
     async fn test_find_by_ids_some_ids_not_found() {
         // Arrange
         let repo = InMemoryTagRepository::new();
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("タグ").unwrap(),
             TagDescription::new("説明").unwrap(),
         );
@@ -183,7 +200,7 @@ mod tests {
     async fn test_find_by_ids_duplicate_ids() {
         // Arrange
         let repo = InMemoryTagRepository::new();
-        let tag = TagAggregate::new(
+        let tag = NewTag::new(
             TagName::new("タグ").unwrap(),
             TagDescription::new("説明").unwrap(),
         );
@@ -198,4 +215,32 @@ mod tests {
         // 重複は除外される
         assert_eq!(result.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_save_and_rename_reject_duplicate_name_ignoring_case() {
+        // Arrange
+        let repo = InMemoryTagRepository::new();
+        let tag = |name: &str| {
+            NewTag::new(
+                TagName::new(name).unwrap(),
+                TagDescription::new("").unwrap(),
+            )
+        };
+        let work = repo.save(tag("Work")).await.unwrap();
+        let mut home = repo.save(tag("Home")).await.unwrap();
+        home.change_name(TagName::new("WORK").unwrap()).unwrap();
+
+        // Act
+        let duplicate = repo.save(tag("work")).await;
+        let renamed = repo.update(home).await;
+
+        // Assert
+        let expected = format!("Tag 'Work' already exists (id {})", work.id().value());
+        assert_eq!(duplicate.unwrap_err().to_string(), expected);
+        assert_eq!(renamed.unwrap_err().to_string(), expected);
+        assert_eq!(
+            repo.find_by_name("wOrK").await.unwrap().unwrap().id(),
+            work.id()
+        );
+    }
 }
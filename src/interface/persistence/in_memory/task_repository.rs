@@ -1,24 +1,22 @@
-#[cfg(test)]
 use crate::domain::task::{
-    aggregate::TaskAggregate, repository::TaskRepository, specification::TaskSpecification,
+    aggregate::{NewTask, TaskAggregate},
+    repository::TaskRepository,
+    specification::TaskSpecification,
     value_objects::TaskId,
 };
-#[cfg(test)]
 use anyhow::{Result, bail};
-#[cfg(test)]
+use chrono::NaiveDate;
 use std::sync::{Arc, RwLock};
 
-/// InMemoryTaskRepository - テスト用のタスクリポジトリ実装
+/// InMemoryTaskRepository - メモリ上のタスクリポジトリ実装
 ///
-/// メモリ上にタスクを保持します。本番環境では使用しないでください。
+/// メモリ上にタスクを保持します。テストとエフェメラルモードで使用し、内容はプロセス終了時に失われます。
 #[derive(Clone)]
-#[cfg(test)]
 pub struct InMemoryTaskRepository {
     tasks: Arc<RwLock<Vec<TaskAggregate>>>,
     next_id: Arc<RwLock<i32>>,
 }
 
-#[cfg(test)]
 impl InMemoryTaskRepository {
     /// 新しいInMemoryTaskRepositoryを作成
     pub fn new() -> Self {
@@ -28,6 +26,21 @@ impl InMemoryTaskRepository {
         }
     }
 
+    /// 現在の状態（保持しているタスクと次のID）を取得
+    pub fn snapshot(&self) -> (Vec<TaskAggregate>, i32) {
+        (
+            self.tasks.read().unwrap().clone(),
+            *self.next_id.read().unwrap(),
+        )
+    }
+
+    /// snapshot()で取得した状態に戻す
+    pub fn restore(&self, snapshot: (Vec<TaskAggregate>, i32)) {
+        let (tasks, next_id) = snapshot;
+        *self.tasks.write().unwrap() = tasks;
+        *self.next_id.write().unwrap() = next_id;
+    }
+
     /// 次のIDを生成
     fn generate_id(&self) -> Result<i32> {
         let mut next_id = self.next_id.write().unwrap();
@@ -37,7 +50,6 @@ impl InMemoryTaskRepository {
     }
 }
 
-#[cfg(test)]
 impl Default for InMemoryTaskRepository {
     fn default() -> Self {
         Self::new()
@@ -45,7 +57,6 @@ impl Default for InMemoryTaskRepository {
 }
 
 #[async_trait::async_trait]
-#[cfg(test)]
 impl TaskRepository for InMemoryTaskRepository {
     async fn find_by_id(&self, id: &TaskId) -> Result<Option<TaskAggregate>> {
         let tasks = self.tasks.read().unwrap();
@@ -70,25 +81,31 @@ impl TaskRepository for InMemoryTaskRepository {
         Ok(filtered_tasks)
     }
 
-    async fn save(&self, task: TaskAggregate) -> Result<TaskAggregate> {
-        let task_to_save = if task.id().value() == 0 {
-            // IDが0の場合、新しいIDを割り当てる
-            let new_id = self.generate_id()?;
-            task.with_id(TaskId::new(new_id)?)
-        } else {
-            task
-        };
+    async fn find_by_due_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<TaskAggregate>> {
+        let tasks = self.tasks.read().unwrap();
+        let mut found: Vec<TaskAggregate> = tasks
+            .iter()
+            .filter(|task| {
+                task.due_date()
+                    .is_some_and(|due| (from..=to).contains(&due.value()))
+            })
+            .cloned()
+            .collect();
+        found.sort_by_key(|task| (task.due_date().map(|due| due.value()), task.id().value()));
 
-        let mut tasks = self.tasks.write().unwrap();
+        Ok(found)
+    }
 
-        // 既存のタスクがあれば更新、なければ追加
-        if let Some(index) = tasks.iter().position(|t| t.id() == task_to_save.id()) {
-            tasks[index] = task_to_save.clone();
-        } else {
-            tasks.push(task_to_save.clone());
-        }
+    async fn save(&self, task: NewTask) -> Result<TaskAggregate> {
+        // IDは単調増加で割り当て、削除されたタスクのIDは再利用しない
+        let saved = task.into_aggregate(TaskId::new(self.generate_id()?)?);
+        self.tasks.write().unwrap().push(saved.clone());
 
-        Ok(task_to_save)
+        Ok(saved)
     }
 
     async fn update(&self, task: TaskAggregate) -> Result<TaskAggregate> {
@@ -98,7 +115,7 @@ impl TaskRepository for InMemoryTaskRepository {
             tasks[index] = task.clone();
             Ok(task)
         } else {
-            bail!("タスクが見つかりません: {}", task.id().value())
+            bail!("Task ID {} does not exist", task.id().value())
         }
     }
 
@@ -117,7 +134,14 @@ impl TaskRepository for InMemoryTaskRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::task::value_objects::{Priority, Status, TaskDescription, TaskTitle};
+    use crate::domain::clock::SystemClock;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::value_objects::{
+        DueDate, Priority, Status, TaskDescription, TaskTitle,
+    };
+    use crate::interface::persistence::conformance::task_repository_conformance_tests;
+
+    task_repository_conformance_tests!(async { ((), InMemoryTaskRepository::new()) });
 
     #[tokio::test]
     async fn test_in_memory_repository_new() {
@@ -145,14 +169,10 @@ mod tests {
     async fn test_save_new_task() {
         let repo = InMemoryTaskRepository::new();
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("新しいタスク").unwrap(),
-            TaskDescription::new("説明").unwrap(),
-            Status::Pending,
-            Priority::High,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("新しいタスク").unwrap())
+            .description(TaskDescription::new("説明").unwrap())
+            .priority(Priority::High)
+            .build();
 
         let saved = repo.save(task).await.unwrap();
 
@@ -166,27 +186,49 @@ mod tests {
         assert_eq!(found.unwrap().id(), saved.id());
     }
 
+    #[tokio::test]
+    async fn test_find_by_due_date_range() {
+        // Arrange
+        let repo = InMemoryTaskRepository::new();
+        let date = |day| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+        for (title, due) in [
+            ("範囲外", Some(date(1))),
+            ("後", Some(date(20))),
+            ("期限なし", None),
+            ("先", Some(date(5))),
+            ("終端", Some(date(31))),
+        ] {
+            repo.save(
+                TaskBuilder::new(TaskTitle::new(title).unwrap())
+                    .due_date(due.map(|d| DueDate::new(d).unwrap()))
+                    .build(),
+            )
+            .await
+            .unwrap();
+        }
+
+        // Act
+        let found = repo
+            .find_by_due_date_range(date(2), date(31))
+            .await
+            .unwrap();
+
+        // Assert: 期限日の昇順で、終了日も含む
+        let titles: Vec<&str> = found.iter().map(|t| t.title().value()).collect();
+        assert_eq!(titles, vec!["先", "後", "終端"]);
+    }
+
     #[tokio::test]
     async fn test_save_multiple_tasks() {
         let repo = InMemoryTaskRepository::new();
 
-        let task1 = TaskAggregate::new(
-            TaskTitle::new("タスク1").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
-
-        let task2 = TaskAggregate::new(
-            TaskTitle::new("タスク2").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::InProgress,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task1 = TaskBuilder::new(TaskTitle::new("タスク1").unwrap())
+            .priority(Priority::Low)
+            .build();
+
+        let task2 = TaskBuilder::new(TaskTitle::new("タスク2").unwrap())
+            .status(Status::InProgress)
+            .build();
 
         let saved1 = repo.save(task1).await.unwrap();
         let saved2 = repo.save(task2).await.unwrap();
@@ -203,19 +245,17 @@ mod tests {
     async fn test_update_task() {
         let repo = InMemoryTaskRepository::new();
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("元のタイトル").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Low,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("元のタイトル").unwrap())
+            .priority(Priority::Low)
+            .build();
 
         let saved = repo.save(task).await.unwrap();
         let mut updated_task = saved.clone();
         updated_task
-            .change_title(TaskTitle::new("更新後のタイトル").unwrap())
+            .change_title(
+                TaskTitle::new("更新後のタイトル").unwrap(),
+                &SystemClock::default(),
+            )
             .unwrap();
 
         let result = repo.update(updated_task.clone()).await.unwrap();
@@ -231,14 +271,7 @@ mod tests {
     async fn test_delete_task() {
         let repo = InMemoryTaskRepository::new();
 
-        let task = TaskAggregate::new(
-            TaskTitle::new("削除するタスク").unwrap(),
-            TaskDescription::new("").unwrap(),
-            Status::Pending,
-            Priority::Medium,
-            vec![],
-            None,
-        );
+        let task = TaskBuilder::new(TaskTitle::new("削除するタスク").unwrap()).build();
 
         let saved = repo.save(task).await.unwrap();
         let deleted = repo.delete(saved.id()).await.unwrap();
@@ -0,0 +1,159 @@
+use crate::domain::{
+    task::value_objects::TaskId,
+    time_entry::{
+        aggregate::{NewTimeEntry, TimeEntryAggregate},
+        repository::TimeEntryRepository,
+        value_objects::TimeEntryId,
+    },
+};
+use anyhow::Result;
+use std::sync::{Arc, RwLock};
+
+/// InMemoryTimeEntryRepository - メモリ上の作業記録リポジトリ実装
+///
+/// メモリ上に作業記録を保持します。テストとエフェメラルモードで使用し、内容はプロセス終了時に失われます。
+#[derive(Clone)]
+pub struct InMemoryTimeEntryRepository {
+    entries: Arc<RwLock<Vec<TimeEntryAggregate>>>,
+    next_id: Arc<RwLock<i32>>,
+}
+
+impl InMemoryTimeEntryRepository {
+    /// 新しいInMemoryTimeEntryRepositoryを作成
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(Vec::new())),
+            next_id: Arc::new(RwLock::new(1)),
+        }
+    }
+
+    /// 現在の状態（保持している作業記録と次のID）を取得
+    pub fn snapshot(&self) -> (Vec<TimeEntryAggregate>, i32) {
+        (
+            self.entries.read().unwrap().clone(),
+            *self.next_id.read().unwrap(),
+        )
+    }
+
+    /// snapshot()で取得した状態に戻す
+    pub fn restore(&self, snapshot: (Vec<TimeEntryAggregate>, i32)) {
+        let (entries, next_id) = snapshot;
+        *self.entries.write().unwrap() = entries;
+        *self.next_id.write().unwrap() = next_id;
+    }
+
+    /// 指定したタスクの作業記録をすべて削除
+    pub fn remove_by_task(&self, task_id: &TaskId) {
+        self.entries
+            .write()
+            .unwrap()
+            .retain(|entry| entry.task_id() != task_id);
+    }
+
+    /// 次のIDを生成
+    fn generate_id(&self) -> i32 {
+        let mut next_id = self.next_id.write().unwrap();
+        let id = *next_id;
+        *next_id += 1;
+        id
+    }
+}
+
+impl Default for InMemoryTimeEntryRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl TimeEntryRepository for InMemoryTimeEntryRepository {
+    async fn save(&self, entry: NewTimeEntry) -> Result<TimeEntryAggregate> {
+        let entry = entry.into_aggregate(TimeEntryId::new(self.generate_id())?);
+        self.entries.write().unwrap().push(entry.clone());
+        Ok(entry)
+    }
+
+    async fn find_by_task(&self, task_id: &TaskId) -> Result<Vec<TimeEntryAggregate>> {
+        let mut entries: Vec<_> = self
+            .entries
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|entry| entry.task_id() == task_id)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|entry| *entry.started_at());
+        Ok(entries)
+    }
+
+    async fn find_all(&self) -> Result<Vec<TimeEntryAggregate>> {
+        let mut entries = self.entries.read().unwrap().clone();
+        entries.sort_by_key(|entry| *entry.started_at());
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeDelta, Utc};
+
+    fn entry(task_id: i32, started_minutes_ago: i64) -> NewTimeEntry {
+        let started_at = Utc::now() - TimeDelta::minutes(started_minutes_ago);
+        NewTimeEntry::new(
+            TaskId::new(task_id).unwrap(),
+            started_at,
+            started_at + TimeDelta::minutes(25),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_assigns_ids_and_find_by_task_orders_by_start() {
+        // Arrange
+        let repo = InMemoryTimeEntryRepository::new();
+
+        // Act
+        let first = repo.save(entry(1, 30)).await.unwrap();
+        let second = repo.save(entry(1, 60)).await.unwrap();
+        repo.save(entry(2, 10)).await.unwrap();
+        let entries = repo.find_by_task(&TaskId::new(1).unwrap()).await.unwrap();
+
+        // Assert
+        assert_eq!(first.id().value(), 1);
+        assert_eq!(second.id().value(), 2);
+        let ids: Vec<i32> = entries.iter().map(|e| e.id().value()).collect();
+        assert_eq!(ids, vec![2, 1]);
+        let all: Vec<i32> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.id().value())
+            .collect();
+        assert_eq!(all, vec![2, 1, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_remove_by_task() {
+        let repo = InMemoryTimeEntryRepository::new();
+        repo.save(entry(1, 30)).await.unwrap();
+        repo.save(entry(2, 30)).await.unwrap();
+
+        repo.remove_by_task(&TaskId::new(1).unwrap());
+
+        assert!(
+            repo.find_by_task(&TaskId::new(1).unwrap())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            repo.find_by_task(&TaskId::new(2).unwrap())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
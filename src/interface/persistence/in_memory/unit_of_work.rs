@@ -0,0 +1,147 @@
+use crate::{
+    domain::{
+        tag::{aggregate::TagAggregate, repository::TagRepository},
+        task::{aggregate::TaskAggregate, repository::TaskRepository},
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+    },
+    interface::persistence::in_memory::{InMemoryTagRepository, InMemoryTaskRepository},
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// InMemoryUnitOfWorkFactory - メモリ上のリポジトリ向けUnitOfWorkFactory実装
+///
+/// 開始時点の状態を保存し、ロールバック時にその状態へ戻します。
+/// トランザクション間の分離は行わないため、単一プロセス内での利用に限ります。
+pub struct InMemoryUnitOfWorkFactory {
+    task_repository: Arc<InMemoryTaskRepository>,
+    tag_repository: Arc<InMemoryTagRepository>,
+}
+
+impl InMemoryUnitOfWorkFactory {
+    /// 新しいInMemoryUnitOfWorkFactoryを作成
+    pub fn new(
+        task_repository: Arc<InMemoryTaskRepository>,
+        tag_repository: Arc<InMemoryTagRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            tag_repository,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWorkFactory for InMemoryUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>> {
+        Ok(Box::new(InMemoryUnitOfWork {
+            snapshot: Some((
+                self.task_repository.snapshot(),
+                self.tag_repository.snapshot(),
+            )),
+            task_repository: self.task_repository.clone(),
+            tag_repository: self.tag_repository.clone(),
+        }))
+    }
+}
+
+/// InMemoryUnitOfWork - メモリ上のリポジトリ向けUnitOfWork実装
+///
+/// コミットせずに破棄した場合は、開始時点の状態に戻します。
+pub struct InMemoryUnitOfWork {
+    task_repository: Arc<InMemoryTaskRepository>,
+    tag_repository: Arc<InMemoryTagRepository>,
+    /// 開始時点の状態（コミット・ロールバック後はNone）
+    snapshot: Option<(TaskSnapshot, TagSnapshot)>,
+}
+
+type TaskSnapshot = (Vec<TaskAggregate>, i32);
+type TagSnapshot = (Vec<TagAggregate>, i32);
+
+impl InMemoryUnitOfWork {
+    /// 開始時点の状態に戻す（コミット・ロールバック済みの場合は何もしない）
+    fn restore(&mut self) {
+        if let Some((task_snapshot, tag_snapshot)) = self.snapshot.take() {
+            self.task_repository.restore(task_snapshot);
+            self.tag_repository.restore(tag_snapshot);
+        }
+    }
+}
+
+impl Drop for InMemoryUnitOfWork {
+    fn drop(&mut self) {
+        self.restore();
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    fn task_repository(&self) -> Arc<dyn TaskRepository> {
+        self.task_repository.clone()
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        self.tag_repository.clone()
+    }
+
+    async fn commit(mut self: Box<Self>) -> Result<()> {
+        self.snapshot = None;
+        Ok(())
+    }
+
+    async fn rollback(mut self: Box<Self>) -> Result<()> {
+        self.restore();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tag::aggregate::NewTag;
+    use crate::domain::tag::value_objects::{TagDescription, TagName};
+
+    fn new_tag(name: &str) -> NewTag {
+        NewTag::new(
+            TagName::new(name).unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+    }
+
+    fn factory() -> (Arc<InMemoryTagRepository>, InMemoryUnitOfWorkFactory) {
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let factory = InMemoryUnitOfWorkFactory::new(
+            Arc::new(InMemoryTaskRepository::new()),
+            tag_repo.clone(),
+        );
+        (tag_repo, factory)
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_commit_keeps_changes() {
+        // Arrange
+        let (tag_repo, factory) = factory();
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        uow.tag_repository().save(new_tag("仕事")).await.unwrap();
+        uow.commit().await.unwrap();
+
+        // Assert
+        assert_eq!(tag_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_rolls_back_when_dropped_without_commit() {
+        // Arrange
+        let (tag_repo, factory) = factory();
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        uow.tag_repository().save(new_tag("仕事")).await.unwrap();
+        drop(uow);
+
+        // Assert
+        assert!(tag_repo.find_all().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,11 @@
+pub mod store;
+pub mod tag_repository;
+pub mod task_repository;
+pub mod time_entry_repository;
+pub mod unit_of_work;
+
+pub use store::JsonFileStore;
+pub use tag_repository::JsonTagRepository;
+pub use task_repository::JsonTaskRepository;
+pub use time_entry_repository::JsonTimeEntryRepository;
+pub use unit_of_work::JsonUnitOfWorkFactory;
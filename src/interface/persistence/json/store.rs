@@ -0,0 +1,93 @@
+use crate::{
+    domain::{tag::value_objects::TagId, task::value_objects::TaskId},
+    interface::persistence::in_memory::{
+        InMemorySnapshot, InMemoryTagRepository, InMemoryTaskRepository,
+        InMemoryTimeEntryRepository, snapshot::TaskRecord,
+    },
+};
+use anyhow::Result;
+use std::{path::PathBuf, sync::Arc};
+
+/// JsonFileStore - タスク・タグ・作業記録を1つのJSONファイルに保存するストア
+///
+/// 起動時にファイル全体をメモリに読み込み、変更のたびにファイル全体を書き出します。
+/// ファイル形式はエフェメラルモードのスナップショットと同じです。
+pub struct JsonFileStore {
+    path: PathBuf,
+    tasks: Arc<InMemoryTaskRepository>,
+    tags: Arc<InMemoryTagRepository>,
+    time_entries: Arc<InMemoryTimeEntryRepository>,
+}
+
+impl JsonFileStore {
+    /// JSONファイルを開く
+    ///
+    /// ファイルが存在しない場合は空のストアとして開き、最初の変更時に作成します。
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let tasks = Arc::new(InMemoryTaskRepository::new());
+        let tags = Arc::new(InMemoryTagRepository::new());
+        let time_entries = Arc::new(InMemoryTimeEntryRepository::new());
+
+        if path.exists() {
+            InMemorySnapshot::load(&path)?.restore(&tasks, &tags, &time_entries)?;
+        }
+
+        Ok(Self {
+            path,
+            tasks,
+            tags,
+            time_entries,
+        })
+    }
+
+    /// メモリ上のタスクリポジトリを取得
+    pub fn tasks(&self) -> &Arc<InMemoryTaskRepository> {
+        &self.tasks
+    }
+
+    /// メモリ上のタグリポジトリを取得
+    pub fn tags(&self) -> &Arc<InMemoryTagRepository> {
+        &self.tags
+    }
+
+    /// メモリ上の作業記録リポジトリを取得
+    pub fn time_entries(&self) -> &Arc<InMemoryTimeEntryRepository> {
+        &self.time_entries
+    }
+
+    /// 現在の内容をJSONファイルに書き出す
+    pub fn flush(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        InMemorySnapshot::capture(&self.tasks, &self.tags, &self.time_entries).save(&self.path)
+    }
+
+    /// 削除されたタグへの参照をすべてのタスクから取り除く
+    ///
+    /// SQLiteの外部キー（ON DELETE CASCADE）と同様に、タスクの更新日時は変更しません。
+    pub fn remove_tag_references(&self, tag_id: &TagId) -> Result<()> {
+        let (tasks, next_id) = self.tasks.snapshot();
+        let tasks = tasks
+            .iter()
+            .map(|task| {
+                let mut record = TaskRecord::from_domain(task);
+                record.tags.retain(|id| *id != tag_id.value());
+                record.into_domain()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.tasks.restore((tasks, next_id));
+
+        Ok(())
+    }
+
+    /// 削除されたタスクの作業記録を取り除く
+    ///
+    /// SQLiteの外部キー（ON DELETE CASCADE）と同様の動作です。
+    pub fn remove_task_references(&self, task_id: &TaskId) {
+        self.time_entries.remove_by_task(task_id);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    domain::tag::{
+        aggregate::{NewTag, TagAggregate},
+        repository::TagRepository,
+        value_objects::TagId,
+    },
+    interface::persistence::json::JsonFileStore,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// JSONファイル実装のTagRepository
+///
+/// 読み込みはメモリ上のデータから行い、変更のたびにファイルへ書き出します。
+pub struct JsonTagRepository {
+    store: Arc<JsonFileStore>,
+}
+
+impl JsonTagRepository {
+    /// 新しいJsonTagRepositoryを作成
+    pub fn new(store: Arc<JsonFileStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl TagRepository for JsonTagRepository {
+    async fn find_by_id(&self, id: &TagId) -> Result<Option<TagAggregate>> {
+        self.store.tags().find_by_id(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TagAggregate>> {
+        self.store.tags().find_all().await
+    }
+
+    async fn save(&self, tag: NewTag) -> Result<TagAggregate> {
+        let saved = self.store.tags().save(tag).await?;
+        self.store.flush()?;
+        Ok(saved)
+    }
+
+    async fn update(&self, tag: TagAggregate) -> Result<TagAggregate> {
+        let updated = self.store.tags().update(tag).await?;
+        self.store.flush()?;
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: &TagId) -> Result<bool> {
+        let deleted = self.store.tags().delete(id).await?;
+        if deleted {
+            self.store.remove_tag_references(id)?;
+            self.store.flush()?;
+        }
+        Ok(deleted)
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>> {
+        self.store.tags().find_by_name(name).await
+    }
+
+    async fn find_by_ids(&self, ids: &[TagId]) -> Result<Vec<TagAggregate>> {
+        self.store.tags().find_by_ids(ids).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            tag::value_objects::{TagDescription, TagName},
+            task::{builder::TaskBuilder, repository::TaskRepository, value_objects::TaskTitle},
+        },
+        interface::persistence::json::JsonTaskRepository,
+    };
+
+    #[tokio::test]
+    async fn test_json_tag_delete_removes_task_references() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.json");
+        let store = Arc::new(JsonFileStore::open(&path).unwrap());
+        let tag_repo = JsonTagRepository::new(store.clone());
+        let task_repo = JsonTaskRepository::new(store);
+        let tag = tag_repo
+            .save(NewTag::new(
+                TagName::new("仕事").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let task = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("タスク").unwrap())
+                    .tags(vec![*tag.id()])
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        // Act
+        let deleted = tag_repo.delete(tag.id()).await.unwrap();
+
+        // Assert
+        assert!(deleted);
+        let reopened = JsonTaskRepository::new(Arc::new(JsonFileStore::open(&path).unwrap()));
+        let found = reopened.find_by_id(task.id()).await.unwrap().unwrap();
+        assert!(found.tags().is_empty());
+        assert_eq!(found.updated_at(), task.updated_at());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    domain::task::{
+        aggregate::{NewTask, TaskAggregate},
+        repository::TaskRepository,
+        specification::TaskSpecification,
+        value_objects::TaskId,
+    },
+    interface::persistence::json::JsonFileStore,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+/// JSONファイル実装のTaskRepository
+///
+/// 読み込みはメモリ上のデータから行い、変更のたびにファイルへ書き出します。
+pub struct JsonTaskRepository {
+    store: Arc<JsonFileStore>,
+}
+
+impl JsonTaskRepository {
+    /// 新しいJsonTaskRepositoryを作成
+    pub fn new(store: Arc<JsonFileStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl TaskRepository for JsonTaskRepository {
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<TaskAggregate>> {
+        self.store.tasks().find_by_id(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TaskAggregate>> {
+        self.store.tasks().find_all().await
+    }
+
+    async fn find_by_specification(
+        &self,
+        spec: Box<dyn TaskSpecification>,
+    ) -> Result<Vec<TaskAggregate>> {
+        self.store.tasks().find_by_specification(spec).await
+    }
+
+    async fn find_by_due_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<TaskAggregate>> {
+        self.store.tasks().find_by_due_date_range(from, to).await
+    }
+
+    async fn save(&self, task: NewTask) -> Result<TaskAggregate> {
+        let saved = self.store.tasks().save(task).await?;
+        self.store.flush()?;
+        Ok(saved)
+    }
+
+    async fn save_all(&self, tasks: Vec<NewTask>) -> Result<Vec<TaskAggregate>> {
+        // ファイルへの書き出しは最後に1回だけ行う
+        let saved = self.store.tasks().save_all(tasks).await?;
+        self.store.flush()?;
+        Ok(saved)
+    }
+
+    async fn update(&self, task: TaskAggregate) -> Result<TaskAggregate> {
+        let updated = self.store.tasks().update(task).await?;
+        self.store.flush()?;
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: &TaskId) -> Result<bool> {
+        let deleted = self.store.tasks().delete(id).await?;
+        if deleted {
+            self.store.remove_task_references(id);
+            self.store.flush()?;
+        }
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::builder::TaskBuilder;
+    use crate::domain::task::value_objects::TaskTitle;
+    use crate::interface::persistence::conformance::task_repository_conformance_tests;
+
+    task_repository_conformance_tests!(async {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JsonFileStore::open(dir.path().join("yaru.json")).unwrap();
+        (dir, JsonTaskRepository::new(Arc::new(store)))
+    });
+
+    fn new_task(title: &str) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap()).build()
+    }
+
+    #[tokio::test]
+    async fn test_json_task_repository_persists_across_reopen() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("yaru.json");
+        let repo = JsonTaskRepository::new(Arc::new(JsonFileStore::open(&path).unwrap()));
+        let first = repo.save(new_task("タスク1")).await.unwrap();
+        repo.save(new_task("タスク2")).await.unwrap();
+        repo.delete(first.id()).await.unwrap();
+
+        // Act
+        let reopened = JsonTaskRepository::new(Arc::new(JsonFileStore::open(&path).unwrap()));
+        let tasks = reopened.find_all().await.unwrap();
+        let saved = reopened.save(new_task("タスク3")).await.unwrap();
+
+        // Assert
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title().value(), "タスク2");
+        assert_eq!(saved.id().value(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_json_task_repository_save_all() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.json");
+        let repo = JsonTaskRepository::new(Arc::new(JsonFileStore::open(&path).unwrap()));
+
+        // Act
+        let saved = repo
+            .save_all(vec![new_task("タスク1"), new_task("タスク2")])
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(saved.len(), 2);
+        let reopened = JsonTaskRepository::new(Arc::new(JsonFileStore::open(&path).unwrap()));
+        assert_eq!(reopened.find_all().await.unwrap().len(), 2);
+    }
+}
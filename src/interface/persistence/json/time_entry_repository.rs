@@ -0,0 +1,87 @@
+use crate::{
+    domain::{
+        task::value_objects::TaskId,
+        time_entry::{
+            aggregate::{NewTimeEntry, TimeEntryAggregate},
+            repository::TimeEntryRepository,
+        },
+    },
+    interface::persistence::json::JsonFileStore,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// JSONファイル実装のTimeEntryRepository
+///
+/// 読み込みはメモリ上のデータから行い、変更のたびにファイルへ書き出します。
+pub struct JsonTimeEntryRepository {
+    store: Arc<JsonFileStore>,
+}
+
+impl JsonTimeEntryRepository {
+    /// 新しいJsonTimeEntryRepositoryを作成
+    pub fn new(store: Arc<JsonFileStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for JsonTimeEntryRepository {
+    async fn save(&self, entry: NewTimeEntry) -> Result<TimeEntryAggregate> {
+        let saved = self.store.time_entries().save(entry).await?;
+        self.store.flush()?;
+        Ok(saved)
+    }
+
+    async fn find_by_task(&self, task_id: &TaskId) -> Result<Vec<TimeEntryAggregate>> {
+        self.store.time_entries().find_by_task(task_id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TimeEntryAggregate>> {
+        self.store.time_entries().find_all().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::task::{
+            builder::TaskBuilder, repository::TaskRepository, value_objects::TaskTitle,
+        },
+        interface::persistence::json::JsonTaskRepository,
+    };
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_entries_persist_and_are_removed_with_task() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.json");
+        let store = Arc::new(JsonFileStore::open(&path).unwrap());
+        let task_repo = JsonTaskRepository::new(store.clone());
+        let task = task_repo
+            .save(TaskBuilder::new(TaskTitle::new("資料作成").unwrap()).build())
+            .await
+            .unwrap();
+        let repo = JsonTimeEntryRepository::new(store);
+        let now = Utc::now();
+
+        // Act
+        repo.save(NewTimeEntry::new(*task.id(), now, now).unwrap())
+            .await
+            .unwrap();
+        let reopened = Arc::new(JsonFileStore::open(&path).unwrap());
+        let persisted = reopened
+            .time_entries()
+            .find_by_task(task.id())
+            .await
+            .unwrap();
+        task_repo.delete(task.id()).await.unwrap();
+
+        // Assert
+        assert_eq!(persisted.len(), 1);
+        assert!(repo.find_by_task(task.id()).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    domain::{
+        tag::repository::TagRepository,
+        task::repository::TaskRepository,
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+    },
+    interface::persistence::{in_memory::InMemoryUnitOfWorkFactory, json::JsonFileStore},
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// JsonUnitOfWorkFactory - JSONファイルストア向けのUnitOfWorkFactory実装
+///
+/// トランザクション中の変更はメモリ上でのみ行い、コミット時にまとめてファイルへ書き出します。
+pub struct JsonUnitOfWorkFactory {
+    store: Arc<JsonFileStore>,
+    in_memory: InMemoryUnitOfWorkFactory,
+}
+
+impl JsonUnitOfWorkFactory {
+    /// 新しいJsonUnitOfWorkFactoryを作成
+    pub fn new(store: Arc<JsonFileStore>) -> Self {
+        let in_memory = InMemoryUnitOfWorkFactory::new(store.tasks().clone(), store.tags().clone());
+        Self { store, in_memory }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWorkFactory for JsonUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>> {
+        Ok(Box::new(JsonUnitOfWork {
+            store: self.store.clone(),
+            inner: self.in_memory.begin().await?,
+        }))
+    }
+}
+
+/// JsonUnitOfWork - JSONファイルストア向けのUnitOfWork実装
+pub struct JsonUnitOfWork {
+    store: Arc<JsonFileStore>,
+    inner: Box<dyn UnitOfWork>,
+}
+
+#[async_trait::async_trait]
+impl UnitOfWork for JsonUnitOfWork {
+    fn task_repository(&self) -> Arc<dyn TaskRepository> {
+        self.inner.task_repository()
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        self.inner.tag_repository()
+    }
+
+    /// ファイルへの書き込みに失敗した場合は、メモリ上の変更も取り消す
+    async fn commit(self: Box<Self>) -> Result<()> {
+        self.store.flush()?;
+        self.inner.commit().await
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<()> {
+        self.inner.rollback().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        tag::{
+            aggregate::NewTag,
+            value_objects::{TagDescription, TagName},
+        },
+        unit_of_work::commit_or_rollback,
+    };
+
+    fn new_tag(name: &str) -> NewTag {
+        NewTag::new(
+            TagName::new(name).unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_json_unit_of_work_commit_writes_file() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.json");
+        let factory = JsonUnitOfWorkFactory::new(Arc::new(JsonFileStore::open(&path).unwrap()));
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let result = uow.tag_repository().save(new_tag("仕事")).await;
+        commit_or_rollback(uow, result).await.unwrap();
+
+        // Assert
+        let reopened = JsonFileStore::open(&path).unwrap();
+        assert_eq!(reopened.tags().find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_json_unit_of_work_rollback_keeps_file() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.json");
+        let store = Arc::new(JsonFileStore::open(&path).unwrap());
+        let factory = JsonUnitOfWorkFactory::new(store.clone());
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let result: Result<()> = async {
+            uow.tag_repository().save(new_tag("仕事")).await?;
+            anyhow::bail!("failure")
+        }
+        .await;
+        let outcome = commit_or_rollback(uow, result).await;
+
+        // Assert
+        assert!(outcome.is_err());
+        assert!(store.tags().find_all().await.unwrap().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_json_unit_of_work_write_failure_discards_changes() {
+        // Arrange: 保存先にディレクトリを作り、ファイルを書き込めないようにする
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("yaru.json");
+        let store = Arc::new(JsonFileStore::open(&path).unwrap());
+        let factory = JsonUnitOfWorkFactory::new(store.clone());
+        std::fs::create_dir(&path).unwrap();
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let result = uow.tag_repository().save(new_tag("仕事")).await;
+        let outcome = commit_or_rollback(uow, result).await;
+
+        // Assert: メモリ上の変更もファイルと同じく取り消される
+        assert!(outcome.is_err());
+        assert!(store.tags().find_all().await.unwrap().is_empty());
+    }
+}
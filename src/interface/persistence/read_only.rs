@@ -0,0 +1,278 @@
+//! 読み取り専用のリポジトリ
+//!
+//! 既存のリポジトリ・UnitOfWorkをラップし、読み込みだけを委譲して書き込みは[`ReadOnlyError`]で拒否します。
+//! 共有のデータベースや本番のデータベースを確認のために開く場合（`--read-only`）に使います。
+//! 書き込みはユースケースの途中でも元のリポジトリに届かないため、データベースが変更されることはありません。
+
+use crate::domain::{
+    tag::{
+        aggregate::{NewTag, TagAggregate},
+        repository::TagRepository,
+        value_objects::TagId,
+    },
+    task::{
+        aggregate::{NewTask, TaskAggregate},
+        repository::{TaskRepository, TaskStream},
+        specification::TaskSpecification,
+        value_objects::TaskId,
+    },
+    time_entry::{
+        aggregate::{NewTimeEntry, TimeEntryAggregate},
+        repository::TimeEntryRepository,
+    },
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::{fmt, sync::Arc};
+
+/// 読み取り専用モードで書き込もうとした場合のエラー
+///
+/// `anyhow::Error::is`や`downcast_ref`で、他のエラーと区別できます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadOnlyError;
+
+impl fmt::Display for ReadOnlyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(
+            "The database is open in read-only mode (--read-only or [storage] read_only); \
+             this command cannot modify it",
+        )
+    }
+}
+
+impl std::error::Error for ReadOnlyError {}
+
+/// 書き込みを拒否するエラーを返す
+fn deny<T>() -> Result<T> {
+    Err(ReadOnlyError.into())
+}
+
+/// 書き込みを拒否するTaskRepository
+pub struct ReadOnlyTaskRepository {
+    inner: Arc<dyn TaskRepository>,
+}
+
+impl ReadOnlyTaskRepository {
+    pub fn new(inner: Arc<dyn TaskRepository>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskRepository for ReadOnlyTaskRepository {
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<TaskAggregate>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TaskAggregate>> {
+        self.inner.find_all().await
+    }
+
+    fn find_all_stream(&self) -> TaskStream<'_> {
+        self.inner.find_all_stream()
+    }
+
+    async fn find_by_specification(
+        &self,
+        spec: Box<dyn TaskSpecification>,
+    ) -> Result<Vec<TaskAggregate>> {
+        self.inner.find_by_specification(spec).await
+    }
+
+    async fn count_by_specification(&self, spec: Box<dyn TaskSpecification>) -> Result<u64> {
+        self.inner.count_by_specification(spec).await
+    }
+
+    async fn find_by_due_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<TaskAggregate>> {
+        self.inner.find_by_due_date_range(from, to).await
+    }
+
+    async fn save(&self, _task: NewTask) -> Result<TaskAggregate> {
+        deny()
+    }
+
+    async fn save_all(&self, _tasks: Vec<NewTask>) -> Result<Vec<TaskAggregate>> {
+        deny()
+    }
+
+    async fn update(&self, _task: TaskAggregate) -> Result<TaskAggregate> {
+        deny()
+    }
+
+    async fn delete(&self, _id: &TaskId) -> Result<bool> {
+        deny()
+    }
+}
+
+/// 書き込みを拒否するTagRepository
+pub struct ReadOnlyTagRepository {
+    inner: Arc<dyn TagRepository>,
+}
+
+impl ReadOnlyTagRepository {
+    pub fn new(inner: Arc<dyn TagRepository>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl TagRepository for ReadOnlyTagRepository {
+    async fn find_by_id(&self, id: &TagId) -> Result<Option<TagAggregate>> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TagAggregate>> {
+        self.inner.find_all().await
+    }
+
+    async fn save(&self, _tag: NewTag) -> Result<TagAggregate> {
+        deny()
+    }
+
+    async fn update(&self, _tag: TagAggregate) -> Result<TagAggregate> {
+        deny()
+    }
+
+    async fn delete(&self, _id: &TagId) -> Result<bool> {
+        deny()
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>> {
+        self.inner.find_by_name(name).await
+    }
+
+    async fn find_by_ids(&self, ids: &[TagId]) -> Result<Vec<TagAggregate>> {
+        self.inner.find_by_ids(ids).await
+    }
+}
+
+/// 書き込みを拒否するTimeEntryRepository
+pub struct ReadOnlyTimeEntryRepository {
+    inner: Arc<dyn TimeEntryRepository>,
+}
+
+impl ReadOnlyTimeEntryRepository {
+    pub fn new(inner: Arc<dyn TimeEntryRepository>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl TimeEntryRepository for ReadOnlyTimeEntryRepository {
+    async fn save(&self, _entry: NewTimeEntry) -> Result<TimeEntryAggregate> {
+        deny()
+    }
+
+    async fn find_by_task(&self, task_id: &TaskId) -> Result<Vec<TimeEntryAggregate>> {
+        self.inner.find_by_task(task_id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<TimeEntryAggregate>> {
+        self.inner.find_all().await
+    }
+}
+
+/// 書き込みを拒否するリポジトリを返すUnitOfWorkFactory
+pub struct ReadOnlyUnitOfWorkFactory {
+    inner: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl ReadOnlyUnitOfWorkFactory {
+    pub fn new(inner: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWorkFactory for ReadOnlyUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>> {
+        Ok(Box::new(ReadOnlyUnitOfWork {
+            inner: self.inner.begin().await?,
+        }))
+    }
+}
+
+/// 書き込みを拒否するUnitOfWork
+struct ReadOnlyUnitOfWork {
+    inner: Box<dyn UnitOfWork>,
+}
+
+#[async_trait::async_trait]
+impl UnitOfWork for ReadOnlyUnitOfWork {
+    fn task_repository(&self) -> Arc<dyn TaskRepository> {
+        Arc::new(ReadOnlyTaskRepository::new(self.inner.task_repository()))
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        Arc::new(ReadOnlyTagRepository::new(self.inner.tag_repository()))
+    }
+
+    /// 書き込みは行われていないため、コミットの代わりにロールバックしてトランザクションを閉じる
+    async fn commit(self: Box<Self>) -> Result<()> {
+        self.inner.rollback().await
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<()> {
+        self.inner.rollback().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        task::{builder::TaskBuilder, value_objects::TaskTitle},
+        unit_of_work::commit_or_rollback,
+    };
+    use crate::interface::persistence::in_memory::{
+        InMemoryTagRepository, InMemoryTaskRepository, InMemoryUnitOfWorkFactory,
+    };
+
+    fn new_task(title: &str) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap()).build()
+    }
+
+    #[tokio::test]
+    async fn test_read_only_task_repository_reads_but_rejects_writes() {
+        // Arrange
+        let inner = Arc::new(InMemoryTaskRepository::new());
+        let saved = inner.save(new_task("既存のタスク")).await.unwrap();
+        let repository = ReadOnlyTaskRepository::new(inner.clone());
+
+        // Act
+        let found = repository.find_by_id(saved.id()).await.unwrap();
+        let save = repository.save(new_task("新しいタスク")).await;
+        let delete = repository.delete(saved.id()).await;
+
+        // Assert
+        assert_eq!(found.as_ref(), Some(&saved));
+        assert!(save.unwrap_err().is::<ReadOnlyError>());
+        assert!(delete.unwrap_err().is::<ReadOnlyError>());
+        assert_eq!(inner.find_all().await.unwrap(), vec![saved]);
+    }
+
+    #[tokio::test]
+    async fn test_read_only_unit_of_work_rejects_writes() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let factory = ReadOnlyUnitOfWorkFactory::new(Arc::new(InMemoryUnitOfWorkFactory::new(
+            task_repo.clone(),
+            tag_repo,
+        )));
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let result = uow.task_repository().save(new_task("タスク")).await;
+        let result = commit_or_rollback(uow, result).await;
+
+        // Assert
+        assert!(result.unwrap_err().is::<ReadOnlyError>());
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+    }
+}
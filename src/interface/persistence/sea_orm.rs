@@ -1,6 +1,18 @@
+pub mod database_status;
+pub mod encryption;
 pub mod mapper;
+pub mod sync_state;
 pub mod tag_repository;
 pub mod task_repository;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod time_entry_repository;
+pub mod unit_of_work;
 
+pub use database_status::{DatabaseStatus, SeaOrmDatabaseAdmin, sqlite_path_from_url};
+pub use encryption::SeaOrmEncryptionManager;
+pub use sync_state::SeaOrmSyncStateStore;
 pub use tag_repository::SeaOrmTagRepository;
 pub use task_repository::SeaOrmTaskRepository;
+pub use time_entry_repository::SeaOrmTimeEntryRepository;
+pub use unit_of_work::SeaOrmUnitOfWorkFactory;
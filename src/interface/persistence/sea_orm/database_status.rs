@@ -0,0 +1,417 @@
+use crate::interface::persistence::sea_orm::SeaOrmEncryptionManager;
+use anyhow::{Context, Result};
+use entity::prelude::{
+    ChecklistItems, CustomFields, ExternalRefs, Settings, Tags, TaskLinks, TaskStatusChanges,
+    TaskTags, Tasks, TimeEntries,
+};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait, PaginatorTrait, Statement,
+};
+use std::path::{Path, PathBuf};
+
+/// データベースの状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseStatus {
+    /// データベースファイルのパス（メモリ上のデータベースの場合はNone）
+    pub path: Option<PathBuf>,
+    /// データベースファイルのサイズ（バイト）
+    pub size_bytes: Option<u64>,
+    /// 適用済みのマイグレーション
+    pub applied_migrations: Vec<String>,
+    /// 未適用のマイグレーション
+    pub pending_migrations: Vec<String>,
+    /// テーブルごとの行数（テーブルが未作成の場合はNone）
+    pub table_counts: Vec<(&'static str, Option<u64>)>,
+    /// `PRAGMA integrity_check`の結果
+    pub integrity_check: Vec<String>,
+    /// 暗号化されているか（判定できない場合はNone）
+    pub encrypted: Option<bool>,
+}
+
+impl DatabaseStatus {
+    /// 整合性チェックが成功したか
+    pub fn is_healthy(&self) -> bool {
+        self.integrity_check == ["ok"]
+    }
+}
+
+/// SeaOrmDatabaseAdmin - マイグレーションの適用やデータベースの状態確認を行う
+pub struct SeaOrmDatabaseAdmin {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmDatabaseAdmin {
+    /// 新しいSeaOrmDatabaseAdminを作成
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 未適用のマイグレーション名を取得
+    pub async fn pending_migrations(&self) -> Result<Vec<String>> {
+        let migrations = Migrator::get_pending_migrations(&self.db)
+            .await
+            .context("Failed to read migration status")?;
+        Ok(migrations.iter().map(|m| m.name().to_string()).collect())
+    }
+
+    /// 未適用のマイグレーションをすべて適用
+    ///
+    /// # Returns
+    /// * `Ok(Vec<String>)` - 適用したマイグレーション名
+    pub async fn migrate(&self) -> Result<Vec<String>> {
+        let pending = self.pending_migrations().await?;
+        if !pending.is_empty() {
+            tracing::info!(count = pending.len(), "applying migrations");
+            Migrator::up(&self.db, None)
+                .await
+                .context("Failed to run migrations")?;
+        }
+        self.set_schema_version(expected_schema_version()).await?;
+        Ok(pending)
+    }
+
+    /// スキーマを最新の状態にする
+    ///
+    /// `PRAGMA user_version`に記録したスキーマバージョンが最新であれば、
+    /// マイグレーション履歴の確認を省略します。
+    ///
+    /// # Arguments
+    /// * `auto_migrate` - trueの場合は未適用のマイグレーションを適用し、falseの場合は未適用があればエラーにする
+    pub async fn ensure_up_to_date(&self, auto_migrate: bool) -> Result<()> {
+        if self.schema_version().await? == expected_schema_version() {
+            tracing::debug!("schema is up to date");
+            return Ok(());
+        }
+
+        if auto_migrate {
+            self.migrate().await?;
+            return Ok(());
+        }
+
+        let pending = self.pending_migrations().await?;
+        if !pending.is_empty() {
+            anyhow::bail!(
+                "Database has {} pending migration(s); run `yaru db migrate` to apply them",
+                pending.len()
+            );
+        }
+        self.set_schema_version(expected_schema_version()).await
+    }
+
+    /// スキーマが最新であることを確認する（読み取り専用モード用）
+    ///
+    /// `ensure_up_to_date`と異なり、マイグレーションの適用やスキーマバージョンの記録を含め一切書き込みません。
+    pub async fn check_up_to_date(&self) -> Result<()> {
+        if self.schema_version().await? == expected_schema_version() {
+            return Ok(());
+        }
+
+        let pending = self.pending_migrations().await?;
+        if !pending.is_empty() {
+            anyhow::bail!(
+                "Database has {} pending migration(s); run `yaru db migrate` without --read-only to apply them",
+                pending.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// データベースの内容を別のファイルに書き出す（`VACUUM INTO`）
+    ///
+    /// 書き込み中の変更（WALファイルの内容）を含めた一貫した状態で複製します。
+    /// 書き出し先のファイルが既にある場合はエラーになります。
+    pub async fn back_up_to(&self, path: &Path) -> Result<()> {
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                "VACUUM INTO ?",
+                [path.to_string_lossy().into_owned().into()],
+            ))
+            .await
+            .with_context(|| format!("Failed to back up the database to {}", path.display()))?;
+        Ok(())
+    }
+
+    /// `PRAGMA user_version`に記録されたスキーマバージョンを取得
+    async fn schema_version(&self) -> Result<i64> {
+        let row = self
+            .db
+            .query_one(Statement::from_string(
+                DbBackend::Sqlite,
+                "PRAGMA user_version",
+            ))
+            .await
+            .context("Failed to read schema version")?
+            .context("Failed to read schema version")?;
+        row.try_get_by_index::<i64>(0)
+            .context("Failed to read schema version")
+    }
+
+    /// `PRAGMA user_version`にスキーマバージョンを記録
+    async fn set_schema_version(&self, version: i64) -> Result<()> {
+        // PRAGMAはパラメータをバインドできないため、整数値を直接埋め込む
+        self.db
+            .execute(Statement::from_string(
+                DbBackend::Sqlite,
+                format!("PRAGMA user_version = {}", version),
+            ))
+            .await
+            .context("Failed to write schema version")?;
+        Ok(())
+    }
+
+    /// データベースの状態を収集
+    ///
+    /// # Arguments
+    /// * `database_url` - 接続に使用したデータベースURL（ファイルパスの特定に使用）
+    pub async fn status(&self, database_url: &str) -> Result<DatabaseStatus> {
+        let path = sqlite_path_from_url(database_url);
+        let size_bytes = path
+            .as_ref()
+            .and_then(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len());
+
+        let applied_migrations = Migrator::get_applied_migrations(&self.db)
+            .await
+            .context("Failed to read migration status")?
+            .iter()
+            .map(|m| m.name().to_string())
+            .collect();
+        let pending_migrations = self.pending_migrations().await?;
+
+        // マイグレーション前はテーブルが存在しないため、件数を取得できなくてもエラーにしない
+        let table_counts = vec![
+            ("tasks", Tasks::find().count(&self.db).await.ok()),
+            ("tags", Tags::find().count(&self.db).await.ok()),
+            ("task_tags", TaskTags::find().count(&self.db).await.ok()),
+            (
+                "time_entries",
+                TimeEntries::find().count(&self.db).await.ok(),
+            ),
+            (
+                "custom_fields",
+                CustomFields::find().count(&self.db).await.ok(),
+            ),
+            (
+                "checklist_items",
+                ChecklistItems::find().count(&self.db).await.ok(),
+            ),
+            ("task_links", TaskLinks::find().count(&self.db).await.ok()),
+            (
+                "external_refs",
+                ExternalRefs::find().count(&self.db).await.ok(),
+            ),
+            (
+                "task_status_changes",
+                TaskStatusChanges::find().count(&self.db).await.ok(),
+            ),
+            ("settings", Settings::find().count(&self.db).await.ok()),
+        ];
+
+        let integrity_check = self.integrity_check().await?;
+        let encrypted = SeaOrmEncryptionManager::new(self.db.clone())
+            .is_encrypted()
+            .await
+            .ok();
+
+        Ok(DatabaseStatus {
+            path,
+            size_bytes,
+            applied_migrations,
+            pending_migrations,
+            table_counts,
+            integrity_check,
+            encrypted,
+        })
+    }
+
+    /// `PRAGMA integrity_check`を実行
+    async fn integrity_check(&self) -> Result<Vec<String>> {
+        let rows = self
+            .db
+            .query_all(Statement::from_string(
+                DbBackend::Sqlite,
+                "PRAGMA integrity_check",
+            ))
+            .await
+            .context("Failed to run integrity check")?;
+
+        rows.iter()
+            .map(|row| {
+                row.try_get_by_index::<String>(0)
+                    .context("Failed to read integrity check result")
+            })
+            .collect()
+    }
+}
+
+/// 最新のスキーマバージョン（定義済みのマイグレーション数）
+fn expected_schema_version() -> i64 {
+    Migrator::migrations().len() as i64
+}
+
+/// SQLiteのデータベースURLからファイルパスを取り出す
+///
+/// メモリ上のデータベースの場合はNoneを返します。
+pub fn sqlite_path_from_url(database_url: &str) -> Option<PathBuf> {
+    let path = database_url
+        .strip_prefix("sqlite://")
+        .or_else(|| database_url.strip_prefix("sqlite:"))?;
+    let path = path.split('?').next().unwrap_or_default();
+
+    if path.is_empty() || path == ":memory:" {
+        None
+    } else {
+        Some(PathBuf::from(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::persistence::sea_orm::test_support::setup_empty_db;
+    use sea_orm::Database;
+
+    #[test]
+    fn test_sqlite_path_from_url() {
+        assert_eq!(
+            sqlite_path_from_url("sqlite:///home/user/yaru.db?mode=rwc"),
+            Some(PathBuf::from("/home/user/yaru.db"))
+        );
+        assert_eq!(
+            sqlite_path_from_url("sqlite://yaru.db"),
+            Some(PathBuf::from("yaru.db"))
+        );
+        assert_eq!(sqlite_path_from_url("sqlite::memory:"), None);
+        assert_eq!(sqlite_path_from_url("postgres://localhost/yaru"), None);
+    }
+
+    #[tokio::test]
+    async fn test_back_up_to() {
+        // Arrange
+        let (dir, _url, db) = setup_empty_db().await;
+        let admin = SeaOrmDatabaseAdmin::new(db);
+        admin.migrate().await.unwrap();
+        let backup = dir.path().join("backup.db");
+
+        // Act
+        admin.back_up_to(&backup).await.unwrap();
+
+        // Assert: 複製したファイルはマイグレーション済みのデータベースとして開ける
+        let copy = Database::connect(format!("sqlite://{}", backup.display()))
+            .await
+            .unwrap();
+        let copy_admin = SeaOrmDatabaseAdmin::new(copy);
+        assert!(copy_admin.pending_migrations().await.unwrap().is_empty());
+        assert!(admin.back_up_to(&backup).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_status_before_migration() {
+        // Arrange
+        let (_dir, url, db) = setup_empty_db().await;
+        let admin = SeaOrmDatabaseAdmin::new(db);
+
+        // Act
+        let status = admin.status(&url).await.unwrap();
+
+        // Assert
+        assert!(status.applied_migrations.is_empty());
+        assert!(!status.pending_migrations.is_empty());
+        assert!(status.table_counts.iter().all(|(_, count)| count.is_none()));
+        assert!(status.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_applies_pending_migrations() {
+        // Arrange
+        let (_dir, url, db) = setup_empty_db().await;
+        let admin = SeaOrmDatabaseAdmin::new(db);
+        let pending = admin.pending_migrations().await.unwrap();
+
+        // Act
+        let applied = admin.migrate().await.unwrap();
+        let status = admin.status(&url).await.unwrap();
+
+        // Assert
+        assert_eq!(applied, pending);
+        assert_eq!(status.applied_migrations, pending);
+        assert!(status.pending_migrations.is_empty());
+        assert!(
+            status
+                .table_counts
+                .iter()
+                .all(|(_, count)| *count == Some(0))
+        );
+        assert_eq!(status.encrypted, Some(false));
+        assert!(status.size_bytes.unwrap() > 0);
+        // 2回目は何も適用しない
+        assert!(admin.migrate().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ensure_up_to_date_without_auto_migrate() {
+        // Arrange
+        let (_dir, _url, db) = setup_empty_db().await;
+        let admin = SeaOrmDatabaseAdmin::new(db);
+
+        // Act & Assert: 未適用のマイグレーションがあればエラー
+        let err = admin.ensure_up_to_date(false).await.unwrap_err();
+        assert!(err.to_string().contains("yaru db migrate"));
+
+        // Act & Assert: 自動適用後は成功
+        admin.ensure_up_to_date(true).await.unwrap();
+        admin.ensure_up_to_date(false).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_check_up_to_date_does_not_migrate() {
+        // Arrange
+        let (_dir, _url, db) = setup_empty_db().await;
+        let admin = SeaOrmDatabaseAdmin::new(db);
+
+        // Act & Assert: 未適用のマイグレーションがあればエラーになり、適用もしない
+        let err = admin.check_up_to_date().await.unwrap_err();
+        assert!(err.to_string().contains("--read-only"));
+        assert!(!admin.pending_migrations().await.unwrap().is_empty());
+
+        // Act & Assert: 適用後は成功
+        admin.migrate().await.unwrap();
+        admin.check_up_to_date().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_migrate_records_schema_version() {
+        // Arrange
+        let (_dir, _url, db) = setup_empty_db().await;
+        let admin = SeaOrmDatabaseAdmin::new(db);
+        assert_eq!(admin.schema_version().await.unwrap(), 0);
+
+        // Act
+        admin.ensure_up_to_date(true).await.unwrap();
+
+        // Assert
+        assert_eq!(
+            admin.schema_version().await.unwrap(),
+            expected_schema_version()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensure_up_to_date_records_version_for_existing_database() {
+        // Arrange: バージョン記録前にマイグレーション済みのデータベース
+        let (_dir, _url, db) = setup_empty_db().await;
+        Migrator::up(&db, None).await.unwrap();
+        let admin = SeaOrmDatabaseAdmin::new(db);
+
+        // Act
+        admin.ensure_up_to_date(false).await.unwrap();
+
+        // Assert
+        assert_eq!(
+            admin.schema_version().await.unwrap(),
+            expected_schema_version()
+        );
+    }
+}
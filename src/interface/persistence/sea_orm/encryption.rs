@@ -0,0 +1,275 @@
+use crate::interface::persistence::{
+    encryption::FieldCipher, sea_orm::task_repository::restore_updated_at,
+};
+use anyhow::{Context, Result};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use entity::{
+    prelude::{Settings, Tasks},
+    settings, tasks,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection,
+    EntityTrait, QueryFilter, TransactionTrait,
+};
+
+/// 鍵導出用のソルトを保存する設定名
+const SALT_SETTING: &str = "encryption.salt";
+
+/// パスフレーズ検証用の暗号文を保存する設定名
+const CHECK_SETTING: &str = "encryption.check";
+
+/// パスフレーズ検証用の平文
+const CHECK_PLAINTEXT: &str = "yaru";
+
+/// SeaOrmEncryptionManager - データベースの暗号化状態を管理する
+///
+/// 鍵そのものは保存せず、ソルトと検証用の暗号文のみを`settings`テーブルに保存します。
+pub struct SeaOrmEncryptionManager {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmEncryptionManager {
+    /// 新しいSeaOrmEncryptionManagerを作成
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// データベースが暗号化されているか判定
+    pub async fn is_encrypted(&self) -> Result<bool> {
+        Ok(find_setting(&self.db, SALT_SETTING).await?.is_some())
+    }
+
+    /// パスフレーズを検証し、暗号化に使用する鍵を取得
+    ///
+    /// # Returns
+    /// * `Ok(FieldCipher)` - パスフレーズが正しい場合
+    /// * `Err` - データベースが暗号化されていない、またはパスフレーズが誤っている場合
+    pub async fn unlock(&self, passphrase: &str) -> Result<FieldCipher> {
+        let salt = find_setting(&self.db, SALT_SETTING)
+            .await?
+            .context("Database is not encrypted")?;
+        let check = find_setting(&self.db, CHECK_SETTING)
+            .await?
+            .context("Encryption settings are incomplete")?;
+
+        let salt = STANDARD.decode(salt).context("Invalid encryption salt")?;
+        let cipher = FieldCipher::derive(passphrase, &salt)?;
+        match cipher.decrypt(&check) {
+            Ok(plaintext) if plaintext == CHECK_PLAINTEXT => Ok(cipher),
+            _ => anyhow::bail!("Incorrect passphrase"),
+        }
+    }
+
+    /// 全タスクのタイトルと説明を暗号化
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 暗号化したタスクの件数
+    /// * `Err` - すでに暗号化されている場合、またはエラーが発生した場合
+    pub async fn encrypt(&self, passphrase: &str) -> Result<usize> {
+        if self.is_encrypted().await? {
+            anyhow::bail!("Database is already encrypted");
+        }
+
+        let salt = FieldCipher::generate_salt()?;
+        let cipher = FieldCipher::derive(passphrase, &salt)?;
+
+        // 途中で失敗しても平文と暗号文が混在しないよう、1つのトランザクションで書き換える
+        let txn = self.db.begin().await?;
+        let count = rewrite_tasks(&txn, |value| {
+            if FieldCipher::is_encrypted(value) {
+                Ok(value.to_string())
+            } else {
+                cipher.encrypt(value)
+            }
+        })
+        .await?;
+
+        Settings::insert_many([
+            settings::ActiveModel {
+                name: Set(SALT_SETTING.to_string()),
+                value: Set(STANDARD.encode(&salt)),
+            },
+            settings::ActiveModel {
+                name: Set(CHECK_SETTING.to_string()),
+                value: Set(cipher.encrypt(CHECK_PLAINTEXT)?),
+            },
+        ])
+        .exec(&txn)
+        .await?;
+        txn.commit().await?;
+
+        Ok(count)
+    }
+
+    /// 全タスクを復号し、暗号化を解除
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - 復号したタスクの件数
+    /// * `Err` - 暗号化されていない、パスフレーズが誤っている、またはエラーが発生した場合
+    pub async fn decrypt(&self, passphrase: &str) -> Result<usize> {
+        let cipher = self.unlock(passphrase).await?;
+
+        let txn = self.db.begin().await?;
+        let count = rewrite_tasks(&txn, |value| cipher.decrypt(value)).await?;
+
+        Settings::delete_many()
+            .filter(settings::Column::Name.is_in([SALT_SETTING, CHECK_SETTING]))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+
+        Ok(count)
+    }
+}
+
+/// 設定値を取得
+async fn find_setting<C: ConnectionTrait>(db: &C, name: &str) -> Result<Option<String>> {
+    Ok(Settings::find_by_id(name.to_string())
+        .one(db)
+        .await?
+        .map(|setting| setting.value))
+}
+
+/// 全タスクのタイトルと説明を変換して書き戻す（更新日時は変更しない）
+async fn rewrite_tasks<C, F>(db: &C, transform: F) -> Result<usize>
+where
+    C: ConnectionTrait,
+    F: Fn(&str) -> Result<String>,
+{
+    let task_models = Tasks::find().all(db).await?;
+    let count = task_models.len();
+
+    for task_model in task_models {
+        tasks::ActiveModel {
+            id: Set(task_model.id),
+            title: Set(transform(&task_model.title)?),
+            description: Set(transform(&task_model.description)?),
+            ..Default::default()
+        }
+        .update(db)
+        .await?;
+        // 暗号化の有無は利用者による変更ではないため、トリガーが進めた更新日時を戻す
+        restore_updated_at(db, task_model.id, task_model.updated_at).await?;
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::{
+        domain::task::{
+            aggregate::NewTask,
+            builder::TaskBuilder,
+            repository::TaskRepository,
+            value_objects::{TaskDescription, TaskTitle},
+        },
+        interface::persistence::sea_orm::SeaOrmTaskRepository,
+    };
+    use std::sync::Arc;
+
+    fn new_task(title: &str) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .description(TaskDescription::new("秘密の説明").unwrap())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_and_decrypt_database() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let saved = SeaOrmTaskRepository::new(db.clone())
+            .save(new_task("機密タスク"))
+            .await
+            .unwrap();
+        let manager = SeaOrmEncryptionManager::new(db.clone());
+
+        // Act
+        let encrypted_count = manager.encrypt("secret").await.unwrap();
+
+        // Assert: 保存値は暗号化され、鍵なしでは読めない
+        assert_eq!(encrypted_count, 1);
+        assert!(manager.is_encrypted().await.unwrap());
+        let raw = Tasks::find_by_id(saved.id().value())
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(FieldCipher::is_encrypted(&raw.title));
+        assert!(FieldCipher::is_encrypted(&raw.description));
+        assert!(
+            SeaOrmTaskRepository::new(db.clone())
+                .find_all()
+                .await
+                .is_err()
+        );
+
+        // Assert: 正しいパスフレーズでのみ読み書きできる
+        assert!(manager.unlock("wrong").await.is_err());
+        let cipher = Arc::new(manager.unlock("secret").await.unwrap());
+        let repo = SeaOrmTaskRepository::new(db.clone()).with_cipher(Some(cipher));
+        repo.save(new_task("追加タスク")).await.unwrap();
+        let titles: Vec<String> = repo
+            .find_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|task| task.title().value().to_string())
+            .collect();
+        assert_eq!(titles, vec!["機密タスク", "追加タスク"]);
+
+        // Act & Assert: 復号後は鍵なしで読める
+        assert_eq!(manager.decrypt("secret").await.unwrap(), 2);
+        assert!(!manager.is_encrypted().await.unwrap());
+        let tasks = SeaOrmTaskRepository::new(db).find_all().await.unwrap();
+        assert_eq!(tasks[1].title().value(), "追加タスク");
+        assert_eq!(tasks[1].description().value(), "秘密の説明");
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_twice_fails() {
+        let (_dir, db) = setup_db().await;
+        let manager = SeaOrmEncryptionManager::new(db);
+
+        manager.encrypt("secret").await.unwrap();
+
+        assert!(manager.encrypt("secret").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_and_decrypt_keep_updated_at() {
+        // Arrange: 更新日時を過去にして、トリガーで進んだかどうかを判別できるようにする
+        let (_dir, db) = setup_db().await;
+        let saved = SeaOrmTaskRepository::new(db.clone())
+            .save(new_task("機密タスク"))
+            .await
+            .unwrap();
+        let past = "2026-01-05T09:00:00Z"
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .unwrap();
+        restore_updated_at(&db, saved.id().value(), past.into())
+            .await
+            .unwrap();
+        let manager = SeaOrmEncryptionManager::new(db.clone());
+
+        // Act
+        manager.encrypt("secret").await.unwrap();
+        let encrypted = Tasks::find_by_id(saved.id().value())
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        manager.decrypt("secret").await.unwrap();
+        let decrypted = Tasks::find_by_id(saved.id().value())
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(encrypted.updated_at, past);
+        assert_eq!(decrypted.updated_at, past);
+    }
+}
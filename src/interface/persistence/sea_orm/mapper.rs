@@ -1,17 +1,38 @@
 use crate::domain::{
     tag::{
-        aggregate::{TagAggregate, TagReconstructParams},
+        aggregate::{NewTag, TagAggregate, TagReconstructParams},
         value_objects::{TagDescription, TagId, TagName},
     },
     task::{
-        aggregate::{TaskAggregate, TaskReconstructParams},
-        value_objects::{DueDate, Priority, Status, TaskDescription, TaskId, TaskTitle},
+        aggregate::{NewTask, TaskAggregate, TaskReconstructParams},
+        value_objects::{
+            Assignee, ChecklistItem, CustomField, DueDate, Estimate, ExternalRef, GitLink,
+            Priority, Recurrence, Status, StatusChange, TaskContext, TaskDescription, TaskId,
+            TaskTitle,
+        },
+    },
+    time_entry::{
+        aggregate::{NewTimeEntry, TimeEntryAggregate, TimeEntryReconstructParams},
+        value_objects::TimeEntryId,
     },
 };
 use anyhow::Result;
-use entity::{tags, tasks};
+use entity::{
+    checklist_items, custom_fields, external_refs, tags, task_links, task_status_changes, tasks,
+    time_entries,
+};
 use sea_orm::ActiveValue::Set;
 
+/// タスクに従属するテーブルの行（カスタムフィールド・チェックリスト項目・gitの紐づけ・外部の項目との対応・ステータスの変更履歴）
+#[derive(Debug, Clone, Default)]
+pub struct TaskChildRows {
+    pub custom_fields: Vec<custom_fields::Model>,
+    pub checklist_items: Vec<checklist_items::Model>,
+    pub task_links: Vec<task_links::Model>,
+    pub external_refs: Vec<external_refs::Model>,
+    pub status_changes: Vec<task_status_changes::Model>,
+}
+
 /// TaskMapper - TaskAggregateとSeaORM Entityの相互変換
 pub struct TaskMapper;
 
@@ -21,14 +42,14 @@ impl TaskMapper {
     /// # Arguments
     /// * `task_model` - tasks::Model
     /// * `tag_ids` - タスクに紐づくタグIDのリスト
-    pub fn to_domain(task_model: tasks::Model, tag_ids: Vec<i32>) -> Result<TaskAggregate> {
+    /// * `children` - タスクに紐づくカスタムフィールド・チェックリスト項目・gitの紐づけ・外部の項目との対応・ステータスの変更履歴
+    pub fn to_domain(
+        task_model: tasks::Model,
+        tag_ids: Vec<i32>,
+        mut children: TaskChildRows,
+    ) -> Result<TaskAggregate> {
         // Status変換
-        let status = match task_model.status.as_str() {
-            "Pending" => Status::Pending,
-            "InProgress" => Status::InProgress,
-            "Completed" => Status::Completed,
-            _ => anyhow::bail!("Unknown status: {}", task_model.status),
-        };
+        let status = Self::status_from_string(&task_model.status)?;
 
         // Priority変換
         let priority = match task_model.priority.as_str() {
@@ -63,23 +84,100 @@ impl TaskMapper {
             updated_at: task_model.updated_at.into(),
             due_date,
             completed_at: task_model.completed_at.map(|dt| dt.into()),
+            custom_fields: children
+                .custom_fields
+                .into_iter()
+                .map(|field| CustomField::new(field.key, field.value))
+                .collect::<Result<Vec<_>>>()?,
+            checklist: {
+                children.checklist_items.sort_by_key(|item| item.position);
+                children
+                    .checklist_items
+                    .into_iter()
+                    .map(|item| ChecklistItem::new(item.text, item.done))
+                    .collect::<Result<Vec<_>>>()?
+            },
+            git_links: {
+                children.task_links.sort_by_key(|link| link.id);
+                children
+                    .task_links
+                    .into_iter()
+                    .map(|link| {
+                        GitLink::new(
+                            link.repository,
+                            link.branch,
+                            link.commit_hash,
+                            link.linked_at.into(),
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            },
+            external_refs: {
+                children
+                    .external_refs
+                    .sort_by_key(|external_ref| external_ref.id);
+                children
+                    .external_refs
+                    .into_iter()
+                    .map(|external_ref| {
+                        ExternalRef::new(
+                            external_ref.source,
+                            external_ref.external_id,
+                            external_ref.url,
+                            external_ref.remote_updated_at.into(),
+                            Self::status_from_string(&external_ref.synced_status)?,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            },
+            recurrence: task_model
+                .recurrence
+                .as_deref()
+                .map(str::parse::<Recurrence>)
+                .transpose()?,
+            snoozed_until: task_model.snoozed_until,
+            estimate: task_model
+                .estimate_minutes
+                .map(|minutes| Estimate::from_minutes(u32::try_from(minutes)?))
+                .transpose()?,
+            context: task_model.context.map(TaskContext::new).transpose()?,
+            assignee: task_model.assignee.map(Assignee::new).transpose()?,
+            status_history: {
+                children.status_changes.sort_by_key(|change| change.id);
+                children
+                    .status_changes
+                    .into_iter()
+                    .map(|change| {
+                        Ok(StatusChange::new(
+                            Self::status_from_string(&change.from_status)?,
+                            Self::status_from_string(&change.to_status)?,
+                            change.changed_at.into(),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            },
         };
 
         Ok(TaskAggregate::reconstruct(params))
     }
 
-    /// TaskAggregateからSeaORM ActiveModelに変換（新規作成用）
-    pub fn to_active_model_for_insert(aggregate: &TaskAggregate) -> tasks::ActiveModel {
+    /// NewTaskからSeaORM ActiveModelに変換（新規作成用、IDはデータベースが割り当てる）
+    pub fn to_active_model_for_insert(task: &NewTask) -> tasks::ActiveModel {
         tasks::ActiveModel {
             id: sea_orm::ActiveValue::NotSet,
-            title: Set(aggregate.title().value().to_string()),
-            description: Set(aggregate.description().value().to_string()),
-            status: Set(Self::status_to_string(aggregate.status())),
-            priority: Set(Self::priority_to_string(aggregate.priority())),
-            created_at: sea_orm::ActiveValue::NotSet,
+            title: Set(task.title().value().to_string()),
+            description: Set(task.description().value().to_string()),
+            status: Set(Self::status_to_string(task.status())),
+            priority: Set(Self::priority_to_string(task.priority())),
+            created_at: Set((*task.created_at()).into()),
             updated_at: sea_orm::ActiveValue::NotSet,
-            due_date: Set(aggregate.due_date().as_ref().map(|dd| dd.value())),
-            completed_at: Set(aggregate.completed_at().map(|dt| dt.into())),
+            due_date: Set(task.due_date().as_ref().map(|dd| dd.value())),
+            completed_at: Set(task.completed_at().map(|dt| dt.into())),
+            recurrence: Set(task.recurrence().map(ToString::to_string)),
+            snoozed_until: Set(task.snoozed_until()),
+            estimate_minutes: Set(task.estimate().map(Self::estimate_to_i32)),
+            context: Set(task.context().map(ToString::to_string)),
+            assignee: Set(task.assignee().map(ToString::to_string)),
         }
     }
 
@@ -95,10 +193,29 @@ impl TaskMapper {
             updated_at: sea_orm::ActiveValue::NotSet,
             due_date: Set(aggregate.due_date().as_ref().map(|dd| dd.value())),
             completed_at: Set(aggregate.completed_at().map(|dt| dt.into())),
+            recurrence: Set(aggregate.recurrence().map(ToString::to_string)),
+            snoozed_until: Set(aggregate.snoozed_until()),
+            estimate_minutes: Set(aggregate.estimate().map(Self::estimate_to_i32)),
+            context: Set(aggregate.context().map(ToString::to_string)),
+            assignee: Set(aggregate.assignee().map(ToString::to_string)),
         }
     }
 
-    fn status_to_string(status: &Status) -> String {
+    /// 見積もり時間をカラムの値（分）に変換（上限が999時間のためi32に収まる）
+    fn estimate_to_i32(estimate: Estimate) -> i32 {
+        i32::try_from(estimate.minutes()).unwrap_or(i32::MAX)
+    }
+
+    fn status_from_string(status: &str) -> Result<Status> {
+        match status {
+            "Pending" => Ok(Status::Pending),
+            "InProgress" => Ok(Status::InProgress),
+            "Completed" => Ok(Status::Completed),
+            _ => anyhow::bail!("Unknown status: {}", status),
+        }
+    }
+
+    pub fn status_to_string(status: &Status) -> String {
         match status {
             Status::Pending => "Pending".to_string(),
             Status::InProgress => "InProgress".to_string(),
@@ -106,7 +223,7 @@ impl TaskMapper {
         }
     }
 
-    fn priority_to_string(priority: &Priority) -> String {
+    pub fn priority_to_string(priority: &Priority) -> String {
         match priority {
             Priority::Low => "Low".to_string(),
             Priority::Medium => "Medium".to_string(),
@@ -116,6 +233,103 @@ impl TaskMapper {
     }
 }
 
+/// CustomFieldMapper - CustomFieldとSeaORM Entityの相互変換
+pub struct CustomFieldMapper;
+
+impl CustomFieldMapper {
+    /// CustomFieldからSeaORM ActiveModelに変換（新規作成用）
+    pub fn to_active_model_for_insert(
+        task_id: i32,
+        field: &CustomField,
+    ) -> custom_fields::ActiveModel {
+        custom_fields::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            task_id: Set(task_id),
+            key: Set(field.key().to_string()),
+            value: Set(field.value().to_string()),
+        }
+    }
+}
+
+/// ChecklistItemMapper - ChecklistItemとSeaORM Entityの相互変換
+pub struct ChecklistItemMapper;
+
+impl ChecklistItemMapper {
+    /// ChecklistItemからSeaORM ActiveModelに変換（新規作成用）
+    ///
+    /// `position`はタスク内での表示順（0始まり）です。
+    pub fn to_active_model_for_insert(
+        task_id: i32,
+        position: usize,
+        item: &ChecklistItem,
+    ) -> checklist_items::ActiveModel {
+        checklist_items::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            task_id: Set(task_id),
+            position: Set(position as i32),
+            text: Set(item.text().to_string()),
+            done: Set(item.is_done()),
+        }
+    }
+}
+
+/// GitLinkMapper - GitLinkとSeaORM Entityの相互変換
+pub struct GitLinkMapper;
+
+impl GitLinkMapper {
+    /// GitLinkからSeaORM ActiveModelに変換（新規作成用）
+    pub fn to_active_model_for_insert(task_id: i32, link: &GitLink) -> task_links::ActiveModel {
+        task_links::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            task_id: Set(task_id),
+            repository: Set(link.repository().to_string()),
+            branch: Set(link.branch().map(str::to_string)),
+            commit_hash: Set(link.commit().to_string()),
+            linked_at: Set((*link.linked_at()).into()),
+        }
+    }
+}
+
+/// StatusChangeMapper - StatusChangeとSeaORM Entityの相互変換
+pub struct StatusChangeMapper;
+
+impl StatusChangeMapper {
+    /// StatusChangeからSeaORM ActiveModelに変換（新規作成用）
+    pub fn to_active_model_for_insert(
+        task_id: i32,
+        change: &StatusChange,
+    ) -> task_status_changes::ActiveModel {
+        task_status_changes::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            task_id: Set(task_id),
+            from_status: Set(TaskMapper::status_to_string(&change.from())),
+            to_status: Set(TaskMapper::status_to_string(&change.to())),
+            changed_at: Set((*change.changed_at()).into()),
+        }
+    }
+}
+
+/// ExternalRefMapper - ExternalRefとSeaORM Entityの相互変換
+pub struct ExternalRefMapper;
+
+impl ExternalRefMapper {
+    /// ExternalRefからSeaORM ActiveModelに変換（新規作成用）
+    pub fn to_active_model_for_insert(
+        task_id: i32,
+        external_ref: &ExternalRef,
+    ) -> external_refs::ActiveModel {
+        external_refs::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            task_id: Set(task_id),
+            source: Set(external_ref.source().to_string()),
+            external_id: Set(external_ref.external_id().to_string()),
+            url: Set(external_ref.url().map(str::to_string)),
+            remote_updated_at: Set((*external_ref.remote_updated_at()).into()),
+            synced_status: Set(TaskMapper::status_to_string(&external_ref.synced_status())),
+        }
+    }
+}
+
 /// TagMapper - TagAggregateとSeaORM Entityの相互変換
 pub struct TagMapper;
 
@@ -126,6 +340,7 @@ impl TagMapper {
             id: TagId::new(tag_model.id)?,
             name: TagName::new(tag_model.name)?,
             description: TagDescription::new(tag_model.description)?,
+            parent_id: tag_model.parent_id.map(TagId::new).transpose()?,
             created_at: tag_model.created_at.into(),
             updated_at: tag_model.updated_at.into(),
         };
@@ -133,12 +348,13 @@ impl TagMapper {
         Ok(TagAggregate::reconstruct(params))
     }
 
-    /// TagAggregateからSeaORM ActiveModelに変換（新規作成用）
-    pub fn to_active_model_for_insert(aggregate: &TagAggregate) -> tags::ActiveModel {
+    /// NewTagからSeaORM ActiveModelに変換（新規作成用）
+    pub fn to_active_model_for_insert(tag: &NewTag) -> tags::ActiveModel {
         tags::ActiveModel {
             id: sea_orm::ActiveValue::NotSet,
-            name: Set(aggregate.name().value().to_string()),
-            description: Set(aggregate.description().value().to_string()),
+            name: Set(tag.name().value().to_string()),
+            description: Set(tag.description().value().to_string()),
+            parent_id: Set(tag.parent_id().map(|id| id.value())),
             created_at: sea_orm::ActiveValue::NotSet,
             updated_at: sea_orm::ActiveValue::NotSet,
         }
@@ -150,12 +366,40 @@ impl TagMapper {
             id: Set(aggregate.id().value()),
             name: Set(aggregate.name().value().to_string()),
             description: Set(aggregate.description().value().to_string()),
+            parent_id: Set(aggregate.parent_id().map(|id| id.value())),
             created_at: sea_orm::ActiveValue::NotSet,
             updated_at: sea_orm::ActiveValue::NotSet,
         }
     }
 }
 
+/// TimeEntryMapper - TimeEntryAggregateとSeaORM Entityの相互変換
+pub struct TimeEntryMapper;
+
+impl TimeEntryMapper {
+    /// SeaORM ModelからTimeEntryAggregateに変換
+    pub fn to_domain(model: time_entries::Model) -> Result<TimeEntryAggregate> {
+        let params = TimeEntryReconstructParams {
+            id: TimeEntryId::new(model.id)?,
+            task_id: TaskId::new(model.task_id)?,
+            started_at: model.started_at.into(),
+            ended_at: model.ended_at.into(),
+        };
+
+        Ok(TimeEntryAggregate::reconstruct(params))
+    }
+
+    /// TimeEntryAggregateからSeaORM ActiveModelに変換（新規作成用）
+    pub fn to_active_model_for_insert(entry: &NewTimeEntry) -> time_entries::ActiveModel {
+        time_entries::ActiveModel {
+            id: sea_orm::ActiveValue::NotSet,
+            task_id: Set(entry.task_id().value()),
+            started_at: Set((*entry.started_at()).into()),
+            ended_at: Set((*entry.ended_at()).into()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,11 +418,16 @@ mod tests {
             updated_at: Utc::now().into(),
             due_date: Some(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()),
             completed_at: None,
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: Some(150),
+            context: Some("@office".to_string()),
+            assignee: Some("alice".to_string()),
         };
         let tag_ids = vec![1, 2];
 
         // Act
-        let result = TaskMapper::to_domain(task_model, tag_ids);
+        let result = TaskMapper::to_domain(task_model, tag_ids, TaskChildRows::default());
 
         // Assert
         assert!(result.is_ok());
@@ -186,6 +435,9 @@ mod tests {
         assert_eq!(aggregate.id().value(), 1);
         assert_eq!(aggregate.title().value(), "テストタスク");
         assert_eq!(aggregate.tags().len(), 2);
+        assert_eq!(aggregate.estimate().unwrap().to_string(), "2h30m");
+        assert_eq!(aggregate.context().unwrap().value(), "@office");
+        assert_eq!(aggregate.assignee().unwrap().value(), "alice");
     }
 
     #[test]
@@ -195,6 +447,7 @@ mod tests {
             id: 1,
             name: "重要".to_string(),
             description: "重要なタスク".to_string(),
+            parent_id: None,
             created_at: Utc::now().into(),
             updated_at: Utc::now().into(),
         };
@@ -0,0 +1,110 @@
+use crate::application::use_cases::task::sync_databases::{SyncBase, SyncStateStore};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use entity::{prelude::Settings, settings};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, EntityTrait, sea_query::OnConflict};
+
+/// データベースのIDを保存する設定名
+const REPLICA_ID_SETTING: &str = "sync.replica_id";
+
+/// 同期相手ごとの前回同期したときの内容を保存する設定名の接頭辞
+const BASE_SETTING_PREFIX: &str = "sync.base.";
+
+/// SeaOrmSyncStateStore - データベース間の同期の状態を`settings`テーブルに保存する
+pub struct SeaOrmSyncStateStore {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmSyncStateStore {
+    /// 新しいSeaOrmSyncStateStoreを作成
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 設定値を取得
+    async fn find_setting(&self, name: &str) -> Result<Option<String>> {
+        Ok(Settings::find_by_id(name.to_string())
+            .one(&self.db)
+            .await?
+            .map(|setting| setting.value))
+    }
+
+    /// 設定値を保存（既にある場合は上書き）
+    async fn save_setting(&self, name: &str, value: String) -> Result<()> {
+        Settings::insert(settings::ActiveModel {
+            name: Set(name.to_string()),
+            value: Set(value),
+        })
+        .on_conflict(
+            OnConflict::column(settings::Column::Name)
+                .update_column(settings::Column::Value)
+                .to_owned(),
+        )
+        .exec(&self.db)
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SyncStateStore for SeaOrmSyncStateStore {
+    async fn replica_id(&self) -> Result<String> {
+        if let Some(id) = self.find_setting(REPLICA_ID_SETTING).await? {
+            return Ok(id);
+        }
+        self.reset_replica_id().await
+    }
+
+    async fn reset_replica_id(&self) -> Result<String> {
+        let id = format!("{:016x}", fastrand::u64(..));
+        self.save_setting(REPLICA_ID_SETTING, id.clone()).await?;
+        Ok(id)
+    }
+
+    async fn load_base(&self, peer_id: &str) -> Result<SyncBase> {
+        match self
+            .find_setting(&format!("{}{}", BASE_SETTING_PREFIX, peer_id))
+            .await?
+        {
+            Some(value) => serde_json::from_str(&value).context("Invalid sync state"),
+            None => Ok(SyncBase::new()),
+        }
+    }
+
+    async fn save_base(&self, peer_id: &str, base: &SyncBase) -> Result<()> {
+        self.save_setting(
+            &format!("{}{}", BASE_SETTING_PREFIX, peer_id),
+            serde_json::to_string(base)?,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+
+    #[tokio::test]
+    async fn test_sync_state_store() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let store = SeaOrmSyncStateStore::new(db);
+        let base = SyncBase::from([("a1".to_string(), "0123".to_string())]);
+
+        // Act
+        let replica_id = store.replica_id().await.unwrap();
+        let empty = store.load_base("peer").await.unwrap();
+        store.save_base("peer", &SyncBase::new()).await.unwrap();
+        store.save_base("peer", &base).await.unwrap();
+
+        // Assert
+        assert_eq!(store.replica_id().await.unwrap(), replica_id);
+        let reset_id = store.reset_replica_id().await.unwrap();
+        assert_ne!(reset_id, replica_id);
+        assert_eq!(store.replica_id().await.unwrap(), reset_id);
+        assert!(empty.is_empty());
+        assert_eq!(store.load_base("peer").await.unwrap(), base);
+        assert!(store.load_base("other").await.unwrap().is_empty());
+    }
+}
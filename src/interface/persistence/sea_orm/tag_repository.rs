@@ -1,5 +1,9 @@
 use crate::{
-    domain::tag::{aggregate::TagAggregate, repository::TagRepository, value_objects::TagId},
+    domain::tag::{
+        aggregate::{NewTag, TagAggregate},
+        repository::TagRepository,
+        value_objects::{TagId, TagName},
+    },
     interface::persistence::sea_orm::mapper::TagMapper,
 };
 use anyhow::Result;
@@ -9,25 +13,58 @@ use entity::{
     tags, task_tags,
 };
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter,
+    sea_query::{Expr, Func},
 };
+use std::sync::Arc;
+use tracing::instrument;
 
 /// SeaORM実装のTagRepository
-pub struct SeaOrmTagRepository {
-    db: DatabaseConnection,
+///
+/// 接続`C`には通常の`DatabaseConnection`のほか、Unit of Work用の`DatabaseTransaction`も使用できます。
+pub struct SeaOrmTagRepository<C = DatabaseConnection> {
+    db: Arc<C>,
 }
 
 impl SeaOrmTagRepository {
     /// 新しいSeaOrmTagRepositoryを作成
     pub fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+impl<C: ConnectionTrait> SeaOrmTagRepository<C> {
+    /// 共有された接続（トランザクションなど）からSeaOrmTagRepositoryを作成
+    pub fn with_connection(db: Arc<C>) -> Self {
         Self { db }
     }
 }
 
+impl<C: ConnectionTrait + Send + Sync + 'static> SeaOrmTagRepository<C> {
+    /// 同じ名前（大文字小文字を区別しない）の別のタグがあればエラーにする
+    ///
+    /// データベースの一意インデックスでも弾かれますが、既存のタグのIDを含む分かりやすいエラーを返すために先に確認します。
+    /// `id`には更新するタグのIDを指定します（新しいタグはNone）。
+    async fn ensure_unique_name(&self, name: &TagName, id: Option<&TagId>) -> Result<()> {
+        if let Some(existing) = self.find_by_name(name.value()).await?
+            && Some(existing.id()) != id
+        {
+            anyhow::bail!(
+                "Tag '{}' already exists (id {})",
+                existing.name().value(),
+                existing.id().value()
+            );
+        }
+        Ok(())
+    }
+}
+
 #[async_trait]
-impl TagRepository for SeaOrmTagRepository {
+impl<C: ConnectionTrait + Send + Sync + 'static> TagRepository for SeaOrmTagRepository<C> {
+    #[instrument(name = "tag_repository.find_by_id", level = "debug", skip_all, fields(id = id.value()))]
     async fn find_by_id(&self, id: &TagId) -> Result<Option<TagAggregate>> {
-        let tag_model = Tags::find_by_id(id.value()).one(&self.db).await?;
+        let tag_model = Tags::find_by_id(id.value()).one(self.db.as_ref()).await?;
 
         match tag_model {
             Some(model) => {
@@ -38,8 +75,9 @@ impl TagRepository for SeaOrmTagRepository {
         }
     }
 
+    #[instrument(name = "tag_repository.find_all", level = "debug", skip_all)]
     async fn find_all(&self) -> Result<Vec<TagAggregate>> {
-        let tag_models = Tags::find().all(&self.db).await?;
+        let tag_models = Tags::find().all(self.db.as_ref()).await?;
 
         let mut aggregates = Vec::new();
         for model in tag_models {
@@ -50,10 +88,15 @@ impl TagRepository for SeaOrmTagRepository {
         Ok(aggregates)
     }
 
+    #[instrument(name = "tag_repository.find_by_name", level = "debug", skip(self))]
     async fn find_by_name(&self, name: &str) -> Result<Option<TagAggregate>> {
+        // 大文字小文字を区別せずに比較する
         let tag_model = Tags::find()
-            .filter(tags::Column::Name.eq(name))
-            .one(&self.db)
+            .filter(
+                Expr::expr(Func::lower(Expr::col(tags::Column::Name)))
+                    .eq(name.trim().to_lowercase()),
+            )
+            .one(self.db.as_ref())
             .await?;
 
         match tag_model {
@@ -65,59 +108,60 @@ impl TagRepository for SeaOrmTagRepository {
         }
     }
 
-    async fn save(&self, tag: TagAggregate) -> Result<TagAggregate> {
-        // タグの保存（IDが0の場合は新規作成、それ以外は更新）
-        let tag_to_save = if tag.id().value() == 0 {
-            // 新規作成
-            let active_model = TagMapper::to_active_model_for_insert(&tag);
-            let saved_model = active_model.insert(&self.db).await?;
+    #[instrument(name = "tag_repository.save", level = "debug", skip_all)]
+    async fn save(&self, tag: NewTag) -> Result<TagAggregate> {
+        self.ensure_unique_name(tag.name(), None).await?;
+        let active_model = TagMapper::to_active_model_for_insert(&tag);
+        let saved_model = active_model.insert(self.db.as_ref()).await?;
 
-            TagMapper::to_domain(saved_model)?
-        } else {
-            // 既存IDがある場合は更新
-            self.update(tag).await?
-        };
-
-        Ok(tag_to_save)
+        TagMapper::to_domain(saved_model)
     }
 
+    #[instrument(name = "tag_repository.update", level = "debug", skip_all, fields(id = tag.id().value()))]
     async fn update(&self, tag: TagAggregate) -> Result<TagAggregate> {
         // 既存のタグを取得
-        let existing = Tags::find_by_id(tag.id().value()).one(&self.db).await?;
+        let existing = Tags::find_by_id(tag.id().value())
+            .one(self.db.as_ref())
+            .await?;
 
         if existing.is_none() {
-            anyhow::bail!("タグID {}は存在しません", tag.id().value());
+            anyhow::bail!("Tag ID {} does not exist", tag.id().value());
         }
+        self.ensure_unique_name(tag.name(), Some(tag.id())).await?;
 
         // タグを更新
         let active_model = TagMapper::to_active_model_for_update(&tag);
-        let updated_model = active_model.update(&self.db).await?;
+        let updated_model = active_model.update(self.db.as_ref()).await?;
 
         let aggregate = TagMapper::to_domain(updated_model)?;
 
         Ok(aggregate)
     }
 
+    #[instrument(name = "tag_repository.delete", level = "debug", skip_all, fields(id = id.value()))]
     async fn delete(&self, id: &TagId) -> Result<bool> {
         // タグが使用されているかチェック（RESTRICT制約）
         let task_count = TaskTags::find()
             .filter(task_tags::Column::TagId.eq(id.value()))
-            .count(&self.db)
+            .count(self.db.as_ref())
             .await?;
 
         if task_count > 0 {
             anyhow::bail!(
-                "タグID {}は{}個のタスクで使用されているため削除できません",
+                "Tag ID {} cannot be deleted because it is used by {} task(s)",
                 id.value(),
                 task_count
             );
         }
 
-        let result = Tags::delete_by_id(id.value()).exec(&self.db).await?;
+        let result = Tags::delete_by_id(id.value())
+            .exec(self.db.as_ref())
+            .await?;
 
         Ok(result.rows_affected > 0)
     }
 
+    #[instrument(name = "tag_repository.find_by_ids", level = "debug", skip_all, fields(count = ids.len()))]
     async fn find_by_ids(&self, ids: &[TagId]) -> Result<Vec<TagAggregate>> {
         if ids.is_empty() {
             return Ok(Vec::new());
@@ -128,7 +172,7 @@ impl TagRepository for SeaOrmTagRepository {
         // SELECT * FROM tags WHERE id IN (?, ?, ...)
         let tag_models = Tags::find()
             .filter(tags::Column::Id.is_in(id_values))
-            .all(&self.db)
+            .all(self.db.as_ref())
             .await?;
 
         let mut aggregates = Vec::new();
@@ -1,37 +1,400 @@
 use crate::{
     domain::task::{
-        aggregate::TaskAggregate, repository::TaskRepository, specification::TaskSpecification,
+        aggregate::{NewTask, TaskAggregate},
+        repository::{TaskRepository, TaskStream},
+        specification::{TaskCriteria, TaskSpecification},
         value_objects::TaskId,
     },
-    interface::persistence::sea_orm::mapper::TaskMapper,
+    interface::persistence::{
+        encryption::FieldCipher,
+        sea_orm::mapper::{
+            ChecklistItemMapper, CustomFieldMapper, ExternalRefMapper, GitLinkMapper,
+            StatusChangeMapper, TaskChildRows, TaskMapper,
+        },
+    },
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use entity::{
-    prelude::{Tags, TaskTags, Tasks},
-    task_tags,
+    checklist_items, custom_fields, external_refs,
+    prelude::{
+        ChecklistItems, CustomFields, ExternalRefs, TaskLinks, TaskStatusChanges, TaskTags, Tasks,
+    },
+    task_links, task_status_changes, task_tags, tasks,
 };
+use futures_util::{StreamExt, TryStreamExt, stream};
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    ActiveModelTrait,
+    ActiveValue::{self, Set},
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+    prelude::DateTimeWithTimeZone,
+    sea_query::{Expr, Query, SimpleExpr},
 };
+use std::{collections::HashMap, sync::Arc};
+use tracing::instrument;
+
+/// 一括挿入の1文あたりの最大行数
+///
+/// SQLiteのバインド変数の上限を超えないよう、行数を制限して分割します。
+const INSERT_BATCH_SIZE: usize = 500;
+
+/// IDで絞り込んで読み込む場合の1回のクエリあたりの最大ID数
+///
+/// SQLiteのバインド変数の上限を超えないよう、`IN`に渡すIDの数を制限して分割します。
+const QUERY_CHUNK_SIZE: usize = 500;
+
+/// ストリーム取得時に1回のクエリで読み込むタスク数
+const STREAM_PAGE_SIZE: u64 = 500;
 
 /// SeaORM実装のTaskRepository
-pub struct SeaOrmTaskRepository {
-    db: DatabaseConnection,
+///
+/// 接続`C`には通常の`DatabaseConnection`のほか、Unit of Work用の`DatabaseTransaction`も使用できます。
+/// 鍵を設定した場合、タスクのタイトルと説明は暗号化して保存されます。
+pub struct SeaOrmTaskRepository<C = DatabaseConnection> {
+    db: Arc<C>,
+    cipher: Option<Arc<FieldCipher>>,
 }
 
 impl SeaOrmTaskRepository {
     /// 新しいSeaOrmTaskRepositoryを作成
     pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+        Self::with_connection(Arc::new(db))
+    }
+}
+
+impl<C: ConnectionTrait> SeaOrmTaskRepository<C> {
+    /// 共有された接続（トランザクションなど）からSeaOrmTaskRepositoryを作成
+    pub fn with_connection(db: Arc<C>) -> Self {
+        Self { db, cipher: None }
+    }
+
+    /// タイトルと説明の暗号化に使用する鍵を設定
+    pub fn with_cipher(mut self, cipher: Option<Arc<FieldCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// 暗号化されたフィールドを復号
+    fn decrypt_field(&self, value: String) -> Result<String> {
+        match &self.cipher {
+            Some(cipher) => cipher.decrypt(&value),
+            None if FieldCipher::is_encrypted(&value) => {
+                anyhow::bail!("Task data is encrypted; a passphrase is required")
+            }
+            None => Ok(value),
+        }
+    }
+
+    /// SeaORM ModelからTaskAggregateに変換（暗号化されたフィールドは復号）
+    fn to_domain(
+        &self,
+        mut task_model: tasks::Model,
+        tag_ids: Vec<i32>,
+        children: TaskChildRows,
+    ) -> Result<TaskAggregate> {
+        task_model.title = self.decrypt_field(task_model.title)?;
+        task_model.description = self.decrypt_field(task_model.description)?;
+        TaskMapper::to_domain(task_model, tag_ids, children)
+    }
+
+    /// タスクIDごとのカスタムフィールド・チェックリスト項目・gitの紐づけ・外部の項目との対応・ステータスの変更履歴を一括取得（N+1問題の回避）
+    ///
+    /// `task_ids`がNoneの場合は全タスク分を取得します。
+    async fn load_children<T: ConnectionTrait>(
+        db: &T,
+        task_ids: Option<&[i32]>,
+    ) -> Result<HashMap<i32, TaskChildRows>> {
+        let (fields, items, links, refs, changes) = match task_ids {
+            None => (
+                CustomFields::find().all(db).await?,
+                ChecklistItems::find().all(db).await?,
+                TaskLinks::find().all(db).await?,
+                ExternalRefs::find().all(db).await?,
+                TaskStatusChanges::find().all(db).await?,
+            ),
+            Some(task_ids) => {
+                // SQLiteのバインド変数の上限を超えないよう分割して取得する
+                let mut fields = Vec::new();
+                let mut items = Vec::new();
+                let mut links = Vec::new();
+                let mut refs = Vec::new();
+                let mut changes = Vec::new();
+                for chunk in task_ids.chunks(QUERY_CHUNK_SIZE) {
+                    fields.extend(
+                        CustomFields::find()
+                            .filter(custom_fields::Column::TaskId.is_in(chunk.to_vec()))
+                            .all(db)
+                            .await?,
+                    );
+                    items.extend(
+                        ChecklistItems::find()
+                            .filter(checklist_items::Column::TaskId.is_in(chunk.to_vec()))
+                            .all(db)
+                            .await?,
+                    );
+                    links.extend(
+                        TaskLinks::find()
+                            .filter(task_links::Column::TaskId.is_in(chunk.to_vec()))
+                            .all(db)
+                            .await?,
+                    );
+                    refs.extend(
+                        ExternalRefs::find()
+                            .filter(external_refs::Column::TaskId.is_in(chunk.to_vec()))
+                            .all(db)
+                            .await?,
+                    );
+                    changes.extend(
+                        TaskStatusChanges::find()
+                            .filter(task_status_changes::Column::TaskId.is_in(chunk.to_vec()))
+                            .all(db)
+                            .await?,
+                    );
+                }
+                (fields, items, links, refs, changes)
+            }
+        };
+
+        let mut by_task: HashMap<i32, TaskChildRows> = HashMap::new();
+        for field in fields {
+            by_task
+                .entry(field.task_id)
+                .or_default()
+                .custom_fields
+                .push(field);
+        }
+        for item in items {
+            by_task
+                .entry(item.task_id)
+                .or_default()
+                .checklist_items
+                .push(item);
+        }
+        for link in links {
+            by_task
+                .entry(link.task_id)
+                .or_default()
+                .task_links
+                .push(link);
+        }
+        for external_ref in refs {
+            by_task
+                .entry(external_ref.task_id)
+                .or_default()
+                .external_refs
+                .push(external_ref);
+        }
+        for change in changes {
+            by_task
+                .entry(change.task_id)
+                .or_default()
+                .status_changes
+                .push(change);
+        }
+        Ok(by_task)
+    }
+
+    /// タスクIDごとのタグIDを一括取得（N+1問題の回避）
+    ///
+    /// タグの行は結合せず、中間テーブルの`task_tags`だけを読み込みます。
+    /// `task_ids`がNoneの場合は全タスク分を取得します。
+    async fn load_tag_ids<T: ConnectionTrait>(
+        db: &T,
+        task_ids: Option<&[i32]>,
+    ) -> Result<HashMap<i32, Vec<i32>>> {
+        let task_tags = match task_ids {
+            None => {
+                TaskTags::find()
+                    .order_by_asc(task_tags::Column::TagId)
+                    .all(db)
+                    .await?
+            }
+            Some(task_ids) => {
+                // SQLiteのバインド変数の上限を超えないよう分割して取得する
+                let mut task_tags = Vec::new();
+                for chunk in task_ids.chunks(QUERY_CHUNK_SIZE) {
+                    task_tags.extend(
+                        TaskTags::find()
+                            .filter(task_tags::Column::TaskId.is_in(chunk.to_vec()))
+                            .order_by_asc(task_tags::Column::TagId)
+                            .all(db)
+                            .await?,
+                    );
+                }
+                task_tags
+            }
+        };
+
+        let mut by_task: HashMap<i32, Vec<i32>> = HashMap::new();
+        for task_tag in task_tags {
+            by_task
+                .entry(task_tag.task_id)
+                .or_default()
+                .push(task_tag.tag_id);
+        }
+        Ok(by_task)
+    }
+
+    /// タスクの行にタグと従属するテーブルの行を一括で読み込み、TaskAggregateを組み立てる
+    ///
+    /// タスク数にかかわらず、タスクの行とは別にテーブルごとに1回（IDで絞り込む場合はIDの分割ごとに1回）のクエリで読み込みます。
+    /// `all_tasks`がtrueの場合はIDで絞り込まずに全件を読み込みます。
+    async fn assemble(
+        &self,
+        task_models: Vec<tasks::Model>,
+        all_tasks: bool,
+    ) -> Result<Vec<TaskAggregate>> {
+        if task_models.is_empty() {
+            return Ok(vec![]);
+        }
+        let task_ids: Vec<i32> = task_models.iter().map(|model| model.id).collect();
+        let scope = (!all_tasks).then_some(task_ids.as_slice());
+        let mut tag_ids_by_task = Self::load_tag_ids(self.db.as_ref(), scope).await?;
+        let mut children_by_task = Self::load_children(self.db.as_ref(), scope).await?;
+
+        task_models
+            .into_iter()
+            .map(|model| {
+                let tag_ids = tag_ids_by_task.remove(&model.id).unwrap_or_default();
+                let children = children_by_task.remove(&model.id).unwrap_or_default();
+                self.to_domain(model, tag_ids, children)
+            })
+            .collect()
+    }
+
+    /// IDを指定してタスクを1件取得（タグと従属するテーブルの行を含む）
+    async fn fetch_one(&self, task_id: i32) -> Result<Option<TaskAggregate>> {
+        let Some(task_model) = Tasks::find_by_id(task_id).one(self.db.as_ref()).await? else {
+            return Ok(None);
+        };
+        Ok(self.assemble(vec![task_model], false).await?.pop())
+    }
+
+    /// タスクのカスタムフィールド・チェックリスト・gitの紐づけ・外部の項目との対応・ステータスの変更履歴を更新（既存を削除して新規作成）
+    async fn update_children<T: ConnectionTrait>(
+        db: &T,
+        task_id: i32,
+        task: &TaskAggregate,
+    ) -> Result<()> {
+        CustomFields::delete_many()
+            .filter(custom_fields::Column::TaskId.eq(task_id))
+            .exec(db)
+            .await?;
+        ChecklistItems::delete_many()
+            .filter(checklist_items::Column::TaskId.eq(task_id))
+            .exec(db)
+            .await?;
+        TaskLinks::delete_many()
+            .filter(task_links::Column::TaskId.eq(task_id))
+            .exec(db)
+            .await?;
+        ExternalRefs::delete_many()
+            .filter(external_refs::Column::TaskId.eq(task_id))
+            .exec(db)
+            .await?;
+        TaskStatusChanges::delete_many()
+            .filter(task_status_changes::Column::TaskId.eq(task_id))
+            .exec(db)
+            .await?;
+
+        Self::insert_children(db, &[(task_id, task)]).await
+    }
+
+    /// タスクのカスタムフィールド・チェックリスト項目・gitの紐づけ・外部の項目との対応・ステータスの変更履歴を一括挿入
+    async fn insert_children<T: ConnectionTrait>(
+        db: &T,
+        tasks: &[(i32, &TaskAggregate)],
+    ) -> Result<()> {
+        let field_models: Vec<custom_fields::ActiveModel> = tasks
+            .iter()
+            .flat_map(|(task_id, task)| {
+                task.custom_fields()
+                    .iter()
+                    .map(|field| CustomFieldMapper::to_active_model_for_insert(*task_id, field))
+            })
+            .collect();
+        for chunk in field_models.chunks(INSERT_BATCH_SIZE) {
+            CustomFields::insert_many(chunk.to_vec()).exec(db).await?;
+        }
+
+        let item_models: Vec<checklist_items::ActiveModel> = tasks
+            .iter()
+            .flat_map(|(task_id, task)| {
+                task.checklist().iter().enumerate().map(|(position, item)| {
+                    ChecklistItemMapper::to_active_model_for_insert(*task_id, position, item)
+                })
+            })
+            .collect();
+        for chunk in item_models.chunks(INSERT_BATCH_SIZE) {
+            ChecklistItems::insert_many(chunk.to_vec()).exec(db).await?;
+        }
+
+        let link_models: Vec<task_links::ActiveModel> = tasks
+            .iter()
+            .flat_map(|(task_id, task)| {
+                task.git_links()
+                    .iter()
+                    .map(|link| GitLinkMapper::to_active_model_for_insert(*task_id, link))
+            })
+            .collect();
+        for chunk in link_models.chunks(INSERT_BATCH_SIZE) {
+            TaskLinks::insert_many(chunk.to_vec()).exec(db).await?;
+        }
+
+        let ref_models: Vec<external_refs::ActiveModel> = tasks
+            .iter()
+            .flat_map(|(task_id, task)| {
+                task.external_refs().iter().map(|external_ref| {
+                    ExternalRefMapper::to_active_model_for_insert(*task_id, external_ref)
+                })
+            })
+            .collect();
+        for chunk in ref_models.chunks(INSERT_BATCH_SIZE) {
+            ExternalRefs::insert_many(chunk.to_vec()).exec(db).await?;
+        }
+
+        let change_models: Vec<task_status_changes::ActiveModel> = tasks
+            .iter()
+            .flat_map(|(task_id, task)| {
+                task.status_history()
+                    .iter()
+                    .map(|change| StatusChangeMapper::to_active_model_for_insert(*task_id, change))
+            })
+            .collect();
+        for chunk in change_models.chunks(INSERT_BATCH_SIZE) {
+            TaskStatusChanges::insert_many(chunk.to_vec())
+                .exec(db)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// ActiveModelのタイトルと説明を暗号化（鍵が設定されている場合のみ）
+    fn encrypt_fields(&self, mut active_model: tasks::ActiveModel) -> Result<tasks::ActiveModel> {
+        if let Some(cipher) = &self.cipher {
+            if let ActiveValue::Set(title) = &active_model.title {
+                active_model.title = Set(cipher.encrypt(title)?);
+            }
+            if let ActiveValue::Set(description) = &active_model.description {
+                active_model.description = Set(cipher.encrypt(description)?);
+            }
+        }
+        Ok(active_model)
     }
 
     /// タスクのタグ関連付けを更新（既存を削除して新規作成）
-    async fn update_task_tags(&self, task_id: i32, tag_ids: &[i32]) -> Result<()> {
+    async fn update_task_tags<T: ConnectionTrait>(
+        db: &T,
+        task_id: i32,
+        tag_ids: &[i32],
+    ) -> Result<()> {
         // 既存のタグ関連付けを削除
         TaskTags::delete_many()
             .filter(task_tags::Column::TaskId.eq(task_id))
-            .exec(&self.db)
+            .exec(db)
             .await?;
 
         // 新しいタグ関連付けを一括作成（N+1問題の回避）
@@ -44,123 +407,938 @@ impl SeaOrmTaskRepository {
                 })
                 .collect();
 
-            TaskTags::insert_many(task_tag_models)
-                .exec(&self.db)
-                .await?;
+            TaskTags::insert_many(task_tag_models).exec(db).await?;
         }
 
         Ok(())
     }
-}
 
-#[async_trait]
-impl TaskRepository for SeaOrmTaskRepository {
-    async fn find_by_id(&self, id: &TaskId) -> Result<Option<TaskAggregate>> {
-        let result = Tasks::find_by_id(id.value())
-            .find_with_related(Tags)
-            .all(&self.db)
+    /// 指定したIDより大きいタスクをID順に1ページ分取得
+    ///
+    /// タグの関連付けはページ内のタスク分だけをまとめて読み込みます。
+    #[instrument(name = "task_repository.find_page", level = "debug", skip(self))]
+    async fn find_page_after(&self, last_id: i32) -> Result<Vec<TaskAggregate>> {
+        let task_models = Tasks::find()
+            .filter(tasks::Column::Id.gt(last_id))
+            .order_by_asc(tasks::Column::Id)
+            .limit(STREAM_PAGE_SIZE)
+            .all(self.db.as_ref())
             .await?;
 
-        if result.is_empty() {
-            return Ok(None);
+        self.assemble(task_models, false).await
+    }
+
+    /// 新規タスクを一括挿入
+    ///
+    /// `INSERT ... RETURNING`で複数行を挿入した場合、返る行の順序はSQLiteでは保証されないため、
+    /// タスクは1件ずつ挿入して割り当てられたIDを確実に対応付けます。
+    /// タグの関連付けと子の行は、全タスク分をまとめて挿入します。
+    async fn insert_batch<T: ConnectionTrait>(
+        &self,
+        db: &T,
+        tasks: Vec<NewTask>,
+    ) -> Result<Vec<TaskAggregate>> {
+        let mut models = Vec::with_capacity(tasks.len());
+        let mut inserted = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            let active_model =
+                self.encrypt_fields(TaskMapper::to_active_model_for_insert(&task))?;
+            let model = active_model.insert(db).await?;
+            inserted.push(task.into_aggregate(TaskId::new(model.id)?));
+            models.push(model);
+        }
+
+        let task_tag_models: Vec<task_tags::ActiveModel> = inserted
+            .iter()
+            .flat_map(|task| {
+                task.tags().iter().map(|tag_id| task_tags::ActiveModel {
+                    task_id: Set(task.id().value()),
+                    tag_id: Set(tag_id.value()),
+                })
+            })
+            .collect();
+        for chunk in task_tag_models.chunks(INSERT_BATCH_SIZE) {
+            TaskTags::insert_many(chunk.to_vec()).exec(db).await?;
         }
 
-        let (task_model, tags) = &result[0];
-        let tag_ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
-        let aggregate = TaskMapper::to_domain(task_model.clone(), tag_ids)?;
-        Ok(Some(aggregate))
+        let task_ids: Vec<i32> = models.iter().map(|model| model.id).collect();
+        let with_ids: Vec<(i32, &TaskAggregate)> =
+            task_ids.iter().copied().zip(&inserted).collect();
+        Self::insert_children(db, &with_ids).await?;
+        let mut children_by_task = Self::load_children(db, Some(&task_ids)).await?;
+
+        let mut saved = Vec::with_capacity(inserted.len());
+        for (model, task) in models.into_iter().zip(&inserted) {
+            let tag_ids: Vec<i32> = task.tags().iter().map(|tag_id| tag_id.value()).collect();
+            let children = children_by_task.remove(&model.id).unwrap_or_default();
+            saved.push(self.to_domain(model, tag_ids, children)?);
+        }
+
+        Ok(saved)
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + TransactionTrait + Send + Sync + 'static> TaskRepository
+    for SeaOrmTaskRepository<C>
+{
+    #[instrument(name = "task_repository.find_by_id", level = "debug", skip_all, fields(id = id.value()))]
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<TaskAggregate>> {
+        self.fetch_one(id.value()).await
     }
 
+    #[instrument(name = "task_repository.find_all", level = "debug", skip_all)]
     async fn find_all(&self) -> Result<Vec<TaskAggregate>> {
-        // find_with_relatedを使って一括取得（N+1問題の回避）
-        let tasks_with_tags = Tasks::find().find_with_related(Tags).all(&self.db).await?;
+        let task_models = Tasks::find()
+            .order_by_asc(tasks::Column::Id)
+            .all(self.db.as_ref())
+            .await?;
+        self.assemble(task_models, true).await
+    }
 
-        let aggregates = tasks_with_tags
-            .into_iter()
-            .map(|(task_model, tags)| {
-                let tag_ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
-                TaskMapper::to_domain(task_model, tag_ids)
-            })
-            .collect::<Result<Vec<_>>>()?;
+    fn find_all_stream(&self) -> TaskStream<'_> {
+        // IDの昇順にページ単位で読み込み、メモリには1ページ分だけを保持する
+        stream::try_unfold(Some(0), move |last_id| async move {
+            let Some(last_id) = last_id else {
+                return Ok::<_, anyhow::Error>(None);
+            };
+
+            let page = self.find_page_after(last_id).await?;
+            if page.is_empty() {
+                return Ok(None);
+            }
 
-        Ok(aggregates)
+            let next_last_id = if (page.len() as u64) < STREAM_PAGE_SIZE {
+                None
+            } else {
+                page.last().map(|task| task.id().value())
+            };
+
+            Ok(Some((stream::iter(page.into_iter().map(Ok)), next_last_id)))
+        })
+        .try_flatten()
+        .boxed()
     }
 
+    #[instrument(
+        name = "task_repository.find_by_specification",
+        level = "debug",
+        skip_all
+    )]
     async fn find_by_specification(
         &self,
         spec: Box<dyn TaskSpecification>,
     ) -> Result<Vec<TaskAggregate>> {
-        // すべてのタスクを取得してメモリ上でフィルタリング
-        // 将来的にはSeaORM用のクエリビルダーで最適化可能
-        let all_tasks = self.find_all().await?;
-        let filtered_tasks: Vec<TaskAggregate> = all_tasks
-            .into_iter()
-            .filter(|task| spec.is_satisfied_by(task))
-            .collect();
+        // SQLに変換できる条件はデータベース側で絞り込み、変換できない残りの条件だけをメモリ上で判定する
+        let exact = spec.criteria();
+        let is_exact = exact.is_some();
+        let Some(criteria) = exact.or_else(|| spec.prefilter()) else {
+            let all_tasks = self.find_all().await?;
+            return Ok(all_tasks
+                .into_iter()
+                .filter(|task| spec.is_satisfied_by(task))
+                .collect());
+        };
+
+        let task_models = Tasks::find()
+            .filter(to_condition(&criteria))
+            .order_by_asc(tasks::Column::Id)
+            .all(self.db.as_ref())
+            .await?;
+        let mut filtered_tasks = self.assemble(task_models, false).await?;
+        if !is_exact {
+            filtered_tasks.retain(|task| spec.is_satisfied_by(task));
+        }
 
         Ok(filtered_tasks)
     }
 
-    async fn save(&self, task: TaskAggregate) -> Result<TaskAggregate> {
-        // タスクの保存（IDが0の場合は新規作成、それ以外は更新）
-        let task_to_save = if task.id().value() == 0 {
-            // 新規作成
-            let active_model = TaskMapper::to_active_model_for_insert(&task);
-            let saved_model = active_model.insert(&self.db).await?;
+    #[instrument(
+        name = "task_repository.count_by_specification",
+        level = "debug",
+        skip_all
+    )]
+    async fn count_by_specification(&self, spec: Box<dyn TaskSpecification>) -> Result<u64> {
+        // SQLに変換できる条件はCOUNTだけで済ませ、タスクを読み込まない
+        match spec.criteria() {
+            Some(criteria) => Ok(Tasks::find()
+                .filter(to_condition(&criteria))
+                .count(self.db.as_ref())
+                .await?),
+            None => Ok(self.find_by_specification(spec).await?.len() as u64),
+        }
+    }
 
-            // タグの関連付けを保存
-            let tag_ids: Vec<i32> = task.tags().iter().map(|tag_id| tag_id.value()).collect();
-            self.update_task_tags(saved_model.id, &tag_ids).await?;
+    #[instrument(
+        name = "task_repository.find_by_due_date_range",
+        level = "debug",
+        skip(self)
+    )]
+    async fn find_by_due_date_range(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<TaskAggregate>> {
+        // 期限日の絞り込みと並び替えはSQLで行う
+        let task_models = Tasks::find()
+            .filter(tasks::Column::DueDate.between(from, to))
+            .order_by_asc(tasks::Column::DueDate)
+            .order_by_asc(tasks::Column::Id)
+            .all(self.db.as_ref())
+            .await?;
+        self.assemble(task_models, false).await
+    }
 
-            // 保存されたタスクを取得して返す
-            let result = Tasks::find_by_id(saved_model.id)
-                .find_with_related(Tags)
-                .all(&self.db)
-                .await?;
-            let (task_model, tags) = &result[0];
-            let tag_ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
-            TaskMapper::to_domain(task_model.clone(), tag_ids)?
-        } else {
-            // 既存IDがある場合は更新
-            self.update(task).await?
-        };
+    #[instrument(name = "task_repository.save", level = "debug", skip_all)]
+    async fn save(&self, task: NewTask) -> Result<TaskAggregate> {
+        // 子の行の保存に失敗した場合にタスクだけが残らないよう、トランザクション内で保存する
+        let txn = self.db.begin().await?;
+        // IDはデータベースが割り当てる（INSERT ... RETURNING）
+        let active_model = self.encrypt_fields(TaskMapper::to_active_model_for_insert(&task))?;
+        let saved_model = active_model.insert(&txn).await?;
+        let task = task.into_aggregate(TaskId::new(saved_model.id)?);
+
+        // タグの関連付けを保存
+        let tag_ids: Vec<i32> = task.tags().iter().map(|tag_id| tag_id.value()).collect();
+        Self::update_task_tags(&txn, saved_model.id, &tag_ids).await?;
+        Self::update_children(&txn, saved_model.id, &task).await?;
+        txn.commit().await?;
+
+        // 保存されたタスクを取得して返す
+        self.fetch_one(saved_model.id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Failed to read back the saved task"))
+    }
+
+    #[instrument(name = "task_repository.save_all", level = "debug", skip_all, fields(count = tasks.len()))]
+    async fn save_all(&self, tasks: Vec<NewTask>) -> Result<Vec<TaskAggregate>> {
+        // 途中で失敗した場合に一部だけ保存されないよう、トランザクション内で挿入する
+        let txn = self.db.begin().await?;
+        let saved = self.insert_batch(&txn, tasks).await?;
+        txn.commit().await?;
 
-        Ok(task_to_save)
+        Ok(saved)
     }
 
+    #[instrument(name = "task_repository.update", level = "debug", skip_all, fields(id = task.id().value()))]
     async fn update(&self, task: TaskAggregate) -> Result<TaskAggregate> {
-        // 既存のタスクを取得
-        let existing = Tasks::find_by_id(task.id().value()).one(&self.db).await?;
+        // 子の行を削除してから作り直すため、途中で失敗しても元の内容が残るようトランザクション内で更新する
+        let txn = self.db.begin().await?;
 
-        if existing.is_none() {
-            anyhow::bail!("タスクID {}は存在しません", task.id().value());
-        }
+        // 既存のタスクを取得
+        let Some(existing) = Tasks::find_by_id(task.id().value()).one(&txn).await? else {
+            anyhow::bail!("Task ID {} does not exist", task.id().value());
+        };
 
         // タスクを更新
-        let active_model = TaskMapper::to_active_model_for_update(&task);
-        let updated_model = active_model.update(&self.db).await?;
+        let active_model = self.encrypt_fields(TaskMapper::to_active_model_for_update(&task))?;
+        let updated_model = active_model.update(&txn).await?;
+        // 自動のタグ付けなど更新日時を変えない変更では、トリガーが進めた更新日時を元に戻す
+        if DateTime::<Utc>::from(existing.updated_at) == *task.updated_at() {
+            restore_updated_at(&txn, updated_model.id, existing.updated_at).await?;
+        }
 
         // タグの関連付けを更新
         let tag_ids: Vec<i32> = task.tags().iter().map(|tag_id| tag_id.value()).collect();
-        self.update_task_tags(updated_model.id, &tag_ids).await?;
+        Self::update_task_tags(&txn, updated_model.id, &tag_ids).await?;
+        Self::update_children(&txn, updated_model.id, &task).await?;
+        txn.commit().await?;
 
         // 更新されたタスクを取得して返す
-        let result = Tasks::find_by_id(updated_model.id)
-            .find_with_related(Tags)
-            .all(&self.db)
-            .await?;
-        let (task_model, tags) = &result[0];
-        let tag_ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
-        let aggregate = TaskMapper::to_domain(task_model.clone(), tag_ids)?;
-
-        Ok(aggregate)
+        self.fetch_one(updated_model.id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Failed to read back the updated task"))
     }
 
+    #[instrument(name = "task_repository.delete", level = "debug", skip_all, fields(id = id.value()))]
     async fn delete(&self, id: &TaskId) -> Result<bool> {
-        let result = Tasks::delete_by_id(id.value()).exec(&self.db).await?;
+        let result = Tasks::delete_by_id(id.value())
+            .exec(self.db.as_ref())
+            .await?;
 
-        // task_tagsは CASCADE DELETE で自動削除される
+        // task_tags・custom_fields・checklist_items・task_links・external_refs・task_status_changesは CASCADE DELETE で自動削除される
 
         Ok(result.rows_affected > 0)
     }
 }
+
+/// タスクの更新日時を指定した値に戻す
+///
+/// `update_tasks_timestamp`トリガーは更新日時を変更しないUPDATEで現在時刻に進めるため、
+/// 更新日時を変えない書き込みの後に別のUPDATEで書き戻します（値が変わるためトリガーは動作しません）。
+pub(super) async fn restore_updated_at<C: ConnectionTrait>(
+    db: &C,
+    task_id: i32,
+    updated_at: DateTimeWithTimeZone,
+) -> Result<()> {
+    Tasks::update_many()
+        .col_expr(tasks::Column::UpdatedAt, Expr::value(updated_at))
+        .filter(tasks::Column::Id.eq(task_id))
+        .exec(db)
+        .await?;
+    Ok(())
+}
+
+/// 検索条件をSQLの条件に変換
+///
+/// 期限日の条件には`IS NOT NULL`を含めるため、NOTで反転しても期限日のないタスクに一致します
+/// （メモリ上の判定と同じ結果になります）。
+fn to_condition(criteria: &TaskCriteria) -> Condition {
+    match criteria {
+        TaskCriteria::Id(id) => Condition::all().add(tasks::Column::Id.eq(id.value())),
+        TaskCriteria::Status(status) => {
+            Condition::all().add(tasks::Column::Status.eq(TaskMapper::status_to_string(status)))
+        }
+        TaskCriteria::Priority(priority) => Condition::all()
+            .add(tasks::Column::Priority.eq(TaskMapper::priority_to_string(priority))),
+        TaskCriteria::Tag(tag_id) => Condition::all().add(
+            tasks::Column::Id.in_subquery(
+                Query::select()
+                    .column(task_tags::Column::TaskId)
+                    .from(task_tags::Entity)
+                    .and_where(task_tags::Column::TagId.eq(tag_id.value()))
+                    .to_owned(),
+            ),
+        ),
+        TaskCriteria::DueBefore(date) => Condition::all()
+            .add(tasks::Column::DueDate.is_not_null())
+            .add(tasks::Column::DueDate.lt(*date)),
+        TaskCriteria::DueAfter(date) => Condition::all()
+            .add(tasks::Column::DueDate.is_not_null())
+            .add(tasks::Column::DueDate.gt(*date)),
+        TaskCriteria::DueBetween(from, to) => Condition::all()
+            .add(tasks::Column::DueDate.is_not_null())
+            .add(tasks::Column::DueDate.between(*from, *to)),
+        TaskCriteria::HasDueDate => Condition::all().add(tasks::Column::DueDate.is_not_null()),
+        TaskCriteria::SnoozedAfter(date) => Condition::all()
+            .add(tasks::Column::SnoozedUntil.is_not_null())
+            .add(tasks::Column::SnoozedUntil.gt(*date)),
+        TaskCriteria::Context(context) => Condition::all()
+            .add(tasks::Column::Context.is_not_null())
+            .add(tasks::Column::Context.eq(context.value())),
+        TaskCriteria::HasContext => Condition::all().add(tasks::Column::Context.is_not_null()),
+        TaskCriteria::Assignee(assignee) => Condition::all()
+            .add(tasks::Column::Assignee.is_not_null())
+            .add(tasks::Column::Assignee.eq(assignee.value())),
+        TaskCriteria::CreatedAfter(at) => {
+            Condition::all().add(timestamp_since(tasks::Column::CreatedAt, at))
+        }
+        TaskCriteria::UpdatedSince(at) => {
+            Condition::all().add(timestamp_since(tasks::Column::UpdatedAt, at))
+        }
+        TaskCriteria::And(left, right) => Condition::all()
+            .add(to_condition(left))
+            .add(to_condition(right)),
+        TaskCriteria::Or(left, right) => Condition::any()
+            .add(to_condition(left))
+            .add(to_condition(right)),
+        TaskCriteria::Not(inner) => to_condition(inner).not(),
+    }
+}
+
+/// 日時の列が指定日時以降であることを表す条件
+///
+/// 日時は秒単位で保存されるため、指定日時の端数は切り上げて比較します。
+/// 保存形式（`CURRENT_TIMESTAMP`かRFC 3339か）によらず比較できるよう、`datetime()`で正規化します。
+fn timestamp_since(column: tasks::Column, at: &DateTime<Utc>) -> SimpleExpr {
+    let seconds = at.timestamp() + i64::from(at.timestamp_subsec_nanos() > 0);
+    let at = DateTime::from_timestamp(seconds, 0).unwrap_or(*at);
+    Expr::cust_with_exprs(
+        "datetime(?) >= datetime(?)",
+        [
+            Expr::col((tasks::Entity, column)).into(),
+            Expr::value(at.format("%Y-%m-%d %H:%M:%S").to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::{FixedClock, SystemClock};
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::{
+        domain::{
+            tag::{
+                aggregate::NewTag,
+                repository::TagRepository,
+                value_objects::{TagDescription, TagId, TagName},
+            },
+            task::{
+                builder::TaskBuilder,
+                specification::{
+                    NotSpecification, SearchField, TaskByKeyword, TaskByTag, TaskCreatedAfter,
+                    TaskDueBefore, TaskDueBetween, TaskHasContext, TaskHasDueDate, TaskInContext,
+                    TaskSnoozed, TaskUpdatedSince,
+                },
+                value_objects::{
+                    ChecklistItem, CustomField, DueDate, ExternalRef, GitLink, Status, TaskContext,
+                    TaskTitle,
+                },
+            },
+        },
+        interface::persistence::{
+            conformance::{task_repository_conformance_tests, with_checklist},
+            sea_orm::SeaOrmTagRepository,
+        },
+    };
+    use chrono::Utc;
+
+    task_repository_conformance_tests!(async {
+        let (dir, db) = setup_db().await;
+        (dir, SeaOrmTaskRepository::new(db))
+    });
+
+    fn new_task(title: &str, tags: Vec<TagId>) -> NewTask {
+        TaskBuilder::new(TaskTitle::new(title).unwrap())
+            .tags(tags)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_save_all_inserts_tasks_with_tags() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let tag_repo = SeaOrmTagRepository::new(db.clone());
+        let tag = tag_repo
+            .save(NewTag::new(
+                TagName::new("import").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let repo = SeaOrmTaskRepository::new(db);
+        // 分割挿入の境界をまたぐ件数
+        let tasks: Vec<NewTask> = (0..INSERT_BATCH_SIZE + 5)
+            .map(|i| new_task(&format!("タスク{}", i), vec![*tag.id()]))
+            .collect();
+
+        // Act
+        let saved = repo.save_all(tasks).await.unwrap();
+
+        // Assert
+        assert_eq!(saved.len(), INSERT_BATCH_SIZE + 5);
+        assert_eq!(saved[0].title().value(), "タスク0");
+        assert_eq!(
+            saved[INSERT_BATCH_SIZE + 4].title().value(),
+            format!("タスク{}", INSERT_BATCH_SIZE + 4)
+        );
+        let found = repo
+            .find_by_id(saved[INSERT_BATCH_SIZE].id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            found.title().value(),
+            format!("タスク{}", INSERT_BATCH_SIZE)
+        );
+        assert_eq!(found.tags(), &vec![*tag.id()]);
+        assert_eq!(repo.find_all().await.unwrap().len(), INSERT_BATCH_SIZE + 5);
+    }
+
+    #[tokio::test]
+    async fn test_save_all_keeps_tags_with_their_task() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let tag_repo = SeaOrmTagRepository::new(db.clone());
+        let mut tags = Vec::new();
+        for name in ["a", "b", "c"] {
+            tags.push(
+                tag_repo
+                    .save(NewTag::new(
+                        TagName::new(name).unwrap(),
+                        TagDescription::new("").unwrap(),
+                    ))
+                    .await
+                    .unwrap(),
+            );
+        }
+        let repo = SeaOrmTaskRepository::new(db);
+        let tasks: Vec<NewTask> = tags
+            .iter()
+            .map(|tag| new_task(tag.name().value(), vec![*tag.id()]))
+            .collect();
+
+        // Act
+        let saved = repo.save_all(tasks).await.unwrap();
+
+        // Assert: データベースから読み直しても、各タスクにはタイトルと同じ名前のタグが付いている
+        for task in repo.find_all().await.unwrap() {
+            let tag = tags
+                .iter()
+                .find(|tag| tag.name().value() == task.title().value())
+                .unwrap();
+            assert_eq!(task.tags(), &vec![*tag.id()]);
+        }
+        let titles: Vec<&str> = saved.iter().map(|task| task.title().value()).collect();
+        assert_eq!(titles, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_find_all_assembles_tags_per_task() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let tag_repo = SeaOrmTagRepository::new(db.clone());
+        let mut tag_ids = Vec::new();
+        for name in ["work", "home", "urgent"] {
+            let tag = tag_repo
+                .save(NewTag::new(
+                    TagName::new(name).unwrap(),
+                    TagDescription::new("").unwrap(),
+                ))
+                .await
+                .unwrap();
+            tag_ids.push(*tag.id());
+        }
+        let repo = SeaOrmTaskRepository::new(db);
+        let both = repo
+            .save(new_task("両方", vec![tag_ids[2], tag_ids[0]]))
+            .await
+            .unwrap();
+        let none = repo.save(new_task("タグなし", vec![])).await.unwrap();
+        let one = repo.save(new_task("1つ", vec![tag_ids[1]])).await.unwrap();
+
+        // Act
+        let all = repo.find_all().await.unwrap();
+
+        // Assert: タスクはID順、タグはタグID順に並ぶ
+        let ids: Vec<_> = all.iter().map(|task| *task.id()).collect();
+        assert_eq!(ids, vec![*both.id(), *none.id(), *one.id()]);
+        assert_eq!(all[0].tags(), &vec![tag_ids[0], tag_ids[2]]);
+        assert!(all[1].tags().is_empty());
+        assert_eq!(all[2].tags(), &vec![tag_ids[1]]);
+        let found = repo.find_by_id(none.id()).await.unwrap().unwrap();
+        assert!(found.tags().is_empty());
+        assert!(
+            repo.find_by_id(&TaskId::new(999).unwrap())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_all_stream_reads_all_pages() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let tag_repo = SeaOrmTagRepository::new(db.clone());
+        let tag = tag_repo
+            .save(NewTag::new(
+                TagName::new("stream").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let repo = SeaOrmTaskRepository::new(db);
+        let count = STREAM_PAGE_SIZE as usize * 2 + 3;
+        let tasks: Vec<NewTask> = (0..count)
+            .map(|i| {
+                let tags = if i % 2 == 0 { vec![*tag.id()] } else { vec![] };
+                new_task(&format!("タスク{}", i), tags)
+            })
+            .collect();
+        repo.save_all(tasks).await.unwrap();
+
+        // Act
+        let streamed: Vec<TaskAggregate> = repo.find_all_stream().try_collect().await.unwrap();
+
+        // Assert
+        assert_eq!(streamed.len(), count);
+        assert_eq!(streamed[0].title().value(), "タスク0");
+        assert_eq!(
+            streamed[count - 1].title().value(),
+            format!("タスク{}", count - 1)
+        );
+        assert_eq!(streamed[STREAM_PAGE_SIZE as usize].tags(), &vec![*tag.id()]);
+        assert!(streamed[STREAM_PAGE_SIZE as usize + 1].tags().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_all_stream_empty() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+
+        // Act
+        let streamed: Vec<TaskAggregate> = repo.find_all_stream().try_collect().await.unwrap();
+
+        // Assert
+        assert!(streamed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_due_date_range() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+        let date = |day| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+        for (title, due) in [
+            ("範囲外", Some(date(1))),
+            ("後", Some(date(20))),
+            ("期限なし", None),
+            ("先", Some(date(5))),
+            ("終端", Some(date(31))),
+        ] {
+            let mut task = repo.save(new_task(title, vec![])).await.unwrap();
+            task.change_due_date(
+                due.map(|d| DueDate::new(d).unwrap()),
+                &SystemClock::default(),
+            )
+            .unwrap();
+            repo.update(task).await.unwrap();
+        }
+
+        // Act
+        let found = repo
+            .find_by_due_date_range(date(2), date(31))
+            .await
+            .unwrap();
+
+        // Assert: 期限日の昇順で、終了日も含む
+        let titles: Vec<&str> = found.iter().map(|t| t.title().value()).collect();
+        assert_eq!(titles, vec!["先", "後", "終端"]);
+    }
+
+    #[tokio::test]
+    async fn test_find_by_specification_matches_in_memory_evaluation() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let tag_repo = SeaOrmTagRepository::new(db.clone());
+        let tag = tag_repo
+            .save(NewTag::new(
+                TagName::new("仕事").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let repo = SeaOrmTaskRepository::new(db);
+        let date = |day| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+        for (title, due, tags) in [
+            ("早い", Some(date(1)), vec![*tag.id()]),
+            ("遅い", Some(date(20)), vec![*tag.id()]),
+            ("期限なし", None, vec![*tag.id()]),
+            ("タグなし", Some(date(25)), vec![]),
+        ] {
+            let mut task = repo.save(new_task(title, tags)).await.unwrap();
+            task.change_due_date(
+                due.map(|d| DueDate::new(d).unwrap()),
+                &SystemClock::default(),
+            )
+            .unwrap();
+            if title == "遅い" {
+                task.snooze(date(15), &FixedClock::on(date(5))).unwrap();
+            }
+            let context = match title {
+                "早い" => Some("@home"),
+                "タグなし" => Some("@office"),
+                _ => None,
+            };
+            task.change_context(
+                context.map(|c| TaskContext::new(c).unwrap()),
+                &SystemClock::default(),
+            )
+            .unwrap();
+            repo.update(task).await.unwrap();
+        }
+        let all_tasks = repo.find_all().await.unwrap();
+        let specs: Vec<fn(TagId) -> Box<dyn TaskSpecification>> = vec![
+            // NOTで反転した期限日の条件は、期限日のないタスクにも一致する
+            |tag_id| {
+                Box::new(TaskByTag::new(tag_id)).and(Box::new(NotSpecification::new(Box::new(
+                    TaskDueBefore::new(NaiveDate::from_ymd_opt(2026, 10, 10).unwrap()),
+                ))))
+            },
+            |_| {
+                Box::new(TaskDueBetween::new(
+                    NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+                    NaiveDate::from_ymd_opt(2026, 10, 20).unwrap(),
+                ))
+            },
+            |_| Box::new(NotSpecification::new(Box::new(TaskHasDueDate))),
+            |_| {
+                Box::new(TaskSnoozed::new(
+                    NaiveDate::from_ymd_opt(2026, 10, 10).unwrap(),
+                ))
+            },
+            |_| {
+                Box::new(NotSpecification::new(Box::new(TaskSnoozed::new(
+                    NaiveDate::from_ymd_opt(2026, 10, 15).unwrap(),
+                ))))
+            },
+            // NOTで反転したコンテキストの条件は、コンテキストのないタスクにも一致する
+            |_| {
+                Box::new(NotSpecification::new(Box::new(TaskInContext::new(
+                    TaskContext::new("@home").unwrap(),
+                ))))
+            },
+            |_| {
+                Box::new(TaskInContext::new(TaskContext::new("@office").unwrap()))
+                    .or(Box::new(NotSpecification::new(Box::new(TaskHasContext))))
+            },
+            |_| {
+                Box::new(TaskCreatedAfter::new(
+                    Utc::now() - chrono::Duration::hours(1),
+                ))
+            },
+            |_| {
+                Box::new(TaskUpdatedSince::new(
+                    Utc::now() + chrono::Duration::hours(1),
+                ))
+            },
+            // キーワードは変換できないため、タグで絞り込んだ後にメモリ上で判定する
+            |tag_id| {
+                Box::new(TaskByTag::new(tag_id)).and(Box::new(TaskByKeyword::new(
+                    vec!["遅".to_string()],
+                    SearchField::Title,
+                )))
+            },
+        ];
+
+        for spec in specs {
+            let expected: Vec<TaskId> = all_tasks
+                .iter()
+                .filter(|task| spec(*tag.id()).is_satisfied_by(task))
+                .map(|task| *task.id())
+                .collect();
+
+            // Act
+            let found = repo.find_by_specification(spec(*tag.id())).await.unwrap();
+
+            // Assert
+            let ids: Vec<TaskId> = found.iter().map(|task| *task.id()).collect();
+            assert_eq!(ids, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_fields_round_trip() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+        let mut task = new_task("見積もり", vec![]);
+        task.set_custom_field(CustomField::new("client", "ACME").unwrap());
+        task.set_custom_field(CustomField::new("estimate", "3h").unwrap());
+
+        // Act
+        let saved = repo.save(task).await.unwrap();
+        let mut edited = saved.clone();
+        edited
+            .remove_custom_field("estimate", &SystemClock::default())
+            .unwrap();
+        edited
+            .set_custom_field(
+                CustomField::new("client", "Globex").unwrap(),
+                &SystemClock::default(),
+            )
+            .unwrap();
+        let updated = repo.update(edited).await.unwrap();
+        let mut batch_task = new_task("一括", vec![]);
+        batch_task.set_custom_field(CustomField::new("client", "Initech").unwrap());
+        let batch = repo.save_all(vec![batch_task]).await.unwrap();
+
+        // Assert
+        let fields = |task: &TaskAggregate| -> Vec<String> {
+            task.custom_fields().iter().map(|f| f.to_string()).collect()
+        };
+        assert_eq!(fields(&saved), vec!["client=ACME", "estimate=3h"]);
+        assert_eq!(fields(&updated), vec!["client=Globex"]);
+        assert_eq!(fields(&batch[0]), vec!["client=Initech"]);
+        let all = repo.find_all().await.unwrap();
+        assert_eq!(fields(&all[0]), vec!["client=Globex"]);
+        assert_eq!(fields(&all[1]), vec!["client=Initech"]);
+    }
+
+    #[tokio::test]
+    async fn test_checklist_round_trip_keeps_order() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+        let task = with_checklist(
+            new_task("リリース", vec![]),
+            ["write tests", "update docs", "tag release"]
+                .into_iter()
+                .map(|text| ChecklistItem::new(text, false).unwrap())
+                .collect(),
+        );
+        let saved = repo.save(task.clone()).await.unwrap();
+
+        // Act
+        let mut edited = saved.clone();
+        edited
+            .toggle_checklist_item(2, &SystemClock::default())
+            .unwrap();
+        edited
+            .remove_checklist_item(1, &SystemClock::default())
+            .unwrap();
+        repo.update(edited).await.unwrap();
+        let found = repo.find_by_id(saved.id()).await.unwrap().unwrap();
+        let batch = repo.save_all(vec![task]).await.unwrap();
+
+        // Assert
+        let items = |task: &TaskAggregate| -> Vec<String> {
+            task.checklist().iter().map(|i| i.to_string()).collect()
+        };
+        assert_eq!(items(&found), vec!["[x] update docs", "[ ] tag release"]);
+        assert_eq!(items(&batch[0]), items(&saved));
+        assert_eq!(repo.find_all().await.unwrap()[0].checklist().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_git_links_round_trip() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+        let link = |branch: Option<&str>, commit: &str| {
+            GitLink::new("/src/yaru", branch.map(str::to_string), commit, Utc::now()).unwrap()
+        };
+        let mut saved = repo.save(new_task("ログイン画面", vec![])).await.unwrap();
+        saved
+            .link_git(
+                link(Some("feature/login"), "3f2a9c1d"),
+                &SystemClock::default(),
+            )
+            .unwrap();
+        let saved = repo.update(saved).await.unwrap();
+
+        // Act
+        let mut edited = saved.clone();
+        edited
+            .link_git(link(None, "0b8e7f6a"), &SystemClock::default())
+            .unwrap();
+        repo.update(edited).await.unwrap();
+        let found = repo.find_by_id(saved.id()).await.unwrap().unwrap();
+
+        // Assert
+        let links: Vec<String> = found.git_links().iter().map(|l| l.to_string()).collect();
+        assert_eq!(
+            links,
+            vec!["/src/yaru (feature/login) 3f2a9c1", "/src/yaru 0b8e7f6"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_external_refs_round_trip() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+        let remote_updated_at = Utc::now();
+        let mut task = new_task("ログイン画面", vec![]);
+        task.add_external_ref(
+            ExternalRef::new(
+                "github",
+                "acme/app#7",
+                Some("https://github.com/acme/app/issues/7".to_string()),
+                remote_updated_at,
+                Status::Pending,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let saved = repo.save(task).await.unwrap();
+
+        // Act
+        let mut edited = saved.clone();
+        let later = remote_updated_at + chrono::Duration::minutes(5);
+        edited
+            .record_external_sync("github", "acme/app#7", later, Status::Completed)
+            .unwrap();
+        repo.update(edited).await.unwrap();
+        let found = repo.find_by_id(saved.id()).await.unwrap().unwrap();
+
+        // Assert
+        let external_ref = found.external_ref("github", "acme/app#7").unwrap();
+        assert_eq!(
+            external_ref.remote_updated_at().timestamp(),
+            later.timestamp()
+        );
+        assert_eq!(external_ref.synced_status(), Status::Completed);
+        assert_eq!(
+            external_ref.url(),
+            Some("https://github.com/acme/app/issues/7")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_all_rolls_back_on_error() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+        // 存在しないタグIDは外部キー制約違反になる
+        let missing_tag = TagId::new(999).unwrap();
+        let tasks = vec![
+            new_task("タスク1", vec![]),
+            new_task("タスク2", vec![missing_tag]),
+        ];
+
+        // Act
+        let result = repo.save_all(tasks).await;
+
+        // Assert
+        assert!(result.is_err());
+        assert!(repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_keeps_updated_at_for_automatic_tag() {
+        // Arrange: 更新日時を過去にして、トリガーで進んだかどうかを判別できるようにする
+        let (_dir, db) = setup_db().await;
+        let tag = SeaOrmTagRepository::new(db.clone())
+            .save(NewTag::new(
+                TagName::new("overdue").unwrap(),
+                TagDescription::new("").unwrap(),
+            ))
+            .await
+            .unwrap();
+        let repo = SeaOrmTaskRepository::new(db.clone());
+        let saved = repo.save(new_task("期限切れ", vec![])).await.unwrap();
+        let past = "2026-01-05T09:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        restore_updated_at(&db, saved.id().value(), past.into())
+            .await
+            .unwrap();
+        let mut task = repo.find_by_id(saved.id()).await.unwrap().unwrap();
+
+        // Act
+        task.set_automatic_tag(*tag.id(), true);
+        repo.update(task).await.unwrap();
+
+        // Assert
+        let found = repo.find_by_id(saved.id()).await.unwrap().unwrap();
+        assert_eq!(found.tags(), &vec![*tag.id()]);
+        assert_eq!(found.updated_at(), &past);
+    }
+
+    #[tokio::test]
+    async fn test_update_failure_keeps_task_and_children() {
+        // Arrange: 外部の項目は1つのタスクにのみ対応するため、同じ項目を対応させると挿入に失敗する
+        let (_dir, db) = setup_db().await;
+        let repo = SeaOrmTaskRepository::new(db);
+        let clock = SystemClock::default();
+        let external_ref =
+            || ExternalRef::new("github", "acme/app#7", None, Utc::now(), Status::Pending).unwrap();
+        let mut other = new_task("他のタスク", vec![]);
+        other.add_external_ref(external_ref()).unwrap();
+        repo.save(other).await.unwrap();
+        let mut task = repo.save(new_task("変更前", vec![])).await.unwrap();
+        task.set_custom_field(CustomField::new("client", "ACME").unwrap(), &clock)
+            .unwrap();
+        task.add_checklist_item(ChecklistItem::new("下書き", false).unwrap(), &clock)
+            .unwrap();
+        let task = repo.update(task).await.unwrap();
+
+        // Act
+        let mut edited = task.clone();
+        edited
+            .change_title(TaskTitle::new("変更後").unwrap(), &clock)
+            .unwrap();
+        edited.add_external_ref(external_ref(), &clock).unwrap();
+        let result = repo.update(edited).await;
+
+        // Assert
+        assert!(result.is_err());
+        let found = repo.find_by_id(task.id()).await.unwrap().unwrap();
+        assert_eq!(found.title().value(), "変更前");
+        assert_eq!(found.custom_fields(), task.custom_fields());
+        assert_eq!(found.checklist(), task.checklist());
+        assert!(found.external_refs().is_empty());
+    }
+}
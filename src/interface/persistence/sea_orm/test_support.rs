@@ -0,0 +1,23 @@
+//! テスト用のデータベース
+//!
+//! SeaORMの実装やユースケースのテストで使う、一時ファイル上のSQLiteデータベースを作成します。
+//! 一時ディレクトリは削除されないよう、テストの終了まで保持してください。
+
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{Database, DatabaseConnection};
+use tempfile::TempDir;
+
+/// 一時ファイル上にマイグレーション済みのデータベースを作成
+pub async fn setup_db() -> (TempDir, DatabaseConnection) {
+    let (dir, _, db) = setup_empty_db().await;
+    Migrator::up(&db, None).await.unwrap();
+    (dir, db)
+}
+
+/// 一時ファイル上にマイグレーション前のデータベースを作成し、接続先のURLとともに返す
+pub async fn setup_empty_db() -> (TempDir, String, DatabaseConnection) {
+    let dir = tempfile::tempdir().unwrap();
+    let url = format!("sqlite://{}?mode=rwc", dir.path().join("test.db").display());
+    let db = Database::connect(&url).await.unwrap();
+    (dir, url, db)
+}
@@ -0,0 +1,138 @@
+use crate::{
+    domain::{
+        task::value_objects::TaskId,
+        time_entry::{
+            aggregate::{NewTimeEntry, TimeEntryAggregate},
+            repository::TimeEntryRepository,
+        },
+    },
+    interface::persistence::sea_orm::mapper::TimeEntryMapper,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use entity::{prelude::TimeEntries, time_entries};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use std::sync::Arc;
+use tracing::instrument;
+
+/// SeaORM実装のTimeEntryRepository
+///
+/// 接続`C`には通常の`DatabaseConnection`のほか、Unit of Work用の`DatabaseTransaction`も使用できます。
+pub struct SeaOrmTimeEntryRepository<C = DatabaseConnection> {
+    db: Arc<C>,
+}
+
+impl SeaOrmTimeEntryRepository {
+    /// 新しいSeaOrmTimeEntryRepositoryを作成
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send + Sync + 'static> TimeEntryRepository
+    for SeaOrmTimeEntryRepository<C>
+{
+    #[instrument(name = "time_entry_repository.save", level = "debug", skip_all)]
+    async fn save(&self, entry: NewTimeEntry) -> Result<TimeEntryAggregate> {
+        let active_model = TimeEntryMapper::to_active_model_for_insert(&entry);
+        let saved_model = active_model.insert(self.db.as_ref()).await?;
+
+        TimeEntryMapper::to_domain(saved_model)
+    }
+
+    #[instrument(name = "time_entry_repository.find_by_task", level = "debug", skip_all, fields(task_id = task_id.value()))]
+    async fn find_by_task(&self, task_id: &TaskId) -> Result<Vec<TimeEntryAggregate>> {
+        TimeEntries::find()
+            .filter(time_entries::Column::TaskId.eq(task_id.value()))
+            .order_by_asc(time_entries::Column::StartedAt)
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(TimeEntryMapper::to_domain)
+            .collect()
+    }
+
+    #[instrument(name = "time_entry_repository.find_all", level = "debug", skip_all)]
+    async fn find_all(&self) -> Result<Vec<TimeEntryAggregate>> {
+        TimeEntries::find()
+            .order_by_asc(time_entries::Column::StartedAt)
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(TimeEntryMapper::to_domain)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::{
+        domain::task::{
+            builder::TaskBuilder,
+            repository::TaskRepository,
+            value_objects::{Priority, Status, TaskTitle},
+        },
+        interface::persistence::sea_orm::SeaOrmTaskRepository,
+    };
+    use chrono::{TimeDelta, Utc};
+
+    #[tokio::test]
+    async fn test_save_and_find_by_task() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let task_repo = SeaOrmTaskRepository::new(db.clone());
+        let task = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("資料作成").unwrap())
+                    .status(Status::InProgress)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let repo = SeaOrmTimeEntryRepository::new(db);
+        let started_at = Utc::now() - TimeDelta::minutes(25);
+        let entry = NewTimeEntry::new(*task.id(), started_at, Utc::now()).unwrap();
+
+        // Act
+        let saved = repo.save(entry).await.unwrap();
+        let entries = repo.find_by_task(task.id()).await.unwrap();
+        let all = repo.find_all().await.unwrap();
+
+        // Assert
+        assert!(saved.id().value() > 0);
+        assert_eq!(entries, vec![saved]);
+        assert_eq!(all, entries);
+    }
+
+    #[tokio::test]
+    async fn test_entries_are_deleted_with_task() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let task_repo = SeaOrmTaskRepository::new(db.clone());
+        let task = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("削除するタスク").unwrap())
+                    .priority(Priority::Low)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let repo = SeaOrmTimeEntryRepository::new(db);
+        let now = Utc::now();
+        repo.save(NewTimeEntry::new(*task.id(), now, now).unwrap())
+            .await
+            .unwrap();
+
+        // Act
+        task_repo.delete(task.id()).await.unwrap();
+
+        // Assert
+        assert!(repo.find_by_task(task.id()).await.unwrap().is_empty());
+    }
+}
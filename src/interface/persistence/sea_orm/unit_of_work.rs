@@ -0,0 +1,177 @@
+use crate::{
+    domain::{
+        tag::repository::TagRepository,
+        task::repository::TaskRepository,
+        unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+    },
+    interface::persistence::{
+        encryption::FieldCipher,
+        sea_orm::{SeaOrmTagRepository, SeaOrmTaskRepository},
+    },
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use std::sync::Arc;
+
+/// SeaORM実装のUnitOfWorkFactory
+///
+/// `begin()`ごとにデータベーストランザクションを開始します。
+pub struct SeaOrmUnitOfWorkFactory {
+    db: DatabaseConnection,
+    cipher: Option<Arc<FieldCipher>>,
+}
+
+impl SeaOrmUnitOfWorkFactory {
+    /// 新しいSeaOrmUnitOfWorkFactoryを作成
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db, cipher: None }
+    }
+
+    /// トランザクション内のタスクリポジトリが使用する暗号化鍵を設定
+    pub fn with_cipher(mut self, cipher: Option<Arc<FieldCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for SeaOrmUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .context("Failed to begin transaction")?;
+
+        Ok(Box::new(SeaOrmUnitOfWork {
+            txn: Arc::new(txn),
+            cipher: self.cipher.clone(),
+        }))
+    }
+}
+
+/// SeaORM実装のUnitOfWork
+///
+/// リポジトリは同じトランザクションを共有します。
+/// コミット前に、取得したリポジトリをすべて破棄しておく必要があります。
+pub struct SeaOrmUnitOfWork {
+    txn: Arc<DatabaseTransaction>,
+    cipher: Option<Arc<FieldCipher>>,
+}
+
+impl SeaOrmUnitOfWork {
+    /// リポジトリとの共有を解除してトランザクションを取り出す
+    fn into_transaction(self) -> Result<DatabaseTransaction> {
+        Arc::try_unwrap(self.txn)
+            .map_err(|_| anyhow::anyhow!("Transaction is still in use by a repository"))
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for SeaOrmUnitOfWork {
+    fn task_repository(&self) -> Arc<dyn TaskRepository> {
+        Arc::new(
+            SeaOrmTaskRepository::with_connection(self.txn.clone())
+                .with_cipher(self.cipher.clone()),
+        )
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        Arc::new(SeaOrmTagRepository::with_connection(self.txn.clone()))
+    }
+
+    async fn commit(self: Box<Self>) -> Result<()> {
+        tracing::debug!("committing transaction");
+        self.into_transaction()?
+            .commit()
+            .await
+            .context("Failed to commit transaction")
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<()> {
+        tracing::debug!("rolling back transaction");
+        match self.into_transaction() {
+            Ok(txn) => txn
+                .rollback()
+                .await
+                .context("Failed to roll back transaction"),
+            // リポジトリが残っている場合も、最後の参照が破棄された時点でロールバックされる
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        tag::{
+            aggregate::NewTag,
+            value_objects::{TagDescription, TagName},
+        },
+        unit_of_work::commit_or_rollback,
+    };
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+
+    fn new_tag(name: &str) -> NewTag {
+        NewTag::new(
+            TagName::new(name).unwrap(),
+            TagDescription::new("").unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_commit() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let factory = SeaOrmUnitOfWorkFactory::new(db.clone());
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let result = uow.tag_repository().save(new_tag("仕事")).await.map(|_| ());
+        commit_or_rollback(uow, result).await.unwrap();
+
+        // Assert
+        let tags = SeaOrmTagRepository::new(db).find_all().await.unwrap();
+        assert_eq!(tags.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_rollback_on_error() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let factory = SeaOrmUnitOfWorkFactory::new(db.clone());
+
+        // Act: タグ保存後にエラーが発生した場合
+        let uow = factory.begin().await.unwrap();
+        let tag_repo = uow.tag_repository();
+        let result: Result<()> = async {
+            tag_repo.save(new_tag("仕事")).await?;
+            anyhow::bail!("failure after save")
+        }
+        .await;
+        drop(tag_repo);
+        let result = commit_or_rollback(uow, result).await;
+
+        // Assert: 保存したタグも取り消される
+        assert_eq!(result.unwrap_err().to_string(), "failure after save");
+        let tags = SeaOrmTagRepository::new(db).find_all().await.unwrap();
+        assert!(tags.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unit_of_work_commit_fails_while_repository_in_use() {
+        // Arrange
+        let (_dir, db) = setup_db().await;
+        let factory = SeaOrmUnitOfWorkFactory::new(db);
+
+        // Act
+        let uow = factory.begin().await.unwrap();
+        let _tag_repo = uow.tag_repository();
+        let result = uow.commit().await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
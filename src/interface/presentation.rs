@@ -1,23 +1,76 @@
 use crate::{
-    application::dto::{stats_dto::StatsDTO, tag_dto::TagDTO, task_dto::TaskDTO},
-    interface::cli::display::{
-        create_rich_stats_display, create_tag_detail_table, create_tag_table,
-        create_task_detail_table, create_task_table,
+    application::{
+        dto::{
+            agenda_dto::AgendaDTO, estimate_accuracy_dto::EstimateAccuracyDTO,
+            flow_stats_dto::FlowStatsDTO, stats_dto::StatsDTO, summary_dto::TaskSummaryDTO,
+            tag_dto::TagDTO, task_detail_dto::TaskDetailDTO, task_dto::TaskDTO,
+            task_group_dto::TaskGroupDTO, workload_dto::WorkloadDTO,
+        },
+        use_cases::task::add_task::AddTaskError,
+    },
+    domain::task::value_objects::Estimate,
+    interface::{
+        cli::{
+            display::{
+                TaskColumn, TaskListFormat, TaskListView, TaskTemplate, apply_highlights,
+                create_accuracy_table, create_db_status_table, create_flow_stats_display,
+                create_rich_stats_display, create_tag_detail_table, create_tag_table,
+                create_task_detail_table, create_task_related_table, create_task_table,
+                create_workload_table,
+                format::{format_checklist_items, format_checklist_progress, format_date},
+                markdown::render_markdown,
+                theme::Theme,
+            },
+            doctor_handler::{CheckStatus, DoctorCheck},
+        },
+        i18n::Msg,
+        persistence::sea_orm::DatabaseStatus,
     },
 };
 use anyhow::Result;
-use inquire::Confirm;
+use console::{Color, Style};
+use inquire::{Confirm, InquireError, Text};
+use serde::Serialize;
+use std::{
+    fmt::Display,
+    io::{IsTerminal, stderr, stdout},
+    sync::Mutex,
+};
 
 /// プレゼンテーション層の抽象トレイト
 ///
 /// CLI/TUIの両方に対応できるよう、プレゼンテーションロジックを抽象化します。
 pub trait Presenter: Send + Sync {
     /// タスク一覧を表示
-    fn present_task_list(&self, tasks: &[TaskDTO]) -> Result<()>;
+    fn present_task_list(&self, tasks: &[TaskDTO], view: &TaskListView) -> Result<()>;
+
+    /// タスク一覧をテンプレートに従って1タスク1行で表示
+    fn present_task_lines(&self, tasks: &[TaskDTO], template: &TaskTemplate) -> Result<()>;
+
+    /// グループ化されたタスク一覧を表示
+    fn present_task_groups(&self, groups: &[TaskGroupDTO], view: &TaskListView) -> Result<()>;
+
+    /// 今日の予定（期限切れ・今日期限・今後の期限・進行中）をセクションごとに表示
+    fn present_agenda(&self, agenda: &AgendaDTO) -> Result<()>;
+
+    /// 日ごとの作業量（見積もり時間の合計）を表示
+    fn present_workload(&self, workload: &WorkloadDTO) -> Result<()>;
 
     /// タスク詳細を表示
     fn present_task_detail(&self, task: &TaskDTO) -> Result<()>;
 
+    /// 関連情報付きでタスク詳細を表示
+    ///
+    /// 説明はMarkdownとして整形して表示します。`raw_description`の場合は入力したままの文字列を表示します。
+    fn present_task_detail_with_related(
+        &self,
+        detail: &TaskDetailDTO,
+        raw_description: bool,
+    ) -> Result<()>;
+
+    /// タスクのチェックリストを進捗付きで表示
+    fn present_checklist(&self, task: &TaskDTO) -> Result<()>;
+
     /// タグ一覧を表示
     fn present_tag_list(&self, tags: &[TagDTO]) -> Result<()>;
 
@@ -27,58 +80,477 @@ pub trait Presenter: Send + Sync {
     /// 統計情報を表示
     fn present_stats(&self, stats: &StatsDTO) -> Result<()>;
 
-    /// 成功メッセージを表示
+    /// タスクの流れの統計（ステータスごとの滞在時間・サイクルタイム・リードタイム）を表示
+    fn present_flow_stats(&self, stats: &FlowStatsDTO) -> Result<()>;
+
+    /// 見積もり時間と実績時間を比べたレポートを表示
+    fn present_estimate_accuracy(&self, report: &EstimateAccuracyDTO) -> Result<()>;
+
+    /// 未完了のタスクの件数の概要と、フォーカス中のタスクの状況を表示（`oneline`の場合は1行で表示）
+    fn present_summary(
+        &self,
+        summary: &TaskSummaryDTO,
+        focus: Option<&str>,
+        oneline: bool,
+    ) -> Result<()>;
+
+    /// データベースの状態を表示
+    fn present_db_status(&self, status: &DatabaseStatus) -> Result<()>;
+
+    /// 環境の診断結果を表示（警告・エラーには対処方法を添える）
+    fn present_doctor_report(&self, checks: &[DoctorCheck]) -> Result<()>;
+
+    /// 成功メッセージを表示（操作が完了したことの報告）
     fn present_success(&self, message: &str) -> Result<()>;
 
+    /// 情報メッセージを表示（該当なし・取り消しなど、変更を伴わない報告）
+    fn present_info(&self, message: &str) -> Result<()>;
+
+    /// 警告メッセージを表示（処理は続行する）
+    fn present_warning(&self, message: &str) -> Result<()>;
+
+    /// エラーメッセージを表示（操作が失敗した）
+    fn present_error(&self, message: &str) -> Result<()>;
+
+    /// コマンドの結果のデータ（JSONなど）を、メッセージの形式に関係なくそのまま表示
+    fn present_data(&self, data: &str) -> Result<()>;
+
     /// 確認メッセージを表示し、ユーザーの入力を取得
+    ///
+    /// 破壊的な操作の前には必ずこのメソッドで確認します。
+    /// `--yes`が指定された場合は表示せずに承諾したものとして扱います。
     fn confirm(&self, message: &str, default: bool) -> Result<bool>;
+
+    /// 確認メッセージを表示し、`expected`（影響する件数やデータベース名）の入力を求める
+    ///
+    /// 取り消せない一括の操作の前に使い、`expected`と同じ文字列を入力した場合だけ承諾したものとして扱います。
+    /// `--yes`が指定された場合は表示せずに承諾したものとして扱います。
+    fn confirm_typed(&self, message: &str, expected: &str) -> Result<bool>;
+
+    /// 影響する件数に応じて確認する
+    ///
+    /// [`TYPED_CONFIRMATION_MIN_COUNT`]件以上の場合は件数を入力させ、それより少ない場合は通常の確認を行います。
+    fn confirm_count(&self, message: &str, count: usize) -> Result<bool> {
+        if count >= TYPED_CONFIRMATION_MIN_COUNT {
+            self.confirm_typed(message, &count.to_string())
+        } else {
+            self.confirm(message, false)
+        }
+    }
+}
+
+/// 確認で件数の入力を求める最小の件数
+pub const TYPED_CONFIRMATION_MIN_COUNT: usize = 2;
+
+/// 確認の入力が求めた文字列と一致するか（前後の空白は無視する）
+pub fn matches_confirmation(input: &str, expected: &str) -> bool {
+    input.trim() == expected
+}
+
+/// メッセージの重要度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Success,
+    Info,
+    Warning,
+    Error,
+}
+
+/// メッセージの出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageFormat {
+    /// 人が読むテキスト（警告・エラーは色付きで標準エラー出力）
+    #[default]
+    Text,
+    /// 1メッセージ1行のJSON（`{"level": "error", "message": "..."}`）
+    Json,
+}
+
+impl MessageFormat {
+    /// エラーを表示する文字列に変換する
+    ///
+    /// テキストでは原因を1行ずつ、JSONでは原因を`: `でつないだ1行にします。
+    /// アプリケーション層のエラーは、表示する言語の文言に置き換えます。
+    pub fn error_text(self, error: &anyhow::Error) -> String {
+        match (self, localize_error(error)) {
+            (Self::Text, None) => format!("{:?}", error),
+            (Self::Json, None) => format!("{:#}", error),
+            (Self::Text, Some(messages)) => format_causes(&messages),
+            (Self::Json, Some(messages)) => messages.join(": "),
+        }
+    }
+}
+
+/// 原因にアプリケーション層のエラーがあれば、原因ごとにカタログの文言に置き換える
+fn localize_error(error: &anyhow::Error) -> Option<Vec<String>> {
+    if !error.chain().any(|cause| cause.is::<AddTaskError>()) {
+        return None;
+    }
+    let messages = error
+        .chain()
+        .map(|cause| match cause.downcast_ref::<AddTaskError>() {
+            Some(AddTaskError::TagIdNotFound(id)) => Msg::ErrorTagIdNotExist.format(&[id]),
+            Some(AddTaskError::TagNotFound(name)) => Msg::ErrorTagNameNotExist.format(&[name]),
+            Some(AddTaskError::TaskFailed { title, .. }) => {
+                Msg::ErrorAddTaskFailed.format(&[title])
+            }
+            Some(AddTaskError::SaveFailed(_)) => Msg::ErrorAddTasksFailed.text().to_string(),
+            None => cause.to_string(),
+        })
+        .collect();
+    Some(messages)
+}
+
+/// 原因の文言を`anyhow::Error`のテキスト表示と同じ形式（`Caused by:`）で並べる
+fn format_causes(messages: &[String]) -> String {
+    let Some((first, causes)) = messages.split_first() else {
+        return String::new();
+    };
+    let mut text = first.clone();
+    if !causes.is_empty() {
+        text.push_str("\n\nCaused by:");
+    }
+    for (number, cause) in causes.iter().enumerate() {
+        let (prefix, indent) = if causes.len() > 1 {
+            (format!("{: >5}: ", number), "       ")
+        } else {
+            ("    ".to_string(), "    ")
+        };
+        text.push('\n');
+        text.push_str(&prefix);
+        text.push_str(&cause.replace('\n', &format!("\n{}", indent)));
+    }
+    text
+}
+
+/// JSON形式で出力するメッセージ
+#[derive(Serialize)]
+struct JsonMessage<'a> {
+    level: Severity,
+    message: &'a str,
+}
+
+/// 標準出力・標準エラー出力の代わりに貯めた出力
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CapturedOutput {
+    pub stdout: String,
+    pub stderr: String,
 }
 
 /// CLIプレゼンター
 ///
 /// コマンドラインインターフェース用のプレゼンター実装。
 /// テーブル形式でデータを表示します。
-pub struct CliPresenter;
+/// デーモンで実行する場合は、出力を貯めてクライアントに返せるようにします。
+pub struct CliPresenter {
+    theme: Theme,
+    captured: Option<Mutex<CapturedOutput>>,
+    assume_yes: bool,
+    message_format: MessageFormat,
+}
 
 impl CliPresenter {
-    pub fn new() -> Self {
-        Self
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            theme,
+            captured: None,
+            assume_yes: false,
+            message_format: MessageFormat::default(),
+        }
+    }
+
+    /// メッセージの出力形式を設定する
+    pub fn with_message_format(mut self, format: MessageFormat) -> Self {
+        self.message_format = format;
+        self
+    }
+
+    /// エラーをメッセージの形式に合わせて表示する
+    pub fn report_error(&self, error: &anyhow::Error) {
+        // 表示できなくても終了コードで失敗を伝えるため、結果は無視する
+        let _ = self.present_error(&self.message_format.error_text(error));
+    }
+
+    /// 確認をすべて承諾したものとして扱うかを設定する（`--yes`）
+    pub fn with_assume_yes(mut self, assume_yes: bool) -> Self {
+        self.assume_yes = assume_yes;
+        self
+    }
+
+    /// 出力を表示せずに貯めるプレゼンターを作成
+    ///
+    /// 確認が必要な操作には対話できないため、常に既定値を返します。
+    pub fn capturing(theme: Theme) -> Self {
+        Self {
+            theme,
+            captured: Some(Mutex::new(CapturedOutput::default())),
+            assume_yes: false,
+            message_format: MessageFormat::default(),
+        }
+    }
+
+    /// 貯めた出力を取り出す（貯めていない場合は空）
+    pub fn take_output(&self) -> CapturedOutput {
+        self.captured
+            .as_ref()
+            .map(|captured| std::mem::take(&mut *captured.lock().unwrap()))
+            .unwrap_or_default()
+    }
+
+    /// 標準出力を装飾するか（色付けが有効で、端末に直接表示する場合のみ）
+    fn styles_stdout(&self) -> bool {
+        self.theme.enabled && self.captured.is_none() && stdout().is_terminal()
+    }
+
+    /// 1行を標準出力（または貯めた出力）に書き出す
+    fn line(&self, text: impl Display) {
+        match &self.captured {
+            Some(captured) => {
+                let stdout = &mut captured.lock().unwrap().stdout;
+                stdout.push_str(&text.to_string());
+                stdout.push('\n');
+            }
+            None => println!("{}", text),
+        }
+    }
+
+    /// 重要度に応じてメッセージを書き出す
+    ///
+    /// 成功・情報は標準出力、警告・エラーは標準エラー出力に書き出します。
+    /// テキスト形式の警告・エラーは、端末に直接表示する場合のみ色付けします。
+    fn message(&self, severity: Severity, message: &str) {
+        let to_stderr = matches!(severity, Severity::Warning | Severity::Error);
+        let text = match self.message_format {
+            MessageFormat::Json => serde_json::to_string(&JsonMessage {
+                level: severity,
+                message,
+            })
+            .unwrap_or_default(),
+            MessageFormat::Text => {
+                let (text, color) = match severity {
+                    Severity::Success | Severity::Info => (message.to_string(), None),
+                    Severity::Warning => {
+                        (Msg::WarningPrefix.format(&[&message]), Some(Color::Yellow))
+                    }
+                    Severity::Error => (Msg::ErrorPrefix.format(&[&message]), Some(Color::Red)),
+                };
+                let is_terminal = if to_stderr {
+                    stderr().is_terminal()
+                } else {
+                    stdout().is_terminal()
+                };
+                match color {
+                    Some(color) if self.theme.enabled && self.captured.is_none() && is_terminal => {
+                        Style::new()
+                            .fg(color)
+                            .force_styling(true)
+                            .apply_to(text)
+                            .to_string()
+                    }
+                    _ => text,
+                }
+            }
+        };
+
+        if to_stderr {
+            self.error_line(text);
+        } else {
+            self.line(text);
+        }
+    }
+
+    /// 1行を標準エラー出力（または貯めた出力）に書き出す
+    fn error_line(&self, text: impl Display) {
+        match &self.captured {
+            Some(captured) => {
+                let stderr = &mut captured.lock().unwrap().stderr;
+                stderr.push_str(&text.to_string());
+                stderr.push('\n');
+            }
+            None => eprintln!("{}", text),
+        }
     }
 }
 
 impl Default for CliPresenter {
     fn default() -> Self {
-        Self::new()
+        Self::new(Theme::default())
     }
 }
 
 impl Presenter for CliPresenter {
-    fn present_task_list(&self, tasks: &[TaskDTO]) -> Result<()> {
+    fn present_task_list(&self, tasks: &[TaskDTO], view: &TaskListView) -> Result<()> {
         if tasks.is_empty() {
-            println!("No tasks found");
+            self.line(Msg::NoTasksFound.text());
         } else {
-            println!("Task list ({} tasks):", tasks.len());
-            let table = create_task_table(tasks);
-            println!("{}", table);
+            self.line(Msg::TaskListHeader.format(&[&tasks.len()]));
+            let table = create_task_table(tasks, &self.theme, view);
+            self.line(apply_highlights(&table.to_string(), self.styles_stdout()));
+        }
+
+        Ok(())
+    }
+
+    fn present_task_lines(&self, tasks: &[TaskDTO], template: &TaskTemplate) -> Result<()> {
+        // スクリプトから使うため、見出しや該当なしのメッセージは出力しない
+        for task in tasks {
+            self.line(template.render(task));
+        }
+        Ok(())
+    }
+
+    fn present_task_groups(&self, groups: &[TaskGroupDTO], view: &TaskListView) -> Result<()> {
+        if groups.is_empty() {
+            self.line(Msg::NoTasksFound.text());
+            return Ok(());
+        }
+
+        for (i, group) in groups.iter().enumerate() {
+            if i > 0 {
+                self.line("");
+            }
+            self.line(Msg::TaskGroupHeader.format(&[&group.label, &group.count()]));
+            let table = create_task_table(&group.tasks, &self.theme, view);
+            self.line(table);
+        }
+
+        Ok(())
+    }
+
+    fn present_agenda(&self, agenda: &AgendaDTO) -> Result<()> {
+        if agenda.is_empty() {
+            self.line(Msg::AgendaEmpty.text());
+            return Ok(());
+        }
+
+        let view = TaskListView::new(
+            TaskListFormat::Compact,
+            vec![
+                TaskColumn::Id,
+                TaskColumn::Title,
+                TaskColumn::Priority,
+                TaskColumn::Status,
+                TaskColumn::DueDate,
+                TaskColumn::Tags,
+            ],
+        );
+        let upcoming = agenda
+            .upcoming
+            .iter()
+            .map(|day| (format_date(&Some(day.date)), &day.tasks));
+        let sections = [
+            (Msg::AgendaOverdue.text().to_string(), &agenda.overdue),
+            (Msg::AgendaDueToday.text().to_string(), &agenda.due_today),
+        ]
+        .into_iter()
+        .chain(upcoming)
+        .chain([(
+            Msg::AgendaInProgress.text().to_string(),
+            &agenda.in_progress,
+        )])
+        .filter(|(_, tasks)| !tasks.is_empty());
+
+        for (i, (label, tasks)) in sections.enumerate() {
+            if i > 0 {
+                self.line("");
+            }
+            self.line(Msg::TaskGroupHeader.format(&[&label, &tasks.len()]));
+            self.line(create_task_table(tasks, &self.theme, &view));
+        }
+
+        Ok(())
+    }
+
+    fn present_workload(&self, workload: &WorkloadDTO) -> Result<()> {
+        let (Some(first), Some(last)) = (workload.days.first(), workload.days.last()) else {
+            return Ok(());
+        };
+        self.line(Msg::WorkloadHeader.format(&[
+            &format_date(&Some(first.date)),
+            &format_date(&Some(last.date)),
+            &Estimate::format_minutes(workload.daily_capacity_minutes),
+        ]));
+        self.line(create_workload_table(workload, &self.theme));
+
+        let mut total = Estimate::format_minutes(workload.total_minutes());
+        let unestimated = workload.unestimated_count();
+        if unestimated > 0 {
+            total = format!(
+                "{} {}",
+                total,
+                Msg::WorkloadUnestimated.format(&[&unestimated])
+            );
+        }
+        self.line(Msg::WorkloadTotal.format(&[&total]));
+        let overcommitted = workload.days.iter().filter(|day| day.overcommitted).count();
+        if overcommitted > 0 {
+            self.present_warning(&Msg::WorkloadOvercommitted.format(&[&overcommitted]))?;
         }
 
         Ok(())
     }
 
     fn present_task_detail(&self, task: &TaskDTO) -> Result<()> {
-        let table = create_task_detail_table(task);
-        println!("{}", table);
+        let table = create_task_detail_table(task, &self.theme, true);
+        self.line(table);
+
+        Ok(())
+    }
+
+    fn present_task_detail_with_related(
+        &self,
+        detail: &TaskDetailDTO,
+        raw_description: bool,
+    ) -> Result<()> {
+        let description = detail
+            .task
+            .description
+            .as_deref()
+            .filter(|description| !raw_description && !description.is_empty());
+        match description {
+            // 装飾を含む文字列は表の幅の計算を崩すため、説明は表の下に表示する
+            Some(description) => {
+                self.line(create_task_detail_table(&detail.task, &self.theme, false));
+                self.line(format!("{}:", Msg::FieldDescription.text()));
+                let styled = self.styles_stdout();
+                for line in render_markdown(description, styled) {
+                    self.line(format!("  {}", line));
+                }
+            }
+            None => self.present_task_detail(&detail.task)?,
+        }
+        self.line(Msg::RelatedInfoHeader.text());
+        self.line(create_task_related_table(detail));
+
+        Ok(())
+    }
+
+    fn present_checklist(&self, task: &TaskDTO) -> Result<()> {
+        if task.checklist.is_empty() {
+            self.line(Msg::NoChecklistItems.text());
+            return Ok(());
+        }
+
+        self.line(Msg::ChecklistHeader.format(&[
+            &task.id,
+            &task.title,
+            &format_checklist_progress(&task.checklist),
+        ]));
+        for line in format_checklist_items(&task.checklist) {
+            self.line(format!("  {}", line));
+        }
 
         Ok(())
     }
 
     fn present_tag_list(&self, tags: &[TagDTO]) -> Result<()> {
         if tags.is_empty() {
-            println!("No tags found");
+            self.line(Msg::NoTagsFound.text());
         } else {
-            println!("Tag list ({} tags):", tags.len());
+            self.line(Msg::TagListHeader.format(&[&tags.len()]));
             let table = create_tag_table(tags);
-            println!("{}", table);
+            self.line(table);
         }
 
         Ok(())
@@ -86,29 +558,271 @@ impl Presenter for CliPresenter {
 
     fn present_tag_detail(&self, tag: &TagDTO) -> Result<()> {
         let table = create_tag_detail_table(tag);
-        println!("{}", table);
+        self.line(table);
 
         Ok(())
     }
 
     fn present_stats(&self, stats: &StatsDTO) -> Result<()> {
-        let display = create_rich_stats_display(stats);
-        println!("{display}");
+        let display = create_rich_stats_display(stats, &self.theme);
+        self.line(display);
+
+        Ok(())
+    }
+
+    fn present_flow_stats(&self, stats: &FlowStatsDTO) -> Result<()> {
+        self.line(create_flow_stats_display(stats));
+
+        Ok(())
+    }
+
+    fn present_estimate_accuracy(&self, report: &EstimateAccuracyDTO) -> Result<()> {
+        if report.rows.is_empty() {
+            self.present_info(Msg::AccuracyNoData.text())?;
+        } else {
+            self.line(create_accuracy_table(report, &self.theme));
+            let overall = match report.total.variance_percent() {
+                Some(percent) if percent > 0 => Msg::AccuracyOver.format(&[&percent]),
+                Some(percent) if percent < 0 => Msg::AccuracyUnder.format(&[&-percent]),
+                _ => Msg::AccuracyOnTarget.text().to_string(),
+            };
+            self.line(overall);
+        }
+        if report.untracked_count > 0 {
+            self.present_info(&Msg::AccuracyUntracked.format(&[&report.untracked_count]))?;
+        }
+
+        Ok(())
+    }
+
+    fn present_summary(
+        &self,
+        summary: &TaskSummaryDTO,
+        focus: Option<&str>,
+        oneline: bool,
+    ) -> Result<()> {
+        let counts: [&dyn Display; 3] = [&summary.overdue, &summary.due_today, &summary.open];
+        if oneline {
+            // ステータスバーに埋め込むため、色は付けない
+            let mut line = Msg::SummaryOneline.format(&counts);
+            if let Some(focus) = focus {
+                line = format!("{} · {}", line, focus);
+            }
+            self.line(line);
+        } else {
+            self.line(Msg::SummaryDetail.format(&counts));
+            self.line(Msg::SummaryStreak.format(&[
+                &summary.completed_today,
+                &summary.streak_days,
+                &summary.longest_streak_days,
+                &summary.completed_total,
+            ]));
+            if let Some(focus) = focus {
+                self.line(focus.to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn present_db_status(&self, status: &DatabaseStatus) -> Result<()> {
+        let table = create_db_status_table(status);
+        self.line(table);
+
+        Ok(())
+    }
+
+    fn present_doctor_report(&self, checks: &[DoctorCheck]) -> Result<()> {
+        let colored = self.styles_stdout();
+        for check in checks {
+            let label = format!("{:<8}", format!("[{}]", check.status.label()));
+            let label = match check.status {
+                _ if !colored => label,
+                CheckStatus::Ok => Style::new().green().apply_to(label).to_string(),
+                CheckStatus::Info => label,
+                CheckStatus::Warning => Style::new().yellow().apply_to(label).to_string(),
+                CheckStatus::Error => Style::new().red().apply_to(label).to_string(),
+            };
+            // 複数行の詳細（設定ファイルの構文エラーなど）はラベルの幅だけ字下げする
+            let detail = check.detail.trim_end().replace('\n', "\n        ");
+            self.line(format!("{}{}: {}", label, check.name, detail));
+            if let Some(fix) = &check.fix {
+                self.line(format!("{:8}{}", "", Msg::DoctorFix.format(&[fix])));
+            }
+        }
 
         Ok(())
     }
 
     fn present_success(&self, message: &str) -> Result<()> {
-        println!("{}", message);
+        self.message(Severity::Success, message);
+        Ok(())
+    }
+
+    fn present_info(&self, message: &str) -> Result<()> {
+        self.message(Severity::Info, message);
+        Ok(())
+    }
+
+    fn present_warning(&self, message: &str) -> Result<()> {
+        self.message(Severity::Warning, message);
+        Ok(())
+    }
+
+    fn present_error(&self, message: &str) -> Result<()> {
+        self.message(Severity::Error, message);
+        Ok(())
+    }
+
+    fn present_data(&self, data: &str) -> Result<()> {
+        self.line(data);
         Ok(())
     }
 
     fn confirm(&self, message: &str, default: bool) -> Result<bool> {
-        let result = Confirm::new(message)
-            .with_default(default)
-            .prompt()
-            .unwrap_or(false);
+        if self.assume_yes {
+            return Ok(true);
+        }
+        if self.captured.is_some() {
+            return Ok(default);
+        }
+
+        match Confirm::new(message).with_default(default).prompt() {
+            Ok(answer) => Ok(answer),
+            // 端末がない場合（スクリプトなど）は、黙って取り消さずに--yesの指定を促す
+            Err(InquireError::NotTTY) => {
+                anyhow::bail!(Msg::ErrorConfirmationRequired.format(&[&message]))
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn confirm_typed(&self, message: &str, expected: &str) -> Result<bool> {
+        if self.assume_yes {
+            return Ok(true);
+        }
+        if self.captured.is_some() {
+            return Ok(false);
+        }
+
+        let prompt = Msg::PromptTypeToConfirm.format(&[&message, &expected]);
+        match Text::new(&prompt).prompt() {
+            Ok(answer) => Ok(matches_confirmation(&answer, expected)),
+            Err(InquireError::NotTTY) => {
+                anyhow::bail!(Msg::ErrorConfirmationRequired.format(&[&message]))
+            }
+            Err(_) => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirm_without_prompt() {
+        // Arrange
+        let capturing = CliPresenter::capturing(Theme::plain());
+        let assume_yes = CliPresenter::capturing(Theme::plain()).with_assume_yes(true);
+
+        // Act & Assert: 対話できない場合は既定値、--yesの場合は常に承諾
+        assert!(!capturing.confirm("Delete?", false).unwrap());
+        assert!(capturing.confirm("Delete?", true).unwrap());
+        assert!(assume_yes.confirm("Delete?", false).unwrap());
+        assert_eq!(assume_yes.take_output(), CapturedOutput::default());
+    }
+
+    #[test]
+    fn test_confirm_typed_without_prompt() {
+        // Arrange
+        let capturing = CliPresenter::capturing(Theme::plain());
+        let assume_yes = CliPresenter::capturing(Theme::plain()).with_assume_yes(true);
+
+        // Act & Assert: 対話できない場合は承諾せず、--yesの場合は常に承諾
+        assert!(!capturing.confirm_typed("Delete 37 tasks?", "37").unwrap());
+        assert!(!capturing.confirm_count("Delete 1 task?", 1).unwrap());
+        assert!(assume_yes.confirm_typed("Delete 37 tasks?", "37").unwrap());
+        assert!(assume_yes.confirm_count("Delete 37 tasks?", 37).unwrap());
+    }
+
+    #[test]
+    fn test_error_text_localizes_add_task_error() {
+        // Arrange
+        let error = anyhow::Error::new(AddTaskError::TaskFailed {
+            title: "Buy milk".to_string(),
+            source: anyhow::anyhow!(AddTaskError::TagNotFound("home".to_string())).context("outer"),
+        });
+
+        // Act
+        let text = MessageFormat::Text.error_text(&error);
+        let json = MessageFormat::Json.error_text(&error);
+
+        // Assert: anyhow::Errorと同じ形式で、各原因をカタログの文言にする
+        assert_eq!(
+            text,
+            "Failed to add task 'Buy milk'; no tasks were added\n\nCaused by:\n    0: outer\n    1: Tag 'home' does not exist"
+        );
+        assert_eq!(
+            json,
+            "Failed to add task 'Buy milk'; no tasks were added: outer: Tag 'home' does not exist"
+        );
+    }
+
+    #[test]
+    fn test_matches_confirmation() {
+        assert!(matches_confirmation("37", "37"));
+        assert!(matches_confirmation(" yaru.db \n", "yaru.db"));
+        assert!(!matches_confirmation("y", "37"));
+        assert!(!matches_confirmation("", "37"));
+        assert!(!matches_confirmation("YARU.DB", "yaru.db"));
+    }
+
+    #[test]
+    fn test_messages_by_severity() {
+        // Arrange
+        let presenter = CliPresenter::capturing(Theme::default());
+
+        // Act
+        presenter.present_success("Task added").unwrap();
+        presenter.present_info("No tasks found").unwrap();
+        presenter
+            .present_warning("Due date is in the past")
+            .unwrap();
+        presenter.present_error("Task not found").unwrap();
+        presenter.present_data("{}").unwrap();
+
+        // Assert: 警告・エラーは標準エラー出力、貯めた出力は色付けしない
+        assert_eq!(
+            presenter.take_output(),
+            CapturedOutput {
+                stdout: "Task added\nNo tasks found\n{}\n".to_string(),
+                stderr: "Warning: Due date is in the past\nError: Task not found\n".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_messages_in_json() {
+        // Arrange
+        let presenter =
+            CliPresenter::capturing(Theme::default()).with_message_format(MessageFormat::Json);
+        let error = anyhow::anyhow!("disk full").context("Failed to save task");
+
+        // Act
+        presenter.present_info("No \"tasks\"").unwrap();
+        presenter.present_data(r#"{"committed":true}"#).unwrap();
+        presenter.report_error(&error);
 
-        Ok(result)
+        // Assert
+        let output = presenter.take_output();
+        assert_eq!(
+            output.stdout,
+            "{\"level\":\"info\",\"message\":\"No \\\"tasks\\\"\"}\n{\"committed\":true}\n"
+        );
+        assert_eq!(
+            output.stderr,
+            "{\"level\":\"error\",\"message\":\"Failed to save task: disk full\"}\n"
+        );
     }
 }
@@ -0,0 +1,117 @@
+//! リモートストレージとの同期
+//!
+//! データベースのスナップショットをパスフレーズで暗号化し、S3互換のストレージやWebDAVサーバーに置きます。
+//! 競合の検出にはストレージが返すETagを使い、前回のpush・pull以降に変更されていれば上書きしません。
+
+pub mod s3;
+pub mod webdav;
+
+use crate::interface::persistence::encryption::FieldCipher;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+
+/// スナップショットの先頭に付ける識別子（形式のバージョンを含む）
+const SNAPSHOT_MAGIC: &[u8] = b"YARUSNAP1";
+
+/// ソルトのバイト数
+const SALT_LEN: usize = 16;
+
+/// SQLiteのデータベースファイルの先頭
+const SQLITE_HEADER: &[u8] = b"SQLite format 3\0";
+
+/// ストレージから取得したスナップショット
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteObject {
+    pub body: Vec<u8>,
+    pub etag: Option<String>,
+}
+
+/// 書き込みの条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PutCondition {
+    /// ETagが一致する場合だけ上書きする（`If-Match`）
+    Matches(String),
+    /// まだ存在しない場合だけ作成する（`If-None-Match: *`）
+    Absent,
+    /// 常に上書きする
+    Always,
+}
+
+/// 書き込みの結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PutOutcome {
+    /// 書き込んだ（ストレージが返したETag）
+    Stored(Option<String>),
+    /// 条件を満たさなかったため書き込まなかった
+    PreconditionFailed,
+}
+
+/// スナップショットの保存先
+#[async_trait]
+pub trait RemoteStore: Send + Sync {
+    /// 表示用の保存先（`s3://bucket/key`など）
+    fn location(&self) -> String;
+
+    /// スナップショットを取得する（存在しない場合はNone）
+    async fn get(&self) -> Result<Option<RemoteObject>>;
+
+    /// スナップショットのETagを取得する（存在しない場合はNone）
+    async fn etag(&self) -> Result<Option<String>>;
+
+    /// スナップショットを書き込む
+    async fn put(&self, body: Vec<u8>, condition: PutCondition) -> Result<PutOutcome>;
+}
+
+/// データベースファイルをパスフレーズで暗号化する
+///
+/// 識別子・ソルトに続けて、ソルトとパスフレーズから導出した鍵による暗号文を格納します。
+pub fn seal_snapshot(passphrase: &str, database: &[u8]) -> Result<Vec<u8>> {
+    let salt = FieldCipher::generate_salt()?;
+    let cipher = FieldCipher::derive(passphrase, &salt)?;
+    let mut sealed = SNAPSHOT_MAGIC.to_vec();
+    sealed.extend_from_slice(&salt);
+    sealed.extend_from_slice(&cipher.encrypt_bytes(database)?);
+    Ok(sealed)
+}
+
+/// `seal_snapshot`で暗号化したスナップショットを復号する
+///
+/// # Returns
+/// * `Err` - yaruのスナップショットでない、パスフレーズが誤っている、または内容がSQLiteのデータベースでない場合
+pub fn open_snapshot(passphrase: &str, sealed: &[u8]) -> Result<Vec<u8>> {
+    let rest = sealed
+        .strip_prefix(SNAPSHOT_MAGIC)
+        .context("Not a yaru snapshot")?;
+    if rest.len() < SALT_LEN {
+        anyhow::bail!("Snapshot is truncated");
+    }
+    let (salt, payload) = rest.split_at(SALT_LEN);
+    let database = FieldCipher::derive(passphrase, salt)?
+        .decrypt_bytes(payload)
+        .context("Failed to decrypt the snapshot (wrong passphrase?)")?;
+    if !database.starts_with(SQLITE_HEADER) {
+        anyhow::bail!("Snapshot does not contain a SQLite database");
+    }
+    Ok(database)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_and_open_snapshot() {
+        // Arrange
+        let database = [SQLITE_HEADER, b"tasks"].concat();
+
+        // Act
+        let sealed = seal_snapshot("secret", &database).unwrap();
+
+        // Assert
+        assert!(sealed.starts_with(SNAPSHOT_MAGIC));
+        assert!(!sealed.windows(5).any(|window| window == b"tasks"));
+        assert_eq!(open_snapshot("secret", &sealed).unwrap(), database);
+        assert!(open_snapshot("wrong", &sealed).is_err());
+        assert!(open_snapshot("secret", b"plain").is_err());
+    }
+}
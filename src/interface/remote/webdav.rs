@@ -0,0 +1,169 @@
+//! WebDAVサーバー
+//!
+//! 指定したURLのファイルをGET・PUTで読み書きします（Nextcloudなど）。
+//! 置き場所のフォルダはあらかじめ作成しておく必要があります。
+
+use super::{PutCondition, PutOutcome, RemoteObject, RemoteStore};
+use crate::interface::http::{HttpRequest, HttpResponse};
+use anyhow::Result;
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// WebDAVサーバー上のファイル
+#[derive(Debug, Clone)]
+pub struct WebDavStore {
+    url: String,
+    user: Option<String>,
+    password: Option<String>,
+}
+
+impl WebDavStore {
+    /// 新しいWebDavStoreを作成
+    ///
+    /// # Returns
+    /// * `Err` - URLがhttp(s)のURLでない場合
+    pub fn new(url: &str, user: Option<String>, password: Option<String>) -> Result<Self> {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            anyhow::bail!("Invalid WebDAV URL (expected http:// or https://): {}", url);
+        }
+        Ok(Self {
+            url: url.to_string(),
+            user,
+            password,
+        })
+    }
+
+    /// 認証情報を付けたリクエストを作成する
+    fn request(&self, method: &str) -> HttpRequest {
+        let request = HttpRequest::new(method, &self.url);
+        match &self.user {
+            Some(user) => {
+                let credentials =
+                    format!("{}:{}", user, self.password.as_deref().unwrap_or_default());
+                request.header(
+                    "Authorization",
+                    format!("Basic {}", STANDARD.encode(credentials)),
+                )
+            }
+            None => request,
+        }
+    }
+
+    /// エラーのレスポンスをエラーに変換する
+    fn check(&self, response: HttpResponse) -> Result<HttpResponse> {
+        match response.status() {
+            _ if response.is_success() => Ok(response),
+            401 | 403 => anyhow::bail!(
+                "WebDAV authentication failed for {} (check [remote] user and password)",
+                self.url
+            ),
+            status => anyhow::bail!("WebDAV error ({}) from {}", status, self.url),
+        }
+    }
+}
+
+#[async_trait]
+impl RemoteStore for WebDavStore {
+    fn location(&self) -> String {
+        self.url.clone()
+    }
+
+    async fn get(&self) -> Result<Option<RemoteObject>> {
+        let response = self.request("GET").send().await?;
+        if response.status() == 404 {
+            return Ok(None);
+        }
+        let response = self.check(response)?;
+        Ok(Some(RemoteObject {
+            etag: response.header("ETag").map(str::to_string),
+            body: response.body().to_vec(),
+        }))
+    }
+
+    async fn etag(&self) -> Result<Option<String>> {
+        let response = self.request("HEAD").send().await?;
+        if response.status() == 404 {
+            return Ok(None);
+        }
+        Ok(self.check(response)?.header("ETag").map(str::to_string))
+    }
+
+    async fn put(&self, body: Vec<u8>, condition: PutCondition) -> Result<PutOutcome> {
+        let request = self
+            .request("PUT")
+            .header("Content-Type", "application/octet-stream")
+            .body(body);
+        let request = match condition {
+            PutCondition::Matches(etag) => request.header("If-Match", etag),
+            PutCondition::Absent => request.header("If-None-Match", "*"),
+            PutCondition::Always => request,
+        };
+        let response = request.send().await?;
+        if response.status() == 412 {
+            return Ok(PutOutcome::PreconditionFailed);
+        }
+        Ok(PutOutcome::Stored(
+            self.check(response)?.header("ETag").map(str::to_string),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    /// 受信したリクエストのヘッダーの値（名前の大文字・小文字は区別しない）
+    fn header<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+        request.lines().find_map(|line| {
+            line.split_once(": ")
+                .filter(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_put_sends_condition_and_detects_conflict() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            let mut buffer = [0u8; 1024];
+            while !received.ends_with(b"snapshot") {
+                let n = stream.read(&mut buffer).unwrap();
+                received.extend_from_slice(&buffer[..n]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 412 Precondition Failed\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            String::from_utf8(received).unwrap()
+        });
+        let store = WebDavStore::new(
+            &format!("http://127.0.0.1:{}/dav/yaru.db.enc", port),
+            Some("me".to_string()),
+            Some("pw".to_string()),
+        )
+        .unwrap();
+
+        // Act
+        let outcome = store
+            .put(
+                b"snapshot".to_vec(),
+                PutCondition::Matches("\"v1\"".to_string()),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        let request = server.join().unwrap();
+        assert_eq!(outcome, PutOutcome::PreconditionFailed);
+        assert!(request.starts_with("PUT /dav/yaru.db.enc HTTP/1.1\r\n"));
+        assert_eq!(header(&request, "If-Match"), Some("\"v1\""));
+        assert_eq!(header(&request, "Authorization"), Some("Basic bWU6cHc="));
+    }
+}
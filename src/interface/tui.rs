@@ -1,21 +1,97 @@
 pub mod app;
+pub mod bulk;
+pub mod calendar;
 pub mod event;
+pub mod history;
+pub mod pomodoro;
 pub mod ui;
+pub mod worker;
 
+use crate::{
+    domain::{
+        clock::{Clock, SystemClock},
+        services::UrgencyWeights,
+        tag::repository::TagRepository,
+        task::{
+            repository::TaskRepository,
+            value_objects::{DueDateBuckets, TaskContext},
+        },
+        time_entry::repository::TimeEntryRepository,
+    },
+    interface::{
+        i18n::Msg,
+        persistence::read_only::{
+            ReadOnlyTagRepository, ReadOnlyTaskRepository, ReadOnlyTimeEntryRepository,
+        },
+        tui::{
+            history::HistoryDirection,
+            pomodoro::{BREAK_MINUTES, PhaseChange},
+            worker::Action,
+        },
+    },
+};
 use anyhow::Result;
 use app::App;
+use chrono::{Utc, Weekday};
 use ratatui::{
     Terminal,
     backend::CrosstermBackend,
     crossterm::{
-        self, execute,
+        self,
+        event::KeyEvent,
+        execute,
+        style::Print,
         terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
     },
 };
-use std::{io, time::Duration};
+use std::{io, sync::Arc, thread, time::Duration};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// 画面を描画し直す間隔
+const FRAME_INTERVAL: Duration = Duration::from_millis(100);
+
+/// TUIが使用するリポジトリ
+pub struct TuiRepositories {
+    pub task_repo: Arc<dyn TaskRepository>,
+    pub tag_repo: Arc<dyn TagRepository>,
+    pub time_entry_repo: Arc<dyn TimeEntryRepository>,
+}
+
+impl TuiRepositories {
+    /// 書き込みを拒否するリポジトリでラップする（読み取り専用モード）
+    pub fn read_only(self) -> Self {
+        Self {
+            task_repo: Arc::new(ReadOnlyTaskRepository::new(self.task_repo)),
+            tag_repo: Arc::new(ReadOnlyTagRepository::new(self.tag_repo)),
+            time_entry_repo: Arc::new(ReadOnlyTimeEntryRepository::new(self.time_entry_repo)),
+        }
+    }
+}
+
+/// TUIの表示設定
+#[derive(Debug, Clone)]
+pub struct TuiSettings {
+    /// 一覧を緊急度順で並べるときの重み
+    pub urgency: UrgencyWeights,
+    /// 現在のコンテキスト（指定した場合は、そのコンテキストのタスクとコンテキストのないタスクのみを表示）
+    pub context: Option<TaskContext>,
+    /// 期限日の色付けに使用する分類の境界
+    pub due_date_buckets: DueDateBuckets,
+    /// 「今日」の判定に使用するClock（`[display] timezone`のタイムゾーン）
+    pub clock: SystemClock,
+    /// カレンダーの週の始まりの曜日
+    pub week_start: Weekday,
+}
 
 /// TUIモードで実行する
-pub async fn run_tui() -> Result<()> {
+///
+/// データの読み書きはバックグラウンドのワーカーで行い、画面は一定間隔で描画し続けます。
+pub async fn run_tui(repositories: TuiRepositories, settings: TuiSettings) -> Result<()> {
+    let due_date_buckets = settings.due_date_buckets;
+    let timezone = settings.clock.timezone();
+    let week_start = settings.week_start;
+    let (actions, mut events) = worker::spawn(repositories, settings);
+
     // ターミナルセットアップ
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -27,18 +103,84 @@ pub async fn run_tui() -> Result<()> {
     let _cleanup = CleanupGuard;
 
     // アプリケーション初期化
-    let mut app = App::new();
+    let mut app = App::new()
+        .with_due_date_buckets(due_date_buckets)
+        .with_timezone(timezone)
+        .with_week_start(week_start);
+    send_action(&mut app, &actions, Action::LoadTasks);
+    send_action(&mut app, &actions, Action::LoadTags);
+
+    let mut keys = spawn_input_reader();
+    let mut ticker = tokio::time::interval(FRAME_INTERVAL);
 
     // イベントループ
     loop {
         // 画面描画
-        terminal.draw(ui::render)?;
+        terminal.draw(|frame| ui::render(frame, &app, Utc::now()))?;
 
-        // イベント処理（100msタイムアウト）
-        if crossterm::event::poll(Duration::from_millis(100))?
-            && let crossterm::event::Event::Key(key) = crossterm::event::read()?
-        {
-            event::handle_key_event(&mut app, key);
+        tokio::select! {
+            _ = ticker.tick() => app.on_tick(),
+            Some(key) = keys.recv() => {
+                tracing::debug!(code = ?key.code, modifiers = ?key.modifiers, "dispatching key event");
+                event::handle_key_event(&mut app, key);
+            }
+            Some(event) = events.recv() => app.apply_data_event(event),
+        }
+
+        // 履歴と一覧の整合性を保つため、変更を伴う操作は前の処理が終わってから依頼する
+        if !app.is_busy() {
+            // 取り消し・やり直し
+            if let Some(direction) = app.take_history_request() {
+                match app.history_mut().take(direction) {
+                    Some(changes) => send_action(
+                        &mut app,
+                        &actions,
+                        Action::ReplayHistory { direction, changes },
+                    ),
+                    None => app.set_status(
+                        match direction {
+                            HistoryDirection::Undo => Msg::TuiNothingToUndo,
+                            HistoryDirection::Redo => Msg::TuiNothingToRedo,
+                        }
+                        .text(),
+                    ),
+                }
+            }
+
+            // 確定した一括操作
+            if let Some(action) = app.take_bulk_action() {
+                let targets = app.bulk_targets();
+                send_action(&mut app, &actions, Action::ApplyBulk { action, targets });
+            }
+        }
+
+        // カレンダーで表示する月が変わったら、その月のタスクを読み込む
+        if let Some((year, month)) = app.take_calendar_request() {
+            send_action(&mut app, &actions, Action::LoadCalendar { year, month });
+        }
+
+        // ポモドーロのフェーズが切り替わったらベルで通知し、作業フェーズを記録する
+        if let Some((task_id, change)) = app.tick_pomodoro(Utc::now()) {
+            execute!(io::stdout(), Print('\x07'))?;
+            match change {
+                PhaseChange::WorkCompleted {
+                    started_at,
+                    ended_at,
+                } => {
+                    tracing::info!(task_id, "pomodoro completed");
+                    app.set_status(Msg::PomodoroWorkFinished.format(&[&BREAK_MINUTES]));
+                    send_action(
+                        &mut app,
+                        &actions,
+                        Action::RecordTimeEntry {
+                            task_id,
+                            started_at,
+                            ended_at,
+                        },
+                    );
+                }
+                PhaseChange::BreakCompleted => app.set_status(Msg::PomodoroBreakFinished.text()),
+            }
         }
 
         // 終了チェック
@@ -50,6 +192,44 @@ pub async fn run_tui() -> Result<()> {
     Ok(())
 }
 
+/// ワーカーに処理を依頼し、完了するまで処理中として扱う
+fn send_action(app: &mut App, actions: &UnboundedSender<Action>, action: Action) {
+    if actions.send(action).is_ok() {
+        app.begin_action();
+    }
+}
+
+/// キー入力を別スレッドで読み取り、チャネルに送る
+///
+/// 受信側が破棄されるとスレッドも終了します。
+fn spawn_input_reader() -> UnboundedReceiver<KeyEvent> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    thread::spawn(move || {
+        while !tx.is_closed() {
+            match crossterm::event::poll(FRAME_INTERVAL) {
+                Ok(true) => match crossterm::event::read() {
+                    Ok(crossterm::event::Event::Key(key)) => {
+                        if tx.send(key).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::error!(error = %e, "failed to read terminal event");
+                        break;
+                    }
+                },
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::error!(error = %e, "failed to poll terminal event");
+                    break;
+                }
+            }
+        }
+    });
+    rx
+}
+
 /// ターミナルのクリーンアップを保証する構造体
 struct CleanupGuard;
 
@@ -1,11 +1,116 @@
+use crate::{
+    application::dto::{TagDTO, TaskSummaryDTO, task_dto::TaskDTO},
+    domain::{
+        clock::Timezone,
+        task::value_objects::{DueDateBuckets, Priority},
+    },
+    interface::{
+        i18n::Msg,
+        presentation::{TYPED_CONFIRMATION_MIN_COUNT, matches_confirmation},
+        tui::{
+            bulk::BulkAction,
+            calendar::Calendar,
+            history::{History, HistoryDirection},
+            pomodoro::{PhaseChange, Pomodoro},
+            worker::DataEvent,
+        },
+    },
+};
+use chrono::{DateTime, NaiveDate, Utc, Weekday};
+use std::collections::HashSet;
+use strum::IntoEnumIterator;
+
+/// タスク一覧の操作モード
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// 一覧の閲覧・選択
+    Normal,
+    /// 一括変更する優先度の選択
+    PriorityPicker { selected: usize },
+    /// 一括追加するタグの選択
+    TagPicker { selected: usize },
+    /// 一括操作の実行確認
+    Confirm(BulkAction),
+    /// 件数の入力による一括操作の実行確認（多数のタスクを削除する場合）
+    ConfirmTyped { action: BulkAction, input: String },
+}
+
 /// TUIアプリケーションの状態を管理する構造体
 pub struct App {
     should_quit: bool,
+    tasks: Vec<TaskDTO>,
+    tags: Vec<TagDTO>,
+    summary: Option<TaskSummaryDTO>,
+    selected: usize,
+    marked: HashSet<i32>,
+    mode: Mode,
+    show_detail: bool,
+    calendar: Option<Calendar>,
+    pending_bulk_action: Option<BulkAction>,
+    history: History,
+    pending_history: Option<HistoryDirection>,
+    pomodoro: Option<Pomodoro>,
+    status_message: Option<String>,
+    busy: usize,
+    spinner_frame: usize,
+    due_date_buckets: DueDateBuckets,
+    timezone: Timezone,
+    week_start: Weekday,
 }
 
+/// 処理中に表示するスピナーの各コマ
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
 impl App {
     pub fn new() -> Self {
-        Self { should_quit: false }
+        Self {
+            should_quit: false,
+            tasks: Vec::new(),
+            tags: Vec::new(),
+            summary: None,
+            selected: 0,
+            marked: HashSet::new(),
+            mode: Mode::Normal,
+            show_detail: false,
+            calendar: None,
+            pending_bulk_action: None,
+            history: History::new(),
+            pending_history: None,
+            pomodoro: None,
+            status_message: None,
+            busy: 0,
+            spinner_frame: 0,
+            due_date_buckets: DueDateBuckets::default(),
+            timezone: Timezone::default(),
+            week_start: Weekday::Mon,
+        }
+    }
+
+    /// 期限日の色付けに使用する分類の境界を設定
+    pub fn with_due_date_buckets(mut self, buckets: DueDateBuckets) -> Self {
+        self.due_date_buckets = buckets;
+        self
+    }
+
+    pub fn due_date_buckets(&self) -> DueDateBuckets {
+        self.due_date_buckets
+    }
+
+    /// 「今日」の判定に使用するタイムゾーンを設定
+    pub fn with_timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// カレンダーの週の始まりの曜日を設定
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// `now`の、設定したタイムゾーンでの日付（期限切れなどの判定の基準日）
+    pub fn date_of(&self, now: DateTime<Utc>) -> NaiveDate {
+        self.timezone.date_of(now)
     }
 
     pub fn quit(&mut self) {
@@ -15,6 +120,405 @@ impl App {
     pub fn should_quit(&self) -> bool {
         self.should_quit
     }
+
+    /// 一覧に表示するタスクを設定
+    ///
+    /// 一覧から消えたタスクのマークは外します。
+    pub fn set_tasks(&mut self, tasks: Vec<TaskDTO>) {
+        self.tasks = tasks;
+        self.selected = self.selected.min(self.tasks.len().saturating_sub(1));
+        let ids: HashSet<i32> = self.tasks.iter().map(|task| task.id).collect();
+        self.marked.retain(|id| ids.contains(id));
+    }
+
+    /// タグの追加で選択肢にするタグを設定
+    pub fn set_tags(&mut self, tags: Vec<TagDTO>) {
+        self.tags = tags;
+    }
+
+    pub fn tags(&self) -> &[TagDTO] {
+        &self.tags
+    }
+
+    /// ステータスバーに表示する今日の完了数と連続記録を設定
+    pub fn set_summary(&mut self, summary: TaskSummaryDTO) {
+        self.summary = Some(summary);
+    }
+
+    pub fn summary(&self) -> Option<&TaskSummaryDTO> {
+        self.summary.as_ref()
+    }
+
+    pub fn tasks(&self) -> &[TaskDTO] {
+        &self.tasks
+    }
+
+    /// 選択中の行番号
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// 選択中のタスク
+    pub fn selected_task(&self) -> Option<&TaskDTO> {
+        self.tasks.get(self.selected)
+    }
+
+    /// 次のタスクを選択（末尾では止まる）
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.tasks.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// 前のタスクを選択（先頭では止まる）
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// 選択中のタスクの詳細を右側に並べて表示するかを切り替える
+    pub fn toggle_detail(&mut self) {
+        self.show_detail = !self.show_detail;
+    }
+
+    pub fn show_detail(&self) -> bool {
+        self.show_detail
+    }
+
+    /// 期限日カレンダーを開く
+    pub fn open_calendar(&mut self, today: NaiveDate) {
+        self.calendar = Some(Calendar::new(today).with_week_start(self.week_start));
+    }
+
+    /// 期限日カレンダーを閉じて一覧に戻る
+    pub fn close_calendar(&mut self) {
+        self.calendar = None;
+    }
+
+    pub fn calendar(&self) -> Option<&Calendar> {
+        self.calendar.as_ref()
+    }
+
+    pub fn calendar_mut(&mut self) -> Option<&mut Calendar> {
+        self.calendar.as_mut()
+    }
+
+    /// タスクの読み込みが必要なカレンダーの年月を取り出す（読み込み中として記録する）
+    pub fn take_calendar_request(&mut self) -> Option<(i32, u32)> {
+        let calendar = self
+            .calendar
+            .as_mut()
+            .filter(|calendar| calendar.needs_reload())?;
+        calendar.mark_requested();
+        Some(calendar.year_month())
+    }
+
+    /// カレンダーで選んだタスクを一覧で選択して、カレンダーを閉じる
+    pub fn jump_to_calendar_task(&mut self) {
+        let Some(id) = self
+            .calendar
+            .as_ref()
+            .and_then(Calendar::selected_task)
+            .map(|task| task.id)
+        else {
+            return;
+        };
+        if let Some(index) = self.tasks.iter().position(|task| task.id == id) {
+            self.selected = index;
+        }
+        self.calendar = None;
+    }
+
+    /// 選択中のタスクのマークを切り替えて次の行へ進む
+    pub fn toggle_mark(&mut self) {
+        let Some(id) = self.selected_task().map(|task| task.id) else {
+            return;
+        };
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+        self.select_next();
+    }
+
+    /// すべてのマークを外す
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+
+    pub fn is_marked(&self, id: i32) -> bool {
+        self.marked.contains(&id)
+    }
+
+    pub fn marked_count(&self) -> usize {
+        self.marked.len()
+    }
+
+    /// 一括操作の対象
+    ///
+    /// マークしたタスクを一覧の順に返します。マークがなければ選択中のタスクが対象です。
+    pub fn bulk_targets(&self) -> Vec<TaskDTO> {
+        if self.marked.is_empty() {
+            return self.selected_task().cloned().into_iter().collect();
+        }
+        self.tasks
+            .iter()
+            .filter(|task| self.marked.contains(&task.id))
+            .cloned()
+            .collect()
+    }
+
+    pub fn mode(&self) -> &Mode {
+        &self.mode
+    }
+
+    /// 一括操作の確認を求める
+    ///
+    /// CLIと同じく、[`TYPED_CONFIRMATION_MIN_COUNT`]件以上を削除する場合は件数の入力を求めます。
+    pub fn request_bulk_action(&mut self, action: BulkAction) {
+        let count = self.bulk_targets().len();
+        if count == 0 {
+            return;
+        }
+        self.mode = if action == BulkAction::Delete && count >= TYPED_CONFIRMATION_MIN_COUNT {
+            Mode::ConfirmTyped {
+                action,
+                input: String::new(),
+            }
+        } else {
+            Mode::Confirm(action)
+        };
+    }
+
+    /// 優先度の選択を開く
+    pub fn open_priority_picker(&mut self) {
+        if !self.bulk_targets().is_empty() {
+            self.mode = Mode::PriorityPicker { selected: 0 };
+        }
+    }
+
+    /// タグの選択を開く
+    pub fn open_tag_picker(&mut self) {
+        if self.bulk_targets().is_empty() {
+            return;
+        }
+        if self.tags.is_empty() {
+            self.set_status(Msg::NoTagsFound.text());
+            return;
+        }
+        self.mode = Mode::TagPicker { selected: 0 };
+    }
+
+    /// 選択肢の数
+    fn picker_len(&self) -> usize {
+        match self.mode {
+            Mode::PriorityPicker { .. } => Priority::iter().count(),
+            Mode::TagPicker { .. } => self.tags.len(),
+            _ => 0,
+        }
+    }
+
+    /// 選択肢を1つ下へ移動
+    pub fn picker_next(&mut self) {
+        let len = self.picker_len();
+        if let Mode::PriorityPicker { selected } | Mode::TagPicker { selected } = &mut self.mode
+            && *selected + 1 < len
+        {
+            *selected += 1;
+        }
+    }
+
+    /// 選択肢を1つ上へ移動
+    pub fn picker_previous(&mut self) {
+        if let Mode::PriorityPicker { selected } | Mode::TagPicker { selected } = &mut self.mode {
+            *selected = selected.saturating_sub(1);
+        }
+    }
+
+    /// 選択肢を確定して実行確認へ進む
+    pub fn picker_select(&mut self) {
+        let action = match self.mode {
+            Mode::PriorityPicker { selected } => {
+                Priority::iter().nth(selected).map(BulkAction::SetPriority)
+            }
+            Mode::TagPicker { selected } => self.tags.get(selected).map(|tag| BulkAction::AddTag {
+                id: tag.id,
+                name: tag.name.clone(),
+            }),
+            _ => None,
+        };
+        if let Some(action) = action {
+            self.mode = Mode::Confirm(action);
+        }
+    }
+
+    /// 確認中の一括操作を実行待ちにする
+    ///
+    /// 件数の入力を求めている場合は、入力が件数と一致したときだけ実行します（一致しない場合は取り消す）。
+    pub fn confirm(&mut self) {
+        let count = self.bulk_targets().len();
+        match std::mem::replace(&mut self.mode, Mode::Normal) {
+            Mode::Confirm(action) => self.pending_bulk_action = Some(action),
+            Mode::ConfirmTyped { action, input }
+                if matches_confirmation(&input, &count.to_string()) =>
+            {
+                self.pending_bulk_action = Some(action)
+            }
+            _ => {}
+        }
+    }
+
+    /// 確認のための入力に1文字追加する
+    pub fn push_confirm_input(&mut self, c: char) {
+        if let Mode::ConfirmTyped { input, .. } = &mut self.mode {
+            input.push(c);
+        }
+    }
+
+    /// 確認のための入力を1文字削除する
+    pub fn pop_confirm_input(&mut self) {
+        if let Mode::ConfirmTyped { input, .. } = &mut self.mode {
+            input.pop();
+        }
+    }
+
+    /// 選択・確認を取り消して一覧に戻る
+    pub fn cancel(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    /// 実行待ちの一括操作を取り出す
+    pub fn take_bulk_action(&mut self) -> Option<BulkAction> {
+        self.pending_bulk_action.take()
+    }
+
+    /// セッション中の変更履歴
+    pub fn history_mut(&mut self) -> &mut History {
+        &mut self.history
+    }
+
+    /// 取り消し・やり直しを実行待ちにする
+    pub fn request_history(&mut self, direction: HistoryDirection) {
+        self.pending_history = Some(direction);
+    }
+
+    /// 実行待ちの取り消し・やり直しを取り出す
+    pub fn take_history_request(&mut self) -> Option<HistoryDirection> {
+        self.pending_history.take()
+    }
+
+    /// 選択中のタスクでポモドーロを開始
+    pub fn start_pomodoro(&mut self, now: DateTime<Utc>) {
+        if let Some(task) = self.selected_task() {
+            self.pomodoro = Some(Pomodoro::start(task.id, task.title.clone(), now));
+            self.status_message = None;
+        }
+    }
+
+    /// ポモドーロを終了して一覧に戻る
+    ///
+    /// 途中の作業フェーズは作業記録に含めません。
+    pub fn stop_pomodoro(&mut self) {
+        self.pomodoro = None;
+    }
+
+    pub fn pomodoro(&self) -> Option<&Pomodoro> {
+        self.pomodoro.as_ref()
+    }
+
+    /// ポモドーロの時間を進める
+    ///
+    /// # Returns
+    /// フェーズが切り替わった場合は、対象のタスクIDと切り替わりの内容
+    pub fn tick_pomodoro(&mut self, now: DateTime<Utc>) -> Option<(i32, PhaseChange)> {
+        let pomodoro = self.pomodoro.as_mut()?;
+        pomodoro
+            .tick(now)
+            .map(|change| (pomodoro.task_id(), change))
+    }
+
+    /// バックグラウンドに処理を依頼したことを記録する
+    pub fn begin_action(&mut self) {
+        self.busy += 1;
+    }
+
+    /// バックグラウンドの処理中か
+    pub fn is_busy(&self) -> bool {
+        self.busy > 0
+    }
+
+    /// 一定間隔で呼ばれ、スピナーを進める
+    pub fn on_tick(&mut self) {
+        if self.is_busy() {
+            self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
+        }
+    }
+
+    /// 処理中であればスピナーの現在のコマ
+    pub fn spinner(&self) -> Option<char> {
+        self.is_busy().then(|| SPINNER_FRAMES[self.spinner_frame])
+    }
+
+    /// バックグラウンドの処理結果を状態に反映する
+    pub fn apply_data_event(&mut self, event: DataEvent) {
+        match event {
+            DataEvent::TasksLoaded(tasks) => self.set_tasks(tasks),
+            DataEvent::TagsLoaded(tags) => self.set_tags(tags),
+            DataEvent::SummaryLoaded(summary) => self.set_summary(summary),
+            DataEvent::CalendarLoaded { year_month, tasks } => {
+                if let Some(calendar) = self.calendar.as_mut() {
+                    calendar.set_tasks(year_month, tasks);
+                }
+            }
+            DataEvent::Progress { current, total } => {
+                self.set_status(Msg::TuiBulkProgress.format(&[&current, &total]))
+            }
+            DataEvent::BulkApplied {
+                changes,
+                succeeded,
+                failures,
+            } => {
+                // 一部が失敗しても、適用できた変更は取り消せるように記録する
+                self.history.record(changes);
+                self.clear_marks();
+                self.set_status(match failures.first() {
+                    None => Msg::TuiBulkDone.format(&[&succeeded]),
+                    Some(first) => Msg::TuiBulkFailed.format(&[&succeeded, &failures.len(), first]),
+                });
+            }
+            DataEvent::HistoryReplayed {
+                direction,
+                changes,
+                remapped,
+            } => {
+                // 作り直したタスクはIDが変わるため、履歴内の参照を付け替える
+                for (old, new) in remapped {
+                    self.history.remap_id(old, new);
+                }
+                let count = changes.len();
+                self.history.push_applied(direction, changes);
+                self.set_status(match direction {
+                    HistoryDirection::Undo => Msg::TuiUndone.format(&[&count]),
+                    HistoryDirection::Redo => Msg::TuiRedone.format(&[&count]),
+                });
+            }
+            DataEvent::TimeEntryRecorded => {}
+            DataEvent::Failed { message, calendar } => {
+                if calendar {
+                    self.close_calendar();
+                }
+                self.set_status(message);
+            }
+            DataEvent::Done => self.busy = self.busy.saturating_sub(1),
+        }
+    }
+
+    /// 画面下部に表示するメッセージを設定
+    pub fn set_status(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
+
+    pub fn status_message(&self) -> Option<&str> {
+        self.status_message.as_deref()
+    }
 }
 
 impl Default for App {
@@ -26,11 +530,40 @@ impl Default for App {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::task::value_objects::Status;
+    use crate::interface::tui::{history::TaskChange, pomodoro::WORK_MINUTES};
+    use chrono::TimeDelta;
+
+    fn task_dto(id: i32, title: &str) -> TaskDTO {
+        TaskDTO {
+            id,
+            title: title.to_string(),
+            description: None,
+            status: Status::Pending,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date: None,
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
 
     #[test]
     fn test_app_initial_state() {
         let app = App::new();
         assert!(!app.should_quit());
+        assert!(app.selected_task().is_none());
+        assert!(app.pomodoro().is_none());
     }
 
     #[test]
@@ -39,4 +572,269 @@ mod tests {
         app.quit();
         assert!(app.should_quit());
     }
+
+    #[test]
+    fn test_selection_stays_within_bounds() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A"), task_dto(2, "B")]);
+
+        // Act & Assert
+        app.select_previous();
+        assert_eq!(app.selected(), 0);
+        app.select_next();
+        app.select_next();
+        assert_eq!(app.selected_task().unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_start_pomodoro_for_selected_task_and_tick() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A"), task_dto(2, "B")]);
+        app.select_next();
+        let now = Utc::now();
+
+        // Act
+        app.start_pomodoro(now);
+        let change = app.tick_pomodoro(now + TimeDelta::minutes(WORK_MINUTES));
+
+        // Assert
+        assert_eq!(app.pomodoro().unwrap().task_title(), "B");
+        assert!(matches!(
+            change,
+            Some((2, PhaseChange::WorkCompleted { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_toggle_detail() {
+        let mut app = App::new();
+
+        app.toggle_detail();
+        assert!(app.show_detail());
+        app.toggle_detail();
+        assert!(!app.show_detail());
+    }
+
+    #[test]
+    fn test_calendar_request_and_jump_to_task() {
+        // Arrange
+        let mut app = App::new();
+        let mut due = task_dto(2, "B");
+        due.due_date = NaiveDate::from_ymd_opt(2026, 10, 20);
+        app.set_tasks(vec![task_dto(1, "A"), due.clone()]);
+        app.open_calendar(NaiveDate::from_ymd_opt(2026, 10, 17).unwrap());
+
+        // Act
+        let request = app.take_calendar_request();
+        let second_request = app.take_calendar_request();
+        let calendar = app.calendar_mut().unwrap();
+        calendar.set_tasks((2026, 10), vec![due]);
+        calendar.move_days(3);
+        calendar.focus_day();
+        app.jump_to_calendar_task();
+
+        // Assert
+        assert_eq!(request, Some((2026, 10)));
+        assert_eq!(second_request, None);
+        assert!(app.calendar().is_none());
+        assert_eq!(app.selected_task().unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_busy_until_done_and_spinner() {
+        // Arrange
+        let mut app = App::new();
+
+        // Act & Assert
+        app.begin_action();
+        assert!(app.is_busy());
+        let first = app.spinner();
+        app.on_tick();
+        assert_ne!(app.spinner(), first);
+        app.apply_data_event(DataEvent::TasksLoaded(vec![task_dto(1, "A")]));
+        assert!(app.is_busy());
+        app.apply_data_event(DataEvent::Done);
+        assert!(!app.is_busy());
+        assert!(app.spinner().is_none());
+        assert_eq!(app.tasks().len(), 1);
+    }
+
+    #[test]
+    fn test_bulk_applied_records_history_and_clears_marks() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A")]);
+        app.toggle_mark();
+
+        // Act
+        app.apply_data_event(DataEvent::BulkApplied {
+            changes: vec![TaskChange::new(Some(task_dto(1, "A")), None)],
+            succeeded: 1,
+            failures: vec![],
+        });
+
+        // Assert
+        assert_eq!(app.marked_count(), 0);
+        assert!(app.history_mut().take(HistoryDirection::Undo).is_some());
+    }
+
+    #[test]
+    fn test_failed_calendar_load_closes_calendar() {
+        // Arrange
+        let mut app = App::new();
+        app.open_calendar(NaiveDate::from_ymd_opt(2026, 10, 17).unwrap());
+
+        // Act
+        app.apply_data_event(DataEvent::Failed {
+            message: "disk error".to_string(),
+            calendar: true,
+        });
+
+        // Assert
+        assert!(app.calendar().is_none());
+        assert_eq!(app.status_message(), Some("disk error"));
+    }
+
+    #[test]
+    fn test_toggle_mark_and_bulk_targets() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A"), task_dto(2, "B"), task_dto(3, "C")]);
+
+        // Act: マークがなければ選択中のタスクが対象
+        let unmarked = app.bulk_targets();
+        app.toggle_mark(); // 1をマークして2へ
+        app.select_next(); // 3へ
+        app.toggle_mark(); // 3をマーク
+
+        // Assert
+        assert_eq!(unmarked.iter().map(|t| t.id).collect::<Vec<_>>(), vec![1]);
+        let ids: Vec<i32> = app.bulk_targets().iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+        assert_eq!(app.marked_count(), 2);
+    }
+
+    #[test]
+    fn test_set_tasks_drops_marks_of_removed_tasks() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A"), task_dto(2, "B")]);
+        app.toggle_mark();
+
+        // Act
+        app.set_tasks(vec![task_dto(2, "B")]);
+
+        // Assert
+        assert_eq!(app.marked_count(), 0);
+    }
+
+    #[test]
+    fn test_priority_picker_confirm_flow() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A")]);
+
+        // Act
+        app.open_priority_picker();
+        app.picker_next();
+        app.picker_select();
+        let confirming = app.mode().clone();
+        app.confirm();
+
+        // Assert
+        assert_eq!(
+            confirming,
+            Mode::Confirm(BulkAction::SetPriority(Priority::Medium))
+        );
+        assert_eq!(app.mode(), &Mode::Normal);
+        assert_eq!(
+            app.take_bulk_action(),
+            Some(BulkAction::SetPriority(Priority::Medium))
+        );
+        assert!(app.take_bulk_action().is_none());
+    }
+
+    #[test]
+    fn test_bulk_delete_requires_typed_count() {
+        // Arrange: 2件をマークする
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A"), task_dto(2, "B")]);
+        app.toggle_mark();
+        app.toggle_mark();
+
+        // Act & Assert: 件数と異なる入力では削除しない
+        app.request_bulk_action(BulkAction::Delete);
+        assert_eq!(
+            app.mode(),
+            &Mode::ConfirmTyped {
+                action: BulkAction::Delete,
+                input: String::new()
+            }
+        );
+        app.push_confirm_input('3');
+        app.confirm();
+        assert_eq!(app.mode(), &Mode::Normal);
+        assert!(app.take_bulk_action().is_none());
+
+        // Act & Assert: 件数を入力すると削除する
+        app.request_bulk_action(BulkAction::Delete);
+        app.push_confirm_input('3');
+        app.pop_confirm_input();
+        app.push_confirm_input('2');
+        app.confirm();
+        assert_eq!(app.take_bulk_action(), Some(BulkAction::Delete));
+    }
+
+    #[test]
+    fn test_bulk_complete_and_single_delete_use_yes_no_confirm() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A"), task_dto(2, "B")]);
+
+        // Act & Assert: 1件の削除と、元に戻しやすい一括の完了は通常の確認
+        app.request_bulk_action(BulkAction::Delete);
+        assert_eq!(app.mode(), &Mode::Confirm(BulkAction::Delete));
+        app.cancel();
+        app.toggle_mark();
+        app.toggle_mark();
+        app.request_bulk_action(BulkAction::Complete);
+        assert_eq!(app.mode(), &Mode::Confirm(BulkAction::Complete));
+    }
+
+    #[test]
+    fn test_cancel_discards_bulk_action() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A")]);
+        app.request_bulk_action(BulkAction::Delete);
+
+        // Act
+        app.cancel();
+
+        // Assert
+        assert_eq!(app.mode(), &Mode::Normal);
+        assert!(app.take_bulk_action().is_none());
+    }
+
+    #[test]
+    fn test_tag_picker_requires_tags() {
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1, "A")]);
+
+        app.open_tag_picker();
+
+        assert_eq!(app.mode(), &Mode::Normal);
+        assert!(app.status_message().is_some());
+    }
+
+    #[test]
+    fn test_start_pomodoro_without_tasks_does_nothing() {
+        let mut app = App::new();
+
+        app.start_pomodoro(Utc::now());
+
+        assert!(app.pomodoro().is_none());
+    }
 }
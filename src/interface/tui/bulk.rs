@@ -0,0 +1,483 @@
+use crate::{
+    application::{
+        dto::{TaskDTO, UpdateTaskDTO},
+        use_cases::task::{delete_task::DeleteTaskUseCase, edit_task::EditTaskUseCase},
+    },
+    domain::{
+        clock::Clock,
+        tag::repository::TagRepository,
+        task::{
+            aggregate::TaskAggregate,
+            repository::TaskRepository,
+            value_objects::{Priority, Status, TaskId},
+        },
+        time_entry::{
+            aggregate::{NewTimeEntry, TimeEntryAggregate},
+            repository::TimeEntryRepository,
+        },
+    },
+    interface::{cli::display::format::format_priority, i18n::Msg, tui::history::TaskChange},
+};
+use anyhow::Result;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// マークしたタスクにまとめて適用する操作
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkAction {
+    /// 完了にする
+    Complete,
+    /// 削除する
+    Delete,
+    /// 優先度を変更する
+    SetPriority(Priority),
+    /// タグを追加する
+    AddTag { id: i32, name: String },
+}
+
+impl BulkAction {
+    /// 実行前の確認メッセージ
+    pub fn confirm_message(&self, count: usize) -> String {
+        match self {
+            BulkAction::Complete => Msg::TuiConfirmBulkComplete.format(&[&count]),
+            BulkAction::Delete => Msg::TuiConfirmBulkDelete.format(&[&count]),
+            BulkAction::SetPriority(priority) => {
+                Msg::TuiConfirmBulkPriority.format(&[&count, &format_priority(*priority)])
+            }
+            BulkAction::AddTag { name, .. } => Msg::TuiConfirmBulkTag.format(&[&count, name]),
+        }
+    }
+}
+
+/// 削除したタスクを作り直すためのスナップショット
+#[derive(Debug, Clone)]
+struct DeletedTask {
+    task: TaskAggregate,
+    time_entries: Vec<TimeEntryAggregate>,
+}
+
+/// 一括操作や取り消し・やり直しを既存のユースケースで1件ずつ実行する
+///
+/// 削除したタスクは、取り消しで元どおりに作り直せるよう、削除前の状態と作業記録を保持します。
+pub struct BulkActionRunner {
+    task_repo: Arc<dyn TaskRepository>,
+    time_entry_repo: Arc<dyn TimeEntryRepository>,
+    edit_task: EditTaskUseCase,
+    delete_task: DeleteTaskUseCase,
+    /// 削除したタスクのスナップショット（削除したときのIDごと）
+    deleted: Mutex<HashMap<i32, DeletedTask>>,
+}
+
+impl BulkActionRunner {
+    pub fn new(
+        task_repo: Arc<dyn TaskRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+        time_entry_repo: Arc<dyn TimeEntryRepository>,
+    ) -> Self {
+        Self {
+            task_repo: task_repo.clone(),
+            time_entry_repo,
+            edit_task: EditTaskUseCase::new(task_repo.clone(), tag_repo),
+            delete_task: DeleteTaskUseCase::new(task_repo),
+            deleted: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 更新日時の記録に使用するClockを設定（既定はシステムの時刻）
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.edit_task = self.edit_task.with_clock(clock);
+        self
+    }
+
+    /// 1件のタスクに操作を適用する
+    ///
+    /// # Returns
+    /// * `Ok(Some(TaskChange))` - 変更前後のタスク（取り消し用）
+    /// * `Ok(None)` - 変更がなかった場合
+    pub async fn apply(&self, action: &BulkAction, task: &TaskDTO) -> Result<Option<TaskChange>> {
+        let dto = match action {
+            BulkAction::Delete => {
+                self.delete(task.id).await?;
+                return Ok(Some(TaskChange::new(Some(task.clone()), None)));
+            }
+            BulkAction::Complete => UpdateTaskDTO {
+                status: Some(Status::Completed),
+                ..Default::default()
+            },
+            BulkAction::SetPriority(priority) => UpdateTaskDTO {
+                priority: Some(*priority),
+                ..Default::default()
+            },
+            BulkAction::AddTag { id, .. } => {
+                // 既に付いているタグはそのままにする
+                if task.tags.iter().any(|tag| tag.id == *id) {
+                    return Ok(None);
+                }
+                let mut tags: Vec<i32> = task.tags.iter().map(|tag| tag.id).collect();
+                tags.push(*id);
+                UpdateTaskDTO {
+                    tags: Some(tags),
+                    ..Default::default()
+                }
+            }
+        };
+        let updated = self.edit_task.execute(task.id, dto).await?;
+        Ok(Some(TaskChange::new(Some(task.clone()), Some(updated))))
+    }
+
+    /// タスクを指定した状態に戻す
+    ///
+    /// TUIで変更する項目（ステータス・優先度・タグ）と、タスクの有無を戻します。
+    /// 削除したタスクは、削除前の内容（作成日時・チェックリスト・gitの紐づけ・外部の項目との対応・
+    /// ステータスの変更履歴）と作業記録ごと新しいIDで作り直されます。
+    ///
+    /// # Arguments
+    /// * `current` - 現在のタスク（存在しない場合はNone）
+    /// * `target` - 戻す先のタスク（削除する場合はNone）
+    ///
+    /// # Returns
+    /// 戻した後のタスク（削除した場合はNone）
+    pub async fn restore(
+        &self,
+        current: Option<&TaskDTO>,
+        target: Option<&TaskDTO>,
+    ) -> Result<Option<TaskDTO>> {
+        match (current, target) {
+            (Some(current), Some(target)) => {
+                let dto = UpdateTaskDTO {
+                    status: Some(target.status),
+                    priority: Some(target.priority),
+                    tags: Some(target.tags.iter().map(|tag| tag.id).collect()),
+                    // 完了の取り消しでは完了済みのタスクを元のステータスに戻す
+                    reopen: true,
+                    ..Default::default()
+                };
+                self.edit_task.execute(current.id, dto).await.map(Some)
+            }
+            (Some(current), None) => {
+                self.delete(current.id).await?;
+                Ok(None)
+            }
+            (None, Some(target)) => self.recreate(target).await.map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// 削除前の状態と作業記録を保持してからタスクを削除する
+    async fn delete(&self, id: i32) -> Result<()> {
+        let task_id = TaskId::new(id)?;
+        let snapshot = match self.task_repo.find_by_id(&task_id).await? {
+            Some(task) => Some(DeletedTask {
+                task,
+                time_entries: self.time_entry_repo.find_by_task(&task_id).await?,
+            }),
+            None => None,
+        };
+        self.delete_task.execute(id).await?;
+        if let Some(snapshot) = snapshot {
+            self.deleted_tasks().insert(id, snapshot);
+        }
+        Ok(())
+    }
+
+    /// 削除したタスクを作業記録ごと作り直す
+    async fn recreate(&self, target: &TaskDTO) -> Result<TaskDTO> {
+        let id = target.id;
+        let Some(snapshot) = self.deleted_tasks().remove(&id) else {
+            anyhow::bail!(Msg::TuiCannotRestoreTask.format(&[&id]));
+        };
+        let saved = self
+            .task_repo
+            .save(snapshot.task.clone().into_new_task())
+            .await;
+        let saved = match saved {
+            Ok(saved) => saved,
+            Err(e) => {
+                // もう一度取り消せるよう、スナップショットは残す
+                self.deleted_tasks().insert(id, snapshot);
+                return Err(e);
+            }
+        };
+        for entry in snapshot.time_entries {
+            self.time_entry_repo
+                .save(NewTimeEntry::new(
+                    *saved.id(),
+                    *entry.started_at(),
+                    *entry.ended_at(),
+                )?)
+                .await?;
+        }
+
+        // 作り直したタスクのタグは削除前と同じため、タグ名は削除前の表示から引き継ぐ
+        let mut dto = TaskDTO::from(saved);
+        dto.tags = target.tags.clone();
+        Ok(dto)
+    }
+
+    fn deleted_tasks(&self) -> std::sync::MutexGuard<'_, HashMap<i32, DeletedTask>> {
+        self.deleted.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::SystemClock;
+    use crate::interface::persistence::sea_orm::test_support::setup_db;
+    use crate::{
+        application::{
+            dto::CreateTagDTO,
+            use_cases::{tag::add_tag::AddTagUseCase, task::show_task::ShowTaskUseCase},
+        },
+        domain::{
+            clock::FixedClock,
+            task::{
+                builder::TaskBuilder,
+                status_transition::StatusTransitionPolicy,
+                value_objects::{ChecklistItem, CustomField, GitLink, TaskTitle},
+            },
+        },
+        interface::{
+            persistence::{
+                conformance::with_checklist,
+                in_memory::{
+                    InMemoryTagRepository, InMemoryTaskRepository, InMemoryTimeEntryRepository,
+                },
+                sea_orm::{SeaOrmTagRepository, SeaOrmTaskRepository, SeaOrmTimeEntryRepository},
+            },
+            tui::history::HistoryDirection,
+        },
+    };
+    use chrono::TimeDelta;
+
+    fn runner(
+        task_repo: &Arc<InMemoryTaskRepository>,
+        tag_repo: &Arc<InMemoryTagRepository>,
+    ) -> BulkActionRunner {
+        BulkActionRunner::new(
+            task_repo.clone(),
+            tag_repo.clone(),
+            Arc::new(InMemoryTimeEntryRepository::new()),
+        )
+    }
+
+    async fn setup() -> (
+        Arc<InMemoryTaskRepository>,
+        Arc<InMemoryTagRepository>,
+        TaskDTO,
+    ) {
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let task = task_repo
+            .save(
+                TaskBuilder::new(TaskTitle::new("資料作成").unwrap())
+                    .description(Default::default())
+                    .build(),
+            )
+            .await
+            .unwrap();
+        (task_repo, tag_repo, TaskDTO::from(task))
+    }
+
+    #[tokio::test]
+    async fn test_apply_complete_and_priority() {
+        // Arrange
+        let (task_repo, tag_repo, task) = setup().await;
+        let runner = runner(&task_repo, &tag_repo);
+
+        // Act
+        runner.apply(&BulkAction::Complete, &task).await.unwrap();
+        runner
+            .apply(&BulkAction::SetPriority(Priority::High), &task)
+            .await
+            .unwrap();
+
+        // Assert
+        let updated = ShowTaskUseCase::new(task_repo, tag_repo)
+            .execute(task.id)
+            .await
+            .unwrap();
+        assert_eq!(updated.status, Status::Completed);
+        assert_eq!(updated.priority, Priority::High);
+    }
+
+    #[tokio::test]
+    async fn test_apply_add_tag_keeps_existing_tags() {
+        // Arrange
+        let (task_repo, tag_repo, task) = setup().await;
+        let add_tag = AddTagUseCase::new(tag_repo.clone());
+        let tag = add_tag
+            .execute(CreateTagDTO {
+                name: "仕事".to_string(),
+                description: None,
+                parent_id: None,
+            })
+            .await
+            .unwrap();
+        let runner = runner(&task_repo, &tag_repo);
+        let action = BulkAction::AddTag {
+            id: tag.id,
+            name: tag.name,
+        };
+
+        // Act: 2回適用してもタグは重複しない
+        runner.apply(&action, &task).await.unwrap();
+        let tagged = ShowTaskUseCase::new(task_repo.clone(), tag_repo.clone())
+            .execute(task.id)
+            .await
+            .unwrap();
+        runner.apply(&action, &tagged).await.unwrap();
+
+        // Assert
+        let updated = ShowTaskUseCase::new(task_repo, tag_repo)
+            .execute(task.id)
+            .await
+            .unwrap();
+        assert_eq!(updated.tags.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_apply_delete() {
+        // Arrange
+        let (task_repo, tag_repo, task) = setup().await;
+        let runner = runner(&task_repo, &tag_repo);
+
+        // Act
+        let change = runner.apply(&BulkAction::Delete, &task).await.unwrap();
+
+        // Assert
+        assert!(task_repo.find_all().await.unwrap().is_empty());
+        assert_eq!(change, Some(TaskChange::new(Some(task), None)));
+    }
+
+    #[tokio::test]
+    async fn test_restore_reverts_edit_and_recreates_deleted_task() {
+        // Arrange
+        let (task_repo, tag_repo, task) = setup().await;
+        let runner = runner(&task_repo, &tag_repo);
+        let completed = runner
+            .apply(&BulkAction::Complete, &task)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Act: 完了を取り消してから、削除して作り直す
+        let reverted = runner
+            .restore(completed.after(), completed.before())
+            .await
+            .unwrap()
+            .unwrap();
+        runner.restore(Some(&reverted), None).await.unwrap();
+        let recreated = runner.restore(None, Some(&task)).await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(reverted.status, Status::Pending);
+        assert_ne!(recreated.id, task.id);
+        assert_eq!(recreated.title, task.title);
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_undo_delete_keeps_custom_fields() {
+        // Arrange
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        let mut new_task = TaskBuilder::new(TaskTitle::new("請求書").unwrap()).build();
+        new_task.set_custom_field(CustomField::new("client", "ACME").unwrap());
+        let task = TaskDTO::from(task_repo.save(new_task).await.unwrap());
+        let runner = runner(&task_repo, &tag_repo);
+        let deleted = runner
+            .apply(&BulkAction::Delete, &task)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Act
+        let (current, target) = deleted.endpoints(HistoryDirection::Undo);
+        let recreated = runner.restore(current, target).await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(
+            recreated.custom_fields.get("client").map(String::as_str),
+            Some("ACME")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_undo_delete_restores_dependents_and_time_entries() {
+        // Arrange: チェックリスト・gitの紐づけ・ステータスの変更履歴・作業記録のあるタスク
+        let (_dir, db) = setup_db().await;
+        let task_repo = Arc::new(SeaOrmTaskRepository::new(db.clone()));
+        let time_entry_repo = Arc::new(SeaOrmTimeEntryRepository::new(db.clone()));
+        let created_at = "2026-01-05T09:00:00Z".parse().unwrap();
+        let new_task = with_checklist(
+            TaskBuilder::new(TaskTitle::new("リリース").unwrap()).build(),
+            vec![ChecklistItem::new("タグを打つ", true).unwrap()],
+        );
+        let mut task = task_repo.save(new_task).await.unwrap();
+        task.link_git(
+            GitLink::new("yaru", None, "0123456789abcdef", created_at).unwrap(),
+            &SystemClock::default(),
+        )
+        .unwrap();
+        task.change_status(
+            Status::InProgress,
+            &StatusTransitionPolicy::default(),
+            &FixedClock::new(created_at),
+        )
+        .unwrap();
+        let task = task_repo.update(task).await.unwrap();
+        time_entry_repo
+            .save(
+                NewTimeEntry::new(*task.id(), created_at, created_at + TimeDelta::minutes(25))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let runner = BulkActionRunner::new(
+            task_repo.clone(),
+            Arc::new(SeaOrmTagRepository::new(db.clone())),
+            time_entry_repo.clone(),
+        );
+        let deleted = runner
+            .apply(&BulkAction::Delete, &TaskDTO::from(task.clone()))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Act
+        let (current, target) = deleted.endpoints(HistoryDirection::Undo);
+        let recreated = runner.restore(current, target).await.unwrap().unwrap();
+
+        // Assert
+        let restored = task_repo
+            .find_by_id(&TaskId::new(recreated.id).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_ne!(restored.id(), task.id());
+        assert_eq!(restored.created_at(), task.created_at());
+        assert_eq!(restored.checklist(), task.checklist());
+        assert_eq!(restored.git_links(), task.git_links());
+        assert_eq!(restored.status_history(), task.status_history());
+        let entries = time_entry_repo.find_by_task(restored.id()).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].duration(), TimeDelta::minutes(25));
+    }
+
+    #[tokio::test]
+    async fn test_restore_without_snapshot_fails() {
+        // Arrange: このセッションで削除していないタスク
+        let (task_repo, tag_repo, task) = setup().await;
+        let runner = runner(&task_repo, &tag_repo);
+
+        // Act
+        let result = runner.restore(None, Some(&task)).await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(task_repo.find_all().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::application::dto::task_dto::TaskDTO;
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+/// カレンダーの1週間（週の始まりの曜日から順に、前後の月の日はNone）
+pub type Week = [Option<NaiveDate>; 7];
+
+/// 期限日カレンダーの状態
+///
+/// 表示中の月に期限日があるタスクを保持し、カーソルの日付のタスクを一覧できます。
+pub struct Calendar {
+    cursor: NaiveDate,
+    tasks: Vec<TaskDTO>,
+    loaded_month: Option<(i32, u32)>,
+    requested_month: Option<(i32, u32)>,
+    day_focused: bool,
+    day_selected: usize,
+    week_start: Weekday,
+}
+
+impl Calendar {
+    /// 指定した日付にカーソルを置いたカレンダーを作成
+    pub fn new(cursor: NaiveDate) -> Self {
+        Self {
+            cursor,
+            tasks: Vec::new(),
+            loaded_month: None,
+            requested_month: None,
+            day_focused: false,
+            day_selected: 0,
+            week_start: Weekday::Mon,
+        }
+    }
+
+    /// 週の始まりの曜日を設定（既定は月曜日）
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// 週の始まりから順に並べた曜日
+    pub fn weekdays(&self) -> [Weekday; 7] {
+        let mut weekdays = [self.week_start; 7];
+        for i in 1..7 {
+            weekdays[i] = weekdays[i - 1].succ();
+        }
+        weekdays
+    }
+
+    pub fn cursor(&self) -> NaiveDate {
+        self.cursor
+    }
+
+    /// カーソルのある年月
+    pub fn year_month(&self) -> (i32, u32) {
+        (self.cursor.year(), self.cursor.month())
+    }
+
+    /// カーソルを日単位で移動（負の値で過去へ）
+    pub fn move_days(&mut self, days: i64) {
+        let moved = if days >= 0 {
+            self.cursor.checked_add_days(Days::new(days.unsigned_abs()))
+        } else {
+            self.cursor.checked_sub_days(Days::new(days.unsigned_abs()))
+        };
+        self.set_cursor(moved);
+    }
+
+    /// カーソルを月単位で移動（負の値で過去へ）
+    ///
+    /// 移動先の月に同じ日がない場合は月末に合わせます。
+    pub fn move_months(&mut self, months: i32) {
+        let moved = if months >= 0 {
+            self.cursor
+                .checked_add_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.cursor
+                .checked_sub_months(Months::new(months.unsigned_abs()))
+        };
+        self.set_cursor(moved);
+    }
+
+    fn set_cursor(&mut self, moved: Option<NaiveDate>) {
+        if let Some(date) = moved {
+            self.cursor = date;
+            self.day_selected = 0;
+        }
+    }
+
+    /// 表示中の月のタスクの読み込みが必要か（読み込み中の場合は不要）
+    pub fn needs_reload(&self) -> bool {
+        let current = Some(self.year_month());
+        self.loaded_month != current && self.requested_month != current
+    }
+
+    /// 表示中の月のタスクの読み込みを依頼したことを記録
+    pub fn mark_requested(&mut self) {
+        self.requested_month = Some(self.year_month());
+    }
+
+    /// 指定した月に期限日があるタスクを設定
+    pub fn set_tasks(&mut self, year_month: (i32, u32), tasks: Vec<TaskDTO>) {
+        self.loaded_month = Some(year_month);
+        self.tasks = tasks;
+        self.day_selected = 0;
+    }
+
+    /// 指定した日が期限日のタスク
+    pub fn tasks_on(&self, date: NaiveDate) -> Vec<&TaskDTO> {
+        self.tasks
+            .iter()
+            .filter(|task| task.due_date == Some(date))
+            .collect()
+    }
+
+    /// 指定した日が期限日のタスク数
+    pub fn count_on(&self, date: NaiveDate) -> usize {
+        self.tasks
+            .iter()
+            .filter(|task| task.due_date == Some(date))
+            .count()
+    }
+
+    /// 表示中の月を週ごとに区切った日付
+    pub fn weeks(&self) -> Vec<Week> {
+        let first = self.cursor.with_day(1).unwrap_or(self.cursor);
+        let offset = first.weekday().days_since(self.week_start) as usize;
+
+        let mut weeks = Vec::new();
+        let mut week: Week = [None; 7];
+        let mut column = offset;
+        let mut day = Some(first);
+        while let Some(date) = day.filter(|d| d.month() == first.month()) {
+            week[column] = Some(date);
+            column += 1;
+            if column == 7 {
+                weeks.push(week);
+                week = [None; 7];
+                column = 0;
+            }
+            day = date.succ_opt();
+        }
+        if column > 0 {
+            weeks.push(week);
+        }
+        weeks
+    }
+
+    /// カーソルの日のタスク一覧を操作中か
+    pub fn day_focused(&self) -> bool {
+        self.day_focused
+    }
+
+    /// カーソルの日のタスク一覧に移る（タスクがない日は移らない）
+    pub fn focus_day(&mut self) {
+        if self.count_on(self.cursor) > 0 {
+            self.day_focused = true;
+            self.day_selected = 0;
+        }
+    }
+
+    /// カレンダーの操作に戻る
+    pub fn unfocus_day(&mut self) {
+        self.day_focused = false;
+    }
+
+    pub fn day_selected(&self) -> usize {
+        self.day_selected
+    }
+
+    /// カーソルの日のタスク一覧で次のタスクを選択
+    pub fn select_next_task(&mut self) {
+        if self.day_selected + 1 < self.count_on(self.cursor) {
+            self.day_selected += 1;
+        }
+    }
+
+    /// カーソルの日のタスク一覧で前のタスクを選択
+    pub fn select_previous_task(&mut self) {
+        self.day_selected = self.day_selected.saturating_sub(1);
+    }
+
+    /// カーソルの日のタスク一覧で選択中のタスク
+    pub fn selected_task(&self) -> Option<&TaskDTO> {
+        self.tasks_on(self.cursor).get(self.day_selected).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::value_objects::{Priority, Status};
+    use chrono::Utc;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn task_dto(id: i32, due: NaiveDate) -> TaskDTO {
+        TaskDTO {
+            id,
+            title: format!("タスク{}", id),
+            description: None,
+            status: Status::Pending,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date: Some(due),
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_weeks_start_on_monday() {
+        // Arrange: 2026-10-01は木曜日
+        let calendar = Calendar::new(date(10, 17));
+
+        // Act
+        let weeks = calendar.weeks();
+
+        // Assert
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][..3], [None, None, None]);
+        assert_eq!(weeks[0][3], Some(date(10, 1)));
+        assert_eq!(weeks[4][5], Some(date(10, 31)));
+        assert_eq!(weeks[4][6], None);
+    }
+
+    #[test]
+    fn test_weeks_start_on_sunday() {
+        // Arrange: 2026-10-01は木曜日
+        let calendar = Calendar::new(date(10, 17)).with_week_start(Weekday::Sun);
+
+        // Act
+        let weeks = calendar.weeks();
+
+        // Assert
+        assert_eq!(calendar.weekdays()[0], Weekday::Sun);
+        assert_eq!(calendar.weekdays()[6], Weekday::Sat);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][4], Some(date(10, 1)));
+        assert_eq!(weeks[4][6], Some(date(10, 31)));
+    }
+
+    #[test]
+    fn test_move_across_months_requires_reload() {
+        // Arrange
+        let mut calendar = Calendar::new(date(1, 31));
+        calendar.set_tasks((2026, 1), vec![]);
+
+        // Act & Assert
+        assert!(!calendar.needs_reload());
+        calendar.move_days(1);
+        assert_eq!(calendar.cursor(), date(2, 1));
+        assert!(calendar.needs_reload());
+        calendar.mark_requested();
+        assert!(!calendar.needs_reload());
+        calendar.move_days(-7);
+        assert_eq!(calendar.cursor(), date(1, 25));
+        calendar.move_months(1);
+        assert_eq!(calendar.cursor(), date(2, 25));
+    }
+
+    #[test]
+    fn test_move_months_clamps_to_month_end() {
+        let mut calendar = Calendar::new(date(1, 31));
+
+        calendar.move_months(1);
+
+        assert_eq!(calendar.cursor(), date(2, 28));
+    }
+
+    #[test]
+    fn test_count_and_drill_into_day() {
+        // Arrange
+        let mut calendar = Calendar::new(date(10, 17));
+        calendar.set_tasks(
+            (2026, 10),
+            vec![
+                task_dto(1, date(10, 17)),
+                task_dto(2, date(10, 17)),
+                task_dto(3, date(10, 20)),
+            ],
+        );
+
+        // Act
+        calendar.focus_day();
+        calendar.select_next_task();
+        calendar.select_next_task();
+
+        // Assert
+        assert_eq!(calendar.count_on(date(10, 17)), 2);
+        assert_eq!(calendar.count_on(date(10, 18)), 0);
+        assert!(calendar.day_focused());
+        assert_eq!(calendar.selected_task().unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_focus_day_without_tasks_does_nothing() {
+        let mut calendar = Calendar::new(date(10, 17));
+
+        calendar.focus_day();
+
+        assert!(!calendar.day_focused());
+    }
+}
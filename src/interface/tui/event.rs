@@ -1,4 +1,9 @@
-use crate::interface::tui::app::App;
+use crate::interface::tui::{
+    app::{App, Mode},
+    bulk::BulkAction,
+    history::HistoryDirection,
+};
+use chrono::Utc;
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// キーイベントを処理する
@@ -14,6 +19,104 @@ pub fn handle_key_event(app: &mut App, key: KeyEvent) {
         KeyCode::Char('q') | KeyCode::Char('Q') => {
             app.quit();
         }
+        // ポモドーロ中はEscで一覧に戻る以外の操作を受け付けない
+        KeyCode::Esc if app.pomodoro().is_some() => app.stop_pomodoro(),
+        _ if app.pomodoro().is_some() => {}
+        _ if app.calendar().is_some() => handle_calendar_key(app, key.code),
+        // Ctrl+rで取り消した変更をやり直す
+        KeyCode::Char('r')
+            if key.modifiers.contains(KeyModifiers::CONTROL) && *app.mode() == Mode::Normal =>
+        {
+            app.request_history(HistoryDirection::Redo)
+        }
+        _ => match app.mode() {
+            Mode::Normal => handle_list_key(app, key.code),
+            Mode::PriorityPicker { .. } | Mode::TagPicker { .. } => {
+                handle_picker_key(app, key.code)
+            }
+            Mode::Confirm(_) => handle_confirm_key(app, key.code),
+            Mode::ConfirmTyped { .. } => handle_confirm_typed_key(app, key.code),
+        },
+    }
+}
+
+/// タスク一覧でのキー操作
+fn handle_list_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Down | KeyCode::Char('j') => app.select_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.select_previous(),
+        KeyCode::Char(' ') => app.toggle_mark(),
+        KeyCode::Char('v') => app.toggle_detail(),
+        KeyCode::Char('u') => app.request_history(HistoryDirection::Undo),
+        KeyCode::Char('C') => app.open_calendar(app.date_of(Utc::now())),
+        KeyCode::Esc => app.clear_marks(),
+        KeyCode::Char('c') => app.request_bulk_action(BulkAction::Complete),
+        KeyCode::Char('d') => app.request_bulk_action(BulkAction::Delete),
+        KeyCode::Char('P') => app.open_priority_picker(),
+        KeyCode::Char('t') => app.open_tag_picker(),
+        KeyCode::Char('p') | KeyCode::Enter => app.start_pomodoro(Utc::now()),
+        _ => {}
+    }
+}
+
+/// 期限日カレンダーでのキー操作
+fn handle_calendar_key(app: &mut App, code: KeyCode) {
+    let Some(calendar) = app.calendar_mut() else {
+        return;
+    };
+
+    // その日のタスク一覧を操作中
+    if calendar.day_focused() {
+        match code {
+            KeyCode::Down | KeyCode::Char('j') => calendar.select_next_task(),
+            KeyCode::Up | KeyCode::Char('k') => calendar.select_previous_task(),
+            KeyCode::Enter => app.jump_to_calendar_task(),
+            KeyCode::Esc => calendar.unfocus_day(),
+            _ => {}
+        }
+        return;
+    }
+
+    match code {
+        KeyCode::Left | KeyCode::Char('h') => calendar.move_days(-1),
+        KeyCode::Right | KeyCode::Char('l') => calendar.move_days(1),
+        KeyCode::Up | KeyCode::Char('k') => calendar.move_days(-7),
+        KeyCode::Down | KeyCode::Char('j') => calendar.move_days(7),
+        KeyCode::PageUp | KeyCode::Char('[') => calendar.move_months(-1),
+        KeyCode::PageDown | KeyCode::Char(']') => calendar.move_months(1),
+        KeyCode::Enter => calendar.focus_day(),
+        KeyCode::Esc | KeyCode::Char('C') => app.close_calendar(),
+        _ => {}
+    }
+}
+
+/// 優先度・タグの選択でのキー操作
+fn handle_picker_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Down | KeyCode::Char('j') => app.picker_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.picker_previous(),
+        KeyCode::Enter => app.picker_select(),
+        KeyCode::Esc => app.cancel(),
+        _ => {}
+    }
+}
+
+/// 実行確認でのキー操作
+fn handle_confirm_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => app.confirm(),
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.cancel(),
+        _ => {}
+    }
+}
+
+/// 件数の入力による実行確認でのキー操作
+fn handle_confirm_typed_key(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Char(c) if c.is_ascii_digit() => app.push_confirm_input(c),
+        KeyCode::Backspace => app.pop_confirm_input(),
+        KeyCode::Enter => app.confirm(),
+        KeyCode::Esc => app.cancel(),
         _ => {}
     }
 }
@@ -21,6 +124,32 @@ pub fn handle_key_event(app: &mut App, key: KeyEvent) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::dto::task_dto::TaskDTO;
+    use crate::domain::task::value_objects::{Priority, Status};
+
+    fn task_dto(id: i32) -> TaskDTO {
+        TaskDTO {
+            id,
+            title: "資料作成".to_string(),
+            description: None,
+            status: Status::Pending,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date: None,
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
 
     #[test]
     fn test_handle_q_key_quits() {
@@ -52,6 +181,123 @@ mod tests {
         assert!(app.should_quit());
     }
 
+    #[test]
+    fn test_handle_p_starts_and_esc_stops_pomodoro() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1)]);
+
+        // Act & Assert
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE),
+        );
+        assert!(app.pomodoro().is_some());
+        handle_key_event(&mut app, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.pomodoro().is_none());
+    }
+
+    #[test]
+    fn test_handle_space_marks_and_d_confirms_delete() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1), task_dto(2)]);
+
+        // Act
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE),
+        );
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE),
+        );
+        let confirming = app.mode().clone();
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE),
+        );
+
+        // Assert
+        assert_eq!(confirming, Mode::Confirm(BulkAction::Delete));
+        assert_eq!(app.take_bulk_action(), Some(BulkAction::Delete));
+        assert_eq!(app.bulk_targets()[0].id, 1);
+    }
+
+    #[test]
+    fn test_handle_typed_confirm_for_bulk_delete() {
+        // Arrange: 2件をマークして削除を求める
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1), task_dto(2)]);
+        for code in [KeyCode::Char(' '), KeyCode::Char(' '), KeyCode::Char('d')] {
+            handle_key_event(&mut app, KeyEvent::new(code, KeyModifiers::NONE));
+        }
+
+        // Act: yでは確定せず、件数を入力してEnterで確定する
+        for code in [KeyCode::Char('y'), KeyCode::Char('2'), KeyCode::Enter] {
+            handle_key_event(&mut app, KeyEvent::new(code, KeyModifiers::NONE));
+        }
+
+        // Assert
+        assert_eq!(app.take_bulk_action(), Some(BulkAction::Delete));
+        assert_eq!(app.mode(), &Mode::Normal);
+    }
+
+    #[test]
+    fn test_handle_esc_in_picker_cancels() {
+        // Arrange
+        let mut app = App::new();
+        app.set_tasks(vec![task_dto(1)]);
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT),
+        );
+
+        // Act
+        handle_key_event(&mut app, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        // Assert
+        assert_eq!(app.mode(), &Mode::Normal);
+        assert!(app.take_bulk_action().is_none());
+    }
+
+    #[test]
+    fn test_handle_calendar_navigation_and_close() {
+        // Arrange
+        let mut app = App::new();
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT),
+        );
+        let opened_at = app.calendar().unwrap().cursor();
+
+        // Act
+        handle_key_event(&mut app, KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        let moved_to = app.calendar().unwrap().cursor();
+        handle_key_event(&mut app, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        // Assert
+        assert_eq!((moved_to - opened_at).num_days(), 7);
+        assert!(app.calendar().is_none());
+    }
+
+    #[test]
+    fn test_handle_u_and_ctrl_r_request_history() {
+        let mut app = App::new();
+
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE),
+        );
+        assert_eq!(app.take_history_request(), Some(HistoryDirection::Undo));
+
+        handle_key_event(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL),
+        );
+        assert_eq!(app.take_history_request(), Some(HistoryDirection::Redo));
+    }
+
     #[test]
     fn test_handle_other_key_does_not_quit() {
         let mut app = App::new();
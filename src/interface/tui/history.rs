@@ -0,0 +1,222 @@
+use crate::application::dto::task_dto::TaskDTO;
+
+/// 1件のタスクに対する変更（変更前後のタスク）
+///
+/// 存在しない状態はNoneで表します（削除なら変更後がNone）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskChange {
+    before: Option<TaskDTO>,
+    after: Option<TaskDTO>,
+}
+
+impl TaskChange {
+    pub fn new(before: Option<TaskDTO>, after: Option<TaskDTO>) -> Self {
+        Self { before, after }
+    }
+
+    pub fn before(&self) -> Option<&TaskDTO> {
+        self.before.as_ref()
+    }
+
+    pub fn after(&self) -> Option<&TaskDTO> {
+        self.after.as_ref()
+    }
+
+    /// 指定した方向に戻す・やり直すときの（現在の状態, 目標の状態）
+    pub fn endpoints(&self, direction: HistoryDirection) -> (Option<&TaskDTO>, Option<&TaskDTO>) {
+        match direction {
+            HistoryDirection::Undo => (self.after(), self.before()),
+            HistoryDirection::Redo => (self.before(), self.after()),
+        }
+    }
+
+    /// 戻す・やり直した結果で目標の状態を置き換える
+    ///
+    /// 作り直したタスクはIDが変わるため、次に逆方向へ戻すときは新しいタスクを対象にします。
+    pub fn set_result(&mut self, direction: HistoryDirection, result: Option<TaskDTO>) {
+        match direction {
+            HistoryDirection::Undo => self.before = result,
+            HistoryDirection::Redo => self.after = result,
+        }
+    }
+
+    /// 作り直したタスクのIDを付け替える
+    pub fn remap_id(&mut self, old: i32, new: i32) {
+        for task in [&mut self.before, &mut self.after].into_iter().flatten() {
+            if task.id == old {
+                task.id = new;
+            }
+        }
+    }
+}
+
+/// 取り消し・やり直しの方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryDirection {
+    /// 取り消す
+    Undo,
+    /// やり直す
+    Redo,
+}
+
+/// TUIのセッション中に行った変更の履歴
+///
+/// 1回の操作（一括操作なら対象の全タスク）を1つの単位として取り消し・やり直しします。
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Vec<TaskChange>>,
+    redo: Vec<Vec<TaskChange>>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 新しい操作を記録する（やり直しの履歴は破棄される）
+    pub fn record(&mut self, changes: Vec<TaskChange>) {
+        if changes.is_empty() {
+            return;
+        }
+        self.undo.push(changes);
+        self.redo.clear();
+    }
+
+    /// 指定した方向で次に適用する操作を取り出す
+    pub fn take(&mut self, direction: HistoryDirection) -> Option<Vec<TaskChange>> {
+        match direction {
+            HistoryDirection::Undo => self.undo.pop(),
+            HistoryDirection::Redo => self.redo.pop(),
+        }
+    }
+
+    /// 適用した操作を逆方向の履歴に積む
+    pub fn push_applied(&mut self, direction: HistoryDirection, changes: Vec<TaskChange>) {
+        match direction {
+            HistoryDirection::Undo => self.redo.push(changes),
+            HistoryDirection::Redo => self.undo.push(changes),
+        }
+    }
+
+    /// 作り直したタスクのIDを履歴全体で付け替える
+    pub fn remap_id(&mut self, old: i32, new: i32) {
+        for change in self.undo.iter_mut().chain(self.redo.iter_mut()).flatten() {
+            change.remap_id(old, new);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::value_objects::{Priority, Status};
+    use chrono::Utc;
+
+    fn task_dto(id: i32, status: Status) -> TaskDTO {
+        TaskDTO {
+            id,
+            title: "資料作成".to_string(),
+            description: None,
+            status,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            due_date: None,
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_endpoints_by_direction() {
+        let change = TaskChange::new(
+            Some(task_dto(1, Status::Pending)),
+            Some(task_dto(1, Status::Completed)),
+        );
+
+        let (current, target) = change.endpoints(HistoryDirection::Undo);
+        assert_eq!(current.unwrap().status, Status::Completed);
+        assert_eq!(target.unwrap().status, Status::Pending);
+
+        let (current, target) = change.endpoints(HistoryDirection::Redo);
+        assert_eq!(current.unwrap().status, Status::Pending);
+        assert_eq!(target.unwrap().status, Status::Completed);
+    }
+
+    #[test]
+    fn test_undo_then_redo_moves_between_stacks() {
+        // Arrange
+        let mut history = History::new();
+        let change = TaskChange::new(Some(task_dto(1, Status::Pending)), None);
+        history.record(vec![change.clone()]);
+
+        // Act & Assert
+        let undone = history.take(HistoryDirection::Undo).unwrap();
+        assert!(history.take(HistoryDirection::Undo).is_none());
+        history.push_applied(HistoryDirection::Undo, undone);
+        let redone = history.take(HistoryDirection::Redo).unwrap();
+        assert_eq!(redone, vec![change]);
+    }
+
+    #[test]
+    fn test_record_clears_redo() {
+        // Arrange
+        let mut history = History::new();
+        history.record(vec![TaskChange::new(
+            Some(task_dto(1, Status::Pending)),
+            None,
+        )]);
+        let undone = history.take(HistoryDirection::Undo).unwrap();
+        history.push_applied(HistoryDirection::Undo, undone);
+
+        // Act
+        history.record(vec![TaskChange::new(
+            Some(task_dto(2, Status::Pending)),
+            None,
+        )]);
+
+        // Assert
+        assert!(history.take(HistoryDirection::Redo).is_none());
+    }
+
+    #[test]
+    fn test_record_ignores_empty_changes() {
+        let mut history = History::new();
+
+        history.record(vec![]);
+
+        assert!(history.take(HistoryDirection::Undo).is_none());
+    }
+
+    #[test]
+    fn test_remap_id_updates_all_entries() {
+        // Arrange: 完了にしてから削除したタスク
+        let mut history = History::new();
+        history.record(vec![TaskChange::new(
+            Some(task_dto(1, Status::Pending)),
+            Some(task_dto(1, Status::Completed)),
+        )]);
+        history.record(vec![TaskChange::new(
+            Some(task_dto(1, Status::Completed)),
+            None,
+        )]);
+
+        // Act: 削除を取り消してID 5で作り直した
+        history.remap_id(1, 5);
+
+        // Assert
+        let delete = history.take(HistoryDirection::Undo).unwrap();
+        let complete = history.take(HistoryDirection::Undo).unwrap();
+        assert_eq!(delete[0].before().unwrap().id, 5);
+        assert_eq!(complete[0].after().unwrap().id, 5);
+    }
+}
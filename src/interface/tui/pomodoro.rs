@@ -0,0 +1,196 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// 作業フェーズの長さ（分）
+pub const WORK_MINUTES: i64 = 25;
+/// 休憩フェーズの長さ（分）
+pub const BREAK_MINUTES: i64 = 5;
+
+/// ポモドーロのフェーズ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PomodoroPhase {
+    /// 作業中
+    Work,
+    /// 休憩中
+    Break,
+}
+
+/// フェーズの切り替わり
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhaseChange {
+    /// 作業フェーズが終わり、休憩に入った（作業区間を作業記録として保存する）
+    WorkCompleted {
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+    },
+    /// 休憩フェーズが終わり、次の作業に入った
+    BreakCompleted,
+}
+
+/// 1つのタスクに対するポモドーロタイマー
+///
+/// 作業と休憩を交互に繰り返します。時刻は呼び出し側から渡すため、テストでは任意の時刻で進められます。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pomodoro {
+    task_id: i32,
+    task_title: String,
+    phase: PomodoroPhase,
+    phase_started_at: DateTime<Utc>,
+    work_duration: TimeDelta,
+    break_duration: TimeDelta,
+    completed: u32,
+}
+
+impl Pomodoro {
+    /// 作業フェーズから開始
+    pub fn start(task_id: i32, task_title: impl Into<String>, now: DateTime<Utc>) -> Self {
+        Self {
+            task_id,
+            task_title: task_title.into(),
+            phase: PomodoroPhase::Work,
+            phase_started_at: now,
+            work_duration: TimeDelta::minutes(WORK_MINUTES),
+            break_duration: TimeDelta::minutes(BREAK_MINUTES),
+            completed: 0,
+        }
+    }
+
+    /// 時間を進め、フェーズが切り替わった場合はその内容を返す
+    pub fn tick(&mut self, now: DateTime<Utc>) -> Option<PhaseChange> {
+        let phase_end = self.phase_started_at + self.phase_duration();
+        if now < phase_end {
+            return None;
+        }
+
+        let change = match self.phase {
+            PomodoroPhase::Work => {
+                self.completed += 1;
+                self.phase = PomodoroPhase::Break;
+                PhaseChange::WorkCompleted {
+                    started_at: self.phase_started_at,
+                    ended_at: phase_end,
+                }
+            }
+            PomodoroPhase::Break => {
+                self.phase = PomodoroPhase::Work;
+                PhaseChange::BreakCompleted
+            }
+        };
+        self.phase_started_at = phase_end;
+
+        Some(change)
+    }
+
+    /// 現在のフェーズの残り時間
+    pub fn remaining(&self, now: DateTime<Utc>) -> TimeDelta {
+        let elapsed = now - self.phase_started_at;
+        (self.phase_duration() - elapsed).max(TimeDelta::zero())
+    }
+
+    /// 現在のフェーズの進捗（0.0〜1.0）
+    pub fn progress(&self, now: DateTime<Utc>) -> f64 {
+        let total = self.phase_duration().num_milliseconds() as f64;
+        let remaining = self.remaining(now).num_milliseconds() as f64;
+        ((total - remaining) / total).clamp(0.0, 1.0)
+    }
+
+    /// 現在のフェーズの長さ
+    fn phase_duration(&self) -> TimeDelta {
+        match self.phase {
+            PomodoroPhase::Work => self.work_duration,
+            PomodoroPhase::Break => self.break_duration,
+        }
+    }
+
+    pub fn task_id(&self) -> i32 {
+        self.task_id
+    }
+
+    pub fn task_title(&self) -> &str {
+        &self.task_title
+    }
+
+    pub fn phase(&self) -> PomodoroPhase {
+        self.phase
+    }
+
+    /// 完了した作業フェーズの数
+    pub fn completed(&self) -> u32 {
+        self.completed
+    }
+}
+
+/// 残り時間を`MM:SS`形式で表示
+pub fn format_countdown(remaining: TimeDelta) -> String {
+    let seconds = remaining.num_seconds().max(0);
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn start_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 10, 9, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_work_phase_counts_down() {
+        // Arrange
+        let pomodoro = Pomodoro::start(1, "資料作成", start_time());
+
+        // Act
+        let remaining = pomodoro.remaining(start_time() + TimeDelta::minutes(10));
+
+        // Assert
+        assert_eq!(remaining, TimeDelta::minutes(15));
+        assert_eq!(format_countdown(remaining), "15:00");
+        assert!((pomodoro.progress(start_time() + TimeDelta::minutes(10)) - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_work_completion_switches_to_break() {
+        // Arrange
+        let mut pomodoro = Pomodoro::start(1, "資料作成", start_time());
+
+        // Act
+        let before = pomodoro.tick(start_time() + TimeDelta::minutes(24));
+        let change = pomodoro.tick(start_time() + TimeDelta::minutes(25));
+
+        // Assert
+        assert_eq!(before, None);
+        assert_eq!(
+            change,
+            Some(PhaseChange::WorkCompleted {
+                started_at: start_time(),
+                ended_at: start_time() + TimeDelta::minutes(25),
+            })
+        );
+        assert_eq!(pomodoro.phase(), PomodoroPhase::Break);
+        assert_eq!(pomodoro.completed(), 1);
+    }
+
+    #[test]
+    fn test_break_completion_starts_next_work_phase() {
+        // Arrange
+        let mut pomodoro = Pomodoro::start(1, "資料作成", start_time());
+        pomodoro.tick(start_time() + TimeDelta::minutes(25));
+
+        // Act
+        let change = pomodoro.tick(start_time() + TimeDelta::minutes(30));
+
+        // Assert
+        assert_eq!(change, Some(PhaseChange::BreakCompleted));
+        assert_eq!(pomodoro.phase(), PomodoroPhase::Work);
+        assert_eq!(
+            pomodoro.remaining(start_time() + TimeDelta::minutes(30)),
+            TimeDelta::minutes(25)
+        );
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(TimeDelta::seconds(65)), "01:05");
+        assert_eq!(format_countdown(TimeDelta::seconds(-3)), "00:00");
+    }
+}
@@ -1,51 +1,884 @@
+use crate::{
+    application::dto::task_dto::TaskDTO,
+    domain::task::value_objects::{DueDateStatus, Priority, Status},
+    interface::{
+        cli::display::format::{
+            format_checklist_items, format_checklist_progress, format_date,
+            format_optional_datetime, format_optional_text, format_priority,
+            format_relative_due_date, format_status, format_tags,
+        },
+        cli::display::markdown::{Inline, MarkdownBlock, parse_markdown},
+        i18n::Msg,
+        tui::{
+            app::{App, Mode},
+            calendar::Calendar,
+            pomodoro::{PomodoroPhase, format_countdown},
+        },
+    },
+};
+use chrono::{DateTime, NaiveDate, Utc};
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout},
-    style::{Color, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Wrap},
 };
+use strum::IntoEnumIterator;
 
 /// TUIの画面を描画する
-pub fn render(frame: &mut Frame) {
-    let area = frame.area();
-
-    // 垂直方向に3分割（上部余白、中央コンテンツ、下部余白）
+///
+/// 期限日の相対表示とポモドーロの残り時間は`now`を基準にします。
+pub fn render(frame: &mut Frame, app: &App, now: DateTime<Utc>) {
+    // 垂直方向に3分割（メイン、ステータス、キー操作のヘルプ）
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(30), // 上部余白
-            Constraint::Percentage(40), // 中央コンテンツ
-            Constraint::Percentage(30), // 下部余白
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    let help = if app.pomodoro().is_some() {
+        render_pomodoro(frame, app, chunks[0], now);
+        Msg::TuiPomodoroKeyHelp
+    } else if let Some(calendar) = app.calendar() {
+        render_calendar(frame, calendar, chunks[0], app.date_of(now));
+        if calendar.day_focused() {
+            Msg::TuiCalendarDayKeyHelp
+        } else {
+            Msg::TuiCalendarKeyHelp
+        }
+    } else {
+        render_main(frame, app, chunks[0], now);
+        match app.mode() {
+            Mode::Normal => Msg::TuiKeyHelp,
+            Mode::PriorityPicker { .. } | Mode::TagPicker { .. } => {
+                render_picker(frame, app, chunks[0]);
+                Msg::TuiPickerKeyHelp
+            }
+            Mode::Confirm(_) => {
+                render_confirm(frame, app, chunks[0]);
+                Msg::TuiConfirmKeyHelp
+            }
+            Mode::ConfirmTyped { .. } => {
+                render_confirm(frame, app, chunks[0]);
+                Msg::TuiConfirmTypedKeyHelp
+            }
+        }
+    };
+
+    // 処理中はスピナーを表示する
+    let message = match (app.spinner(), app.status_message()) {
+        (Some(spinner), Some(message)) => Some(format!("{} {}", spinner, message)),
+        (Some(spinner), None) => Some(format!("{} {}", spinner, Msg::TuiLoading.text())),
+        (None, message) => message.map(str::to_string),
+    };
+    // ステータスバーの右端に今日の完了数と連続記録を表示する
+    let streak = app
+        .summary()
+        .map(|summary| Msg::TuiStreak.format(&[&summary.completed_today, &summary.streak_days]));
+    let status_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(streak.as_deref().map_or(0, |s| Span::raw(s).width() as u16)),
+        ])
+        .split(chunks[1]);
+    if let Some(message) = message {
+        let status = Paragraph::new(message).style(Style::default().fg(Color::Yellow));
+        frame.render_widget(status, status_chunks[0]);
+    }
+    if let Some(streak) = streak {
+        let streak = Paragraph::new(streak).style(Style::default().fg(Color::Green));
+        frame.render_widget(streak, status_chunks[1]);
+    }
+
+    let help = Paragraph::new(Span::styled(
+        help.text(),
+        Style::default().fg(Color::DarkGray),
+    ));
+    frame.render_widget(help, chunks[2]);
+}
+
+/// 一覧を描画する（分割表示が有効なら右側に選択中のタスクの詳細を並べる）
+fn render_main(frame: &mut Frame, app: &App, area: Rect, now: DateTime<Utc>) {
+    if !app.show_detail() {
+        render_task_list(frame, app, area, now);
+        return;
+    }
+
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    render_task_list(frame, app, panes[0], now);
+    render_task_detail(frame, app, panes[1], now);
+}
+
+/// 期限の状況に応じた期限日の表示スタイル（完了済みのタスクは装飾しない）
+///
+/// 期限切れ・今日期限・期限が近い（設定の`soon_days`日以内）タスクを強調します。
+fn due_date_style(app: &App, task: &TaskDTO, now: DateTime<Utc>) -> Style {
+    if task.status == Status::Completed {
+        return Style::default();
+    }
+    let status =
+        DueDateStatus::from_due_date(task.due_date, app.date_of(now), app.due_date_buckets());
+    match status {
+        DueDateStatus::Overdue => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        DueDateStatus::DueToday => Style::default().fg(Color::Yellow),
+        DueDateStatus::DueSoon => Style::default().fg(Color::Magenta),
+        _ => Style::default(),
+    }
+}
+
+/// 選択中のタスクの詳細を描画する
+fn render_task_detail(frame: &mut Frame, app: &App, area: Rect, now: DateTime<Utc>) {
+    let block = Block::default()
+        .title(Msg::TuiTaskDetailTitle.text())
+        .borders(Borders::ALL);
+    let Some(task) = app.selected_task() else {
+        frame.render_widget(block, area);
+        return;
+    };
+
+    let label = Style::default().fg(Color::DarkGray);
+    let styled_field = |name: Msg, value: String, style: Style| {
+        Line::from(vec![
+            Span::styled(format!("{}: ", name.text()), label),
+            Span::styled(value, style),
+        ])
+    };
+    let field = |name: Msg, value: String| styled_field(name, value, Style::default());
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("[{}] {}", task.id, task.title),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::default(),
+        field(Msg::FieldStatus, format_status(task.status).to_string()),
+        field(
+            Msg::FieldPriority,
+            format_priority(task.priority).to_string(),
+        ),
+        field(Msg::FieldTags, format_tags(&task.tags, ", ")),
+        styled_field(
+            Msg::FieldDueDate,
+            format_relative_due_date(&task.due_date, app.date_of(now)),
+            due_date_style(app, task, now),
+        ),
+        field(
+            Msg::FieldCreatedAt,
+            format_optional_datetime(&Some(task.created_at)),
+        ),
+        field(
+            Msg::FieldUpdatedAt,
+            format_optional_datetime(&Some(task.updated_at)),
+        ),
+        Line::default(),
+        Line::from(Span::styled(
+            format!("{}:", Msg::FieldDescription.text()),
+            label,
+        )),
+    ];
+    // 説明はMarkdownとして整形し、改行はそのまま行として表示する
+    match task.description.as_deref().filter(|d| !d.is_empty()) {
+        Some(description) => lines.extend(markdown_lines(description)),
+        None => lines.push(Line::raw(format_optional_text(&task.description))),
+    }
+    if !task.checklist.is_empty() {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} ({}):",
+                Msg::FieldChecklist.text(),
+                format_checklist_progress(&task.checklist)
+            ),
+            label,
+        )));
+        lines.extend(
+            format_checklist_items(&task.checklist)
+                .into_iter()
+                .map(Line::raw),
+        );
+    }
+
+    let detail = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(block);
+    frame.render_widget(detail, area);
+}
+
+/// Markdownの説明を、装飾付きの行に変換する
+fn markdown_lines(description: &str) -> Vec<Line<'static>> {
+    let spans = |inlines: &[Inline], base: Style| -> Vec<Span<'static>> {
+        inlines
+            .iter()
+            .flat_map(|inline| match inline {
+                Inline::Text(text) => vec![Span::styled(text.clone(), base)],
+                Inline::Bold(text) => {
+                    vec![Span::styled(
+                        text.clone(),
+                        base.add_modifier(Modifier::BOLD),
+                    )]
+                }
+                Inline::Italic(text) => {
+                    vec![Span::styled(
+                        text.clone(),
+                        base.add_modifier(Modifier::ITALIC),
+                    )]
+                }
+                Inline::Code(text) => vec![Span::styled(text.clone(), base.fg(Color::Cyan))],
+                Inline::Link { text, url } if text == url => vec![Span::styled(
+                    url.clone(),
+                    base.add_modifier(Modifier::UNDERLINED),
+                )],
+                Inline::Link { text, url } => vec![
+                    Span::styled(text.clone(), base.add_modifier(Modifier::UNDERLINED)),
+                    Span::styled(format!(" ({})", url), base.fg(Color::DarkGray)),
+                ],
+            })
+            .collect()
+    };
+
+    parse_markdown(description)
+        .iter()
+        .map(|block| match block {
+            MarkdownBlock::Paragraph(content) => Line::from(spans(content, Style::default())),
+            MarkdownBlock::Heading(content) => Line::from(spans(
+                content,
+                Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            )),
+            MarkdownBlock::ListItem {
+                depth,
+                marker,
+                content,
+            } => {
+                let mut line = vec![Span::raw(format!("{}{} ", "  ".repeat(*depth), marker))];
+                line.extend(spans(content, Style::default()));
+                Line::from(line)
+            }
+            MarkdownBlock::Quote(content) => {
+                let mut line = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
+                line.extend(spans(
+                    content,
+                    Style::default().add_modifier(Modifier::ITALIC),
+                ));
+                Line::from(line)
+            }
+            MarkdownBlock::CodeLine(code) => Line::from(Span::styled(
+                format!("    {}", code),
+                Style::default().fg(Color::Cyan),
+            )),
+            MarkdownBlock::Rule => Line::from(Span::styled(
+                "─".repeat(20),
+                Style::default().fg(Color::DarkGray),
+            )),
+        })
+        .collect()
+}
+
+/// タスク一覧を描画する（マークしていないタスクは期限の状況に応じて色付けする）
+fn render_task_list(frame: &mut Frame, app: &App, area: Rect, now: DateTime<Utc>) {
+    let title = match app.marked_count() {
+        0 => Msg::TuiTaskListTitle.text().to_string(),
+        count => Msg::TuiTaskListMarkedTitle.format(&[&count]),
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+
+    if app.tasks().is_empty() {
+        let empty = Paragraph::new(Msg::NoTasksFound.text())
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .tasks()
+        .iter()
+        .map(|task| {
+            let marked = app.is_marked(task.id);
+            let checklist = if task.checklist.is_empty() {
+                String::new()
+            } else {
+                format!(", {}", format_checklist_progress(&task.checklist))
+            };
+            let item = ListItem::new(format!(
+                "{} [{}] {}  ({}, {}{})",
+                if marked { "*" } else { " " },
+                task.id,
+                task.title,
+                format_status(task.status),
+                format_priority(task.priority),
+                checklist
+            ));
+            if marked {
+                item.style(Style::default().fg(Color::Cyan))
+            } else {
+                item.style(due_date_style(app, task, now))
+            }
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+
+    let mut state = ListState::default().with_selected(Some(app.selected()));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// カレンダーの1日分のセルの幅
+const CALENDAR_CELL_WIDTH: u16 = 6;
+
+/// 期限日カレンダーと、カーソルの日が期限のタスクを描画する
+fn render_calendar(frame: &mut Frame, calendar: &Calendar, area: Rect, today: NaiveDate) {
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(CALENDAR_CELL_WIDTH * 7 + 2),
+            Constraint::Min(0),
         ])
         .split(area);
 
-    // タイトル
-    let title = Paragraph::new("yaru")
-        .style(Style::default().fg(Color::Cyan))
+    let block = Block::default()
+        .title(Msg::TuiCalendarTitle.format(&[&calendar.cursor().format("%Y-%m")]))
+        .borders(Borders::ALL);
+    let inner = block.inner(panes[0]);
+    frame.render_widget(block, panes[0]);
+
+    let weeks = calendar.weeks();
+    let mut row_constraints = vec![Constraint::Length(1)];
+    row_constraints.extend(weeks.iter().map(|_| Constraint::Length(2)));
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(row_constraints)
+        .split(inner);
+    let columns = |row: Rect| {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(CALENDAR_CELL_WIDTH); 7])
+            .split(row)
+    };
+
+    // 曜日の見出し（表示言語の曜日名を、週の始まりの曜日から順に並べる）
+    let header = columns(rows[0]);
+    let names: Vec<&str> = Msg::TuiCalendarWeekdays.text().split_whitespace().collect();
+    let weekday_names = calendar
+        .weekdays()
+        .map(|weekday| names[weekday.num_days_from_monday() as usize]);
+    for (name, cell) in weekday_names.into_iter().zip(header.iter()) {
+        let weekday = Paragraph::new(Span::styled(name, Style::default().fg(Color::DarkGray)))
+            .alignment(Alignment::Center);
+        frame.render_widget(weekday, *cell);
+    }
+
+    // 日付と期限のタスク数（件数が多いほど目立つ色にする）
+    for (week, row) in weeks.iter().zip(rows.iter().skip(1)) {
+        for (date, cell) in week.iter().zip(columns(*row).iter()) {
+            let Some(date) = date else {
+                continue;
+            };
+            let count = calendar.count_on(*date);
+            let density = match count {
+                0 => Style::default(),
+                1 => Style::default().fg(Color::Green),
+                2..=3 => Style::default().fg(Color::Yellow),
+                _ => Style::default().fg(Color::Red),
+            };
+            let mut day_style = Style::default();
+            if *date == today {
+                day_style = day_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+            }
+            if *date == calendar.cursor() {
+                day_style = day_style.add_modifier(Modifier::REVERSED);
+            }
+            let lines = vec![
+                Line::from(Span::styled(date.format("%e").to_string(), day_style)),
+                Line::from(if count > 0 {
+                    Span::styled(format!("●{}", count), density)
+                } else {
+                    Span::raw("")
+                }),
+            ];
+            frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), *cell);
+        }
+    }
+
+    render_calendar_day(frame, calendar, panes[1]);
+}
+
+/// カーソルの日が期限のタスクを描画する
+fn render_calendar_day(frame: &mut Frame, calendar: &Calendar, area: Rect) {
+    let block = Block::default()
+        .title(Msg::TuiCalendarDayTitle.format(&[&format_date(&Some(calendar.cursor()))]))
+        .borders(Borders::ALL);
+    let tasks = calendar.tasks_on(calendar.cursor());
+    if tasks.is_empty() {
+        let empty = Paragraph::new(Msg::NoTasksFound.text())
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = tasks
+        .iter()
+        .map(|task| {
+            ListItem::new(format!(
+                "[{}] {}  ({})",
+                task.id,
+                task.title,
+                format_priority(task.priority)
+            ))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    let mut state =
+        ListState::default().with_selected(calendar.day_focused().then(|| calendar.day_selected()));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// 優先度・タグの選択肢をポップアップで描画する
+fn render_picker(frame: &mut Frame, app: &App, area: Rect) {
+    let (title, items, selected): (_, Vec<ListItem>, _) = match app.mode() {
+        Mode::PriorityPicker { selected } => (
+            Msg::TuiSelectPriorityTitle,
+            Priority::iter()
+                .map(|priority| ListItem::new(format_priority(priority)))
+                .collect(),
+            *selected,
+        ),
+        Mode::TagPicker { selected } => (
+            Msg::TuiSelectTagTitle,
+            app.tags()
+                .iter()
+                .map(|tag| ListItem::new(tag.name.clone()))
+                .collect(),
+            *selected,
+        ),
+        _ => return,
+    };
+
+    let height = (items.len() as u16).saturating_add(2);
+    let popup = centered_rect(area, 40, height);
+    let list = List::new(items)
+        .block(Block::default().title(title.text()).borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut state);
+}
+
+/// 一括操作の確認をポップアップで描画する
+fn render_confirm(frame: &mut Frame, app: &App, area: Rect) {
+    let count = app.bulk_targets().len();
+    let text = match app.mode() {
+        Mode::Confirm(action) => action.confirm_message(count),
+        // 件数の入力を求める場合は、入力中の文字列も表示する
+        Mode::ConfirmTyped { action, input } => format!(
+            "{} {}",
+            Msg::PromptTypeToConfirm.format(&[&action.confirm_message(count), &count]),
+            input
+        ),
+        _ => return,
+    };
+
+    let popup = centered_rect(area, 50, 5);
+    let confirm = Paragraph::new(text)
         .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
         .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(Clear, popup);
+    frame.render_widget(confirm, popup);
+}
+
+/// 領域の中央に指定サイズの矩形を配置する
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// ポモドーロのカウントダウンを描画する
+fn render_pomodoro(frame: &mut Frame, app: &App, area: Rect, now: DateTime<Utc>) {
+    let Some(pomodoro) = app.pomodoro() else {
+        return;
+    };
 
-    frame.render_widget(title, chunks[1]);
+    let block = Block::default()
+        .title(format!(
+            "[{}] {}",
+            pomodoro.task_id(),
+            pomodoro.task_title()
+        ))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
 
-    // メッセージ（タイトルの下に表示）
-    let message_chunks = Layout::default()
+    let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // タイトル用
-            Constraint::Min(0),    // メッセージ用
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Min(0),
         ])
-        .split(chunks[1]);
+        .split(inner);
 
-    let message = Paragraph::new(vec![
-        Line::from("Under Construction"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "q: Quit | Ctrl+C: Quit",
-            Style::default().fg(Color::DarkGray),
-        )),
-    ])
+    let (phase, color) = match pomodoro.phase() {
+        PomodoroPhase::Work => (Msg::PomodoroWork, Color::Red),
+        PomodoroPhase::Break => (Msg::PomodoroBreak, Color::Green),
+    };
+    let phase = Paragraph::new(Line::from(Span::styled(
+        phase.text(),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    )))
     .alignment(Alignment::Center);
+    frame.render_widget(phase, chunks[0]);
+
+    let gauge = Gauge::default()
+        .block(Block::default().borders(Borders::ALL))
+        .gauge_style(Style::default().fg(color))
+        .ratio(pomodoro.progress(now))
+        .label(format_countdown(pomodoro.remaining(now)));
+    frame.render_widget(gauge, chunks[1]);
+
+    let completed = Paragraph::new(Msg::PomodoroCompletedCount.format(&[&pomodoro.completed()]))
+        .alignment(Alignment::Center);
+    frame.render_widget(completed, chunks[2]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        application::dto::{
+            TagDTO, TaskSummaryDTO,
+            task_dto::{ChecklistItemDTO, TagInfo, TaskDTO},
+        },
+        domain::task::value_objects::DueDateBuckets,
+        interface::{cli::display::format::format_local_time, tui::bulk::BulkAction},
+    };
+    use chrono::{NaiveDate, TimeDelta, TimeZone};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    /// スナップショットの端末サイズ
+    const WIDTH: u16 = 120;
+    const HEIGHT: u16 = 20;
+
+    /// 描画の基準日時
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap()
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn task_dto(id: i32, title: &str) -> TaskDTO {
+        TaskDTO {
+            id,
+            title: title.to_string(),
+            description: None,
+            status: Status::Pending,
+            priority: Priority::Medium,
+            tags: vec![],
+            created_at: now() - TimeDelta::days(3),
+            updated_at: now() - TimeDelta::hours(2),
+            due_date: None,
+            completed_at: None,
+            custom_fields: Default::default(),
+            checklist: vec![],
+            git_links: vec![],
+            external_refs: vec![],
+            recurrence: None,
+            snoozed_until: None,
+            estimate_minutes: None,
+            context: None,
+            assignee: None,
+        }
+    }
+
+    fn tag_dto(id: i32, name: &str) -> TagDTO {
+        TagDTO {
+            id,
+            name: name.to_string(),
+            description: None,
+            parent_id: None,
+            path: name.to_string(),
+            created_at: now(),
+            updated_at: now(),
+        }
+    }
+
+    /// 状態・優先度・期限・チェックリストの異なるタスクを表示した一覧
+    fn app_with_tasks() -> App {
+        let mut report = task_dto(1, "Write report");
+        report.status = Status::InProgress;
+        report.priority = Priority::High;
+        report.tags = vec![TagInfo {
+            id: 1,
+            name: "work".to_string(),
+        }];
+        report.due_date = Some(date(12));
+        report.description = Some("Quarterly **numbers**\n- Send to `team`".to_string());
+        report.checklist = vec![
+            ChecklistItemDTO {
+                text: "Collect data".to_string(),
+                done: true,
+            },
+            ChecklistItemDTO {
+                text: "Draft".to_string(),
+                done: false,
+            },
+        ];
+        let mut milk = task_dto(2, "牛乳を買う");
+        milk.due_date = Some(date(10));
+        let mut backup = task_dto(3, "Back up photos");
+        backup.status = Status::Completed;
+        backup.priority = Priority::Low;
+
+        let mut app = App::new();
+        app.set_tasks(vec![report, milk, backup]);
+        app.set_tags(vec![tag_dto(1, "work"), tag_dto(2, "home")]);
+        app
+    }
+
+    /// 画面をTestBackendに描画し、各行の文字列にする（行末の空白は除く）
+    ///
+    /// 全角文字の2桁目のセルは読み飛ばします。作成・更新日時は表示のタイムゾーンに依存するため、
+    /// 同じ幅の`[datetime]`に置き換えます。
+    fn render_to_string(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        terminal.draw(|frame| render(frame, app, now())).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let mut screen = (0..HEIGHT)
+            .map(|y| {
+                let mut line = String::new();
+                let mut x = 0;
+                while x < WIDTH {
+                    let symbol = buffer[(x, y)].symbol();
+                    line.push_str(symbol);
+                    x += Span::raw(symbol).width().max(1) as u16;
+                }
+                line.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        for task in app.tasks() {
+            for datetime in [task.created_at, task.updated_at] {
+                let formatted = format_local_time(&datetime);
+                let placeholder = format!("{:<width$}", "[datetime]", width = formatted.len());
+                screen = screen.replace(&formatted, &placeholder);
+            }
+        }
+        screen
+    }
+
+    #[test]
+    fn test_render_empty_list() {
+        let app = App::new();
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_task_list() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.select_next();
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_marked_tasks_with_status_and_spinner() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.toggle_mark();
+        app.select_next();
+        app.toggle_mark();
+        app.set_status("Saved");
+        app.begin_action();
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_completion_streak_in_status_bar() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.set_status("Saved");
+        app.set_summary(TaskSummaryDTO {
+            completed_today: 3,
+            streak_days: 12,
+            ..TaskSummaryDTO::default()
+        });
+
+        // Act
+        let screen = render_to_string(&app);
+
+        // Assert
+        let status_line = screen.lines().nth(HEIGHT as usize - 2).unwrap();
+        assert!(status_line.starts_with("Saved"));
+        assert!(status_line.ends_with("Done today: 3 · Streak: 12d"));
+    }
+
+    #[test]
+    fn test_render_task_detail() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.toggle_detail();
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_markdown_lines_styles_description() {
+        // Act
+        let lines = markdown_lines("Quarterly **numbers**\n- Send to `team`");
+
+        // Assert
+        assert_eq!(lines[0].spans[1].content, "numbers");
+        assert!(
+            lines[0].spans[1]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+        assert_eq!(lines[1].spans[0].content, "• ");
+        assert_eq!(lines[1].spans[2].style.fg, Some(Color::Cyan));
+    }
+
+    /// 一覧のうち、指定したタイトルを含む行のタイトル部分の前景色
+    fn list_row_color(app: &App, title: &str) -> Option<Color> {
+        let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap();
+        terminal.draw(|frame| render(frame, app, now())).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        (0..HEIGHT).find_map(|y| {
+            let line: String = (0..WIDTH).map(|x| buffer[(x, y)].symbol()).collect();
+            let x = line.find(title)?;
+            buffer[(x as u16, y)].fg.into()
+        })
+    }
+
+    #[test]
+    fn test_render_task_list_colors_due_dates_by_buckets() {
+        // Arrange: 期限が2日後のタスクは既定（7日以内）では期限が近いと扱われる
+        let app = app_with_tasks();
+        let narrow = app_with_tasks().with_due_date_buckets(DueDateBuckets::new(1, 30).unwrap());
+
+        // Act & Assert
+        assert_eq!(list_row_color(&app, "Write report"), Some(Color::Magenta));
+        assert_eq!(list_row_color(&narrow, "Write report"), Some(Color::Reset));
+        assert_eq!(list_row_color(&app, "Back up photos"), Some(Color::Reset));
+    }
+
+    #[test]
+    fn test_render_priority_picker() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.open_priority_picker();
+        app.picker_next();
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_tag_picker() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.open_tag_picker();
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_confirm_delete() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.toggle_mark();
+        app.select_next();
+        app.toggle_mark();
+        app.request_bulk_action(BulkAction::Delete);
+        app.push_confirm_input('2');
+
+        // Act & Assert: 2件以上の削除は件数の入力を求める
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_calendar() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.open_calendar(date(10));
+        let due: Vec<TaskDTO> = app
+            .tasks()
+            .iter()
+            .filter(|task| task.due_date.is_some())
+            .cloned()
+            .collect();
+        app.calendar_mut().unwrap().set_tasks((2026, 3), due);
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_calendar_week_starts_on_sunday() {
+        // Arrange
+        let mut app = app_with_tasks().with_week_start(chrono::Weekday::Sun);
+        app.open_calendar(date(10));
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_calendar_day_focused() {
+        // Arrange
+        let mut app = app_with_tasks();
+        app.open_calendar(date(12));
+        let due: Vec<TaskDTO> = app
+            .tasks()
+            .iter()
+            .filter(|task| task.due_date.is_some())
+            .cloned()
+            .collect();
+        let calendar = app.calendar_mut().unwrap();
+        calendar.set_tasks((2026, 3), due);
+        calendar.focus_day();
+
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
+
+    #[test]
+    fn test_render_pomodoro() {
+        // Arrange: 開始から10分経過した作業フェーズ
+        let mut app = app_with_tasks();
+        app.start_pomodoro(now() - TimeDelta::minutes(10));
 
-    frame.render_widget(message, message_chunks[1]);
+        // Act & Assert
+        insta::assert_snapshot!(render_to_string(&app));
+    }
 }
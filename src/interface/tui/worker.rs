@@ -0,0 +1,293 @@
+use crate::{
+    application::{
+        dto::{TagDTO, TaskDTO, TaskSummaryDTO},
+        use_cases::{
+            tag::list_tags::ListTagsUseCase,
+            task::{
+                due_calendar::DueCalendarUseCase,
+                list_tasks::{ListTasksUseCase, TaskFilter, TaskSortKey},
+                summarize_tasks::SummarizeTasksUseCase,
+            },
+            time_entry::record_time_entry::RecordTimeEntryUseCase,
+        },
+    },
+    domain::task::value_objects::{Status, TaskContext},
+    interface::{
+        i18n::Msg,
+        tui::{
+            TuiRepositories, TuiSettings,
+            bulk::{BulkAction, BulkActionRunner},
+            history::{HistoryDirection, TaskChange},
+        },
+    },
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// 画面からバックグラウンドに依頼するデータ操作
+#[derive(Debug)]
+pub enum Action {
+    /// 一覧に表示するタスクを読み込む
+    LoadTasks,
+    /// タグの選択肢を読み込む
+    LoadTags,
+    /// カレンダーの月のタスクを読み込む
+    LoadCalendar { year: i32, month: u32 },
+    /// 一括操作を適用する
+    ApplyBulk {
+        action: BulkAction,
+        targets: Vec<TaskDTO>,
+    },
+    /// 履歴の操作を取り消す・やり直す
+    ReplayHistory {
+        direction: HistoryDirection,
+        changes: Vec<TaskChange>,
+    },
+    /// ポモドーロの作業フェーズを作業記録として保存する
+    RecordTimeEntry {
+        task_id: i32,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+    },
+}
+
+/// バックグラウンドから画面に届く処理結果
+///
+/// 1つの`Action`の処理が終わるたびに、最後に`Done`が届きます。
+#[derive(Debug)]
+pub enum DataEvent {
+    TasksLoaded(Vec<TaskDTO>),
+    TagsLoaded(Vec<TagDTO>),
+    /// ステータスバーに表示する今日の完了数と連続記録
+    SummaryLoaded(TaskSummaryDTO),
+    CalendarLoaded {
+        year_month: (i32, u32),
+        tasks: Vec<TaskDTO>,
+    },
+    /// 一括操作の進捗（処理中の件数, 全件数）
+    Progress {
+        current: usize,
+        total: usize,
+    },
+    BulkApplied {
+        changes: Vec<TaskChange>,
+        succeeded: usize,
+        failures: Vec<String>,
+    },
+    HistoryReplayed {
+        direction: HistoryDirection,
+        changes: Vec<TaskChange>,
+        /// 作り直したタスクの（元のID, 新しいID）
+        remapped: Vec<(i32, i32)>,
+    },
+    TimeEntryRecorded,
+    /// 処理に失敗した（カレンダーの読み込みに失敗した場合は`calendar`がtrue）
+    Failed {
+        message: String,
+        calendar: bool,
+    },
+    /// 1つの`Action`の処理が終わった
+    Done,
+}
+
+/// データ操作をバックグラウンドで順に実行する
+struct DataWorker {
+    list_tasks: ListTasksUseCase,
+    list_tags: ListTagsUseCase,
+    summarize: SummarizeTasksUseCase,
+    due_calendar: DueCalendarUseCase,
+    bulk_runner: BulkActionRunner,
+    record_time_entry: RecordTimeEntryUseCase,
+    context: Option<TaskContext>,
+    events: UnboundedSender<DataEvent>,
+}
+
+/// バックグラウンドのワーカーを起動する
+///
+/// # Returns
+/// 操作を依頼する送信側と、処理結果を受け取る受信側
+pub fn spawn(
+    repositories: TuiRepositories,
+    settings: TuiSettings,
+) -> (UnboundedSender<Action>, UnboundedReceiver<DataEvent>) {
+    let (action_tx, mut action_rx) = mpsc::unbounded_channel();
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+    let worker = DataWorker {
+        list_tasks: ListTasksUseCase::new(
+            repositories.task_repo.clone(),
+            repositories.tag_repo.clone(),
+        )
+        .with_urgency_weights(settings.urgency)
+        .with_clock(Arc::new(settings.clock)),
+        list_tags: ListTagsUseCase::new(repositories.tag_repo.clone()),
+        summarize: SummarizeTasksUseCase::new(repositories.task_repo.clone())
+            .with_clock(Arc::new(settings.clock))
+            .with_completion_streak(),
+        due_calendar: DueCalendarUseCase::new(
+            repositories.task_repo.clone(),
+            repositories.tag_repo.clone(),
+        ),
+        bulk_runner: BulkActionRunner::new(
+            repositories.task_repo.clone(),
+            repositories.tag_repo,
+            repositories.time_entry_repo.clone(),
+        )
+        .with_clock(Arc::new(settings.clock)),
+        record_time_entry: RecordTimeEntryUseCase::new(
+            repositories.task_repo,
+            repositories.time_entry_repo,
+        ),
+        context: settings.context,
+        events: event_tx,
+    };
+    tokio::spawn(async move {
+        // 画面側が終了して送信側が破棄されると終わる
+        while let Some(action) = action_rx.recv().await {
+            tracing::debug!(?action, "handling tui action");
+            worker.handle(action).await;
+        }
+    });
+
+    (action_tx, event_rx)
+}
+
+impl DataWorker {
+    async fn handle(&self, action: Action) {
+        let is_calendar = matches!(action, Action::LoadCalendar { .. });
+        if let Err(e) = self.run(action).await {
+            tracing::error!(error = %e, "tui action failed");
+            self.send(DataEvent::Failed {
+                message: e.to_string(),
+                calendar: is_calendar,
+            });
+        }
+        self.send(DataEvent::Done);
+    }
+
+    fn send(&self, event: DataEvent) {
+        // 画面側が終了している場合は結果を捨てる
+        let _ = self.events.send(event);
+    }
+
+    async fn run(&self, action: Action) -> Result<()> {
+        match action {
+            Action::LoadTasks => self.reload_tasks().await?,
+            Action::LoadTags => self.send(DataEvent::TagsLoaded(self.list_tags.execute().await?)),
+            Action::LoadCalendar { year, month } => self.send(DataEvent::CalendarLoaded {
+                year_month: (year, month),
+                tasks: self.due_calendar.execute(year, month).await?,
+            }),
+            Action::ApplyBulk { action, targets } => {
+                self.send(self.apply_bulk(&action, &targets).await);
+                self.reload_tasks().await?;
+            }
+            Action::ReplayHistory { direction, changes } => {
+                // 途中で失敗しても、適用できた分を一覧に反映する
+                let replayed = self.replay_history(direction, changes).await;
+                self.reload_tasks().await?;
+                match replayed {
+                    Ok(event) => self.send(event),
+                    Err(e) => {
+                        tracing::error!(error = %e, ?direction, "failed to replay history");
+                        self.send(DataEvent::Failed {
+                            message: Msg::TuiHistoryFailed.format(&[&e]),
+                            calendar: false,
+                        });
+                    }
+                }
+            }
+            Action::RecordTimeEntry {
+                task_id,
+                started_at,
+                ended_at,
+            } => {
+                self.record_time_entry
+                    .execute(task_id, started_at, ended_at)
+                    .await?;
+                self.send(DataEvent::TimeEntryRecorded);
+            }
+        }
+        Ok(())
+    }
+
+    /// 一覧のタスクと、完了によって変わるステータスバーの連続記録を読み込み直す
+    async fn reload_tasks(&self) -> Result<()> {
+        self.send(DataEvent::TasksLoaded(self.load_tasks().await?));
+        self.send(DataEvent::SummaryLoaded(self.summarize.execute().await?));
+        Ok(())
+    }
+
+    /// 一覧に表示するタスク（完了済みとスヌーズ中を除く）を緊急度の高い順に読み込む
+    async fn load_tasks(&self) -> Result<Vec<TaskDTO>> {
+        let mut filters = vec![
+            TaskFilter::ExcludeStatus(Status::Completed),
+            TaskFilter::ExcludeSnoozed,
+        ];
+        filters.extend(self.context.clone().map(TaskFilter::Context));
+        self.list_tasks
+            .execute_filtered(&filters, TaskSortKey::Urgency)
+            .await
+    }
+
+    /// 一括操作を1件ずつ適用し、進捗を通知する
+    async fn apply_bulk(&self, action: &BulkAction, targets: &[TaskDTO]) -> DataEvent {
+        let mut failures = Vec::new();
+        let mut changes = Vec::new();
+        for (index, task) in targets.iter().enumerate() {
+            self.send(DataEvent::Progress {
+                current: index + 1,
+                total: targets.len(),
+            });
+            match self.bulk_runner.apply(action, task).await {
+                Ok(change) => changes.extend(change),
+                Err(e) => {
+                    tracing::error!(task_id = task.id, error = %e, "bulk action failed");
+                    failures.push(format!("[{}] {}", task.id, e));
+                }
+            }
+        }
+        DataEvent::BulkApplied {
+            changes,
+            succeeded: targets.len() - failures.len(),
+            failures,
+        }
+    }
+
+    /// 履歴の操作を1つ取り消す・やり直す
+    async fn replay_history(
+        &self,
+        direction: HistoryDirection,
+        mut changes: Vec<TaskChange>,
+    ) -> Result<DataEvent> {
+        // 取り消しは記録したときと逆の順に適用する
+        let order: Vec<usize> = match direction {
+            HistoryDirection::Undo => (0..changes.len()).rev().collect(),
+            HistoryDirection::Redo => (0..changes.len()).collect(),
+        };
+        let mut remapped = Vec::new();
+        for index in order {
+            let (current, target) = changes[index].endpoints(direction);
+            let target_id = target.map(|task| task.id);
+            let result = self.bulk_runner.restore(current, target).await?;
+            // 作り直したタスクはIDが変わるため、操作内の参照を付け替える
+            if let (Some(old), Some(new)) = (target_id, result.as_ref().map(|task| task.id))
+                && old != new
+            {
+                changes
+                    .iter_mut()
+                    .for_each(|change| change.remap_id(old, new));
+                remapped.push((old, new));
+            }
+            changes[index].set_result(direction, result);
+        }
+
+        Ok(DataEvent::HistoryReplayed {
+            direction,
+            changes,
+            remapped,
+        })
+    }
+}
@@ -0,0 +1,149 @@
+//! タスクのタイトル・説明に含まれるURL
+//!
+//! URLの検出と、外部コマンド（既定はLinuxの`xdg-open`、macOSの`open`）でブラウザを開く処理を提供します。
+
+use anyhow::{Context, Result};
+use std::process::Command;
+
+/// 文字列に含まれる`http://`・`https://`のURLを、現れた順に重複なく取り出す
+///
+/// Markdownのリンク（`[説明](URL)`）や括弧で囲まれたURLも、閉じ括弧や文末の句読点を除いて取り出します。
+pub fn find_urls(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = ["http://", "https://"]
+        .iter()
+        .filter_map(|scheme| rest.find(scheme))
+        .min()
+    {
+        let candidate = &rest[start..];
+        let end = candidate
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '`'))
+            .unwrap_or(candidate.len());
+        let url = trim_url(&candidate[..end]);
+        if url.len() > url.find("://").unwrap_or_default() + 3 && !urls.iter().any(|u| u == url) {
+            urls.push(url.to_string());
+        }
+        rest = &candidate[end.max(1)..];
+    }
+
+    urls
+}
+
+/// URLの末尾の句読点と、対応する開き括弧がない閉じ括弧を取り除く
+fn trim_url(url: &str) -> &str {
+    let mut url = url;
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'', '*']);
+        let unbalanced = |open: char, close: char| {
+            trimmed.ends_with(close)
+                && trimmed.matches(close).count() > trimmed.matches(open).count()
+        };
+        let trimmed = if unbalanced('(', ')') || unbalanced('[', ']') {
+            &trimmed[..trimmed.len() - 1]
+        } else {
+            trimmed
+        };
+        if trimmed == url {
+            return url;
+        }
+        url = trimmed;
+    }
+}
+
+/// タスクのタイトルと説明に含まれるURL（タイトルのURLが先）
+pub fn find_task_urls(title: &str, description: Option<&str>) -> Vec<String> {
+    let mut urls = find_urls(title);
+    for url in find_urls(description.unwrap_or_default()) {
+        if !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+/// URLをブラウザで開く
+///
+/// 環境変数`BROWSER`が設定されている場合はそのコマンドを使います。
+pub fn open_url(url: &str) -> Result<()> {
+    let command_line = open_command_line(std::env::var("BROWSER").ok().as_deref(), url);
+    let status = Command::new(&command_line[0])
+        .args(&command_line[1..])
+        .status()
+        .with_context(|| format!("Failed to run browser command: {}", command_line[0]))?;
+    if !status.success() {
+        anyhow::bail!("Browser command exited with {}", status);
+    }
+    Ok(())
+}
+
+/// URLを開くために実行するコマンドと引数
+fn open_command_line(browser: Option<&str>, url: &str) -> Vec<String> {
+    if let Some(browser) = browser.map(str::trim).filter(|browser| !browser.is_empty()) {
+        let mut command_line: Vec<String> =
+            browser.split_whitespace().map(str::to_string).collect();
+        command_line.push(url.to_string());
+        return command_line;
+    }
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    vec![opener.to_string(), url.to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_urls_in_text() {
+        // Arrange
+        let text = "See https://example.com/docs, and (http://a.test/x_(y)).\n\
+            [PR](https://github.com/o/r/pull/1) <https://example.com/docs>";
+
+        // Act
+        let urls = find_urls(text);
+
+        // Assert
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/docs",
+                "http://a.test/x_(y)",
+                "https://github.com/o/r/pull/1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_urls_ignores_bare_scheme() {
+        assert!(find_urls("no links here, just https:// and http").is_empty());
+    }
+
+    #[test]
+    fn test_find_task_urls_title_first() {
+        let urls = find_task_urls(
+            "Review https://b.test",
+            Some("Spec: https://a.test\nAlso https://b.test"),
+        );
+
+        assert_eq!(urls, vec!["https://b.test", "https://a.test"]);
+    }
+
+    #[test]
+    fn test_open_command_line_uses_browser_variable() {
+        assert_eq!(
+            open_command_line(Some("firefox --new-tab"), "https://a.test"),
+            vec!["firefox", "--new-tab", "https://a.test"]
+        );
+        assert_eq!(
+            open_command_line(Some(" "), "https://a.test")
+                .last()
+                .unwrap(),
+            "https://a.test"
+        );
+    }
+}
@@ -1,76 +1,1438 @@
+pub mod api;
 mod application;
 mod domain;
 mod infrastructure;
 mod interface;
 
+#[cfg(feature = "mail")]
+use crate::interface::cli::{ingest_handler, settings::MailSettings};
+#[cfg(unix)]
+use crate::interface::{
+    cli::{args::DaemonCommands, daemon_handler},
+    daemon::{
+        self,
+        protocol::{PingResult, RpcError, RunParams, RunResult, UNAVAILABLE},
+        server::{DaemonListener, RequestHandler},
+    },
+};
 use crate::{
-    infrastructure::{DatabaseConnectionManager, load_config},
+    application::{tag_cache::TagCache, use_cases::task::sync_databases::Replica},
+    domain::{
+        clock::SystemClock,
+        services::{BusinessCalendar, UrgencyWeights},
+        tag::{repository::TagRepository, value_objects::TagName},
+        task::{
+            repository::TaskRepository,
+            stale_policy::StalePolicy,
+            status_transition::{StatusTransitionPolicy, TransitionRule},
+            value_objects::{DueDateBuckets, Status},
+        },
+        time_entry::repository::TimeEntryRepository,
+        unit_of_work::UnitOfWorkFactory,
+    },
+    infrastructure::{
+        DatabaseConnectionManager, LogVerbosity,
+        calendar::load_holidays,
+        config::{
+            CalendarConfig, Config, DefaultView, DisplayConfig, DueDateConfig, ListConfig,
+            OverdueConfig, RemoteKind, StaleConfig, StorageBackend, UrgencyConfig, WorkflowConfig,
+        },
+        get_config_path, init_logging, load_config,
+        logging::{QueryMetrics, QueryMetricsSnapshot},
+    },
     interface::{
         cli::{
-            args::{Args, Commands},
-            tag_handler, task_handler,
+            agenda_handler, apply_handler,
+            args::{
+                Args, Commands, DbCommands, Filter, RemoteCommands, SortArg, SyncCommands,
+                SyncPreference,
+            },
+            audit_handler::{self, AuditEntry},
+            context_handler, cron_handler, db_handler,
+            display::{
+                DateFormat,
+                format::truncate_text,
+                set_date_format,
+                theme::{Theme, no_color_env, parse_color},
+            },
+            doctor_handler::{self, DoctorCheck},
+            focus_handler::{self, FocusParams},
+            git_handler, import_handler,
+            purge_handler::{self, PurgePaths},
+            query_handler, report_handler,
+            settings::{
+                CliSettings, CronSettings, GithubSettings, ListSettings, RemoteSettings,
+                RemoteTarget,
+            },
+            summary_handler, sync_handler, tag_handler, task_handler, workload_handler,
+        },
+        i18n::{Locale, Msg, set_locale},
+        persistence::{
+            encryption::FieldCipher,
+            in_memory::{
+                InMemorySnapshot, InMemoryTagRepository, InMemoryTaskRepository,
+                InMemoryTimeEntryRepository, InMemoryUnitOfWorkFactory,
+            },
+            json::{
+                JsonFileStore, JsonTagRepository, JsonTaskRepository, JsonTimeEntryRepository,
+                JsonUnitOfWorkFactory,
+            },
+            read_only::{
+                ReadOnlyError, ReadOnlyTagRepository, ReadOnlyTaskRepository,
+                ReadOnlyTimeEntryRepository, ReadOnlyUnitOfWorkFactory,
+            },
+            sea_orm::{
+                SeaOrmDatabaseAdmin, SeaOrmEncryptionManager, SeaOrmSyncStateStore,
+                SeaOrmTagRepository, SeaOrmTaskRepository, SeaOrmTimeEntryRepository,
+                SeaOrmUnitOfWorkFactory, sqlite_path_from_url,
+            },
         },
-        persistence::sea_orm::{SeaOrmTagRepository, SeaOrmTaskRepository},
-        presentation::CliPresenter,
-        tui,
+        presentation::{CliPresenter, MessageFormat, Presenter},
+        tui::{self, TuiRepositories, TuiSettings},
     },
 };
 use anyhow::{Context, Result};
-use clap::Parser;
-use migration::{Migrator, MigratorTrait};
-use std::sync::Arc;
+use chrono::{NaiveDate, Utc, Weekday};
+use clap::{Parser, ValueEnum};
+use sea_orm::DatabaseConnection;
+#[cfg(unix)]
+use std::time::SystemTime;
+use std::{
+    io::IsTerminal,
+    path::PathBuf,
+    process::ExitCode,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 /// アプリケーションのエントリーポイント
 ///
 /// コマンドライン引数をパースし、適切なコマンドを実行します。
-pub async fn run() -> Result<()> {
+/// 失敗した場合はエラーをプレゼンターで表示し、終了コード1を返します。
+/// `[audit] enabled = true`の場合は、実行したコマンドと結果を実行記録に追記します。
+pub async fn run() -> ExitCode {
+    let argv: Vec<String> = std::env::args_os()
+        .skip(1)
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
     let args = Args::parse();
+    let message_format = args.message_format.into();
+    let config = load_config();
+    // 実行記録を見るコマンド自体と、実行記録を消去するコマンドは記録しない
+    let audit_path = match &config {
+        Ok(config)
+            if config.audit.enabled
+                && !matches!(
+                    args.command,
+                    Some(Commands::Audit { .. } | Commands::PurgeAll { .. })
+                ) =>
+        {
+            Some(config.audit.path.clone())
+        }
+        _ => None,
+    };
+    // 設定を読み込めない場合も、エラーは指定された形式で表示する
+    let theme = match &config {
+        Ok(config) => {
+            build_theme(config, args.no_color || no_color_env()).unwrap_or_else(|_| Theme::plain())
+        }
+        Err(_) => Theme::plain(),
+    };
+    let presenter = Arc::new(CliPresenter::new(theme).with_message_format(message_format));
+
+    let started_at = Utc::now();
+    let started = Instant::now();
+    let result = if matches!(args.command, Some(Commands::Doctor)) {
+        // 設定の問題も診断するため、読み込みに失敗しても実行する
+        run_doctor(config, args.no_color, presenter.clone()).await
+    } else {
+        match config {
+            Ok(config) => run_with_config(args, config).await,
+            Err(e) => Err(e),
+        }
+    };
+    if let Some(path) = audit_path {
+        let entry = AuditEntry::new(started_at, &argv, started.elapsed(), &result);
+        // 記録に失敗してもコマンドの結果は変えない
+        if let Err(e) = audit_handler::append_entry(&path, &entry) {
+            tracing::warn!(error = %e, "failed to write the audit log");
+        }
+    }
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            presenter.report_error(&e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 読み込んだ設定でコマンドを実行
+async fn run_with_config(args: Args, mut config: Config) -> Result<()> {
+    // サブコマンドがない場合は、設定に応じてTUIの代わりに今日の予定を表示する
+    let default_subcommand = match (&args.command, config.display.default_view) {
+        (None, DefaultView::Today) => Some("today"),
+        (None, DefaultView::Agenda) => Some("agenda"),
+        _ => None,
+    };
+    let command = match default_subcommand {
+        Some(subcommand) => Args::parse_from(["yaru", subcommand]).command,
+        None => args.command,
+    };
+    config.storage.read_only |= args.read_only;
+
+    // TUIでは画面が崩れるため、標準エラー出力には表示せずログファイルにのみ出力する
+    let verbosity = LogVerbosity::from_flags(args.verbose, args.quiet);
+    let metrics = args.profile.then(QueryMetrics::new);
+    init_logging(
+        verbosity,
+        &config.logging,
+        command.is_some(),
+        metrics.as_ref(),
+    )?;
+    set_locale(Locale::detect(&config.display.language)?);
+    set_date_format(DateFormat::new(
+        &config.display.date_format,
+        &config.display.timezone,
+    )?);
+
+    let started = Instant::now();
+    let result = match command {
+        Some(Commands::Audit { command }) => {
+            let presenter =
+                CliPresenter::new(build_theme(&config, args.no_color || no_color_env())?)
+                    .with_message_format(args.message_format.into());
+            audit_handler::handle_audit_command(command, &config.audit.path, Arc::new(presenter))
+        }
+        Some(Commands::PurgeAll { confirm }) => {
+            if config.storage.read_only {
+                return Err(ReadOnlyError.into());
+            }
+            let presenter =
+                CliPresenter::new(build_theme(&config, args.no_color || no_color_env())?)
+                    .with_assume_yes(args.yes)
+                    .with_message_format(args.message_format.into());
+            purge_handler::handle_purge_all(&purge_paths(&config)?, confirm, Arc::new(presenter))
+        }
+        #[cfg(unix)]
+        Some(Commands::Daemon { command }) => {
+            if args.ephemeral || memory_db_env() {
+                anyhow::bail!(Msg::ErrorDaemonRequiresSqlite.text());
+            }
+            run_daemon_command(
+                command,
+                config,
+                args.no_color,
+                args.no_migrate,
+                args.message_format.into(),
+            )
+            .await
+        }
+        Some(command) => {
+            let ephemeral = args.ephemeral || memory_db_env();
+            // デーモンで実行するとクエリを集計できないため、--profileの場合は直接実行する
+            #[cfg(unix)]
+            if !ephemeral
+                && args.snapshot.is_none()
+                && !args.profile
+                && let Some(result) =
+                    forward_to_daemon(&command, default_subcommand, &config, args.no_color).await
+            {
+                return result;
+            }
+            let presenter =
+                CliPresenter::new(build_theme(&config, args.no_color || no_color_env())?)
+                    .with_assume_yes(args.yes)
+                    .with_message_format(args.message_format.into());
+            run_cli_with_command(
+                command,
+                config,
+                Arc::new(presenter),
+                ephemeral,
+                args.snapshot,
+                args.no_migrate,
+            )
+            .await
+        }
+        None => run_tui(config, args.ephemeral || memory_db_env(), args.no_migrate).await,
+    };
+
+    if let Some(metrics) = &metrics {
+        report_profile(started.elapsed(), &metrics.snapshot());
+    }
+    result
+}
+
+/// `--profile`の計測結果を標準エラー出力に表示
+///
+/// 同じSQL文が繰り返し実行されている場合は、N+1クエリを見つけやすいよう回数の多いものから表示します。
+fn report_profile(elapsed: Duration, metrics: &QueryMetricsSnapshot) {
+    let millis = |duration: Duration| format!("{:.1}", duration.as_secs_f64() * 1000.0);
+    eprintln!(
+        "{}",
+        Msg::ProfileSummary.format(&[
+            &millis(elapsed),
+            &metrics.queries,
+            &millis(metrics.elapsed),
+            &metrics.rows_returned,
+            &metrics.rows_affected,
+        ])
+    );
 
-    match args.command {
-        Some(command) => run_cli_with_command(command).await,
-        None => run_tui().await,
+    let repeated = metrics.repeated_statements(5);
+    if !repeated.is_empty() {
+        eprintln!("{}", Msg::ProfileRepeatedQueries.text());
+        for (statement, count) in repeated {
+            eprintln!(
+                "{}",
+                Msg::ProfileRepeatedQuery.format(&[&count, &truncate_text(statement, 100)])
+            );
+        }
     }
 }
 
 /// CLIモードで指定されたコマンドを実行
-async fn run_cli_with_command(command: Commands) -> Result<()> {
-    // 設定を読み込む
-    let config = load_config()?;
+async fn run_cli_with_command(
+    command: Commands,
+    config: Config,
+    presenter: Arc<CliPresenter>,
+    ephemeral: bool,
+    snapshot: Option<PathBuf>,
+    no_migrate: bool,
+) -> Result<()> {
+    let settings = build_cli_settings(&config)?;
+    if settings.read_only {
+        ensure_read_only_command(&command)?;
+    }
+
+    if ephemeral {
+        tracing::info!("running in ephemeral mode");
+        return run_ephemeral(command, snapshot, presenter, &settings).await;
+    }
+    if snapshot.is_some() {
+        anyhow::bail!(Msg::ErrorSnapshotRequiresEphemeral.text());
+    }
+
+    if config.storage.backend == StorageBackend::Json {
+        tracing::info!(path = %config.storage.json_path.display(), "using JSON storage");
+        let store = Arc::new(JsonFileStore::open(&config.storage.json_path)?);
+        let task_repo = Arc::new(JsonTaskRepository::new(store.clone()));
+        let tag_repo = Arc::new(JsonTagRepository::new(store.clone()));
+        let time_entry_repo = Arc::new(JsonTimeEntryRepository::new(store.clone()));
+        let unit_of_work = Arc::new(JsonUnitOfWorkFactory::new(store));
+        return execute_command(
+            command,
+            task_repo,
+            tag_repo,
+            time_entry_repo,
+            unit_of_work,
+            presenter,
+            &settings,
+        )
+        .await;
+    }
 
     // データベース接続を確立
+    tracing::info!(database_url = %config.storage.database_url, "connecting to database");
     let db = DatabaseConnectionManager::connect_from_config(&config)
         .await
-        .context("Failed to connect to database")?;
+        .context(Msg::ErrorConnectDatabase.text())?;
 
-    // マイグレーション実行
-    Migrator::up(&db, None)
-        .await
-        .context("Failed to run migrations")?;
+    // 状態確認と明示的なマイグレーション以外は、実行前にスキーマを最新にする
+    // （--no-migrateが指定された場合は確認自体を省略する）
+    let admin = SeaOrmDatabaseAdmin::new(db.clone());
+    let is_schema_command = matches!(
+        command,
+        Commands::Db {
+            command: DbCommands::Status | DbCommands::Migrate
+        }
+    );
+    if !is_schema_command && !no_migrate {
+        prepare_schema(&admin, &config).await?;
+    }
+
+    // データベース管理コマンドは暗号化の解除前に実行する
+    let encryption = SeaOrmEncryptionManager::new(db.clone());
+    if let Commands::Db { command } = command {
+        db_handler::handle_db_command(
+            command,
+            &admin,
+            &encryption,
+            &config.storage.database_url,
+            presenter,
+        )
+        .await?;
+        db.close().await?;
+        return Ok(());
+    }
+
+    // リモートストレージとの同期はデータベースファイル全体を扱うため、暗号化の解除前に実行する
+    if let Commands::Sync {
+        command: SyncCommands::Remote { command },
+    } = command
+    {
+        let database_path = sqlite_path_from_url(&config.storage.database_url)
+            .context(Msg::ErrorRemoteSyncRequiresFile.text())?;
+        return sync_handler::handle_sync_remote(command, db, &database_path, presenter, &settings)
+            .await;
+    }
+
+    // 暗号化されている場合はパスフレーズで鍵を取得
+    let cipher = unlock_database(&db).await?;
+
+    // 他のデータベースとの同期は、相手のデータベースにも接続するためここで実行する
+    if let Commands::Sync {
+        command:
+            SyncCommands::File {
+                path,
+                prefer,
+                dry_run,
+            },
+    } = command
+    {
+        let result = run_sync_file(&db, cipher, (path, prefer, dry_run), &config, presenter).await;
+        db.close().await?;
+        return result;
+    }
 
     // リポジトリを初期化
-    let task_repo = Arc::new(SeaOrmTaskRepository::new(db.clone()));
-    let tag_repo = Arc::new(SeaOrmTagRepository::new(db.clone()));
+    let task_repo = Arc::new(SeaOrmTaskRepository::new(db.clone()).with_cipher(cipher.clone()));
+    // タグは一覧・検索のたびに参照されるため、キャッシュを挟んで再取得を避ける
+    let tag_repo = Arc::new(TagCache::new(Arc::new(SeaOrmTagRepository::new(
+        db.clone(),
+    ))));
+    let time_entry_repo = Arc::new(SeaOrmTimeEntryRepository::new(db.clone()));
+    let unit_of_work = Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone()).with_cipher(cipher));
+
+    execute_command(
+        command,
+        task_repo,
+        tag_repo,
+        time_entry_repo,
+        unit_of_work,
+        presenter,
+        &settings,
+    )
+    .await?;
+
+    // 接続を明示的に閉じる
+    db.close().await?;
+
+    Ok(())
+}
+
+/// 他のデータベースファイルとタスクを同期
+///
+/// 相手のデータベースも同じ設定で開き、スキーマを最新にして暗号化を解除してから同期します。
+async fn run_sync_file(
+    db: &DatabaseConnection,
+    cipher: Option<Arc<FieldCipher>>,
+    (path, prefer, dry_run): (PathBuf, Option<SyncPreference>, bool),
+    config: &Config,
+    presenter: Arc<CliPresenter>,
+) -> Result<()> {
+    if !path.is_file() {
+        anyhow::bail!(Msg::ErrorDatabaseFileNotFound.format(&[&path.display()]));
+    }
+    let current = sqlite_path_from_url(&config.storage.database_url)
+        .and_then(|current| std::fs::canonicalize(current).ok());
+    if current.is_some() && current == std::fs::canonicalize(&path).ok() {
+        anyhow::bail!(Msg::ErrorSyncWithItself.format(&[&path.display()]));
+    }
+
+    let other = DatabaseConnectionManager::connect_with(
+        &format!("sqlite://{}?mode=rw", path.display()),
+        &config.storage.sqlite,
+    )
+    .await
+    .with_context(|| Msg::ErrorOpenFile.format(&[&path.display()]))?;
+    SeaOrmDatabaseAdmin::new(other.clone())
+        .ensure_up_to_date(config.storage.auto_migrate)
+        .await?;
+    let other_cipher = unlock_database(&other).await?;
+
+    let replica = |db: &DatabaseConnection, cipher: Option<Arc<FieldCipher>>| Replica {
+        task_repository: Arc::new(
+            SeaOrmTaskRepository::new(db.clone()).with_cipher(cipher.clone()),
+        ),
+        tag_repository: Arc::new(SeaOrmTagRepository::new(db.clone())),
+        unit_of_work: Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone()).with_cipher(cipher)),
+        state: Arc::new(SeaOrmSyncStateStore::new(db.clone())),
+    };
+    let result = sync_handler::handle_sync_file(
+        replica(db, cipher),
+        replica(&other, other_cipher),
+        prefer,
+        dry_run,
+        build_clock(&config.display)?,
+        presenter,
+    )
+    .await;
+    other.close().await?;
+    result
+}
+
+/// エフェメラルモード（メモリ上のリポジトリ）でコマンドを実行
+///
+/// データベースには一切触れません。スナップショットが指定された場合は、
+/// 存在すれば読み込んでから実行し、終了時に結果を書き出します。
+async fn run_ephemeral(
+    command: Commands,
+    snapshot: Option<PathBuf>,
+    presenter: Arc<CliPresenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    let task_repo = Arc::new(InMemoryTaskRepository::new());
+    let tag_repo = Arc::new(InMemoryTagRepository::new());
+    let time_entry_repo = Arc::new(InMemoryTimeEntryRepository::new());
+
+    if let Some(path) = &snapshot
+        && path.exists()
+    {
+        InMemorySnapshot::load(path)?.restore(&task_repo, &tag_repo, &time_entry_repo)?;
+    }
+
+    let unit_of_work = Arc::new(InMemoryUnitOfWorkFactory::new(
+        task_repo.clone(),
+        tag_repo.clone(),
+    ));
+
+    execute_command(
+        command,
+        task_repo.clone(),
+        tag_repo.clone(),
+        time_entry_repo.clone(),
+        unit_of_work,
+        presenter,
+        settings,
+    )
+    .await?;
+
+    if let Some(path) = &snapshot {
+        InMemorySnapshot::capture(&task_repo, &tag_repo, &time_entry_repo).save(path)?;
+    }
+
+    Ok(())
+}
 
-    // プレゼンターを初期化
-    let presenter = Arc::new(CliPresenter::new());
+/// リポジトリを受け取り、コマンドをハンドラに振り分ける
+async fn execute_command(
+    command: Commands,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    time_entry_repo: Arc<dyn TimeEntryRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    presenter: Arc<dyn Presenter>,
+    settings: &CliSettings,
+) -> Result<()> {
+    // 読み取り専用モードでは、データベースへの書き込みをリポジトリで拒否する
+    let read_only = settings.read_only;
+    let task_repo: Arc<dyn TaskRepository> = if read_only {
+        Arc::new(ReadOnlyTaskRepository::new(task_repo))
+    } else {
+        task_repo
+    };
+    let tag_repo: Arc<dyn TagRepository> = if read_only {
+        Arc::new(ReadOnlyTagRepository::new(tag_repo))
+    } else {
+        tag_repo
+    };
+    let time_entry_repo: Arc<dyn TimeEntryRepository> = if read_only {
+        Arc::new(ReadOnlyTimeEntryRepository::new(time_entry_repo))
+    } else {
+        time_entry_repo
+    };
+    let unit_of_work: Arc<dyn UnitOfWorkFactory> = if read_only {
+        Arc::new(ReadOnlyUnitOfWorkFactory::new(unit_of_work))
+    } else {
+        unit_of_work
+    };
 
-    // コマンド実行
     match command {
         Commands::Task { command } => {
-            task_handler::handle_task_command(command, task_repo, tag_repo, presenter).await?
+            task_handler::handle_task_command(
+                command,
+                task_repo,
+                tag_repo,
+                unit_of_work,
+                presenter,
+                settings,
+            )
+            .await
+        }
+        Commands::Add { text, force } => {
+            task_handler::handle_quick_add(
+                &text.join(" "),
+                force,
+                task_repo,
+                tag_repo,
+                unit_of_work,
+                presenter,
+                settings,
+            )
+            .await
         }
         Commands::Tag { command } => {
-            tag_handler::handle_tag_command(command, tag_repo, presenter).await?
+            tag_handler::handle_tag_command(command, tag_repo, presenter).await
+        }
+        Commands::Db { .. } => anyhow::bail!(Msg::ErrorDbRequiresSqlite.text()),
+        Commands::Git { command } => {
+            git_handler::handle_git_command(command, task_repo, presenter, settings).await
+        }
+        Commands::Sync { command } => {
+            sync_handler::handle_sync_command(command, task_repo, tag_repo, presenter, settings)
+                .await
+        }
+        Commands::Import { command } => {
+            import_handler::handle_import_command(command, unit_of_work, presenter).await
+        }
+        #[cfg(feature = "mail")]
+        Commands::Ingest { command } => {
+            ingest_handler::handle_ingest_command(command, unit_of_work, presenter, settings).await
+        }
+        Commands::Apply { input, format } => {
+            apply_handler::handle_apply(input, format, unit_of_work, presenter, settings).await
+        }
+        Commands::Cron { command } => {
+            cron_handler::handle_cron_command(
+                command,
+                task_repo,
+                tag_repo,
+                unit_of_work,
+                presenter,
+                settings,
+            )
+            .await
+        }
+        Commands::Query {
+            query,
+            format,
+            columns,
+        } => {
+            query_handler::handle_query(
+                query, format, columns, task_repo, tag_repo, presenter, settings,
+            )
+            .await
+        }
+        Commands::Context { command } => {
+            context_handler::handle_context_command(command, task_repo, presenter, settings).await
+        }
+        Commands::Today { all_contexts } => {
+            let context = context_handler::current_context(settings, all_contexts)?;
+            agenda_handler::handle_agenda(0, context, task_repo, tag_repo, presenter, settings)
+                .await
+        }
+        Commands::Agenda { days, all_contexts } => {
+            let context = context_handler::current_context(settings, all_contexts)?;
+            agenda_handler::handle_agenda(days, context, task_repo, tag_repo, presenter, settings)
+                .await
+        }
+        Commands::Summary { oneline } => {
+            summary_handler::handle_summary(oneline, task_repo, presenter, settings).await
+        }
+        Commands::Focus { command, id, timer } => {
+            focus_handler::handle_focus_command(
+                FocusParams { command, id, timer },
+                task_repo,
+                tag_repo,
+                unit_of_work,
+                presenter,
+                settings,
+            )
+            .await
+        }
+        Commands::Workload { week } => {
+            workload_handler::handle_workload(week, task_repo, presenter, settings).await
+        }
+        Commands::Report { command } => {
+            report_handler::handle_report_command(
+                command,
+                task_repo,
+                tag_repo,
+                time_entry_repo,
+                presenter,
+            )
+            .await
+        }
+        Commands::Daemon { .. } => anyhow::bail!(Msg::ErrorDaemonRequiresSqlite.text()),
+        Commands::Doctor => unreachable!("doctor runs before the config is applied"),
+        Commands::Audit { .. } | Commands::PurgeAll { .. } => {
+            unreachable!("runs without opening the database")
         }
     }
+}
 
-    // 接続を明示的に閉じる
-    db.close().await?;
+/// 環境を診断する（`yaru doctor`）
+///
+/// 設定を読み込めない場合も、端末・フックの診断は行います。
+async fn run_doctor(
+    config: Result<Config>,
+    no_color: bool,
+    presenter: Arc<CliPresenter>,
+) -> Result<()> {
+    let config_path = get_config_path()?;
+    let config = config.and_then(|config| validate_config(&config).map(|()| config));
+    if let Ok(config) = &config {
+        set_locale(Locale::detect(&config.display.language)?);
+    }
+
+    let mut checks = Vec::new();
+    let config = match config {
+        Ok(config) => {
+            let detail = if config_path.exists() {
+                Msg::DoctorConfigLoaded
+            } else {
+                Msg::DoctorConfigDefault
+            };
+            checks.push(DoctorCheck::ok(
+                Msg::DoctorCheckConfig.text(),
+                detail.format(&[&config_path.display()]),
+            ));
+            Some(config)
+        }
+        Err(e) => {
+            checks.push(DoctorCheck::error(
+                Msg::DoctorCheckConfig.text(),
+                format!("{:#}", e),
+                Msg::DoctorFixConfig.format(&[&config_path.display()]),
+            ));
+            None
+        }
+    };
+
+    match &config {
+        Some(config) => checks.extend(diagnose_storage(config).await),
+        None => checks.push(DoctorCheck::info(
+            Msg::DoctorCheckDatabase.text(),
+            Msg::DoctorSkipped.text(),
+        )),
+    }
+    checks.push(doctor_handler::check_color(
+        no_color || no_color_env(),
+        config
+            .as_ref()
+            .is_none_or(|config| config.display.theme.enabled),
+        std::io::stdout().is_terminal(),
+    ));
+    checks.push(doctor_handler::check_unicode(
+        doctor_handler::ctype_locale().as_deref(),
+    ));
+    checks.extend(doctor_handler::check_git_hooks(&std::env::current_dir()?));
+    if let Some(config) = &config
+        && config.cron.notify_overdue
+        && let Some(check) = doctor_handler::check_notify_command(&config.cron.notify_command)
+    {
+        checks.push(check);
+    }
 
+    doctor_handler::report(&checks, presenter)
+}
+
+/// 起動時に解釈する設定の値をすべて解釈し、誤りがあればエラーを返す
+fn validate_config(config: &Config) -> Result<()> {
+    Locale::detect(&config.display.language)?;
+    DateFormat::new(&config.display.date_format, &config.display.timezone)?;
+    build_theme(config, false)?;
+    build_cli_settings(config)?;
     Ok(())
 }
 
+/// 保存先に接続し、スキーマと整合性を診断する
+///
+/// SQLiteのファイルがまだない場合は、作成せずにその旨を返します。
+async fn diagnose_storage(config: &Config) -> Vec<DoctorCheck> {
+    let name = Msg::DoctorCheckDatabase.text();
+    if memory_db_env() {
+        return vec![DoctorCheck::info(name, Msg::DoctorDatabaseInMemory.text())];
+    }
+
+    if config.storage.backend == StorageBackend::Json {
+        let path = &config.storage.json_path;
+        return vec![match JsonFileStore::open(path) {
+            Ok(_) if !path.exists() => {
+                DoctorCheck::info(name, Msg::DoctorDatabaseMissing.format(&[&path.display()]))
+            }
+            Ok(_) => DoctorCheck::ok(name, Msg::DoctorJsonLoaded.format(&[&path.display()])),
+            Err(e) => DoctorCheck::error(name, format!("{:#}", e), Msg::DoctorFixDatabase.text()),
+        }];
+    }
+
+    let database_url = &config.storage.database_url;
+    let path = sqlite_path_from_url(database_url);
+    if let Some(path) = &path
+        && !path.exists()
+    {
+        return vec![DoctorCheck::info(
+            name,
+            Msg::DoctorDatabaseMissing.format(&[&path.display()]),
+        )];
+    }
+    let db = match DatabaseConnectionManager::connect_from_config(config).await {
+        Ok(db) => db,
+        Err(e) => {
+            return vec![DoctorCheck::error(
+                name,
+                format!("{:#}", e),
+                Msg::DoctorFixDatabase.text(),
+            )];
+        }
+    };
+
+    let location = path
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|| database_url.clone());
+    let mut checks = vec![DoctorCheck::ok(
+        name,
+        Msg::DoctorDatabaseConnected.format(&[&location]),
+    )];
+    match SeaOrmDatabaseAdmin::new(db.clone())
+        .status(database_url)
+        .await
+    {
+        Ok(status) => checks.extend(doctor_handler::check_database_status(&status)),
+        Err(e) => checks.push(DoctorCheck::error(
+            Msg::DoctorCheckSchema.text(),
+            format!("{:#}", e),
+            Msg::DoctorFixDatabase.text(),
+        )),
+    }
+    let _ = db.close().await;
+    checks
+}
+
+/// YARU_DB環境変数でメモリ上のストアが指定されているか判定
+fn memory_db_env() -> bool {
+    std::env::var("YARU_DB").is_ok_and(|v| v.eq_ignore_ascii_case("memory"))
+}
+
+/// 設定から表示テーマを構築
+///
+/// `--no-color`フラグ、NO_COLOR環境変数、または設定で無効化されている場合は装飾なしのテーマを返します。
+fn build_theme(config: &Config, no_color: bool) -> Result<Theme> {
+    let due_date_buckets = build_due_date_buckets(&config.due_date)?;
+    let config = &config.display.theme;
+    if no_color || !config.enabled {
+        return Ok(Theme::plain());
+    }
+
+    Ok(Theme {
+        enabled: true,
+        critical: parse_color(&config.critical)?,
+        high: parse_color(&config.high)?,
+        medium: parse_color(&config.medium)?,
+        low: parse_color(&config.low)?,
+        overdue: parse_color(&config.overdue)?,
+        due_today: parse_color(&config.due_today)?,
+        due_soon: parse_color(&config.due_soon)?,
+        due_date_buckets,
+        dim_completed: config.dim_completed,
+    })
+}
+
+/// 設定からCLIのハンドラに渡す設定を構築
+fn build_cli_settings(config: &Config) -> Result<CliSettings> {
+    Ok(CliSettings {
+        auto_create_tags: config.tags.auto_create,
+        list: build_list_settings(&config.list)?,
+        transition_policy: build_transition_policy(&config.workflow)?,
+        past_due_date: config.due_date.past_dates.parse().map_err(|_| {
+            anyhow::anyhow!(
+                Msg::ErrorInvalidConfig
+                    .format(&[&"due_date past_dates", &config.due_date.past_dates,])
+            )
+        })?,
+        duplicate_threshold: config
+            .duplicates
+            .check
+            .then_some(config.duplicates.threshold),
+        github: GithubSettings {
+            token: config.github.resolve_token(),
+            api_url: config.github.api_url.clone(),
+        },
+        cron: CronSettings {
+            recurrence_lead_days: config.cron.recurrence_lead_days,
+            notify_command: config
+                .cron
+                .notify_overdue
+                .then(|| config.cron.notify_command.clone()),
+            archive_after_days: config.cron.archive_after_days,
+            archive_path: config.cron.archive_path.clone(),
+            state_path: config.cron.state_path.clone(),
+            business_days: config.calendar.business_days,
+        },
+        daily_capacity: config.workload.daily_capacity.parse().map_err(|e| {
+            anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"workload daily_capacity", &e]))
+        })?,
+        urgency: build_urgency_weights(&config.urgency)?,
+        context_state_path: config.context.state_path.clone(),
+        focus_state_path: config.focus.state_path.clone(),
+        stale: build_stale_policy(&config.stale),
+        due_date_buckets: build_due_date_buckets(&config.due_date)?,
+        calendar: build_business_calendar(&config.calendar)?,
+        clock: build_clock(&config.display)?,
+        week_start: build_week_start(&config.display)?,
+        overdue_tag: build_overdue_tag(&config.overdue)?,
+        remote: RemoteSettings {
+            target: config.remote.kind.map(|kind| match kind {
+                RemoteKind::S3 => {
+                    let (access_key_id, secret_access_key) = config.remote.resolve_s3_credentials();
+                    RemoteTarget::S3 {
+                        endpoint: config.remote.endpoint.clone().unwrap_or_else(|| {
+                            format!("https://s3.{}.amazonaws.com", config.remote.region)
+                        }),
+                        bucket: config.remote.bucket.clone(),
+                        key: config.remote.key.clone(),
+                        region: config.remote.region.clone(),
+                        access_key_id,
+                        secret_access_key,
+                    }
+                }
+                RemoteKind::Webdav => RemoteTarget::WebDav {
+                    url: config.remote.url.clone(),
+                    user: config.remote.user.clone(),
+                    password: config.remote.resolve_password(),
+                },
+            }),
+            state_path: config.remote.state_path.clone(),
+        },
+        #[cfg(feature = "mail")]
+        mail: MailSettings {
+            maildir: config.mail.maildir.clone(),
+            imap_host: config.mail.imap_host.clone(),
+            imap_port: config.mail.imap_port,
+            imap_user: config.mail.imap_user.clone(),
+            imap_password: config.mail.resolve_password(),
+            imap_folder: config.mail.imap_folder.clone(),
+            subject_filter: config.mail.subject_filter.clone(),
+            tag: config.mail.tag.clone(),
+        },
+        read_only: config.storage.read_only,
+    })
+}
+
+/// 設定から期限切れのタスクに自動で付けるタグの名前を取得（タグ名として有効か検証する）
+fn build_overdue_tag(config: &OverdueConfig) -> Result<Option<String>> {
+    config
+        .tag
+        .as_ref()
+        .map(|tag| {
+            TagName::new(tag.clone())
+                .map(|name| name.value().to_string())
+                .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"overdue tag", &e])))
+        })
+        .transpose()
+}
+
+/// 設定の`[display] timezone`から「今日」の判定に使用するClockを構築
+fn build_clock(config: &DisplayConfig) -> Result<SystemClock> {
+    let timezone = config
+        .timezone
+        .parse()
+        .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"display timezone", &e])))?;
+    Ok(SystemClock::new(timezone))
+}
+
+/// 設定からカレンダーの週の始まりの曜日を取得
+fn build_week_start(config: &DisplayConfig) -> Result<Weekday> {
+    config
+        .week_start
+        .parse()
+        .map_err(|_| anyhow::anyhow!(Msg::ErrorInvalidWeekStart.format(&[&config.week_start])))
+}
+
+/// 設定から営業日のカレンダーを構築（祝日のファイルがある場合は読み込む）
+fn build_business_calendar(config: &CalendarConfig) -> Result<BusinessCalendar> {
+    let mut holidays = config
+        .holidays
+        .iter()
+        .map(|date| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"calendar holiday", date]))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(path) = &config.holidays_file {
+        holidays.extend(load_holidays(path)?);
+    }
+    Ok(BusinessCalendar::with_holidays(holidays))
+}
+
+/// 設定から期限日の分類の境界を構築
+fn build_due_date_buckets(config: &DueDateConfig) -> Result<DueDateBuckets> {
+    DueDateBuckets::new(config.soon_days, config.upcoming_days)
+        .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"due_date buckets", &e])))
+}
+
+/// 設定から緊急度スコアの重みを構築
+fn build_urgency_weights(config: &UrgencyConfig) -> Result<UrgencyWeights> {
+    UrgencyWeights::new(config.priority, config.due, config.age, config.blocked)
+        .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"urgency weights", &e])))
+}
+
+/// 設定から放置されているタスクを判定するポリシーを構築
+fn build_stale_policy(config: &StaleConfig) -> StalePolicy {
+    StalePolicy::disabled()
+        .with_threshold(Status::Pending, config.pending_days)
+        .with_threshold(Status::InProgress, config.in_progress_days)
+}
+
+/// 設定から`task list`の既定の表示設定を構築
+fn build_list_settings(config: &ListConfig) -> Result<ListSettings> {
+    let sort = SortArg::from_str(&config.sort, true).map_err(|_| {
+        anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"list sort", &config.sort]))
+    })?;
+    let filters = config
+        .filters
+        .iter()
+        .map(|filter| filter.parse::<Filter>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"list filter", &e])))?;
+    let columns = config
+        .columns
+        .iter()
+        .map(|column| column.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"list column", &e])))?;
+    let templates = config
+        .templates
+        .iter()
+        .map(|(name, template)| {
+            template
+                .parse()
+                .map(|template| (name.clone(), template))
+                .map_err(|e| {
+                    anyhow::anyhow!(
+                        Msg::ErrorInvalidConfig.format(&[&format!("list template '{}'", name), &e])
+                    )
+                })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(ListSettings {
+        sort: sort.into(),
+        filters,
+        columns,
+        hide_completed: config.hide_completed,
+        templates,
+    })
+}
+
+/// 設定からステータス遷移のポリシーを構築
+fn build_transition_policy(config: &WorkflowConfig) -> Result<StatusTransitionPolicy> {
+    let mut policy = if config.require_reopen {
+        StatusTransitionPolicy::default()
+    } else {
+        StatusTransitionPolicy::permissive()
+    };
+    for transition in &config.forbidden_transitions {
+        let (from, to) = transition.split_once("->").ok_or_else(|| {
+            anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"transition", transition]))
+        })?;
+        let from = from
+            .parse::<Status>()
+            .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"transition", &e])))?;
+        let to = to
+            .parse::<Status>()
+            .map_err(|e| anyhow::anyhow!(Msg::ErrorInvalidConfig.format(&[&"transition", &e])))?;
+        policy = policy.with_rule(from, to, TransitionRule::Forbidden);
+    }
+    Ok(policy)
+}
+
 /// TUIモードで実行
-async fn run_tui() -> Result<()> {
-    tui::run_tui().await
+///
+/// CLIと同じ保存先を使用します。暗号化されている場合は、画面を切り替える前にパスフレーズを尋ねます。
+async fn run_tui(config: Config, ephemeral: bool, no_migrate: bool) -> Result<()> {
+    let settings = TuiSettings {
+        urgency: build_urgency_weights(&config.urgency)?,
+        context: context_handler::load_current_context(&config.context.state_path)?,
+        due_date_buckets: build_due_date_buckets(&config.due_date)?,
+        clock: build_clock(&config.display)?,
+        week_start: build_week_start(&config.display)?,
+    };
+    // 読み取り専用モードでは、データベースへの書き込みをリポジトリで拒否する
+    let guard = |repositories: TuiRepositories| {
+        if config.storage.read_only {
+            repositories.read_only()
+        } else {
+            repositories
+        }
+    };
+    if ephemeral {
+        tracing::info!("running in ephemeral mode");
+        let task_repo = Arc::new(InMemoryTaskRepository::new());
+        let tag_repo = Arc::new(InMemoryTagRepository::new());
+        return tui::run_tui(
+            guard(TuiRepositories {
+                task_repo: task_repo.clone(),
+                tag_repo: tag_repo.clone(),
+                time_entry_repo: Arc::new(InMemoryTimeEntryRepository::new()),
+            }),
+            settings,
+        )
+        .await;
+    }
+
+    if config.storage.backend == StorageBackend::Json {
+        tracing::info!(path = %config.storage.json_path.display(), "using JSON storage");
+        let store = Arc::new(JsonFileStore::open(&config.storage.json_path)?);
+        return tui::run_tui(
+            guard(TuiRepositories {
+                task_repo: Arc::new(JsonTaskRepository::new(store.clone())),
+                tag_repo: Arc::new(JsonTagRepository::new(store.clone())),
+                time_entry_repo: Arc::new(JsonTimeEntryRepository::new(store.clone())),
+            }),
+            settings,
+        )
+        .await;
+    }
+
+    tracing::info!(database_url = %config.storage.database_url, "connecting to database");
+    let db = DatabaseConnectionManager::connect_from_config(&config)
+        .await
+        .context(Msg::ErrorConnectDatabase.text())?;
+    if !no_migrate {
+        prepare_schema(&SeaOrmDatabaseAdmin::new(db.clone()), &config).await?;
+    }
+    let cipher = unlock_database(&db).await?;
+
+    tui::run_tui(
+        guard(TuiRepositories {
+            task_repo: Arc::new(SeaOrmTaskRepository::new(db.clone()).with_cipher(cipher.clone())),
+            // 一覧を読み込み直すたびにタグを参照するため、CLIと同じくキャッシュを挟む
+            tag_repo: Arc::new(TagCache::new(Arc::new(SeaOrmTagRepository::new(
+                db.clone(),
+            )))),
+            time_entry_repo: Arc::new(SeaOrmTimeEntryRepository::new(db.clone())),
+        }),
+        settings,
+    )
+    .await?;
+
+    db.close().await?;
+
+    Ok(())
+}
+
+/// 設定で有効な場合、コマンドを起動中のデーモンで実行する
+///
+/// 転送できないコマンドの場合や、デーモンが起動していない・実行できない場合は
+/// `None`を返し、呼び出し元でデータベースに直接アクセスして実行させます。
+/// `default_subcommand`は、設定によりサブコマンドなしで実行したコマンドで、引数の末尾に追加して転送します。
+#[cfg(unix)]
+async fn forward_to_daemon(
+    command: &Commands,
+    default_subcommand: Option<&str>,
+    config: &Config,
+    no_color: bool,
+) -> Option<Result<()>> {
+    if !config.daemon.enabled
+        || config.storage.backend != StorageBackend::Sqlite
+        || !daemon::client::is_forwardable(command)
+    {
+        return None;
+    }
+
+    let params = RunParams {
+        args: std::env::args_os()
+            .skip(1)
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .chain(default_subcommand.map(str::to_string))
+            .collect(),
+        database_url: config.storage.database_url.clone(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        no_color: no_color || no_color_env(),
+    };
+    match daemon::client::run(&config.daemon.socket_path, params).await {
+        Ok(output) => {
+            print!("{}", output.stdout);
+            eprint!("{}", output.stderr);
+            Some(match output.error {
+                Some(error) => Err(anyhow::anyhow!(error)),
+                None => Ok(()),
+            })
+        }
+        Err(e) => {
+            tracing::debug!(error = %e, "daemon unavailable, running directly");
+            None
+        }
+    }
+}
+
+/// デーモン管理コマンドを実行
+#[cfg(unix)]
+async fn run_daemon_command(
+    command: DaemonCommands,
+    config: Config,
+    no_color: bool,
+    no_migrate: bool,
+    message_format: MessageFormat,
+) -> Result<()> {
+    let presenter = Arc::new(
+        CliPresenter::new(build_theme(&config, no_color || no_color_env())?)
+            .with_message_format(message_format),
+    );
+    let socket_path = config.daemon.socket_path.clone();
+
+    match command {
+        DaemonCommands::Start { idle_timeout } => {
+            run_daemon(config, idle_timeout, no_migrate, presenter).await
+        }
+        DaemonCommands::Status => daemon_handler::handle_status(&socket_path, presenter).await,
+        DaemonCommands::Stop => daemon_handler::handle_stop(&socket_path, presenter).await,
+    }
+}
+
+/// デーモンとして常駐し、転送されたコマンドを実行する
+///
+/// データベース接続・暗号化の鍵・タグのキャッシュを保持したまま、
+/// `daemon stop`か待ち時間の経過まで待ち受けます。
+#[cfg(unix)]
+async fn run_daemon(
+    config: Config,
+    idle_timeout: Option<u64>,
+    no_migrate: bool,
+    presenter: Arc<CliPresenter>,
+) -> Result<()> {
+    if config.storage.backend != StorageBackend::Sqlite {
+        anyhow::bail!(Msg::ErrorDaemonRequiresSqlite.text());
+    }
+    let settings = build_cli_settings(&config)?;
+    let listener = DaemonListener::open(&config.daemon.socket_path)?;
+
+    tracing::info!(database_url = %config.storage.database_url, "connecting to database");
+    let db = DatabaseConnectionManager::connect_from_config(&config)
+        .await
+        .context(Msg::ErrorConnectDatabase.text())?;
+    if !no_migrate {
+        prepare_schema(&SeaOrmDatabaseAdmin::new(db.clone()), &config).await?;
+    }
+    let cipher = unlock_database(&db).await?;
+
+    let service = DaemonService {
+        database_path: sqlite_path_from_url(&config.storage.database_url),
+        fingerprint: std::sync::Mutex::new(None),
+        encrypted: cipher.is_some(),
+        encryption: SeaOrmEncryptionManager::new(db.clone()),
+        task_repo: Arc::new(SeaOrmTaskRepository::new(db.clone()).with_cipher(cipher.clone())),
+        tag_repo: Arc::new(TagCache::new(Arc::new(SeaOrmTagRepository::new(
+            db.clone(),
+        )))),
+        time_entry_repo: Arc::new(SeaOrmTimeEntryRepository::new(db.clone())),
+        unit_of_work: Arc::new(SeaOrmUnitOfWorkFactory::new(db.clone()).with_cipher(cipher)),
+        settings,
+        config,
+    };
+
+    presenter.present_info(
+        &Msg::DaemonListening.format(&[&service.config.daemon.socket_path.display()]),
+    )?;
+    let idle_timeout = idle_timeout.unwrap_or(service.config.daemon.idle_timeout);
+    daemon::server::serve(
+        listener,
+        &service,
+        (idle_timeout > 0).then(|| Duration::from_secs(idle_timeout)),
+    )
+    .await?;
+
+    db.close().await?;
+    Ok(())
+}
+
+/// デーモンが保持する設定とリポジトリ
+#[cfg(unix)]
+struct DaemonService {
+    config: Config,
+    settings: CliSettings,
+    task_repo: Arc<dyn TaskRepository>,
+    tag_repo: Arc<TagCache>,
+    time_entry_repo: Arc<dyn TimeEntryRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    encryption: SeaOrmEncryptionManager,
+    /// 起動時に暗号化されていたか
+    encrypted: bool,
+    /// データベースファイルのパス（メモリ上の場合はNone）
+    database_path: Option<PathBuf>,
+    /// 前回のリクエスト時のデータベースファイルの状態
+    fingerprint: std::sync::Mutex<Option<Vec<FileStamp>>>,
+}
+
+/// ファイルのサイズと更新日時（ファイルが存在しない場合はNone）
+#[cfg(unix)]
+type FileStamp = Option<(u64, SystemTime)>;
+
+#[cfg(unix)]
+impl DaemonService {
+    /// 他のプロセスがデータベースを変更していれば、タグのキャッシュを破棄する
+    ///
+    /// データベースファイルとWALファイルのサイズ・更新日時で変更を検出します。
+    /// 暗号化の状態が変わっていた場合は、鍵を持ち直す必要があるためエラーを返します。
+    async fn refresh(&self) -> Result<(), RpcError> {
+        let Some(path) = &self.database_path else {
+            return Ok(());
+        };
+        let current: Vec<_> = [
+            path.clone(),
+            PathBuf::from(format!("{}-wal", path.display())),
+        ]
+        .iter()
+        .map(|path| {
+            let metadata = std::fs::metadata(path).ok()?;
+            Some((metadata.len(), metadata.modified().ok()?))
+        })
+        .collect();
+        let changed = {
+            let mut fingerprint = self.fingerprint.lock().unwrap();
+            let changed = fingerprint.as_ref() != Some(&current);
+            *fingerprint = Some(current);
+            changed
+        };
+        if !changed {
+            return Ok(());
+        }
+
+        self.tag_repo.invalidate();
+        let encrypted = self
+            .encryption
+            .is_encrypted()
+            .await
+            .map_err(|e| daemon_unavailable(e.to_string()))?;
+        if encrypted != self.encrypted {
+            return Err(daemon_unavailable(
+                "Database encryption changed; restart the daemon",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+#[async_trait::async_trait]
+impl RequestHandler for DaemonService {
+    fn ping(&self) -> PingResult {
+        PingResult {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            database_url: self.config.storage.database_url.clone(),
+            pid: std::process::id(),
+        }
+    }
+
+    async fn run(&self, params: RunParams) -> Result<RunResult, RpcError> {
+        if params.version != env!("CARGO_PKG_VERSION")
+            || params.database_url != self.config.storage.database_url
+        {
+            return Err(daemon_unavailable(
+                "The daemon serves a different database or version",
+            ));
+        }
+        let args = Args::try_parse_from(std::iter::once("yaru".to_string()).chain(params.args))
+            .map_err(|e| daemon_unavailable(e.to_string()))?;
+        let command = args
+            .command
+            .filter(daemon::client::is_forwardable)
+            .ok_or_else(|| daemon_unavailable("The command cannot run in the daemon"))?;
+        let theme = build_theme(&self.config, params.no_color || args.no_color)
+            .map_err(|e| daemon_unavailable(e.to_string()))?;
+        self.refresh().await?;
+
+        let message_format: MessageFormat = args.message_format.into();
+        let presenter =
+            Arc::new(CliPresenter::capturing(theme).with_message_format(message_format));
+        let result = execute_command(
+            command,
+            self.task_repo.clone(),
+            self.tag_repo.clone(),
+            self.time_entry_repo.clone(),
+            self.unit_of_work.clone(),
+            presenter.clone(),
+            &self.settings,
+        )
+        .await;
+
+        let output = presenter.take_output();
+        Ok(RunResult {
+            stdout: output.stdout,
+            stderr: output.stderr,
+            error: result.err().map(|e| message_format.error_text(&e)),
+        })
+    }
+}
+
+/// クライアントに直接実行させるためのエラー
+#[cfg(unix)]
+fn daemon_unavailable(message: impl Into<String>) -> RpcError {
+    RpcError {
+        code: UNAVAILABLE,
+        message: message.into(),
+    }
+}
+
+/// 実行前にスキーマを最新にする
+///
+/// 読み取り専用モードでは書き込まずに確認だけを行い、未適用のマイグレーションがあればエラーにします。
+async fn prepare_schema(admin: &SeaOrmDatabaseAdmin, config: &Config) -> Result<()> {
+    if config.storage.read_only {
+        admin.check_up_to_date().await
+    } else {
+        admin.ensure_up_to_date(config.storage.auto_migrate).await
+    }
+}
+
+/// `purge-all`で消去するファイルの場所を設定から求める
+fn purge_paths(config: &Config) -> Result<PurgePaths> {
+    let config_path = get_config_path()?;
+    let mut files = vec![
+        config_path.clone(),
+        config.storage.json_path.clone(),
+        config.context.state_path.clone(),
+        config.focus.state_path.clone(),
+        config.cron.state_path.clone(),
+        config.cron.archive_path.clone(),
+        config.remote.state_path.clone(),
+        config.audit.path.clone(),
+    ];
+    if cfg!(unix) {
+        files.push(config.daemon.socket_path.clone());
+    }
+    Ok(PurgePaths {
+        database: sqlite_path_from_url(&config.storage.database_url),
+        log_file: config.logging.file.clone(),
+        files,
+        config_dir: config_path.parent().map(PathBuf::from),
+    })
+}
+
+/// 読み取り専用モードで実行できるコマンドか確認する
+///
+/// リポジトリを経由せずにデータベースを変更するコマンドや、
+/// 外部のサービス・他のデータベースを変更してからデータベースに書き込むコマンドは、実行前に拒否します。
+/// 一覧・表示などの参照するコマンドはデータベースを変更しないため、常に実行できます。
+fn ensure_read_only_command(command: &Commands) -> Result<()> {
+    let modifies = match command {
+        Commands::Db { command } => !matches!(command, DbCommands::Status),
+        // 期限切れの通知やアーカイブのファイルへの書き出しなど、データベース以外も変更する
+        Commands::Cron { .. } => true,
+        Commands::Sync { command } => !matches!(
+            command,
+            SyncCommands::Remote {
+                command: RemoteCommands::Push { .. }
+            }
+        ),
+        _ => false,
+    };
+    if modifies {
+        return Err(ReadOnlyError.into());
+    }
+    Ok(())
+}
+
+/// 暗号化されている場合はパスフレーズで鍵を取得
+async fn unlock_database(db: &DatabaseConnection) -> Result<Option<Arc<FieldCipher>>> {
+    let encryption = SeaOrmEncryptionManager::new(db.clone());
+    if !encryption.is_encrypted().await? {
+        return Ok(None);
+    }
+
+    let passphrase = db_handler::read_passphrase(false)?;
+    Ok(Some(Arc::new(encryption.unlock(&passphrase).await?)))
 }
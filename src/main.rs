@@ -1,7 +1,7 @@
-use anyhow::Result;
+use std::process::ExitCode;
 use yaru::run;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> ExitCode {
     run().await
 }
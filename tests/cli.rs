@@ -0,0 +1,687 @@
+//! ビルドしたyaruのバイナリを実行するエンドツーエンドテスト
+//!
+//! 一時ディレクトリをHOMEにして、そこに置いた設定ファイルのSQLiteデータベースに対してコマンドを実行します。
+//! ユースケース単体のテストでは見つからない、引数の解析・依存関係の組み立て・出力・終了コードの問題を検出します。
+//!
+//! JSON出力のスナップショットは`tests/snapshots/`にあります。出力を変更した場合は
+//! `INSTA_UPDATE=always cargo test --test cli`で更新し、差分を確認してください。
+
+use assert_cmd::Command;
+use chrono::{Datelike, NaiveDate, Utc, Weekday};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::{fs, path::PathBuf};
+use tempfile::TempDir;
+
+/// 一時ディレクトリをHOMEとして使うテスト環境
+struct TestEnv {
+    home: TempDir,
+}
+
+impl TestEnv {
+    /// 一時ディレクトリのSQLiteデータベースを使う設定ファイルを作成する
+    fn new() -> Self {
+        let home = tempfile::tempdir().unwrap();
+        let config_dir = home.path().join(".config").join("yaru");
+        fs::create_dir_all(&config_dir).unwrap();
+        let env = Self { home };
+        fs::write(
+            config_dir.join("config.toml"),
+            format!(
+                "[storage]\ndatabase_url = \"sqlite://{}?mode=rwc\"\n",
+                env.db_path().display()
+            ),
+        )
+        .unwrap();
+        env
+    }
+
+    fn db_path(&self) -> PathBuf {
+        self.home.path().join("data").join("e2e.db")
+    }
+
+    /// 利用者の環境（言語・色・保存先の指定）に影響されないyaruコマンド
+    fn yaru(&self) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_yaru"));
+        command
+            .env("HOME", self.home.path())
+            .env("NO_COLOR", "1")
+            .env("LC_ALL", "C")
+            .env_remove("LC_MESSAGES")
+            .env_remove("LANG")
+            .env_remove("YARU_DB")
+            .env_remove("YARU_PASSPHRASE")
+            .env_remove("RUST_LOG")
+            .env_remove("RUST_BACKTRACE")
+            .env_remove("RUST_LIB_BACKTRACE");
+        command
+    }
+
+    /// 全タスクを`ID タイトル ステータス 優先度 タグ`の形式で取得する
+    fn task_lines(&self) -> String {
+        let output = self
+            .yaru()
+            .args([
+                "task",
+                "list",
+                "--all",
+                "--template",
+                "{{id}} {{title}} {{status}} {{priority}} {{tags}}",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    }
+}
+
+/// 標準出力をJSONとして読み込む
+fn stdout_json(output: &std::process::Output) -> Value {
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_task_lifecycle_against_sqlite() {
+    let env = TestEnv::new();
+
+    // 追加
+    env.yaru()
+        .args([
+            "task",
+            "add",
+            "Write report",
+            "--priority",
+            "high",
+            "--tag-names",
+            "work",
+        ])
+        .assert()
+        .success()
+        .stdout("Task added: [1] Write report\n");
+    env.yaru()
+        .args(["task", "add", "Buy milk", "--due-date", "2099-01-31"])
+        .assert()
+        .success()
+        .stdout("Task added: [2] Buy milk\n");
+    assert!(env.db_path().exists());
+
+    // 一覧
+    assert_eq!(
+        env.task_lines(),
+        "1 Write report pending high work\n2 Buy milk pending medium \n"
+    );
+
+    // 編集
+    env.yaru()
+        .args([
+            "task",
+            "edit",
+            "1",
+            "--status",
+            "in_progress",
+            "--title",
+            "Write the report",
+        ])
+        .assert()
+        .success();
+
+    // 完了
+    env.yaru()
+        .args(["task", "done", "2"])
+        .assert()
+        .success()
+        .stdout("Task completed: [2] Buy milk\n");
+    assert_eq!(
+        env.task_lines(),
+        "1 Write the report in_progress high work\n2 Buy milk completed medium \n"
+    );
+
+    // 統計
+    env.yaru()
+        .args(["task", "stats"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Total tasks: 2"))
+        .stdout(predicate::str::contains("work"));
+    env.yaru()
+        .args(["task", "stats", "--flow"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Tasks analysed: 2"))
+        .stdout(
+            predicate::str::is_match(r"Lead time \(created → completed\) +┆ +\S+ ┆ +1 │").unwrap(),
+        );
+
+    // 削除
+    env.yaru()
+        .args(["--yes", "task", "delete", "2"])
+        .assert()
+        .success();
+    assert_eq!(
+        env.task_lines(),
+        "1 Write the report in_progress high work\n"
+    );
+}
+
+/// `date`から土日を除いて`days`日後の日付
+fn add_weekdays(mut date: NaiveDate, days: u32) -> NaiveDate {
+    let mut remaining = days;
+    while remaining > 0 {
+        date = date.succ_opt().unwrap();
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            remaining -= 1;
+        }
+    }
+    date
+}
+
+#[test]
+fn test_relative_dates_for_due_date_and_snooze() {
+    // Arrange: 今日の日付がずれないようにタイムゾーンをUTCにする
+    let env = TestEnv::new();
+    let config = env
+        .home
+        .path()
+        .join(".config")
+        .join("yaru")
+        .join("config.toml");
+    let mut content = fs::read_to_string(&config).unwrap();
+    content.push_str("[display]\ntimezone = \"+00:00\"\n");
+    fs::write(&config, content).unwrap();
+    let today = Utc::now().date_naive();
+
+    // Act & Assert: 期限日に営業日数を指定できる
+    env.yaru()
+        .args(["task", "add", "Send invoice", "--due-date", "+3bd"])
+        .assert()
+        .success();
+    env.yaru()
+        .args(["task", "list", "--all", "--template", "{{id}} {{due_date}}"])
+        .assert()
+        .success()
+        .stdout(format!("1 {}\n", add_weekdays(today, 3).format("%Y-%m-%d")));
+
+    // Act & Assert: スヌーズの期限にも営業日数を指定できる
+    env.yaru()
+        .args(["task", "snooze", "1", "--until", "+2bd"])
+        .assert()
+        .success()
+        .stdout(format!(
+            "Snoozed task [1] Send invoice until {}\n",
+            add_weekdays(today, 2).format("%Y-%m-%d")
+        ));
+
+    // Act & Assert: 不正な期間はエラーにする
+    env.yaru()
+        .args(["task", "add", "Broken", "--due-date", "+0bd"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid period: '+0bd'"));
+}
+
+#[test]
+fn test_delete_requires_confirmation_without_terminal() {
+    // Arrange
+    let env = TestEnv::new();
+    env.yaru()
+        .args(["task", "add", "Keep me"])
+        .assert()
+        .success();
+
+    // Act & Assert: 端末がない場合は--yesなしでは削除しない
+    env.yaru()
+        .args(["task", "delete", "1"])
+        .write_stdin("")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("pass --yes to confirm"));
+    assert_eq!(env.task_lines(), "1 Keep me pending medium \n");
+}
+
+#[test]
+fn test_delete_rejects_id_prefix() {
+    // Arrange: タスク1を削除し、「1」がタスク10だけの前方一致になる状態にする
+    let env = TestEnv::new();
+    for n in 1..=10 {
+        env.yaru()
+            .args(["task", "add", &format!("Task {n}")])
+            .assert()
+            .success();
+    }
+    env.yaru()
+        .args(["--yes", "task", "delete", "1"])
+        .assert()
+        .success();
+
+    // Act & Assert: 参照するコマンドは前方一致で解決する
+    env.yaru()
+        .args(["task", "show", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Task 10"));
+
+    // Act & Assert: 削除するコマンドは完全なIDが必要
+    env.yaru()
+        .args(["--yes", "task", "delete", "1"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("need the full ID"));
+    assert!(env.task_lines().contains("10 Task 10 "));
+}
+
+#[test]
+fn test_audit_records_invocations() {
+    // Arrange
+    let env = TestEnv::new();
+    let config = env
+        .home
+        .path()
+        .join(".config")
+        .join("yaru")
+        .join("config.toml");
+    let mut content = fs::read_to_string(&config).unwrap();
+    content.push_str("[audit]\nenabled = true\n");
+    fs::write(&config, content).unwrap();
+
+    // Act
+    env.yaru()
+        .args(["task", "add", "Audited"])
+        .assert()
+        .success();
+    env.yaru().args(["task", "show", "99"]).assert().code(1);
+    let output = env
+        .yaru()
+        .args(["audit", "tail", "--json"])
+        .output()
+        .unwrap();
+
+    // Assert: 成功・失敗の両方が記録され、audit自体は記録されない
+    assert!(output.status.success());
+    let entries: Vec<Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["command"], "task add");
+    assert_eq!(
+        entries[0]["args"],
+        serde_json::json!(["task", "add", "Audited"])
+    );
+    assert_eq!(entries[0]["result"], "ok");
+    assert_eq!(entries[1]["command"], "task show");
+    assert_eq!(entries[1]["result"], "error");
+    assert!(entries[1]["error"].is_string());
+    env.yaru()
+        .args(["audit", "tail", "-n", "1"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("error").and(predicate::str::contains("yaru task show 99")),
+        );
+}
+
+#[test]
+fn test_purge_all_lists_then_deletes() {
+    // Arrange
+    let env = TestEnv::new();
+    env.yaru()
+        .args(["task", "add", "Private"])
+        .assert()
+        .success();
+    let config_dir = env.home.path().join(".config").join("yaru");
+
+    // Act & Assert: --confirmがない場合は一覧を表示するだけ
+    env.yaru()
+        .arg("purge-all")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            env.db_path().display().to_string(),
+        ))
+        .stdout(predicate::str::contains(
+            config_dir.join("config.toml").display().to_string(),
+        ));
+    assert!(env.db_path().exists());
+
+    // Act & Assert: --confirmでデータベースと設定を削除する
+    env.yaru()
+        .args(["--yes", "purge-all", "--confirm"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed"));
+    assert!(!env.db_path().exists());
+    assert!(!config_dir.exists());
+}
+
+#[test]
+fn test_read_only_blocks_changes() {
+    // Arrange
+    let env = TestEnv::new();
+    env.yaru()
+        .args(["task", "add", "Keep me"])
+        .assert()
+        .success();
+
+    // Act & Assert: 参照はできる
+    env.yaru()
+        .args(["--read-only", "task", "show", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Keep me"));
+
+    // Act & Assert: 変更するコマンドはエラーになり、データベースは変わらない
+    for args in [
+        vec!["task", "add", "New task"],
+        vec!["task", "done", "1"],
+        vec!["--yes", "task", "delete", "1"],
+        vec!["db", "migrate"],
+    ] {
+        env.yaru()
+            .arg("--read-only")
+            .args(&args)
+            .assert()
+            .code(1)
+            .stderr(predicate::str::contains("read-only mode"));
+    }
+    assert_eq!(env.task_lines(), "1 Keep me pending medium \n");
+}
+
+#[test]
+fn test_read_only_list_with_overdue_tag() {
+    // Arrange: 期限切れのタグを設定し、期限切れのタスクを作成する
+    let env = TestEnv::new();
+    let config = env
+        .home
+        .path()
+        .join(".config")
+        .join("yaru")
+        .join("config.toml");
+    let mut contents = fs::read_to_string(&config).unwrap();
+    contents.push_str("\n[overdue]\ntag = \"overdue\"\n");
+    fs::write(&config, contents).unwrap();
+    let yesterday = Utc::now().date_naive().pred_opt().unwrap();
+    env.yaru()
+        .args([
+            "task",
+            "add",
+            "Pay bill",
+            "--due-date",
+            &yesterday.to_string(),
+        ])
+        .assert()
+        .success();
+
+    // Act & Assert: 一覧はデータベースを変更しないため、読み取り専用でも表示できる
+    env.yaru()
+        .args(["--read-only", "task", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pay bill"));
+
+    // Act & Assert: タグを付けるcron runは実行前に拒否する
+    env.yaru()
+        .args(["--read-only", "cron", "run"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("read-only mode"));
+    assert_eq!(env.task_lines(), "1 Pay bill pending medium \n");
+}
+
+#[test]
+fn test_search_ranks_title_matches_first() {
+    // Arrange
+    let env = TestEnv::new();
+    for (title, description) in [
+        ("Weekly sync", "review the report draft"),
+        ("Report archive", "old files"),
+        ("Review report", "before Friday"),
+    ] {
+        env.yaru()
+            .args(["task", "add", title, "--description", description])
+            .assert()
+            .success();
+    }
+
+    // Act
+    let output = env
+        .yaru()
+        .args(["task", "search", "report review"])
+        .output()
+        .unwrap();
+
+    // Assert: 両方の語がタイトルにあるタスク、説明だけにあるタスクの順（1語だけのタスクは一致しない）
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let position = |title: &str| stdout.find(title).unwrap();
+    assert!(position("Review report") < position("Weekly sync"));
+    assert!(!stdout.contains("Report archive"));
+    assert!(!stdout.contains('\u{200B}'));
+}
+
+#[test]
+fn test_search_piped_output_has_no_highlight() {
+    // Arrange
+    let env = TestEnv::new();
+    env.yaru()
+        .args(["task", "add", "Fix login bug"])
+        .assert()
+        .success();
+
+    // Act: 色付けを有効にしても、パイプへの出力は装飾しない
+    let output = env
+        .yaru()
+        .env_remove("NO_COLOR")
+        .args(["task", "search", "login"])
+        .output()
+        .unwrap();
+
+    // Assert
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Fix login bug"));
+    assert!(!stdout.contains('\x1b'));
+    assert!(!stdout.contains('\u{200B}'));
+}
+
+#[test]
+fn test_missing_task_exits_with_error() {
+    let env = TestEnv::new();
+
+    env.yaru()
+        .args(["task", "show", "42"])
+        .assert()
+        .code(1)
+        .stdout("")
+        .stderr(predicate::str::contains("Task ID 42 does not exist"));
+}
+
+#[test]
+fn test_add_task_error_in_japanese() {
+    // Arrange: タグを自動で作成しない
+    let env = TestEnv::new();
+    let config = env
+        .home
+        .path()
+        .join(".config")
+        .join("yaru")
+        .join("config.toml");
+    let mut content = fs::read_to_string(&config).unwrap();
+    content.push_str("[tags]\nauto_create = false\n");
+    fs::write(&config, content).unwrap();
+
+    // Act & Assert: アプリケーション層のエラーも表示する言語の文言になる
+    env.yaru()
+        .env("LC_ALL", "ja_JP.UTF-8")
+        .args(["task", "add", "Buy milk", "--tag-names", "home"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("タグ「home」は存在しません"));
+}
+
+#[test]
+fn test_messages_in_json_format() {
+    // Arrange
+    let env = TestEnv::new();
+
+    // Act
+    let added = env
+        .yaru()
+        .args(["--message-format", "json", "task", "add", "牛乳を買う"])
+        .output()
+        .unwrap();
+    let failed = env
+        .yaru()
+        .args(["--message-format", "json", "task", "show", "42"])
+        .output()
+        .unwrap();
+
+    // Assert: 成功は標準出力、エラーは標準エラー出力に1行1オブジェクトで出力される
+    assert!(added.status.success());
+    insta::assert_json_snapshot!("message_success", stdout_json(&added));
+    assert_eq!(failed.status.code(), Some(1));
+    let error: Value = serde_json::from_slice(&failed.stderr).unwrap();
+    insta::assert_json_snapshot!("message_error", error);
+}
+
+#[test]
+fn test_apply_report_in_json() {
+    // Arrange
+    let env = TestEnv::new();
+    let operations = r#"[
+        {"op": "create_task", "title": "Write report", "priority": "high", "tag_names": ["work"]},
+        {"op": "create_task", "title": "Temp"},
+        {"op": "update_task", "id": 1, "status": "in_progress"},
+        {"op": "delete_task", "id": 2}
+    ]"#;
+
+    // Act: 削除を含むため、端末がない場合は--yesが必要
+    let output = env
+        .yaru()
+        .args(["--yes", "apply", "-"])
+        .write_stdin(operations)
+        .output()
+        .unwrap();
+
+    // Assert
+    assert!(output.status.success());
+    insta::assert_json_snapshot!("apply_report", stdout_json(&output), {
+        ".results[].task.created_at" => "[timestamp]",
+        ".results[].task.updated_at" => "[timestamp]",
+    });
+    assert_eq!(env.task_lines(), "1 Write report in_progress high work\n");
+}
+
+#[test]
+fn test_apply_with_bulk_deletions_requires_confirmation() {
+    // Arrange
+    let env = TestEnv::new();
+    let create = r#"[{"op": "create_task", "title": "A"}, {"op": "create_task", "title": "B"}]"#;
+    env.yaru()
+        .args(["apply", "-"])
+        .write_stdin(create)
+        .assert()
+        .success();
+    let delete = r#"[{"op": "delete_task", "id": 1}, {"op": "delete_task", "id": 2}]"#;
+
+    // Act: 複数件の削除は件数の入力が必要なため、端末がない場合は実行しない
+    let output = env
+        .yaru()
+        .args(["apply", "-"])
+        .write_stdin(delete)
+        .output()
+        .unwrap();
+
+    // Assert
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("pass --yes"));
+    assert_eq!(env.task_lines().lines().count(), 2);
+}
+
+#[test]
+fn test_failed_apply_rolls_back_and_reports_error() {
+    // Arrange
+    let env = TestEnv::new();
+    let operations = r#"[
+        {"op": "create_task", "title": "Write report", "tag_names": ["work"]},
+        {"op": "update_task", "id": 5, "status": "done"}
+    ]"#;
+
+    // Act
+    let output = env
+        .yaru()
+        .args(["apply", "-"])
+        .write_stdin(operations)
+        .output()
+        .unwrap();
+
+    // Assert: 失敗した操作が報告され、先に作成したタスクとタグも残らない
+    assert_eq!(output.status.code(), Some(1));
+    insta::assert_json_snapshot!("apply_report_failed", stdout_json(&output), {
+        ".results[].task.created_at" => "[timestamp]",
+        ".results[].task.updated_at" => "[timestamp]",
+    });
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("no changes were made")
+    );
+    assert_eq!(env.task_lines(), "");
+    env.yaru()
+        .args(["tag", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("work").not());
+}
+
+#[test]
+fn test_doctor_reports_healthy_database() {
+    // Arrange
+    let env = TestEnv::new();
+    env.yaru().args(["task", "add", "Check"]).assert().success();
+
+    // Act & Assert: LC_ALL=CはUTF-8ではないため警告になるが、終了コードは0
+    env.yaru()
+        .arg("doctor")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "[ok]    Database: Connected to {}",
+            env.db_path().display()
+        )))
+        .stdout(predicate::str::contains("[ok]    Schema: Up to date"))
+        .stdout(predicate::str::contains(
+            "[warn]  Unicode: Locale C is not UTF-8",
+        ))
+        .stderr(predicate::str::contains("1 warning(s), no errors"));
+}
+
+#[test]
+fn test_doctor_reports_invalid_config_with_fix() {
+    // Arrange
+    let env = TestEnv::new();
+    let config = env
+        .home
+        .path()
+        .join(".config")
+        .join("yaru")
+        .join("config.toml");
+    fs::write(&config, "[display]\nlanguage = \"xx\"\n").unwrap();
+
+    // Act & Assert: 設定を読み込めなくても診断を表示し、終了コード1で問題を伝える
+    env.yaru()
+        .arg("doctor")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "[error] Config: Unsupported language: xx",
+        ))
+        .stdout(predicate::str::contains(format!(
+            "Fix: Correct the setting in {}",
+            config.display()
+        )))
+        .stdout(predicate::str::contains(
+            "[info]  Database: Skipped because the config could not be loaded",
+        ))
+        .stderr(predicate::str::contains("1 problem(s) found"));
+}